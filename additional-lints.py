@@ -7,52 +7,52 @@ line_length_exceptions = set((
     ('./malachite-base/src/lib.rs', 65),
     ('./malachite-base/src/num/arithmetic/mod.rs', 333),
     ('./malachite-base/src/num/arithmetic/mod.rs', 334),
-    ('./malachite-base/src/num/arithmetic/mod.rs', 1334),
-    ('./malachite-base/src/num/arithmetic/mod.rs', 1574),
-    ('./malachite-base/src/num/arithmetic/mod.rs', 1575),
-    ('./malachite-base/src/num/arithmetic/mod.rs', 1576),
-    ('./malachite-base/src/num/arithmetic/mod.rs', 1577),
+    ('./malachite-base/src/num/arithmetic/mod.rs', 1339),
+    ('./malachite-base/src/num/arithmetic/mod.rs', 1579),
+    ('./malachite-base/src/num/arithmetic/mod.rs', 1580),
+    ('./malachite-base/src/num/arithmetic/mod.rs', 1581),
+    ('./malachite-base/src/num/arithmetic/mod.rs', 1582),
     ('./malachite-base/src/num/arithmetic/primorial.rs', 85),
     ('./malachite-base/src/num/arithmetic/primorial.rs', 244),
-    ('./malachite-base/src/num/arithmetic/round_to_multiple_of_power_of_2.rs', 118),
+    ('./malachite-base/src/num/arithmetic/round_to_multiple_of_power_of_2.rs', 136),
     ('./malachite-base/src/num/conversion/digits/power_of_2_digit_iterable.rs', 153),
     ('./malachite-base/src/num/conversion/digits/power_of_2_digit_iterable.rs', 155),
     ('./malachite-base/src/num/exhaustive/mod.rs', 1074),
     ('./malachite-float/src/conversion/mantissa_and_exponent.rs', 452),
     ('./malachite-float/src/conversion/mantissa_and_exponent.rs', 641),
-    ('./malachite-float/src/conversion/mod.rs', 227),
+    ('./malachite-float/src/conversion/mod.rs', 230),
     ('./malachite-float/src/lib.rs', 24),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 39),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 40),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 41),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 76),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 77),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 88),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 89),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 90),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 119),
-    ('./malachite-nz/src/integer/arithmetic/mod.rs', 121),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 43),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 44),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 45),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 99),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 100),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 111),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 112),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 113),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 335),
+    ('./malachite-nz/src/integer/arithmetic/mod.rs', 337),
     ('./malachite-nz/src/lib.rs', 36),
     ('./malachite-nz/src/lib.rs', 103),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 46),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 47),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 48),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 158),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 159),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 182),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 183),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 184),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 567),
-    ('./malachite-nz/src/natural/arithmetic/mod.rs', 569),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 50),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 51),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 52),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 162),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 163),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 186),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 187),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 188),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 574),
+    ('./malachite-nz/src/natural/arithmetic/mod.rs', 576),
     ('./malachite-nz/src/natural/conversion/digits/power_of_2_digit_iterable.rs', 528),
     ('./malachite-nz/src/natural/conversion/digits/power_of_2_digit_iterable.rs', 530),
     ('./malachite-nz/src/natural/conversion/digits/power_of_2_digit_iterable.rs', 833),
     ('./malachite-nz/src/natural/conversion/digits/power_of_2_digit_iterable.rs', 835),
-    ('./malachite-nz/src/natural/conversion/mantissa_and_exponent.rs', 323),
-    ('./malachite-nz/src/natural/conversion/mantissa_and_exponent.rs', 508),
-    ('./malachite-nz/src/natural/conversion/mod.rs', 257),
-    ('./malachite-q/src/arithmetic/mod.rs', 79),
-    ('./malachite-q/src/arithmetic/mod.rs', 81),
+    ('./malachite-nz/src/natural/conversion/mantissa_and_exponent.rs', 325),
+    ('./malachite-nz/src/natural/conversion/mantissa_and_exponent.rs', 510),
+    ('./malachite-nz/src/natural/conversion/mod.rs', 259),
+    ('./malachite-q/src/arithmetic/mod.rs', 83),
+    ('./malachite-q/src/arithmetic/mod.rs', 85),
     ('./malachite-q/src/conversion/string/from_sci_string.rs', 145),
     ('./malachite-q/src/conversion/string/from_sci_string.rs', 232),
     ('./malachite-q/src/lib.rs', 54),
@@ -0,0 +1,80 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_11};
+use malachite_base::test_util::num::factorization::divisors::divisors_naive;
+use malachite_base::test_util::runner::Runner;
+
+// See the comment in `factor::register`.
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_divisors, u8, u16, u32, u64, usize);
+
+    register_generic_benches!(runner, benchmark_divisors, u8, u16, u32, u64, usize);
+    register_generic_benches!(
+        runner,
+        benchmark_divisors_algorithms,
+        u8,
+        u16,
+        u32,
+        u64,
+        usize
+    );
+}
+
+fn demo_divisors<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, config).take(limit) {
+        println!("{}.divisors() = {:?}", n, n.divisors());
+    }
+}
+
+fn benchmark_divisors<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisors()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisors()))],
+    );
+}
+
+fn benchmark_divisors_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    usize: TryFrom<T>,
+{
+    run_benchmark(
+        &format!("{}.divisors()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_11::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.divisors())),
+            ("naive", &mut |n| no_out!(divisors_naive(n))),
+        ],
+    );
+}
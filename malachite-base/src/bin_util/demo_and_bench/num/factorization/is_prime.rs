@@ -0,0 +1,86 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_base::test_util::num::factorization::is_prime::is_prime_naive;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_is_prime);
+    register_unsigned_demos!(runner, demo_is_probable_prime);
+
+    register_unsigned_benches!(runner, benchmark_is_prime);
+    register_unsigned_benches!(runner, benchmark_is_prime_algorithms);
+}
+
+fn demo_is_prime<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        if n.is_prime() {
+            println!("{n} is prime");
+        } else {
+            println!("{n} is not prime");
+        }
+    }
+}
+
+fn demo_is_probable_prime<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        println!(
+            "{}.is_probable_prime(10) = {:?}",
+            n,
+            n.is_probable_prime(10)
+        );
+    }
+}
+
+fn benchmark_is_prime<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_prime()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_prime()))],
+    );
+}
+
+fn benchmark_is_prime_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    usize: TryFrom<T>,
+{
+    run_benchmark(
+        &format!("{}.is_prime()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.is_prime())),
+            ("naive", &mut |n| no_out!(is_prime_naive(n))),
+        ],
+    );
+}
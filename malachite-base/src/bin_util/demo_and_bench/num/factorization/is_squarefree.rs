@@ -0,0 +1,45 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::runner::Runner;
+
+// See the comment in `factor::register`.
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_is_squarefree, u8, u16, u32, u64, usize);
+
+    register_generic_benches!(runner, benchmark_is_squarefree, u8, u16, u32, u64, usize);
+}
+
+fn demo_is_squarefree<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        println!("{}.is_squarefree() = {}", n, n.is_squarefree());
+    }
+}
+
+fn benchmark_is_squarefree<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_squarefree()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_squarefree()))],
+    );
+}
@@ -9,9 +9,23 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    divisors::register(runner);
+    euler_totient::register(runner);
+    factor::register(runner);
+    is_prime::register(runner);
+    is_squarefree::register(runner);
+    moebius::register(runner);
+    prime_pi::register(runner);
     primes::register(runner);
     prime_sieve::register(runner);
 }
 
+mod divisors;
+mod euler_totient;
+mod factor;
+mod is_prime;
+mod is_squarefree;
+mod moebius;
+mod prime_pi;
 mod prime_sieve;
 mod primes;
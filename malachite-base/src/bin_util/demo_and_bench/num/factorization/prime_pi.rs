@@ -0,0 +1,73 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::PrimePi;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+use malachite_base::test_util::num::factorization::prime_pi::prime_pi_naive;
+use malachite_base::test_util::runner::Runner;
+
+// Counting the primes up to a random `u64` takes too long, so `u64` and `usize` are omitted.
+pub(crate) fn register(runner: &mut Runner) {
+    register_generic_demos!(runner, demo_prime_pi, u8, u16, u32);
+
+    register_generic_benches!(runner, benchmark_prime_pi, u8, u16, u32);
+    register_generic_benches!(runner, benchmark_prime_pi_algorithms, u8, u16, u32);
+}
+
+fn demo_prime_pi<T: PrimePi + PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        println!("{}.prime_pi() = {}", n, n.prime_pi());
+    }
+}
+
+fn benchmark_prime_pi<T: PrimePi + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.prime_pi()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.prime_pi()))],
+    );
+}
+
+fn benchmark_prime_pi_algorithms<T: PrimePi + PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    usize: TryFrom<T>,
+{
+    run_benchmark(
+        &format!("{}.prime_pi()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_11::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.prime_pi())),
+            ("naive", &mut |n| no_out!(prime_pi_naive(n))),
+        ],
+    );
+}
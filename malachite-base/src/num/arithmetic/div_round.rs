@@ -30,6 +30,20 @@ fn div_round_unsigned<T: PrimitiveUnsigned>(x: T, other: T, rm: RoundingMode) ->
                 (quotient, Less)
             }
         }
+        NearestTiesAway => {
+            if remainder >= other - remainder {
+                (quotient + T::ONE, Greater)
+            } else {
+                (quotient, Less)
+            }
+        }
+        ToOdd => {
+            if quotient.odd() {
+                (quotient, Less)
+            } else {
+                (quotient + T::ONE, Greater)
+            }
+        }
         Exact => {
             panic!("Division is not exact: {x} / {other}");
         }
@@ -69,6 +83,19 @@ macro_rules! impl_div_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, y, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -176,6 +203,20 @@ macro_rules! impl_div_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q) \left \lfloor |q| +
+            /// \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \Z, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
             ///
             /// Then
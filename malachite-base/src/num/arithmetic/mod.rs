@@ -541,6 +541,9 @@ pub mod div_mod;
 /// assert_eq!(20u128.div_round(3, Nearest), (7, Greater));
 /// assert_eq!(10usize.div_round(4, Nearest), (2, Less));
 /// assert_eq!(14u8.div_round(4, Nearest), (4, Greater));
+/// assert_eq!(10u16.div_round(4, NearestTiesAway), (3, Greater));
+/// assert_eq!(10u32.div_round(4, ToOdd), (3, Greater));
+/// assert_eq!(14u64.div_round(4, ToOdd), (3, Less));
 ///
 /// assert_eq!((-10i8).div_round(4, Down), (-2, Greater));
 /// assert_eq!((-10i16).div_round(4, Up), (-3, Less));
@@ -549,6 +552,8 @@ pub mod div_mod;
 /// assert_eq!((-20i128).div_round(3, Nearest), (-7, Less));
 /// assert_eq!((-10isize).div_round(4, Nearest), (-2, Greater));
 /// assert_eq!((-14i8).div_round(4, Nearest), (-4, Less));
+/// assert_eq!((-10i16).div_round(4, NearestTiesAway), (-3, Less));
+/// assert_eq!((-14i32).div_round(4, ToOdd), (-3, Greater));
 ///
 /// assert_eq!((-10i16).div_round(-4, Down), (2, Less));
 /// assert_eq!((-10i32).div_round(-4, Up), (3, Greater));
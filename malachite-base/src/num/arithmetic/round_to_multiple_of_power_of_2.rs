@@ -56,6 +56,20 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) = 2^k \operatorname{sgn}(q)
+            /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     2^k q & \text{if} \\quad q \in \Z, \\\\
+            ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     2^k \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
             ///
             /// The following two expressions are equivalent:
@@ -75,7 +89,9 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             ///   multiple.
             /// - If `rm` is `Up`, but `self` has too large an absolute value to round to the next
             ///   multiple with a greater absolute value.
-            /// - If `rm` is `Nearest`, but the nearest multiple is outside the representable range.
+            /// - If `rm` is `Nearest` or `NearestTiesAway`, but the nearest multiple is outside the
+            ///   representable range.
+            /// - If `rm` is `ToOdd`, but the odd multiple is outside the representable range.
             ///
             /// # Examples
             /// See [here](super::round_to_multiple_of_power_of_2#round_to_multiple_of_power_of_2).
@@ -111,7 +127,9 @@ macro_rules! impl_round_to_multiple_of_power_of_2 {
             ///   multiple.
             /// - If `rm` is `Up`, but `self` has too large an absolute value to round to the next
             ///   multiple with a greater absolute value.
-            /// - If `rm` is `Nearest`, but the nearest multiple is outside the representable range.
+            /// - If `rm` is `Nearest` or `NearestTiesAway`, but the nearest multiple is outside the
+            ///   representable range.
+            /// - If `rm` is `ToOdd`, but the odd multiple is outside the representable range.
             ///
             /// # Examples
             /// See
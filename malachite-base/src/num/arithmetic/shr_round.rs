@@ -33,7 +33,7 @@ fn shr_round_unsigned_unsigned<
             let shifted = x >> bits;
             (shifted, if shifted << bits == x { Equal } else { Less })
         }
-        Up | Ceiling if bits >= width => (T::ONE, Greater),
+        Up | Ceiling | ToOdd if bits >= width => (T::ONE, Greater),
         Up | Ceiling => {
             let shifted = x >> bits;
             if shifted << bits == x {
@@ -64,6 +64,36 @@ fn shr_round_unsigned_unsigned<
                 }
             }
         }
+        NearestTiesAway if bits == width && x >= T::power_of_2(T::WIDTH - 1) => (T::ONE, Greater),
+        NearestTiesAway if bits >= width => (T::ZERO, Less),
+        NearestTiesAway => {
+            let bm1 = bits - U::ONE;
+            let mostly_shifted = x >> bm1;
+            if mostly_shifted.odd() {
+                // round up
+                ((mostly_shifted >> 1) + T::ONE, Greater)
+            } else {
+                // round down
+                (
+                    mostly_shifted >> 1,
+                    if mostly_shifted << bm1 == x {
+                        Equal
+                    } else {
+                        Less
+                    },
+                )
+            }
+        }
+        ToOdd => {
+            let shifted = x >> bits;
+            if shifted << bits == x {
+                (shifted, Equal)
+            } else if shifted.odd() {
+                (shifted, Less)
+            } else {
+                (shifted + T::ONE, Greater)
+            }
+        }
         Exact if bits >= width => {
             panic!("Right shift is not exact: {x} >> {bits}");
         }
@@ -104,7 +134,7 @@ fn shr_round_assign_unsigned_unsigned<
                 Less
             }
         }
-        Up | Ceiling if bits >= width => {
+        Up | Ceiling | ToOdd if bits >= width => {
             *x = T::ONE;
             Greater
         }
@@ -154,6 +184,44 @@ fn shr_round_assign_unsigned_unsigned<
                 }
             }
         }
+        NearestTiesAway if bits == width && *x >= T::power_of_2(T::WIDTH - 1) => {
+            *x = T::ONE;
+            Greater
+        }
+        NearestTiesAway if bits >= width => {
+            *x = T::ZERO;
+            Less
+        }
+        NearestTiesAway => {
+            let original = *x;
+            let bm1 = bits - U::ONE;
+            *x >>= bm1;
+            let bm1_zeros = *x << bm1 == original;
+            let old_x = *x;
+            *x >>= 1;
+            if old_x.odd() {
+                // round up
+                *x += T::ONE;
+                Greater
+            } else if bm1_zeros {
+                Equal
+            } else {
+                // round down
+                Less
+            }
+        }
+        ToOdd => {
+            let original = *x;
+            *x >>= bits;
+            if *x << bits == original {
+                Equal
+            } else if x.odd() {
+                Less
+            } else {
+                *x += T::ONE;
+                Greater
+            }
+        }
         Exact if bits >= width => {
             panic!("Right shift is not exact: {} >>= {}", *x, bits);
         }
@@ -206,6 +274,20 @@ macro_rules! impl_shr_round_unsigned_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) =
+                    /// \left \lfloor q + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{ToOdd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \N, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{otherwise.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -322,6 +404,20 @@ macro_rules! impl_shr_round_signed_unsigned {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+                    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{ToOdd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{otherwise.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
                     ///
                     /// Then
@@ -462,6 +558,20 @@ macro_rules! impl_shr_round_primitive_signed {
                     /// \end{cases}
                     /// $$
                     ///
+                    /// $$
+                    /// g(x, k, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+                    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+                    /// $$
+                    ///
+                    /// $$
+                    /// g(x, k, \mathrm{ToOdd}) = \begin{cases}
+                    ///     q & \text{if} \\quad q \in \Z, \\\\
+                    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+                    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+                    ///     \lceil q \rceil & \text{otherwise.}
+                    /// \end{cases}
+                    /// $$
+                    ///
                     /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
                     ///
                     /// Then
@@ -171,6 +171,12 @@ pub trait CheckedSubMul<Y = Self, Z = Self> {
     fn checked_sub_mul(self, y: Y, z: Z) -> Option<Self::Output>;
 }
 
+/// Finds the unique number, modulo the LCM of several moduli, that is congruent to each of several
+/// residues modulo the corresponding modulus. Returns `None` if no such number exists.
+pub trait ChineseRemainder<M = Self>: Sized {
+    fn chinese_remainder(pairs: &[(Self, M)]) -> Option<Self>;
+}
+
 /// Determines whether two numbers are coprime.
 pub trait CoprimeWith<RHS = Self> {
     fn coprime_with(self, other: RHS) -> bool;
@@ -31,7 +31,9 @@ use crate::num::conversion::traits::{
     SciMantissaAndExponent, VecFromOtherType, VecFromOtherTypeSlice,
 };
 use crate::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use crate::num::factorization::traits::Primes;
+use crate::num::factorization::traits::{
+    Divisors, EulerTotient, Factor, IsPrime, IsSquarefree, Moebius, Primes,
+};
 use crate::num::logic::traits::{BitBlockAccess, HammingDistance};
 
 /// Defines functions on primitive unsigned integer types: uxx and usize.
@@ -62,7 +64,10 @@ pub trait PrimitiveUnsigned:
     + Digits<u64>
     + Digits<u128>
     + Digits<usize>
+    + Divisors
+    + EulerTotient<Output = Self>
     + ExtendedGcd<Self, Gcd = Self>
+    + Factor
     + Factorial
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
@@ -79,6 +84,8 @@ pub trait PrimitiveUnsigned:
     + HammingDistance
     + IntegerMantissaAndExponent<Self, u64>
     + IsPowerOf2
+    + IsPrime
+    + IsSquarefree
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
     + ModIsReduced<Self>
@@ -149,6 +156,7 @@ pub trait PrimitiveUnsigned:
     + ModSquarePrecomputedAssign<u64, Self>
     + ModSub<Self, Self, Output = Self>
     + ModSubAssign<Self, Self>
+    + Moebius
     + Multifactorial
     + NegMod<Self, Output = Self>
     + NegModAssign<Self>
@@ -405,7 +405,7 @@ fn primitive_float_rounding_from_unsigned<T: PrimitiveFloat, U: PrimitiveUnsigne
             Exact => {
                 panic!("Value cannot be represented exactly as an {}", T::NAME)
             }
-            Floor | Down | Nearest => (T::MAX_FINITE, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX_FINITE, Less),
             _ => (T::INFINITY, Greater),
         }
     }
@@ -422,7 +422,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                Down | Floor | Nearest => (T::MAX, Less),
+                Down | Floor | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                 _ => panic!("Cannot round away from positive infinity"),
             }
         } else {
@@ -430,7 +430,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", T::NAME)
                 }
-                Down | Ceiling | Nearest => (T::ZERO, Greater),
+                Down | Ceiling | Nearest | NearestTiesAway => (T::ZERO, Greater),
                 _ => panic!("Cannot round away from negative infinity"),
             }
         };
@@ -443,7 +443,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Value is less than 0 and rounding mode is {rm}"),
         };
     }
@@ -466,7 +466,7 @@ fn unsigned_rounding_from_primitive_float<T: PrimitiveUnsigned, U: PrimitiveFloa
             Exact => {
                 panic!("Value cannot be represented exactly as a {}", T::NAME)
             }
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
             _ => panic!(
                 "Value is greater than {}::MAX and rounding mode is {}",
                 T::NAME,
@@ -565,6 +565,15 @@ macro_rules! impl_from_float_unsigned {
                     ///   least-significant bit in its representation is selected. If the value is
                     ///   larger than the maximum finite float (which can only happen when
                     ///   converting a `u128` to an `f32`), the maximum finite float is returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest float is
+                    ///   returned. If the value is exactly between two floats, the larger one is
+                    ///   selected. If the value is larger than the maximum finite float, the
+                    ///   maximum finite float is returned.
+                    /// - If the rounding mode is `ToOdd`, then the value is returned if it can be
+                    ///   represented exactly; otherwise, whichever of the two adjacent floats has a
+                    ///   one least-significant bit in its representation is selected. If the value
+                    ///   is larger than the maximum finite float, the maximum finite float is
+                    ///   returned.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -605,6 +614,16 @@ macro_rules! impl_from_float_unsigned {
                     ///   float is greater than the maximum representable unsigned value, the
                     ///   maximum unsigned value is returned. If the float is negative, zero is
                     ///   returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest value is
+                    ///   returned. If the value is exactly between two numbers, the larger one is
+                    ///   selected. If the float is greater than the maximum representable unsigned
+                    ///   value, the maximum unsigned value is returned. If the float is negative,
+                    ///   zero is returned.
+                    /// - If the rounding mode is `ToOdd`, then the value is returned if it is an
+                    ///   integer; otherwise, whichever of the two adjacent integers is odd is
+                    ///   selected. If the float is greater than the maximum representable unsigned
+                    ///   value, the maximum unsigned value is returned. If the float is negative,
+                    ///   the function panics.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -615,7 +634,7 @@ macro_rules! impl_from_float_unsigned {
                     ///   unsigned type.
                     /// - If `value` is greater than the maximum value of the unsigned type and `rm`
                     ///   is `Ceiling` or `Up`.
-                    /// - If `value` is negative and `rm` is `Floor` or `Up`.
+                    /// - If `value` is negative and `rm` is `Floor`, `Up`, or `ToOdd`.
                     ///
                     /// # Examples
                     /// See [here](super::from#rounding_from).
@@ -741,7 +760,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                Down | Floor | Nearest => (S::MAX, Less),
+                Down | Floor | Nearest | NearestTiesAway | ToOdd => (S::MAX, Less),
                 _ => panic!("Cannot round away from extreme value"),
             }
         } else {
@@ -749,7 +768,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as a {}", S::NAME)
                 }
-                Down | Nearest | Ceiling => (S::MIN, Greater),
+                Down | Nearest | NearestTiesAway | Ceiling => (S::MIN, Greater),
                 _ => panic!("Cannot round away from extreme value"),
             }
         };
@@ -766,7 +785,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                Floor | Down | Nearest => (S::MAX, Less),
+                Floor | Down | Nearest | NearestTiesAway | ToOdd => (S::MAX, Less),
                 _ => panic!(
                     "Value is greater than {}::MAX and rounding mode is {}",
                     S::NAME,
@@ -788,7 +807,7 @@ fn signed_rounding_from_primitive_float<
                 Exact => {
                     panic!("Value cannot be represented exactly as an {}", S::NAME)
                 }
-                Ceiling | Down | Nearest => (S::MIN, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (S::MIN, Greater),
                 _ => panic!(
                     "Value is smaller than {}::MIN and rounding mode is {}",
                     S::NAME,
@@ -911,6 +930,12 @@ macro_rules! impl_from_float_signed {
                     /// - If the rounding mode is `Nearest`, then the nearest float is returned. If
                     ///   the value is exactly between two floats, the float with the zero
                     ///   least-significant bit in its representation is selected.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest float is
+                    ///   returned. If the value is exactly between two floats, the float with the
+                    ///   greater absolute value is selected.
+                    /// - If the rounding mode is `ToOdd`, then the value is returned if it can be
+                    ///   represented exactly; otherwise, whichever of the two adjacent floats has a
+                    ///   one least-significant bit in its representation is selected.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -953,6 +978,17 @@ macro_rules! impl_from_float_signed {
                     ///   float is greater than the maximum representable signed value, the maximum
                     ///   signed value is returned. If the float is smaller than the minimum
                     ///   representable signed value, the minimum signed value is returned.
+                    /// - If the rounding mode is `NearestTiesAway`, then the nearest value is
+                    ///   returned. If the value is exactly between two numbers, the one with the
+                    ///   greater absolute value is selected. If the float is greater than the
+                    ///   maximum representable signed value, the maximum signed value is returned.
+                    ///   If the float is smaller than the minimum representable signed value, the
+                    ///   minimum signed value is returned.
+                    /// - If the rounding mode is `ToOdd`, then the value is returned if it is an
+                    ///   integer; otherwise, whichever of the two adjacent integers is odd is
+                    ///   selected. If the float is greater than the maximum representable signed
+                    ///   value, the maximum signed value is returned. If the float is smaller than
+                    ///   the minimum representable signed value, the function panics.
                     ///
                     /// # Worst-case complexity
                     /// Constant time and additional memory.
@@ -964,7 +1000,7 @@ macro_rules! impl_from_float_signed {
                     /// - If `value` is greater than the maximum value of the signed type and `rm`
                     ///   is `Ceiling` or `Up`.
                     /// - If `value` is smaller than the minimum value of the signed type and `rm`
-                    ///   is `Floor` or `Up`.
+                    ///   is `Floor`, `Up`, or `ToOdd`.
                     ///
                     /// # Examples
                     /// See [here](super::from#rounding_from).
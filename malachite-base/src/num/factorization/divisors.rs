@@ -0,0 +1,56 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Divisors;
+use alloc::vec;
+use alloc::vec::Vec;
+
+fn divisors_unsigned<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    assert_ne!(n, T::ZERO, "Cannot find the divisors of 0");
+    let mut divisors = vec![T::ONE];
+    for (p, e) in n.factor() {
+        let old_len = divisors.len();
+        let mut power = T::ONE;
+        for _ in 0..e {
+            power *= p;
+            for i in 0..old_len {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl Divisors for $t {
+            /// Returns all the positive divisors of a number, in ascending order.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4} + d(n) \log d(n))$ (expected)
+            ///
+            /// $M(n) = O(d(n))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `self`, and $d(n)$ is the number
+            /// of divisors of $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> Vec<$t> {
+                divisors_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisors);
@@ -0,0 +1,56 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::EulerTotient;
+
+fn euler_totient_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO, "Cannot compute the Euler totient of 0");
+    let mut totient = T::ONE;
+    for (p, e) in n.factor() {
+        totient *= p - T::ONE;
+        for _ in 1..e {
+            totient *= p;
+        }
+    }
+    totient
+}
+
+macro_rules! impl_euler_totient {
+    ($t:ident) => {
+        impl EulerTotient for $t {
+            type Output = $t;
+
+            /// Computes Euler's totient function of a number: the number of integers in $[1, n]$
+            /// that are coprime to $n$.
+            ///
+            /// $$
+            /// f(n) = \varphi(n) = n \prod_{p \mid n} \left ( 1 - \frac{1}{p} \right ),
+            /// $$
+            /// where the product is over the primes dividing $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4})$ (expected)
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::euler_totient#euler_totient).
+            #[inline]
+            fn euler_totient(&self) -> $t {
+                euler_totient_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_euler_totient);
@@ -0,0 +1,184 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::ModMulPrecomputed;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{Factor, Primes};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
+
+// Trial division is used to find prime factors up to this limit, and Pollard's rho algorithm to
+// find larger ones.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+// The number of steps of Pollard's rho algorithm whose differences are multiplied together before a
+// GCD is taken.
+const RHO_BATCH_SIZE: u64 = 128;
+
+// Returns a nontrivial factor of `n`, or `None` if the attempt fails. `n` must be odd and
+// composite. This is Brent's variant of Pollard's rho algorithm, using the polynomial $x^2 + c$.
+fn pollard_rho_with_constant<T: PrimitiveUnsigned>(
+    n: T,
+    c: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> Option<T> {
+    let f = |x: T| x.mod_mul_precomputed(x, n, data).mod_add(c, n);
+    let mut x;
+    let mut y = T::TWO;
+    let mut ys = y;
+    let mut q = T::ONE;
+    let mut g = T::ONE;
+    let mut r = 1;
+    while g == T::ONE {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == T::ONE {
+            ys = y;
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(y);
+                q = q.mod_mul_precomputed(x.abs_diff(y), n, data);
+            }
+            g = q.gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        r <<= 1;
+        if g == n {
+            // The batch overshot, so step through it again one GCD at a time.
+            loop {
+                ys = f(ys);
+                g = x.abs_diff(ys).gcd(n);
+                if g != T::ONE {
+                    break;
+                }
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Returns a nontrivial factor of `n`, which must be odd and composite.
+fn pollard_rho<T: PrimitiveUnsigned>(n: T) -> T {
+    let data = T::precompute_mod_mul_data(&n);
+    let mut c = T::ONE;
+    loop {
+        if let Some(d) = pollard_rho_with_constant(n, c, &data) {
+            return d;
+        }
+        c += T::ONE;
+    }
+}
+
+// If `n` is a perfect power, returns $(r, k)$ such that $r^k = n$ and $k$ is prime; otherwise,
+// returns `None`. `n` must have no prime factors less than or equal to `TRIAL_DIVISION_LIMIT`, so
+// that only exponents up to a tenth of its bit length need to be checked.
+fn perfect_power<T: PrimitiveUnsigned>(n: T) -> Option<(T, u64)> {
+    let max_exponent = n.significant_bits() / 10;
+    u64::primes()
+        .take_while(|&k| k <= max_exponent)
+        .find_map(|k| n.checked_root(k).map(|r| (r, k)))
+}
+
+// Sorts a list of prime-exponent pairs and combines the pairs with equal primes.
+fn group_primes<T: Ord>(mut primes: Vec<(T, u64)>) -> Vec<(T, u64)> {
+    primes.sort_unstable();
+    let mut factors: Vec<(T, u64)> = Vec::new();
+    for (p, e) in primes {
+        match factors.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => factors.push((p, e)),
+        }
+    }
+    factors
+}
+
+fn factor_unsigned<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO, "Cannot factor 0");
+    let mut factors = Vec::new();
+    let limit = T::saturating_from(TRIAL_DIVISION_LIMIT);
+    for p in T::primes() {
+        if p > n / p {
+            // `n` has no prime factors less than or equal to its square root, so it is 1 or prime.
+            if n != T::ONE {
+                factors.push((n, 1));
+            }
+            return factors;
+        }
+        let mut exponent = 0;
+        while n.divisible_by(p) {
+            n /= p;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((p, exponent));
+        }
+        if p >= limit {
+            break;
+        }
+    }
+    if n == T::ONE {
+        return factors;
+    }
+    // `n` has no prime factors less than or equal to the trial division limit. Split it into
+    // primes, using Pollard's rho algorithm for anything that isn't a perfect power.
+    let mut large_primes = Vec::new();
+    let mut composites = vec![(n, 1)];
+    while let Some((m, e)) = composites.pop() {
+        if m.is_prime() {
+            large_primes.push((m, e));
+        } else if let Some((r, k)) = perfect_power(m) {
+            composites.push((r, e * k));
+        } else {
+            let d = pollard_rho(m);
+            composites.push((d, e));
+            composites.push((m / d, e));
+        }
+    }
+    factors.extend(group_primes(large_primes));
+    factors
+}
+
+macro_rules! impl_factor {
+    ($t:ident) => {
+        impl Factor for $t {
+            /// Returns the prime factorization of a number.
+            ///
+            /// The result is a list of pairs $(p, e)$, where each $p$ is a prime and each $e$ is a
+            /// positive exponent, such that the number equals the product of all $p^e$. The list is
+            /// sorted by $p$, in ascending order. The factorization of 1 is empty.
+            ///
+            /// Small prime factors are found by trial division, and larger ones by Pollard's rho
+            /// algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4})$ (expected)
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            #[inline]
+            fn factor(&self) -> Vec<($t, u64)> {
+                factor_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_factor);
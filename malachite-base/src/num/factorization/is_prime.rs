@@ -0,0 +1,307 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{CheckedSqrt, ModMulPrecomputed, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
+use crate::num::factorization::traits::{IsPrime, Primality, Primes};
+use crate::num::logic::traits::TrailingZeros;
+
+// The odd primes less than 64.
+const SMALL_ODD_PRIMES: [u8; 17] =
+    [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+// Every composite number less than this has a prime factor less than 64.
+const SMALL_ODD_PRIMES_LIMIT: u64 = 67 * 67;
+
+// The strong probable-prime test with these bases correctly identifies every prime less than
+// 4759123141.
+const SMALL_WITNESSES_LIMIT: u64 = 4759123141;
+const SMALL_WITNESSES: [u64; 3] = [2, 7, 61];
+
+// The strong probable-prime test with these bases, found by Jim Sinclair, correctly identifies
+// every prime less than $2^{64}$.
+const LARGE_WITNESSES: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+// Divides `n` by the odd primes less than 64, returning whether `n` is prime if that is enough to
+// tell, and `None` otherwise. `n` must be odd and greater than 1.
+fn trial_division<T: PrimitiveUnsigned>(n: T) -> Option<bool> {
+    for p in SMALL_ODD_PRIMES {
+        let p = T::from(p);
+        if n == p {
+            return Some(true);
+        } else if n.divisible_by(p) {
+            return Some(false);
+        }
+    }
+    if n < T::exact_from(SMALL_ODD_PRIMES_LIMIT) {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+// Computes $x^e \bmod n$.
+fn mod_pow_helper<T: PrimitiveUnsigned>(
+    x: T,
+    exp: T,
+    n: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> T {
+    let mut out = T::ONE;
+    for bit in exp.bits().rev() {
+        out = out.mod_mul_precomputed(out, n, data);
+        if bit {
+            out = out.mod_mul_precomputed(x, n, data);
+        }
+    }
+    out
+}
+
+// Returns whether `n` is a strong probable prime to base `a`. `n` must be odd, $n - 1 = 2^s d$ with
+// $d$ odd, and $1 < a < n - 1$.
+fn is_strong_probable_prime<T: PrimitiveUnsigned>(
+    n: T,
+    d: T,
+    s: u64,
+    a: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let mut x = mod_pow_helper(a, d, n, data);
+    if x == T::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.mod_mul_precomputed(x, n, data);
+        if x == n_minus_1 {
+            return true;
+        } else if x == T::ONE {
+            return false;
+        }
+    }
+    false
+}
+
+// Computes $x/2 \bmod n$, where $n$ is odd.
+fn half_mod<T: PrimitiveUnsigned>(x: T, n: T) -> T {
+    if x.odd() {
+        (x >> 1) + (n >> 1) + T::ONE
+    } else {
+        x >> 1
+    }
+}
+
+// Returns whether `n` is a strong Lucas probable prime, with parameters chosen by Selfridge's
+// method: $D$ is the first of 5, -7, 9, -11, ... for which the Jacobi symbol $(D/n)$ is -1, $P =
+// 1$, and $Q = (1 - D)/4$. `n` must be odd, not a perfect square, less than `T::MAX`, and greater
+// than every $|D|$ that is tried; the last condition holds for any `n` that has no prime factor
+// less than 64, since a suitable $D$ is always found long before $|D|$ reaches $n$.
+fn is_strong_lucas_probable_prime<T: PrimitiveUnsigned>(
+    n: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> bool {
+    let mut d_abs = 5u64;
+    let (d, q) = loop {
+        let d_abs_t = T::exact_from(d_abs);
+        let q_abs = T::exact_from((d_abs >> 2) + u64::from(d_abs & 3 == 3));
+        // $D > 0$ exactly when $|D| \equiv 1 \pmod 4$
+        let (d, q) = if d_abs & 3 == 1 {
+            (d_abs_t, n - q_abs)
+        } else {
+            (n - d_abs_t, q_abs)
+        };
+        match d.jacobi_symbol(n) {
+            -1 => break (d, q),
+            0 => return false,
+            _ => d_abs += 2,
+        }
+    };
+    let n_plus_1 = n + T::ONE;
+    let s = TrailingZeros::trailing_zeros(n_plus_1);
+    let mut u = T::ONE;
+    let mut v = T::ONE;
+    let mut q_k = q;
+    // Compute $U_k$, $V_k$, and $Q^k$ for the odd part $k$ of $n + 1$, using $U_{2k} = U_k V_k$,
+    // $V_{2k} = V_k^2 - 2Q^k$, $U_{k+1} = (U_k + V_k)/2$, and $V_{k+1} = (DU_k + V_k)/2$.
+    for bit in (n_plus_1 >> s).bits().rev().skip(1) {
+        u = u.mod_mul_precomputed(v, n, data);
+        v = v
+            .mod_mul_precomputed(v, n, data)
+            .mod_sub(q_k.mod_add(q_k, n), n);
+        q_k = q_k.mod_mul_precomputed(q_k, n, data);
+        if bit {
+            (u, v) = (
+                half_mod(u.mod_add(v, n), n),
+                half_mod(d.mod_mul_precomputed(u, n, data).mod_add(v, n), n),
+            );
+            q_k = q_k.mod_mul_precomputed(q, n, data);
+        }
+    }
+    if u == T::ZERO || v == T::ZERO {
+        return true;
+    }
+    for _ in 1..s {
+        v = v
+            .mod_mul_precomputed(v, n, data)
+            .mod_sub(q_k.mod_add(q_k, n), n);
+        if v == T::ZERO {
+            return true;
+        }
+        q_k = q_k.mod_mul_precomputed(q_k, n, data);
+    }
+    false
+}
+
+// Returns whether `n` is prime, using trial division followed by strong probable-prime tests with a
+// set of bases that is known to give the correct answer for every `u64`.
+fn is_prime_u64(n: u64) -> bool {
+    if n < 3 {
+        return n == 2;
+    } else if n.even() {
+        return false;
+    } else if let Some(result) = trial_division(n) {
+        return result;
+    }
+    let witnesses: &[u64] = if n < SMALL_WITNESSES_LIMIT {
+        &SMALL_WITNESSES
+    } else {
+        &LARGE_WITNESSES
+    };
+    let s = TrailingZeros::trailing_zeros(n - 1);
+    let d = (n - 1) >> s;
+    let data = u64::precompute_mod_mul_data(&n);
+    // Every witness is less than `n - 1`, since `n` is at least 67 * 67 when the small witnesses
+    // are used and at least 4759123141 when the large ones are.
+    witnesses
+        .iter()
+        .all(|&a| is_strong_probable_prime(n, d, s, a, &data))
+}
+
+// Determines whether a `u128` is prime. Numbers less than $2^{64}$ are handled by `is_prime_u64`;
+// larger numbers undergo trial division, the Baillie-PSW test (a strong probable-prime test to base
+// 2 followed by a strong Lucas probable-prime test), and then `rounds` more strong probable-prime
+// tests to the bases 3, 5, 7, 11, ....
+#[allow(clippy::let_unit_value)]
+fn is_probable_prime_u128(n: u128, rounds: u64) -> Primality {
+    if let Ok(n) = u64::try_from(n) {
+        return if is_prime_u64(n) {
+            Primality::Prime
+        } else {
+            Primality::NotPrime
+        };
+    }
+    // `n` is at least $2^{64}$, so it is not equal to any of the small primes or witnesses, and its
+    // Selfridge parameter $|D|$ is much smaller than it. `u128::MAX` is divisible by 3, so if `n`
+    // passes trial division, `n + 1` doesn't overflow.
+    if n.even() || trial_division(n) == Some(false) || n.checked_sqrt().is_some() {
+        return Primality::NotPrime;
+    }
+    let s = TrailingZeros::trailing_zeros(n - 1);
+    let d = (n - 1) >> s;
+    let data = u128::precompute_mod_mul_data(&n);
+    if is_strong_probable_prime(n, d, s, 2, &data)
+        && is_strong_lucas_probable_prime(n, &data)
+        && u128::primes()
+            .skip(1)
+            .take(usize::exact_from(rounds))
+            .all(|a| is_strong_probable_prime(n, d, s, a, &data))
+    {
+        Primality::ProbablyPrime
+    } else {
+        Primality::NotPrime
+    }
+}
+
+macro_rules! impl_is_prime_u64_or_smaller {
+    ($t:ident) => {
+        impl IsPrime for $t {
+            /// Determines whether a number is prime.
+            ///
+            /// The answer is always correct: after trial division by small primes, the number
+            /// undergoes the Miller-Rabin test with a set of bases that is known to identify
+            /// every prime less than $2^{64}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_prime).
+            #[inline]
+            fn is_prime(&self) -> bool {
+                is_prime_u64(u64::wrapping_from(*self))
+            }
+
+            /// Determines whether a number is prime, returning a [`Primality`].
+            ///
+            /// Since [`is_prime`](IsPrime::is_prime) always gives the correct answer for numbers of
+            /// this type, `rounds` is ignored, and the result is never
+            /// [`ProbablyPrime`](Primality::ProbablyPrime).
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_probable_prime).
+            #[inline]
+            fn is_probable_prime(&self, _rounds: u64) -> Primality {
+                if self.is_prime() {
+                    Primality::Prime
+                } else {
+                    Primality::NotPrime
+                }
+            }
+        }
+    };
+}
+impl_is_prime_u64_or_smaller!(u8);
+impl_is_prime_u64_or_smaller!(u16);
+impl_is_prime_u64_or_smaller!(u32);
+impl_is_prime_u64_or_smaller!(u64);
+impl_is_prime_u64_or_smaller!(usize);
+
+impl IsPrime for u128 {
+    /// Determines whether a `u128` is prime.
+    ///
+    /// If the number is less than $2^{64}$, the answer is always correct. Otherwise, after trial
+    /// division by small primes, the number undergoes the Baillie-PSW test: a Miller-Rabin test to
+    /// base 2 followed by a strong Lucas test. No composite number is known to pass this test.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        is_probable_prime_u128(*self, 0) != Primality::NotPrime
+    }
+
+    /// Determines whether a `u128` is prime, returning a [`Primality`].
+    ///
+    /// If the number is less than $2^{64}$, the result is [`Prime`](Primality::Prime) or
+    /// [`NotPrime`](Primality::NotPrime), and `rounds` is ignored. Otherwise, the number undergoes
+    /// the Baillie-PSW test, as in [`is_prime`](IsPrime::is_prime), followed by `rounds` more
+    /// Miller-Rabin tests, using the odd primes 3, 5, 7, 11, ... as bases. The result is
+    /// [`NotPrime`](Primality::NotPrime) if any test fails, and
+    /// [`ProbablyPrime`](Primality::ProbablyPrime) otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `rounds`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_probable_prime).
+    #[inline]
+    fn is_probable_prime(&self, rounds: u64) -> Primality {
+        is_probable_prime_u128(*self, rounds)
+    }
+}
@@ -0,0 +1,38 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::IsSquarefree;
+
+fn is_squarefree_unsigned<T: PrimitiveUnsigned>(n: T) -> bool {
+    n != T::ZERO && n.factor().into_iter().all(|(_, e)| e == 1)
+}
+
+macro_rules! impl_is_squarefree {
+    ($t:ident) => {
+        impl IsSquarefree for $t {
+            /// Determines whether a number is squarefree; that is, whether it is not divisible by
+            /// the square of any prime. 0 is not squarefree, and 1 is.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4})$ (expected)
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Examples
+            /// See [here](super::is_squarefree#is_squarefree).
+            #[inline]
+            fn is_squarefree(&self) -> bool {
+                is_squarefree_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_squarefree);
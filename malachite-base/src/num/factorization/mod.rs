@@ -6,6 +6,124 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// [`Divisors`](traits::Divisors), a trait for finding all the divisors of a number.
+///
+/// # divisors
+/// ```
+/// use malachite_base::num::factorization::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors(), &[1]);
+/// assert_eq!(12u16.divisors(), &[1, 2, 3, 4, 6, 12]);
+/// assert_eq!(97u32.divisors(), &[1, 97]);
+/// assert_eq!(
+///     1000u64.divisors(),
+///     &[1, 2, 4, 5, 8, 10, 20, 25, 40, 50, 100, 125, 200, 250, 500, 1000]
+/// );
+/// ```
+pub mod divisors;
+/// [`EulerTotient`](traits::EulerTotient), a trait for computing Euler's totient function.
+///
+/// # euler_totient
+/// ```
+/// use malachite_base::num::factorization::traits::EulerTotient;
+///
+/// assert_eq!(1u8.euler_totient(), 1);
+/// assert_eq!(12u16.euler_totient(), 4);
+/// assert_eq!(97u32.euler_totient(), 96);
+/// assert_eq!(1000000u64.euler_totient(), 400000);
+/// ```
+pub mod euler_totient;
+/// [`Factor`](traits::Factor), a trait for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor(), &[]);
+/// assert_eq!(12u8.factor(), &[(2, 2), (3, 1)]);
+/// assert_eq!(1001u16.factor(), &[(7, 1), (11, 1), (13, 1)]);
+/// assert_eq!(4294967297u64.factor(), &[(641, 1), (6700417, 1)]);
+/// assert_eq!(
+///     4951760154835678088235319297u128.factor(),
+///     &[(2147483647, 1), (2305843009213693951, 1)]
+/// );
+/// ```
+pub mod factor;
+/// [`IsPrime`](traits::IsPrime), a trait for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(1u8.is_prime(), false);
+/// assert_eq!(2u16.is_prime(), true);
+/// assert_eq!(91u32.is_prime(), false);
+/// assert_eq!(97u32.is_prime(), true);
+/// assert_eq!(3215031751u64.is_prime(), false);
+/// assert_eq!(18446744073709551557u64.is_prime(), true);
+/// assert_eq!(
+///     170141183460469231731687303715884105727u128.is_prime(),
+///     true
+/// );
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::factorization::traits::{IsPrime, Primality};
+///
+/// assert_eq!(91u32.is_probable_prime(0), Primality::NotPrime);
+/// assert_eq!(97u32.is_probable_prime(0), Primality::Prime);
+/// assert_eq!(
+///     18446744073709551557u64.is_probable_prime(10),
+///     Primality::Prime
+/// );
+/// assert_eq!(u128::MAX.is_probable_prime(10), Primality::NotPrime);
+/// assert_eq!(
+///     170141183460469231731687303715884105727u128.is_probable_prime(10),
+///     Primality::ProbablyPrime
+/// );
+/// ```
+pub mod is_prime;
+/// [`IsSquarefree`](traits::IsSquarefree), a trait for determining whether a number is
+/// squarefree.
+///
+/// # is_squarefree
+/// ```
+/// use malachite_base::num::factorization::traits::IsSquarefree;
+///
+/// assert_eq!(0u8.is_squarefree(), false);
+/// assert_eq!(1u8.is_squarefree(), true);
+/// assert_eq!(12u16.is_squarefree(), false);
+/// assert_eq!(30u32.is_squarefree(), true);
+/// assert_eq!(u64::MAX.is_squarefree(), true);
+/// ```
+pub mod is_squarefree;
+/// [`Moebius`](traits::Moebius), a trait for computing the Möbius function.
+///
+/// # moebius
+/// ```
+/// use malachite_base::num::factorization::traits::Moebius;
+///
+/// assert_eq!(1u8.moebius(), 1);
+/// assert_eq!(6u16.moebius(), 1);
+/// assert_eq!(12u16.moebius(), 0);
+/// assert_eq!(30u32.moebius(), -1);
+/// assert_eq!(97u64.moebius(), -1);
+/// ```
+pub mod moebius;
+/// [`PrimePi`](traits::PrimePi), a trait for counting the primes less than or equal to a number.
+///
+/// # prime_pi
+/// ```
+/// use malachite_base::num::factorization::traits::PrimePi;
+///
+/// assert_eq!(0u8.prime_pi(), 0);
+/// assert_eq!(10u8.prime_pi(), 4);
+/// assert_eq!(100u16.prime_pi(), 25);
+/// assert_eq!(u32::MAX.prime_pi(), 203280221);
+/// assert_eq!(1000000000000u64.prime_pi(), 37607912018);
+/// ```
+pub mod prime_pi;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
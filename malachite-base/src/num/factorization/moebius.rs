@@ -0,0 +1,55 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Moebius;
+
+fn moebius_unsigned<T: PrimitiveUnsigned>(n: T) -> i8 {
+    assert_ne!(n, T::ZERO, "Cannot compute the Möbius function of 0");
+    let mut moebius = 1;
+    for (_, e) in n.factor() {
+        if e > 1 {
+            return 0;
+        }
+        moebius = -moebius;
+    }
+    moebius
+}
+
+macro_rules! impl_moebius {
+    ($t:ident) => {
+        impl Moebius for $t {
+            /// Computes the Möbius function of a number.
+            ///
+            /// $$
+            /// f(n) = \mu(n) = \\begin{cases}
+            ///     0 & \text{if} \\quad p^2 \mid n \text{ for some prime } p, \\\\
+            ///     (-1)^k & \text{otherwise, where } n \text{ has } k \text{ prime factors.}
+            /// \\end{cases}
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{1/4})$ (expected)
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius).
+            #[inline]
+            fn moebius(&self) -> i8 {
+                moebius_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_moebius);
@@ -0,0 +1,328 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt, NextPowerOf2};
+use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
+use crate::num::factorization::traits::{PrimePi, Primes};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+// Primes up to this limit are counted directly, by sieving. Above it, the Lagarias-Miller-Odlyzko
+// algorithm is used.
+const SIEVE_LIMIT: u64 = 1 << 22;
+
+// $\phi(x, c)$, the number of positive integers less than or equal to $x$ that are not divisible by
+// any of the first $c$ primes, is looked up in a table when $c$ is the length of this array.
+const SMALL_PRIMES: [u64; 6] = [2, 3, 5, 7, 11, 13];
+
+// The product of `SMALL_PRIMES`.
+const SMALL_PRIMORIAL: u64 = 30030;
+
+// $\phi(30030, 6)$, the product of $p - 1$ over `SMALL_PRIMES`.
+const SMALL_PRIMORIAL_PHI: u64 = 5760;
+
+// Returns a table containing $\phi(x, 6)$ for $0 \leq x < 30030$. Since $\phi(x + 30030, 6) =
+// \phi(x, 6) + 5760$, this is enough to compute $\phi(x, 6)$ for any $x$.
+fn small_phi_table() -> Vec<u16> {
+    let mut count = 0;
+    (0..SMALL_PRIMORIAL)
+        .map(|x| {
+            if x != 0 && SMALL_PRIMES.iter().all(|p| x % p != 0) {
+                count += 1;
+            }
+            count
+        })
+        .collect()
+}
+
+// Returns the least prime factor and the Möbius function of every integer from 0 to `limit`. The
+// least prime factor of 1 is taken to be `u32::MAX`, so that it is greater than every prime.
+fn least_prime_factors_and_moebius(limit: usize) -> (Vec<u32>, Vec<i8>) {
+    let mut lpf = vec![0; limit + 1];
+    let mut mu = vec![1; limit + 1];
+    for p in 2..=limit {
+        if lpf[p] != 0 {
+            continue;
+        }
+        for k in (p..=limit).step_by(p) {
+            if lpf[k] == 0 {
+                lpf[k] = u32::exact_from(p);
+            }
+            mu[k] = -mu[k];
+        }
+        if p <= limit / p {
+            for k in (p * p..=limit).step_by(p * p) {
+                mu[k] = 0;
+            }
+        }
+    }
+    lpf[1] = u32::MAX;
+    (lpf, mu)
+}
+
+// Sets `sieve[i]` to whether `low + i` is prime. `low` must be at least 2, and `primes` must
+// contain every prime whose square is less than `low + sieve.len()`.
+fn sieve_segment(sieve: &mut [bool], low: u64, primes: &[u64]) {
+    sieve.fill(true);
+    let high = low + u64::exact_from(sieve.len());
+    for &p in primes {
+        if p > (high - 1) / p {
+            break;
+        }
+        let mut k = max(p * p, low.div_ceil(p) * p);
+        while k < high {
+            sieve[usize::exact_from(k - low)] = false;
+            k += p;
+        }
+    }
+}
+
+// The number of set bits in `sieve` whose indices are at least `start` and at most `end`.
+fn count_ones_between(sieve: &[u64], start: usize, end: usize) -> u64 {
+    if start > end {
+        return 0;
+    }
+    let (start_word, end_word) = (start >> 6, end >> 6);
+    let start_mask = u64::MAX << (start & 63);
+    let end_mask = u64::MAX >> (63 - (end & 63));
+    if start_word == end_word {
+        return u64::from((sieve[start_word] & start_mask & end_mask).count_ones());
+    }
+    u64::from((sieve[start_word] & start_mask).count_ones())
+        + sieve[start_word + 1..end_word]
+            .iter()
+            .map(|w| u64::from(w.count_ones()))
+            .sum::<u64>()
+        + u64::from((sieve[end_word] & end_mask).count_ones())
+}
+
+// Computes $P_2(x, y)$, the number of integers up to $x$ that are the product of exactly two
+// primes greater than $y$:
+//
+// $$
+// P_2(x, y) = \sum_{y < p \leq \sqrt{x}} (\pi(x/p) - \pi(p) + 1).
+// $$
+//
+// The values of $\pi(x/p)$ are found by sieving $(\sqrt{x}, x/y]$ in segments. The primes $p$ whose
+// quotients $x/p$ fall into a segment form a range no longer than the segment, which is sieved
+// separately. `primes` must contain the primes up to $\max(y, \sqrt{x/y})$, and $\pi(y)$ must be
+// `pi_y`.
+fn p2(x: u64, y: u64, pi_y: u64, primes: &[u64]) -> u64 {
+    let sqrt = x.floor_sqrt();
+    let limit = x / y;
+    let segment_len = limit.floor_sqrt().next_power_of_2();
+    let mut sieve = vec![false; usize::exact_from(segment_len)];
+    let mut p_sieve = vec![false; usize::exact_from(segment_len) + 1];
+    let mut pi_sqrt = pi_y;
+    let mut low = y + 1;
+    while low <= sqrt {
+        let len = min(segment_len, sqrt - low + 1);
+        let sieve = &mut sieve[..usize::exact_from(len)];
+        sieve_segment(sieve, low, primes);
+        pi_sqrt += u64::exact_from(sieve.iter().filter(|&&b| b).count());
+        low += len;
+    }
+    let mut sum = 0;
+    // $\pi(\text{low} - 1)$
+    let mut pi_low = pi_sqrt;
+    let mut low = sqrt + 1;
+    while low <= limit {
+        let len = min(segment_len, limit - low + 1);
+        let high = low + len;
+        let sieve = &mut sieve[..usize::exact_from(len)];
+        sieve_segment(sieve, low, primes);
+        // The primes $p$ with $\text{low} \leq x/p < \text{high}$
+        let p_low = max(x / high + 1, y + 1);
+        let p_high = min(x / low, sqrt);
+        let mut count = pi_low;
+        if p_low <= p_high {
+            let p_sieve = &mut p_sieve[..usize::exact_from(p_high - p_low + 1)];
+            sieve_segment(p_sieve, p_low, primes);
+            // Going through the primes in descending order makes their quotients ascend
+            let mut i = 0;
+            for (p, _) in (p_low..=p_high)
+                .rev()
+                .zip(p_sieve.iter().rev())
+                .filter(|p| *p.1)
+            {
+                let j = usize::exact_from(x / p - low);
+                count += u64::exact_from(sieve[i..=j].iter().filter(|&&b| b).count());
+                i = j + 1;
+                sum += count;
+            }
+            count += u64::exact_from(sieve[i..].iter().filter(|&&b| b).count());
+        } else {
+            count += u64::exact_from(sieve.iter().filter(|&&b| b).count());
+        }
+        pi_low = count;
+        low = high;
+    }
+    // $\sum_{y < p \leq \sqrt{x}} (\pi(p) - 1) = \sum_{\pi(y) < i \leq \pi(\sqrt{x})} (i - 1)$
+    sum - (pi_sqrt * (pi_sqrt - 1) - pi_y * (pi_y - 1)) / 2
+}
+
+// Computes the contribution of the special leaves to $\phi(x, \pi(y))$:
+//
+// $$
+// S_2 = -\sum_{c < b < \pi(y)} \sum_{\substack{m \leq y < mp_b \\ \operatorname{lpf}(m) > p_b}}
+//     \mu(m) \phi(x/(mp_b), b - 1),
+// $$
+//
+// where $c$ is the length of `SMALL_PRIMES`. The values of $\phi$ are found by sieving $[1, x/y]$
+// in segments, crossing off the multiples of each prime in turn and counting the numbers that
+// remain. `primes` must contain the primes up to $y$.
+fn s2(x: u64, y: u64, primes: &[u64], lpf: &[u32], mu: &[i8]) -> i128 {
+    let limit = x / y + 1;
+    let segment_len = max(limit.floor_sqrt().next_power_of_2(), 64);
+    // Bit $i$ of the sieve is set if $\text{low} + i$ has not been crossed off
+    let mut sieve = vec![0; usize::exact_from(segment_len >> 6)];
+    // The next multiple of each prime that may need to be crossed off
+    let mut next = primes.to_vec();
+    // `phi[b]` is $\phi(\text{low} - 1, b)$
+    let mut phi = vec![0; primes.len()];
+    let mut sum = 0;
+    let mut low = 1;
+    while low < limit {
+        let len = min(segment_len, limit - low);
+        let high = low + len;
+        let len = usize::exact_from(len);
+        let sieve = &mut sieve[..len.div_ceil(64)];
+        // Since `low` is odd, the even numbers have odd indices
+        sieve.fill(0x5555_5555_5555_5555);
+        if len & 63 != 0 {
+            sieve[len >> 6] &= (1 << (len & 63)) - 1;
+        }
+        // Leaves $\phi(x/n, b)$ with $b < c$ don't occur, so the multiples of the first $c$ primes
+        // are crossed off before any counting is done
+        for (&p, k) in primes
+            .iter()
+            .zip(next.iter_mut())
+            .take(SMALL_PRIMES.len())
+            .skip(1)
+        {
+            while *k < high {
+                let i = usize::exact_from(*k - low);
+                sieve[i >> 6] &= !(1 << (i & 63));
+                *k += p;
+            }
+        }
+        let mut segment_count = count_ones_between(sieve, 0, len - 1);
+        for b in SMALL_PRIMES.len()..primes.len() {
+            let p = primes[b];
+            let min_m = max(x / p / high, y / p);
+            let max_m = min(x / p / low, y);
+            // Every $m > 1$ in a leaf has a prime factor greater than $p$, and $m = 1$ only occurs
+            // if $p > y$. As `low` increases, `max_m` decreases, so $p$ will never be used again.
+            if p >= max_m {
+                break;
+            }
+            // As $m$ decreases, $x/(mp)$ increases, so the sieve can be counted incrementally
+            let mut count = phi[b];
+            let mut start = 0;
+            for m in (min_m + 1..=max_m).rev() {
+                let m_index = usize::exact_from(m);
+                if mu[m_index] != 0 && p < u64::from(lpf[m_index]) {
+                    let end = usize::exact_from(x / p / m - low);
+                    count += count_ones_between(sieve, start, end);
+                    start = end + 1;
+                    sum -= i128::from(mu[m_index]) * i128::from(count);
+                }
+            }
+            phi[b] += segment_count;
+            let k = &mut next[b];
+            while *k < high {
+                let i = usize::exact_from(*k - low);
+                let mask = 1 << (i & 63);
+                if sieve[i >> 6] & mask != 0 {
+                    sieve[i >> 6] &= !mask;
+                    segment_count -= 1;
+                }
+                *k += p;
+            }
+        }
+        low = high;
+    }
+    sum
+}
+
+// Computes $\pi(x)$ using the Lagarias-Miller-Odlyzko algorithm. We take $y = 2\lfloor x^{1/3}
+// \rfloor$; doubling $y$ makes the sieving interval in $S_2$ and $P_2$ shorter, at the cost of
+// more special leaves. Since $x^{1/3} < y < x^{1/2}$, no integer up to $x$ is the product of three
+// primes greater than $y$, so
+//
+// $$
+// \pi(x) = \phi(x, \pi(y)) + \pi(y) - 1 - P_2(x, y).
+// $$
+//
+// $\phi(x, \pi(y))$ is split into the ordinary leaves
+//
+// $$
+// S_1 = \sum_{\substack{m \leq y \\ \operatorname{lpf}(m) > p_c}} \mu(m) \phi(x/m, c)
+// $$
+//
+// and the special leaves $S_2$, where $c$ is the length of `SMALL_PRIMES`.
+fn prime_pi_lmo(x: u64) -> u64 {
+    let y = x.floor_root(3) << 1;
+    let primes: Vec<u64> =
+        u64::primes_less_than_or_equal_to(&max(y, (x / y).floor_sqrt())).collect();
+    let pi_y = primes.partition_point(|&p| p <= y);
+    let (lpf, mu) = least_prime_factors_and_moebius(usize::exact_from(y));
+    let small_phi = small_phi_table();
+    let mut phi = 0;
+    for (m, (&lpf_m, &mu_m)) in lpf.iter().zip(mu.iter()).enumerate().skip(1) {
+        if mu_m != 0 && u64::from(lpf_m) > SMALL_PRIMES[SMALL_PRIMES.len() - 1] {
+            let q = x / u64::exact_from(m);
+            let phi_q = q / SMALL_PRIMORIAL * SMALL_PRIMORIAL_PHI
+                + u64::from(small_phi[usize::exact_from(q % SMALL_PRIMORIAL)]);
+            phi += i128::from(mu_m) * i128::from(phi_q);
+        }
+    }
+    phi += s2(x, y, &primes[..pi_y], &lpf, &mu);
+    let pi_y = u64::exact_from(pi_y);
+    u64::exact_from(phi) + pi_y - 1 - p2(x, y, pi_y, &primes)
+}
+
+fn prime_pi_u64(n: u64) -> u64 {
+    if n <= SIEVE_LIMIT {
+        u64::exact_from(u64::primes_less_than_or_equal_to(&n).count())
+    } else {
+        prime_pi_lmo(n)
+    }
+}
+
+macro_rules! impl_prime_pi {
+    ($t:ident) => {
+        impl PrimePi for $t {
+            /// Counts the primes less than or equal to a number; that is, computes the
+            /// prime-counting function $\pi(n)$.
+            ///
+            /// Small numbers are handled by sieving, and larger ones by the Lagarias-Miller-Odlyzko
+            /// algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{5/6}/\log n)$
+            ///
+            /// $M(n) = O(n^{1/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Examples
+            /// See [here](super::prime_pi#prime_pi).
+            #[inline]
+            fn prime_pi(&self) -> u64 {
+                prime_pi_u64(u64::wrapping_from(*self))
+            }
+        }
+    };
+}
+impl_prime_pi!(u8);
+impl_prime_pi!(u16);
+impl_prime_pi!(u32);
+impl_prime_pi!(u64);
+impl_prime_pi!(usize);
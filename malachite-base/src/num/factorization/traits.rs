@@ -6,6 +6,8 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use alloc::vec::Vec;
+
 pub trait Primes {
     type I: Iterator<Item = Self>;
     type LI: Iterator<Item = Self>;
@@ -16,3 +18,54 @@ pub trait Primes {
 
     fn primes() -> Self::I;
 }
+
+/// The result of a primality test.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Primality {
+    /// The number is definitely not prime.
+    NotPrime,
+    /// The number passed a probabilistic primality test, and is very likely, but not certainly,
+    /// prime.
+    ProbablyPrime,
+    /// The number is definitely prime.
+    Prime,
+}
+
+/// Determines whether a number is prime.
+pub trait IsPrime {
+    fn is_prime(&self) -> bool;
+
+    fn is_probable_prime(&self, rounds: u64) -> Primality;
+}
+
+/// Finds the prime factorization of a number.
+pub trait Factor: Sized {
+    fn factor(&self) -> Vec<(Self, u64)>;
+}
+
+/// Finds all the positive divisors of a number.
+pub trait Divisors: Sized {
+    fn divisors(&self) -> Vec<Self>;
+}
+
+/// Computes Euler's totient function of a number.
+pub trait EulerTotient {
+    type Output;
+
+    fn euler_totient(&self) -> Self::Output;
+}
+
+/// Computes the Möbius function of a number.
+pub trait Moebius {
+    fn moebius(&self) -> i8;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimePi {
+    fn prime_pi(&self) -> u64;
+}
+
+/// Determines whether a number is squarefree.
+pub trait IsSquarefree {
+    fn is_squarefree(&self) -> bool;
+}
@@ -21,7 +21,7 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate numbers without repetition.
 pub mod exhaustive;
-/// Traits for generating primes, primality testing, and factorization (TODO!)
+/// Traits for generating primes, primality testing, and factorization.
 pub mod factorization;
 /// [`NiceFloat`](float::NiceFloat), a wrapper around primitive floats.
 pub mod float;
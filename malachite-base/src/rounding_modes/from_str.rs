@@ -38,6 +38,11 @@ impl FromStr for RoundingMode {
     /// assert_eq!(RoundingMode::from_str("Floor"), Ok(Floor));
     /// assert_eq!(RoundingMode::from_str("Ceiling"), Ok(Ceiling));
     /// assert_eq!(RoundingMode::from_str("Nearest"), Ok(Nearest));
+    /// assert_eq!(
+    ///     RoundingMode::from_str("NearestTiesAway"),
+    ///     Ok(NearestTiesAway)
+    /// );
+    /// assert_eq!(RoundingMode::from_str("ToOdd"), Ok(ToOdd));
     /// assert_eq!(RoundingMode::from_str("Exact"), Ok(Exact));
     /// assert_eq!(RoundingMode::from_str("abc"), Err("abc".to_string()));
     /// ```
@@ -49,6 +54,8 @@ impl FromStr for RoundingMode {
             "Floor" => Ok(Floor),
             "Ceiling" => Ok(Ceiling),
             "Nearest" => Ok(Nearest),
+            "NearestTiesAway" => Ok(NearestTiesAway),
+            "ToOdd" => Ok(ToOdd),
             "Exact" => Ok(Exact),
             _ => Err(src.to_string()),
         }
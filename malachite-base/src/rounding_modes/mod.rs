@@ -20,17 +20,17 @@ use crate::rounding_modes::RoundingMode::*;
 /// Here are some examples of how floating-point values would be rounded to integer values using the
 /// different `RoundingMode`s.
 ///
-/// | x    | `Floor` | `Ceiling` | `Down` | `Up` | `Nearest` | `Exact`    |
-/// |------|---------|-----------|--------|------|-----------|------------|
-/// |  3.0 |       3 |         3 |      3 |    3 |         3 |          3 |
-/// |  3.2 |       3 |         4 |      3 |    4 |         3 | `panic!()` |
-/// |  3.8 |       3 |         4 |      3 |    4 |         4 | `panic!()` |
-/// |  3.5 |       3 |         4 |      3 |    4 |         4 | `panic!()` |
-/// |  4.5 |       4 |         5 |      4 |    5 |         4 | `panic!()` |
-/// | -3.2 |      -4 |        -3 |     -3 |   -4 |        -3 | `panic!()` |
-/// | -3.8 |      -4 |        -3 |     -3 |   -4 |        -4 | `panic!()` |
-/// | -3.5 |      -4 |        -3 |     -3 |   -4 |        -4 | `panic!()` |
-/// | -4.5 |      -5 |        -4 |     -4 |   -5 |        -4 | `panic!()` |
+/// | x    | Floor | Ceiling | Down | Up | Nearest | NearestTiesAway | ToOdd | Exact      |
+/// |------|-------|---------|------|----|---------|-----------------|-------|------------|
+/// |  3.0 |     3 |       3 |    3 |  3 |       3 |               3 |     3 |          3 |
+/// |  3.2 |     3 |       4 |    3 |  4 |       3 |               3 |     3 | `panic!()` |
+/// |  3.8 |     3 |       4 |    3 |  4 |       4 |               4 |     3 | `panic!()` |
+/// |  3.5 |     3 |       4 |    3 |  4 |       4 |               4 |     3 | `panic!()` |
+/// |  4.5 |     4 |       5 |    4 |  5 |       4 |               5 |     5 | `panic!()` |
+/// | -3.2 |    -4 |      -3 |   -3 | -4 |      -3 |              -3 |    -3 | `panic!()` |
+/// | -3.8 |    -4 |      -3 |   -3 | -4 |      -4 |              -4 |    -3 | `panic!()` |
+/// | -3.5 |    -4 |      -3 |   -3 | -4 |      -4 |              -4 |    -3 | `panic!()` |
+/// | -4.5 |    -5 |      -4 |   -4 | -5 |      -4 |              -5 |    -5 | `panic!()` |
 ///
 /// Sometimes a `RoundingMode` is used in an unusual context, such as rounding an integer to a
 /// floating-point number, in which case further explanation of its behavior is provided at the
@@ -67,14 +67,43 @@ pub enum RoundingMode {
     /// nearest even integer. This is also called _bankers' rounding_ and is often used as a
     /// default.
     Nearest,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       \operatorname{sgn}(x) \lfloor |x| \rfloor &
+    ///         |x| - \lfloor |x| \rfloor < \frac{1}{2} \\\\
+    ///       \operatorname{sgn}(x) \lceil |x| \rceil &
+    ///         |x| - \lfloor |x| \rfloor \geq \frac{1}{2}.
+    ///   \\end{cases}
+    /// $$
+    /// In other words, it rounds to the nearest integer, and when there's a tie, it rounds away
+    /// from zero. This is the `roundTiesToAway` mode of IEEE 754-2008, and it is how rounding is
+    /// usually taught in school.
+    NearestTiesAway,
+    /// Applies the function
+    /// $$
+    ///   x \mapsto \\begin{cases}
+    ///       x & x \in \Z \\\\
+    ///       \lfloor x \rfloor &
+    ///         x \notin \Z \\ \text{and} \\ \lfloor x \rfloor \\ \text{is odd} \\\\
+    ///       \lceil x \rceil &
+    ///         x \notin \Z \\ \text{and} \\ \lceil x \rceil \\ \text{is odd.}
+    ///   \\end{cases}
+    /// $$
+    /// In other words, a value that is already rounded is left unchanged, and any other value is
+    /// rounded to whichever of its two neighbors is odd. This is _round-to-odd_. A result that is
+    /// rounded to odd with at least two extra bits of precision may be safely rounded again, to
+    /// the target precision, using any of the other modes, without any double-rounding error.
+    ToOdd,
     /// Panics if the value is not already rounded.
     Exact,
 }
 
 impl_named!(RoundingMode);
 
-/// A list of all six rounding modes.
-pub const ROUNDING_MODES: [RoundingMode; 6] = [Down, Up, Floor, Ceiling, Nearest, Exact];
+/// A list of all eight rounding modes.
+pub const ROUNDING_MODES: [RoundingMode; 8] =
+    [Down, Up, Floor, Ceiling, Nearest, NearestTiesAway, ToOdd, Exact];
 
 /// Iterators that generate [`RoundingMode`]s without repetition.
 pub mod exhaustive;
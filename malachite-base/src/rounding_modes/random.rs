@@ -31,7 +31,7 @@ pub type RandomRoundingModes = Copied<RandomValuesFromSlice<'static, RoundingMod
 ///
 /// assert_eq!(
 ///     random_rounding_modes(EXAMPLE_SEED).take(10).collect_vec(),
-///     &[Up, Exact, Ceiling, Up, Floor, Nearest, Exact, Up, Floor, Exact]
+///     &[Up, ToOdd, NearestTiesAway, Exact, ToOdd, Ceiling, Up, Floor, Nearest, NearestTiesAway]
 /// )
 /// ```
 #[inline]
@@ -514,9 +514,10 @@ pub fn exhaustive_primitive_float_unsigned_rounding_mode_triple_gen_var_2<T: Pri
 pub(crate) fn float_rounding_mode_filter_var_1<T: PrimitiveFloat>(p: &(T, RoundingMode)) -> bool {
     let &(f, rm) = p;
     match rm {
-        Floor | Up => f >= T::ZERO,
+        Floor | Up | ToOdd => f >= T::ZERO,
         Ceiling | Down => f > T::NEGATIVE_ONE,
         Nearest => f >= T::NEGATIVE_ONE / T::TWO,
+        NearestTiesAway => f > T::NEGATIVE_ONE / T::TWO,
         Exact => f >= T::ZERO && f.is_integer(),
     }
 }
@@ -557,8 +558,8 @@ pub fn exhaustive_primitive_float_rounding_mode_pair_gen_var_3<
         .filter(move |&(f, rm)| match rm {
             Up => f >= f_min && f <= f_max,
             Ceiling => f <= f_max,
-            Floor => f >= f_min,
-            Down | Nearest => true,
+            Floor | ToOdd => f >= f_min,
+            Down | Nearest | NearestTiesAway => true,
             Exact => U::convertible_from(f),
         }),
     )
@@ -1245,7 +1246,7 @@ fn round_to_multiple_unsigned_helper<T: PrimitiveUnsigned>(x: T, y: T, rm: Round
     if x == y {
         true
     } else if y == T::ZERO {
-        rm == Down || rm == Floor || rm == Nearest
+        rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway
     } else {
         x.div_round(y, rm).0.checked_mul(y).is_some()
     }
@@ -3130,7 +3131,7 @@ pub(crate) fn round_to_multiple_unsigned_filter_map<T: PrimitiveUnsigned>(
     if x == y {
         Some((x, y, rm))
     } else if y == T::ZERO {
-        if rm == Floor || rm == Down || rm == Nearest {
+        if rm == Floor || rm == Down || rm == Nearest || rm == NearestTiesAway {
             Some((x, y, rm))
         } else {
             None
@@ -1001,8 +1001,8 @@ pub fn random_primitive_float_rounding_mode_pair_gen_var_3<
         .filter(move |&(f, rm)| match rm {
             Up => f >= f_min && f <= f_max,
             Ceiling => f <= f_max,
-            Floor => f >= f_min,
-            Down | Nearest => true,
+            Floor | ToOdd => f >= f_min,
+            Down | Nearest | NearestTiesAway => true,
             Exact => U::convertible_from(f),
         }),
     )
@@ -0,0 +1,24 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn divisors_naive<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    assert_ne!(n, T::ZERO);
+    let mut divisors = Vec::new();
+    let mut f = T::ONE;
+    loop {
+        if n.divisible_by(f) {
+            divisors.push(f);
+        }
+        if f == n {
+            return divisors;
+        }
+        f += T::ONE;
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn euler_totient_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO);
+    let mut totient = T::ZERO;
+    let mut k = T::ONE;
+    loop {
+        if k.gcd(n) == T::ONE {
+            totient += T::ONE;
+        }
+        if k == n {
+            return totient;
+        }
+        k += T::ONE;
+    }
+}
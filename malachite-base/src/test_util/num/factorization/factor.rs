@@ -0,0 +1,30 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn factor_naive<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO);
+    let mut factors = Vec::new();
+    let mut f = T::TWO;
+    while f <= n / f {
+        let mut exponent = 0;
+        while n.divisible_by(f) {
+            n /= f;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((f, exponent));
+        }
+        f += T::ONE;
+    }
+    if n != T::ONE {
+        factors.push((n, 1));
+    }
+    factors
+}
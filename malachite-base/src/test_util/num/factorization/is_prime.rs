@@ -0,0 +1,28 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn is_prime_naive<T: PrimitiveUnsigned>(n: T) -> bool {
+    if n < T::TWO {
+        return false;
+    } else if n == T::TWO {
+        return true;
+    } else if n.even() {
+        return false;
+    }
+    let limit = n.floor_sqrt();
+    let mut f = T::from(3u8);
+    while f <= limit {
+        if n.divisible_by(f) {
+            return false;
+        }
+        f += T::TWO;
+    }
+    true
+}
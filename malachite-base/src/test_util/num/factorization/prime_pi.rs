@@ -0,0 +1,13 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn prime_pi_naive<T: PrimitiveUnsigned>(n: T) -> u64 {
+    u64::try_from(T::primes_less_than_or_equal_to(&n).count()).unwrap()
+}
@@ -395,8 +395,15 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod divisors;
+        pub mod euler_totient;
+        pub mod factor;
+        pub mod is_prime;
+        pub mod is_squarefree;
+        pub mod moebius;
         pub mod prime_indicator_sequence;
         pub mod prime_indicator_sequence_less_than;
+        pub mod prime_pi;
         pub mod prime_sieve;
         pub mod primes;
     }
@@ -36,6 +36,8 @@ fn test_div_round_unsigned() {
     test::<u8>(0, 1, Up, 0, Equal);
     test::<u8>(0, 1, Ceiling, 0, Equal);
     test::<u8>(0, 1, Nearest, 0, Equal);
+    test::<u8>(0, 1, NearestTiesAway, 0, Equal);
+    test::<u8>(0, 1, ToOdd, 0, Equal);
     test::<u8>(0, 1, Exact, 0, Equal);
 
     test::<u16>(0, 123, Down, 0, Equal);
@@ -43,6 +45,8 @@ fn test_div_round_unsigned() {
     test::<u16>(0, 123, Up, 0, Equal);
     test::<u16>(0, 123, Ceiling, 0, Equal);
     test::<u16>(0, 123, Nearest, 0, Equal);
+    test::<u16>(0, 123, NearestTiesAway, 0, Equal);
+    test::<u16>(0, 123, ToOdd, 0, Equal);
     test::<u16>(0, 123, Exact, 0, Equal);
 
     test::<u32>(1, 1, Down, 1, Equal);
@@ -50,6 +54,8 @@ fn test_div_round_unsigned() {
     test::<u32>(1, 1, Up, 1, Equal);
     test::<u32>(1, 1, Ceiling, 1, Equal);
     test::<u32>(1, 1, Nearest, 1, Equal);
+    test::<u32>(1, 1, NearestTiesAway, 1, Equal);
+    test::<u32>(1, 1, ToOdd, 1, Equal);
     test::<u32>(1, 1, Exact, 1, Equal);
 
     test::<u64>(123, 1, Down, 123, Equal);
@@ -57,6 +63,8 @@ fn test_div_round_unsigned() {
     test::<u64>(123, 1, Up, 123, Equal);
     test::<u64>(123, 1, Ceiling, 123, Equal);
     test::<u64>(123, 1, Nearest, 123, Equal);
+    test::<u64>(123, 1, NearestTiesAway, 123, Equal);
+    test::<u64>(123, 1, ToOdd, 123, Equal);
     test::<u64>(123, 1, Exact, 123, Equal);
 
     test::<u128>(123, 2, Down, 61, Less);
@@ -64,18 +72,24 @@ fn test_div_round_unsigned() {
     test::<u128>(123, 2, Up, 62, Greater);
     test::<u128>(123, 2, Ceiling, 62, Greater);
     test::<u128>(123, 2, Nearest, 62, Greater);
+    test::<u128>(123, 2, NearestTiesAway, 62, Greater);
+    test::<u128>(123, 2, ToOdd, 61, Less);
 
     test::<usize>(125, 2, Down, 62, Less);
     test::<usize>(125, 2, Floor, 62, Less);
     test::<usize>(125, 2, Up, 63, Greater);
     test::<usize>(125, 2, Ceiling, 63, Greater);
     test::<usize>(125, 2, Nearest, 62, Less);
+    test::<usize>(125, 2, NearestTiesAway, 63, Greater);
+    test::<usize>(125, 2, ToOdd, 63, Greater);
 
     test::<u8>(123, 123, Down, 1, Equal);
     test::<u8>(123, 123, Floor, 1, Equal);
     test::<u8>(123, 123, Up, 1, Equal);
     test::<u8>(123, 123, Ceiling, 1, Equal);
     test::<u8>(123, 123, Nearest, 1, Equal);
+    test::<u8>(123, 123, NearestTiesAway, 1, Equal);
+    test::<u8>(123, 123, ToOdd, 1, Equal);
     test::<u8>(123, 123, Exact, 1, Equal);
 
     test::<u16>(123, 456, Down, 0, Less);
@@ -83,12 +97,16 @@ fn test_div_round_unsigned() {
     test::<u16>(123, 456, Up, 1, Greater);
     test::<u16>(123, 456, Ceiling, 1, Greater);
     test::<u16>(123, 456, Nearest, 0, Less);
+    test::<u16>(123, 456, NearestTiesAway, 0, Less);
+    test::<u16>(123, 456, ToOdd, 1, Greater);
 
     test::<u64>(1000000000000, 1, Down, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Floor, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Up, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Ceiling, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Nearest, 1000000000000, Equal);
+    test::<u64>(1000000000000, 1, NearestTiesAway, 1000000000000, Equal);
+    test::<u64>(1000000000000, 1, ToOdd, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Exact, 1000000000000, Equal);
 
     test::<u64>(1000000000000, 3, Down, 333333333333, Less);
@@ -96,18 +114,24 @@ fn test_div_round_unsigned() {
     test::<u64>(1000000000000, 3, Up, 333333333334, Greater);
     test::<u64>(1000000000000, 3, Ceiling, 333333333334, Greater);
     test::<u64>(1000000000000, 3, Nearest, 333333333333, Less);
+    test::<u64>(1000000000000, 3, NearestTiesAway, 333333333333, Less);
+    test::<u64>(1000000000000, 3, ToOdd, 333333333333, Less);
 
     test::<u64>(999999999999, 2, Down, 499999999999, Less);
     test::<u64>(999999999999, 2, Floor, 499999999999, Less);
     test::<u64>(999999999999, 2, Up, 500000000000, Greater);
     test::<u64>(999999999999, 2, Ceiling, 500000000000, Greater);
     test::<u64>(999999999999, 2, Nearest, 500000000000, Greater);
+    test::<u64>(999999999999, 2, NearestTiesAway, 500000000000, Greater);
+    test::<u64>(999999999999, 2, ToOdd, 499999999999, Less);
 
     test::<u64>(1000000000001, 2, Down, 500000000000, Less);
     test::<u64>(1000000000001, 2, Floor, 500000000000, Less);
     test::<u64>(1000000000001, 2, Up, 500000000001, Greater);
     test::<u64>(1000000000001, 2, Ceiling, 500000000001, Greater);
     test::<u64>(1000000000001, 2, Nearest, 500000000000, Less);
+    test::<u64>(1000000000001, 2, NearestTiesAway, 500000000001, Greater);
+    test::<u64>(1000000000001, 2, ToOdd, 500000000001, Greater);
 
     test::<u128>(
         1000000000000000000000000,
@@ -261,6 +285,8 @@ fn test_div_round_signed() {
     test::<i8>(0, 1, Up, 0, Equal);
     test::<i8>(0, 1, Ceiling, 0, Equal);
     test::<i8>(0, 1, Nearest, 0, Equal);
+    test::<i8>(0, 1, NearestTiesAway, 0, Equal);
+    test::<i8>(0, 1, ToOdd, 0, Equal);
     test::<i8>(0, 1, Exact, 0, Equal);
 
     test::<i16>(0, 123, Down, 0, Equal);
@@ -268,6 +294,8 @@ fn test_div_round_signed() {
     test::<i16>(0, 123, Up, 0, Equal);
     test::<i16>(0, 123, Ceiling, 0, Equal);
     test::<i16>(0, 123, Nearest, 0, Equal);
+    test::<i16>(0, 123, NearestTiesAway, 0, Equal);
+    test::<i16>(0, 123, ToOdd, 0, Equal);
     test::<i16>(0, 123, Exact, 0, Equal);
 
     test::<i32>(1, 1, Down, 1, Equal);
@@ -275,6 +303,8 @@ fn test_div_round_signed() {
     test::<i32>(1, 1, Up, 1, Equal);
     test::<i32>(1, 1, Ceiling, 1, Equal);
     test::<i32>(1, 1, Nearest, 1, Equal);
+    test::<i32>(1, 1, NearestTiesAway, 1, Equal);
+    test::<i32>(1, 1, ToOdd, 1, Equal);
     test::<i32>(1, 1, Exact, 1, Equal);
 
     test::<i64>(123, 1, Down, 123, Equal);
@@ -282,6 +312,8 @@ fn test_div_round_signed() {
     test::<i64>(123, 1, Up, 123, Equal);
     test::<i64>(123, 1, Ceiling, 123, Equal);
     test::<i64>(123, 1, Nearest, 123, Equal);
+    test::<i64>(123, 1, NearestTiesAway, 123, Equal);
+    test::<i64>(123, 1, ToOdd, 123, Equal);
     test::<i64>(123, 1, Exact, 123, Equal);
 
     test::<i128>(123, 2, Down, 61, Less);
@@ -289,18 +321,24 @@ fn test_div_round_signed() {
     test::<i128>(123, 2, Up, 62, Greater);
     test::<i128>(123, 2, Ceiling, 62, Greater);
     test::<i128>(123, 2, Nearest, 62, Greater);
+    test::<i128>(123, 2, NearestTiesAway, 62, Greater);
+    test::<i128>(123, 2, ToOdd, 61, Less);
 
     test::<isize>(125, 2, Down, 62, Less);
     test::<isize>(125, 2, Floor, 62, Less);
     test::<isize>(125, 2, Up, 63, Greater);
     test::<isize>(125, 2, Ceiling, 63, Greater);
     test::<isize>(125, 2, Nearest, 62, Less);
+    test::<isize>(125, 2, NearestTiesAway, 63, Greater);
+    test::<isize>(125, 2, ToOdd, 63, Greater);
 
     test::<i8>(123, 123, Down, 1, Equal);
     test::<i8>(123, 123, Floor, 1, Equal);
     test::<i8>(123, 123, Up, 1, Equal);
     test::<i8>(123, 123, Ceiling, 1, Equal);
     test::<i8>(123, 123, Nearest, 1, Equal);
+    test::<i8>(123, 123, NearestTiesAway, 1, Equal);
+    test::<i8>(123, 123, ToOdd, 1, Equal);
     test::<i8>(123, 123, Exact, 1, Equal);
 
     test::<i16>(123, 456, Down, 0, Less);
@@ -308,12 +346,16 @@ fn test_div_round_signed() {
     test::<i16>(123, 456, Up, 1, Greater);
     test::<i16>(123, 456, Ceiling, 1, Greater);
     test::<i16>(123, 456, Nearest, 0, Less);
+    test::<i16>(123, 456, NearestTiesAway, 0, Less);
+    test::<i16>(123, 456, ToOdd, 1, Greater);
 
     test::<i64>(1000000000000, 1, Down, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Floor, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Up, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Ceiling, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Nearest, 1000000000000, Equal);
+    test::<i64>(1000000000000, 1, NearestTiesAway, 1000000000000, Equal);
+    test::<i64>(1000000000000, 1, ToOdd, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Exact, 1000000000000, Equal);
 
     test::<i64>(1000000000000, 3, Down, 333333333333, Less);
@@ -321,18 +363,24 @@ fn test_div_round_signed() {
     test::<i64>(1000000000000, 3, Up, 333333333334, Greater);
     test::<i64>(1000000000000, 3, Ceiling, 333333333334, Greater);
     test::<i64>(1000000000000, 3, Nearest, 333333333333, Less);
+    test::<i64>(1000000000000, 3, NearestTiesAway, 333333333333, Less);
+    test::<i64>(1000000000000, 3, ToOdd, 333333333333, Less);
 
     test::<i64>(999999999999, 2, Down, 499999999999, Less);
     test::<i64>(999999999999, 2, Floor, 499999999999, Less);
     test::<i64>(999999999999, 2, Up, 500000000000, Greater);
     test::<i64>(999999999999, 2, Ceiling, 500000000000, Greater);
     test::<i64>(999999999999, 2, Nearest, 500000000000, Greater);
+    test::<i64>(999999999999, 2, NearestTiesAway, 500000000000, Greater);
+    test::<i64>(999999999999, 2, ToOdd, 499999999999, Less);
 
     test::<i64>(1000000000001, 2, Down, 500000000000, Less);
     test::<i64>(1000000000001, 2, Floor, 500000000000, Less);
     test::<i64>(1000000000001, 2, Up, 500000000001, Greater);
     test::<i64>(1000000000001, 2, Ceiling, 500000000001, Greater);
     test::<i64>(1000000000001, 2, Nearest, 500000000000, Less);
+    test::<i64>(1000000000001, 2, NearestTiesAway, 500000000001, Greater);
+    test::<i64>(1000000000001, 2, ToOdd, 500000000001, Greater);
 
     test::<i128>(
         1000000000000000000000000,
@@ -476,6 +524,8 @@ fn test_div_round_signed() {
     test::<i8>(0, -1, Up, 0, Equal);
     test::<i8>(0, -1, Ceiling, 0, Equal);
     test::<i8>(0, -1, Nearest, 0, Equal);
+    test::<i8>(0, -1, NearestTiesAway, 0, Equal);
+    test::<i8>(0, -1, ToOdd, 0, Equal);
     test::<i8>(0, -1, Exact, 0, Equal);
 
     test::<i16>(0, -123, Down, 0, Equal);
@@ -483,6 +533,8 @@ fn test_div_round_signed() {
     test::<i16>(0, -123, Up, 0, Equal);
     test::<i16>(0, -123, Ceiling, 0, Equal);
     test::<i16>(0, -123, Nearest, 0, Equal);
+    test::<i16>(0, -123, NearestTiesAway, 0, Equal);
+    test::<i16>(0, -123, ToOdd, 0, Equal);
     test::<i16>(0, -123, Exact, 0, Equal);
 
     test::<i32>(1, -1, Down, -1, Equal);
@@ -490,6 +542,8 @@ fn test_div_round_signed() {
     test::<i32>(1, -1, Up, -1, Equal);
     test::<i32>(1, -1, Ceiling, -1, Equal);
     test::<i32>(1, -1, Nearest, -1, Equal);
+    test::<i32>(1, -1, NearestTiesAway, -1, Equal);
+    test::<i32>(1, -1, ToOdd, -1, Equal);
     test::<i32>(1, -1, Exact, -1, Equal);
 
     test::<i64>(123, -1, Down, -123, Equal);
@@ -497,6 +551,8 @@ fn test_div_round_signed() {
     test::<i64>(123, -1, Up, -123, Equal);
     test::<i64>(123, -1, Ceiling, -123, Equal);
     test::<i64>(123, -1, Nearest, -123, Equal);
+    test::<i64>(123, -1, NearestTiesAway, -123, Equal);
+    test::<i64>(123, -1, ToOdd, -123, Equal);
     test::<i64>(123, -1, Exact, -123, Equal);
 
     test::<i128>(123, -2, Down, -61, Greater);
@@ -504,18 +560,24 @@ fn test_div_round_signed() {
     test::<i128>(123, -2, Up, -62, Less);
     test::<i128>(123, -2, Ceiling, -61, Greater);
     test::<i128>(123, -2, Nearest, -62, Less);
+    test::<i128>(123, -2, NearestTiesAway, -62, Less);
+    test::<i128>(123, -2, ToOdd, -61, Greater);
 
     test::<isize>(125, -2, Down, -62, Greater);
     test::<isize>(125, -2, Floor, -63, Less);
     test::<isize>(125, -2, Up, -63, Less);
     test::<isize>(125, -2, Ceiling, -62, Greater);
     test::<isize>(125, -2, Nearest, -62, Greater);
+    test::<isize>(125, -2, NearestTiesAway, -63, Less);
+    test::<isize>(125, -2, ToOdd, -63, Less);
 
     test::<i8>(123, -123, Down, -1, Equal);
     test::<i8>(123, -123, Floor, -1, Equal);
     test::<i8>(123, -123, Up, -1, Equal);
     test::<i8>(123, -123, Ceiling, -1, Equal);
     test::<i8>(123, -123, Nearest, -1, Equal);
+    test::<i8>(123, -123, NearestTiesAway, -1, Equal);
+    test::<i8>(123, -123, ToOdd, -1, Equal);
     test::<i8>(123, -123, Exact, -1, Equal);
 
     test::<i16>(123, -456, Down, 0, Greater);
@@ -523,12 +585,16 @@ fn test_div_round_signed() {
     test::<i16>(123, -456, Up, -1, Less);
     test::<i16>(123, -456, Ceiling, 0, Greater);
     test::<i16>(123, -456, Nearest, 0, Greater);
+    test::<i16>(123, -456, NearestTiesAway, 0, Greater);
+    test::<i16>(123, -456, ToOdd, -1, Less);
 
     test::<i64>(1000000000000, -1, Down, -1000000000000, Equal);
     test::<i64>(1000000000000, -1, Floor, -1000000000000, Equal);
     test::<i64>(1000000000000, -1, Up, -1000000000000, Equal);
     test::<i64>(1000000000000, -1, Ceiling, -1000000000000, Equal);
     test::<i64>(1000000000000, -1, Nearest, -1000000000000, Equal);
+    test::<i64>(1000000000000, -1, NearestTiesAway, -1000000000000, Equal);
+    test::<i64>(1000000000000, -1, ToOdd, -1000000000000, Equal);
     test::<i64>(1000000000000, -1, Exact, -1000000000000, Equal);
 
     test::<i64>(1000000000000, -3, Down, -333333333333, Greater);
@@ -536,18 +602,24 @@ fn test_div_round_signed() {
     test::<i64>(1000000000000, -3, Up, -333333333334, Less);
     test::<i64>(1000000000000, -3, Ceiling, -333333333333, Greater);
     test::<i64>(1000000000000, -3, Nearest, -333333333333, Greater);
+    test::<i64>(1000000000000, -3, NearestTiesAway, -333333333333, Greater);
+    test::<i64>(1000000000000, -3, ToOdd, -333333333333, Greater);
 
     test::<i64>(999999999999, -2, Down, -499999999999, Greater);
     test::<i64>(999999999999, -2, Floor, -500000000000, Less);
     test::<i64>(999999999999, -2, Up, -500000000000, Less);
     test::<i64>(999999999999, -2, Ceiling, -499999999999, Greater);
     test::<i64>(999999999999, -2, Nearest, -500000000000, Less);
+    test::<i64>(999999999999, -2, NearestTiesAway, -500000000000, Less);
+    test::<i64>(999999999999, -2, ToOdd, -499999999999, Greater);
 
     test::<i64>(1000000000001, -2, Down, -500000000000, Greater);
     test::<i64>(1000000000001, -2, Floor, -500000000001, Less);
     test::<i64>(1000000000001, -2, Up, -500000000001, Less);
     test::<i64>(1000000000001, -2, Ceiling, -500000000000, Greater);
     test::<i64>(1000000000001, -2, Nearest, -500000000000, Greater);
+    test::<i64>(1000000000001, -2, NearestTiesAway, -500000000001, Less);
+    test::<i64>(1000000000001, -2, ToOdd, -500000000001, Less);
 
     test::<i128>(
         1000000000000000000000000,
@@ -691,6 +763,8 @@ fn test_div_round_signed() {
     test::<i8>(-1, 1, Up, -1, Equal);
     test::<i8>(-1, 1, Ceiling, -1, Equal);
     test::<i8>(-1, 1, Nearest, -1, Equal);
+    test::<i8>(-1, 1, NearestTiesAway, -1, Equal);
+    test::<i8>(-1, 1, ToOdd, -1, Equal);
     test::<i8>(-1, 1, Exact, -1, Equal);
 
     test::<i16>(-123, 1, Down, -123, Equal);
@@ -698,6 +772,8 @@ fn test_div_round_signed() {
     test::<i16>(-123, 1, Up, -123, Equal);
     test::<i16>(-123, 1, Ceiling, -123, Equal);
     test::<i16>(-123, 1, Nearest, -123, Equal);
+    test::<i16>(-123, 1, NearestTiesAway, -123, Equal);
+    test::<i16>(-123, 1, ToOdd, -123, Equal);
     test::<i16>(-123, 1, Exact, -123, Equal);
 
     test::<i32>(-123, 2, Down, -61, Greater);
@@ -705,18 +781,24 @@ fn test_div_round_signed() {
     test::<i32>(-123, 2, Up, -62, Less);
     test::<i32>(-123, 2, Ceiling, -61, Greater);
     test::<i32>(-123, 2, Nearest, -62, Less);
+    test::<i32>(-123, 2, NearestTiesAway, -62, Less);
+    test::<i32>(-123, 2, ToOdd, -61, Greater);
 
     test::<i64>(-125, 2, Down, -62, Greater);
     test::<i64>(-125, 2, Floor, -63, Less);
     test::<i64>(-125, 2, Up, -63, Less);
     test::<i64>(-125, 2, Ceiling, -62, Greater);
     test::<i64>(-125, 2, Nearest, -62, Greater);
+    test::<i64>(-125, 2, NearestTiesAway, -63, Less);
+    test::<i64>(-125, 2, ToOdd, -63, Less);
 
     test::<i128>(-123, 123, Down, -1, Equal);
     test::<i128>(-123, 123, Floor, -1, Equal);
     test::<i128>(-123, 123, Up, -1, Equal);
     test::<i128>(-123, 123, Ceiling, -1, Equal);
     test::<i128>(-123, 123, Nearest, -1, Equal);
+    test::<i128>(-123, 123, NearestTiesAway, -1, Equal);
+    test::<i128>(-123, 123, ToOdd, -1, Equal);
     test::<i128>(-123, 123, Exact, -1, Equal);
 
     test::<isize>(-123, 456, Down, 0, Greater);
@@ -724,12 +806,16 @@ fn test_div_round_signed() {
     test::<isize>(-123, 456, Up, -1, Less);
     test::<isize>(-123, 456, Ceiling, 0, Greater);
     test::<isize>(-123, 456, Nearest, 0, Greater);
+    test::<isize>(-123, 456, NearestTiesAway, 0, Greater);
+    test::<isize>(-123, 456, ToOdd, -1, Less);
 
     test::<i64>(-1000000000000, 1, Down, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Floor, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Up, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Ceiling, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Nearest, -1000000000000, Equal);
+    test::<i64>(-1000000000000, 1, NearestTiesAway, -1000000000000, Equal);
+    test::<i64>(-1000000000000, 1, ToOdd, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Exact, -1000000000000, Equal);
 
     test::<i64>(-1000000000000, 3, Down, -333333333333, Greater);
@@ -737,18 +823,24 @@ fn test_div_round_signed() {
     test::<i64>(-1000000000000, 3, Up, -333333333334, Less);
     test::<i64>(-1000000000000, 3, Ceiling, -333333333333, Greater);
     test::<i64>(-1000000000000, 3, Nearest, -333333333333, Greater);
+    test::<i64>(-1000000000000, 3, NearestTiesAway, -333333333333, Greater);
+    test::<i64>(-1000000000000, 3, ToOdd, -333333333333, Greater);
 
     test::<i64>(-999999999999, 2, Down, -499999999999, Greater);
     test::<i64>(-999999999999, 2, Floor, -500000000000, Less);
     test::<i64>(-999999999999, 2, Up, -500000000000, Less);
     test::<i64>(-999999999999, 2, Ceiling, -499999999999, Greater);
     test::<i64>(-999999999999, 2, Nearest, -500000000000, Less);
+    test::<i64>(-999999999999, 2, NearestTiesAway, -500000000000, Less);
+    test::<i64>(-999999999999, 2, ToOdd, -499999999999, Greater);
 
     test::<i64>(-1000000000001, 2, Down, -500000000000, Greater);
     test::<i64>(-1000000000001, 2, Floor, -500000000001, Less);
     test::<i64>(-1000000000001, 2, Up, -500000000001, Less);
     test::<i64>(-1000000000001, 2, Ceiling, -500000000000, Greater);
     test::<i64>(-1000000000001, 2, Nearest, -500000000000, Greater);
+    test::<i64>(-1000000000001, 2, NearestTiesAway, -500000000001, Less);
+    test::<i64>(-1000000000001, 2, ToOdd, -500000000001, Less);
 
     test::<i128>(
         -1000000000000000000000000,
@@ -892,6 +984,8 @@ fn test_div_round_signed() {
     test::<i8>(-1, -1, Up, 1, Equal);
     test::<i8>(-1, -1, Ceiling, 1, Equal);
     test::<i8>(-1, -1, Nearest, 1, Equal);
+    test::<i8>(-1, -1, NearestTiesAway, 1, Equal);
+    test::<i8>(-1, -1, ToOdd, 1, Equal);
     test::<i8>(-1, -1, Exact, 1, Equal);
 
     test::<i16>(-123, -1, Down, 123, Equal);
@@ -899,6 +993,8 @@ fn test_div_round_signed() {
     test::<i16>(-123, -1, Up, 123, Equal);
     test::<i16>(-123, -1, Ceiling, 123, Equal);
     test::<i16>(-123, -1, Nearest, 123, Equal);
+    test::<i16>(-123, -1, NearestTiesAway, 123, Equal);
+    test::<i16>(-123, -1, ToOdd, 123, Equal);
     test::<i16>(-123, -1, Exact, 123, Equal);
 
     test::<i32>(-123, -2, Down, 61, Less);
@@ -906,18 +1002,24 @@ fn test_div_round_signed() {
     test::<i32>(-123, -2, Up, 62, Greater);
     test::<i32>(-123, -2, Ceiling, 62, Greater);
     test::<i32>(-123, -2, Nearest, 62, Greater);
+    test::<i32>(-123, -2, NearestTiesAway, 62, Greater);
+    test::<i32>(-123, -2, ToOdd, 61, Less);
 
     test::<i64>(-125, -2, Down, 62, Less);
     test::<i64>(-125, -2, Floor, 62, Less);
     test::<i64>(-125, -2, Up, 63, Greater);
     test::<i64>(-125, -2, Ceiling, 63, Greater);
     test::<i64>(-125, -2, Nearest, 62, Less);
+    test::<i64>(-125, -2, NearestTiesAway, 63, Greater);
+    test::<i64>(-125, -2, ToOdd, 63, Greater);
 
     test::<i128>(-123, -123, Down, 1, Equal);
     test::<i128>(-123, -123, Floor, 1, Equal);
     test::<i128>(-123, -123, Up, 1, Equal);
     test::<i128>(-123, -123, Ceiling, 1, Equal);
     test::<i128>(-123, -123, Nearest, 1, Equal);
+    test::<i128>(-123, -123, NearestTiesAway, 1, Equal);
+    test::<i128>(-123, -123, ToOdd, 1, Equal);
     test::<i128>(-123, -123, Exact, 1, Equal);
 
     test::<isize>(-123, -456, Down, 0, Less);
@@ -925,12 +1027,16 @@ fn test_div_round_signed() {
     test::<isize>(-123, -456, Up, 1, Greater);
     test::<isize>(-123, -456, Ceiling, 1, Greater);
     test::<isize>(-123, -456, Nearest, 0, Less);
+    test::<isize>(-123, -456, NearestTiesAway, 0, Less);
+    test::<isize>(-123, -456, ToOdd, 1, Greater);
 
     test::<i64>(-1000000000000, -1, Down, 1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Floor, 1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Up, 1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Ceiling, 1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Nearest, 1000000000000, Equal);
+    test::<i64>(-1000000000000, -1, NearestTiesAway, 1000000000000, Equal);
+    test::<i64>(-1000000000000, -1, ToOdd, 1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Exact, 1000000000000, Equal);
 
     test::<i64>(-1000000000000, -3, Down, 333333333333, Less);
@@ -938,18 +1044,24 @@ fn test_div_round_signed() {
     test::<i64>(-1000000000000, -3, Up, 333333333334, Greater);
     test::<i64>(-1000000000000, -3, Ceiling, 333333333334, Greater);
     test::<i64>(-1000000000000, -3, Nearest, 333333333333, Less);
+    test::<i64>(-1000000000000, -3, NearestTiesAway, 333333333333, Less);
+    test::<i64>(-1000000000000, -3, ToOdd, 333333333333, Less);
 
     test::<i64>(-999999999999, -2, Down, 499999999999, Less);
     test::<i64>(-999999999999, -2, Floor, 499999999999, Less);
     test::<i64>(-999999999999, -2, Up, 500000000000, Greater);
     test::<i64>(-999999999999, -2, Ceiling, 500000000000, Greater);
     test::<i64>(-999999999999, -2, Nearest, 500000000000, Greater);
+    test::<i64>(-999999999999, -2, NearestTiesAway, 500000000000, Greater);
+    test::<i64>(-999999999999, -2, ToOdd, 499999999999, Less);
 
     test::<i64>(-1000000000001, -2, Down, 500000000000, Less);
     test::<i64>(-1000000000001, -2, Floor, 500000000000, Less);
     test::<i64>(-1000000000001, -2, Up, 500000000001, Greater);
     test::<i64>(-1000000000001, -2, Ceiling, 500000000001, Greater);
     test::<i64>(-1000000000001, -2, Nearest, 500000000000, Less);
+    test::<i64>(-1000000000001, -2, NearestTiesAway, 500000000001, Greater);
+    test::<i64>(-1000000000001, -2, ToOdd, 500000000001, Greater);
 
     test::<i128>(
         -1000000000000000000000000,
@@ -1093,6 +1205,8 @@ fn test_div_round_signed() {
     test::<i8>(-128, 1, Floor, -128, Equal);
     test::<i8>(-128, 1, Ceiling, -128, Equal);
     test::<i8>(-128, 1, Nearest, -128, Equal);
+    test::<i8>(-128, 1, NearestTiesAway, -128, Equal);
+    test::<i8>(-128, 1, ToOdd, -128, Equal);
     test::<i8>(-128, 1, Exact, -128, Equal);
 }
 
@@ -1158,6 +1272,8 @@ fn div_round_properties_helper_unsigned<T: PrimitiveUnsigned>() {
         assert_eq!(x.div_round(y, Floor), (q, o));
         assert_eq!(x.div_round(y, Ceiling), (q, o));
         assert_eq!(x.div_round(y, Nearest), (q, o));
+        assert_eq!(x.div_round(y, NearestTiesAway), (q, o));
+        assert_eq!(x.div_round(y, ToOdd), (q, o));
         assert_eq!(x.div_round(y, Exact), (q, o));
     });
 
@@ -1170,6 +1286,11 @@ fn div_round_properties_helper_unsigned<T: PrimitiveUnsigned>() {
         assert_eq!(x.div_round(y, Ceiling), up);
         let nearest = x.div_round(y, Nearest);
         assert!(nearest == down || nearest == up);
+        let nearest_ties_away = x.div_round(y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = x.div_round(y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
+        assert!(to_odd.0.odd());
     });
 
     unsigned_rounding_mode_pair_gen::<T>().test_properties(|(x, rm)| {
@@ -1239,6 +1360,8 @@ fn div_round_properties_helper_signed<T: PrimitiveSigned>() {
         assert_eq!(x.div_round(y, Floor), (q, o));
         assert_eq!(x.div_round(y, Ceiling), (q, o));
         assert_eq!(x.div_round(y, Nearest), (q, o));
+        assert_eq!(x.div_round(y, NearestTiesAway), (q, o));
+        assert_eq!(x.div_round(y, ToOdd), (q, o));
         assert_eq!(x.div_round(y, Exact), (q, o));
     });
 
@@ -1255,6 +1378,11 @@ fn div_round_properties_helper_signed<T: PrimitiveSigned>() {
         assert_eq!(x.div_round(y, Ceiling), ceiling);
         let nearest = x.div_round(y, Nearest);
         assert!(nearest == down || nearest == up);
+        let nearest_ties_away = x.div_round(y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = x.div_round(y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
+        assert!(to_odd.0.odd());
     });
 
     signed_rounding_mode_pair_gen::<T>().test_properties(|(x, rm)| {
@@ -36,6 +36,8 @@ fn test_round_to_multiple_unsigned() {
     test::<u8>(0, 1, Up, 0, Equal);
     test::<u8>(0, 1, Ceiling, 0, Equal);
     test::<u8>(0, 1, Nearest, 0, Equal);
+    test::<u8>(0, 1, NearestTiesAway, 0, Equal);
+    test::<u8>(0, 1, ToOdd, 0, Equal);
     test::<u8>(0, 1, Exact, 0, Equal);
 
     test::<u16>(0, 123, Down, 0, Equal);
@@ -43,6 +45,8 @@ fn test_round_to_multiple_unsigned() {
     test::<u16>(0, 123, Up, 0, Equal);
     test::<u16>(0, 123, Ceiling, 0, Equal);
     test::<u16>(0, 123, Nearest, 0, Equal);
+    test::<u16>(0, 123, NearestTiesAway, 0, Equal);
+    test::<u16>(0, 123, ToOdd, 0, Equal);
     test::<u16>(0, 123, Exact, 0, Equal);
 
     test::<u32>(1, 1, Down, 1, Equal);
@@ -50,6 +54,8 @@ fn test_round_to_multiple_unsigned() {
     test::<u32>(1, 1, Up, 1, Equal);
     test::<u32>(1, 1, Ceiling, 1, Equal);
     test::<u32>(1, 1, Nearest, 1, Equal);
+    test::<u32>(1, 1, NearestTiesAway, 1, Equal);
+    test::<u32>(1, 1, ToOdd, 1, Equal);
     test::<u32>(1, 1, Exact, 1, Equal);
 
     test::<u64>(123, 1, Down, 123, Equal);
@@ -57,6 +63,8 @@ fn test_round_to_multiple_unsigned() {
     test::<u64>(123, 1, Up, 123, Equal);
     test::<u64>(123, 1, Ceiling, 123, Equal);
     test::<u64>(123, 1, Nearest, 123, Equal);
+    test::<u64>(123, 1, NearestTiesAway, 123, Equal);
+    test::<u64>(123, 1, ToOdd, 123, Equal);
     test::<u64>(123, 1, Exact, 123, Equal);
 
     test::<u128>(123, 2, Down, 122, Less);
@@ -64,18 +72,24 @@ fn test_round_to_multiple_unsigned() {
     test::<u128>(123, 2, Up, 124, Greater);
     test::<u128>(123, 2, Ceiling, 124, Greater);
     test::<u128>(123, 2, Nearest, 124, Greater);
+    test::<u128>(123, 2, NearestTiesAway, 124, Greater);
+    test::<u128>(123, 2, ToOdd, 122, Less);
 
     test::<usize>(125, 2, Down, 124, Less);
     test::<usize>(125, 2, Floor, 124, Less);
     test::<usize>(125, 2, Up, 126, Greater);
     test::<usize>(125, 2, Ceiling, 126, Greater);
     test::<usize>(125, 2, Nearest, 124, Less);
+    test::<usize>(125, 2, NearestTiesAway, 126, Greater);
+    test::<usize>(125, 2, ToOdd, 126, Greater);
 
     test::<u8>(123, 123, Down, 123, Equal);
     test::<u8>(123, 123, Floor, 123, Equal);
     test::<u8>(123, 123, Up, 123, Equal);
     test::<u8>(123, 123, Ceiling, 123, Equal);
     test::<u8>(123, 123, Nearest, 123, Equal);
+    test::<u8>(123, 123, NearestTiesAway, 123, Equal);
+    test::<u8>(123, 123, ToOdd, 123, Equal);
     test::<u8>(123, 123, Exact, 123, Equal);
 
     test::<u16>(123, 456, Down, 0, Less);
@@ -83,12 +97,16 @@ fn test_round_to_multiple_unsigned() {
     test::<u16>(123, 456, Up, 456, Greater);
     test::<u16>(123, 456, Ceiling, 456, Greater);
     test::<u16>(123, 456, Nearest, 0, Less);
+    test::<u16>(123, 456, NearestTiesAway, 0, Less);
+    test::<u16>(123, 456, ToOdd, 456, Greater);
 
     test::<u64>(1000000000000, 1, Down, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Floor, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Up, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Ceiling, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Nearest, 1000000000000, Equal);
+    test::<u64>(1000000000000, 1, NearestTiesAway, 1000000000000, Equal);
+    test::<u64>(1000000000000, 1, ToOdd, 1000000000000, Equal);
     test::<u64>(1000000000000, 1, Exact, 1000000000000, Equal);
 
     test::<u64>(1000000000000, 3, Down, 999999999999, Less);
@@ -96,18 +114,24 @@ fn test_round_to_multiple_unsigned() {
     test::<u64>(1000000000000, 3, Up, 1000000000002, Greater);
     test::<u64>(1000000000000, 3, Ceiling, 1000000000002, Greater);
     test::<u64>(1000000000000, 3, Nearest, 999999999999, Less);
+    test::<u64>(1000000000000, 3, NearestTiesAway, 999999999999, Less);
+    test::<u64>(1000000000000, 3, ToOdd, 999999999999, Less);
 
     test::<u64>(999999999999, 2, Down, 999999999998, Less);
     test::<u64>(999999999999, 2, Floor, 999999999998, Less);
     test::<u64>(999999999999, 2, Up, 1000000000000, Greater);
     test::<u64>(999999999999, 2, Ceiling, 1000000000000, Greater);
     test::<u64>(999999999999, 2, Nearest, 1000000000000, Greater);
+    test::<u64>(999999999999, 2, NearestTiesAway, 1000000000000, Greater);
+    test::<u64>(999999999999, 2, ToOdd, 999999999998, Less);
 
     test::<u64>(1000000000001, 2, Down, 1000000000000, Less);
     test::<u64>(1000000000001, 2, Floor, 1000000000000, Less);
     test::<u64>(1000000000001, 2, Up, 1000000000002, Greater);
     test::<u64>(1000000000001, 2, Ceiling, 1000000000002, Greater);
     test::<u64>(1000000000001, 2, Nearest, 1000000000000, Less);
+    test::<u64>(1000000000001, 2, NearestTiesAway, 1000000000002, Greater);
+    test::<u64>(1000000000001, 2, ToOdd, 1000000000002, Greater);
 
     test::<u128>(
         1000000000000000000000000,
@@ -272,6 +296,8 @@ fn test_round_to_multiple_signed() {
     test::<i8>(0, 1, Up, 0, Equal);
     test::<i8>(0, 1, Ceiling, 0, Equal);
     test::<i8>(0, 1, Nearest, 0, Equal);
+    test::<i8>(0, 1, NearestTiesAway, 0, Equal);
+    test::<i8>(0, 1, ToOdd, 0, Equal);
     test::<i8>(0, 1, Exact, 0, Equal);
 
     test::<i16>(0, 123, Down, 0, Equal);
@@ -279,6 +305,8 @@ fn test_round_to_multiple_signed() {
     test::<i16>(0, 123, Up, 0, Equal);
     test::<i16>(0, 123, Ceiling, 0, Equal);
     test::<i16>(0, 123, Nearest, 0, Equal);
+    test::<i16>(0, 123, NearestTiesAway, 0, Equal);
+    test::<i16>(0, 123, ToOdd, 0, Equal);
     test::<i16>(0, 123, Exact, 0, Equal);
 
     test::<i32>(1, 1, Down, 1, Equal);
@@ -286,6 +314,8 @@ fn test_round_to_multiple_signed() {
     test::<i32>(1, 1, Up, 1, Equal);
     test::<i32>(1, 1, Ceiling, 1, Equal);
     test::<i32>(1, 1, Nearest, 1, Equal);
+    test::<i32>(1, 1, NearestTiesAway, 1, Equal);
+    test::<i32>(1, 1, ToOdd, 1, Equal);
     test::<i32>(1, 1, Exact, 1, Equal);
 
     test::<i64>(123, 1, Down, 123, Equal);
@@ -293,6 +323,8 @@ fn test_round_to_multiple_signed() {
     test::<i64>(123, 1, Up, 123, Equal);
     test::<i64>(123, 1, Ceiling, 123, Equal);
     test::<i64>(123, 1, Nearest, 123, Equal);
+    test::<i64>(123, 1, NearestTiesAway, 123, Equal);
+    test::<i64>(123, 1, ToOdd, 123, Equal);
     test::<i64>(123, 1, Exact, 123, Equal);
 
     test::<i128>(123, 2, Down, 122, Less);
@@ -300,18 +332,24 @@ fn test_round_to_multiple_signed() {
     test::<i128>(123, 2, Up, 124, Greater);
     test::<i128>(123, 2, Ceiling, 124, Greater);
     test::<i128>(123, 2, Nearest, 124, Greater);
+    test::<i128>(123, 2, NearestTiesAway, 124, Greater);
+    test::<i128>(123, 2, ToOdd, 122, Less);
 
     test::<isize>(125, 2, Down, 124, Less);
     test::<isize>(125, 2, Floor, 124, Less);
     test::<isize>(125, 2, Up, 126, Greater);
     test::<isize>(125, 2, Ceiling, 126, Greater);
     test::<isize>(125, 2, Nearest, 124, Less);
+    test::<isize>(125, 2, NearestTiesAway, 126, Greater);
+    test::<isize>(125, 2, ToOdd, 126, Greater);
 
     test::<i8>(123, 123, Down, 123, Equal);
     test::<i8>(123, 123, Floor, 123, Equal);
     test::<i8>(123, 123, Up, 123, Equal);
     test::<i8>(123, 123, Ceiling, 123, Equal);
     test::<i8>(123, 123, Nearest, 123, Equal);
+    test::<i8>(123, 123, NearestTiesAway, 123, Equal);
+    test::<i8>(123, 123, ToOdd, 123, Equal);
     test::<i8>(123, 123, Exact, 123, Equal);
 
     test::<i16>(123, 456, Down, 0, Less);
@@ -319,12 +357,16 @@ fn test_round_to_multiple_signed() {
     test::<i16>(123, 456, Up, 456, Greater);
     test::<i16>(123, 456, Ceiling, 456, Greater);
     test::<i16>(123, 456, Nearest, 0, Less);
+    test::<i16>(123, 456, NearestTiesAway, 0, Less);
+    test::<i16>(123, 456, ToOdd, 456, Greater);
 
     test::<i64>(1000000000000, 1, Down, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Floor, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Up, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Ceiling, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Nearest, 1000000000000, Equal);
+    test::<i64>(1000000000000, 1, NearestTiesAway, 1000000000000, Equal);
+    test::<i64>(1000000000000, 1, ToOdd, 1000000000000, Equal);
     test::<i64>(1000000000000, 1, Exact, 1000000000000, Equal);
 
     test::<i64>(1000000000000, 3, Down, 999999999999, Less);
@@ -332,18 +374,24 @@ fn test_round_to_multiple_signed() {
     test::<i64>(1000000000000, 3, Up, 1000000000002, Greater);
     test::<i64>(1000000000000, 3, Ceiling, 1000000000002, Greater);
     test::<i64>(1000000000000, 3, Nearest, 999999999999, Less);
+    test::<i64>(1000000000000, 3, NearestTiesAway, 999999999999, Less);
+    test::<i64>(1000000000000, 3, ToOdd, 999999999999, Less);
 
     test::<i64>(999999999999, 2, Down, 999999999998, Less);
     test::<i64>(999999999999, 2, Floor, 999999999998, Less);
     test::<i64>(999999999999, 2, Up, 1000000000000, Greater);
     test::<i64>(999999999999, 2, Ceiling, 1000000000000, Greater);
     test::<i64>(999999999999, 2, Nearest, 1000000000000, Greater);
+    test::<i64>(999999999999, 2, NearestTiesAway, 1000000000000, Greater);
+    test::<i64>(999999999999, 2, ToOdd, 999999999998, Less);
 
     test::<i64>(1000000000001, 2, Down, 1000000000000, Less);
     test::<i64>(1000000000001, 2, Floor, 1000000000000, Less);
     test::<i64>(1000000000001, 2, Up, 1000000000002, Greater);
     test::<i64>(1000000000001, 2, Ceiling, 1000000000002, Greater);
     test::<i64>(1000000000001, 2, Nearest, 1000000000000, Less);
+    test::<i64>(1000000000001, 2, NearestTiesAway, 1000000000002, Greater);
+    test::<i64>(1000000000001, 2, ToOdd, 1000000000002, Greater);
 
     test::<i128>(
         1000000000000000000000000,
@@ -487,6 +535,8 @@ fn test_round_to_multiple_signed() {
     test::<i8>(0, -1, Up, 0, Equal);
     test::<i8>(0, -1, Ceiling, 0, Equal);
     test::<i8>(0, -1, Nearest, 0, Equal);
+    test::<i8>(0, -1, NearestTiesAway, 0, Equal);
+    test::<i8>(0, -1, ToOdd, 0, Equal);
     test::<i8>(0, -1, Exact, 0, Equal);
 
     test::<i16>(0, -123, Down, 0, Equal);
@@ -494,6 +544,8 @@ fn test_round_to_multiple_signed() {
     test::<i16>(0, -123, Up, 0, Equal);
     test::<i16>(0, -123, Ceiling, 0, Equal);
     test::<i16>(0, -123, Nearest, 0, Equal);
+    test::<i16>(0, -123, NearestTiesAway, 0, Equal);
+    test::<i16>(0, -123, ToOdd, 0, Equal);
     test::<i16>(0, -123, Exact, 0, Equal);
 
     test::<i32>(1, -1, Down, 1, Equal);
@@ -501,6 +553,8 @@ fn test_round_to_multiple_signed() {
     test::<i32>(1, -1, Up, 1, Equal);
     test::<i32>(1, -1, Ceiling, 1, Equal);
     test::<i32>(1, -1, Nearest, 1, Equal);
+    test::<i32>(1, -1, NearestTiesAway, 1, Equal);
+    test::<i32>(1, -1, ToOdd, 1, Equal);
     test::<i32>(1, -1, Exact, 1, Equal);
 
     test::<i64>(123, -1, Down, 123, Equal);
@@ -508,6 +562,8 @@ fn test_round_to_multiple_signed() {
     test::<i64>(123, -1, Up, 123, Equal);
     test::<i64>(123, -1, Ceiling, 123, Equal);
     test::<i64>(123, -1, Nearest, 123, Equal);
+    test::<i64>(123, -1, NearestTiesAway, 123, Equal);
+    test::<i64>(123, -1, ToOdd, 123, Equal);
     test::<i64>(123, -1, Exact, 123, Equal);
 
     test::<i128>(123, -2, Down, 122, Less);
@@ -515,18 +571,24 @@ fn test_round_to_multiple_signed() {
     test::<i128>(123, -2, Up, 124, Greater);
     test::<i128>(123, -2, Ceiling, 124, Greater);
     test::<i128>(123, -2, Nearest, 124, Greater);
+    test::<i128>(123, -2, NearestTiesAway, 124, Greater);
+    test::<i128>(123, -2, ToOdd, 122, Less);
 
     test::<isize>(125, -2, Down, 124, Less);
     test::<isize>(125, -2, Floor, 124, Less);
     test::<isize>(125, -2, Up, 126, Greater);
     test::<isize>(125, -2, Ceiling, 126, Greater);
     test::<isize>(125, -2, Nearest, 124, Less);
+    test::<isize>(125, -2, NearestTiesAway, 126, Greater);
+    test::<isize>(125, -2, ToOdd, 126, Greater);
 
     test::<i8>(123, -123, Down, 123, Equal);
     test::<i8>(123, -123, Floor, 123, Equal);
     test::<i8>(123, -123, Up, 123, Equal);
     test::<i8>(123, -123, Ceiling, 123, Equal);
     test::<i8>(123, -123, Nearest, 123, Equal);
+    test::<i8>(123, -123, NearestTiesAway, 123, Equal);
+    test::<i8>(123, -123, ToOdd, 123, Equal);
     test::<i8>(123, -123, Exact, 123, Equal);
 
     test::<i16>(123, -456, Down, 0, Less);
@@ -534,12 +596,16 @@ fn test_round_to_multiple_signed() {
     test::<i16>(123, -456, Up, 456, Greater);
     test::<i16>(123, -456, Ceiling, 456, Greater);
     test::<i16>(123, -456, Nearest, 0, Less);
+    test::<i16>(123, -456, NearestTiesAway, 0, Less);
+    test::<i16>(123, -456, ToOdd, 456, Greater);
 
     test::<i64>(1000000000000, -1, Down, 1000000000000, Equal);
     test::<i64>(1000000000000, -1, Floor, 1000000000000, Equal);
     test::<i64>(1000000000000, -1, Up, 1000000000000, Equal);
     test::<i64>(1000000000000, -1, Ceiling, 1000000000000, Equal);
     test::<i64>(1000000000000, -1, Nearest, 1000000000000, Equal);
+    test::<i64>(1000000000000, -1, NearestTiesAway, 1000000000000, Equal);
+    test::<i64>(1000000000000, -1, ToOdd, 1000000000000, Equal);
     test::<i64>(1000000000000, -1, Exact, 1000000000000, Equal);
 
     test::<i64>(1000000000000, -3, Down, 999999999999, Less);
@@ -547,18 +613,24 @@ fn test_round_to_multiple_signed() {
     test::<i64>(1000000000000, -3, Up, 1000000000002, Greater);
     test::<i64>(1000000000000, -3, Ceiling, 1000000000002, Greater);
     test::<i64>(1000000000000, -3, Nearest, 999999999999, Less);
+    test::<i64>(1000000000000, -3, NearestTiesAway, 999999999999, Less);
+    test::<i64>(1000000000000, -3, ToOdd, 999999999999, Less);
 
     test::<i64>(999999999999, -2, Down, 999999999998, Less);
     test::<i64>(999999999999, -2, Floor, 999999999998, Less);
     test::<i64>(999999999999, -2, Up, 1000000000000, Greater);
     test::<i64>(999999999999, -2, Ceiling, 1000000000000, Greater);
     test::<i64>(999999999999, -2, Nearest, 1000000000000, Greater);
+    test::<i64>(999999999999, -2, NearestTiesAway, 1000000000000, Greater);
+    test::<i64>(999999999999, -2, ToOdd, 999999999998, Less);
 
     test::<i64>(1000000000001, -2, Down, 1000000000000, Less);
     test::<i64>(1000000000001, -2, Floor, 1000000000000, Less);
     test::<i64>(1000000000001, -2, Up, 1000000000002, Greater);
     test::<i64>(1000000000001, -2, Ceiling, 1000000000002, Greater);
     test::<i64>(1000000000001, -2, Nearest, 1000000000000, Less);
+    test::<i64>(1000000000001, -2, NearestTiesAway, 1000000000002, Greater);
+    test::<i64>(1000000000001, -2, ToOdd, 1000000000002, Greater);
 
     test::<i128>(
         1000000000000000000000000,
@@ -702,6 +774,8 @@ fn test_round_to_multiple_signed() {
     test::<i32>(-1, 1, Up, -1, Equal);
     test::<i32>(-1, 1, Ceiling, -1, Equal);
     test::<i32>(-1, 1, Nearest, -1, Equal);
+    test::<i32>(-1, 1, NearestTiesAway, -1, Equal);
+    test::<i32>(-1, 1, ToOdd, -1, Equal);
     test::<i32>(-1, 1, Exact, -1, Equal);
 
     test::<i64>(-123, 1, Down, -123, Equal);
@@ -709,6 +783,8 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-123, 1, Up, -123, Equal);
     test::<i64>(-123, 1, Ceiling, -123, Equal);
     test::<i64>(-123, 1, Nearest, -123, Equal);
+    test::<i64>(-123, 1, NearestTiesAway, -123, Equal);
+    test::<i64>(-123, 1, ToOdd, -123, Equal);
     test::<i64>(-123, 1, Exact, -123, Equal);
 
     test::<i128>(-123, 2, Down, -122, Greater);
@@ -716,18 +792,24 @@ fn test_round_to_multiple_signed() {
     test::<i128>(-123, 2, Up, -124, Less);
     test::<i128>(-123, 2, Ceiling, -122, Greater);
     test::<i128>(-123, 2, Nearest, -124, Less);
+    test::<i128>(-123, 2, NearestTiesAway, -124, Less);
+    test::<i128>(-123, 2, ToOdd, -122, Greater);
 
     test::<isize>(-125, 2, Down, -124, Greater);
     test::<isize>(-125, 2, Floor, -126, Less);
     test::<isize>(-125, 2, Up, -126, Less);
     test::<isize>(-125, 2, Ceiling, -124, Greater);
     test::<isize>(-125, 2, Nearest, -124, Greater);
+    test::<isize>(-125, 2, NearestTiesAway, -126, Less);
+    test::<isize>(-125, 2, ToOdd, -126, Less);
 
     test::<i8>(-123, 123, Down, -123, Equal);
     test::<i8>(-123, 123, Floor, -123, Equal);
     test::<i8>(-123, 123, Up, -123, Equal);
     test::<i8>(-123, 123, Ceiling, -123, Equal);
     test::<i8>(-123, 123, Nearest, -123, Equal);
+    test::<i8>(-123, 123, NearestTiesAway, -123, Equal);
+    test::<i8>(-123, 123, ToOdd, -123, Equal);
     test::<i8>(-123, 123, Exact, -123, Equal);
 
     test::<i16>(-123, 456, Down, 0, Greater);
@@ -735,12 +817,16 @@ fn test_round_to_multiple_signed() {
     test::<i16>(-123, 456, Up, -456, Less);
     test::<i16>(-123, 456, Ceiling, 0, Greater);
     test::<i16>(-123, 456, Nearest, 0, Greater);
+    test::<i16>(-123, 456, NearestTiesAway, 0, Greater);
+    test::<i16>(-123, 456, ToOdd, -456, Less);
 
     test::<i64>(-1000000000000, 1, Down, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Floor, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Up, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Ceiling, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Nearest, -1000000000000, Equal);
+    test::<i64>(-1000000000000, 1, NearestTiesAway, -1000000000000, Equal);
+    test::<i64>(-1000000000000, 1, ToOdd, -1000000000000, Equal);
     test::<i64>(-1000000000000, 1, Exact, -1000000000000, Equal);
 
     test::<i64>(-1000000000000, 3, Down, -999999999999, Greater);
@@ -748,18 +834,24 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-1000000000000, 3, Up, -1000000000002, Less);
     test::<i64>(-1000000000000, 3, Ceiling, -999999999999, Greater);
     test::<i64>(-1000000000000, 3, Nearest, -999999999999, Greater);
+    test::<i64>(-1000000000000, 3, NearestTiesAway, -999999999999, Greater);
+    test::<i64>(-1000000000000, 3, ToOdd, -999999999999, Greater);
 
     test::<i64>(-999999999999, 2, Down, -999999999998, Greater);
     test::<i64>(-999999999999, 2, Floor, -1000000000000, Less);
     test::<i64>(-999999999999, 2, Up, -1000000000000, Less);
     test::<i64>(-999999999999, 2, Ceiling, -999999999998, Greater);
     test::<i64>(-999999999999, 2, Nearest, -1000000000000, Less);
+    test::<i64>(-999999999999, 2, NearestTiesAway, -1000000000000, Less);
+    test::<i64>(-999999999999, 2, ToOdd, -999999999998, Greater);
 
     test::<i64>(-1000000000001, 2, Down, -1000000000000, Greater);
     test::<i64>(-1000000000001, 2, Floor, -1000000000002, Less);
     test::<i64>(-1000000000001, 2, Up, -1000000000002, Less);
     test::<i64>(-1000000000001, 2, Ceiling, -1000000000000, Greater);
     test::<i64>(-1000000000001, 2, Nearest, -1000000000000, Greater);
+    test::<i64>(-1000000000001, 2, NearestTiesAway, -1000000000002, Less);
+    test::<i64>(-1000000000001, 2, ToOdd, -1000000000002, Less);
 
     test::<i128>(
         -1000000000000000000000000,
@@ -903,6 +995,8 @@ fn test_round_to_multiple_signed() {
     test::<i32>(-1, -1, Up, -1, Equal);
     test::<i32>(-1, -1, Ceiling, -1, Equal);
     test::<i32>(-1, -1, Nearest, -1, Equal);
+    test::<i32>(-1, -1, NearestTiesAway, -1, Equal);
+    test::<i32>(-1, -1, ToOdd, -1, Equal);
     test::<i32>(-1, -1, Exact, -1, Equal);
 
     test::<i64>(-123, -1, Down, -123, Equal);
@@ -910,6 +1004,8 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-123, -1, Up, -123, Equal);
     test::<i64>(-123, -1, Ceiling, -123, Equal);
     test::<i64>(-123, -1, Nearest, -123, Equal);
+    test::<i64>(-123, -1, NearestTiesAway, -123, Equal);
+    test::<i64>(-123, -1, ToOdd, -123, Equal);
     test::<i64>(-123, -1, Exact, -123, Equal);
 
     test::<i128>(-123, -2, Down, -122, Greater);
@@ -917,18 +1013,24 @@ fn test_round_to_multiple_signed() {
     test::<i128>(-123, -2, Up, -124, Less);
     test::<i128>(-123, -2, Ceiling, -122, Greater);
     test::<i128>(-123, -2, Nearest, -124, Less);
+    test::<i128>(-123, -2, NearestTiesAway, -124, Less);
+    test::<i128>(-123, -2, ToOdd, -122, Greater);
 
     test::<isize>(-125, -2, Down, -124, Greater);
     test::<isize>(-125, -2, Floor, -126, Less);
     test::<isize>(-125, -2, Up, -126, Less);
     test::<isize>(-125, -2, Ceiling, -124, Greater);
     test::<isize>(-125, -2, Nearest, -124, Greater);
+    test::<isize>(-125, -2, NearestTiesAway, -126, Less);
+    test::<isize>(-125, -2, ToOdd, -126, Less);
 
     test::<i8>(-123, -123, Down, -123, Equal);
     test::<i8>(-123, -123, Floor, -123, Equal);
     test::<i8>(-123, -123, Up, -123, Equal);
     test::<i8>(-123, -123, Ceiling, -123, Equal);
     test::<i8>(-123, -123, Nearest, -123, Equal);
+    test::<i8>(-123, -123, NearestTiesAway, -123, Equal);
+    test::<i8>(-123, -123, ToOdd, -123, Equal);
     test::<i8>(-123, -123, Exact, -123, Equal);
 
     test::<i16>(-123, -456, Down, 0, Greater);
@@ -936,12 +1038,16 @@ fn test_round_to_multiple_signed() {
     test::<i16>(-123, -456, Up, -456, Less);
     test::<i16>(-123, -456, Ceiling, 0, Greater);
     test::<i16>(-123, -456, Nearest, 0, Greater);
+    test::<i16>(-123, -456, NearestTiesAway, 0, Greater);
+    test::<i16>(-123, -456, ToOdd, -456, Less);
 
     test::<i64>(-1000000000000, -1, Down, -1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Floor, -1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Up, -1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Ceiling, -1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Nearest, -1000000000000, Equal);
+    test::<i64>(-1000000000000, -1, NearestTiesAway, -1000000000000, Equal);
+    test::<i64>(-1000000000000, -1, ToOdd, -1000000000000, Equal);
     test::<i64>(-1000000000000, -1, Exact, -1000000000000, Equal);
 
     test::<i64>(-1000000000000, -3, Down, -999999999999, Greater);
@@ -949,18 +1055,24 @@ fn test_round_to_multiple_signed() {
     test::<i64>(-1000000000000, -3, Up, -1000000000002, Less);
     test::<i64>(-1000000000000, -3, Ceiling, -999999999999, Greater);
     test::<i64>(-1000000000000, -3, Nearest, -999999999999, Greater);
+    test::<i64>(-1000000000000, -3, NearestTiesAway, -999999999999, Greater);
+    test::<i64>(-1000000000000, -3, ToOdd, -999999999999, Greater);
 
     test::<i64>(-999999999999, -2, Down, -999999999998, Greater);
     test::<i64>(-999999999999, -2, Floor, -1000000000000, Less);
     test::<i64>(-999999999999, -2, Up, -1000000000000, Less);
     test::<i64>(-999999999999, -2, Ceiling, -999999999998, Greater);
     test::<i64>(-999999999999, -2, Nearest, -1000000000000, Less);
+    test::<i64>(-999999999999, -2, NearestTiesAway, -1000000000000, Less);
+    test::<i64>(-999999999999, -2, ToOdd, -999999999998, Greater);
 
     test::<i64>(-1000000000001, -2, Down, -1000000000000, Greater);
     test::<i64>(-1000000000001, -2, Floor, -1000000000002, Less);
     test::<i64>(-1000000000001, -2, Up, -1000000000002, Less);
     test::<i64>(-1000000000001, -2, Ceiling, -1000000000000, Greater);
     test::<i64>(-1000000000001, -2, Nearest, -1000000000000, Greater);
+    test::<i64>(-1000000000001, -2, NearestTiesAway, -1000000000002, Less);
+    test::<i64>(-1000000000001, -2, ToOdd, -1000000000002, Less);
 
     test::<i128>(
         -1000000000000000000000000,
@@ -1104,6 +1216,8 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, 1, Floor, -128, Equal);
     test::<i8>(-128, 1, Ceiling, -128, Equal);
     test::<i8>(-128, 1, Nearest, -128, Equal);
+    test::<i8>(-128, 1, NearestTiesAway, -128, Equal);
+    test::<i8>(-128, 1, ToOdd, -128, Equal);
     test::<i8>(-128, 1, Exact, -128, Equal);
 
     test::<i8>(-128, -1, Down, -128, Equal);
@@ -1111,6 +1225,8 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, -1, Floor, -128, Equal);
     test::<i8>(-128, -1, Ceiling, -128, Equal);
     test::<i8>(-128, -1, Nearest, -128, Equal);
+    test::<i8>(-128, -1, NearestTiesAway, -128, Equal);
+    test::<i8>(-128, -1, ToOdd, -128, Equal);
     test::<i8>(-128, -1, Exact, -128, Equal);
 
     test::<i8>(-128, -128, Down, -128, Equal);
@@ -1118,6 +1234,8 @@ fn test_round_to_multiple_signed() {
     test::<i8>(-128, -128, Floor, -128, Equal);
     test::<i8>(-128, -128, Ceiling, -128, Equal);
     test::<i8>(-128, -128, Nearest, -128, Equal);
+    test::<i8>(-128, -128, NearestTiesAway, -128, Equal);
+    test::<i8>(-128, -128, ToOdd, -128, Equal);
     test::<i8>(-128, -128, Exact, -128, Equal);
 
     test::<i8>(0, 0, Floor, 0, Equal);
@@ -1219,7 +1337,7 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
                 assert!(rounded >= x);
             }
             Exact => assert_eq!(rounded, x),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if y == T::ZERO {
                     assert_eq!(rounded, T::ZERO);
                 } else {
@@ -1237,11 +1355,20 @@ fn round_to_multiple_properties_helper_unsigned<T: PrimitiveUnsigned>() {
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(rounded.div_exact(y).even());
+                            if rm == Nearest {
+                                assert!(rounded.div_exact(y).even());
+                            } else {
+                                assert!(rounded > x);
+                            }
                         }
                     }
                 }
             }
+            ToOdd => {
+                if o != Equal {
+                    assert!(rounded.div_exact(y).odd());
+                }
+            }
         }
 
         if o == Equal {
@@ -1320,7 +1447,7 @@ fn round_to_multiple_properties_helper_signed<
             Down => assert!(rounded.le_abs(&x)),
             Up => assert!(rounded.ge_abs(&x)),
             Exact => assert_eq!(rounded, x),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if y == S::ZERO {
                     assert_eq!(rounded, S::ZERO);
                 } else {
@@ -1343,11 +1470,20 @@ fn round_to_multiple_properties_helper_signed<
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(rounded.div_exact(y).even());
+                            if rm == Nearest {
+                                assert!(rounded.div_exact(y).even());
+                            } else {
+                                assert!(rounded.gt_abs(&x));
+                            }
                         }
                     }
                 }
             }
+            ToOdd => {
+                if o != Equal {
+                    assert!(rounded.div_exact(y).odd());
+                }
+            }
         }
 
         if o == Equal {
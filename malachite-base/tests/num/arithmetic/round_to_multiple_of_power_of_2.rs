@@ -39,6 +39,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<u32>(10, 2, Down, 8, Less);
     test::<u64>(10, 2, Up, 12, Greater);
     test::<u128>(10, 2, Nearest, 8, Less);
+    test::<u128>(10, 2, NearestTiesAway, 12, Greater);
+    test::<u128>(10, 2, ToOdd, 12, Greater);
     test::<usize>(12, 2, Exact, 12, Equal);
 
     test::<i8>(-10, 2, Floor, -12, Less);
@@ -46,6 +48,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<i32>(-10, 2, Down, -8, Greater);
     test::<i64>(-10, 2, Up, -12, Less);
     test::<i128>(-10, 2, Nearest, -8, Greater);
+    test::<i128>(-10, 2, NearestTiesAway, -12, Less);
+    test::<i128>(-10, 2, ToOdd, -12, Less);
     test::<isize>(-12, 2, Exact, -12, Equal);
 
     test::<u8>(0xff, 4, Down, 0xf0, Less);
@@ -54,6 +58,7 @@ fn test_round_to_multiple_of_power_of_2() {
     test::<u8>(0xef, 4, Ceiling, 0xf0, Greater);
     test::<u8>(0xe8, 4, Nearest, 0xe0, Less);
     test::<u8>(1, 8, Nearest, 0, Less);
+    test::<u8>(1, 8, NearestTiesAway, 0, Less);
 
     test::<i8>(0x7f, 4, Down, 0x70, Less);
     test::<i8>(0x7f, 4, Floor, 0x70, Less);
@@ -133,7 +138,7 @@ fn round_to_multiple_of_power_of_2_properties_helper_unsigned<T: PrimitiveUnsign
                 assert!(rounded >= n);
             }
             Exact => assert_eq!(rounded, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if let Some(k) = T::ONE.arithmetic_checked_shl(pow) {
                     let mut closest = None;
                     let mut second_closest = None;
@@ -149,11 +154,20 @@ fn round_to_multiple_of_power_of_2_properties_helper_unsigned<T: PrimitiveUnsign
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(!rounded.get_bit(pow));
+                            if rm == Nearest {
+                                assert!(!rounded.get_bit(pow));
+                            } else {
+                                assert!(rounded > n);
+                            }
                         }
                     }
                 }
             }
+            ToOdd => {
+                if o != Equal {
+                    assert!(rounded.get_bit(pow));
+                }
+            }
         }
 
         if o == Equal {
@@ -254,7 +268,7 @@ fn round_to_multiple_of_power_of_2_properties_helper_signed<
             Down => assert!(rounded.le_abs(&n)),
             Up => assert!(rounded.ge_abs(&n)),
             Exact => assert_eq!(rounded, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 if let Some(k) = S::ONE.arithmetic_checked_shl(pow) {
                     let mut closest = None;
                     let mut second_closest = None;
@@ -270,11 +284,20 @@ fn round_to_multiple_of_power_of_2_properties_helper_signed<
                     if let (Some(closest), Some(second_closest)) = (closest, second_closest) {
                         assert!(closest <= second_closest);
                         if closest == second_closest {
-                            assert!(!rounded.get_bit(pow));
+                            if rm == Nearest {
+                                assert!(!rounded.get_bit(pow));
+                            } else {
+                                assert!(rounded.gt_abs(&n));
+                            }
                         }
                     }
                 }
             }
+            ToOdd => {
+                if o != Equal {
+                    assert!(rounded.get_bit(pow));
+                }
+            }
         }
 
         if o == Equal {
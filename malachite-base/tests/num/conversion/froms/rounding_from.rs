@@ -41,6 +41,8 @@ pub fn test_rounding_from() {
     test_from_floating_point::<f32, u8>(0.0, Up, 0, Equal);
     test_from_floating_point::<f32, u8>(0.0, Ceiling, 0, Equal);
     test_from_floating_point::<f32, u8>(0.0, Nearest, 0, Equal);
+    test_from_floating_point::<f32, u8>(0.0, NearestTiesAway, 0, Equal);
+    test_from_floating_point::<f32, u8>(0.0, ToOdd, 0, Equal);
     test_from_floating_point::<f32, u8>(0.0, Exact, 0, Equal);
 
     test_from_floating_point::<f32, u8>(-0.0, Down, 0, Equal);
@@ -48,6 +50,8 @@ pub fn test_rounding_from() {
     test_from_floating_point::<f32, u8>(-0.0, Up, 0, Equal);
     test_from_floating_point::<f32, u8>(-0.0, Ceiling, 0, Equal);
     test_from_floating_point::<f32, u8>(-0.0, Nearest, 0, Equal);
+    test_from_floating_point::<f32, u8>(-0.0, NearestTiesAway, 0, Equal);
+    test_from_floating_point::<f32, u8>(-0.0, ToOdd, 0, Equal);
     test_from_floating_point::<f32, u8>(-0.0, Exact, 0, Equal);
 
     test_from_floating_point::<f32, u8>(100.0, Down, 100, Equal);
@@ -55,6 +59,8 @@ pub fn test_rounding_from() {
     test_from_floating_point::<f32, u8>(100.0, Up, 100, Equal);
     test_from_floating_point::<f32, u8>(100.0, Ceiling, 100, Equal);
     test_from_floating_point::<f32, u8>(100.0, Nearest, 100, Equal);
+    test_from_floating_point::<f32, u8>(100.0, NearestTiesAway, 100, Equal);
+    test_from_floating_point::<f32, u8>(100.0, ToOdd, 100, Equal);
     test_from_floating_point::<f32, u8>(100.0, Exact, 100, Equal);
 
     test_from_floating_point::<f32, u8>(100.1, Down, 100, Less);
@@ -62,54 +68,74 @@ pub fn test_rounding_from() {
     test_from_floating_point::<f32, u8>(100.1, Up, 101, Greater);
     test_from_floating_point::<f32, u8>(100.1, Ceiling, 101, Greater);
     test_from_floating_point::<f32, u8>(100.1, Nearest, 100, Less);
+    test_from_floating_point::<f32, u8>(100.1, NearestTiesAway, 100, Less);
+    test_from_floating_point::<f32, u8>(100.1, ToOdd, 101, Greater);
 
     test_from_floating_point::<f32, u8>(100.9, Down, 100, Less);
     test_from_floating_point::<f32, u8>(100.9, Floor, 100, Less);
     test_from_floating_point::<f32, u8>(100.9, Up, 101, Greater);
     test_from_floating_point::<f32, u8>(100.9, Ceiling, 101, Greater);
     test_from_floating_point::<f32, u8>(100.9, Nearest, 101, Greater);
+    test_from_floating_point::<f32, u8>(100.9, NearestTiesAway, 101, Greater);
+    test_from_floating_point::<f32, u8>(100.9, ToOdd, 101, Greater);
 
     test_from_floating_point::<f32, u8>(100.5, Down, 100, Less);
     test_from_floating_point::<f32, u8>(100.5, Floor, 100, Less);
     test_from_floating_point::<f32, u8>(100.5, Up, 101, Greater);
     test_from_floating_point::<f32, u8>(100.5, Ceiling, 101, Greater);
     test_from_floating_point::<f32, u8>(100.5, Nearest, 100, Less);
+    test_from_floating_point::<f32, u8>(100.5, NearestTiesAway, 101, Greater);
+    test_from_floating_point::<f32, u8>(100.5, ToOdd, 101, Greater);
 
     test_from_floating_point::<f32, u8>(101.5, Down, 101, Less);
     test_from_floating_point::<f32, u8>(101.5, Floor, 101, Less);
     test_from_floating_point::<f32, u8>(101.5, Up, 102, Greater);
     test_from_floating_point::<f32, u8>(101.5, Ceiling, 102, Greater);
     test_from_floating_point::<f32, u8>(101.5, Nearest, 102, Greater);
+    test_from_floating_point::<f32, u8>(101.5, NearestTiesAway, 102, Greater);
+    test_from_floating_point::<f32, u8>(101.5, ToOdd, 101, Less);
 
     test_from_floating_point::<f32, u8>(256.0, Down, 255, Less);
     test_from_floating_point::<f32, u8>(256.0, Floor, 255, Less);
     test_from_floating_point::<f32, u8>(256.0, Nearest, 255, Less);
+    test_from_floating_point::<f32, u8>(256.0, NearestTiesAway, 255, Less);
+    test_from_floating_point::<f32, u8>(256.0, ToOdd, 255, Less);
 
     test_from_floating_point::<f32, u8>(-100.0, Down, 0, Greater);
     test_from_floating_point::<f32, u8>(-100.0, Ceiling, 0, Greater);
     test_from_floating_point::<f32, u8>(-100.0, Nearest, 0, Greater);
+    test_from_floating_point::<f32, u8>(-100.0, NearestTiesAway, 0, Greater);
 
     test_from_floating_point::<f32, i8>(128.0, Down, 127, Less);
     test_from_floating_point::<f32, i8>(128.0, Floor, 127, Less);
     test_from_floating_point::<f32, i8>(128.0, Nearest, 127, Less);
+    test_from_floating_point::<f32, i8>(128.0, NearestTiesAway, 127, Less);
+    test_from_floating_point::<f32, i8>(128.0, ToOdd, 127, Less);
 
     test_from_floating_point::<f32, i8>(-129.0, Down, -128, Greater);
     test_from_floating_point::<f32, i8>(-129.0, Ceiling, -128, Greater);
     test_from_floating_point::<f32, i8>(-129.0, Nearest, -128, Greater);
+    test_from_floating_point::<f32, i8>(-129.0, NearestTiesAway, -128, Greater);
 
     test_from_floating_point::<f32, u8>(f32::INFINITY, Down, 255, Less);
     test_from_floating_point::<f32, u8>(f32::INFINITY, Floor, 255, Less);
     test_from_floating_point::<f32, u8>(f32::INFINITY, Nearest, 255, Less);
+    test_from_floating_point::<f32, u8>(f32::INFINITY, NearestTiesAway, 255, Less);
+    test_from_floating_point::<f32, u8>(f32::INFINITY, ToOdd, 255, Less);
     test_from_floating_point::<f32, u8>(f32::NEGATIVE_INFINITY, Down, 0, Greater);
     test_from_floating_point::<f32, u8>(f32::NEGATIVE_INFINITY, Ceiling, 0, Greater);
     test_from_floating_point::<f32, u8>(f32::NEGATIVE_INFINITY, Nearest, 0, Greater);
+    test_from_floating_point::<f32, u8>(f32::NEGATIVE_INFINITY, NearestTiesAway, 0, Greater);
 
     test_from_floating_point::<f32, i8>(f32::INFINITY, Down, 127, Less);
     test_from_floating_point::<f32, i8>(f32::INFINITY, Floor, 127, Less);
     test_from_floating_point::<f32, i8>(f32::INFINITY, Nearest, 127, Less);
+    test_from_floating_point::<f32, i8>(f32::INFINITY, NearestTiesAway, 127, Less);
+    test_from_floating_point::<f32, i8>(f32::INFINITY, ToOdd, 127, Less);
     test_from_floating_point::<f32, i8>(f32::NEGATIVE_INFINITY, Down, -128, Greater);
     test_from_floating_point::<f32, i8>(f32::NEGATIVE_INFINITY, Ceiling, -128, Greater);
     test_from_floating_point::<f32, i8>(f32::NEGATIVE_INFINITY, Nearest, -128, Greater);
+    test_from_floating_point::<f32, i8>(f32::NEGATIVE_INFINITY, NearestTiesAway, -128, Greater);
 
     fn test_from_primitive_int<T: PrimitiveInt, U: PrimitiveFloat + RoundingFrom<T>>(
         n_in: T,
@@ -125,6 +151,8 @@ pub fn test_rounding_from() {
     test_from_primitive_int::<u8, f32>(0, Up, 0.0, Equal);
     test_from_primitive_int::<u8, f32>(0, Ceiling, 0.0, Equal);
     test_from_primitive_int::<u8, f32>(0, Nearest, 0.0, Equal);
+    test_from_primitive_int::<u8, f32>(0, NearestTiesAway, 0.0, Equal);
+    test_from_primitive_int::<u8, f32>(0, ToOdd, 0.0, Equal);
     test_from_primitive_int::<u8, f32>(0, Exact, 0.0, Equal);
 
     test_from_primitive_int::<u8, f32>(100, Down, 100.0, Equal);
@@ -132,6 +160,8 @@ pub fn test_rounding_from() {
     test_from_primitive_int::<u8, f32>(100, Up, 100.0, Equal);
     test_from_primitive_int::<u8, f32>(100, Ceiling, 100.0, Equal);
     test_from_primitive_int::<u8, f32>(100, Nearest, 100.0, Equal);
+    test_from_primitive_int::<u8, f32>(100, NearestTiesAway, 100.0, Equal);
+    test_from_primitive_int::<u8, f32>(100, ToOdd, 100.0, Equal);
     test_from_primitive_int::<u8, f32>(100, Exact, 100.0, Equal);
 
     test_from_primitive_int::<i8, f32>(-100, Down, -100.0, Equal);
@@ -139,6 +169,8 @@ pub fn test_rounding_from() {
     test_from_primitive_int::<i8, f32>(-100, Up, -100.0, Equal);
     test_from_primitive_int::<i8, f32>(-100, Ceiling, -100.0, Equal);
     test_from_primitive_int::<i8, f32>(-100, Nearest, -100.0, Equal);
+    test_from_primitive_int::<i8, f32>(-100, NearestTiesAway, -100.0, Equal);
+    test_from_primitive_int::<i8, f32>(-100, ToOdd, -100.0, Equal);
     test_from_primitive_int::<i8, f32>(-100, Exact, -100.0, Equal);
 
     test_from_primitive_int::<i32, f32>(i32::MIN, Down, -2147483600.0, Equal);
@@ -146,6 +178,8 @@ pub fn test_rounding_from() {
     test_from_primitive_int::<i32, f32>(i32::MIN, Up, -2147483600.0, Equal);
     test_from_primitive_int::<i32, f32>(i32::MIN, Ceiling, -2147483600.0, Equal);
     test_from_primitive_int::<i32, f32>(i32::MIN, Nearest, -2147483600.0, Equal);
+    test_from_primitive_int::<i32, f32>(i32::MIN, NearestTiesAway, -2147483600.0, Equal);
+    test_from_primitive_int::<i32, f32>(i32::MIN, ToOdd, -2147483600.0, Equal);
     test_from_primitive_int::<i32, f32>(i32::MIN, Exact, -2147483600.0, Equal);
 
     test_from_primitive_int::<i32, f32>(i32::MAX, Down, 2147483500.0, Less);
@@ -153,12 +187,16 @@ pub fn test_rounding_from() {
     test_from_primitive_int::<i32, f32>(i32::MAX, Up, 2147483600.0, Greater);
     test_from_primitive_int::<i32, f32>(i32::MAX, Ceiling, 2147483600.0, Greater);
     test_from_primitive_int::<i32, f32>(i32::MAX, Nearest, 2147483600.0, Greater);
+    test_from_primitive_int::<i32, f32>(i32::MAX, NearestTiesAway, 2147483600.0, Greater);
+    test_from_primitive_int::<i32, f32>(i32::MAX, ToOdd, 2147483500.0, Less);
 
     test_from_primitive_int::<u128, f32>(u128::MAX, Down, 3.4028235e38, Less);
     test_from_primitive_int::<u128, f32>(u128::MAX, Floor, 3.4028235e38, Less);
     test_from_primitive_int::<u128, f32>(u128::MAX, Up, f32::INFINITY, Greater);
     test_from_primitive_int::<u128, f32>(u128::MAX, Ceiling, f32::INFINITY, Greater);
     test_from_primitive_int::<u128, f32>(u128::MAX, Nearest, 3.4028235e38, Less);
+    test_from_primitive_int::<u128, f32>(u128::MAX, NearestTiesAway, 3.4028235e38, Less);
+    test_from_primitive_int::<u128, f32>(u128::MAX, ToOdd, 3.4028235e38, Less);
 }
 
 #[test]
@@ -171,6 +209,7 @@ fn exact_from_fail() {
     assert_panic!(u8::rounding_from(-100.0f32, Exact));
     assert_panic!(u8::rounding_from(-100.0f32, Up));
     assert_panic!(u8::rounding_from(-100.0f32, Floor));
+    assert_panic!(u8::rounding_from(-100.0f32, ToOdd));
 
     assert_panic!(i8::rounding_from(128.0f32, Exact));
     assert_panic!(i8::rounding_from(128.0f32, Up));
@@ -178,12 +217,15 @@ fn exact_from_fail() {
     assert_panic!(i8::rounding_from(-129.0f32, Exact));
     assert_panic!(i8::rounding_from(-129.0f32, Up));
     assert_panic!(i8::rounding_from(-129.0f32, Floor));
+    assert_panic!(i8::rounding_from(-129.0f32, ToOdd));
 
     assert_panic!(u8::rounding_from(f32::NAN, Down));
     assert_panic!(u8::rounding_from(f32::NAN, Floor));
     assert_panic!(u8::rounding_from(f32::NAN, Up));
     assert_panic!(u8::rounding_from(f32::NAN, Ceiling));
     assert_panic!(u8::rounding_from(f32::NAN, Nearest));
+    assert_panic!(u8::rounding_from(f32::NAN, NearestTiesAway));
+    assert_panic!(u8::rounding_from(f32::NAN, ToOdd));
     assert_panic!(u8::rounding_from(f32::NAN, Exact));
 
     assert_panic!(u8::rounding_from(f32::INFINITY, Up));
@@ -191,6 +233,7 @@ fn exact_from_fail() {
     assert_panic!(u8::rounding_from(f32::INFINITY, Exact));
     assert_panic!(u8::rounding_from(f32::NEGATIVE_INFINITY, Up));
     assert_panic!(u8::rounding_from(f32::NEGATIVE_INFINITY, Floor));
+    assert_panic!(u8::rounding_from(f32::NEGATIVE_INFINITY, ToOdd));
     assert_panic!(u8::rounding_from(f32::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(i8::rounding_from(f32::INFINITY, Up));
@@ -198,6 +241,7 @@ fn exact_from_fail() {
     assert_panic!(i8::rounding_from(f32::INFINITY, Exact));
     assert_panic!(i8::rounding_from(f32::NEGATIVE_INFINITY, Up));
     assert_panic!(i8::rounding_from(f32::NEGATIVE_INFINITY, Floor));
+    assert_panic!(i8::rounding_from(f32::NEGATIVE_INFINITY, ToOdd));
     assert_panic!(i8::rounding_from(f32::NEGATIVE_INFINITY, Exact));
 
     assert_panic!(u8::rounding_from(f32::NAN, Down));
@@ -242,6 +286,8 @@ where
         assert_eq!(no, T::rounding_from(f, Down));
         assert_eq!(no, T::rounding_from(f, Up));
         assert_eq!(no, T::rounding_from(f, Nearest));
+        assert_eq!(no, T::rounding_from(f, NearestTiesAway));
+        assert_eq!(no, T::rounding_from(f, ToOdd));
     });
 
     let f_max = U::rounding_from(T::MAX, Down).0;
@@ -265,6 +311,9 @@ where
         let ceiling = (floor.0 + T::ONE, Greater);
         let nearest = T::rounding_from(f, Nearest);
         assert_eq!(nearest, if floor.0.even() { floor } else { ceiling });
+        assert_eq!(T::rounding_from(f, NearestTiesAway), ceiling);
+        let to_odd = T::rounding_from(f, ToOdd);
+        assert_eq!(to_odd, if floor.0.odd() { floor } else { ceiling });
     });
 }
 
@@ -304,6 +353,8 @@ where
         assert_eq!(no, T::rounding_from(f, Down));
         assert_eq!(no, T::rounding_from(f, Up));
         assert_eq!(no, T::rounding_from(f, Nearest));
+        assert_eq!(no, T::rounding_from(f, NearestTiesAway));
+        assert_eq!(no, T::rounding_from(f, ToOdd));
     });
 
     let f_min = U::rounding_from(T::MIN, Down).0;
@@ -332,6 +383,13 @@ where
         let ceiling = (floor.0 + T::ONE, Greater);
         let nearest = T::rounding_from(f, Nearest);
         assert_eq!(nearest, if floor.0.even() { floor } else { ceiling });
+        let nearest_ties_away = T::rounding_from(f, NearestTiesAway);
+        assert_eq!(
+            nearest_ties_away,
+            if f >= U::ZERO { ceiling } else { floor }
+        );
+        let to_odd = T::rounding_from(f, ToOdd);
+        assert_eq!(to_odd, if floor.0.odd() { floor } else { ceiling });
     });
 }
 
@@ -374,6 +432,12 @@ fn rounding_from_helper_primitive_float_unsigned<
         let (f_alt, o_alt) = T::rounding_from(u, Nearest);
         assert_eq!(NiceFloat(f_alt), NiceFloat(f));
         assert_eq!(o_alt, o);
+        let (f_alt, o_alt) = T::rounding_from(u, NearestTiesAway);
+        assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+        assert_eq!(o_alt, o);
+        let (f_alt, o_alt) = T::rounding_from(u, ToOdd);
+        assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+        assert_eq!(o_alt, o);
         assert_eq!(U::rounding_from(f, Exact), (u, Equal));
     });
 
@@ -411,6 +475,17 @@ fn rounding_from_helper_primitive_float_unsigned<
                     (NiceFloat(ceiling), Greater)
                 }
             );
+            let (nearest_ties_away, o) = T::rounding_from(u, NearestTiesAway);
+            assert_eq!((NiceFloat(nearest_ties_away), o), (NiceFloat(ceiling), Greater));
+            let (to_odd, o) = T::rounding_from(u, ToOdd);
+            assert_eq!(
+                (NiceFloat(to_odd), o),
+                if floor.to_bits().odd() {
+                    (NiceFloat(floor), Less)
+                } else {
+                    (NiceFloat(ceiling), Greater)
+                }
+            );
         });
     }
 }
@@ -459,6 +534,12 @@ fn rounding_from_helper_primitive_float_signed<
         let (f_alt, o_alt) = T::rounding_from(i, Nearest);
         assert_eq!(NiceFloat(f_alt), NiceFloat(f));
         assert_eq!(o_alt, o);
+        let (f_alt, o_alt) = T::rounding_from(i, NearestTiesAway);
+        assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+        assert_eq!(o_alt, o);
+        let (f_alt, o_alt) = T::rounding_from(i, ToOdd);
+        assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+        assert_eq!(o_alt, o);
         assert_eq!(S::rounding_from(f, Exact), (i, Equal));
     });
 
@@ -505,6 +586,24 @@ fn rounding_from_helper_primitive_float_signed<
                     (NiceFloat(ceiling), Greater)
                 }
             );
+            let (nearest_ties_away, o) = T::rounding_from(i, NearestTiesAway);
+            assert_eq!(
+                (NiceFloat(nearest_ties_away), o),
+                if i >= S::ZERO {
+                    (NiceFloat(ceiling), Greater)
+                } else {
+                    (NiceFloat(floor), Less)
+                }
+            );
+            let (to_odd, o) = T::rounding_from(i, ToOdd);
+            assert_eq!(
+                (NiceFloat(to_odd), o),
+                if floor.to_bits().odd() {
+                    (NiceFloat(floor), Less)
+                } else {
+                    (NiceFloat(ceiling), Greater)
+                }
+            );
         });
     }
 }
@@ -119,6 +119,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1, Ceiling, Some((1.0, 0, Equal)));
     test::<u8, f32>(1, Up, Some((1.0, 0, Equal)));
     test::<u8, f32>(1, Nearest, Some((1.0, 0, Equal)));
+    test::<u8, f32>(1, NearestTiesAway, Some((1.0, 0, Equal)));
+    test::<u8, f32>(1, ToOdd, Some((1.0, 0, Equal)));
     test::<u8, f32>(1, Exact, Some((1.0, 0, Equal)));
 
     test::<u8, f32>(2, Floor, Some((1.0, 1, Equal)));
@@ -131,6 +133,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u16, f32>(u16::MAX, Ceiling, Some((1.9999695, 15, Equal)));
     test::<u16, f32>(u16::MAX, Up, Some((1.9999695, 15, Equal)));
     test::<u16, f32>(u16::MAX, Nearest, Some((1.9999695, 15, Equal)));
+    test::<u16, f32>(u16::MAX, NearestTiesAway, Some((1.9999695, 15, Equal)));
+    test::<u16, f32>(u16::MAX, ToOdd, Some((1.9999695, 15, Equal)));
     test::<u16, f32>(u16::MAX, Exact, Some((1.9999695, 15, Equal)));
 
     test::<u32, f32>(u32::MAX, Floor, Some((1.9999999, 31, Less)));
@@ -138,6 +142,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u32, f32>(u32::MAX, Ceiling, Some((1.0, 32, Greater)));
     test::<u32, f32>(u32::MAX, Up, Some((1.0, 32, Greater)));
     test::<u32, f32>(u32::MAX, Nearest, Some((1.0, 32, Greater)));
+    test::<u32, f32>(u32::MAX, NearestTiesAway, Some((1.0, 32, Greater)));
+    test::<u32, f32>(u32::MAX, ToOdd, Some((1.9999999, 31, Less)));
     test::<u32, f32>(u32::MAX, Exact, None);
 
     test::<u64, f32>(u64::MAX, Floor, Some((1.9999999, 63, Less)));
@@ -145,6 +151,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u64, f32>(u64::MAX, Ceiling, Some((1.0, 64, Greater)));
     test::<u64, f32>(u64::MAX, Up, Some((1.0, 64, Greater)));
     test::<u64, f32>(u64::MAX, Nearest, Some((1.0, 64, Greater)));
+    test::<u64, f32>(u64::MAX, NearestTiesAway, Some((1.0, 64, Greater)));
+    test::<u64, f32>(u64::MAX, ToOdd, Some((1.9999999, 63, Less)));
     test::<u64, f32>(u64::MAX, Exact, None);
 
     test::<u16, f64>(u16::MAX, Floor, Some((1.999969482421875, 15, Equal)));
@@ -152,6 +160,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u16, f64>(u16::MAX, Ceiling, Some((1.999969482421875, 15, Equal)));
     test::<u16, f64>(u16::MAX, Up, Some((1.999969482421875, 15, Equal)));
     test::<u16, f64>(u16::MAX, Nearest, Some((1.999969482421875, 15, Equal)));
+    test::<u16, f64>(u16::MAX, NearestTiesAway, Some((1.999969482421875, 15, Equal)));
+    test::<u16, f64>(u16::MAX, ToOdd, Some((1.999969482421875, 15, Equal)));
     test::<u16, f64>(u16::MAX, Exact, Some((1.999969482421875, 15, Equal)));
 
     test::<u32, f64>(u32::MAX, Floor, Some((1.9999999995343387, 31, Equal)));
@@ -159,6 +169,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u32, f64>(u32::MAX, Ceiling, Some((1.9999999995343387, 31, Equal)));
     test::<u32, f64>(u32::MAX, Up, Some((1.9999999995343387, 31, Equal)));
     test::<u32, f64>(u32::MAX, Nearest, Some((1.9999999995343387, 31, Equal)));
+    test::<u32, f64>(u32::MAX, NearestTiesAway, Some((1.9999999995343387, 31, Equal)));
+    test::<u32, f64>(u32::MAX, ToOdd, Some((1.9999999995343387, 31, Equal)));
     test::<u32, f64>(u32::MAX, Exact, Some((1.9999999995343387, 31, Equal)));
 
     test::<u64, f64>(u64::MAX, Floor, Some((1.9999999999999998, 63, Less)));
@@ -166,6 +178,8 @@ pub fn test_sci_mantissa_and_exponent_round() {
     test::<u64, f64>(u64::MAX, Ceiling, Some((1.0, 64, Greater)));
     test::<u64, f64>(u64::MAX, Up, Some((1.0, 64, Greater)));
     test::<u64, f64>(u64::MAX, Nearest, Some((1.0, 64, Greater)));
+    test::<u64, f64>(u64::MAX, NearestTiesAway, Some((1.0, 64, Greater)));
+    test::<u64, f64>(u64::MAX, ToOdd, Some((1.9999999999999998, 63, Less)));
     test::<u64, f64>(u64::MAX, Exact, None);
 }
 
@@ -249,6 +263,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.0, 0, Ceiling, Some((1, Equal)));
     test::<u8, f32>(1.0, 0, Up, Some((1, Equal)));
     test::<u8, f32>(1.0, 0, Nearest, Some((1, Equal)));
+    test::<u8, f32>(1.0, 0, NearestTiesAway, Some((1, Equal)));
+    test::<u8, f32>(1.0, 0, ToOdd, Some((1, Equal)));
     test::<u8, f32>(1.0, 0, Exact, Some((1, Equal)));
 
     test::<u8, f32>(1.25, 0, Floor, Some((1, Less)));
@@ -256,6 +272,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.25, 0, Ceiling, Some((2, Greater)));
     test::<u8, f32>(1.25, 0, Up, Some((2, Greater)));
     test::<u8, f32>(1.25, 0, Nearest, Some((1, Less)));
+    test::<u8, f32>(1.25, 0, NearestTiesAway, Some((1, Less)));
+    test::<u8, f32>(1.25, 0, ToOdd, Some((1, Less)));
     test::<u8, f32>(1.25, 0, Exact, None);
 
     test::<u8, f32>(1.5, 0, Floor, Some((1, Less)));
@@ -263,6 +281,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.5, 0, Ceiling, Some((2, Greater)));
     test::<u8, f32>(1.5, 0, Up, Some((2, Greater)));
     test::<u8, f32>(1.5, 0, Nearest, Some((2, Greater)));
+    test::<u8, f32>(1.5, 0, NearestTiesAway, Some((2, Greater)));
+    test::<u8, f32>(1.5, 0, ToOdd, Some((1, Less)));
     test::<u8, f32>(1.5, 0, Exact, None);
 
     test::<u8, f32>(1.75, 0, Floor, Some((1, Less)));
@@ -270,6 +290,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.75, 0, Ceiling, Some((2, Greater)));
     test::<u8, f32>(1.75, 0, Up, Some((2, Greater)));
     test::<u8, f32>(1.75, 0, Nearest, Some((2, Greater)));
+    test::<u8, f32>(1.75, 0, NearestTiesAway, Some((2, Greater)));
+    test::<u8, f32>(1.75, 0, ToOdd, Some((1, Less)));
     test::<u8, f32>(1.75, 0, Exact, None);
 
     test::<u8, f32>(1.5, 1, Floor, Some((3, Equal)));
@@ -277,6 +299,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.5, 1, Ceiling, Some((3, Equal)));
     test::<u8, f32>(1.5, 1, Up, Some((3, Equal)));
     test::<u8, f32>(1.5, 1, Nearest, Some((3, Equal)));
+    test::<u8, f32>(1.5, 1, NearestTiesAway, Some((3, Equal)));
+    test::<u8, f32>(1.5, 1, ToOdd, Some((3, Equal)));
     test::<u8, f32>(1.5, 1, Exact, Some((3, Equal)));
 
     test::<u8, f32>(1.0, 100, Floor, None);
@@ -284,6 +308,8 @@ pub fn test_from_sci_mantissa_and_exponent_round() {
     test::<u8, f32>(1.0, 100, Ceiling, None);
     test::<u8, f32>(1.0, 100, Up, None);
     test::<u8, f32>(1.0, 100, Nearest, None);
+    test::<u8, f32>(1.0, 100, NearestTiesAway, None);
+    test::<u8, f32>(1.0, 100, ToOdd, None);
     test::<u8, f32>(1.0, 100, Exact, None);
 }
 
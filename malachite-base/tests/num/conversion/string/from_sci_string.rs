@@ -361,6 +361,10 @@ pub fn test_from_sci_string_with_options() {
     test::<u8>("123.4", options, Some(124));
     options.set_rounding_mode(Nearest);
     test::<u8>("123.4", options, Some(123));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<u8>("123.4", options, Some(123));
+    options.set_rounding_mode(ToOdd);
+    test::<u8>("123.4", options, Some(123));
     options.set_rounding_mode(Exact);
     test::<u8>("123.4", options, None);
 
@@ -374,6 +378,10 @@ pub fn test_from_sci_string_with_options() {
     test::<u8>("123.5", options, Some(124));
     options.set_rounding_mode(Nearest);
     test::<u8>("123.5", options, Some(124));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<u8>("123.5", options, Some(124));
+    options.set_rounding_mode(ToOdd);
+    test::<u8>("123.5", options, Some(123));
     options.set_rounding_mode(Exact);
     test::<u8>("123.5", options, None);
 
@@ -387,6 +395,10 @@ pub fn test_from_sci_string_with_options() {
     test::<u8>("0.4", options, Some(1));
     options.set_rounding_mode(Nearest);
     test::<u8>("0.4", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<u8>("0.4", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<u8>("0.4", options, Some(1));
     options.set_rounding_mode(Exact);
     test::<u8>("0.4", options, None);
 
@@ -400,6 +412,10 @@ pub fn test_from_sci_string_with_options() {
     test::<u8>("0.04", options, Some(1));
     options.set_rounding_mode(Nearest);
     test::<u8>("0.04", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<u8>("0.04", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<u8>("0.04", options, Some(1));
     options.set_rounding_mode(Exact);
     test::<u8>("0.04", options, None);
 
@@ -582,6 +598,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("123.4", options, Some(124));
     options.set_rounding_mode(Nearest);
     test::<i8>("123.4", options, Some(123));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("123.4", options, Some(123));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("123.4", options, Some(123));
     options.set_rounding_mode(Exact);
     test::<i8>("123.4", options, None);
 
@@ -595,6 +615,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-123.4", options, Some(-123));
     options.set_rounding_mode(Nearest);
     test::<i8>("-123.4", options, Some(-123));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("-123.4", options, Some(-123));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("-123.4", options, Some(-123));
     options.set_rounding_mode(Exact);
     test::<i8>("-123.4", options, None);
 
@@ -608,6 +632,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("123.5", options, Some(124));
     options.set_rounding_mode(Nearest);
     test::<i8>("123.5", options, Some(124));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("123.5", options, Some(124));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("123.5", options, Some(123));
     options.set_rounding_mode(Exact);
     test::<i8>("123.5", options, None);
 
@@ -621,6 +649,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-123.5", options, Some(-123));
     options.set_rounding_mode(Nearest);
     test::<i8>("-123.5", options, Some(-124));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("-123.5", options, Some(-124));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("-123.5", options, Some(-123));
     options.set_rounding_mode(Exact);
     test::<i8>("-123.5", options, None);
 
@@ -634,6 +666,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("0.4", options, Some(1));
     options.set_rounding_mode(Nearest);
     test::<i8>("0.4", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("0.4", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("0.4", options, Some(1));
     options.set_rounding_mode(Exact);
     test::<i8>("0.4", options, None);
 
@@ -647,6 +683,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-0.4", options, Some(0));
     options.set_rounding_mode(Nearest);
     test::<i8>("-0.4", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("-0.4", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("-0.4", options, Some(-1));
     options.set_rounding_mode(Exact);
     test::<i8>("-0.4", options, None);
 
@@ -660,6 +700,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("0.04", options, Some(1));
     options.set_rounding_mode(Nearest);
     test::<i8>("0.04", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("0.04", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("0.04", options, Some(1));
     options.set_rounding_mode(Exact);
     test::<i8>("0.04", options, None);
 
@@ -673,6 +717,10 @@ pub fn test_from_sci_string_with_options() {
     test::<i8>("-0.04", options, Some(0));
     options.set_rounding_mode(Nearest);
     test::<i8>("-0.04", options, Some(0));
+    options.set_rounding_mode(NearestTiesAway);
+    test::<i8>("-0.04", options, Some(0));
+    options.set_rounding_mode(ToOdd);
+    test::<i8>("-0.04", options, Some(-1));
     options.set_rounding_mode(Exact);
     test::<i8>("-0.04", options, None);
 
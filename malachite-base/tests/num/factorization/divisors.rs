@@ -0,0 +1,86 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Divisors;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_11};
+use malachite_base::test_util::num::factorization::divisors::divisors_naive;
+use std::panic::catch_unwind;
+
+fn test_divisors_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[u8]| {
+        let n = T::from(n);
+        let out = out.iter().map(|&d| T::from(d)).collect::<Vec<_>>();
+        assert_eq!(n.divisors(), out);
+        assert_eq!(divisors_naive(n), out);
+    };
+    test(1, &[1]);
+    test(2, &[1, 2]);
+    test(12, &[1, 2, 3, 4, 6, 12]);
+    test(64, &[1, 2, 4, 8, 16, 32, 64]);
+    test(97, &[1, 97]);
+    test(
+        240,
+        &[1, 2, 3, 4, 5, 6, 8, 10, 12, 15, 16, 20, 24, 30, 40, 48, 60, 80, 120, 240],
+    );
+}
+
+#[test]
+fn test_divisors() {
+    apply_fn_to_unsigneds!(test_divisors_helper);
+
+    let test = |n: u64, out: &[u64]| {
+        assert_eq!(n.divisors(), out);
+    };
+    test(
+        1000,
+        &[1, 2, 4, 5, 8, 10, 20, 25, 40, 50, 100, 125, 200, 250, 500, 1000],
+    );
+    test(4294967297, &[1, 641, 6700417, 4294967297]);
+    test(2305843009213693951, &[1, 2305843009213693951]);
+    assert_eq!(u64::MAX.divisors().len(), 128);
+    assert_eq!(u128::MAX.divisors().len(), 512);
+}
+
+fn divisors_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisors());
+}
+
+#[test]
+fn divisors_fail() {
+    apply_fn_to_unsigneds!(divisors_fail_helper);
+}
+
+fn divisors_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let divisors = n.divisors();
+        assert!(divisors.windows(2).all(|w| w[0] < w[1]));
+        assert!(divisors.iter().all(|&d| n.divisible_by(d)));
+        assert_eq!(divisors[0], T::ONE);
+        assert_eq!(*divisors.last().unwrap(), n);
+        assert_eq!(
+            u64::exact_from(divisors.len()),
+            n.factor().iter().map(|&(_, e)| e + 1).product::<u64>()
+        );
+    });
+
+    unsigned_gen_var_11::<T>().test_properties(|n| {
+        assert_eq!(n.divisors(), divisors_naive(n));
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    // See the comment in `factor_properties`.
+    divisors_properties_helper::<u8>();
+    divisors_properties_helper::<u16>();
+    divisors_properties_helper::<u32>();
+    divisors_properties_helper::<u64>();
+    divisors_properties_helper::<usize>();
+}
@@ -0,0 +1,98 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Gcd;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::EulerTotient;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_11, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::factorization::euler_totient::euler_totient_naive;
+use std::panic::catch_unwind;
+
+fn test_euler_totient_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        let n = T::from(n);
+        let out = T::from(out);
+        assert_eq!(n.euler_totient(), out);
+        assert_eq!(euler_totient_naive(n), out);
+    };
+    test(1, 1);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(12, 4);
+    test(97, 96);
+    test(128, 64);
+    test(210, 48);
+    test(255, 128);
+}
+
+#[test]
+fn test_euler_totient() {
+    apply_fn_to_unsigneds!(test_euler_totient_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.euler_totient(), out);
+        assert_eq!(u128::from(n).euler_totient(), u128::from(out));
+    };
+    test(360, 96);
+    test(4294967295, 2147483648);
+    test(1000000000000000000, 400000000000000000);
+    test(2305843009213693951, 2305843009213693950);
+    test(u64::MAX, 9208981628670443520);
+
+    assert_eq!(
+        u128::MAX.euler_totient(),
+        169875107699410294159549716941399654400
+    );
+}
+
+fn euler_totient_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.euler_totient());
+}
+
+#[test]
+fn euler_totient_fail() {
+    apply_fn_to_unsigneds!(euler_totient_fail_helper);
+}
+
+fn euler_totient_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let totient = n.euler_totient();
+        assert_ne!(totient, T::ZERO);
+        assert!(totient <= n);
+        if n > T::TWO {
+            assert!(totient.even());
+        }
+        assert_eq!(totient == n - T::ONE, n.is_prime());
+    });
+
+    unsigned_gen_var_11::<T>().test_properties(|n| {
+        assert_eq!(n.euler_totient(), euler_totient_naive(n));
+    });
+}
+
+#[test]
+fn euler_totient_properties() {
+    // See the comment in `factor_properties`.
+    euler_totient_properties_helper::<u8>();
+    euler_totient_properties_helper::<u16>();
+    euler_totient_properties_helper::<u32>();
+    euler_totient_properties_helper::<u64>();
+    euler_totient_properties_helper::<usize>();
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.gcd(y) == 1 {
+            assert_eq!(
+                (u64::from(x) * u64::from(y)).euler_totient(),
+                u64::from(x.euler_totient()) * u64::from(y.euler_totient())
+            );
+        }
+    });
+}
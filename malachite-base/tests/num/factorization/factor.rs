@@ -0,0 +1,161 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_11, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::factorization::factor::factor_naive;
+use std::panic::catch_unwind;
+
+fn test_factor_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[(u8, u64)]| {
+        let n = T::from(n);
+        let out = out
+            .iter()
+            .map(|&(p, e)| (T::from(p), e))
+            .collect::<Vec<_>>();
+        assert_eq!(n.factor(), out);
+        assert_eq!(factor_naive(n), out);
+    };
+    test(1, &[]);
+    test(2, &[(2, 1)]);
+    test(3, &[(3, 1)]);
+    test(4, &[(2, 2)]);
+    test(12, &[(2, 2), (3, 1)]);
+    test(97, &[(97, 1)]);
+    test(128, &[(2, 7)]);
+    test(210, &[(2, 1), (3, 1), (5, 1), (7, 1)]);
+    test(255, &[(3, 1), (5, 1), (17, 1)]);
+}
+
+#[test]
+fn test_factor() {
+    apply_fn_to_unsigneds!(test_factor_helper);
+
+    let test = |n: u64, out: &[(u64, u64)]| {
+        assert_eq!(n.factor(), out);
+        assert_eq!(
+            u128::from(n).factor(),
+            out.iter()
+                .map(|&(p, e)| (u128::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    };
+    test(65536, &[(2, 16)]);
+    test(1062961, &[(1031, 2)]);
+    test(4294967295, &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]);
+    test(4294967297, &[(641, 1), (6700417, 1)]);
+    test(600851475143, &[(71, 1), (839, 1), (1471, 1), (6857, 1)]);
+    test(1000000000000000000, &[(2, 18), (5, 18)]);
+    test(2305843009213693951, &[(2305843009213693951, 1)]);
+    test(18446743979220271189, &[(4294967279, 1), (4294967291, 1)]);
+    test(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+
+    let test = |n: u128, out: &[(u128, u64)]| {
+        assert_eq!(n.factor(), out);
+    };
+    test(
+        5316911983139663487003542222693990401,
+        &[(2305843009213693951, 2)],
+    );
+    test(
+        1203277717625069775441182589171,
+        &[(3, 5), (2147483647, 1), (2305843009213693951, 1)],
+    );
+    test(
+        170141183460469231731687303715884105727,
+        &[(170141183460469231731687303715884105727, 1)],
+    );
+    test(
+        u128::MAX,
+        &[
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1),
+        ],
+    );
+}
+
+fn factor_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.factor());
+}
+
+#[test]
+fn factor_fail() {
+    apply_fn_to_unsigneds!(factor_fail_helper);
+}
+
+fn factor_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let factors = n.factor();
+        let mut product = T::ONE;
+        for &(p, e) in &factors {
+            assert!(p.is_prime());
+            assert_ne!(e, 0);
+            product *= p.pow(e);
+        }
+        assert_eq!(product, n);
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+    });
+
+    unsigned_gen_var_11::<T>().test_properties(|n| {
+        assert_eq!(n.factor(), factor_naive(n));
+    });
+}
+
+#[test]
+fn factor_properties() {
+    // Random `u128`s may have two large prime factors, which Pollard's rho algorithm would take
+    // too long to find, so `u128`s are only tested with the products below.
+    factor_properties_helper::<u8>();
+    factor_properties_helper::<u16>();
+    factor_properties_helper::<u32>();
+    factor_properties_helper::<u64>();
+    factor_properties_helper::<usize>();
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x == 0 || y == 0 {
+            return;
+        }
+        let n = u64::from(x) * u64::from(y);
+        let factors = n.factor();
+        let mut union = x
+            .factor()
+            .into_iter()
+            .chain(y.factor())
+            .map(|(p, e)| (u64::from(p), e))
+            .collect::<Vec<_>>();
+        union.sort_unstable();
+        let mut grouped: Vec<(u64, u64)> = Vec::new();
+        for (p, e) in union {
+            match grouped.last_mut() {
+                Some((q, f)) if *q == p => *f += e,
+                _ => grouped.push((p, e)),
+            }
+        }
+        assert_eq!(factors, grouped);
+        assert_eq!(
+            u128::from(n).factor(),
+            factors
+                .iter()
+                .map(|&(p, e)| (u128::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    });
+}
@@ -0,0 +1,167 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primality, Primes};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_gen_var_5, unsigned_pair_gen_var_27,
+};
+use malachite_base::test_util::num::factorization::is_prime::is_prime_naive;
+
+fn test_is_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out| {
+        let n = T::from(n);
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(is_prime_naive(n), out);
+    };
+    test(0, false);
+    test(1, false);
+    test(2, true);
+    test(3, true);
+    test(4, false);
+    test(5, true);
+    test(9, false);
+    test(25, false);
+    test(91, false);
+    test(97, true);
+    test(251, true);
+    test(255, false);
+}
+
+#[test]
+fn test_is_prime() {
+    apply_fn_to_unsigneds!(test_is_prime_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(u128::from(n).is_prime(), out);
+    };
+    // strong pseudoprimes to base 2
+    test(2047, false);
+    test(3277, false);
+    test(4033, false);
+    // Carmichael numbers
+    test(561, false);
+    test(1105, false);
+    test(41041, false);
+    test(4489, false);
+    test(4493, true);
+    // a strong pseudoprime to bases 2, 3, 5, and 7
+    test(3215031751, false);
+    test(4294967291, true);
+    test(4294967297, false);
+    test(4759123141, false);
+    test(4759123153, true);
+    test(2305843009213693951, true);
+    // a strong pseudoprime to the first nine prime bases
+    test(3825123056546413051, false);
+    test(18446744073709551557, true);
+    test(u64::MAX, false);
+
+    let test = |n: u128, out| {
+        assert_eq!(n.is_prime(), out);
+    };
+    test(18446744073709551616, false);
+    test(18446744073709551617, false);
+    test(18446744073709551629, true);
+    // strong pseudoprimes to the first eleven and twelve prime bases
+    test(318665857834031151167461, false);
+    test(3317044064679887385961981, false);
+    // the square of a prime
+    test(5316911983139663487003542222693990401, false);
+    test(618970019642690137449562111, true);
+    test(170141183460469231731687303715884105727, true);
+    test(340282366920938463463374607431768211297, true);
+    test(u128::MAX, false);
+}
+
+#[test]
+fn test_is_probable_prime() {
+    let test = |n: u64, rounds, out| {
+        assert_eq!(n.is_probable_prime(rounds), out);
+    };
+    test(0, 0, Primality::NotPrime);
+    test(1, 10, Primality::NotPrime);
+    test(2, 0, Primality::Prime);
+    test(3215031751, 0, Primality::NotPrime);
+    test(18446744073709551557, 0, Primality::Prime);
+    test(18446744073709551557, 10, Primality::Prime);
+
+    let test = |n: u128, rounds, out| {
+        assert_eq!(n.is_probable_prime(rounds), out);
+    };
+    test(97, 0, Primality::Prime);
+    test(18446744073709551557, 10, Primality::Prime);
+    test(18446744073709551629, 0, Primality::ProbablyPrime);
+    test(18446744073709551629, 10, Primality::ProbablyPrime);
+    test(318665857834031151167461, 0, Primality::NotPrime);
+    test(318665857834031151167461, 20, Primality::NotPrime);
+    test(
+        170141183460469231731687303715884105727,
+        100,
+        Primality::ProbablyPrime,
+    );
+    test(u128::MAX, 10, Primality::NotPrime);
+}
+
+#[test]
+fn is_prime_matches_primes() {
+    let mut primes = u16::primes().peekable();
+    for n in 0..=u16::MAX {
+        let is_prime = primes.peek() == Some(&n);
+        if is_prime {
+            primes.next();
+        }
+        assert_eq!(n.is_prime(), is_prime);
+        assert_eq!(u32::from(n).is_prime(), is_prime);
+    }
+    for p in u64::primes().take(10000) {
+        assert!(p.is_prime());
+    }
+}
+
+fn is_prime_properties_helper<T: PrimitiveUnsigned>()
+where
+    u128: WrappingFrom<T>,
+{
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(0) != Primality::NotPrime, is_prime);
+        assert_eq!(n.is_probable_prime(10) != Primality::NotPrime, is_prime);
+        if n > T::TWO && is_prime {
+            assert!(n.odd());
+        }
+        if T::WIDTH <= u64::WIDTH {
+            assert_ne!(n.is_probable_prime(0), Primality::ProbablyPrime);
+            assert_eq!(u128::wrapping_from(n).is_prime(), is_prime);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        assert_eq!(n.is_prime(), is_prime_naive(n));
+    });
+}
+
+#[test]
+fn is_prime_properties() {
+    apply_fn_to_unsigneds!(is_prime_properties_helper);
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x > 1 && y > 1 {
+            assert!(!(u64::from(x) * u64::from(y)).is_prime());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u64>().test_properties(|(x, y)| {
+        if x > 1 && y > 1 {
+            assert!(!(u128::from(x) * u128::from(y)).is_prime());
+        }
+    });
+}
@@ -0,0 +1,72 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::IsSquarefree;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+
+fn test_is_squarefree_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out| {
+        assert_eq!(T::from(n).is_squarefree(), out);
+    };
+    test(0, false);
+    test(1, true);
+    test(2, true);
+    test(4, false);
+    test(6, true);
+    test(12, false);
+    test(30, true);
+    test(97, true);
+    test(250, false);
+    test(255, true);
+}
+
+#[test]
+fn test_is_squarefree() {
+    apply_fn_to_unsigneds!(test_is_squarefree_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.is_squarefree(), out);
+        assert_eq!(u128::from(n).is_squarefree(), out);
+    };
+    test(4294967295, true);
+    test(1000000000000000000, false);
+    test(18446743979220271189, true);
+    test(u64::MAX, true);
+
+    assert!(u128::MAX.is_squarefree());
+    assert!(!5316911983139663487003542222693990401u128.is_squarefree());
+}
+
+fn is_squarefree_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_squarefree = n.is_squarefree();
+        if n != T::ZERO {
+            assert_eq!(is_squarefree, n.factor().iter().all(|&(_, e)| e == 1));
+        }
+        if n.is_prime() {
+            assert!(is_squarefree);
+        }
+    });
+
+    unsigned_gen_var_11::<T>().test_properties(|n| {
+        if let Some(square) = n.checked_square() {
+            assert_eq!(square.is_squarefree(), n == T::ONE);
+        }
+    });
+}
+
+#[test]
+fn is_squarefree_properties() {
+    // See the comment in `factor_properties`.
+    is_squarefree_properties_helper::<u8>();
+    is_squarefree_properties_helper::<u16>();
+    is_squarefree_properties_helper::<u32>();
+    is_squarefree_properties_helper::<u64>();
+    is_squarefree_properties_helper::<usize>();
+}
@@ -0,0 +1,87 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, Parity};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Moebius;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use std::panic::catch_unwind;
+
+fn test_moebius_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out| {
+        assert_eq!(T::from(n).moebius(), out);
+    };
+    test(1, 1);
+    test(2, -1);
+    test(4, 0);
+    test(6, 1);
+    test(12, 0);
+    test(30, -1);
+    test(97, -1);
+    test(210, 1);
+    test(255, -1);
+}
+
+#[test]
+fn test_moebius() {
+    apply_fn_to_unsigneds!(test_moebius_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.moebius(), out);
+        assert_eq!(u128::from(n).moebius(), out);
+    };
+    test(360, 0);
+    test(4294967295, -1);
+    test(4294967297, 1);
+    test(1000000000000000000, 0);
+    test(u64::MAX, -1);
+
+    assert_eq!(u128::MAX.moebius(), -1);
+    assert_eq!(5316911983139663487003542222693990401u128.moebius(), 0);
+}
+
+fn moebius_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.moebius());
+}
+
+#[test]
+fn moebius_fail() {
+    apply_fn_to_unsigneds!(moebius_fail_helper);
+}
+
+fn moebius_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let moebius = n.moebius();
+        assert_eq!(moebius != 0, n.is_squarefree());
+        if moebius != 0 {
+            assert_eq!(moebius, if n.factor().len().even() { 1 } else { -1 });
+        }
+        if n.is_prime() {
+            assert_eq!(moebius, -1);
+        }
+    });
+}
+
+#[test]
+fn moebius_properties() {
+    // See the comment in `factor_properties`.
+    moebius_properties_helper::<u8>();
+    moebius_properties_helper::<u16>();
+    moebius_properties_helper::<u32>();
+    moebius_properties_helper::<u64>();
+    moebius_properties_helper::<usize>();
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.gcd(y) == 1 {
+            assert_eq!(
+                (u64::from(x) * u64::from(y)).moebius(),
+                x.moebius() * y.moebius()
+            );
+        }
+    });
+}
@@ -0,0 +1,87 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::PrimePi;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+use malachite_base::test_util::num::factorization::prime_pi::prime_pi_naive;
+
+fn test_prime_pi_helper<T: PrimePi + PrimitiveUnsigned>() {
+    let test = |n: u8, out: u64| {
+        let n = T::from(n);
+        assert_eq!(n.prime_pi(), out);
+        assert_eq!(prime_pi_naive(n), out);
+    };
+    test(0, 0);
+    test(1, 0);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(10, 4);
+    test(97, 25);
+    test(100, 25);
+    test(255, 54);
+}
+
+#[test]
+fn test_prime_pi() {
+    test_prime_pi_helper::<u8>();
+    test_prime_pi_helper::<u16>();
+    test_prime_pi_helper::<u32>();
+    test_prime_pi_helper::<u64>();
+    test_prime_pi_helper::<usize>();
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.prime_pi(), out);
+    };
+    test(1000, 168);
+    test(10000, 1229);
+    test(65535, 6542);
+    test(100000, 9592);
+    test(1000000, 78498);
+    test(4194303, 295947);
+    test(4194304, 295947);
+    test(4194305, 295947);
+    test(4194319, 295948);
+    test(10000000, 664579);
+    test(100000000, 5761455);
+    test(1000000000, 50847534);
+    test(4294967295, 203280221);
+    test(10000000000, 455052511);
+    test(100000000000, 4118054813);
+    test(1000000000000, 37607912018);
+
+    // Numbers just above the sieve limit, for which the Lagarias-Miller-Odlyzko algorithm is used
+    for n in [4194305u64, 5000000, 8388608, 12345678] {
+        assert_eq!(n.prime_pi(), prime_pi_naive(n));
+    }
+
+    assert_eq!(u16::MAX.prime_pi(), 6542);
+    assert_eq!(u32::MAX.prime_pi(), 203280221);
+}
+
+fn prime_pi_properties_helper<T: PrimePi + PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        if n != T::ZERO {
+            assert_eq!(
+                n.prime_pi(),
+                (n - T::ONE).prime_pi() + u64::from(n.is_prime())
+            );
+        }
+    });
+
+    unsigned_gen_var_11::<T>().test_properties(|n| {
+        assert_eq!(n.prime_pi(), prime_pi_naive(n));
+    });
+}
+
+#[test]
+fn prime_pi_properties() {
+    prime_pi_properties_helper::<u8>();
+    prime_pi_properties_helper::<u16>();
+}
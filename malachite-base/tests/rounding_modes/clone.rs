@@ -21,6 +21,8 @@ fn test_clone() {
     test(Floor);
     test(Ceiling);
     test(Nearest);
+    test(NearestTiesAway);
+    test(ToOdd);
     test(Exact);
 }
 
@@ -15,7 +15,16 @@ use std::cmp::Ordering::*;
 
 #[test]
 fn test_cmp() {
-    test_cmp_helper::<RoundingMode>(&["Down", "Up", "Floor", "Ceiling", "Nearest", "Exact"]);
+    test_cmp_helper::<RoundingMode>(&[
+        "Down",
+        "Up",
+        "Floor",
+        "Ceiling",
+        "Nearest",
+        "NearestTiesAway",
+        "ToOdd",
+        "Exact",
+    ]);
 }
 
 #[test]
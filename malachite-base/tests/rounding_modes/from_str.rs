@@ -21,6 +21,8 @@ fn test_from_str() {
     test("Floor", Ok(Floor));
     test("Ceiling", Ok(Ceiling));
     test("Nearest", Ok(Nearest));
+    test("NearestTiesAway", Ok(NearestTiesAway));
+    test("ToOdd", Ok(ToOdd));
     test("Exact", Ok(Exact));
 
     test("", Err("".to_string()));
@@ -22,6 +22,8 @@ fn test_to_string() {
     test(Floor, "Floor");
     test(Ceiling, "Ceiling");
     test(Nearest, "Nearest");
+    test(NearestTiesAway, "NearestTiesAway");
+    test(ToOdd, "ToOdd");
     test(Exact, "Exact");
 }
 
@@ -12,6 +12,7 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::arithmetic::round_to_odd::{round_via_odd, round_via_odd_assign};
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{
     float_either_zero, float_infinity, float_nan, float_negative_infinity, float_negative_zero,
@@ -49,6 +50,12 @@ impl Float {
         subtract: bool,
     ) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.add_prec_round_assign_helper(other, prec, Down, subtract)
+            });
+        }
         match (&mut *self, other, subtract) {
             (float_nan!(), _, _)
             | (_, float_nan!(), _)
@@ -165,6 +172,12 @@ impl Float {
         subtract: bool,
     ) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.add_prec_round_assign_ref_helper(other, prec, Down, subtract)
+            });
+        }
         match (&mut *self, other, subtract) {
             (x @ float_nan!(), _, _)
             | (x, float_nan!(), _)
@@ -280,6 +293,12 @@ impl Float {
         subtract: bool,
     ) -> (Float, Ordering) {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| {
+                self.add_prec_round_ref_ref_helper(other, prec, Down, subtract)
+            });
+        }
         match (self, other, subtract) {
             (float_nan!(), _, _)
             | (_, float_nan!(), _)
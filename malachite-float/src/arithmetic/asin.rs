@@ -0,0 +1,1211 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::atan::{atan_enclosure, half_pi_with_sign, pi_with_sign};
+use crate::arithmetic::enclosure::{round_enclosed, round_near, Enclosure};
+use crate::arithmetic::sin_cos::{is_tiny, toward_zero};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, float_zero, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// The arcsine of a finite `Float` $x$ with $0 < |x| < 1$, computed as
+// $$
+// \arcsin x = 2\arctan\frac{x}{1+\sqrt{(1-x)(1+x)}}.
+// $$
+// If $x$ is tiny, $\arcsin x = x + x^3/6 + O(x^5)$ lies strictly between $x$ and its neighbor in
+// the direction away from zero.
+fn asin_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if is_tiny(x, prec) {
+        return round_near(x, toward_zero(x).reverse(), prec, rm);
+    }
+    let one = Enclosure::exact(Float::ONE);
+    let x = Enclosure::exact(x.clone());
+    round_enclosed(prec, rm, |working_prec| {
+        let root = one
+            .sub(&x, working_prec)
+            .mul(&one.add(&x, working_prec), working_prec)
+            .sqrt(working_prec);
+        let t = x.div(&one.add(&root, working_prec), working_prec)?;
+        Some(atan_enclosure(&t, working_prec)?.shl(1))
+    })
+}
+
+// The arccosine of a finite `Float` $x$ with $0 < |x| < 1$, computed as
+// $$
+// \arccos x = 2\arctan\sqrt{\frac{1-x}{1+x}}.
+// $$
+fn acos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let one = Enclosure::exact(Float::ONE);
+    let x = Enclosure::exact(x.clone());
+    round_enclosed(prec, rm, |working_prec| {
+        let t = one
+            .sub(&x, working_prec)
+            .div(&one.add(&x, working_prec), working_prec)?
+            .sqrt(working_prec);
+        Some(atan_enclosure(&t, working_prec)?.shl(1))
+    })
+}
+
+impl Float {
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\pi/2$
+    /// - $f(-1.0,p,m)=-\pi/2$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is nonzero and its absolute value
+    /// is at most 1, since the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.5236");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\pi/2$
+    /// - $f(-1.0,p,m)=-\pi/2$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is nonzero and its absolute value
+    /// is at most 1, since the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Floor);
+    /// assert_eq!(asin.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(5, Nearest);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Floor);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Ceiling);
+    /// assert_eq!(asin.to_string(), "0.5236");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_round_ref(20, Nearest);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn asin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            x => match x.partial_cmp_abs(&1u32).unwrap() {
+                Less => asin_finite(x, prec, rm),
+                Equal => half_pi_with_sign(*x > 0u32, prec, rm),
+                Greater => (float_nan!(), Equal),
+            },
+        }
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec(5);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec(20);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_ref(5);
+    /// assert_eq!(asin.to_string(), "0.53");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_prec_ref(20);
+    /// assert_eq!(asin.to_string(), "0.523599");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn asin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is nonzero and its absolute value is at most 1, since
+    /// the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round(Floor);
+    /// assert_eq!(asin.to_string(), "0.5235987755982988");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.5235987755982989");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round(Nearest);
+    /// assert_eq!(asin.to_string(), "0.5235987755982989");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arcsine is less than, equal to, or greater than the exact arcsine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is nonzero and its absolute value is at most 1, since
+    /// the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round_ref(Floor);
+    /// assert_eq!(asin.to_string(), "0.5235987755982988");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round_ref(Ceiling);
+    /// assert_eq!(asin.to_string(), "0.5235987755982989");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (asin, o) = Float::from(0.5).asin_round_ref(Nearest);
+    /// assert_eq!(asin.to_string(), "0.5235987755982989");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.asin_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is nonzero and its absolute value
+    /// is at most 1, since the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.53");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.53");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.523599");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.5236");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.523599");
+    /// ```
+    #[inline]
+    pub fn asin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.asin_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded arcsine
+    /// is less than, equal to, or greater than the exact arcsine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "0.53");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "0.523599");
+    /// ```
+    #[inline]
+    pub fn asin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asin_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arcsine is less
+    /// than, equal to, or greater than the exact arcsine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is nonzero and its absolute value is at most 1, since
+    /// the arcsine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "0.5235987755982988");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.5235987755982989");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.asin_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.5235987755982989");
+    /// ```
+    #[inline]
+    pub fn asin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arccosine is less than, equal to, or greater than
+    /// the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=\pi/2$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(-1.0,p,m)=\pi$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is at least -1 and less than 1,
+    /// since the arccosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(5, Floor);
+    /// assert_eq!(acos.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(5, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(5, Nearest);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(20, Floor);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(20, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.047199");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round(20, Nearest);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arccosine is less than, equal to, or greater
+    /// than the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=\pi/2$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(-1.0,p,m)=\pi$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is at least -1 and less than 1,
+    /// since the arccosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(5, Floor);
+    /// assert_eq!(acos.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(5, Nearest);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(20, Floor);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(acos.to_string(), "1.047199");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_round_ref(20, Nearest);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn acos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => half_pi_with_sign(true, prec, rm),
+            x => match x.partial_cmp_abs(&1u32).unwrap() {
+                Less => acos_finite(x, prec, rm),
+                Equal if *x > 0u32 => (float_zero!(), Equal),
+                Equal => pi_with_sign(true, prec, rm),
+                Greater => (float_nan!(), Equal),
+            },
+        }
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 \arccos
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec(5);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec(20);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 \arccos
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_ref(5);
+    /// assert_eq!(acos.to_string(), "1.06");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_prec_ref(20);
+    /// assert_eq!(acos.to_string(), "1.047197");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn acos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is at least -1 and less than 1, since the arccosine is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round(Floor);
+    /// assert_eq!(acos.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round(Ceiling);
+    /// assert_eq!(acos.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round(Nearest);
+    /// assert_eq!(acos.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn acos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is at least -1 and less than 1, since the arccosine is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round_ref(Floor);
+    /// assert_eq!(acos.to_string(), "1.0471975511965976");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round_ref(Ceiling);
+    /// assert_eq!(acos.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (acos, o) = Float::from(0.5).acos_round_ref(Nearest);
+    /// assert_eq!(acos.to_string(), "1.0471975511965979");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn acos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.acos_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arccosine is less than, equal to, or greater than the exact arccosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is at least -1 and less than 1,
+    /// since the arccosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.047197");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.047199");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn acos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.acos_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arccosine is less than, equal to, or greater than the exact arccosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 \arccos
+    ///   x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.06");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.047197");
+    /// ```
+    #[inline]
+    pub fn acos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acos_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arccosine is less
+    /// than, equal to, or greater than the exact arccosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \arccos x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is at least -1 and less than 1, since the arccosine is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.0471975511965976");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.0471975511965979");
+    ///
+    /// let mut x = Float::from(0.5);
+    /// assert_eq!(x.acos_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.0471975511965979");
+    /// ```
+    #[inline]
+    pub fn acos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, rm)
+    }
+}
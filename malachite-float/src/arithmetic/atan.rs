@@ -0,0 +1,2125 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{
+    is_negligible, reduction_steps, round_enclosed, round_near, Enclosure,
+};
+use crate::arithmetic::sin_cos::{is_tiny, toward_zero};
+use crate::constants::pi::pi_enclosure;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, float_negative_zero, float_zero, Float};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns an enclosure of $\arctan x$, where $x$ is nonzero and $|x| < 2$. The argument is reduced
+// $k$ times using $\arctan x = 2\arctan(x/(1+\sqrt{1+x^2}))$, and the Taylor series is summed.
+fn atan_series(x: &Float, prec: u64) -> Enclosure {
+    let e = i64::from(x.get_exponent().unwrap());
+    if e << 1 <= -i64::exact_from(prec) {
+        // $x^2 < 2^{-p}$, so $\arctan x / x$ lies in $(1 - x^2/3, 1) \subset [1 - 2^{-p}, 1]$. This
+        // avoids squaring $x$, which might underflow the exponent range.
+        let factor = Enclosure {
+            lo: Float::ONE
+                .sub_prec(Float::power_of_2(-i64::exact_from(prec)), prec)
+                .0,
+            hi: Float::ONE,
+        };
+        return Enclosure::exact(x.clone()).mul(&factor, prec);
+    }
+    let k = reduction_steps(prec, e);
+    let working_prec = prec + k + 10;
+    let one = Enclosure::exact(Float::ONE);
+    let mut x = Enclosure::exact(x.clone());
+    for _ in 0..k {
+        let denominator = one.add(
+            &one.add(&x.square(working_prec), working_prec)
+                .sqrt(working_prec),
+            working_prec,
+        );
+        x = x.div(&denominator, working_prec).unwrap();
+    }
+    // The reduced argument has absolute value less than 1, and $\arctan$ is 1-Lipschitz.
+    let width = x.width(working_prec);
+    let x_squared = Enclosure::exact(x.lo.clone()).square(working_prec);
+    let mut power = Enclosure::exact(x.lo);
+    let mut sum = power.clone();
+    let mut i = 1u64;
+    // The series alternates and its terms decrease, so the sum is within its last term of the
+    // exact value.
+    let term = loop {
+        power = power.mul(&x_squared, working_prec).neg();
+        let term = power
+            .div(&Enclosure::exact(Float::from((i << 1) | 1)), working_prec)
+            .unwrap();
+        sum = sum.add(&term, working_prec);
+        if is_negligible(&term.hi, &sum.lo, working_prec) {
+            break term;
+        }
+        i += 1;
+    };
+    sum.widen(&term.magnitude(), working_prec)
+        .widen(&width, working_prec)
+        .shl(i64::exact_from(k))
+}
+
+// Returns an enclosure of $\arctan x$, where $x$ is finite and nonzero. If $|x| \geq 2$, then
+// $\arctan x = \operatorname{sgn}(x)\pi/2 - \arctan(1/x)$ is used.
+fn atan_point(x: &Float, prec: u64) -> Enclosure {
+    let e = x.get_exponent().unwrap();
+    if e <= 1 {
+        return atan_series(x, prec);
+    } else if i64::from(e) > i64::exact_from(prec) + 3 {
+        // $|1/x| < 2^{-p-2}$, so $\arctan x$ is within $2^{-p-2}$ of $\operatorname{sgn}(x)\pi/2$.
+        return half_pi_enclosure(*x > 0u32, prec);
+    }
+    let reciprocal = Enclosure::exact(Float::ONE)
+        .div(&Enclosure::exact(x.clone()), prec)
+        .unwrap();
+    let atan_reciprocal = atan_series(&reciprocal.lo, prec).widen(&reciprocal.width(prec), prec);
+    let half_pi = pi_enclosure(prec).shl(-1);
+    if *x > 0u32 {
+        half_pi.sub(&atan_reciprocal, prec)
+    } else {
+        half_pi.neg().sub(&atan_reciprocal, prec)
+    }
+}
+
+// Returns an enclosure of $\arctan x$ for all $x$ in the enclosure `x`, or `None` if `x` contains
+// zero. Since $\arctan$ is 1-Lipschitz, it suffices to evaluate it at one endpoint.
+pub(crate) fn atan_enclosure(x: &Enclosure, prec: u64) -> Option<Enclosure> {
+    if x.contains_zero() {
+        None
+    } else {
+        Some(atan_point(&x.lo, prec).widen(&x.width(prec), prec))
+    }
+}
+
+// Returns an enclosure of every value within $2^{-p-2}$ of $\pi/2$ if `sign` is `true`, and of
+// $-\pi/2$ otherwise, where $p$ is `prec`.
+fn half_pi_enclosure(sign: bool, prec: u64) -> Enclosure {
+    let half_pi = pi_enclosure(prec).shl(-1);
+    (if sign { half_pi } else { half_pi.neg() })
+        .widen(&Float::power_of_2(-i64::exact_from(prec) - 2), prec)
+}
+
+// Returns $\pi/2$ if `sign` is `true`, and $-\pi/2$ otherwise, rounded to `prec` bits using `rm`.
+pub(crate) fn half_pi_with_sign(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    round_enclosed(prec, rm, |working_prec| {
+        let half_pi = pi_enclosure(working_prec).shl(-1);
+        Some(if sign { half_pi } else { half_pi.neg() })
+    })
+}
+
+// The arctangent of a finite, nonzero `Float`. If $x$ is tiny, $\arctan x = x - x^3/3 + O(x^5)$
+// lies strictly between $x$ and its neighbor in the direction of zero.
+fn atan_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if is_tiny(x, prec) {
+        round_near(x, toward_zero(x), prec, rm)
+    } else {
+        round_enclosed(prec, rm, |working_prec| {
+            atan_enclosure(&Enclosure::exact(x.clone()), working_prec)
+        })
+    }
+}
+
+// $\arctan(y/x)$, adjusted to the quadrant of $(x, y)$, where $x$ and $y$ are finite and nonzero.
+fn atan2_finite(y: Float, x: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let x_positive = x > 0u32;
+    let y_positive = y > 0u32;
+    // $2^{d-1} < |y/x| < 2^{d+1}$. If $|y/x|$ is huge or tiny, the quotient is not computed, since
+    // it might overflow or underflow the exponent range.
+    let d = i64::from(y.get_exponent().unwrap()) - i64::from(x.get_exponent().unwrap());
+    if x_positive && d << 1 < -i64::exact_from(prec) {
+        // If $y/x$ is exact and tiny, $\arctan(y/x)$ lies strictly between $y/x$ and its neighbor
+        // in the direction of zero. If $y/x$ is exact, its precision is at most that of $y$.
+        let (q, o) = y.div_prec_round_ref_ref(&x, y.get_prec().unwrap(), Nearest);
+        if o == Equal && is_tiny(&q, prec) {
+            return round_near(&q, toward_zero(&q), prec, rm);
+        }
+    }
+    let y = Enclosure::exact(y);
+    let x = Enclosure::exact(x);
+    round_enclosed(prec, rm, |working_prec| {
+        let p = i64::exact_from(working_prec);
+        if d > p + 3 {
+            // The result is within $|x/y| < 2^{-p-2}$ of $\operatorname{sgn}(y)\pi/2$.
+            return Some(half_pi_enclosure(y_positive, working_prec));
+        } else if !x_positive && d < -p - 3 {
+            // The result is within $|y/x| < 2^{-p-2}$ of $\operatorname{sgn}(y)\pi$.
+            let pi = pi_enclosure(working_prec);
+            return Some(
+                (if y_positive { pi } else { pi.neg() })
+                    .widen(&Float::power_of_2(-p - 2), working_prec),
+            );
+        }
+        let atan = atan_enclosure(&y.div(&x, working_prec)?, working_prec)?;
+        Some(if x_positive {
+            atan
+        } else if y_positive {
+            atan.add(&pi_enclosure(working_prec), working_prec)
+        } else {
+            atan.sub(&pi_enclosure(working_prec), working_prec)
+        })
+    })
+}
+
+// $\arctan(y/x)$, adjusted to the quadrant of $(x, y)$, if $x$ or $y$ is zero, infinite, or `NaN`.
+// Returns `None` if both are finite and nonzero.
+fn atan2_special(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+    assert_ne!(prec, 0);
+    Some(match (y, x) {
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Equal),
+        (Float(Zero { sign: y_sign }), x) => {
+            if x.is_sign_positive() {
+                (Float(Zero { sign: *y_sign }), Equal)
+            } else {
+                pi_with_sign(*y_sign, prec, rm)
+            }
+        }
+        (Float(Infinity { sign: y_sign }), Float(Infinity { sign: x_sign })) => {
+            // $\pm\pi/4$ or $\pm3\pi/4$
+            let multiplier = Enclosure::exact(Float::from(if *x_sign { 1u32 } else { 3 }));
+            round_enclosed(prec, rm, |working_prec| {
+                let y = pi_enclosure(working_prec)
+                    .mul(&multiplier, working_prec)
+                    .shl(-2);
+                Some(if *y_sign { y } else { y.neg() })
+            })
+        }
+        (Float(Infinity { sign: y_sign }), _)
+        | (Float(Finite { sign: y_sign, .. }), float_either_zero!()) => {
+            half_pi_with_sign(*y_sign, prec, rm)
+        }
+        (Float(Finite { sign: y_sign, .. }), Float(Infinity { sign: x_sign })) => {
+            if *x_sign {
+                (
+                    if *y_sign {
+                        float_zero!()
+                    } else {
+                        float_negative_zero!()
+                    },
+                    Equal,
+                )
+            } else {
+                pi_with_sign(*y_sign, prec, rm)
+            }
+        }
+        _ => return None,
+    })
+}
+
+// Returns $\pi$ if `sign` is `true`, and $-\pi$ otherwise, rounded to `prec` bits using `rm`.
+pub(crate) fn pi_with_sign(sign: bool, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    round_enclosed(prec, rm, |working_prec| {
+        let pi = pi_enclosure(working_prec);
+        Some(if sign { pi } else { pi.neg() })
+    })
+}
+
+impl Float {
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\pi/2$
+    /// - $f(-\infty,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is neither `NaN` nor zero, since
+    /// the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Floor);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(5, Nearest);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Floor);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round(20, Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\pi/2$
+    /// - $f(-\infty,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is neither `NaN` nor zero, since
+    /// the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Floor);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(5, Nearest);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Floor);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(atan.to_string(), "1.262629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_round_ref(20, Nearest);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn atan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            Float(Infinity { sign }) => half_pi_with_sign(*sign, prec, rm),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => atan_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec(5);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec(20);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_ref(5);
+    /// assert_eq!(atan.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_prec_ref(20);
+    /// assert_eq!(atan.to_string(), "1.262627");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is neither `NaN` nor zero, since the arctangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Floor);
+    /// assert_eq!(atan.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round(Nearest);
+    /// assert_eq!(atan.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is neither `NaN` nor zero, since the arctangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Floor);
+    /// assert_eq!(atan.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Ceiling);
+    /// assert_eq!(atan.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan, o) = Float::from(PI).atan_round_ref(Nearest);
+    /// assert_eq!(atan.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.atan_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is neither `NaN` nor zero, since
+    /// the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.262629");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.atan_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is neither `NaN` nor zero, since the arctangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// ```
+    #[inline]
+    pub fn atan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the specified precision and with the specified rounding mode. Both [`Float`]s are taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded arctangent is less
+    /// than, equal to, or greater than the exact arctangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y<0.0$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::atan2_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        atan2_special(&self, &other, prec, rm)
+            .unwrap_or_else(|| atan2_finite(self, other, prec, rm))
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the specified precision and with the specified rounding mode. The first [`Float`] is taken
+    /// by value and the second by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y<0.0$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_special(&self, other, prec, rm)
+            .unwrap_or_else(|| atan2_finite(self, other.clone(), prec, rm))
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the specified precision and with the specified rounding mode. The first [`Float`] is taken
+    /// by reference and the second by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y<0.0$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_special(self, &other, prec, rm)
+            .unwrap_or_else(|| atan2_finite(self.clone(), other, prec, rm))
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the specified precision and with the specified rounding mode. Both [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x=0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x=-0.0$ or $x<0.0$
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y<0.0$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.88");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn atan2_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        atan2_special(self, other, prec, rm)
+            .unwrap_or_else(|| atan2_finite(self.clone(), other.clone(), prec, rm))
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the nearest value of the specified precision. Both [`Float`]s are taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round(other, prec, Nearest)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the nearest value of the specified precision. The first [`Float`] is taken by value and the
+    /// second by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, Nearest)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the nearest value of the specified precision. The first [`Float`] is taken by reference and
+    /// the second by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_val(other, prec, Nearest)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result to
+    /// the nearest value of the specified precision. Both [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(atan2.to_string(), "0.84");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(atan2.to_string(), "0.8575115");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, Nearest)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result with
+    /// the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan2_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result with
+    /// the specified rounding mode. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result with
+    /// the specified rounding mode. The first [`Float`] is taken by reference and the second by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded arctangent is less
+    /// than, equal to, or greater than the exact arctangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of two [`Float`]s $y$ and $x$, rounding the result with
+    /// the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (atan2, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(atan2.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 5, Floor), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 5, Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.88");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 5, Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 20, Floor), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.8575125");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign(Float::from(E), 20, Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_val(other, prec, rm);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result to the specified precision and with the specified rounding mode.
+    /// The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::atan2_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and the result is neither zero nor `NaN`,
+    /// since the arctangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 5, Floor), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 5, Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.88");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 5, Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 20, Floor), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.8575125");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_round_assign_ref(&Float::from(E), 20, Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result to the nearest value of the specified precision. The [`Float`] on
+    /// the right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign(Float::from(E), 20), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_val(other, prec, Nearest);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result to the nearest value of the specified precision. The [`Float`] on
+    /// the right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(y.to_string(), "0.84");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_prec_assign_ref(&Float::from(E), 20), Less);
+    /// assert_eq!(y.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(other, prec, Nearest);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result with the specified rounding mode. The [`Float`] on the right-hand
+    /// side is taken by value. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(y.to_string(), "0.8575117863558462");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.8575117863558464");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign(Float::from(E), Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_val(other, prec, rm);
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ by the two-argument arctangent of $y$ and another [`Float`] $x$, in
+    /// place, rounding the result with the specified rounding mode. The [`Float`] on the right-hand
+    /// side is taken by reference. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The two-argument arctangent $\operatorname{atan2}(y,x)$ is the angle, in $(-\pi, \pi]$,
+    /// between the positive $x$-axis and the ray from the origin through the point $(x, y)$. When
+    /// $x>0$, it is equal to $\arctan(y/x)$.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed
+    ///   to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and the result is neither zero nor `NaN`, since the arctangent is
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(y.to_string(), "0.8575117863558462");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(y.to_string(), "0.8575117863558464");
+    ///
+    /// let mut y = Float::from(PI);
+    /// assert_eq!(y.atan2_round_assign_ref(&Float::from(E), Nearest), Less);
+    /// assert_eq!(y.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        let o;
+        (*self, o) = self.atan2_prec_round_ref_ref(other, prec, rm);
+        o
+    }
+}
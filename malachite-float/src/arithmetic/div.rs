@@ -7,6 +7,7 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::arithmetic::is_power_of_2::abs_is_power_of_2;
+use crate::arithmetic::round_to_odd::{round_via_odd, round_via_odd_assign};
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{float_either_infinity, float_either_zero, float_nan, Float};
 use core::cmp::max;
@@ -1059,6 +1060,12 @@ impl Float {
         rm: RoundingMode,
     ) -> (Float, Ordering) {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| {
+                self.div_prec_round_ref_val(other, prec, Down)
+            });
+        }
         match (self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -1228,6 +1235,12 @@ impl Float {
         rm: RoundingMode,
     ) -> (Float, Ordering) {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| {
+                self.div_prec_round_ref_ref(other, prec, Down)
+            });
+        }
         match (self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -1995,6 +2008,12 @@ impl Float {
     #[inline]
     pub fn div_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.div_prec_round_assign(other, prec, Down)
+            });
+        }
         match (&mut *self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -2164,6 +2183,12 @@ impl Float {
         rm: RoundingMode,
     ) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.div_prec_round_assign_ref(other, prec, Down)
+            });
+        }
         match (&mut *self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
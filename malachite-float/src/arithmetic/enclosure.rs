@@ -0,0 +1,306 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// Helpers for correctly rounding functions whose values are irrational. Such a value is computed
+// as an enclosure `[lo, hi]` at some working precision, with every intermediate operation rounded
+// outward using `Floor` or `Ceiling`. If both ends of the enclosure round to the same `Float`, that
+// `Float` is the correctly rounded value; otherwise the working precision is increased and the
+// enclosure is recomputed (Ziv's strategy). Since the value is irrational it never coincides with
+// an endpoint or with a rounding boundary, so the loop always terminates.
+
+use crate::Float;
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Abs, FloorSqrt};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::platform::Limb;
+
+// Given `lo` and `hi` with `lo < y < hi` for some real $y$, returns $y$ rounded to `prec` bits
+// using `rm`, together with the `Ordering` of the result relative to $y$. Returns `None` if the
+// enclosure is too wide to determine the result.
+pub(crate) fn round_enclosure(
+    lo: &Float,
+    hi: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    if !lo.is_normal() || !hi.is_normal() || lo.is_sign_positive() != hi.is_sign_positive() {
+        return None;
+    }
+    let (r_lo, o_lo) = Float::from_float_prec_round_ref(lo, prec, rm);
+    let (r_hi, o_hi) = Float::from_float_prec_round_ref(hi, prec, rm);
+    if r_lo != r_hi {
+        None
+    } else if o_hi != Less {
+        Some((r_hi, Greater))
+    } else if o_lo != Greater {
+        Some((r_lo, Less))
+    } else {
+        None
+    }
+}
+
+// The working precision used for the first attempt at rounding a value to `prec` bits.
+pub(crate) fn initial_working_prec(prec: u64) -> u64 {
+    prec + prec.significant_bits() + 10
+}
+
+// The working precision used for the next attempt, after an attempt at `working_prec` failed.
+pub(crate) fn next_working_prec(working_prec: u64) -> u64 {
+    working_prec + max(working_prec >> 1, Limb::WIDTH)
+}
+
+// Rounds an irrational value to `prec` bits using `rm`. `enclose` is called with a working
+// precision and must return an `Enclosure` of the value, or `None` if no useful enclosure can be
+// computed at that precision. The enclosures must shrink to the value as the working precision
+// increases.
+//
+// Panics if `rm` is `Exact`, since the value cannot be represented exactly.
+pub(crate) fn round_enclosed<F: FnMut(u64) -> Option<Enclosure>>(
+    prec: u64,
+    rm: RoundingMode,
+    mut enclose: F,
+) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact result");
+    let mut working_prec = initial_working_prec(prec);
+    loop {
+        if let Some(result) = enclose(working_prec).and_then(|y| y.round(prec, rm)) {
+            return result;
+        }
+        working_prec = next_working_prec(working_prec);
+    }
+}
+
+// Rounds an irrational value to `prec` bits using `rm`. `bound` is called with a working precision
+// and a rounding mode of `Floor` or `Ceiling`, and must return a lower or upper bound of the value,
+// respectively. The bounds must converge to the value as the working precision increases.
+//
+// Panics if `rm` is `Exact`, since the value cannot be represented exactly.
+pub(crate) fn round_irrational<F: FnMut(u64, RoundingMode) -> Float>(
+    prec: u64,
+    rm: RoundingMode,
+    mut bound: F,
+) -> (Float, Ordering) {
+    round_enclosed(prec, rm, |working_prec| {
+        Some(Enclosure {
+            lo: bound(working_prec, Floor),
+            hi: bound(working_prec, Ceiling),
+        })
+    })
+}
+
+// Given a value $y$ that lies strictly between `x` and the neighbor of `x` in the direction
+// `direction`, where the neighbor is taken at precision `max(prec, x.get_prec()) + 2`, returns $y$
+// rounded to `prec` bits using `rm`, together with the `Ordering` of the result relative to $y$.
+//
+// This is used to round $f(x)$ when $x$ is tiny and $f(x) = x + O(x^2)$, or when $f(x)$ is
+// extremely close to a simple value like 1. No precision-`prec` `Float` or midpoint between two
+// such `Float`s lies strictly between `x` and its neighbor, so $y$ rounds the same way as the
+// neighbor does.
+//
+// Panics if `rm` is `Exact`.
+pub(crate) fn round_near(
+    x: &Float,
+    direction: Ordering,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact result");
+    let (mut y, o) = Float::from_float_prec_ref(x, max(prec, x.get_prec().unwrap()) + 2);
+    assert_eq!(o, Equal);
+    match direction {
+        Less => y.decrement(),
+        Greater => y.increment(),
+        Equal => unreachable!(),
+    }
+    Float::from_float_prec_round(y, prec, rm)
+}
+
+// Returns whether adding `term` to `sum` can no longer affect `sum` at precision `prec`, even
+// cumulatively, given that the terms that follow decrease at least geometrically.
+pub(crate) fn is_negligible(term: &Float, sum: &Float, prec: u64) -> bool {
+    term.is_zero()
+        || i64::from(term.get_exponent().unwrap()) + i64::exact_from(prec) + 2
+            < i64::from(sum.get_exponent().unwrap())
+}
+
+// The number of argument-halving (or square-rooting) steps to perform before summing a power
+// series at working precision `prec`, given that the argument is less than $2^e$. Roughly balances
+// the number of series terms against the number of squarings.
+pub(crate) fn reduction_steps(prec: u64, e: i64) -> u64 {
+    let target = i64::exact_from(max(prec.floor_sqrt() >> 1, 1));
+    u64::exact_from(max(target + e, 0))
+}
+
+// A closed interval `[lo, hi]` known to contain some real value. Each operation rounds the lower
+// endpoint of its result using `Floor` and the upper endpoint using `Ceiling`, so the result
+// contains the exact result of the operation applied to any values contained in the operands.
+#[derive(Clone, Debug)]
+pub(crate) struct Enclosure {
+    pub(crate) lo: Float,
+    pub(crate) hi: Float,
+}
+
+impl Enclosure {
+    pub(crate) fn exact(x: Float) -> Enclosure {
+        Enclosure {
+            lo: x.clone(),
+            hi: x,
+        }
+    }
+
+    // Returns whether the enclosure contains zero, in which case its sign is unknown.
+    pub(crate) fn contains_zero(&self) -> bool {
+        self.lo <= 0u32 && self.hi >= 0u32
+    }
+
+    pub(crate) fn neg(self) -> Enclosure {
+        Enclosure {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+
+    pub(crate) fn add(&self, other: &Enclosure, prec: u64) -> Enclosure {
+        Enclosure {
+            lo: self.lo.add_prec_round_ref_ref(&other.lo, prec, Floor).0,
+            hi: self.hi.add_prec_round_ref_ref(&other.hi, prec, Ceiling).0,
+        }
+    }
+
+    pub(crate) fn sub(&self, other: &Enclosure, prec: u64) -> Enclosure {
+        Enclosure {
+            lo: self.lo.sub_prec_round_ref_ref(&other.hi, prec, Floor).0,
+            hi: self.hi.sub_prec_round_ref_ref(&other.lo, prec, Ceiling).0,
+        }
+    }
+
+    // Applies `f`, which must be monotonic in each argument, to every combination of endpoints,
+    // and returns the smallest lower bound and the largest upper bound.
+    fn combine<F: Fn(&Float, &Float, RoundingMode) -> Float>(
+        &self,
+        other: &Enclosure,
+        f: F,
+    ) -> Enclosure {
+        let mut lo = f(&self.lo, &other.lo, Floor);
+        let mut hi = f(&self.lo, &other.lo, Ceiling);
+        for (x, y) in [(&self.lo, &other.hi), (&self.hi, &other.lo), (&self.hi, &other.hi)] {
+            let z = f(x, y, Floor);
+            if z < lo {
+                lo = z;
+            }
+            let z = f(x, y, Ceiling);
+            if z > hi {
+                hi = z;
+            }
+        }
+        Enclosure { lo, hi }
+    }
+
+    pub(crate) fn mul(&self, other: &Enclosure, prec: u64) -> Enclosure {
+        if self.lo >= 0u32 && other.lo >= 0u32 {
+            Enclosure {
+                lo: self.lo.mul_prec_round_ref_ref(&other.lo, prec, Floor).0,
+                hi: self.hi.mul_prec_round_ref_ref(&other.hi, prec, Ceiling).0,
+            }
+        } else {
+            self.combine(other, |x, y, rm| x.mul_prec_round_ref_ref(y, prec, rm).0)
+        }
+    }
+
+    // Returns `None` if `other` contains zero.
+    pub(crate) fn div(&self, other: &Enclosure, prec: u64) -> Option<Enclosure> {
+        if other.contains_zero() {
+            None
+        } else if self.lo >= 0u32 && other.lo > 0u32 {
+            Some(Enclosure {
+                lo: self.lo.div_prec_round_ref_ref(&other.hi, prec, Floor).0,
+                hi: self.hi.div_prec_round_ref_ref(&other.lo, prec, Ceiling).0,
+            })
+        } else {
+            Some(self.combine(other, |x, y, rm| x.div_prec_round_ref_ref(y, prec, rm).0))
+        }
+    }
+
+    pub(crate) fn square(&self, prec: u64) -> Enclosure {
+        if self.lo >= 0u32 {
+            Enclosure {
+                lo: self.lo.square_prec_round_ref(prec, Floor).0,
+                hi: self.hi.square_prec_round_ref(prec, Ceiling).0,
+            }
+        } else if self.hi <= 0u32 {
+            Enclosure {
+                lo: self.hi.square_prec_round_ref(prec, Floor).0,
+                hi: self.lo.square_prec_round_ref(prec, Ceiling).0,
+            }
+        } else {
+            let lo_square = self.lo.square_prec_round_ref(prec, Ceiling).0;
+            let hi_square = self.hi.square_prec_round_ref(prec, Ceiling).0;
+            Enclosure {
+                lo: Float::ZERO,
+                hi: if lo_square > hi_square {
+                    lo_square
+                } else {
+                    hi_square
+                },
+            }
+        }
+    }
+
+    // The enclosure must not lie entirely below zero. Negative parts are discarded.
+    pub(crate) fn sqrt(&self, prec: u64) -> Enclosure {
+        Enclosure {
+            lo: if self.lo <= 0u32 {
+                Float::ZERO
+            } else {
+                self.lo.sqrt_prec_round_ref(prec, Floor).0
+            },
+            hi: self.hi.sqrt_prec_round_ref(prec, Ceiling).0,
+        }
+    }
+
+    // Multiplies the enclosure by $2^k$. This is exact.
+    pub(crate) fn shl(self, k: i64) -> Enclosure {
+        Enclosure {
+            lo: self.lo << k,
+            hi: self.hi << k,
+        }
+    }
+
+    // Widens the enclosure by `error` on both sides.
+    pub(crate) fn widen(&self, error: &Float, prec: u64) -> Enclosure {
+        Enclosure {
+            lo: self.lo.sub_prec_round_ref_ref(error, prec, Floor).0,
+            hi: self.hi.add_prec_round_ref_ref(error, prec, Ceiling).0,
+        }
+    }
+
+    // Returns an upper bound of `hi - lo`.
+    pub(crate) fn width(&self, prec: u64) -> Float {
+        self.hi.sub_prec_round_ref_ref(&self.lo, prec, Ceiling).0
+    }
+
+    // Returns the larger of the absolute values of the endpoints.
+    pub(crate) fn magnitude(&self) -> Float {
+        let lo = (&self.lo).abs();
+        let hi = (&self.hi).abs();
+        if lo > hi {
+            lo
+        } else {
+            hi
+        }
+    }
+
+    pub(crate) fn round(&self, prec: u64, rm: RoundingMode) -> Option<(Float, Ordering)> {
+        round_enclosure(&self.lo, &self.hi, prec, rm)
+    }
+}
@@ -0,0 +1,1277 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, reduction_steps, round_irrational, round_near};
+use crate::constants::ln_2::ln_2_bound;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{
+    float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero, Float,
+};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use core::f64::consts::LN_2;
+use malachite_base::num::basic::traits::{NegativeOne, One};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $e^x$,
+// where $x$ is positive and less than 1. The argument is halved $k$ times, the Taylor series is
+// summed, and the result is squared $k$ times. Every operation is rounded in the direction of
+// `rm`, and since all quantities involved are positive, the result is a bound in that direction.
+fn exp_series_bound(x: &Float, prec: u64, rm: RoundingMode) -> Float {
+    let k = reduction_steps(prec, i64::from(x.get_exponent().unwrap()));
+    let working_prec = prec + k + 10;
+    let x = x >> k;
+    let mut sum = Float::one_prec(working_prec);
+    let mut term = Float::one_prec(working_prec);
+    let mut i = 1u64;
+    loop {
+        term = term.mul_prec_round_val_ref(&x, working_prec, rm).0;
+        term = term.div_prec_round(Float::from(i), working_prec, rm).0;
+        sum = sum.add_prec_round_ref_ref(&term, working_prec, rm).0;
+        if is_negligible(&term, &sum, working_prec) {
+            break;
+        }
+        i += 1;
+    }
+    if rm == Ceiling {
+        // Since $x < 1/2$, the remaining terms sum to less than the last term added.
+        sum = sum.add_prec_round(term, working_prec, rm).0;
+    }
+    for _ in 0..k {
+        sum = sum.square_prec_round(working_prec, rm).0;
+    }
+    sum
+}
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $e^x$,
+// where $x$ is finite. The argument is first reduced to $r = x - n\log 2$ with $|r| \leq 1/2$, so
+// that $e^x = 2^ne^r$.
+//
+// Panics if the exponent of $e^x$ is too large or too small to be represented.
+pub(crate) fn exp_bound(x: &Float, prec: u64, rm: RoundingMode) -> Float {
+    if *x == 0u32 {
+        return Float::one_prec(prec);
+    }
+    // If |x| >= 2^32, then the exponent of e^x is out of range.
+    assert!(x.get_exponent().unwrap() <= 32, "Float exponent overflow");
+    let n = i64::rounding_from(f64::rounding_from(x, Nearest).0 / LN_2, Nearest).0;
+    let working_prec = prec + 64 + n.significant_bits();
+    let r = if n == 0 {
+        x.clone()
+    } else {
+        // A lower bound of r needs an upper bound of n * log(2), and vice versa.
+        let ln_2_rm = if (n > 0) == (rm == Floor) { -rm } else { rm };
+        let ln_2 = ln_2_bound(working_prec, ln_2_rm);
+        let n_ln_2 = ln_2.mul_prec_round(Float::from(n), working_prec, -rm).0;
+        x.sub_prec_round_ref_val(n_ln_2, working_prec, rm).0
+    };
+    let y = if r == 0u32 {
+        Float::one_prec(prec)
+    } else if r > 0u32 {
+        exp_series_bound(&r, prec, rm)
+    } else {
+        exp_series_bound(&-r, prec, -rm)
+            .reciprocal_prec_round(prec + 10, rm)
+            .0
+    };
+    y << n
+}
+
+// The exponential of a finite, nonzero `Float`.
+fn exp_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    // If |x| < 2^(-prec - 2), then e^x lies strictly between 1 and its neighbor in the direction
+    // of x, at precision prec + 2.
+    if i64::from(x.get_exponent().unwrap()) <= -i64::exact_from(prec) - 2 {
+        round_near(
+            &Float::ONE,
+            if *x > 0u32 { Greater } else { Less },
+            prec,
+            rm,
+        )
+    } else {
+        round_irrational(prec, rm, |working_prec, rm| exp_bound(x, working_prec, rm))
+    }
+}
+
+// The exponential of a finite, nonzero `Float`, minus 1.
+fn exp_m1_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let exponent = i64::from(x.get_exponent().unwrap());
+    // If |x| < 2^(-q), where q = max(prec, x.get_prec()) + 2, then e^x - 1 lies strictly between
+    // x and its neighbor at precision q in the direction of positive infinity. If x < -prec - 4,
+    // then e^x - 1 lies strictly between -1 and its neighbor at precision prec + 2 in the
+    // direction of positive infinity.
+    let q = i64::exact_from(max(prec, x.get_prec().unwrap())) + 2;
+    if exponent <= -q {
+        round_near(x, Greater, prec, rm)
+    } else if *x < -i64::exact_from(prec) - 4 {
+        round_near(&Float::NEGATIVE_ONE, Greater, prec, rm)
+    } else {
+        // When x is small, e^x - 1 suffers from cancellation, so more precision is needed.
+        let extra = u64::exact_from(max(-exponent, 0));
+        round_irrational(prec, rm, |working_prec, rm| {
+            let working_prec = working_prec + extra;
+            exp_bound(x, working_prec, rm)
+                .sub_prec_round(Float::ONE, working_prec, rm)
+                .0
+        })
+    }
+}
+
+impl Float {
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded exponential is less than, equal to, or greater than
+    /// the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// exponential is then irrational. Also panics if the exponent of the result is too large or
+    /// too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Floor);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Ceiling);
+    /// assert_eq!(exp.to_string(), "24.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(5, Nearest);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Floor);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round(20, Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded exponential is less than, equal to, or greater
+    /// than the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// exponential is then irrational. Also panics if the exponent of the result is too large or
+    /// too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Floor);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Ceiling);
+    /// assert_eq!(exp.to_string(), "24.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(5, Nearest);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Floor);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Ceiling);
+    /// assert_eq!(exp.to_string(), "23.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_round_ref(20, Nearest);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn exp_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_negative_infinity!() => (float_zero!(), Equal),
+            float_either_zero!() => (Float::one_prec(prec), Equal),
+            _ => exp_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec(5);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec(20);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_ref(5);
+    /// assert_eq!(exp.to_string(), "23.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_prec_ref(20);
+    /// assert_eq!(exp.to_string(), "23.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded exponential is less than, equal to, or greater than the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the exponential is then
+    /// irrational. Also panics if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Floor);
+    /// assert_eq!(exp.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Ceiling);
+    /// assert_eq!(exp.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round(Nearest);
+    /// assert_eq!(exp.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the exponential is then
+    /// irrational. Also panics if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Floor);
+    /// assert_eq!(exp.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Ceiling);
+    /// assert_eq!(exp.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp, o) = Float::from(PI).exp_round_ref(Nearest);
+    /// assert_eq!(exp.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.exp_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// exponential is then irrational. Also panics if the exponent of the result is too large or
+    /// too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "24.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.exp_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result to the nearest value
+    /// of the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// exponential is less than, equal to, or greater than the exact exponential. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 e^x\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded exponential is
+    /// less than, equal to, or greater than the exact exponential. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 e^x\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the exponential is then
+    /// irrational. Also panics if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// ```
+    #[inline]
+    pub fn exp_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, rm)
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded value is less than, equal to,
+    /// or greater than the exact value. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_m1_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_m1_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// result is then irrational. Also panics if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(5, Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(5, Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(5, Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(20, Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(20, Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "22.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round(20, Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_m1_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.exp_m1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded value is less than, equal to,
+    /// or greater than the exact value. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_m1_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_m1_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// result is then irrational. Also panics if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(5, Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(5, Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(5, Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(20, Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(20, Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "22.14072");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_round_ref(20, Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn exp_m1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_nan!() => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_negative_infinity!() => (Float::negative_one_prec(prec), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => exp_m1_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded value is less than, equal to, or greater than the
+    /// exact value. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the value is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_m1_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec(5);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec(20);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_m1_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp_m1_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded value is less than, equal to, or greater than
+    /// the exact value. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the value is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_m1_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_ref(5);
+    /// assert_eq!(exp_m1.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_prec_ref(20);
+    /// assert_eq!(exp_m1.to_string(), "22.14069");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn exp_m1_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp_m1_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than, equal to, or greater than the exact
+    /// value. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_m1_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the result is then
+    /// irrational. Also panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round(Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round(Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round(Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn exp_m1_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_m1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes one less than the exponential of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded value is less than, equal to, or greater than the
+    /// exact value. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_m1_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the result is then
+    /// irrational. Also panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round_ref(Floor);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round_ref(Ceiling);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (exp_m1, o) = Float::from(PI).exp_m1_round_ref(Nearest);
+    /// assert_eq!(exp_m1.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn exp_m1_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.exp_m1_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes one less than the exponential of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded value is less than, equal to, or greater than the exact
+    /// value. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_m1_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_m1_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// result is then irrational. Also panics if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn exp_m1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.exp_m1_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes one less than the exponential of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded value is less than, equal to, or greater than the exact value. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the value is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_m1_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn exp_m1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp_m1_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes one less than the exponential of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded value
+    /// is less than, equal to, or greater than the exact value. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_m1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::exp_m1_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the result is then
+    /// irrational. Also panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_m1_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// ```
+    #[inline]
+    pub fn exp_m1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp_m1_prec_round_assign(prec, rm)
+    }
+}
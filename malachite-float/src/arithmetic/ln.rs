@@ -0,0 +1,1717 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, reduction_steps, round_irrational, round_near};
+use crate::constants::ln_2::ln_2_bound;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{
+    float_either_zero, float_infinity, float_nan, float_negative_infinity, float_zero, Float,
+};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::Ln;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+
+// Returns a bound of $\operatorname{atanh} z$ in the direction of `rm`, where $0 < |z| \leq 1/4$.
+fn atanh_bound(z: &Float, prec: u64, rm: RoundingMode) -> Float {
+    if *z < 0u32 {
+        return -atanh_bound(&-z, prec, -rm);
+    }
+    let z_squared = z.square_prec_round_ref(prec, rm).0;
+    let mut power = z.clone();
+    let mut sum = z.clone();
+    let mut i = 1u64;
+    loop {
+        power = power.mul_prec_round_val_ref(&z_squared, prec, rm).0;
+        let term = power
+            .div_prec_round_ref_val(Float::from((i << 1) | 1), prec, rm)
+            .0;
+        sum = sum.add_prec_round_ref_ref(&term, prec, rm).0;
+        if is_negligible(&term, &sum, prec) {
+            if rm == Ceiling {
+                // Since $z^2 \leq 1/16$, the remaining terms sum to less than the last term added.
+                sum = sum.add_prec_round(term, prec, rm).0;
+            }
+            return sum;
+        }
+        i += 1;
+    }
+}
+
+// Returns a bound of $\log(1+t)$ in the direction of `rm`, where $t$ is nonzero and $-1/4 \leq t
+// \leq 1/2$, using $\log(1+t) = 2\operatorname{atanh}(t/(2+t))$.
+fn ln_1p_series_bound(t: &Float, prec: u64, rm: RoundingMode) -> Float {
+    // z = t/(2+t) increases with t, so a lower bound of z needs an upper bound of 2+t when t > 0,
+    // and a lower bound when t < 0.
+    let denominator_rm = if *t > 0u32 { -rm } else { rm };
+    let denominator = t.add_prec_round_ref_val(Float::TWO, prec, denominator_rm).0;
+    let z = t.div_prec_round_ref_val(denominator, prec, rm).0;
+    atanh_bound(&z, prec, rm) << 1u32
+}
+
+// Returns a bound of $\log(1+t)$ in the direction of `rm`, where $t$ is nonzero and $-1/4 \leq t
+// \leq 1/2$. To speed up the series, $1+t$ is first replaced by its $2^k$th root, using
+// $\log(1+t) = 2^k\log((1+t)^{1/2^k})$. Square roots are monotonic, so rounding each one in the
+// direction of `rm` preserves the bound.
+pub(crate) fn ln_1p_bound(t: &Float, prec: u64, rm: RoundingMode) -> Float {
+    let k = reduction_steps(prec, i64::from(t.get_exponent().unwrap()));
+    let working_prec = prec + k + 10;
+    if k == 0 {
+        return ln_1p_series_bound(t, working_prec, rm);
+    }
+    let mut y = t.add_prec_round_ref_val(Float::ONE, working_prec, rm).0;
+    for _ in 0..k {
+        y = y.sqrt_prec_round(working_prec, rm).0;
+    }
+    let t = y.sub_prec_round(Float::ONE, working_prec, rm).0;
+    ln_1p_series_bound(&t, working_prec, rm) << k
+}
+
+// Given a positive, finite `x`, returns $t$ and $e$ such that $x = 2^e(1+t)$ and $-1/4 \leq t <
+// 1/2$. The subtraction is exact, since $1+t$ lies in $[3/4, 3/2)$.
+fn ln_reduce(x: &Float) -> (Float, i64) {
+    let prec = x.get_prec().unwrap();
+    let mut exponent = i64::from(x.get_exponent().unwrap());
+    let mut y = x >> exponent;
+    if y < 0.75 {
+        y <<= 1u32;
+        exponent -= 1;
+    }
+    (y.sub_prec_round(Float::ONE, prec, Exact).0, exponent)
+}
+
+// Returns a bound of $e\log 2 + \log(1+t)$ in the direction of `rm`, where $-1/4 \leq t \leq 1/2$
+// and $e$ and $t$ are not both zero.
+fn ln_reduced_bound(t: &Float, exponent: i64, prec: u64, rm: RoundingMode) -> Float {
+    if exponent == 0 {
+        return ln_1p_bound(t, prec, rm);
+    }
+    let working_prec = prec + exponent.significant_bits() + 10;
+    // A lower bound of e * log(2) needs an upper bound of log(2) when e is negative.
+    let ln_2 = ln_2_bound(working_prec, if exponent > 0 { rm } else { -rm });
+    let scaled_ln_2 = ln_2
+        .mul_prec_round(Float::from(exponent), working_prec, rm)
+        .0;
+    if *t == 0u32 {
+        scaled_ln_2
+    } else {
+        ln_1p_bound(t, working_prec, rm)
+            .add_prec_round(scaled_ln_2, working_prec, rm)
+            .0
+    }
+}
+
+// Returns a bound of $\log x$ in the direction of `rm`, where $x$ is positive, finite, and not 1.
+pub(crate) fn ln_bound(x: &Float, prec: u64, rm: RoundingMode) -> Float {
+    let (t, exponent) = ln_reduce(x);
+    ln_reduced_bound(&t, exponent, prec, rm)
+}
+
+// $\log(1+x)$, where $x$ is finite, nonzero, and greater than -1.
+fn ln_1p_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    // If |x| < 2^(-q), where q = max(prec, x.get_prec()) + 2, then log(1+x) lies strictly between
+    // x and its neighbor at precision q in the direction of negative infinity.
+    let q = i64::exact_from(max(prec, x.get_prec().unwrap())) + 2;
+    if i64::from(x.get_exponent().unwrap()) <= -q {
+        round_near(x, Less, prec, rm)
+    } else if *x >= -0.25 && *x <= 0.5 {
+        round_irrational(prec, rm, |working_prec, rm| {
+            ln_1p_bound(x, working_prec, rm)
+        })
+    } else {
+        round_irrational(prec, rm, |working_prec, rm| {
+            let y = x.add_prec_round_ref_val(Float::ONE, working_prec, rm).0;
+            ln_bound(&y, working_prec, rm)
+        })
+    }
+}
+
+// $\log x$, where $x$ is positive, finite, and not 1.
+fn ln_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (t, exponent) = ln_reduce(x);
+    if exponent == 0 {
+        ln_1p_finite(&t, prec, rm)
+    } else {
+        round_irrational(prec, rm, |working_prec, rm| {
+            ln_reduced_bound(&t, exponent, working_prec, rm)
+        })
+    }
+}
+
+// $\log x$, where $x$ is a positive `Rational` other than 1, given $y = x/2^e$ and $e$, where $1/2
+// \leq y < 1$.
+fn ln_rational_reduced(
+    mut y: Rational,
+    mut exponent: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if y < Rational::from_unsigneds(3u8, 4) {
+        y <<= 1u32;
+        exponent -= 1;
+    }
+    y -= Rational::ONE;
+    round_irrational(prec, rm, |working_prec, rm| {
+        let t = Float::from_rational_prec_round_ref(&y, working_prec, rm).0;
+        ln_reduced_bound(&t, exponent, working_prec, rm)
+    })
+}
+
+impl Float {
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round`] instead. If both of these things are true, consider using [`Float::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is positive, finite, and not
+    /// equal to 1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Floor);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(5, Nearest);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Floor);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144732");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round(20, Nearest);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=-\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_ref`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is positive, finite, and not
+    /// equal to 1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Floor);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.19");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(5, Nearest);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Floor);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ln.to_string(), "1.144732");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_round_ref(20, Nearest);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn ln_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            float_infinity!() => (float_infinity!(), Equal),
+            float_either_zero!() => (float_negative_infinity!(), Equal),
+            Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }) => {
+                (float_nan!(), Equal)
+            }
+            x if *x == 1u32 => (float_zero!(), Equal),
+            x => ln_finite(x, prec, rm),
+        }
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round`] instead. If you know that your target precision is the precision of
+    /// the input, consider using [`Float::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec(5);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec(20);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_ref(5);
+    /// assert_eq!(ln.to_string(), "1.12");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_prec_ref(20);
+    /// assert_eq!(ln.to_string(), "1.14473");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round`] instead.
+    /// If you know you'll be using the `Nearest` rounding mode, consider using [`Float::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is positive, finite, and not equal to 1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Floor);
+    /// assert_eq!(ln.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Ceiling);
+    /// assert_eq!(ln.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round(Nearest);
+    /// assert_eq!(ln.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).ln()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is positive, finite, and not equal to 1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Floor);
+    /// assert_eq!(ln.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Ceiling);
+    /// assert_eq!(ln.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::from(PI).ln_round_ref(Nearest);
+    /// assert_eq!(ln.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.ln_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is positive, finite, and not
+    /// equal to 1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.144732");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.ln_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is positive, finite, and not equal to 1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// ```
+    #[inline]
+    pub fn ln_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded logarithm is less than,
+    /// equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite, nonzero, and greater
+    /// than -1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(5, Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.38");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(5, Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(5, Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(20, Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(20, Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.421082");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round(20, Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded logarithm is
+    /// less than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite, nonzero, and greater
+    /// than -1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(5, Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.38");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(5, Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(5, Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(20, Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(20, Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.421082");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_round_ref(20, Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn ln_1p_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { sign: false }) => (float_nan!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            x => match x.partial_cmp(&-1i32).unwrap() {
+                Less => (float_nan!(), Equal),
+                Equal => (float_negative_infinity!(), Equal),
+                Greater => ln_1p_finite(x, prec, rm),
+            },
+        }
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result to the
+    /// nearest value of the specified precision. The [`Float`] is taken by value. An [`Ordering`]
+    /// is also returned, indicating whether the rounded logarithm is less than, equal to, or
+    /// greater than the exact logarithm. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec(5);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec(20);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result to the
+    /// nearest value of the specified precision. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_ref(5);
+    /// assert_eq!(ln_1p.to_string(), "1.44");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_prec_ref(20);
+    /// assert_eq!(ln_1p.to_string(), "1.42108");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite, nonzero, and greater than -1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round(Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round(Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round(Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite, nonzero, and greater than -1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round_ref(Floor);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round_ref(Ceiling);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln_1p, o) = Float::from(PI).ln_1p_round_ref(Nearest);
+    /// assert_eq!(ln_1p.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`] in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite, nonzero, and greater
+    /// than -1, since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.38");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.421082");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.ln_1p_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`] in place, rounding the result to
+    /// the nearest value of the specified precision. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_1p_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of one more than a [`Float`] in place, rounding the result
+    /// with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite, nonzero, and greater than -1, since the
+    /// logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Rational`], returning a [`Float`] rounded to the
+    /// specified precision and with the specified rounding mode. The [`Rational`] is taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded logarithm is less
+    /// than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=-\infty$
+    /// - $f(1,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_rational_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `x` is positive and not equal to 1,
+    /// since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Floor);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Ceiling);
+    /// assert_eq!(ln.to_string(), "-0.39");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Nearest);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_round(Rational::ONE, 5, Exact);
+    /// assert_eq!(ln.to_string(), "0.0");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn ln_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if x == 0u32 {
+            (float_negative_infinity!(), Equal)
+        } else if x < 0u32 {
+            (float_nan!(), Equal)
+        } else if x == 1u32 {
+            (float_zero!(), Equal)
+        } else {
+            let exponent = x.floor_log_base_2_abs() + 1;
+            ln_rational_reduced(x >> exponent, exponent, prec, rm)
+        }
+    }
+
+    /// Computes the natural logarithm of a [`Rational`], returning a [`Float`] rounded to the
+    /// specified precision and with the specified rounding mode. The [`Rational`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded logarithm is
+    /// less than, equal to, or greater than the exact logarithm. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=-\infty$
+    /// - $f(1,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `x` is positive and not equal to 1,
+    /// since the logarithm is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) =
+    ///     Float::ln_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Floor);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) =
+    ///     Float::ln_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Ceiling);
+    /// assert_eq!(ln.to_string(), "-0.39");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (ln, o) =
+    ///     Float::ln_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Nearest);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_round_ref(&Rational::ONE, 5, Exact);
+    /// assert_eq!(ln.to_string(), "0.0");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn ln_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if *x == 0u32 {
+            (float_negative_infinity!(), Equal)
+        } else if *x < 0u32 {
+            (float_nan!(), Equal)
+        } else if *x == 1u32 {
+            (float_zero!(), Equal)
+        } else {
+            let exponent = x.floor_log_base_2_abs() + 1;
+            ln_rational_reduced(x >> exponent, exponent, prec, rm)
+        }
+    }
+
+    /// Computes the natural logarithm of a [`Rational`], returning a [`Float`] rounded to the
+    /// nearest value of the specified precision. The [`Rational`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_rational_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::ln_rational_prec(Rational::from_unsigneds(2u8, 3), 5);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec(Rational::from_unsigneds(2u8, 3), 20);
+    /// assert_eq!(ln.to_string(), "-0.4054651");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_rational_prec(x: Rational, prec: u64) -> (Float, Ordering) {
+        Float::ln_rational_prec_round(x, prec, Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Rational`], returning a [`Float`] rounded to the
+    /// nearest value of the specified precision. The [`Rational`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_rational_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_rational_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_ref(&Rational::from_unsigneds(2u8, 3), 5);
+    /// assert_eq!(ln.to_string(), "-0.41");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (ln, o) = Float::ln_rational_prec_ref(&Rational::from_unsigneds(2u8, 3), 20);
+    /// assert_eq!(ln.to_string(), "-0.4054651");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_rational_prec_ref(x: &Rational, prec: u64) -> (Float, Ordering) {
+        Float::ln_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Ln for Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=-\infty$
+    /// - $f(-0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::ln_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::ln_prec`]. If
+    /// you want both of these things, consider using [`Float::ln_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.ln().is_nan());
+    /// assert_eq!(Float::INFINITY.ln(), Float::INFINITY);
+    /// assert!(Float::NEGATIVE_INFINITY.ln().is_nan());
+    /// assert_eq!(Float::from(1.5).ln().to_string(), "0.40546510810816438");
+    /// assert!(Float::from(-1.5).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl<'a> Ln for &'a Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=-\infty$
+    /// - $f(-0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_round_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::ln_prec_ref`]. If you want both of these things, consider using
+    /// [`Float::ln_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).ln().is_nan());
+    /// assert_eq!((&Float::INFINITY).ln(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).ln().is_nan());
+    /// assert_eq!((&Float::from(1.5)).ln().to_string(), "0.40546510810816438");
+    /// assert!((&Float::from(-1.5)).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        self.ln_prec_round_ref(self.significant_bits(), Nearest).0
+    }
+}
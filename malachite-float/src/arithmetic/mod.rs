@@ -11,25 +11,54 @@ pub mod abs;
 /// Addition of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod add;
+/// Arcsines and arccosines of [`Float`](super::Float)s.
+pub mod asin;
+/// Arctangents of [`Float`](super::Float)s, and two-argument arctangents of pairs of
+/// [`Float`](super::Float)s.
+pub mod atan;
 /// Division of [`Float`](super::Float)s, of [`Float`](super::Float)s by
 /// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
 /// [`Float`](super::Float)s.
 pub mod div;
+pub(crate) mod enclosure;
+/// Exponentials of [`Float`](super::Float)s.
+pub mod exp;
 /// An implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Natural logarithms of [`Float`](super::Float)s, and of [`Rational`](malachite_q::Rational)s
+/// with [`Float`](super::Float) results.
+pub mod ln;
 /// Multiplication of [`Float`](super::Float)s, and of [`Float`](super::Float)s with
 /// [`Rational`](malachite_q::Rational)s.
 pub mod mul;
+/// Fused multiply-add of [`Float`](super::Float)s, with a single rounding.
+pub mod mul_add;
 /// Negation of [`Float`](super::Float)s.
 pub mod neg;
+/// Raising [`Float`](super::Float)s to integer and [`Float`](super::Float) powers, and
+/// implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
+/// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign).
+pub mod pow;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// Correctly rounded products of many [`Float`](super::Float)s, and implementations of
+/// [`Product`](core::iter::Product).
+pub mod product;
 /// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
 /// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
 /// computing the reciprocal of a number.
 pub mod reciprocal;
+/// Remainders of [`Float`](super::Float)s, with the quotient rounded either toward zero (as in C's
+/// `fmod`) or to the nearest integer (as in C's `remainder`).
+pub mod rem;
+/// Rounding [`Float`](super::Float)s to integers, including implementations of
+/// [`Floor`](malachite_base::num::arithmetic::traits::Floor) and
+/// [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling), and splitting
+/// [`Float`](super::Float)s into integer and fractional parts.
+pub mod round_to_integral;
+pub(crate) mod round_to_odd;
 /// Left-shifting a [`Float`](super::Float) (multiplying it by a power of 2).
 ///
 /// # shl
@@ -115,9 +144,19 @@ pub mod shr;
 /// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
 /// determining the sign of a number.
 pub mod sign;
+/// Sines and cosines of [`Float`](super::Float)s.
+pub mod sin_cos;
+/// Square roots of [`Float`](super::Float)s, and of [`Rational`](malachite_q::Rational)s with
+/// [`Float`](super::Float) results.
+pub mod sqrt;
 /// Squaring of [`Float`](super::Float)s.
 pub mod square;
+/// Correctly rounded sums and dot products of many [`Float`](super::Float)s, and implementations of
+/// [`Sum`](core::iter::Sum).
+pub mod sum;
 /// Subtraction of [`Float`](super::Float)s, of [`Float`](super::Float)s by
 /// [`Rational`](malachite_q::Rational)s, and of [`Rational`](malachite_q::Rational)s by
 /// [`Float`](super::Float)s.
 pub mod sub;
+/// Tangents of [`Float`](super::Float)s.
+pub mod tan;
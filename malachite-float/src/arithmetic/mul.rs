@@ -6,6 +6,7 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::arithmetic::round_to_odd::{round_via_odd, round_via_odd_assign};
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{float_either_infinity, float_either_zero, float_nan, Float};
 use core::cmp::{
@@ -783,6 +784,12 @@ impl Float {
         rm: RoundingMode,
     ) -> (Float, Ordering) {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| {
+                self.mul_prec_round_ref_ref(other, prec, Down)
+            });
+        }
         match (self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -1474,6 +1481,12 @@ impl Float {
     #[inline]
     pub fn mul_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.mul_prec_round_assign(other, prec, Down)
+            });
+        }
         match (&mut *self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -1636,6 +1649,12 @@ impl Float {
         rm: RoundingMode,
     ) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.mul_prec_round_assign_ref(other, prec, Down)
+            });
+        }
         match (&mut *self, other) {
             (float_nan!(), _)
             | (_, float_nan!())
@@ -0,0 +1,558 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::sum::ExactSum;
+use crate::Float;
+use core::cmp::max;
+use core::cmp::Ordering;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+impl Float {
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// value. The product and the sum are computed exactly and rounded only once. An [`Ordering`]
+    /// is also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,z,p,m)=f(\pm0.0,\pm\infty,z,p,m)=\text{NaN}$
+    /// - $f(x,y,z,p,m)=\text{NaN}$ if $xy$ and $z$ are infinities of opposite signs
+    /// - $f(x,y,z,p,m)=\infty$ if $xy$ or $z$ is $\infty$ and the other is not $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $xy$ or $z$ is $-\infty$ and the other is not $\infty$
+    /// - If $xy+z$ is zero, the signs of zero $xy$ and $z$ are treated as in addition: if $xy$ and
+    ///   $z$ are both $-0.0$, the result is $-0.0$; otherwise the result is 0.0, unless $m$ is
+    ///   `Floor`, in which case it is $-0.0$.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_add_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the three inputs,
+    /// consider using [`Float::mul_add_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// // 0.1 is not exactly representable, so 0.1 * 10 - 1 is not zero
+    /// let (r, o) =
+    ///     Float::from(0.1).mul_add_prec_round(Float::from(10), Float::from(-1), 10, Exact);
+    /// assert_eq!(r.to_string(), "5.55e-17");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (r, o) = Float::from(PI).mul_add_prec_round(Float::from(2), Float::from(1), 5, Floor);
+    /// assert_eq!(r.to_string(), "7.2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (r, o) =
+    ///     Float::from(PI).mul_add_prec_round(Float::from(2), Float::from(1), 5, Ceiling);
+    /// assert_eq!(r.to_string(), "7.5");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_round(
+        self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        sum.push_product(&self, y);
+        sum.push(z);
+        sum.round(prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// reference. The product and the sum are computed exactly and rounded only once. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal
+    /// to, or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r, o) =
+    ///     Float::from(PI).mul_add_prec_round_ref_ref_ref(&Float::from(2), &Float::ONE, 5, Floor);
+    /// assert_eq!(r.to_string(), "7.2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (r, o) = Float::from(PI).mul_add_prec_round_ref_ref_ref(
+    ///     &Float::from(2),
+    ///     &Float::ONE,
+    ///     5,
+    ///     Ceiling,
+    /// );
+    /// assert_eq!(r.to_string(), "7.5");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        sum.push_product_ref(self, y);
+        sum.push(z.clone());
+        sum.round(prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// nearest value of the specified precision. All three [`Float`]s are taken by value. The
+    /// product and the sum are computed exactly and rounded only once. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r, o) = Float::from(PI).mul_add_prec(Float::from(2), Float::from(1), 5);
+    /// assert_eq!(r.to_string(), "7.2");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec(self, y: Float, z: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_add_prec_round(y, z, prec, Nearest)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to the
+    /// nearest value of the specified precision. All three [`Float`]s are taken by reference. The
+    /// product and the sum are computed exactly and rounded only once. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r, o) = Float::from(PI).mul_add_prec_ref_ref_ref(&Float::from(2), &Float::ONE, 5);
+    /// assert_eq!(r.to_string(), "7.2");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_ref_ref_ref(&self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_add_prec_round_ref_ref_ref(y, z, prec, Nearest)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by value. The product and the sum
+    /// are computed exactly and rounded only once. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), d)`, where `d` is the difference between the
+    /// exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r, o) = Float::from(0.1).mul_add_round(Float::from(10), Float::from(-1), Nearest);
+    /// assert_eq!(r.to_string(), "5.551115123125783e-17");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn mul_add_round(self, y: Float, z: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.mul_add_prec_round(y, z, prec, rm)
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by reference. The product and the
+    /// sum are computed exactly and rounded only once. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), d)`, where `d` is the difference between the
+    /// exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r, o) =
+    ///     Float::from(0.1).mul_add_round_ref_ref_ref(&Float::from(10), &Float::from(-1), Nearest);
+    /// assert_eq!(r.to_string(), "5.551115123125783e-17");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn mul_add_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.mul_add_prec_round_ref_ref_ref(y, z, prec, rm)
+    }
+
+    /// Replaces $x$ with $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to
+    /// the specified precision and with the specified rounding mode. $y$ and $z$ are taken by
+    /// value. The product and the sum are computed exactly and rounded only once. An [`Ordering`]
+    /// is returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_add_prec_round_assign(Float::from(2), Float::from(1), 5, Floor),
+    ///     Less
+    /// );
+    /// assert_eq!(x.to_string(), "7.2");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_add_prec_round_assign(Float::from(2), Float::from(1), 5, Ceiling),
+    ///     Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.5");
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_round_assign(
+        &mut self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let mut sum = ExactSum::new();
+        sum.push_product(self, y);
+        sum.push(z);
+        let o;
+        (*self, o) = sum.round(prec, rm);
+        o
+    }
+
+    /// Replaces $x$ with $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result to
+    /// the nearest value of the specified precision. $y$ and $z$ are taken by value. The product
+    /// and the sum are computed exactly and rounded only once. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |xy+z|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_add_prec_assign(Float::from(2), Float::from(1), 5), Less);
+    /// assert_eq!(x.to_string(), "7.2");
+    /// ```
+    #[inline]
+    pub fn mul_add_prec_assign(&mut self, y: Float, z: Float, prec: u64) -> Ordering {
+        self.mul_add_prec_round_assign(y, z, prec, Nearest)
+    }
+
+    /// Replaces $x$ with $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result with
+    /// the specified rounding mode. $y$ and $z$ are taken by value. The product and the sum are
+    /// computed exactly and rounded only once. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precisions of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets xy+z+\varepsilon.
+    /// $$
+    /// - If $xy+z$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $xy+z$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |xy+z|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::mul_add_prec_round`] documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), d)`, where `d` is the difference between the
+    /// exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(0.1);
+    /// assert_eq!(
+    ///     x.mul_add_round_assign(Float::from(10), Float::from(-1), Nearest),
+    ///     Equal
+    /// );
+    /// assert_eq!(x.to_string(), "5.551115123125783e-17");
+    /// ```
+    #[inline]
+    pub fn mul_add_round_assign(&mut self, y: Float, z: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.mul_add_prec_round_assign(y, z, prec, rm)
+    }
+}
@@ -0,0 +1,3824 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{round_enclosed, round_irrational, round_near, Enclosure};
+use crate::arithmetic::exp::exp_bound;
+use crate::arithmetic::ln::ln_bound;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, Float};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{
+    Abs, CheckedSqrt, Parity, Pow, PowAssign, ReciprocalAssign,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Given a finite, nonzero `x`, returns the odd `Natural` $m$ and the integer $e$ such that $|x| =
+// m2^e$.
+fn odd_significand_and_exponent(x: &Float) -> (Natural, i64) {
+    let significand = x.significand_ref().unwrap();
+    let zeros = significand.trailing_zeros().unwrap();
+    (
+        significand >> zeros,
+        i64::from(x.get_exponent().unwrap()) - i64::exact_from(significand.significant_bits())
+            + i64::exact_from(zeros),
+    )
+}
+
+// Returns whether a finite, nonzero `x` is an odd integer.
+fn is_odd_integer(x: &Float) -> bool {
+    odd_significand_and_exponent(x).1 == 0
+}
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $x^n$,
+// where $x$ is positive and finite and $n$ is positive. The power is computed by repeated squaring;
+// since every intermediate value is positive, rounding each operation in the direction of `rm`
+// gives a bound in that direction.
+fn pow_natural_bound(x: &Float, n: &Natural, prec: u64, rm: RoundingMode) -> Float {
+    let bits = n.significant_bits();
+    let working_prec = prec + bits + 10;
+    let mut y = Float::from_float_prec_round_ref(x, working_prec, rm).0;
+    for i in (0..bits - 1).rev() {
+        y = y.square_prec_round(working_prec, rm).0;
+        if n.get_bit(i) {
+            y = y.mul_prec_round_val_ref(x, working_prec, rm).0;
+        }
+    }
+    y
+}
+
+// $|x|^n$, where $x$ is finite and nonzero and $n$ is nonzero.
+fn pow_integer_abs(x: &Float, n: &Integer, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (m, e) = odd_significand_and_exponent(x);
+    if m == 1u32 {
+        return (
+            Float::one_prec(prec) << i64::exact_from(&(Integer::from(e) * n)),
+            Equal,
+        );
+    }
+    // Since $m$ is odd and greater than 1, $m^{|n|}$ is odd and has more than $(b-1)|n|$ bits,
+    // where $b$ is the number of bits of $m$. If $(b-1)|n|$ is at least `prec + 1`, then either $n
+    // > 0$ and $|x|^n$ needs more than `prec + 1` bits, or $n < 0$ and $|x|^n$ is not a dyadic
+    // rational at all. Either way $|x|^n$ is neither a `Float` of precision `prec` nor a
+    // midpoint between two such `Float`s, so Ziv's strategy terminates. Otherwise, the power is
+    // small enough to compute exactly.
+    let n_abs = n.unsigned_abs_ref();
+    if Natural::from(m.significant_bits() - 1) * n_abs <= prec {
+        let n = i64::exact_from(n);
+        let mut q = Rational::from(m.pow(n.unsigned_abs()));
+        if n < 0 {
+            q.reciprocal_assign();
+        }
+        Float::from_rational_prec_round(q << (e * n), prec, rm)
+    } else {
+        let x = x.abs();
+        if *n > 0u32 {
+            round_irrational(prec, rm, |working_prec, rm| {
+                pow_natural_bound(&x, n_abs, working_prec, rm)
+            })
+        } else {
+            round_irrational(prec, rm, |working_prec, rm| {
+                pow_natural_bound(&x, n_abs, working_prec, -rm)
+                    .reciprocal_prec_round(working_prec, rm)
+                    .0
+            })
+        }
+    }
+}
+
+fn pow_integer_ref(x: &Float, n: &Integer, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if *n == 0u32 {
+        return (Float::one_prec(prec), Equal);
+    }
+    match x {
+        float_nan!() => (float_nan!(), Equal),
+        Float(Zero { sign }) => {
+            let sign = *sign || n.even();
+            if *n > 0u32 {
+                (Float(Zero { sign }), Equal)
+            } else {
+                (Float(Infinity { sign }), Equal)
+            }
+        }
+        Float(Infinity { sign }) => {
+            let sign = *sign || n.even();
+            if *n > 0u32 {
+                (Float(Infinity { sign }), Equal)
+            } else {
+                (Float(Zero { sign }), Equal)
+            }
+        }
+        _ => {
+            if *x < 0u32 && n.odd() {
+                let (y, o) = pow_integer_abs(x, n, prec, -rm);
+                (-y, o.reverse())
+            } else {
+                pow_integer_abs(x, n, prec, rm)
+            }
+        }
+    }
+}
+
+// If $x$ is the $2^k$th power of some `Float`, returns that `Float`. $x$ must be positive, finite,
+// and not 1. Each successful square root halves the size of the significand or of the exponent,
+// so the loop finishes quickly even if $k$ is large.
+fn exact_power_of_2_root(x: &Float, k: u64) -> Option<Float> {
+    let (mut m, mut e) = odd_significand_and_exponent(x);
+    for _ in 0..k {
+        if e.odd() {
+            return None;
+        }
+        m = m.checked_sqrt()?;
+        e >>= 1;
+    }
+    Some(Float::from(m) << e)
+}
+
+// Returns an enclosure of $y\log x$, where $x$ is positive, finite, and not 1, and $y$ is finite
+// and nonzero.
+fn mul_ln_enclosure(x: &Float, y: &Enclosure, prec: u64) -> Enclosure {
+    Enclosure {
+        lo: ln_bound(x, prec, Floor),
+        hi: ln_bound(x, prec, Ceiling),
+    }
+    .mul(y, prec)
+}
+
+// $x^y$, where $x$ is positive, finite, and not 1, and $y$ is finite and not an integer.
+fn pow_non_integer(x: &Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    // Write $y = a/2^k$, with $a$ odd and $k > 0$. If $x$ is the $2^k$th power of some $r$, then
+    // $x^y = r^a$. Otherwise, $x^y$ is irrational: if it were a rational $q$, then, choosing $u$
+    // and $v$ with $ua + v2^k = 1$, $x^{1/2^k} = q^ux^v$ would be rational too.
+    let y_positive = y > 0u32;
+    let (a, e) = odd_significand_and_exponent(&y);
+    if let Some(r) = exact_power_of_2_root(x, e.unsigned_abs()) {
+        return pow_integer_ref(&r, &Integer::from_sign_and_abs(y_positive, a), prec, rm);
+    }
+    let y = Enclosure::exact(y);
+    let t_exponent = i64::from(
+        mul_ln_enclosure(x, &y, 64)
+            .magnitude()
+            .get_exponent()
+            .unwrap(),
+    );
+    // If $|y\log x| < 2^{-p-2}$, then $x^y$ lies strictly between 1 and its neighbor at precision
+    // $p+2$ in the direction of $y\log x$.
+    if t_exponent <= -i64::exact_from(prec) - 2 {
+        return round_near(
+            &Float::ONE,
+            if y_positive == (*x > 1u32) {
+                Greater
+            } else {
+                Less
+            },
+            prec,
+            rm,
+        );
+    }
+    // The absolute error of $y\log x$ becomes the relative error of $x^y$.
+    let extra = u64::exact_from(max(t_exponent, 0));
+    round_enclosed(prec, rm, |working_prec| {
+        let working_prec = working_prec + extra;
+        let t = mul_ln_enclosure(x, &y, working_prec);
+        Some(Enclosure {
+            lo: exp_bound(&t.lo, working_prec, Floor),
+            hi: exp_bound(&t.hi, working_prec, Ceiling),
+        })
+    })
+}
+
+// $x^y$, if $y$ is zero, infinite, or `NaN`, or if $x$ is 1, zero, infinite, or `NaN`. Returns
+// `None` otherwise.
+fn pow_special(x: &Float, y: &Float, prec: u64) -> Option<(Float, Ordering)> {
+    assert_ne!(prec, 0);
+    Some(match (x, y) {
+        (_, float_either_zero!()) => (Float::one_prec(prec), Equal),
+        (x, _) if *x == 1u32 => (Float::one_prec(prec), Equal),
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Equal),
+        (x, Float(Infinity { sign })) => match x.partial_cmp_abs(&1u32).unwrap() {
+            Equal => (Float::one_prec(prec), Equal),
+            Less => (
+                if *sign {
+                    Float(Zero { sign: true })
+                } else {
+                    Float(Infinity { sign: true })
+                },
+                Equal,
+            ),
+            Greater => (
+                if *sign {
+                    Float(Infinity { sign: true })
+                } else {
+                    Float(Zero { sign: true })
+                },
+                Equal,
+            ),
+        },
+        (Float(Zero { sign }), y) => {
+            let sign = *sign || !is_odd_integer(y);
+            if *y > 0u32 {
+                (Float(Zero { sign }), Equal)
+            } else {
+                (Float(Infinity { sign }), Equal)
+            }
+        }
+        (Float(Infinity { sign }), y) => {
+            let sign = *sign || !is_odd_integer(y);
+            if *y > 0u32 {
+                (Float(Infinity { sign }), Equal)
+            } else {
+                (Float(Zero { sign }), Equal)
+            }
+        }
+        _ => return None,
+    })
+}
+
+fn pow_ref_val(x: &Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if let Some(result) = pow_special(x, &y, prec) {
+        result
+    } else if y.is_integer() {
+        pow_integer_ref(x, &Integer::exact_from(y), prec, rm)
+    } else if *x < 0u32 {
+        (float_nan!(), Equal)
+    } else {
+        pow_non_integer(x, y, prec, rm)
+    }
+}
+
+fn pow_ref_ref(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if let Some(result) = pow_special(x, y, prec) {
+        result
+    } else if y.is_integer() {
+        pow_integer_ref(x, &Integer::exact_from(y), prec, rm)
+    } else if *x < 0u32 {
+        (float_nan!(), Equal)
+    } else {
+        pow_non_integer(x, y.clone(), prec, rm)
+    }
+}
+
+impl Float {
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p,m)=1.0$, even if $x$ is `NaN`
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$ if $n>0$
+    /// - $f(\infty,n,p,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n>0$ and $n$ is odd
+    /// - $f(-\infty,n,p,m)=\infty$ if $n>0$ and $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n>0$ and $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n>0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_u64_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::pow_u64_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 5, Floor);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "32.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 5, Nearest);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 20, Floor);
+    /// assert_eq!(pow.to_string(), "31.00626");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round(3, 20, Nearest);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec_round(self, exp: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        pow_integer_ref(&self, &Integer::from(exp), prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_u64_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::pow_u64_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 5, Floor);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "32.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 5, Nearest);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 20, Floor);
+    /// assert_eq!(pow.to_string(), "31.00626");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_round_ref(3, 20, Nearest);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec_round_ref(
+        &self,
+        exp: u64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        pow_integer_ref(self, &Integer::from(exp), prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec(3, 5);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec(3, 20);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec(self, exp: u64, prec: u64) -> (Float, Ordering) {
+        self.pow_u64_prec_round(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_ref(3, 5);
+    /// assert_eq!(pow.to_string(), "31.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_prec_ref(3, 20);
+    /// assert_eq!(pow.to_string(), "31.00629");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec_ref(&self, exp: u64, prec: u64) -> (Float, Ordering) {
+        self.pow_u64_prec_round_ref(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded power is less than, equal to, or greater than the exact power. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_u64_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round(3, Floor);
+    /// assert_eq!(pow.to_string(), "31.006276680299816");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round(3, Ceiling);
+    /// assert_eq!(pow.to_string(), "31.00627668029982");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round(3, Nearest);
+    /// assert_eq!(pow.to_string(), "31.006276680299816");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_u64_round(self, exp: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power, rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded power is less than, equal to, or greater than the exact power. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_u64_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round_ref(3, Floor);
+    /// assert_eq!(pow.to_string(), "31.006276680299816");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round_ref(3, Ceiling);
+    /// assert_eq!(pow.to_string(), "31.00627668029982");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_u64_round_ref(3, Nearest);
+    /// assert_eq!(pow.to_string(), "31.006276680299816");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_u64_round_ref(&self, exp: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round_ref(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_u64_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::pow_u64_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "31.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "32.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "31.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "31.00626");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "31.00629");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_round_assign(3, 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "31.00629");
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec_round_assign(&mut self, exp: u64, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = pow_integer_ref(self, &Integer::from(exp), prec, rm);
+        o
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded power
+    /// is less than, equal to, or greater than the exact power. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_assign(3, 5), Less);
+    /// assert_eq!(x.to_string(), "31.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_prec_assign(3, 20), Greater);
+    /// assert_eq!(x.to_string(), "31.00629");
+    /// ```
+    #[inline]
+    pub fn pow_u64_prec_assign(&mut self, exp: u64, prec: u64) -> Ordering {
+        self.pow_u64_prec_round_assign(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`u64`] power in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::pow_u64_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_round_assign(3, Floor), Less);
+    /// assert_eq!(x.to_string(), "31.006276680299816");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_round_assign(3, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "31.00627668029982");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_u64_round_assign(3, Nearest), Less);
+    /// assert_eq!(x.to_string(), "31.006276680299816");
+    /// ```
+    #[inline]
+    pub fn pow_u64_round_assign(&mut self, exp: u64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round_assign(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p,m)=1.0$, even if $x$ is `NaN`
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$ if $n\neq0$
+    /// - $f(\infty,n,p,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n>0$ and $n$ is odd
+    /// - $f(-\infty,n,p,m)=\infty$ if $n>0$ and $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n>0$ and $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n>0$ and $n$ is even
+    /// - $f(\infty,n,p,m)=0.0$ if $n<0$
+    /// - $f(-\infty,n,p,m)=-0.0$ if $n<0$ and $n$ is odd
+    /// - $f(-\infty,n,p,m)=0.0$ if $n<0$ and $n$ is even
+    /// - $f(0.0,n,p,m)=\infty$ if $n<0$
+    /// - $f(-0.0,n,p,m)=-\infty$ if $n<0$ and $n$ is odd
+    /// - $f(-0.0,n,p,m)=\infty$ if $n<0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_i64_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::pow_i64_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 5, Floor);
+    /// assert_eq!(pow.to_string(), "0.031");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 5, Nearest);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 20, Floor);
+    /// assert_eq!(pow.to_string(), "0.03225148");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round(-3, 20, Nearest);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec_round(self, exp: i64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        pow_integer_ref(&self, &Integer::from(exp), prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_i64_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::pow_i64_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 5, Floor);
+    /// assert_eq!(pow.to_string(), "0.031");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 5, Nearest);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 20, Floor);
+    /// assert_eq!(pow.to_string(), "0.03225148");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_round_ref(-3, 20, Nearest);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec_round_ref(
+        &self,
+        exp: i64,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        pow_integer_ref(self, &Integer::from(exp), prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec(-3, 5);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec(-3, 20);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec(self, exp: i64, prec: u64) -> (Float, Ordering) {
+        self.pow_i64_prec_round(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_ref(-3, 5);
+    /// assert_eq!(pow.to_string(), "0.033");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_prec_ref(-3, 20);
+    /// assert_eq!(pow.to_string(), "0.03225154");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec_ref(&self, exp: i64, prec: u64) -> (Float, Ordering) {
+        self.pow_i64_prec_round_ref(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded power is less than, equal to, or greater than the exact power. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_i64_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round(-3, Floor);
+    /// assert_eq!(pow.to_string(), "0.03225153443319949");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round(-3, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.032251534433199495");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round(-3, Nearest);
+    /// assert_eq!(pow.to_string(), "0.032251534433199495");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_round(self, exp: i64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power, rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded power is less than, equal to, or greater than the exact power. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_i64_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round_ref(-3, Floor);
+    /// assert_eq!(pow.to_string(), "0.03225153443319949");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round_ref(-3, Ceiling);
+    /// assert_eq!(pow.to_string(), "0.032251534433199495");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_i64_round_ref(-3, Nearest);
+    /// assert_eq!(pow.to_string(), "0.032251534433199495");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_i64_round_ref(&self, exp: i64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round_ref(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_i64_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::pow_i64_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.031");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.033");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.033");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.03225148");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.03225154");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_round_assign(-3, 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.03225154");
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec_round_assign(&mut self, exp: i64, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = pow_integer_ref(self, &Integer::from(exp), prec, rm);
+        o
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded power
+    /// is less than, equal to, or greater than the exact power. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_assign(-3, 5), Greater);
+    /// assert_eq!(x.to_string(), "0.033");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_prec_assign(-3, 20), Greater);
+    /// assert_eq!(x.to_string(), "0.03225154");
+    /// ```
+    #[inline]
+    pub fn pow_i64_prec_assign(&mut self, exp: i64, prec: u64) -> Ordering {
+        self.pow_i64_prec_round_assign(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`i64`] power in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::pow_i64_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_round_assign(-3, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.03225153443319949");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_round_assign(-3, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.032251534433199495");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_i64_round_assign(-3, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "0.032251534433199495");
+    /// ```
+    #[inline]
+    pub fn pow_i64_round_assign(&mut self, exp: i64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round_assign(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value and the [`Integer`] by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(x,0,p,m)=1.0$, even if $x$ is `NaN`
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$ if $n\neq0$
+    /// - $f(\infty,n,p,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n>0$ and $n$ is odd
+    /// - $f(-\infty,n,p,m)=\infty$ if $n>0$ and $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n>0$ and $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n>0$ and $n$ is even
+    /// - $f(\infty,n,p,m)=0.0$ if $n<0$
+    /// - $f(-\infty,n,p,m)=-0.0$ if $n<0$ and $n$ is odd
+    /// - $f(-\infty,n,p,m)=0.0$ if $n<0$ and $n$ is even
+    /// - $f(0.0,n,p,m)=\infty$ if $n<0$
+    /// - $f(-0.0,n,p,m)=-\infty$ if $n<0$ and $n$ is odd
+    /// - $f(-0.0,n,p,m)=\infty$ if $n<0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_integer_prec`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::pow_integer_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 5, Floor);
+    /// assert_eq!(pow.to_string(), "9.0e4");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 5, Nearest);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 20, Floor);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "93648.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round(&Integer::from(10), 20, Nearest);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec_round(
+        self,
+        exp: &Integer,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        pow_integer_ref(&self, exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] and the [`Integer`] are both taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_integer_prec_ref`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::pow_integer_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 5, Floor);
+    /// assert_eq!(pow.to_string(), "9.0e4");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 5, Nearest);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 20, Floor);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "93648.1");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_round_ref(&Integer::from(10), 20, Nearest);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec_round_ref(
+        &self,
+        exp: &Integer,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        pow_integer_ref(self, exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value and the [`Integer`] by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded power is less than, equal to,
+    /// or greater than the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec(&Integer::from(10), 5);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec(&Integer::from(10), 20);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec(self, exp: &Integer, prec: u64) -> (Float, Ordering) {
+        self.pow_integer_prec_round(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] and the [`Integer`] are both taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded power is less than, equal to,
+    /// or greater than the exact power. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_ref(&Integer::from(10), 5);
+    /// assert_eq!(pow.to_string(), "9.4e4");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_prec_ref(&Integer::from(10), 20);
+    /// assert_eq!(pow.to_string(), "93648.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec_ref(&self, exp: &Integer, prec: u64) -> (Float, Ordering) {
+        self.pow_integer_prec_round_ref(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value and the [`Integer`] by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_integer_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round(&Integer::from(10), Floor);
+    /// assert_eq!(pow.to_string(), "93648.04747608298");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round(&Integer::from(10), Ceiling);
+    /// assert_eq!(pow.to_string(), "93648.047476083");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round(&Integer::from(10), Nearest);
+    /// assert_eq!(pow.to_string(), "93648.04747608298");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_round(self, exp: &Integer, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_integer_prec_round(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power, rounding the result with the specified rounding
+    /// mode. The [`Float`] and the [`Integer`] are both taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::pow_integer_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round_ref(&Integer::from(10), Floor);
+    /// assert_eq!(pow.to_string(), "93648.04747608298");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round_ref(&Integer::from(10), Ceiling);
+    /// assert_eq!(pow.to_string(), "93648.047476083");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_integer_round_ref(&Integer::from(10), Nearest);
+    /// assert_eq!(pow.to_string(), "93648.04747608298");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pow_integer_round_ref(&self, exp: &Integer, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.pow_integer_prec_round_ref(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Integer`] is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded power is less than, equal to, or
+    /// greater than the exact power. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_integer_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::pow_integer_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "9.0e4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "9.4e4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "9.4e4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "93648.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "93648.1");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_round_assign(&Integer::from(10), 20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "93648.0");
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec_round_assign(
+        &mut self,
+        exp: &Integer,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = pow_integer_ref(self, exp, prec, rm);
+        o
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power in place, rounding the result to the nearest value
+    /// of the specified precision. The [`Integer`] is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(), prec)`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_assign(&Integer::from(10), 5), Greater);
+    /// assert_eq!(x.to_string(), "9.4e4");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_prec_assign(&Integer::from(10), 20), Less);
+    /// assert_eq!(x.to_string(), "93648.0");
+    /// ```
+    #[inline]
+    pub fn pow_integer_prec_assign(&mut self, exp: &Integer, prec: u64) -> Ordering {
+        self.pow_integer_prec_round_assign(exp, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Integer`] power in place, rounding the result with the specified
+    /// rounding mode. The [`Integer`] is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^n$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^n|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::pow_integer_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is too small for an exact result.
+    /// Also panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_round_assign(&Integer::from(10), Floor), Less);
+    /// assert_eq!(x.to_string(), "93648.04747608298");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_round_assign(&Integer::from(10), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "93648.047476083");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_integer_round_assign(&Integer::from(10), Nearest), Less);
+    /// assert_eq!(x.to_string(), "93648.04747608298");
+    /// ```
+    #[inline]
+    pub fn pow_integer_round_assign(&mut self, exp: &Integer, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.pow_integer_prec_round_assign(exp, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The special cases follow the C99 `pow` function:
+    /// - $f(x,\pm0.0,p,m)=1.0$, even if $x$ is `NaN`
+    /// - $f(1.0,y,p,m)=1.0$, even if $y$ is `NaN`
+    /// - $f(\text{NaN},y,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$ otherwise
+    /// - $f(-1.0,\pm\infty,p,m)=1.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $|x|<1$, and $\infty$ if $|x|>1$
+    /// - $f(x,-\infty,p,m)=\infty$ if $|x|<1$, and $0.0$ if $|x|>1$
+    /// - $f(\pm0.0,y,p,m)=\pm0.0$ if $y>0$ is an odd integer, and $0.0$ for any other $y>0$
+    /// - $f(\pm0.0,y,p,m)=\pm\infty$ if $y<0$ is an odd integer, and $\infty$ for any other $y<0$
+    /// - $f(\infty,y,p,m)=\infty$ if $y>0$, and $0.0$ if $y<0$
+    /// - $f(-\infty,y,p,m)=-\infty$ if $y>0$ is an odd integer, and $\infty$ for any other $y>0$
+    /// - $f(-\infty,y,p,m)=-0.0$ if $y<0$ is an odd integer, and $0.0$ for any other $y<0$
+    /// - $f(x,y,p,m)=\text{NaN}$ if $x<0$ is finite and $y$ is finite and not an integer
+    ///
+    /// When $y$ is an integer, the result is the same as that of [`Float::pow_integer_prec_round`].
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::pow_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result;
+    /// in particular, if $x^y$ is irrational. Also panics if the exponent of the result is too
+    /// large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 5, Floor);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 5, Nearest);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 20, Floor);
+    /// assert_eq!(pow.to_string(), "22.45914");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round(Float::from(E), 20, Nearest);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        pow_ref_val(&self, other, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result to the specified precision and
+    /// with the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::pow_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result;
+    /// in particular, if $x^y$ is irrational. Also panics if the exponent of the result is too
+    /// large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 5, Floor);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 5, Ceiling);
+    /// assert_eq!(pow.to_string(), "23.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 5, Nearest);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 20, Floor);
+    /// assert_eq!(pow.to_string(), "22.45914");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 20, Ceiling);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_round_ref_ref(&Float::from(E), 20, Nearest);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        pow_ref_ref(self, other, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec(Float::from(E), 5);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec(Float::from(E), 20);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.pow_prec_round(other, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result to the nearest value of the
+    /// specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(pow.to_string(), "22.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(pow.to_string(), "22.45917");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.pow_prec_round_ref_ref(other, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result; in particular, if $x^y$ is irrational. Also panics if the exponent of the result is
+    /// too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round(Float::from(E), Floor);
+    /// assert_eq!(pow.to_string(), "22.459157718361038");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round(Float::from(E), Ceiling);
+    /// assert_eq!(pow.to_string(), "22.459157718361041");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round(Float::from(E), Nearest);
+    /// assert_eq!(pow.to_string(), "22.459157718361041");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.pow_prec_round(other, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power, rounding the result with the specified rounding
+    /// mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_prec_round_ref_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result; in particular, if $x^y$ is irrational. Also panics if the exponent of the result is
+    /// too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round_ref_ref(&Float::from(E), Floor);
+    /// assert_eq!(pow.to_string(), "22.459157718361038");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round_ref_ref(&Float::from(E), Ceiling);
+    /// assert_eq!(pow.to_string(), "22.459157718361041");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pow, o) = Float::from(PI).pow_round_ref_ref(&Float::from(E), Nearest);
+    /// assert_eq!(pow.to_string(), "22.459157718361041");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn pow_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.pow_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::pow_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result;
+    /// in particular, if $x^y$ is irrational. Also panics if the exponent of the result is too
+    /// large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.45914");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign(Float::from(E), 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = pow_ref_val(self, other, prec, rm);
+        o
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded power is
+    /// less than, equal to, or greater than the exact power. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pow_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::pow_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact result;
+    /// in particular, if $x^y$ is irrational. Also panics if the exponent of the result is too
+    /// large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 5, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 20, Floor), Less);
+    /// assert_eq!(x.to_string(), "22.45914");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_round_assign_ref(&Float::from(E), 20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    /// ```
+    #[inline]
+    pub fn pow_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let o;
+        (*self, o) = pow_ref_ref(self, other, prec, rm);
+        o
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded power is less than, equal to, or
+    /// greater than the exact power. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_assign(Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_assign(Float::from(E), 20), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    /// ```
+    #[inline]
+    pub fn pow_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.pow_prec_round_assign(other, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded power is less than, equal to, or
+    /// greater than the exact power. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large or too small to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_assign_ref(&Float::from(E), 5), Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_prec_assign_ref(&Float::from(E), 20), Greater);
+    /// assert_eq!(x.to_string(), "22.45917");
+    /// ```
+    #[inline]
+    pub fn pow_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.pow_prec_round_assign_ref(other, prec, Nearest)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::pow_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result; in particular, if $x^y$ is irrational. Also panics if the exponent of the result is
+    /// too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign(Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "22.459157718361038");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign(Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign(Float::from(E), Nearest), Greater);
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    pub fn pow_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.pow_prec_round_assign(other, prec, rm)
+    }
+
+    /// Raises a [`Float`] to a [`Float`] power in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`]
+    /// is returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x^y$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::pow_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// result; in particular, if $x^y$ is irrational. Also panics if the exponent of the result is
+    /// too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign_ref(&Float::from(E), Floor), Less);
+    /// assert_eq!(x.to_string(), "22.459157718361038");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign_ref(&Float::from(E), Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.pow_round_assign_ref(&Float::from(E), Nearest), Greater);
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    pub fn pow_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.pow_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Pow<u64> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`u64`] power, taking the [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_u64_prec`]. If you want both of these things, consider using
+    /// [`Float::pow_u64_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.pow(0u64).to_string(), "1.0");
+    /// assert!(Float::NAN.pow(3u64).is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.pow(3u64), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::NEGATIVE_ZERO.pow(3u64), Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::from(1.5).pow(3u64).to_string(), "3.375");
+    /// assert_eq!(Float::from(PI).pow(3u64).to_string(), "31.006276680299816");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> Float {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round(exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<u64> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`u64`] power, taking the [`Float`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_round_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_u64_prec_ref`]. If you want both of these things, consider using
+    /// [`Float::pow_u64_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::NAN).pow(0u64).to_string(), "1.0");
+    /// assert!((&Float::NAN).pow(3u64).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).pow(3u64), Float::NEGATIVE_INFINITY);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).pow(3u64), Float::NEGATIVE_ZERO);
+    /// assert_eq!((&Float::from(1.5)).pow(3u64).to_string(), "3.375");
+    /// assert_eq!((&Float::from(PI)).pow(3u64).to_string(), "31.006276680299816");
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> Float {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round_ref(exp, prec, Nearest).0
+    }
+}
+
+impl Pow<i64> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`i64`] power, taking the [`Float`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_i64_prec`]. If you want both of these things, consider using
+    /// [`Float::pow_i64_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.pow(0i64).to_string(), "1.0");
+    /// assert!(Float::NAN.pow(-3i64).is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.pow(-3i64), Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::NEGATIVE_ZERO.pow(-3i64), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(1.5).pow(-3i64).to_string(), "0.29629629629629628");
+    /// assert_eq!(Float::from(PI).pow(-3i64).to_string(), "0.032251534433199495");
+    /// ```
+    #[inline]
+    fn pow(self, exp: i64) -> Float {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round(exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<i64> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`i64`] power, taking the [`Float`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_round_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_i64_prec_ref`]. If you want both of these things, consider using
+    /// [`Float::pow_i64_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::NAN).pow(0i64).to_string(), "1.0");
+    /// assert!((&Float::NAN).pow(-3i64).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).pow(-3i64), Float::NEGATIVE_ZERO);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).pow(-3i64), Float::NEGATIVE_INFINITY);
+    /// assert_eq!((&Float::from(1.5)).pow(-3i64).to_string(), "0.29629629629629628");
+    /// assert_eq!((&Float::from(PI)).pow(-3i64).to_string(), "0.032251534433199495");
+    /// ```
+    #[inline]
+    fn pow(self, exp: i64) -> Float {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round_ref(exp, prec, Nearest).0
+    }
+}
+
+impl Pow<Integer> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to an [`Integer`] power, taking the [`Float`] by value and the
+    /// [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_integer_prec`]. If you want both of these things, consider using
+    /// [`Float::pow_integer_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Float::NAN.pow(Integer::from(0)).to_string(), "1.0");
+    /// assert!(Float::NAN.pow(Integer::from(-3)).is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.pow(Integer::from(-3)), Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::NEGATIVE_ZERO.pow(Integer::from(-4)), Float::INFINITY);
+    /// assert_eq!(Float::from(1.5).pow(Integer::from(10)).to_string(), "57.6650390625");
+    /// assert_eq!(Float::from(PI).pow(Integer::from(-10)).to_string(), "0.000010678279226861537");
+    /// ```
+    #[inline]
+    fn pow(self, exp: Integer) -> Float {
+        let prec = self.significant_bits();
+        pow_integer_ref(&self, &exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<&'a Integer> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to an [`Integer`] power, taking the [`Float`] by value and the
+    /// [`Integer`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_integer_prec`]. If you want both of these things, consider using
+    /// [`Float::pow_integer_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Float::NAN.pow(&Integer::from(0)).to_string(), "1.0");
+    /// assert!(Float::NAN.pow(&Integer::from(-3)).is_nan());
+    /// assert_eq!(Float::NEGATIVE_INFINITY.pow(&Integer::from(-3)), Float::NEGATIVE_ZERO);
+    /// assert_eq!(Float::NEGATIVE_ZERO.pow(&Integer::from(-4)), Float::INFINITY);
+    /// assert_eq!(Float::from(1.5).pow(&Integer::from(10)).to_string(), "57.6650390625");
+    /// assert_eq!(Float::from(PI).pow(&Integer::from(-10)).to_string(), "0.000010678279226861537");
+    /// ```
+    #[inline]
+    fn pow(self, exp: &'a Integer) -> Float {
+        let prec = self.significant_bits();
+        pow_integer_ref(&self, exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<Integer> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to an [`Integer`] power, taking the [`Float`] by reference and the
+    /// [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_integer_prec_ref`]. If you want both of these things, consider
+    /// using [`Float::pow_integer_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Float::NAN).pow(Integer::from(0)).to_string(), "1.0");
+    /// assert!((&Float::NAN).pow(Integer::from(-3)).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).pow(Integer::from(-3)), Float::NEGATIVE_ZERO);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).pow(Integer::from(-4)), Float::INFINITY);
+    /// assert_eq!((&Float::from(1.5)).pow(Integer::from(10)).to_string(), "57.6650390625");
+    /// assert_eq!(
+    ///     (&Float::from(PI)).pow(Integer::from(-10)).to_string(),
+    ///     "0.000010678279226861537"
+    /// );
+    /// ```
+    #[inline]
+    fn pow(self, exp: Integer) -> Float {
+        let prec = self.significant_bits();
+        pow_integer_ref(self, &exp, prec, Nearest).0
+    }
+}
+
+impl<'a, 'b> Pow<&'b Integer> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to an [`Integer`] power, taking the [`Float`] by reference and the
+    /// [`Integer`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_integer_prec_ref`]. If you want both of these things, consider
+    /// using [`Float::pow_integer_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero};
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Float::NAN).pow(&Integer::from(0)).to_string(), "1.0");
+    /// assert!((&Float::NAN).pow(&Integer::from(-3)).is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).pow(&Integer::from(-3)), Float::NEGATIVE_ZERO);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).pow(&Integer::from(-4)), Float::INFINITY);
+    /// assert_eq!((&Float::from(1.5)).pow(&Integer::from(10)).to_string(), "57.6650390625");
+    /// assert_eq!(
+    ///     (&Float::from(PI)).pow(&Integer::from(-10)).to_string(),
+    ///     "0.000010678279226861537"
+    /// );
+    /// ```
+    #[inline]
+    fn pow(self, exp: &'b Integer) -> Float {
+        let prec = self.significant_bits();
+        pow_integer_ref(self, exp, prec, Nearest).0
+    }
+}
+
+impl Pow<Float> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`Float`] power. Both [`Float`]s are taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::pow_round`]
+    /// instead. If you want to specify the output precision, consider using [`Float::pow_prec`]. If
+    /// you want both of these things, consider using [`Float::pow_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.pow(Float::ZERO).to_string(), "1.0");
+    /// assert_eq!(Float::ONE.pow(Float::NAN).to_string(), "1.0");
+    /// assert_eq!(Float::NEGATIVE_ONE.pow(Float::INFINITY).to_string(), "1.0");
+    /// assert_eq!(Float::ZERO.pow(Float::from(-0.5)), Float::INFINITY);
+    /// assert!(Float::from(-8).pow(Float::from(1.5)).is_nan());
+    /// assert_eq!(Float::from(-2).pow(Float::from(-3)).to_string(), "-0.1");
+    /// assert_eq!(Float::from(PI).pow(Float::from(E)).to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow(self, exp: Float) -> Float {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        pow_ref_val(&self, exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<&'a Float> for Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`Float`] power. The first [`Float`] is taken by value and the
+    /// second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_round_ref_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Float::pow_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::NAN.pow(&Float::ZERO).to_string(), "1.0");
+    /// assert_eq!(Float::ONE.pow(&Float::NAN).to_string(), "1.0");
+    /// assert_eq!(Float::NEGATIVE_ONE.pow(&Float::INFINITY).to_string(), "1.0");
+    /// assert_eq!(Float::ZERO.pow(&Float::from(-0.5)), Float::INFINITY);
+    /// assert!(Float::from(-8).pow(&Float::from(1.5)).is_nan());
+    /// assert_eq!(Float::from(-2).pow(&Float::from(-3)).to_string(), "-0.1");
+    /// assert_eq!(Float::from(PI).pow(&Float::from(E)).to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow(self, exp: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        pow_ref_ref(&self, exp, prec, Nearest).0
+    }
+}
+
+impl<'a> Pow<Float> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`Float`] power. The first [`Float`] is taken by reference and the
+    /// second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_round_ref_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Float::pow_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::NAN).pow(Float::ZERO).to_string(), "1.0");
+    /// assert_eq!((&Float::ONE).pow(Float::NAN).to_string(), "1.0");
+    /// assert_eq!((&Float::NEGATIVE_ONE).pow(Float::INFINITY).to_string(), "1.0");
+    /// assert_eq!((&Float::ZERO).pow(Float::from(-0.5)), Float::INFINITY);
+    /// assert!((&Float::from(-8)).pow(Float::from(1.5)).is_nan());
+    /// assert_eq!((&Float::from(-2)).pow(Float::from(-3)).to_string(), "-0.1");
+    /// assert_eq!((&Float::from(PI)).pow(Float::from(E)).to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow(self, exp: Float) -> Float {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        pow_ref_val(self, exp, prec, Nearest).0
+    }
+}
+
+impl<'a, 'b> Pow<&'b Float> for &'a Float {
+    type Output = Float;
+
+    /// Raises a [`Float`] to a [`Float`] power. Both [`Float`]s are taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_round_ref_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_prec_ref_ref`]. If you want both of these things, consider using
+    /// [`Float::pow_prec_round_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeOne, One, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::NAN).pow(&Float::ZERO).to_string(), "1.0");
+    /// assert_eq!((&Float::ONE).pow(&Float::NAN).to_string(), "1.0");
+    /// assert_eq!((&Float::NEGATIVE_ONE).pow(&Float::INFINITY).to_string(), "1.0");
+    /// assert_eq!((&Float::ZERO).pow(&Float::from(-0.5)), Float::INFINITY);
+    /// assert!((&Float::from(-8)).pow(&Float::from(1.5)).is_nan());
+    /// assert_eq!((&Float::from(-2)).pow(&Float::from(-3)).to_string(), "-0.1");
+    /// assert_eq!((&Float::from(PI)).pow(&Float::from(E)).to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow(self, exp: &'b Float) -> Float {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        pow_ref_ref(self, exp, prec, Nearest).0
+    }
+}
+
+impl PowAssign<u64> for Float {
+    /// Raises a [`Float`] to a [`u64`] power in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_u64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_u64_round_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_u64_prec_assign`]. If you want both of these things, consider
+    /// using [`Float::pow_u64_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(3u64);
+    /// assert_eq!(x.to_string(), "31.006276680299816");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: u64) {
+        let prec = self.significant_bits();
+        self.pow_u64_prec_round_assign(exp, prec, Nearest);
+    }
+}
+
+impl PowAssign<i64> for Float {
+    /// Raises a [`Float`] to a [`i64`] power in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_i64_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_i64_round_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_i64_prec_assign`]. If you want both of these things, consider
+    /// using [`Float::pow_i64_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(-3i64);
+    /// assert_eq!(x.to_string(), "0.032251534433199495");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: i64) {
+        let prec = self.significant_bits();
+        self.pow_i64_prec_round_assign(exp, prec, Nearest);
+    }
+}
+
+impl PowAssign<Integer> for Float {
+    /// Raises a [`Float`] to an [`Integer`] power in place, taking the [`Integer`] by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_integer_prec_assign`]. If you want both of these things,
+    /// consider using [`Float::pow_integer_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(Integer::from(10));
+    /// assert_eq!(x.to_string(), "93648.04747608298");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: Integer) {
+        let prec = self.significant_bits();
+        self.pow_integer_prec_round_assign(&exp, prec, Nearest);
+    }
+}
+
+impl<'a> PowAssign<&'a Integer> for Float {
+    /// Raises a [`Float`] to an [`Integer`] power in place, taking the [`Integer`] by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^n+\varepsilon.
+    /// $$
+    /// - If $x^n$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^n$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^n|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::pow_integer_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_integer_round_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_integer_prec_assign`]. If you want both of these things,
+    /// consider using [`Float::pow_integer_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $m$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(&Integer::from(10));
+    /// assert_eq!(x.to_string(), "93648.04747608298");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: &'a Integer) {
+        let prec = self.significant_bits();
+        self.pow_integer_prec_round_assign(exp, prec, Nearest);
+    }
+}
+
+impl PowAssign<Float> for Float {
+    /// Raises a [`Float`] to a [`Float`] power in place, taking the [`Float`] on the right-hand
+    /// side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_round_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::pow_prec_assign`]. If you want both of these things, consider using
+    /// [`Float::pow_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(Float::from(E));
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: Float) {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        self.pow_prec_round_assign(exp, prec, Nearest);
+    }
+}
+
+impl<'a> PowAssign<&'a Float> for Float {
+    /// Raises a [`Float`] to a [`Float`] power in place, taking the [`Float`] on the right-hand
+    /// side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// power is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x\gets x^y+\varepsilon.
+    /// $$
+    /// - If $x^y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`Float::pow_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pow_round_assign_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::pow_prec_assign_ref`]. If you want both of these things, consider
+    /// using [`Float::pow_prec_round_assign_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// exp.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::{E, PI};
+    /// use malachite_base::num::arithmetic::traits::PowAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(PI);
+    /// x.pow_assign(&Float::from(E));
+    /// assert_eq!(x.to_string(), "22.459157718361041");
+    /// ```
+    #[inline]
+    fn pow_assign(&mut self, exp: &'a Float) {
+        let prec = max(self.significant_bits(), exp.significant_bits());
+        self.pow_prec_round_assign_ref(exp, prec, Nearest);
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{significand_bits, Float};
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use core::iter::Product;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, NegativeZero, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+
+// Accumulates the exact product of `Float`s, so that it can be rounded once at the end. The
+// product of the finite, nonzero values is $(-1)^s 2^e \prod_i m_i$, where $s$ is `negative`, $e$
+// is `exponent`, and the $m_i$ are the `significands`.
+struct ExactProduct {
+    significands: Vec<Natural>,
+    exponent: i64,
+    negative: bool,
+    nan: bool,
+    infinity: bool,
+    zero: bool,
+}
+
+impl ExactProduct {
+    const fn new() -> ExactProduct {
+        ExactProduct {
+            significands: Vec::new(),
+            exponent: 0,
+            negative: false,
+            nan: false,
+            infinity: false,
+            zero: false,
+        }
+    }
+
+    fn push(&mut self, x: Float) {
+        match x {
+            Float(NaN) => self.nan = true,
+            Float(Infinity { sign }) => {
+                self.infinity = true;
+                self.negative ^= !sign;
+            }
+            Float(Zero { sign }) => {
+                self.zero = true;
+                self.negative ^= !sign;
+            }
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => {
+                self.exponent +=
+                    i64::from(exponent) - i64::exact_from(significand_bits(&significand));
+                self.negative ^= !sign;
+                self.significands.push(significand);
+            }
+        }
+    }
+
+    fn round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if self.nan || self.infinity && self.zero {
+            (Float::NAN, Equal)
+        } else if self.infinity {
+            if self.negative {
+                (Float::NEGATIVE_INFINITY, Equal)
+            } else {
+                (Float::INFINITY, Equal)
+            }
+        } else if self.zero {
+            if self.negative {
+                (Float::NEGATIVE_ZERO, Equal)
+            } else {
+                (Float::ZERO, Equal)
+            }
+        } else if self.negative {
+            let (product, o) = Float::from_natural_prec_round(
+                Natural::product(self.significands.into_iter()),
+                prec,
+                -rm,
+            );
+            (-(product << self.exponent), o.reverse())
+        } else {
+            let (product, o) = Float::from_natural_prec_round(
+                Natural::product(self.significands.into_iter()),
+                prec,
+                rm,
+            );
+            (product << self.exponent, o)
+        }
+    }
+}
+
+impl Float {
+    /// Multiplies together the [`Float`]s produced by an iterator, rounding the result to the
+    /// specified precision and with the specified rounding mode. The result is rounded only once,
+    /// so it is the exact product, correctly rounded. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \prod_i x_i+\varepsilon.
+    /// $$
+    /// - If $\prod_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\prod_i x_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\prod_i x_i|\rfloor-p+1}$.
+    /// - If $\prod_i x_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\prod_i x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If any $x_i$ is `NaN`, or if both an infinity and a zero occur, the result is `NaN`.
+    /// - Otherwise, if an infinity or a zero occurs, the result is infinite or zero, respectively.
+    ///   Its sign is negative if and only if an odd number of the $x_i$ are negative (counting
+    ///   $-0.0$ and $-\infty$ as negative).
+    /// - The product of no values is 1.0.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::product_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the inputs,
+    /// consider using [`Float::product_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec` and the
+    /// precisions of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact
+    /// product.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(1.5), Float::from(-3), Float::from(0.1)];
+    /// let (product, o) = Float::product_prec_round(xs.clone(), 10, Floor);
+    /// assert_eq!(product.to_string(), "-0.4502");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (product, o) = Float::product_prec_round(xs.clone(), 10, Ceiling);
+    /// assert_eq!(product.to_string(), "-0.4497");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (product, o) = Float::product_prec_round(xs, 100, Exact);
+    /// assert_eq!(product.to_string(), "-0.450000000000000024980018054066");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (product, o) = Float::product_prec_round([], 10, Ceiling);
+    /// assert_eq!(product, Float::ONE);
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn product_prec_round<I: IntoIterator<Item = Float>>(
+        xs: I,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut product = ExactProduct::new();
+        for x in xs {
+            product.push(x);
+        }
+        product.round(prec, rm)
+    }
+
+    /// Multiplies together the [`Float`]s produced by an iterator, rounding the result to the
+    /// nearest value of the specified precision. The result is rounded only once, so it is the
+    /// exact product, correctly rounded. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \prod_i x_i+\varepsilon.
+    /// $$
+    /// - If $\prod_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\prod_i x_i$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\prod_i
+    ///   x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::product_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec` and the
+    /// precisions of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(1.5), Float::from(-3), Float::from(0.1)];
+    /// let (product, o) = Float::product_prec(xs.clone(), 10);
+    /// assert_eq!(product.to_string(), "-0.4502");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (product, o) = Float::product_prec(xs, 20);
+    /// assert_eq!(product.to_string(), "-0.4499998");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn product_prec<I: IntoIterator<Item = Float>>(xs: I, prec: u64) -> (Float, Ordering) {
+        Float::product_prec_round(xs, prec, Nearest)
+    }
+
+    /// Multiplies together the [`Float`]s produced by an iterator, rounding the result with the
+    /// specified rounding mode. The precision of the result is the maximum of the precisions of
+    /// the inputs. The result is rounded only once, so it is the exact product, correctly
+    /// rounded. An [`Ordering`] is also returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \prod_i x_i+\varepsilon.
+    /// $$
+    /// - If $\prod_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\prod_i x_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\prod_i x_i|\rfloor-p+1}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    /// - If $\prod_i x_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\prod_i x_i|\rfloor-p}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See [`Float::product_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// product.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(1.5), Float::from(-3), Float::from(0.1)];
+    /// let (product, o) = Float::product_round(xs.clone(), Floor);
+    /// assert_eq!(product.to_string(), "-0.45000000000000007");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (product, o) = Float::product_round(xs, Ceiling);
+    /// assert_eq!(product.to_string(), "-0.45000000000000001");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn product_round<I: IntoIterator<Item = Float>>(
+        xs: I,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut product = ExactProduct::new();
+        let mut prec = 1;
+        for x in xs {
+            prec = max(prec, x.significant_bits());
+            product.push(x);
+        }
+        product.round(prec, rm)
+    }
+}
+
+impl Product for Float {
+    /// Multiplies together all the [`Float`]s in an iterator. The result is the exact product,
+    /// rounded once to the nearest value whose precision is the maximum of the precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i+\varepsilon.
+    /// $$
+    ///
+    /// See [`Float::product_round`] for details, and [`Float::product_prec_round`] for the special
+    /// cases. The product of no values is 1.0, with precision 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::iter::Product;
+    ///
+    /// let xs = [Float::from(1.5), Float::from(-3), Float::from(0.1)];
+    /// assert_eq!(Float::product(xs.into_iter()).to_string(), "-0.45000000000000001");
+    /// ```
+    fn product<I>(xs: I) -> Float
+    where
+        I: Iterator<Item = Float>,
+    {
+        Float::product_round(xs, Nearest).0
+    }
+}
+
+impl<'a> Product<&'a Float> for Float {
+    /// Multiplies together all the [`Float`]s in an iterator of [`Float`] references. The result
+    /// is the exact product, rounded once to the nearest value whose precision is the maximum of
+    /// the precisions of the inputs.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i+\varepsilon.
+    /// $$
+    ///
+    /// See [`Float::product_round`] for details, and [`Float::product_prec_round`] for the special
+    /// cases. The product of no values is 1.0, with precision 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::iter::Product;
+    ///
+    /// let xs = [Float::from(1.5), Float::from(-3), Float::from(0.1)];
+    /// assert_eq!(Float::product(xs.iter()).to_string(), "-0.45000000000000001");
+    /// ```
+    fn product<I>(xs: I) -> Float
+    where
+        I: Iterator<Item = &'a Float>,
+    {
+        Float::product_round(xs.cloned(), Nearest).0
+    }
+}
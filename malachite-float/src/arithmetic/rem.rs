@@ -0,0 +1,591 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_either_infinity, float_either_zero, significand_bits, Float};
+use core::cmp::max;
+use core::cmp::Ordering::*;
+use core::ops::{Rem, RemAssign};
+use malachite_base::num::arithmetic::traits::ModPow;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+// Computes x - n * y exactly, where n is x / y rounded to an integer: toward zero if `nearest` is
+// false, and to the nearest integer, with ties broken toward the even integer, if `nearest` is
+// true.
+//
+// The remainder is a multiple of the smaller of the two ulps, and its absolute value is at most the
+// absolute value of x and less than the absolute value of y, so it is representable with the
+// larger of the two precisions. If y is infinite, the remainder is x, with its own precision.
+fn rem_helper(x: &Float, y: Float, nearest: bool) -> Float {
+    match (x, y) {
+        (Float(NaN | Infinity { .. }), _) | (_, Float(NaN | Zero { .. })) => Float(NaN),
+        (float_either_zero!(), _) | (_, float_either_infinity!()) => x.clone(),
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exp,
+                precision: x_prec,
+                significand: x_significand,
+            }),
+            Float(Finite {
+                exponent: y_exp,
+                precision: y_prec,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let prec = max(*x_prec, y_prec);
+            // If |x| < |y|, the truncated quotient is 0; if |x| < |y| / 2, so is the rounded
+            // quotient. Either way, the remainder is x.
+            if *x_exp < y_exp - i32::from(nearest) {
+                return Float::from_float_prec_ref(x, prec).0;
+            }
+            // x = x_significand * 2^x_scale and y = y_significand * 2^y_scale
+            let x_scale = i64::from(*x_exp) - i64::exact_from(significand_bits(x_significand));
+            let y_scale = i64::from(y_exp) - i64::exact_from(significand_bits(&y_significand));
+            // Working in units of 2^scale, compute |x| mod 2|y|. This determines both |x| mod |y|
+            // and the parity of the truncated quotient.
+            let (r, y_units, scale) = if x_scale >= y_scale {
+                let double_y = &y_significand << 1u32;
+                let power = Natural::from(2u32)
+                    .mod_pow(Natural::from(u64::exact_from(x_scale - y_scale)), &double_y);
+                let r = (x_significand % &double_y) * power % &double_y;
+                (r, y_significand, y_scale)
+            } else {
+                // Since |x| >= |y| / 2, the shift is small
+                let y_units = y_significand << u64::exact_from(y_scale - x_scale);
+                let r = x_significand % (&y_units << 1u32);
+                (r, y_units, x_scale)
+            };
+            let odd_quotient = r >= y_units;
+            let mut r = if odd_quotient { r - &y_units } else { r };
+            let mut sign = *x_sign;
+            if nearest {
+                let half_comparison = (&r << 1u32).cmp(&y_units);
+                if half_comparison == Greater || half_comparison == Equal && odd_quotient {
+                    r = y_units - r;
+                    sign = !sign;
+                }
+            }
+            if r == 0u32 {
+                return Float(Zero { sign: *x_sign });
+            }
+            let (abs_rem, o) = Float::from_natural_prec(r, prec);
+            debug_assert_eq!(o, Equal);
+            let abs_rem = abs_rem << scale;
+            if sign {
+                abs_rem
+            } else {
+                -abs_rem
+            }
+        }
+    }
+}
+
+impl Rem<Float> for Float {
+    type Output = Float;
+
+    /// Finds the remainder of a [`Float`] divided by another, where the quotient is rounded toward
+    /// zero. Both [`Float`]s are taken by value. This is analogous to C's `fmod` and to the `%`
+    /// operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(x,\pm\infty)=x$ if $x$ is finite
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is not NaN or zero
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((Float::from(7.5) % Float::from(2)).to_string(), "1.5");
+    /// assert_eq!((Float::from(-7.5) % Float::from(2)).to_string(), "-1.5");
+    /// assert_eq!((Float::from(7.5) % Float::from(-2)).to_string(), "1.5");
+    /// assert_eq!((Float::from(-6) % Float::from(2)).to_string(), "-0.0");
+    /// assert_eq!((Float::from(7.5) % Float::INFINITY).to_string(), "7.5");
+    /// assert_eq!((Float::from(7.5) % Float::ZERO).to_string(), "NaN");
+    /// ```
+    #[inline]
+    fn rem(self, other: Float) -> Float {
+        rem_helper(&self, other, false)
+    }
+}
+
+impl<'a> Rem<&'a Float> for Float {
+    type Output = Float;
+
+    /// Finds the remainder of a [`Float`] divided by another, where the quotient is rounded toward
+    /// zero. The first [`Float`] is taken by value and the second by reference. This is analogous
+    /// to C's `fmod` and to the `%` operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(x,\pm\infty)=x$ if $x$ is finite
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is not NaN or zero
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((Float::from(7.5) % &Float::from(2)).to_string(), "1.5");
+    /// assert_eq!((Float::from(-7.5) % &Float::from(2)).to_string(), "-1.5");
+    /// assert_eq!((Float::from(7.5) % &Float::from(-2)).to_string(), "1.5");
+    /// assert_eq!((Float::from(-6) % &Float::from(2)).to_string(), "-0.0");
+    /// assert_eq!((Float::from(7.5) % &Float::INFINITY).to_string(), "7.5");
+    /// assert_eq!((Float::from(7.5) % &Float::ZERO).to_string(), "NaN");
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Float) -> Float {
+        rem_helper(&self, other.clone(), false)
+    }
+}
+
+impl<'a> Rem<Float> for &'a Float {
+    type Output = Float;
+
+    /// Finds the remainder of a [`Float`] divided by another, where the quotient is rounded toward
+    /// zero. The first [`Float`] is taken by reference and the second by value. This is analogous
+    /// to C's `fmod` and to the `%` operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(x,\pm\infty)=x$ if $x$ is finite
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is not NaN or zero
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(7.5) % Float::from(2)).to_string(), "1.5");
+    /// assert_eq!((&Float::from(-7.5) % Float::from(2)).to_string(), "-1.5");
+    /// assert_eq!((&Float::from(7.5) % Float::from(-2)).to_string(), "1.5");
+    /// assert_eq!((&Float::from(-6) % Float::from(2)).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(7.5) % Float::INFINITY).to_string(), "7.5");
+    /// assert_eq!((&Float::from(7.5) % Float::ZERO).to_string(), "NaN");
+    /// ```
+    #[inline]
+    fn rem(self, other: Float) -> Float {
+        rem_helper(self, other, false)
+    }
+}
+
+impl<'a, 'b> Rem<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Finds the remainder of a [`Float`] divided by another, where the quotient is rounded toward
+    /// zero. Both [`Float`]s are taken by reference. This is analogous to C's `fmod` and to the `%`
+    /// operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(x,\pm\infty)=x$ if $x$ is finite
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is not NaN or zero
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(7.5) % &Float::from(2)).to_string(), "1.5");
+    /// assert_eq!((&Float::from(-7.5) % &Float::from(2)).to_string(), "-1.5");
+    /// assert_eq!((&Float::from(7.5) % &Float::from(-2)).to_string(), "1.5");
+    /// assert_eq!((&Float::from(-6) % &Float::from(2)).to_string(), "-0.0");
+    /// assert_eq!((&Float::from(7.5) % &Float::INFINITY).to_string(), "7.5");
+    /// assert_eq!((&Float::from(7.5) % &Float::ZERO).to_string(), "NaN");
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Float) -> Float {
+        rem_helper(self, other.clone(), false)
+    }
+}
+
+impl RemAssign<Float> for Float {
+    /// Replaces a [`Float`] with its remainder when divided by another, where the quotient is
+    /// rounded toward zero. The [`Float`] on the right-hand side is taken by value. This is
+    /// analogous to C's `fmod` and to the `%=` operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// See the `%` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-7.5);
+    /// x %= Float::from(2);
+    /// assert_eq!(x.to_string(), "-1.5");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Float) {
+        *self = rem_helper(self, other, false);
+    }
+}
+
+impl<'a> RemAssign<&'a Float> for Float {
+    /// Replaces a [`Float`] with its remainder when divided by another, where the quotient is
+    /// rounded toward zero. The [`Float`] on the right-hand side is taken by reference. This is
+    /// analogous to C's `fmod` and to the `%=` operator on primitive floats.
+    ///
+    /// The remainder is computed exactly. It has the same sign as the dividend, even if it is zero,
+    /// and its absolute value is less than the absolute value of the divisor. If the remainder is
+    /// nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(x/y) \left \lfloor \left | \frac{x}{y} \right | \right
+    /// \rfloor.
+    /// $$
+    ///
+    /// See the `%` documentation for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-7.5);
+    /// x %= &Float::from(2);
+    /// assert_eq!(x.to_string(), "-1.5");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &'a Float) {
+        *self = rem_helper(self, other.clone(), false);
+    }
+}
+
+impl Float {
+    /// Finds the IEEE 754 remainder of a [`Float`] divided by another, where the quotient is
+    /// rounded to the nearest integer, with ties broken toward the even integer. Both [`Float`]s
+    /// are taken by value. This is analogous to C's `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(x,\pm\infty)=x$ if $x$ is finite
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is not NaN or zero
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(7.5).remainder(Float::from(2)).to_string(), "-0.5");
+    /// assert_eq!(Float::from(-7.5).remainder(Float::from(2)).to_string(), "0.5");
+    /// assert_eq!(Float::from(5).remainder(Float::from(2)).to_string(), "1.0");
+    /// assert_eq!(Float::from(7).remainder(Float::from(2)).to_string(), "-1.0");
+    /// assert_eq!(Float::from(-6).remainder(Float::from(2)).to_string(), "-0.0");
+    /// assert_eq!(Float::from(7.5).remainder(Float::INFINITY).to_string(), "7.5");
+    /// assert_eq!(Float::from(7.5).remainder(Float::ZERO).to_string(), "NaN");
+    /// ```
+    #[inline]
+    pub fn remainder(self, other: Float) -> Float {
+        rem_helper(&self, other, true)
+    }
+
+    /// Finds the IEEE 754 remainder of a [`Float`] divided by another, where the quotient is
+    /// rounded to the nearest integer, with ties broken toward the even integer. The first
+    /// [`Float`] is taken by value and the second by reference. This is analogous to C's
+    /// `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// See [`Float::remainder`] for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(7.5).remainder_val_ref(&Float::from(2)).to_string(), "-0.5");
+    /// assert_eq!(Float::from(5).remainder_val_ref(&Float::from(2)).to_string(), "1.0");
+    /// ```
+    #[inline]
+    pub fn remainder_val_ref(self, other: &Float) -> Float {
+        rem_helper(&self, other.clone(), true)
+    }
+
+    /// Finds the IEEE 754 remainder of a [`Float`] divided by another, where the quotient is
+    /// rounded to the nearest integer, with ties broken toward the even integer. The first
+    /// [`Float`] is taken by reference and the second by value. This is analogous to C's
+    /// `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// See [`Float::remainder`] for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(7.5).remainder_ref_val(Float::from(2)).to_string(), "-0.5");
+    /// assert_eq!(Float::from(5).remainder_ref_val(Float::from(2)).to_string(), "1.0");
+    /// ```
+    #[inline]
+    pub fn remainder_ref_val(&self, other: Float) -> Float {
+        rem_helper(self, other, true)
+    }
+
+    /// Finds the IEEE 754 remainder of a [`Float`] divided by another, where the quotient is
+    /// rounded to the nearest integer, with ties broken toward the even integer. Both [`Float`]s
+    /// are taken by reference. This is analogous to C's `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// f(x, y) = x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// See [`Float::remainder`] for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(7.5).remainder_ref_ref(&Float::from(2)).to_string(), "-0.5");
+    /// assert_eq!(Float::from(5).remainder_ref_ref(&Float::from(2)).to_string(), "1.0");
+    /// ```
+    #[inline]
+    pub fn remainder_ref_ref(&self, other: &Float) -> Float {
+        rem_helper(self, other.clone(), true)
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder when divided by another, where the
+    /// quotient is rounded to the nearest integer, with ties broken toward the even integer. The
+    /// [`Float`] on the right-hand side is taken by value. This is analogous to C's `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// x \gets x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// See [`Float::remainder`] for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(7.5);
+    /// x.remainder_assign(Float::from(2));
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// ```
+    #[inline]
+    pub fn remainder_assign(&mut self, other: Float) {
+        *self = rem_helper(self, other, true);
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder when divided by another, where the
+    /// quotient is rounded to the nearest integer, with ties broken toward the even integer. The
+    /// [`Float`] on the right-hand side is taken by reference. This is analogous to C's
+    /// `remainder`.
+    ///
+    /// The remainder is computed exactly, and its absolute value is at most half the absolute
+    /// value of the divisor. A zero remainder has the same sign as the dividend. If the remainder
+    /// is nonzero and finite, its precision is the maximum of the precisions of the inputs; if the
+    /// divisor is infinite, the remainder is the dividend, with its original precision.
+    ///
+    /// $$
+    /// x \gets x - ny,
+    /// $$
+    /// where $n$ is the integer nearest to $x/y$, choosing the even integer in case of a tie.
+    ///
+    /// See [`Float::remainder`] for information on special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n \log n \log\log n \log m)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`, and $m$ is `max(2, self.get_exponent() - other.get_exponent())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(7.5);
+    /// x.remainder_assign_ref(&Float::from(2));
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// ```
+    #[inline]
+    pub fn remainder_assign_ref(&mut self, other: &Float) {
+        *self = rem_helper(self, other.clone(), true);
+    }
+}
@@ -0,0 +1,751 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Ceiling, CeilingAssign, Floor, FloorAssign};
+use malachite_base::num::conversion::traits::{IsInteger, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+
+impl Float {
+    /// Rounds a [`Float`] to an integer, using the specified rounding mode. The [`Float`] is taken
+    /// by value. An [`Ordering`] is also returned, indicating whether the rounded value is less
+    /// than, equal to, or greater than the original value.
+    ///
+    /// The result has the same precision as the input, and this precision is always large enough
+    /// to represent the result exactly, so the only rounding that takes place is the rounding to an
+    /// integer. `NaN`, infinities, and zeros are returned unchanged, along with `Equal`. If the
+    /// result is zero, it has the same sign as the input.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes. `Nearest` rounds
+    /// ties to the even integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `self` is not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral(Floor);
+    /// assert_eq!(x.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral(Ceiling);
+    /// assert_eq!(x.to_string(), "3.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral(Nearest);
+    /// assert_eq!(x.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(-2.5).round_to_integral(NearestTiesAway);
+    /// assert_eq!(x.to_string(), "-3.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(-0.25).round_to_integral(Down);
+    /// assert_eq!(x.to_string(), "-0.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from(3).round_to_integral(Exact);
+    /// assert_eq!(x.to_string(), "3.0");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn round_to_integral(self, rm: RoundingMode) -> (Float, Ordering) {
+        if self.is_finite() && !self.is_integer() {
+            self.round_to_integral_ref(rm)
+        } else {
+            (self, Equal)
+        }
+    }
+
+    /// Rounds a [`Float`] to an integer, using the specified rounding mode. The [`Float`] is taken
+    /// by reference. An [`Ordering`] is also returned, indicating whether the rounded value is less
+    /// than, equal to, or greater than the original value.
+    ///
+    /// The result has the same precision as the input, and this precision is always large enough
+    /// to represent the result exactly, so the only rounding that takes place is the rounding to an
+    /// integer. `NaN`, infinities, and zeros are returned unchanged, along with `Equal`. If the
+    /// result is zero, it has the same sign as the input.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes. `Nearest` rounds
+    /// ties to the even integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `self` is not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral_ref(Floor);
+    /// assert_eq!(x.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral_ref(Ceiling);
+    /// assert_eq!(x.to_string(), "3.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from(2.5).round_to_integral_ref(Nearest);
+    /// assert_eq!(x.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(-2.5).round_to_integral_ref(NearestTiesAway);
+    /// assert_eq!(x.to_string(), "-3.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from(-0.25).round_to_integral_ref(Down);
+    /// assert_eq!(x.to_string(), "-0.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from(3).round_to_integral_ref(Exact);
+    /// assert_eq!(x.to_string(), "3.0");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn round_to_integral_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        match self {
+            Float(Finite {
+                sign, precision, ..
+            }) if !self.is_integer() => {
+                assert_ne!(rm, Exact, "Inexact rounding of {self} to an integer");
+                let (i, o) = Integer::rounding_from(self, rm);
+                if i == 0u32 {
+                    (Float(Zero { sign: *sign }), o)
+                } else {
+                    let (rounded, o_alt) = Float::from_integer_prec(i, *precision);
+                    debug_assert_eq!(o_alt, Equal);
+                    (rounded, o)
+                }
+            }
+            _ => (self.clone(), Equal),
+        }
+    }
+
+    /// Rounds a [`Float`] to an integer in place, using the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded value is less than, equal to, or
+    /// greater than the original value.
+    ///
+    /// The precision of the [`Float`] does not change, and it is always large enough to represent
+    /// the result exactly, so the only rounding that takes place is the rounding to an integer.
+    /// `NaN`, infinities, and zeros are left unchanged, and `Equal` is returned. If the result is
+    /// zero, it has the same sign as the original value.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes. `Nearest` rounds
+    /// ties to the even integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `self` is not an integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(2.5);
+    /// assert_eq!(x.round_to_integral_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "2.0");
+    ///
+    /// let mut x = Float::from(2.5);
+    /// assert_eq!(x.round_to_integral_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "3.0");
+    ///
+    /// let mut x = Float::from(-0.25);
+    /// assert_eq!(x.round_to_integral_assign(Down), Greater);
+    /// assert_eq!(x.to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn round_to_integral_assign(&mut self, rm: RoundingMode) -> Ordering {
+        if self.is_finite() && !self.is_integer() {
+            let o;
+            (*self, o) = self.round_to_integral_ref(rm);
+            o
+        } else {
+            Equal
+        }
+    }
+
+    /// Rounds a [`Float`] toward zero, taking it by value.
+    ///
+    /// $$
+    /// f(x) = \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).trunc().to_string(), "2.0");
+    /// assert_eq!(Float::from(-2.5).trunc().to_string(), "-2.0");
+    /// assert_eq!(Float::from(-0.5).trunc().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn trunc(self) -> Float {
+        self.round_to_integral(Down).0
+    }
+
+    /// Rounds a [`Float`] toward zero, taking it by reference.
+    ///
+    /// $$
+    /// f(x) = \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).trunc_ref().to_string(), "2.0");
+    /// assert_eq!(Float::from(-2.5).trunc_ref().to_string(), "-2.0");
+    /// assert_eq!(Float::from(-0.5).trunc_ref().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn trunc_ref(&self) -> Float {
+        self.round_to_integral_ref(Down).0
+    }
+
+    /// Rounds a [`Float`] toward zero in place.
+    ///
+    /// $$
+    /// x \gets \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The precision does not change, and the result is exact. A zero result has the same sign as
+    /// the original value. `NaN` and infinities are left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x.trunc_assign();
+    /// assert_eq!(x.to_string(), "-2.0");
+    /// ```
+    #[inline]
+    pub fn trunc_assign(&mut self) {
+        self.round_to_integral_assign(Down);
+    }
+
+    /// Rounds a [`Float`] to the nearest integer, breaking ties by choosing the even integer. The
+    /// [`Float`] is taken by value.
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).round_even().to_string(), "2.0");
+    /// assert_eq!(Float::from(3.5).round_even().to_string(), "4.0");
+    /// assert_eq!(Float::from(-2.75).round_even().to_string(), "-3.0");
+    /// assert_eq!(Float::from(-0.5).round_even().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn round_even(self) -> Float {
+        self.round_to_integral(Nearest).0
+    }
+
+    /// Rounds a [`Float`] to the nearest integer, breaking ties by choosing the even integer. The
+    /// [`Float`] is taken by reference.
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).round_even_ref().to_string(), "2.0");
+    /// assert_eq!(Float::from(3.5).round_even_ref().to_string(), "4.0");
+    /// assert_eq!(Float::from(-2.75).round_even_ref().to_string(), "-3.0");
+    /// assert_eq!(Float::from(-0.5).round_even_ref().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn round_even_ref(&self) -> Float {
+        self.round_to_integral_ref(Nearest).0
+    }
+
+    /// Rounds a [`Float`] to the nearest integer in place, breaking ties by choosing the even
+    /// integer.
+    ///
+    /// The precision does not change, and the result is exact. A zero result has the same sign as
+    /// the original value. `NaN` and infinities are left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(3.5);
+    /// x.round_even_assign();
+    /// assert_eq!(x.to_string(), "4.0");
+    /// ```
+    #[inline]
+    pub fn round_even_assign(&mut self) {
+        self.round_to_integral_assign(Nearest);
+    }
+
+    /// Returns the fractional part of a [`Float`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = x - \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. It has the same sign as the
+    /// input, even if it is zero. The fractional part of an infinity is a zero of the same sign,
+    /// and the fractional part of `NaN` is `NaN`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NegativeInfinity;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.75).frac().to_string(), "0.75");
+    /// assert_eq!(Float::from(-2.75).frac().to_string(), "-0.75");
+    /// assert_eq!(Float::from(-3).frac().to_string(), "-0.0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.frac().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn frac(self) -> Float {
+        match self {
+            Float(Finite { exponent, .. }) if exponent <= 0 => self,
+            _ => self.frac_ref(),
+        }
+    }
+
+    /// Returns the fractional part of a [`Float`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = x - \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. It has the same sign as the
+    /// input, even if it is zero. The fractional part of an infinity is a zero of the same sign,
+    /// and the fractional part of `NaN` is `NaN`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NegativeInfinity;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.75).frac_ref().to_string(), "0.75");
+    /// assert_eq!(Float::from(-2.75).frac_ref().to_string(), "-0.75");
+    /// assert_eq!(Float::from(-3).frac_ref().to_string(), "-0.0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.frac_ref().to_string(), "-0.0");
+    /// ```
+    pub fn frac_ref(&self) -> Float {
+        match self {
+            float_nan!() => Float(NaN),
+            Float(Infinity { sign }) => Float(Zero { sign: *sign }),
+            Float(Finite {
+                sign,
+                exponent,
+                precision,
+                ..
+            }) => {
+                if *exponent <= 0 {
+                    self.clone()
+                } else if self.is_integer() {
+                    Float(Zero { sign: *sign })
+                } else {
+                    let (frac, o) = self.sub_prec_ref_ref(&self.trunc_ref(), *precision);
+                    debug_assert_eq!(o, Equal);
+                    frac
+                }
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Replaces a [`Float`] with its fractional part.
+    ///
+    /// $$
+    /// x \gets x - \operatorname{sgn}(x) \lfloor |x| \rfloor.
+    /// $$
+    ///
+    /// The precision does not change, and the result is exact. It has the same sign as the
+    /// original value, even if it is zero. The fractional part of an infinity is a zero of the
+    /// same sign, and the fractional part of `NaN` is `NaN`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-2.75);
+    /// x.frac_assign();
+    /// assert_eq!(x.to_string(), "-0.75");
+    /// ```
+    #[inline]
+    pub fn frac_assign(&mut self) {
+        if !matches!(self, Float(Finite { exponent, .. }) if *exponent <= 0) {
+            *self = self.frac_ref();
+        }
+    }
+
+    /// Splits a [`Float`] into its integer and fractional parts, taking it by value. This is
+    /// analogous to C's `modf`.
+    ///
+    /// The integer part is the [`Float`] rounded toward zero, and the fractional part is the
+    /// difference between the [`Float`] and its integer part. Both parts have the same precision
+    /// and sign as the input, and both are exact. An infinity is split into itself and a zero of
+    /// the same sign, and `NaN` is split into two `NaN`s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NegativeInfinity;
+    /// use malachite_float::Float;
+    ///
+    /// let (i, f) = Float::from(-2.75).modf();
+    /// assert_eq!(i.to_string(), "-2.0");
+    /// assert_eq!(f.to_string(), "-0.75");
+    ///
+    /// let (i, f) = Float::NEGATIVE_INFINITY.modf();
+    /// assert_eq!(i.to_string(), "-Infinity");
+    /// assert_eq!(f.to_string(), "-0.0");
+    /// ```
+    #[inline]
+    pub fn modf(self) -> (Float, Float) {
+        let frac = self.frac_ref();
+        (self.trunc(), frac)
+    }
+
+    /// Splits a [`Float`] into its integer and fractional parts, taking it by reference. This is
+    /// analogous to C's `modf`.
+    ///
+    /// The integer part is the [`Float`] rounded toward zero, and the fractional part is the
+    /// difference between the [`Float`] and its integer part. Both parts have the same precision
+    /// and sign as the input, and both are exact. An infinity is split into itself and a zero of
+    /// the same sign, and `NaN` is split into two `NaN`s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NegativeInfinity;
+    /// use malachite_float::Float;
+    ///
+    /// let (i, f) = Float::from(-2.75).modf_ref();
+    /// assert_eq!(i.to_string(), "-2.0");
+    /// assert_eq!(f.to_string(), "-0.75");
+    ///
+    /// let (i, f) = Float::NEGATIVE_INFINITY.modf_ref();
+    /// assert_eq!(i.to_string(), "-Infinity");
+    /// assert_eq!(f.to_string(), "-0.0");
+    /// ```
+    pub fn modf_ref(&self) -> (Float, Float) {
+        (self.trunc_ref(), self.frac_ref())
+    }
+}
+
+impl Floor for Float {
+    type Output = Float;
+
+    /// Finds the floor of a [`Float`], taking the [`Float`] by value.
+    ///
+    /// $$
+    /// f(x) = \lfloor x \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Floor;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).floor().to_string(), "2.0");
+    /// assert_eq!(Float::from(-2.5).floor().to_string(), "-3.0");
+    /// assert_eq!(Float::from(0.5).floor().to_string(), "0.0");
+    /// ```
+    #[inline]
+    fn floor(self) -> Float {
+        self.round_to_integral(Floor).0
+    }
+}
+
+impl<'a> Floor for &'a Float {
+    type Output = Float;
+
+    /// Finds the floor of a [`Float`], taking the [`Float`] by reference.
+    ///
+    /// $$
+    /// f(x) = \lfloor x \rfloor.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Floor;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(2.5)).floor().to_string(), "2.0");
+    /// assert_eq!((&Float::from(-2.5)).floor().to_string(), "-3.0");
+    /// assert_eq!((&Float::from(0.5)).floor().to_string(), "0.0");
+    /// ```
+    #[inline]
+    fn floor(self) -> Float {
+        self.round_to_integral_ref(Floor).0
+    }
+}
+
+impl FloorAssign for Float {
+    /// Replaces a [`Float`] with its floor.
+    ///
+    /// $$
+    /// x \gets \lfloor x \rfloor.
+    /// $$
+    ///
+    /// The precision does not change, and the result is exact. A zero result has the same sign as
+    /// the original value. `NaN` and infinities are left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FloorAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(-2.5);
+    /// x.floor_assign();
+    /// assert_eq!(x.to_string(), "-3.0");
+    /// ```
+    #[inline]
+    fn floor_assign(&mut self) {
+        self.round_to_integral_assign(Floor);
+    }
+}
+
+impl Ceiling for Float {
+    type Output = Float;
+
+    /// Finds the ceiling of a [`Float`], taking the [`Float`] by value.
+    ///
+    /// $$
+    /// f(x) = \lceil x \rceil.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ceiling;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Float::from(2.5).ceiling().to_string(), "3.0");
+    /// assert_eq!(Float::from(-2.5).ceiling().to_string(), "-2.0");
+    /// assert_eq!(Float::from(-0.5).ceiling().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    fn ceiling(self) -> Float {
+        self.round_to_integral(Ceiling).0
+    }
+}
+
+impl<'a> Ceiling for &'a Float {
+    type Output = Float;
+
+    /// Finds the ceiling of a [`Float`], taking the [`Float`] by reference.
+    ///
+    /// $$
+    /// f(x) = \lceil x \rceil.
+    /// $$
+    ///
+    /// The result has the same precision as the input, and is exact. A zero result has the same
+    /// sign as the input. `NaN` and infinities are returned unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ceiling;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!((&Float::from(2.5)).ceiling().to_string(), "3.0");
+    /// assert_eq!((&Float::from(-2.5)).ceiling().to_string(), "-2.0");
+    /// assert_eq!((&Float::from(-0.5)).ceiling().to_string(), "-0.0");
+    /// ```
+    #[inline]
+    fn ceiling(self) -> Float {
+        self.round_to_integral_ref(Ceiling).0
+    }
+}
+
+impl CeilingAssign for Float {
+    /// Replaces a [`Float`] with its ceiling.
+    ///
+    /// $$
+    /// x \gets \lceil x \rceil.
+    /// $$
+    ///
+    /// The precision does not change, and the result is exact. A zero result has the same sign as
+    /// the original value. `NaN` and infinities are left unchanged.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CeilingAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.5);
+    /// x.ceiling_assign();
+    /// assert_eq!(x.to_string(), "3.0");
+    /// ```
+    #[inline]
+    fn ceiling_assign(&mut self) {
+        self.round_to_integral_assign(Ceiling);
+    }
+}
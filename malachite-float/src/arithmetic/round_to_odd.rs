@@ -0,0 +1,90 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// Helpers for rounding with `NearestTiesAway` and `ToOdd`, which the significand-level arithmetic
+// functions (ported from MPFR) don't support. A value is first computed with `Down`; if the result
+// is inexact, its least-significant bit is set, which yields the value rounded to odd. Rounding to
+// odd at two extra bits of precision and then rounding the result to the target precision gives the
+// same result as rounding the value directly, for any rounding mode. This is how `NearestTiesAway`
+// is handled.
+
+use crate::InnerFloat::Finite;
+use crate::{significand_bits, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::logic::traits::BitAccess;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Given `x`, a value rounded using `Down`, and `o`, the `Ordering` of `x` relative to the exact
+// value, converts `x` into the exact value rounded using `ToOdd` and returns the new `Ordering`.
+fn down_to_odd(x: &mut Float, o: Ordering) -> Ordering {
+    if o == Equal {
+        return Equal;
+    }
+    if let Float(Finite {
+        precision,
+        significand,
+        ..
+    }) = x
+    {
+        let index = significand_bits(significand) - *precision;
+        if !significand.get_bit(index) {
+            // The bit is zero, so setting it moves `x` one ulp away from zero without carrying.
+            significand.set_bit(index);
+            return o.reverse();
+        }
+    }
+    o
+}
+
+// Rounds a value to `prec` bits using `rm`, which must be `NearestTiesAway` or `ToOdd`. `f` is
+// called with a working precision and must return the value rounded to that precision using
+// `Down`, together with the `Ordering` of the result relative to the value.
+pub(crate) fn round_via_odd<F: FnOnce(u64) -> (Float, Ordering)>(
+    prec: u64,
+    rm: RoundingMode,
+    f: F,
+) -> (Float, Ordering) {
+    let (mut x, o) = f(working_prec(prec, rm));
+    let o = finish(&mut x, o, prec, rm);
+    (x, o)
+}
+
+// Like `round_via_odd`, but `f` writes the value rounded using `Down` to `x` and returns the
+// `Ordering`.
+pub(crate) fn round_via_odd_assign<F: FnOnce(&mut Float, u64) -> Ordering>(
+    x: &mut Float,
+    prec: u64,
+    rm: RoundingMode,
+    f: F,
+) -> Ordering {
+    let o = f(x, working_prec(prec, rm));
+    finish(x, o, prec, rm)
+}
+
+fn working_prec(prec: u64, rm: RoundingMode) -> u64 {
+    match rm {
+        ToOdd => prec,
+        NearestTiesAway => prec + 2,
+        _ => unreachable!(),
+    }
+}
+
+fn finish(x: &mut Float, o: Ordering, prec: u64, rm: RoundingMode) -> Ordering {
+    let o = down_to_odd(x, o);
+    if rm == ToOdd {
+        return o;
+    }
+    // If `x` was inexact, it has a one in its least-significant bit, so rounding it to `prec` bits
+    // is also inexact.
+    let o_2 = x.set_prec_round(prec, rm);
+    if o_2 == Equal {
+        o
+    } else {
+        o_2
+    }
+}
@@ -0,0 +1,1755 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{
+    initial_working_prec, is_negligible, next_working_prec, reduction_steps, round_enclosed,
+    round_near, Enclosure,
+};
+use crate::constants::pi::pi_enclosure;
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, Float};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::ModPowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+
+// Returns enclosures of $\sin x$ and $\cos x$, where $x$ is nonzero and $|x| < 1$. The argument is
+// halved $k$ times, the Taylor series are summed, and the angle is doubled $k$ times using
+// $\sin 2a = 2\sin a\cos a$ and $\cos 2a = 1-2\sin^2 a$.
+fn sin_cos_series(x: &Float, prec: u64) -> (Enclosure, Enclosure) {
+    let k = reduction_steps(prec, i64::from(x.get_exponent().unwrap()));
+    let working_prec = prec + (k << 1) + 10;
+    let x = Enclosure::exact(x >> k);
+    let x_squared = x.square(working_prec);
+    let one = Enclosure::exact(Float::ONE);
+    // Both series alternate and their terms decrease, so each sum is within its last term of the
+    // exact value.
+    let mut term = x;
+    let mut sin = term.clone();
+    let mut i = 1u64;
+    loop {
+        let divisor = Enclosure::exact(Float::from((i << 1) * ((i << 1) | 1)));
+        term = term
+            .mul(&x_squared, working_prec)
+            .div(&divisor, working_prec)
+            .unwrap()
+            .neg();
+        sin = sin.add(&term, working_prec);
+        if is_negligible(&term.hi, &sin.lo, working_prec) {
+            break;
+        }
+        i += 1;
+    }
+    let mut sin = sin.widen(&term.magnitude(), working_prec);
+    let mut term = one.clone();
+    let mut cos = one.clone();
+    let mut i = 1u64;
+    loop {
+        let divisor = Enclosure::exact(Float::from(((i << 1) - 1) * (i << 1)));
+        term = term
+            .mul(&x_squared, working_prec)
+            .div(&divisor, working_prec)
+            .unwrap()
+            .neg();
+        cos = cos.add(&term, working_prec);
+        if is_negligible(&term.lo, &cos.lo, working_prec) {
+            break;
+        }
+        i += 1;
+    }
+    let mut cos = cos.widen(&term.magnitude(), working_prec);
+    for _ in 0..k {
+        let new_sin = sin.mul(&cos, working_prec).shl(1);
+        cos = one.sub(&sin.square(working_prec).shl(1), working_prec);
+        sin = new_sin;
+    }
+    (sin, cos)
+}
+
+// Returns enclosures of $\sin x$ and $\cos x$, where $x$ is finite and nonzero, or `None` if the
+// working precision is too low to determine their signs.
+//
+// If $|x| \geq 1$, the argument is reduced to $r = x - k\pi/2$, where $k$ is an integer and $|r|
+// \lesssim \pi/4$. To make $r$ accurate, $\pi$ is computed with as many extra bits as $x$ has
+// integer bits, so the reduction works for arbitrarily large exponents.
+pub(crate) fn sin_cos_enclosure(x: &Float, prec: u64) -> Option<(Enclosure, Enclosure)> {
+    let exponent = x.get_exponent().unwrap();
+    if exponent <= 0 {
+        return Some(sin_cos_series(x, prec));
+    }
+    let exponent = u64::exact_from(exponent);
+    let working_prec = prec + exponent + 10;
+    let half_pi = pi_enclosure(working_prec).shl(-1);
+    let k = Integer::rounding_from(
+        x.div_prec_round_ref_ref(&half_pi.lo, exponent + 2, Nearest).0,
+        Nearest,
+    )
+    .0;
+    let quadrant = u64::exact_from(&(&k).mod_power_of_2(2));
+    let k = Enclosure::exact(Float::from(k));
+    let r = Enclosure::exact(x.clone()).sub(&k.mul(&half_pi, working_prec), working_prec);
+    if r.contains_zero() {
+        return None;
+    }
+    // Both $\sin$ and $\cos$ are 1-Lipschitz, so evaluating them at one endpoint and widening the
+    // results by the width of the enclosure of $r$ gives enclosures of $\sin r$ and $\cos r$.
+    let width = r.width(prec);
+    let (sin, cos) = sin_cos_series(&r.lo, prec);
+    let sin = sin.widen(&width, prec);
+    let cos = cos.widen(&width, prec);
+    Some(match quadrant {
+        0 => (sin, cos),
+        1 => (cos, sin.neg()),
+        2 => (sin.neg(), cos.neg()),
+        3 => (cos.neg(), sin),
+        _ => unreachable!(),
+    })
+}
+
+// Returns whether $|x| < 2^{-q/2}$, where $q$ = max(prec, x.get_prec()) + 2. In that case $\sin x$
+// lies strictly between $x$ and its neighbor at precision $q$ in the direction of zero, and $\cos
+// x$ lies strictly between 1 and its neighbor at precision `prec + 2` in the direction of zero.
+pub(crate) fn is_tiny(x: &Float, prec: u64) -> bool {
+    let q = i64::exact_from(max(prec, x.get_prec().unwrap())) + 2;
+    i64::from(x.get_exponent().unwrap()) << 1 <= -q
+}
+
+// Returns the direction from a `Float` toward zero.
+pub(crate) fn toward_zero(x: &Float) -> Ordering {
+    if *x > 0u32 {
+        Less
+    } else {
+        Greater
+    }
+}
+
+// The sine of a finite, nonzero `Float`.
+fn sin_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if is_tiny(x, prec) {
+        round_near(x, toward_zero(x), prec, rm)
+    } else {
+        round_enclosed(prec, rm, |working_prec| {
+            sin_cos_enclosure(x, working_prec).map(|(sin, _)| sin)
+        })
+    }
+}
+
+// The cosine of a finite, nonzero `Float`.
+fn cos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if is_tiny(x, prec) {
+        round_near(&Float::ONE, Less, prec, rm)
+    } else {
+        round_enclosed(prec, rm, |working_prec| {
+            sin_cos_enclosure(x, working_prec).map(|(_, cos)| cos)
+        })
+    }
+}
+
+// The sine and cosine of a finite, nonzero `Float`.
+fn sin_cos_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+    if is_tiny(x, prec) {
+        let (sin, sin_o) = round_near(x, toward_zero(x), prec, rm);
+        let (cos, cos_o) = round_near(&Float::ONE, Less, prec, rm);
+        return (sin, cos, sin_o, cos_o);
+    }
+    assert_ne!(rm, Exact, "Inexact result");
+    let mut working_prec = initial_working_prec(prec);
+    loop {
+        if let Some((sin, cos)) = sin_cos_enclosure(x, working_prec) {
+            if let (Some((sin, sin_o)), Some((cos, cos_o))) =
+                (sin.round(prec, rm), cos.round(prec, rm))
+            {
+                return (sin, cos, sin_o, cos_o);
+            }
+        }
+        working_prec = next_working_prec(working_prec);
+    }
+}
+
+impl Float {
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// sine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// sine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_round_ref(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn sin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => sin_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the sine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the sine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sin, o) = Float::from(PI).sin_round_ref(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.sin_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// sine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.sin_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded sine is
+    /// less than, equal to, or greater than the exact sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(5), Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sin_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded sine is less than, equal
+    /// to, or greater than the exact sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the sine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(Nearest), Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// ```
+    #[inline]
+    pub fn sin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// cosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(5, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round(20, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=1.0$
+    /// - $f(-0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// cosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn cos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (Float::one_prec(prec), Equal),
+            _ => cos_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec(5);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec(20);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_ref(5);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_prec_ref(20);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the cosine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the cosine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Floor);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Ceiling);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (cos, o) = Float::from(PI).cos_round_ref(Nearest);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn cos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.cos_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// cosine is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.cos_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded cosine is
+    /// less than, equal to, or greater than the exact cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cos_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded cosine is less than, equal
+    /// to, or greater than the exact cosine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the cosine is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_prec_round`] and [`Float::cos_prec_round`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// sine and cosine are then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_prec_round_ref`] and [`Float::cos_prec_round_ref`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// sine and cosine are then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(5, Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(5, Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(20, Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_round_ref(20, Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    pub fn sin_cos_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Float, Ordering, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), float_nan!(), Equal, Equal),
+            float_either_zero!() => (self.clone(), Float::one_prec(prec), Equal, Equal),
+            _ => sin_cos_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_prec`] and [`Float::cos_prec`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec(self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. Two [`Ordering`]s are also
+    /// returned, indicating whether the rounded sine and cosine are less than, equal to, or greater
+    /// than the exact sine and cosine, respectively. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_prec_ref`] and [`Float::cos_prec_ref`].
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_ref(&self, prec: u64) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by value. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_round`] and [`Float::cos_round`].
+    ///
+    /// If the outputs have a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the sine and cosine are
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round(self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine and cosine of a [`Float`], rounding both results to the precision of the
+    /// input and with the specified rounding mode. The [`Float`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the rounded sine and cosine are less
+    /// than, equal to, or greater than the exact sine and cosine, respectively. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// This is faster than computing the sine and cosine separately, since the argument only needs
+    /// to be reduced once.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// The errors $\varepsilon_1$ and $\varepsilon_2$ satisfy the same bounds as the errors of
+    /// [`Float::sin_round_ref`] and [`Float::cos_round_ref`].
+    ///
+    /// If the outputs have a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the sine and cosine are
+    /// then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round_ref(Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o_sin, Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round_ref(Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o_cos, Greater);
+    ///
+    /// let (sin, cos, o_sin, o_cos) = Float::from(PI).sin_cos_round_ref(Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round_ref(&self, rm: RoundingMode) -> (Float, Float, Ordering, Ordering) {
+        self.sin_cos_prec_round_ref(self.significant_bits(), rm)
+    }
+}
@@ -0,0 +1,1124 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::round_to_odd::round_via_odd;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_zero, float_zero, significand_bits, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{DivRem, Parity, Sqrt, SqrtAssign, SqrtRem};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+pub fn sqrt_prec_round_naive(x: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match x {
+        Float(NaN | Zero { .. }) => (x, Equal),
+        float_infinity!() => (float_infinity!(), Equal),
+        Float(Infinity { sign: false } | Finite { sign: false, .. }) => (float_nan!(), Equal),
+        x => Float::sqrt_rational_prec_round(Rational::exact_from(x), prec, rm),
+    }
+}
+
+// Given $r = \lfloor\sqrt{y}\rfloor$, where $r$ has more than `prec + 1` significant bits, returns
+// $\sqrt{y}2^e$ rounded to `prec` bits. If $y$ is not a perfect square, then $\sqrt{y}$ lies
+// strictly between two consecutive integers, as does $r + 1/2$, and since every `prec`-bit value
+// and every midpoint between `prec`-bit values near $r$ is an integer, both round identically.
+fn round_floor_sqrt(
+    r: Natural,
+    inexact: bool,
+    exp: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if inexact {
+        let (s, o) = Float::from_natural_prec_round((r << 1u32) + Natural::ONE, prec, rm);
+        (s << (exp - 1), o)
+    } else {
+        let (s, o) = Float::from_natural_prec_round(r, prec, rm);
+        (s << exp, o)
+    }
+}
+
+fn sqrt_float_significand_ref(
+    x: &Natural,
+    x_exp: i32,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    // x = significand * 2^(x_exp - bits). Shift the significand left so that it has at least
+    // 2 * prec + 5 bits, and so that the remaining power of 2 is even.
+    let bits = significand_bits(x);
+    let exp = i64::from(x_exp) - i64::exact_from(bits);
+    let mut shift = ((prec + 2) << 1 | 1).saturating_sub(bits);
+    if (exp - i64::exact_from(shift)).odd() {
+        shift += 1;
+    }
+    let (r, rem) = (x << shift).sqrt_rem();
+    round_floor_sqrt(
+        r,
+        rem != 0u32,
+        (exp - i64::exact_from(shift)) >> 1,
+        prec,
+        rm,
+    )
+}
+
+// Returns the square root of the positive ratio $n/d$, rounded to `prec` bits.
+fn sqrt_ratio(n: &Natural, d: &Natural, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    // Choose j so that floor(x * 2^(2 * j)) has at least 2 * prec + 5 bits. Since floor(sqrt(y))
+    // = floor(sqrt(floor(y))), the square root of the truncated quotient has the right floor.
+    let t = i64::exact_from((prec + 3) << 1) - i64::exact_from(n.significant_bits())
+        + i64::exact_from(d.significant_bits());
+    let j = (t + 1) >> 1;
+    let (q, r) = if j >= 0 {
+        (n << (j << 1)).div_rem(d)
+    } else {
+        n.div_rem(d << ((-j) << 1))
+    };
+    let (s, rem) = q.sqrt_rem();
+    round_floor_sqrt(s, rem != 0u32 || r != 0u32, -j, prec, rm)
+}
+
+impl Float {
+    /// Takes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round`] instead. If both of these things are true, consider using
+    /// [`Float::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(5, Floor);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(5, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.81");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(5, Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(20, Floor);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(20, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.772455");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round(20, Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round_ref`] instead. If both of these things are true, consider using
+    /// `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(5, Floor);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(5, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.81");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(5, Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(20, Floor);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(20, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.772455");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_round_ref(20, Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| self.sqrt_prec_round_ref(prec, Down));
+        }
+        match self {
+            float_zero!() => (float_zero!(), Equal),
+            float_negative_zero!() => (float_negative_zero!(), Equal),
+            float_infinity!() => (float_infinity!(), Equal),
+            Float(NaN | Infinity { sign: false } | Finite { sign: false, .. }) => {
+                (float_nan!(), Equal)
+            }
+            Float(Finite {
+                exponent,
+                significand,
+                ..
+            }) => sqrt_float_significand_ref(significand, *exponent, prec, rm),
+        }
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the
+    /// exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Float::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec(5);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec(20);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sqrt_prec_round_ref(prec, Nearest)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square root is less than, equal to, or greater than the
+    /// exact square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_ref(5);
+    /// assert_eq!(sqrt.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_prec_ref(20);
+    /// assert_eq!(sqrt.to_string(), "1.772453");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sqrt_prec_round_ref(prec, Nearest)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded square root is less than, equal to, or greater than the exact square root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sqrt_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Float::sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round(Floor);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round(Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055161");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round(Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the square root of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded square root is less than, equal to, or greater than the exact square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sqrt_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// `(&Float).sqrt()` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round_ref(Floor);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round_ref(Ceiling);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055161");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) = Float::from(PI).sqrt_round_ref(Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.7724538509055159");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.sqrt_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded square root is less than, equal to, or greater than the exact square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sqrt_round_assign`] instead. If both of these things are true, consider using
+    /// [`Float::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.772453");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.772455");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_round_assign(20, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.sqrt_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded square
+    /// root is less than, equal to, or greater than the exact square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Float::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// prec)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_prec_assign(20), Less);
+    /// assert_eq!(x.to_string(), "1.772453");
+    /// ```
+    #[inline]
+    pub fn sqrt_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sqrt_prec_round_assign(prec, Nearest)
+    }
+
+    /// Takes the square root of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded square root is
+    /// less than, equal to, or greater than the exact square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt{x}$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sqrt_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sqrt_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Float::sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "1.7724538509055161");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sqrt_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.7724538509055159");
+    /// ```
+    #[inline]
+    pub fn sqrt_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_assign(prec, rm)
+    }
+
+    /// Takes the square root of a [`Rational`], returning a [`Float`] rounded to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded square root is less than,
+    /// equal to, or greater than the exact square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_rational_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::sqrt_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Floor);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "0.84");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round(Rational::from_unsigneds(2u8, 3), 5, Nearest);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round(Rational::from_unsigneds(9u8, 4), 5, Exact);
+    /// assert_eq!(sqrt.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn sqrt_rational_prec_round(x: Rational, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if x < 0u32 {
+            return (float_nan!(), Equal);
+        } else if x == 0u32 {
+            return (float_zero!(), Equal);
+        }
+        let (n, d) = x.into_numerator_and_denominator();
+        sqrt_ratio(&n, &d, prec, rm)
+    }
+
+    /// Takes the square root of a [`Rational`], returning a [`Float`] rounded to the specified
+    /// precision and with the specified rounding mode. The [`Rational`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded square root is less than,
+    /// equal to, or greater than the exact square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 \sqrt{x}\rfloor-p+1}$.
+    /// - If $\sqrt{x}$ is nonzero, and $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sqrt_rational_prec_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact square root, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Floor);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "0.84");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round_ref(&Rational::from_unsigneds(2u8, 3), 5, Nearest);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) =
+    ///     Float::sqrt_rational_prec_round_ref(&Rational::from_unsigneds(9u8, 4), 5, Exact);
+    /// assert_eq!(sqrt.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn sqrt_rational_prec_round_ref(
+        x: &Rational,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if *x < 0u32 {
+            return (float_nan!(), Equal);
+        } else if *x == 0u32 {
+            return (float_zero!(), Equal);
+        }
+        sqrt_ratio(x.numerator_ref(), x.denominator_ref(), prec, rm)
+    }
+
+    /// Takes the square root of a [`Rational`], returning a [`Float`] rounded to the nearest value
+    /// of the specified precision. The [`Rational`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_rational_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::sqrt_rational_prec(Rational::from_unsigneds(2u8, 3), 5);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::sqrt_rational_prec(Rational::from_unsigneds(2u8, 3), 20);
+    /// assert_eq!(sqrt.to_string(), "0.816497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sqrt_rational_prec(x: Rational, prec: u64) -> (Float, Ordering) {
+        Float::sqrt_rational_prec_round(x, prec, Nearest)
+    }
+
+    /// Takes the square root of a [`Rational`], returning a [`Float`] rounded to the nearest value
+    /// of the specified precision. The [`Rational`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded square root is less than, equal to, or greater
+    /// than the exact square root. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the square root is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is zero or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sqrt{x}$ is nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 \sqrt{x}\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sqrt_rational_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_rational_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (sqrt, o) = Float::sqrt_rational_prec_ref(&Rational::from_unsigneds(2u8, 3), 5);
+    /// assert_eq!(sqrt.to_string(), "0.81");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = Float::sqrt_rational_prec_ref(&Rational::from_unsigneds(2u8, 3), 20);
+    /// assert_eq!(sqrt.to_string(), "0.816497");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn sqrt_rational_prec_ref(x: &Rational, prec: u64) -> (Float, Ordering) {
+        Float::sqrt_rational_prec_round_ref(x, prec, Nearest)
+    }
+}
+
+impl Sqrt for Float {
+    type Output = Float;
+
+    /// Takes the square root of a [`Float`], taking it by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sqrt_prec`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Float::NAN.sqrt().is_nan());
+    /// assert_eq!(Float::INFINITY.sqrt(), Float::INFINITY);
+    /// assert!(Float::NEGATIVE_INFINITY.sqrt().is_nan());
+    /// assert_eq!(Float::from(1.5).sqrt().to_string(), "1.2247448713915889");
+    /// assert!(Float::from(-1.5).sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_ref(prec, Nearest).0
+    }
+}
+
+impl<'a> Sqrt for &'a Float {
+    type Output = Float;
+
+    /// Takes the square root of a [`Float`], taking it by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sqrt_prec_ref`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::NAN).sqrt().is_nan());
+    /// assert_eq!((&Float::INFINITY).sqrt(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).sqrt().is_nan());
+    /// assert_eq!((&Float::from(1.5)).sqrt().to_string(), "1.2247448713915889");
+    /// assert!((&Float::from(-1.5)).sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn sqrt(self) -> Float {
+        self.sqrt_prec_round_ref(self.significant_bits(), Nearest).0
+    }
+}
+
+impl SqrtAssign for Float {
+    /// Takes the square root of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square root is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x\gets = \sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   \sqrt{x}\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Float::sqrt`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sqrt_round_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sqrt_prec_assign`]. If you want both of these things, consider using
+    /// [`Float::sqrt_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SqrtAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::NAN;
+    /// x.sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.sqrt_assign();
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.sqrt_assign();
+    /// assert_eq!(x.to_string(), "1.2247448713915889");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.sqrt_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sqrt_prec_round_assign(prec, Nearest);
+    }
+}
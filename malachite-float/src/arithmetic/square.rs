@@ -6,6 +6,7 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::arithmetic::round_to_odd::{round_via_odd, round_via_odd_assign};
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{
     float_either_infinity, float_either_zero, float_infinity, float_nan, float_zero, Float,
@@ -174,6 +175,10 @@ impl Float {
     #[inline]
     pub fn square_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd(prec, rm, |prec| self.square_prec_round_ref(prec, Down));
+        }
         match self {
             float_nan!() => (float_nan!(), Equal),
             float_either_infinity!() => (float_infinity!(), Equal),
@@ -510,6 +515,12 @@ impl Float {
     #[inline]
     pub fn square_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
         assert_ne!(prec, 0);
+        // The significand functions don't support these modes; see `round_via_odd`.
+        if rm == NearestTiesAway || rm == ToOdd {
+            return round_via_odd_assign(self, prec, rm, |x, prec| {
+                x.square_prec_round_assign(prec, Down)
+            });
+        }
         match self {
             float_nan!() => Equal,
             Float(Infinity { sign } | Zero { sign }) => {
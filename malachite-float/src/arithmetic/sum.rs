@@ -0,0 +1,728 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{initial_working_prec, round_enclosure};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{significand_bits, Float};
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use core::iter::Sum;
+use malachite_base::num::arithmetic::traits::ShrRound;
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, NegativeInfinity, NegativeZero, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// A finite, nonzero term, equal to $(-1)^s m 2^e$, where $s$ is `negative`, $m$ is the odd
+// `significand`, and $e$ is `exponent`.
+struct Term {
+    negative: bool,
+    significand: Natural,
+    exponent: i64,
+}
+
+impl Term {
+    fn new(negative: bool, significand: Natural, exponent: i64) -> Term {
+        let zeros = significand.trailing_zeros().unwrap();
+        Term {
+            negative,
+            significand: significand >> zeros,
+            exponent: exponent + i64::exact_from(zeros),
+        }
+    }
+
+    fn top(&self) -> i64 {
+        self.exponent + i64::exact_from(self.significand.significant_bits())
+    }
+}
+
+// Accumulates the exact sum of `Float`s and of exact products of `Float`s, so that it can be
+// rounded once at the end.
+//
+// The sum is rounded using Ziv's strategy. Every term is truncated to a multiple of $2^c$, for some
+// cutoff $c$, and the truncated terms are added exactly. If $k$ terms were truncated, the exact sum
+// lies strictly between the truncated sum minus $k2^c$ and the truncated sum plus $k2^c$; if both
+// ends of this enclosure round to the same `Float`, that is the result. Otherwise the cutoff is
+// lowered. Once the cutoff reaches the lowest bit of every term, the sum is exact. This way, terms
+// whose magnitudes differ greatly don't force the computation of a huge exact sum, unless they
+// almost cancel out.
+pub(crate) struct ExactSum {
+    terms: Vec<Term>,
+    nan: bool,
+    infinity: bool,
+    negative_infinity: bool,
+    // Whether every value added so far has been positive zero or negative zero, respectively
+    all_positive_zero: bool,
+    all_negative_zero: bool,
+}
+
+impl ExactSum {
+    pub(crate) const fn new() -> ExactSum {
+        ExactSum {
+            terms: Vec::new(),
+            nan: false,
+            infinity: false,
+            negative_infinity: false,
+            all_positive_zero: true,
+            all_negative_zero: true,
+        }
+    }
+
+    fn push_zero(&mut self, sign: bool) {
+        if sign {
+            self.all_negative_zero = false;
+        } else {
+            self.all_positive_zero = false;
+        }
+    }
+
+    fn push_infinity(&mut self, sign: bool) {
+        self.all_positive_zero = false;
+        self.all_negative_zero = false;
+        if sign {
+            self.infinity = true;
+        } else {
+            self.negative_infinity = true;
+        }
+    }
+
+    fn push_term(&mut self, term: Term) {
+        self.all_positive_zero = false;
+        self.all_negative_zero = false;
+        self.terms.push(term);
+    }
+
+    pub(crate) fn push(&mut self, x: Float) {
+        match x {
+            Float(NaN) => self.nan = true,
+            Float(Infinity { sign }) => self.push_infinity(sign),
+            Float(Zero { sign }) => self.push_zero(sign),
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => {
+                let exponent =
+                    i64::from(exponent) - i64::exact_from(significand_bits(&significand));
+                self.push_term(Term::new(!sign, significand, exponent));
+            }
+        }
+    }
+
+    // Adds the exact product of `x` and `y`, taking `y` by value.
+    pub(crate) fn push_product(&mut self, x: &Float, y: Float) {
+        match (x, y) {
+            (
+                Float(Finite {
+                    sign: x_sign,
+                    exponent: x_exp,
+                    significand: x,
+                    ..
+                }),
+                Float(Finite {
+                    sign: y_sign,
+                    exponent: y_exp,
+                    significand: y,
+                    ..
+                }),
+            ) => {
+                let exponent = i64::from(*x_exp) + i64::from(y_exp)
+                    - i64::exact_from(significand_bits(x) + significand_bits(&y));
+                self.push_term(Term::new(*x_sign != y_sign, x * y, exponent));
+            }
+            (x, y) => self.push_product_ref(x, &y),
+        }
+    }
+
+    // Adds the exact product of `x` and `y`, taking both by reference.
+    pub(crate) fn push_product_ref(&mut self, x: &Float, y: &Float) {
+        match (x, y) {
+            (Float(NaN), _)
+            | (_, Float(NaN))
+            | (Float(Infinity { .. }), Float(Zero { .. }))
+            | (Float(Zero { .. }), Float(Infinity { .. })) => self.nan = true,
+            (
+                Float(Infinity { sign: x_sign }),
+                Float(Infinity { sign: y_sign } | Finite { sign: y_sign, .. }),
+            )
+            | (Float(Finite { sign: x_sign, .. }), Float(Infinity { sign: y_sign })) => {
+                self.push_infinity(x_sign == y_sign);
+            }
+            (
+                Float(Zero { sign: x_sign }),
+                Float(Zero { sign: y_sign } | Finite { sign: y_sign, .. }),
+            )
+            | (Float(Finite { sign: x_sign, .. }), Float(Zero { sign: y_sign })) => {
+                self.push_zero(x_sign == y_sign);
+            }
+            (
+                Float(Finite {
+                    sign: x_sign,
+                    exponent: x_exp,
+                    significand: x,
+                    ..
+                }),
+                Float(Finite {
+                    sign: y_sign,
+                    exponent: y_exp,
+                    significand: y,
+                    ..
+                }),
+            ) => {
+                let exponent = i64::from(*x_exp) + i64::from(*y_exp)
+                    - i64::exact_from(significand_bits(x) + significand_bits(y));
+                self.push_term(Term::new(x_sign != y_sign, x * y, exponent));
+            }
+        }
+    }
+
+    fn zero(&self, rm: RoundingMode) -> Float {
+        // This follows IEEE 754: an exact zero sum is negative if every value was negative zero,
+        // or if the values had different signs and the rounding mode is `Floor`.
+        if self.all_positive_zero {
+            Float::ZERO
+        } else if self.all_negative_zero || rm == Floor {
+            Float::NEGATIVE_ZERO
+        } else {
+            Float::ZERO
+        }
+    }
+
+    pub(crate) fn round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if self.nan || self.infinity && self.negative_infinity {
+            return (Float::NAN, Equal);
+        } else if self.infinity {
+            return (Float::INFINITY, Equal);
+        } else if self.negative_infinity {
+            return (Float::NEGATIVE_INFINITY, Equal);
+        } else if self.terms.is_empty() {
+            return (self.zero(rm), Equal);
+        }
+        let top = self.terms.iter().map(Term::top).max().unwrap();
+        let bottom = self.terms.iter().map(|t| t.exponent).min().unwrap();
+        let mut cutoff = if rm == Exact {
+            bottom
+        } else {
+            top - i64::exact_from(
+                initial_working_prec(prec) + u64::exact_from(self.terms.len()).significant_bits(),
+            )
+        };
+        loop {
+            cutoff = max(cutoff, bottom);
+            let mut sum = Integer::ZERO;
+            let mut truncated = 0u64;
+            for t in &self.terms {
+                let m = if t.exponent >= cutoff {
+                    &t.significand << u64::exact_from(t.exponent - cutoff)
+                } else {
+                    let (m, o) =
+                        (&t.significand).shr_round(u64::exact_from(cutoff - t.exponent), Down);
+                    if o != Equal {
+                        truncated += 1;
+                    }
+                    m
+                };
+                if t.negative {
+                    sum -= Integer::from(m);
+                } else {
+                    sum += Integer::from(m);
+                }
+            }
+            if truncated == 0 {
+                if sum == 0u32 {
+                    return (self.zero(rm), Equal);
+                }
+                let (x, o) = Float::from_integer_prec_round(sum, prec, rm);
+                return (x << cutoff, o);
+            }
+            let truncated = Integer::from(truncated);
+            let lo = Float::from(&sum - &truncated) << cutoff;
+            let hi = Float::from(sum + truncated) << cutoff;
+            if let Some(result) = round_enclosure(&lo, &hi, prec, rm) {
+                return result;
+            }
+            cutoff -= top - cutoff;
+        }
+    }
+}
+
+impl Float {
+    /// Adds the [`Float`]s produced by an iterator, rounding the result to the specified
+    /// precision and with the specified rounding mode. The result is rounded only once, so it is
+    /// the exact sum, correctly rounded. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sum_i x_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sum_i x_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_i|\rfloor-p+1}$.
+    /// - If $\sum_i x_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - If any $x_i$ is `NaN`, or if both $\infty$ and $-\infty$ occur, the result is `NaN`.
+    /// - Otherwise, if $\infty$ occurs, the result is $\infty$; if $-\infty$ occurs, the result is
+    ///   $-\infty$.
+    /// - The sum of no values is 0.0.
+    /// - If the exact sum is zero, the result is $-0.0$ if every $x_i$ is $-0.0$, or if $m$ is
+    ///   `Floor` and not every $x_i$ is 0.0. Otherwise, it is 0.0.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sum_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the inputs, consider
+    /// using [`Float::sum_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec`, the precisions of
+    /// the inputs, and the difference between the largest and smallest exponents of the inputs.
+    /// Usually, unless the inputs almost cancel out, the time is much closer to linear in the
+    /// total precision of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but `prec` is too small for an exact sum.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(1.0e20), Float::ONE, Float::from(-1.0e20)];
+    /// let (sum, o) = Float::sum_prec_round(xs.clone(), 10, Floor);
+    /// assert_eq!(sum.to_string(), "1.0");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let xs = [Float::from(0.1), Float::from(0.2), Float::from(0.3)];
+    /// let (sum, o) = Float::sum_prec_round(xs.clone(), 10, Floor);
+    /// assert_eq!(sum.to_string(), "0.6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sum, o) = Float::sum_prec_round(xs, 10, Ceiling);
+    /// assert_eq!(sum.to_string(), "0.601");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (sum, o) = Float::sum_prec_round([], 10, Ceiling);
+    /// assert_eq!(sum, Float::ZERO);
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn sum_prec_round<I: IntoIterator<Item = Float>>(
+        xs: I,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        for x in xs {
+            sum.push(x);
+        }
+        sum.round(prec, rm)
+    }
+
+    /// Adds the [`Float`]s produced by an iterator, rounding the result to the nearest value of
+    /// the specified precision. The result is rounded only once, so it is the exact sum, correctly
+    /// rounded. An [`Ordering`] is also returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sum_i x_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sum_i x_i$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sum_i
+    ///   x_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::sum_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec`, the precisions of
+    /// the inputs, and the difference between the largest and smallest exponents of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(0.2), Float::from(0.3)];
+    /// let (sum, o) = Float::sum_prec(xs.clone(), 10);
+    /// assert_eq!(sum.to_string(), "0.6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sum, o) = Float::sum_prec(xs, 100);
+    /// assert_eq!(sum.to_string(), "0.600000000000000005551115123126");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn sum_prec<I: IntoIterator<Item = Float>>(xs: I, prec: u64) -> (Float, Ordering) {
+        Float::sum_prec_round(xs, prec, Nearest)
+    }
+
+    /// Adds the [`Float`]s produced by an iterator, rounding the result with the specified
+    /// rounding mode. The precision of the result is the maximum of the precisions of the inputs.
+    /// The result is rounded only once, so it is the exact sum, correctly rounded. An [`Ordering`]
+    /// is also returned, indicating whether the rounded sum is less than, equal to, or greater than
+    /// the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sum_i x_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\sum_i x_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_i|\rfloor-p+1}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    /// - If $\sum_i x_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_i|\rfloor-p}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See [`Float::sum_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs and the difference between the largest and smallest exponents of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is too small for an exact
+    /// sum.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(0.2), Float::from(0.3)];
+    /// let (sum, o) = Float::sum_round(xs.clone(), Floor);
+    /// assert_eq!(sum.to_string(), "0.6");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sum, o) = Float::sum_round(xs, Ceiling);
+    /// assert_eq!(sum.to_string(), "0.6000000000000001");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn sum_round<I: IntoIterator<Item = Float>>(xs: I, rm: RoundingMode) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        let mut prec = 1;
+        for x in xs {
+            prec = max(prec, x.significant_bits());
+            sum.push(x);
+        }
+        sum.round(prec, rm)
+    }
+
+    /// Computes the dot product of the [`Float`]s produced by two iterators, rounding the result
+    /// to the specified precision and with the specified rounding mode. The products and their sum
+    /// are computed exactly and rounded only once. An [`Ordering`] is also returned, indicating
+    /// whether the rounded dot product is less than, equal to, or greater than the exact dot
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = \sum_i x_iy_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_iy_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sum_i x_iy_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_iy_i|\rfloor-p+1}$.
+    /// - If $\sum_i x_iy_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_iy_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// The special cases are those of [`Float::sum_prec_round`], applied to the products $x_iy_i$,
+    /// where $0\infty$ is `NaN` and the signs of zero and infinite products are determined as in
+    /// multiplication.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec`, the precisions of
+    /// the inputs, and the difference between the largest and smallest exponents of the products.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, if `xs` and `ys` have different lengths, or if `rm` is `Exact`
+    /// but `prec` is too small for an exact dot product.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(-3)];
+    /// let ys = [Float::from(10), Float::from(1)];
+    /// let (dot, o) = Float::dot_prec_round(xs.clone(), ys.clone(), 10, Floor);
+    /// assert_eq!(dot.to_string(), "-2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (dot, o) = Float::dot_prec_round(xs, ys, 100, Nearest);
+    /// assert_eq!(dot.to_string(), "-1.999999999999999944488848768742");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn dot_prec_round<I: IntoIterator<Item = Float>, J: IntoIterator<Item = Float>>(
+        xs: I,
+        ys: J,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        let mut xs = xs.into_iter();
+        let mut ys = ys.into_iter();
+        loop {
+            match (xs.next(), ys.next()) {
+                (Some(x), Some(y)) => sum.push_product(&x, y),
+                (None, None) => break,
+                _ => panic!("xs and ys must have the same length"),
+            }
+        }
+        sum.round(prec, rm)
+    }
+
+    /// Computes the dot product of the [`Float`]s produced by two iterators, rounding the result
+    /// to the nearest value of the specified precision. The products and their sum are computed
+    /// exactly and rounded only once. An [`Ordering`] is also returned, indicating whether the
+    /// rounded dot product is less than, equal to, or greater than the exact dot product. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the dot product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = \sum_i x_iy_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_iy_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sum_i x_iy_i$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sum_i
+    ///   x_iy_i|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See [`Float::dot_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `prec`, the precisions of
+    /// the inputs, and the difference between the largest and smallest exponents of the products.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `xs` and `ys` have different lengths.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(-3)];
+    /// let ys = [Float::from(10), Float::from(1)];
+    /// let (dot, o) = Float::dot_prec(xs, ys, 10);
+    /// assert_eq!(dot.to_string(), "-2.0");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn dot_prec<I: IntoIterator<Item = Float>, J: IntoIterator<Item = Float>>(
+        xs: I,
+        ys: J,
+        prec: u64,
+    ) -> (Float, Ordering) {
+        Float::dot_prec_round(xs, ys, prec, Nearest)
+    }
+
+    /// Computes the dot product of the [`Float`]s produced by two iterators, rounding the result
+    /// with the specified rounding mode. The precision of the result is the maximum of the
+    /// precisions of the inputs. The products and their sum are computed exactly and rounded only
+    /// once. An [`Ordering`] is also returned, indicating whether the rounded dot product is less
+    /// than, equal to, or greater than the exact dot product. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = \sum_i x_iy_i+\varepsilon.
+    /// $$
+    /// - If $\sum_i x_iy_i$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sum_i x_iy_i$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_iy_i|\rfloor-p+1}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    /// - If $\sum_i x_iy_i$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sum_i x_iy_i|\rfloor-p}$, where $p$ is the maximum precision of the
+    ///   inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See [`Float::dot_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs and the difference between the largest and smallest exponents of the products.
+    ///
+    /// # Panics
+    /// Panics if `xs` and `ys` have different lengths, or if `rm` is `Exact` but the maximum
+    /// precision of the inputs is too small for an exact dot product.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(-3)];
+    /// let ys = [Float::from(10), Float::from(1)];
+    /// let (dot, o) = Float::dot_round(xs.clone(), ys.clone(), Floor);
+    /// assert_eq!(dot.to_string(), "-2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (dot, o) = Float::dot_round(xs, ys, Ceiling);
+    /// assert_eq!(dot.to_string(), "-1.9999999999999998");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn dot_round<I: IntoIterator<Item = Float>, J: IntoIterator<Item = Float>>(
+        xs: I,
+        ys: J,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let mut sum = ExactSum::new();
+        let mut prec = 1;
+        let mut xs = xs.into_iter();
+        let mut ys = ys.into_iter();
+        loop {
+            match (xs.next(), ys.next()) {
+                (Some(x), Some(y)) => {
+                    prec = max(prec, max(x.significant_bits(), y.significant_bits()));
+                    sum.push_product(&x, y);
+                }
+                (None, None) => break,
+                _ => panic!("xs and ys must have the same length"),
+            }
+        }
+        sum.round(prec, rm)
+    }
+}
+
+impl Sum for Float {
+    /// Adds up all the [`Float`]s in an iterator. The result is the exact sum, rounded once to the
+    /// nearest value whose precision is the maximum of the precisions of the inputs.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i+\varepsilon.
+    /// $$
+    ///
+    /// See [`Float::sum_round`] for details, and [`Float::sum_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs and the difference between the largest and smallest exponents of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::iter::Sum;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(0.2), Float::from(0.3)];
+    /// assert_eq!(Float::sum(xs.into_iter()).to_string(), "0.6");
+    /// ```
+    fn sum<I>(xs: I) -> Float
+    where
+        I: Iterator<Item = Float>,
+    {
+        Float::sum_round(xs, Nearest).0
+    }
+}
+
+impl<'a> Sum<&'a Float> for Float {
+    /// Adds up all the [`Float`]s in an iterator of [`Float`] references. The result is the exact
+    /// sum, rounded once to the nearest value whose precision is the maximum of the precisions of
+    /// the inputs.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i+\varepsilon.
+    /// $$
+    ///
+    /// See [`Float::sum_round`] for details, and [`Float::sum_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the precisions of the
+    /// inputs and the difference between the largest and smallest exponents of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::iter::Sum;
+    ///
+    /// let xs = [Float::from(0.1), Float::from(0.2), Float::from(0.3)];
+    /// assert_eq!(Float::sum(xs.iter()).to_string(), "0.6");
+    /// ```
+    fn sum<I>(xs: I) -> Float
+    where
+        I: Iterator<Item = &'a Float>,
+    {
+        Float::sum_round(xs.cloned(), Nearest).0
+    }
+}
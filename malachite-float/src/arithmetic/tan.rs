@@ -0,0 +1,600 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{round_enclosed, round_near};
+use crate::arithmetic::sin_cos::{is_tiny, sin_cos_enclosure, toward_zero};
+use crate::InnerFloat::{Infinity, NaN, Zero};
+use crate::{float_either_zero, float_nan, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// The tangent of a finite, nonzero `Float`. If $x$ is tiny, $\tan x = x + x^3/3 + O(x^5)$ lies
+// strictly between $x$ and its neighbor in the direction away from zero.
+fn tan_finite(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    if is_tiny(x, prec) {
+        round_near(x, toward_zero(x).reverse(), prec, rm)
+    } else {
+        round_enclosed(prec, rm, |working_prec| {
+            let (sin, cos) = sin_cos_enclosure(x, working_prec)?;
+            sin.div(&cos, working_prec)
+        })
+    }
+}
+
+impl Float {
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// tangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Floor);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(5, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Floor);
+    /// assert_eq!(tan.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round(20, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded tangent is less than, equal to, or greater than the
+    /// exact tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// tangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Floor);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.18e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(5, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Floor);
+    /// assert_eq!(tan.to_string(), "-1.224649e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_round_ref(20, Nearest);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn tan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(NaN | Infinity { .. }) => (float_nan!(), Equal),
+            float_either_zero!() => (self.clone(), Equal),
+            _ => tan_finite(self, prec, rm),
+        }
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec(5);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec(20);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded tangent is less than, equal to, or greater than the exact
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_ref(5);
+    /// assert_eq!(tan.to_string(), "-1.25e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_prec_ref(20);
+    /// assert_eq!(tan.to_string(), "-1.224647e-16");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn tan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.tan_prec_round_ref(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the tangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Floor);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the tangent of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded tangent is less than, equal to, or greater than the exact tangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_ref`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the tangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Floor);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Ceiling);
+    /// assert_eq!(tan.to_string(), "-1.224646799147353e-16");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (tan, o) = Float::from(PI).tan_round_ref(Nearest);
+    /// assert_eq!(tan.to_string(), "-1.2246467991473532e-16");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn tan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        self.tan_prec_round_ref(self.significant_bits(), rm)
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded tangent is less than, equal to, or greater than the exact tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tan_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tan_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` and `self` is finite and nonzero, since the
+    /// tangent is then irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(5, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_round_assign(20, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn tan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let o;
+        (*self, o) = self.tan_prec_round_ref(prec, rm);
+        o
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded tangent
+    /// is less than, equal to, or greater than the exact tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the tangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tan_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), prec)`
+    /// plus the exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(5), Less);
+    /// assert_eq!(x.to_string(), "-1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_prec_assign(20), Greater);
+    /// assert_eq!(x.to_string(), "-1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn tan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tan_prec_round_assign(prec, Nearest)
+    }
+
+    /// Computes the tangent of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded tangent is less
+    /// than, equal to, or greater than the exact tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets \tan x+\varepsilon.
+    /// $$
+    /// - If $\tan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tan x$ is finite and nonzero, and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::tan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tan_prec_round_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` and `self` is finite and nonzero, since the tangent is then
+    /// irrational.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::f64::consts::PI;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Floor), Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "-1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tan_round_assign(Nearest), Less);
+    /// assert_eq!(x.to_string(), "-1.2246467991473532e-16");
+    /// ```
+    #[inline]
+    pub fn tan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tan_prec_round_assign(prec, rm)
+    }
+}
@@ -0,0 +1,1379 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::atan::{
+    rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round, rug_atan_prec, rug_atan_prec_round,
+    rug_atan_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_1_float_complexity_bucketer, pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_float_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+    triple_float_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_29, float_float_rounding_mode_triple_gen_var_29_rm,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5_rm,
+    float_float_unsigned_triple_gen_var_1, float_float_unsigned_triple_gen_var_1_rm,
+    float_rounding_mode_pair_gen_var_19, float_rounding_mode_pair_gen_var_19_rm,
+    float_unsigned_pair_gen_var_1, float_unsigned_pair_gen_var_1_rm,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+    float_unsigned_rounding_mode_triple_gen_var_5_rm,
+};
+use malachite_float::ComparableFloat;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_atan_prec);
+    register_demo!(runner, demo_float_atan_prec_debug);
+    register_demo!(runner, demo_float_atan_prec_ref);
+    register_demo!(runner, demo_float_atan_prec_ref_debug);
+    register_demo!(runner, demo_float_atan_prec_assign);
+    register_demo!(runner, demo_float_atan_prec_assign_debug);
+    register_demo!(runner, demo_float_atan_round);
+    register_demo!(runner, demo_float_atan_round_debug);
+    register_demo!(runner, demo_float_atan_round_ref);
+    register_demo!(runner, demo_float_atan_round_ref_debug);
+    register_demo!(runner, demo_float_atan_round_assign);
+    register_demo!(runner, demo_float_atan_round_assign_debug);
+    register_demo!(runner, demo_float_atan_prec_round);
+    register_demo!(runner, demo_float_atan_prec_round_debug);
+    register_demo!(runner, demo_float_atan_prec_round_ref);
+    register_demo!(runner, demo_float_atan_prec_round_ref_debug);
+    register_demo!(runner, demo_float_atan_prec_round_assign);
+    register_demo!(runner, demo_float_atan_prec_round_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec);
+    register_demo!(runner, demo_float_atan2_prec_debug);
+    register_demo!(runner, demo_float_atan2_prec_val_ref);
+    register_demo!(runner, demo_float_atan2_prec_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_ref_val);
+    register_demo!(runner, demo_float_atan2_prec_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_prec_ref_ref);
+    register_demo!(runner, demo_float_atan2_prec_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_assign);
+    register_demo!(runner, demo_float_atan2_prec_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec_assign_ref);
+    register_demo!(runner, demo_float_atan2_prec_assign_ref_debug);
+    register_demo!(runner, demo_float_atan2_round);
+    register_demo!(runner, demo_float_atan2_round_debug);
+    register_demo!(runner, demo_float_atan2_round_val_ref);
+    register_demo!(runner, demo_float_atan2_round_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_round_ref_val);
+    register_demo!(runner, demo_float_atan2_round_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_round_ref_ref);
+    register_demo!(runner, demo_float_atan2_round_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_round_assign);
+    register_demo!(runner, demo_float_atan2_round_assign_debug);
+    register_demo!(runner, demo_float_atan2_round_assign_ref);
+    register_demo!(runner, demo_float_atan2_round_assign_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round);
+    register_demo!(runner, demo_float_atan2_prec_round_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_val_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_val);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_assign);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_ref_debug);
+
+    register_bench!(runner, benchmark_float_atan_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan_prec_library_comparison);
+    register_bench!(runner, benchmark_float_atan_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan_round_library_comparison);
+    register_bench!(runner, benchmark_float_atan_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan_prec_round_library_comparison);
+    register_bench!(runner, benchmark_float_atan2_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_prec_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atan2_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atan2_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_atan_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).atan_prec({}) = {:?}", x_old, prec, x.atan_prec(prec));
+    }
+}
+
+fn demo_float_atan_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let (sum, o) = x.atan_prec(prec);
+        println!(
+            "({:#x}).atan_prec({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).atan_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.atan_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_atan_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let (sum, o) = x.atan_prec_ref(prec);
+        println!(
+            "(&{:#x}).atan_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atan_prec_assign(prec);
+        println!("x := {x_old}; x.atan_prec_assign({prec}); x = {x}");
+    }
+}
+
+fn demo_float_atan_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let o = x.atan_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.atan_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).atan_round({}) = {:?}", x_old, rm, x.atan_round(rm));
+    }
+}
+
+fn demo_float_atan_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.atan_round(rm);
+        println!(
+            "({:#x}).atan_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan_round_ref({}) = {:?}",
+            x,
+            rm,
+            x.atan_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_atan_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.atan_round_ref(rm);
+        println!(
+            "(&{:#x}).atan_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.atan_round_assign(rm);
+        println!("x := {x_old}; x.atan_round_assign({rm}); x = {x}");
+    }
+}
+
+fn demo_float_atan_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan_round_assign(rm);
+        println!(
+            "x := {:#x}; x.atan_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).atan_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.atan_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.atan_prec_round(prec, rm);
+        println!(
+            "({:#x}).atan_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.atan_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.atan_prec_round_ref(prec, rm);
+        println!(
+            "({:#x}).atan_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_atan_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.atan_prec_round({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.atan_prec_round({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).atan2_prec({}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            x.atan2_prec(y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_prec(y, prec);
+        println!(
+            "({:#x}).atan2_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_prec_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            x.atan2_prec_val_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (atan2, o) = x.atan2_prec_val_ref(&y, prec);
+        println!(
+            "({:#x}).atan2_prec_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).atan2_prec_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            x.atan2_prec_ref_val(y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_prec_ref_val(y, prec);
+        println!(
+            "(&{:#x}).atan2_prec_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_prec_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            x.atan2_prec_ref_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atan2, o) = x.atan2_prec_ref_ref(&y, prec);
+        println!(
+            "(&{:#x}).atan2_prec_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.atan2_prec_assign(y, prec);
+        println!("x := {x_old}; x.atan2_prec_assign({y_old}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.atan2_prec_assign(y, prec);
+        println!(
+            "x := {:#x}; x.atan2_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.atan2_prec_assign_ref(&y, prec);
+        println!("x := {x_old}; x.atan2_prec_assign({y}, {prec}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_assign_ref(&y, prec);
+        println!(
+            "x := {:#x}; x.atan2_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).atan2_round({}, {}) = {:?}",
+            x_old,
+            y_old,
+            rm,
+            x.atan2_round(y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_round(y, rm);
+        println!(
+            "({:#x}).atan2_round({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_round_val_ref(&{}, {}) = {:?}",
+            x_old,
+            y,
+            rm,
+            x.atan2_round_val_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (atan2, o) = x.atan2_round_val_ref(&y, rm);
+        println!(
+            "({:#x}).atan2_round_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).atan2_round_ref_val({}, {}) = {:?}",
+            x,
+            y_old,
+            rm,
+            x.atan2_round_ref_val(y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_round_ref_val(y, rm);
+        println!(
+            "(&{:#x}).atan2_round_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_round_ref_ref(&{}, {}) = {:?}",
+            x,
+            y,
+            rm,
+            x.atan2_round_ref_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atan2, o) = x.atan2_round_ref_ref(&y, rm);
+        println!(
+            "(&{:#x}).atan2_round_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.atan2_round_assign(y, rm);
+        println!("x := {x_old}; x.atan2_round_assign({y_old}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.atan2_round_assign(y, rm);
+        println!(
+            "x := {:#x}; x.atan2_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.atan2_round_assign_ref(&y, rm);
+        println!("x := {x_old}; x.atan2_round_assign({y}, {rm}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_29()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_round_assign_ref(&y, rm);
+        println!(
+            "x := {:#x}; x.atan2_round_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!(
+            "({}).atan2_prec_round({}, {}, {}) = {:?}",
+            x_old,
+            y_old,
+            prec,
+            rm,
+            x.atan2_prec_round(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_prec_round(y, prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).atan2_prec_round(&{}, {}, {}) = {:?}",
+            x_old,
+            y,
+            prec,
+            rm,
+            x.atan2_prec_round_val_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (atan2, o) = x.atan2_prec_round_val_ref(&y, prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round_val_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        println!(
+            "(&{}).atan2_prec_round_ref_val({}, {}, {}) = {:?}",
+            x,
+            y_old,
+            prec,
+            rm,
+            x.atan2_prec_round_ref_val(y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let y_old = y.clone();
+        let (atan2, o) = x.atan2_prec_round_ref_val(y, prec, rm);
+        println!(
+            "(&{:#x}).atan2_prec_round_ref_val({:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_prec_round({}, {}, {}) = {:?}",
+            x,
+            y,
+            prec,
+            rm,
+            x.atan2_prec_round_ref_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atan2, o) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round_ref_ref(&{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            ComparableFloat(y),
+            prec,
+            rm,
+            ComparableFloat(atan2),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.atan2_prec_round_assign(y, prec, rm);
+        println!("x := {x_old}; x.atan2_prec_round({y_old}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let o = x.atan2_prec_round_assign(y, prec, rm);
+        println!(
+            "x := {:#x}; x.atan2_prec_round({:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign_ref(&y, prec, rm);
+        println!("x := {x_old}; x.atan2_prec_round_ref(&{y}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {:#x}; x.atan2_prec_round_ref(&{:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_atan_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.atan_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.atan_prec(prec))
+            }),
+            ("(&Float).atan_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.atan_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.atan_prec_ref(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug_atan_prec(&x, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_19().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.atan_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.atan_round(rm))
+            }),
+            ("(&Float).atan_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.atan_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_19_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| {
+                no_out!(x.atan_round_ref(rm))
+            }),
+            ("rug", &mut |((x, rm), _)| no_out!(rug_atan_round(&x, rm))),
+        ],
+    );
+}
+
+fn benchmark_float_atan_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.atan_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.atan_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).atan_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.atan_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_5_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.atan_prec_round_ref(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_atan_prec_round(&x, prec, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.atan2_prec(Float, u64)", &mut |(x, y, prec)| {
+                no_out!(x.atan2_prec(y, prec))
+            }),
+            (
+                "Float.atan2_prec_val_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_val_ref(&y, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_val(Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_ref_val(y, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_ref_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_triple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec))| {
+                no_out!(x.atan2_prec_ref_ref(&y, prec))
+            }),
+            ("rug", &mut |((x, y, prec), _)| {
+                no_out!(rug_atan2_prec(&x, &y, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_assign(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.atan2_prec_assign(Float, u64)", &mut |(
+                mut x,
+                y,
+                prec,
+            )| {
+                no_out!(x.atan2_prec_assign(y, prec))
+            }),
+            (
+                "Float.atan2_prec_assign_ref(&Float, u64)",
+                &mut |(mut x, y, prec)| no_out!(x.atan2_prec_assign_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_29().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.atan2_round(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round(y, rm)),
+            ),
+            (
+                "Float.atan2_round_val_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_val_ref(&y, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_val(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_ref_val(y, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_ref_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_rounding_mode_triple_gen_var_29_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, rm))| {
+                no_out!(x.atan2_round_ref_ref(&y, rm))
+            }),
+            ("rug", &mut |((x, y, rm), _)| {
+                no_out!(rug_atan2_round(&x, &y, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round_assign(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_29().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.atan2_round_assign(Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.atan2_round_assign(y, rm)),
+            ),
+            (
+                "Float.atan2_round_assign_ref(&Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.atan2_round_assign_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.atan2_prec_round(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round(y, prec, rm)),
+            ),
+            (
+                "Float.atan2_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_val_ref(&y, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_ref_val(y, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_ref_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_5_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec, rm))| {
+                no_out!(x.atan2_prec_round_ref_ref(&y, prec, rm))
+            }),
+            ("rug", &mut |((x, y, prec, rm), _)| {
+                no_out!(rug_atan2_prec_round(&x, &y, prec, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round_assign(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_float_primitive_int_max_complexity_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.atan2_prec_round_assign(Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.atan2_prec_round_assign(y, prec, rm)),
+            ),
+            (
+                "Float.atan2_prec_round_assign_ref(&Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.atan2_prec_round_assign_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
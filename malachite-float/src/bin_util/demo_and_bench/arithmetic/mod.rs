@@ -11,8 +11,12 @@ use malachite_base::test_util::runner::Runner;
 pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
     add::register(runner);
+    asin::register(runner);
+    atan::register(runner);
     div::register(runner);
+    exp::register(runner);
     is_power_of_2::register(runner);
+    ln::register(runner);
     mul::register(runner);
     neg::register(runner);
     power_of_2::register(runner);
@@ -20,14 +24,21 @@ pub(crate) fn register(runner: &mut Runner) {
     shl::register(runner);
     shr::register(runner);
     sign::register(runner);
+    sin_cos::register(runner);
+    sqrt::register(runner);
     square::register(runner);
     sub::register(runner);
+    tan::register(runner);
 }
 
 mod abs;
 mod add;
+mod asin;
+mod atan;
 mod div;
+mod exp;
 mod is_power_of_2;
+mod ln;
 mod mul;
 mod neg;
 mod power_of_2;
@@ -35,5 +46,8 @@ mod reciprocal;
 mod shl;
 mod shr;
 mod sign;
+mod sin_cos;
+mod sqrt;
 mod square;
 mod sub;
+mod tan;
@@ -0,0 +1,447 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::arithmetic::tan::{
+    rug_tan_prec, rug_tan_prec_round, rug_tan_round,
+};
+use malachite_float::test_util::bench::bucketers::{
+    pair_1_float_complexity_bucketer, pair_2_pair_1_float_complexity_bucketer,
+    pair_2_pair_float_primitive_int_max_complexity_bucketer,
+    pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer,
+    pair_float_primitive_int_max_complexity_bucketer,
+    triple_1_2_float_primitive_int_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_19, float_rounding_mode_pair_gen_var_19_rm,
+    float_unsigned_pair_gen_var_1, float_unsigned_pair_gen_var_1_rm,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+    float_unsigned_rounding_mode_triple_gen_var_5_rm,
+};
+use malachite_float::ComparableFloat;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_tan_prec);
+    register_demo!(runner, demo_float_tan_prec_debug);
+    register_demo!(runner, demo_float_tan_prec_ref);
+    register_demo!(runner, demo_float_tan_prec_ref_debug);
+    register_demo!(runner, demo_float_tan_prec_assign);
+    register_demo!(runner, demo_float_tan_prec_assign_debug);
+    register_demo!(runner, demo_float_tan_round);
+    register_demo!(runner, demo_float_tan_round_debug);
+    register_demo!(runner, demo_float_tan_round_ref);
+    register_demo!(runner, demo_float_tan_round_ref_debug);
+    register_demo!(runner, demo_float_tan_round_assign);
+    register_demo!(runner, demo_float_tan_round_assign_debug);
+    register_demo!(runner, demo_float_tan_prec_round);
+    register_demo!(runner, demo_float_tan_prec_round_debug);
+    register_demo!(runner, demo_float_tan_prec_round_ref);
+    register_demo!(runner, demo_float_tan_prec_round_ref_debug);
+    register_demo!(runner, demo_float_tan_prec_round_assign);
+    register_demo!(runner, demo_float_tan_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_tan_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_tan_prec_library_comparison);
+    register_bench!(runner, benchmark_float_tan_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_tan_round_library_comparison);
+    register_bench!(runner, benchmark_float_tan_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_tan_prec_round_library_comparison);
+}
+
+fn demo_float_tan_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).tan_prec({}) = {:?}", x_old, prec, x.tan_prec(prec));
+    }
+}
+
+fn demo_float_tan_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let (sum, o) = x.tan_prec(prec);
+        println!(
+            "({:#x}).tan_prec({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "(&{}).tan_prec_ref({}) = {:?}",
+            x,
+            prec,
+            x.tan_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_tan_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let (sum, o) = x.tan_prec_ref(prec);
+        println!(
+            "(&{:#x}).tan_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.tan_prec_assign(prec);
+        println!("x := {x_old}; x.tan_prec_assign({prec}); x = {x}");
+    }
+}
+
+fn demo_float_tan_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let o = x.tan_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.tan_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_tan_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).tan_round({}) = {:?}", x_old, rm, x.tan_round(rm));
+    }
+}
+
+fn demo_float_tan_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.tan_round(rm);
+        println!(
+            "({:#x}).tan_round({}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("(&{}).tan_round_ref({}) = {:?}", x, rm, x.tan_round_ref(rm));
+    }
+}
+
+fn demo_float_tan_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.tan_round_ref(rm);
+        println!(
+            "(&{:#x}).tan_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.tan_round_assign(rm);
+        println!("x := {x_old}; x.tan_round_assign({rm}); x = {x}");
+    }
+}
+
+fn demo_float_tan_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.tan_round_assign(rm);
+        println!(
+            "x := {:#x}; x.tan_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_tan_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).tan_prec_round({}, {}) = {:?}",
+            x_old,
+            prec,
+            rm,
+            x.tan_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_tan_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let (sum, o) = x.tan_prec_round(prec, rm);
+        println!(
+            "({:#x}).tan_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).tan_prec_round_ref({}, {}) = {:?}",
+            x,
+            prec,
+            rm,
+            x.tan_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_tan_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.tan_prec_round_ref(prec, rm);
+        println!(
+            "({:#x}).tan_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloat(x),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_tan_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.tan_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.tan_prec_round({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_tan_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.tan_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.tan_prec_round({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_tan_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Float.tan_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.tan_prec(prec))
+            }),
+            ("(&Float).tan_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.tan_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_tan_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.tan_prec_ref(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| no_out!(rug_tan_prec(&x, prec))),
+        ],
+    );
+}
+
+fn benchmark_float_tan_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_19().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.tan_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.tan_round(rm))
+            }),
+            ("(&Float).tan_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.tan_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_tan_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_19_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            (
+                "Malachite",
+                &mut |(_, (x, rm))| no_out!(x.tan_round_ref(rm)),
+            ),
+            ("rug", &mut |((x, rm), _)| no_out!(rug_tan_round(&x, rm))),
+        ],
+    );
+}
+
+fn benchmark_float_tan_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.tan_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.tan_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).tan_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.tan_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_tan_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.tan_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_5_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_primitive_int_max_complexity_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.tan_prec_round_ref(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug_tan_prec_round(&x, prec, rm))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,1761 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+// The special cases, including the signs of zero parts, follow MPC, which in turn follows Annex G
+// of the C99 standard. Finite results are correctly rounded: products and squares of parts are
+// computed exactly and combined with a single rounding where possible; otherwise each part is
+// computed as an `Enclosure` whose working precision is increased until the part can be rounded
+// (Ziv's strategy). Parts that might be exactly representable are detected as soon as their
+// inputs are known exactly, and are then rounded directly.
+
+use crate::arithmetic::enclosure::{initial_working_prec, next_working_prec, Enclosure};
+use crate::arithmetic::exp::exp_bound;
+use crate::arithmetic::ln::ln_bound;
+use crate::arithmetic::sin_cos::sin_cos_enclosure;
+use crate::complex::ComplexFloat;
+use crate::Float;
+use core::cmp::Ordering::{self, *};
+use core::cmp::{max, min};
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+const fn signed_zero(negative: bool) -> Float {
+    if negative {
+        Float::NEGATIVE_ZERO
+    } else {
+        Float::ZERO
+    }
+}
+
+const fn signed_infinity(negative: bool) -> Float {
+    if negative {
+        Float::NEGATIVE_INFINITY
+    } else {
+        Float::INFINITY
+    }
+}
+
+// -1 if the sign bit of `x` is set, and 1 otherwise.
+const fn sign_bit(x: &Float) -> i8 {
+    if x.is_sign_negative() {
+        -1
+    } else {
+        1
+    }
+}
+
+// The sign of a non-NaN `Float`: -1, 0, or 1.
+fn sign(x: &Float) -> i8 {
+    if *x == 0u32 {
+        0
+    } else {
+        sign_bit(x)
+    }
+}
+
+// The sign of `x - y`, where `x` and `y` are finite: -1, 0, or 1.
+fn sign_of_difference(x: &Float, y: &Float) -> i8 {
+    match x.partial_cmp(y).unwrap() {
+        Less => -1,
+        Equal => 0,
+        Greater => 1,
+    }
+}
+
+fn exact_mul(x: &Float, y: &Float) -> Float {
+    let prec = x.get_prec().unwrap_or(1) + y.get_prec().unwrap_or(1);
+    x.mul_prec_round_ref_ref(y, prec, Exact).0
+}
+
+fn exact_square(x: &Float) -> Float {
+    x.square_prec_round_ref(x.get_prec().unwrap_or(1) << 1, Exact)
+        .0
+}
+
+// The exact sum of two positive, finite `Float`s.
+fn exact_add(x: &Float, y: &Float) -> Float {
+    let x_exponent = i64::from(x.get_exponent().unwrap());
+    let y_exponent = i64::from(y.get_exponent().unwrap());
+    let low = min(
+        x_exponent - i64::exact_from(x.get_prec().unwrap()),
+        y_exponent - i64::exact_from(y.get_prec().unwrap()),
+    );
+    let prec = u64::exact_from(max(x_exponent, y_exponent) + 1 - low);
+    x.add_prec_round_ref_ref(y, prec, Exact).0
+}
+
+// If `x` is the square of a `Float`, returns that `Float`'s absolute value. `x` must be positive
+// and finite.
+fn exact_sqrt(x: &Float) -> Option<Float> {
+    // If the square root is exact, its precision is at most that of `x`.
+    let (sqrt, o) = x.sqrt_prec_round_ref(x.get_prec().unwrap(), Floor);
+    if o == Equal {
+        Some(sqrt)
+    } else {
+        None
+    }
+}
+
+// Rounds `-x` to `prec` bits using `rm`.
+fn round_negated(x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let (y, o) = Float::from_float_prec_round_ref(x, prec, -rm);
+    (-y, o.reverse())
+}
+
+// Rounds a part of a result, given an `Enclosure` that strictly contains it. If `rm` is `Exact`, no
+// attempt is made, since such a part can only be rounded once it is known exactly.
+fn round_enclosed_part(
+    x: Option<Enclosure>,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    if rm == Exact {
+        None
+    } else {
+        x?.round(prec, rm)
+    }
+}
+
+// Calls `approximate` with increasing working precisions until it returns a rounded value.
+fn round_part<F: FnMut(u64) -> Option<(Float, Ordering)>>(
+    prec: u64,
+    mut approximate: F,
+) -> (Float, Ordering) {
+    let mut working_prec = initial_working_prec(prec);
+    loop {
+        if let Some(result) = approximate(working_prec) {
+            return result;
+        }
+        working_prec = next_working_prec(working_prec);
+    }
+}
+
+type RoundedPart = Option<(Float, Ordering)>;
+
+// Calls `approximate` with increasing working precisions until both parts of a result have been
+// rounded. `approximate` also receives flags indicating which parts are still needed, and returns
+// each part that it was able to round.
+fn round_parts<F: FnMut(u64, bool, bool) -> (RoundedPart, RoundedPart)>(
+    prec: u64,
+    mut approximate: F,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let mut re = None;
+    let mut im = None;
+    let mut working_prec = initial_working_prec(prec);
+    loop {
+        let (new_re, new_im) = approximate(working_prec, re.is_none(), im.is_none());
+        (re, im) = match (re.or(new_re), im.or(new_im)) {
+            (Some((re, o_re)), Some((im, o_im))) => return (ComplexFloat { re, im }, o_re, o_im),
+            parts => parts,
+        };
+        working_prec = next_working_prec(working_prec);
+    }
+}
+
+// Multiplies `x` by `y`, where `x` is infinite. If the schoolbook formula gives NaN for both parts,
+// the infinities are recovered as in Annex G.5.1 of the C99 standard.
+fn mul_infinite(x: &ComplexFloat, y: &ComplexFloat) -> (ComplexFloat, Ordering, Ordering) {
+    let (xrs, xis) = (sign_bit(&x.re), sign_bit(&x.im));
+    let (yrs, yis) = (sign_bit(&y.re), sign_bit(&y.im));
+    let (mut u, mut v) = if x.re.is_nan() || x.im.is_nan() || y.re.is_nan() || y.im.is_nan() {
+        (0, 0)
+    } else if x.re.is_infinite() {
+        let u = if y.re == 0u32
+            || x.im.is_infinite() && y.im == 0u32
+            || x.im == 0u32 && y.im.is_infinite()
+            || (x.im.is_infinite() || y.im.is_infinite()) && xrs * yrs == xis * yis
+        {
+            0
+        } else {
+            xrs * yrs
+        };
+        let v = if y.im == 0u32
+            || x.im.is_infinite() && y.re == 0u32
+            || x.im == 0u32 && y.re.is_infinite()
+            || x.im.is_infinite() && xrs * yis != xis * yrs
+        {
+            0
+        } else {
+            xrs * yis
+        };
+        (u, v)
+    } else {
+        let u = if y.im == 0u32
+            || x.re == 0u32 && y.re.is_infinite()
+            || y.re.is_infinite() && xrs * yrs == xis * yis
+        {
+            0
+        } else {
+            -xis * yis
+        };
+        let v = if y.re == 0u32
+            || x.re == 0u32 && y.im.is_infinite()
+            || y.im.is_infinite() && xrs * yis != xis * yrs
+        {
+            0
+        } else {
+            xis * yrs
+        };
+        (u, v)
+    };
+    if u == 0 && v == 0 {
+        let xr = i8::from(x.re.is_infinite());
+        let xi = i8::from(x.im.is_infinite());
+        let (yr, yi) = if y.is_infinite() {
+            (i8::from(y.re.is_infinite()), i8::from(y.im.is_infinite()))
+        } else {
+            (
+                i8::from(y.re != 0u32 && !y.re.is_nan()),
+                i8::from(y.im != 0u32 && !y.im.is_nan()),
+            )
+        };
+        u = xrs * xr * yrs * yr - xis * xi * yis * yi;
+        v = xrs * xr * yis * yi + xis * xi * yrs * yr;
+    }
+    let part = |s: i8| {
+        if s == 0 {
+            Float::NAN
+        } else {
+            signed_infinity(s < 0)
+        }
+    };
+    (
+        ComplexFloat {
+            re: part(u),
+            im: part(v),
+        },
+        Equal,
+        Equal,
+    )
+}
+
+// Multiplies `x` by `y`, where both are finite and `y` has a zero imaginary part.
+fn mul_real(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (mut re, o_re) = x.re.mul_prec_round_ref_ref(&y.re, prec_re, rm_re);
+    let (mut im, o_im) = x.im.mul_prec_round_ref_ref(&y.re, prec_im, rm_im);
+    let (xrs, xis) = (x.re.is_sign_negative(), x.im.is_sign_negative());
+    let (yrs, yis) = (y.re.is_sign_negative(), y.im.is_sign_negative());
+    if re == 0u32 {
+        re = signed_zero(rm_re == Floor || xrs != yrs && xis == yis);
+    }
+    if im == 0u32 {
+        im = signed_zero(rm_im == Floor || xrs != yis && xis != yrs);
+    }
+    (ComplexFloat { re, im }, o_re, o_im)
+}
+
+// Multiplies `x` by `y`, where both are finite, both have nonzero imaginary parts, and `y` has a
+// zero real part.
+fn mul_imag(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (re, o_re) = (-&x.im).mul_prec_round_ref_ref(&y.im, prec_re, rm_re);
+    let (mut im, o_im) = x.re.mul_prec_round_ref_ref(&y.im, prec_im, rm_im);
+    if im == 0u32 {
+        let (xrs, xis) = (x.re.is_sign_negative(), x.im.is_sign_negative());
+        let (yrs, yis) = (y.re.is_sign_negative(), y.im.is_sign_negative());
+        im = signed_zero(rm_im == Floor || yrs != xis && yis != xrs);
+    }
+    (ComplexFloat { re, im }, o_re, o_im)
+}
+
+// Divides `x` by zero.
+fn div_zero(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let infinity = signed_infinity(y.re.is_sign_negative());
+    let (re, o_re) = infinity.mul_prec_round_ref_ref(&x.re, prec_re, rm_re);
+    let (im, o_im) = infinity.mul_prec_round_ref_ref(&x.im, prec_im, rm_im);
+    (ComplexFloat { re, im }, o_re, o_im)
+}
+
+// Divides `x` by `y`, where `x` is infinite and `y` is finite and nonzero.
+fn div_infinite_finite(x: &ComplexFloat, y: &ComplexFloat) -> (ComplexFloat, Ordering, Ordering) {
+    let a = if x.re.is_infinite() {
+        sign_bit(&x.re)
+    } else {
+        0
+    };
+    let b = if x.im.is_infinite() {
+        sign_bit(&x.im)
+    } else {
+        0
+    };
+    let (c, d) = (&y.re, &y.im);
+    let (u, v) = if a == 0 || b == 0 {
+        (a * sign(c) + b * sign(d), b * sign(c) - a * sign(d))
+    } else {
+        // The signs of a * c + b * d and b * c - a * d
+        let neg_d = -d;
+        match (a, b) {
+            (1, 1) => (sign_of_difference(c, &neg_d), sign_of_difference(c, d)),
+            (1, _) => (sign_of_difference(c, d), -sign_of_difference(c, &neg_d)),
+            (_, 1) => (sign_of_difference(d, c), sign_of_difference(c, &neg_d)),
+            _ => (-sign_of_difference(c, &neg_d), sign_of_difference(d, c)),
+        }
+    };
+    let part = |s: i8| {
+        if s == 0 {
+            Float::NAN
+        } else {
+            signed_infinity(s < 0)
+        }
+    };
+    (
+        ComplexFloat {
+            re: part(u),
+            im: part(v),
+        },
+        Equal,
+        Equal,
+    )
+}
+
+// Divides `x` by `y`, where `x` is finite and `y` is infinite. The result is zero; the signs of its
+// parts are those of the quotient of `x` and `y` with the infinite parts of `y` replaced by 1 and
+// the others by 0.
+fn div_finite_infinite(x: &ComplexFloat, y: &ComplexFloat) -> (ComplexFloat, Ordering, Ordering) {
+    let unit = |x: &Float| {
+        let negative = x.is_sign_negative();
+        if x.is_infinite() {
+            if negative {
+                Float::NEGATIVE_ONE
+            } else {
+                Float::ONE
+            }
+        } else {
+            signed_zero(negative)
+        }
+    };
+    let c = unit(&y.re);
+    let d = unit(&y.im);
+    let u = &x.re * &c + &x.im * &d;
+    let v = &x.im * &c - &x.re * &d;
+    (
+        ComplexFloat {
+            re: signed_zero(u.is_sign_negative()),
+            im: signed_zero(v.is_sign_negative()),
+        },
+        Equal,
+        Equal,
+    )
+}
+
+// Divides `x` by `y`, where both are finite and `y` has a zero imaginary part and a nonzero real
+// part.
+fn div_real(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (mut re, o_re) = x.re.div_prec_round_ref_ref(&y.re, prec_re, rm_re);
+    let (mut im, o_im) = x.im.div_prec_round_ref_ref(&y.re, prec_im, rm_im);
+    let (xrs, xis) = (x.re.is_sign_negative(), x.im.is_sign_negative());
+    let (yrs, yis) = (y.re.is_sign_negative(), y.im.is_sign_negative());
+    if re == 0u32 {
+        re = signed_zero(xrs != yrs && xis != yis);
+    }
+    if im == 0u32 {
+        im = signed_zero(xis != yrs && xrs == yis);
+    }
+    (ComplexFloat { re, im }, o_re, o_im)
+}
+
+// Divides `x` by `y`, where both are finite and `y` has a zero real part and a nonzero imaginary
+// part.
+fn div_imag(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (mut re, o_re) = x.im.div_prec_round_ref_ref(&y.im, prec_re, rm_re);
+    let (mut im, o_im) = x.re.div_prec_round_ref_ref(&-&y.im, prec_im, rm_im);
+    let (xrs, xis) = (x.re.is_sign_negative(), x.im.is_sign_negative());
+    let (yrs, yis) = (y.re.is_sign_negative(), y.im.is_sign_negative());
+    if re == 0u32 {
+        re = signed_zero(xrs != yrs && xis != yis);
+    }
+    if x.re == 0u32 {
+        im = signed_zero(xis != yrs && xrs == yis);
+    }
+    (ComplexFloat { re, im }, o_re, o_im)
+}
+
+// Divides `x` by `y`, where both are finite and both parts of `y` are nonzero. Each part of the
+// quotient is a sum of exact products divided by the exact norm of `y`. Once the working precision
+// suffices to represent the sum and the norm exactly, the part is obtained by a single correctly
+// rounded division.
+fn div_general(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (a, b, c, d) = (&x.re, &x.im, &y.re, &y.im);
+    let c_squared = exact_square(c);
+    let d_squared = exact_square(d);
+    let numerators = [(exact_mul(a, c), exact_mul(b, d)), (exact_mul(b, c), -exact_mul(a, d))];
+    // A zero numerator gives a zero part, whose sign is that of the numerator's exact sum.
+    let zero_part = |i: usize| {
+        let (p, q) = &numerators[i];
+        if *p == -q {
+            Some((p.add_prec_round_ref_ref(q, 1, Nearest).0, Equal))
+        } else {
+            None
+        }
+    };
+    let (zero_re, zero_im) = (zero_part(0), zero_part(1));
+    round_parts(max(prec_re, prec_im), |working_prec, need_re, need_im| {
+        let (norm_lo, o) = c_squared.add_prec_round_ref_ref(&d_squared, working_prec, Floor);
+        let norm_is_exact = o == Equal;
+        let norm = Enclosure {
+            hi: if norm_is_exact {
+                norm_lo.clone()
+            } else {
+                c_squared
+                    .add_prec_round_ref_ref(&d_squared, working_prec, Ceiling)
+                    .0
+            },
+            lo: norm_lo,
+        };
+        let part = |i: usize, prec: u64, rm: RoundingMode| {
+            let (p, q) = &numerators[i];
+            let (numerator_lo, o) = p.add_prec_round_ref_ref(q, working_prec, Floor);
+            if o == Equal && norm_is_exact {
+                return Some(numerator_lo.div_prec_round_ref_ref(&norm.lo, prec, rm));
+            }
+            let numerator = Enclosure {
+                hi: p.add_prec_round_ref_ref(q, working_prec, Ceiling).0,
+                lo: numerator_lo,
+            };
+            round_enclosed_part(numerator.div(&norm, working_prec), prec, rm)
+        };
+        (
+            if need_re {
+                zero_re.clone().or_else(|| part(0, prec_re, rm_re))
+            } else {
+                None
+            },
+            if need_im {
+                zero_im.clone().or_else(|| part(1, prec_im, rm_im))
+            } else {
+                None
+            },
+        )
+    })
+}
+
+// The square root of `x`, where both parts of `x` are finite and nonzero.
+//
+// Let $x = a+bi$ and $t = (|x|+|a|)/2$. Then $\sqrt t$ is the real part of the square root if $a >
+// 0$, and the absolute value of its imaginary part otherwise; the other part is $b/(2\sqrt t)$ in
+// absolute value. The square root can only be exact if $|x|$ and $\sqrt t$ are both exact, which is
+// checked as soon as $|x|^2$ is known exactly.
+fn sqrt_general(
+    x: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    let (a, b) = (&x.re, &x.im);
+    let abs_a = a.abs();
+    let abs_b = b.abs();
+    let a_squared = exact_square(a);
+    let b_squared = exact_square(b);
+    let real_is_larger = *a > 0u32;
+    let b_negative = *b < 0u32;
+    // Given $\sqrt t$ and $|b|/(2\sqrt t)$, rounds the parts of the square root, or returns `None`
+    // for parts that cannot be rounded yet.
+    let assign = |root: (RoundedPart, RoundedPart)| -> (RoundedPart, RoundedPart) {
+        let (sqrt_t, quotient) = root;
+        if real_is_larger {
+            (sqrt_t, quotient)
+        } else {
+            (quotient, sqrt_t)
+        }
+    };
+    let (prec_sqrt_t, rm_sqrt_t, prec_quotient, rm_quotient) = if real_is_larger {
+        (prec_re, rm_re, prec_im, rm_im)
+    } else {
+        (prec_im, rm_im, prec_re, rm_re)
+    };
+    // The part equal to $\pm\sqrt t$ has the sign of $b$ if it is the imaginary part; the other
+    // part has the sign of $b$ if it is the imaginary part, and is positive otherwise.
+    let sqrt_t_negative = !real_is_larger && b_negative;
+    let quotient_negative = real_is_larger && b_negative;
+    let round_signed = |x: &Float, negative: bool, prec: u64, rm: RoundingMode| {
+        if negative {
+            round_negated(x, prec, rm)
+        } else {
+            Float::from_float_prec_round_ref(x, prec, rm)
+        }
+    };
+    let round_signed_enclosure = |x: Enclosure, negative: bool, prec: u64, rm: RoundingMode| {
+        round_enclosed_part(Some(if negative { x.neg() } else { x }), prec, rm)
+    };
+    // Set once the square root is known to be inexact.
+    let mut irrational = false;
+    round_parts(max(prec_re, prec_im), |working_prec, need_re, need_im| {
+        let (norm_squared_lo, o) =
+            a_squared.add_prec_round_ref_ref(&b_squared, working_prec, Floor);
+        if o == Equal && !irrational {
+            if let Some(abs_x) = exact_sqrt(&norm_squared_lo) {
+                let t = exact_add(&abs_x, &abs_a) >> 1u32;
+                if let Some(sqrt_t) = exact_sqrt(&t) {
+                    let divisor = if quotient_negative {
+                        -(&sqrt_t << 1u32)
+                    } else {
+                        &sqrt_t << 1u32
+                    };
+                    return assign((
+                        Some(round_signed(
+                            &sqrt_t,
+                            sqrt_t_negative,
+                            prec_sqrt_t,
+                            rm_sqrt_t,
+                        )),
+                        Some(abs_b.div_prec_round_ref_ref(&divisor, prec_quotient, rm_quotient)),
+                    ));
+                }
+            }
+            irrational = true;
+            assert!(rm_re != Exact && rm_im != Exact, "Inexact result");
+        }
+        let norm_squared = Enclosure {
+            hi: a_squared
+                .add_prec_round_ref_ref(&b_squared, working_prec, Ceiling)
+                .0,
+            lo: norm_squared_lo,
+        };
+        let t = norm_squared
+            .sqrt(working_prec)
+            .add(&Enclosure::exact(abs_a.clone()), working_prec)
+            .shl(-1);
+        let sqrt_t = t.sqrt(working_prec);
+        let quotient = Enclosure::exact(abs_b.clone()).div(&sqrt_t.clone().shl(1), working_prec);
+        let (need_sqrt_t, need_quotient) = if real_is_larger {
+            (need_re, need_im)
+        } else {
+            (need_im, need_re)
+        };
+        assign((
+            if need_sqrt_t {
+                round_signed_enclosure(sqrt_t, sqrt_t_negative, prec_sqrt_t, rm_sqrt_t)
+            } else {
+                None
+            },
+            if need_quotient {
+                quotient.and_then(|quotient| {
+                    round_signed_enclosure(quotient, quotient_negative, prec_quotient, rm_quotient)
+                })
+            } else {
+                None
+            },
+        ))
+    })
+}
+
+// The absolute value of `x`, where both parts of `x` are finite and nonzero. Once $|x|^2$ is known
+// exactly, its square root is rounded directly.
+fn abs_general(x: &ComplexFloat, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    let a_squared = exact_square(&x.re);
+    let b_squared = exact_square(&x.im);
+    round_part(prec, |working_prec| {
+        let (lo, o) = a_squared.add_prec_round_ref_ref(&b_squared, working_prec, Floor);
+        if o == Equal {
+            return Some(lo.sqrt_prec_round(prec, rm));
+        }
+        let norm_squared = Enclosure {
+            lo,
+            hi: a_squared
+                .add_prec_round_ref_ref(&b_squared, working_prec, Ceiling)
+                .0,
+        };
+        round_enclosed_part(Some(norm_squared.sqrt(working_prec)), prec, rm)
+    })
+}
+
+// The exponential of `x`, where both parts of `x` are finite and nonzero. Both parts of the result
+// are irrational.
+fn exp_general(
+    x: &ComplexFloat,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) -> (ComplexFloat, Ordering, Ordering) {
+    assert!(rm_re != Exact && rm_im != Exact, "Inexact result");
+    round_parts(max(prec_re, prec_im), |working_prec, need_re, need_im| {
+        let Some((sin, cos)) = sin_cos_enclosure(&x.im, working_prec) else {
+            return (None, None);
+        };
+        let exp = Enclosure {
+            lo: exp_bound(&x.re, working_prec, Floor),
+            hi: exp_bound(&x.re, working_prec, Ceiling),
+        };
+        (
+            if need_re {
+                exp.mul(&cos, working_prec).round(prec_re, rm_re)
+            } else {
+                None
+            },
+            if need_im {
+                exp.mul(&sin, working_prec).round(prec_im, rm_im)
+            } else {
+                None
+            },
+        )
+    })
+}
+
+// The real part of the logarithm of `x`, $\log(a^2+b^2)/2$, where both parts of `x` are finite and
+// nonzero. Since $a^2+b^2 \neq 1$, it is irrational.
+fn ln_abs_general(x: &ComplexFloat, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(rm, Exact, "Inexact result");
+    let a_squared = exact_square(&x.re);
+    let b_squared = exact_square(&x.im);
+    round_part(prec, |working_prec| {
+        let bound = |rm: RoundingMode| {
+            let norm_squared = a_squared
+                .add_prec_round_ref_ref(&b_squared, working_prec, rm)
+                .0;
+            if norm_squared == 1u32 {
+                Float::ZERO
+            } else {
+                ln_bound(&norm_squared, working_prec, rm)
+            }
+        };
+        Enclosure {
+            lo: bound(Floor),
+            hi: bound(Ceiling),
+        }
+        .shl(-1)
+        .round(prec, rm)
+    })
+}
+
+impl ComplexFloat {
+    /// Adds two [`ComplexFloat`]s, rounding the real part of the result to `prec_re` bits using
+    /// `rm_re` and the imaginary part to `prec_im` bits using `rm_im`. Both [`ComplexFloat`]s are
+    /// taken by reference. Two [`Ordering`]s are also returned, indicating whether the real and
+    /// imaginary parts of the rounded sum are less than, equal to, or greater than those of the
+    /// exact sum.
+    ///
+    /// Each part is the sum of the corresponding parts of the inputs, computed as by
+    /// [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (sum, o_re, o_im) = x.add_prec_round(&y, 2, 10, Floor, Nearest);
+    /// assert_eq!(sum.to_string(), "(1.5, 2.0)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    pub fn add_prec_round(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        let (re, o_re) = self.re.add_prec_round_ref_ref(&other.re, prec_re, rm_re);
+        let (im, o_im) = self.im.add_prec_round_ref_ref(&other.im, prec_im, rm_im);
+        (ComplexFloat { re, im }, o_re, o_im)
+    }
+
+    /// Adds two [`ComplexFloat`]s, rounding the real part of the result to the nearest value of
+    /// precision `prec_re` and the imaginary part to the nearest value of precision `prec_im`. Both
+    /// [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are also returned, indicating
+    /// whether the real and imaginary parts of the rounded sum are less than, equal to, or greater
+    /// than those of the exact sum.
+    ///
+    /// See [`ComplexFloat::add_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (sum, o_re, o_im) = x.add_prec(&y, 10, 10);
+    /// assert_eq!(sum.to_string(), "(1.75, 2.0)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    #[inline]
+    pub fn add_prec(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        self.add_prec_round(other, prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Subtracts one [`ComplexFloat`] from another, rounding the real part of the result to
+    /// `prec_re` bits using `rm_re` and the imaginary part to `prec_im` bits using `rm_im`. Both
+    /// [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are also returned, indicating
+    /// whether the real and imaginary parts of the rounded difference are less than, equal to, or
+    /// greater than those of the exact difference.
+    ///
+    /// Each part is the difference of the corresponding parts of the inputs, computed as by
+    /// [`Float::sub_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (difference, o_re, o_im) = x.sub_prec_round(&y, 2, 10, Ceiling, Nearest);
+    /// assert_eq!(difference.to_string(), "(1.5, 4.0)");
+    /// assert_eq!(o_re, Greater);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    pub fn sub_prec_round(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        let (re, o_re) = self.re.sub_prec_round_ref_ref(&other.re, prec_re, rm_re);
+        let (im, o_im) = self.im.sub_prec_round_ref_ref(&other.im, prec_im, rm_im);
+        (ComplexFloat { re, im }, o_re, o_im)
+    }
+
+    /// Subtracts one [`ComplexFloat`] from another, rounding the real part of the result to the
+    /// nearest value of precision `prec_re` and the imaginary part to the nearest value of
+    /// precision `prec_im`. Both [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are
+    /// also returned, indicating whether the real and imaginary parts of the rounded difference are
+    /// less than, equal to, or greater than those of the exact difference.
+    ///
+    /// See [`ComplexFloat::sub_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (difference, o_re, o_im) = x.sub_prec(&y, 10, 10);
+    /// assert_eq!(difference.to_string(), "(1.25, 4.0)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    #[inline]
+    pub fn sub_prec(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        self.sub_prec_round(other, prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Multiplies two [`ComplexFloat`]s, rounding the real part of the result to `prec_re` bits
+    /// using `rm_re` and the imaginary part to `prec_im` bits using `rm_im`. Both
+    /// [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are also returned, indicating
+    /// whether the real and imaginary parts of the rounded product are less than, equal to, or
+    /// greater than those of the exact product.
+    ///
+    /// The products of the parts are computed exactly, so each part of the result is rounded only
+    /// once. If either input is infinite (has an infinite part), the result is infinite unless the
+    /// other input is zero or NaN, as specified by Annex G of the C99 standard. Otherwise, if
+    /// either input has a NaN part, both parts of the result are NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (product, o_re, o_im) = x.mul_prec_round(&y, 3, 10, Floor, Nearest);
+    /// assert_eq!(product.to_string(), "(3.0, -0.75)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Equal);
+    ///
+    /// let x = ComplexFloat::new(Float::INFINITY, Float::ONE);
+    /// let (product, o_re, o_im) = x.mul_prec_round(&y, 10, 10, Nearest, Nearest);
+    /// assert_eq!(product.to_string(), "(Infinity, -Infinity)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    pub fn mul_prec_round(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        assert_ne!(prec_re, 0);
+        assert_ne!(prec_im, 0);
+        if self.is_infinite() {
+            return mul_infinite(self, other);
+        } else if other.is_infinite() {
+            return mul_infinite(other, self);
+        } else if self.re.is_nan() || self.im.is_nan() || other.re.is_nan() || other.im.is_nan() {
+            return (
+                ComplexFloat {
+                    re: Float::NAN,
+                    im: Float::NAN,
+                },
+                Equal,
+                Equal,
+            );
+        } else if self.im == 0u32 {
+            return mul_real(other, self, prec_re, prec_im, rm_re, rm_im);
+        } else if other.im == 0u32 {
+            return mul_real(self, other, prec_re, prec_im, rm_re, rm_im);
+        } else if self.re == 0u32 {
+            return mul_imag(other, self, prec_re, prec_im, rm_re, rm_im);
+        } else if other.re == 0u32 {
+            return mul_imag(self, other, prec_re, prec_im, rm_re, rm_im);
+        }
+        let (a, b, c, d) = (&self.re, &self.im, &other.re, &other.im);
+        let (re, o_re) = exact_mul(a, c).sub_prec_round(exact_mul(b, d), prec_re, rm_re);
+        let (im, o_im) = exact_mul(a, d).add_prec_round(exact_mul(b, c), prec_im, rm_im);
+        (ComplexFloat { re, im }, o_re, o_im)
+    }
+
+    /// Multiplies two [`ComplexFloat`]s, rounding the real part of the result to the nearest value
+    /// of precision `prec_re` and the imaginary part to the nearest value of precision `prec_im`.
+    /// Both [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are also returned,
+    /// indicating whether the real and imaginary parts of the rounded product are less than, equal
+    /// to, or greater than those of the exact product.
+    ///
+    /// See [`ComplexFloat::mul_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(3));
+    /// let y = ComplexFloat::new(Float::from(0.25), Float::from(-1));
+    /// let (product, o_re, o_im) = x.mul_prec(&y, 10, 10);
+    /// assert_eq!(product.to_string(), "(3.375, -0.75)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    #[inline]
+    pub fn mul_prec(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        self.mul_prec_round(other, prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Divides one [`ComplexFloat`] by another, rounding the real part of the result to `prec_re`
+    /// bits using `rm_re` and the imaginary part to `prec_im` bits using `rm_im`. Both
+    /// [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are also returned, indicating
+    /// whether the real and imaginary parts of the rounded quotient are less than, equal to, or
+    /// greater than those of the exact quotient.
+    ///
+    /// Each part of the result is correctly rounded, and exact quotients are detected. Special
+    /// cases follow Annex G of the C99 standard: dividing by zero multiplies each part of the
+    /// dividend by an infinity; an infinite dividend and a finite divisor give an infinite
+    /// quotient; a finite dividend and an infinite divisor give a zero quotient; and any other
+    /// quotient involving an infinite or NaN part is NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::from(2));
+    /// let y = ComplexFloat::new(Float::from(3), Float::from(4));
+    /// let (quotient, o_re, o_im) = x.div_prec_round(&y, 10, 10, Floor, Ceiling);
+    /// assert_eq!(quotient.to_string(), "(0.4399, 0.0801)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Greater);
+    ///
+    /// let x = ComplexFloat::new(Float::from(5), Float::ZERO);
+    /// let y = ComplexFloat::new(Float::from(2), Float::ONE);
+    /// let (quotient, o_re, o_im) = x.div_prec_round(&y, 10, 10, Exact, Exact);
+    /// assert_eq!(quotient.to_string(), "(2.0, -1.0)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    /// ```
+    pub fn div_prec_round(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        assert_ne!(prec_re, 0);
+        assert_ne!(prec_im, 0);
+        if other.is_zero() {
+            div_zero(self, other, prec_re, prec_im, rm_re, rm_im)
+        } else if self.is_infinite() && other.is_finite() {
+            div_infinite_finite(self, other)
+        } else if self.is_finite() && other.is_infinite() {
+            div_finite_infinite(self, other)
+        } else if !self.is_finite() || !other.is_finite() {
+            (
+                ComplexFloat {
+                    re: Float::NAN,
+                    im: Float::NAN,
+                },
+                Equal,
+                Equal,
+            )
+        } else if other.im == 0u32 {
+            div_real(self, other, prec_re, prec_im, rm_re, rm_im)
+        } else if other.re == 0u32 {
+            div_imag(self, other, prec_re, prec_im, rm_re, rm_im)
+        } else {
+            div_general(self, other, prec_re, prec_im, rm_re, rm_im)
+        }
+    }
+
+    /// Divides one [`ComplexFloat`] by another, rounding the real part of the result to the
+    /// nearest value of precision `prec_re` and the imaginary part to the nearest value of
+    /// precision `prec_im`. Both [`ComplexFloat`]s are taken by reference. Two [`Ordering`]s are
+    /// also returned, indicating whether the real and imaginary parts of the rounded quotient are
+    /// less than, equal to, or greater than those of the exact quotient.
+    ///
+    /// See [`ComplexFloat::div_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the inputs.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::from(2));
+    /// let y = ComplexFloat::new(Float::from(3), Float::from(4));
+    /// let (quotient, o_re, o_im) = x.div_prec(&y, 10, 10);
+    /// assert_eq!(quotient.to_string(), "(0.4399, 0.08)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Less);
+    /// ```
+    #[inline]
+    pub fn div_prec(
+        &self,
+        other: &ComplexFloat,
+        prec_re: u64,
+        prec_im: u64,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        self.div_prec_round(other, prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Computes the principal square root of a [`ComplexFloat`], rounding the real part of the
+    /// result to `prec_re` bits using `rm_re` and the imaginary part to `prec_im` bits using
+    /// `rm_im`. The [`ComplexFloat`] is taken by reference. Two [`Ordering`]s are also returned,
+    /// indicating whether the real and imaginary parts of the rounded square root are less than,
+    /// equal to, or greater than those of the exact square root.
+    ///
+    /// The real part of the result is never negative, and the imaginary part has the sign of the
+    /// imaginary part of the input, even if that is zero. Exact square roots are detected.
+    ///
+    /// Special cases:
+    /// - $f(x\pm\infty i)=\infty\pm\infty i$, even if $x$ is NaN
+    /// - $f(-\infty\pm yi)=0.0\pm\infty i$ if $y$ is finite
+    /// - $f(\infty\pm yi)=\infty\pm0.0 i$ if $y$ is finite
+    /// - $f(-\infty+\text{NaN}i)=\text{NaN}+\infty i$
+    /// - $f(\infty+\text{NaN}i)=\infty+\text{NaN}i$
+    /// - $f(z)=\text{NaN}+\text{NaN}i$ if $z$ has a NaN part and no infinite part
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(3), Float::from(4));
+    /// let (sqrt, o_re, o_im) = x.sqrt_prec_round(10, 10, Exact, Exact);
+    /// assert_eq!(sqrt.to_string(), "(2.0, 1.0)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Equal);
+    ///
+    /// let x = ComplexFloat::new(Float::from(-1), Float::from(1));
+    /// let (sqrt, o_re, o_im) = x.sqrt_prec_round(10, 10, Floor, Ceiling);
+    /// assert_eq!(sqrt.to_string(), "(0.4551, 1.1)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Greater);
+    /// ```
+    pub fn sqrt_prec_round(
+        &self,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        assert_ne!(prec_re, 0);
+        assert_ne!(prec_im, 0);
+        let (a, b) = (&self.re, &self.im);
+        let b_negative = b.is_sign_negative();
+        let special = |re: Float, im: Float| (ComplexFloat { re, im }, Equal, Equal);
+        if b.is_infinite() {
+            return special(Float::INFINITY, signed_infinity(b_negative));
+        } else if a.is_infinite() {
+            return if a.is_sign_negative() {
+                if b.is_nan() {
+                    special(Float::NAN, signed_infinity(b_negative))
+                } else {
+                    special(Float::ZERO, signed_infinity(b_negative))
+                }
+            } else if b.is_nan() {
+                special(Float::INFINITY, Float::NAN)
+            } else {
+                special(Float::INFINITY, signed_zero(b_negative))
+            };
+        } else if a.is_nan() || b.is_nan() {
+            return special(Float::NAN, Float::NAN);
+        }
+        if *b == 0u32 {
+            if *a == 0u32 {
+                special(Float::ZERO, b.clone())
+            } else if *a > 0u32 {
+                let (re, o_re) = a.sqrt_prec_round_ref(prec_re, rm_re);
+                (ComplexFloat { re, im: b.clone() }, o_re, Equal)
+            } else {
+                let (im, o_im) = if b_negative {
+                    let (im, o) = (-a).sqrt_prec_round(prec_im, -rm_im);
+                    (-im, o.reverse())
+                } else {
+                    (-a).sqrt_prec_round(prec_im, rm_im)
+                };
+                (
+                    ComplexFloat {
+                        re: Float::ZERO,
+                        im,
+                    },
+                    Equal,
+                    o_im,
+                )
+            }
+        } else if *a == 0u32 {
+            let half_abs_b = b.abs() >> 1u32;
+            let (re, o_re) = half_abs_b.sqrt_prec_round_ref(prec_re, rm_re);
+            let (im, o_im) = if b_negative {
+                let (im, o) = half_abs_b.sqrt_prec_round(prec_im, -rm_im);
+                (-im, o.reverse())
+            } else {
+                half_abs_b.sqrt_prec_round(prec_im, rm_im)
+            };
+            (ComplexFloat { re, im }, o_re, o_im)
+        } else {
+            sqrt_general(self, prec_re, prec_im, rm_re, rm_im)
+        }
+    }
+
+    /// Computes the principal square root of a [`ComplexFloat`], rounding the real part of the
+    /// result to the nearest value of precision `prec_re` and the imaginary part to the nearest
+    /// value of precision `prec_im`. The [`ComplexFloat`] is taken by reference. Two [`Ordering`]s
+    /// are also returned, indicating whether the real and imaginary parts of the rounded square
+    /// root are less than, equal to, or greater than those of the exact square root.
+    ///
+    /// See [`ComplexFloat::sqrt_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(-1), Float::from(1));
+    /// let (sqrt, o_re, o_im) = x.sqrt_prec(10, 10);
+    /// assert_eq!(sqrt.to_string(), "(0.4551, 1.1)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Greater);
+    /// ```
+    #[inline]
+    pub fn sqrt_prec(&self, prec_re: u64, prec_im: u64) -> (ComplexFloat, Ordering, Ordering) {
+        self.sqrt_prec_round(prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Computes the absolute value of a [`ComplexFloat`], $\sqrt{a^2+b^2}$, rounding the result to
+    /// the specified precision with the specified rounding mode. The [`ComplexFloat`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded absolute value
+    /// is less than, equal to, or greater than the exact absolute value.
+    ///
+    /// The absolute value is infinite if either part is infinite, even if the other part is NaN.
+    /// Otherwise it is NaN if either part is NaN. Exact absolute values are detected.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec` and the
+    /// precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(3), Float::from(-4));
+    /// let (abs, o) = x.abs_prec_round(10, Exact);
+    /// assert_eq!(abs.to_string(), "5.0");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let x = ComplexFloat::new(Float::from(1), Float::from(1));
+    /// let (abs, o) = x.abs_prec_round(10, Floor);
+    /// assert_eq!(abs.to_string(), "1.414");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn abs_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        let (a, b) = (&self.re, &self.im);
+        if a.is_infinite() || b.is_infinite() {
+            (Float::INFINITY, Equal)
+        } else if a.is_nan() || b.is_nan() {
+            (Float::NAN, Equal)
+        } else if *a == 0u32 {
+            Float::from_float_prec_round(b.abs(), prec, rm)
+        } else if *b == 0u32 {
+            Float::from_float_prec_round(a.abs(), prec, rm)
+        } else {
+            abs_general(self, prec, rm)
+        }
+    }
+
+    /// Computes the absolute value of a [`ComplexFloat`], $\sqrt{a^2+b^2}$, rounding the result to
+    /// the nearest value of the specified precision. The [`ComplexFloat`] is taken by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded absolute value is less
+    /// than, equal to, or greater than the exact absolute value.
+    ///
+    /// See [`ComplexFloat::abs_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec` and the
+    /// precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1), Float::from(1));
+    /// let (abs, o) = x.abs_prec(10);
+    /// assert_eq!(abs.to_string(), "1.414");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn abs_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.abs_prec_round(prec, Nearest)
+    }
+
+    /// Computes the argument of a [`ComplexFloat`], the angle $\theta$ in $[-\pi, \pi]$ with $a+bi
+    /// = |a+bi|e^{i\theta}$, rounding the result to the specified precision with the specified
+    /// rounding mode. The [`ComplexFloat`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded argument is less than, equal to, or greater than
+    /// the exact argument.
+    ///
+    /// The argument of $a+bi$ is computed as by [`Float::atan2_prec_round`] applied to $b$ and $a$,
+    /// so the signs of zero parts determine the results for points on the real axis.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec` and the
+    /// precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeZero, One};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (arg, o) = x.arg_prec_round(10, Floor);
+    /// assert_eq!(arg.to_string(), "0.785");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = ComplexFloat::new(Float::from(-1), Float::NEGATIVE_ZERO);
+    /// let (arg, o) = x.arg_prec_round(10, Floor);
+    /// assert_eq!(arg.to_string(), "-3.145");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn arg_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.im.atan2_prec_round_ref_ref(&self.re, prec, rm)
+    }
+
+    /// Computes the argument of a [`ComplexFloat`], the angle $\theta$ in $[-\pi, \pi]$ with $a+bi
+    /// = |a+bi|e^{i\theta}$, rounding the result to the nearest value of the specified precision.
+    /// The [`ComplexFloat`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded argument is less than, equal to, or greater than the exact argument.
+    ///
+    /// See [`ComplexFloat::arg_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec` and the
+    /// precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (arg, o) = x.arg_prec(10);
+    /// assert_eq!(arg.to_string(), "0.785");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn arg_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.arg_prec_round(prec, Nearest)
+    }
+
+    /// Computes the exponential of a [`ComplexFloat`], $e^a(\cos b+i\sin b)$, rounding the real
+    /// part of the result to `prec_re` bits using `rm_re` and the imaginary part to `prec_im` bits
+    /// using `rm_im`. The [`ComplexFloat`] is taken by reference. Two [`Ordering`]s are also
+    /// returned, indicating whether the real and imaginary parts of the rounded exponential are
+    /// less than, equal to, or greater than those of the exact exponential.
+    ///
+    /// If the imaginary part of the input is zero, the real part of the result is computed as by
+    /// [`Float::exp_prec_round`] and the imaginary part is that zero. If the real part of the input
+    /// is zero, the parts of the result are computed as by [`Float::cos_prec_round`] and
+    /// [`Float::sin_prec_round`].
+    ///
+    /// Special cases, where $y$ is finite and nonzero:
+    /// - $f(\text{NaN}\pm0.0i)=\text{NaN}\pm0.0i$
+    /// - $f(-\infty+\text{NaN}i)=0.0+0.0i$
+    /// - $f(\infty+\text{NaN}i)=\infty+\text{NaN}i$
+    /// - $f(-\infty+yi)=0.0(\cos y + i\sin y)$, where only the signs of $\cos y$ and $\sin y$
+    ///   matter
+    /// - $f(\infty+yi)=\infty(\cos y + i\sin y)$, where only the signs of $\cos y$ and $\sin y$
+    ///   matter
+    /// - $f(-\infty\pm\infty i)=0.0+0.0i$
+    /// - $f(\infty\pm\infty i)=\infty+\text{NaN}i$
+    /// - $f(z)=\text{NaN}+\text{NaN}i$ for any other $z$ with a NaN part, and if $z$ has a finite,
+    ///   nonzero real part and an infinite imaginary part
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact. Also panics if the exponent of $e^a$ is too
+    /// large or too small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (exp, o_re, o_im) = x.exp_prec_round(10, 20, Floor, Ceiling);
+    /// assert_eq!(exp.to_string(), "(1.467, 2.287357)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Greater);
+    /// ```
+    pub fn exp_prec_round(
+        &self,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        assert_ne!(prec_re, 0);
+        assert_ne!(prec_im, 0);
+        let (a, b) = (&self.re, &self.im);
+        let special = |re: Float, im: Float| (ComplexFloat { re, im }, Equal, Equal);
+        if a.is_nan() || b.is_nan() {
+            if *b == 0u32 {
+                special(Float::NAN, b.clone())
+            } else if a.is_infinite() {
+                if a.is_sign_negative() {
+                    special(Float::ZERO, Float::ZERO)
+                } else {
+                    special(Float::INFINITY, Float::NAN)
+                }
+            } else {
+                special(Float::NAN, Float::NAN)
+            }
+        } else if *b == 0u32 {
+            let (re, o_re) = a.exp_prec_round_ref(prec_re, rm_re);
+            (ComplexFloat { re, im: b.clone() }, o_re, Equal)
+        } else if *a == 0u32 {
+            let (re, o_re) = b.cos_prec_round_ref(prec_re, rm_re);
+            let (im, o_im) = b.sin_prec_round_ref(prec_im, rm_im);
+            (ComplexFloat { re, im }, o_re, o_im)
+        } else if a.is_infinite() {
+            let negative = a.is_sign_negative();
+            let magnitude = if negative {
+                Float::ZERO
+            } else {
+                Float::INFINITY
+            };
+            if b.is_infinite() {
+                special(
+                    magnitude.clone(),
+                    if negative { magnitude } else { Float::NAN },
+                )
+            } else {
+                let (sin, cos, _, _) = b.sin_cos_prec_round_ref(1, Nearest);
+                let with_sign = |x: &Float| {
+                    if x.is_sign_negative() {
+                        -&magnitude
+                    } else {
+                        magnitude.clone()
+                    }
+                };
+                special(with_sign(&cos), with_sign(&sin))
+            }
+        } else if b.is_infinite() {
+            special(Float::NAN, Float::NAN)
+        } else {
+            exp_general(self, prec_re, prec_im, rm_re, rm_im)
+        }
+    }
+
+    /// Computes the exponential of a [`ComplexFloat`], $e^a(\cos b+i\sin b)$, rounding the real
+    /// part of the result to the nearest value of precision `prec_re` and the imaginary part to the
+    /// nearest value of precision `prec_im`. The [`ComplexFloat`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the real and imaginary parts of the
+    /// rounded exponential are less than, equal to, or greater than those of the exact exponential.
+    ///
+    /// See [`ComplexFloat::exp_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if the exponent of $e^a$ is too large or too
+    /// small to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (exp, o_re, o_im) = x.exp_prec(10, 20);
+    /// assert_eq!(exp.to_string(), "(1.469, 2.287354)");
+    /// assert_eq!(o_re, Greater);
+    /// assert_eq!(o_im, Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec(&self, prec_re: u64, prec_im: u64) -> (ComplexFloat, Ordering, Ordering) {
+        self.exp_prec_round(prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Computes the principal natural logarithm of a [`ComplexFloat`], $\log|z| + i\arg z$,
+    /// rounding the real part of the result to `prec_re` bits using `rm_re` and the imaginary part
+    /// to `prec_im` bits using `rm_im`. The [`ComplexFloat`] is taken by reference. Two
+    /// [`Ordering`]s are also returned, indicating whether the real and imaginary parts of the
+    /// rounded logarithm are less than, equal to, or greater than those of the exact logarithm.
+    ///
+    /// The imaginary part of the result is computed as by [`ComplexFloat::arg_prec_round`]. The
+    /// real part is $\infty$ if either part of the input is infinite, NaN if either part is NaN,
+    /// and $-\infty$ if the input is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero, or if a rounding mode is `Exact` but the
+    /// corresponding part of the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeZero, One};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (ln, o_re, o_im) = x.ln_prec_round(10, 20, Floor, Ceiling);
+    /// assert_eq!(ln.to_string(), "(0.3462, 0.7853985)");
+    /// assert_eq!(o_re, Less);
+    /// assert_eq!(o_im, Greater);
+    ///
+    /// let x = ComplexFloat::new(Float::from(-1), Float::NEGATIVE_ZERO);
+    /// let (ln, o_re, o_im) = x.ln_prec_round(10, 10, Exact, Floor);
+    /// assert_eq!(ln.to_string(), "(0.0, -3.145)");
+    /// assert_eq!(o_re, Equal);
+    /// assert_eq!(o_im, Less);
+    /// ```
+    pub fn ln_prec_round(
+        &self,
+        prec_re: u64,
+        prec_im: u64,
+        rm_re: RoundingMode,
+        rm_im: RoundingMode,
+    ) -> (ComplexFloat, Ordering, Ordering) {
+        assert_ne!(prec_re, 0);
+        assert_ne!(prec_im, 0);
+        let (a, b) = (&self.re, &self.im);
+        let (re, o_re) = if a.is_infinite() || b.is_infinite() {
+            (Float::INFINITY, Equal)
+        } else if a.is_nan() || b.is_nan() {
+            (Float::NAN, Equal)
+        } else if *b == 0u32 {
+            if *a == 0u32 {
+                (Float::NEGATIVE_INFINITY, Equal)
+            } else {
+                a.abs().ln_prec_round(prec_re, rm_re)
+            }
+        } else if *a == 0u32 {
+            b.abs().ln_prec_round(prec_re, rm_re)
+        } else {
+            ln_abs_general(self, prec_re, rm_re)
+        };
+        let (im, o_im) = if a.is_nan() || b.is_nan() {
+            (Float::NAN, Equal)
+        } else {
+            self.arg_prec_round(prec_im, rm_im)
+        };
+        (ComplexFloat { re, im }, o_re, o_im)
+    }
+
+    /// Computes the principal natural logarithm of a [`ComplexFloat`], $\log|z| + i\arg z$,
+    /// rounding the real part of the result to the nearest value of precision `prec_re` and the
+    /// imaginary part to the nearest value of precision `prec_im`. The [`ComplexFloat`] is taken by
+    /// reference. Two [`Ordering`]s are also returned, indicating whether the real and imaginary
+    /// parts of the rounded logarithm are less than, equal to, or greater than those of the exact
+    /// logarithm.
+    ///
+    /// See [`ComplexFloat::ln_prec_round`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `prec_re`, `prec_im`,
+    /// and the precisions of the parts of the input.
+    ///
+    /// # Panics
+    /// Panics if `prec_re` or `prec_im` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = ComplexFloat::new(Float::ONE, Float::ONE);
+    /// let (ln, o_re, o_im) = x.ln_prec(10, 20);
+    /// assert_eq!(ln.to_string(), "(0.3467, 0.7853985)");
+    /// assert_eq!(o_re, Greater);
+    /// assert_eq!(o_im, Greater);
+    /// ```
+    #[inline]
+    pub fn ln_prec(&self, prec_re: u64, prec_im: u64) -> (ComplexFloat, Ordering, Ordering) {
+        self.ln_prec_round(prec_re, prec_im, Nearest, Nearest)
+    }
+
+    /// Returns the complex conjugate of a [`ComplexFloat`], negating its imaginary part. The
+    /// [`ComplexFloat`] is taken by reference.
+    ///
+    /// The conjugate is exact, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the parts
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(x.conj().to_string(), "(1.5, 2.0)");
+    /// ```
+    pub fn conj(&self) -> ComplexFloat {
+        ComplexFloat {
+            re: self.re.clone(),
+            im: -&self.im,
+        }
+    }
+}
+
+impl Neg for ComplexFloat {
+    type Output = ComplexFloat;
+
+    /// Negates a [`ComplexFloat`], taking it by value.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!((-x).to_string(), "(-1.5, 2.0)");
+    /// ```
+    fn neg(self) -> ComplexFloat {
+        ComplexFloat {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<'a> Neg for &'a ComplexFloat {
+    type Output = ComplexFloat;
+
+    /// Negates a [`ComplexFloat`], taking it by reference.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the parts
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let x = ComplexFloat::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!((-&x).to_string(), "(-1.5, 2.0)");
+    /// ```
+    fn neg(self) -> ComplexFloat {
+        ComplexFloat {
+            re: -&self.re,
+            im: -&self.im,
+        }
+    }
+}
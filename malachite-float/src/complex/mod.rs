@@ -0,0 +1,255 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use core::fmt::{Debug, Display, Formatter, Result};
+use malachite_base::num::basic::traits::Zero;
+
+/// A complex number whose real and imaginary parts are [`Float`]s.
+///
+/// The two parts are independent [`Float`]s, so each has its own precision and may be zero,
+/// infinite, or NaN. Arithmetic functions take a separate precision and rounding mode for each part
+/// of the result, and return an [`Ordering`](core::cmp::Ordering) for each part, indicating whether
+/// the rounded part is less than, equal to, or greater than the corresponding part of the exact
+/// result. Every finite part of a result is correctly rounded.
+///
+/// `ComplexFloat` is designed to be similar to the `mpc_t` type in
+/// [MPC](https://www.multiprecision.org/mpc/), and its functions produce the same results as their
+/// counterparts in MPC, including the signs of zeros and the values returned for infinite and NaN
+/// arguments.
+#[derive(Clone)]
+pub struct ComplexFloat {
+    pub(crate) re: Float,
+    pub(crate) im: Float,
+}
+
+impl ComplexFloat {
+    /// Creates a [`ComplexFloat`] from its real and imaginary parts.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComplexFloat::new(Float::from(1.5), Float::from(-2)).to_string(),
+    ///     "(1.5, -2.0)"
+    /// );
+    /// ```
+    pub const fn new(re: Float, im: Float) -> ComplexFloat {
+        ComplexFloat { re, im }
+    }
+
+    /// Returns a reference to the real part of a [`ComplexFloat`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let z = ComplexFloat::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(*z.re(), 1.5);
+    /// ```
+    pub const fn re(&self) -> &Float {
+        &self.re
+    }
+
+    /// Returns a reference to the imaginary part of a [`ComplexFloat`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let z = ComplexFloat::new(Float::from(1.5), Float::from(-2));
+    /// assert_eq!(*z.im(), -2);
+    /// ```
+    pub const fn im(&self) -> &Float {
+        &self.im
+    }
+
+    /// Returns the real and imaginary parts of a [`ComplexFloat`], taking the [`ComplexFloat`] by
+    /// value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// let (re, im) = ComplexFloat::new(Float::from(1.5), Float::from(-2)).into_parts();
+    /// assert_eq!(re, 1.5);
+    /// assert_eq!(im, -2);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_parts(self) -> (Float, Float) {
+        (self.re, self.im)
+    }
+
+    /// Determines whether both parts of a [`ComplexFloat`] are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(ComplexFloat::new(Float::ONE, Float::ZERO).is_finite());
+    /// assert!(!ComplexFloat::new(Float::ONE, Float::INFINITY).is_finite());
+    /// assert!(!ComplexFloat::new(Float::NAN, Float::ONE).is_finite());
+    /// ```
+    pub const fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Determines whether either part of a [`ComplexFloat`] is infinite.
+    ///
+    /// Following the C99 standard, a [`ComplexFloat`] with an infinite part is infinite even if its
+    /// other part is NaN.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(!ComplexFloat::new(Float::ONE, Float::ZERO).is_infinite());
+    /// assert!(ComplexFloat::new(Float::ONE, Float::INFINITY).is_infinite());
+    /// assert!(ComplexFloat::new(Float::NAN, Float::INFINITY).is_infinite());
+    /// ```
+    pub const fn is_infinite(&self) -> bool {
+        self.re.is_infinite() || self.im.is_infinite()
+    }
+
+    /// Determines whether both parts of a [`ComplexFloat`] are zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeZero, One, Zero};
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(ComplexFloat::new(Float::ZERO, Float::NEGATIVE_ZERO).is_zero());
+    /// assert!(!ComplexFloat::new(Float::ZERO, Float::ONE).is_zero());
+    /// ```
+    pub const fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl From<Float> for ComplexFloat {
+    /// Converts a [`Float`] to a [`ComplexFloat`] with a zero imaginary part.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComplexFloat::from(Float::from(1.5)).to_string(),
+    ///     "(1.5, 0.0)"
+    /// );
+    /// ```
+    fn from(re: Float) -> ComplexFloat {
+        ComplexFloat {
+            re,
+            im: Float::ZERO,
+        }
+    }
+}
+
+impl PartialEq for ComplexFloat {
+    /// Determines whether two [`ComplexFloat`]s are equal.
+    ///
+    /// Each part is compared using [`Float`] equality, so a [`ComplexFloat`] with a NaN part is not
+    /// equal to anything, zeros of either sign are equal, and the precisions of the parts are not
+    /// compared.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the parts.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One, Zero};
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComplexFloat::new(Float::ONE, Float::ZERO),
+    ///     ComplexFloat::new(Float::one_prec(100), Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_ne!(
+    ///     ComplexFloat::new(Float::ONE, Float::NAN),
+    ///     ComplexFloat::new(Float::ONE, Float::NAN)
+    /// );
+    /// ```
+    fn eq(&self, other: &ComplexFloat) -> bool {
+        self.re == other.re && self.im == other.im
+    }
+}
+
+impl Display for ComplexFloat {
+    /// Converts a [`ComplexFloat`] to a [`String`](alloc::string::String), writing its real and
+    /// imaginary parts in parentheses.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity};
+    /// use malachite_float::complex::ComplexFloat;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     ComplexFloat::new(Float::NEGATIVE_INFINITY, Float::NAN).to_string(),
+    ///     "(-Infinity, NaN)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "({}, {})", self.re, self.im)
+    }
+}
+
+impl Debug for ComplexFloat {
+    /// Converts a [`ComplexFloat`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Correctly rounded arithmetic, square roots, absolute values, arguments, exponentials, and
+/// logarithms of [`ComplexFloat`]s.
+pub mod arithmetic;
@@ -0,0 +1,91 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::Enclosure;
+use crate::Float;
+use core::cell::UnsafeCell;
+use core::cmp::max;
+use core::hint::spin_loop;
+use core::sync::atomic::AtomicBool;
+use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+use malachite_base::rounding_modes::RoundingMode::*;
+
+// Holds the most precise enclosure of a constant computed so far, so that requests for the
+// constant at the same or a lower precision don't need to recompute it. This crate may be built
+// without `std`, so access is guarded by a spin lock rather than a `Mutex`. The lock is only held
+// while the cached value is read or replaced, never while the constant is being computed.
+pub(crate) struct ConstantCache {
+    locked: AtomicBool,
+    value: UnsafeCell<Option<(u64, Enclosure)>>,
+}
+
+// The value is only accessed while `locked` is held.
+unsafe impl Sync for ConstantCache {}
+
+impl ConstantCache {
+    pub(crate) const fn new() -> ConstantCache {
+        ConstantCache {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(None),
+        }
+    }
+
+    fn with_lock<T, F: FnOnce(&mut Option<(u64, Enclosure)>) -> T>(&self, f: F) -> T {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Acquire, Relaxed)
+            .is_err()
+        {
+            spin_loop();
+        }
+        // The lock is held, so no other reference to the value exists. `f` never panics, so the
+        // lock is always released.
+        let result = f(unsafe { &mut *self.value.get() });
+        self.locked.store(false, Release);
+        result
+    }
+
+    // Returns an enclosure of the constant whose endpoints have precision `prec`. If an enclosure
+    // at precision `prec` or higher has already been computed, its endpoints are rounded outward;
+    // otherwise `compute` is called, and its result replaces the cached enclosure.
+    //
+    // The cached endpoints are rounded by reference while the lock is held, so that only the top
+    // `prec` bits of each are copied; cloning the whole cached enclosure would make every request
+    // take time linear in the cached precision rather than in `prec`.
+    //
+    // When a cached enclosure is replaced, the new one is computed at no less than 3/2 times the
+    // old precision, so that requesting the constant at a slowly increasing sequence of precisions
+    // doesn't recompute it every time.
+    pub(crate) fn get<F: FnOnce(u64) -> Enclosure>(&self, prec: u64, compute: F) -> Enclosure {
+        let cached = self.with_lock(|value| match value {
+            Some((cached_prec, y)) if *cached_prec >= prec => Ok(round_enclosure(y, prec)),
+            Some((cached_prec, _)) => Err(max(prec, *cached_prec + (*cached_prec >> 1))),
+            None => Err(prec),
+        });
+        cached.unwrap_or_else(|new_prec| {
+            let y = compute(new_prec);
+            let rounded = round_enclosure(&y, prec);
+            self.with_lock(|value| {
+                if value
+                    .as_ref()
+                    .map_or(true, |(cached_prec, _)| *cached_prec < new_prec)
+                {
+                    *value = Some((new_prec, y));
+                }
+            });
+            rounded
+        })
+    }
+}
+
+fn round_enclosure(y: &Enclosure, prec: u64) -> Enclosure {
+    Enclosure {
+        lo: Float::from_float_prec_round_ref(&y.lo, prec, Floor).0,
+        hi: Float::from_float_prec_round_ref(&y.hi, prec, Ceiling).0,
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::constants::pi::pi_enclosure;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of
+// $$
+// S = \sum_{k=0}^\infty \frac{(k!)^2}{(2k)!(2k+1)^2}.
+// $$
+fn catalan_series_bound(prec: u64, rm: RoundingMode) -> Float {
+    let working_prec = prec + 10;
+    let mut ratio = Float::ONE;
+    let mut sum = Float::ONE;
+    let mut k = 1u64;
+    loop {
+        // $(k!)^2/(2k)!$ is $k/(2(2k-1))$ times its previous value.
+        ratio = ratio
+            .mul_prec_round(Float::from(k), working_prec, rm)
+            .0
+            .div_prec_round(Float::from((k << 1) - 1), working_prec, rm)
+            .0
+            >> 1u32;
+        let odd = u128::from((k << 1) | 1);
+        let term = ratio
+            .div_prec_round_ref_val(Float::from(odd * odd), working_prec, rm)
+            .0;
+        sum = sum.add_prec_round_ref_ref(&term, working_prec, rm).0;
+        if is_negligible(&term, &sum, working_prec) {
+            if rm == Ceiling {
+                // Each remaining term is at most half of the previous one, so together they sum to
+                // less than the last term added.
+                sum = sum.add_prec_round(term, working_prec, rm).0;
+            }
+            return sum;
+        }
+        k += 1;
+    }
+}
+
+// Returns an enclosure of Catalan's constant $G$, using
+// $$
+// G = \frac{\pi}{8}\log(2+\sqrt{3}) + \frac{3}{8}\sum_{k=0}^\infty \frac{(k!)^2}{(2k)!(2k+1)^2}.
+// $$
+fn compute_catalan(prec: u64) -> Enclosure {
+    let working_prec = prec + 10;
+    let x = Enclosure::exact(Float::from(3u32))
+        .sqrt(working_prec)
+        .add(&Enclosure::exact(Float::TWO), working_prec);
+    // $\log$ is increasing, so it maps an enclosure of $x$ to an enclosure of $\log x$.
+    let ln_x = Enclosure {
+        lo: x.lo.ln_prec_round_ref(working_prec, Floor).0,
+        hi: x.hi.ln_prec_round_ref(working_prec, Ceiling).0,
+    };
+    let sum = Enclosure {
+        lo: catalan_series_bound(working_prec, Floor),
+        hi: catalan_series_bound(working_prec, Ceiling),
+    };
+    pi_enclosure(working_prec)
+        .mul(&ln_x, working_prec)
+        .add(
+            &sum.mul(&Enclosure::exact(Float::from(3u32)), working_prec),
+            working_prec,
+        )
+        .shl(-3)
+}
+
+static CATALAN: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of Catalan's constant whose endpoints have precision `prec`.
+pub(crate) fn catalan_enclosure(prec: u64) -> Enclosure {
+    CATALAN.get(prec, compute_catalan)
+}
+
+impl Float {
+    /// Returns an approximation to Catalan's constant $G$, with the given precision and rounded
+    /// using the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Although the
+    /// constant is not known to be irrational, it is known not to be equal to any [`Float`] of
+    /// practical precision, so the rounded value is never equal to the exact value.)
+    ///
+    /// Catalan's constant is the alternating sum of the reciprocals of the odd squares. That is,
+    /// $$
+    /// G = \sum_{k=0}^\infty \frac{(-1)^k}{(2k+1)^2}.
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $G$ again at the same
+    /// or a lower precision only takes time linear in `prec`, since the cached value just needs to
+    /// be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (g, o) = Float::catalan_prec_round(100, Floor);
+    /// assert_eq!(g.to_string(), "0.915965594177219015054603514932");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (g, o) = Float::catalan_prec_round(100, Ceiling);
+    /// assert_eq!(g.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn catalan_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| {
+            Some(catalan_enclosure(working_prec))
+        })
+    }
+
+    /// Returns an approximation to Catalan's constant $G$, with the given precision and rounded to
+    /// the nearest [`Float`] of that precision. An [`Ordering`] is also returned, indicating
+    /// whether the rounded value is less than or greater than the exact value of the constant.
+    /// (Although the constant is not known to be irrational, it is known not to be equal to any
+    /// [`Float`] of practical precision, so the rounded value is never equal to the exact value.)
+    ///
+    /// Catalan's constant is the alternating sum of the reciprocals of the odd squares. That is,
+    /// $$
+    /// G = \sum_{k=0}^\infty \frac{(-1)^k}{(2k+1)^2}.
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $G$ again at the same
+    /// or a lower precision only takes time linear in `prec`, since the cached value just needs to
+    /// be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (g, o) = Float::catalan_prec(1);
+    /// assert_eq!(g.to_string(), "1.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (g, o) = Float::catalan_prec(10);
+    /// assert_eq!(g.to_string(), "0.916");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (g, o) = Float::catalan_prec(100);
+    /// assert_eq!(g.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec(prec: u64) -> (Float, Ordering) {
+        Float::catalan_prec_round(prec, Nearest)
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $e$, using
+// $$
+// e = \sum_{k=0}^\infty \frac{1}{k!}.
+// $$
+fn e_series_bound(prec: u64, rm: RoundingMode) -> Float {
+    let working_prec = prec + 10;
+    let mut term = Float::ONE;
+    let mut sum = Float::TWO;
+    let mut k = 2u64;
+    loop {
+        term = term.div_prec_round(Float::from(k), working_prec, rm).0;
+        sum = sum.add_prec_round_ref_ref(&term, working_prec, rm).0;
+        if is_negligible(&term, &sum, working_prec) {
+            if rm == Ceiling {
+                // Each remaining term is at most half of the previous one, so together they sum to
+                // less than the last term added.
+                sum = sum.add_prec_round(term, working_prec, rm).0;
+            }
+            return sum;
+        }
+        k += 1;
+    }
+}
+
+static E: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of $e$ whose endpoints have precision `prec`.
+pub(crate) fn e_enclosure(prec: u64) -> Enclosure {
+    E.get(prec, |prec| Enclosure {
+        lo: e_series_bound(prec, Floor),
+        hi: e_series_bound(prec, Ceiling),
+    })
+}
+
+impl Float {
+    /// Returns an approximation to $e$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $e$ is the base of the natural logarithm. That is,
+    /// $$
+    /// e = \sum_{k=0}^\infty \frac{1}{k!}.
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $e$ again at the same
+    /// or a lower precision only takes time linear in `prec`, since the cached value just needs to
+    /// be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (e, o) = Float::e_prec_round(100, Floor);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (e, o) = Float::e_prec_round(100, Ceiling);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471354");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn e_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| Some(e_enclosure(working_prec)))
+    }
+
+    /// Returns an approximation to $e$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $e$ is the base of the natural logarithm. That is,
+    /// $$
+    /// e = \sum_{k=0}^\infty \frac{1}{k!}.
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $e$ again at the same
+    /// or a lower precision only takes time linear in `prec`, since the cached value just needs to
+    /// be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (e, o) = Float::e_prec(1);
+    /// assert_eq!(e.to_string(), "2.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (e, o) = Float::e_prec(10);
+    /// assert_eq!(e.to_string(), "2.719");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (e, o) = Float::e_prec(100);
+    /// assert_eq!(e.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn e_prec(prec: u64) -> (Float, Ordering) {
+        Float::e_prec_round(prec, Nearest)
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns lower bounds (if `rm` is `Floor`) or upper bounds (if `rm` is `Ceiling`) of
+// $$
+// V = \sum_{k=0}^\infty \left(\frac{n^k}{k!}\right)^2 \quad\text{and}\quad
+// S = \sum_{k=0}^\infty \left(\frac{n^k}{k!}\right)^2 H_k,
+// $$
+// where $H_k$ is the $k$th harmonic number. The terms $a_k$ of $S$ satisfy $a_k = (a_{k-1}n^2/k +
+// b_k)/k$, where $b_k$ are the terms of $V$, so each step only multiplies and divides by small
+// integers.
+fn euler_gamma_series_bounds(n: u64, prec: u64, rm: RoundingMode) -> (Float, Float) {
+    let working_prec = prec + 10;
+    let n_squared = Float::from(u128::from(n) * u128::from(n));
+    let mut b = Float::ONE;
+    let mut a = Float::ZERO;
+    let mut v = Float::ONE;
+    let mut s = Float::ZERO;
+    let mut k = 1u64;
+    loop {
+        let k_float = Float::from(k);
+        b = b
+            .mul_prec_round_ref_ref(&n_squared, working_prec, rm)
+            .0
+            .div_prec_round_ref_ref(&k_float, working_prec, rm)
+            .0
+            .div_prec_round_ref_ref(&k_float, working_prec, rm)
+            .0;
+        a = a
+            .mul_prec_round_ref_ref(&n_squared, working_prec, rm)
+            .0
+            .div_prec_round_ref_ref(&k_float, working_prec, rm)
+            .0
+            .add_prec_round_ref_ref(&b, working_prec, rm)
+            .0
+            .div_prec_round(k_float, working_prec, rm)
+            .0;
+        v = v.add_prec_round_ref_ref(&b, working_prec, rm).0;
+        s = s.add_prec_round_ref_ref(&a, working_prec, rm).0;
+        // Once $k \geq 2n$, each term of $V$ is at most a quarter of the previous one, and each
+        // term of $S$ at most half of the previous one.
+        if k >= n << 1 && is_negligible(&b, &v, working_prec) && is_negligible(&a, &s, working_prec)
+        {
+            if rm == Ceiling {
+                // The remaining terms of each sum add up to less than the last term added.
+                v = v.add_prec_round(b, working_prec, rm).0;
+                s = s.add_prec_round(a, working_prec, rm).0;
+            }
+            return (v, s);
+        }
+        k += 1;
+    }
+}
+
+// Returns an enclosure of the Euler-Mascheroni constant $\gamma$, using the Brent-McMillan formula
+// $$
+// \gamma = \frac{S}{V} - \log n - \frac{K_0(2n)}{I_0(2n)},
+// $$
+// where $S$ and $V$ are the sums computed by `euler_gamma_series_bounds` and $0 < K_0(2n)/I_0(2n)
+// < \pi e^{-4n} < 2^{2-5n}$.
+fn compute_euler_gamma(prec: u64) -> Enclosure {
+    let working_prec = prec + prec.significant_bits() + 10;
+    let n = working_prec / 5 + 2;
+    let (v_lo, s_lo) = euler_gamma_series_bounds(n, working_prec, Floor);
+    let (v_hi, s_hi) = euler_gamma_series_bounds(n, working_prec, Ceiling);
+    let v = Enclosure { lo: v_lo, hi: v_hi };
+    let s = Enclosure { lo: s_lo, hi: s_hi };
+    let n_float = Float::from(n);
+    let ln_n = Enclosure {
+        lo: n_float.ln_prec_round_ref(working_prec, Floor).0,
+        hi: n_float.ln_prec_round_ref(working_prec, Ceiling).0,
+    };
+    let error = Enclosure {
+        lo: -Float::power_of_2(2 - 5 * i64::exact_from(n)),
+        hi: Float::ZERO,
+    };
+    s.div(&v, working_prec)
+        .unwrap()
+        .sub(&ln_n, working_prec)
+        .add(&error, working_prec)
+}
+
+static EULER_GAMMA: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of the Euler-Mascheroni constant whose endpoints have precision `prec`.
+pub(crate) fn euler_gamma_enclosure(prec: u64) -> Enclosure {
+    EULER_GAMMA.get(prec, compute_euler_gamma)
+}
+
+impl Float {
+    /// Returns an approximation to the Euler-Mascheroni constant $\gamma$, with the given precision
+    /// and rounded using the given [`RoundingMode`]. An [`Ordering`] is also returned, indicating
+    /// whether the rounded value is less than or greater than the exact value of the constant.
+    /// (Although the constant is not known to be irrational, it is known not to be equal to any
+    /// [`Float`] of practical precision, so the rounded value is never equal to the exact value.)
+    ///
+    /// The Euler-Mascheroni constant is the limiting difference between the harmonic series and the
+    /// natural logarithm. That is,
+    /// $$
+    /// \gamma = \lim_{n\to\infty}\left(\sum_{k=1}^n \frac{1}{k} - \log n\right).
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\gamma$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, Floor);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec_round(100, Ceiling);
+    /// assert_eq!(gamma.to_string(), "0.577215664901532860606512090083");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn euler_gamma_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| {
+            Some(euler_gamma_enclosure(working_prec))
+        })
+    }
+
+    /// Returns an approximation to the Euler-Mascheroni constant $\gamma$, with the given precision
+    /// and rounded to the nearest [`Float`] of that precision. An [`Ordering`] is also returned,
+    /// indicating whether the rounded value is less than or greater than the exact value of the
+    /// constant. (Although the constant is not known to be irrational, it is known not to be equal
+    /// to any [`Float`] of practical precision, so the rounded value is never equal to the exact
+    /// value.)
+    ///
+    /// The Euler-Mascheroni constant is the limiting difference between the harmonic series and the
+    /// natural logarithm. That is,
+    /// $$
+    /// \gamma = \lim_{n\to\infty}\left(\sum_{k=1}^n \frac{1}{k} - \log n\right).
+    /// $$
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\gamma$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(1);
+    /// assert_eq!(gamma.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(10);
+    /// assert_eq!(gamma.to_string(), "0.577");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (gamma, o) = Float::euler_gamma_prec(100);
+    /// assert_eq!(gamma.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec(prec: u64) -> (Float, Ordering) {
+        Float::euler_gamma_prec_round(prec, Nearest)
+    }
+}
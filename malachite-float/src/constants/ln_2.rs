@@ -0,0 +1,153 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{is_negligible, round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::Two;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $\log 2$,
+// using
+// $$
+// \log 2 = 2\operatorname{atanh}(1/3) = \sum_{k=0}^\infty \frac{2}{(2k+1)3^{2k+1}}.
+// $$
+fn ln_2_series_bound(prec: u64, rm: RoundingMode) -> Float {
+    let working_prec = prec + 10;
+    let mut power = Float::TWO
+        .div_prec_round(Float::from(3u32), working_prec, rm)
+        .0;
+    let mut sum = power.clone();
+    let mut i = 1u64;
+    loop {
+        power = power.div_prec_round(Float::from(9u32), working_prec, rm).0;
+        let term = power
+            .div_prec_round_ref_val(Float::from((i << 1) | 1), working_prec, rm)
+            .0;
+        sum = sum.add_prec_round_ref_ref(&term, working_prec, rm).0;
+        if is_negligible(&term, &sum, working_prec) {
+            if rm == Ceiling {
+                // The remaining terms sum to less than the last term added.
+                sum = sum.add_prec_round(term, working_prec, rm).0;
+            }
+            return sum;
+        }
+        i += 1;
+    }
+}
+
+static LN_2: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of $\log 2$ whose endpoints have precision `prec`.
+pub(crate) fn ln_2_enclosure(prec: u64) -> Enclosure {
+    LN_2.get(prec, |prec| Enclosure {
+        lo: ln_2_series_bound(prec, Floor),
+        hi: ln_2_series_bound(prec, Ceiling),
+    })
+}
+
+// Returns a lower bound (if `rm` is `Floor`) or an upper bound (if `rm` is `Ceiling`) of $\log 2$,
+// with precision `prec`.
+pub(crate) fn ln_2_bound(prec: u64, rm: RoundingMode) -> Float {
+    let ln_2 = ln_2_enclosure(prec);
+    if rm == Floor {
+        ln_2.lo
+    } else {
+        ln_2.hi
+    }
+}
+
+impl Float {
+    /// Returns an approximation to $\log 2$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\log 2$ is the natural logarithm of 2.
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\log 2$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (l2, o) = Float::ln_2_prec_round(100, Floor);
+    /// assert_eq!(l2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (l2, o) = Float::ln_2_prec_round(100, Ceiling);
+    /// assert_eq!(l2.to_string(), "0.693147180559945309417232121459");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn ln_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| Some(ln_2_enclosure(working_prec)))
+    }
+
+    /// Returns an approximation to $\log 2$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// $\log 2$ is the natural logarithm of 2.
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\log 2$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (l2, o) = Float::ln_2_prec(1);
+    /// assert_eq!(l2.to_string(), "0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (l2, o) = Float::ln_2_prec(10);
+    /// assert_eq!(l2.to_string(), "0.693");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (l2, o) = Float::ln_2_prec(100);
+    /// assert_eq!(l2.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::ln_2_prec_round(prec, Nearest)
+    }
+}
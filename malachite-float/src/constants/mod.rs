@@ -6,9 +6,22 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub(crate) mod cache;
+/// Functions for approximating Catalan's constant.
+pub mod catalan;
+/// Functions for approximating $e$, the base of the natural logarithm.
+pub mod e;
+/// Functions for approximating the Euler-Mascheroni constant $\gamma$.
+pub mod euler_gamma;
+/// Functions for approximating $\log 2$.
+pub mod ln_2;
+/// Functions for approximating $\pi$.
+pub mod pi;
 /// Functions for approximating the prime constant (the constant whose $n$th bit is 1 if and only if
 /// $n$ is prime).
 pub mod prime_constant;
+/// Functions for approximating $\sqrt{2}$.
+pub mod sqrt_2;
 /// Functions for approximating the Thue-Morse constant (the constant whose bits are the Thue-Morse
 /// sequence).
 pub mod thue_morse_constant;
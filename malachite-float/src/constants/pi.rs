@@ -0,0 +1,165 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+
+// $640320^3/24$.
+const CHUDNOVSKY_Q: u64 = 10939058860032000;
+
+// Returns $P(a, b)$, $Q(a, b)$, and $T(a, b)$ for the Chudnovsky series, computed by binary
+// splitting. The partial sum of the first $b$ terms of
+// $$
+// S = \sum_{k=0}^\infty \frac{(-1)^k(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}}
+// $$
+// is $T(0, b)/Q(0, b)$.
+fn chudnovsky_split(a: u64, b: u64) -> (Integer, Integer, Integer) {
+    if b - a == 1 {
+        if a == 0 {
+            return (Integer::ONE, Integer::ONE, Integer::from(13591409u32));
+        }
+        let p =
+            -(Integer::from(6 * a - 5) * Integer::from((a << 1) - 1) * Integer::from(6 * a - 1));
+        let a_cubed = Integer::from(a) * Integer::from(a) * Integer::from(a);
+        let q = a_cubed * Integer::from(CHUDNOVSKY_Q);
+        let t = &p * Integer::from(13591409 + 545140134 * a);
+        (p, q, t)
+    } else {
+        let mid = (a + b) >> 1;
+        let (p_1, q_1, t_1) = chudnovsky_split(a, mid);
+        let (p_2, q_2, t_2) = chudnovsky_split(mid, b);
+        let t = t_1 * &q_2 + &p_1 * t_2;
+        (p_1 * p_2, q_1 * q_2, t)
+    }
+}
+
+// Returns an enclosure of $\pi$, using the Chudnovsky formula $\pi = 426880\sqrt{10005}/S$. Each
+// term of $S$ is less than $2^{-47}$ times the previous one.
+fn compute_pi(prec: u64) -> Enclosure {
+    let working_prec = prec + 10;
+    let n = (working_prec + 73) / 47 + 1;
+    let (_, q, t) = chudnovsky_split(0, n);
+    let q = Enclosure {
+        lo: Float::from_integer_prec_round_ref(&q, working_prec, Floor).0,
+        hi: Float::from_integer_prec_round_ref(&q, working_prec, Ceiling).0,
+    };
+    let t = Enclosure {
+        lo: Float::from_integer_prec_round_ref(&t, working_prec, Floor).0,
+        hi: Float::from_integer_prec_round_ref(&t, working_prec, Ceiling).0,
+    };
+    // The terms that were left out sum to less than $2^{30-47n}(n+1)$.
+    let error = Float::power_of_2(
+        30 + i64::exact_from((n + 1).significant_bits()) - 47 * i64::exact_from(n),
+    );
+    let sum = t.div(&q, working_prec).unwrap().widen(&error, working_prec);
+    Enclosure::exact(Float::from(10005u32))
+        .sqrt(working_prec)
+        .mul(&Enclosure::exact(Float::from(426880u32)), working_prec)
+        .div(&sum, working_prec)
+        .unwrap()
+}
+
+static PI: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of $\pi$ whose endpoints have precision `prec`.
+pub(crate) fn pi_enclosure(prec: u64) -> Enclosure {
+    PI.get(prec, compute_pi)
+}
+
+impl Float {
+    /// Returns an approximation to $\pi$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\pi$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, Floor);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, Ceiling);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383282");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn pi_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| Some(pi_enclosure(working_prec)))
+    }
+
+    /// Returns an approximation to $\pi$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\pi$ again at the
+    /// same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (pi, o) = Float::pi_prec(1);
+    /// assert_eq!(pi.to_string(), "4.0");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (pi, o) = Float::pi_prec(10);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (pi, o) = Float::pi_prec(100);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn pi_prec(prec: u64) -> (Float, Ordering) {
+        Float::pi_prec_round(prec, Nearest)
+    }
+}
@@ -57,11 +57,13 @@ impl Float {
         // know exactly how many bits `non_dyadic_from_bits_prec_round` will read, we can get away
         // with this.
         Float::non_dyadic_from_bits_prec_round(
-            prime_indicator_sequence_less_than_or_equal_to(if rm == Nearest {
-                prec + 2
-            } else {
-                prec + 1
-            }),
+            prime_indicator_sequence_less_than_or_equal_to(
+                if rm == Nearest || rm == NearestTiesAway {
+                    prec + 2
+                } else {
+                    prec + 1
+                },
+            ),
             prec,
             rm,
         )
@@ -0,0 +1,108 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::enclosure::{round_enclosed, Enclosure};
+use crate::constants::cache::ConstantCache;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::basic::traits::Two;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+static SQRT_2: ConstantCache = ConstantCache::new();
+
+// Returns an enclosure of $\sqrt{2}$ whose endpoints have precision `prec`.
+pub(crate) fn sqrt_2_enclosure(prec: u64) -> Enclosure {
+    SQRT_2.get(prec, |prec| Enclosure::exact(Float::TWO).sqrt(prec))
+}
+
+impl Float {
+    /// Returns an approximation to $\sqrt{2}$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\sqrt{2}$ again at
+    /// the same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r2, o) = Float::sqrt_2_prec_round(100, Floor);
+    /// assert_eq!(r2.to_string(), "1.414213562373095048801688724209");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (r2, o) = Float::sqrt_2_prec_round(100, Ceiling);
+    /// assert_eq!(r2.to_string(), "1.414213562373095048801688724211");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn sqrt_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        round_enclosed(prec, rm, |working_prec| {
+            Some(sqrt_2_enclosure(working_prec))
+        })
+    }
+
+    /// Returns an approximation to $\sqrt{2}$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached, so computing $\sqrt{2}$ again at
+    /// the same or a lower precision only takes time linear in `prec`, since the cached value just
+    /// needs to be rounded.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (r2, o) = Float::sqrt_2_prec(1);
+    /// assert_eq!(r2.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (r2, o) = Float::sqrt_2_prec(10);
+    /// assert_eq!(r2.to_string(), "1.414");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (r2, o) = Float::sqrt_2_prec(100);
+    /// assert_eq!(r2.to_string(), "1.414213562373095048801688724209");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn sqrt_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::sqrt_2_prec_round(prec, Nearest)
+    }
+}
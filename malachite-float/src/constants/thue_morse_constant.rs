@@ -79,7 +79,7 @@ impl Float {
         assert_ne!(rm, Exact);
         // If the result is 1/2 then the exponent is 0 rather than -1, so we handle that case
         // separately.
-        if prec == 1 && (rm == Nearest || rm == Ceiling || rm == Up) {
+        if prec == 1 && (rm == Nearest || rm == NearestTiesAway || rm == Ceiling || rm == Up) {
             return (Float::ONE_HALF, Greater);
         } else if prec == 2 && (rm == Ceiling || rm == Up) {
             // TODO implement const_from_unsigned_prec_times_power_of_2
@@ -107,11 +107,13 @@ impl Float {
         let increment = match rm {
             Up | Ceiling | Exact => true, // Exact never happens
             Down | Floor => false,
-            Nearest => match lsb {
+            // The constant is irrational, so there are no ties
+            Nearest | NearestTiesAway => match lsb {
                 1 => !next_tms,
                 2 => tms.next().unwrap(),
                 _ => limbs[0] & (lsb >> 1) != 0,
             },
+            ToOdd => limbs[0] & lsb == 0,
         };
         limbs[0] &= !(lsb - 1);
         let mut significand = Natural::from_owned_limbs_asc(limbs);
@@ -0,0 +1,365 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::context::Context;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::rounding_modes::RoundingMode;
+
+impl Context {
+    // Finishes an operation whose arguments included a NaN if `nan_argument` is true.
+    fn finish(
+        &mut self,
+        x: Float,
+        o: Ordering,
+        rm: RoundingMode,
+        nan_argument: bool,
+    ) -> (Float, Ordering) {
+        if x.is_nan() && !nan_argument {
+            self.flags.invalid = true;
+        }
+        self.check_range(x, o, rm)
+    }
+
+    /// Rounds a [`Float`] to the specified precision with the specified rounding mode, and brings
+    /// it into the [`Context`]'s range. An [`Ordering`] is also returned, indicating whether the
+    /// result is less than, equal to, or greater than the original value.
+    ///
+    /// See [`Context::check_range`] for how values outside the range are handled and which flags
+    /// are raised.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// // Converting to half precision
+    /// let mut ctx = Context::from_ieee_format(IeeeFormat::BINARY16);
+    /// let (x, o) = ctx.from_float_prec_round(&Float::from(1.0e5), 11, Nearest);
+    /// assert_eq!(x.to_string(), "Infinity");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = ctx.from_float_prec_round(&Float::from(1.0e-5), 11, Nearest);
+    /// assert_eq!(x.to_string(), "0.000010014");
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn from_float_prec_round(
+        &mut self,
+        x: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let (y, o) = Float::from_float_prec_round_ref(x, prec, rm);
+        self.check_range(y, o, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision with the specified
+    /// rounding mode and bringing it into the [`Context`]'s range. An [`Ordering`] is also
+    /// returned, indicating whether the result is less than, equal to, or greater than the exact
+    /// sum.
+    ///
+    /// Invalid is raised if the result is NaN but neither argument is. See [`Context::check_range`]
+    /// for how results outside the range are handled and which other flags are raised, and
+    /// [`Float::add_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NegativeInfinity};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (sum, o) = ctx.add_prec_round(&Float::from(1000), &Float::from(1000), 10, Nearest);
+    /// assert_eq!(sum.to_string(), "Infinity");
+    /// assert_eq!(o, Greater);
+    /// assert!(ctx.get_flags().overflow);
+    ///
+    /// let (sum, o) = ctx.add_prec_round(&Float::INFINITY, &Float::NEGATIVE_INFINITY, 10, Nearest);
+    /// assert!(sum.is_nan());
+    /// assert_eq!(o, Equal);
+    /// assert!(ctx.get_flags().invalid);
+    /// ```
+    pub fn add_prec_round(
+        &mut self,
+        x: &Float,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let (sum, o) = x.add_prec_round_ref_ref(y, prec, rm);
+        self.finish(sum, o, rm, x.is_nan() || y.is_nan())
+    }
+
+    /// Subtracts one [`Float`] from another, rounding the result to the specified precision with
+    /// the specified rounding mode and bringing it into the [`Context`]'s range. An [`Ordering`]
+    /// is also returned, indicating whether the result is less than, equal to, or greater than the
+    /// exact difference.
+    ///
+    /// Invalid is raised if the result is NaN but neither argument is. See [`Context::check_range`]
+    /// for how results outside the range are handled and which other flags are raised, and
+    /// [`Float::sub_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (difference, o) =
+    ///     ctx.sub_prec_round(&Float::from(0.75), &Float::from(0.749755859375), 10, Floor);
+    /// assert_eq!(difference.to_string(), "0.0");
+    /// assert_eq!(o, Less);
+    /// assert!(ctx.get_flags().underflow);
+    /// ```
+    pub fn sub_prec_round(
+        &mut self,
+        x: &Float,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let (difference, o) = x.sub_prec_round_ref_ref(y, prec, rm);
+        self.finish(difference, o, rm, x.is_nan() || y.is_nan())
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision with the
+    /// specified rounding mode and bringing it into the [`Context`]'s range. An [`Ordering`] is
+    /// also returned, indicating whether the result is less than, equal to, or greater than the
+    /// exact product.
+    ///
+    /// Invalid is raised if the result is NaN but neither argument is. See [`Context::check_range`]
+    /// for how results outside the range are handled and which other flags are raised, and
+    /// [`Float::mul_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (product, o) = ctx.mul_prec_round(&Float::from(100), &Float::from(100), 10, Down);
+    /// assert_eq!(product.to_string(), "1023.0");
+    /// assert_eq!(o, Less);
+    /// assert!(ctx.get_flags().overflow);
+    /// ```
+    pub fn mul_prec_round(
+        &mut self,
+        x: &Float,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let (product, o) = x.mul_prec_round_ref_ref(y, prec, rm);
+        self.finish(product, o, rm, x.is_nan() || y.is_nan())
+    }
+
+    /// Divides one [`Float`] by another, rounding the result to the specified precision with the
+    /// specified rounding mode and bringing it into the [`Context`]'s range. An [`Ordering`] is
+    /// also returned, indicating whether the result is less than, equal to, or greater than the
+    /// exact quotient.
+    ///
+    /// Divide-by-zero is raised if a finite nonzero [`Float`] is divided by zero, and invalid is
+    /// raised if the result is NaN but neither argument is. See [`Context::check_range`] for how
+    /// results outside the range are handled and which other flags are raised, and
+    /// [`Float::div_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// y.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (quotient, o) = ctx.div_prec_round(&Float::ONE, &Float::ZERO, 10, Nearest);
+    /// assert_eq!(quotient.to_string(), "Infinity");
+    /// assert_eq!(o, Equal);
+    /// assert!(ctx.get_flags().divide_by_zero);
+    /// assert!(!ctx.get_flags().overflow);
+    ///
+    /// let (quotient, o) = ctx.div_prec_round(&Float::ZERO, &Float::ZERO, 10, Nearest);
+    /// assert!(quotient.is_nan());
+    /// assert!(ctx.get_flags().invalid);
+    /// ```
+    pub fn div_prec_round(
+        &mut self,
+        x: &Float,
+        y: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        if *y == 0u32 && x.is_normal() {
+            self.flags.divide_by_zero = true;
+        }
+        let (quotient, o) = x.div_prec_round_ref_ref(y, prec, rm);
+        self.finish(quotient, o, rm, x.is_nan() || y.is_nan())
+    }
+
+    /// Computes the square root of a [`Float`], rounding the result to the specified precision
+    /// with the specified rounding mode and bringing it into the [`Context`]'s range. An
+    /// [`Ordering`] is also returned, indicating whether the result is less than, equal to, or
+    /// greater than the exact square root.
+    ///
+    /// Invalid is raised if the argument is less than zero. See [`Context::check_range`] for how
+    /// results outside the range are handled and which other flags are raised, and
+    /// [`Float::sqrt_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::{Context, Flags};
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (sqrt, o) = ctx.sqrt_prec_round(&Float::from(2), 10, Nearest);
+    /// assert_eq!(sqrt.to_string(), "1.414");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (sqrt, o) = ctx.sqrt_prec_round(&Float::from(-2), 10, Nearest);
+    /// assert!(sqrt.is_nan());
+    /// assert_eq!(
+    ///     ctx.get_flags(),
+    ///     Flags {
+    ///         inexact: true,
+    ///         invalid: true,
+    ///         ..Flags::default()
+    ///     }
+    /// );
+    /// ```
+    pub fn sqrt_prec_round(&mut self, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let (sqrt, o) = x.sqrt_prec_round_ref(prec, rm);
+        self.finish(sqrt, o, rm, x.is_nan())
+    }
+
+    /// Computes $xy+z$, where $x$, $y$, and $z$ are [`Float`]s, rounding the result once to the
+    /// specified precision with the specified rounding mode and bringing it into the
+    /// [`Context`]'s range. An [`Ordering`] is also returned, indicating whether the result is
+    /// less than, equal to, or greater than the exact result.
+    ///
+    /// Invalid is raised if the result is NaN but no argument is. See [`Context::check_range`] for
+    /// how results outside the range are handled and which other flags are raised, and
+    /// [`Float::mul_add_prec_round`] for the special cases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits() +
+    /// y.significant_bits(), z.significant_bits(), prec, d)`, where `d` is the difference between
+    /// the exponents of $xy$ and $z$.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (result, o) = ctx.mul_add_prec_round(
+    ///     &Float::from(32),
+    ///     &Float::from(32),
+    ///     &Float::from(-1),
+    ///     10,
+    ///     Nearest,
+    /// );
+    /// assert_eq!(result.to_string(), "1023.0");
+    /// assert_eq!(o, Equal);
+    /// assert!(!ctx.get_flags().overflow);
+    /// ```
+    pub fn mul_add_prec_round(
+        &mut self,
+        x: &Float,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let (result, o) = x.mul_add_prec_round_ref_ref_ref(y, z, prec, rm);
+        self.finish(result, o, rm, x.is_nan() || y.is_nan() || z.is_nan())
+    }
+}
@@ -0,0 +1,522 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::conversion::ieee_bits::IeeeFormat;
+use crate::Float;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{Infinity, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::LowMask;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+
+/// The exception flags that a [`Context`] can raise, as in IEEE 754 and MPFR.
+///
+/// The flags are sticky: once an operation raises a flag, it stays raised until the [`Context`]'s
+/// flags are cleared.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Flags {
+    /// Whether a result was not equal to the exact result of its operation.
+    pub inexact: bool,
+    /// Whether a result was nonzero and smaller in absolute value than the smallest normal value,
+    /// and also inexact. Tininess is detected after rounding, as in MPFR.
+    pub underflow: bool,
+    /// Whether a finite result was larger in absolute value than the largest finite value.
+    pub overflow: bool,
+    /// Whether an operation produced an exact infinity from finite arguments, as in $1/0$.
+    pub divide_by_zero: bool,
+    /// Whether an operation produced a NaN from arguments that were not NaN, as in $0/0$ or
+    /// $\sqrt{-1}$.
+    pub invalid: bool,
+}
+
+// Where a finite nonzero value lies relative to the range of a `Context`.
+enum RangeStatus {
+    Normal,
+    Tiny,
+    Overflow,
+}
+
+/// An exponent range, optionally with subnormal numbers, together with a set of exception
+/// [`Flags`].
+///
+/// On their own, [`Float`]s have an effectively unbounded exponent range. A [`Context`] restricts
+/// results to a fixed range, as MPFR does with `mpfr_set_emin`, `mpfr_set_emax`,
+/// `mpfr_check_range`, and `mpfr_subnormalize`, and records exceptions as they occur. Combined
+/// with a fixed precision, this makes it possible to emulate a hardware floating-point format
+/// exactly; see [`Context::from_ieee_format`].
+///
+/// A finite nonzero result $x$ is in range if its exponent $e$ (one more than the floor of the
+/// base-2 logarithm of $|x|$, as returned by [`Float::get_exponent`]) satisfies $e_\text{min} \leq
+/// e \leq e_\text{max}$. Results outside the range overflow to an infinity or to the largest finite
+/// value, or underflow to zero or to the smallest positive value $2^{e_\text{min}-1}$, depending on
+/// the rounding mode. If subnormals are enabled, results of precision $p$ whose exponent is less
+/// than $e_\text{min}+p-1$ are additionally rounded to $e-e_\text{min}+1$ significant bits, so that
+/// $2^{e_\text{min}-1}$ is the smallest subnormal and $2^{e_\text{min}+p-2}$ is the smallest
+/// normal value.
+///
+/// The operations of a [`Context`] assume that their arguments are in range.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Context {
+    emin: i32,
+    emax: i32,
+    subnormals: bool,
+    flags: Flags,
+}
+
+impl Default for Context {
+    /// Returns a [`Context`] with the widest possible exponent range, no subnormals, and no flags
+    /// raised.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// let ctx = Context::default();
+    /// assert_eq!(ctx.get_emin(), i32::MIN);
+    /// assert_eq!(ctx.get_emax(), i32::MAX);
+    /// assert!(!ctx.get_subnormals());
+    /// ```
+    fn default() -> Context {
+        Context::new(i32::MIN, i32::MAX)
+    }
+}
+
+impl Context {
+    /// Creates a [`Context`] with the given exponent range, no subnormals, and no flags raised.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `emin` is greater than `emax`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::{Context, Flags};
+    ///
+    /// let ctx = Context::new(-10, 10);
+    /// assert_eq!(ctx.get_emin(), -10);
+    /// assert_eq!(ctx.get_emax(), 10);
+    /// assert!(!ctx.get_subnormals());
+    /// assert_eq!(ctx.get_flags(), Flags::default());
+    /// ```
+    pub const fn new(emin: i32, emax: i32) -> Context {
+        assert!(emin <= emax);
+        Context {
+            emin,
+            emax,
+            subnormals: false,
+            flags: Flags {
+                inexact: false,
+                underflow: false,
+                overflow: false,
+                divide_by_zero: false,
+                invalid: false,
+            },
+        }
+    }
+
+    /// Creates a [`Context`] that emulates a binary interchange format, with subnormals enabled.
+    /// To emulate the format exactly, operations should use the format's precision,
+    /// `format.precision()`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// let ctx = Context::from_ieee_format(IeeeFormat::BINARY64);
+    /// assert_eq!(ctx.get_emin(), -1073);
+    /// assert_eq!(ctx.get_emax(), 1024);
+    /// assert!(ctx.get_subnormals());
+    /// ```
+    pub fn from_ieee_format(format: IeeeFormat) -> Context {
+        let mut ctx = Context::new(
+            i32::exact_from(format.min_exponent() - i64::exact_from(format.significand_bits()) + 1),
+            i32::exact_from(format.max_exponent() + 1),
+        );
+        ctx.subnormals = true;
+        ctx
+    }
+
+    /// Returns the smallest allowed exponent.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// assert_eq!(Context::new(-10, 10).get_emin(), -10);
+    /// ```
+    #[inline]
+    pub const fn get_emin(&self) -> i32 {
+        self.emin
+    }
+
+    /// Returns the largest allowed exponent.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// assert_eq!(Context::new(-10, 10).get_emax(), 10);
+    /// ```
+    #[inline]
+    pub const fn get_emax(&self) -> i32 {
+        self.emax
+    }
+
+    /// Returns whether subnormals are enabled.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert!(!Context::new(-10, 10).get_subnormals());
+    /// assert!(Context::from_ieee_format(IeeeFormat::BINARY32).get_subnormals());
+    /// ```
+    #[inline]
+    pub const fn get_subnormals(&self) -> bool {
+        self.subnormals
+    }
+
+    /// Returns the flags that have been raised since the [`Context`] was created or its flags
+    /// were last cleared.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::{Context, Flags};
+    /// use malachite_float::Float;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.div_prec_round(&Float::from(1), &Float::from(3), 10, Nearest);
+    /// assert_eq!(
+    ///     ctx.get_flags(),
+    ///     Flags {
+    ///         inexact: true,
+    ///         ..Flags::default()
+    ///     }
+    /// );
+    /// ```
+    #[inline]
+    pub const fn get_flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Sets the smallest allowed exponent.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `emin` is greater than the largest allowed exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.set_emin(-20);
+    /// assert_eq!(ctx.get_emin(), -20);
+    /// ```
+    pub fn set_emin(&mut self, emin: i32) {
+        assert!(emin <= self.emax);
+        self.emin = emin;
+    }
+
+    /// Sets the largest allowed exponent.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `emax` is less than the smallest allowed exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.set_emax(20);
+    /// assert_eq!(ctx.get_emax(), 20);
+    /// ```
+    pub fn set_emax(&mut self, emax: i32) {
+        assert!(emax >= self.emin);
+        self.emax = emax;
+    }
+
+    /// Enables or disables subnormals.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::context::Context;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.set_subnormals(true);
+    /// assert!(ctx.get_subnormals());
+    /// ```
+    #[inline]
+    pub fn set_subnormals(&mut self, subnormals: bool) {
+        self.subnormals = subnormals;
+    }
+
+    /// Lowers all flags.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::{Context, Flags};
+    /// use malachite_float::Float;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.div_prec_round(&Float::from(1), &Float::from(3), 10, Nearest);
+    /// ctx.clear_flags();
+    /// assert_eq!(ctx.get_flags(), Flags::default());
+    /// ```
+    #[inline]
+    pub fn clear_flags(&mut self) {
+        self.flags = Flags::default();
+    }
+
+    /// Returns the flags that have been raised, and lowers them.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::{Context, Flags};
+    /// use malachite_float::Float;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// ctx.mul_prec_round(&Float::from(100), &Float::from(100), 10, Nearest);
+    /// assert_eq!(
+    ///     ctx.take_flags(),
+    ///     Flags {
+    ///         inexact: true,
+    ///         overflow: true,
+    ///         ..Flags::default()
+    ///     }
+    /// );
+    /// assert_eq!(ctx.get_flags(), Flags::default());
+    /// ```
+    #[inline]
+    pub fn take_flags(&mut self) -> Flags {
+        core::mem::take(&mut self.flags)
+    }
+
+    // The result of an overflow with precision `prec`, for a positive value and the given rounding
+    // mode.
+    fn overflow(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        match rm {
+            Up | Ceiling | Nearest | NearestTiesAway => (Float::INFINITY, Greater),
+            Down | Floor | ToOdd => (
+                Float::from_natural_prec_round(Natural::low_mask(prec), prec, Exact).0
+                    << (i64::from(self.emax) - i64::exact_from(prec)),
+                Less,
+            ),
+            Exact => panic!("Inexact Float overflow"),
+        }
+    }
+
+    // The result of an underflow with precision `prec`, for a positive value `x` and the given
+    // rounding mode. `o` indicates whether `x` is less than, equal to, or greater than the exact
+    // value.
+    fn underflow(&self, x: &Float, o: Ordering, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let round_up = match rm {
+            Up | Ceiling | ToOdd => true,
+            Down | Floor => false,
+            Nearest | NearestTiesAway => {
+                // The midpoint between 0 and the smallest positive value is $2^{e_\text{min}-2}$.
+                // If `x` is the midpoint, `o` tells us on which side of it the exact value lies.
+                let exponent = x.get_exponent().unwrap();
+                if i64::from(exponent) < i64::from(self.emin) - 1 {
+                    false
+                } else if x.get_min_prec() != Some(1) {
+                    true
+                } else {
+                    match o {
+                        Less => true,
+                        Greater => false,
+                        Equal => rm == NearestTiesAway,
+                    }
+                }
+            }
+            Exact => panic!("Inexact Float underflow"),
+        };
+        if round_up {
+            (
+                Float::power_of_2_prec(i64::from(self.emin) - 1, prec),
+                Greater,
+            )
+        } else {
+            (Float::ZERO, Less)
+        }
+    }
+
+    // Rounds a positive subnormal value `x` of precision `prec` to `bits` significant bits,
+    // keeping its precision. `o` indicates whether `x` is less than, equal to, or greater than the
+    // exact value; it is used to avoid double rounding.
+    fn subnormalize(
+        x: Float,
+        o: Ordering,
+        prec: u64,
+        bits: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        // Directed roundings and rounding to odd give the same result whether or not the exact
+        // value was rounded first. Rounding to nearest does too, unless `x` is the midpoint of two
+        // adjacent values with `bits` bits; in that case, `o` tells us which way to go.
+        let rm = match rm {
+            Nearest | NearestTiesAway if o != Equal && x.get_min_prec() == Some(bits + 1) => {
+                if o == Greater {
+                    Floor
+                } else {
+                    Ceiling
+                }
+            }
+            rm => rm,
+        };
+        let (y, o_y) = Float::from_float_prec_round(x, bits, rm);
+        let y = Float::from_float_prec_round(y, prec, Exact).0;
+        (y, if o_y == Equal { o } else { o_y })
+    }
+
+    // Brings a positive finite value in range, returning the new value and ordering and where the
+    // value lies relative to the range.
+    fn check_positive_range(
+        &self,
+        x: Float,
+        o: Ordering,
+        rm: RoundingMode,
+    ) -> (Float, Ordering, RangeStatus) {
+        let prec = x.get_prec().unwrap();
+        let exponent = i64::from(x.get_exponent().unwrap());
+        let emin = i64::from(self.emin);
+        if exponent > i64::from(self.emax) {
+            let (x, o) = self.overflow(prec, rm);
+            (x, o, RangeStatus::Overflow)
+        } else if exponent < emin {
+            let (x, o) = self.underflow(&x, o, prec, rm);
+            (x, o, RangeStatus::Tiny)
+        } else if self.subnormals && exponent < emin + i64::exact_from(prec) - 1 {
+            let (x, o) =
+                Context::subnormalize(x, o, prec, u64::exact_from(exponent - emin + 1), rm);
+            (x, o, RangeStatus::Tiny)
+        } else {
+            (x, o, RangeStatus::Normal)
+        }
+    }
+
+    /// Brings the result of an operation into the [`Context`]'s exponent range, and raises the
+    /// appropriate flags. This is the equivalent of MPFR's `mpfr_check_range`, followed by
+    /// `mpfr_subnormalize` if subnormals are enabled.
+    ///
+    /// `x` must be the result of rounding the exact value of an operation, with unbounded
+    /// exponent range, using the rounding mode `rm`; `o` must indicate whether `x` is less than,
+    /// equal to, or greater than the exact value. The result is the same as if the exact value had
+    /// been rounded directly to the [`Context`]'s range, with no double rounding. An [`Ordering`]
+    /// is returned, indicating whether the result is less than, equal to, or greater than the
+    /// exact value.
+    ///
+    /// If $x$ is finite and its exponent is greater than $e_\text{max}$, the result is $\infty$
+    /// (for `Up`, `Ceiling`, `Nearest`, and `NearestTiesAway`) or the largest finite value with
+    /// the precision of $x$ (for `Down`, `Floor`, and `ToOdd`), and overflow is raised. This
+    /// follows IEEE 754, so unlike when converting to a primitive float, `Nearest` overflows to an
+    /// infinity. For negative $x$, the roles of `Floor` and `Ceiling` are reversed. Similarly, if
+    /// the exponent of $x$ is less than $e_\text{min}$, the result is zero or
+    /// $\pm2^{e_\text{min}-1}$. NaNs, infinities, and zeros are returned unchanged. If the result
+    /// is tiny (nonzero and below the normal range) and inexact, underflow is raised, and if it
+    /// is inexact, inexact is raised.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is inexact.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::context::Context;
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut ctx = Context::new(-10, 10);
+    /// let (x, o) = ctx.check_range(Float::from(2000), Equal, Nearest);
+    /// assert_eq!(x.to_string(), "Infinity");
+    /// assert_eq!(o, Greater);
+    /// assert!(ctx.get_flags().overflow);
+    ///
+    /// let (x, o) = ctx.check_range(Float::from(-2000), Equal, Down);
+    /// assert_eq!(x.to_string(), "-1023.5");
+    /// assert_eq!(o, Greater);
+    ///
+    /// // Emulate half precision. The exact value 1.0e-7 is first rounded to the precision of half
+    /// // precision normals, 11 bits, and then to the single bit that a subnormal this small has.
+    /// let mut ctx = Context::from_ieee_format(IeeeFormat::BINARY16);
+    /// let (x, o) = Float::from_float_prec_round(Float::from(1.0e-7), 11, Nearest);
+    /// let (x, o) = ctx.check_range(x, o, Nearest);
+    /// assert_eq!(x.to_string(), "1.192e-7");
+    /// assert_eq!(o, Greater);
+    /// assert!(ctx.get_flags().underflow);
+    /// ```
+    pub fn check_range(&mut self, x: Float, o: Ordering, rm: RoundingMode) -> (Float, Ordering) {
+        if !x.is_normal() {
+            return (x, o);
+        }
+        let (x, o, status) = if x > 0u32 {
+            self.check_positive_range(x, o, rm)
+        } else {
+            let (x, o, status) = self.check_positive_range(-x, o.reverse(), -rm);
+            (-x, o.reverse(), status)
+        };
+        match status {
+            RangeStatus::Normal => {}
+            RangeStatus::Tiny => self.flags.underflow |= o != Equal,
+            RangeStatus::Overflow => self.flags.overflow = true,
+        }
+        self.flags.inexact |= o != Equal;
+        (x, o)
+    }
+}
+
+/// Arithmetic within a [`Context`].
+pub mod arithmetic;
@@ -15,7 +15,7 @@ use malachite_base::num::arithmetic::traits::{
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::ExactFrom;
-use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
@@ -31,8 +31,8 @@ impl Float {
     /// The approximation has precision `prec` and is rounded according to the provided rounding
     /// mode.
     ///
-    /// This function reads `prec + z` bits, or `prec + z + 1` bits if `rm` is `Nearest`, where `z`
-    /// is the number of leading false bits in `bits`.
+    /// This function reads `prec + z` bits, or `prec + z + 1` bits if `rm` is `Nearest` or
+    /// `NearestTiesAway`, where `z` is the number of leading false bits in `bits`.
     ///
     /// $$
     /// f((x_k),p,m) = C+\varepsilon,
@@ -41,8 +41,10 @@ impl Float {
     /// $$
     /// C=\sum_{k=0}^\infty x_k 2^{-(k+1)}.
     /// $$
-    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C\rfloor-p+1}$.
-    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{\lfloor\log_2 C\rfloor-p}$.
+    /// - If $m$ is not `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C\rfloor-p+1}$.
+    /// - If $m$ is `Nearest` or `NearestTiesAway`, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   C\rfloor-p}$.
     ///
     /// The output has precision `prec`.
     ///
@@ -145,10 +147,18 @@ impl Float {
             }
         }
         let mut significand = Natural::from_owned_limbs_asc(limbs);
-        let increment = rm == Up || rm == Ceiling || (rm == Nearest && bits.next() == Some(true));
+        let lsb_index = prec.neg_mod_power_of_2(Limb::LOG_WIDTH);
+        let increment = match rm {
+            Up | Ceiling => true,
+            Down | Floor => false,
+            Nearest | NearestTiesAway => bits.next() == Some(true),
+            // The value is never exact, so the result must have a one in its least-significant
+            // bit. Setting that bit never carries.
+            ToOdd => !significand.get_bit(lsb_index),
+            Exact => unreachable!(),
+        };
         if increment {
-            significand +=
-                Natural::from(Limb::power_of_2(prec.neg_mod_power_of_2(Limb::LOG_WIDTH)));
+            significand += Natural::from(Limb::power_of_2(lsb_index));
             if !significand
                 .significant_bits()
                 .divisible_by_power_of_2(Limb::LOG_WIDTH)
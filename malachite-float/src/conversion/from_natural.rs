@@ -107,6 +107,40 @@ fn from_natural_prec_round_helper(
                 Equal
             }
         }
+        NearestTiesAway => {
+            if x.get_bit(bits - prec - 1) {
+                let original_limb_count = significand.limb_count();
+                significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
+                significand += Natural::power_of_2(mask_width);
+                if significand.limb_count() > original_limb_count {
+                    significand >>= 1;
+                    exponent = exponent.checked_add(1).unwrap();
+                }
+                Greater
+            } else if !x.divisible_by_power_of_2(bits - prec - 1) {
+                significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
+                Less
+            } else {
+                Equal
+            }
+        }
+        ToOdd => {
+            let inexact = erased_limb != 0
+                || !significand.divisible_by_power_of_2(mask_width)
+                || rev_limbs.any(|y| y != 0);
+            if inexact {
+                // Rounding to odd never carries into a new limb.
+                significand.round_to_multiple_of_power_of_2_assign(mask_width, Floor);
+                if x.get_bit(bits - prec) {
+                    Less
+                } else {
+                    significand += Natural::power_of_2(mask_width);
+                    Greater
+                }
+            } else {
+                Equal
+            }
+        }
     };
     (
         Float(Finite {
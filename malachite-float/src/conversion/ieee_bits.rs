@@ -0,0 +1,477 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{significand_bits, Float};
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{ModPowerOf2, PowerOf2, ShrRound};
+use malachite_base::num::basic::traits::{
+    Infinity as InfinityTrait, NaN as NaNTrait, One, Zero as ZeroTrait,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+
+/// A binary interchange format in the style of IEEE 754, described by the width of its exponent
+/// field and the width of its trailing significand field.
+///
+/// An encoding consists of a sign bit, followed by `exponent_bits` bits of biased exponent,
+/// followed by `significand_bits` bits of trailing significand. The implicit leading bit of the
+/// significand is not stored, so the precision of the format is `significand_bits + 1`. Biased
+/// exponent fields that are all zeros encode zeros and subnormals, and those that are all ones
+/// encode infinities and NaNs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IeeeFormat {
+    exponent_bits: u64,
+    significand_bits: u64,
+}
+
+impl IeeeFormat {
+    /// IEEE 754 binary16, or half precision: 5 exponent bits and 10 trailing significand bits.
+    pub const BINARY16: IeeeFormat = IeeeFormat::new(5, 10);
+
+    /// bfloat16: 8 exponent bits and 7 trailing significand bits.
+    pub const BFLOAT16: IeeeFormat = IeeeFormat::new(8, 7);
+
+    /// IEEE 754 binary32, or single precision: 8 exponent bits and 23 trailing significand bits.
+    /// This is the format of [`f32`].
+    pub const BINARY32: IeeeFormat = IeeeFormat::new(8, 23);
+
+    /// IEEE 754 binary64, or double precision: 11 exponent bits and 52 trailing significand bits.
+    /// This is the format of [`f64`].
+    pub const BINARY64: IeeeFormat = IeeeFormat::new(11, 52);
+
+    /// IEEE 754 binary128, or quadruple precision: 15 exponent bits and 112 trailing significand
+    /// bits.
+    pub const BINARY128: IeeeFormat = IeeeFormat::new(15, 112);
+
+    /// Creates an [`IeeeFormat`] with the given exponent field width and trailing significand field
+    /// width.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `exponent_bits` is less than 2 or greater than 30, or if `significand_bits` is
+    /// zero or greater than $2^{30}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// let format = IeeeFormat::new(4, 3);
+    /// assert_eq!(format.exponent_bits(), 4);
+    /// assert_eq!(format.significand_bits(), 3);
+    /// assert_eq!(format.precision(), 4);
+    /// assert_eq!(format.width(), 8);
+    /// assert_eq!(format.max_exponent(), 7);
+    /// assert_eq!(format.min_exponent(), -6);
+    /// ```
+    pub const fn new(exponent_bits: u64, significand_bits: u64) -> IeeeFormat {
+        assert!(exponent_bits >= 2 && exponent_bits <= 30);
+        assert!(significand_bits != 0 && significand_bits <= 1 << 30);
+        IeeeFormat {
+            exponent_bits,
+            significand_bits,
+        }
+    }
+
+    /// Returns the width of the exponent field.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.exponent_bits(), 5);
+    /// assert_eq!(IeeeFormat::BFLOAT16.exponent_bits(), 8);
+    /// ```
+    #[inline]
+    pub const fn exponent_bits(self) -> u64 {
+        self.exponent_bits
+    }
+
+    /// Returns the width of the trailing significand field. This does not include the implicit
+    /// leading bit.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.significand_bits(), 10);
+    /// assert_eq!(IeeeFormat::BFLOAT16.significand_bits(), 7);
+    /// ```
+    #[inline]
+    pub const fn significand_bits(self) -> u64 {
+        self.significand_bits
+    }
+
+    /// Returns the precision of the format's normal numbers; that is, the width of the trailing
+    /// significand field plus one.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.precision(), 11);
+    /// assert_eq!(IeeeFormat::BFLOAT16.precision(), 8);
+    /// ```
+    #[inline]
+    pub const fn precision(self) -> u64 {
+        self.significand_bits + 1
+    }
+
+    /// Returns the total width of an encoding, including the sign bit.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.width(), 16);
+    /// assert_eq!(IeeeFormat::BINARY128.width(), 128);
+    /// ```
+    #[inline]
+    pub const fn width(self) -> u64 {
+        self.exponent_bits + self.significand_bits + 1
+    }
+
+    /// Returns the largest scientific exponent of a finite number in the format (the exponent
+    /// bias), $2^{w-1}-1$, where $w$ is the width of the exponent field.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.max_exponent(), 15);
+    /// assert_eq!(IeeeFormat::BFLOAT16.max_exponent(), 127);
+    /// ```
+    #[inline]
+    pub const fn max_exponent(self) -> i64 {
+        (1 << (self.exponent_bits - 1)) - 1
+    }
+
+    /// Returns the smallest scientific exponent of a normal number in the format, $2-2^{w-1}$,
+    /// where $w$ is the width of the exponent field.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    ///
+    /// assert_eq!(IeeeFormat::BINARY16.min_exponent(), -14);
+    /// assert_eq!(IeeeFormat::BFLOAT16.min_exponent(), -126);
+    /// ```
+    #[inline]
+    pub const fn min_exponent(self) -> i64 {
+        1 - self.max_exponent()
+    }
+
+    // The bits of positive infinity.
+    fn infinity_bits(self) -> Natural {
+        Natural::low_mask(self.exponent_bits) << self.significand_bits
+    }
+
+    fn overflow(self, abs_rm: RoundingMode) -> (Natural, Ordering) {
+        match abs_rm {
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                (self.infinity_bits() - Natural::ONE, Less)
+            }
+            Ceiling | Up => (self.infinity_bits(), Greater),
+            Exact => panic!("Float too large for exact conversion"),
+        }
+    }
+}
+
+fn finite_abs_to_ieee_bits(
+    exponent: i32,
+    significand: &Natural,
+    format: IeeeFormat,
+    abs_rm: RoundingMode,
+) -> (Natural, Ordering) {
+    let exponent = i64::from(exponent);
+    let sci_exponent = exponent - 1;
+    if sci_exponent > format.max_exponent() {
+        return format.overflow(abs_rm);
+    }
+    let t = format.significand_bits;
+    // Values below the normal range are encoded with the minimum normal exponent and no implicit
+    // leading bit.
+    let sci_exponent = max(sci_exponent, format.min_exponent());
+    let (mantissa, o) = significand.shr_round(
+        sci_exponent - i64::exact_from(t) - exponent
+            + i64::exact_from(significand_bits(significand)),
+        abs_rm,
+    );
+    // Adding the mantissa, rather than its trailing bits, to the biased exponent minus one takes
+    // care of the implicit bit, of subnormals that round up to normals, and of normals whose
+    // mantissas round up to the next power of 2.
+    let bits =
+        (Natural::from(u64::exact_from(sci_exponent + format.max_exponent() - 1)) << t) + mantissa;
+    if bits >= format.infinity_bits() {
+        format.overflow(abs_rm)
+    } else {
+        (bits, o)
+    }
+}
+
+impl Float {
+    /// Encodes a [`Float`] as the bits of a binary interchange format, rounding according to a
+    /// specified rounding mode. The bits are returned as a [`Natural`] whose bit $i$ is bit $i$ of
+    /// the encoding. An [`Ordering`] is also returned, indicating whether the encoded value is
+    /// less than, equal to, or greater than the original value.
+    ///
+    /// Values too small to be represented as subnormals are rounded to zero or to the smallest
+    /// subnormal, keeping their sign. If the value is too large to be represented, the behavior
+    /// matches that of [`rounding_from`](malachite_base::num::conversion::traits::RoundingFrom)
+    /// from a [`Float`] to a primitive float: [`Ceiling`] and [`Up`] (or [`Floor`] and [`Down`],
+    /// for negative values) produce an infinity, and all other inexact rounding modes produce the
+    /// largest finite value of the appropriate sign. Encoding with [`IeeeFormat::BINARY32`] or
+    /// [`IeeeFormat::BINARY64`] gives the same bits as converting to an [`f32`] or [`f64`].
+    ///
+    /// Zeros and infinities keep their sign. Since a [`Float`] NaN has no sign or payload, it is
+    /// encoded as the positive quiet NaN whose payload is zero; that is, the exponent field and the
+    /// most-significant bit of the trailing significand are set and all other bits are clear.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// format.width())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `self` cannot be represented exactly in the format.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let half = IeeeFormat::BINARY16;
+    /// let (bits, o) = Float::from(1.0).to_ieee_bits_round(half, Exact);
+    /// assert_eq!(bits, 0x3c00u32);
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (bits, o) = Float::from(-1.0).to_ieee_bits_round(half, Exact);
+    /// assert_eq!(bits, 0xbc00u32);
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (bits, o) = Float::from(1.0 / 3.0).to_ieee_bits_round(half, Floor);
+    /// assert_eq!(bits, 0x3555u32);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (bits, o) = Float::from(1.0 / 3.0).to_ieee_bits_round(half, Ceiling);
+    /// assert_eq!(bits, 0x3556u32);
+    /// assert_eq!(o, Greater);
+    ///
+    /// // The smallest positive subnormal
+    /// let (bits, o) = Float::from(5.0e-8).to_ieee_bits_round(half, Nearest);
+    /// assert_eq!(bits, 1u32);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (bits, o) = Float::from(1.0e5).to_ieee_bits_round(half, Nearest);
+    /// assert_eq!(bits, 0x7bffu32);
+    /// assert_eq!(o, Less);
+    ///
+    /// let (bits, o) = Float::from(1.0e5).to_ieee_bits_round(half, Up);
+    /// assert_eq!(bits, 0x7c00u32);
+    /// assert_eq!(o, Greater);
+    ///
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_ieee_bits_round(half, Exact).0, 0x8000u32);
+    /// assert_eq!(Float::INFINITY.to_ieee_bits_round(half, Exact).0, 0x7c00u32);
+    /// assert_eq!(Float::NAN.to_ieee_bits_round(half, Exact).0, 0x7e00u32);
+    ///
+    /// let (bits, o) = Float::from(1.0 / 3.0).to_ieee_bits_round(IeeeFormat::BFLOAT16, Nearest);
+    /// assert_eq!(bits, 0x3eabu32);
+    /// assert_eq!(o, Greater);
+    /// ```
+    pub fn to_ieee_bits_round(&self, format: IeeeFormat, rm: RoundingMode) -> (Natural, Ordering) {
+        let sign_bit = format.width() - 1;
+        match self {
+            float_nan!() => (
+                format.infinity_bits() | Natural::power_of_2(format.significand_bits - 1),
+                Equal,
+            ),
+            float_infinity!() => (format.infinity_bits(), Equal),
+            float_negative_infinity!() => (
+                format.infinity_bits() | Natural::power_of_2(sign_bit),
+                Equal,
+            ),
+            float_zero!() => (Natural::ZERO, Equal),
+            float_negative_zero!() => (Natural::power_of_2(sign_bit), Equal),
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => {
+                let abs_rm = if *sign { rm } else { -rm };
+                let (bits, o) = finite_abs_to_ieee_bits(*exponent, significand, format, abs_rm);
+                if *sign {
+                    (bits, o)
+                } else {
+                    (bits | Natural::power_of_2(sign_bit), o.reverse())
+                }
+            }
+        }
+    }
+
+    /// Encodes a [`Float`] as the bits of a binary interchange format, rounding to the nearest
+    /// representable value. The bits are returned as a [`Natural`] whose bit $i$ is bit $i$ of the
+    /// encoding. An [`Ordering`] is also returned, indicating whether the encoded value is less
+    /// than, equal to, or greater than the original value.
+    ///
+    /// See [`Float::to_ieee_bits_round`] for details, including the treatment of overflow and
+    /// NaN.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// format.width())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (bits, o) = Float::from(1.0 / 3.0).to_ieee_bits(IeeeFormat::BINARY128);
+    /// assert_eq!(
+    ///     bits,
+    ///     Natural::from(0x3ffd5555555555555000000000000000u128)
+    /// );
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (bits, o) = Float::from(0.1).to_ieee_bits(IeeeFormat::BINARY16);
+    /// assert_eq!(bits, 0x2e66u32);
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn to_ieee_bits(&self, format: IeeeFormat) -> (Natural, Ordering) {
+        self.to_ieee_bits_round(format, Nearest)
+    }
+
+    /// Decodes the bits of a binary interchange format as a [`Float`]. Bit $i$ of `bits` is taken
+    /// to be bit $i$ of the encoding.
+    ///
+    /// The conversion is always exact. Normal numbers are given the precision of the format, and
+    /// subnormal numbers are given the smallest precision that represents them, as with the
+    /// conversions from primitive floats. Signed zeros and infinities are preserved; every NaN,
+    /// regardless of sign, payload, or whether it is quiet or signaling, decodes to
+    /// [`Float::NAN`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `format.width()`.
+    ///
+    /// # Panics
+    /// Panics if `bits` has more significant bits than the width of the format.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::conversion::ieee_bits::IeeeFormat;
+    /// use malachite_float::Float;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let half = IeeeFormat::BINARY16;
+    /// let x = Float::from_ieee_bits(&Natural::from(0x3c00u32), half);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(x.get_prec(), Some(11));
+    ///
+    /// let x = Float::from_ieee_bits(&Natural::from(0xc248u32), half);
+    /// assert_eq!(x.to_string(), "-3.141");
+    ///
+    /// // The largest finite binary16 value
+    /// let x = Float::from_ieee_bits(&Natural::from(0x7bffu32), half);
+    /// assert_eq!(x.to_string(), "6.55e4");
+    ///
+    /// // The smallest positive subnormal binary16 value
+    /// let x = Float::from_ieee_bits(&Natural::from(1u32), half);
+    /// assert_eq!(x.to_string(), "6.0e-8");
+    /// assert_eq!(x.get_prec(), Some(1));
+    ///
+    /// assert_eq!(
+    ///     Float::from_ieee_bits(&Natural::from(0x8000u32), half).to_string(),
+    ///     "-0.0"
+    /// );
+    /// assert_eq!(
+    ///     Float::from_ieee_bits(&Natural::from(0xfc00u32), half).to_string(),
+    ///     "-Infinity"
+    /// );
+    /// assert_eq!(
+    ///     Float::from_ieee_bits(&Natural::from(0x7c01u32), half).to_string(),
+    ///     "NaN"
+    /// );
+    ///
+    /// let x = Float::from_ieee_bits(&Natural::from(0x3f80u32), IeeeFormat::BFLOAT16);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(x.get_prec(), Some(8));
+    /// ```
+    pub fn from_ieee_bits(bits: &Natural, format: IeeeFormat) -> Float {
+        assert!(
+            bits.significant_bits() <= format.width(),
+            "bits do not fit in the format"
+        );
+        let t = format.significand_bits;
+        let w = format.exponent_bits;
+        let biased_exponent = u64::exact_from(&(bits >> t).mod_power_of_2(w));
+        let fraction = bits.mod_power_of_2(t);
+        let abs = if biased_exponent == u64::low_mask(w) {
+            if fraction == 0u32 {
+                Float::INFINITY
+            } else {
+                return Float::NAN;
+            }
+        } else if biased_exponent == 0 {
+            if fraction == 0u32 {
+                Float::ZERO
+            } else {
+                let prec = fraction.significant_bits();
+                Float::from_natural_prec(fraction, prec).0
+                    << (format.min_exponent() - i64::exact_from(t))
+            }
+        } else {
+            Float::from_natural_prec(fraction | Natural::power_of_2(t), t + 1).0
+                << (i64::exact_from(biased_exponent) - format.max_exponent() - i64::exact_from(t))
+        };
+        if bits.get_bit(w + t) {
+            -abs
+        } else {
+            abs
+        }
+    }
+}
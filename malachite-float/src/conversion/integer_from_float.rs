@@ -82,8 +82,8 @@ impl RoundingFrom<Float> for Integer {
                 let abs_rm = if sign { rm } else { -rm };
                 let (abs_i, abs_o) = if exponent < 0 {
                     match abs_rm {
-                        Floor | Down | Nearest => (Natural::ZERO, Less),
-                        Ceiling | Up => (Natural::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (Natural::ZERO, Less),
+                        Ceiling | Up | ToOdd => (Natural::ONE, Greater),
                         Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -176,8 +176,8 @@ impl<'a> RoundingFrom<&'a Float> for Integer {
                     let abs_rm = if *sign { rm } else { -rm };
                     let (abs_i, abs_o) = if *exponent < 0 {
                         match abs_rm {
-                            Floor | Down | Nearest => (Natural::ZERO, Less),
-                            Ceiling | Up => (Natural::ONE, Greater),
+                            Floor | Down | Nearest | NearestTiesAway => (Natural::ZERO, Less),
+                            Ceiling | Up | ToOdd => (Natural::ONE, Greater),
                             Exact => {
                                 panic!("Cannot convert Float to Integer using {rm}")
                             }
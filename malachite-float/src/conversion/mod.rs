@@ -209,6 +209,9 @@ pub mod from_primitive_int;
 /// Implementations of the [`From`] trait for converting a [`Rational`](malachite_q::Rational) to a
 /// [`Float`](crate::Float).
 pub mod from_rational;
+/// Functions for converting a [`Float`](crate::Float) to and from the bits of an IEEE 754-style
+/// binary interchange format, such as binary16, bfloat16, or binary128.
+pub mod ieee_bits;
 /// Implementations of traits for converting a [`Float`](crate::Float) to an
 /// [`Integer`](malachite_nz::integer::Integer).
 ///
@@ -711,6 +714,9 @@ pub mod primitive_float_from_float;
 /// assert_eq!(i8::convertible_from(&Float::NAN), false);
 /// ```
 pub mod primitive_int_from_float;
+/// Implementations of traits for conversions between Python `float`s and `decimal.Decimal`s and
+/// [`Float`](crate::Float)s using [pyo3](https://pyo3.rs/).
+pub mod pyo3;
 /// Implementations of traits for converting a [`Float`](crate::Float) to a
 /// [`Rational`](malachite_q::Rational).
 ///
@@ -718,6 +724,9 @@ pub mod primitive_int_from_float;
 /// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
 /// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
 pub mod rational_from_float;
+/// Implementations of traits for serialization and deserialization using
+/// [serde](https://serde.rs/).
+pub mod serde;
 /// Implementations of traits for converting [`Float`](crate::Float)s to and from [`String`]s.
 ///
 /// Warning: these implementations are unstable and will definitely be changed in the future.
@@ -22,8 +22,8 @@ impl RoundingFrom<Float> for Natural {
     /// is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including negative infinity), then it will be rounded to zero
-    /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-    /// panic.
+    /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise,
+    /// this function will panic.
     ///
     /// If the [`Float`] is NaN or positive infinity, the function will panic regardless of the
     /// rounding mode.
@@ -37,8 +37,8 @@ impl RoundingFrom<Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// positive infinity.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, or if the
+    /// [`Float`] is NaN or positive infinity.
     ///
     /// # Examples
     /// ```
@@ -79,7 +79,7 @@ impl RoundingFrom<Float> for Natural {
         match f {
             float_either_zero!() => (Natural::ZERO, Equal),
             float_negative_infinity!() => match rm {
-                Ceiling | Down | Nearest => (Natural::ZERO, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (Natural::ZERO, Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -90,13 +90,13 @@ impl RoundingFrom<Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        Ceiling | Down | Nearest => (Natural::ZERO, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (Natural::ZERO, Greater),
                         _ => panic!("Cannot convert negative number to Natural using {rm}"),
                     }
                 } else if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (Natural::ZERO, Less),
-                        Ceiling | Up => (Natural::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (Natural::ZERO, Less),
+                        Ceiling | Up | ToOdd => (Natural::ONE, Greater),
                         Exact => panic!("Cannot convert Float to Natural using {rm}"),
                     }
                 } else {
@@ -120,8 +120,8 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Float`] is negative (including negative infinity), then it will be rounded to zero
-    /// when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-    /// panic.
+    /// when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise,
+    /// this function will panic.
     ///
     /// If the [`Float`] is NaN or positive infinity, the function will panic regardless of the
     /// rounding mode.
@@ -135,8 +135,8 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`] is less
-    /// than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or if the [`Float`] is NaN or
-    /// positive infinity.
+    /// than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, or if the
+    /// [`Float`] is NaN or positive infinity.
     ///
     /// # Examples
     /// ```
@@ -177,7 +177,7 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
         match f {
             float_either_zero!() => (Natural::ZERO, Equal),
             float_negative_infinity!() => match rm {
-                Ceiling | Down | Nearest => (Natural::ZERO, Greater),
+                Ceiling | Down | Nearest | NearestTiesAway => (Natural::ZERO, Greater),
                 _ => panic!("Can't convert -Infinity to Natural using {rm}"),
             },
             Float(Finite {
@@ -188,13 +188,13 @@ impl<'a> RoundingFrom<&'a Float> for Natural {
             }) => {
                 if !sign {
                     match rm {
-                        Ceiling | Down | Nearest => (Natural::ZERO, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (Natural::ZERO, Greater),
                         _ => panic!("Cannot convert -Infinity to Natural using {rm}"),
                     }
                 } else if *exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (Natural::ZERO, Less),
-                        Ceiling | Up => (Natural::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (Natural::ZERO, Less),
+                        Ceiling | Up | ToOdd => (Natural::ONE, Greater),
                         Exact => panic!("Cannot convert Float to Natural using {rm}"),
                     }
                 } else {
@@ -39,7 +39,7 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         Floor | Down => (T::ZERO, Less),
-                        Ceiling | Up => (T::MIN_POSITIVE_SUBNORMAL, Greater),
+                        Ceiling | Up | ToOdd => (T::MIN_POSITIVE_SUBNORMAL, Greater),
                         Nearest => {
                             if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
                                 (T::MIN_POSITIVE_SUBNORMAL, Greater)
@@ -47,11 +47,18 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                                 (T::ZERO, Less)
                             }
                         }
+                        NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1 {
+                                (T::MIN_POSITIVE_SUBNORMAL, Greater)
+                            } else {
+                                (T::ZERO, Less)
+                            }
+                        }
                         Exact => panic!("Float too small for exact conversion"),
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        Floor | Down | Nearest => (T::MAX_FINITE, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX_FINITE, Less),
                         Ceiling | Up => (T::INFINITY, Greater),
                         Exact => panic!("Float too large for exact conversion"),
                     }
@@ -64,7 +71,9 @@ fn primitive_float_rounding_from_float<T: PrimitiveFloat>(
                     if mantissa.significant_bits() > target_prec {
                         if exponent == T::MAX_EXPONENT {
                             match abs_rm {
-                                Floor | Down | Nearest => (T::MAX_FINITE, Less),
+                                Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                    (T::MAX_FINITE, Less)
+                                }
                                 Ceiling | Up => (T::INFINITY, Greater),
 
                                 Exact => {
@@ -124,7 +133,7 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                 if exponent < T::MIN_EXPONENT {
                     match abs_rm {
                         Floor | Down => (T::ZERO, Less),
-                        Ceiling | Up => (T::MIN_POSITIVE_SUBNORMAL, Greater),
+                        Ceiling | Up | ToOdd => (T::MIN_POSITIVE_SUBNORMAL, Greater),
                         Nearest => {
                             if exponent == T::MIN_EXPONENT - 1 && !significand.is_power_of_2() {
                                 (T::MIN_POSITIVE_SUBNORMAL, Greater)
@@ -132,11 +141,18 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                                 (T::ZERO, Less)
                             }
                         }
+                        NearestTiesAway => {
+                            if exponent == T::MIN_EXPONENT - 1 {
+                                (T::MIN_POSITIVE_SUBNORMAL, Greater)
+                            } else {
+                                (T::ZERO, Less)
+                            }
+                        }
                         Exact => panic!("Float too small for exact conversion"),
                     }
                 } else if exponent > T::MAX_EXPONENT {
                     match abs_rm {
-                        Floor | Down | Nearest => (T::MAX_FINITE, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX_FINITE, Less),
                         Ceiling | Up => (T::INFINITY, Greater),
                         Exact => panic!("Float too large for exact conversion"),
                     }
@@ -149,7 +165,9 @@ fn primitive_float_rounding_from_float_ref<T: PrimitiveFloat>(
                     if mantissa.significant_bits() > target_prec {
                         if exponent == T::MAX_EXPONENT {
                             match abs_rm {
-                                Floor | Down | Nearest => (T::MAX_FINITE, Less),
+                                Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                    (T::MAX_FINITE, Less)
+                                }
                                 Ceiling | Up => (T::INFINITY, Greater),
 
                                 Exact => {
@@ -27,11 +27,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -44,20 +44,20 @@ where
             let exponent = i64::from(exponent);
             if !sign {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if exponent < 0 {
                 match rm {
-                    Floor | Down | Nearest => (T::ZERO, Less),
-                    Ceiling | Up => (T::ONE, Greater),
+                    Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                    Ceiling | Up | ToOdd => (T::ONE, Greater),
                     Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    Floor | Down | Nearest => (T::MAX, Less),
+                    Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -72,7 +72,7 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 };
@@ -93,11 +93,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::ZERO, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -110,20 +110,20 @@ where
             let exponent = i64::from(*exponent);
             if !sign {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
                     _ => panic!("Cannot convert negative Float to {} using {}", T::NAME, rm),
                 }
             } else if exponent < 0 {
                 match rm {
-                    Floor | Down | Nearest => (T::ZERO, Less),
-                    Ceiling | Up => (T::ONE, Greater),
+                    Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                    Ceiling | Up | ToOdd => (T::ONE, Greater),
                     Exact => {
                         panic!("Cannot convert Float to {} using {}", T::NAME, rm)
                     }
                 }
             } else if exponent > i64::wrapping_from(T::WIDTH) {
                 match rm {
-                    Floor | Down | Nearest => (T::MAX, Less),
+                    Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                     _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                 }
             } else {
@@ -138,7 +138,7 @@ where
                     (n, o)
                 } else {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                         _ => panic!("Cannot convert large Float to {} using {}", T::NAME, rm),
                     }
                 };
@@ -253,13 +253,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including negative infinity), then it will be rounded
-            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise,
-            /// this function will panic.
+            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or
+            /// `NearestTiesAway`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -268,9 +268,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is not
-            /// `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, if the [`Float`] is greater than the maximum representable value
+            /// of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`,
+            /// or `ToOdd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -287,13 +288,13 @@ macro_rules! impl_unsigned_from {
             /// than the original value.
             ///
             /// If the [`Float`] is negative (including negative infinity), then it will be rounded
-            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise,
-            /// this function will panic.
+            /// to zero when the [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or
+            /// `NearestTiesAway`. Otherwise, this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the unsigned
             /// type (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -302,9 +303,10 @@ macro_rules! impl_unsigned_from {
             ///
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
-            /// is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, if the [`Float`]
-            /// is greater than the maximum representable value of the unsigned type and `rm` is not
-            /// `Down`, `Floor`, or `Nearest`, or if the [`Float`] is NaN.
+            /// is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, if the [`Float`] is greater than the maximum representable value
+            /// of the unsigned type and `rm` is not `Down`, `Floor`, `Nearest`, `NearestTiesAway`,
+            /// or `ToOdd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -384,11 +386,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::MIN, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -402,15 +404,15 @@ where
             if sign {
                 if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (T::ZERO, Less),
-                        Ceiling | Up => (T::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                        Ceiling | Up | ToOdd => (T::ONE, Greater),
                         Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -427,7 +429,7 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            Floor | Down | Nearest => (T::MAX, Less),
+                            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -437,8 +439,8 @@ where
                 }
             } else if exponent < 0 {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
-                    Floor | Up => (T::NEGATIVE_ONE, Less),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
+                    Floor | Up | ToOdd => (T::NEGATIVE_ONE, Less),
                     Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -447,7 +449,7 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok because the
                 // next else block handles it.
                 match rm {
-                    Ceiling | Down | Nearest => (T::MIN, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -464,7 +466,7 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        Ceiling | Down | Nearest => (T::MIN, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -486,11 +488,11 @@ where
     match f {
         float_nan!() => panic!("Can't convert NaN to {}", T::NAME),
         float_infinity!() => match rm {
-            Floor | Down | Nearest => (T::MAX, Less),
+            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
             _ => panic!("Can't convert Infinity to {} using {}", T::NAME, rm),
         },
         float_negative_infinity!() => match rm {
-            Ceiling | Down | Nearest => (T::MIN, Greater),
+            Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
             _ => panic!("Can't convert -Infinity to {} using {}", T::NAME, rm),
         },
         float_either_zero!() => (T::ZERO, Equal),
@@ -504,15 +506,15 @@ where
             if *sign {
                 if exponent < 0 {
                     match rm {
-                        Floor | Down | Nearest => (T::ZERO, Less),
-                        Ceiling | Up => (T::ONE, Greater),
+                        Floor | Down | Nearest | NearestTiesAway => (T::ZERO, Less),
+                        Ceiling | Up | ToOdd => (T::ONE, Greater),
                         Exact => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
                     }
                 } else if exponent >= i64::wrapping_from(T::WIDTH) {
                     match rm {
-                        Floor | Down | Nearest => (T::MAX, Less),
+                        Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                         _ => {
                             panic!("Cannot convert Float to Integer using {rm}")
                         }
@@ -529,7 +531,7 @@ where
                         (n, o)
                     } else {
                         match rm {
-                            Floor | Down | Nearest => (T::MAX, Less),
+                            Floor | Down | Nearest | NearestTiesAway | ToOdd => (T::MAX, Less),
                             _ => {
                                 panic!("Cannot convert large Float to {} using {}", T::NAME, rm)
                             }
@@ -539,8 +541,8 @@ where
                 }
             } else if exponent < 0 {
                 match rm {
-                    Ceiling | Down | Nearest => (T::ZERO, Greater),
-                    Floor | Up => (T::NEGATIVE_ONE, Less),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::ZERO, Greater),
+                    Floor | Up | ToOdd => (T::NEGATIVE_ONE, Less),
                     Exact => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -549,7 +551,7 @@ where
                 // This doesn't catch the case where -2^(W+1) < x < -2^W, but that's ok because the
                 // next else block handles it.
                 match rm {
-                    Ceiling | Down | Nearest => (T::MIN, Greater),
+                    Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                     _ => {
                         panic!("Cannot convert Float to Integer using {rm}")
                     }
@@ -566,7 +568,7 @@ where
                     (n, o.reverse())
                 } else {
                     match rm {
-                        Ceiling | Down | Nearest => (T::MIN, Greater),
+                        Ceiling | Down | Nearest | NearestTiesAway => (T::MIN, Greater),
                         _ => panic!(
                             "Cannot convert large negative Float to {} using {}",
                             T::NAME,
@@ -698,13 +700,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including negative infinity), then it will be rounded to zero when the
-            /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise,
+            /// this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -714,9 +716,9 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, if the [`Float`] is greater than
+            /// the maximum representable value of the signed type and `rm` is not `Down`, `Floor`,
+            /// `Nearest`, `NearestTiesAway`, or `ToOdd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -734,13 +736,13 @@ macro_rules! impl_signed_from {
             ///
             /// If the [`Float`] is less than the minimum representable value of the signed type
             /// (including negative infinity), then it will be rounded to zero when the
-            /// [`RoundingMode`] is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise,
+            /// this function will panic.
             ///
             /// If the [`Float`] is greater than the maximum representable value of the signed type
             /// (including infinity), then it will be rounded to the maximum value when the
-            /// [`RoundingMode`] is `Floor`, `Down`, or `Nearest`. Otherwise, this function will
-            /// panic.
+            /// [`RoundingMode`] is `Floor`, `Down`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
+            /// Otherwise, this function will panic.
             ///
             /// If the [`Float`] is NaN, the function will panic regardless of the rounding mode.
             ///
@@ -750,9 +752,9 @@ macro_rules! impl_signed_from {
             /// # Panics
             /// Panics if the [`Float`] is not an integer and `rm` is `Exact`, or if the [`Float`]
             /// is smaller than the minimum representable value of the signed type and `rm` is not
-            /// `Down`, `Ceiling`, or `Nearest`, if the [`Float`] is greater than the maximum
-            /// representable value of the signed type and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`, or if the [`Float`] is NaN.
+            /// `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`, if the [`Float`] is greater than
+            /// the maximum representable value of the signed type and `rm` is not `Down`, `Floor`,
+            /// `Nearest`, `NearestTiesAway`, or `ToOdd`, or if the [`Float`] is NaN.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_float#rounding_from).
@@ -0,0 +1,376 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "enable_pyo3")]
+
+//!
+//! A [`Float`] is converted to a Python `float`, rounding to the nearest `f64` if necessary. As
+//! with [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom) and the `Nearest`
+//! rounding mode, a finite [`Float`] too large for an `f64` becomes the largest finite `f64` of the
+//! same sign. To convert a [`Float`] to a Python `decimal.Decimal` instead, wrap it in a
+//! [`DecimalFloat`]; every finite [`Float`] is a dyadic rational, so it can always be represented
+//! exactly by a `Decimal`.
+//!
+//! Both [`Float`] and [`DecimalFloat`] can be extracted from a Python `float`, `int`, or
+//! `decimal.Decimal`, and all three conversions are exact. Since a `Float` can only represent
+//! dyadic rationals, extracting a `Decimal` such as `Decimal("0.1")` fails with `ValueError`. Any
+//! other object is converted with Python's `float()`.
+//!
+//! To use this enable the `enable_pyo3` feature.
+//!
+//! ## Examples
+//!
+//! ```rust
+//! use malachite_float::conversion::pyo3::DecimalFloat;
+//! use malachite_float::Float;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn sqrt(x: Float) -> Float {
+//!     x.sqrt_prec(100).0
+//! }
+//!
+//! #[pyfunction]
+//! fn sqrt_decimal(x: Float) -> DecimalFloat {
+//!     DecimalFloat(x.sqrt_prec(100).0)
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(sqrt, m)?)?;
+//!     m.add_function(wrap_pyfunction!(sqrt_decimal, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from decimal import Decimal
+//! from my_module import sqrt, sqrt_decimal
+//!
+//! assert sqrt(2.0) == 1.4142135623730951
+//! assert str(sqrt_decimal(Decimal(2))).startswith("1.41421356237309504880168872420917")
+//! ```
+
+use crate::Float;
+use alloc::format;
+use alloc::string::{String, ToString};
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use pyo3::{
+    exceptions::PyValueError, intern, types::*, FromPyObject, IntoPy, PyErr, PyObject, PyResult,
+    Python, ToPyObject,
+};
+
+/// A wrapper around a [`Float`] that is converted to a Python `decimal.Decimal` rather than a
+/// Python `float`. The conversion is exact.
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+#[derive(Clone, Debug)]
+pub struct DecimalFloat(pub Float);
+
+// Converts a Python `decimal.Decimal` to a `Float` exactly, or returns `None` if it is not a dyadic
+// rational.
+fn decimal_to_float(d: &PyAny) -> PyResult<Option<Float>> {
+    let py = d.py();
+    if d.call_method0(intern!(py, "is_nan"))?.is_truthy()? {
+        return Ok(Some(Float::NAN));
+    }
+    let sign = !d.call_method0(intern!(py, "is_signed"))?.is_truthy()?;
+    if d.call_method0(intern!(py, "is_infinite"))?.is_truthy()? {
+        return Ok(Some(if sign {
+            Float::INFINITY
+        } else {
+            Float::NEGATIVE_INFINITY
+        }));
+    }
+    if d.call_method0(intern!(py, "is_zero"))?.is_truthy()? {
+        return Ok(Some(if sign {
+            Float::ZERO
+        } else {
+            Float::NEGATIVE_ZERO
+        }));
+    }
+    let (numerator, denominator): (Integer, Integer) =
+        d.call_method0(intern!(py, "as_integer_ratio"))?.extract()?;
+    Ok(Float::try_from(Rational::from_integers(numerator, denominator)).ok())
+}
+
+// Writes a `Float` as a string that `decimal.Decimal` parses exactly. A finite `Float` equals
+// $n/2^k$ for some integers $n$ and $k \geq 0$, which is $n5^k \times 10^{-k}$.
+fn float_to_decimal_string(x: &Float) -> String {
+    if x.is_nan() {
+        "NaN".to_string()
+    } else if x.is_infinite() {
+        if x.is_sign_positive() {
+            "Infinity".to_string()
+        } else {
+            "-Infinity".to_string()
+        }
+    } else if *x == 0u32 {
+        if x.is_sign_positive() {
+            "0".to_string()
+        } else {
+            "-0".to_string()
+        }
+    } else {
+        let q = Rational::exact_from(x);
+        let sign = if q < 0u32 { "-" } else { "" };
+        let (numerator, denominator) = q.into_numerator_and_denominator();
+        let k = denominator.significant_bits() - 1;
+        if k == 0 {
+            format!("{sign}{numerator}")
+        } else {
+            format!("{sign}{}E-{k}", numerator * Natural::from(5u32).pow(k))
+        }
+    }
+}
+
+fn extract_float(ob: &PyAny) -> PyResult<Float> {
+    let py = ob.py();
+    if let Ok(x) = ob.downcast::<PyFloat>() {
+        return Ok(Float::from(x.value()));
+    }
+    if ob.downcast::<PyLong>().is_ok() {
+        return Ok(Float::from(ob.extract::<Integer>()?));
+    }
+    let decimal = py
+        .import_bound(intern!(py, "decimal"))?
+        .getattr(intern!(py, "Decimal"))?;
+    if ob.is_instance(decimal.as_gil_ref())? {
+        decimal_to_float(ob)?.ok_or_else(|| {
+            PyErr::new::<PyValueError, _>("Decimal is not exactly representable as a Float")
+        })
+    } else {
+        Ok(Float::from(ob.extract::<f64>()?))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'source> FromPyObject<'source> for Float {
+    fn extract(ob: &'source PyAny) -> PyResult<Float> {
+        extract_float(ob)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl ToPyObject for Float {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        f64::rounding_from(self, Nearest).0.to_object(py)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl IntoPy<PyObject> for Float {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'source> FromPyObject<'source> for DecimalFloat {
+    fn extract(ob: &'source PyAny) -> PyResult<DecimalFloat> {
+        extract_float(ob).map(DecimalFloat)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl ToPyObject for DecimalFloat {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        py.import_bound(intern!(py, "decimal"))
+            .and_then(|decimal| decimal.getattr(intern!(py, "Decimal")))
+            .and_then(|decimal| decimal.call1((float_to_decimal_string(&self.0),)))
+            .expect("decimal.Decimal() failed during to_object()")
+            .into()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl IntoPy<PyObject> for DecimalFloat {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComparableFloat;
+    use malachite_base::num::basic::floats::PrimitiveFloat;
+    use malachite_base::num::float::NiceFloat;
+
+    /// Prepare Python
+    fn prepare_python() {
+        pyo3::prepare_freethreaded_python();
+    }
+
+    /// Evaluate a Python expression, with the `decimal` module available
+    fn eval<'py>(py: Python<'py>, code: &str) -> &'py PyAny {
+        let locals = PyDict::new_bound(py);
+        locals
+            .set_item("decimal", py.import_bound("decimal").unwrap())
+            .unwrap();
+        py.eval_bound(code, None, Some(&locals))
+            .unwrap()
+            .into_gil_ref()
+    }
+
+    fn float(s: &str) -> Float {
+        crate::test_util::common::parse_hex_string(s)
+    }
+
+    /// Test conversion to and from Python floats
+    #[test]
+    fn convert_float() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let test = |code: &str, x: f64| {
+                let ob = eval(py, code);
+                // Python -> Rust
+                let f = ob.extract::<Float>().unwrap();
+                assert_eq!(ComparableFloat(f.clone()), ComparableFloat(Float::from(x)));
+                // Rust -> Python
+                let y: f64 = f.to_object(py).extract(py).unwrap();
+                assert_eq!(NiceFloat(y), NiceFloat(x));
+            };
+            test("0.0", 0.0);
+            test("-0.0", -0.0);
+            test("1.5", 1.5);
+            test("-0.1", -0.1);
+            test("float('inf')", f64::INFINITY);
+            test("float('-inf')", f64::NEGATIVE_INFINITY);
+            test("float('nan')", f64::NAN);
+            test("5e-324", f64::MIN_POSITIVE_SUBNORMAL);
+            test("1.7976931348623157e308", f64::MAX);
+        });
+    }
+
+    /// Test that Python ints are converted exactly, rather than through a Python float
+    #[test]
+    fn convert_int() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let test = |code: &str, s: &str| {
+                let f = eval(py, code).extract::<Float>().unwrap();
+                assert!(f.is_valid());
+                assert_eq!(ComparableFloat(f), ComparableFloat(float(s)));
+            };
+            test("0", "0x0.0");
+            test("True", "0x1.0#1");
+            test("3", "0x3.0#2");
+            test("-5", "-0x5.0#3");
+            test("2**100 + 1", "0x10000000000000000000000001.0#101");
+            test("-(2**100 + 1)", "-0x10000000000000000000000001.0#101");
+            let f = eval(py, "10**400").extract::<Float>().unwrap();
+            assert_eq!(
+                ComparableFloat(f),
+                ComparableFloat(Float::from(Natural::from(10u32).pow(400)))
+            );
+        });
+    }
+
+    /// Test that conversion to a Python float rounds to nearest
+    #[test]
+    fn round_to_float() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let test = |s: &str, out: f64| {
+                let y: f64 = float(s).to_object(py).extract(py).unwrap();
+                assert_eq!(NiceFloat(y), NiceFloat(out));
+            };
+            let x = Float::from_rational_prec(Rational::from_signeds(1, 3), 100).0;
+            let y: f64 = x.to_object(py).extract(py).unwrap();
+            assert_eq!(NiceFloat(y), NiceFloat(1.0 / 3.0));
+            test("0x1.00000000000008#56", 1.0);
+            test("0x1.00000000000018#56", 1.0000000000000004);
+            test("0x1.0E+300#1", f64::MAX);
+            test("-0x1.0E+300#1", -f64::MAX);
+            test("0x1.0E-300#1", 0.0);
+        });
+    }
+
+    /// Test conversion to and from Python Decimals
+    #[test]
+    fn convert_decimal() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let test = |code: &str, s: &str| {
+                let ob = eval(py, code);
+                // Python -> Rust
+                let f = ob.extract::<DecimalFloat>().unwrap().0;
+                assert_eq!(ComparableFloat(f.clone()), ComparableFloat(float(s)));
+                // Rust -> Python
+                let d = DecimalFloat(f).to_object(py);
+                let d = d.bind(py);
+                assert_eq!(d.get_type().qualname().unwrap(), "Decimal");
+                assert!(
+                    d.eq(ob).unwrap() || d.call_method0("is_nan").unwrap().is_truthy().unwrap()
+                );
+                assert_eq!(
+                    d.call_method0("is_signed").unwrap().is_truthy().unwrap(),
+                    ob.call_method0("is_signed").unwrap().is_truthy().unwrap()
+                );
+            };
+            test("decimal.Decimal('0')", "0x0.0");
+            test("decimal.Decimal('-0.000')", "-0x0.0");
+            test("decimal.Decimal('NaN')", "NaN");
+            test("decimal.Decimal('Infinity')", "Infinity");
+            test("decimal.Decimal('-Infinity')", "-Infinity");
+            test("decimal.Decimal('1')", "0x1.0#1");
+            test("decimal.Decimal('-2.5')", "-0x2.8#3");
+            test("decimal.Decimal('0.0009765625')", "0x0.004#1");
+            test("decimal.Decimal('1E+3')", "0x3e8.0#10");
+        });
+    }
+
+    /// Test that Decimals which are not dyadic rationals are rejected
+    #[test]
+    fn reject_non_dyadic_decimal() {
+        prepare_python();
+        Python::with_gil(|py| {
+            assert!(eval(py, "decimal.Decimal('0.1')")
+                .extract::<Float>()
+                .is_err());
+            assert!(eval(py, "decimal.Decimal('1') / 3")
+                .extract::<DecimalFloat>()
+                .is_err());
+        });
+    }
+
+    /// Test that conversion to a Decimal is exact, even when the Decimal context has a lower
+    /// precision
+    #[test]
+    fn exact_decimal() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let x = Float::from_rational_prec(Rational::from_signeds(1, 3), 100).0;
+            let d = DecimalFloat(x.clone()).to_object(py);
+            assert_eq!(
+                d.bind(py).str().unwrap().to_str().unwrap(),
+                concat!(
+                    "0.333333333333333333333333333333464810150870168634235288094213797704945534",
+                    "40585150383412837982177734375"
+                )
+            );
+            let y = d.extract::<Float>(py).unwrap();
+            assert_eq!(Rational::exact_from(&y), Rational::exact_from(&x));
+
+            let x = float("-0x1.0E-100#1");
+            let y = DecimalFloat(x.clone())
+                .to_object(py)
+                .extract::<Float>(py)
+                .unwrap();
+            assert_eq!(ComparableFloat(y), ComparableFloat(x));
+        });
+    }
+}
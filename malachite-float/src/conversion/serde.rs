@@ -0,0 +1,100 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::InnerFloat::Finite;
+use crate::{ComparableFloatRef, Float, SerdeFloat};
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering::*;
+use core::convert::TryFrom;
+use core::str::FromStr;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::FromStringBase;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::natural::Natural;
+
+impl From<Float> for SerdeFloat {
+    #[inline]
+    fn from(x: Float) -> SerdeFloat {
+        SerdeFloat(format!("{:#x}", ComparableFloatRef(&x)))
+    }
+}
+
+// Parses the absolute value of a finite, nonzero `Float`, written as a hexadecimal mantissa, an
+// optional exponent marked by 'E', and a precision following '#'; for example, "1.8E+10#3". Unlike
+// `from_string_base`, this returns `None` on invalid input rather than panicking, and it never
+// allocates much more memory than the length of the string, even if the exponent or precision is
+// huge.
+fn from_finite_hex_string(s: &str) -> Option<Float> {
+    let (s, precision) = s.split_once('#')?;
+    let (mantissa, exponent) = match s.split_once('E') {
+        Some((mantissa, exponent)) => (mantissa, i64::from_str(exponent).ok()?),
+        None => (s, 0),
+    };
+    let (int_digits, frac_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if int_digits.is_empty()
+        || !int_digits
+            .bytes()
+            .chain(frac_digits.bytes())
+            .all(|b| b.is_ascii_hexdigit())
+    {
+        return None;
+    }
+    let mut digits = String::with_capacity(int_digits.len() + frac_digits.len());
+    digits.push_str(int_digits);
+    digits.push_str(frac_digits);
+    // Every hex digit holds 4 bits, and `Float`s are always written with enough digits to show
+    // their full precision.
+    let precision = u64::from_str(precision).ok()?;
+    if precision == 0 || precision > (u64::try_from(digits.len()).ok()? + 1) << 2 {
+        return None;
+    }
+    let significand = Natural::from_string_base(16, &digits)?;
+    if significand == 0u32 {
+        return None;
+    }
+    // The value is `significand` * 2^shift.
+    let shift = exponent
+        .checked_sub(i64::try_from(frac_digits.len()).ok()?)?
+        .checked_mul(4)?;
+    let (mut x, o) = Float::from_natural_prec_round(significand, precision, Floor);
+    if o != Equal {
+        return None;
+    }
+    if let Float(Finite { exponent, .. }) = &mut x {
+        *exponent = i32::try_from(i64::from(*exponent).checked_add(shift)?).ok()?;
+    }
+    Some(x)
+}
+
+impl TryFrom<SerdeFloat> for Float {
+    type Error = String;
+
+    fn try_from(s: SerdeFloat) -> Result<Float, String> {
+        match s.0.as_str() {
+            "NaN" => Ok(Float::NAN),
+            "Infinity" => Ok(Float::INFINITY),
+            "-Infinity" => Ok(Float::NEGATIVE_INFINITY),
+            "0x0.0" => Ok(Float::ZERO),
+            "-0x0.0" => Ok(Float::NEGATIVE_ZERO),
+            t => {
+                let (t, sign) = if let Some(t) = t.strip_prefix('-') {
+                    (t, false)
+                } else {
+                    (t, true)
+                };
+                let t = t
+                    .strip_prefix("0x")
+                    .ok_or_else(|| format!("String '{}' does not start with '0x' or '-0x'", s.0))?;
+                let x = from_finite_hex_string(t)
+                    .ok_or_else(|| format!("Unrecognized Float {}", s.0))?;
+                Ok(if sign { x } else { -x })
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{ComparableFloatRef, Float};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{FloorLogBase2, Pow};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::string::from_sci_string::{
+    parse_exponent, preprocess_sci_string, validate_helper,
+};
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// The digits must already have been validated.
+fn natural_from_digits(digits: &[u8], base: u8) -> Natural {
+    Natural::from_string_base(base, core::str::from_utf8(digits).unwrap()).unwrap()
+}
+
+// Returns $b^e2^p$.
+fn scale(base: u8, exponent: i64, binary_exponent: i64) -> Rational {
+    Rational::from(base).pow(exponent) << binary_exponent
+}
+
+fn signed_rational(x: Natural, neg: bool) -> Rational {
+    let x = Rational::from(x);
+    if neg {
+        -x
+    } else {
+        x
+    }
+}
+
+impl Float {
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the specified
+    /// precision, rounding with the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the exact
+    /// value represented by the string.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored; `rm` is used instead.
+    ///
+    /// The string may start with a `'+'` or `'-'` sign. The strings `"nan"`, `"inf"`, and
+    /// `"infinity"`, in any case, are parsed as NaN and infinities; they take priority over digits
+    /// in bases 24 and above. A minus sign in front of zero produces negative zero.
+    ///
+    /// Otherwise, the syntax is the same as the syntax accepted by the
+    /// [`FromSciString`](malachite_base::num::conversion::traits::FromSciString) implementation of
+    /// [`Rational`], with two additions:
+    /// - If the base is 16, the digits may be preceded by `"0x"` or `"0X"`.
+    /// - If the base is 16 or less, a binary exponent may follow the digits (and the ordinary
+    ///   exponent, if there is one). It is indicated using the character `'p'` or `'P'`, and
+    ///   multiplies the value by a power of 2, as in C's hexadecimal floating-point literals. Like
+    ///   the ordinary exponent, it is written in base 10.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// The result is correctly rounded. Long inputs are not converted to a [`Rational`] in their
+    /// entirety: only as many leading digits as are needed to determine the rounding are used,
+    /// unless the value is very close to a rounding boundary.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(s.len(), prec, e)`, where `e`
+    /// is the absolute value of the exponent.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact` but the value represented by the string
+    /// cannot be represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let options = FromSciStringOptions::default();
+    /// let (x, o) = Float::from_sci_string_prec_round("3.14159", options, 10, Floor).unwrap();
+    /// assert_eq!(x.to_string(), "3.141");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec_round("3.14159", options, 10, Ceiling).unwrap();
+    /// assert_eq!(x.to_string(), "3.145");
+    /// assert_eq!(x.get_prec(), Some(10));
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec_round("-1.5e-3", options, 20, Nearest).unwrap();
+    /// assert_eq!(x.to_string(), "-0.001499999");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec_round("-0", options, 10, Nearest).unwrap();
+    /// assert_eq!(x.to_string(), "-0.0");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec_round("-inf", options, 10, Nearest).unwrap();
+    /// assert_eq!(x.to_string(), "-Infinity");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// let (x, o) = Float::from_sci_string_prec_round("0x1.8p-3", options, 10, Exact).unwrap();
+    /// assert_eq!(x.to_string(), "0.1875");
+    /// assert_eq!(o, Equal);
+    ///
+    /// assert!(Float::from_sci_string_prec_round("1.2.3", options, 10, Exact).is_none());
+    /// ```
+    pub fn from_sci_string_prec_round(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Option<(Float, Ordering)> {
+        assert_ne!(prec, 0);
+        let (neg, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if s.eq_ignore_ascii_case("nan") {
+            return Some((Float::NAN, Equal));
+        } else if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
+            return Some((
+                if neg {
+                    Float::NEGATIVE_INFINITY
+                } else {
+                    Float::INFINITY
+                },
+                Equal,
+            ));
+        }
+        let base = options.get_base();
+        let s = if base == 16 {
+            s.strip_prefix("0x")
+                .or_else(|| s.strip_prefix("0X"))
+                .unwrap_or(s)
+        } else {
+            s
+        };
+        let mut binary_exponent = 0;
+        let mut s = s;
+        if base <= 16 {
+            if let Some(i) = s.rfind(['p', 'P']) {
+                binary_exponent = parse_exponent(&s.as_bytes()[i + 1..])?;
+                s = &s[..i];
+            }
+        }
+        let (digits, exponent) = preprocess_sci_string(s, options)?;
+        if digits.is_empty() {
+            return None;
+        }
+        validate_helper(&digits, base)?;
+        let first_nonzero = if let Some(i) = digits.iter().position(|&c| c != b'0') {
+            i
+        } else {
+            return Some((
+                if neg {
+                    Float::NEGATIVE_ZERO
+                } else {
+                    Float::ZERO
+                },
+                Equal,
+            ));
+        };
+        let digits = &digits[first_nonzero..];
+        if rm != Exact {
+            // Start with a few more digits than are needed to distinguish between adjacent `Float`s
+            // of precision `prec`, and double the number of digits until the rounding is
+            // determined. Usually one iteration suffices.
+            let mut len = usize::exact_from((prec + 10) / base.floor_log_base_2()) + 2;
+            while len < digits.len() {
+                let (kept, dropped) = digits.split_at(len);
+                let exponent = exponent.checked_add(i64::try_from(dropped.len()).ok()?)?;
+                let scale = scale(base, exponent, binary_exponent);
+                let n = natural_from_digits(kept, base);
+                if dropped.iter().all(|&c| c == b'0') {
+                    return Some(Float::from_rational_prec_round(
+                        signed_rational(n, neg) * scale,
+                        prec,
+                        rm,
+                    ));
+                }
+                // The exact value lies strictly between `lo` and `hi`.
+                let mut lo = signed_rational(n, neg);
+                let mut hi = &lo + if neg { -Rational::ONE } else { Rational::ONE };
+                if neg {
+                    core::mem::swap(&mut lo, &mut hi);
+                }
+                let (lo, o_lo) = Float::from_rational_prec_round(lo * &scale, prec, rm);
+                let (hi, o_hi) = Float::from_rational_prec_round(hi * scale, prec, rm);
+                // Rounding is monotonic, so if both endpoints round to the same `Float`, so does
+                // the exact value. It is only known on which side of the exact value the result
+                // lies if the result lies outside the interval.
+                if ComparableFloatRef(&lo) == ComparableFloatRef(&hi) {
+                    if o_lo == Less {
+                        return Some((lo, Less));
+                    } else if o_hi == Greater {
+                        return Some((hi, Greater));
+                    }
+                }
+                len <<= 1;
+            }
+        }
+        Some(Float::from_rational_prec_round(
+            signed_rational(natural_from_digits(digits, base), neg)
+                * scale(base, exponent, binary_exponent),
+            prec,
+            rm,
+        ))
+    }
+
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the specified
+    /// precision, rounding to the nearest value. An [`Ordering`] is also returned, indicating
+    /// whether the returned value is less than, equal to, or greater than the exact value
+    /// represented by the string.
+    ///
+    /// If the exact value is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// The syntax is described in the documentation of
+    /// [`Float::from_sci_string_prec_round`]. The rounding mode option in `options` is ignored.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(s.len(), prec, e)`, where `e`
+    /// is the absolute value of the exponent.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let options = FromSciStringOptions::default();
+    /// let (x, o) = Float::from_sci_string_prec("0.1", options, 53).unwrap();
+    /// assert_eq!(x, 0.1);
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("1e20", options, 100).unwrap();
+    /// assert_eq!(x.to_string(), "100000000000000000000.0");
+    /// assert_eq!(o, Equal);
+    ///
+    /// assert_eq!(
+    ///     Float::from_sci_string_prec("NaN", options, 100).unwrap().0.to_string(),
+    ///     "NaN"
+    /// );
+    /// assert!(Float::from_sci_string_prec("abc", options, 100).is_none());
+    /// ```
+    #[inline]
+    pub fn from_sci_string_prec(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+    ) -> Option<(Float, Ordering)> {
+        Float::from_sci_string_prec_round(s, options, prec, Nearest)
+    }
+}
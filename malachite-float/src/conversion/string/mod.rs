@@ -6,5 +6,7 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod from_sci_string;
 pub mod from_string;
+pub mod to_sci;
 pub mod to_string;
@@ -0,0 +1,242 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::alloc::string::{String, ToString};
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use core::cmp::Ordering::{self, *};
+use core::fmt::{Formatter, Write};
+use malachite_base::num::arithmetic::traits::{Abs, FloorLogBase, Pow};
+use malachite_base::num::basic::traits::Zero as ZeroTrait;
+use malachite_base::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom, ToSci};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+impl Float {
+    /// Converts a [`Float`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation, and returns an [`Ordering`] indicating whether the value of the
+    /// string is less than, equal to, or greater than the [`Float`].
+    ///
+    /// The string is the same as the one produced by
+    /// [`to_sci_with_options`](ToSci::to_sci_with_options). See [`ToSciOptions`] for details on the
+    /// available options. If the [`Float`] is NaN, infinite, or zero, the returned [`Ordering`] is
+    /// `Equal`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// self.get_exponent().abs(), s)`, where `s` depends on the size type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is `self.get_prec()`.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded, or if `options` has `size_complete` specified but the [`Float`] has a
+    /// non-terminating expansion in the base.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Float::from(std::f64::consts::PI);
+    /// let mut options = ToSciOptions::default();
+    /// options.set_scale(4);
+    /// assert_eq!(
+    ///     x.to_sci_string_and_ordering(options),
+    ///     ("3.1416".to_string(), Greater)
+    /// );
+    ///
+    /// options.set_rounding_mode(Floor);
+    /// assert_eq!(
+    ///     x.to_sci_string_and_ordering(options),
+    ///     ("3.1415".to_string(), Less)
+    /// );
+    ///
+    /// options.set_size_complete();
+    /// assert_eq!(
+    ///     x.to_sci_string_and_ordering(options),
+    ///     (
+    ///         "3.141592653589793115997963468544185161590576171875".to_string(),
+    ///         Equal
+    ///     )
+    /// );
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_base(2);
+    /// options.set_precision(3);
+    /// assert_eq!(
+    ///     (-x).to_sci_string_and_ordering(options),
+    ///     ("-11".to_string(), Greater)
+    /// );
+    /// ```
+    pub fn to_sci_string_and_ordering(&self, options: ToSciOptions) -> (String, Ordering) {
+        let s = self.to_sci_with_options(options).to_string();
+        let o = if let Float(Finite { .. }) = self {
+            let q = Rational::exact_from(self);
+            let q_base = Rational::from(options.get_base());
+            let scale = match options.get_size_options() {
+                SciSizeOptions::Complete => return (s, Equal),
+                SciSizeOptions::Scale(scale) => i64::exact_from(scale),
+                SciSizeOptions::Precision(precision) => {
+                    i64::exact_from(precision - 1) - (&q).abs().floor_log_base(&q_base)
+                }
+            };
+            Integer::rounding_from(q * q_base.pow(scale), options.get_rounding_mode()).1
+        } else {
+            Equal
+        };
+        (s, o)
+    }
+}
+
+impl ToSci for Float {
+    /// Determines whether a [`Float`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// NaN, infinities, and zeros can always be converted. Any other [`Float`] can be converted iff
+    /// the [`Rational`] with the same value can be converted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// self.get_exponent().abs(), s)`, where `s` depends on the size type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is `self.get_prec()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// assert!(Float::from(0.125).fmt_sci_valid(options));
+    /// options.set_rounding_mode(Exact);
+    /// options.set_precision(2);
+    /// assert!(!Float::from(0.125).fmt_sci_valid(options));
+    ///
+    /// // Every finite `Float` has a terminating expansion in an even base...
+    /// let mut options = ToSciOptions::default();
+    /// options.set_size_complete();
+    /// assert!(Float::from(0.1).fmt_sci_valid(options));
+    ///
+    /// // ...but non-integers have non-terminating expansions in odd bases
+    /// options.set_base(3);
+    /// assert!(!Float::from(0.1).fmt_sci_valid(options));
+    /// assert!(Float::from(10).fmt_sci_valid(options));
+    /// ```
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        match self {
+            Float(Finite { .. }) => Rational::exact_from(self).fmt_sci_valid(options),
+            _ => true,
+        }
+    }
+
+    /// Converts a [`Float`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// See [`ToSciOptions`] for details on the available options. The digits are correctly
+    /// rounded from the exact value of the [`Float`], using the rounding mode specified in
+    /// `options`. NaN and infinities are written as `NaN`, `Infinity`, and `-Infinity`, and
+    /// negative zero is written with a minus sign.
+    ///
+    /// Unlike the [`Display`](core::fmt::Display) implementation, this function does not take the
+    /// [`Float`]'s precision into account; use
+    /// [`to_sci_string_and_ordering`](Float::to_sci_string_and_ordering) to also find out in
+    /// which direction the output was rounded.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// self.get_exponent().abs(), s)`, where `s` depends on the size type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is `self.get_prec()`.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded, or if `options` has `size_complete` specified but the [`Float`] has a
+    /// non-terminating expansion in the base.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeZero, NegativeInfinity};
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from(std::f64::consts::PI);
+    /// assert_eq!(x.to_sci().to_string(), "3.141592653589793");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(3);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.14");
+    ///
+    /// options.set_rounding_mode(Ceiling);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.15");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_scale(2);
+    /// options.set_include_trailing_zeros(true);
+    /// assert_eq!(Float::from(2.5).to_sci_with_options(options).to_string(), "2.50");
+    ///
+    /// options.set_base(16);
+    /// options.set_precision(4);
+    /// options.set_uppercase();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.244");
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(5);
+    /// options.set_e_uppercase();
+    /// options.set_force_exponent_plus_sign(true);
+    /// assert_eq!(
+    ///     Float::from(123456789.0).to_sci_with_options(options).to_string(),
+    ///     "1.2346E+8"
+    /// );
+    ///
+    /// options.set_neg_exp_threshold(-10);
+    /// assert_eq!(
+    ///     Float::from(1.0e-8).to_sci_with_options(options).to_string(),
+    ///     "0.00000001"
+    /// );
+    ///
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_sci().to_string(), "-0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.to_sci().to_string(), "-Infinity");
+    /// ```
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> core::fmt::Result {
+        match self {
+            float_nan!() => f.write_str("NaN"),
+            float_infinity!() => f.write_str("Infinity"),
+            float_negative_infinity!() => f.write_str("-Infinity"),
+            float_zero!() => Rational::ZERO.fmt_sci(f, options),
+            float_negative_zero!() => {
+                f.write_char('-')?;
+                Rational::ZERO.fmt_sci(f, options)
+            }
+            _ => Rational::exact_from(self).fmt_sci(f, options),
+        }
+    }
+}
@@ -6,16 +6,165 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
-use crate::alloc::string::ToString;
+use crate::alloc::string::{String, ToString};
+use crate::alloc::vec;
 use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
-use crate::{ComparableFloat, ComparableFloatRef, Float};
+use crate::{significand_bits, ComparableFloat, ComparableFloatRef, Float};
+use core::cmp::Ordering::*;
+use core::cmp::{max, min};
 use core::fmt::{Debug, Display, Formatter, LowerHex, Result, Write};
-use malachite_base::num::arithmetic::traits::{Abs, ModPowerOf2, ShrRound};
+use malachite_base::num::arithmetic::traits::{Abs, DivMod, ModPowerOf2, Parity, Pow, ShrRound};
+use malachite_base::num::basic::traits::One;
 use malachite_base::num::conversion::string::options::ToSciOptions;
 use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
 use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::natural::Natural;
 use malachite_q::Rational;
 
+// $\log_{10} 2$, times $2^{64}$, rounded down.
+const LOG_10_2_FIXED: i128 = 5553023288523357132;
+
+// Rounds a positive number $v$ to `precision` significant decimal digits, with ties broken toward
+// the even significand, where $v10^t = q + r/b$, $0 \leq r < b$, and $q$ has `digits` digits.
+// Returns the rounded significand, which has `precision` digits, and the decimal exponent of its
+// leading digit.
+fn round_scaled(
+    q: &Natural,
+    r: &Natural,
+    b: &Natural,
+    digits: u64,
+    t: i64,
+    precision: u64,
+) -> (Natural, i64) {
+    let mut log = i64::exact_from(digits) - 1 - t;
+    let shift = digits - precision;
+    let (mut n, o) = if shift == 0 {
+        (q.clone(), (r << 1u32).cmp(b))
+    } else {
+        let (n, remainder) = q.div_mod(Natural::from(10u32).pow(shift));
+        let half = Natural::from(5u32) * Natural::from(10u32).pow(shift - 1);
+        let o = remainder.cmp(&half);
+        (n, if o == Equal && *r != 0u32 { Greater } else { o })
+    };
+    if o == Greater || o == Equal && n.odd() {
+        n += Natural::ONE;
+        if n == Natural::from(10u32).pow(precision) {
+            n /= Natural::from(10u32);
+            log += 1;
+        }
+    }
+    (n, log)
+}
+
+// Finds the smallest number of significant digits for which rounding the absolute value of a
+// finite, nonzero `Float` to the nearest decimal gives a different result than rounding either of
+// its neighbors, and returns that rounding as a string of digits and the decimal exponent of the
+// leading digit.
+//
+// Rather than converting all three numbers to decimal for each candidate number of digits, this
+// function computes $|x|10^t$ exactly, as an integer and a fraction, for a number of digits $T$
+// that is a little larger than the answer. The neighbors differ from $|x|$ by powers of 2, so their
+// scaled values are found from $|x|$'s by adding or subtracting a much smaller correction. Rounding
+// the three scaled values to fewer digits only requires division by small powers of 10.
+fn shortest_digits(x: &Float) -> (String, i64) {
+    let Float(Finite {
+        exponent,
+        precision,
+        significand,
+        ..
+    }) = x
+    else {
+        unreachable!()
+    };
+    let exponent = i64::from(*exponent);
+    // $|x| = m2^k$, where $m$ is the significand
+    let k = exponent - i64::exact_from(significand_bits(significand));
+    // The neighbors of $|x|$, as given by `decrement` and `increment`, are $|x| \pm 2^u$
+    let ulp_exp = exponent - i64::exact_from(*precision);
+    // Since $2^{e-1} \leq |x| < 2^e$, $\lfloor \log_{10} |x| \rfloor$ is either this or one more
+    let log_guess = i64::exact_from((i128::from(exponent - 1) * LOG_10_2_FIXED) >> 64);
+    // The string can only differ from both neighbors' strings if two rounding boundaries lie
+    // between the neighbors, which are at most 2 ulps apart. Boundaries are at least a tenth of a
+    // unit in the last digit apart, so no precision below roughly `(prec - 1) * log_10(2) - 1.3`
+    // can work; starting just below that bound, rather than at 1, keeps the number of candidates
+    // small.
+    let start = max(
+        1,
+        u64::exact_from(u128::from(*precision - 1) * 30102 / 100000).saturating_sub(2),
+    );
+    let mut margin = 8;
+    loop {
+        let total_digits = start + margin;
+        // $|x|10^t$ has $T$ or $T + 1$ digits before the point
+        let t = i64::exact_from(total_digits) - 1 - log_guess;
+        let five_power = Natural::from(5u32).pow(t.unsigned_abs());
+        let (five_power, b) = if t >= 0 {
+            (five_power, Natural::ONE)
+        } else {
+            (Natural::ONE, five_power)
+        };
+        // Write $|x|10^t = a/b$ and the neighbors' differences from it as $\pm d/b$, with $b =
+        // 2^i5^j$ and both $a$ and $d$ integers.
+        let b_twos = max(-k - t, 0);
+        let shift = u64::exact_from(k + t + b_twos);
+        let b = b << u64::exact_from(b_twos);
+        let (q, r) = ((significand << shift) * &five_power).div_mod(&b);
+        let (q_diff, r_diff) = (five_power << (u64::exact_from(ulp_exp - k) + shift)).div_mod(&b);
+        let (q_higher, r_higher) = (&r + &r_diff).div_mod(&b);
+        let q_higher = q_higher + &q + &q_diff;
+        let (q_lower, r_lower) = if r >= r_diff {
+            (&q - q_diff, &r - r_diff)
+        } else {
+            (&q - q_diff - Natural::ONE, &r + &b - r_diff)
+        };
+        // Each scaled value has between $T - 1$ and $T + 2$ digits
+        let mut powers = vec![Natural::from(10u32).pow(total_digits - 2)];
+        for _ in 0..3 {
+            let power = powers.last().unwrap() * Natural::from(10u32);
+            powers.push(power);
+        }
+        let digit_count = |q: &Natural| {
+            total_digits - 2 + u64::exact_from(powers.iter().take_while(|&p| p <= q).count())
+        };
+        let digits = digit_count(&q);
+        let lower_digits = digit_count(&q_lower);
+        let higher_digits = digit_count(&q_higher);
+        for p in start..=min(digits, min(lower_digits, higher_digits)) {
+            let rounded = round_scaled(&q, &r, &b, digits, t, p);
+            if rounded != round_scaled(&q_lower, &r_lower, &b, lower_digits, t, p)
+                && rounded != round_scaled(&q_higher, &r_higher, &b, higher_digits, t, p)
+            {
+                return (rounded.0.to_string(), rounded.1);
+            }
+        }
+        margin <<= 1;
+    }
+}
+
+// Writes a positive decimal number, given its significant digits and the decimal exponent of its
+// leading digit, in the same way as [`Rational::to_sci`] with the default options, except that
+// ".0" is appended to the significand if it has no point.
+fn write_digits(f: &mut Formatter, digits: &str, log: i64) -> Result {
+    let precision = i64::exact_from(digits.len());
+    if log <= -6 || log >= precision {
+        let digits = digits.trim_end_matches('0');
+        let (first, rest) = digits.split_at(1);
+        let rest = if rest.is_empty() { "0" } else { rest };
+        write!(f, "{first}.{rest}e{log}")
+    } else if log < 0 {
+        f.write_str("0.")?;
+        for _ in 0..-log - 1 {
+            f.write_char('0')?;
+        }
+        f.write_str(digits.trim_end_matches('0'))
+    } else {
+        let (before, after) = digits.split_at(usize::exact_from(log + 1));
+        let after = after.trim_end_matches('0');
+        let after = if after.is_empty() { "0" } else { after };
+        write!(f, "{before}.{after}")
+    }
+}
+
 impl Display for Float {
     fn fmt(&self, f: &mut Formatter) -> Result {
         match self {
@@ -25,30 +174,11 @@ impl Display for Float {
             float_zero!() => write!(f, "0.0"),
             float_negative_zero!() => write!(f, "-0.0"),
             _ => {
-                let mut lower = self.clone();
-                let mut higher = self.clone();
-                lower.decrement();
-                higher.increment();
-                let self_q = Rational::exact_from(self);
-                let lower_q = Rational::exact_from(lower);
-                let higher_q = Rational::exact_from(higher);
-                let mut options = ToSciOptions::default();
-                for precision in 1.. {
-                    options.set_precision(precision);
-                    let s = self_q.to_sci_with_options(options).to_string();
-                    let s_lower = lower_q.to_sci_with_options(options).to_string();
-                    let s_higher = higher_q.to_sci_with_options(options).to_string();
-                    if s != s_lower && s != s_higher {
-                        return if s.contains('.') {
-                            write!(f, "{s}")
-                        } else if let Some(i) = s.find('e') {
-                            write!(f, "{}.0e{}", &s[..i], &s[i + 1..])
-                        } else {
-                            write!(f, "{s}.0")
-                        };
-                    }
+                if *self < 0u32 {
+                    f.write_char('-')?;
                 }
-                panic!();
+                let (digits, log) = shortest_digits(self);
+                write_digits(f, &digits, log)
             }
         }
     }
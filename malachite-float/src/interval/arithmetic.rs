@@ -0,0 +1,718 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use crate::Float;
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Checks, in debug builds, that an endpoint was rounded in the intended direction.
+fn check_rounding(o: Ordering, rm: RoundingMode) {
+    debug_assert!(match rm {
+        Floor => o != Greater,
+        Ceiling => o != Less,
+        _ => unreachable!(),
+    });
+}
+
+// Multiplies two endpoints. An infinite endpoint is never attained, so the product of zero and an
+// infinite endpoint is taken to be zero.
+fn mul_endpoints(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> Float {
+    if *x == 0u32 && y.is_infinite() || x.is_infinite() && *y == 0u32 {
+        Float::ZERO
+    } else {
+        let (product, o) = x.mul_prec_round_ref_ref(y, prec, rm);
+        check_rounding(o, rm);
+        product
+    }
+}
+
+// Divides two endpoints. A quotient of two infinite endpoints is NaN; it may be ignored, since the
+// quotients of the other endpoints already bound the result on that side.
+fn div_endpoints(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> Float {
+    let (quotient, o) = x.div_prec_round_ref_ref(y, prec, rm);
+    if !quotient.is_nan() {
+        check_rounding(o, rm);
+    }
+    quotient
+}
+
+fn min_endpoint<I: Iterator<Item = Float>>(xs: I) -> Float {
+    xs.filter(|x| !x.is_nan())
+        .reduce(|x, y| if y < x { y } else { x })
+        .unwrap()
+}
+
+fn max_endpoint<I: Iterator<Item = Float>>(xs: I) -> Float {
+    xs.filter(|x| !x.is_nan())
+        .reduce(|x, y| if y > x { y } else { x })
+        .unwrap()
+}
+
+impl Interval {
+    // The maximum precision of the endpoints.
+    fn max_prec(&self) -> u64 {
+        max(self.lo.significant_bits(), self.hi.significant_bits())
+    }
+
+    const fn corners<'a>(&'a self, other: &'a Interval) -> [(&'a Float, &'a Float); 4] {
+        [(&self.lo, &other.lo), (&self.lo, &other.hi), (&self.hi, &other.lo), (&self.hi, &other.hi)]
+    }
+
+    /// Adds two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x+y$ for every $x$ in `self` and $y$ in `other`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), other.lo().significant_bits(),
+    /// other.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(&Rational::from_unsigneds(1u8, 3), 20);
+    /// let y = Interval::from_rational_prec(&Rational::from_unsigneds(2u8, 3), 20);
+    /// let sum = x.add_prec(&y, 20);
+    /// assert_eq!(sum.to_string(), "[0.999999, 1.000002]");
+    /// assert!(sum.contains(&Rational::from(1)));
+    /// ```
+    pub fn add_prec(&self, other: &Interval, prec: u64) -> Interval {
+        let (lo, o) = self.lo.add_prec_round_ref_ref(&other.lo, prec, Floor);
+        check_rounding(o, Floor);
+        let (hi, o) = self.hi.add_prec_round_ref_ref(&other.hi, prec, Ceiling);
+        check_rounding(o, Ceiling);
+        Interval::from_sorted(lo, hi)
+    }
+
+    /// Subtracts one [`Interval`] from another, rounding the endpoints of the result outward to
+    /// the specified precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x-y$ for every $x$ in `self` and $y$ in `other`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), other.lo().significant_bits(),
+    /// other.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = Interval::from_rational_prec(&Rational::from_unsigneds(1u8, 3), 20);
+    /// let difference = x.sub_prec(&x, 20);
+    /// assert_eq!(difference.to_string(), "[-4.76837e-7, 4.76837e-7]");
+    /// assert!(difference.contains(&Rational::from(0)));
+    /// ```
+    pub fn sub_prec(&self, other: &Interval, prec: u64) -> Interval {
+        let (lo, o) = self.lo.sub_prec_round_ref_ref(&other.hi, prec, Floor);
+        check_rounding(o, Floor);
+        let (hi, o) = self.hi.sub_prec_round_ref_ref(&other.lo, prec, Ceiling);
+        check_rounding(o, Ceiling);
+        Interval::from_sorted(lo, hi)
+    }
+
+    /// Multiplies two [`Interval`]s, rounding the endpoints of the result outward to the specified
+    /// precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $xy$ for every $x$ in `self` and $y$ in `other`. Since infinite
+    /// endpoints are not attained, the product of $[0, 0]$ and any [`Interval`] is $[0, 0]$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), other.lo().significant_bits(),
+    /// other.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-2), Float::from(3)).unwrap();
+    /// let y = Interval::new(Float::from(-5), Float::from(1.5)).unwrap();
+    /// assert_eq!(x.mul_prec(&y, 10).to_string(), "[-15.0, 10.0]");
+    ///
+    /// let x = Interval::from_float(Float::ZERO).unwrap();
+    /// let y = Interval::new(Float::ONE, Float::INFINITY).unwrap();
+    /// assert_eq!(x.mul_prec(&y, 10).to_string(), "[0.0, 0.0]");
+    /// ```
+    pub fn mul_prec(&self, other: &Interval, prec: u64) -> Interval {
+        let corners = self.corners(other);
+        Interval::from_sorted(
+            min_endpoint(
+                corners
+                    .iter()
+                    .map(|(x, y)| mul_endpoints(x, y, prec, Floor)),
+            ),
+            max_endpoint(
+                corners
+                    .iter()
+                    .map(|(x, y)| mul_endpoints(x, y, prec, Ceiling)),
+            ),
+        )
+    }
+
+    /// Divides one [`Interval`] by another, rounding the endpoints of the result outward to the
+    /// specified precision. Both [`Interval`]s are taken by reference.
+    ///
+    /// The result contains $x/y$ for every $x$ in `self` and nonzero $y$ in `other`. If `other`
+    /// contains zero, the result is $(-\infty, \infty)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), other.lo().significant_bits(),
+    /// other.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::ONE).unwrap();
+    /// let y = Interval::new(Float::from(3), Float::from(4)).unwrap();
+    /// assert_eq!(x.div_prec(&y, 10).to_string(), "[0.25, 0.3335]");
+    ///
+    /// let y = Interval::new(Float::from(-1), Float::from(4)).unwrap();
+    /// assert_eq!(x.div_prec(&y, 10).to_string(), "[-Infinity, Infinity]");
+    /// ```
+    pub fn div_prec(&self, other: &Interval, prec: u64) -> Interval {
+        if other.lo <= 0u32 && other.hi >= 0u32 {
+            return Interval::entire();
+        }
+        let corners = self.corners(other);
+        Interval::from_sorted(
+            min_endpoint(
+                corners
+                    .iter()
+                    .map(|(x, y)| div_endpoints(x, y, prec, Floor)),
+            ),
+            max_endpoint(
+                corners
+                    .iter()
+                    .map(|(x, y)| div_endpoints(x, y, prec, Ceiling)),
+            ),
+        )
+    }
+
+    /// Computes the midpoint of an [`Interval`], rounding it to the specified precision and with
+    /// the specified rounding mode. An [`Ordering`] is also returned, indicating whether the
+    /// rounded midpoint is less than, equal to, or greater than the exact midpoint.
+    ///
+    /// If the [`Interval`] is unbounded on one side, the midpoint is the infinite endpoint; if it
+    /// is unbounded on both sides, the midpoint is zero. In these cases the returned [`Ordering`]
+    /// is `Equal`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the midpoint cannot be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One, Zero};
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Interval::new(Float::ONE, Float::from(1.25)).unwrap();
+    /// let (mid, o) = x.midpoint_prec_round(2, Floor);
+    /// assert_eq!(mid.to_string(), "1.0");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (mid, o) = x.midpoint_prec_round(10, Exact);
+    /// assert_eq!(mid.to_string(), "1.125");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::INFINITY).unwrap();
+    /// let (mid, o) = x.midpoint_prec_round(10, Floor);
+    /// assert_eq!(mid.to_string(), "Infinity");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn midpoint_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match (
+            self.lo == Float::NEGATIVE_INFINITY,
+            self.hi == Float::INFINITY,
+        ) {
+            (true, true) => (Float::ZERO, Equal),
+            (true, false) => (Float::NEGATIVE_INFINITY, Equal),
+            (false, true) => (Float::INFINITY, Equal),
+            (false, false) => {
+                let (sum, o) = self.lo.add_prec_round_ref_ref(&self.hi, prec, rm);
+                (sum.abs_negative_zero() >> 1u32, o)
+            }
+        }
+    }
+
+    /// Computes the midpoint of an [`Interval`], rounding it to the nearest value of the specified
+    /// precision. An [`Ordering`] is also returned, indicating whether the rounded midpoint is
+    /// less than, equal to, or greater than the exact midpoint.
+    ///
+    /// See [`Interval::midpoint_prec_round`] for the treatment of unbounded [`Interval`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Interval::new(Float::ONE, Float::from(4)).unwrap();
+    /// let (mid, o) = x.midpoint_prec(10);
+    /// assert_eq!(mid.to_string(), "2.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn midpoint_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.midpoint_prec_round(prec, Nearest)
+    }
+
+    /// Computes the radius of an [`Interval`], half of the distance between its endpoints,
+    /// rounding it to the specified precision and with the specified rounding mode. An
+    /// [`Ordering`] is also returned, indicating whether the rounded radius is less than, equal
+    /// to, or greater than the exact radius.
+    ///
+    /// Every number in the [`Interval`] lies within the exact radius of the exact midpoint. To get
+    /// a radius that is safe to use as an error bound, use `Ceiling`. If the [`Interval`] is
+    /// unbounded, the radius is $\infty$ and the returned [`Ordering`] is `Equal`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the radius cannot be represented
+    /// exactly with the specified precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Interval::from_rational_prec(&Rational::from_unsigneds(1u8, 3), 20);
+    /// let (radius, o) = x.radius_prec_round(10, Ceiling);
+    /// assert_eq!(radius.to_string(), "2.384e-7");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (radius, o) = x.radius_prec_round(1, Ceiling);
+    /// assert_eq!(radius.to_string(), "2.0e-7");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn radius_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if self.is_bounded() {
+            let (width, o) = self.hi.sub_prec_round_ref_ref(&self.lo, prec, rm);
+            (width.abs_negative_zero() >> 1u32, o)
+        } else {
+            (Float::INFINITY, Equal)
+        }
+    }
+
+    /// Computes the radius of an [`Interval`], half of the distance between its endpoints,
+    /// rounding it to the nearest value of the specified precision. An [`Ordering`] is also
+    /// returned, indicating whether the rounded radius is less than, equal to, or greater than the
+    /// exact radius.
+    ///
+    /// See [`Interval::radius_prec_round`] for the treatment of unbounded [`Interval`]s.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Interval::new(Float::ONE, Float::from(4)).unwrap();
+    /// let (radius, o) = x.radius_prec(10);
+    /// assert_eq!(radius.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn radius_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.radius_prec_round(prec, Nearest)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    /// Negates an [`Interval`], taking it by value.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (-Interval::new(Float::ONE, Float::INFINITY).unwrap()).to_string(),
+    ///     "[-Infinity, -1.0]"
+    /// );
+    /// ```
+    fn neg(self) -> Interval {
+        Interval::from_sorted(-self.hi, -self.lo)
+    }
+}
+
+impl<'a> Neg for &'a Interval {
+    type Output = Interval;
+
+    /// Negates an [`Interval`], taking it by reference.
+    ///
+    /// Negation is exact, so no rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (-&Interval::new(Float::ONE, Float::INFINITY).unwrap()).to_string(),
+    ///     "[-Infinity, -1.0]"
+    /// );
+    /// ```
+    fn neg(self) -> Interval {
+        Interval::from_sorted(-&self.hi, -&self.lo)
+    }
+}
+
+impl Add<Interval> for Interval {
+    type Output = Interval;
+
+    /// Adds two [`Interval`]s, taking both by value. The endpoints of the result are rounded
+    /// outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::add_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1.5)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((x + y).to_string(), "[2.5, 3.5]");
+    /// ```
+    #[inline]
+    fn add(self, other: Interval) -> Interval {
+        &self + &other
+    }
+}
+
+impl<'a, 'b> Add<&'a Interval> for &'b Interval {
+    type Output = Interval;
+
+    /// Adds two [`Interval`]s, taking both by reference. The endpoints of the result are rounded
+    /// outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::add_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1.5)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((&x + &y).to_string(), "[2.5, 3.5]");
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Interval) -> Interval {
+        self.add_prec(other, max(self.max_prec(), other.max_prec()))
+    }
+}
+
+impl Sub<Interval> for Interval {
+    type Output = Interval;
+
+    /// Subtracts one [`Interval`] from another, taking both by value. The endpoints of the result
+    /// are rounded outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::sub_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1.5)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((x - y).to_string(), "[-0.5, 0.5]");
+    /// ```
+    #[inline]
+    fn sub(self, other: Interval) -> Interval {
+        &self - &other
+    }
+}
+
+impl<'a, 'b> Sub<&'a Interval> for &'b Interval {
+    type Output = Interval;
+
+    /// Subtracts one [`Interval`] from another, taking both by reference. The endpoints of the
+    /// result are rounded outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::sub_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1.5)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((&x - &y).to_string(), "[-0.5, 0.5]");
+    /// ```
+    #[inline]
+    fn sub(self, other: &'a Interval) -> Interval {
+        self.sub_prec(other, max(self.max_prec(), other.max_prec()))
+    }
+}
+
+impl Mul<Interval> for Interval {
+    type Output = Interval;
+
+    /// Multiplies two [`Interval`]s, taking both by value. The endpoints of the result are rounded
+    /// outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::mul_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((x * y).to_string(), "[-3.0, 2.0]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Interval) -> Interval {
+        &self * &other
+    }
+}
+
+impl<'a, 'b> Mul<&'a Interval> for &'b Interval {
+    type Output = Interval;
+
+    /// Multiplies two [`Interval`]s, taking both by reference. The endpoints of the result are
+    /// rounded outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::mul_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(-1.5), Float::from(1)).unwrap();
+    /// let y = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// assert_eq!((&x * &y).to_string(), "[-3.0, 2.0]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Interval) -> Interval {
+        self.mul_prec(other, max(self.max_prec(), other.max_prec()))
+    }
+}
+
+impl Div<Interval> for Interval {
+    type Output = Interval;
+
+    /// Divides one [`Interval`] by another, taking both by value. The endpoints of the result are
+    /// rounded outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::div_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1)).unwrap();
+    /// let y = Interval::from_float(Float::from(3)).unwrap();
+    /// assert_eq!((x / y).to_string(), "[0.2, 0.4]");
+    /// ```
+    #[inline]
+    fn div(self, other: Interval) -> Interval {
+        &self / &other
+    }
+}
+
+impl<'a, 'b> Div<&'a Interval> for &'b Interval {
+    type Output = Interval;
+
+    /// Divides one [`Interval`] by another, taking both by reference. The endpoints of the result
+    /// are rounded outward to the maximum precision of the endpoints of the inputs.
+    ///
+    /// See [`Interval::div_prec`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::from_float(Float::from(1)).unwrap();
+    /// let y = Interval::from_float(Float::from(3)).unwrap();
+    /// assert_eq!((&x / &y).to_string(), "[0.2, 0.4]");
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Interval) -> Interval {
+        self.div_prec(other, max(self.max_prec(), other.max_prec()))
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::interval::Interval;
+use crate::Float;
+use malachite_q::Rational;
+
+impl Interval {
+    /// Determines whether every number in an [`Interval`] is less than every number in another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.hi().significant_bits(),
+    /// other.lo().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(2), Float::from(3)).unwrap();
+    /// let z = Interval::new(Float::from(2.5), Float::from(3)).unwrap();
+    /// assert!(!x.certainly_lt(&y));
+    /// assert!(x.certainly_lt(&z));
+    /// ```
+    pub fn certainly_lt(&self, other: &Interval) -> bool {
+        self.hi < other.lo
+    }
+
+    /// Determines whether every number in an [`Interval`] is less than or equal to every number
+    /// in another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.hi().significant_bits(),
+    /// other.lo().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(2), Float::from(3)).unwrap();
+    /// assert!(x.certainly_le(&y));
+    /// assert!(!y.certainly_le(&x));
+    /// ```
+    pub fn certainly_le(&self, other: &Interval) -> bool {
+        self.hi <= other.lo
+    }
+
+    /// Determines whether some number in an [`Interval`] is less than some number in another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// other.hi().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(2), Float::from(3)).unwrap();
+    /// assert!(x.possibly_lt(&y));
+    /// assert!(!y.possibly_lt(&x));
+    /// ```
+    pub fn possibly_lt(&self, other: &Interval) -> bool {
+        self.lo < other.hi
+    }
+
+    /// Determines whether some number in an [`Interval`] is less than or equal to some number in
+    /// another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// other.hi().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(2), Float::from(3)).unwrap();
+    /// let z = Interval::new(Float::from(2.5), Float::from(3)).unwrap();
+    /// assert!(y.possibly_le(&x));
+    /// assert!(!z.possibly_le(&x));
+    /// ```
+    pub fn possibly_le(&self, other: &Interval) -> bool {
+        self.lo <= other.hi
+    }
+
+    /// Determines whether an [`Interval`] contains a [`Rational`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// let third = Rational::from_unsigneds(1u8, 3);
+    /// let x = Interval::from_rational_prec(&third, 10);
+    /// assert!(x.contains(&third));
+    /// assert!(!x.contains(&Rational::from_unsigneds(1u8, 2)));
+    /// ```
+    pub fn contains(&self, x: &Rational) -> bool {
+        self.lo <= *x && self.hi >= *x
+    }
+
+    /// Determines whether an [`Interval`] contains a [`Float`].
+    ///
+    /// NaN and infinities are not contained in any [`Interval`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits(), x.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::INFINITY).unwrap();
+    /// assert!(x.contains_float(&Float::from(1.5)));
+    /// assert!(!x.contains_float(&Float::from(-1.5)));
+    /// assert!(!x.contains_float(&Float::INFINITY));
+    /// assert!(!x.contains_float(&Float::NAN));
+    /// ```
+    pub fn contains_float(&self, x: &Float) -> bool {
+        x.is_finite() && self.lo <= *x && self.hi >= *x
+    }
+
+    /// Determines whether every number in an [`Interval`] is contained in another.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(0), Float::from(3)).unwrap();
+    /// assert!(x.is_subset(&y));
+    /// assert!(!y.is_subset(&x));
+    /// assert!(x.is_subset(&x));
+    /// ```
+    pub fn is_subset(&self, other: &Interval) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Returns the intersection of two [`Interval`]s, or `None` if they are disjoint.
+    ///
+    /// No rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(3)).unwrap();
+    /// let y = Interval::new(Float::from(2), Float::from(4)).unwrap();
+    /// let z = Interval::new(Float::from(5), Float::from(6)).unwrap();
+    /// assert_eq!(x.intersection(&y).unwrap().to_string(), "[2.0, 3.0]");
+    /// assert!(x.intersection(&z).is_none());
+    /// ```
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let lo = if self.lo >= other.lo {
+            &self.lo
+        } else {
+            &other.lo
+        };
+        let hi = if self.hi <= other.hi {
+            &self.hi
+        } else {
+            &other.hi
+        };
+        if lo <= hi {
+            Some(Interval::from_sorted(lo.clone(), hi.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hull of two [`Interval`]s: the smallest [`Interval`] that contains both.
+    ///
+    /// No rounding takes place.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::from(1), Float::from(2)).unwrap();
+    /// let y = Interval::new(Float::from(5), Float::from(6)).unwrap();
+    /// assert_eq!(x.hull(&y).to_string(), "[1.0, 6.0]");
+    /// ```
+    pub fn hull(&self, other: &Interval) -> Interval {
+        let lo = if self.lo <= other.lo {
+            &self.lo
+        } else {
+            &other.lo
+        };
+        let hi = if self.hi >= other.hi {
+            &self.hi
+        } else {
+            &other.hi
+        };
+        Interval::from_sorted(lo.clone(), hi.clone())
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use core::fmt::{Debug, Display, Formatter, Result};
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_q::Rational;
+
+/// A closed interval of real numbers whose endpoints are [`Float`]s.
+///
+/// An [`Interval`] with endpoints $a$ and $b$ represents the set of all real numbers $x$ with $a
+/// \leq x \leq b$. An endpoint may be infinite, in which case the interval is unbounded on that
+/// side. The lower endpoint is never $\infty$, the upper endpoint is never $-\infty$, and neither
+/// is NaN, so an [`Interval`] is never empty. A zero endpoint is always stored as $0.0$, never as
+/// $-0.0$.
+///
+/// Arithmetic on [`Interval`]s rounds outward: the lower endpoint of a result is rounded down and
+/// the upper endpoint up, so the result contains every value that the exact operation takes when
+/// its arguments range over the input intervals. An [`Interval`] therefore carries a guaranteed
+/// bound on the rounding error accumulated over a computation.
+#[derive(Clone)]
+pub struct Interval {
+    pub(crate) lo: Float,
+    pub(crate) hi: Float,
+}
+
+impl Interval {
+    // The endpoints must already be known to be valid, except possibly for the sign of zero.
+    pub(crate) fn from_sorted(lo: Float, hi: Float) -> Interval {
+        debug_assert!(lo <= hi && lo != Float::INFINITY && hi != Float::NEGATIVE_INFINITY);
+        Interval {
+            lo: lo.abs_negative_zero(),
+            hi: hi.abs_negative_zero(),
+        }
+    }
+
+    /// Creates an [`Interval`] from its lower and upper endpoints.
+    ///
+    /// Returns `None` if either endpoint is NaN, if `lo` is greater than `hi`, if `lo` is
+    /// $\infty$, or if `hi` is $-\infty$. A $-0.0$ endpoint is replaced by $0.0$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(lo.significant_bits(),
+    /// hi.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::new(Float::ZERO, Float::ONE).unwrap().to_string(),
+    ///     "[0.0, 1.0]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::new(Float::ONE, Float::INFINITY)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "[1.0, Infinity]"
+    /// );
+    /// assert!(Interval::new(Float::ONE, Float::ZERO).is_none());
+    /// assert!(Interval::new(Float::NAN, Float::ONE).is_none());
+    /// assert!(Interval::new(Float::INFINITY, Float::INFINITY).is_none());
+    /// ```
+    pub fn new(lo: Float, hi: Float) -> Option<Interval> {
+        if lo <= hi && lo != Float::INFINITY && hi != Float::NEGATIVE_INFINITY {
+            Some(Interval::from_sorted(lo, hi))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an [`Interval`] containing a single finite [`Float`].
+    ///
+    /// Returns `None` if `x` is NaN or infinite.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NegativeZero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::from_float(Float::from(1.5)).unwrap().to_string(),
+    ///     "[1.5, 1.5]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_float(Float::NEGATIVE_ZERO)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "[0.0, 0.0]"
+    /// );
+    /// assert!(Interval::from_float(Float::INFINITY).is_none());
+    /// ```
+    pub fn from_float(x: Float) -> Option<Interval> {
+        if x.is_finite() {
+            Some(Interval::from_sorted(x.clone(), x))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the smallest [`Interval`] with endpoints of the specified precision that contains a
+    /// [`Rational`]. The [`Rational`] is taken by reference.
+    ///
+    /// If the [`Rational`] is exactly representable with the specified precision, both endpoints
+    /// are equal to it.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Interval::from_rational_prec(&Rational::from_unsigneds(1u8, 3), 10).to_string(),
+    ///     "[0.333, 0.3335]"
+    /// );
+    /// assert_eq!(
+    ///     Interval::from_rational_prec(&Rational::from_signeds(-3i8, 4), 10).to_string(),
+    ///     "[-0.75, -0.75]"
+    /// );
+    /// ```
+    pub fn from_rational_prec(x: &Rational, prec: u64) -> Interval {
+        Interval::from_sorted(
+            Float::from_rational_prec_round_ref(x, prec, Floor).0,
+            Float::from_rational_prec_round_ref(x, prec, Ceiling).0,
+        )
+    }
+
+    /// Returns the [`Interval`] containing every real number, $(-\infty, \infty)$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::interval::Interval;
+    ///
+    /// assert_eq!(Interval::entire().to_string(), "[-Infinity, Infinity]");
+    /// ```
+    pub const fn entire() -> Interval {
+        Interval {
+            lo: Float::NEGATIVE_INFINITY,
+            hi: Float::INFINITY,
+        }
+    }
+
+    /// Returns a reference to the lower endpoint of an [`Interval`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::ONE).unwrap();
+    /// assert_eq!(*x.lo(), 0);
+    /// ```
+    pub const fn lo(&self) -> &Float {
+        &self.lo
+    }
+
+    /// Returns a reference to the upper endpoint of an [`Interval`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Interval::new(Float::ZERO, Float::ONE).unwrap();
+    /// assert_eq!(*x.hi(), 1);
+    /// ```
+    pub const fn hi(&self) -> &Float {
+        &self.hi
+    }
+
+    /// Returns the lower and upper endpoints of an [`Interval`], taking the [`Interval`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// let (lo, hi) = Interval::new(Float::ZERO, Float::ONE)
+    ///     .unwrap()
+    ///     .into_endpoints();
+    /// assert_eq!(lo, 0);
+    /// assert_eq!(hi, 1);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_endpoints(self) -> (Float, Float) {
+        (self.lo, self.hi)
+    }
+
+    /// Determines whether an [`Interval`] contains exactly one number.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.lo().significant_bits(),
+    /// self.hi().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Interval::from_float(Float::ONE).unwrap().is_point());
+    /// assert!(!Interval::new(Float::ZERO, Float::ONE).unwrap().is_point());
+    /// ```
+    pub fn is_point(&self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Determines whether both endpoints of an [`Interval`] are finite.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert!(Interval::new(Float::ZERO, Float::ONE).unwrap().is_bounded());
+    /// assert!(!Interval::new(Float::ZERO, Float::INFINITY)
+    ///     .unwrap()
+    ///     .is_bounded());
+    /// ```
+    pub const fn is_bounded(&self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+}
+
+impl PartialEq for Interval {
+    /// Determines whether two [`Interval`]s have the same endpoints.
+    ///
+    /// The precisions of the endpoints are not compared.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum precision of the
+    /// endpoints.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::new(Float::ZERO, Float::ONE).unwrap(),
+    ///     Interval::new(Float::ZERO, Float::one_prec(100)).unwrap()
+    /// );
+    /// assert_ne!(
+    ///     Interval::new(Float::ZERO, Float::ONE).unwrap(),
+    ///     Interval::from_float(Float::ONE).unwrap()
+    /// );
+    /// ```
+    fn eq(&self, other: &Interval) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl Eq for Interval {}
+
+impl Display for Interval {
+    /// Converts an [`Interval`] to a [`String`](alloc::string::String), writing its endpoints in
+    /// brackets.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{NegativeInfinity, One};
+    /// use malachite_float::interval::Interval;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Interval::new(Float::NEGATIVE_INFINITY, Float::ONE)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "[-Infinity, 1.0]"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "[{}, {}]", self.lo, self.hi)
+    }
+}
+
+impl Debug for Interval {
+    /// Converts an [`Interval`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// Outward-rounded arithmetic on [`Interval`]s.
+pub mod arithmetic;
+/// Comparison predicates, containment, intersection, and hull of [`Interval`]s.
+pub mod comparison;
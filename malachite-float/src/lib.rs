@@ -87,12 +87,16 @@ extern crate alloc;
 
 #[macro_use]
 extern crate malachite_base;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
 
 #[cfg(feature = "test_build")]
 use crate::InnerFloat::Finite;
+use alloc::string::String;
 use core::ops::Deref;
 #[cfg(feature = "test_build")]
 use malachite_base::num::arithmetic::traits::DivisibleByPowerOf2;
@@ -155,6 +159,8 @@ use malachite_nz::platform::Limb;
 /// `Float`s whose precision is 64 bits or less can be represented without any memory allocation.
 /// (Unless Malachite is compiled with `32_bit_limbs`, in which case the limit is 32).
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeFloat", into = "SerdeFloat"))]
 pub struct Float(pub(crate) InnerFloat);
 
 // We want to limit the visibility of the `NaN`, `Zero`, `Infinity`, and `Finite` constructors to
@@ -176,6 +182,10 @@ pub(crate) enum InnerFloat {
     },
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub(crate) struct SerdeFloat(String);
+
 #[inline]
 pub(crate) fn significand_bits(significand: &Natural) -> u64 {
     significand.limb_count() << Limb::LOG_WIDTH
@@ -290,14 +300,21 @@ pub mod arithmetic;
 pub mod basic;
 /// Traits for comparing [`Float`]s for equality or order.
 pub mod comparison;
+/// Complex numbers with [`Float`] parts and correctly rounded arithmetic.
+pub mod complex;
 /// Functions that produce [`Float`] approximations of mathematical constants, using a given
 /// precision and rounding mode.
 pub mod constants;
+/// Exponent ranges, subnormals, and exception flags, for emulating fixed-format floating-point
+/// arithmetic with [`Float`]s.
+pub mod context;
 /// Traits for converting to and from [`Float`]s, including converting [`Float`]s to and from
 /// strings.
 pub mod conversion;
 /// Iterators that generate [`Float`]s without repetition.
 pub mod exhaustive;
+/// Intervals with [`Float`] endpoints and outward-rounded arithmetic, for verified computation.
+pub mod interval;
 #[cfg(feature = "random")]
 /// Iterators that generate [`Float`]s randomly.
 pub mod random;
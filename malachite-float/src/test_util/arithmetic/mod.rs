@@ -7,8 +7,19 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 pub mod add;
+pub mod asin;
+pub mod atan;
 pub mod div;
+pub mod exp;
+pub mod ln;
 pub mod mul;
+pub mod mul_add;
+pub mod pow;
+pub mod product;
 pub mod reciprocal;
+pub mod sin_cos;
+pub mod sqrt;
 pub mod square;
+pub mod sum;
 pub mod sub;
+pub mod tan;
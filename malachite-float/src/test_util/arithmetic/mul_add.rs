@@ -0,0 +1,61 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::arithmetic::add::add_prec_round_naive;
+use crate::test_util::common::rug_float_significant_bits;
+use crate::Float;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use rug::float::Round;
+use rug::ops::AssignRound;
+use std::cmp::max;
+use std::cmp::Ordering;
+
+pub fn rug_mul_add_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut result = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = result.assign_round(x.mul_add_ref(y, z), rm);
+    (result, o)
+}
+
+pub fn rug_mul_add_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_mul_add_prec_round(
+        x,
+        y,
+        z,
+        max(
+            max(rug_float_significant_bits(x), rug_float_significant_bits(y)),
+            rug_float_significant_bits(z),
+        ),
+        rm,
+    )
+}
+
+// The product is computed exactly, so only the addition rounds.
+pub fn mul_add_prec_round_naive(
+    x: Float,
+    y: Float,
+    z: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let product_prec = x.significant_bits() + y.significant_bits();
+    let product = x.mul_prec_round(y, product_prec, Exact).0;
+    add_prec_round_naive(product, z, prec, rm)
+}
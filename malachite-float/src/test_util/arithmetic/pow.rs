@@ -0,0 +1,64 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::common::rug_float_significant_bits;
+use core::cmp::{max, Ordering};
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use rug::ops::{AssignRound, Pow};
+
+pub fn rug_pow_integer_prec_round(
+    x: &rug::Float,
+    exp: &rug::Integer,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut pow = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = pow.assign_round(x.pow(exp), rm);
+    (pow, o)
+}
+
+pub fn rug_pow_integer_prec(
+    x: &rug::Float,
+    exp: &rug::Integer,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_pow_integer_prec_round(x, exp, prec, Round::Nearest)
+}
+
+pub fn rug_pow_integer_round(
+    x: &rug::Float,
+    exp: &rug::Integer,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_pow_integer_prec_round(x, exp, rug_float_significant_bits(x), rm)
+}
+
+pub fn rug_pow_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut pow = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = pow.assign_round(x.pow(y), rm);
+    (pow, o)
+}
+
+pub fn rug_pow_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_pow_prec_round(x, y, prec, Round::Nearest)
+}
+
+pub fn rug_pow_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_pow_prec_round(
+        x,
+        y,
+        max(rug_float_significant_bits(x), rug_float_significant_bits(y)),
+        rm,
+    )
+}
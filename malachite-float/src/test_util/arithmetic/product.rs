@@ -0,0 +1,43 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+
+pub fn product_prec_round_naive(xs: &[Float], prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if xs.iter().any(Float::is_nan) {
+        return (Float::NAN, Equal);
+    }
+    let has_infinity = xs.iter().any(Float::is_infinite);
+    let has_zero = xs.iter().any(|x| *x == 0u32);
+    let negative = xs.iter().filter(|x| x.is_sign_negative()).count().odd();
+    if has_infinity && has_zero {
+        (Float::NAN, Equal)
+    } else if has_infinity {
+        if negative {
+            (Float::NEGATIVE_INFINITY, Equal)
+        } else {
+            (Float::INFINITY, Equal)
+        }
+    } else if has_zero {
+        if negative {
+            (Float::NEGATIVE_ZERO, Equal)
+        } else {
+            (Float::ZERO, Equal)
+        }
+    } else {
+        let product: Rational = xs.iter().map(Rational::exact_from).product();
+        Float::from_rational_prec_round(product, prec, rm)
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+use std::cmp::Ordering::{self, *};
+
+pub fn rug_sum_prec_round(xs: &[rug::Float], prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut sum = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = sum.assign_round(rug::Float::sum(xs.iter()), rm);
+    (sum, o)
+}
+
+pub fn rug_dot_prec_round(
+    xs: &[rug::Float],
+    ys: &[rug::Float],
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut dot = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = dot.assign_round(rug::Float::dot(xs.iter().zip(ys.iter())), rm);
+    (dot, o)
+}
+
+pub fn sum_prec_round_naive(xs: &[Float], prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let has_infinity = xs.contains(&Float::INFINITY);
+    let has_negative_infinity = xs.contains(&Float::NEGATIVE_INFINITY);
+    if xs.iter().any(Float::is_nan) || has_infinity && has_negative_infinity {
+        return (Float::NAN, Equal);
+    } else if has_infinity {
+        return (Float::INFINITY, Equal);
+    } else if has_negative_infinity {
+        return (Float::NEGATIVE_INFINITY, Equal);
+    }
+    let sum: Rational = xs.iter().map(Rational::exact_from).sum();
+    if sum == 0u32 {
+        let zero = if xs.iter().all(|x| *x == 0u32 && x.is_sign_positive()) {
+            Float::ZERO
+        } else if xs.iter().all(|x| *x == 0u32 && x.is_sign_negative()) || rm == Floor {
+            Float::NEGATIVE_ZERO
+        } else {
+            Float::ZERO
+        };
+        (zero, Equal)
+    } else {
+        Float::from_rational_prec_round(sum, prec, rm)
+    }
+}
+
+// The products are computed exactly, so only the sum rounds.
+pub fn dot_prec_round_naive(
+    xs: Vec<Float>,
+    ys: Vec<Float>,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_eq!(xs.len(), ys.len());
+    let products: Vec<Float> = xs
+        .into_iter()
+        .zip(ys)
+        .map(|(x, y)| {
+            let product_prec = x.significant_bits() + y.significant_bits();
+            x.mul_prec_round(y, product_prec, Exact).0
+        })
+        .collect();
+    sum_prec_round_naive(&products, prec, rm)
+}
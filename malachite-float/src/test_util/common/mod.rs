@@ -43,7 +43,7 @@ pub const fn rug_round_try_from_rounding_mode(rm: RoundingMode) -> Result<Round,
         Down => Ok(Round::Zero),
         Up => Ok(Round::AwayZero),
         Nearest => Ok(Round::Nearest),
-        Exact => Err(()),
+        Exact | NearestTiesAway | ToOdd => Err(()),
     }
 }
 
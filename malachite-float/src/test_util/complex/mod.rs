@@ -0,0 +1,159 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::complex::ComplexFloat;
+use crate::test_util::common::to_hex_string;
+use crate::Float;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::Exact;
+use malachite_q::Rational;
+use rug::float::Round;
+use rug::ops::AssignRound;
+
+pub fn to_hex_strings(x: &ComplexFloat) -> (String, String) {
+    (to_hex_string(x.re()), to_hex_string(x.im()))
+}
+
+pub fn rug_complex_from_complex_float(x: &ComplexFloat) -> rug::Complex {
+    rug::Complex::from((
+        rug::Float::exact_from(x.re()),
+        rug::Float::exact_from(x.im()),
+    ))
+}
+
+pub fn complex_float_from_rug_complex(x: &rug::Complex) -> ComplexFloat {
+    ComplexFloat::new(Float::from(x.real()), Float::from(x.imag()))
+}
+
+fn rug_complex_with_prec(prec_re: u64, prec_im: u64) -> rug::Complex {
+    rug::Complex::new((u32::exact_from(prec_re), u32::exact_from(prec_im)))
+}
+
+pub fn rug_complex_add_prec_round(
+    x: &rug::Complex,
+    y: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut sum = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = sum.assign_round(x + y, (rm_re, rm_im));
+    (sum, o_re, o_im)
+}
+
+pub fn rug_complex_sub_prec_round(
+    x: &rug::Complex,
+    y: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut difference = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = difference.assign_round(x - y, (rm_re, rm_im));
+    (difference, o_re, o_im)
+}
+
+pub fn rug_complex_mul_prec_round(
+    x: &rug::Complex,
+    y: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut product = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = product.assign_round(x * y, (rm_re, rm_im));
+    (product, o_re, o_im)
+}
+
+pub fn rug_complex_div_prec_round(
+    x: &rug::Complex,
+    y: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut quotient = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = quotient.assign_round(x / y, (rm_re, rm_im));
+    (quotient, o_re, o_im)
+}
+
+pub fn rug_complex_sqrt_prec_round(
+    x: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut sqrt = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = sqrt.assign_round(x.sqrt_ref(), (rm_re, rm_im));
+    (sqrt, o_re, o_im)
+}
+
+pub fn rug_complex_exp_prec_round(
+    x: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut exp = rug_complex_with_prec(prec_re, prec_im);
+    let (o_re, o_im) = exp.assign_round(x.exp_ref(), (rm_re, rm_im));
+    (exp, o_re, o_im)
+}
+
+pub fn rug_complex_ln_prec_round(
+    x: &rug::Complex,
+    prec_re: u64,
+    prec_im: u64,
+    rm_re: Round,
+    rm_im: Round,
+) -> (rug::Complex, Ordering, Ordering) {
+    let mut ln = rug_complex_with_prec(prec_re, prec_im);
+    let (mut o_re, o_im) = ln.assign_round(x.ln_ref(), (rm_re, rm_im));
+    let (a, b) = (x.real(), x.imag());
+    if a.is_normal() && b.is_normal() {
+        // MPC sometimes rounds the real part incorrectly when the absolute value is close to 1, so
+        // it's computed from the exact value of a^2 + b^2 - 1 instead.
+        let s = Rational::exact_from(&Float::from(a)).square()
+            + Rational::exact_from(&Float::from(b)).square()
+            - Rational::ONE;
+        let prec = s.numerator_ref().significant_bits();
+        let s = rug::Float::exact_from(&Float::from_rational_prec_round(s, prec, Exact).0);
+        o_re = ln.mut_real().assign_round(s.ln_1p_ref(), rm_re);
+        *ln.mut_real() >>= 1u32;
+    }
+    (ln, o_re, o_im)
+}
+
+pub fn rug_complex_abs_prec_round(
+    x: &rug::Complex,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut abs = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = abs.assign_round(x.abs_ref(), rm);
+    (abs, o)
+}
+
+pub fn rug_complex_arg_prec_round(
+    x: &rug::Complex,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let mut arg = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = arg.assign_round(x.arg_ref(), rm);
+    (arg, o)
+}
@@ -0,0 +1,18 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::{Constant, Round};
+use rug::ops::AssignRound;
+
+pub fn rug_catalan_prec_round(prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut g = rug::Float::with_val(u32::exact_from(prec), 0);
+    let o = g.assign_round(Constant::Catalan, rm);
+    (g, o)
+}
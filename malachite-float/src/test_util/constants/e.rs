@@ -0,0 +1,17 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+
+pub fn rug_e_prec_round(prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut e = rug::Float::with_val(u32::exact_from(prec), 1);
+    let o = e.exp_round(rm);
+    (e, o)
+}
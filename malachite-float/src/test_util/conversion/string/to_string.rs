@@ -0,0 +1,43 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Float;
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_q::Rational;
+
+// Like `Float::to_string`, but tries every precision starting from 1.
+pub fn to_string_naive(x: &Float) -> String {
+    if !x.is_finite() || *x == 0u32 {
+        return x.to_string();
+    }
+    let mut lower = x.clone();
+    let mut higher = x.clone();
+    lower.decrement();
+    higher.increment();
+    let x_q = Rational::exact_from(x);
+    let lower_q = Rational::exact_from(lower);
+    let higher_q = Rational::exact_from(higher);
+    let mut options = ToSciOptions::default();
+    for precision in 1.. {
+        options.set_precision(precision);
+        let s = x_q.to_sci_with_options(options).to_string();
+        let s_lower = lower_q.to_sci_with_options(options).to_string();
+        let s_higher = higher_q.to_sci_with_options(options).to_string();
+        if s != s_lower && s != s_higher {
+            return if s.contains('.') {
+                s
+            } else if let Some(i) = s.find('e') {
+                format!("{}.0e{}", &s[..i], &s[i + 1..])
+            } else {
+                format!("{s}.0")
+            };
+        }
+    }
+    unreachable!()
+}
@@ -6,6 +6,7 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::interval::Interval;
 use crate::test_util::common::rug_round_exact_from_rounding_mode;
 use crate::Float;
 use malachite_base::num::basic::floats::PrimitiveFloat;
@@ -18,6 +19,15 @@ use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
 use malachite_q::Rational;
 
+// Returns the `Interval` whose endpoints are `x` and `y`, in either order, if there is one.
+pub fn interval_from_float_pair(x: Float, y: Float) -> Option<Interval> {
+    if x <= y {
+        Interval::new(x, y)
+    } else {
+        Interval::new(y, x)
+    }
+}
+
 pub fn float_rm(xs: It<Float>) -> It<(rug::Float, Float)> {
     Box::new(xs.map(|x| (rug::Float::exact_from(&x), x)))
 }
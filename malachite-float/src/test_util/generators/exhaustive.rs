@@ -6,17 +6,20 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::complex::ComplexFloat;
 use crate::exhaustive::{
     exhaustive_finite_floats, exhaustive_floats, exhaustive_non_negative_finite_floats,
     exhaustive_nonzero_finite_floats, exhaustive_positive_finite_floats,
     exhaustive_positive_floats_with_precision, exhaustive_positive_floats_with_sci_exponent,
 };
+use crate::interval::Interval;
 use crate::test_util::extra_variadic::{
     exhaustive_triples_from_single, exhaustive_triples_xxy, exhaustive_triples_xxy_custom_output,
 };
+use crate::test_util::generators::common::interval_from_float_pair;
 use crate::{significand_bits, Float};
 use malachite_base::iterators::bit_distributor::BitDistributorOutputType;
-use malachite_base::num::arithmetic::traits::{IsPowerOf2, Reciprocal, Square};
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, IsPowerOf2, Reciprocal, Square};
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
@@ -40,6 +43,7 @@ use malachite_base::tuples::exhaustive::{
     exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples,
     exhaustive_triples_custom_output, exhaustive_triples_xyy, lex_pairs,
 };
+use malachite_base::vecs::exhaustive::exhaustive_vecs;
 use malachite_nz::integer::exhaustive::exhaustive_integers;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::exhaustive::exhaustive_naturals;
@@ -454,6 +458,23 @@ pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4(
     )
 }
 
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        reshape_3_1_to_4(Box::new(lex_pairs(
+            exhaustive_triples_xxy_custom_output(
+                exhaustive_floats(),
+                exhaustive_positive_primitive_ints::<u64>(),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::normal(1),
+                BitDistributorOutputType::tiny(),
+            ),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|&(_, _, _, rm)| rm != Exact),
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rational)> {
@@ -828,6 +849,17 @@ pub fn exhaustive_float_float_rounding_mode_triple_gen_var_28() -> It<(Float, Fl
     )
 }
 
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_29() -> It<(Float, Float, RoundingMode)>
+{
+    Box::new(
+        reshape_2_1_to_3(Box::new(lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )))
+        .filter(|&(_, _, rm)| rm != Exact),
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn exhaustive_float_integer_pair_gen() -> It<(Float, Integer)> {
@@ -943,6 +975,13 @@ pub fn exhaustive_float_unsigned_pair_gen_var_2<T: PrimitiveUnsigned>() -> It<(F
     ))
 }
 
+pub fn exhaustive_float_unsigned_pair_gen_var_3() -> It<(Float, u64)> {
+    Box::new(
+        exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints())
+            .filter(|(x, _)| exp_valid(x)),
+    )
+}
+
 // -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn exhaustive_float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> It<(Float, T, T)> {
@@ -1029,6 +1068,54 @@ pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_3() -> It<(Float,
     ))
 }
 
+pub fn sqrt_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    if rm != Exact || !x.is_finite() || *x <= 0u32 {
+        return true;
+    }
+    if let Some(sqrt) = Rational::exact_from(x).checked_sqrt() {
+        if let Some(min_prec) = Float::exact_from(sqrt).get_min_prec() {
+            prec >= min_prec
+        } else {
+            true
+        }
+    } else {
+        false
+    }
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_4() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_5() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&(_, rm)| rm != Exact),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_6() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| exp_round_valid(x, rm)),
+    ))
+}
+
 // -- (Float, Rational) --
 
 pub fn exhaustive_float_rational_pair_gen() -> It<(Float, Rational)> {
@@ -1449,8 +1536,10 @@ pub fn exhaustive_float_rational_rounding_mode_triple_gen_var_6(
 
 pub(crate) fn natural_rounding_from_float_valid(f: &Float, rm: RoundingMode) -> bool {
     match rm {
-        Down | Ceiling | Nearest => f.is_finite() || *f == Float::NEGATIVE_INFINITY,
-        Up | Floor => f.is_finite() && (f.is_sign_positive() || f.is_negative_zero()),
+        Down | Ceiling | Nearest | NearestTiesAway => {
+            f.is_finite() || *f == Float::NEGATIVE_INFINITY
+        }
+        Up | Floor | ToOdd => f.is_finite() && (f.is_sign_positive() || f.is_negative_zero()),
         Exact => Natural::convertible_from(f),
     }
 }
@@ -1498,9 +1587,9 @@ where
     for<'a> T: ConvertibleFrom<&'a Float>,
 {
     match rm {
-        Floor => f.is_sign_positive() || f.is_negative_zero(),
+        Floor | ToOdd => f.is_sign_positive() || f.is_negative_zero(),
         Ceiling => *f <= T::MAX,
-        Down | Nearest => !f.is_nan(),
+        Down | Nearest | NearestTiesAway => !f.is_nan(),
         Up => (f.is_sign_positive() || f.is_negative_zero()) && *f <= T::MAX,
         Exact => T::convertible_from(f),
     }
@@ -1529,9 +1618,9 @@ where
     for<'a> T: ConvertibleFrom<&'a Float>,
 {
     match rm {
-        Floor => *f >= T::MIN,
+        Floor | ToOdd => *f >= T::MIN,
         Ceiling => *f <= T::MAX,
-        Down | Nearest => !f.is_nan(),
+        Down | Nearest | NearestTiesAway => !f.is_nan(),
         Up => *f >= T::MIN && *f <= T::MAX,
         Exact => T::convertible_from(f),
     }
@@ -1710,6 +1799,44 @@ pub fn exhaustive_float_rounding_mode_pair_gen_var_17() -> It<(Float, RoundingMo
     )
 }
 
+pub(crate) fn sqrt_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    if let Some(prec) = x.get_prec() {
+        sqrt_prec_round_valid(x, prec, rm)
+    } else {
+        true
+    }
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_18() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| sqrt_round_valid(f, *rm)),
+    )
+}
+
+// Whether the exponent of `x` is small enough that the result of `Float::exp` doesn't overflow.
+pub(crate) fn exp_valid(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 20)
+}
+
+// Whether `Float::exp_round` can be called with `x` and `rm` without panicking, excluding `Exact`.
+pub(crate) fn exp_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != Exact && exp_valid(x)
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_19() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes()).filter(|&(_, rm)| rm != Exact),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_20() -> It<(Float, RoundingMode)> {
+    Box::new(
+        lex_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(f, rm)| exp_round_valid(f, *rm)),
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 through 2 are in malachite-nz.
@@ -1905,3 +2032,38 @@ pub fn exhaustive_rational_rounding_mode_pair_gen_var_6() -> It<(Rational, Round
         }),
     )
 }
+
+// -- Vec<Float> --
+
+pub fn exhaustive_float_vec_gen() -> It<Vec<Float>> {
+    Box::new(exhaustive_vecs(exhaustive_floats()))
+}
+
+// -- Interval --
+
+pub fn exhaustive_interval_gen() -> It<Interval> {
+    Box::new(
+        exhaustive_pairs_from_single(exhaustive_floats())
+            .filter_map(|(x, y)| interval_from_float_pair(x, y)),
+    )
+}
+
+// -- (Interval, Interval) --
+
+pub fn exhaustive_interval_pair_gen() -> It<(Interval, Interval)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_interval_gen()))
+}
+
+// -- ComplexFloat --
+
+pub fn exhaustive_complex_float_gen() -> It<ComplexFloat> {
+    Box::new(
+        exhaustive_pairs_from_single(exhaustive_floats()).map(|(re, im)| ComplexFloat::new(re, im)),
+    )
+}
+
+// -- (ComplexFloat, ComplexFloat) --
+
+pub fn exhaustive_complex_float_pair_gen() -> It<(ComplexFloat, ComplexFloat)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_complex_float_gen()))
+}
@@ -6,6 +6,8 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::complex::ComplexFloat;
+use crate::interval::Interval;
 use crate::test_util::generators::common::{
     float_float_anything_rounding_mode_quadruple_rm, float_float_anything_triple_rm,
     float_float_rounding_mode_triple_rm, float_integer_pair_rm, float_natural_pair_rm,
@@ -477,6 +479,40 @@ pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_4_rm() -> Generator<
     )
 }
 
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::atan2_prec_round`,
+// excluding `Exact`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_5_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5(),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(config),
+            )
+        },
+        &|config| {
+            float_float_anything_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)> {
@@ -858,6 +894,39 @@ pub fn float_float_rounding_mode_triple_gen_var_28() -> Generator<(Float, Float,
     )
 }
 
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::atan2_round`, excluding
+// `Exact`.
+pub fn float_float_rounding_mode_triple_gen_var_29() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_29,
+        &random_float_float_rounding_mode_triple_gen_var_29,
+        &special_random_float_float_rounding_mode_triple_gen_var_29,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_29_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_29(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_29(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_29(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn float_integer_pair_gen() -> Generator<(Float, Integer)> {
@@ -1073,6 +1142,24 @@ pub fn float_unsigned_pair_gen_var_2_rm<T: PrimitiveUnsigned>(
     )
 }
 
+// All `(Float, u64)` where the `u64` is positive and the `Float`'s exponent is small enough for
+// `Float::exp_prec` not to overflow.
+pub fn float_unsigned_pair_gen_var_3() -> Generator<(Float, u64)> {
+    Generator::new(
+        &exhaustive_float_unsigned_pair_gen_var_3,
+        &random_float_unsigned_pair_gen_var_3,
+        &special_random_float_unsigned_pair_gen_var_3,
+    )
+}
+
+pub fn float_unsigned_pair_gen_var_3_rm() -> Generator<((rug::Float, u64), (Float, u64))> {
+    Generator::new(
+        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen_var_3()),
+        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen_var_3(config)),
+        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen_var_3(config)),
+    )
+}
+
 // -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(Float, T, T)> {
@@ -1181,6 +1268,104 @@ pub fn float_unsigned_rounding_mode_triple_gen_var_3_rm() -> Generator<(
     )
 }
 
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_4() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_4,
+        &random_float_unsigned_rounding_mode_triple_gen_var_4,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_4,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_4_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_4(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_4(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_4(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.ln_prec_round`, excluding
+// `Exact`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_5() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_5,
+        &random_float_unsigned_rounding_mode_triple_gen_var_5,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_5,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_5_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_5(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_5(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_5(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.exp_prec_round`, excluding
+// `Exact`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_6() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_6,
+        &random_float_unsigned_rounding_mode_triple_gen_var_6,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_6,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_6_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_6(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_6(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_6(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Rational) --
 
 pub fn float_rational_pair_gen() -> Generator<(Float, Rational)> {
@@ -1732,6 +1917,66 @@ pub fn float_rounding_mode_pair_gen_var_17() -> Generator<(Float, RoundingMode)>
     )
 }
 
+// All `(Float, RoundingMode)` that are valid inputs to `sqrt_round`.
+pub fn float_rounding_mode_pair_gen_var_18() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_18,
+        &random_float_rounding_mode_pair_gen_var_18,
+        &special_random_float_rounding_mode_pair_gen_var_18,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_18_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_18()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_18(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_18(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `ln_round`, excluding `Exact`.
+pub fn float_rounding_mode_pair_gen_var_19() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_19,
+        &random_float_rounding_mode_pair_gen_var_19,
+        &special_random_float_rounding_mode_pair_gen_var_19,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_19_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_19()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_19(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_19(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `exp_round`, excluding `Exact`.
+pub fn float_rounding_mode_pair_gen_var_20() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_20,
+        &random_float_rounding_mode_pair_gen_var_20,
+        &special_random_float_rounding_mode_pair_gen_var_20,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_20_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_20()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_20(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_20(config))
+        },
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 through 2 are in malachite-nz.
@@ -1900,6 +2145,56 @@ pub fn rational_rounding_mode_pair_gen_var_6() -> Generator<(Rational, RoundingM
     )
 }
 
+// -- Vec<Float> --
+
+pub fn float_vec_gen() -> Generator<Vec<Float>> {
+    Generator::new(
+        &exhaustive_float_vec_gen,
+        &random_float_vec_gen,
+        &special_random_float_vec_gen,
+    )
+}
+
+// -- Interval --
+
+pub fn interval_gen() -> Generator<Interval> {
+    Generator::new(
+        &exhaustive_interval_gen,
+        &random_interval_gen,
+        &special_random_interval_gen,
+    )
+}
+
+// -- (Interval, Interval) --
+
+pub fn interval_pair_gen() -> Generator<(Interval, Interval)> {
+    Generator::new(
+        &exhaustive_interval_pair_gen,
+        &random_interval_pair_gen,
+        &special_random_interval_pair_gen,
+    )
+}
+
+// -- ComplexFloat --
+
+pub fn complex_float_gen() -> Generator<ComplexFloat> {
+    Generator::new(
+        &exhaustive_complex_float_gen,
+        &random_complex_float_gen,
+        &special_random_complex_float_gen,
+    )
+}
+
+// -- (ComplexFloat, ComplexFloat) --
+
+pub fn complex_float_pair_gen() -> Generator<(ComplexFloat, ComplexFloat)> {
+    Generator::new(
+        &exhaustive_complex_float_pair_gen,
+        &random_complex_float_pair_gen,
+        &special_random_complex_float_pair_gen,
+    )
+}
+
 pub mod common;
 pub mod exhaustive;
 pub mod random;
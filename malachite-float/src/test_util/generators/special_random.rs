@@ -6,6 +6,8 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+use crate::complex::ComplexFloat;
+use crate::interval::Interval;
 use crate::random::{
     striped_random_finite_floats, striped_random_floats, striped_random_non_negative_finite_floats,
     striped_random_nonzero_finite_floats, striped_random_positive_finite_floats,
@@ -15,18 +17,20 @@ use crate::test_util::extra_variadic::{
     random_quadruples, random_quadruples_xxyz, random_triples, random_triples_from_single,
     random_triples_xxy, random_triples_xyy,
 };
+use crate::test_util::generators::common::interval_from_float_pair;
 use crate::test_util::generators::{
     add_prec_round_valid, add_rational_prec_round_valid, add_rational_round_valid, add_round_valid,
     div_prec_round_valid, div_rational_prec_round_valid, div_rational_round_valid, div_round_valid,
-    mul_prec_round_valid, mul_rational_prec_round_valid, mul_rational_round_valid, mul_round_valid,
-    rational_div_float_prec_round_valid, rational_div_float_round_valid, reciprocal_round_valid,
+    exp_round_valid, exp_valid, mul_prec_round_valid, mul_rational_prec_round_valid,
+    mul_rational_round_valid, mul_round_valid, rational_div_float_prec_round_valid,
+    rational_div_float_round_valid, reciprocal_round_valid, sqrt_round_valid,
     square_prec_round_valid, square_round_valid, sub_prec_round_valid,
     sub_rational_prec_round_valid, sub_rational_round_valid, sub_round_valid,
 };
 use crate::test_util::generators::{
     from_primitive_float_prec_round_valid, integer_rounding_from_float_valid,
     natural_rounding_from_float_valid, reciprocal_prec_round_valid, set_prec_round_valid,
-    signed_rounding_from_float_valid, unsigned_rounding_from_float_valid,
+    signed_rounding_from_float_valid, sqrt_prec_round_valid, unsigned_rounding_from_float_valid,
 };
 use crate::Float;
 use crate::InnerFloat::Finite;
@@ -48,6 +52,7 @@ use malachite_base::rounding_modes::random::random_rounding_modes;
 use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_base::test_util::generators::common::{reshape_2_1_to_3, GenConfig, It};
 use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
+use malachite_base::vecs::random::random_vecs;
 use malachite_nz::integer::random::striped_random_integers;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::random::{striped_random_naturals, StripedRandomNaturalInclusiveRange};
@@ -873,6 +878,38 @@ pub fn special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_4(
     )
 }
 
+pub fn special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples_xxyz(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(_, _, _, rm)| rm != Exact),
+    )
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn special_random_float_float_rational_triple_gen(
@@ -1533,6 +1570,31 @@ pub fn special_random_float_float_rounding_mode_triple_gen_var_28(
     ))
 }
 
+pub fn special_random_float_float_rounding_mode_triple_gen_var_29(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(_, _, rm)| rm != Exact),
+    )
+}
+
 // -- (Float, Integer) --
 
 pub fn special_random_float_integer_pair_gen(config: &GenConfig) -> It<(Float, Integer)> {
@@ -1968,6 +2030,35 @@ pub fn special_random_float_unsigned_pair_gen_var_2<T: PrimitiveUnsigned>(
     ))
 }
 
+pub fn special_random_float_unsigned_pair_gen_var_3(config: &GenConfig) -> It<(Float, u64)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+        )
+        .filter(|(x, _)| exp_valid(x)),
+    )
+}
+
 // -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
 
 pub fn special_random_float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>(
@@ -2096,6 +2187,102 @@ pub fn special_random_float_unsigned_rounding_mode_triple_gen_var_3(
     )
 }
 
+pub fn special_random_float_unsigned_rounding_mode_triple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| sqrt_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn special_random_float_unsigned_rounding_mode_triple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(_, _, rm)| rm != Exact),
+    )
+}
+
+pub fn special_random_float_unsigned_rounding_mode_triple_gen_var_6(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| exp_round_valid(x, rm)),
+    )
+}
+
 // -- (Float, Rational) --
 
 pub fn special_random_float_rational_pair_gen(config: &GenConfig) -> It<(Float, Rational)> {
@@ -3053,6 +3240,81 @@ pub fn special_random_float_rounding_mode_pair_gen_var_17(
     )
 }
 
+pub fn special_random_float_rounding_mode_pair_gen_var_18(
+    config: &GenConfig,
+) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| sqrt_round_valid(f, *rm)),
+    )
+}
+
+pub fn special_random_float_rounding_mode_pair_gen_var_19(
+    config: &GenConfig,
+) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(_, rm)| rm != Exact),
+    )
+}
+
+pub fn special_random_float_rounding_mode_pair_gen_var_20(
+    config: &GenConfig,
+) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                striped_random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_stripe_n", 32),
+                    config.get_or("mean_stripe_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| exp_round_valid(f, *rm)),
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 through 2 are in malachite-nz.
@@ -3414,3 +3676,84 @@ pub fn special_random_rational_rounding_mode_pair_gen_var_6(
         }),
     )
 }
+
+// -- Vec<Float> --
+
+pub fn special_random_float_vec_gen(config: &GenConfig) -> It<Vec<Float>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            striped_random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
+// -- Interval --
+
+fn striped_random_intervals(config: &GenConfig) -> impl Iterator<Item = Interval> {
+    random_pairs_from_single(striped_random_floats(
+        EXAMPLE_SEED,
+        config.get_or("mean_exponent_n", 64),
+        config.get_or("mean_exponent_d", 1),
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_precision_n", 64),
+        config.get_or("mean_precision_d", 1),
+        config.get_or("mean_zero_p_n", 1),
+        config.get_or("mean_zero_p_d", 64),
+    ))
+    .filter_map(|(x, y)| interval_from_float_pair(x, y))
+}
+
+pub fn special_random_interval_gen(config: &GenConfig) -> It<Interval> {
+    Box::new(striped_random_intervals(config))
+}
+
+// -- (Interval, Interval) --
+
+pub fn special_random_interval_pair_gen(config: &GenConfig) -> It<(Interval, Interval)> {
+    Box::new(random_pairs_from_single(striped_random_intervals(config)))
+}
+
+// -- ComplexFloat --
+
+fn striped_random_complex_floats(config: &GenConfig) -> impl Iterator<Item = ComplexFloat> {
+    random_pairs_from_single(striped_random_floats(
+        EXAMPLE_SEED,
+        config.get_or("mean_exponent_n", 64),
+        config.get_or("mean_exponent_d", 1),
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_precision_n", 64),
+        config.get_or("mean_precision_d", 1),
+        config.get_or("mean_zero_p_n", 1),
+        config.get_or("mean_zero_p_d", 64),
+    ))
+    .map(|(re, im)| ComplexFloat::new(re, im))
+}
+
+pub fn special_random_complex_float_gen(config: &GenConfig) -> It<ComplexFloat> {
+    Box::new(striped_random_complex_floats(config))
+}
+
+// -- (ComplexFloat, ComplexFloat) --
+
+pub fn special_random_complex_float_pair_gen(
+    config: &GenConfig,
+) -> It<(ComplexFloat, ComplexFloat)> {
+    Box::new(random_pairs_from_single(striped_random_complex_floats(
+        config,
+    )))
+}
@@ -9,7 +9,9 @@
 pub mod arithmetic;
 pub mod bench;
 pub mod common;
+pub mod complex;
 pub mod constants;
+pub mod conversion;
 pub mod exhaustive;
 pub mod extra_variadic;
 pub mod generators;
@@ -2043,6 +2043,17 @@ fn test_add_round() {
     test("NaN", "NaN", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2060,6 +2071,19 @@ fn test_add_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2114,6 +2138,26 @@ fn test_add_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -2130,6 +2174,17 @@ fn test_add_round() {
     test("NaN", "NaN", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
@@ -2137,6 +2192,17 @@ fn test_add_round() {
     test("NaN", "NaN", "-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2154,6 +2220,19 @@ fn test_add_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -2173,6 +2252,19 @@ fn test_add_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2227,6 +2319,26 @@ fn test_add_round() {
         "NaN",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -2253,6 +2365,19 @@ fn test_add_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2272,6 +2397,19 @@ fn test_add_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2326,6 +2464,26 @@ fn test_add_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2387,6 +2545,26 @@ fn test_add_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2448,6 +2626,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2509,6 +2707,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2570,6 +2788,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2586,6 +2824,17 @@ fn test_add_round() {
     test("0.0", "0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2603,6 +2852,19 @@ fn test_add_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2657,6 +2919,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -2677,6 +2959,17 @@ fn test_add_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
 
     // Note different behavior for Floor
@@ -2694,7 +2987,20 @@ fn test_add_round() {
         "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
     test(
-        "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
     );
 
     test("-0.0", "-0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
@@ -2702,6 +3008,17 @@ fn test_add_round() {
     test("-0.0", "-0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2719,6 +3036,19 @@ fn test_add_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2773,6 +3103,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -2798,6 +3148,19 @@ fn test_add_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
     );
@@ -2817,6 +3180,19 @@ fn test_add_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2832,6 +3208,19 @@ fn test_add_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -2851,6 +3240,19 @@ fn test_add_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2905,6 +3307,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -2931,6 +3353,19 @@ fn test_add_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -2950,6 +3385,19 @@ fn test_add_round() {
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -2965,6 +3413,19 @@ fn test_add_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
     );
@@ -2984,6 +3445,19 @@ fn test_add_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Exact, "Infinity", "Infinity", Equal,
     );
@@ -3038,6 +3512,26 @@ fn test_add_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3064,6 +3558,19 @@ fn test_add_round() {
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -3083,6 +3590,19 @@ fn test_add_round() {
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -3107,6 +3627,19 @@ fn test_add_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
@@ -3215,6 +3748,26 @@ fn test_add_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -3277,6 +3830,26 @@ fn test_add_round() {
         "0x4.8e4950f0795fc#53",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -3333,24 +3906,44 @@ fn test_add_round() {
         "0x1.6a09e667f3bcd#53",
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
-        Exact,
+        NearestTiesAway,
         "-1.727379091216698",
         "-0x1.ba35842091e63#53",
         Equal,
     );
-
-    test(
-        "-1.4142135623730951",
-        "-0x1.6a09e667f3bcd#53",
-        "3.1415926535897931",
-        "0x3.243f6a8885a30#53",
-        Floor,
-        "1.727379091216698",
-        "0x1.ba35842091e63#53",
-        Equal,
-    );
     test(
-        "-1.4142135623730951",
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "-1.727379091216698",
+        "-0x1.ba35842091e63#53",
+        Equal,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "-1.727379091216698",
+        "-0x1.ba35842091e63#53",
+        Equal,
+    );
+
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Equal,
+    );
+    test(
+        "-1.4142135623730951",
         "-0x1.6a09e667f3bcd#53",
         "3.1415926535897931",
         "0x3.243f6a8885a30#53",
@@ -3389,6 +3982,26 @@ fn test_add_round() {
         "0x1.ba35842091e63#53",
         Equal,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Equal,
+    );
     test(
         "-1.4142135623730951",
         "-0x1.6a09e667f3bcd#53",
@@ -3533,9 +4146,42 @@ fn test_add_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
     test(
         "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     // - x_exp == y_exp in add_float_significands_same_prec_lt_w
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Equal,
@@ -3628,6 +4274,26 @@ fn test_add_round() {
         "0x2.0000000000000000#64",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000#64",
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        NearestTiesAway,
+        "2.0000000000000000002",
+        "0x2.0000000000000004#64",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000#64",
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        ToOdd,
+        "2.0000000000000000002",
+        "0x2.0000000000000004#64",
+        Greater,
+    );
     // - round_bit != 0 && (sticky_bit != 0 || (sum & 1) != 0) in add_float_significands_same_prec_w
     // - round_bit != 0 && (sticky_bit != 0 || (sum & 1) != 0) and !overflow in
     //   add_float_significands_same_prec_w
@@ -4311,6 +4977,26 @@ fn test_add_round() {
         "0x3.000000000000000000000000000000000000000000000000#192",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000000000000000000000000000000000000000000000000#192",
+        "2.0",
+        "0x2.000000000000000000000000000000000000000000000000#192",
+        NearestTiesAway,
+        "3.0",
+        "0x3.000000000000000000000000000000000000000000000000#192",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000000000000000000000000000000000000000000000000#192",
+        "2.0",
+        "0x2.000000000000000000000000000000000000000000000000#192",
+        ToOdd,
+        "3.0",
+        "0x3.000000000000000000000000000000000000000000000000#192",
+        Equal,
+    );
     // - x_exp > y_exp in add_float_significands_same_prec_ge_3w
     test(
         "2.0",
@@ -4346,6 +5032,26 @@ fn test_add_round() {
         "0x2.000000000000000000000000000000000000000000000000#192",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.000000000000000000000000000000000000000000000000#192",
+        "1.0000000000000000000000000000000000000000000000000000000003",
+        "0x1.000000000000000000000000000000000000000000000002#192",
+        NearestTiesAway,
+        "2.0000000000000000000000000000000000000000000000000000000006",
+        "0x2.000000000000000000000000000000000000000000000004#192",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000000000000000000000000000000000000000000000000#192",
+        "1.0000000000000000000000000000000000000000000000000000000003",
+        "0x1.000000000000000000000000000000000000000000000002#192",
+        ToOdd,
+        "2.0000000000000000000000000000000000000000000000000000000006",
+        "0x2.000000000000000000000000000000000000000000000004#192",
+        Greater,
+    );
     // - 0 < exp_diff < prec && rm == Nearest && round_bit != 0 && sticky_bit == 0 && out[0] &
     //   shift_bit == 0 in add_float_significands_same_prec_ge_3w
     test(
@@ -4644,6 +5350,19 @@ fn test_add_round() {
     test(
         "1.0", "0x1.0#1", "1.5", "0x1.8#2", Nearest, "2.0", "0x2.0#2", Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.5",
+        "0x1.8#2",
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.5", "0x1.8#2", ToOdd, "3.0", "0x3.0#2", Greater,
+    );
     // - rm == Nearest && following_bits == False && out[0] & shift_bit != 0 in
     //   add_float_significands_same_prec_general_round
     // - rm == Nearest && following_bits == False && out[0] & shift_bit != 0 && carry in
@@ -5114,6 +5833,18 @@ fn test_add_prec_round() {
     test("NaN", "NaN", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -5131,6 +5862,20 @@ fn test_add_prec_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5190,6 +5935,28 @@ fn test_add_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -5211,6 +5978,18 @@ fn test_add_prec_round() {
     test(
         "NaN", "NaN", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -5224,6 +6003,20 @@ fn test_add_prec_round() {
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5243,6 +6036,20 @@ fn test_add_prec_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5262,6 +6069,20 @@ fn test_add_prec_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5321,6 +6142,28 @@ fn test_add_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -5349,14 +6192,28 @@ fn test_add_prec_round() {
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
     test(
-        "Infinity", "Infinity", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
-    );
-
-    test(
-        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
-    );
-    test(
-        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal,
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Ceiling, "Infinity", "Infinity", Equal,
     );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Down, "Infinity", "Infinity", Equal,
@@ -5367,6 +6224,20 @@ fn test_add_prec_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5426,6 +6297,28 @@ fn test_add_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5493,6 +6386,28 @@ fn test_add_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5560,6 +6475,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5627,6 +6564,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5694,6 +6653,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5715,6 +6696,18 @@ fn test_add_prec_round() {
     test(
         "0.0", "0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -5732,6 +6725,20 @@ fn test_add_prec_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5791,6 +6798,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -5816,6 +6845,20 @@ fn test_add_prec_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5836,6 +6879,20 @@ fn test_add_prec_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5851,6 +6908,20 @@ fn test_add_prec_round() {
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5870,6 +6941,20 @@ fn test_add_prec_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5929,6 +7014,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -5957,6 +7064,20 @@ fn test_add_prec_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5977,8 +7098,22 @@ fn test_add_prec_round() {
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
     test(
-        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
-    );
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
+    );
 
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Floor, "NaN", "NaN", Equal,
@@ -5995,6 +7130,20 @@ fn test_add_prec_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6014,6 +7163,20 @@ fn test_add_prec_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -6073,6 +7236,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -6140,6 +7325,28 @@ fn test_add_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "123.0",
@@ -6196,6 +7403,28 @@ fn test_add_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Floor, "NaN", "NaN", Equal,
@@ -6212,6 +7441,20 @@ fn test_add_prec_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6231,6 +7474,20 @@ fn test_add_prec_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -6290,6 +7547,28 @@ fn test_add_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6357,6 +7636,28 @@ fn test_add_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "-0.0",
@@ -6413,6 +7714,28 @@ fn test_add_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
@@ -6429,6 +7752,20 @@ fn test_add_prec_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -6485,6 +7822,28 @@ fn test_add_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -6512,6 +7871,20 @@ fn test_add_prec_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -6568,6 +7941,28 @@ fn test_add_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -6595,6 +7990,20 @@ fn test_add_prec_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -6651,6 +8060,28 @@ fn test_add_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -6678,6 +8109,20 @@ fn test_add_prec_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#2", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -6734,6 +8179,28 @@ fn test_add_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -6801,6 +8268,28 @@ fn test_add_prec_round() {
         "0x4.0#1",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
 
     test(
         "1.0",
@@ -6857,6 +8346,28 @@ fn test_add_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -6924,6 +8435,28 @@ fn test_add_prec_round() {
         "0x4.8e#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "4.555",
+        "0x4.8e#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "4.555",
+        "0x4.8e#10",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -6980,6 +8513,28 @@ fn test_add_prec_round() {
         "-0x1.ba0#10",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-1.727",
+        "-0x1.ba0#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-1.729",
+        "-0x1.ba8#10",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -7036,14 +8591,69 @@ fn test_add_prec_round() {
         "0x1.ba0#10",
         Less,
     );
-
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "1.727",
+        "0x1.ba0#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "1.729",
+        "0x1.ba8#10",
+        Greater,
+    );
+
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-4.56",
+        "-0x4.90#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-4.555",
+        "-0x4.8e#10",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-4.555",
+        "-0x4.8e#10",
+        Greater,
+    );
     test(
         "-1.4142135623730951",
         "-0x1.6a09e667f3bcd#53",
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         10,
-        Floor,
+        Up,
         "-4.56",
         "-0x4.90#10",
         Less,
@@ -7054,7 +8664,7 @@ fn test_add_prec_round() {
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         10,
-        Ceiling,
+        Nearest,
         "-4.555",
         "-0x4.8e#10",
         Greater,
@@ -7065,7 +8675,7 @@ fn test_add_prec_round() {
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         10,
-        Down,
+        NearestTiesAway,
         "-4.555",
         "-0x4.8e#10",
         Greater,
@@ -7076,18 +8686,7 @@ fn test_add_prec_round() {
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         10,
-        Up,
-        "-4.56",
-        "-0x4.90#10",
-        Less,
-    );
-    test(
-        "-1.4142135623730951",
-        "-0x1.6a09e667f3bcd#53",
-        "-3.1415926535897931",
-        "-0x3.243f6a8885a30#53",
-        10,
-        Nearest,
+        ToOdd,
         "-4.555",
         "-0x4.8e#10",
         Greater,
@@ -7148,6 +8747,28 @@ fn test_add_prec_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
 
     test(
         "1.0",
@@ -7204,6 +8825,28 @@ fn test_add_prec_round() {
         "0x1.00100#20",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        20,
+        NearestTiesAway,
+        "1.000244",
+        "0x1.00100#20",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0002",
+        "0x0.001#1",
+        20,
+        ToOdd,
+        "1.000244",
+        "0x1.00100#20",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -7231,6 +8874,20 @@ fn test_add_prec_round() {
     test(
         "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 10, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7744,6 +9401,8 @@ fn test_add_rational_round() {
     test("NaN", "NaN", "123", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -7761,6 +9420,18 @@ fn test_add_rational_round() {
     test(
         "Infinity", "Infinity", "123", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123", Exact, "Infinity", "Infinity", Equal,
     );
@@ -7810,6 +9481,24 @@ fn test_add_rational_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7825,6 +9514,8 @@ fn test_add_rational_round() {
     test("0.0", "0x0.0", "0", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", Floor, "-0.0", "-0x0.0", Equal);
@@ -7832,6 +9523,16 @@ fn test_add_rational_round() {
     test("-0.0", "-0x0.0", "0", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "123", Floor, "6.0e1", "0x4.0E+1#1", Less);
@@ -7855,6 +9556,16 @@ fn test_add_rational_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("0.0", "0x0.0", "123", ToOdd, "6.0e1", "0x4.0E+1#1", Less);
 
     test("-0.0", "-0x0.0", "123", Floor, "6.0e1", "0x4.0E+1#1", Less);
     test(
@@ -7877,6 +9588,16 @@ fn test_add_rational_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", "123", ToOdd, "6.0e1", "0x4.0E+1#1", Less);
 
     test("123.0", "0x7b.0#7", "0", Floor, "123.0", "0x7b.0#7", Equal);
     test(
@@ -7887,6 +9608,16 @@ fn test_add_rational_round() {
     test(
         "123.0", "0x7b.0#7", "0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "0", ToOdd, "123.0", "0x7b.0#7", Equal);
     test("123.0", "0x7b.0#7", "0", Exact, "123.0", "0x7b.0#7", Equal);
 
     test("0.0", "0x0.0", "1/3", Floor, "0.2", "0x0.4#1", Less);
@@ -7894,24 +9625,64 @@ fn test_add_rational_round() {
     test("0.0", "0x0.0", "1/3", Down, "0.2", "0x0.4#1", Less);
     test("0.0", "0x0.0", "1/3", Up, "0.5", "0x0.8#1", Greater);
     test("0.0", "0x0.0", "1/3", Nearest, "0.2", "0x0.4#1", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "1/3", ToOdd, "0.2", "0x0.4#1", Less);
 
     test("-0.0", "-0x0.0", "1/3", Floor, "0.2", "0x0.4#1", Less);
     test("-0.0", "-0x0.0", "1/3", Ceiling, "0.5", "0x0.8#1", Greater);
     test("-0.0", "-0x0.0", "1/3", Down, "0.2", "0x0.4#1", Less);
     test("-0.0", "-0x0.0", "1/3", Up, "0.5", "0x0.8#1", Greater);
     test("-0.0", "-0x0.0", "1/3", Nearest, "0.2", "0x0.4#1", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "1/3", ToOdd, "0.2", "0x0.4#1", Less);
 
     test("1.0", "0x1.0#1", "2", Floor, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "2", Ceiling, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "2", Down, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "2", Up, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "2", Nearest, "4.0", "0x4.0#1", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", "2", ToOdd, "2.0", "0x2.0#1", Less);
 
     test("1.0", "0x1.0#2", "2", Floor, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Ceiling, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Down, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Up, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Nearest, "3.0", "0x3.0#2", Equal);
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2",
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Equal,
+    );
+    test("1.0", "0x1.0#2", "2", ToOdd, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Exact, "3.0", "0x3.0#2", Equal);
 
     test("1.0", "0x1.000#10", "2", Floor, "3.0", "0x3.00#10", Equal);
@@ -7919,6 +9690,16 @@ fn test_add_rational_round() {
     test("1.0", "0x1.000#10", "2", Down, "3.0", "0x3.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Up, "3.0", "0x3.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Nearest, "3.0", "0x3.00#10", Equal);
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2",
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test("1.0", "0x1.000#10", "2", ToOdd, "3.0", "0x3.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Exact, "3.0", "0x3.00#10", Equal);
 
     test(
@@ -7966,6 +9747,24 @@ fn test_add_rational_round() {
         "0x1.558#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        NearestTiesAway,
+        "1.334",
+        "0x1.558#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        ToOdd,
+        "1.334",
+        "0x1.558#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -8012,6 +9811,24 @@ fn test_add_rational_round() {
         "0x1.5555555555555555555555556#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        NearestTiesAway,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        ToOdd,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -8058,6 +9875,24 @@ fn test_add_rational_round() {
         "0x3.7994bfdddaf86#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "3.4749259869231266",
+        "0x3.7994bfdddaf86#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "3.4749259869231266",
+        "0x3.7994bfdddaf86#53",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -8104,6 +9939,24 @@ fn test_add_rational_round() {
         "0x2.ceea1533304da#53",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        NearestTiesAway,
+        "2.8082593202564596",
+        "0x2.ceea1533304da#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        ToOdd,
+        "2.8082593202564596",
+        "0x2.ceea1533304da#53",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8150,6 +10003,24 @@ fn test_add_rational_round() {
         "-0x2.ceea1533304da#53",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "-2.8082593202564596",
+        "-0x2.ceea1533304da#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "-2.8082593202564596",
+        "-0x2.ceea1533304da#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8191,7 +10062,25 @@ fn test_add_rational_round() {
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         "-1/3",
-        Nearest,
+        Nearest,
+        "-3.4749259869231266",
+        "-0x3.7994bfdddaf86#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        NearestTiesAway,
+        "-3.4749259869231266",
+        "-0x3.7994bfdddaf86#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        ToOdd,
         "-3.4749259869231266",
         "-0x3.7994bfdddaf86#53",
         Less,
@@ -8204,12 +10093,32 @@ fn test_add_rational_round() {
     test("1.0", "0x1.0#1", "1/50000", Down, "1.0", "0x1.0#1", Less);
     test("1.0", "0x1.0#1", "1/50000", Up, "2.0", "0x2.0#1", Greater);
     test("1.0", "0x1.0#1", "1/50000", Nearest, "1.0", "0x1.0#1", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/50000",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "1/50000", ToOdd, "1.0", "0x1.0#1", Less);
 
     test("1.0", "0x1.0#1", "-1", Floor, "-0.0", "-0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", Ceiling, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", Down, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", Up, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "-1", ToOdd, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -8266,6 +10175,24 @@ fn test_add_rational_round() {
         "0x7.10E+149#10",
         Greater,
     );
+    test(
+        "1.832e180",
+        "0x7.10E+149#10",
+        "-1/8388607",
+        NearestTiesAway,
+        "1.832e180",
+        "0x7.10E+149#10",
+        Greater,
+    );
+    test(
+        "1.832e180",
+        "0x7.10E+149#10",
+        "-1/8388607",
+        ToOdd,
+        "1.83e180",
+        "0x7.0eE+149#10",
+        Less,
+    );
     test("1.0", "0x1.0#1", "0", Nearest, "1.0", "0x1.0#1", Equal);
     // - o == Equal in add_rational_prec_round_assign_helper
     test("1.0", "0x1.0#1", "1", Nearest, "2.0", "0x2.0#1", Equal);
@@ -8468,6 +10395,8 @@ fn test_add_rational_prec_round() {
     test("NaN", "NaN", "123", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -8485,6 +10414,19 @@ fn test_add_rational_prec_round() {
     test(
         "Infinity", "Infinity", "123", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -8539,6 +10481,26 @@ fn test_add_rational_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -8555,6 +10517,17 @@ fn test_add_rational_prec_round() {
     test("0.0", "0x0.0", "0", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -8562,6 +10535,17 @@ fn test_add_rational_prec_round() {
     test("-0.0", "-0x0.0", "0", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "123", 1, Floor, "6.0e1", "0x4.0E+1#1", Less);
@@ -8587,6 +10571,17 @@ fn test_add_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("0.0", "0x0.0", "123", 1, ToOdd, "6.0e1", "0x4.0E+1#1", Less);
 
     test(
         "-0.0",
@@ -8638,6 +10633,26 @@ fn test_add_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test("0.0", "0x0.0", "1/3", 1, Floor, "0.2", "0x0.4#1", Less);
     test("0.0", "0x0.0", "1/3", 1, Ceiling, "0.5", "0x0.8#1", Greater);
@@ -8703,18 +10718,60 @@ fn test_add_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test("1.0", "0x1.0#1", "2", 1, Floor, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "2", 1, Ceiling, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "2", 1, Down, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "2", 1, Up, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "2", 1, Nearest, "4.0", "0x4.0#1", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", "2", 1, ToOdd, "2.0", "0x2.0#1", Less);
 
     test("1.0", "0x1.0#1", "2", 2, Floor, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "2", 2, Ceiling, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "2", 2, Down, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "2", 2, Up, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "2", 2, Nearest, "3.0", "0x3.0#2", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        2,
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", 2, ToOdd, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "2", 2, Exact, "3.0", "0x3.0#2", Equal);
 
     test(
@@ -8767,6 +10824,26 @@ fn test_add_rational_prec_round() {
         "0x1.5555555555555555555555556#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        100,
+        NearestTiesAway,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        100,
+        ToOdd,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -8818,6 +10895,26 @@ fn test_add_rational_prec_round() {
         "0x3.7a#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "3.477",
+        "0x3.7a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "3.473",
+        "0x3.79#10",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -8869,6 +10966,26 @@ fn test_add_rational_prec_round() {
         "0x2.cf#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        NearestTiesAway,
+        "2.809",
+        "0x2.cf#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        ToOdd,
+        "2.809",
+        "0x2.cf#10",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8920,6 +11037,26 @@ fn test_add_rational_prec_round() {
         "-0x2.cf#10",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "-2.809",
+        "-0x2.cf#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "-2.809",
+        "-0x2.cf#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8971,6 +11108,26 @@ fn test_add_rational_prec_round() {
         "-0x3.7a#10",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        NearestTiesAway,
+        "-3.477",
+        "-0x3.7a#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        ToOdd,
+        "-3.473",
+        "-0x3.79#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -9022,12 +11179,43 @@ fn test_add_rational_prec_round() {
         "0x1.000#10",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/50000",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1/50000",
+        10,
+        ToOdd,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
 
     test("1.0", "0x1.0#1", "-1", 10, Floor, "-0.0", "-0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", 10, Ceiling, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", 10, Down, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", 10, Up, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1",
+        10,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "-1", 10, ToOdd, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "-1", 10, Exact, "0.0", "0x0.0", Equal);
 }
 
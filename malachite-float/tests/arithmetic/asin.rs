@@ -0,0 +1,1664 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, OneHalf, Two, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_4;
+use malachite_float::test_util::arithmetic::asin::{
+    rug_acos_prec, rug_acos_prec_round, rug_acos_round, rug_asin_prec, rug_asin_prec_round,
+    rug_asin_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_assign(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 10, "0.523", "0x0.860#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 10, "-0.523", "-0x0.860#10", Greater);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.57", "0x1.920#10", Less);
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.57", "-0x1.920#10", Greater);
+    test("2.0", "0x2.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        "0.1001674211615598019246037950514",
+        "0x0.19a492760378842724bade9df4#100",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        1,
+        "-0.1",
+        "-0x0.2#1",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        "-0.1002",
+        "-0x0.19a8#10",
+        Less,
+    );
+    test("0.94", "0x0.f#4", 1, "1.0", "0x1.0#1", Less);
+    test("0.94", "0x0.f#4", 10, "1.215", "0x1.370#10", Less);
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        1,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_prec_fail() {
+    assert_panic!(Float::NAN.asin_prec(0));
+    assert_panic!(Float::NAN.asin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.asin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_asin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_round_assign(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("0.5", "0x0.8#1", Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-0.5", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("2.0", "0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Floor,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Ceiling,
+        "0.10016742116155981",
+        "0x0.19a49276037885#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Down,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Up,
+        "0.10016742116155981",
+        "0x0.19a49276037885#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Nearest,
+        "0.1001674211615598",
+        "0x0.19a49276037884#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Floor,
+        "-0.10016742116155981",
+        "-0x0.19a49276037885#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Ceiling,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Down,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Up,
+        "-0.10016742116155981",
+        "-0x0.19a49276037885#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Nearest,
+        "-0.1001674211615598",
+        "-0x0.19a49276037884#53",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", Floor, "1.1", "0x1.2#4", Less);
+    test("0.94", "0x0.f#4", Ceiling, "1.2", "0x1.4#4", Greater);
+    test("0.94", "0x0.f#4", Down, "1.1", "0x1.2#4", Less);
+    test("0.94", "0x0.f#4", Up, "1.2", "0x1.4#4", Greater);
+    test("0.94", "0x0.f#4", Nearest, "1.2", "0x1.4#4", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Floor, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "7.0e-9",
+        "0x2.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "4.0e-9", "0x1.0E-7#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "7.0e-9", "0x2.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Floor,
+        "-7.0e-9",
+        "-0x2.0E-7#1",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Ceiling,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Down,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "-7.0e-9", "-0x2.0E-7#1", Less);
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Nearest,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+}
+
+#[test]
+fn asin_round_fail() {
+    assert_panic!(Float::ONE_HALF.asin_round(Exact));
+    assert_panic!(Float::ONE_HALF.asin_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_asin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_round_assign(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("0.5", "0x0.8#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("0.5", "0x0.8#1", 10, Floor, "0.523", "0x0.860#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "0.524",
+        "0x0.864#10",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 10, Down, "0.523", "0x0.860#10", Less);
+    test("0.5", "0x0.8#1", 10, Up, "0.524", "0x0.864#10", Greater);
+    test("0.5", "0x0.8#1", 10, Nearest, "0.523", "0x0.860#10", Less);
+    test("-0.5", "-0x0.8#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("-0.5", "-0x0.8#1", 10, Floor, "-0.524", "-0x0.864#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Down,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 10, Up, "-0.524", "-0x0.864#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Nearest,
+        "-0.523",
+        "-0x0.860#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Floor, "1.57", "0x1.920#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.57", "0x1.920#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.572", "0x1.928#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.57", "0x1.920#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-1.572", "-0x1.928#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.572", "-0x1.928#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Floor,
+        "0.1001",
+        "0x0.19a0#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Down,
+        "0.1001",
+        "0x0.19a0#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Up,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "0.1002",
+        "0x0.19a8#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Floor,
+        "-0.1002",
+        "-0x0.19a8#10",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "-0.1001",
+        "-0x0.19a0#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Down,
+        "-0.1001",
+        "-0x0.19a0#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Up,
+        "-0.1002",
+        "-0x0.19a8#10",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "-0.1002",
+        "-0x0.19a8#10",
+        Less,
+    );
+    test("0.94", "0x0.f#4", 10, Floor, "1.215", "0x1.370#10", Less);
+    test(
+        "0.94",
+        "0x0.f#4",
+        10,
+        Ceiling,
+        "1.217",
+        "0x1.378#10",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", 10, Down, "1.215", "0x1.370#10", Less);
+    test("0.94", "0x0.f#4", 10, Up, "1.217", "0x1.378#10", Greater);
+    test("0.94", "0x0.f#4", 10, Nearest, "1.215", "0x1.370#10", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Floor,
+        "-3.733e-9",
+        "-0x1.008E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Ceiling,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Down,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Up,
+        "-3.733e-9",
+        "-0x1.008E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Nearest,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        NearestTiesAway,
+        "0.523",
+        "0x0.860#10",
+        Less,
+    );
+    test("0.5", "0x0.8#1", 10, ToOdd, "0.524", "0x0.864#10", Greater);
+}
+
+#[test]
+fn asin_prec_round_fail() {
+    assert_panic!(Float::ONE.asin_prec_round(0, Floor));
+    assert_panic!(Float::ONE.asin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.asin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.asin_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.asin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_acos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_assign(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "1.57", "0x1.920#10", Less);
+    test("-0.0", "-0x0.0", 10, "1.57", "0x1.920#10", Less);
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 10, "1.047", "0x1.0c0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 10, "2.094", "0x2.18#10", Less);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "3.141", "0x3.24#10", Less);
+    test("2.0", "0x2.0#1", 1, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        100,
+        "1.470628905633336817306717896588",
+        "0x1.787b22ce3f59004264d1e6790#100",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        "1.672",
+        "0x1.ac0#10",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", 1, "0.2", "0x0.4#1", Less);
+    test("0.94", "0x0.f#4", 10, "0.3555", "0x0.5b0#10", Greater);
+    test("4.0e-9", "0x1.0E-7#1", 1, "2.0", "0x2.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", 10, "1.57", "0x1.920#10", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", 1, "2.0", "0x2.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, "1.57", "0x1.920#10", Less);
+}
+
+#[test]
+fn acos_prec_fail() {
+    assert_panic!(Float::NAN.acos_prec(0));
+    assert_panic!(Float::NAN.acos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.acos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_acos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_round_assign(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Floor, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", Down, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", Up, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("-0.5", "-0x0.8#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Down, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Nearest, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "4.0", "0x4.0#1", Greater);
+    test("2.0", "0x2.0#1", Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Floor,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Ceiling,
+        "1.470628905633337",
+        "0x1.787b22ce3f591#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Down,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Up,
+        "1.470628905633337",
+        "0x1.787b22ce3f591#53",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        Nearest,
+        "1.4706289056333368",
+        "0x1.787b22ce3f590#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Floor,
+        "1.6709637479564563",
+        "0x1.abc447ba464a0#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Ceiling,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Down,
+        "1.6709637479564563",
+        "0x1.abc447ba464a0#53",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Up,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        Nearest,
+        "1.6709637479564565",
+        "0x1.abc447ba464a1#53",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", Floor, "0.34", "0x0.58#4", Less);
+    test("0.94", "0x0.f#4", Ceiling, "0.38", "0x0.60#4", Greater);
+    test("0.94", "0x0.f#4", Down, "0.34", "0x0.58#4", Less);
+    test("0.94", "0x0.f#4", Up, "0.38", "0x0.60#4", Greater);
+    test("0.94", "0x0.f#4", Nearest, "0.34", "0x0.58#4", Less);
+    test("4.0e-9", "0x1.0E-7#1", Floor, "1.0", "0x1.0#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Down, "1.0", "0x1.0#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "2.0", "0x2.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", Floor, "1.0", "0x1.0#1", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", Down, "1.0", "0x1.0#1", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "2.0", "0x2.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", Nearest, "2.0", "0x2.0#1", Greater);
+}
+
+#[test]
+fn acos_round_fail() {
+    assert_panic!(Float::ONE_HALF.acos_round(Exact));
+    assert_panic!(Float::ONE_HALF.acos_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_acos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_round_assign(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "1.57", "0x1.920#10", Less);
+    test("-0.0", "-0x0.0", 10, Floor, "1.57", "0x1.920#10", Less);
+    test("0.5", "0x0.8#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("0.5", "0x0.8#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("0.5", "0x0.8#1", 10, Floor, "1.047", "0x1.0c0#10", Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "1.049",
+        "0x1.0c8#10",
+        Greater,
+    );
+    test("0.5", "0x0.8#1", 10, Down, "1.047", "0x1.0c0#10", Less);
+    test("0.5", "0x0.8#1", 10, Up, "1.049", "0x1.0c8#10", Greater);
+    test("0.5", "0x0.8#1", 10, Nearest, "1.047", "0x1.0c0#10", Less);
+    test("-0.5", "-0x0.8#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("-0.5", "-0x0.8#1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("-0.5", "-0x0.8#1", 10, Floor, "2.094", "0x2.18#10", Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        Ceiling,
+        "2.098",
+        "0x2.19#10",
+        Greater,
+    );
+    test("-0.5", "-0x0.8#1", 10, Down, "2.094", "0x2.18#10", Less);
+    test("-0.5", "-0x0.8#1", 10, Up, "2.098", "0x2.19#10", Greater);
+    test("-0.5", "-0x0.8#1", 10, Nearest, "2.094", "0x2.18#10", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "3.141", "0x3.24#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "3.141", "0x3.24#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "3.145", "0x3.25#10", Greater);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "3.141", "0x3.24#10", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Floor,
+        "1.469",
+        "0x1.780#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Down,
+        "1.469",
+        "0x1.780#10",
+        Less,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Up,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "0.10000000000000001",
+        "0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "1.471",
+        "0x1.788#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Floor,
+        "1.67",
+        "0x1.ab8#10",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Ceiling,
+        "1.672",
+        "0x1.ac0#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Down,
+        "1.67",
+        "0x1.ab8#10",
+        Less,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Up,
+        "1.672",
+        "0x1.ac0#10",
+        Greater,
+    );
+    test(
+        "-0.10000000000000001",
+        "-0x0.1999999999999a#53",
+        10,
+        Nearest,
+        "1.672",
+        "0x1.ac0#10",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", 10, Floor, "0.355", "0x0.5ae#10", Less);
+    test(
+        "0.94",
+        "0x0.f#4",
+        10,
+        Ceiling,
+        "0.3555",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test("0.94", "0x0.f#4", 10, Down, "0.355", "0x0.5ae#10", Less);
+    test("0.94", "0x0.f#4", 10, Up, "0.3555", "0x0.5b0#10", Greater);
+    test(
+        "0.94",
+        "0x0.f#4",
+        10,
+        Nearest,
+        "0.3555",
+        "0x0.5b0#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 10, Down, "1.57", "0x1.920#10", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Down,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        NearestTiesAway,
+        "1.047",
+        "0x1.0c0#10",
+        Less,
+    );
+    test("0.5", "0x0.8#1", 10, ToOdd, "1.049", "0x1.0c8#10", Greater);
+}
+
+#[test]
+fn acos_prec_round_fail() {
+    assert_panic!(Float::ONE.acos_prec_round(0, Floor));
+    assert_panic!(Float::ONE.acos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.acos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::ONE_HALF.acos_prec_round(1, Exact));
+    assert_panic!(Float::ONE_HALF.acos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn asin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (asin_alt, o_alt) = (-&x).asin_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-asin_alt), ComparableFloat(asin.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        if x.gt_abs(&1u32) {
+            assert!(asin.is_nan());
+        }
+
+        if asin.is_normal() {
+            assert_eq!(asin.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.asin_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (asin, o) = Float::NAN.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_INFINITY.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (asin, o) = Float::NEGATIVE_ZERO.asin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(asin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            ComparableFloat(Float::ONE.asin_prec_round(prec, rm).0),
+            ComparableFloat(Float::ONE.atan2_prec_round(Float::ZERO, prec, rm).0)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ONE.asin_prec_round(prec, rm).0),
+            ComparableFloat(
+                Float::NEGATIVE_ONE
+                    .atan2_prec_round(Float::ZERO, prec, rm)
+                    .0
+            )
+        );
+        let (asin, o) = Float::TWO.asin_prec_round(prec, rm);
+        assert!(asin.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asin_prec_properties_helper(x: Float, prec: u64) {
+    let (asin, o) = x.clone().asin_prec(prec);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_asin)),
+        ComparableFloatRef(&asin),
+    );
+    assert_eq!(rug_o, o);
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if asin.is_normal() {
+        assert_eq!(asin.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn asin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        asin_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn asin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (asin, o) = x.clone().asin_round(rm);
+    assert!(asin.is_valid());
+
+    let (asin_alt, o_alt) = x.asin_round_ref(rm);
+    assert!(asin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.asin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    let (asin_alt, o_alt) = x.asin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn asin_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        asin_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn acos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if x.gt_abs(&1u32) {
+            assert!(acos.is_nan());
+        } else if !x.is_nan() {
+            assert!(acos >= 0u32);
+        }
+
+        if acos.is_normal() {
+            assert_eq!(acos.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.acos_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (acos, o) = Float::NAN.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        let (acos, o) = Float::NEGATIVE_INFINITY.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            ComparableFloat(Float::ZERO.acos_prec_round(prec, rm).0),
+            ComparableFloat(Float::ONE.atan2_prec_round(Float::ZERO, prec, rm).0)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ZERO.acos_prec_round(prec, rm).0),
+            ComparableFloat(Float::ONE.atan2_prec_round(Float::ZERO, prec, rm).0)
+        );
+        let (acos, o) = Float::ONE.acos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(acos), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ONE.acos_prec_round(prec, rm).0),
+            ComparableFloat(
+                Float::ZERO
+                    .atan2_prec_round(Float::NEGATIVE_ONE, prec, rm)
+                    .0
+            )
+        );
+        let (acos, o) = Float::TWO.acos_prec_round(prec, rm);
+        assert!(acos.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acos_prec_properties_helper(x: Float, prec: u64) {
+    let (acos, o) = x.clone().acos_prec(prec);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_acos)),
+        ComparableFloatRef(&acos),
+    );
+    assert_eq!(rug_o, o);
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if acos.is_normal() {
+        assert_eq!(acos.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn acos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        acos_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn acos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (acos, o) = x.clone().acos_round(rm);
+    assert!(acos.is_valid());
+
+    let (acos_alt, o_alt) = x.acos_round_ref(rm);
+    assert!(acos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.acos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    let (acos_alt, o_alt) = x.acos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn acos_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        acos_round_properties_helper(x, rm);
+    });
+}
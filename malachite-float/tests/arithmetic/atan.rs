@@ -0,0 +1,3834 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::max;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_4;
+use malachite_float::test_util::arithmetic::atan::{
+    rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round, rug_atan_prec, rug_atan_prec_round,
+    rug_atan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_29,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5, float_float_unsigned_triple_gen_var_1,
+    float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_assign(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "1.57", "0x1.920#10", Less);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.785", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.785", "-0x0.c90#10", Greater);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, "1.107", "0x1.1b8#10", Greater);
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, "1.25", "0x1.400#10", Greater);
+    test("123.0", "0x7b.0#7", 1, "2.0", "0x2.0#1", Greater);
+    test("123.0", "0x7b.0#7", 10, "1.562", "0x1.900#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, "-2.0", "-0x2.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-1.562", "-0x1.900#10", Greater);
+    test("355.0", "0x163.0#9", 1, "2.0", "0x2.0#1", Greater);
+    test("355.0", "0x163.0#9", 10, "1.568", "0x1.918#10", Greater);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.262",
+        "-0x1.430#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.219",
+        "0x1.380#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", 10, "1.57", "0x1.920#10", Less);
+    test("2.0e3010", "0x1.0E+2500#1", 1, "2.0", "0x2.0#1", Greater);
+    test("2.0e3010", "0x1.0E+2500#1", 10, "1.57", "0x1.920#10", Less);
+}
+
+#[test]
+fn atan_prec_fail() {
+    assert_panic!(Float::NAN.atan_prec(0));
+    assert_panic!(Float::NAN.atan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.atan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_atan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_round_assign(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "1.0", "0x1.0#1", Less);
+    test("-Infinity", "-Infinity", Floor, "-2.0", "-0x2.0#1", Less);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.7853981633974483096156608458206",
+        "0x0.c90fdaa22168c234c4c6628b9#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", Floor, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Down, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Less);
+    test("123.0", "0x7b.0#7", Floor, "1.56", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "1.58", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "1.56", "0x1.90#7", Less);
+    test("123.0", "0x7b.0#7", Up, "1.58", "0x1.94#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "1.56", "0x1.90#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-1.58", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-1.56",
+        "-0x1.90#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-1.56", "-0x1.90#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.58", "-0x1.94#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-1.56",
+        "-0x1.90#7",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", Floor, "1.566", "0x1.91#9", Less);
+    test("355.0", "0x163.0#9", Ceiling, "1.57", "0x1.92#9", Greater);
+    test("355.0", "0x163.0#9", Down, "1.566", "0x1.91#9", Less);
+    test("355.0", "0x163.0#9", Up, "1.57", "0x1.92#9", Greater);
+    test("355.0", "0x163.0#9", Nearest, "1.566", "0x1.91#9", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "2.0e-9", "0x8.0E-8#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "2.0e-9", "0x8.0E-8#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("2.0e3010", "0x1.0E+2500#1", Floor, "1.0", "0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("2.0e3010", "0x1.0E+2500#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0e3010", "0x1.0E+2500#1", Up, "2.0", "0x2.0#1", Greater);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+}
+
+#[test]
+fn atan_round_fail() {
+    assert_panic!(Float::TWO.atan_round(Exact));
+    assert_panic!(Float::TWO.atan_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.atan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_atan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_round_assign(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.785", "0x0.c90#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.785", "0x0.c90#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.786", "0x0.c94#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.785", "0x0.c90#10", Less);
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-0.786", "-0x0.c94#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.786", "-0x0.c94#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "1.105", "0x1.1b0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "1.107",
+        "0x1.1b8#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "1.105", "0x1.1b0#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "1.107", "0x1.1b8#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "1.107",
+        "0x1.1b8#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Floor, "1.248", "0x1.3f8#10", Less);
+    test("3.0", "0x3.0#2", 10, Ceiling, "1.25", "0x1.400#10", Greater);
+    test("3.0", "0x3.0#2", 10, Down, "1.248", "0x1.3f8#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.25", "0x1.400#10", Greater);
+    test("3.0", "0x3.0#2", 10, Nearest, "1.25", "0x1.400#10", Greater);
+    test("123.0", "0x7b.0#7", 10, Floor, "1.562", "0x1.900#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "1.564",
+        "0x1.908#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "1.562", "0x1.900#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "1.564", "0x1.908#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "1.562",
+        "0x1.900#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.564",
+        "-0x1.908#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-1.562",
+        "-0x1.900#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-1.562",
+        "-0x1.900#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "-1.564", "-0x1.908#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.562",
+        "-0x1.900#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Floor, "1.566", "0x1.910#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "1.568",
+        "0x1.918#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Down, "1.566", "0x1.910#10", Less);
+    test("355.0", "0x163.0#9", 10, Up, "1.568", "0x1.918#10", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "1.568",
+        "0x1.918#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.264",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.264",
+        "0x1.438#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.262",
+        "0x1.430#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.264",
+        "-0x1.438#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-1.262",
+        "-0x1.430#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-1.262",
+        "-0x1.430#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.264",
+        "-0x1.438#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.262",
+        "-0x1.430#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.217",
+        "0x1.378#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.219",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.217",
+        "0x1.378#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.219",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.219",
+        "0x1.380#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Down,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Ceiling,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Nearest,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "0.786", "0x0.c94#10", Greater);
+}
+
+#[test]
+fn atan_prec_round_fail() {
+    assert_panic!(Float::ONE.atan_prec_round(0, Floor));
+    assert_panic!(Float::ONE.atan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.atan_prec_round(1, Exact));
+    assert_panic!(Float::TWO.atan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.atan_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_atan2_prec() {
+    let test = |s, s_hex, t, t_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_assign_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 10, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 10, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 10, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 10, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 10, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 10, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 10, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 10, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-0.785",
+        "-0x0.c90#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.857",
+        "-0x0.db8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "2.285",
+        "0x2.49#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_prec_fail() {
+    assert_panic!(Float::NAN.atan2_prec(Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::NAN.atan2_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!({
+        let mut y = Float::NAN;
+        y.atan2_prec_assign(Float::NAN, 0)
+    });
+    assert_panic!({
+        let mut y = Float::NAN;
+        y.atan2_prec_assign_ref(&Float::NAN, 0)
+    });
+}
+
+#[test]
+fn test_atan2_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_round_assign_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "1.0", "0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "Infinity", "Infinity", "-1.0", "-0x1.0#1", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test("1.0", "0x1.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test("-1.0", "-0x1.0#1", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "0.0", "0x0.0", Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-0.0", "-0x0.0", Floor, "-2.0", "-0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.8575117863558464",
+        "-0x0.db85e4765538e0#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Floor,
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Ceiling,
+        "2.2840808672339472",
+        "0x2.48b98612306a4#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        Nearest,
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.12",
+        "0x3.20#7",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "3.16",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "3.16",
+        "0x3.28#7",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        Floor,
+        "1.5",
+        "0x1.8#2",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        Ceiling,
+        "2.0",
+        "0x2.0#2",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        Nearest,
+        "1.5",
+        "0x1.8#2",
+        Less,
+    );
+}
+
+#[test]
+fn atan2_round_fail() {
+    assert_panic!(Float::ONE.atan2_round(Float::TWO, Exact));
+    assert_panic!(Float::ONE.atan2_round_val_ref(&Float::TWO, Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_val(Float::TWO, Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_ref(&Float::TWO, Exact));
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_round_assign(Float::TWO, Exact)
+    });
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_round_assign_ref(&Float::TWO, Exact)
+    });
+}
+
+#[test]
+fn test_atan2_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let y = parse_hex_string(s_hex);
+        assert_eq!(y.to_string(), s);
+        let x = parse_hex_string(t_hex);
+        assert_eq!(x.to_string(), t);
+
+        let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+        assert_eq!(o, o_out);
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = y.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "-0.786",
+        "-0x0.c94#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 10, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 10, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", 10, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "-3.145",
+        "-0x3.25#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "-0.786",
+        "-0x0.c94#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Down,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Up,
+        "0.786",
+        "0x0.c94#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Down, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Up, "4.0", "0x4.0#1", Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "2.359",
+        "0x2.5c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "2.359",
+        "0x2.5c#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "2.355",
+        "0x2.5b#10",
+        Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Floor, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Ceiling, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Down, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Up, "-4.0", "-0x4.0#1", Less,
+    );
+    test(
+        "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Up,
+        "-2.359",
+        "-0x2.5c#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-2.355",
+        "-0x2.5b#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.858",
+        "0x0.dbc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.858",
+        "0x0.dbc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.857",
+        "0x0.db8#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.858",
+        "-0x0.dbc#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.857",
+        "-0x0.db8#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.857",
+        "-0x0.db8#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.858",
+        "-0x0.dbc#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.857",
+        "-0x0.db8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "2.281",
+        "0x2.48#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "2.285",
+        "0x2.49#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "2.281",
+        "0x2.48#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "2.285",
+        "0x2.49#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "2.285",
+        "0x2.49#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Down,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Up,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Nearest,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Up,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0e180617997",
+        "0x1.0E+150000000#1",
+        10,
+        Floor,
+        "3.992e-180617998",
+        "0xf.fcE-150000001#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0e180617997",
+        "0x1.0E+150000000#1",
+        10,
+        Ceiling,
+        "3.996e-180617998",
+        "0x1.000E-150000000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0e180617997",
+        "0x1.0E+150000000#1",
+        10,
+        Nearest,
+        "3.996e-180617998",
+        "0x1.000E-150000000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e180617997",
+        "0x3.0E+150000000#2",
+        10,
+        Nearest,
+        "1.333e-180617998",
+        "0x5.56E-150000001#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        10,
+        Floor,
+        "-2.168e-301029996",
+        "-0x1.000E-250000000#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        10,
+        Ceiling,
+        "-2.166e-301029996",
+        "-0xf.fcE-250000001#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Floor,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        "2.0e-301029996",
+        "0x1.0E-250000000#1",
+        10,
+        Floor,
+        "1.57",
+        "0x1.920#10",
+        Less,
+    );
+    test(
+        "5.0e301029995",
+        "0x1.0E+250000000#1",
+        "2.0e-301029996",
+        "0x1.0E-250000000#1",
+        10,
+        Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Greater,
+    );
+    test(
+        "2.0e-301029996",
+        "0x1.0E-250000000#1",
+        "-5.0e301029995",
+        "-0x1.0E+250000000#1",
+        10,
+        Nearest,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        NearestTiesAway,
+        "0.3218",
+        "0x0.526#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        ToOdd,
+        "0.3218",
+        "0x0.526#10",
+        Greater,
+    );
+}
+
+#[test]
+fn atan2_prec_round_fail() {
+    assert_panic!(Float::ONE.atan2_prec_round(Float::TWO, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_val_ref(&Float::TWO, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_val(Float::TWO, 0, Floor));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_ref(&Float::TWO, 0, Floor));
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_prec_round_assign(Float::TWO, 0, Floor)
+    });
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_prec_round_assign_ref(&Float::TWO, 0, Floor)
+    });
+
+    assert_panic!(Float::ONE.atan2_prec_round(Float::TWO, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_val_ref(&Float::TWO, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_val(Float::TWO, 1, Exact));
+    assert_panic!(Float::ONE.atan2_prec_round_ref_ref(&Float::TWO, 1, Exact));
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_prec_round_assign(Float::TWO, 1, Exact)
+    });
+    assert_panic!({
+        let mut y = Float::ONE;
+        y.atan2_prec_round_assign_ref(&Float::TWO, 1, Exact)
+    });
+}
+
+#[test]
+fn atan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (atan_alt, o_alt) = (-&x).atan_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-atan_alt), ComparableFloat(atan.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        assert!(atan.is_nan() || atan.le_abs(&2u32));
+
+        if atan.is_normal() {
+            assert_eq!(atan.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.atan_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (atan, o) = Float::NAN.atan_prec_round(prec, rm);
+        assert!(atan.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            ComparableFloat(Float::INFINITY.atan_prec_round(prec, rm).0),
+            ComparableFloat(Float::ONE.atan2_prec_round(Float::ZERO, prec, rm).0)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_INFINITY.atan_prec_round(prec, rm).0),
+            ComparableFloat(
+                Float::NEGATIVE_ONE
+                    .atan2_prec_round(Float::ZERO, prec, rm)
+                    .0
+            )
+        );
+        let (atan, o) = Float::ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (atan, o) = Float::NEGATIVE_ZERO.atan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(atan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan_prec_properties_helper(x: Float, prec: u64) {
+    let (atan, o) = x.clone().atan_prec(prec);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan)),
+        ComparableFloatRef(&atan),
+    );
+    assert_eq!(rug_o, o);
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if atan.is_normal() {
+        assert_eq!(atan.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn atan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        atan_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (atan, o) = x.clone().atan_round(rm);
+    assert!(atan.is_valid());
+
+    let (atan_alt, o_alt) = x.atan_round_ref(rm);
+    assert!(atan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.atan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    let (atan_alt, o_alt) = x.atan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn atan_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        atan_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn atan2_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(|(y, x, prec, rm)| {
+        let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&x),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (atan2_alt, o_alt) = (-&y).atan2_prec_round_ref_ref(&x, prec, -rm);
+        assert_eq!(ComparableFloat(-atan2_alt), ComparableFloat(atan2.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        if x > 0u32 && y.is_finite() {
+            assert!(atan2.le_abs(&2u32));
+        }
+        if atan2.is_normal() {
+            assert_eq!(atan2.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(y.atan2_prec_round_ref_ref(&x, prec, Exact));
+        }
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (atan2, o) = Float::NAN.atan2_prec_round_ref_ref(&x, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+
+        let (atan2, o) = x.atan2_prec_round_ref_ref(&Float::NAN, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Equal);
+
+        if x.is_finite() && x != 0u32 {
+            let (atan2, o) = x.atan2_prec_round_ref_ref(&Float::INFINITY, prec, rm);
+            assert_eq!(
+                ComparableFloat(atan2),
+                ComparableFloat(if x > 0u32 {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                })
+            );
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[test]
+fn atan2_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(y, x, prec)| {
+        let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_assign(x.clone(), prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_assign_ref(&x, prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2),
+        );
+        assert_eq!(rug_o, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, Nearest);
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if atan2.is_normal() {
+            assert_eq!(atan2.get_prec(), Some(prec));
+        }
+    });
+}
+
+#[test]
+fn atan2_round_properties() {
+    float_float_rounding_mode_triple_gen_var_29().test_properties(|(y, x, rm)| {
+        let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_round_assign(x.clone(), rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_round_assign_ref(&x, rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) =
+            y.atan2_prec_round_ref_ref(&x, max(y.significant_bits(), x.significant_bits()), rm);
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2),
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
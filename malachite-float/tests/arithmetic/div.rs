@@ -2755,6 +2755,17 @@ fn test_div_round() {
     test("NaN", "NaN", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2772,6 +2783,19 @@ fn test_div_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2826,6 +2850,26 @@ fn test_div_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -2842,6 +2886,17 @@ fn test_div_round() {
     test("NaN", "NaN", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
@@ -2849,6 +2904,17 @@ fn test_div_round() {
     test("NaN", "NaN", "-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "1.0", "0x1.0#1", Floor, "NaN", "NaN", Equal);
@@ -2856,6 +2922,17 @@ fn test_div_round() {
     test("NaN", "NaN", "1.0", "0x1.0#1", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "1.0", "0x1.0#1", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
@@ -2867,6 +2944,17 @@ fn test_div_round() {
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2884,6 +2972,19 @@ fn test_div_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -2903,6 +3004,19 @@ fn test_div_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2957,6 +3071,26 @@ fn test_div_round() {
         "NaN",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -2983,6 +3117,19 @@ fn test_div_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -3037,6 +3184,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -3063,6 +3230,19 @@ fn test_div_round() {
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", Exact, "Infinity", "Infinity", Equal,
     );
@@ -3117,6 +3297,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -3178,6 +3378,26 @@ fn test_div_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3239,6 +3459,26 @@ fn test_div_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3300,6 +3540,26 @@ fn test_div_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3361,6 +3621,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3427,26 +3707,46 @@ fn test_div_round() {
         "-Infinity",
         "-0.0",
         "-0x0.0",
-        Exact,
+        NearestTiesAway,
         "Infinity",
         "Infinity",
         Equal,
     );
-
     test(
         "-Infinity",
         "-Infinity",
-        "1.0",
-        "0x1.0#1",
-        Floor,
-        "-Infinity",
-        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "Infinity",
+        "Infinity",
         Equal,
     );
     test(
         "-Infinity",
         "-Infinity",
-        "1.0",
+        "-0.0",
+        "-0x0.0",
+        Exact,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
         "0x1.0#1",
         Ceiling,
         "-Infinity",
@@ -3483,6 +3783,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3544,6 +3864,26 @@ fn test_div_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3560,6 +3900,17 @@ fn test_div_round() {
     test("0.0", "0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3577,6 +3928,19 @@ fn test_div_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3631,6 +3995,26 @@ fn test_div_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -3647,6 +4031,17 @@ fn test_div_round() {
     test("0.0", "0x0.0", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("0.0", "0x0.0", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
@@ -3658,6 +4053,17 @@ fn test_div_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3673,6 +4079,19 @@ fn test_div_round() {
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3692,6 +4111,19 @@ fn test_div_round() {
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -3701,6 +4133,17 @@ fn test_div_round() {
     test("-0.0", "-0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3718,6 +4161,19 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -3772,6 +4228,26 @@ fn test_div_round() {
         "0x0.0",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -3792,6 +4268,17 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3807,6 +4294,19 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
     );
@@ -3826,6 +4326,19 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -3845,6 +4358,19 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3860,6 +4386,19 @@ fn test_div_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -3879,6 +4418,19 @@ fn test_div_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3933,6 +4485,26 @@ fn test_div_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -3959,6 +4531,19 @@ fn test_div_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -4013,6 +4598,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -4039,6 +4644,19 @@ fn test_div_round() {
     test(
         "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -4093,6 +4711,26 @@ fn test_div_round() {
         "-0x7b.0#7",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -4115,6 +4753,19 @@ fn test_div_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
     );
@@ -4134,6 +4785,19 @@ fn test_div_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Exact, "Infinity", "Infinity", Equal,
     );
@@ -4188,6 +4852,26 @@ fn test_div_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -4214,6 +4898,19 @@ fn test_div_round() {
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Exact, "0.0", "0x0.0", Equal,
     );
@@ -4233,6 +4930,19 @@ fn test_div_round() {
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -4287,6 +4997,26 @@ fn test_div_round() {
         "0x0.0218#7",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0082",
+        "0x0.0218#7",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "0.0082",
+        "0x0.0218#7",
+        Greater,
+    );
 
     test(
         "-1.0",
@@ -4338,6 +5068,26 @@ fn test_div_round() {
         "-0x0.0218#7",
         Less,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-0.0082",
+        "-0x0.0218#7",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "-0.0082",
+        "-0x0.0218#7",
+        Less,
+    );
 
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Equal,
@@ -4354,6 +5104,19 @@ fn test_div_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Nearest, "0.5", "0x0.8#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", ToOdd, "0.5", "0x0.8#1", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Exact, "0.5", "0x0.8#1", Equal,
     );
@@ -4408,6 +5171,26 @@ fn test_div_round() {
         "0x0.733d90a6f99888#53",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "0.45015815807855308",
+        "0x0.733d90a6f99888#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "0.45015815807855303",
+        "0x0.733d90a6f99884#53",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -4459,6 +5242,26 @@ fn test_div_round() {
         "-0x0.733d90a6f99888#53",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-0.45015815807855308",
+        "-0x0.733d90a6f99888#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "-0.45015815807855303",
+        "-0x0.733d90a6f99884#53",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -4510,6 +5313,26 @@ fn test_div_round() {
         "-0x0.733d90a6f99888#53",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-0.45015815807855308",
+        "-0x0.733d90a6f99888#53",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "-0.45015815807855303",
+        "-0x0.733d90a6f99884#53",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -4561,6 +5384,26 @@ fn test_div_round() {
         "0x0.733d90a6f99888#53",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "0.45015815807855308",
+        "0x0.733d90a6f99888#53",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "0.45015815807855303",
+        "0x0.733d90a6f99884#53",
+        Less,
+    );
 
     // - rm == Floor || rm == Down in div_float_significands_same_prec_lt_w
     test(
@@ -4889,6 +5732,19 @@ fn test_div_round() {
     test(
         "1.0", "0x1.0#2", "1.5", "0x1.8#2", Nearest, "0.8", "0x0.c#2", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "1.5",
+        "0x1.8#2",
+        NearestTiesAway,
+        "0.8",
+        "0x0.c#2",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#2", "1.5", "0x1.8#2", ToOdd, "0.8", "0x0.c#2", Greater,
+    );
     // - h != 0 || l >= y in div_float_significands_same_prec_lt_w
     test(
         "1.5", "0x1.8#2", "1.0", "0x1.0#2", Nearest, "1.5", "0x1.8#2", Equal,
@@ -4929,16 +5785,36 @@ fn test_div_round() {
         "0x0.fffffffffffffffe#64",
         Less,
     );
-    // - hi != 0 || lo >= y in div_float_significands_same_prec_w
     test(
-        "1.0000000000000000001",
-        "0x1.0000000000000002#64",
         "1.0",
         "0x1.0000000000000000#64",
-        Nearest,
         "1.0000000000000000001",
         "0x1.0000000000000002#64",
-        Equal,
+        NearestTiesAway,
+        "0.99999999999999999989",
+        "0x0.fffffffffffffffe#64",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000#64",
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        ToOdd,
+        "0.99999999999999999995",
+        "0x0.ffffffffffffffff#64",
+        Greater,
+    );
+    // - hi != 0 || lo >= y in div_float_significands_same_prec_w
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        "1.0",
+        "0x1.0000000000000000#64",
+        Nearest,
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        Equal,
     );
     // - rm == Nearest || round_bit != 0 && (sticky_bit != 0 || (quotient & 1) != 0) in
     //   div_float_significands_same_prec_w
@@ -5000,6 +5876,26 @@ fn test_div_round() {
         "0x0.ffffffffffffffff0#65",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000#65",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        NearestTiesAway,
+        "0.99999999999999999995",
+        "0x0.ffffffffffffffff0#65",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000#65",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        ToOdd,
+        "0.99999999999999999997",
+        "0x0.ffffffffffffffff8#65",
+        Greater,
+    );
     // - r_1 != 0 in div_float_2_approx
     test(
         "1.0",
@@ -5061,6 +5957,19 @@ fn test_div_round() {
     test(
         "1.0", "0x1.0#1", "1.5", "0x1.8#2", Nearest, "0.8", "0x0.c#2", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.5",
+        "0x1.8#2",
+        NearestTiesAway,
+        "0.8",
+        "0x0.c#2",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.5", "0x1.8#2", ToOdd, "0.8", "0x0.c#2", Greater,
+    );
     // - rm == Nearest && (round_bit == 0 || (sticky_bit == 0 && (ys[0] & shift_bit) == 0)) in
     //   div_float_significands_long_by_short
     test(
@@ -5151,6 +6060,26 @@ fn test_div_round() {
         "0x0.ffffffffffffffff0#65",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        NearestTiesAway,
+        "0.99999999999999999995",
+        "0x0.ffffffffffffffff0#65",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        ToOdd,
+        "0.99999999999999999997",
+        "0x0.ffffffffffffffff8#65",
+        Greater,
+    );
     // - up[u_size - 1] != vp[vsize - 1] in div_float_significands_general
     test(
         "1.0",
@@ -5925,6 +6854,18 @@ fn test_div_prec_round() {
     test("NaN", "NaN", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -5942,6 +6883,20 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6001,6 +6956,28 @@ fn test_div_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -6022,6 +6999,18 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -6035,6 +7024,20 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6050,6 +7053,20 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "1.0", "0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "1.0", "0x1.0#1", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6067,6 +7084,20 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6086,6 +7117,20 @@ fn test_div_prec_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6105,6 +7150,20 @@ fn test_div_prec_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6164,6 +7223,28 @@ fn test_div_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -6191,6 +7272,20 @@ fn test_div_prec_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -6250,6 +7345,28 @@ fn test_div_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -6277,6 +7394,20 @@ fn test_div_prec_round() {
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -6342,26 +7473,48 @@ fn test_div_prec_round() {
         "-1.0",
         "-0x1.0#1",
         1,
-        Exact,
+        NearestTiesAway,
         "-Infinity",
         "-Infinity",
         Equal,
     );
-
     test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        ToOdd,
         "-Infinity",
         "-Infinity",
-        "NaN",
-        "NaN",
-        1,
-        Floor,
-        "NaN",
-        "NaN",
         Equal,
     );
     test(
-        "-Infinity",
-        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
         "NaN",
         "NaN",
         1,
@@ -6403,6 +7556,28 @@ fn test_div_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6470,6 +7645,28 @@ fn test_div_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6537,6 +7734,28 @@ fn test_div_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6604,6 +7823,28 @@ fn test_div_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6671,6 +7912,28 @@ fn test_div_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6738,6 +8001,28 @@ fn test_div_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6805,6 +8090,28 @@ fn test_div_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -6826,6 +8133,18 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -6843,6 +8162,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -6902,6 +8235,28 @@ fn test_div_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -6925,6 +8280,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6942,6 +8311,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6961,6 +8344,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -6980,6 +8377,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -6995,6 +8406,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7014,6 +8439,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -7073,6 +8512,28 @@ fn test_div_prec_round() {
         "0x0.0",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -7098,6 +8559,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7117,6 +8592,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7136,6 +8625,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -7155,6 +8658,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7174,6 +8691,20 @@ fn test_div_prec_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7193,6 +8724,20 @@ fn test_div_prec_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7252,6 +8797,28 @@ fn test_div_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -7279,6 +8846,20 @@ fn test_div_prec_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -7338,6 +8919,28 @@ fn test_div_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -7378,41 +8981,85 @@ fn test_div_prec_round() {
         "1.0",
         "0x1.0#1",
         1,
-        Down,
+        Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        Nearest,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        ToOdd,
         "6.0e1",
         "0x4.0E+1#1",
         Less,
     );
+
     test(
         "123.0",
         "0x7b.0#7",
         "1.0",
         "0x1.0#1",
-        1,
-        Up,
-        "1.0e2",
-        "0x8.0E+1#1",
-        Greater,
+        10,
+        Floor,
+        "123.0",
+        "0x7b.0#10",
+        Equal,
     );
     test(
         "123.0",
         "0x7b.0#7",
         "1.0",
         "0x1.0#1",
-        1,
-        Nearest,
-        "1.0e2",
-        "0x8.0E+1#1",
-        Greater,
+        10,
+        Ceiling,
+        "123.0",
+        "0x7b.0#10",
+        Equal,
     );
-
     test(
         "123.0",
         "0x7b.0#7",
         "1.0",
         "0x1.0#1",
         10,
-        Floor,
+        Down,
         "123.0",
         "0x7b.0#10",
         Equal,
@@ -7423,7 +9070,7 @@ fn test_div_prec_round() {
         "1.0",
         "0x1.0#1",
         10,
-        Ceiling,
+        Up,
         "123.0",
         "0x7b.0#10",
         Equal,
@@ -7434,7 +9081,7 @@ fn test_div_prec_round() {
         "1.0",
         "0x1.0#1",
         10,
-        Down,
+        Nearest,
         "123.0",
         "0x7b.0#10",
         Equal,
@@ -7445,7 +9092,7 @@ fn test_div_prec_round() {
         "1.0",
         "0x1.0#1",
         10,
-        Up,
+        NearestTiesAway,
         "123.0",
         "0x7b.0#10",
         Equal,
@@ -7456,7 +9103,7 @@ fn test_div_prec_round() {
         "1.0",
         "0x1.0#1",
         10,
-        Nearest,
+        ToOdd,
         "123.0",
         "0x7b.0#10",
         Equal,
@@ -7528,6 +9175,28 @@ fn test_div_prec_round() {
         "-0x8.0E+1#1",
         Less,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        ToOdd,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Greater,
+    );
 
     test(
         "123.0",
@@ -7584,6 +9253,28 @@ fn test_div_prec_round() {
         "-0x7b.0#10",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        NearestTiesAway,
+        "-123.0",
+        "-0x7b.0#10",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        ToOdd,
+        "-123.0",
+        "-0x7b.0#10",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -7611,6 +9302,20 @@ fn test_div_prec_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7630,6 +9335,20 @@ fn test_div_prec_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -7689,6 +9408,28 @@ fn test_div_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7716,6 +9457,20 @@ fn test_div_prec_round() {
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7735,6 +9490,20 @@ fn test_div_prec_round() {
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -7754,6 +9523,20 @@ fn test_div_prec_round() {
     test(
         "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, Nearest, "0.008", "0x0.02#1", Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.008",
+        "0x0.02#1",
+        Less,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", 1, ToOdd, "0.008", "0x0.02#1", Less,
+    );
 
     test(
         "1.0",
@@ -7810,6 +9593,28 @@ fn test_div_prec_round() {
         "0x0.0215#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
 
     test(
         "-1.0",
@@ -7866,6 +9671,28 @@ fn test_div_prec_round() {
         "-0x0.02#1",
         Greater,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-0.008",
+        "-0x0.02#1",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "-0.008",
+        "-0x0.02#1",
+        Greater,
+    );
 
     test(
         "-1.0",
@@ -7922,6 +9749,28 @@ fn test_div_prec_round() {
         "-0x0.0215#10",
         Less,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "-0.00813",
+        "-0x0.0215#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "-0.00813",
+        "-0x0.0215#10",
+        Less,
+    );
 
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Floor, "0.5", "0x0.8#1", Equal,
@@ -7938,6 +9787,20 @@ fn test_div_prec_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Nearest, "0.5", "0x0.8#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, ToOdd, "0.5", "0x0.8#1", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Exact, "0.5", "0x0.8#1", Equal,
     );
@@ -7997,6 +9860,28 @@ fn test_div_prec_round() {
         "0x0.800#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        ToOdd,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -8064,6 +9949,28 @@ fn test_div_prec_round() {
         "0x0.8#1",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -8120,6 +10027,28 @@ fn test_div_prec_round() {
         "0x0.734#10",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "0.4502",
+        "0x0.734#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "0.4497",
+        "0x0.732#10",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -8232,6 +10161,28 @@ fn test_div_prec_round() {
         "-0x0.734#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-0.4502",
+        "-0x0.734#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-0.4497",
+        "-0x0.732#10",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -8288,6 +10239,28 @@ fn test_div_prec_round() {
         "-0x0.8#1",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "-0.5",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "-0.2",
+        "-0x0.4#1",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -8344,6 +10317,28 @@ fn test_div_prec_round() {
         "-0x0.734#10",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-0.4502",
+        "-0x0.734#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-0.4497",
+        "-0x0.732#10",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -8400,6 +10395,28 @@ fn test_div_prec_round() {
         "0x0.8#1",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -8456,6 +10473,28 @@ fn test_div_prec_round() {
         "0x0.734#10",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "0.4502",
+        "0x0.734#10",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "0.4497",
+        "0x0.732#10",
+        Less,
+    );
 
     // - (rm == Ceiling || rm == Up) && overflow in div_float_significands_long_by_short
     test(
@@ -9169,6 +11208,8 @@ fn test_div_rational_round() {
     test("NaN", "NaN", "123", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -9186,6 +11227,18 @@ fn test_div_rational_round() {
     test(
         "Infinity", "Infinity", "123", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123", Exact, "Infinity", "Infinity", Equal,
     );
@@ -9235,6 +11288,24 @@ fn test_div_rational_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -9250,6 +11321,8 @@ fn test_div_rational_round() {
     test("NaN", "NaN", "0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -9267,6 +11340,18 @@ fn test_div_rational_round() {
     test(
         "Infinity", "Infinity", "0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -9316,6 +11401,24 @@ fn test_div_rational_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -9331,6 +11434,8 @@ fn test_div_rational_round() {
     test("0.0", "0x0.0", "0", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "0", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", Exact, "NaN", "NaN", Equal);
 
     test("-0.0", "-0x0.0", "0", Floor, "NaN", "NaN", Equal);
@@ -9338,6 +11443,8 @@ fn test_div_rational_round() {
     test("-0.0", "-0x0.0", "0", Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", Nearest, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("-0.0", "-0x0.0", "0", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", Exact, "NaN", "NaN", Equal);
 
     test("0.0", "0x0.0", "123", Floor, "0.0", "0x0.0", Equal);
@@ -9345,6 +11452,16 @@ fn test_div_rational_round() {
     test("0.0", "0x0.0", "123", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "123", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "123", Floor, "-0.0", "-0x0.0", Equal);
@@ -9352,6 +11469,16 @@ fn test_div_rational_round() {
     test("-0.0", "-0x0.0", "123", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "123", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "-123", Floor, "-0.0", "-0x0.0", Equal);
@@ -9359,6 +11486,16 @@ fn test_div_rational_round() {
     test("0.0", "0x0.0", "-123", Down, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Up, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "-123", ToOdd, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Exact, "-0.0", "-0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "-123", Floor, "0.0", "0x0.0", Equal);
@@ -9366,6 +11503,16 @@ fn test_div_rational_round() {
     test("-0.0", "-0x0.0", "-123", Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "-123", ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Exact, "0.0", "0x0.0", Equal);
 
     test("0.0", "0x0.0", "1/3", Floor, "0.0", "0x0.0", Equal);
@@ -9373,6 +11520,16 @@ fn test_div_rational_round() {
     test("0.0", "0x0.0", "1/3", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "1/3", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "1/3", Floor, "-0.0", "-0x0.0", Equal);
@@ -9380,6 +11537,16 @@ fn test_div_rational_round() {
     test("-0.0", "-0x0.0", "1/3", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "1/3", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Exact, "-0.0", "-0x0.0", Equal);
 
     test("123.0", "0x7b.0#7", "1", Floor, "123.0", "0x7b.0#7", Equal);
@@ -9391,6 +11558,16 @@ fn test_div_rational_round() {
     test(
         "123.0", "0x7b.0#7", "1", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "1", ToOdd, "123.0", "0x7b.0#7", Equal);
     test("123.0", "0x7b.0#7", "1", Exact, "123.0", "0x7b.0#7", Equal);
 
     test(
@@ -9406,15 +11583,45 @@ fn test_div_rational_round() {
     test(
         "123.0", "0x7b.0#7", "0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0", Exact, "Infinity", "Infinity", Equal,
     );
-
+
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-123.0",
         "-0x7b.0#7",
         "0",
-        Floor,
+        Down,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -9423,7 +11630,7 @@ fn test_div_rational_round() {
         "-123.0",
         "-0x7b.0#7",
         "0",
-        Ceiling,
+        Up,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -9432,7 +11639,7 @@ fn test_div_rational_round() {
         "-123.0",
         "-0x7b.0#7",
         "0",
-        Down,
+        Nearest,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -9441,7 +11648,7 @@ fn test_div_rational_round() {
         "-123.0",
         "-0x7b.0#7",
         "0",
-        Up,
+        NearestTiesAway,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -9450,7 +11657,7 @@ fn test_div_rational_round() {
         "-123.0",
         "-0x7b.0#7",
         "0",
-        Nearest,
+        ToOdd,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -9470,6 +11677,16 @@ fn test_div_rational_round() {
     test("1.0", "0x1.0#1", "2", Down, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", Up, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", Nearest, "0.5", "0x0.8#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", ToOdd, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", Exact, "0.5", "0x0.8#1", Equal);
 
     test(
@@ -9517,6 +11734,24 @@ fn test_div_rational_round() {
         "0x0.aac#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "3/2",
+        NearestTiesAway,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "3/2",
+        ToOdd,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -9563,12 +11798,40 @@ fn test_div_rational_round() {
         "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "3/2",
+        NearestTiesAway,
+        "0.666666666666666666666666666667",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "3/2",
+        ToOdd,
+        "0.666666666666666666666666666667",
+        "0x0.aaaaaaaaaaaaaaaaaaaaaaaab#100",
+        Greater,
+    );
 
     test("3.0", "0x3.0#2", "2", Floor, "1.5", "0x1.8#2", Equal);
     test("3.0", "0x3.0#2", "2", Ceiling, "1.5", "0x1.8#2", Equal);
     test("3.0", "0x3.0#2", "2", Down, "1.5", "0x1.8#2", Equal);
     test("3.0", "0x3.0#2", "2", Up, "1.5", "0x1.8#2", Equal);
     test("3.0", "0x3.0#2", "2", Nearest, "1.5", "0x1.8#2", Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2",
+        NearestTiesAway,
+        "1.5",
+        "0x1.8#2",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", "2", ToOdd, "1.5", "0x1.8#2", Equal);
     test("3.0", "0x3.0#2", "2", Exact, "1.5", "0x1.8#2", Equal);
 
     test("3.0", "0x3.00#10", "3/2", Floor, "2.0", "0x2.00#10", Equal);
@@ -9592,6 +11855,16 @@ fn test_div_rational_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "3/2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("3.0", "0x3.00#10", "3/2", ToOdd, "2.0", "0x2.00#10", Equal);
     test("3.0", "0x3.00#10", "3/2", Exact, "2.0", "0x2.00#10", Equal);
 
     test(
@@ -9639,6 +11912,24 @@ fn test_div_rational_round() {
         "0x2.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "3/2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "3/2",
+        ToOdd,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.0000000000000000000000000#100",
@@ -9694,6 +11985,24 @@ fn test_div_rational_round() {
         "0x2.182a4705ae6ca#53",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        NearestTiesAway,
+        "2.0943951023931953",
+        "0x2.182a4705ae6ca#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        ToOdd,
+        "2.0943951023931953",
+        "0x2.182a4705ae6ca#53",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -9740,6 +12049,24 @@ fn test_div_rational_round() {
         "-0x2.182a4705ae6ca#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        NearestTiesAway,
+        "-2.0943951023931953",
+        "-0x2.182a4705ae6ca#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        ToOdd,
+        "-2.0943951023931953",
+        "-0x2.182a4705ae6ca#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -9786,6 +12113,24 @@ fn test_div_rational_round() {
         "-0x2.182a4705ae6ca#53",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        NearestTiesAway,
+        "-2.0943951023931953",
+        "-0x2.182a4705ae6ca#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        ToOdd,
+        "-2.0943951023931953",
+        "-0x2.182a4705ae6ca#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -9982,6 +12327,8 @@ fn test_div_rational_prec_round() {
     test("NaN", "NaN", "123", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -9999,6 +12346,19 @@ fn test_div_rational_prec_round() {
     test(
         "Infinity", "Infinity", "123", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -10053,6 +12413,26 @@ fn test_div_rational_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -10069,6 +12449,8 @@ fn test_div_rational_prec_round() {
     test("NaN", "NaN", "0", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -10086,6 +12468,19 @@ fn test_div_rational_prec_round() {
     test(
         "Infinity", "Infinity", "0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -10140,6 +12535,26 @@ fn test_div_rational_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -10156,6 +12571,8 @@ fn test_div_rational_prec_round() {
     test("0.0", "0x0.0", "0", 1, Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", 1, Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "0", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test("-0.0", "-0x0.0", "0", 1, Floor, "NaN", "NaN", Equal);
@@ -10163,6 +12580,17 @@ fn test_div_rational_prec_round() {
     test("-0.0", "-0x0.0", "0", 1, Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", 1, Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test("0.0", "0x0.0", "123", 1, Floor, "0.0", "0x0.0", Equal);
@@ -10170,6 +12598,17 @@ fn test_div_rational_prec_round() {
     test("0.0", "0x0.0", "123", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "123", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "123", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -10177,6 +12616,17 @@ fn test_div_rational_prec_round() {
     test("-0.0", "-0x0.0", "123", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "123", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "-123", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -10184,6 +12634,17 @@ fn test_div_rational_prec_round() {
     test("0.0", "0x0.0", "-123", 1, Down, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Up, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "-123", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "-123", 1, Floor, "0.0", "0x0.0", Equal);
@@ -10191,6 +12652,17 @@ fn test_div_rational_prec_round() {
     test("-0.0", "-0x0.0", "-123", 1, Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "-123", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("0.0", "0x0.0", "1/3", 1, Floor, "0.0", "0x0.0", Equal);
@@ -10198,6 +12670,17 @@ fn test_div_rational_prec_round() {
     test("0.0", "0x0.0", "1/3", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "1/3", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "1/3", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -10205,6 +12688,17 @@ fn test_div_rational_prec_round() {
     test("-0.0", "-0x0.0", "1/3", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "1/3", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test(
@@ -10257,6 +12751,26 @@ fn test_div_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "123.0", "0x7b.0#7", "0", 1, Floor, "Infinity", "Infinity", Equal,
@@ -10273,6 +12787,19 @@ fn test_div_rational_prec_round() {
     test(
         "123.0", "0x7b.0#7", "0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -10327,6 +12854,26 @@ fn test_div_rational_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-123.0",
         "-0x7b.0#7",
@@ -10343,6 +12890,17 @@ fn test_div_rational_prec_round() {
     test("1.0", "0x1.0#1", "2", 1, Down, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Up, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Nearest, "0.5", "0x0.8#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", 1, ToOdd, "0.5", "0x0.8#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Exact, "0.5", "0x0.8#1", Equal);
 
     test("1.0", "0x1.0#1", "2", 10, Floor, "0.5", "0x0.800#10", Equal);
@@ -10368,6 +12926,17 @@ fn test_div_rational_prec_round() {
         "0x0.800#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        10,
+        NearestTiesAway,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", 10, ToOdd, "0.5", "0x0.800#10", Equal);
     test("1.0", "0x1.0#1", "2", 10, Exact, "0.5", "0x0.800#10", Equal);
 
     test("1.0", "0x1.000#10", "3/2", 1, Floor, "0.5", "0x0.8#1", Less);
@@ -10393,6 +12962,17 @@ fn test_div_rational_prec_round() {
         "0x0.8#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "3/2",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test("1.0", "0x1.000#10", "3/2", 1, ToOdd, "0.5", "0x0.8#1", Less);
 
     test(
         "1.0",
@@ -10444,12 +13024,43 @@ fn test_div_rational_prec_round() {
         "0x0.aac#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "3/2",
+        10,
+        NearestTiesAway,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "3/2",
+        10,
+        ToOdd,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
 
     test("3.0", "0x3.0#2", "2", 1, Floor, "1.0", "0x1.0#1", Less);
     test("3.0", "0x3.0#2", "2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
     test("3.0", "0x3.0#2", "2", 1, Down, "1.0", "0x1.0#1", Less);
     test("3.0", "0x3.0#2", "2", 1, Up, "2.0", "0x2.0#1", Greater);
     test("3.0", "0x3.0#2", "2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "2", 1, ToOdd, "1.0", "0x1.0#1", Less);
 
     test("3.0", "0x3.00#10", "3/2", 1, Floor, "2.0", "0x2.0#1", Equal);
     test(
@@ -10474,6 +13085,17 @@ fn test_div_rational_prec_round() {
         "0x2.0#1",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "3/2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("3.0", "0x3.00#10", "3/2", 1, ToOdd, "2.0", "0x2.0#1", Equal);
     test("3.0", "0x3.00#10", "3/2", 1, Exact, "2.0", "0x2.0#1", Equal);
 
     test(
@@ -10526,6 +13148,26 @@ fn test_div_rational_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "3/2",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "3/2",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.0000000000000000000000000#100",
@@ -10587,6 +13229,26 @@ fn test_div_rational_prec_round() {
         "0x2.0#1",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -10638,6 +13300,26 @@ fn test_div_rational_prec_round() {
         "0x2.18#10",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        10,
+        NearestTiesAway,
+        "2.094",
+        "0x2.18#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3/2",
+        10,
+        ToOdd,
+        "2.098",
+        "0x2.19#10",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -10689,6 +13371,26 @@ fn test_div_rational_prec_round() {
         "-0x2.0#1",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        1,
+        NearestTiesAway,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        1,
+        ToOdd,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -10740,6 +13442,26 @@ fn test_div_rational_prec_round() {
         "-0x2.18#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        10,
+        NearestTiesAway,
+        "-2.094",
+        "-0x2.18#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3/2",
+        10,
+        ToOdd,
+        "-2.098",
+        "-0x2.19#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10791,6 +13513,26 @@ fn test_div_rational_prec_round() {
         "-0x2.0#1",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        1,
+        NearestTiesAway,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        1,
+        ToOdd,
+        "-2.0",
+        "-0x2.0#1",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10842,13 +13584,53 @@ fn test_div_rational_prec_round() {
         "-0x2.18#10",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        10,
+        NearestTiesAway,
+        "-2.094",
+        "-0x2.18#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3/2",
+        10,
+        ToOdd,
+        "-2.098",
+        "-0x2.19#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         "-3/2",
         1,
-        Floor,
+        Floor,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-3/2",
+        1,
+        Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-3/2",
+        1,
+        Down,
         "2.0",
         "0x2.0#1",
         Less,
@@ -10858,7 +13640,7 @@ fn test_div_rational_prec_round() {
         "-0x3.243f6a8885a30#53",
         "-3/2",
         1,
-        Ceiling,
+        Up,
         "4.0",
         "0x4.0#1",
         Greater,
@@ -10868,7 +13650,7 @@ fn test_div_rational_prec_round() {
         "-0x3.243f6a8885a30#53",
         "-3/2",
         1,
-        Down,
+        Nearest,
         "2.0",
         "0x2.0#1",
         Less,
@@ -10878,17 +13660,17 @@ fn test_div_rational_prec_round() {
         "-0x3.243f6a8885a30#53",
         "-3/2",
         1,
-        Up,
-        "4.0",
-        "0x4.0#1",
-        Greater,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Less,
     );
     test(
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
         "-3/2",
         1,
-        Nearest,
+        ToOdd,
         "2.0",
         "0x2.0#1",
         Less,
@@ -11363,6 +14145,8 @@ fn test_rational_div_float_round() {
     test("123", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("123", "NaN", "NaN", NearestTiesAway, "NaN", "NaN", Equal);
+    test("123", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test("123", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
@@ -11374,6 +14158,16 @@ fn test_rational_div_float_round() {
     test(
         "123", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("123", "Infinity", "Infinity", ToOdd, "0.0", "0x0.0", Equal);
     test("123", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -11413,6 +14207,24 @@ fn test_rational_div_float_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "123",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "123",
         "-Infinity",
@@ -11428,6 +14240,8 @@ fn test_rational_div_float_round() {
     test("0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("0", "NaN", "NaN", NearestTiesAway, "NaN", "NaN", Equal);
+    test("0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test("0", "Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
@@ -11435,6 +14249,16 @@ fn test_rational_div_float_round() {
     test("0", "Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
     test("0", "Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
     test("0", "Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0", "Infinity", "Infinity", ToOdd, "0.0", "0x0.0", Equal);
     test("0", "Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -11466,6 +14290,24 @@ fn test_rational_div_float_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "0",
         "-Infinity",
@@ -11481,6 +14323,8 @@ fn test_rational_div_float_round() {
     test("0", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("0", "0.0", "0x0.0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("0", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("0", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
@@ -11488,6 +14332,8 @@ fn test_rational_div_float_round() {
     test("0", "-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test("0", "-0.0", "-0x0.0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("0", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("123", "0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
@@ -11499,6 +14345,16 @@ fn test_rational_div_float_round() {
     test(
         "123", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("123", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal);
     test("123", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
 
     test(
@@ -11538,6 +14394,24 @@ fn test_rational_div_float_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123",
         "-0.0",
@@ -11585,6 +14459,24 @@ fn test_rational_div_float_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-123",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-123",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-123",
         "0.0",
@@ -11608,6 +14500,18 @@ fn test_rational_div_float_round() {
     test(
         "-123", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-123",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-123", "-0.0", "-0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-123", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -11621,6 +14525,16 @@ fn test_rational_div_float_round() {
     test(
         "1/3", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("1/3", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal);
     test("1/3", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
 
     test(
@@ -11660,6 +14574,24 @@ fn test_rational_div_float_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "1/3",
         "-0.0",
@@ -11707,6 +14639,24 @@ fn test_rational_div_float_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-1/3",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1/3",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-1/3",
         "0.0",
@@ -11730,6 +14680,18 @@ fn test_rational_div_float_round() {
     test(
         "-1/3", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-1/3",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-1/3", "-0.0", "-0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-1/3", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -11771,12 +14733,40 @@ fn test_rational_div_float_round() {
         "0x0.0218#7",
         Greater,
     );
+    test(
+        "1",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0082",
+        "0x0.0218#7",
+        Greater,
+    );
+    test(
+        "1",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "0.0082",
+        "0x0.0218#7",
+        Greater,
+    );
 
     test("0", "123.0", "0x7b.0#7", Floor, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", Ceiling, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", Down, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", Up, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0", "123.0", "0x7b.0#7", ToOdd, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", Exact, "0.0", "0x0.0", Equal);
 
     test("0", "-123.0", "-0x7b.0#7", Floor, "-0.0", "-0x0.0", Equal);
@@ -11784,6 +14774,16 @@ fn test_rational_div_float_round() {
     test("0", "-123.0", "-0x7b.0#7", Down, "-0.0", "-0x0.0", Equal);
     test("0", "-123.0", "-0x7b.0#7", Up, "-0.0", "-0x0.0", Equal);
     test("0", "-123.0", "-0x7b.0#7", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "0",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0", "-123.0", "-0x7b.0#7", ToOdd, "-0.0", "-0x0.0", Equal);
     test("0", "-123.0", "-0x7b.0#7", Exact, "-0.0", "-0x0.0", Equal);
 
     test("2", "1.0", "0x1.0#1", Floor, "2.0", "0x2.0#1", Equal);
@@ -11791,6 +14791,16 @@ fn test_rational_div_float_round() {
     test("2", "1.0", "0x1.0#1", Down, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "2",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("2", "1.0", "0x1.0#1", ToOdd, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", Exact, "2.0", "0x2.0#1", Equal);
 
     test("2", "1.0", "0x1.0#2", Floor, "2.0", "0x2.0#2", Equal);
@@ -11798,6 +14808,16 @@ fn test_rational_div_float_round() {
     test("2", "1.0", "0x1.0#2", Down, "2.0", "0x2.0#2", Equal);
     test("2", "1.0", "0x1.0#2", Up, "2.0", "0x2.0#2", Equal);
     test("2", "1.0", "0x1.0#2", Nearest, "2.0", "0x2.0#2", Equal);
+    test(
+        "2",
+        "1.0",
+        "0x1.0#2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Equal,
+    );
+    test("2", "1.0", "0x1.0#2", ToOdd, "2.0", "0x2.0#2", Equal);
     test("2", "1.0", "0x1.0#2", Exact, "2.0", "0x2.0#2", Equal);
 
     test("2", "1.0", "0x1.000#10", Floor, "2.0", "0x2.00#10", Equal);
@@ -11805,6 +14825,16 @@ fn test_rational_div_float_round() {
     test("2", "1.0", "0x1.000#10", Down, "2.0", "0x2.00#10", Equal);
     test("2", "1.0", "0x1.000#10", Up, "2.0", "0x2.00#10", Equal);
     test("2", "1.0", "0x1.000#10", Nearest, "2.0", "0x2.00#10", Equal);
+    test(
+        "2",
+        "1.0",
+        "0x1.000#10",
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("2", "1.0", "0x1.000#10", ToOdd, "2.0", "0x2.00#10", Equal);
     test("2", "1.0", "0x1.000#10", Exact, "2.0", "0x2.00#10", Equal);
 
     test(
@@ -11836,6 +14866,24 @@ fn test_rational_div_float_round() {
         "0x1.800#10",
         Equal,
     );
+    test(
+        "3/2",
+        "1.0",
+        "0x1.000#10",
+        NearestTiesAway,
+        "1.5",
+        "0x1.800#10",
+        Equal,
+    );
+    test(
+        "3/2",
+        "1.0",
+        "0x1.000#10",
+        ToOdd,
+        "1.5",
+        "0x1.800#10",
+        Equal,
+    );
     test(
         "3/2",
         "1.0",
@@ -11851,24 +14899,52 @@ fn test_rational_div_float_round() {
     test("2", "3.0", "0x3.0#2", Down, "0.5", "0x0.8#2", Less);
     test("2", "3.0", "0x3.0#2", Up, "0.8", "0x0.c#2", Greater);
     test("2", "3.0", "0x3.0#2", Nearest, "0.8", "0x0.c#2", Greater);
+    test(
+        "2",
+        "3.0",
+        "0x3.0#2",
+        NearestTiesAway,
+        "0.8",
+        "0x0.c#2",
+        Greater,
+    );
+    test("2", "3.0", "0x3.0#2", ToOdd, "0.8", "0x0.c#2", Greater);
 
     test("2", "3.0", "0x3.00#10", Floor, "0.666", "0x0.aa8#10", Less);
     test(
         "2",
         "3.0",
         "0x3.00#10",
-        Ceiling,
+        Ceiling,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
+    test("2", "3.0", "0x3.00#10", Down, "0.666", "0x0.aa8#10", Less);
+    test("2", "3.0", "0x3.00#10", Up, "0.667", "0x0.aac#10", Greater);
+    test(
+        "2",
+        "3.0",
+        "0x3.00#10",
+        Nearest,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
+    test(
+        "2",
+        "3.0",
+        "0x3.00#10",
+        NearestTiesAway,
         "0.667",
         "0x0.aac#10",
         Greater,
     );
-    test("2", "3.0", "0x3.00#10", Down, "0.666", "0x0.aa8#10", Less);
-    test("2", "3.0", "0x3.00#10", Up, "0.667", "0x0.aac#10", Greater);
     test(
         "2",
         "3.0",
         "0x3.00#10",
-        Nearest,
+        ToOdd,
         "0.667",
         "0x0.aac#10",
         Greater,
@@ -11895,6 +14971,16 @@ fn test_rational_div_float_round() {
         "0x0.800#10",
         Equal,
     );
+    test(
+        "3/2",
+        "3.0",
+        "0x3.00#10",
+        NearestTiesAway,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
+    test("3/2", "3.0", "0x3.00#10", ToOdd, "0.5", "0x0.800#10", Equal);
     test("3/2", "3.0", "0x3.00#10", Exact, "0.5", "0x0.800#10", Equal);
 
     test(
@@ -11942,6 +15028,24 @@ fn test_rational_div_float_round() {
         "0x0.7a3b2292bab310#53",
         Less,
     );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "0.47746482927568601",
+        "0x0.7a3b2292bab310#53",
+        Less,
+    );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "0.47746482927568606",
+        "0x0.7a3b2292bab314#53",
+        Greater,
+    );
 
     test(
         "3/2",
@@ -11988,6 +15092,24 @@ fn test_rational_div_float_round() {
         "-0x0.7a3b2292bab310#53",
         Greater,
     );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-0.47746482927568601",
+        "-0x0.7a3b2292bab310#53",
+        Greater,
+    );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "-0.47746482927568606",
+        "-0x0.7a3b2292bab314#53",
+        Less,
+    );
 
     test(
         "-3/2",
@@ -12034,6 +15156,24 @@ fn test_rational_div_float_round() {
         "-0x0.7a3b2292bab310#53",
         Greater,
     );
+    test(
+        "-3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-0.47746482927568601",
+        "-0x0.7a3b2292bab310#53",
+        Greater,
+    );
+    test(
+        "-3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "-0.47746482927568606",
+        "-0x0.7a3b2292bab314#53",
+        Less,
+    );
 
     test(
         "-3/2",
@@ -12224,6 +15364,8 @@ fn test_rational_div_float_prec_round() {
     test("123", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("123", "NaN", "NaN", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("123", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("123", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -12239,6 +15381,19 @@ fn test_rational_div_float_prec_round() {
     test(
         "123", "Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "123", "Infinity", "Infinity", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "123", "Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -12293,6 +15448,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "123",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "123",
         "-Infinity",
@@ -12309,6 +15484,8 @@ fn test_rational_div_float_prec_round() {
     test("0", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "NaN", "NaN", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test("0", "Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
@@ -12320,6 +15497,17 @@ fn test_rational_div_float_prec_round() {
     test(
         "0", "Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0", "Infinity", "Infinity", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0", "Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -12372,6 +15560,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "0",
         "-Infinity",
@@ -12388,6 +15596,8 @@ fn test_rational_div_float_prec_round() {
     test("0", "0.0", "0x0.0", 1, Down, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", 1, Up, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal);
+    test("0", "0.0", "0x0.0", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("0", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("0", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test("0", "-0.0", "-0x0.0", 1, Floor, "NaN", "NaN", Equal);
@@ -12395,6 +15605,17 @@ fn test_rational_div_float_prec_round() {
     test("0", "-0.0", "-0x0.0", 1, Down, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", 1, Up, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("0", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -12410,6 +15631,19 @@ fn test_rational_div_float_prec_round() {
     test(
         "123", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -12464,6 +15698,26 @@ fn test_rational_div_float_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123",
         "-0.0",
@@ -12525,6 +15779,26 @@ fn test_rational_div_float_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-123",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-123",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-123",
         "0.0",
@@ -12551,6 +15825,19 @@ fn test_rational_div_float_prec_round() {
     test(
         "-123", "-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-123",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-123", "-0.0", "-0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-123", "-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -12568,6 +15855,19 @@ fn test_rational_div_float_prec_round() {
     test(
         "1/3", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "1/3",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "1/3", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "1/3", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -12622,6 +15922,26 @@ fn test_rational_div_float_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "1/3",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "1/3",
         "-0.0",
@@ -12683,6 +16003,26 @@ fn test_rational_div_float_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-1/3",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1/3",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-1/3",
         "0.0",
@@ -12709,6 +16049,19 @@ fn test_rational_div_float_prec_round() {
     test(
         "-1/3", "-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "-1/3",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-1/3", "-0.0", "-0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "-1/3", "-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -12719,10 +16072,23 @@ fn test_rational_div_float_prec_round() {
     test(
         "1", "123.0", "0x7b.0#7", 1, Ceiling, "0.02", "0x0.04#1", Greater,
     );
-    test("1", "123.0", "0x7b.0#7", 1, Down, "0.008", "0x0.02#1", Less);
-    test("1", "123.0", "0x7b.0#7", 1, Up, "0.02", "0x0.04#1", Greater);
+    test("1", "123.0", "0x7b.0#7", 1, Down, "0.008", "0x0.02#1", Less);
+    test("1", "123.0", "0x7b.0#7", 1, Up, "0.02", "0x0.04#1", Greater);
+    test(
+        "1", "123.0", "0x7b.0#7", 1, Nearest, "0.008", "0x0.02#1", Less,
+    );
+    test(
+        "1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.008",
+        "0x0.02#1",
+        Less,
+    );
     test(
-        "1", "123.0", "0x7b.0#7", 1, Nearest, "0.008", "0x0.02#1", Less,
+        "1", "123.0", "0x7b.0#7", 1, ToOdd, "0.008", "0x0.02#1", Less,
     );
 
     test(
@@ -12775,12 +16141,43 @@ fn test_rational_div_float_prec_round() {
         "0x0.0215#10",
         Greater,
     );
+    test(
+        "1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
+    test(
+        "1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
 
     test("0", "123.0", "0x7b.0#7", 1, Floor, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", 1, Ceiling, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", 1, Down, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", 1, Up, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0", "123.0", "0x7b.0#7", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0", "123.0", "0x7b.0#7", 1, Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -12815,6 +16212,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "0",
         "-123.0",
@@ -12831,6 +16248,17 @@ fn test_rational_div_float_prec_round() {
     test("2", "1.0", "0x1.0#1", 1, Down, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "2",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("2", "1.0", "0x1.0#1", 1, ToOdd, "2.0", "0x2.0#1", Equal);
     test("2", "1.0", "0x1.0#1", 1, Exact, "2.0", "0x2.0#1", Equal);
 
     test("2", "1.0", "0x1.0#1", 10, Floor, "2.0", "0x2.00#10", Equal);
@@ -12856,6 +16284,17 @@ fn test_rational_div_float_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "2",
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("2", "1.0", "0x1.0#1", 10, ToOdd, "2.0", "0x2.00#10", Equal);
     test("2", "1.0", "0x1.0#1", 10, Exact, "2.0", "0x2.00#10", Equal);
 
     test("3/2", "1.0", "0x1.000#10", 1, Floor, "1.0", "0x1.0#1", Less);
@@ -12881,6 +16320,17 @@ fn test_rational_div_float_prec_round() {
         "0x2.0#1",
         Greater,
     );
+    test(
+        "3/2",
+        "1.0",
+        "0x1.000#10",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("3/2", "1.0", "0x1.000#10", 1, ToOdd, "1.0", "0x1.0#1", Less);
 
     test(
         "3/2",
@@ -12932,6 +16382,26 @@ fn test_rational_div_float_prec_round() {
         "0x1.800#10",
         Equal,
     );
+    test(
+        "3/2",
+        "1.0",
+        "0x1.000#10",
+        10,
+        NearestTiesAway,
+        "1.5",
+        "0x1.800#10",
+        Equal,
+    );
+    test(
+        "3/2",
+        "1.0",
+        "0x1.000#10",
+        10,
+        ToOdd,
+        "1.5",
+        "0x1.800#10",
+        Equal,
+    );
     test(
         "3/2",
         "1.0",
@@ -12948,6 +16418,17 @@ fn test_rational_div_float_prec_round() {
     test("2", "3.0", "0x3.0#2", 1, Down, "0.5", "0x0.8#1", Less);
     test("2", "3.0", "0x3.0#2", 1, Up, "1.0", "0x1.0#1", Greater);
     test("2", "3.0", "0x3.0#2", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test(
+        "2",
+        "3.0",
+        "0x3.0#2",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test("2", "3.0", "0x3.0#2", 1, ToOdd, "0.5", "0x0.8#1", Less);
 
     test(
         "2",
@@ -12990,6 +16471,26 @@ fn test_rational_div_float_prec_round() {
         "0x0.aac#10",
         Greater,
     );
+    test(
+        "2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        NearestTiesAway,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
+    test(
+        "2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        ToOdd,
+        "0.667",
+        "0x0.aac#10",
+        Greater,
+    );
 
     test("3/2", "3.0", "0x3.00#10", 1, Floor, "0.5", "0x0.8#1", Equal);
     test(
@@ -13014,6 +16515,17 @@ fn test_rational_div_float_prec_round() {
         "0x0.8#1",
         Equal,
     );
+    test(
+        "3/2",
+        "3.0",
+        "0x3.00#10",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Equal,
+    );
+    test("3/2", "3.0", "0x3.00#10", 1, ToOdd, "0.5", "0x0.8#1", Equal);
     test("3/2", "3.0", "0x3.00#10", 1, Exact, "0.5", "0x0.8#1", Equal);
 
     test(
@@ -13066,6 +16578,26 @@ fn test_rational_div_float_prec_round() {
         "0x0.800#10",
         Equal,
     );
+    test(
+        "3/2",
+        "3.0",
+        "0x3.00#10",
+        10,
+        NearestTiesAway,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
+    test(
+        "3/2",
+        "3.0",
+        "0x3.00#10",
+        10,
+        ToOdd,
+        "0.5",
+        "0x0.800#10",
+        Equal,
+    );
     test(
         "3/2",
         "3.0",
@@ -13127,6 +16659,26 @@ fn test_rational_div_float_prec_round() {
         "0x0.8#1",
         Greater,
     );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
 
     test(
         "3/2",
@@ -13178,6 +16730,26 @@ fn test_rational_div_float_prec_round() {
         "0x0.7a4#10",
         Greater,
     );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "0.4775",
+        "0x0.7a4#10",
+        Greater,
+    );
+    test(
+        "3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "0.4771",
+        "0x0.7a2#10",
+        Less,
+    );
 
     test(
         "3/2",
@@ -13280,6 +16852,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.7a4#10",
         Less,
     );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-0.4775",
+        "-0x0.7a4#10",
+        Less,
+    );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Greater,
+    );
 
     test(
         "3/2",
@@ -13331,6 +16923,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.8#1",
         Less,
     );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "-0.5",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "-0.2",
+        "-0x0.4#1",
+        Greater,
+    );
 
     test(
         "-3/2",
@@ -13382,6 +16994,26 @@ fn test_rational_div_float_prec_round() {
         "-0x0.7a4#10",
         Less,
     );
+    test(
+        "-3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-0.4775",
+        "-0x0.7a4#10",
+        Less,
+    );
+    test(
+        "-3/2",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-0.4771",
+        "-0x0.7a2#10",
+        Greater,
+    );
 
     test(
         "-3/2",
@@ -13433,6 +17065,26 @@ fn test_rational_div_float_prec_round() {
         "0x0.8#1",
         Greater,
     );
+    test(
+        "-3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "-3/2",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
 
     test(
         "-3/2",
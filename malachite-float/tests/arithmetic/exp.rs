@@ -0,0 +1,2166 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::test_util::arithmetic::exp::{
+    rug_exp_m1_prec, rug_exp_m1_prec_round, rug_exp_m1_round, rug_exp_prec, rug_exp_prec_round,
+    rug_exp_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_20, float_unsigned_pair_gen_var_3,
+    float_unsigned_rounding_mode_triple_gen_var_6,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_assign(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 10, "2.719", "0x2.b8#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.3677", "0x0.5e2#10", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "2.719",
+        "0x2.b8#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "7.39", "0x7.64#10", Greater);
+    test("3.0", "0x3.0#2", 1, "2.0e1", "0x1.0E+1#1", Less);
+    test("3.0", "0x3.0#2", 10, "20.09", "0x14.18#10", Greater);
+    test("123.0", "0x7b.0#7", 1, "2.0e53", "0x2.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 10, "2.619e53", "0x2.bcE+44#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, "3.0e-54", "0x4.0E-45#1", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        "3.818e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.03",
+        "0x0.08#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "15.16",
+        "0xf.28#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "1.0", "0x1.0#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", 10, "1.0", "0x1.000#10", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", 1, "1.0", "0x1.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, "1.0", "0x1.000#10", Greater);
+}
+
+#[test]
+fn exp_prec_fail() {
+    assert_panic!(Float::NAN.exp_prec(0));
+    assert_panic!(Float::NAN.exp_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_round_assign(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "0.5", "0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.2", "0x0.4#1", Less);
+    test("2.0", "0x2.0#1", Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "8.0", "0x8.0#1", Greater);
+    test("3.0", "0x3.0#2", Floor, "16.0", "0x10.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "24.0", "0x18.0#2", Greater);
+    test("3.0", "0x3.0#2", Down, "16.0", "0x10.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "24.0", "0x18.0#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "24.0", "0x18.0#2", Greater);
+    test("123.0", "0x7b.0#7", Floor, "2.6e53", "0x2.b8E+44#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "2.6e53", "0x2.b8E+44#7", Less);
+    test("123.0", "0x7b.0#7", Up, "2.63e53", "0x2.c0E+44#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Floor,
+        "3.79e-54",
+        "0x5.dE-45#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "3.83e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "3.79e-54", "0x5.dE-45#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Up,
+        "3.83e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "3.83e-54",
+        "0x5.eE-45#7",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "1.0", "0x1.0#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Down, "1.0", "0x1.0#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "2.0", "0x2.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", Floor, "0.5", "0x0.8#1", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", Down, "0.5", "0x0.8#1", Less);
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", Nearest, "1.0", "0x1.0#1", Greater);
+}
+
+#[test]
+fn exp_round_fail() {
+    assert_panic!(Float::TWO.exp_round(Exact));
+    assert_panic!(Float::TWO.exp_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.exp_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 10, Floor, "0.0", "0x0.0", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "4.0", "0x4.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "2.715", "0x2.b7#10", Less);
+    test("1.0", "0x1.0#1", 10, Ceiling, "2.719", "0x2.b8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Down, "2.715", "0x2.b7#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "2.719", "0x2.b8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "2.719", "0x2.b8#10", Greater);
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "0.5", "0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "0.5", "0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.2", "0x0.4#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.3677", "0x0.5e2#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.3682",
+        "0x0.5e4#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.3677", "0x0.5e2#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.3682", "0x0.5e4#10", Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "0.3677",
+        "0x0.5e2#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "7.383", "0x7.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "7.39", "0x7.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Down, "7.383", "0x7.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "7.39", "0x7.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "7.39", "0x7.64#10", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "20.06", "0x14.10#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "20.09",
+        "0x14.18#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "20.06", "0x14.10#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "20.09", "0x14.18#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "20.09",
+        "0x14.18#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.623e53",
+        "0x2.bdE+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "2.623e53",
+        "0x2.bdE+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "3.813e-54",
+        "0x5.d8E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "3.818e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "3.813e-54",
+        "0x5.d8E-45#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Up,
+        "3.818e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "3.818e-54",
+        "0x5.daE-45#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "23.12",
+        "0x17.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "23.12",
+        "0x17.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "23.16",
+        "0x17.28#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "0.04327",
+        "0x0.0b14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "0.04327",
+        "0x0.0b14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "0.04321",
+        "0x0.0b10#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "15.14",
+        "0xf.24#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "15.16",
+        "0xf.28#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "15.14",
+        "0xf.24#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "15.16",
+        "0xf.28#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "15.16",
+        "0xf.28#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 10, Floor, "1.0", "0x1.000#10", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 10, Down, "1.0", "0x1.000#10", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Down,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Up,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "2.719",
+        "0x2.b8#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "2.715", "0x2.b7#10", Less);
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    assert_panic!(Float::ONE.exp_prec_round(0, Floor));
+    assert_panic!(Float::ONE.exp_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.exp_prec_round(1, Exact));
+    assert_panic!(Float::TWO.exp_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.exp_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_exp_m1_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp_m1, o) = x.clone().exp_m1_prec(prec);
+        assert!(exp_m1.is_valid());
+
+        assert_eq!(exp_m1.to_string(), out);
+        assert_eq!(to_hex_string(&exp_m1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_m1_alt, o_alt) = x.exp_m1_prec_ref(prec);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_m1_alt = x.clone();
+        let o_alt = exp_m1_alt.exp_m1_prec_assign(prec);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp_m1, rug_o) = rug_exp_m1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp_m1)),
+            ComparableFloatRef(&exp_m1),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 10, "-1.0", "-0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.719", "0x1.b80#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, "-0.632", "-0x0.a1c#10", Greater);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "6.39", "0x6.64#10", Greater);
+    test("3.0", "0x3.0#2", 1, "2.0e1", "0x1.0E+1#1", Less);
+    test("3.0", "0x3.0#2", 10, "19.09", "0x13.18#10", Greater);
+    test("123.0", "0x7b.0#7", 1, "2.0e53", "0x2.0E+44#1", Less);
+    test("123.0", "0x7b.0#7", 10, "2.619e53", "0x2.bcE+44#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-1.0", "-0x1.000#10", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "14.16",
+        "0xe.28#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", 1, "-4.0e-9", "-0x1.0E-7#1", Less);
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Less,
+    );
+}
+
+#[test]
+fn exp_m1_prec_fail() {
+    assert_panic!(Float::NAN.exp_m1_prec(0));
+    assert_panic!(Float::NAN.exp_m1_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.exp_m1_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_exp_m1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp_m1, o) = x.clone().exp_m1_round(rm);
+        assert!(exp_m1.is_valid());
+
+        assert_eq!(exp_m1.to_string(), out);
+        assert_eq!(to_hex_string(&exp_m1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_m1_alt, o_alt) = x.exp_m1_round_ref(rm);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_m1_alt = x.clone();
+        let o_alt = exp_m1_alt.exp_m1_round_assign(rm);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp_m1, rug_o) = rug_exp_m1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp_m1)),
+                ComparableFloatRef(&exp_m1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "-1.0", "-0x1.0#1", Equal);
+    test("-Infinity", "-Infinity", Exact, "-1.0", "-0x1.0#1", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("2.0", "0x2.0#1", Floor, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "4.0", "0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "8.0", "0x8.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "8.0", "0x8.0#1", Greater);
+    test("3.0", "0x3.0#2", Floor, "16.0", "0x10.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "24.0", "0x18.0#2", Greater);
+    test("3.0", "0x3.0#2", Down, "16.0", "0x10.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "24.0", "0x18.0#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "16.0", "0x10.0#2", Less);
+    test("123.0", "0x7b.0#7", Floor, "2.6e53", "0x2.b8E+44#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Ceiling,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", Down, "2.6e53", "0x2.b8E+44#7", Less);
+    test("123.0", "0x7b.0#7", Up, "2.63e53", "0x2.c0E+44#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        Nearest,
+        "2.63e53",
+        "0x2.c0E+44#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Floor, "-1.0", "-0x1.00#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.99",
+        "-0x0.fe#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.99", "-0x0.fe#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-1.0", "-0x1.00#7", Less);
+    test("-123.0", "-0x7b.0#7", Nearest, "-1.0", "-0x1.00#7", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "7.0e-9",
+        "0x2.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "4.0e-9", "0x1.0E-7#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "7.0e-9", "0x2.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Floor,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Ceiling,
+        "-2.0e-9",
+        "-0x8.0E-8#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Down,
+        "-2.0e-9",
+        "-0x8.0E-8#1",
+        Greater,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "-4.0e-9", "-0x1.0E-7#1", Less);
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Nearest,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Less,
+    );
+}
+
+#[test]
+fn exp_m1_round_fail() {
+    assert_panic!(Float::TWO.exp_m1_round(Exact));
+    assert_panic!(Float::TWO.exp_m1_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.exp_m1_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_exp_m1_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp_m1, o) = x.clone().exp_m1_prec_round(prec, rm);
+        assert!(exp_m1.is_valid());
+
+        assert_eq!(exp_m1.to_string(), out);
+        assert_eq!(to_hex_string(&exp_m1), out_hex);
+        assert_eq!(o, o_out);
+
+        let (exp_m1_alt, o_alt) = x.exp_m1_prec_round_ref(prec, rm);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_m1_alt = x.clone();
+        let o_alt = exp_m1_alt.exp_m1_prec_round_assign(prec, rm);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1), ComparableFloatRef(&exp_m1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp_m1, rug_o) = rug_exp_m1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp_m1)),
+                ComparableFloatRef(&exp_m1),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Equal,
+    );
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.717", "0x1.b78#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.717", "0x1.b78#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.719", "0x1.b80#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-0.633", "-0x0.a20#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.632",
+        "-0x0.a1c#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.632",
+        "-0x0.a1c#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.633", "-0x0.a20#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.632",
+        "-0x0.a1c#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "6.383", "0x6.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "6.39", "0x6.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Down, "6.383", "0x6.62#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "6.39", "0x6.64#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "6.39", "0x6.64#10", Greater);
+    test("3.0", "0x3.0#2", 10, Floor, "19.06", "0x13.10#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "19.09",
+        "0x13.18#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "19.06", "0x13.10#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "19.09", "0x13.18#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "19.09",
+        "0x13.18#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "2.623e53",
+        "0x2.bdE+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Up,
+        "2.623e53",
+        "0x2.bdE+44#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "2.619e53",
+        "0x2.bcE+44#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "-1.0", "-0x1.000#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "22.12",
+        "0x16.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "22.12",
+        "0x16.20#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "22.16",
+        "0x16.28#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-0.956",
+        "-0x0.f4c#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-0.956",
+        "-0x0.f4c#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-0.957",
+        "-0x0.f50#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "14.14",
+        "0xe.24#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "14.16",
+        "0xe.28#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "14.14",
+        "0xe.24#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "14.16",
+        "0xe.28#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "14.16",
+        "0xe.28#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Floor,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Ceiling,
+        "-3.722e-9",
+        "-0xf.fcE-8#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Down,
+        "-3.722e-9",
+        "-0xf.fcE-8#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Up,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Nearest,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.719",
+        "0x1.b80#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "1.717", "0x1.b78#10", Less);
+}
+
+#[test]
+fn exp_m1_prec_round_fail() {
+    assert_panic!(Float::ONE.exp_m1_prec_round(0, Floor));
+    assert_panic!(Float::ONE.exp_m1_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp_m1_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.exp_m1_prec_round(1, Exact));
+    assert_panic!(Float::TWO.exp_m1_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.exp_m1_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, prec, rm)| {
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if exp.is_normal() {
+            assert_eq!(exp.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.exp_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (exp, o) = Float::NAN.exp_prec_round(prec, rm);
+        assert!(exp.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::INFINITY.exp_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (exp, o) = Float::NEGATIVE_INFINITY.exp_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(exp), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::ZERO.exp_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.exp_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_prec_properties_helper(x: Float, prec: u64) {
+    let (exp, o) = x.clone().exp_prec(prec);
+    assert!(exp.is_valid());
+
+    let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp)),
+        ComparableFloatRef(&exp),
+    );
+    assert_eq!(rug_o, o);
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if exp.is_normal() {
+        assert_eq!(exp.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn exp_prec_properties() {
+    float_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        exp_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (exp, o) = x.clone().exp_round(rm);
+    assert!(exp.is_valid());
+
+    let (exp_alt, o_alt) = x.exp_round_ref(rm);
+    assert!(exp_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    let (exp_alt, o_alt) = x.exp_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn exp_round_properties() {
+    float_rounding_mode_pair_gen_var_20().test_properties(|(x, rm)| {
+        exp_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn exp_m1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, prec, rm)| {
+        let (exp_m1, o) = x.clone().exp_m1_prec_round(prec, rm);
+        assert!(exp_m1.is_valid());
+
+        let (exp_m1_alt, o_alt) = x.exp_m1_prec_round_ref(prec, rm);
+        assert!(exp_m1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_m1_alt), ComparableFloatRef(&exp_m1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_m1_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp_m1));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp_m1, rug_o) = rug_exp_m1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp_m1)),
+                ComparableFloatRef(&exp_m1),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if exp_m1.is_normal() {
+            assert_eq!(exp_m1.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.exp_m1_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (exp_m1, o) = Float::NAN.exp_m1_prec_round(prec, rm);
+        assert!(exp_m1.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::INFINITY.exp_m1_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_INFINITY.exp_m1_prec_round(prec, rm),
+            (Float::negative_one_prec(prec), Equal)
+        );
+
+        let (exp_m1, o) = Float::ZERO.exp_m1_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(exp_m1), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (exp_m1, o) = Float::NEGATIVE_ZERO.exp_m1_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(exp_m1),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_m1_prec_properties_helper(x: Float, prec: u64) {
+    let (exp_m1, o) = x.clone().exp_m1_prec(prec);
+    assert!(exp_m1.is_valid());
+
+    let (exp_m1_alt, o_alt) = x.exp_m1_prec_ref(prec);
+    assert!(exp_m1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_m1_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_m1_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    let (rug_exp_m1, rug_o) = rug_exp_m1_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_exp_m1)),
+        ComparableFloatRef(&exp_m1),
+    );
+    assert_eq!(rug_o, o);
+
+    let (exp_m1_alt, o_alt) = x.exp_m1_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&exp_m1_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    if exp_m1.is_normal() {
+        assert_eq!(exp_m1.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn exp_m1_prec_properties() {
+    float_unsigned_pair_gen_var_3().test_properties(|(x, prec)| {
+        exp_m1_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn exp_m1_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (exp_m1, o) = x.clone().exp_m1_round(rm);
+    assert!(exp_m1.is_valid());
+
+    let (exp_m1_alt, o_alt) = x.exp_m1_round_ref(rm);
+    assert!(exp_m1_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&exp_m1_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.exp_m1_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    let (exp_m1_alt, o_alt) = x.exp_m1_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&exp_m1_alt), ComparableFloatRef(&exp_m1));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_exp_m1, rug_o) = rug_exp_m1_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp_m1)),
+            ComparableFloatRef(&exp_m1),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn exp_m1_round_properties() {
+    float_rounding_mode_pair_gen_var_20().test_properties(|(x, rm)| {
+        exp_m1_round_properties_helper(x, rm);
+    });
+}
@@ -0,0 +1,2723 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::Ln;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::test_util::arithmetic::ln::{
+    rug_ln, rug_ln_1p_prec, rug_ln_1p_prec_round, rug_ln_1p_round, rug_ln_prec, rug_ln_prec_round,
+    rug_ln_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+    rational_unsigned_rounding_mode_triple_gen_var_2,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_ln_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_prec(prec);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_prec_assign(prec);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_ln, rug_o) = rug_ln_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, "0.693", "0x0.b18#10", Greater);
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, "1.098", "0x1.190#10", Less);
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "4.81", "0x4.d0#10", Greater);
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.144729885849400135161709026159",
+        "0x1.250d048e7a1bcdee499fa5b0c#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "-2.0e1", "-0x1.0E+1#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", 10, "-19.41", "-0x13.68#10", Greater);
+    test("-4.0e-9", "-0x1.0E-7#1", 1, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, "NaN", "NaN", Equal);
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        "2.0e3",
+        "0x8.0E+2#1",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        "-2.0e3",
+        "-0x8.0E+2#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        "-2776.0",
+        "-0xad8.0#10",
+        Less,
+    );
+}
+
+#[test]
+fn ln_prec_fail() {
+    assert_panic!(Float::NAN.ln_prec(0));
+    assert_panic!(Float::NAN.ln_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.ln_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_ln_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_round(rm);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_round_ref(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_round_assign(rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln, rug_o) = rug_ln_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln)),
+                ComparableFloatRef(&ln),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("3.0", "0x3.0#2", Floor, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Down, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.0", "0x1.0#2", Less);
+    test("123.0", "0x7b.0#7", Floor, "4.75", "0x4.c#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "4.81", "0x4.d#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "4.75", "0x4.c#7", Less);
+    test("123.0", "0x7b.0#7", Up, "4.81", "0x4.d#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "4.81", "0x4.d#7", Greater);
+    test("-123.0", "-0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.1447298858493999",
+        "0x1.250d048e7a1bc#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.1447298858493999",
+        "0x1.250d048e7a1bc#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.9999999999999999",
+        "0x0.fffffffffffff8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.0",
+        "0x1.0000000000000#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.9999999999999999",
+        "0x0.fffffffffffff8#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.0",
+        "0x1.0000000000000#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.0",
+        "0x1.0000000000000#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "-3.0e1", "-0x2.0E+1#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Down,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Up, "-3.0e1", "-0x2.0E+1#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Greater,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", Floor, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", Ceiling, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", Down, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", Nearest, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Floor,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Ceiling,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Down,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Up,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Nearest,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Floor,
+        "-3.0e3",
+        "-0xc.0E+2#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Ceiling,
+        "-2.0e3",
+        "-0x8.0E+2#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Down,
+        "-2.0e3",
+        "-0x8.0E+2#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Up,
+        "-3.0e3",
+        "-0xc.0E+2#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Nearest,
+        "-3.0e3",
+        "-0xc.0E+2#2",
+        Less,
+    );
+}
+
+#[test]
+fn ln_round_fail() {
+    assert_panic!(Float::TWO.ln_round(Exact));
+    assert_panic!(Float::TWO.ln_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.ln_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_ln_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln, o) = x.clone().ln_prec_round(prec, rm);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_alt = x.clone();
+        let o_alt = ln_alt.ln_prec_round_assign(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln, rug_o) = rug_ln_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln)),
+                ComparableFloatRef(&ln),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0.0", "0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test("2.0", "0x2.0#1", 10, Floor, "0.692", "0x0.b14#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "0.692", "0x0.b14#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "0.693", "0x0.b18#10", Greater);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Floor, "1.098", "0x1.190#10", Less);
+    test("3.0", "0x3.0#2", 10, Ceiling, "1.1", "0x1.198#10", Greater);
+    test("3.0", "0x3.0#2", 10, Down, "1.098", "0x1.190#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.1", "0x1.198#10", Greater);
+    test("3.0", "0x3.0#2", 10, Nearest, "1.098", "0x1.190#10", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "4.805", "0x4.ce#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "4.81",
+        "0x4.d0#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "4.805", "0x4.ce#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "4.81", "0x4.d0#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "4.81",
+        "0x4.d0#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Nearest, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.146",
+        "0x1.258#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.146",
+        "0x1.258#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.145",
+        "0x1.250#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "-19.44",
+        "-0x13.70#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "-19.41",
+        "-0x13.68#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "-19.41",
+        "-0x13.68#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "-19.44",
+        "-0x13.70#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "-19.41",
+        "-0x13.68#10",
+        Greater,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Down, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Up, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-4.0e-9", "-0x1.0E-7#1", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Floor,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Ceiling,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Down,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Up,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Nearest,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Floor,
+        "-2776.0",
+        "-0xad8.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Ceiling,
+        "-2772.0",
+        "-0xad4.0#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Down,
+        "-2772.0",
+        "-0xad4.0#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Up,
+        "-2776.0",
+        "-0xad8.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Nearest,
+        "-2776.0",
+        "-0xad8.0#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        NearestTiesAway,
+        "1.098",
+        "0x1.190#10",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 10, ToOdd, "1.1", "0x1.198#10", Greater);
+}
+
+#[test]
+fn ln_prec_round_fail() {
+    assert_panic!(Float::ONE.ln_prec_round(0, Floor));
+    assert_panic!(Float::ONE.ln_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.ln_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.ln_prec_round(1, Exact));
+    assert_panic!(Float::TWO.ln_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.ln_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_ln_1p_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_1p, o) = x.clone().ln_1p_prec(prec);
+        assert!(ln_1p.is_valid());
+
+        assert_eq!(ln_1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln_1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_1p_alt, o_alt) = x.ln_1p_prec_ref(prec);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_1p_alt = x.clone();
+        let o_alt = ln_1p_alt.ln_1p_prec_assign(prec);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_ln_1p, rug_o) = rug_ln_1p_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_1p)),
+            ComparableFloatRef(&ln_1p),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.693", "0x0.b18#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", 10, "-Infinity", "-Infinity", Equal);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, "1.098", "0x1.190#10", Less);
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, "1.387", "0x1.630#10", Greater);
+    test("123.0", "0x7b.0#7", 1, "4.0", "0x4.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "4.82", "0x4.d2#10", Greater);
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.421080412794292603484306978989",
+        "0x1.6bcbed09f00aece2ea800b6b0#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.312",
+        "0x1.500#10",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        1,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        "2.0e3",
+        "0x8.0E+2#1",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        "9.0e-1206",
+        "0x2.0E-1001#1",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        "1.422e-1205",
+        "0x3.00E-1001#10",
+        Greater,
+    );
+}
+
+#[test]
+fn ln_1p_prec_fail() {
+    assert_panic!(Float::NAN.ln_1p_prec(0));
+    assert_panic!(Float::NAN.ln_1p_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.ln_1p_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_ln_1p_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_1p, o) = x.clone().ln_1p_round(rm);
+        assert!(ln_1p.is_valid());
+
+        assert_eq!(ln_1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln_1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_1p_alt, o_alt) = x.ln_1p_round_ref(rm);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_1p_alt = x.clone();
+        let o_alt = ln_1p_alt.ln_1p_round_assign(rm);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_1p, rug_o) = rug_ln_1p_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_1p)),
+                ComparableFloatRef(&ln_1p),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Down, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Up, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "-Infinity", "-Infinity", Equal);
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", Floor, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Down, "1.0", "0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Up, "1.5", "0x1.8#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.5", "0x1.8#2", Greater);
+    test("123.0", "0x7b.0#7", Floor, "4.81", "0x4.d#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "4.88", "0x4.e#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "4.81", "0x4.d#7", Less);
+    test("123.0", "0x7b.0#7", Up, "4.88", "0x4.e#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "4.81", "0x4.d#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.4210804127942924",
+        "0x1.6bcbed09f00ae#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.4210804127942924",
+        "0x1.6bcbed09f00ae#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.4210804127942926",
+        "0x1.6bcbed09f00af#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.3132616875182226",
+        "0x1.5031eafefb048#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.3132616875182228",
+        "0x1.5031eafefb049#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.3132616875182226",
+        "0x1.5031eafefb048#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.3132616875182228",
+        "0x1.5031eafefb049#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.3132616875182228",
+        "0x1.5031eafefb049#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "2.0e-9", "0x8.0E-8#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "2.0e-9", "0x8.0E-8#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Floor,
+        "-7.0e-9",
+        "-0x2.0E-7#1",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Ceiling,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Down,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test("-4.0e-9", "-0x1.0E-7#1", Up, "-7.0e-9", "-0x2.0E-7#1", Less);
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Nearest,
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Floor,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Ceiling,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Down,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Up,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Nearest,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Floor,
+        "9.0e-1206",
+        "0x2.0E-1001#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Ceiling,
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Down,
+        "9.0e-1206",
+        "0x2.0E-1001#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Up,
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Nearest,
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Greater,
+    );
+}
+
+#[test]
+fn ln_1p_round_fail() {
+    assert_panic!(Float::TWO.ln_1p_round(Exact));
+    assert_panic!(Float::TWO.ln_1p_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.ln_1p_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_ln_1p_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (ln_1p, o) = x.clone().ln_1p_prec_round(prec, rm);
+        assert!(ln_1p.is_valid());
+
+        assert_eq!(ln_1p.to_string(), out);
+        assert_eq!(to_hex_string(&ln_1p), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_1p_alt, o_alt) = x.ln_1p_prec_round_ref(prec, rm);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut ln_1p_alt = x.clone();
+        let o_alt = ln_1p_alt.ln_1p_prec_round_assign(prec, rm);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p), ComparableFloatRef(&ln_1p_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_1p, rug_o) = rug_ln_1p_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_1p)),
+                ComparableFloatRef(&ln_1p),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.692", "0x0.b14#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.692", "0x0.b14#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.693", "0x0.b18#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, Down, "-Infinity", "-Infinity", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-Infinity", "-Infinity", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Exact,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "1.098", "0x1.190#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "1.1", "0x1.198#10", Greater);
+    test("2.0", "0x2.0#1", 10, Down, "1.098", "0x1.190#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "1.1", "0x1.198#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "1.098", "0x1.190#10", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "1.385", "0x1.628#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.387",
+        "0x1.630#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "1.385", "0x1.628#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.387", "0x1.630#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "1.387",
+        "0x1.630#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Floor, "4.81", "0x4.d0#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "4.82",
+        "0x4.d2#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "4.81", "0x4.d0#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "4.82", "0x4.d2#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "4.82",
+        "0x4.d2#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Nearest, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.42",
+        "0x1.6b8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.42",
+        "0x1.6b8#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.422",
+        "0x1.6c0#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.312",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.314",
+        "0x1.508#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.312",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.314",
+        "0x1.508#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.312",
+        "0x1.500#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Floor,
+        "-3.733e-9",
+        "-0x1.008E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Ceiling,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Down,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Up,
+        "-3.733e-9",
+        "-0x1.008E-7#10",
+        Less,
+    );
+    test(
+        "-4.0e-9",
+        "-0x1.0E-7#1",
+        10,
+        Nearest,
+        "-3.725e-9",
+        "-0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Floor,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Ceiling,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Down,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Up,
+        "2776.0",
+        "0xad8.0#10",
+        Greater,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Nearest,
+        "2772.0",
+        "0xad4.0#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Floor,
+        "1.421e-1205",
+        "0x2.ffE-1001#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Ceiling,
+        "1.422e-1205",
+        "0x3.00E-1001#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Down,
+        "1.421e-1205",
+        "0x2.ffE-1001#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Up,
+        "1.422e-1205",
+        "0x3.00E-1001#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Nearest,
+        "1.422e-1205",
+        "0x3.00E-1001#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.693",
+        "0x0.b18#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "0.692", "0x0.b14#10", Less);
+}
+
+#[test]
+fn ln_1p_prec_round_fail() {
+    assert_panic!(Float::ONE.ln_1p_prec_round(0, Floor));
+    assert_panic!(Float::ONE.ln_1p_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.ln_1p_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.ln_1p_prec_round(1, Exact));
+    assert_panic!(Float::TWO.ln_1p_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.ln_1p_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_ln() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ln = x.clone().ln();
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+
+        let ln_alt = (&x).ln();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&ln)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0000000000000000000000000#100", "0.0", "0x0.0");
+    test("2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test("123.0", "0x7b.0#7", "4.81", "0x4.d#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.1447298858494002",
+        "0x1.250d048e7a1bd#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.0",
+        "0x1.0000000000000#53",
+    );
+    test("4.0e-9", "0x1.0E-7#1", "-2.0e1", "-0x1.0E+1#1");
+    test("-4.0e-9", "-0x1.0E-7#1", "NaN", "NaN");
+    test("1.318e1204", "0x1.000E+1000#10", "2772.0", "0xad4.0#10");
+    test("1.0e-1205", "0x3.0E-1001#2", "-3.0e3", "-0xc.0E+2#2");
+}
+
+#[test]
+fn test_ln_rational_prec_round() {
+    let test = |s, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (ln, o) = Float::ln_rational_prec_round(x.clone(), prec, rm);
+        assert!(ln.is_valid());
+
+        assert_eq!(ln.to_string(), out);
+        assert_eq!(to_hex_string(&ln), out_hex);
+        assert_eq!(o, o_out);
+
+        let (ln_alt, o_alt) = Float::ln_rational_prec_round_ref(&x, prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+        assert_eq!(o_alt, o_out);
+
+        if rm == Nearest {
+            let (ln_alt, o_alt) = Float::ln_rational_prec_ref(&x, prec);
+            assert_eq!(ComparableFloatRef(&ln), ComparableFloatRef(&ln_alt));
+            assert_eq!(o_alt, o_out);
+        }
+    };
+    test("0", 10, Floor, "-Infinity", "-Infinity", Equal);
+    test("0", 10, Exact, "-Infinity", "-Infinity", Equal);
+    test("1", 10, Floor, "0.0", "0x0.0", Equal);
+    test("1", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("1/2", 10, Floor, "-0.693", "-0x0.b18#10", Less);
+    test("1/2", 10, Ceiling, "-0.692", "-0x0.b14#10", Greater);
+    test("1/2", 10, Down, "-0.692", "-0x0.b14#10", Greater);
+    test("1/2", 10, Up, "-0.693", "-0x0.b18#10", Less);
+    test("1/2", 10, Nearest, "-0.693", "-0x0.b18#10", Less);
+    test(
+        "1/2",
+        100,
+        Nearest,
+        "-0.693147180559945309417232121458",
+        "-0x0.b17217f7d1cf79abc9e3b3980#100",
+        Greater,
+    );
+    test("1/3", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("1/3", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("1/3", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("1/3", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("1/3", 1, Nearest, "-1.0", "-0x1.0#1", Greater);
+    test("1/3", 10, Floor, "-1.1", "-0x1.198#10", Less);
+    test("1/3", 10, Ceiling, "-1.098", "-0x1.190#10", Greater);
+    test("1/3", 10, Down, "-1.098", "-0x1.190#10", Greater);
+    test("1/3", 10, Up, "-1.1", "-0x1.198#10", Less);
+    test("1/3", 10, Nearest, "-1.098", "-0x1.190#10", Greater);
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "-1.098612288668109691395245236922",
+        "-0x1.193ea7aad030a976a4198d550#100",
+        Greater,
+    );
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, Floor, "1.145", "0x1.250#10", Less);
+    test("22/7", 10, Ceiling, "1.146", "0x1.258#10", Greater);
+    test("22/7", 10, Down, "1.145", "0x1.250#10", Less);
+    test("22/7", 10, Up, "1.146", "0x1.258#10", Greater);
+    test("22/7", 10, Nearest, "1.145", "0x1.250#10", Less);
+    test(
+        "22/7",
+        100,
+        Nearest,
+        "1.14513230430300254837382295598",
+        "0x1.2527640493137b5c5ee7165de#100",
+        Greater,
+    );
+    test("-22/7", 10, Floor, "NaN", "NaN", Equal);
+    test("-22/7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-22/7", 10, Down, "NaN", "NaN", Equal);
+    test("-22/7", 10, Up, "NaN", "NaN", Equal);
+    test("-22/7", 10, Nearest, "NaN", "NaN", Equal);
+    test("-22/7", 10, Exact, "NaN", "NaN", Equal);
+    test("-22/7", 100, Nearest, "NaN", "NaN", Equal);
+    test("1000000000000", 10, Floor, "27.62", "0x1b.a0#10", Less);
+    test("1000000000000", 10, Ceiling, "27.66", "0x1b.a8#10", Greater);
+    test("1000000000000", 10, Down, "27.62", "0x1b.a0#10", Less);
+    test("1000000000000", 10, Up, "27.66", "0x1b.a8#10", Greater);
+    test("1000000000000", 10, Nearest, "27.62", "0x1b.a0#10", Less);
+    test(
+        "1000000000000",
+        100,
+        Nearest,
+        "27.63102111592854820821589745621",
+        "0x1b.a18a998fffa0444bf0482828#100",
+        Less,
+    );
+    test("1/1000000000000", 10, Floor, "-27.66", "-0x1b.a8#10", Less);
+    test(
+        "1/1000000000000",
+        10,
+        Ceiling,
+        "-27.62",
+        "-0x1b.a0#10",
+        Greater,
+    );
+    test(
+        "1/1000000000000",
+        10,
+        Down,
+        "-27.62",
+        "-0x1b.a0#10",
+        Greater,
+    );
+    test("1/1000000000000", 10, Up, "-27.66", "-0x1b.a8#10", Less);
+    test(
+        "1/1000000000000",
+        10,
+        Nearest,
+        "-27.62",
+        "-0x1b.a0#10",
+        Greater,
+    );
+    test(
+        "1/1000000000000",
+        100,
+        Nearest,
+        "-27.63102111592854820821589745621",
+        "-0x1b.a18a998fffa0444bf0482828#100",
+        Greater,
+    );
+    test("1/3", 10, NearestTiesAway, "-1.098", "-0x1.190#10", Greater);
+    test("1/3", 10, ToOdd, "-1.1", "-0x1.198#10", Less);
+}
+
+#[test]
+fn ln_rational_prec_round_fail() {
+    assert_panic!(Float::ln_rational_prec_round(Rational::ONE, 0, Floor));
+    assert_panic!(Float::ln_rational_prec_round(
+        Rational::from(2u32),
+        1,
+        Exact
+    ));
+    assert_panic!(Float::ln_rational_prec_round_ref(&Rational::ONE, 0, Floor));
+    assert_panic!(Float::ln_rational_prec_round_ref(
+        &Rational::from(2u32),
+        1,
+        Exact
+    ));
+}
+
+#[test]
+fn ln_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (ln, o) = x.clone().ln_prec_round(prec, rm);
+        assert!(ln.is_valid());
+
+        let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.ln_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln, rug_o) = rug_ln_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln)),
+                ComparableFloatRef(&ln),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if ln.is_normal() {
+            assert_eq!(ln.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.ln_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln, o) = Float::NAN.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::INFINITY.ln_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (ln, o) = Float::NEGATIVE_INFINITY.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::ZERO.ln_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+        assert_eq!(
+            Float::NEGATIVE_ZERO.ln_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+
+        let (ln, o) = Float::ONE.ln_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (ln, o) = Float::NEGATIVE_ONE.ln_prec_round(prec, rm);
+        assert!(ln.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_prec_properties_helper(x: Float, prec: u64) {
+    let (ln, o) = x.clone().ln_prec(prec);
+    assert!(ln.is_valid());
+
+    let (ln_alt, o_alt) = x.ln_prec_ref(prec);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let (rug_ln, rug_o) = rug_ln_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln)),
+        ComparableFloatRef(&ln),
+    );
+    assert_eq!(rug_o, o);
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if ln.is_normal() {
+        assert_eq!(ln.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn ln_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        ln_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ln, o) = x.clone().ln_round(rm);
+    assert!(ln.is_valid());
+
+    let (ln_alt, o_alt) = x.ln_round_ref(rm);
+    assert!(ln_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    let (ln_alt, o_alt) = x.ln_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln, rug_o) = rug_ln_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn ln_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        ln_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn ln_1p_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (ln_1p, o) = x.clone().ln_1p_prec_round(prec, rm);
+        assert!(ln_1p.is_valid());
+
+        let (ln_1p_alt, o_alt) = x.ln_1p_prec_round_ref(prec, rm);
+        assert!(ln_1p_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_1p_alt), ComparableFloatRef(&ln_1p));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.ln_1p_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_1p));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_ln_1p, rug_o) = rug_ln_1p_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_ln_1p)),
+                ComparableFloatRef(&ln_1p),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if ln_1p.is_normal() {
+            assert_eq!(ln_1p.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.ln_1p_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (ln_1p, o) = Float::NAN.ln_1p_prec_round(prec, rm);
+        assert!(ln_1p.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::INFINITY.ln_1p_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (ln_1p, o) = Float::NEGATIVE_INFINITY.ln_1p_prec_round(prec, rm);
+        assert!(ln_1p.is_nan());
+        assert_eq!(o, Equal);
+
+        let (ln_1p, o) = Float::ZERO.ln_1p_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(ln_1p), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (ln_1p, o) = Float::NEGATIVE_ZERO.ln_1p_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloat(ln_1p),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::NEGATIVE_ONE.ln_1p_prec_round(prec, rm),
+            (Float::NEGATIVE_INFINITY, Equal)
+        );
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_1p_prec_properties_helper(x: Float, prec: u64) {
+    let (ln_1p, o) = x.clone().ln_1p_prec(prec);
+    assert!(ln_1p.is_valid());
+
+    let (ln_1p_alt, o_alt) = x.ln_1p_prec_ref(prec);
+    assert!(ln_1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_1p_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_1p_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    let (rug_ln_1p, rug_o) = rug_ln_1p_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_ln_1p)),
+        ComparableFloatRef(&ln_1p),
+    );
+    assert_eq!(rug_o, o);
+
+    let (ln_1p_alt, o_alt) = x.ln_1p_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&ln_1p_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    if ln_1p.is_normal() {
+        assert_eq!(ln_1p.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn ln_1p_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        ln_1p_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn ln_1p_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (ln_1p, o) = x.clone().ln_1p_round(rm);
+    assert!(ln_1p.is_valid());
+
+    let (ln_1p_alt, o_alt) = x.ln_1p_round_ref(rm);
+    assert!(ln_1p_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&ln_1p_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.ln_1p_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    let (ln_1p_alt, o_alt) = x.ln_1p_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&ln_1p_alt), ComparableFloatRef(&ln_1p));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_ln_1p, rug_o) = rug_ln_1p_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln_1p)),
+            ComparableFloatRef(&ln_1p),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn ln_1p_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        ln_1p_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn ln_properties() {
+    float_gen().test_properties(|x| {
+        let ln = x.clone().ln();
+        assert!(ln.is_valid());
+
+        let ln_alt = (&x).ln();
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+
+        let ln_alt = x.ln_prec_round_ref(x.significant_bits(), Nearest).0;
+        assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+
+        if x < 0u32 {
+            assert!(ln.is_nan());
+        } else if x > 1u32 {
+            assert!(ln > 0u32);
+        } else if x < 1u32 && x > 0u32 {
+            assert!(ln < 0u32);
+        }
+
+        let rug_ln = rug_ln(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_ln)),
+            ComparableFloatRef(&ln),
+        );
+    });
+}
+
+#[test]
+fn ln_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_2().test_properties(|(x, prec, rm)| {
+        let (ln, o) = Float::ln_rational_prec_round_ref(&x, prec, rm);
+        assert!(ln.is_valid());
+
+        let (ln_alt, o_alt) = Float::ln_rational_prec_round(x.clone(), prec, rm);
+        assert!(ln_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+        assert_eq!(o_alt, o);
+
+        if x < 0u32 {
+            assert!(ln.is_nan());
+            assert_eq!(o, Equal);
+            return;
+        }
+        if let Ok(x) = Float::try_from(&x) {
+            let (ln_alt, o_alt) = x.ln_prec_round(prec, rm);
+            assert_eq!(ComparableFloatRef(&ln_alt), ComparableFloatRef(&ln));
+            assert_eq!(o_alt, o);
+        }
+        if ln.is_normal() {
+            assert_eq!(ln.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+    });
+}
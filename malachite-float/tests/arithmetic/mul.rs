@@ -1613,6 +1613,17 @@ fn test_mul_round() {
     test("NaN", "NaN", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -1630,6 +1641,19 @@ fn test_mul_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -1684,6 +1708,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -1700,6 +1744,17 @@ fn test_mul_round() {
     test("NaN", "NaN", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "-0.0", "-0x0.0", Floor, "NaN", "NaN", Equal);
@@ -1707,6 +1762,17 @@ fn test_mul_round() {
     test("NaN", "NaN", "-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "1.0", "0x1.0#1", Floor, "NaN", "NaN", Equal);
@@ -1714,6 +1780,17 @@ fn test_mul_round() {
     test("NaN", "NaN", "1.0", "0x1.0#1", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "1.0", "0x1.0#1", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
@@ -1725,6 +1802,17 @@ fn test_mul_round() {
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-1.0", "-0x1.0#1", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -1742,6 +1830,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -1761,6 +1862,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -1815,6 +1929,26 @@ fn test_mul_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -1841,6 +1975,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal,
     );
@@ -1860,6 +2007,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal,
     );
@@ -1879,6 +2039,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", Exact, "Infinity", "Infinity", Equal,
     );
@@ -1933,6 +2106,26 @@ fn test_mul_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -1994,6 +2187,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2055,6 +2268,26 @@ fn test_mul_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2116,6 +2349,26 @@ fn test_mul_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2177,6 +2430,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2238,6 +2511,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2304,7 +2597,27 @@ fn test_mul_round() {
         "-Infinity",
         "1.0",
         "0x1.0#1",
-        Exact,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        Exact,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -2360,6 +2673,26 @@ fn test_mul_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2376,6 +2709,17 @@ fn test_mul_round() {
     test("0.0", "0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2393,6 +2737,19 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2447,6 +2804,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -2467,6 +2844,17 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -2484,6 +2872,19 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2501,6 +2902,19 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", Exact, "0.0", "0x0.0", Equal,
     );
@@ -2520,6 +2934,19 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2529,6 +2956,17 @@ fn test_mul_round() {
     test("-0.0", "-0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2546,6 +2984,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2600,6 +3051,26 @@ fn test_mul_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -2626,6 +3097,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2645,6 +3129,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
     );
@@ -2664,6 +3161,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2683,6 +3193,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", Exact, "0.0", "0x0.0", Equal,
     );
@@ -2698,6 +3221,19 @@ fn test_mul_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -2717,6 +3253,19 @@ fn test_mul_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2771,6 +3320,26 @@ fn test_mul_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -2797,6 +3366,19 @@ fn test_mul_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal,
     );
@@ -2816,6 +3398,19 @@ fn test_mul_round() {
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -2835,6 +3430,19 @@ fn test_mul_round() {
     test(
         "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "1.0", "0x1.0#1", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "1.0", "0x1.0#1", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -2889,6 +3497,26 @@ fn test_mul_round() {
         "-0x7b.0#7",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        ToOdd,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -2911,6 +3539,19 @@ fn test_mul_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", Exact, "NaN", "NaN", Equal,
     );
@@ -2930,6 +3571,19 @@ fn test_mul_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2984,6 +3638,26 @@ fn test_mul_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3010,6 +3684,19 @@ fn test_mul_round() {
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3029,6 +3716,19 @@ fn test_mul_round() {
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -3048,6 +3748,19 @@ fn test_mul_round() {
     test(
         "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "123.0", "0x7b.0#7", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "123.0", "0x7b.0#7", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -3102,6 +3815,26 @@ fn test_mul_round() {
         "-0x7b.0#7",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        ToOdd,
+        "-123.0",
+        "-0x7b.0#7",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0#1",
@@ -3128,6 +3861,19 @@ fn test_mul_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Nearest, "2.0", "0x2.0#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", ToOdd, "2.0", "0x2.0#1", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", Exact, "2.0", "0x2.0#1", Equal,
     );
@@ -3145,7 +3891,20 @@ fn test_mul_round() {
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", Up, "2.0", "0x2.0#2", Equal,
     );
     test(
-        "1.0", "0x1.0#1", "2.0", "0x2.0#2", Nearest, "2.0", "0x2.0#2", Equal,
+        "1.0", "0x1.0#1", "2.0", "0x2.0#2", Nearest, "2.0", "0x2.0#2", Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#2", ToOdd, "2.0", "0x2.0#2", Equal,
     );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", Exact, "2.0", "0x2.0#2", Equal,
@@ -3166,6 +3925,19 @@ fn test_mul_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#1", Nearest, "2.0", "0x2.0#2", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#1", ToOdd, "2.0", "0x2.0#2", Equal,
+    );
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#1", Exact, "2.0", "0x2.0#2", Equal,
     );
@@ -3185,6 +3957,19 @@ fn test_mul_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#2", Nearest, "2.0", "0x2.0#2", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#2", ToOdd, "2.0", "0x2.0#2", Equal,
+    );
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#2", Exact, "2.0", "0x2.0#2", Equal,
     );
@@ -3239,6 +4024,26 @@ fn test_mul_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -3300,6 +4105,26 @@ fn test_mul_round() {
         "0x4.7160c6b758b90#53",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "4.442882938158366",
+        "0x4.7160c6b758b90#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "4.442882938158367",
+        "0x4.7160c6b758b94#53",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -3351,6 +4176,26 @@ fn test_mul_round() {
         "-0x4.7160c6b758b90#53",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "-4.442882938158367",
+        "-0x4.7160c6b758b94#53",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -3402,6 +4247,26 @@ fn test_mul_round() {
         "-0x4.7160c6b758b90#53",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-4.442882938158366",
+        "-0x4.7160c6b758b90#53",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "-4.442882938158367",
+        "-0x4.7160c6b758b94#53",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -3453,6 +4318,26 @@ fn test_mul_round() {
         "0x4.7160c6b758b90#53",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "4.442882938158366",
+        "0x4.7160c6b758b90#53",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "4.442882938158367",
+        "0x4.7160c6b758b94#53",
+        Greater,
+    );
 
     // - rm == Floor || rm == Down in mul_float_significands_same_prec_lt_w
     test(
@@ -3588,6 +4473,19 @@ fn test_mul_round() {
     test(
         "1.5", "0x1.8#2", "1.5", "0x1.8#2", Nearest, "2.0", "0x2.0#2", Less,
     );
+    test(
+        "1.5",
+        "0x1.8#2",
+        "1.5",
+        "0x1.8#2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Less,
+    );
+    test(
+        "1.5", "0x1.8#2", "1.5", "0x1.8#2", ToOdd, "3.0", "0x3.0#2", Greater,
+    );
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 || (product & shift_bit) != 0) &&
     //   product.overflowing_add_assign(shift_bit) in mul_float_significands_same_prec_lt_w
     test(
@@ -3626,6 +4524,26 @@ fn test_mul_round() {
         "0x1.0000000000000004#64",
         Less,
     );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        NearestTiesAway,
+        "1.0000000000000000002",
+        "0x1.0000000000000004#64",
+        Less,
+    );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        ToOdd,
+        "1.0000000000000000003",
+        "0x1.0000000000000006#64",
+        Greater,
+    );
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 || product.even()) &&
     //   !product.overflowing_add_assign(1) in mul_float_significands_same_prec_w
     test(
@@ -3689,6 +4607,26 @@ fn test_mul_round() {
         "0x1.0000000000000002#65",
         Less,
     );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        NearestTiesAway,
+        "1.00000000000000000011",
+        "0x1.0000000000000002#65",
+        Less,
+    );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        ToOdd,
+        "1.00000000000000000016",
+        "0x1.0000000000000003#65",
+        Greater,
+    );
     // - l.wrapping_add(2) & (mask >> 2) > 2 in mul_float_significands_same_prec_gt_w_lt_2w
     test(
         "1.00000000000000000011",
@@ -3763,6 +4701,26 @@ fn test_mul_round() {
         "0x1.00000000000000000000000000000002#129",
         Less,
     );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        NearestTiesAway,
+        "1.000000000000000000000000000000000000006",
+        "0x1.00000000000000000000000000000002#129",
+        Less,
+    );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        ToOdd,
+        "1.000000000000000000000000000000000000009",
+        "0x1.00000000000000000000000000000003#129",
+        Greater,
+    );
     // - a0.wrapping_add(4) & (mask >> 2) > 4 in mul_float_significands_same_prec_gt_2w_lt_3w
     // - !decrement_exp in mul_float_significands_same_prec_gt_2w_lt_3w
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 || z_0 & shift_bit != 0) in
@@ -4432,6 +5390,18 @@ fn test_mul_prec_round() {
     test("NaN", "NaN", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -4449,6 +5419,20 @@ fn test_mul_prec_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4508,6 +5492,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -4529,6 +5535,18 @@ fn test_mul_prec_round() {
     test(
         "NaN", "NaN", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -4542,6 +5560,20 @@ fn test_mul_prec_round() {
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4555,7 +5587,21 @@ fn test_mul_prec_round() {
     test("NaN", "NaN", "1.0", "0x1.0#1", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "1.0", "0x1.0#1", 1, Up, "NaN", "NaN", Equal);
     test(
-        "NaN", "NaN", "1.0", "0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", 1, ToOdd, "NaN", "NaN", Equal,
     );
     test(
         "NaN", "NaN", "1.0", "0x1.0#1", 1, Exact, "NaN", "NaN", Equal,
@@ -4574,6 +5620,20 @@ fn test_mul_prec_round() {
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-1.0", "-0x1.0#1", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4593,6 +5653,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4612,6 +5686,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -4671,6 +5759,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -4698,6 +5808,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4717,6 +5841,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -4736,6 +5874,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "1.0", "0x1.0#1", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -4795,6 +5947,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -4862,6 +6036,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -4929,6 +6125,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -4996,6 +6214,28 @@ fn test_mul_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5063,6 +6303,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5130,6 +6392,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5197,6 +6481,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5226,7 +6532,29 @@ fn test_mul_prec_round() {
         "-1.0",
         "-0x1.0#1",
         1,
-        Ceiling,
+        Ceiling,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Down,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        Up,
         "Infinity",
         "Infinity",
         Equal,
@@ -5237,7 +6565,7 @@ fn test_mul_prec_round() {
         "-1.0",
         "-0x1.0#1",
         1,
-        Down,
+        Nearest,
         "Infinity",
         "Infinity",
         Equal,
@@ -5248,7 +6576,7 @@ fn test_mul_prec_round() {
         "-1.0",
         "-0x1.0#1",
         1,
-        Up,
+        NearestTiesAway,
         "Infinity",
         "Infinity",
         Equal,
@@ -5259,7 +6587,7 @@ fn test_mul_prec_round() {
         "-1.0",
         "-0x1.0#1",
         1,
-        Nearest,
+        ToOdd,
         "Infinity",
         "Infinity",
         Equal,
@@ -5285,6 +6613,18 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -5302,6 +6642,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "0.0", "0x0.0", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5361,6 +6715,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -5386,6 +6762,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5405,6 +6795,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -5424,6 +6828,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "1.0", "0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5443,6 +6861,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-1.0", "-0x1.0#1", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -5458,6 +6890,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5477,6 +6923,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5536,6 +6996,28 @@ fn test_mul_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -5563,6 +7045,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -5582,6 +7078,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5601,6 +7111,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "1.0", "0x1.0#1", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -5620,6 +7144,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-1.0", "-0x1.0#1", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5639,6 +7177,20 @@ fn test_mul_prec_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5658,6 +7210,20 @@ fn test_mul_prec_round() {
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5717,6 +7283,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -5744,6 +7332,20 @@ fn test_mul_prec_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -5761,7 +7363,21 @@ fn test_mul_prec_round() {
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal,
     );
     test(
-        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal,
     );
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
@@ -5822,6 +7438,28 @@ fn test_mul_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1.0",
+        "0x1.0#1",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "123.0",
@@ -5878,6 +7516,28 @@ fn test_mul_prec_round() {
         "-0x8.0E+1#1",
         Less,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        ToOdd,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Greater,
+    );
 
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Floor, "NaN", "NaN", Equal,
@@ -5894,6 +7554,20 @@ fn test_mul_prec_round() {
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "123.0", "0x7b.0#7", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "123.0", "0x7b.0#7", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -5913,6 +7587,20 @@ fn test_mul_prec_round() {
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123.0", "0x7b.0#7", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -5972,6 +7660,28 @@ fn test_mul_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -5999,6 +7709,20 @@ fn test_mul_prec_round() {
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "123.0", "0x7b.0#7", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -6018,6 +7742,20 @@ fn test_mul_prec_round() {
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "123.0", "0x7b.0#7", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -6077,6 +7815,28 @@ fn test_mul_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "1.0",
@@ -6133,6 +7893,28 @@ fn test_mul_prec_round() {
         "0x7b.0#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "123.0",
+        "0x7b.0#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -6200,6 +7982,28 @@ fn test_mul_prec_round() {
         "-0x8.0E+1#1",
         Less,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-1.0e2",
+        "-0x8.0E+1#1",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        1,
+        ToOdd,
+        "-6.0e1",
+        "-0x4.0E+1#1",
+        Greater,
+    );
 
     test(
         "-1.0",
@@ -6256,6 +8060,28 @@ fn test_mul_prec_round() {
         "-0x7b.0#10",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "-123.0",
+        "-0x7b.0#10",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "-123.0",
+        "-0x7b.0#10",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0#1",
@@ -6283,6 +8109,20 @@ fn test_mul_prec_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Nearest, "2.0", "0x2.0#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#1", 1, Exact, "2.0", "0x2.0#1", Equal,
     );
@@ -6342,6 +8182,28 @@ fn test_mul_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -6369,6 +8231,20 @@ fn test_mul_prec_round() {
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", 1, Nearest, "2.0", "0x2.0#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "2.0", "0x2.0#2", 1, Exact, "2.0", "0x2.0#1", Equal,
     );
@@ -6412,7 +8288,29 @@ fn test_mul_prec_round() {
         "2.0",
         "0x2.0#2",
         10,
-        Up,
+        Up,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        10,
+        Nearest,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#2",
+        10,
+        NearestTiesAway,
         "2.0",
         "0x2.00#10",
         Equal,
@@ -6423,7 +8321,7 @@ fn test_mul_prec_round() {
         "2.0",
         "0x2.0#2",
         10,
-        Nearest,
+        ToOdd,
         "2.0",
         "0x2.00#10",
         Equal,
@@ -6455,6 +8353,20 @@ fn test_mul_prec_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#1", 1, Nearest, "2.0", "0x2.0#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Equal,
+    );
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#1", 1, Exact, "2.0", "0x2.0#1", Equal,
     );
@@ -6514,6 +8426,28 @@ fn test_mul_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#1",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -6541,6 +8475,20 @@ fn test_mul_prec_round() {
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#2", 1, Nearest, "2.0", "0x2.0#1", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#2", "2.0", "0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Equal,
+    );
     test(
         "1.0", "0x1.0#2", "2.0", "0x2.0#2", 1, Exact, "2.0", "0x2.0#1", Equal,
     );
@@ -6600,6 +8548,28 @@ fn test_mul_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2.0",
+        "0x2.0#2",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -6667,6 +8637,28 @@ fn test_mul_prec_round() {
         "0x2.0#1",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -6734,6 +8726,28 @@ fn test_mul_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2.0",
+        "0x2.00#10",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -6801,6 +8815,28 @@ fn test_mul_prec_round() {
         "0x4.0#1",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -6857,6 +8893,28 @@ fn test_mul_prec_round() {
         "0x4.72#10",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "4.445",
+        "0x4.72#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "4.445",
+        "0x4.72#10",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -6913,6 +8971,28 @@ fn test_mul_prec_round() {
         "-0x4.0#1",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -6969,6 +9049,28 @@ fn test_mul_prec_round() {
         "-0x4.72#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-4.445",
+        "-0x4.72#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-4.445",
+        "-0x4.72#10",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -7025,6 +9127,28 @@ fn test_mul_prec_round() {
         "-0x4.0#1",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "-4.0",
+        "-0x4.0#1",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -7081,6 +9205,28 @@ fn test_mul_prec_round() {
         "-0x4.72#10",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-4.445",
+        "-0x4.72#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-4.445",
+        "-0x4.72#10",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -7137,6 +9283,28 @@ fn test_mul_prec_round() {
         "0x4.0#1",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "4.0",
+        "0x4.0#1",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -7742,6 +9910,8 @@ fn test_mul_rational_round() {
     test("NaN", "NaN", "123", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -7757,7 +9927,19 @@ fn test_mul_rational_round() {
         "Infinity", "Infinity", "123", Up, "Infinity", "Infinity", Equal,
     );
     test(
-        "Infinity", "Infinity", "123", Nearest, "Infinity", "Infinity", Equal,
+        "Infinity", "Infinity", "123", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", ToOdd, "Infinity", "Infinity", Equal,
     );
     test(
         "Infinity", "Infinity", "123", Exact, "Infinity", "Infinity", Equal,
@@ -7808,6 +9990,24 @@ fn test_mul_rational_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7823,6 +10023,8 @@ fn test_mul_rational_round() {
     test("NaN", "NaN", "0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", Exact, "NaN", "NaN", Equal);
 
     test("Infinity", "Infinity", "0", Floor, "NaN", "NaN", Equal);
@@ -7830,6 +10032,16 @@ fn test_mul_rational_round() {
     test("Infinity", "Infinity", "0", Down, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", Up, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("Infinity", "Infinity", "0", ToOdd, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", Exact, "NaN", "NaN", Equal);
 
     test("-Infinity", "-Infinity", "0", Floor, "NaN", "NaN", Equal);
@@ -7837,6 +10049,16 @@ fn test_mul_rational_round() {
     test("-Infinity", "-Infinity", "0", Down, "NaN", "NaN", Equal);
     test("-Infinity", "-Infinity", "0", Up, "NaN", "NaN", Equal);
     test("-Infinity", "-Infinity", "0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", "0", ToOdd, "NaN", "NaN", Equal);
     test("-Infinity", "-Infinity", "0", Exact, "NaN", "NaN", Equal);
 
     test("0.0", "0x0.0", "0", Floor, "0.0", "0x0.0", Equal);
@@ -7844,6 +10066,8 @@ fn test_mul_rational_round() {
     test("0.0", "0x0.0", "0", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", Floor, "-0.0", "-0x0.0", Equal);
@@ -7851,6 +10075,16 @@ fn test_mul_rational_round() {
     test("-0.0", "-0x0.0", "0", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "123", Floor, "0.0", "0x0.0", Equal);
@@ -7858,6 +10092,16 @@ fn test_mul_rational_round() {
     test("0.0", "0x0.0", "123", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "123", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "123", Floor, "-0.0", "-0x0.0", Equal);
@@ -7865,6 +10109,16 @@ fn test_mul_rational_round() {
     test("-0.0", "-0x0.0", "123", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "123", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "-123", Floor, "-0.0", "-0x0.0", Equal);
@@ -7872,6 +10126,16 @@ fn test_mul_rational_round() {
     test("0.0", "0x0.0", "-123", Down, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Up, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "-123", ToOdd, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", Exact, "-0.0", "-0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "-123", Floor, "0.0", "0x0.0", Equal);
@@ -7879,6 +10143,16 @@ fn test_mul_rational_round() {
     test("-0.0", "-0x0.0", "-123", Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "-123", ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", Exact, "0.0", "0x0.0", Equal);
 
     test("0.0", "0x0.0", "1/3", Floor, "0.0", "0x0.0", Equal);
@@ -7886,6 +10160,16 @@ fn test_mul_rational_round() {
     test("0.0", "0x0.0", "1/3", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "1/3", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "1/3", Floor, "-0.0", "-0x0.0", Equal);
@@ -7893,6 +10177,16 @@ fn test_mul_rational_round() {
     test("-0.0", "-0x0.0", "1/3", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "1/3", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", Exact, "-0.0", "-0x0.0", Equal);
 
     test("123.0", "0x7b.0#7", "1", Floor, "123.0", "0x7b.0#7", Equal);
@@ -7904,6 +10198,16 @@ fn test_mul_rational_round() {
     test(
         "123.0", "0x7b.0#7", "1", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "1", ToOdd, "123.0", "0x7b.0#7", Equal);
     test("123.0", "0x7b.0#7", "1", Exact, "123.0", "0x7b.0#7", Equal);
 
     test("123.0", "0x7b.0#7", "0", Floor, "0.0", "0x0.0", Equal);
@@ -7911,6 +10215,16 @@ fn test_mul_rational_round() {
     test("123.0", "0x7b.0#7", "0", Down, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", Up, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "0", ToOdd, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", Exact, "0.0", "0x0.0", Equal);
 
     test("-123.0", "-0x7b.0#7", "0", Floor, "-0.0", "-0x0.0", Equal);
@@ -7918,6 +10232,16 @@ fn test_mul_rational_round() {
     test("-123.0", "-0x7b.0#7", "0", Down, "-0.0", "-0x0.0", Equal);
     test("-123.0", "-0x7b.0#7", "0", Up, "-0.0", "-0x0.0", Equal);
     test("-123.0", "-0x7b.0#7", "0", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-123.0", "-0x7b.0#7", "0", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-123.0", "-0x7b.0#7", "0", Exact, "-0.0", "-0x0.0", Equal);
 
     test("1.0", "0x1.0#1", "2", Floor, "2.0", "0x2.0#1", Equal);
@@ -7925,6 +10249,16 @@ fn test_mul_rational_round() {
     test("1.0", "0x1.0#1", "2", Down, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", Up, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", ToOdd, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", Exact, "2.0", "0x2.0#1", Equal);
 
     test("1.0", "0x1.0#2", "2", Floor, "2.0", "0x2.0#2", Equal);
@@ -7932,6 +10266,16 @@ fn test_mul_rational_round() {
     test("1.0", "0x1.0#2", "2", Down, "2.0", "0x2.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Up, "2.0", "0x2.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Nearest, "2.0", "0x2.0#2", Equal);
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#2",
+        Equal,
+    );
+    test("1.0", "0x1.0#2", "2", ToOdd, "2.0", "0x2.0#2", Equal);
     test("1.0", "0x1.0#2", "2", Exact, "2.0", "0x2.0#2", Equal);
 
     test("1.0", "0x1.000#10", "2", Floor, "2.0", "0x2.00#10", Equal);
@@ -7939,6 +10283,16 @@ fn test_mul_rational_round() {
     test("1.0", "0x1.000#10", "2", Down, "2.0", "0x2.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Up, "2.0", "0x2.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Nearest, "2.0", "0x2.00#10", Equal);
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2",
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("1.0", "0x1.000#10", "2", ToOdd, "2.0", "0x2.00#10", Equal);
     test("1.0", "0x1.000#10", "2", Exact, "2.0", "0x2.00#10", Equal);
 
     test(
@@ -7986,6 +10340,24 @@ fn test_mul_rational_round() {
         "0x0.556#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        NearestTiesAway,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        ToOdd,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -8032,6 +10404,24 @@ fn test_mul_rational_round() {
         "0x0.55555555555555555555555558#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        NearestTiesAway,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        ToOdd,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Greater,
+    );
 
     test(
         "3.0",
@@ -8078,6 +10468,24 @@ fn test_mul_rational_round() {
         "0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.0000000000000000000000000#100",
@@ -8133,6 +10541,24 @@ fn test_mul_rational_round() {
         "0x1.0c152382d7365#53",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "1.0471975511965976",
+        "0x1.0c152382d7365#53",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -8179,6 +10605,24 @@ fn test_mul_rational_round() {
         "-0x1.0c152382d7365#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        NearestTiesAway,
+        "-1.0471975511965976",
+        "-0x1.0c152382d7365#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        ToOdd,
+        "-1.0471975511965976",
+        "-0x1.0c152382d7365#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8225,6 +10669,24 @@ fn test_mul_rational_round() {
         "-0x1.0c152382d7365#53",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "-1.0471975511965976",
+        "-0x1.0c152382d7365#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "-1.0471975511965976",
+        "-0x1.0c152382d7365#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -8422,6 +10884,8 @@ fn test_mul_rational_prec_round() {
     test("NaN", "NaN", "123", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "123", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "123", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -8439,16 +10903,49 @@ fn test_mul_rational_prec_round() {
     test(
         "Infinity", "Infinity", "123", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "123",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "123", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "123", 1, Exact, "Infinity", "Infinity", Equal,
     );
-
+
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "123",
+        1,
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
         "123",
         1,
-        Floor,
+        Down,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -8458,7 +10955,7 @@ fn test_mul_rational_prec_round() {
         "-Infinity",
         "123",
         1,
-        Ceiling,
+        Up,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -8468,7 +10965,7 @@ fn test_mul_rational_prec_round() {
         "-Infinity",
         "123",
         1,
-        Down,
+        Nearest,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -8478,7 +10975,7 @@ fn test_mul_rational_prec_round() {
         "-Infinity",
         "123",
         1,
-        Up,
+        NearestTiesAway,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -8488,7 +10985,7 @@ fn test_mul_rational_prec_round() {
         "-Infinity",
         "123",
         1,
-        Nearest,
+        ToOdd,
         "-Infinity",
         "-Infinity",
         Equal,
@@ -8509,6 +11006,8 @@ fn test_mul_rational_prec_round() {
     test("NaN", "NaN", "0", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test("Infinity", "Infinity", "0", 1, Floor, "NaN", "NaN", Equal);
@@ -8516,6 +11015,17 @@ fn test_mul_rational_prec_round() {
     test("Infinity", "Infinity", "0", 1, Down, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", 1, Up, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        "0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("Infinity", "Infinity", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("Infinity", "Infinity", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test("-Infinity", "-Infinity", "0", 1, Floor, "NaN", "NaN", Equal);
@@ -8541,6 +11051,17 @@ fn test_mul_rational_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", "0", 1, ToOdd, "NaN", "NaN", Equal);
     test("-Infinity", "-Infinity", "0", 1, Exact, "NaN", "NaN", Equal);
 
     test("0.0", "0x0.0", "0", 1, Floor, "0.0", "0x0.0", Equal);
@@ -8548,6 +11069,17 @@ fn test_mul_rational_prec_round() {
     test("0.0", "0x0.0", "0", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -8555,6 +11087,17 @@ fn test_mul_rational_prec_round() {
     test("-0.0", "-0x0.0", "0", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "123", 1, Floor, "0.0", "0x0.0", Equal);
@@ -8562,6 +11105,17 @@ fn test_mul_rational_prec_round() {
     test("0.0", "0x0.0", "123", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "123", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "123", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "123", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -8569,6 +11123,17 @@ fn test_mul_rational_prec_round() {
     test("-0.0", "-0x0.0", "123", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "123",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "123", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "123", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "-123", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -8576,6 +11141,17 @@ fn test_mul_rational_prec_round() {
     test("0.0", "0x0.0", "-123", 1, Down, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Up, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "-123", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("0.0", "0x0.0", "-123", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "-123", 1, Floor, "0.0", "0x0.0", Equal);
@@ -8583,6 +11159,17 @@ fn test_mul_rational_prec_round() {
     test("-0.0", "-0x0.0", "-123", 1, Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "-123", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", "-123", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("0.0", "0x0.0", "1/3", 1, Floor, "0.0", "0x0.0", Equal);
@@ -8590,6 +11177,17 @@ fn test_mul_rational_prec_round() {
     test("0.0", "0x0.0", "1/3", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "1/3", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "1/3", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "1/3", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -8597,6 +11195,17 @@ fn test_mul_rational_prec_round() {
     test("-0.0", "-0x0.0", "1/3", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "1/3", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "1/3", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test(
@@ -8649,12 +11258,43 @@ fn test_mul_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "1",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test("123.0", "0x7b.0#7", "0", 1, Floor, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", 1, Ceiling, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", 1, Down, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", 1, Up, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("123.0", "0x7b.0#7", "0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test(
@@ -8689,6 +11329,26 @@ fn test_mul_rational_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        "0",
+        1,
+        ToOdd,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
     test(
         "-123.0",
         "-0x7b.0#7",
@@ -8705,6 +11365,17 @@ fn test_mul_rational_prec_round() {
     test("1.0", "0x1.0#1", "2", 1, Down, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Up, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", 1, ToOdd, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#1", "2", 1, Exact, "2.0", "0x2.0#1", Equal);
 
     test("1.0", "0x1.0#1", "2", 10, Floor, "2.0", "0x2.00#10", Equal);
@@ -8730,6 +11401,17 @@ fn test_mul_rational_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "2", 10, ToOdd, "2.0", "0x2.00#10", Equal);
     test("1.0", "0x1.0#1", "2", 10, Exact, "2.0", "0x2.00#10", Equal);
 
     test("1.0", "0x1.0#2", "2", 1, Floor, "2.0", "0x2.0#1", Equal);
@@ -8737,6 +11419,17 @@ fn test_mul_rational_prec_round() {
     test("1.0", "0x1.0#2", "2", 1, Down, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#2", "2", 1, Up, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#2", "2", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#2", "2", 1, ToOdd, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.0#2", "2", 1, Exact, "2.0", "0x2.0#1", Equal);
 
     test("1.0", "0x1.0#2", "2", 10, Floor, "2.0", "0x2.00#10", Equal);
@@ -8762,6 +11455,17 @@ fn test_mul_rational_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "2",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#2", "2", 10, ToOdd, "2.0", "0x2.00#10", Equal);
     test("1.0", "0x1.0#2", "2", 10, Exact, "2.0", "0x2.00#10", Equal);
 
     test("1.0", "0x1.000#10", "2", 1, Floor, "2.0", "0x2.0#1", Equal);
@@ -8787,6 +11491,17 @@ fn test_mul_rational_prec_round() {
         "0x2.0#1",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.000#10", "2", 1, ToOdd, "2.0", "0x2.0#1", Equal);
     test("1.0", "0x1.000#10", "2", 1, Exact, "2.0", "0x2.0#1", Equal);
 
     test(
@@ -8830,6 +11545,26 @@ fn test_mul_rational_prec_round() {
         "0x2.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "2",
+        10,
+        ToOdd,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -8864,6 +11599,17 @@ fn test_mul_rational_prec_round() {
         "0x0.4#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test("1.0", "0x1.000#10", "1/3", 1, ToOdd, "0.2", "0x0.4#1", Less);
 
     test(
         "1.0",
@@ -8915,6 +11661,26 @@ fn test_mul_rational_prec_round() {
         "0x0.556#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "1/3",
+        10,
+        ToOdd,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -8961,7 +11727,27 @@ fn test_mul_rational_prec_round() {
         "0x1.0000000000000000000000000#100",
         "1/3",
         1,
-        Nearest,
+        Nearest,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        1,
+        ToOdd,
         "0.2",
         "0x0.4#1",
         Less,
@@ -9017,12 +11803,43 @@ fn test_mul_rational_prec_round() {
         "0x0.556#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1/3",
+        10,
+        ToOdd,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
 
     test("3.0", "0x3.0#2", "2", 1, Floor, "4.0", "0x4.0#1", Less);
     test("3.0", "0x3.0#2", "2", 1, Ceiling, "8.0", "0x8.0#1", Greater);
     test("3.0", "0x3.0#2", "2", 1, Down, "4.0", "0x4.0#1", Less);
     test("3.0", "0x3.0#2", "2", 1, Up, "8.0", "0x8.0#1", Greater);
     test("3.0", "0x3.0#2", "2", 1, Nearest, "8.0", "0x8.0#1", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2",
+        1,
+        NearestTiesAway,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "2", 1, ToOdd, "4.0", "0x4.0#1", Less);
 
     test("3.0", "0x3.0#2", "2", 10, Floor, "6.0", "0x6.00#10", Equal);
     test(
@@ -9047,6 +11864,17 @@ fn test_mul_rational_prec_round() {
         "0x6.00#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2",
+        10,
+        NearestTiesAway,
+        "6.0",
+        "0x6.00#10",
+        Equal,
+    );
+    test("3.0", "0x3.0#2", "2", 10, ToOdd, "6.0", "0x6.00#10", Equal);
     test("3.0", "0x3.0#2", "2", 10, Exact, "6.0", "0x6.00#10", Equal);
 
     test("3.0", "0x3.00#10", "2", 1, Floor, "4.0", "0x4.0#1", Less);
@@ -9072,6 +11900,17 @@ fn test_mul_rational_prec_round() {
         "0x8.0#1",
         Greater,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "2",
+        1,
+        NearestTiesAway,
+        "8.0",
+        "0x8.0#1",
+        Greater,
+    );
+    test("3.0", "0x3.00#10", "2", 1, ToOdd, "4.0", "0x4.0#1", Less);
 
     test(
         "3.0",
@@ -9105,6 +11944,26 @@ fn test_mul_rational_prec_round() {
         "0x6.00#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "2",
+        10,
+        NearestTiesAway,
+        "6.0",
+        "0x6.00#10",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "2",
+        10,
+        ToOdd,
+        "6.0",
+        "0x6.00#10",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.00#10",
@@ -9139,6 +11998,17 @@ fn test_mul_rational_prec_round() {
         "0x1.0#1",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test("3.0", "0x3.00#10", "1/3", 1, ToOdd, "1.0", "0x1.0#1", Equal);
     test("3.0", "0x3.00#10", "1/3", 1, Exact, "1.0", "0x1.0#1", Equal);
 
     test(
@@ -9191,6 +12061,26 @@ fn test_mul_rational_prec_round() {
         "0x1.000#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.00#10",
+        "1/3",
+        10,
+        ToOdd,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.00#10",
@@ -9252,6 +12142,26 @@ fn test_mul_rational_prec_round() {
         "0x1.0#1",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.0000000000000000000000000#100",
@@ -9313,6 +12223,26 @@ fn test_mul_rational_prec_round() {
         "0x1.000#10",
         Equal,
     );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        "1/3",
+        10,
+        ToOdd,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
     test(
         "3.0",
         "0x3.0000000000000000000000000#100",
@@ -9374,6 +12304,26 @@ fn test_mul_rational_prec_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -9425,6 +12375,26 @@ fn test_mul_rational_prec_round() {
         "0x1.0c0#10",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "1.047",
+        "0x1.0c0#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "1.049",
+        "0x1.0c8#10",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -9476,6 +12446,26 @@ fn test_mul_rational_prec_round() {
         "-0x1.0#1",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        1,
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        1,
+        ToOdd,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -9527,6 +12517,26 @@ fn test_mul_rational_prec_round() {
         "-0x1.0c0#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        NearestTiesAway,
+        "-1.047",
+        "-0x1.0c0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        ToOdd,
+        "-1.049",
+        "-0x1.0c8#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -9578,6 +12588,26 @@ fn test_mul_rational_prec_round() {
         "-0x1.0#1",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        1,
+        ToOdd,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -9629,6 +12659,26 @@ fn test_mul_rational_prec_round() {
         "-0x1.0c0#10",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "-1.047",
+        "-0x1.0c0#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "-1.049",
+        "-0x1.0c8#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -9680,6 +12730,26 @@ fn test_mul_rational_prec_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -0,0 +1,320 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::test_util::arithmetic::mul_add::{
+    mul_add_prec_round_naive, rug_mul_add_prec_round, rug_mul_add_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{float_pair_gen, float_triple_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering::*};
+use std::panic::catch_unwind;
+
+const PRECS: [u64; 4] = [1, 10, 64, 100];
+
+#[test]
+fn test_mul_add_prec_round() {
+    let test = |s, t, u, prec: u64, rm: RoundingMode, out: &str, o_out| {
+        let x = parse_hex_string(s);
+        let y = parse_hex_string(t);
+        let z = parse_hex_string(u);
+        let (r, o) = x.clone().mul_add_prec_round(y.clone(), z.clone(), prec, rm);
+        assert!(r.is_valid());
+        assert_eq!(to_hex_string(&r), out);
+        assert_eq!(o, o_out);
+
+        let (r_alt, o_alt) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_add_prec_round_assign(y.clone(), z.clone(), prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&r));
+        assert_eq!(o_alt, o);
+
+        let (r_alt, o_alt) = mul_add_prec_round_naive(x.clone(), y.clone(), z.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_r, rug_o) = rug_mul_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_r)),
+                ComparableFloatRef(&r)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    // 0.1 * 10 - 1 is exactly 2^-54, and separate rounding of the product would give 0
+    test(
+        "0x0.1999999999999a#53",
+        "0xa.0#3",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "0x4.00E-14#10",
+        Equal,
+    );
+    test(
+        "0x0.1999999999999a#53",
+        "0xa.0#3",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0x4.00E-14#10",
+        Equal,
+    );
+    test(
+        "0x0.1999999999999a#53",
+        "0xa.0#3",
+        "-0x1.0#1",
+        53,
+        Nearest,
+        "0x4.0000000000000E-14#53",
+        Equal,
+    );
+    test(
+        "0x1.8#2",
+        "0x2.0#1",
+        "0x0.4#1",
+        10,
+        Exact,
+        "0x3.40#10",
+        Equal,
+    );
+
+    test(
+        "0x1.0E+100#1",
+        "0x1.0E+100#1",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0x1.000E+200#10",
+        Less,
+    );
+    test(
+        "0x1.0E+100#1",
+        "0x1.0E+100#1",
+        "-0x1.0#1",
+        10,
+        Down,
+        "0xf.fcE+199#10",
+        Less,
+    );
+    test(
+        "0x1.0E+100#1",
+        "0x1.0E+100#1",
+        "-0x1.0#1",
+        10,
+        Up,
+        "0x1.000E+200#10",
+        Greater,
+    );
+
+    test(
+        "0x1.0#1", "0x1.0#1", "-0x1.0#1", 10, Nearest, "0x0.0", Equal,
+    );
+    test("0x1.0#1", "0x1.0#1", "-0x1.0#1", 10, Floor, "-0x0.0", Equal);
+    test("-0x0.0", "0x1.0#1", "-0x0.0", 10, Nearest, "-0x0.0", Equal);
+    test("0x0.0", "0x1.0#1", "-0x0.0", 10, Nearest, "0x0.0", Equal);
+
+    test("0x0.0", "Infinity", "0x1.0#1", 10, Nearest, "NaN", Equal);
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "-Infinity",
+        10,
+        Nearest,
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "-0x1.0#1",
+        "-Infinity",
+        10,
+        Nearest,
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0x1.0#1", "0x1.0#1", "Infinity", 10, Nearest, "Infinity", Equal,
+    );
+    test("NaN", "0x1.0#1", "0x1.0#1", 10, Nearest, "NaN", Equal);
+
+    test("0x1.8#2", "0x1.8#2", "0x0.0", 2, Nearest, "0x2.0#2", Less);
+    test(
+        "0x1.8#2",
+        "0x1.8#2",
+        "0x0.0",
+        2,
+        NearestTiesAway,
+        "0x2.0#2",
+        Less,
+    );
+    test("0x1.8#2", "0x1.8#2", "0x0.0", 2, ToOdd, "0x3.0#2", Greater);
+}
+
+#[test]
+fn mul_add_prec_round_fail() {
+    assert_panic!(Float::ONE.mul_add_prec_round(Float::ONE, Float::ONE, 0, Floor));
+    assert_panic!(Float::ONE.mul_add_prec_round_ref_ref_ref(&Float::ONE, &Float::ONE, 0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_add_prec_round_assign(Float::ONE, Float::ONE, 0, Floor)
+    });
+    assert_panic!(Float::from(3).mul_add_prec_round(Float::from(3), Float::ONE, 1, Exact));
+}
+
+fn mul_add_prec_round_properties_helper(x: Float, y: Float, z: Float, prec: u64, rm: RoundingMode) {
+    let (r, o) = x.clone().mul_add_prec_round(y.clone(), z.clone(), prec, rm);
+    assert!(r.is_valid());
+
+    let (r_alt, o_alt) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, rm);
+    assert!(r_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.mul_add_prec_round_assign(y.clone(), z.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&r));
+    assert_eq!(o_alt, o);
+
+    let (r_alt, o_alt) = mul_add_prec_round_naive(x.clone(), y.clone(), z.clone(), prec, rm);
+    assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_r, rug_o) = rug_mul_add_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_r)),
+            ComparableFloatRef(&r)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if r.is_finite() && r != 0u32 {
+        assert_eq!(r.get_prec(), Some(prec));
+        let r_exact =
+            Rational::exact_from(&x) * Rational::exact_from(&y) + Rational::exact_from(&z);
+        assert_eq!(r.partial_cmp(&r_exact), Some(o));
+    }
+
+    let (r_alt, o_alt) = y.mul_add_prec_round_ref_ref_ref(&x, &z, prec, rm);
+    assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+    assert_eq!(o_alt, o);
+
+    let (r_alt, o_alt) = Float::dot_prec_round([x, Float::ONE], [y, z], prec, rm);
+    assert_eq!(ComparableFloat(r_alt), ComparableFloat(r));
+    assert_eq!(o_alt, o);
+}
+
+#[test]
+fn mul_add_prec_round_properties() {
+    float_triple_gen().test_properties(|(x, y, z)| {
+        for prec in PRECS {
+            let (_, o) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, Floor);
+            for rm in exhaustive_rounding_modes() {
+                if rm != Exact || o == Equal {
+                    mul_add_prec_round_properties_helper(x.clone(), y.clone(), z.clone(), prec, rm);
+                }
+            }
+
+            let (r, o) = x.clone().mul_add_prec(y.clone(), z.clone(), prec);
+            assert!(r.is_valid());
+            let (r_alt, o_alt) = x.mul_add_prec_ref_ref_ref(&y, &z, prec);
+            assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+            assert_eq!(o_alt, o);
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.mul_add_prec_assign(y.clone(), z.clone(), prec);
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&r));
+            assert_eq!(o_alt, o);
+            let (r_alt, o_alt) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, Nearest);
+            assert_eq!(ComparableFloat(r_alt), ComparableFloat(r));
+            assert_eq!(o_alt, o);
+        }
+
+        let prec = max(
+            max(x.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (r, o) = x.clone().mul_add_round(y.clone(), z.clone(), rm);
+            assert!(r.is_valid());
+            let (r_alt, o_alt) = x.mul_add_round_ref_ref_ref(&y, &z, rm);
+            assert_eq!(ComparableFloatRef(&r_alt), ComparableFloatRef(&r));
+            assert_eq!(o_alt, o);
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.mul_add_round_assign(y.clone(), z.clone(), rm);
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&r));
+            assert_eq!(o_alt, o);
+            let (r_alt, o_alt) = x.mul_add_prec_round_ref_ref_ref(&y, &z, prec, rm);
+            assert_eq!(ComparableFloat(r_alt), ComparableFloat(r.clone()));
+            assert_eq!(o_alt, o);
+
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_r, rug_o) = rug_mul_add_round(
+                    &rug::Float::exact_from(&x),
+                    &rug::Float::exact_from(&y),
+                    &rug::Float::exact_from(&z),
+                    rm,
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_r)),
+                    ComparableFloatRef(&r)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+
+    float_pair_gen().test_properties(|(x, z)| {
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            // x * 1 + z is x + z, and x * 0 + z is z, unless x is infinite or NaN
+            let (r, o) = x.mul_add_prec_round_ref_ref_ref(&Float::ONE, &z, 10, rm);
+            let (r_alt, o_alt) = x.add_prec_round_ref_ref(&z, 10, rm);
+            assert_eq!(ComparableFloat(r_alt), ComparableFloat(r));
+            assert_eq!(o_alt, o);
+
+            if x.is_finite() && z != 0u32 {
+                let (r, o) = x.mul_add_prec_round_ref_ref_ref(&Float::ZERO, &z, 10, rm);
+                let (r_alt, o_alt) = Float::from_float_prec_round_ref(&z, 10, rm);
+                assert_eq!(ComparableFloat(r_alt), ComparableFloat(r));
+                assert_eq!(o_alt, o);
+            }
+        }
+    });
+}
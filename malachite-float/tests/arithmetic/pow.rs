@@ -0,0 +1,1448 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::{Parity, Pow, PowAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::test_util::arithmetic::pow::{rug_pow_integer_prec_round, rug_pow_prec_round};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5, float_integer_pair_gen,
+    float_unsigned_rounding_mode_triple_gen_var_6,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::integer::Integer;
+use std::panic::catch_unwind;
+
+fn check_integer_pow(
+    x: &Float,
+    exp: &Integer,
+    prec: u64,
+    rm: RoundingMode,
+    pow: &Float,
+    o: Ordering,
+) {
+    assert!(pow.is_valid());
+    let (pow_alt, o_alt) = x.clone().pow_integer_prec_round(exp, prec, rm);
+    assert!(pow_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(pow));
+    assert_eq!(o_alt, o);
+
+    let (pow_alt, o_alt) = x.pow_integer_prec_round_ref(exp, prec, rm);
+    assert!(pow_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(pow));
+    assert_eq!(o_alt, o);
+
+    let mut pow_alt = x.clone();
+    let o_alt = pow_alt.pow_integer_prec_round_assign(exp, prec, rm);
+    assert!(pow_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(pow));
+    assert_eq!(o_alt, o);
+
+    if rm == Nearest {
+        let (pow_alt, o_alt) = x.pow_integer_prec_ref(exp, prec);
+        assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(pow));
+        assert_eq!(o_alt, o);
+    }
+    if x.get_prec() == Some(prec) {
+        let (pow_alt, o_alt) = x.pow_integer_round_ref(exp, rm);
+        assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(pow));
+        assert_eq!(o_alt, o);
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_pow, rug_o) = rug_pow_integer_prec_round(
+            &rug::Float::exact_from(x),
+            &rug::Integer::from(exp),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_pow)),
+            ComparableFloatRef(pow),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn test_pow_u64_prec_round() {
+    let test = |s, s_hex, exp: u64, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (pow, o) = x.clone().pow_u64_prec_round(exp, prec, rm);
+        assert_eq!(pow.to_string(), out);
+        assert_eq!(to_hex_string(&pow), out_hex);
+        assert_eq!(o, o_out);
+
+        let (pow_alt, o_alt) = x.pow_u64_prec_round_ref(exp, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut pow_alt = x.clone();
+        let o_alt = pow_alt.pow_u64_prec_round_assign(exp, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        check_integer_pow(&x, &Integer::from(exp), prec, rm, &pow, o);
+    };
+    test("NaN", "NaN", 0, 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("NaN", "NaN", 3, 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 3, 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        10,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 3, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 3, 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 2, 10, Floor, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 0, 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 100, 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("3.0", "0x3.0#2", 3, 10, Exact, "27.0", "0x1b.00#10", Equal);
+    test("3.0", "0x3.0#2", 3, 2, Floor, "24.0", "0x18.0#2", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        2,
+        Ceiling,
+        "3.0e1",
+        "0x2.0E+1#2",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 3, 2, Nearest, "24.0", "0x18.0#2", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        5,
+        Floor,
+        "31.0",
+        "0x1f.0#5",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        5,
+        Ceiling,
+        "32.0",
+        "0x20.0#5",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        5,
+        Nearest,
+        "31.0",
+        "0x1f.0#5",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        20,
+        Floor,
+        "31.00626",
+        "0x1f.019a#20",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        20,
+        Ceiling,
+        "31.00629",
+        "0x1f.019c#20",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        20,
+        Nearest,
+        "31.00629",
+        "0x1f.019c#20",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        53,
+        Nearest,
+        "31.006276680299816",
+        "0x1f.019b59389d7b#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        20,
+        Floor,
+        "-31.00629",
+        "-0x1f.019c#20",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        20,
+        Ceiling,
+        "-31.00626",
+        "-0x1f.019a#20",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        20,
+        Down,
+        "9.8696",
+        "0x9.de9e#20",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        Up,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        100,
+        20,
+        Nearest,
+        "4.065609e17",
+        "0x5.a4650E+14#20",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        1000,
+        10,
+        Floor,
+        "9.33e-302",
+        "0x1.000E-250#10",
+        Equal,
+    );
+    test(
+        "1.00000006",
+        "0x1.000001#25",
+        1000000,
+        20,
+        Nearest,
+        "1.061417",
+        "0x1.0fb90#20",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        2,
+        3,
+        NearestTiesAway,
+        "10.0",
+        "0xa.0#3",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 2, 3, ToOdd, "10.0", "0xa.0#3", Greater);
+}
+
+#[test]
+fn pow_u64_prec_round_fail() {
+    assert_panic!(Float::ONE.pow_u64_prec_round(2, 0, Floor));
+    assert_panic!(Float::ONE.pow_u64_prec_round_ref(2, 0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.pow_u64_prec_round_assign(2, 0, Floor)
+    });
+
+    let x = Float::from(3);
+    assert_panic!(x.clone().pow_u64_prec_round(3, 1, Exact));
+    assert_panic!(x.pow_u64_prec_round_ref(3, 1, Exact));
+    assert_panic!({
+        let mut x = x.clone();
+        x.pow_u64_prec_round_assign(3, 1, Exact)
+    });
+}
+
+#[test]
+fn test_pow_i64_prec_round() {
+    let test = |s, s_hex, exp: i64, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (pow, o) = x.clone().pow_i64_prec_round(exp, prec, rm);
+        assert_eq!(pow.to_string(), out);
+        assert_eq!(to_hex_string(&pow), out_hex);
+        assert_eq!(o, o_out);
+
+        let (pow_alt, o_alt) = x.pow_i64_prec_round_ref(exp, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut pow_alt = x.clone();
+        let o_alt = pow_alt.pow_i64_prec_round_assign(exp, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        check_integer_pow(&x, &Integer::from(exp), prec, rm, &pow, o);
+    };
+    test("NaN", "NaN", 0, 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("NaN", "NaN", -3, 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", -1, 10, Floor, "0.0", "0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        -1,
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        -2,
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", -3, 10, Floor, "Infinity", "Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        -3,
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", -2, 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test("3.0", "0x3.0#2", -1, 10, Floor, "0.333", "0x0.554#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        -1,
+        10,
+        Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        -1,
+        10,
+        Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        -3,
+        10,
+        Exact,
+        "0.125",
+        "0x0.200#10",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        -3,
+        10,
+        Nearest,
+        "-0.125",
+        "-0x0.200#10",
+        Equal,
+    );
+    test(
+        "1.5",
+        "0x1.8#2",
+        -3,
+        53,
+        Nearest,
+        "0.29629629629629628",
+        "0x0.4bda12f684bda0#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        -3,
+        53,
+        Nearest,
+        "0.032251534433199495",
+        "0x0.0841a2f5a2ebbf8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        -10,
+        53,
+        Floor,
+        "0.000010678279226861537",
+        "0x0.0000b326dc2cb0d3e0#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        -10,
+        53,
+        Ceiling,
+        "0.000010678279226861539",
+        "0x0.0000b326dc2cb0d3e8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        -3,
+        20,
+        Floor,
+        "-0.03225154",
+        "-0x0.0841a3#20",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        -3,
+        20,
+        Ceiling,
+        "-0.03225148",
+        "-0x0.0841a2#20",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        53,
+        Nearest,
+        "93648.04747608298",
+        "0x16dd0.0c27647fc#53",
+        Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        -1000,
+        10,
+        Floor,
+        "1.072e301",
+        "0x1.000E+250#10",
+        Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        2,
+        3,
+        NearestTiesAway,
+        "10.0",
+        "0xa.0#3",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 2, 3, ToOdd, "10.0", "0xa.0#3", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        -2,
+        10,
+        NearestTiesAway,
+        "0.1111",
+        "0x0.1c70#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        -2,
+        10,
+        ToOdd,
+        "0.1112",
+        "0x0.1c78#10",
+        Greater,
+    );
+}
+
+#[test]
+fn pow_i64_prec_round_fail() {
+    assert_panic!(Float::ONE.pow_i64_prec_round(-2, 0, Floor));
+    assert_panic!(Float::ONE.pow_i64_prec_round_ref(-2, 0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.pow_i64_prec_round_assign(-2, 0, Floor)
+    });
+
+    let x = Float::from(3);
+    assert_panic!(x.clone().pow_i64_prec_round(-1, 10, Exact));
+    assert_panic!(x.pow_i64_prec_round_ref(-1, 10, Exact));
+    assert_panic!({
+        let mut x = x.clone();
+        x.pow_i64_prec_round_assign(-1, 10, Exact)
+    });
+}
+
+#[test]
+fn test_pow_integer_prec_round() {
+    let test = |s, s_hex, exp, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let exp = Integer::from_str(exp).unwrap();
+
+        let (pow, o) = x.clone().pow_integer_prec_round(&exp, prec, rm);
+        assert_eq!(pow.to_string(), out);
+        assert_eq!(to_hex_string(&pow), out_hex);
+        assert_eq!(o, o_out);
+
+        check_integer_pow(&x, &exp, prec, rm, &pow, o);
+    };
+    test("NaN", "NaN", "0", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-3",
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "1000000000000000000001",
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1000000000000000000001",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-1000000000000000000000",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3",
+        20,
+        Nearest,
+        "31.00629",
+        "0x1f.019c#20",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3",
+        20,
+        Nearest,
+        "0.03225154",
+        "0x0.0841a3#20",
+        Greater,
+    );
+    test(
+        "1.00000006",
+        "0x1.000001#25",
+        "1099511627776",
+        20,
+        Nearest,
+        "8.36214e28461",
+        "0x1.5ffceE+23637#20",
+        Less,
+    );
+    test(
+        "1.00000006",
+        "0x1.000001#25",
+        "-1099511627776",
+        20,
+        Nearest,
+        "1.195865e-28462",
+        "0xb.a303E-23638#20",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "100",
+        10,
+        Floor,
+        "5.152e47",
+        "0x5.a4E+39#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "100",
+        10,
+        Ceiling,
+        "5.16e47",
+        "0x5.a6E+39#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2",
+        3,
+        NearestTiesAway,
+        "10.0",
+        "0xa.0#3",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", "2", 3, ToOdd, "10.0", "0xa.0#3", Greater);
+}
+
+#[test]
+fn pow_integer_prec_round_fail() {
+    assert_panic!(Float::ONE.pow_integer_prec_round(&Integer::TWO, 0, Floor));
+    assert_panic!(Float::ONE.pow_integer_prec_round_ref(&Integer::TWO, 0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.pow_integer_prec_round_assign(&Integer::TWO, 0, Floor)
+    });
+}
+
+#[test]
+fn test_pow_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (pow, o) = x.clone().pow_prec_round(y.clone(), prec, rm);
+        assert!(pow.is_valid());
+        assert_eq!(pow.to_string(), out);
+        assert_eq!(to_hex_string(&pow), out_hex);
+        assert_eq!(o, o_out);
+
+        let (pow_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut pow_alt = x.clone();
+        let o_alt = pow_alt.pow_prec_round_assign(y.clone(), prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut pow_alt = x.clone();
+        let o_alt = pow_alt.pow_prec_round_assign_ref(&y, prec, rm);
+        assert!(pow_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&pow), ComparableFloatRef(&pow_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_pow, rug_o) = rug_pow_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_pow)),
+                ComparableFloatRef(&pow),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "0.5", "0x0.8#1", "Infinity", "Infinity", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "2.0", "0x2.0#1", "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-2.0", "-0x2.0#1", "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.5", "0x0.8#1", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.5", "-0x0.8#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "2.5",
+        "0x2.8#3",
+        10,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-2.5",
+        "-0x2.8#3",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.5", "-0x0.8#1", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-2.0", "-0x2.0#1", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "3.0", "0x3.0#2", 10, Floor, "-0.0", "-0x0.0", Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.5", "0x0.8#1", 10, Floor, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "-8.0", "-0x8.0#1", "1.5", "0x1.8#2", 10, Floor, "NaN", "NaN", Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "-8.0",
+        "-0x8.00#10",
+        Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        Floor,
+        "-0.125",
+        "-0x0.200#10",
+        Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        "0.5",
+        "0x0.8#1",
+        10,
+        Exact,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test(
+        "2.0e1",
+        "0x1.0E+1#1",
+        "0.8",
+        "0x0.c#2",
+        10,
+        Exact,
+        "8.0",
+        "0x8.00#10",
+        Equal,
+    );
+    test(
+        "2.0e1",
+        "0x1.0E+1#1",
+        "-0.8",
+        "-0x0.c#2",
+        10,
+        Exact,
+        "0.125",
+        "0x0.200#10",
+        Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "0.5",
+        "0x0.8#1",
+        53,
+        Nearest,
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "-0.5",
+        "-0x0.8#1",
+        53,
+        Nearest,
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "0.5",
+        "0x0.8#1",
+        10,
+        Floor,
+        "1.414",
+        "0x1.6a0#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "0.5",
+        "0x0.8#1",
+        10,
+        Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        5,
+        Nearest,
+        "22.0",
+        "0x16.0#5",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        20,
+        Floor,
+        "22.45914",
+        "0x16.758a#20",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        20,
+        Nearest,
+        "22.45917",
+        "0x16.758c#20",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        53,
+        Floor,
+        "22.459157718361038",
+        "0x16.758b5c38110f#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        53,
+        Ceiling,
+        "22.459157718361041",
+        "0x16.758b5c381110#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        53,
+        Nearest,
+        "22.459157718361041",
+        "0x16.758b5c381110#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        53,
+        Nearest,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        53,
+        Nearest,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "2.0",
+        "0x2.0#1",
+        3,
+        NearestTiesAway,
+        "10.0",
+        "0xa.0#3",
+        Greater,
+    );
+    test(
+        "3.0", "0x3.0#2", "2.0", "0x2.0#1", 3, ToOdd, "10.0", "0xa.0#3", Greater,
+    );
+}
+
+#[test]
+fn pow_prec_round_fail() {
+    assert_panic!(Float::TWO.pow_prec_round(Float::TWO, 0, Floor));
+    assert_panic!(Float::TWO.pow_prec_round_ref_ref(&Float::TWO, 0, Floor));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.pow_prec_round_assign(Float::TWO, 0, Floor)
+    });
+
+    let half = Float::from(0.5);
+    assert_panic!(Float::TWO.pow_prec_round(half.clone(), 10, Exact));
+    assert_panic!(Float::TWO.pow_prec_round_ref_ref(&half, 10, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.pow_prec_round_assign_ref(&half, 10, Exact)
+    });
+}
+
+#[test]
+fn test_pow() {
+    let x = Float::from(1.5);
+    assert_eq!((&x).pow(10u64).to_string(), "57.6650390625");
+    assert_eq!((&x).pow(-3i64).to_string(), "0.29629629629629628");
+    assert_eq!((&x).pow(Integer::from(3)).to_string(), "3.375");
+    assert_eq!(x.clone().pow(&Float::TWO).to_string(), "2.25");
+    assert_eq!(
+        Float::NEGATIVE_ONE
+            .pow(Float::NEGATIVE_INFINITY)
+            .to_string(),
+        "1.0"
+    );
+
+    let mut y = x.clone();
+    y.pow_assign(2u64);
+    assert_eq!(y.to_string(), "2.25");
+    let mut y = x.clone();
+    y.pow_assign(&Float::from(0.5));
+    assert_eq!(y.to_string(), "1.2247448713915889");
+}
+
+// Keeps the exponents of the intermediate and final results well within range.
+fn small_exponent(x: &Float, bound: i32) -> bool {
+    x.get_exponent().map_or(true, |e| e.abs() <= bound)
+}
+
+#[test]
+fn pow_u64_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, prec, rm)| {
+        if !small_exponent(&x, 10000) {
+            return;
+        }
+        for exp in 0..=5u64 {
+            let (pow, o) = x.clone().pow_u64_prec_round(exp, prec, rm);
+            assert!(pow.is_valid());
+
+            let (pow_alt, o_alt) = x.pow_u64_prec_round_ref(exp, prec, rm);
+            assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(&pow));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.pow_u64_prec_round_assign(exp, prec, rm);
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&pow));
+            assert_eq!(o_alt, o);
+
+            check_integer_pow(&x, &Integer::from(exp), prec, rm, &pow, o);
+
+            if pow.is_normal() {
+                assert_eq!(pow.get_prec(), Some(prec));
+            }
+            match rm {
+                Floor => assert_ne!(o, Greater),
+                Ceiling => assert_ne!(o, Less),
+                _ => {}
+            }
+            if o != Equal {
+                assert_panic!(x.pow_u64_prec_round_ref(exp, prec, Exact));
+            }
+
+            if exp.even() {
+                let (pow_alt, o_alt) = (-&x).pow_u64_prec_round(exp, prec, rm);
+                assert_eq!(ComparableFloat(pow_alt), ComparableFloat(pow.clone()));
+                assert_eq!(o_alt, o);
+            } else {
+                let (pow_alt, o_alt) = (-&x).pow_u64_prec_round(exp, prec, -rm);
+                assert_eq!(ComparableFloat(-pow_alt), ComparableFloat(pow.clone()));
+                assert_eq!(o_alt.reverse(), o);
+            }
+        }
+
+        let (one, o) = x.pow_u64_prec_round_ref(0, prec, rm);
+        assert_eq!(ComparableFloat(one), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+
+        let (pow, o) = x.pow_u64_prec_round_ref(1, prec, rm);
+        let (pow_alt, o_alt) = Float::from_float_prec_round_ref(&x, prec, rm);
+        assert_eq!(ComparableFloat(pow), ComparableFloat(pow_alt));
+        assert_eq!(o, o_alt);
+
+        let (pow, o) = x.pow_u64_prec_round_ref(2, prec, rm);
+        let (square, o_alt) = x.square_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloat(pow), ComparableFloat(square));
+        assert_eq!(o, o_alt);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (pow, o) = Float::NAN.pow_u64_prec_round(3, prec, rm);
+        assert!(pow.is_nan());
+        assert_eq!(o, Equal);
+        assert_eq!(
+            ComparableFloat(Float::NAN.pow_u64_prec_round(0, prec, rm).0),
+            ComparableFloat(Float::one_prec(prec))
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ZERO.pow_u64_prec_round(3, prec, rm).0),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_INFINITY.pow_u64_prec_round(2, prec, rm).0),
+            ComparableFloat(Float::INFINITY)
+        );
+    });
+}
+
+#[test]
+fn pow_i64_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, prec, rm)| {
+        if !small_exponent(&x, 10000) {
+            return;
+        }
+        for exp in -4..=4i64 {
+            let (pow, o) = x.clone().pow_i64_prec_round(exp, prec, rm);
+            assert!(pow.is_valid());
+
+            let (pow_alt, o_alt) = x.pow_i64_prec_round_ref(exp, prec, rm);
+            assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(&pow));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.pow_i64_prec_round_assign(exp, prec, rm);
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&pow));
+            assert_eq!(o_alt, o);
+
+            check_integer_pow(&x, &Integer::from(exp), prec, rm, &pow, o);
+
+            if exp >= 0 {
+                let (pow_alt, o_alt) = x.pow_u64_prec_round_ref(exp.unsigned_abs(), prec, rm);
+                assert_eq!(ComparableFloat(pow_alt), ComparableFloat(pow.clone()));
+                assert_eq!(o_alt, o);
+            }
+            match rm {
+                Floor => assert_ne!(o, Greater),
+                Ceiling => assert_ne!(o, Less),
+                _ => {}
+            }
+            if o != Equal {
+                assert_panic!(x.pow_i64_prec_round_ref(exp, prec, Exact));
+            }
+        }
+
+        let (pow, o) = x.pow_i64_prec_round_ref(-1, prec, rm);
+        let (reciprocal, o_alt) = x.reciprocal_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloat(pow), ComparableFloat(reciprocal));
+        assert_eq!(o, o_alt);
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        assert_eq!(
+            ComparableFloat(Float::ZERO.pow_i64_prec_round(-2, prec, rm).0),
+            ComparableFloat(Float::INFINITY)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_ZERO.pow_i64_prec_round(-3, prec, rm).0),
+            ComparableFloat(Float::NEGATIVE_INFINITY)
+        );
+        assert_eq!(
+            ComparableFloat(Float::NEGATIVE_INFINITY.pow_i64_prec_round(-3, prec, rm).0),
+            ComparableFloat(Float::NEGATIVE_ZERO)
+        );
+    });
+}
+
+#[test]
+fn pow_integer_properties() {
+    float_integer_pair_gen().test_properties(|(x, exp)| {
+        if !small_exponent(&x, 1000) || exp.significant_bits() > 10 {
+            return;
+        }
+        let prec = x.significant_bits();
+        for rm in [Floor, Ceiling, Down, Up, Nearest] {
+            let (pow, o) = x.pow_integer_round_ref(&exp, rm);
+            check_integer_pow(&x, &exp, prec, rm, &pow, o);
+            match rm {
+                Floor => assert_ne!(o, Greater),
+                Ceiling => assert_ne!(o, Less),
+                _ => {}
+            }
+        }
+
+        let pow = (&x).pow(&exp);
+        assert_eq!(
+            ComparableFloat(pow.clone()),
+            ComparableFloat(x.pow_integer_round_ref(&exp, Nearest).0)
+        );
+        let mut x_alt = x.clone();
+        x_alt.pow_assign(&exp);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(pow));
+    });
+}
+
+#[test]
+fn pow_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(|(x, y, prec, rm)| {
+        if !small_exponent(&x, 1000) || !small_exponent(&y, 8) {
+            return;
+        }
+        let (pow, o) = x.clone().pow_prec_round(y.clone(), prec, rm);
+        assert!(pow.is_valid());
+
+        let (pow_alt, o_alt) = x.pow_prec_round_ref_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&pow_alt), ComparableFloatRef(&pow));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.pow_prec_round_assign(y.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&pow));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.pow_prec_round_assign_ref(&y, prec, rm);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&pow));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_pow, rug_o) = rug_pow_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_pow)),
+                ComparableFloatRef(&pow),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if let Ok(n) = Integer::try_from(&y) {
+            let (pow_alt, o_alt) = x.pow_integer_prec_round_ref(&n, prec, rm);
+            assert_eq!(ComparableFloat(pow_alt), ComparableFloat(pow.clone()));
+            assert_eq!(o_alt, o);
+        }
+
+        if pow.is_normal() {
+            assert_eq!(pow.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.pow_prec_round_ref_ref(&y, prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        for y in [Float::NAN, Float::INFINITY, Float::NEGATIVE_INFINITY, Float::from(0.5)] {
+            assert_eq!(
+                ComparableFloat(Float::ONE.pow_prec_round_ref_ref(&y, prec, rm).0),
+                ComparableFloat(Float::one_prec(prec))
+            );
+            assert_eq!(
+                ComparableFloat(y.clone().pow_prec_round(Float::NEGATIVE_ZERO, prec, rm).0),
+                ComparableFloat(Float::one_prec(prec))
+            );
+        }
+        let (pow, o) = Float::NEGATIVE_ONE.pow_prec_round(Float::from(0.5), prec, rm);
+        assert!(pow.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
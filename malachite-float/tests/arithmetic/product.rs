@@ -0,0 +1,216 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::test_util::arithmetic::product::product_prec_round_naive;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_gen, float_vec_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering::*};
+use std::iter::Product;
+use std::panic::catch_unwind;
+
+const PRECS: [u64; 4] = [1, 10, 64, 100];
+
+fn parse_hex_strings(xs: &[&str]) -> Vec<Float> {
+    xs.iter().map(|x| parse_hex_string(x)).collect()
+}
+
+#[test]
+fn test_product_prec_round() {
+    let test = |xs: &[&str], prec: u64, rm: RoundingMode, out: &str, o_out| {
+        let xs = parse_hex_strings(xs);
+        let (product, o) = Float::product_prec_round(xs.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(to_hex_string(&product), out);
+        assert_eq!(o, o_out);
+
+        let (product_alt, o_alt) = product_prec_round_naive(&xs, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+    };
+    test(&[], 10, Nearest, "0x1.000#10", Equal);
+    test(&["-0x0.0"], 10, Nearest, "-0x0.0", Equal);
+    test(&["-0x0.0", "-0x1.0#1"], 10, Nearest, "0x0.0", Equal);
+    test(&["0x0.0", "-0x1.0#1"], 10, Nearest, "-0x0.0", Equal);
+
+    test(&["NaN", "0x1.0#1"], 10, Nearest, "NaN", Equal);
+    test(&["Infinity", "0x0.0"], 10, Nearest, "NaN", Equal);
+    test(&["Infinity", "-0x1.0#1"], 10, Nearest, "-Infinity", Equal);
+    test(&["-Infinity", "-Infinity"], 10, Nearest, "Infinity", Equal);
+
+    // The exponents cancel out
+    test(
+        &["0x1.0E+100#1", "0x1.0E-100#1", "-0x3.0#2"],
+        10,
+        Nearest,
+        "-0x3.00#10",
+        Equal,
+    );
+    test(
+        &["0x1.0E+100#1", "0x1.0E+100#1"],
+        10,
+        Nearest,
+        "0x1.000E+200#10",
+        Equal,
+    );
+
+    // 0.1 * 0.2 * 0.3
+    let xs = ["0x0.1999999999999a#53", "0x0.33333333333334#53", "0x0.4ccccccccccccc#53"];
+    test(&xs, 53, Nearest, "0x0.0189374bc6a7efa#53", Less);
+    test(&xs, 53, Floor, "0x0.0189374bc6a7efa#53", Less);
+    test(&xs, 10, Ceiling, "0x0.01898#10", Greater);
+    test(
+        &xs,
+        200,
+        Exact,
+        "0x0.0189374bc6a7efa5e353f7ced9167ef9db22d0e5600000000000#200",
+        Equal,
+    );
+
+    let xs = ["0x3.0#2", "0x3.0#2", "0x3.0#2"];
+    test(&xs, 2, Floor, "0x18.0#2", Less);
+    test(&xs, 2, Ceiling, "0x2.0E+1#2", Greater);
+    test(&xs, 2, Nearest, "0x18.0#2", Less);
+    test(&xs, 5, Exact, "0x1b.0#5", Equal);
+    test(
+        &["0x3.0#2", "0x3.0#2"],
+        3,
+        NearestTiesAway,
+        "0xa.0#3",
+        Greater,
+    );
+    test(&["0x3.0#2", "0x3.0#2"], 3, ToOdd, "0xa.0#3", Greater);
+}
+
+#[test]
+fn product_prec_round_fail() {
+    assert_panic!(Float::product_prec_round([Float::ONE], 0, Floor));
+    assert_panic!(Float::product_prec_round(
+        [Float::from(3), Float::from(3), Float::from(3)],
+        2,
+        Exact
+    ));
+}
+
+#[test]
+fn test_product() {
+    let xs = parse_hex_strings(&["0x3.0#2", "0x3.0#2", "-0x3.0#2"]);
+    assert_eq!(to_hex_string(&Float::product(xs.iter())), "-0x18.0#2");
+    assert_eq!(to_hex_string(&Float::product(xs.into_iter())), "-0x18.0#2");
+    assert_eq!(
+        to_hex_string(&Float::product(Vec::<Float>::new().iter())),
+        "0x1.0#1"
+    );
+}
+
+// Keeps the exponent of the product well within range.
+fn small_exponents(xs: &[Float]) -> bool {
+    xs.iter()
+        .map(|x| x.get_exponent().map_or(0, |e| i64::from(e).abs()))
+        .sum::<i64>()
+        <= 10000
+}
+
+fn product_prec_round_properties_helper(xs: &[Float], prec: u64, rm: RoundingMode) {
+    let (product, o) = Float::product_prec_round(xs.to_vec(), prec, rm);
+    assert!(product.is_valid());
+
+    let (product_alt, o_alt) = product_prec_round_naive(xs, prec, rm);
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    if product.is_finite() && product != 0u32 {
+        assert_eq!(product.get_prec(), Some(prec));
+        let r_product: Rational = xs.iter().map(Rational::exact_from).product();
+        assert_eq!(product.partial_cmp(&r_product), Some(o));
+    }
+
+    // Negating one factor negates the product, with the rounding mode reversed
+    if let Some((first, rest)) = xs.split_first() {
+        let (neg_product, neg_o) =
+            Float::product_prec_round([-first].into_iter().chain(rest.iter().cloned()), prec, -rm);
+        if product.is_nan() {
+            assert!(neg_product.is_nan());
+        } else {
+            assert_eq!(ComparableFloat(-neg_product), ComparableFloat(product));
+        }
+        assert_eq!(neg_o.reverse(), o);
+    }
+}
+
+#[test]
+fn product_prec_round_properties() {
+    float_vec_gen().test_properties(|xs| {
+        if !small_exponents(&xs) {
+            return;
+        }
+        for prec in PRECS {
+            let (_, o) = Float::product_prec_round(xs.clone(), prec, Floor);
+            for rm in exhaustive_rounding_modes() {
+                if rm != Exact || o == Equal {
+                    product_prec_round_properties_helper(&xs, prec, rm);
+                }
+            }
+            let (product, o) = Float::product_prec(xs.clone(), prec);
+            let (product_alt, o_alt) = Float::product_prec_round(xs.clone(), prec, Nearest);
+            assert_eq!(ComparableFloat(product_alt), ComparableFloat(product));
+            assert_eq!(o_alt, o);
+        }
+        let prec = max(
+            1,
+            xs.iter()
+                .map(SignificantBits::significant_bits)
+                .max()
+                .unwrap_or(1),
+        );
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (product, o) = Float::product_round(xs.clone(), rm);
+            let (product_alt, o_alt) = Float::product_prec_round(xs.clone(), prec, rm);
+            assert_eq!(ComparableFloat(product_alt), ComparableFloat(product));
+            assert_eq!(o_alt, o);
+        }
+        let product = Float::product(xs.iter());
+        assert_eq!(
+            ComparableFloat(Float::product(xs.clone().into_iter())),
+            ComparableFloat(product.clone())
+        );
+        assert_eq!(
+            ComparableFloat(Float::product_round(xs, Nearest).0),
+            ComparableFloat(product)
+        );
+    });
+
+    float_gen().test_properties(|x| {
+        for prec in PRECS {
+            for rm in exhaustive_rounding_modes() {
+                if rm == Exact {
+                    continue;
+                }
+                let (product, o) = Float::product_prec_round([x.clone()], prec, rm);
+                let (x_alt, o_alt) = Float::from_float_prec_round_ref(&x, prec, rm);
+                assert_eq!(ComparableFloat(x_alt), ComparableFloat(product));
+                assert_eq!(o_alt, o);
+            }
+        }
+    });
+}
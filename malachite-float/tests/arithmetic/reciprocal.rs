@@ -813,6 +813,8 @@ fn test_reciprocal_round() {
     test("NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test("Infinity", "Infinity", Floor, "0.0", "0x0.0", Equal);
@@ -820,6 +822,15 @@ fn test_reciprocal_round() {
     test("Infinity", "Infinity", Down, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", Up, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("Infinity", "Infinity", ToOdd, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", Exact, "0.0", "0x0.0", Equal);
 
     test("-Infinity", "-Infinity", Floor, "-0.0", "-0x0.0", Equal);
@@ -827,6 +838,15 @@ fn test_reciprocal_round() {
     test("-Infinity", "-Infinity", Down, "-0.0", "-0x0.0", Equal);
     test("-Infinity", "-Infinity", Up, "-0.0", "-0x0.0", Equal);
     test("-Infinity", "-Infinity", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-Infinity", "-Infinity", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", Floor, "Infinity", "Infinity", Equal);
@@ -834,6 +854,15 @@ fn test_reciprocal_round() {
     test("0.0", "0x0.0", Down, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", Up, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal);
 
     test("-0.0", "-0x0.0", Floor, "-Infinity", "-Infinity", Equal);
@@ -841,6 +870,15 @@ fn test_reciprocal_round() {
     test("-0.0", "-0x0.0", Down, "-Infinity", "-Infinity", Equal);
     test("-0.0", "-0x0.0", Up, "-Infinity", "-Infinity", Equal);
     test("-0.0", "-0x0.0", Nearest, "-Infinity", "-Infinity", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", ToOdd, "-Infinity", "-Infinity", Equal);
     test("-0.0", "-0x0.0", Exact, "-Infinity", "-Infinity", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Equal);
@@ -848,6 +886,8 @@ fn test_reciprocal_round() {
     test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", ToOdd, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
 
     test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Equal);
@@ -855,6 +895,15 @@ fn test_reciprocal_round() {
     test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", ToOdd, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Exact, "-1.0", "-0x1.0#1", Equal);
 
     test(
@@ -897,6 +946,22 @@ fn test_reciprocal_round() {
         "0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0000000000000000000000000#100",
@@ -946,6 +1011,22 @@ fn test_reciprocal_round() {
         "-0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        ToOdd,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0000000000000000000000000#100",
@@ -974,6 +1055,15 @@ fn test_reciprocal_round() {
         "0x0.0218#7",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "0.0082",
+        "0x0.0218#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", ToOdd, "0.0082", "0x0.0218#7", Greater);
 
     test("-123.0", "-0x7b.0#7", Floor, "-0.0082", "-0x0.0218#7", Less);
     test(
@@ -1001,6 +1091,15 @@ fn test_reciprocal_round() {
         "-0x0.0218#7",
         Less,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "-0.0082",
+        "-0x0.0218#7",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", ToOdd, "-0.0082", "-0x0.0218#7", Less);
 
     test(
         "1.4142135623730951",
@@ -1042,6 +1141,22 @@ fn test_reciprocal_round() {
         "0x0.b504f333f9de60#53",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        NearestTiesAway,
+        "0.7071067811865475",
+        "0x0.b504f333f9de60#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        ToOdd,
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -1083,6 +1198,22 @@ fn test_reciprocal_round() {
         "-0x0.b504f333f9de60#53",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        NearestTiesAway,
+        "-0.7071067811865475",
+        "-0x0.b504f333f9de60#53",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        ToOdd,
+        "-0.7071067811865476",
+        "-0x0.b504f333f9de68#53",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -1124,6 +1255,22 @@ fn test_reciprocal_round() {
         "0x0.517cc1b727220c#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "0.31830988618379069",
+        "0x0.517cc1b727220c#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -1165,6 +1312,22 @@ fn test_reciprocal_round() {
         "-0x0.517cc1b727220c#53",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-0.31830988618379069",
+        "-0x0.517cc1b727220c#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "-0.31830988618379069",
+        "-0x0.517cc1b727220c#53",
+        Less,
+    );
 
     // yyy
 
@@ -1243,6 +1406,8 @@ fn test_reciprocal_round() {
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 && reciprocal & shift_bit != 0) in
     //   reciprocal_float_significand_same_prec_lt_w
     test("1.5", "0x1.8#2", Nearest, "0.8", "0x0.c#2", Greater);
+    test("1.5", "0x1.8#2", NearestTiesAway, "0.8", "0x0.c#2", Greater);
+    test("1.5", "0x1.8#2", ToOdd, "0.8", "0x0.c#2", Greater);
     // - rm == Nearest && (round_bit == 0 || sticky_bit == 0 && reciprocal & shift_bit == 0) in
     //   reciprocal_float_significand_same_prec_lt_w
     test("1.2", "0x1.4#3", Nearest, "0.8", "0x0.c#3", Less);
@@ -1274,6 +1439,22 @@ fn test_reciprocal_round() {
         "0x0.fffffffffffffffe#64",
         Less,
     );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        NearestTiesAway,
+        "0.99999999999999999989",
+        "0x0.fffffffffffffffe#64",
+        Less,
+    );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        ToOdd,
+        "0.99999999999999999995",
+        "0x0.ffffffffffffffff#64",
+        Greater,
+    );
     // - round_bit in reciprocal_float_significand_same_prec_w
     // - rm == Exact && round_bit && (sticky_bit != 0 || reciprocal.even() in
     //   reciprocal_float_significand_same_prec_w
@@ -1311,6 +1492,22 @@ fn test_reciprocal_round() {
         "0x0.ffffffffffffffff0#65",
         Less,
     );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        NearestTiesAway,
+        "0.99999999999999999995",
+        "0x0.ffffffffffffffff0#65",
+        Less,
+    );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        ToOdd,
+        "0.99999999999999999997",
+        "0x0.ffffffffffffffff8#65",
+        Greater,
+    );
     // - r_1 != 0 in reciprocal_float_2_approx
     // - s_1 >= x_1 && (s_1 != x_1 || s_0 >= x_0) in
     //   reciprocal_float_significand_same_prec_gt_w_lt_2w
@@ -1555,6 +1752,8 @@ fn test_reciprocal_prec_round() {
     test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test("Infinity", "Infinity", 1, Floor, "0.0", "0x0.0", Equal);
@@ -1562,6 +1761,16 @@ fn test_reciprocal_prec_round() {
     test("Infinity", "Infinity", 1, Down, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", 1, Up, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("Infinity", "Infinity", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("Infinity", "Infinity", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-Infinity", "-Infinity", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -1585,6 +1794,16 @@ fn test_reciprocal_prec_round() {
         "-0x0.0",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-Infinity", "-Infinity", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", 1, Floor, "Infinity", "Infinity", Equal);
@@ -1592,6 +1811,16 @@ fn test_reciprocal_prec_round() {
     test("0.0", "0x0.0", 1, Down, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", 1, Up, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal);
     test("0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal);
 
     test("-0.0", "-0x0.0", 1, Floor, "-Infinity", "-Infinity", Equal);
@@ -1615,6 +1844,16 @@ fn test_reciprocal_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, ToOdd, "-Infinity", "-Infinity", Equal);
     test("-0.0", "-0x0.0", 1, Exact, "-Infinity", "-Infinity", Equal);
 
     test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
@@ -1622,6 +1861,16 @@ fn test_reciprocal_prec_round() {
     test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, ToOdd, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
 
     test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Equal);
@@ -1629,6 +1878,16 @@ fn test_reciprocal_prec_round() {
     test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0#1",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, ToOdd, "-1.0", "-0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Exact, "-1.0", "-0x1.0#1", Equal);
 
     test("1.0", "0x1.0#1", 10, Floor, "1.0", "0x1.000#10", Equal);
@@ -1636,6 +1895,16 @@ fn test_reciprocal_prec_round() {
     test("1.0", "0x1.0#1", 10, Down, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Up, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
 
     test("-1.0", "-0x1.0#1", 10, Floor, "-1.0", "-0x1.000#10", Equal);
@@ -1659,6 +1928,16 @@ fn test_reciprocal_prec_round() {
         "-0x1.000#10",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.000#10",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 10, ToOdd, "-1.0", "-0x1.000#10", Equal);
     test("-1.0", "-0x1.0#1", 10, Exact, "-1.0", "-0x1.000#10", Equal);
 
     test("123.0", "0x7b.0#7", 1, Floor, "0.008", "0x0.02#1", Less);
@@ -1666,6 +1945,16 @@ fn test_reciprocal_prec_round() {
     test("123.0", "0x7b.0#7", 1, Down, "0.008", "0x0.02#1", Less);
     test("123.0", "0x7b.0#7", 1, Up, "0.02", "0x0.04#1", Greater);
     test("123.0", "0x7b.0#7", 1, Nearest, "0.008", "0x0.02#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "0.008",
+        "0x0.02#1",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, ToOdd, "0.008", "0x0.02#1", Less);
 
     test("-123.0", "-0x7b.0#7", 1, Floor, "-0.02", "-0x0.04#1", Less);
     test(
@@ -1696,6 +1985,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.02#1",
         Greater,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-0.008",
+        "-0x0.02#1",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "-0.008",
+        "-0x0.02#1",
+        Greater,
+    );
 
     test(
         "123.0",
@@ -1742,6 +2049,24 @@ fn test_reciprocal_prec_round() {
         "0x0.0215#10",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "0.00813",
+        "0x0.0215#10",
+        Greater,
+    );
 
     test(
         "-123.0",
@@ -1788,6 +2113,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.0215#10",
         Less,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "-0.00813",
+        "-0x0.0215#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        ToOdd,
+        "-0.00813",
+        "-0x0.0215#10",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -1834,6 +2177,24 @@ fn test_reciprocal_prec_round() {
         "0x0.8#1",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        NearestTiesAway,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        ToOdd,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -1880,6 +2241,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.8#1",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        NearestTiesAway,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        ToOdd,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -1926,6 +2305,24 @@ fn test_reciprocal_prec_round() {
         "0x0.b50#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        NearestTiesAway,
+        "0.707",
+        "0x0.b50#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        ToOdd,
+        "0.708",
+        "0x0.b54#10",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -1972,6 +2369,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.b50#10",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        NearestTiesAway,
+        "-0.707",
+        "-0x0.b50#10",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        ToOdd,
+        "-0.708",
+        "-0x0.b54#10",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -2018,6 +2433,24 @@ fn test_reciprocal_prec_round() {
         "0x0.4#1",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -2064,6 +2497,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.4#1",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "-0.2",
+        "-0x0.4#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "-0.2",
+        "-0x0.4#1",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -2110,6 +2561,24 @@ fn test_reciprocal_prec_round() {
         "0x0.518#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "0.3184",
+        "0x0.518#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "0.3179",
+        "0x0.516#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -2156,6 +2625,24 @@ fn test_reciprocal_prec_round() {
         "-0x0.518#10",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-0.3184",
+        "-0x0.518#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-0.3179",
+        "-0x0.516#10",
+        Greater,
+    );
 
     // yyy
 
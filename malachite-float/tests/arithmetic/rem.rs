@@ -0,0 +1,277 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_gen, float_pair_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::max;
+
+#[test]
+fn test_rem() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let rem = x.clone() % y.clone();
+        assert!(rem.is_valid());
+        assert_eq!(rem.to_string(), out);
+        assert_eq!(to_hex_string(&rem), out_hex);
+
+        let rem_alt = x.clone() % &y;
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let rem_alt = &x % y.clone();
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let rem_alt = &x % &y;
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+
+        let mut rem_alt = x.clone();
+        rem_alt %= y.clone();
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let mut rem_alt = x.clone();
+        rem_alt %= &y;
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+
+        let rug_rem = rug::Float::exact_from(&x) % rug::Float::exact_from(&y);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_rem)),
+            ComparableFloatRef(&Float::from_float_prec_ref(&rem, x.significant_bits()).0)
+        );
+    };
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "Infinity", "Infinity", "1.0", "0x1.0#1");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "-0.0", "-0x0.0");
+
+    test("7.5", "0x7.8#4", "2.0", "0x2.0#1", "1.5", "0x1.8#4");
+    test("-7.5", "-0x7.8#4", "2.0", "0x2.0#1", "-1.5", "-0x1.8#4");
+    test("7.5", "0x7.8#4", "-2.0", "-0x2.0#1", "1.5", "0x1.8#4");
+    test("-7.5", "-0x7.8#4", "-2.0", "-0x2.0#1", "-1.5", "-0x1.8#4");
+    test("6.0", "0x6.0#2", "2.0", "0x2.0#1", "0.0", "0x0.0");
+    test("-6.0", "-0x6.0#2", "2.0", "0x2.0#1", "-0.0", "-0x0.0");
+    test("1.5", "0x1.8#2", "2.0", "0x2.0#1", "1.5", "0x1.8#2");
+    test("1.5", "0x1.8#2", "0.2", "0x0.4#1", "0.0", "0x0.0");
+    test("1.5", "0x1.8#2", "0.4", "0x0.6#2", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "0.4", "0x0.6#2", "0.2", "0x0.4#2");
+    test("1.0", "0x1.0#1", "0.8", "0x0.c#2", "0.2", "0x0.4#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+    );
+    test(
+        "1000000000000000000000000000000.0",
+        "0xc9f2c9cd04674edea40000000.0#100",
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+    );
+}
+
+#[test]
+fn test_remainder() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let rem = x.clone().remainder(y.clone());
+        assert!(rem.is_valid());
+        assert_eq!(rem.to_string(), out);
+        assert_eq!(to_hex_string(&rem), out_hex);
+
+        let rem_alt = x.clone().remainder_val_ref(&y);
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let rem_alt = x.remainder_ref_val(y.clone());
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let rem_alt = x.remainder_ref_ref(&y);
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+
+        let mut rem_alt = x.clone();
+        rem_alt.remainder_assign(y.clone());
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+        let mut rem_alt = x.clone();
+        rem_alt.remainder_assign_ref(&y);
+        assert!(rem_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+
+        let rug_rem = rug::Float::exact_from(&x).remainder(&rug::Float::exact_from(&y));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_rem)),
+            ComparableFloatRef(&Float::from_float_prec_ref(&rem, x.significant_bits()).0)
+        );
+    };
+    test("NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "NaN", "NaN", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1", "-0.0", "-0x0.0");
+
+    test("7.5", "0x7.8#4", "2.0", "0x2.0#1", "-0.5", "-0x0.8#4");
+    test("-7.5", "-0x7.8#4", "2.0", "0x2.0#1", "0.5", "0x0.8#4");
+    test("6.5", "0x6.8#4", "2.0", "0x2.0#1", "0.5", "0x0.8#4");
+    test("5.0", "0x5.0#3", "2.0", "0x2.0#1", "1.0", "0x1.0#3");
+    test("7.0", "0x7.0#3", "2.0", "0x2.0#1", "-1.0", "-0x1.0#3");
+    test("-5.0", "-0x5.0#3", "2.0", "0x2.0#1", "-1.0", "-0x1.0#3");
+    test("-7.0", "-0x7.0#3", "2.0", "0x2.0#1", "1.0", "0x1.0#3");
+    test("-6.0", "-0x6.0#2", "2.0", "0x2.0#1", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("3.0", "0x3.0#2", "2.0", "0x2.0#1", "-1.0", "-0x1.0#2");
+    test("0.2", "0x0.4#1", "2.0", "0x2.0#1", "0.2", "0x0.4#1");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.0",
+        "0x2.0#1",
+        "-0.8584073464102069",
+        "-0x0.dbc095777a5d00#53",
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn rem_properties_helper(x: Float, y: Float) {
+    let rem = x.clone() % y.clone();
+    assert!(rem.is_valid());
+    let rem_alt = x.clone() % &y;
+    assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+    let rem_alt = &x % y.clone();
+    assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+    let rem_alt = &x % &y;
+    assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+    let mut rem_alt = x.clone();
+    rem_alt %= y.clone();
+    assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+    let mut rem_alt = x.clone();
+    rem_alt %= &y;
+    assert_eq!(ComparableFloatRef(&rem_alt), ComparableFloatRef(&rem));
+
+    let remainder = x.clone().remainder(y.clone());
+    assert!(remainder.is_valid());
+    let remainder_alt = x.clone().remainder_val_ref(&y);
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    let remainder_alt = x.remainder_ref_val(y.clone());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    let remainder_alt = x.remainder_ref_ref(&y);
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    let mut remainder_alt = x.clone();
+    remainder_alt.remainder_assign(y.clone());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    let mut remainder_alt = x.clone();
+    remainder_alt.remainder_assign_ref(&y);
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+
+    let prec = max(x.significant_bits(), y.significant_bits());
+    let rug_x = rug::Float::with_val(u32::exact_from(prec), rug::Float::exact_from(&x));
+    let rug_y = rug::Float::exact_from(&y);
+    assert_eq!(
+        ComparableFloat(Float::from(&(rug_x.clone() % &rug_y))),
+        ComparableFloat(Float::from_float_prec_ref(&rem, prec).0)
+    );
+    assert_eq!(
+        ComparableFloat(Float::from(&rug_x.remainder(&rug_y))),
+        ComparableFloat(Float::from_float_prec_ref(&remainder, prec).0)
+    );
+
+    assert_eq!(ComparableFloat(-&x % &y), ComparableFloat(-rem.clone()));
+    assert_eq!(ComparableFloat(&x % -&y), ComparableFloat(rem.clone()));
+    assert_eq!(
+        ComparableFloat((-&x).remainder_ref_ref(&y)),
+        ComparableFloat(-remainder.clone())
+    );
+
+    if x.is_finite() && y.is_finite() && y != 0u32 {
+        assert!(rem.get_prec().map_or(true, |p| p == prec));
+        assert!(remainder.get_prec().map_or(true, |p| p == prec));
+        assert_eq!(rem.is_sign_negative(), x.is_sign_negative());
+        if remainder == 0u32 {
+            assert_eq!(remainder.is_sign_negative(), x.is_sign_negative());
+        }
+        let x = Rational::exact_from(&x);
+        let y = Rational::exact_from(&y);
+        let rem = Rational::exact_from(&rem);
+        let remainder = Rational::exact_from(&remainder);
+        assert!((&rem).abs() < (&y).abs());
+        assert!((&rem).abs() <= (&x).abs());
+        assert!(((&x - &rem) / &y).is_integer());
+        assert!((&remainder).abs() * Rational::from(2) <= (&y).abs());
+        assert!(((x - remainder) / y).is_integer());
+    } else if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0u32 {
+        assert!(rem.is_nan());
+        assert!(remainder.is_nan());
+    } else {
+        assert_eq!(ComparableFloatRef(&rem), ComparableFloatRef(&x));
+        assert_eq!(ComparableFloatRef(&remainder), ComparableFloatRef(&x));
+    }
+}
+
+#[test]
+fn rem_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        rem_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        if x.is_finite() && x != 0u32 {
+            let zero = &x % &x;
+            assert!(zero == 0u32 && zero.is_sign_negative() == x.is_sign_negative());
+            let zero = x.remainder_ref_ref(&x);
+            assert!(zero == 0u32 && zero.is_sign_negative() == x.is_sign_negative());
+            assert_eq!(
+                ComparableFloat(&x % Float::from(1)),
+                ComparableFloat(x.frac_ref())
+            );
+        }
+    });
+}
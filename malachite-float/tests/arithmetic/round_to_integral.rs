@@ -0,0 +1,453 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Ceiling, CeilingAssign, Floor, FloorAssign};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger, RoundingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_gen, float_rounding_mode_pair_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_round_to_integral() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, out_o: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (rounded, o) = x.clone().round_to_integral(rm);
+        assert!(rounded.is_valid());
+        assert_eq!(rounded.to_string(), out);
+        assert_eq!(to_hex_string(&rounded), out_hex);
+        assert_eq!(o, out_o);
+
+        let (rounded_alt, o_alt) = x.round_to_integral_ref(rm);
+        assert!(rounded_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&rounded_alt),
+            ComparableFloatRef(&rounded)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut rounded_alt = x;
+        let o_alt = rounded_alt.round_to_integral_assign(rm);
+        assert!(rounded_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&rounded_alt),
+            ComparableFloatRef(&rounded)
+        );
+        assert_eq!(o_alt, o);
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        Ceiling,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+
+    test("2.5", "0x2.8#3", Floor, "2.0", "0x2.0#3", Less);
+    test("2.5", "0x2.8#3", Ceiling, "3.0", "0x3.0#3", Greater);
+    test("2.5", "0x2.8#3", Down, "2.0", "0x2.0#3", Less);
+    test("2.5", "0x2.8#3", Up, "3.0", "0x3.0#3", Greater);
+    test("2.5", "0x2.8#3", Nearest, "2.0", "0x2.0#3", Less);
+    test("2.5", "0x2.8#3", NearestTiesAway, "3.0", "0x3.0#3", Greater);
+    test("2.5", "0x2.8#3", ToOdd, "3.0", "0x3.0#3", Greater);
+
+    test("-2.5", "-0x2.8#3", Floor, "-3.0", "-0x3.0#3", Less);
+    test("-2.5", "-0x2.8#3", Ceiling, "-2.0", "-0x2.0#3", Greater);
+    test("-2.5", "-0x2.8#3", Down, "-2.0", "-0x2.0#3", Greater);
+    test("-2.5", "-0x2.8#3", Up, "-3.0", "-0x3.0#3", Less);
+    test("-2.5", "-0x2.8#3", Nearest, "-2.0", "-0x2.0#3", Greater);
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0#3",
+        Less,
+    );
+    test("-2.5", "-0x2.8#3", ToOdd, "-3.0", "-0x3.0#3", Less);
+
+    test("3.5", "0x3.8#3", Nearest, "4.0", "0x4.0#3", Greater);
+    test("3.5", "0x3.8#3", ToOdd, "3.0", "0x3.0#3", Less);
+    test("0.5", "0x0.8#1", Nearest, "0.0", "0x0.0", Less);
+    test("0.5", "0x0.8#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-0.5", "-0x0.8#1", Nearest, "-0.0", "-0x0.0", Greater);
+    test("-0.5", "-0x0.8#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("0.8", "0x0.c#2", Nearest, "1.0", "0x1.0#2", Greater);
+    test("0.8", "0x0.c#2", Down, "0.0", "0x0.0", Less);
+    test("-0.1", "-0x0.2#1", Down, "-0.0", "-0x0.0", Greater);
+    test("-0.1", "-0x0.2#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("3.8", "0x3.c#4", Ceiling, "4.0", "0x4.0#4", Greater);
+    test("3.0", "0x3.0#2", Exact, "3.0", "0x3.0#2", Equal);
+    test(
+        "1000000.0",
+        "0xf4240.0#20",
+        Floor,
+        "1000000.0",
+        "0xf4240.0#20",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "3.0",
+        "0x3.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-4.0",
+        "-0x4.0000000000000#53",
+        Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        Floor,
+        "3.0e120",
+        "0x1.0E+100#1",
+        Equal,
+    );
+    test("4.0e-121", "0x1.0E-100#1", Floor, "0.0", "0x0.0", Less);
+    test(
+        "4.0e-121",
+        "0x1.0E-100#1",
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+}
+
+#[test]
+fn round_to_integral_fail() {
+    assert_panic!(Float::from(2.5).round_to_integral(Exact));
+    assert_panic!(Float::from(2.5).round_to_integral_ref(Exact));
+    assert_panic!({
+        let mut x = Float::from(2.5);
+        x.round_to_integral_assign(Exact);
+    });
+}
+
+#[test]
+fn test_floor_ceiling_trunc_round_even() {
+    let test = |s_hex, floor_hex: &str, ceiling_hex: &str, trunc_hex: &str, even_hex: &str| {
+        let x = parse_hex_string(s_hex);
+
+        let floor = x.clone().floor();
+        assert!(floor.is_valid());
+        assert_eq!(to_hex_string(&floor), floor_hex);
+        assert_eq!(
+            ComparableFloat((&x).floor()),
+            ComparableFloat(floor.clone())
+        );
+        let mut floor_alt = x.clone();
+        floor_alt.floor_assign();
+        assert_eq!(ComparableFloat(floor_alt), ComparableFloat(floor));
+
+        let ceiling = x.clone().ceiling();
+        assert!(ceiling.is_valid());
+        assert_eq!(to_hex_string(&ceiling), ceiling_hex);
+        assert_eq!(
+            ComparableFloat((&x).ceiling()),
+            ComparableFloat(ceiling.clone())
+        );
+        let mut ceiling_alt = x.clone();
+        ceiling_alt.ceiling_assign();
+        assert_eq!(ComparableFloat(ceiling_alt), ComparableFloat(ceiling));
+
+        let trunc = x.clone().trunc();
+        assert!(trunc.is_valid());
+        assert_eq!(to_hex_string(&trunc), trunc_hex);
+        assert_eq!(
+            ComparableFloat(x.trunc_ref()),
+            ComparableFloat(trunc.clone())
+        );
+        let mut trunc_alt = x.clone();
+        trunc_alt.trunc_assign();
+        assert_eq!(ComparableFloat(trunc_alt), ComparableFloat(trunc));
+
+        let even = x.clone().round_even();
+        assert!(even.is_valid());
+        assert_eq!(to_hex_string(&even), even_hex);
+        assert_eq!(
+            ComparableFloat(x.round_even_ref()),
+            ComparableFloat(even.clone())
+        );
+        let mut even_alt = x;
+        even_alt.round_even_assign();
+        assert_eq!(ComparableFloat(even_alt), ComparableFloat(even));
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "Infinity");
+    test("-0x0.0", "-0x0.0", "-0x0.0", "-0x0.0", "-0x0.0");
+    test("0x2.8#3", "0x2.0#3", "0x3.0#3", "0x2.0#3", "0x2.0#3");
+    test("-0x2.8#3", "-0x3.0#3", "-0x2.0#3", "-0x2.0#3", "-0x2.0#3");
+    test("0x3.8#3", "0x3.0#3", "0x4.0#3", "0x3.0#3", "0x4.0#3");
+    test("0x0.4#1", "0x0.0", "0x1.0#1", "0x0.0", "0x0.0");
+    test("-0x0.4#1", "-0x1.0#1", "-0x0.0", "-0x0.0", "-0x0.0");
+    test("0x0.c#2", "0x0.0", "0x1.0#2", "0x0.0", "0x1.0#2");
+    test("0x5.0#3", "0x5.0#3", "0x5.0#3", "0x5.0#3", "0x5.0#3");
+}
+
+#[test]
+fn test_frac_and_modf() {
+    let test = |s_hex, trunc_hex: &str, frac_hex: &str| {
+        let x = parse_hex_string(s_hex);
+
+        let frac = x.clone().frac();
+        assert!(frac.is_valid());
+        assert_eq!(to_hex_string(&frac), frac_hex);
+        assert_eq!(ComparableFloat(x.frac_ref()), ComparableFloat(frac.clone()));
+        let mut frac_alt = x.clone();
+        frac_alt.frac_assign();
+        assert_eq!(ComparableFloat(frac_alt), ComparableFloat(frac.clone()));
+
+        let (trunc, frac_alt) = x.clone().modf();
+        assert!(trunc.is_valid());
+        assert!(frac_alt.is_valid());
+        assert_eq!(to_hex_string(&trunc), trunc_hex);
+        assert_eq!(to_hex_string(&frac_alt), frac_hex);
+        let (trunc_alt, frac_alt) = x.modf_ref();
+        assert_eq!(ComparableFloat(trunc_alt), ComparableFloat(trunc));
+        assert_eq!(ComparableFloat(frac_alt), ComparableFloat(frac));
+    };
+    test("NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0x0.0");
+    test("-Infinity", "-Infinity", "-0x0.0");
+    test("0x0.0", "0x0.0", "0x0.0");
+    test("-0x0.0", "-0x0.0", "-0x0.0");
+    test("0x2.c#4", "0x2.0#4", "0x0.c#4");
+    test("-0x2.c#4", "-0x2.0#4", "-0x0.c#4");
+    test("0x0.c#2", "0x0.0", "0x0.c#2");
+    test("-0x0.c#2", "-0x0.0", "-0x0.c#2");
+    test("0x3.0#2", "0x3.0#2", "0x0.0");
+    test("-0x3.0#2", "-0x3.0#2", "-0x0.0");
+    test(
+        "0x3.243f6a8885a30#53",
+        "0x3.0000000000000#53",
+        "0x0.243f6a8885a300#53",
+    );
+}
+
+fn rug_round(x: &rug::Float, rm: RoundingMode) -> Option<rug::Float> {
+    match rm {
+        Floor => Some(x.clone().floor()),
+        Ceiling => Some(x.clone().ceil()),
+        Down => Some(x.clone().trunc()),
+        Nearest => Some(x.clone().round_even()),
+        NearestTiesAway => Some(x.clone().round()),
+        Exact => Some(x.clone()),
+        _ => None,
+    }
+}
+
+#[test]
+fn round_to_integral_properties() {
+    float_rounding_mode_pair_gen().test_properties(|(x, rm)| {
+        if rm == Exact && x.is_finite() && !(&x).is_integer() {
+            return;
+        }
+        let (rounded, o) = x.clone().round_to_integral(rm);
+        assert!(rounded.is_valid());
+
+        let (rounded_alt, o_alt) = x.round_to_integral_ref(rm);
+        assert!(rounded_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&rounded_alt),
+            ComparableFloatRef(&rounded)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut rounded_alt = x.clone();
+        let o_alt = rounded_alt.round_to_integral_assign(rm);
+        assert!(rounded_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&rounded_alt),
+            ComparableFloatRef(&rounded)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Some(rug_rounded) = rug_round(&rug::Float::exact_from(&x), rm) {
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_rounded)),
+                ComparableFloatRef(&rounded)
+            );
+        }
+
+        if x.is_finite() {
+            assert!((&rounded).is_integer());
+            assert_eq!(rounded.partial_cmp(&x), Some(o));
+            assert_eq!(rounded.is_sign_negative(), x.is_sign_negative());
+            if rounded != 0u32 {
+                assert_eq!(rounded.get_prec(), x.get_prec());
+            }
+            if x != 0u32 {
+                let (i, i_o) = Integer::rounding_from(&x, rm);
+                assert_eq!(rounded, i);
+                assert_eq!(i_o, o);
+            }
+            let (neg_rounded, neg_o) = (-&x).round_to_integral(-rm);
+            assert_eq!(ComparableFloat(neg_rounded), ComparableFloat(-&rounded));
+            assert_eq!(neg_o, o.reverse());
+            assert_eq!(
+                ComparableFloat(rounded.round_to_integral_ref(Exact).0),
+                ComparableFloat(rounded)
+            );
+        } else {
+            assert_eq!(ComparableFloatRef(&rounded), ComparableFloatRef(&x));
+            assert_eq!(o, Equal);
+        }
+    });
+}
+
+#[test]
+fn floor_ceiling_trunc_round_even_properties() {
+    float_gen().test_properties(|x| {
+        let floor = x.clone().floor();
+        assert!(floor.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&floor),
+            ComparableFloatRef(&x.round_to_integral_ref(Floor).0)
+        );
+        assert_eq!(
+            ComparableFloat((&x).floor()),
+            ComparableFloat(floor.clone())
+        );
+        let mut floor_alt = x.clone();
+        floor_alt.floor_assign();
+        assert_eq!(ComparableFloat(floor_alt), ComparableFloat(floor.clone()));
+
+        let ceiling = x.clone().ceiling();
+        assert!(ceiling.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&ceiling),
+            ComparableFloatRef(&x.round_to_integral_ref(Ceiling).0)
+        );
+        assert_eq!(
+            ComparableFloat((&x).ceiling()),
+            ComparableFloat(ceiling.clone())
+        );
+        let mut ceiling_alt = x.clone();
+        ceiling_alt.ceiling_assign();
+        assert_eq!(
+            ComparableFloat(ceiling_alt),
+            ComparableFloat(ceiling.clone())
+        );
+        assert_eq!(ComparableFloat((-&x).floor()), ComparableFloat(-&ceiling));
+
+        let trunc = x.clone().trunc();
+        assert!(trunc.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&trunc),
+            ComparableFloatRef(&x.round_to_integral_ref(Down).0)
+        );
+        assert_eq!(
+            ComparableFloat(x.trunc_ref()),
+            ComparableFloat(trunc.clone())
+        );
+        let mut trunc_alt = x.clone();
+        trunc_alt.trunc_assign();
+        assert_eq!(ComparableFloat(trunc_alt), ComparableFloat(trunc.clone()));
+        if x.is_sign_positive() {
+            assert_eq!(ComparableFloatRef(&trunc), ComparableFloatRef(&floor));
+        } else {
+            assert_eq!(ComparableFloatRef(&trunc), ComparableFloatRef(&ceiling));
+        }
+
+        let even = x.clone().round_even();
+        assert!(even.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&even),
+            ComparableFloatRef(&x.round_to_integral_ref(Nearest).0)
+        );
+        assert_eq!(
+            ComparableFloat(x.round_even_ref()),
+            ComparableFloat(even.clone())
+        );
+        let mut even_alt = x.clone();
+        even_alt.round_even_assign();
+        assert_eq!(ComparableFloat(even_alt), ComparableFloat(even.clone()));
+        if x.is_finite() {
+            assert!(floor <= even && even <= ceiling);
+        }
+    });
+}
+
+#[test]
+fn frac_and_modf_properties() {
+    float_gen().test_properties(|x| {
+        let frac = x.clone().frac();
+        assert!(frac.is_valid());
+        assert_eq!(ComparableFloat(x.frac_ref()), ComparableFloat(frac.clone()));
+        let mut frac_alt = x.clone();
+        frac_alt.frac_assign();
+        assert_eq!(ComparableFloat(frac_alt), ComparableFloat(frac.clone()));
+
+        let (trunc, frac_alt) = x.clone().modf();
+        assert!(trunc.is_valid());
+        assert!(frac_alt.is_valid());
+        assert_eq!(
+            ComparableFloat(x.trunc_ref()),
+            ComparableFloat(trunc.clone())
+        );
+        let (trunc_alt, frac_alt_2) = x.modf_ref();
+        assert_eq!(ComparableFloat(trunc_alt), ComparableFloat(trunc.clone()));
+        assert_eq!(
+            ComparableFloat(frac_alt_2),
+            ComparableFloat(frac_alt.clone())
+        );
+
+        let rug_x = rug::Float::exact_from(&x);
+        assert_eq!(
+            ComparableFloat(Float::from(&rug_x.clone().fract())),
+            ComparableFloat(frac.clone())
+        );
+        let (rug_trunc, rug_frac) =
+            rug_x.trunc_fract(rug::Float::new(u32::exact_from(x.significant_bits())));
+        assert_eq!(
+            ComparableFloat(Float::from(&rug_trunc)),
+            ComparableFloat(trunc.clone())
+        );
+        assert_eq!(
+            ComparableFloat(Float::from(&rug_frac)),
+            ComparableFloat(frac_alt.clone())
+        );
+
+        assert_eq!(ComparableFloatRef(&frac), ComparableFloatRef(&frac_alt));
+        if x.is_finite() {
+            assert_eq!(frac.is_sign_negative(), x.is_sign_negative());
+            assert!(frac.get_prec().map_or(true, |p| p == x.significant_bits()));
+            assert!(frac.lt_abs(&1u32));
+            assert_eq!(
+                Rational::exact_from(&trunc) + Rational::exact_from(&frac),
+                Rational::exact_from(&x)
+            );
+        } else if x.is_nan() {
+            assert!(frac.is_nan());
+        } else {
+            assert_eq!(frac, 0u32);
+            assert_eq!(frac.is_sign_negative(), x.is_sign_negative());
+        }
+    });
+}
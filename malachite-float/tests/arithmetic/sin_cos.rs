@@ -0,0 +1,3164 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::test_util::arithmetic::sin_cos::{
+    rug_cos_prec, rug_cos_prec_round, rug_cos_round, rug_sin_cos_prec, rug_sin_cos_prec_round,
+    rug_sin_cos_round, rug_sin_prec, rug_sin_prec_round, rug_sin_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec(prec);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_assign(prec);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "0.842", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "0.909", "0x0.e8c#10", Less);
+    test("3.0", "0x3.0#2", 1, "0.1", "0x0.2#1", Less);
+    test("3.0", "0x3.0#2", 10, "0.1411", "0x0.242#10", Less);
+    test("123.0", "0x7b.0#7", 1, "-0.5", "-0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 10, "-0.46", "-0x0.75c#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, "0.5", "0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 10, "0.46", "0x0.75c#10", Greater);
+    test("355.0", "0x163.0#9", 1, "-0.00003", "-0x0.0002#1", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+    );
+    test("2.0e3010", "0x1.0E+2500#1", 1, "-0.5", "-0x0.8#1", Greater);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_prec_fail() {
+    assert_panic!(Float::NAN.sin_prec(0));
+    assert_panic!(Float::NAN.sin_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sin_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_round(rm);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_round_ref(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_round_assign(rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.841470984807896506652502321631",
+        "0x0.d76aa47848677020c6e9e909d#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Less,
+    );
+    test("2.0", "0x2.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("3.0", "0x3.0#2", Floor, "0.1", "0x0.2#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "0.19", "0x0.3#2", Greater);
+    test("3.0", "0x3.0#2", Down, "0.1", "0x0.2#2", Less);
+    test("3.0", "0x3.0#2", Up, "0.19", "0x0.3#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "0.1", "0x0.2#2", Less);
+    test("123.0", "0x7b.0#7", Floor, "-0.461", "-0x0.76#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "-0.457", "-0x0.75#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "-0.457", "-0x0.75#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.461", "-0x0.76#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.461", "-0x0.76#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "0.457", "0x0.75#7", Less);
+    test("-123.0", "-0x7b.0#7", Ceiling, "0.461", "0x0.76#7", Greater);
+    test("-123.0", "-0x7b.0#7", Down, "0.457", "0x0.75#7", Less);
+    test("-123.0", "-0x7b.0#7", Up, "0.461", "0x0.76#7", Greater);
+    test("-123.0", "-0x7b.0#7", Nearest, "0.461", "0x0.76#7", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "-0.0000301",
+        "-0x0.0001f9#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "-0.0000301",
+        "-0x0.0001f9#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "-0.00003016",
+        "-0x0.0001fa#9",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "0.41078129050290879",
+        "0x0.6928f67084c778#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "0.41078129050290885",
+        "0x0.6928f67084c77c#53",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "2.0e-9", "0x8.0E-8#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "2.0e-9", "0x8.0E-8#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "4.0e-9", "0x1.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", Down, "-0.5", "-0x0.8#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Up, "-1.0", "-0x1.0#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Nearest, "-1.0", "-0x1.0#1", Less);
+    test("2.0e3010", "0x1.0E+2500#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Down,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("2.0e3010", "0x1.0E+2500#1", Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+}
+
+#[test]
+fn sin_round_fail() {
+    assert_panic!(Float::TWO.sin_round(Exact));
+    assert_panic!(Float::TWO.sin_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.sin_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sin_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, o) = x.clone().sin_prec_round(prec, rm);
+        assert!(sin.is_valid());
+
+        assert_eq!(sin.to_string(), out);
+        assert_eq!(to_hex_string(&sin), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sin_alt = x.clone();
+        let o_alt = sin_alt.sin_prec_round_assign(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "0.841", "0x0.d74#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.841", "0x0.d74#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.842", "0x0.d78#10", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, Floor, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-0.5", "-0x0.8#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-1.0", "-0x1.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-0.841",
+        "-0x0.d74#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-0.842", "-0x0.d78#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "0.909", "0x0.e8c#10", Less);
+    test("2.0", "0x2.0#1", 10, Ceiling, "0.91", "0x0.e90#10", Greater);
+    test("2.0", "0x2.0#1", 10, Down, "0.909", "0x0.e8c#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "0.91", "0x0.e90#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "0.909", "0x0.e8c#10", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "0.1411", "0x0.242#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.1414",
+        "0x0.243#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "0.1411", "0x0.242#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "0.1414", "0x0.243#10", Greater);
+    test("3.0", "0x3.0#2", 10, Nearest, "0.1411", "0x0.242#10", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "-0.46", "-0x0.75c#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.4595",
+        "-0x0.75a#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.4595",
+        "-0x0.75a#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Up, "-0.46", "-0x0.75c#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.46",
+        "-0x0.75c#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "0.4595",
+        "0x0.75a#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "0.46",
+        "0x0.75c#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "0.4595",
+        "0x0.75a#10",
+        Less,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "0.46", "0x0.75c#10", Greater);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "0.46",
+        "0x0.75c#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "-0.00003016",
+        "-0x0.0001fa0#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "-0.00003016",
+        "-0x0.0001fa0#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.4111",
+        "0x0.694#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "0.4111",
+        "0x0.694#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "-0.873",
+        "-0x0.df8#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "-0.873",
+        "-0x0.df8#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Floor,
+        "-0.611",
+        "-0x0.9c8#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Ceiling,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Down,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Up,
+        "-0.611",
+        "-0x0.9c8#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Nearest,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "0.841", "0x0.d74#10", Less);
+}
+
+#[test]
+fn sin_prec_round_fail() {
+    assert_panic!(Float::ONE.sin_prec_round(0, Floor));
+    assert_panic!(Float::ONE.sin_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sin_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.sin_prec_round(1, Exact));
+    assert_panic!(Float::TWO.sin_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.sin_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_cos_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_assign(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "-0.5", "-0x0.8#1", Less);
+    test("2.0", "0x2.0#1", 10, "-0.416", "-0x0.6a8#10", Greater);
+    test("3.0", "0x3.0#2", 1, "-1.0", "-0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, "-0.99", "-0x0.fd8#10", Less);
+    test("123.0", "0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "-0.888", "-0x0.e34#10", Greater);
+    test("-123.0", "-0x7b.0#7", 1, "-1.0", "-0x1.0#1", Less);
+    test("-123.0", "-0x7b.0#7", 10, "-0.888", "-0x0.e34#10", Greater);
+    test("355.0", "0x163.0#9", 1, "-1.0", "-0x1.0#1", Less);
+    test("355.0", "0x163.0#9", 10, "-1.0", "-0x1.000#10", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "1.0", "0x1.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", 10, "1.0", "0x1.000#10", Greater);
+    test("1.0e30", "0x1.0E+25#1", 1, "0.5", "0x0.8#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", 10, "0.4893", "0x0.7d4#10", Greater);
+    test("2.0e3010", "0x1.0E+2500#1", 1, "-1.0", "-0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+}
+
+#[test]
+fn cos_prec_fail() {
+    assert_panic!(Float::NAN.cos_prec(0));
+    assert_panic!(Float::NAN.cos_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.cos_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_cos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_round_assign(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("0.0", "0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Floor, "1.0", "0x1.0#1", Equal);
+    test("-0.0", "-0x0.0", Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Floor, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Nearest, "0.5", "0x0.8#1", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", Floor, "-0.5", "-0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "-0.2", "-0x0.4#1", Greater);
+    test("2.0", "0x2.0#1", Down, "-0.2", "-0x0.4#1", Greater);
+    test("2.0", "0x2.0#1", Up, "-0.5", "-0x0.8#1", Less);
+    test("2.0", "0x2.0#1", Nearest, "-0.5", "-0x0.8#1", Less);
+    test("3.0", "0x3.0#2", Floor, "-1.0", "-0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "-0.8", "-0x0.c#2", Greater);
+    test("3.0", "0x3.0#2", Down, "-0.8", "-0x0.c#2", Greater);
+    test("3.0", "0x3.0#2", Up, "-1.0", "-0x1.0#2", Less);
+    test("3.0", "0x3.0#2", Nearest, "-1.0", "-0x1.0#2", Less);
+    test("123.0", "0x7b.0#7", Floor, "-0.89", "-0x0.e4#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "-0.883", "-0x0.e2#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "-0.883", "-0x0.e2#7", Greater);
+    test("123.0", "0x7b.0#7", Up, "-0.89", "-0x0.e4#7", Less);
+    test("123.0", "0x7b.0#7", Nearest, "-0.89", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.89", "-0x0.e4#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.883",
+        "-0x0.e2#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.883", "-0x0.e2#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.89", "-0x0.e4#7", Less);
+    test("-123.0", "-0x7b.0#7", Nearest, "-0.89", "-0x0.e4#7", Less);
+    test("355.0", "0x163.0#9", Floor, "-1.0", "-0x1.00#9", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "-0.998",
+        "-0x0.ff8#9",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", Down, "-0.998", "-0x0.ff8#9", Greater);
+    test("355.0", "0x163.0#9", Up, "-1.0", "-0x1.00#9", Less);
+    test("355.0", "0x163.0#9", Nearest, "-1.0", "-0x1.00#9", Less);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "0.5", "0x0.8#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Ceiling, "1.0", "0x1.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Down, "0.5", "0x0.8#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "1.0", "0x1.0#1", Greater);
+    test("4.0e-9", "0x1.0E-7#1", Nearest, "1.0", "0x1.0#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Floor, "0.2", "0x0.4#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Ceiling, "0.5", "0x0.8#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Down, "0.2", "0x0.4#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Up, "0.5", "0x0.8#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Nearest, "0.5", "0x0.8#1", Greater);
+    test("2.0e3010", "0x1.0E+2500#1", Floor, "-1.0", "-0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Down,
+        "-0.5",
+        "-0x0.8#1",
+        Greater,
+    );
+    test("2.0e3010", "0x1.0E+2500#1", Up, "-1.0", "-0x1.0#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Less,
+    );
+}
+
+#[test]
+fn cos_round_fail() {
+    assert_panic!(Float::TWO.cos_round(Exact));
+    assert_panic!(Float::TWO.cos_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.cos_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_cos_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+        assert_eq!(o, o_out);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_round_assign(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("0.0", "0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("1.0", "0x1.0#1", 10, Floor, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "0.54", "0x0.8a4#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "0.541", "0x0.8a8#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "0.54", "0x0.8a4#10", Less);
+    test("-1.0", "-0x1.0#1", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "0.54", "0x0.8a4#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Down, "0.54", "0x0.8a4#10", Less);
+    test("-1.0", "-0x1.0#1", 10, Up, "0.541", "0x0.8a8#10", Greater);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "0.54", "0x0.8a4#10", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "-0.4165", "-0x0.6aa#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "-0.416",
+        "-0x0.6a8#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "-0.416", "-0x0.6a8#10", Greater);
+    test("2.0", "0x2.0#1", 10, Up, "-0.4165", "-0x0.6aa#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "-0.416",
+        "-0x0.6a8#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Floor, "-0.99", "-0x0.fd8#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "-0.989",
+        "-0x0.fd4#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "-0.989", "-0x0.fd4#10", Greater);
+    test("3.0", "0x3.0#2", 10, Up, "-0.99", "-0x0.fd8#10", Less);
+    test("3.0", "0x3.0#2", 10, Nearest, "-0.99", "-0x0.fd8#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.889",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Down,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Up, "-0.889", "-0x0.e38#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-0.889",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "-0.889", "-0x0.e38#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Floor, "-1.0", "-0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test("355.0", "0x163.0#9", 10, Up, "-1.0", "-0x1.000#10", Less);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.911",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.911",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 10, Up, "1.0", "0x1.000#10", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "0.4888",
+        "0x0.7d2#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "0.4893",
+        "0x0.7d4#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "0.4888",
+        "0x0.7d2#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "0.4893",
+        "0x0.7d4#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "0.4893",
+        "0x0.7d4#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Floor,
+        "-0.793",
+        "-0x0.cb0#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Ceiling,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Down,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Up,
+        "-0.793",
+        "-0x0.cb0#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Nearest,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "0.54", "0x0.8a4#10", Less);
+}
+
+#[test]
+fn cos_prec_round_fail() {
+    assert_panic!(Float::ONE.cos_prec_round(0, Floor));
+    assert_panic!(Float::ONE.cos_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cos_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.cos_prec_round(1, Exact));
+    assert_panic!(Float::TWO.cos_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.cos_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_sin_cos_prec_round() {
+    let test = |s,
+                s_hex,
+                prec: u64,
+                rm,
+                out_sin: &str,
+                out_sin_hex: &str,
+                o_out_sin: Ordering,
+                out_cos: &str,
+                out_cos_hex: &str,
+                o_out_cos: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(o_sin, o_out_sin);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+        assert_eq!(o_cos, o_out_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_out_sin);
+        assert_eq!(o_cos_alt, o_out_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN", "NaN", 10, Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal, "NaN", "NaN", Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        Floor,
+        "NaN",
+        "NaN",
+        Equal,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        Floor,
+        "0.0",
+        "0x0.0",
+        Equal,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        Floor,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Floor,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Floor,
+        "-0.842",
+        "-0x0.d78#10",
+        Less,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        Greater,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        Less,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+        "0.541",
+        "0x0.8a8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Floor,
+        "0.909",
+        "0x0.e8c#10",
+        Less,
+        "-0.4165",
+        "-0x0.6aa#10",
+        Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "0.91",
+        "0x0.e90#10",
+        Greater,
+        "-0.416",
+        "-0x0.6a8#10",
+        Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "0.909",
+        "0x0.e8c#10",
+        Less,
+        "-0.416",
+        "-0x0.6a8#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Floor,
+        "0.1411",
+        "0x0.242#10",
+        Less,
+        "-0.99",
+        "-0x0.fd8#10",
+        Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "0.1414",
+        "0x0.243#10",
+        Greater,
+        "-0.989",
+        "-0x0.fd4#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "0.1411",
+        "0x0.242#10",
+        Less,
+        "-0.99",
+        "-0x0.fd8#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Floor,
+        "-0.46",
+        "-0x0.75c#10",
+        Less,
+        "-0.889",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.4595",
+        "-0x0.75a#10",
+        Greater,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "-0.46",
+        "-0x0.75c#10",
+        Less,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "0.4595",
+        "0x0.75a#10",
+        Less,
+        "-0.889",
+        "-0x0.e38#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "0.46",
+        "0x0.75c#10",
+        Greater,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "0.46",
+        "0x0.75c#10",
+        Greater,
+        "-0.888",
+        "-0x0.e34#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "-0.00003016",
+        "-0x0.0001fa0#10",
+        Less,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "-0.00003013",
+        "-0x0.0001f98#10",
+        Greater,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+        "-0.999",
+        "-0x0.ffc#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+        "-1.0",
+        "-0x1.000#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "0.4111",
+        "0x0.694#10",
+        Greater,
+        "-0.911",
+        "-0x0.e94#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "0.4106",
+        "0x0.692#10",
+        Less,
+        "-0.912",
+        "-0x0.e98#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.722e-9",
+        "0xf.fcE-8#10",
+        Less,
+        "0.999",
+        "0x0.ffc#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Greater,
+        "1.0",
+        "0x1.000#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "-0.873",
+        "-0x0.df8#10",
+        Less,
+        "0.4888",
+        "0x0.7d2#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+        "0.4893",
+        "0x0.7d4#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "-0.872",
+        "-0x0.df4#10",
+        Greater,
+        "0.4893",
+        "0x0.7d4#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Floor,
+        "-0.611",
+        "-0x0.9c8#10",
+        Less,
+        "-0.793",
+        "-0x0.cb0#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Ceiling,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Nearest,
+        "-0.61",
+        "-0x0.9c4#10",
+        Greater,
+        "-0.792",
+        "-0x0.cac#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.842",
+        "0x0.d78#10",
+        Greater,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        ToOdd,
+        "0.841",
+        "0x0.d74#10",
+        Less,
+        "0.54",
+        "0x0.8a4#10",
+        Less,
+    );
+}
+
+#[test]
+fn sin_cos_prec_round_fail() {
+    assert_panic!(Float::ONE.sin_cos_prec_round(0, Floor));
+    assert_panic!(Float::ONE.sin_cos_prec_round_ref(0, Floor));
+    assert_panic!(Float::TWO.sin_cos_prec_round(1, Exact));
+    assert_panic!(Float::TWO.sin_cos_prec_round_ref(1, Exact));
+}
+
+#[test]
+fn sin_cos_round_fail() {
+    assert_panic!(Float::TWO.sin_cos_round(Exact));
+    assert_panic!(Float::TWO.sin_cos_round_ref(Exact));
+}
+
+#[test]
+fn sin_cos_prec_fail() {
+    assert_panic!(Float::NAN.sin_cos_prec(0));
+    assert_panic!(Float::NAN.sin_cos_prec_ref(0));
+}
+
+#[test]
+fn sin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (sin, o) = x.clone().sin_prec_round(prec, rm);
+        assert!(sin.is_valid());
+
+        let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sin_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_o) = rug_sin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (sin_alt, o_alt) = (-&x).sin_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-sin_alt), ComparableFloat(sin.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        if x.is_finite() {
+            assert!(sin.le_abs(&1u32));
+        }
+
+        if sin.is_normal() {
+            assert_eq!(sin.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.sin_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sin, o) = Float::NAN.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_INFINITY.sin_prec_round(prec, rm);
+        assert!(sin.is_nan());
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (sin, o) = Float::NEGATIVE_ZERO.sin_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_prec_properties_helper(x: Float, prec: u64) {
+    let (sin, o) = x.clone().sin_prec(prec);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = x.sin_prec_ref(prec);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (rug_sin, rug_o) = rug_sin_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sin)),
+        ComparableFloatRef(&sin),
+    );
+    assert_eq!(rug_o, o);
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if sin.is_normal() {
+        assert_eq!(sin.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn sin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sin_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sin_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sin, o) = x.clone().sin_round(rm);
+    assert!(sin.is_valid());
+
+    let (sin_alt, o_alt) = x.sin_round_ref(rm);
+    assert!(sin_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sin_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    let (sin_alt, o_alt) = x.sin_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sin, rug_o) = rug_sin_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn sin_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        sin_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (cos_alt, o_alt) = (-&x).cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cos_alt), ComparableFloat(cos.clone()));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(cos.le_abs(&1u32));
+        }
+
+        if cos.is_normal() {
+            assert_eq!(cos.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.cos_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (cos, o) = Float::NAN.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_INFINITY.cos_prec_round(prec, rm);
+        assert!(cos.is_nan());
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::ZERO.cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+        let (cos, o) = Float::NEGATIVE_ZERO.cos_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(cos), ComparableFloat(Float::one_prec(prec)));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cos_prec_properties_helper(x: Float, prec: u64) {
+    let (cos, o) = x.clone().cos_prec(prec);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_cos)),
+        ComparableFloatRef(&cos),
+    );
+    assert_eq!(rug_o, o);
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if cos.is_normal() {
+        assert_eq!(cos.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        cos_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn cos_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (cos, o) = x.clone().cos_round(rm);
+    assert!(cos.is_valid());
+
+    let (cos_alt, o_alt) = x.cos_round_ref(rm);
+    assert!(cos_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.cos_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    let (cos_alt, o_alt) = x.cos_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn cos_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        cos_round_properties_helper(x, rm);
+    });
+}
+
+#[test]
+fn sin_cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let (sin_alt, o_sin_alt) = x.sin_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_sin_alt, o_sin);
+        let (cos_alt, o_cos_alt) = x.cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_cos_alt, o_cos);
+
+        if rm == Nearest {
+            let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+            assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+            assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+            assert_eq!(o_sin_alt, o_sin);
+            assert_eq!(o_cos_alt, o_cos);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) =
+            x.sin_cos_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+                rug_sin_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin),
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos),
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (sin, cos, o_sin, o_cos) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt, o_sin_alt, o_cos_alt) = x.sin_cos_prec_ref(prec);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let (rug_sin, rug_cos, rug_o_sin, rug_o_cos) =
+            rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin),
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos),
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    });
+}
@@ -0,0 +1,2823 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign, Square};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, rounding_mode_gen, unsigned_gen_var_11,
+    unsigned_rounding_mode_pair_gen_var_3,
+};
+use malachite_float::arithmetic::sqrt::sqrt_prec_round_naive;
+use malachite_float::test_util::arithmetic::sqrt::{
+    rug_sqrt, rug_sqrt_prec, rug_sqrt_prec_round, rug_sqrt_round,
+};
+use malachite_float::test_util::common::{
+    emulate_primitive_float_fn, parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_6, float_gen_var_7, float_rounding_mode_pair_gen_var_18,
+    float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_4,
+    rational_unsigned_rounding_mode_triple_gen_var_2,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sqrt = x.clone().sqrt();
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+
+        let sqrt_alt = (&x).sqrt();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        let mut sqrt_alt = x.clone();
+        sqrt_alt.sqrt_assign();
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&sqrt)
+        );
+
+        let sqrt_alt = sqrt_prec_round_naive(x.clone(), x.significant_bits(), Nearest).0;
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+    );
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("3.0", "0x3.0#2", "1.5", "0x1.8#2");
+    test("4.0", "0x4.0#1", "2.0", "0x2.0#1");
+    test("123.0", "0x7b.0#7", "11.1", "0xb.2#7");
+    test("-123.0", "-0x7b.0#7", "NaN", "NaN");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+    );
+    test("4.0e-9", "0x1.0E-7#1", "0.00006", "0x0.0004#1");
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        "1.148e602",
+        "0x1.000E+500#10",
+    );
+    test("1.0e-1205", "0x3.0E-1001#2", "3.0e-603", "0x6.0E-501#2");
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec(prec);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_assign(prec);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), prec, Nearest);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let (rug_sqrt, rug_o) = rug_sqrt_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 100, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 1, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 10, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", 100, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 100, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 100, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 100, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Equal);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, "1.414", "0x1.6a0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test("3.0", "0x3.0#2", 1, "2.0", "0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", 10, "1.732", "0x1.bb8#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.732050807568877293527446341506",
+        "0x1.bb67ae8584caa73b25742d708#100",
+        Greater,
+    );
+    test("4.0", "0x4.0#1", 1, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 10, "2.0", "0x2.00#10", Equal);
+    test(
+        "4.0",
+        "0x4.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", 1, "8.0", "0x8.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, "11.09", "0xb.18#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        100,
+        "11.09053650640941716205160010262",
+        "0xb.172d66861f5eda75e3bedbc0#100",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 100, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.648",
+        "0x1.a60#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.648721270700128103007192640211",
+        "0x1.a61298e1e069b96e729041524#100",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "0.00006", "0x0.0004#1", Equal);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        100,
+        "0.00006103515625",
+        "0x0.00040000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        100,
+        "1.148130695274254524232833201178e602",
+        "0x1.0000000000000000000000000E+500#100",
+        Equal,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        "4.0e-603",
+        "0x8.0E-501#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        "3.772e-603",
+        "0x6.eeE-501#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        100,
+        "3.771458281487591216403510410474e-603",
+        "0x6.ed9eba16132a9cec95d0b5c20E-501#100",
+        Greater,
+    );
+}
+
+#[test]
+fn sqrt_prec_fail() {
+    assert_panic!(Float::NAN.sqrt_prec(0));
+    assert_panic!(Float::NAN.sqrt_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_round(rm);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_round_assign(rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", Down, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal);
+    test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", ToOdd, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Nearest, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", ToOdd, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Up, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", ToOdd, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", ToOdd, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Nearest, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", NearestTiesAway, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", ToOdd, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", Floor, "1.5", "0x1.8#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "2.0", "0x2.0#2", Greater);
+    test("3.0", "0x3.0#2", Down, "1.5", "0x1.8#2", Less);
+    test("3.0", "0x3.0#2", Up, "2.0", "0x2.0#2", Greater);
+    test("3.0", "0x3.0#2", Nearest, "1.5", "0x1.8#2", Less);
+    test("3.0", "0x3.0#2", NearestTiesAway, "1.5", "0x1.8#2", Less);
+    test("3.0", "0x3.0#2", ToOdd, "1.5", "0x1.8#2", Less);
+    test("4.0", "0x4.0#1", Floor, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", Ceiling, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", Down, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", Up, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", Nearest, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", NearestTiesAway, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", ToOdd, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", Exact, "2.0", "0x2.0#1", Equal);
+    test("123.0", "0x7b.0#7", Floor, "11.0", "0xb.0#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "11.1", "0xb.2#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "11.0", "0xb.0#7", Less);
+    test("123.0", "0x7b.0#7", Up, "11.1", "0xb.2#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "11.1", "0xb.2#7", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "11.1",
+        "0xb.2#7",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", ToOdd, "11.1", "0xb.2#7", Greater);
+    test("-123.0", "-0x7b.0#7", Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Nearest, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", NearestTiesAway, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", ToOdd, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "1.648721270700128",
+        "0x1.a61298e1e069b#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "1.648721270700128",
+        "0x1.a61298e1e069b#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        NearestTiesAway,
+        "1.6487212707001282",
+        "0x1.a61298e1e069c#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        ToOdd,
+        "1.648721270700128",
+        "0x1.a61298e1e069b#53",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Floor,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "0.00006", "0x0.0004#1", Equal);
+    test("4.0e-9", "0x1.0E-7#1", Up, "0.00006", "0x0.0004#1", Equal);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        NearestTiesAway,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        ToOdd,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Exact,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Floor,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Ceiling,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Down,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Up,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Nearest,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        NearestTiesAway,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        ToOdd,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        Exact,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Floor,
+        "3.0e-603",
+        "0x6.0E-501#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Ceiling,
+        "4.0e-603",
+        "0x8.0E-501#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Down,
+        "3.0e-603",
+        "0x6.0E-501#2",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Up,
+        "4.0e-603",
+        "0x8.0E-501#2",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        Nearest,
+        "3.0e-603",
+        "0x6.0E-501#2",
+        Less,
+    );
+}
+
+#[test]
+fn sqrt_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(THREE.sqrt_round(Exact));
+    assert_panic!(THREE.sqrt_round_ref(Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sqrt_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sqrt_alt = x.clone();
+        let o_alt = sqrt_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 1, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Ceiling, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Down, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Up, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, ToOdd, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "Infinity", "Infinity", 1, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Down, "Infinity", "Infinity", Equal,
+    );
+    test("Infinity", "Infinity", 1, Up, "Infinity", "Infinity", Equal);
+    test(
+        "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Floor, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Ceiling, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Down, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Up, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Nearest, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, ToOdd, "Infinity", "Infinity", Equal,
+    );
+    test(
+        "Infinity", "Infinity", 10, Exact, "Infinity", "Infinity", Equal,
+    );
+    test("-Infinity", "-Infinity", 1, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 1, ToOdd, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 1, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Down, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Up, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Nearest, "NaN", "NaN", Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-Infinity", "-Infinity", 10, ToOdd, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, ToOdd, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Ceiling, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Down, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Up, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", 10, ToOdd, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, ToOdd, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Ceiling, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Down, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Up, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "1.0", "0x1.000#10", Equal);
+    test("1.0", "0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("-1.0", "-0x1.0#1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, ToOdd, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Down, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Up, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, NearestTiesAway, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, ToOdd, "NaN", "NaN", Equal);
+    test("-1.0", "-0x1.0#1", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Up,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        Exact,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        ToOdd,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Exact,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("2.0", "0x2.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, NearestTiesAway, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 1, ToOdd, "1.0", "0x1.0#1", Less);
+    test("2.0", "0x2.0#1", 10, Floor, "1.414", "0x1.6a0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "1.414", "0x1.6a0#10", Less);
+    test("2.0", "0x2.0#1", 10, Up, "1.416", "0x1.6a8#10", Greater);
+    test("2.0", "0x2.0#1", 10, Nearest, "1.414", "0x1.6a0#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        NearestTiesAway,
+        "1.414",
+        "0x1.6a0#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 10, ToOdd, "1.416", "0x1.6a8#10", Greater);
+    test("3.0", "0x3.0#2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 1, ToOdd, "1.0", "0x1.0#1", Less);
+    test("3.0", "0x3.0#2", 10, Floor, "1.73", "0x1.bb0#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "1.732",
+        "0x1.bb8#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Down, "1.73", "0x1.bb0#10", Less);
+    test("3.0", "0x3.0#2", 10, Up, "1.732", "0x1.bb8#10", Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "1.732",
+        "0x1.bb8#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        NearestTiesAway,
+        "1.732",
+        "0x1.bb8#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, ToOdd, "1.732", "0x1.bb8#10", Greater);
+    test("4.0", "0x4.0#1", 1, Floor, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 1, Ceiling, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 1, Down, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 1, Up, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test(
+        "4.0",
+        "0x4.0#1",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Equal,
+    );
+    test("4.0", "0x4.0#1", 1, ToOdd, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("4.0", "0x4.0#1", 10, Floor, "2.0", "0x2.00#10", Equal);
+    test("4.0", "0x4.0#1", 10, Ceiling, "2.0", "0x2.00#10", Equal);
+    test("4.0", "0x4.0#1", 10, Down, "2.0", "0x2.00#10", Equal);
+    test("4.0", "0x4.0#1", 10, Up, "2.0", "0x2.00#10", Equal);
+    test("4.0", "0x4.0#1", 10, Nearest, "2.0", "0x2.00#10", Equal);
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Equal,
+    );
+    test("4.0", "0x4.0#1", 10, ToOdd, "2.0", "0x2.00#10", Equal);
+    test("4.0", "0x4.0#1", 10, Exact, "2.0", "0x2.00#10", Equal);
+    test("123.0", "0x7b.0#7", 1, Floor, "8.0", "0x8.0#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, Down, "8.0", "0x8.0#1", Less);
+    test("123.0", "0x7b.0#7", 1, Up, "2.0e1", "0x1.0E+1#1", Greater);
+    test("123.0", "0x7b.0#7", 1, Nearest, "8.0", "0x8.0#1", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 1, ToOdd, "8.0", "0x8.0#1", Less);
+    test("123.0", "0x7b.0#7", 10, Floor, "11.08", "0xb.14#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "11.09",
+        "0xb.18#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "11.08", "0xb.14#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "11.09", "0xb.18#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "11.09",
+        "0xb.18#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "11.09",
+        "0xb.18#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, ToOdd, "11.08", "0xb.14#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 1, Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 1, Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-123.0", "-0x7b.0#7", 1, ToOdd, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 1, Exact, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Floor, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Down, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Up, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Nearest, "NaN", "NaN", Equal);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-123.0", "-0x7b.0#7", 10, ToOdd, "NaN", "NaN", Equal);
+    test("-123.0", "-0x7b.0#7", 10, Exact, "NaN", "NaN", Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.773",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.773",
+        "0x1.c60#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "1.771",
+        "0x1.c58#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Floor,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Down,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Up,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        Nearest,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "1.648",
+        "0x1.a60#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "1.65",
+        "0x1.a68#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "1.648",
+        "0x1.a60#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "1.65",
+        "0x1.a68#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "1.648",
+        "0x1.a60#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        NearestTiesAway,
+        "1.648",
+        "0x1.a60#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        ToOdd,
+        "1.65",
+        "0x1.a68#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Floor,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Ceiling,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Down,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Up,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Nearest,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        NearestTiesAway,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        ToOdd,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        1,
+        Exact,
+        "0.00006",
+        "0x0.0004#1",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        NearestTiesAway,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        ToOdd,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Exact,
+        "0.000061",
+        "0x0.000400#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Floor,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Ceiling,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Down,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Up,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Nearest,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        NearestTiesAway,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        ToOdd,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        1,
+        Exact,
+        "1.0e602",
+        "0x1.0E+500#1",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Floor,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Ceiling,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Down,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Up,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Nearest,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        NearestTiesAway,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        ToOdd,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.318e1204",
+        "0x1.000E+1000#10",
+        10,
+        Exact,
+        "1.148e602",
+        "0x1.000E+500#10",
+        Equal,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        Floor,
+        "2.0e-603",
+        "0x4.0E-501#1",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        Ceiling,
+        "4.0e-603",
+        "0x8.0E-501#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        Down,
+        "2.0e-603",
+        "0x4.0E-501#1",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        Up,
+        "4.0e-603",
+        "0x8.0E-501#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        Nearest,
+        "4.0e-603",
+        "0x8.0E-501#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        NearestTiesAway,
+        "4.0e-603",
+        "0x8.0E-501#1",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        1,
+        ToOdd,
+        "2.0e-603",
+        "0x4.0E-501#1",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Floor,
+        "3.768e-603",
+        "0x6.ecE-501#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Ceiling,
+        "3.772e-603",
+        "0x6.eeE-501#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Down,
+        "3.768e-603",
+        "0x6.ecE-501#10",
+        Less,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Up,
+        "3.772e-603",
+        "0x6.eeE-501#10",
+        Greater,
+    );
+    test(
+        "1.0e-1205",
+        "0x3.0E-1001#2",
+        10,
+        Nearest,
+        "3.772e-603",
+        "0x6.eeE-501#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sqrt_prec_round_fail() {
+    const THREE: Float = Float::const_from_unsigned(3);
+    assert_panic!(Float::one_prec(1).sqrt_prec_round(0, Floor));
+    assert_panic!(Float::one_prec(1).sqrt_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sqrt_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(THREE.sqrt_prec_round(1, Exact));
+    assert_panic!(THREE.sqrt_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = THREE;
+        x.sqrt_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn test_sqrt_rational_prec_round() {
+    let test = |s, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = Rational::from_str(s).unwrap();
+
+        let (sqrt, o) = Float::sqrt_rational_prec_round(x.clone(), prec, rm);
+        assert!(sqrt.is_valid());
+
+        assert_eq!(sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&sqrt), out_hex);
+        assert_eq!(o, o_out);
+
+        let (sqrt_alt, o_alt) = Float::sqrt_rational_prec_round_ref(&x, prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+        assert_eq!(o_alt, o_out);
+
+        if rm == Nearest {
+            let (sqrt_alt, o_alt) = Float::sqrt_rational_prec_ref(&x, prec);
+            assert_eq!(ComparableFloatRef(&sqrt), ComparableFloatRef(&sqrt_alt));
+            assert_eq!(o_alt, o_out);
+        }
+    };
+    test("0", 1, Floor, "0.0", "0x0.0", Equal);
+    test("0", 1, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 1, Down, "0.0", "0x0.0", Equal);
+    test("0", 1, Up, "0.0", "0x0.0", Equal);
+    test("0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 1, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0", 1, ToOdd, "0.0", "0x0.0", Equal);
+    test("0", 1, Exact, "0.0", "0x0.0", Equal);
+    test("0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0", 10, Ceiling, "0.0", "0x0.0", Equal);
+    test("0", 10, Down, "0.0", "0x0.0", Equal);
+    test("0", 10, Up, "0.0", "0x0.0", Equal);
+    test("0", 10, Nearest, "0.0", "0x0.0", Equal);
+    test("0", 10, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0", 10, ToOdd, "0.0", "0x0.0", Equal);
+    test("0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("1", 1, Floor, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Ceiling, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Down, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Up, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test("1", 1, NearestTiesAway, "1.0", "0x1.0#1", Equal);
+    test("1", 1, ToOdd, "1.0", "0x1.0#1", Equal);
+    test("1", 1, Exact, "1.0", "0x1.0#1", Equal);
+    test("1", 10, Floor, "1.0", "0x1.000#10", Equal);
+    test("1", 10, Ceiling, "1.0", "0x1.000#10", Equal);
+    test("1", 10, Down, "1.0", "0x1.000#10", Equal);
+    test("1", 10, Up, "1.0", "0x1.000#10", Equal);
+    test("1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test("1", 10, NearestTiesAway, "1.0", "0x1.000#10", Equal);
+    test("1", 10, ToOdd, "1.0", "0x1.000#10", Equal);
+    test("1", 10, Exact, "1.0", "0x1.000#10", Equal);
+    test("2", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("2", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("2", 1, Down, "1.0", "0x1.0#1", Less);
+    test("2", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("2", 1, Nearest, "1.0", "0x1.0#1", Less);
+    test("2", 1, NearestTiesAway, "1.0", "0x1.0#1", Less);
+    test("2", 1, ToOdd, "1.0", "0x1.0#1", Less);
+    test("2", 10, Floor, "1.414", "0x1.6a0#10", Less);
+    test("2", 10, Ceiling, "1.416", "0x1.6a8#10", Greater);
+    test("2", 10, Down, "1.414", "0x1.6a0#10", Less);
+    test("2", 10, Up, "1.416", "0x1.6a8#10", Greater);
+    test("2", 10, Nearest, "1.414", "0x1.6a0#10", Less);
+    test("2", 10, NearestTiesAway, "1.414", "0x1.6a0#10", Less);
+    test("2", 10, ToOdd, "1.416", "0x1.6a8#10", Greater);
+    test(
+        "2",
+        100,
+        Floor,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test(
+        "2",
+        100,
+        Ceiling,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Greater,
+    );
+    test(
+        "2",
+        100,
+        Down,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test(
+        "2",
+        100,
+        Up,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Greater,
+    );
+    test(
+        "2",
+        100,
+        Nearest,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test(
+        "2",
+        100,
+        NearestTiesAway,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test(
+        "2",
+        100,
+        ToOdd,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test("4", 1, Floor, "2.0", "0x2.0#1", Equal);
+    test("4", 1, Ceiling, "2.0", "0x2.0#1", Equal);
+    test("4", 1, Down, "2.0", "0x2.0#1", Equal);
+    test("4", 1, Up, "2.0", "0x2.0#1", Equal);
+    test("4", 1, Nearest, "2.0", "0x2.0#1", Equal);
+    test("4", 1, NearestTiesAway, "2.0", "0x2.0#1", Equal);
+    test("4", 1, ToOdd, "2.0", "0x2.0#1", Equal);
+    test("4", 1, Exact, "2.0", "0x2.0#1", Equal);
+    test("4", 10, Floor, "2.0", "0x2.00#10", Equal);
+    test("4", 10, Ceiling, "2.0", "0x2.00#10", Equal);
+    test("4", 10, Down, "2.0", "0x2.00#10", Equal);
+    test("4", 10, Up, "2.0", "0x2.00#10", Equal);
+    test("4", 10, Nearest, "2.0", "0x2.00#10", Equal);
+    test("4", 10, NearestTiesAway, "2.0", "0x2.00#10", Equal);
+    test("4", 10, ToOdd, "2.0", "0x2.00#10", Equal);
+    test("4", 10, Exact, "2.0", "0x2.00#10", Equal);
+    test("1/3", 1, Floor, "0.5", "0x0.8#1", Less);
+    test("1/3", 1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Down, "0.5", "0x0.8#1", Less);
+    test("1/3", 1, Up, "1.0", "0x1.0#1", Greater);
+    test("1/3", 1, Nearest, "0.5", "0x0.8#1", Less);
+    test("1/3", 1, NearestTiesAway, "0.5", "0x0.8#1", Less);
+    test("1/3", 1, ToOdd, "0.5", "0x0.8#1", Less);
+    test("1/3", 10, Floor, "0.577", "0x0.93c#10", Less);
+    test("1/3", 10, Ceiling, "0.578", "0x0.940#10", Greater);
+    test("1/3", 10, Down, "0.577", "0x0.93c#10", Less);
+    test("1/3", 10, Up, "0.578", "0x0.940#10", Greater);
+    test("1/3", 10, Nearest, "0.577", "0x0.93c#10", Less);
+    test("1/3", 10, NearestTiesAway, "0.577", "0x0.93c#10", Less);
+    test("1/3", 10, ToOdd, "0.577", "0x0.93c#10", Less);
+    test(
+        "1/3",
+        100,
+        Floor,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Less,
+    );
+    test(
+        "1/3",
+        100,
+        Ceiling,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        Down,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Less,
+    );
+    test(
+        "1/3",
+        100,
+        Up,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        Nearest,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        NearestTiesAway,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Greater,
+    );
+    test(
+        "1/3",
+        100,
+        ToOdd,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Less,
+    );
+    test("9/4", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("9/4", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("9/4", 1, Down, "1.0", "0x1.0#1", Less);
+    test("9/4", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("9/4", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("9/4", 1, NearestTiesAway, "2.0", "0x2.0#1", Greater);
+    test("9/4", 1, ToOdd, "1.0", "0x1.0#1", Less);
+    test("9/4", 10, Floor, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, Ceiling, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, Down, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, Up, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, Nearest, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, NearestTiesAway, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, ToOdd, "1.5", "0x1.800#10", Equal);
+    test("9/4", 10, Exact, "1.5", "0x1.800#10", Equal);
+    test("22/7", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Down, "1.0", "0x1.0#1", Less);
+    test("22/7", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, NearestTiesAway, "2.0", "0x2.0#1", Greater);
+    test("22/7", 1, ToOdd, "1.0", "0x1.0#1", Less);
+    test("22/7", 10, Floor, "1.771", "0x1.c58#10", Less);
+    test("22/7", 10, Ceiling, "1.773", "0x1.c60#10", Greater);
+    test("22/7", 10, Down, "1.771", "0x1.c58#10", Less);
+    test("22/7", 10, Up, "1.773", "0x1.c60#10", Greater);
+    test("22/7", 10, Nearest, "1.773", "0x1.c60#10", Greater);
+    test("22/7", 10, NearestTiesAway, "1.773", "0x1.c60#10", Greater);
+    test("22/7", 10, ToOdd, "1.771", "0x1.c58#10", Less);
+    test("-1", 1, Floor, "NaN", "NaN", Equal);
+    test("-1", 1, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 1, Down, "NaN", "NaN", Equal);
+    test("-1", 1, Up, "NaN", "NaN", Equal);
+    test("-1", 1, Nearest, "NaN", "NaN", Equal);
+    test("-1", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("-1", 1, ToOdd, "NaN", "NaN", Equal);
+    test("-1", 1, Exact, "NaN", "NaN", Equal);
+    test("-1", 10, Floor, "NaN", "NaN", Equal);
+    test("-1", 10, Ceiling, "NaN", "NaN", Equal);
+    test("-1", 10, Down, "NaN", "NaN", Equal);
+    test("-1", 10, Up, "NaN", "NaN", Equal);
+    test("-1", 10, Nearest, "NaN", "NaN", Equal);
+    test("-1", 10, NearestTiesAway, "NaN", "NaN", Equal);
+    test("-1", 10, ToOdd, "NaN", "NaN", Equal);
+    test("-1", 10, Exact, "NaN", "NaN", Equal);
+    test("1/1000000", 1, Floor, "0.001", "0x0.004#1", Less);
+    test("1/1000000", 1, Ceiling, "0.002", "0x0.008#1", Greater);
+    test("1/1000000", 1, Down, "0.001", "0x0.004#1", Less);
+    test("1/1000000", 1, Up, "0.002", "0x0.008#1", Greater);
+    test("1/1000000", 1, Nearest, "0.001", "0x0.004#1", Less);
+    test("1/1000000", 1, NearestTiesAway, "0.001", "0x0.004#1", Less);
+    test("1/1000000", 1, ToOdd, "0.001", "0x0.004#1", Less);
+    test("1/1000000", 10, Floor, "0.000999", "0x0.00418#10", Less);
+    test(
+        "1/1000000",
+        10,
+        Ceiling,
+        "0.001001",
+        "0x0.0041a#10",
+        Greater,
+    );
+    test("1/1000000", 10, Down, "0.000999", "0x0.00418#10", Less);
+    test("1/1000000", 10, Up, "0.001001", "0x0.0041a#10", Greater);
+    test("1/1000000", 10, Nearest, "0.000999", "0x0.00418#10", Less);
+    test(
+        "1/1000000",
+        10,
+        NearestTiesAway,
+        "0.000999",
+        "0x0.00418#10",
+        Less,
+    );
+    test("1/1000000", 10, ToOdd, "0.001001", "0x0.0041a#10", Greater);
+    test("1000000000000", 1, Floor, "5.0e5", "0x8.0E+4#1", Less);
+    test("1000000000000", 1, Ceiling, "1.0e6", "0x1.0E+5#1", Greater);
+    test("1000000000000", 1, Down, "5.0e5", "0x8.0E+4#1", Less);
+    test("1000000000000", 1, Up, "1.0e6", "0x1.0E+5#1", Greater);
+    test("1000000000000", 1, Nearest, "1.0e6", "0x1.0E+5#1", Greater);
+    test(
+        "1000000000000",
+        1,
+        NearestTiesAway,
+        "1.0e6",
+        "0x1.0E+5#1",
+        Greater,
+    );
+    test("1000000000000", 1, ToOdd, "5.0e5", "0x8.0E+4#1", Less);
+    test("1000000000000", 10, Floor, "9.99e5", "0xf.40E+4#10", Less);
+    test(
+        "1000000000000",
+        10,
+        Ceiling,
+        "1.0e6",
+        "0xf.44E+4#10",
+        Greater,
+    );
+    test("1000000000000", 10, Down, "9.99e5", "0xf.40E+4#10", Less);
+    test("1000000000000", 10, Up, "1.0e6", "0xf.44E+4#10", Greater);
+    test(
+        "1000000000000",
+        10,
+        Nearest,
+        "1.0e6",
+        "0xf.44E+4#10",
+        Greater,
+    );
+}
+
+#[test]
+fn sqrt_rational_prec_round_fail() {
+    assert_panic!(Float::sqrt_rational_prec_round(Rational::ONE, 0, Floor));
+    assert_panic!(Float::sqrt_rational_prec_round(
+        Rational::from(2u32),
+        1,
+        Exact
+    ));
+    assert_panic!(Float::sqrt_rational_prec_round_ref(
+        &Rational::ONE,
+        0,
+        Floor
+    ));
+    assert_panic!(Float::sqrt_rational_prec_round_ref(
+        &Rational::from(2u32),
+        1,
+        Exact
+    ));
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_4().test_properties(|(x, prec, rm)| {
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_valid());
+
+        let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sqrt_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sqrt, rug_o) = rug_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sqrt)),
+                ComparableFloatRef(&sqrt),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if sqrt.is_finite() && x.is_finite() {
+            if sqrt.is_normal() {
+                assert_eq!(sqrt.get_prec(), Some(prec));
+            }
+            let r_x = Rational::exact_from(&x);
+            let r_sqrt = Rational::exact_from(&sqrt);
+            assert_eq!(r_sqrt.square().partial_cmp(&r_x), Some(o));
+            if o == Less {
+                let mut next = sqrt.clone();
+                next.increment();
+                assert!(Rational::exact_from(&next).square() > r_x);
+            } else if o == Greater {
+                let mut next = sqrt.clone();
+                next.decrement();
+                assert!(Rational::exact_from(&next).square() < r_x);
+            }
+            match rm {
+                Floor | Down => assert_ne!(o, Greater),
+                Ceiling | Up => assert_ne!(o, Less),
+                Exact => assert_eq!(o, Equal),
+                _ => {}
+            }
+        } else {
+            assert_eq!(o, Equal);
+        }
+
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.sqrt_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(sqrt.clone()));
+                assert_eq!(oo, Equal);
+            }
+        } else {
+            assert_panic!(x.sqrt_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (sqrt, o) = Float::NAN.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::INFINITY.sqrt_prec_round(prec, rm),
+            (Float::INFINITY, Equal)
+        );
+        let (sqrt, o) = Float::NEGATIVE_INFINITY.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+
+        let (s, o) = Float::ZERO.sqrt_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(s), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (s, o) = Float::NEGATIVE_ZERO.sqrt_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(s), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        assert_eq!(
+            Float::ONE.sqrt_prec_round(prec, rm),
+            (Float::one_prec(prec), Equal)
+        );
+        let (sqrt, o) = Float::NEGATIVE_ONE.sqrt_prec_round(prec, rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_prec_properties_helper(x: Float, prec: u64) {
+    let (sqrt, o) = x.clone().sqrt_prec(prec);
+    assert!(sqrt.is_valid());
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_ref(prec);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sqrt_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let (rug_sqrt, rug_o) = rug_sqrt_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sqrt)),
+        ComparableFloatRef(&sqrt),
+    );
+    assert_eq!(rug_o, o);
+
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if sqrt.is_finite() && x.is_finite() {
+        if sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(prec));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            Rational::exact_from(&sqrt).square().partial_cmp(&r_x),
+            Some(o)
+        );
+    } else {
+        assert_eq!(o, Equal);
+    }
+}
+
+#[test]
+fn sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        sqrt_prec_properties_helper(x, prec);
+    });
+
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 2048);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    float_unsigned_pair_gen_var_1().test_properties_with_config(&config, |(x, prec)| {
+        sqrt_prec_properties_helper(x, prec);
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (sqrt, o) = Float::NAN.sqrt_prec(prec);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sqrt, o) = Float::ZERO.sqrt_prec(prec);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sqrt, o) = Float::NEGATIVE_ZERO.sqrt_prec(prec);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        assert_eq!(Float::INFINITY.sqrt_prec(prec), (Float::INFINITY, Equal));
+        assert!(Float::NEGATIVE_INFINITY.sqrt_prec(prec).0.is_nan());
+        assert_eq!(Float::ONE.sqrt_prec(prec), (Float::one_prec(prec), Equal));
+        assert!(Float::NEGATIVE_ONE.sqrt_prec(prec).0.is_nan());
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (sqrt, o) = x.clone().sqrt_round(rm);
+    assert!(sqrt.is_valid());
+
+    let (sqrt_alt, o_alt) = x.sqrt_round_ref(rm);
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(o_alt, o);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sqrt_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    let (sqrt_alt, o_alt) = sqrt_prec_round_naive(x.clone(), x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+    let (sqrt_alt, o_alt) = x.sqrt_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    assert_eq!(o_alt, o);
+
+    if sqrt.is_finite() && x.is_finite() {
+        if x.is_normal() && sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(x.get_prec().unwrap()));
+        }
+        let r_x = Rational::exact_from(&x);
+        assert_eq!(
+            Rational::exact_from(&sqrt).square().partial_cmp(&r_x),
+            Some(o)
+        );
+        match rm {
+            Floor | Down => assert_ne!(o, Greater),
+            Ceiling | Up => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+    } else {
+        assert_eq!(o, Equal);
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sqrt, rug_o) = rug_sqrt_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sqrt)),
+            ComparableFloatRef(&sqrt),
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.sqrt_round_ref(rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(sqrt.clone()));
+            assert_eq!(oo, Equal);
+        }
+    } else {
+        assert_panic!(x.sqrt_round_ref(Exact));
+    }
+}
+
+#[test]
+fn sqrt_round_properties() {
+    float_rounding_mode_pair_gen_var_18().test_properties(|(x, rm)| {
+        sqrt_round_properties_helper(x, rm);
+    });
+
+    rounding_mode_gen().test_properties(|rm| {
+        let (sqrt, o) = Float::NAN.sqrt_round(rm);
+        assert!(sqrt.is_nan());
+        assert_eq!(o, Equal);
+
+        let (sqrt, o) = Float::ZERO.sqrt_round(rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+
+        let (sqrt, o) = Float::NEGATIVE_ZERO.sqrt_round(rm);
+        assert_eq!(ComparableFloat(sqrt), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+
+        assert_eq!(Float::INFINITY.sqrt_round(rm), (Float::INFINITY, Equal));
+        assert!(Float::NEGATIVE_INFINITY.sqrt_round(rm).0.is_nan());
+        assert_eq!(Float::ONE.sqrt_round(rm), (Float::ONE, Equal));
+        assert!(Float::NEGATIVE_ONE.sqrt_round(rm).0.is_nan());
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sqrt_properties_helper_1(x: Float) {
+    let sqrt = x.clone().sqrt();
+    assert!(sqrt.is_valid());
+
+    let sqrt_alt = (&x).sqrt();
+    assert!(sqrt_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+    let mut x_alt = x.clone();
+    x_alt.sqrt_assign();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sqrt));
+
+    let sqrt_alt = sqrt_prec_round_naive(x.clone(), x.significant_bits(), Nearest).0;
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    let sqrt_alt = x.sqrt_prec_round_ref(x.significant_bits(), Nearest).0;
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    let sqrt_alt = x.sqrt_prec_ref(x.significant_bits()).0;
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+    let sqrt_alt = x.sqrt_round_ref(Nearest).0;
+    assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+
+    if x.is_sign_negative() && x != 0u32 {
+        assert!(sqrt.is_nan());
+    } else if !x.is_nan() {
+        assert_eq!(sqrt.is_sign_positive(), x.is_sign_positive());
+    }
+
+    let rug_sqrt = rug_sqrt(&rug::Float::exact_from(&x));
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sqrt)),
+        ComparableFloatRef(&sqrt),
+    );
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+fn sqrt_properties_helper_2<T: PrimitiveFloat>()
+where
+    Float: From<T> + PartialOrd<T>,
+    for<'a> T: ExactFrom<&'a Float> + RoundingFrom<&'a Float>,
+{
+    primitive_float_gen::<T>().test_properties(|x| {
+        let sqrt_1 = x.sqrt();
+        let sqrt_2 = emulate_primitive_float_fn(|x, prec| x.sqrt_prec(prec).0, x);
+        assert_eq!(NiceFloat(sqrt_1), NiceFloat(sqrt_2));
+    });
+}
+
+#[test]
+fn sqrt_properties() {
+    float_gen().test_properties(|x| {
+        sqrt_properties_helper_1(x);
+    });
+
+    float_gen_var_6().test_properties(|x| {
+        sqrt_properties_helper_1(x);
+    });
+
+    float_gen_var_7().test_properties(|x| {
+        sqrt_properties_helper_1(x);
+    });
+
+    apply_fn_to_primitive_floats!(sqrt_properties_helper_2);
+}
+
+#[test]
+fn sqrt_rational_prec_round_properties() {
+    rational_unsigned_rounding_mode_triple_gen_var_2().test_properties(|(x, prec, rm)| {
+        let (sqrt, o) = Float::sqrt_rational_prec_round_ref(&x, prec, rm);
+        assert!(sqrt.is_valid());
+
+        let (sqrt_alt, o_alt) = Float::sqrt_rational_prec_round(x.clone(), prec, rm);
+        assert!(sqrt_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+        assert_eq!(o_alt, o);
+
+        if x < 0u32 {
+            assert!(sqrt.is_nan());
+            assert_eq!(o, Equal);
+            return;
+        }
+        if let Ok(x) = Float::try_from(&x) {
+            let (sqrt_alt, o_alt) = x.sqrt_prec_round(prec, rm);
+            assert_eq!(ComparableFloatRef(&sqrt_alt), ComparableFloatRef(&sqrt));
+            assert_eq!(o_alt, o);
+        }
+        if sqrt.is_normal() {
+            assert_eq!(sqrt.get_prec(), Some(prec));
+            assert_eq!(
+                Rational::exact_from(&sqrt).square().partial_cmp(&x),
+                Some(o)
+            );
+            if o == Less {
+                let mut next = sqrt.clone();
+                next.increment();
+                assert!(Rational::exact_from(&next).square() > x);
+            } else if o == Greater {
+                let mut next = sqrt.clone();
+                next.decrement();
+                assert!(Rational::exact_from(&next).square() < x);
+            }
+        }
+        match rm {
+            Floor | Down => assert_ne!(o, Greater),
+            Ceiling | Up => assert_ne!(o, Less),
+            _ => {}
+        }
+    });
+}
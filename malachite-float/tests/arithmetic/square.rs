@@ -783,6 +783,8 @@ fn test_square_round() {
     test("NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test("Infinity", "Infinity", Floor, "Infinity", "Infinity", Equal);
@@ -794,6 +796,15 @@ fn test_square_round() {
     test(
         "Infinity", "Infinity", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test("Infinity", "Infinity", ToOdd, "Infinity", "Infinity", Equal);
     test("Infinity", "Infinity", Exact, "Infinity", "Infinity", Equal);
 
     test(
@@ -829,6 +840,22 @@ fn test_square_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -843,6 +870,8 @@ fn test_square_round() {
     test("0.0", "0x0.0", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", Floor, "0.0", "0x0.0", Equal);
@@ -850,6 +879,8 @@ fn test_square_round() {
     test("-0.0", "-0x0.0", Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Equal);
@@ -864,6 +895,8 @@ fn test_square_round() {
     test("-1.0", "-0x1.0#1", Down, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Up, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "1.0", "0x1.0#1", Equal);
+    test("-1.0", "-0x1.0#1", ToOdd, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", Exact, "1.0", "0x1.0#1", Equal);
 
     test(
@@ -906,6 +939,22 @@ fn test_square_round() {
         "0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0000000000000000000000000#100",
@@ -955,6 +1004,22 @@ fn test_square_round() {
         "0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0000000000000000000000000#100",
@@ -976,6 +1041,15 @@ fn test_square_round() {
     test("123.0", "0x7b.0#7", Down, "1.51e4", "0x3.b0E+3#7", Less);
     test("123.0", "0x7b.0#7", Up, "1.52e4", "0x3.b8E+3#7", Greater);
     test("123.0", "0x7b.0#7", Nearest, "1.51e4", "0x3.b0E+3#7", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        NearestTiesAway,
+        "1.51e4",
+        "0x3.b0E+3#7",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", ToOdd, "1.52e4", "0x3.b8E+3#7", Greater);
 
     test("-123.0", "-0x7b.0#7", Floor, "1.51e4", "0x3.b0E+3#7", Less);
     test(
@@ -996,6 +1070,22 @@ fn test_square_round() {
         "0x3.b0E+3#7",
         Less,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "1.51e4",
+        "0x3.b0E+3#7",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "1.52e4",
+        "0x3.b8E+3#7",
+        Greater,
+    );
 
     test(
         "1.4142135623730951",
@@ -1037,6 +1127,22 @@ fn test_square_round() {
         "0x2.0000000000002#53",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        NearestTiesAway,
+        "2.0000000000000004",
+        "0x2.0000000000002#53",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        ToOdd,
+        "2.0000000000000004",
+        "0x2.0000000000002#53",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -1078,6 +1184,22 @@ fn test_square_round() {
         "0x2.0000000000002#53",
         Greater,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        NearestTiesAway,
+        "2.0000000000000004",
+        "0x2.0000000000002#53",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        ToOdd,
+        "2.0000000000000004",
+        "0x2.0000000000002#53",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -1119,6 +1241,22 @@ fn test_square_round() {
         "0x9.de9e64df22ef0#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "9.869604401089356",
+        "0x9.de9e64df22ee8#53",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -1160,6 +1298,22 @@ fn test_square_round() {
         "0x9.de9e64df22ef0#53",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "9.869604401089356",
+        "0x9.de9e64df22ee8#53",
+        Less,
+    );
 
     // - rm == Floor || rm == Down in square_float_significand_same_prec_lt_w
     test("1.5", "0x1.8#2", Down, "2.0", "0x2.0#2", Less);
@@ -1230,12 +1384,16 @@ fn test_square_round() {
     // - decrement_exp in square_float_significand_same_prec_lt_w
     // - round_bit == 0 && sticky_bit == 0 in square_float_significand_same_prec_lt_w
     test("1.0", "0x1.0#1", Nearest, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1.0", "0x1.0#1", Equal);
+    test("1.0", "0x1.0#1", ToOdd, "1.0", "0x1.0#1", Equal);
     // - !decrement_exp in square_float_significand_same_prec_lt_w
     // - round_bit != 0 || sticky_bit != 0 in square_float_significand_same_prec_lt_w
     // - rm == Nearest in square_float_significand_same_prec_lt_w
     // - rm == Nearest && (round_bit == 0 || (sticky_bit == 0 && square & shift_bit == 0)) in
     //   square_float_significand_same_prec_lt_w
     test("1.5", "0x1.8#2", Nearest, "2.0", "0x2.0#2", Less);
+    test("1.5", "0x1.8#2", NearestTiesAway, "2.0", "0x2.0#2", Less);
+    test("1.5", "0x1.8#2", ToOdd, "3.0", "0x3.0#2", Greater);
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 || square & shift_bit != 0) &&
     //   !overflow in square_float_significand_same_prec_lt_w
     test("1.6", "0x1.a#4", Nearest, "2.8", "0x2.c#4", Greater);
@@ -1265,6 +1423,22 @@ fn test_square_round() {
         "0x1.0000000000000004#64",
         Less,
     );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        NearestTiesAway,
+        "1.0000000000000000002",
+        "0x1.0000000000000004#64",
+        Less,
+    );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        ToOdd,
+        "1.0000000000000000003",
+        "0x1.0000000000000006#64",
+        Greater,
+    );
     // - !decrement_exp in square_float_significand_same_prec_w
     // - rm == Nearest && round_bit != 0 && (sticky_bit != 0 || product.odd()) && !overflow in
     //   square_float_significand_same_prec_w
@@ -1333,6 +1507,22 @@ fn test_square_round() {
         "0x1.0000000000000002#65",
         Less,
     );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        NearestTiesAway,
+        "1.00000000000000000011",
+        "0x1.0000000000000002#65",
+        Less,
+    );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        ToOdd,
+        "1.00000000000000000016",
+        "0x1.0000000000000003#65",
+        Greater,
+    );
     // - lo.wrapping_add(2) & (mask >> 2) > 2 in square_float_significand_same_prec_gt_w_lt_2w
     test(
         "1.00000000000000000011",
@@ -1385,6 +1575,22 @@ fn test_square_round() {
         "0x1.00000000000000000000000000000002#129",
         Less,
     );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        NearestTiesAway,
+        "1.000000000000000000000000000000000000006",
+        "0x1.00000000000000000000000000000002#129",
+        Less,
+    );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        ToOdd,
+        "1.000000000000000000000000000000000000009",
+        "0x1.00000000000000000000000000000003#129",
+        Greater,
+    );
     // - a0.wrapping_add(4) & (mask >> 2) > 4 in square_float_significand_same_prec_gt_2w_lt_3w
     // - !decrement_exp in square_float_significand_same_prec_gt_2w_lt_3w
     test(
@@ -1462,6 +1668,8 @@ fn test_square_prec_round() {
     test("NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -1477,6 +1685,18 @@ fn test_square_prec_round() {
     test(
         "Infinity", "Infinity", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -1526,6 +1746,24 @@ fn test_square_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -1541,6 +1779,8 @@ fn test_square_prec_round() {
     test("0.0", "0x0.0", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", 1, Floor, "0.0", "0x0.0", Equal);
@@ -1548,6 +1788,8 @@ fn test_square_prec_round() {
     test("-0.0", "-0x0.0", 1, Down, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", 1, Up, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("-0.0", "-0x0.0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
@@ -1555,6 +1797,16 @@ fn test_square_prec_round() {
     test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Up, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 1, ToOdd, "1.0", "0x1.0#1", Equal);
     test("1.0", "0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
 
     test("1.0", "0x1.0#1", 10, Floor, "1.0", "0x1.000#10", Equal);
@@ -1562,6 +1814,16 @@ fn test_square_prec_round() {
     test("1.0", "0x1.0#1", 10, Down, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Up, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "1.0", "0x1.000#10", Equal);
     test("1.0", "0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
 
     test("-1.0", "-0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Equal);
@@ -1569,6 +1831,16 @@ fn test_square_prec_round() {
     test("-1.0", "-0x1.0#1", 1, Down, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Up, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Nearest, "1.0", "0x1.0#1", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, ToOdd, "1.0", "0x1.0#1", Equal);
     test("-1.0", "-0x1.0#1", 1, Exact, "1.0", "0x1.0#1", Equal);
 
     test("-1.0", "-0x1.0#1", 10, Floor, "1.0", "0x1.000#10", Equal);
@@ -1576,6 +1848,16 @@ fn test_square_prec_round() {
     test("-1.0", "-0x1.0#1", 10, Down, "1.0", "0x1.000#10", Equal);
     test("-1.0", "-0x1.0#1", 10, Up, "1.0", "0x1.000#10", Equal);
     test("-1.0", "-0x1.0#1", 10, Nearest, "1.0", "0x1.000#10", Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test("-1.0", "-0x1.0#1", 10, ToOdd, "1.0", "0x1.000#10", Equal);
     test("-1.0", "-0x1.0#1", 10, Exact, "1.0", "0x1.000#10", Equal);
 
     test(
@@ -1623,6 +1905,24 @@ fn test_square_prec_round() {
         "0x1.0#1",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0000000000000000000000000#100",
@@ -1678,6 +1978,24 @@ fn test_square_prec_round() {
         "0x1.000#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        ToOdd,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0000000000000000000000000#100",
@@ -1733,6 +2051,24 @@ fn test_square_prec_round() {
         "0x1.0#1",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0000000000000000000000000#100",
@@ -1788,6 +2124,24 @@ fn test_square_prec_round() {
         "0x1.000#10",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        10,
+        ToOdd,
+        "1.0",
+        "0x1.000#10",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0000000000000000000000000#100",
@@ -1819,6 +2173,16 @@ fn test_square_prec_round() {
         "0x4.0E+3#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "2.0e4",
+        "0x4.0E+3#1",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 1, ToOdd, "8.0e3", "0x2.0E+3#1", Less);
 
     test(
         "123.0",
@@ -1865,6 +2229,24 @@ fn test_square_prec_round() {
         "0x3.b2E+3#10",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "1.514e4",
+        "0x3.b2E+3#10",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        ToOdd,
+        "1.512e4",
+        "0x3.b1E+3#10",
+        Less,
+    );
 
     test("-123.0", "-0x7b.0#7", 1, Floor, "8.0e3", "0x2.0E+3#1", Less);
     test(
@@ -1887,6 +2269,16 @@ fn test_square_prec_round() {
         "0x4.0E+3#1",
         Greater,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "2.0e4",
+        "0x4.0E+3#1",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 1, ToOdd, "8.0e3", "0x2.0E+3#1", Less);
 
     test(
         "-123.0",
@@ -1933,6 +2325,24 @@ fn test_square_prec_round() {
         "0x3.b2E+3#10",
         Greater,
     );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        NearestTiesAway,
+        "1.514e4",
+        "0x3.b2E+3#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        ToOdd,
+        "1.512e4",
+        "0x3.b1E+3#10",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -1979,6 +2389,24 @@ fn test_square_prec_round() {
         "0x2.0#1",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -2025,6 +2453,24 @@ fn test_square_prec_round() {
         "0x2.00#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        10,
+        ToOdd,
+        "2.004",
+        "0x2.01#10",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -2071,6 +2517,24 @@ fn test_square_prec_round() {
         "0x2.0#1",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        NearestTiesAway,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -2117,6 +2581,24 @@ fn test_square_prec_round() {
         "0x2.00#10",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        NearestTiesAway,
+        "2.0",
+        "0x2.00#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        10,
+        ToOdd,
+        "2.004",
+        "0x2.01#10",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -2163,6 +2645,24 @@ fn test_square_prec_round() {
         "0x8.0#1",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -2209,6 +2709,24 @@ fn test_square_prec_round() {
         "0x9.e0#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "9.88",
+        "0x9.e0#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "9.86",
+        "0x9.dc#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -2255,6 +2773,24 @@ fn test_square_prec_round() {
         "0x8.0#1",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        NearestTiesAway,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        ToOdd,
+        "8.0",
+        "0x8.0#1",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -2605,6 +2605,17 @@ fn test_sub_round() {
     test("NaN", "NaN", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2657,6 +2668,26 @@ fn test_sub_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -2683,6 +2714,19 @@ fn test_sub_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2692,6 +2736,17 @@ fn test_sub_round() {
     test("NaN", "NaN", "-0.0", "-0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-0.0", "-0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-0.0", "-0x0.0", Exact, "NaN", "NaN", Equal);
 
     test("NaN", "NaN", "0.0", "0x0.0", Floor, "NaN", "NaN", Equal);
@@ -2699,6 +2754,17 @@ fn test_sub_round() {
     test("NaN", "NaN", "0.0", "0x0.0", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -2716,6 +2782,19 @@ fn test_sub_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -2770,6 +2849,26 @@ fn test_sub_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -2796,6 +2895,19 @@ fn test_sub_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", Exact, "NaN", "NaN", Equal,
     );
@@ -2815,6 +2927,19 @@ fn test_sub_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2834,6 +2959,19 @@ fn test_sub_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", Exact, "Infinity", "Infinity", Equal,
     );
@@ -2888,6 +3026,26 @@ fn test_sub_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -2949,6 +3107,26 @@ fn test_sub_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3010,6 +3188,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3071,6 +3269,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3132,6 +3350,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3148,6 +3386,17 @@ fn test_sub_round() {
     test("0.0", "0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("0.0", "0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3200,6 +3449,26 @@ fn test_sub_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -3261,6 +3530,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -3286,9 +3575,22 @@ fn test_sub_round() {
         "0.0", "0x0.0", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
     test(
-        "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
-    );
-
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
+    );
+
     // Note different behavior for Floor
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", Floor, "-0.0", "-0x0.0", Equal,
@@ -3301,6 +3603,17 @@ fn test_sub_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "NaN", "NaN", Floor, "NaN", "NaN", Equal);
@@ -3308,6 +3621,17 @@ fn test_sub_round() {
     test("-0.0", "-0x0.0", "NaN", "NaN", Down, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Up, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Nearest, "NaN", "NaN", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal);
     test("-0.0", "-0x0.0", "NaN", "NaN", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -3360,6 +3684,26 @@ fn test_sub_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -3421,6 +3765,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -3448,6 +3812,19 @@ fn test_sub_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", Exact, "0.0", "0x0.0", Equal,
     );
@@ -3467,6 +3844,19 @@ fn test_sub_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -3482,6 +3872,19 @@ fn test_sub_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", Exact, "NaN", "NaN", Equal,
     );
@@ -3536,6 +3939,26 @@ fn test_sub_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -3597,6 +4020,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -3623,6 +4066,19 @@ fn test_sub_round() {
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "-0.0", "-0x0.0", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "-0.0", "-0x0.0", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -3642,6 +4098,19 @@ fn test_sub_round() {
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "0.0", "0x0.0", ToOdd, "123.0", "0x7b.0#7", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "0.0", "0x0.0", Exact, "123.0", "0x7b.0#7", Equal,
     );
@@ -3687,6 +4156,26 @@ fn test_sub_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -3748,6 +4237,26 @@ fn test_sub_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -3809,6 +4318,26 @@ fn test_sub_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -3870,6 +4399,26 @@ fn test_sub_round() {
         "0x7b.0#7",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -3931,6 +4480,26 @@ fn test_sub_round() {
         "0x7b.0#7",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        ToOdd,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -3957,13 +4526,26 @@ fn test_sub_round() {
     test(
         "1.0", "0x1.0#1", "-2.0", "-0x2.0#1", Nearest, "4.0", "0x4.0#1", Greater,
     );
-
-    test(
-        "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
-    );
-    test(
-        "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
-    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-2.0", "-0x2.0#1", ToOdd, "2.0", "0x2.0#1", Less,
+    );
+
+    test(
+        "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", Floor, "3.0", "0x3.0#2", Equal,
     );
@@ -4065,6 +4647,26 @@ fn test_sub_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -4126,6 +4728,26 @@ fn test_sub_round() {
         "0x4.8e4950f0795fc#53",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "4.555806215962888",
+        "0x4.8e4950f0795fc#53",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -4177,6 +4799,26 @@ fn test_sub_round() {
         "-0x1.ba35842091e63#53",
         Equal,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "-1.727379091216698",
+        "-0x1.ba35842091e63#53",
+        Equal,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        ToOdd,
+        "-1.727379091216698",
+        "-0x1.ba35842091e63#53",
+        Equal,
+    );
     test(
         "1.4142135623730951",
         "0x1.6a09e667f3bcd#53",
@@ -4238,6 +4880,26 @@ fn test_sub_round() {
         "0x1.ba35842091e63#53",
         Equal,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        NearestTiesAway,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Equal,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        ToOdd,
+        "1.727379091216698",
+        "0x1.ba35842091e63#53",
+        Equal,
+    );
     test(
         "-1.4142135623730951",
         "-0x1.6a09e667f3bcd#53",
@@ -4383,12 +5045,45 @@ fn test_sub_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
     // - in sub_float_significands_same_prec_lt_w
     // - x_exp == y_exp in sub_float_significands_same_prec_lt_w
     // - x == y in sub_float_significands_same_prec_lt_w
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#1", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", ToOdd, "0.0", "0x0.0", Equal,
+    );
     // - x_exp < y_exp in sub_float_significands_same_prec_lt_w
     // - exp_diff < Limb::WIDTH in sub_float_significands_same_prec_lt_w
     // - leading_zeros != 0 in sub_float_significands_same_prec_lt_w
@@ -4410,6 +5105,19 @@ fn test_sub_round() {
     test(
         "1.0", "0x1.0#1", "0.2", "0x0.4#1", Nearest, "1.0", "0x1.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.2",
+        "0x0.4#1",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "0.2", "0x0.4#1", ToOdd, "0.5", "0x0.8#1", Less,
+    );
     // - x < y in sub_float_significands_same_prec_lt_w
     test(
         "1.0", "0x1.0#2", "1.5", "0x1.8#2", Nearest, "-0.5", "-0x0.8#2", Equal,
@@ -4538,6 +5246,26 @@ fn test_sub_round() {
         "-0x3.0000000000000000#64",
         Less,
     );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        "4.0",
+        "0x4.0000000000000000#64",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0000000000000000#64",
+        Less,
+    );
+    test(
+        "1.0000000000000000001",
+        "0x1.0000000000000002#64",
+        "4.0",
+        "0x4.0000000000000000#64",
+        ToOdd,
+        "-2.9999999999999999998",
+        "-0x2.fffffffffffffffc#64",
+        Greater,
+    );
     // - !neg in sub_float_significands_same_prec_w
     test(
         "4.0",
@@ -4748,6 +5476,26 @@ fn test_sub_round() {
         "-0x3.0000000000000000#65",
         Less,
     );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        "4.0",
+        "0x4.0000000000000000#65",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0000000000000000#65",
+        Less,
+    );
+    test(
+        "1.00000000000000000005",
+        "0x1.0000000000000001#65",
+        "4.0",
+        "0x4.0000000000000000#65",
+        ToOdd,
+        "-2.9999999999999999999",
+        "-0x2.fffffffffffffffe#65",
+        Greater,
+    );
     // - !neg in sub_float_significands_same_prec_gt_w_lt_2w
     test(
         "4.0",
@@ -4961,6 +5709,26 @@ fn test_sub_round() {
         "-0x3.00000000000000000000000000000000#128",
         Less,
     );
+    test(
+        "1.000000000000000000000000000000000000006",
+        "0x1.00000000000000000000000000000002#128",
+        "4.0",
+        "0x4.00000000000000000000000000000000#128",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.00000000000000000000000000000000#128",
+        Less,
+    );
+    test(
+        "1.000000000000000000000000000000000000006",
+        "0x1.00000000000000000000000000000002#128",
+        "4.0",
+        "0x4.00000000000000000000000000000000#128",
+        ToOdd,
+        "-2.99999999999999999999999999999999999999",
+        "-0x2.fffffffffffffffffffffffffffffffc#128",
+        Greater,
+    );
     // - !neg in sub_float_significands_same_prec_2w
     test(
         "4.0",
@@ -5308,6 +6076,26 @@ fn test_sub_round() {
         "-0x3.00000000000000000000000000000000#129",
         Less,
     );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        "4.0",
+        "0x4.00000000000000000000000000000000#129",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.00000000000000000000000000000000#129",
+        Less,
+    );
+    test(
+        "1.000000000000000000000000000000000000003",
+        "0x1.00000000000000000000000000000001#129",
+        "4.0",
+        "0x4.00000000000000000000000000000000#129",
+        ToOdd,
+        "-2.999999999999999999999999999999999999994",
+        "-0x2.fffffffffffffffffffffffffffffffe#129",
+        Greater,
+    );
     // - !neg in sub_float_significands_same_prec_gt_2w_lt_3w
     test(
         "4.0",
@@ -5650,6 +6438,26 @@ fn test_sub_round() {
         "-0x3.000000000000000000000000000000000000000000000000#192",
         Less,
     );
+    test(
+        "1.0000000000000000000000000000000000000000000000000000000003",
+        "0x1.000000000000000000000000000000000000000000000002#192",
+        "4.0",
+        "0x4.000000000000000000000000000000000000000000000000#192",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.000000000000000000000000000000000000000000000000#192",
+        Less,
+    );
+    test(
+        "1.0000000000000000000000000000000000000000000000000000000003",
+        "0x1.000000000000000000000000000000000000000000000002#192",
+        "4.0",
+        "0x4.000000000000000000000000000000000000000000000000#192",
+        ToOdd,
+        "-2.9999999999999999999999999999999999999999999999999999999994",
+        "-0x2.fffffffffffffffffffffffffffffffffffffffffffffffc#192",
+        Greater,
+    );
     // - out[usize::exact_from(k)] == 0 in sub_float_significands_same_prec_ge_3w
     test(
         "1.0000000000000000000000000000000000000000000000000000000002",
@@ -6075,6 +6883,19 @@ fn test_sub_round() {
     test(
         "0.5", "0x0.8#1", "3.0", "0x3.0#2", Nearest, "-2.0", "-0x2.0#2", Greater,
     );
+    test(
+        "0.5",
+        "0x0.8#1",
+        "3.0",
+        "0x3.0#2",
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0#2",
+        Less,
+    );
+    test(
+        "0.5", "0x0.8#1", "3.0", "0x3.0#2", ToOdd, "-3.0", "-0x3.0#2", Less,
+    );
     // - (out[0] >> sh) & 1 != 0 in sub_float_significands_general
     // - cmp_low >= 0 in sub_float_significands_general
     // - cmp_low >= 0 && !carry in sub_float_significands_general
@@ -6415,6 +7236,19 @@ fn test_sub_round() {
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#2", Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#2",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#2", ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#2", Exact, "0.0", "0x0.0", Equal,
     );
@@ -6469,6 +7303,26 @@ fn test_sub_round() {
         "0x0.0",
         Equal,
     );
+    test(
+        "7.0e-9",
+        "0x2.0E-7#2",
+        "7.450580596923828125e-9",
+        "0x2.00000000000000000E-7#69",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.0e-9",
+        "0x2.0E-7#2",
+        "7.450580596923828125e-9",
+        "0x2.00000000000000000E-7#69",
+        ToOdd,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
     test(
         "7.0e-9",
         "0x2.0E-7#2",
@@ -6530,6 +7384,26 @@ fn test_sub_round() {
         "0x0.0",
         Equal,
     );
+    test(
+        "7.0e-9",
+        "0x2.0E-7#2",
+        "7.450580596923828125e-9",
+        "0x2.00000000000000000000000000000000000000000000000000E-7#200",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "7.0e-9",
+        "0x2.0E-7#2",
+        "7.450580596923828125e-9",
+        "0x2.00000000000000000000000000000000000000000000000000E-7#200",
+        ToOdd,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
     test(
         "7.0e-9",
         "0x2.0E-7#2",
@@ -6827,6 +7701,18 @@ fn test_sub_prec_round() {
     test("NaN", "NaN", "NaN", "NaN", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -6884,6 +7770,28 @@ fn test_sub_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "NaN",
         "NaN",
@@ -6911,6 +7819,20 @@ fn test_sub_prec_round() {
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6926,6 +7848,20 @@ fn test_sub_prec_round() {
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "NaN", "NaN", "-0.0", "-0x0.0", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "NaN", "NaN", "-0.0", "-0x0.0", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -6939,6 +7875,18 @@ fn test_sub_prec_round() {
     test(
         "NaN", "NaN", "0.0", "0x0.0", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "0.0", "0x0.0", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -6956,6 +7904,20 @@ fn test_sub_prec_round() {
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7015,6 +7977,28 @@ fn test_sub_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -7042,6 +8026,20 @@ fn test_sub_prec_round() {
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "Infinity", "Infinity", "Infinity", "Infinity", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7061,6 +8059,20 @@ fn test_sub_prec_round() {
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-0.0", "-0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-0.0", "-0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -7080,6 +8092,20 @@ fn test_sub_prec_round() {
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "0.0", "0x0.0", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -7139,6 +8165,28 @@ fn test_sub_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7206,6 +8254,28 @@ fn test_sub_prec_round() {
         "NaN",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "NaN",
+        "NaN",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7273,6 +8343,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7340,6 +8432,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7407,6 +8521,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -7428,13 +8564,25 @@ fn test_sub_prec_round() {
     test(
         "0.0", "0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
-    test("0.0", "0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
-
     test(
         "0.0",
         "0x0.0",
-        "-Infinity",
-        "-Infinity",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("0.0", "0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal);
+
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
         1,
         Floor,
         "Infinity",
@@ -7485,6 +8633,28 @@ fn test_sub_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -7552,6 +8722,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "0.0",
         "0x0.0",
@@ -7579,6 +8771,20 @@ fn test_sub_prec_round() {
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "-0.0", "-0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7597,6 +8803,20 @@ fn test_sub_prec_round() {
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "0.0", "0x0.0", "0.0", "0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7612,6 +8832,20 @@ fn test_sub_prec_round() {
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7671,6 +8905,28 @@ fn test_sub_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -7738,6 +8994,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-0.0",
         "-0x0.0",
@@ -7766,6 +9044,20 @@ fn test_sub_prec_round() {
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "-0.0", "-0x0.0", 1, Exact, "0.0", "0x0.0", Equal,
     );
@@ -7785,6 +9077,20 @@ fn test_sub_prec_round() {
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Nearest, "-0.0", "-0x0.0", Equal,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", 1, ToOdd, "-0.0", "-0x0.0", Equal,
+    );
     test(
         "-0.0", "-0x0.0", "0.0", "0x0.0", 1, Exact, "-0.0", "-0x0.0", Equal,
     );
@@ -7804,6 +9110,20 @@ fn test_sub_prec_round() {
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Nearest, "NaN", "NaN", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "NaN",
+        "NaN",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test(
+        "123.0", "0x7b.0#7", "NaN", "NaN", 1, ToOdd, "NaN", "NaN", Equal,
+    );
     test(
         "123.0", "0x7b.0#7", "NaN", "NaN", 1, Exact, "NaN", "NaN", Equal,
     );
@@ -7863,6 +9183,28 @@ fn test_sub_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-Infinity",
+        "-Infinity",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -7930,6 +9272,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "Infinity",
+        "Infinity",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "123.0",
         "0x7b.0#7",
@@ -7997,6 +9361,28 @@ fn test_sub_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "-0.0",
+        "-0x0.0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "123.0",
@@ -8053,6 +9439,28 @@ fn test_sub_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0.0",
+        "0x0.0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "NaN",
@@ -8115,26 +9523,48 @@ fn test_sub_prec_round() {
         "-123.0",
         "-0x7b.0#7",
         1,
-        Exact,
+        NearestTiesAway,
         "NaN",
         "NaN",
         Equal,
     );
-
     test(
-        "Infinity",
-        "Infinity",
+        "NaN",
+        "NaN",
         "-123.0",
         "-0x7b.0#7",
         1,
-        Floor,
-        "Infinity",
-        "Infinity",
+        ToOdd,
+        "NaN",
+        "NaN",
         Equal,
     );
+    test(
+        "NaN",
+        "NaN",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Exact,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+
     test(
         "Infinity",
-        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        Floor,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
         "-123.0",
         "-0x7b.0#7",
         1,
@@ -8176,6 +9606,28 @@ fn test_sub_prec_round() {
         "Infinity",
         Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
     test(
         "Infinity",
         "Infinity",
@@ -8243,6 +9695,28 @@ fn test_sub_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -8310,6 +9784,28 @@ fn test_sub_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "-0.0",
@@ -8366,6 +9862,28 @@ fn test_sub_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123.0",
+        "-0x7b.0#7",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "1.0", "0x1.0#1", "-2.0", "-0x2.0#1", 1, Floor, "2.0", "0x2.0#1", Less,
@@ -8382,6 +9900,20 @@ fn test_sub_prec_round() {
     test(
         "1.0", "0x1.0#1", "-2.0", "-0x2.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-2.0", "-0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -8438,6 +9970,28 @@ fn test_sub_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -8465,6 +10019,20 @@ fn test_sub_prec_round() {
     test(
         "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#1", "-2.0", "-0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -8521,6 +10089,28 @@ fn test_sub_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#2",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2.0",
+        "-0x2.0#2",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -8548,6 +10138,20 @@ fn test_sub_prec_round() {
     test(
         "1.0", "0x1.0#2", "-2.0", "-0x2.0#1", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#1",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#2", "-2.0", "-0x2.0#1", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -8604,6 +10208,28 @@ fn test_sub_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -8631,6 +10257,20 @@ fn test_sub_prec_round() {
     test(
         "1.0", "0x1.0#2", "-2.0", "-0x2.0#2", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0", "0x1.0#2", "-2.0", "-0x2.0#2", 1, ToOdd, "2.0", "0x2.0#1", Less,
+    );
 
     test(
         "1.0",
@@ -8687,6 +10327,28 @@ fn test_sub_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#2",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2.0",
+        "-0x2.0#2",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#2",
@@ -8754,6 +10416,28 @@ fn test_sub_prec_round() {
         "0x4.0#1",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        1,
+        ToOdd,
+        "2.0",
+        "0x2.0#1",
+        Less,
+    );
 
     test(
         "1.0",
@@ -8810,6 +10494,28 @@ fn test_sub_prec_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        10,
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2.0",
+        "-0x2.00#10",
+        10,
+        ToOdd,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.000#10",
@@ -8877,6 +10583,28 @@ fn test_sub_prec_round() {
         "0x4.8e#10",
         Less,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "4.555",
+        "0x4.8e#10",
+        Less,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "4.555",
+        "0x4.8e#10",
+        Less,
+    );
 
     test(
         "1.4142135623730951",
@@ -8933,6 +10661,28 @@ fn test_sub_prec_round() {
         "-0x1.ba0#10",
         Greater,
     );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "-1.727",
+        "-0x1.ba0#10",
+        Greater,
+    );
+    test(
+        "1.4142135623730951",
+        "0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "-1.729",
+        "-0x1.ba8#10",
+        Less,
+    );
 
     test(
         "-1.4142135623730951",
@@ -8989,6 +10739,28 @@ fn test_sub_prec_round() {
         "0x1.ba0#10",
         Less,
     );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "1.727",
+        "0x1.ba0#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "1.729",
+        "0x1.ba8#10",
+        Greater,
+    );
 
     test(
         "-1.4142135623730951",
@@ -9018,7 +10790,29 @@ fn test_sub_prec_round() {
         "3.1415926535897931",
         "0x3.243f6a8885a30#53",
         10,
-        Down,
+        Down,
+        "-4.555",
+        "-0x4.8e#10",
+        Greater,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-4.56",
+        "-0x4.90#10",
+        Less,
+    );
+    test(
+        "-1.4142135623730951",
+        "-0x1.6a09e667f3bcd#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
         "-4.555",
         "-0x4.8e#10",
         Greater,
@@ -9029,10 +10823,10 @@ fn test_sub_prec_round() {
         "3.1415926535897931",
         "0x3.243f6a8885a30#53",
         10,
-        Up,
-        "-4.56",
-        "-0x4.90#10",
-        Less,
+        NearestTiesAway,
+        "-4.555",
+        "-0x4.8e#10",
+        Greater,
     );
     test(
         "-1.4142135623730951",
@@ -9040,7 +10834,7 @@ fn test_sub_prec_round() {
         "3.1415926535897931",
         "0x3.243f6a8885a30#53",
         10,
-        Nearest,
+        ToOdd,
         "-4.555",
         "-0x4.8e#10",
         Greater,
@@ -9101,6 +10895,28 @@ fn test_sub_prec_round() {
         "0x1.0#1",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        1,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        1,
+        ToOdd,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
 
     test(
         "1.0",
@@ -9157,6 +10973,28 @@ fn test_sub_prec_round() {
         "0x1.00100#20",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        20,
+        NearestTiesAway,
+        "1.000244",
+        "0x1.00100#20",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0002",
+        "-0x0.001#1",
+        20,
+        ToOdd,
+        "1.000244",
+        "0x1.00100#20",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -9184,6 +11022,20 @@ fn test_sub_prec_round() {
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#1", 10, Nearest, "0.0", "0x0.0", Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", 10, ToOdd, "0.0", "0x0.0", Equal,
+    );
     test(
         "1.0", "0x1.0#1", "1.0", "0x1.0#1", 10, Exact, "0.0", "0x0.0", Equal,
     );
@@ -9633,6 +11485,8 @@ fn test_sub_rational_round() {
     test("NaN", "NaN", "-123", Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", Nearest, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-123", NearestTiesAway, "NaN", "NaN", Equal);
+    test("NaN", "NaN", "-123", ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", Exact, "NaN", "NaN", Equal);
 
     test(
@@ -9650,6 +11504,18 @@ fn test_sub_rational_round() {
     test(
         "Infinity", "Infinity", "-123", Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-123", ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-123", Exact, "Infinity", "Infinity", Equal,
     );
@@ -9699,6 +11565,24 @@ fn test_sub_rational_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123",
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123",
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -9714,6 +11598,8 @@ fn test_sub_rational_round() {
     test("0.0", "0x0.0", "0", Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Nearest, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", NearestTiesAway, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", "0", ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", Floor, "-0.0", "-0x0.0", Equal);
@@ -9721,6 +11607,16 @@ fn test_sub_rational_round() {
     test("-0.0", "-0x0.0", "0", Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", Exact, "-0.0", "-0x0.0", Equal);
 
     test("0.0", "0x0.0", "-123", Floor, "6.0e1", "0x4.0E+1#1", Less);
@@ -9744,6 +11640,16 @@ fn test_sub_rational_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("0.0", "0x0.0", "-123", ToOdd, "6.0e1", "0x4.0E+1#1", Less);
 
     test("-0.0", "-0x0.0", "-123", Floor, "6.0e1", "0x4.0E+1#1", Less);
     test(
@@ -9766,6 +11672,16 @@ fn test_sub_rational_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test("-0.0", "-0x0.0", "-123", ToOdd, "6.0e1", "0x4.0E+1#1", Less);
 
     test("123.0", "0x7b.0#7", "0", Floor, "123.0", "0x7b.0#7", Equal);
     test(
@@ -9776,6 +11692,16 @@ fn test_sub_rational_round() {
     test(
         "123.0", "0x7b.0#7", "0", Nearest, "123.0", "0x7b.0#7", Equal,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        NearestTiesAway,
+        "123.0",
+        "0x7b.0#7",
+        Equal,
+    );
+    test("123.0", "0x7b.0#7", "0", ToOdd, "123.0", "0x7b.0#7", Equal);
     test("123.0", "0x7b.0#7", "0", Exact, "123.0", "0x7b.0#7", Equal);
 
     test("0.0", "0x0.0", "-1/3", Floor, "0.2", "0x0.4#1", Less);
@@ -9783,24 +11709,64 @@ fn test_sub_rational_round() {
     test("0.0", "0x0.0", "-1/3", Down, "0.2", "0x0.4#1", Less);
     test("0.0", "0x0.0", "-1/3", Up, "0.5", "0x0.8#1", Greater);
     test("0.0", "0x0.0", "-1/3", Nearest, "0.2", "0x0.4#1", Less);
+    test(
+        "0.0",
+        "0x0.0",
+        "-1/3",
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test("0.0", "0x0.0", "-1/3", ToOdd, "0.2", "0x0.4#1", Less);
 
     test("-0.0", "-0x0.0", "-1/3", Floor, "0.2", "0x0.4#1", Less);
     test("-0.0", "-0x0.0", "-1/3", Ceiling, "0.5", "0x0.8#1", Greater);
     test("-0.0", "-0x0.0", "-1/3", Down, "0.2", "0x0.4#1", Less);
     test("-0.0", "-0x0.0", "-1/3", Up, "0.5", "0x0.8#1", Greater);
     test("-0.0", "-0x0.0", "-1/3", Nearest, "0.2", "0x0.4#1", Less);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1/3",
+        NearestTiesAway,
+        "0.2",
+        "0x0.4#1",
+        Less,
+    );
+    test("-0.0", "-0x0.0", "-1/3", ToOdd, "0.2", "0x0.4#1", Less);
 
     test("1.0", "0x1.0#1", "-2", Floor, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "-2", Ceiling, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "-2", Down, "2.0", "0x2.0#1", Less);
     test("1.0", "0x1.0#1", "-2", Up, "4.0", "0x4.0#1", Greater);
     test("1.0", "0x1.0#1", "-2", Nearest, "4.0", "0x4.0#1", Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2",
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", "-2", ToOdd, "2.0", "0x2.0#1", Less);
 
     test("1.0", "0x1.0#2", "-2", Floor, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "-2", Ceiling, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "-2", Down, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "-2", Up, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "-2", Nearest, "3.0", "0x3.0#2", Equal);
+    test(
+        "1.0",
+        "0x1.0#2",
+        "-2",
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Equal,
+    );
+    test("1.0", "0x1.0#2", "-2", ToOdd, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#2", "-2", Exact, "3.0", "0x3.0#2", Equal);
 
     test("1.0", "0x1.000#10", "-2", Floor, "3.0", "0x3.00#10", Equal);
@@ -9824,6 +11790,16 @@ fn test_sub_rational_round() {
         "0x3.00#10",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-2",
+        NearestTiesAway,
+        "3.0",
+        "0x3.00#10",
+        Equal,
+    );
+    test("1.0", "0x1.000#10", "-2", ToOdd, "3.0", "0x3.00#10", Equal);
     test("1.0", "0x1.000#10", "-2", Exact, "3.0", "0x3.00#10", Equal);
 
     test(
@@ -9871,6 +11847,24 @@ fn test_sub_rational_round() {
         "0x1.558#10",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-1/3",
+        NearestTiesAway,
+        "1.334",
+        "0x1.558#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-1/3",
+        ToOdd,
+        "1.334",
+        "0x1.558#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -9917,6 +11911,24 @@ fn test_sub_rational_round() {
         "0x1.5555555555555555555555556#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "-1/3",
+        NearestTiesAway,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        "-1/3",
+        ToOdd,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -9963,6 +11975,24 @@ fn test_sub_rational_round() {
         "0x3.7994bfdddaf86#53",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        NearestTiesAway,
+        "3.4749259869231266",
+        "0x3.7994bfdddaf86#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        ToOdd,
+        "3.4749259869231266",
+        "0x3.7994bfdddaf86#53",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -10009,6 +12039,24 @@ fn test_sub_rational_round() {
         "0x2.ceea1533304da#53",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "2.8082593202564596",
+        "0x2.ceea1533304da#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "2.8082593202564596",
+        "0x2.ceea1533304da#53",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10055,6 +12103,24 @@ fn test_sub_rational_round() {
         "-0x2.ceea1533304da#53",
         Greater,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        NearestTiesAway,
+        "-2.8082593202564596",
+        "-0x2.ceea1533304da#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        ToOdd,
+        "-2.8082593202564596",
+        "-0x2.ceea1533304da#53",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10101,6 +12167,24 @@ fn test_sub_rational_round() {
         "-0x3.7994bfdddaf86#53",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        NearestTiesAway,
+        "-3.4749259869231266",
+        "-0x3.7994bfdddaf86#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        ToOdd,
+        "-3.4749259869231266",
+        "-0x3.7994bfdddaf86#53",
+        Less,
+    );
 
     test("1.0", "0x1.0#1", "-1/50000", Floor, "1.0", "0x1.0#1", Less);
     test(
@@ -10111,12 +12195,32 @@ fn test_sub_rational_round() {
     test(
         "1.0", "0x1.0#1", "-1/50000", Nearest, "1.0", "0x1.0#1", Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1/50000",
+        NearestTiesAway,
+        "1.0",
+        "0x1.0#1",
+        Less,
+    );
+    test("1.0", "0x1.0#1", "-1/50000", ToOdd, "1.0", "0x1.0#1", Less);
 
     test("1.0", "0x1.0#1", "1", Floor, "-0.0", "-0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", Ceiling, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", Down, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", Up, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "1", ToOdd, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", Exact, "0.0", "0x0.0", Equal);
 }
 
@@ -10206,6 +12310,17 @@ fn test_sub_rational_prec_round() {
     test("NaN", "NaN", "-123", 1, Down, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", 1, Up, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", 1, Nearest, "NaN", "NaN", Equal);
+    test(
+        "NaN",
+        "NaN",
+        "-123",
+        1,
+        NearestTiesAway,
+        "NaN",
+        "NaN",
+        Equal,
+    );
+    test("NaN", "NaN", "-123", 1, ToOdd, "NaN", "NaN", Equal);
     test("NaN", "NaN", "-123", 1, Exact, "NaN", "NaN", Equal);
 
     test(
@@ -10223,6 +12338,19 @@ fn test_sub_rational_prec_round() {
     test(
         "Infinity", "Infinity", "-123", 1, Nearest, "Infinity", "Infinity", Equal,
     );
+    test(
+        "Infinity",
+        "Infinity",
+        "-123",
+        1,
+        NearestTiesAway,
+        "Infinity",
+        "Infinity",
+        Equal,
+    );
+    test(
+        "Infinity", "Infinity", "-123", 1, ToOdd, "Infinity", "Infinity", Equal,
+    );
     test(
         "Infinity", "Infinity", "-123", 1, Exact, "Infinity", "Infinity", Equal,
     );
@@ -10277,6 +12405,26 @@ fn test_sub_rational_prec_round() {
         "-Infinity",
         Equal,
     );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123",
+        1,
+        NearestTiesAway,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-123",
+        1,
+        ToOdd,
+        "-Infinity",
+        "-Infinity",
+        Equal,
+    );
     test(
         "-Infinity",
         "-Infinity",
@@ -10293,6 +12441,17 @@ fn test_sub_rational_prec_round() {
     test("0.0", "0x0.0", "0", 1, Down, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Up, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "0.0",
+        "0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("0.0", "0x0.0", "0", 1, ToOdd, "0.0", "0x0.0", Equal);
     test("0.0", "0x0.0", "0", 1, Exact, "0.0", "0x0.0", Equal);
 
     test("-0.0", "-0x0.0", "0", 1, Floor, "-0.0", "-0x0.0", Equal);
@@ -10300,6 +12459,17 @@ fn test_sub_rational_prec_round() {
     test("-0.0", "-0x0.0", "0", 1, Down, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Up, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Nearest, "-0.0", "-0x0.0", Equal);
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0",
+        1,
+        NearestTiesAway,
+        "-0.0",
+        "-0x0.0",
+        Equal,
+    );
+    test("-0.0", "-0x0.0", "0", 1, ToOdd, "-0.0", "-0x0.0", Equal);
     test("-0.0", "-0x0.0", "0", 1, Exact, "-0.0", "-0x0.0", Equal);
 
     test(
@@ -10343,6 +12513,26 @@ fn test_sub_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-123",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test(
         "-0.0",
@@ -10394,6 +12584,26 @@ fn test_sub_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-123",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test("0.0", "0x0.0", "-1/3", 1, Floor, "0.2", "0x0.4#1", Less);
     test(
@@ -10461,6 +12671,26 @@ fn test_sub_rational_prec_round() {
         "0x8.0E+1#1",
         Greater,
     );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        NearestTiesAway,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Greater,
+    );
+    test(
+        "123.0",
+        "0x7b.0#7",
+        "0",
+        1,
+        ToOdd,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Less,
+    );
 
     test("1.0", "0x1.0#1", "-2", 1, Floor, "2.0", "0x2.0#1", Less);
     test(
@@ -10471,12 +12701,34 @@ fn test_sub_rational_prec_round() {
     test(
         "1.0", "0x1.0#1", "-2", 1, Nearest, "4.0", "0x4.0#1", Greater,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2",
+        1,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#1",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", "-2", 1, ToOdd, "2.0", "0x2.0#1", Less);
 
     test("1.0", "0x1.0#1", "-2", 2, Floor, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "-2", 2, Ceiling, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "-2", 2, Down, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "-2", 2, Up, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "-2", 2, Nearest, "3.0", "0x3.0#2", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-2",
+        2,
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "-2", 2, ToOdd, "3.0", "0x3.0#2", Equal);
     test("1.0", "0x1.0#1", "-2", 2, Exact, "3.0", "0x3.0#2", Equal);
 
     test(
@@ -10529,6 +12781,26 @@ fn test_sub_rational_prec_round() {
         "0x1.5555555555555555555555556#100",
         Greater,
     );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-1/3",
+        100,
+        NearestTiesAway,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        "-1/3",
+        100,
+        ToOdd,
+        "1.333333333333333333333333333334",
+        "0x1.5555555555555555555555556#100",
+        Greater,
+    );
 
     test(
         "3.1415926535897931",
@@ -10580,6 +12852,26 @@ fn test_sub_rational_prec_round() {
         "0x3.7a#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        NearestTiesAway,
+        "3.477",
+        "0x3.7a#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        ToOdd,
+        "3.473",
+        "0x3.79#10",
+        Less,
+    );
 
     test(
         "3.1415926535897931",
@@ -10631,6 +12923,26 @@ fn test_sub_rational_prec_round() {
         "0x2.cf#10",
         Greater,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "2.809",
+        "0x2.cf#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "2.809",
+        "0x2.cf#10",
+        Greater,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10682,6 +12994,26 @@ fn test_sub_rational_prec_round() {
         "-0x2.cf#10",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        NearestTiesAway,
+        "-2.809",
+        "-0x2.cf#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1/3",
+        10,
+        ToOdd,
+        "-2.809",
+        "-0x2.cf#10",
+        Less,
+    );
 
     test(
         "-3.1415926535897931",
@@ -10733,6 +13065,26 @@ fn test_sub_rational_prec_round() {
         "-0x3.7a#10",
         Less,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        NearestTiesAway,
+        "-3.477",
+        "-0x3.7a#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1/3",
+        10,
+        ToOdd,
+        "-3.473",
+        "-0x3.79#10",
+        Greater,
+    );
 
     test(
         "1.0",
@@ -10784,12 +13136,43 @@ fn test_sub_rational_prec_round() {
         "0x1.000#10",
         Less,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1/50000",
+        10,
+        NearestTiesAway,
+        "1.0",
+        "0x1.000#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1/50000",
+        10,
+        ToOdd,
+        "1.002",
+        "0x1.008#10",
+        Greater,
+    );
 
     test("1.0", "0x1.0#1", "1", 10, Floor, "-0.0", "-0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", 10, Ceiling, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", 10, Down, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", 10, Up, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", 10, Nearest, "0.0", "0x0.0", Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1",
+        10,
+        NearestTiesAway,
+        "0.0",
+        "0x0.0",
+        Equal,
+    );
+    test("1.0", "0x1.0#1", "1", 10, ToOdd, "0.0", "0x0.0", Equal);
     test("1.0", "0x1.0#1", "1", 10, Exact, "0.0", "0x0.0", Equal);
 }
 
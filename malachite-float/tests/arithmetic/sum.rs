@@ -0,0 +1,397 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::test_util::arithmetic::sum::{
+    dot_prec_round_naive, rug_dot_prec_round, rug_sum_prec_round, sum_prec_round_naive,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{float_gen, float_vec_gen};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering::*};
+use std::iter::Sum;
+use std::panic::catch_unwind;
+
+const PRECS: [u64; 4] = [1, 10, 64, 100];
+
+fn parse_hex_strings(xs: &[&str]) -> Vec<Float> {
+    xs.iter().map(|x| parse_hex_string(x)).collect()
+}
+
+#[test]
+fn test_sum_prec_round() {
+    let test = |xs: &[&str], prec: u64, rm: RoundingMode, out: &str, o_out| {
+        let xs = parse_hex_strings(xs);
+        let (sum, o) = Float::sum_prec_round(xs.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(to_hex_string(&sum), out);
+        assert_eq!(o, o_out);
+
+        let (sum_alt, o_alt) = sum_prec_round_naive(&xs, prec, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+            let (rug_sum, rug_o) = rug_sum_prec_round(&rug_xs, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(&[], 10, Nearest, "0x0.0", Equal);
+    test(&["-0x0.0"], 10, Nearest, "-0x0.0", Equal);
+    test(&["-0x0.0", "0x0.0"], 10, Nearest, "0x0.0", Equal);
+    test(&["-0x0.0", "0x0.0"], 10, Floor, "-0x0.0", Equal);
+    test(&["0x1.0#1", "-0x1.0#1"], 10, Floor, "-0x0.0", Equal);
+    test(&["0x1.0#1", "-0x1.0#1"], 10, Ceiling, "0x0.0", Equal);
+
+    test(&["NaN", "0x1.0#1"], 10, Nearest, "NaN", Equal);
+    test(&["Infinity", "-Infinity"], 10, Nearest, "NaN", Equal);
+    test(&["Infinity", "0x1.0#1"], 10, Nearest, "Infinity", Equal);
+    test(&["-Infinity", "0x1.0#1"], 10, Nearest, "-Infinity", Equal);
+
+    // The large terms cancel, so only a sum computed without intermediate rounding gets 1
+    test(
+        &["0x1.0E+100#1", "0x1.0#1", "-0x1.0E+100#1"],
+        10,
+        Nearest,
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        &["0x1.0E+100#1", "0x1.0#1"],
+        10,
+        Nearest,
+        "0x1.000E+100#10",
+        Less,
+    );
+    test(
+        &["0x1.0E+100#1", "0x1.0#1"],
+        10,
+        Ceiling,
+        "0x1.008E+100#10",
+        Greater,
+    );
+    test(
+        &["0x1.0E+100#1", "-0x1.0#1"],
+        10,
+        Floor,
+        "0xf.fcE+99#10",
+        Less,
+    );
+    test(
+        &["0x1.0E+100#1", "-0x1.0#1"],
+        10,
+        Nearest,
+        "0x1.000E+100#10",
+        Greater,
+    );
+
+    // 0.1 + 0.2 + 0.3
+    let xs = ["0x0.1999999999999a#53", "0x0.33333333333334#53", "0x0.4ccccccccccccc#53"];
+    test(&xs, 53, Nearest, "0x0.99999999999998#53", Less);
+    test(&xs, 53, Floor, "0x0.99999999999998#53", Less);
+    test(&xs, 10, Ceiling, "0x0.99c#10", Greater);
+
+    let xs = ["0x1.0#1", "0x1.0#1", "0x1.0#1"];
+    test(&xs, 1, Nearest, "0x4.0#1", Greater);
+    test(&xs, 1, NearestTiesAway, "0x4.0#1", Greater);
+    test(&xs, 1, ToOdd, "0x2.0#1", Less);
+    test(&xs, 2, Exact, "0x3.0#2", Equal);
+}
+
+#[test]
+fn sum_prec_round_fail() {
+    assert_panic!(Float::sum_prec_round([Float::ONE], 0, Floor));
+    assert_panic!(Float::sum_prec_round(
+        [Float::ONE, Float::ONE, Float::ONE],
+        1,
+        Exact
+    ));
+}
+
+#[test]
+fn test_sum() {
+    let xs = parse_hex_strings(&["0x1.0#1", "0x1.0#1", "0x1.0#1"]);
+    assert_eq!(to_hex_string(&Float::sum(xs.iter())), "0x4.0#1");
+    assert_eq!(to_hex_string(&Float::sum(xs.into_iter())), "0x4.0#1");
+    assert_eq!(
+        to_hex_string(&Float::sum(Vec::<Float>::new().iter())),
+        "0x0.0"
+    );
+}
+
+#[test]
+fn test_dot_prec_round() {
+    let test = |xs: &[&str], ys: &[&str], prec: u64, rm: RoundingMode, out: &str, o_out| {
+        let xs = parse_hex_strings(xs);
+        let ys = parse_hex_strings(ys);
+        let (dot, o) = Float::dot_prec_round(xs.clone(), ys.clone(), prec, rm);
+        assert!(dot.is_valid());
+        assert_eq!(to_hex_string(&dot), out);
+        assert_eq!(o, o_out);
+
+        let (dot_alt, o_alt) = dot_prec_round_naive(xs.clone(), ys.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&dot_alt), ComparableFloatRef(&dot));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+            let rug_ys: Vec<rug::Float> = ys.iter().map(rug::Float::exact_from).collect();
+            let (rug_dot, rug_o) = rug_dot_prec_round(&rug_xs, &rug_ys, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_dot)),
+                ComparableFloatRef(&dot)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(&[], &[], 10, Nearest, "0x0.0", Equal);
+    test(
+        &["0x0.0", "0x1.0#1"],
+        &["-0x1.0#1", "-0x0.0"],
+        10,
+        Nearest,
+        "-0x0.0",
+        Equal,
+    );
+    test(&["0x0.0"], &["Infinity"], 10, Nearest, "NaN", Equal);
+    test(
+        &["-0x1.0#1"],
+        &["Infinity"],
+        10,
+        Nearest,
+        "-Infinity",
+        Equal,
+    );
+
+    // 0.1 * 10 - 3 * 1
+    let xs = ["0x0.1999999999999a#53", "-0x3.0#2"];
+    let ys = ["0xa.0#3", "0x1.0#1"];
+    test(&xs, &ys, 10, Floor, "-0x2.00#10", Less);
+    test(
+        &xs,
+        &ys,
+        100,
+        Nearest,
+        "-0x1.fffffffffffffc00000000000#100",
+        Equal,
+    );
+
+    test(
+        &["0x1.0E+100#1", "0x1.0#1", "0x1.0E+100#1"],
+        &["0x1.0E+100#1", "0x1.0#1", "-0x1.0E+100#1"],
+        10,
+        Nearest,
+        "0x1.000#10",
+        Equal,
+    );
+    test(
+        &["0x3.0#2"],
+        &["0x3.0#2"],
+        3,
+        NearestTiesAway,
+        "0xa.0#3",
+        Greater,
+    );
+    test(&["0x3.0#2"], &["0x3.0#2"], 3, ToOdd, "0xa.0#3", Greater);
+}
+
+#[test]
+fn dot_prec_round_fail() {
+    assert_panic!(Float::dot_prec_round([Float::ONE], [Float::ONE], 0, Floor));
+    assert_panic!(Float::dot_prec_round(
+        [Float::ONE, Float::ONE],
+        [Float::ONE],
+        10,
+        Floor
+    ));
+    assert_panic!(Float::dot_prec_round(
+        [Float::from(3), Float::ONE],
+        [Float::from(3), Float::ONE],
+        1,
+        Exact
+    ));
+}
+
+fn sum_prec_round_properties_helper(xs: &[Float], prec: u64, rm: RoundingMode) {
+    let (sum, o) = Float::sum_prec_round(xs.to_vec(), prec, rm);
+    assert!(sum.is_valid());
+
+    let (sum_alt, o_alt) = sum_prec_round_naive(xs, prec, rm);
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+        let (rug_sum, rug_o) = rug_sum_prec_round(&rug_xs, prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if sum.is_finite() && sum != 0u32 {
+        assert_eq!(sum.get_prec(), Some(prec));
+        let r_sum: Rational = xs.iter().map(Rational::exact_from).sum();
+        assert_eq!(sum.partial_cmp(&r_sum), Some(o));
+    }
+
+    // Negating every term negates the sum, with the rounding mode reversed
+    let (neg_sum, neg_o) = Float::sum_prec_round(xs.iter().map(|x| -x), prec, -rm);
+    if sum == 0u32 {
+        assert_eq!(neg_sum, 0u32);
+    } else {
+        assert_eq!(ComparableFloat(-neg_sum), ComparableFloat(sum));
+    }
+    assert_eq!(neg_o.reverse(), o);
+}
+
+#[test]
+fn sum_prec_round_properties() {
+    float_vec_gen().test_properties(|xs| {
+        for prec in PRECS {
+            let (_, o) = Float::sum_prec_round(xs.clone(), prec, Floor);
+            for rm in exhaustive_rounding_modes() {
+                if rm != Exact || o == Equal {
+                    sum_prec_round_properties_helper(&xs, prec, rm);
+                }
+            }
+            let (sum, o) = Float::sum_prec(xs.clone(), prec);
+            let (sum_alt, o_alt) = Float::sum_prec_round(xs.clone(), prec, Nearest);
+            assert_eq!(ComparableFloat(sum_alt), ComparableFloat(sum));
+            assert_eq!(o_alt, o);
+        }
+        let prec = max(
+            1,
+            xs.iter()
+                .map(SignificantBits::significant_bits)
+                .max()
+                .unwrap_or(1),
+        );
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (sum, o) = Float::sum_round(xs.clone(), rm);
+            let (sum_alt, o_alt) = Float::sum_prec_round(xs.clone(), prec, rm);
+            assert_eq!(ComparableFloat(sum_alt), ComparableFloat(sum));
+            assert_eq!(o_alt, o);
+        }
+        let sum = Float::sum(xs.iter());
+        assert_eq!(
+            ComparableFloat(Float::sum(xs.clone().into_iter())),
+            ComparableFloat(sum.clone())
+        );
+        assert_eq!(
+            ComparableFloat(Float::sum_round(xs, Nearest).0),
+            ComparableFloat(sum)
+        );
+    });
+
+    float_gen().test_properties(|x| {
+        for prec in PRECS {
+            for rm in exhaustive_rounding_modes() {
+                if rm == Exact {
+                    continue;
+                }
+                let (sum, o) = Float::sum_prec_round([x.clone()], prec, rm);
+                let (x_alt, o_alt) = Float::from_float_prec_round_ref(&x, prec, rm);
+                assert_eq!(ComparableFloat(x_alt), ComparableFloat(sum));
+                assert_eq!(o_alt, o);
+            }
+        }
+    });
+}
+
+#[test]
+fn dot_prec_round_properties() {
+    float_vec_gen().test_properties(|xs| {
+        let mut xs = xs;
+        if xs.len() % 2 == 1 {
+            xs.pop();
+        }
+        let ys: Vec<Float> = xs.iter().skip(1).step_by(2).cloned().collect();
+        let xs: Vec<Float> = xs.into_iter().step_by(2).collect();
+        for prec in PRECS {
+            let (_, o) = Float::dot_prec_round(xs.clone(), ys.clone(), prec, Floor);
+            for rm in exhaustive_rounding_modes() {
+                if rm == Exact && o != Equal {
+                    continue;
+                }
+                let (dot, o) = Float::dot_prec_round(xs.clone(), ys.clone(), prec, rm);
+                assert!(dot.is_valid());
+
+                let (dot_alt, o_alt) = dot_prec_round_naive(xs.clone(), ys.clone(), prec, rm);
+                assert_eq!(ComparableFloatRef(&dot_alt), ComparableFloatRef(&dot));
+                assert_eq!(o_alt, o);
+
+                if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                    let rug_xs: Vec<rug::Float> = xs.iter().map(rug::Float::exact_from).collect();
+                    let rug_ys: Vec<rug::Float> = ys.iter().map(rug::Float::exact_from).collect();
+                    let (rug_dot, rug_o) = rug_dot_prec_round(&rug_xs, &rug_ys, prec, rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_dot)),
+                        ComparableFloatRef(&dot)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+            let (dot, o) = Float::dot_prec(xs.clone(), ys.clone(), prec);
+            let (dot_alt, o_alt) = Float::dot_prec_round(xs.clone(), ys.clone(), prec, Nearest);
+            assert_eq!(ComparableFloat(dot_alt), ComparableFloat(dot));
+            assert_eq!(o_alt, o);
+        }
+        let prec = max(
+            1,
+            xs.iter()
+                .chain(ys.iter())
+                .map(SignificantBits::significant_bits)
+                .max()
+                .unwrap_or(1),
+        );
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (dot, o) = Float::dot_round(xs.clone(), ys.clone(), rm);
+            let (dot_alt, o_alt) = Float::dot_prec_round(xs.clone(), ys.clone(), prec, rm);
+            assert_eq!(ComparableFloat(dot_alt), ComparableFloat(dot));
+            assert_eq!(o_alt, o);
+        }
+    });
+
+    float_vec_gen().test_properties(|xs| {
+        let ones = vec![Float::ONE; xs.len()];
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (dot, o) = Float::dot_prec_round(xs.clone(), ones.clone(), 10, rm);
+            let (sum, o_alt) = Float::sum_prec_round(xs.clone(), 10, rm);
+            assert_eq!(ComparableFloat(sum), ComparableFloat(dot));
+            assert_eq!(o_alt, o);
+        }
+    });
+
+    assert_eq!(
+        ComparableFloat(Float::dot_prec([Float::ZERO], [Float::ZERO], 1).0),
+        ComparableFloat(Float::ZERO)
+    );
+}
@@ -0,0 +1,1276 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::test_util::arithmetic::tan::{
+    rug_tan_prec, rug_tan_prec_round, rug_tan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_tan_prec() {
+    let test = |s, s_hex, prec: u64, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec(prec);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_assign(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 10, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, "1.557", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, "-1.557", "-0x1.8e8#10", Greater);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        "1.557",
+        "0x1.8e8#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 1, "-2.0", "-0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", 10, "-2.184", "-0x2.2f#10", Greater);
+    test("3.0", "0x3.0#2", 1, "-0.1", "-0x0.2#1", Greater);
+    test("3.0", "0x3.0#2", 10, "-0.1426", "-0x0.248#10", Less);
+    test("123.0", "0x7b.0#7", 1, "0.5", "0x0.8#1", Less);
+    test("123.0", "0x7b.0#7", 10, "0.518", "0x0.848#10", Less);
+    test("-123.0", "-0x7b.0#7", 1, "-0.5", "-0x0.8#1", Greater);
+    test("-123.0", "-0x7b.0#7", 10, "-0.518", "-0x0.848#10", Greater);
+    test("355.0", "0x163.0#9", 1, "0.00003", "0x0.0002#1", Greater);
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        "0.00003013",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.4507",
+        "-0x0.736#10",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", 1, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "-2.0", "-0x2.0#1", Less);
+    test("1.0e30", "0x1.0E+25#1", 10, "-1.783", "-0x1.c88#10", Less);
+    test("2.0e3010", "0x1.0E+2500#1", 1, "1.0", "0x1.0#1", Greater);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        "0.7705",
+        "0x0.c54#10",
+        Less,
+    );
+}
+
+#[test]
+fn tan_prec_fail() {
+    assert_panic!(Float::NAN.tan_prec(0));
+    assert_panic!(Float::NAN.tan_prec_ref(0));
+    assert_panic!({
+        let mut x = Float::NAN;
+        x.tan_prec_assign(0)
+    });
+}
+
+#[test]
+fn test_tan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_round(rm);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_round_ref(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_round_assign(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", Nearest, "2.0", "0x2.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Floor,
+        "1.557407724654902230506974807458",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ceiling,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Down,
+        "1.557407724654902230506974807458",
+        "0x1.8eb245cbee3a5b8acc7d41322#100",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Up,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Nearest,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", Floor, "-4.0", "-0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Ceiling, "-2.0", "-0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Down, "-2.0", "-0x2.0#1", Greater);
+    test("2.0", "0x2.0#1", Up, "-4.0", "-0x4.0#1", Less);
+    test("2.0", "0x2.0#1", Nearest, "-2.0", "-0x2.0#1", Greater);
+    test("3.0", "0x3.0#2", Floor, "-0.19", "-0x0.3#2", Less);
+    test("3.0", "0x3.0#2", Ceiling, "-0.1", "-0x0.2#2", Greater);
+    test("3.0", "0x3.0#2", Down, "-0.1", "-0x0.2#2", Greater);
+    test("3.0", "0x3.0#2", Up, "-0.19", "-0x0.3#2", Less);
+    test("3.0", "0x3.0#2", Nearest, "-0.1", "-0x0.2#2", Greater);
+    test("123.0", "0x7b.0#7", Floor, "0.516", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Ceiling, "0.523", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Down, "0.516", "0x0.84#7", Less);
+    test("123.0", "0x7b.0#7", Up, "0.523", "0x0.86#7", Greater);
+    test("123.0", "0x7b.0#7", Nearest, "0.516", "0x0.84#7", Less);
+    test("-123.0", "-0x7b.0#7", Floor, "-0.523", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Ceiling,
+        "-0.516",
+        "-0x0.84#7",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", Down, "-0.516", "-0x0.84#7", Greater);
+    test("-123.0", "-0x7b.0#7", Up, "-0.523", "-0x0.86#7", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        Nearest,
+        "-0.516",
+        "-0x0.84#7",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Floor,
+        "0.0000301",
+        "0x0.0001f9#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Ceiling,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Down,
+        "0.0000301",
+        "0x0.0001f9#9",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Up,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        Nearest,
+        "0.00003016",
+        "0x0.0001fa#9",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Floor,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Ceiling,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Down,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Up,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        Nearest,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Less,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Floor, "4.0e-9", "0x1.0E-7#1", Less);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Ceiling,
+        "7.0e-9",
+        "0x2.0E-7#1",
+        Greater,
+    );
+    test("4.0e-9", "0x1.0E-7#1", Down, "4.0e-9", "0x1.0E-7#1", Less);
+    test("4.0e-9", "0x1.0E-7#1", Up, "7.0e-9", "0x2.0E-7#1", Greater);
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Nearest,
+        "4.0e-9",
+        "0x1.0E-7#1",
+        Less,
+    );
+    test("1.0e30", "0x1.0E+25#1", Floor, "-2.0", "-0x2.0#1", Less);
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", Down, "-1.0", "-0x1.0#1", Greater);
+    test("1.0e30", "0x1.0E+25#1", Up, "-2.0", "-0x2.0#1", Less);
+    test("1.0e30", "0x1.0E+25#1", Nearest, "-2.0", "-0x2.0#1", Less);
+    test("2.0e3010", "0x1.0E+2500#1", Floor, "0.5", "0x0.8#1", Less);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+    test("2.0e3010", "0x1.0E+2500#1", Down, "0.5", "0x0.8#1", Less);
+    test("2.0e3010", "0x1.0E+2500#1", Up, "1.0", "0x1.0#1", Greater);
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        Nearest,
+        "1.0",
+        "0x1.0#1",
+        Greater,
+    );
+}
+
+#[test]
+fn tan_round_fail() {
+    assert_panic!(Float::TWO.tan_round(Exact));
+    assert_panic!(Float::TWO.tan_round_ref(Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.tan_round_assign(Exact);
+    });
+}
+
+#[test]
+fn test_tan_prec_round() {
+    let test = |s, s_hex, prec: u64, rm, out: &str, out_hex: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+        assert_eq!(o, o_out);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_round_assign(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test("NaN", "NaN", 10, Floor, "NaN", "NaN", Equal);
+    test("NaN", "NaN", 10, Exact, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("Infinity", "Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Floor, "NaN", "NaN", Equal);
+    test("-Infinity", "-Infinity", 10, Exact, "NaN", "NaN", Equal);
+    test("0.0", "0x0.0", 10, Floor, "0.0", "0x0.0", Equal);
+    test("0.0", "0x0.0", 10, Exact, "0.0", "0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Floor, "-0.0", "-0x0.0", Equal);
+    test("-0.0", "-0x0.0", 10, Exact, "-0.0", "-0x0.0", Equal);
+    test("1.0", "0x1.0#1", 1, Floor, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Down, "1.0", "0x1.0#1", Less);
+    test("1.0", "0x1.0#1", 1, Up, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 1, Nearest, "2.0", "0x2.0#1", Greater);
+    test("1.0", "0x1.0#1", 10, Floor, "1.557", "0x1.8e8#10", Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test("1.0", "0x1.0#1", 10, Down, "1.557", "0x1.8e8#10", Less);
+    test("1.0", "0x1.0#1", 10, Up, "1.559", "0x1.8f0#10", Greater);
+    test("1.0", "0x1.0#1", 10, Nearest, "1.557", "0x1.8e8#10", Less);
+    test("-1.0", "-0x1.0#1", 1, Floor, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Ceiling, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Down, "-1.0", "-0x1.0#1", Greater);
+    test("-1.0", "-0x1.0#1", 1, Up, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 1, Nearest, "-2.0", "-0x2.0#1", Less);
+    test("-1.0", "-0x1.0#1", 10, Floor, "-1.559", "-0x1.8f0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Ceiling,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Down,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test("-1.0", "-0x1.0#1", 10, Up, "-1.559", "-0x1.8f0#10", Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        Nearest,
+        "-1.557",
+        "-0x1.8e8#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Floor,
+        "1.557",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Down,
+        "1.557",
+        "0x1.8e8#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Up,
+        "1.559",
+        "0x1.8f0#10",
+        Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        10,
+        Nearest,
+        "1.557",
+        "0x1.8e8#10",
+        Less,
+    );
+    test("2.0", "0x2.0#1", 10, Floor, "-2.188", "-0x2.30#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Ceiling,
+        "-2.184",
+        "-0x2.2f#10",
+        Greater,
+    );
+    test("2.0", "0x2.0#1", 10, Down, "-2.184", "-0x2.2f#10", Greater);
+    test("2.0", "0x2.0#1", 10, Up, "-2.188", "-0x2.30#10", Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        Nearest,
+        "-2.184",
+        "-0x2.2f#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Floor, "-0.1426", "-0x0.248#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Ceiling,
+        "-0.1423",
+        "-0x0.247#10",
+        Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Down,
+        "-0.1423",
+        "-0x0.247#10",
+        Greater,
+    );
+    test("3.0", "0x3.0#2", 10, Up, "-0.1426", "-0x0.248#10", Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        Nearest,
+        "-0.1426",
+        "-0x0.248#10",
+        Less,
+    );
+    test("123.0", "0x7b.0#7", 10, Floor, "0.518", "0x0.848#10", Less);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Ceiling,
+        "0.519",
+        "0x0.84c#10",
+        Greater,
+    );
+    test("123.0", "0x7b.0#7", 10, Down, "0.518", "0x0.848#10", Less);
+    test("123.0", "0x7b.0#7", 10, Up, "0.519", "0x0.84c#10", Greater);
+    test(
+        "123.0",
+        "0x7b.0#7",
+        10,
+        Nearest,
+        "0.518",
+        "0x0.848#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Floor,
+        "-0.519",
+        "-0x0.84c#10",
+        Less,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Ceiling,
+        "-0.518",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Down,
+        "-0.518",
+        "-0x0.848#10",
+        Greater,
+    );
+    test("-123.0", "-0x7b.0#7", 10, Up, "-0.519", "-0x0.84c#10", Less);
+    test(
+        "-123.0",
+        "-0x7b.0#7",
+        10,
+        Nearest,
+        "-0.518",
+        "-0x0.848#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Floor,
+        "0.00003013",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Ceiling,
+        "0.00003016",
+        "0x0.0001fa0#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Down,
+        "0.00003013",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Up,
+        "0.00003016",
+        "0x0.0001fa0#10",
+        Greater,
+    );
+    test(
+        "355.0",
+        "0x163.0#9",
+        10,
+        Nearest,
+        "0.00003013",
+        "0x0.0001f98#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Down,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Up,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Floor,
+        "-0.4507",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Ceiling,
+        "-0.4502",
+        "-0x0.734#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Down,
+        "-0.4502",
+        "-0x0.734#10",
+        Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Up,
+        "-0.4507",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        Nearest,
+        "-0.4507",
+        "-0x0.736#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Floor,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Ceiling,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Down,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Up,
+        "3.733e-9",
+        "0x1.008E-7#10",
+        Greater,
+    );
+    test(
+        "4.0e-9",
+        "0x1.0E-7#1",
+        10,
+        Nearest,
+        "3.725e-9",
+        "0x1.000E-7#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Floor,
+        "-1.783",
+        "-0x1.c88#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Ceiling,
+        "-1.781",
+        "-0x1.c80#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Down,
+        "-1.781",
+        "-0x1.c80#10",
+        Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Up,
+        "-1.783",
+        "-0x1.c88#10",
+        Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        Nearest,
+        "-1.783",
+        "-0x1.c88#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Floor,
+        "0.7705",
+        "0x0.c54#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Ceiling,
+        "0.7715",
+        "0x0.c58#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Down,
+        "0.7705",
+        "0x0.c54#10",
+        Less,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Up,
+        "0.7715",
+        "0x0.c58#10",
+        Greater,
+    );
+    test(
+        "2.0e3010",
+        "0x1.0E+2500#1",
+        10,
+        Nearest,
+        "0.7705",
+        "0x0.c54#10",
+        Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        NearestTiesAway,
+        "1.557",
+        "0x1.8e8#10",
+        Less,
+    );
+    test("1.0", "0x1.0#1", 10, ToOdd, "1.557", "0x1.8e8#10", Less);
+}
+
+#[test]
+fn tan_prec_round_fail() {
+    assert_panic!(Float::ONE.tan_prec_round(0, Floor));
+    assert_panic!(Float::ONE.tan_prec_round_ref(0, Floor));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.tan_prec_round_assign(0, Floor)
+    });
+
+    assert_panic!(Float::TWO.tan_prec_round(1, Exact));
+    assert_panic!(Float::TWO.tan_prec_round_ref(1, Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.tan_prec_round_assign(1, Exact)
+    });
+}
+
+#[test]
+fn tan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.tan_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan),
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        let (tan_alt, o_alt) = (-&x).tan_prec_round(prec, -rm);
+        assert_eq!(ComparableFloat(-tan_alt), ComparableFloat(tan.clone()));
+        assert_eq!(o_alt.reverse(), o);
+
+        if tan.is_normal() {
+            assert_eq!(tan.get_prec(), Some(prec));
+        }
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            _ => {}
+        }
+        if o != Equal {
+            assert_panic!(x.tan_prec_round_ref(prec, Exact));
+        }
+    });
+
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (tan, o) = Float::NAN.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_INFINITY.tan_prec_round(prec, rm);
+        assert!(tan.is_nan());
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::ZERO));
+        assert_eq!(o, Equal);
+        let (tan, o) = Float::NEGATIVE_ZERO.tan_prec_round(prec, rm);
+        assert_eq!(ComparableFloat(tan), ComparableFloat(Float::NEGATIVE_ZERO));
+        assert_eq!(o, Equal);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_prec_properties_helper(x: Float, prec: u64) {
+    let (tan, o) = x.clone().tan_prec(prec);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_prec_assign(prec);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_tan)),
+        ComparableFloatRef(&tan),
+    );
+    assert_eq!(rug_o, o);
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, Nearest);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if tan.is_normal() {
+        assert_eq!(tan.get_prec(), Some(prec));
+    }
+}
+
+#[test]
+fn tan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        tan_prec_properties_helper(x, prec);
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tan_round_properties_helper(x: Float, rm: RoundingMode) {
+    let (tan, o) = x.clone().tan_round(rm);
+    assert!(tan.is_valid());
+
+    let (tan_alt, o_alt) = x.tan_round_ref(rm);
+    assert!(tan_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.tan_round_assign(rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    let (tan_alt, o_alt) = x.tan_prec_round_ref(x.significant_bits(), rm);
+    assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+    assert_eq!(o_alt, o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan),
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+fn tan_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        tan_round_properties_helper(x, rm);
+    });
+}
@@ -13,7 +13,7 @@ use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode::*;
 use malachite_base::strings::ToDebugString;
 use malachite_float::test_util::common::{
-    parse_hex_string, rug_round_exact_from_rounding_mode, to_hex_string,
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
 };
 use malachite_float::test_util::generators::{
     float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_1,
@@ -370,15 +370,9 @@ fn test_set_prec_round() {
         assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
         assert_eq!(o_alt, o);
 
-        if rm != Exact {
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
             let mut rug_x = rug::Float::exact_from(&old_x);
-            assert_eq!(
-                rug_x.set_prec_round(
-                    u32::exact_from(prec),
-                    rug_round_exact_from_rounding_mode(rm)
-                ),
-                o
-            );
+            assert_eq!(rug_x.set_prec_round(u32::exact_from(prec), rm), o);
             assert_eq!(ComparableFloat(x), ComparableFloat(Float::from(&rug_x)));
         }
     };
@@ -458,6 +452,24 @@ fn test_set_prec_round() {
         "0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        NearestTiesAway,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        ToOdd,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "1.0",
         "0x1.0#1",
@@ -512,6 +524,24 @@ fn test_set_prec_round() {
         "0x3.243f6a8885a30000000000000#100",
         Equal,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        NearestTiesAway,
+        "3.141592653589793115997963468544",
+        "0x3.243f6a8885a30000000000000#100",
+        Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        ToOdd,
+        "3.141592653589793115997963468544",
+        "0x3.243f6a8885a30000000000000#100",
+        Equal,
+    );
     test(
         "3.1415926535897931",
         "0x3.243f6a8885a30#53",
@@ -566,6 +596,24 @@ fn test_set_prec_round() {
         "0x3.24#10",
         Less,
     );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        NearestTiesAway,
+        "3.141",
+        "0x3.24#10",
+        Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        ToOdd,
+        "3.145",
+        "0x3.25#10",
+        Greater,
+    );
 
     test(
         "-1.0",
@@ -612,6 +660,24 @@ fn test_set_prec_round() {
         "-0x1.0000000000000000000000000#100",
         Equal,
     );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        NearestTiesAway,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        ToOdd,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Equal,
+    );
     test(
         "-1.0",
         "-0x1.0#1",
@@ -666,6 +732,24 @@ fn test_set_prec_round() {
         "-0x3.243f6a8885a30000000000000#100",
         Equal,
     );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        NearestTiesAway,
+        "-3.141592653589793115997963468544",
+        "-0x3.243f6a8885a30000000000000#100",
+        Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        ToOdd,
+        "-3.141592653589793115997963468544",
+        "-0x3.243f6a8885a30000000000000#100",
+        Equal,
+    );
     test(
         "-3.1415926535897931",
         "-0x3.243f6a8885a30#53",
@@ -720,6 +804,36 @@ fn test_set_prec_round() {
         "-0x3.24#10",
         Greater,
     );
+    test("2.5", "0x2.8#3", 2, Nearest, "2.0", "0x2.0#2", Less);
+    test(
+        "2.5",
+        "0x2.8#3",
+        2,
+        NearestTiesAway,
+        "3.0",
+        "0x3.0#2",
+        Greater,
+    );
+    test("2.5", "0x2.8#3", 2, ToOdd, "3.0", "0x3.0#2", Greater);
+    test(
+        "-2.5",
+        "-0x2.8#3",
+        2,
+        NearestTiesAway,
+        "-3.0",
+        "-0x3.0#2",
+        Less,
+    );
+    test(
+        "3.5",
+        "0x3.8#3",
+        2,
+        NearestTiesAway,
+        "4.0",
+        "0x4.0#2",
+        Greater,
+    );
+    test("3.5", "0x3.8#3", 2, ToOdd, "3.0", "0x3.0#2", Less);
 }
 
 #[test]
@@ -756,15 +870,9 @@ fn set_prec_round_properties() {
 
         if rm == Exact {
             assert_eq!(o, Equal);
-        } else {
+        } else if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
             let mut rug_x = rug::Float::exact_from(&old_x);
-            assert_eq!(
-                rug_x.set_prec_round(
-                    u32::exact_from(prec),
-                    rug_round_exact_from_rounding_mode(rm)
-                ),
-                o
-            );
+            assert_eq!(rug_x.set_prec_round(u32::exact_from(prec), rm), o);
             assert_eq!(
                 ComparableFloatRef(&x),
                 ComparableFloatRef(&Float::from(&rug_x))
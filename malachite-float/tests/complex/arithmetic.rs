@@ -0,0 +1,2805 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::complex::ComplexFloat;
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_exact_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::complex::{
+    complex_float_from_rug_complex, rug_complex_abs_prec_round, rug_complex_add_prec_round,
+    rug_complex_arg_prec_round, rug_complex_div_prec_round, rug_complex_exp_prec_round,
+    rug_complex_from_complex_float, rug_complex_ln_prec_round, rug_complex_mul_prec_round,
+    rug_complex_sqrt_prec_round, rug_complex_sub_prec_round, to_hex_strings,
+};
+use malachite_float::test_util::generators::{complex_float_gen, complex_float_pair_gen};
+use malachite_float::{ComparableFloatRef, Float};
+use rug::float::Round;
+use std::panic::{catch_unwind, RefUnwindSafe};
+
+const PREC_PAIRS: [(u64, u64); 3] = [(1, 10), (20, 20), (100, 64)];
+
+const ROUNDING_MODE_PAIRS: [(RoundingMode, RoundingMode); 4] =
+    [(Floor, Ceiling), (Nearest, Nearest), (Down, Up), (Ceiling, Floor)];
+
+type ComplexResult = (ComplexFloat, Ordering, Ordering);
+
+type RugComplexResult = (rug::Complex, Ordering, Ordering);
+
+fn complex(re: &str, im: &str) -> ComplexFloat {
+    ComplexFloat::new(parse_hex_string(re), parse_hex_string(im))
+}
+
+fn assert_same(x: &ComplexFloat, y: &ComplexFloat) {
+    assert_eq!(ComparableFloatRef(x.re()), ComparableFloatRef(y.re()));
+    assert_eq!(ComparableFloatRef(x.im()), ComparableFloatRef(y.im()));
+}
+
+fn assert_same_ignoring_zero_signs(x: &ComplexFloat, y: &ComplexFloat) {
+    for (x, y) in [(x.re(), y.re()), (x.im(), y.im())] {
+        if *x == 0u32 {
+            assert_eq!(*y, 0u32);
+        } else {
+            assert_eq!(ComparableFloatRef(x), ComparableFloatRef(y));
+        }
+    }
+}
+
+fn assert_valid(x: &ComplexFloat) {
+    assert!(x.re().is_valid());
+    assert!(x.im().is_valid());
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.add_prec(&y, prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        10,
+        10,
+        ("0x4.00#10", "0x6.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x7.00#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x2.00#10", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x1.000#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("Infinity", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x1.000#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("Infinity", "0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "0x2.00#10"),
+        Equal,
+        Equal,
+    );
+}
+
+#[test]
+fn add_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.add_prec(&x, 0, 1));
+    assert_panic!(x.add_prec(&x, 1, 0));
+}
+
+#[test]
+fn test_add_prec_round() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.add_prec_round(&y, prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.c#3", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x4.0#3", "0x6.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x7.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x2.0#3", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x3.8#3", "0x3.0#3"),
+        Greater,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x3.8#3", "0x3.0#3"),
+        Greater,
+        Equal,
+    );
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.sub_prec(&y, prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x1.400#10", "0x4.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        10,
+        10,
+        ("-0x2.00#10", "-0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x3.00#10", "-0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x1.000#10", "-0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.0", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("Infinity", "0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x1.000#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("-Infinity", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "0x0.0"),
+        Equal,
+        Equal,
+    );
+}
+
+#[test]
+fn sub_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.sub_prec(&x, 0, 1));
+    assert_panic!(x.sub_prec(&x, 1, 0));
+}
+
+#[test]
+fn test_sub_prec_round() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.sub_prec_round(&y, prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.4#3", "0x4.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x2.0#3", "-0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x3.0#3", "-0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "-0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-Infinity", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "-0x0.4#1",
+        "0x1.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x3.8#3", "0x3.0#3"),
+        Greater,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "-0x0.4#1",
+        "0x1.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x3.8#3", "0x3.0#3"),
+        Greater,
+        Equal,
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.mul_prec(&y, prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x3.60#10", "-0x0.c00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        10,
+        10,
+        ("-0x5.00#10", "0xa.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0xa.00#10", "0x5.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x2.00#10", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("Infinity", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+}
+
+#[test]
+fn mul_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.mul_prec(&x, 0, 1));
+    assert_panic!(x.mul_prec(&x, 1, 0));
+}
+
+#[test]
+fn test_mul_prec_round() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.mul_prec_round(&y, prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x3.0#3", "-0x0.c#3"),
+        Less,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x5.0#3", "0xa.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0xa.0#3", "0x5.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x2.0#3", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "0x3.0#2",
+        "0x1.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x5.0#3", "0xe.0#3"),
+        Equal,
+        Less,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        "0x3.0#2",
+        "0x1.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x5.0#3", "0x10.0#3"),
+        Equal,
+        Greater,
+    );
+}
+
+#[test]
+fn mul_prec_round_fail() {
+    let x = complex("0x1.8#2", "0x3.0#2");
+    let y = complex("0x0.4#1", "-0x1.0#1");
+    assert_panic!(x.mul_prec_round(&y, 3, 3, Exact, Nearest));
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.div_prec(&y, prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("-0x2.78#10", "0x2.1e#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        10,
+        10,
+        ("0x0.70a#10", "0x0.1478#10"),
+        Less,
+        Less,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x2.00#10", "-0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("-0x0.0", "-0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        10,
+        10,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        10,
+        10,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+}
+
+#[test]
+fn div_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.div_prec(&x, 0, 1));
+    assert_panic!(x.div_prec(&x, 1, 0));
+}
+
+#[test]
+fn test_div_prec_round() {
+    let test = |s, s_im, t, t_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let y = complex(t, t_im);
+        let (result, o_re_alt, o_im_alt) = x.div_prec_round(&y, prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x1.8#2",
+        "0x3.0#2",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x2.8#3", "0x2.8#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x1.0#1",
+        "0x2.0#1",
+        "0x3.0#2",
+        "0x4.0#3",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.7#3", "0x0.18#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x2.0#3", "-0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "-0x0.0",
+        "-0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0", "-0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "0x1.0#1",
+        "0x0.4#1",
+        "-0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x0.0",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x5.0#3",
+        "0x0.0",
+        "0x2.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Exact,
+        Exact,
+        ("0x2.0#3", "-0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x3.0#2",
+        "0x1.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x0.6#3", "0x0.38#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        "0x3.0#2",
+        "0x1.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x0.7#3", "0x0.30#3"),
+        Greater,
+        Less,
+    );
+}
+
+#[test]
+fn div_prec_round_fail() {
+    let x = complex("0x1.0#1", "0x2.0#1");
+    let y = complex("0x3.0#2", "0x4.0#3");
+    assert_panic!(x.div_prec_round(&y, 10, 10, Exact, Nearest));
+    assert_panic!(x.div_prec_round(&y, 10, 10, Nearest, Exact));
+}
+
+#[test]
+fn test_sqrt_prec() {
+    let test = |s, s_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.sqrt_prec(prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        10,
+        10,
+        ("0x2.00#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.748#10", "0x1.198#10"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.0", "0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        10,
+        10,
+        ("0x0.0", "-0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        10,
+        10,
+        ("0x1.000#10", "0x1.000#10"),
+        Equal,
+        Equal,
+    );
+    test("0x0.0", "-0x0.0", 10, 10, ("0x0.0", "-0x0.0"), Equal, Equal);
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x1.198#10", "0x0.748#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        10,
+        10,
+        ("0x1.000#10", "-0x2.00#10"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.b50#10", "0x0.b50#10"),
+        Less,
+        Less,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        10,
+        10,
+        ("Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test("NaN", "0x0.0", 10, 10, ("NaN", "NaN"), Equal, Equal);
+    test(
+        "-Infinity",
+        "NaN",
+        10,
+        10,
+        ("NaN", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test("Infinity", "NaN", 10, 10, ("Infinity", "NaN"), Equal, Equal);
+    test(
+        "0x1.0#1",
+        "Infinity",
+        10,
+        10,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test("NaN", "0x1.0#1", 10, 10, ("NaN", "NaN"), Equal, Equal);
+}
+
+#[test]
+fn sqrt_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.sqrt_prec(0, 1));
+    assert_panic!(x.sqrt_prec(1, 0));
+}
+
+#[test]
+fn test_sqrt_prec_round() {
+    let test = |s, s_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.sqrt_prec_round(prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x2.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.7#3", "0x1.4#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "-0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "0x1.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "0x0.8#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "-0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.a#3", "0x0.c#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        3,
+        3,
+        Exact,
+        Exact,
+        ("0x1.0#3", "-0x2.0#3"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x0.7#3", "0x1.4#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x0.7#3", "0x1.0#3"),
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn sqrt_prec_round_fail() {
+    let x = complex("-0x1.0#1", "0x1.0#1");
+    assert_panic!(x.sqrt_prec_round(10, 10, Exact, Nearest));
+    assert_panic!(x.sqrt_prec_round(10, 10, Nearest, Exact));
+}
+
+#[test]
+fn test_abs_prec() {
+    let test = |s, s_im, prec, out, o| {
+        let x = complex(s, s_im);
+        let (result, o_alt) = x.abs_prec(prec);
+        assert!(result.is_valid());
+        assert_eq!(to_hex_string(&result), out);
+        assert_eq!(o_alt, o);
+    };
+    test("0x3.0#2", "0x4.0#1", 10, "0x5.00#10", Equal);
+    test("-0x1.0#1", "0x1.0#1", 10, "0x1.6a0#10", Less);
+    test("-0x4.0#1", "0x0.0", 10, "0x4.00#10", Equal);
+    test("-0x4.0#1", "-0x0.0", 10, "0x4.00#10", Equal);
+    test("0x0.0", "0x2.0#1", 10, "0x2.00#10", Equal);
+    test("0x0.0", "-0x0.0", 10, "0x0.0", Equal);
+    test("0x1.0#1", "0x1.0#1", 10, "0x1.6a0#10", Less);
+    test("-0x3.0#2", "-0x4.0#1", 10, "0x5.00#10", Equal);
+    test("0x0.0", "0x1.0#1", 10, "0x1.000#10", Equal);
+    test("-Infinity", "0x1.0#1", 10, "Infinity", Equal);
+    test("Infinity", "-0x3.0#2", 10, "Infinity", Equal);
+    test("NaN", "0x0.0", 10, "NaN", Equal);
+    test("-Infinity", "NaN", 10, "Infinity", Equal);
+    test("Infinity", "NaN", 10, "Infinity", Equal);
+    test("0x1.0#1", "Infinity", 10, "Infinity", Equal);
+    test("NaN", "0x1.0#1", 10, "NaN", Equal);
+}
+
+#[test]
+fn abs_prec_fail() {
+    assert_panic!(complex("0x1.0#1", "0x1.0#1").abs_prec(0));
+}
+
+#[test]
+fn test_abs_prec_round() {
+    let test = |s, s_im, prec, rm, out, o| {
+        let x = complex(s, s_im);
+        let (result, o_alt) = x.abs_prec_round(prec, rm);
+        assert!(result.is_valid());
+        assert_eq!(to_hex_string(&result), out);
+        assert_eq!(o_alt, o);
+    };
+    test("0x3.0#2", "0x4.0#1", 3, Floor, "0x5.0#3", Equal);
+    test("-0x1.0#1", "0x1.0#1", 3, Floor, "0x1.4#3", Less);
+    test("-0x4.0#1", "0x0.0", 3, Floor, "0x4.0#3", Equal);
+    test("-0x4.0#1", "-0x0.0", 3, Floor, "0x4.0#3", Equal);
+    test("0x0.0", "0x2.0#1", 3, Floor, "0x2.0#3", Equal);
+    test("0x0.0", "-0x0.0", 3, Floor, "0x0.0", Equal);
+    test("0x1.0#1", "0x1.0#1", 3, Floor, "0x1.4#3", Less);
+    test("-0x3.0#2", "-0x4.0#1", 3, Floor, "0x5.0#3", Equal);
+    test("0x0.0", "0x1.0#1", 3, Floor, "0x1.0#3", Equal);
+    test("-Infinity", "0x1.0#1", 3, Floor, "Infinity", Equal);
+    test("Infinity", "-0x3.0#2", 3, Floor, "Infinity", Equal);
+    test("NaN", "0x0.0", 3, Floor, "NaN", Equal);
+    test("-Infinity", "NaN", 3, Floor, "Infinity", Equal);
+    test("Infinity", "NaN", 3, Floor, "Infinity", Equal);
+    test("0x1.0#1", "Infinity", 3, Floor, "Infinity", Equal);
+    test("NaN", "0x1.0#1", 3, Floor, "NaN", Equal);
+    test("-0x3.0#2", "-0x4.0#1", 3, Exact, "0x5.0#3", Equal);
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        NearestTiesAway,
+        "0x1.8#3",
+        Greater,
+    );
+    test("-0x1.0#1", "0x1.0#1", 3, ToOdd, "0x1.4#3", Less);
+}
+
+#[test]
+fn abs_prec_round_fail() {
+    assert_panic!(complex("0x1.0#1", "0x1.0#1").abs_prec_round(10, Exact));
+}
+
+#[test]
+fn test_arg_prec() {
+    let test = |s, s_im, prec, out, o| {
+        let x = complex(s, s_im);
+        let (result, o_alt) = x.arg_prec(prec);
+        assert!(result.is_valid());
+        assert_eq!(to_hex_string(&result), out);
+        assert_eq!(o_alt, o);
+    };
+    test("0x3.0#2", "0x4.0#1", 10, "0x0.ed8#10", Greater);
+    test("-0x1.0#1", "0x1.0#1", 10, "0x2.5b#10", Less);
+    test("-0x4.0#1", "0x0.0", 10, "0x3.24#10", Less);
+    test("-0x4.0#1", "-0x0.0", 10, "-0x3.24#10", Greater);
+    test("0x0.0", "0x2.0#1", 10, "0x1.920#10", Less);
+    test("0x0.0", "-0x0.0", 10, "-0x0.0", Equal);
+    test("0x1.0#1", "0x1.0#1", 10, "0x0.c90#10", Less);
+    test("-0x3.0#2", "-0x4.0#1", 10, "-0x2.37#10", Less);
+    test("0x0.0", "0x1.0#1", 10, "0x1.920#10", Less);
+    test("-Infinity", "0x1.0#1", 10, "0x3.24#10", Less);
+    test("Infinity", "-0x3.0#2", 10, "-0x0.0", Equal);
+    test("NaN", "0x0.0", 10, "NaN", Equal);
+    test("-Infinity", "NaN", 10, "NaN", Equal);
+    test("Infinity", "NaN", 10, "NaN", Equal);
+    test("0x1.0#1", "Infinity", 10, "0x1.920#10", Less);
+    test("NaN", "0x1.0#1", 10, "NaN", Equal);
+}
+
+#[test]
+fn arg_prec_fail() {
+    assert_panic!(complex("0x1.0#1", "0x1.0#1").arg_prec(0));
+}
+
+#[test]
+fn test_arg_prec_round() {
+    let test = |s, s_im, prec, rm, out, o| {
+        let x = complex(s, s_im);
+        let (result, o_alt) = x.arg_prec_round(prec, rm);
+        assert!(result.is_valid());
+        assert_eq!(to_hex_string(&result), out);
+        assert_eq!(o_alt, o);
+    };
+    test("0x3.0#2", "0x4.0#1", 3, Floor, "0x0.e#3", Less);
+    test("-0x1.0#1", "0x1.0#1", 3, Floor, "0x2.0#3", Less);
+    test("-0x4.0#1", "0x0.0", 3, Floor, "0x3.0#3", Less);
+    test("-0x4.0#1", "-0x0.0", 3, Floor, "-0x3.8#3", Less);
+    test("0x0.0", "0x2.0#1", 3, Floor, "0x1.8#3", Less);
+    test("0x0.0", "-0x0.0", 3, Floor, "-0x0.0", Equal);
+    test("0x1.0#1", "0x1.0#1", 3, Floor, "0x0.c#3", Less);
+    test("-0x3.0#2", "-0x4.0#1", 3, Floor, "-0x2.8#3", Less);
+    test("0x0.0", "0x1.0#1", 3, Floor, "0x1.8#3", Less);
+    test("-Infinity", "0x1.0#1", 3, Floor, "0x3.0#3", Less);
+    test("Infinity", "-0x3.0#2", 3, Floor, "-0x0.0", Equal);
+    test("NaN", "0x0.0", 3, Floor, "NaN", Equal);
+    test("-Infinity", "NaN", 3, Floor, "NaN", Equal);
+    test("Infinity", "NaN", 3, Floor, "NaN", Equal);
+    test("0x1.0#1", "Infinity", 3, Floor, "0x1.8#3", Less);
+    test("NaN", "0x1.0#1", 3, Floor, "NaN", Equal);
+    test("0x3.0#2", "0x4.0#1", 3, NearestTiesAway, "0x0.e#3", Less);
+    test("0x3.0#2", "0x4.0#1", 3, ToOdd, "0x0.e#3", Less);
+}
+
+#[test]
+fn arg_prec_round_fail() {
+    assert_panic!(complex("0x1.0#1", "0x1.0#1").arg_prec_round(10, Exact));
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |s, s_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.exp_prec(prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        10,
+        10,
+        ("-0xd.20#10", "-0xf.34#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.32e#10", "0x0.4f4#10"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        10,
+        10,
+        ("0x0.04b0#10", "0x0.0"),
+        Less,
+        Equal,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        10,
+        10,
+        ("0x0.04b0#10", "-0x0.0"),
+        Less,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        10,
+        10,
+        ("-0x0.6a8#10", "0x0.e8c#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        10,
+        10,
+        ("0x1.000#10", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x1.780#10", "0x2.4a#10"),
+        Greater,
+        Greater,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        10,
+        10,
+        ("-0x0.0854#10", "0x0.09a4#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.8a4#10", "0x0.d78#10"),
+        Less,
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        10,
+        10,
+        ("-Infinity", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test("NaN", "0x0.0", 10, 10, ("NaN", "0x0.0"), Equal, Equal);
+    test("-Infinity", "NaN", 10, 10, ("0x0.0", "0x0.0"), Equal, Equal);
+    test("Infinity", "NaN", 10, 10, ("Infinity", "NaN"), Equal, Equal);
+    test("0x1.0#1", "Infinity", 10, 10, ("NaN", "NaN"), Equal, Equal);
+    test("NaN", "0x1.0#1", 10, 10, ("NaN", "NaN"), Equal, Equal);
+}
+
+#[test]
+fn exp_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.exp_prec(0, 1));
+    assert_panic!(x.exp_prec(1, 0));
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s, s_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.exp_prec_round(prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0xe.0#3", "-0xe.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.30#3", "0x0.5#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.04#3", "0x0.0"),
+        Less,
+        Equal,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.04#3", "-0x0.0"),
+        Less,
+        Equal,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.7#3", "0x1.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.0#3", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.4#3", "0x2.8#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-0x0.0a#3", "0x0.0a#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.8#3", "0x0.e#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-Infinity", "-Infinity"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("-0xe.0#3", "-0xe.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("-0xe.0#3", "-0x10.0#3"),
+        Less,
+        Less,
+    );
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.exp_prec_round(10, 10, Exact, Nearest));
+    assert_panic!(x.exp_prec_round(10, 10, Nearest, Exact));
+}
+
+#[test]
+fn test_ln_prec() {
+    let test = |s, s_im, prec_re, prec_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.ln_prec(prec_re, prec_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        10,
+        10,
+        ("0x1.9c0#10", "0x0.ed8#10"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.58c#10", "0x2.5b#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        10,
+        10,
+        ("0x1.630#10", "0x3.24#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        10,
+        10,
+        ("0x1.630#10", "-0x3.24#10"),
+        Greater,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        10,
+        10,
+        ("0x0.b18#10", "0x1.920#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        10,
+        10,
+        ("-Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.58c#10", "0x0.c90#10"),
+        Greater,
+        Less,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        10,
+        10,
+        ("0x1.9c0#10", "-0x2.37#10"),
+        Less,
+        Less,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        10,
+        10,
+        ("0x0.0", "0x1.920#10"),
+        Equal,
+        Less,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        10,
+        10,
+        ("Infinity", "0x3.24#10"),
+        Equal,
+        Less,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        10,
+        10,
+        ("Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test("NaN", "0x0.0", 10, 10, ("NaN", "NaN"), Equal, Equal);
+    test(
+        "-Infinity",
+        "NaN",
+        10,
+        10,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test("Infinity", "NaN", 10, 10, ("Infinity", "NaN"), Equal, Equal);
+    test(
+        "0x1.0#1",
+        "Infinity",
+        10,
+        10,
+        ("Infinity", "0x1.920#10"),
+        Equal,
+        Less,
+    );
+    test("NaN", "0x1.0#1", 10, 10, ("NaN", "NaN"), Equal, Equal);
+}
+
+#[test]
+fn ln_prec_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.ln_prec(0, 1));
+    assert_panic!(x.ln_prec(1, 0));
+}
+
+#[test]
+fn test_ln_prec_round() {
+    let test = |s, s_im, prec_re, prec_im, rm_re, rm_im, out: (&str, &str), o_re, o_im| {
+        let x = complex(s, s_im);
+        let (result, o_re_alt, o_im_alt) = x.ln_prec_round(prec_re, prec_im, rm_re, rm_im);
+        assert_valid(&result);
+        assert_eq!(
+            to_hex_strings(&result),
+            (out.0.to_string(), out.1.to_string())
+        );
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    };
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.8#3", "0x1.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.5#3", "0x2.8#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.4#3", "0x3.8#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x4.0#1",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.4#3", "-0x3.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "0x2.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.a#3", "0x1.c#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "-0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("-Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.5#3", "0x0.e#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "-0x3.0#2",
+        "-0x4.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x1.8#3", "-0x2.0#3"),
+        Less,
+        Greater,
+    );
+    test(
+        "0x0.0",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("0x0.0", "0x1.c#3"),
+        Equal,
+        Greater,
+    );
+    test(
+        "-Infinity",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "0x3.8#3"),
+        Equal,
+        Greater,
+    );
+    test(
+        "Infinity",
+        "-0x3.0#2",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "-0x0.0"),
+        Equal,
+        Equal,
+    );
+    test(
+        "NaN",
+        "0x0.0",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "-Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "Infinity",
+        "NaN",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x1.0#1",
+        "Infinity",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("Infinity", "0x1.c#3"),
+        Equal,
+        Greater,
+    );
+    test(
+        "NaN",
+        "0x1.0#1",
+        3,
+        3,
+        Floor,
+        Ceiling,
+        ("NaN", "NaN"),
+        Equal,
+        Equal,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        NearestTiesAway,
+        ToOdd,
+        ("0x1.8#3", "0x0.e#3"),
+        Less,
+        Less,
+    );
+    test(
+        "0x3.0#2",
+        "0x4.0#1",
+        3,
+        3,
+        ToOdd,
+        NearestTiesAway,
+        ("0x1.c#3", "0x0.e#3"),
+        Greater,
+        Less,
+    );
+}
+
+#[test]
+fn ln_prec_round_fail() {
+    let x = complex("0x1.0#1", "0x1.0#1");
+    assert_panic!(x.ln_prec_round(10, 10, Exact, Nearest));
+    assert_panic!(x.ln_prec_round(10, 10, Nearest, Exact));
+}
+
+// Compares a result with MPC's, and checks that a part is obtainable with `Exact` if and only if it
+// is exact.
+fn check_result<F: Fn(RoundingMode, RoundingMode) -> ComplexResult + RefUnwindSafe>(
+    f: F,
+    rug_result: RugComplexResult,
+    rm_re: RoundingMode,
+    rm_im: RoundingMode,
+) {
+    let (result, o_re, o_im) = f(rm_re, rm_im);
+    assert_valid(&result);
+    let (rug_result, rug_o_re, rug_o_im) = rug_result;
+    assert_same(&complex_float_from_rug_complex(&rug_result), &result);
+    assert_eq!((rug_o_re, rug_o_im), (o_re, o_im));
+    match rm_re {
+        Floor => assert_ne!(o_re, Greater),
+        Ceiling => assert_ne!(o_re, Less),
+        _ => {}
+    }
+    match rm_im {
+        Floor => assert_ne!(o_im, Greater),
+        Ceiling => assert_ne!(o_im, Less),
+        _ => {}
+    }
+    if o_re == Equal && o_im == Equal {
+        // Exact zeros may have different signs when the rounding mode is `Exact`
+        let (result_alt, o_re_alt, o_im_alt) = f(Exact, Exact);
+        assert_valid(&result_alt);
+        assert_same_ignoring_zero_signs(&result_alt, &result);
+        assert_eq!((o_re_alt, o_im_alt), (Equal, Equal));
+    }
+    if o_re != Equal {
+        assert_panic!(f(Exact, rm_im));
+    }
+    if o_im != Equal {
+        assert_panic!(f(rm_re, Exact));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn binary_properties_helper(
+    x: &ComplexFloat,
+    y: &ComplexFloat,
+    f: fn(&ComplexFloat, &ComplexFloat, u64, u64, RoundingMode, RoundingMode) -> ComplexResult,
+    rug_f: fn(&rug::Complex, &rug::Complex, u64, u64, Round, Round) -> RugComplexResult,
+) {
+    let rug_x = rug_complex_from_complex_float(x);
+    let rug_y = rug_complex_from_complex_float(y);
+    for (prec_re, prec_im) in PREC_PAIRS {
+        for (rm_re, rm_im) in ROUNDING_MODE_PAIRS {
+            check_result(
+                |rm_re, rm_im| f(x, y, prec_re, prec_im, rm_re, rm_im),
+                rug_f(
+                    &rug_x,
+                    &rug_y,
+                    prec_re,
+                    prec_im,
+                    rug_round_exact_from_rounding_mode(rm_re),
+                    rug_round_exact_from_rounding_mode(rm_im),
+                ),
+                rm_re,
+                rm_im,
+            );
+        }
+    }
+}
+
+fn unary_properties_helper(
+    x: &ComplexFloat,
+    f: fn(&ComplexFloat, u64, u64, RoundingMode, RoundingMode) -> ComplexResult,
+    rug_f: fn(&rug::Complex, u64, u64, Round, Round) -> RugComplexResult,
+) {
+    let rug_x = rug_complex_from_complex_float(x);
+    for (prec_re, prec_im) in PREC_PAIRS {
+        for (rm_re, rm_im) in ROUNDING_MODE_PAIRS {
+            check_result(
+                |rm_re, rm_im| f(x, prec_re, prec_im, rm_re, rm_im),
+                rug_f(
+                    &rug_x,
+                    prec_re,
+                    prec_im,
+                    rug_round_exact_from_rounding_mode(rm_re),
+                    rug_round_exact_from_rounding_mode(rm_im),
+                ),
+                rm_re,
+                rm_im,
+            );
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn real_properties_helper(
+    x: &ComplexFloat,
+    f: fn(&ComplexFloat, u64, RoundingMode) -> (Float, Ordering),
+    rug_f: fn(&rug::Complex, u64, Round) -> (rug::Float, Ordering),
+) {
+    let rug_x = rug_complex_from_complex_float(x);
+    for (prec, _) in PREC_PAIRS {
+        for (rm, _) in ROUNDING_MODE_PAIRS {
+            let (result, o) = f(x, prec, rm);
+            assert!(result.is_valid());
+            let (rug_result, rug_o) = rug_f(&rug_x, prec, rug_round_exact_from_rounding_mode(rm));
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+            if o == Equal {
+                let (result_alt, o_alt) = f(x, prec, Exact);
+                assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+                assert_eq!(o_alt, Equal);
+            } else {
+                assert_panic!(f(x, prec, Exact));
+            }
+        }
+    }
+}
+
+#[test]
+fn add_prec_round_properties() {
+    complex_float_pair_gen().test_properties(|(x, y)| {
+        binary_properties_helper(
+            &x,
+            &y,
+            ComplexFloat::add_prec_round,
+            rug_complex_add_prec_round,
+        );
+        let (sum, o_re, o_im) = x.add_prec(&y, 20, 30);
+        let (sum_alt, o_re_alt, o_im_alt) = y.add_prec(&x, 20, 30);
+        assert_same(&sum_alt, &sum);
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    });
+}
+
+#[test]
+fn sub_prec_round_properties() {
+    complex_float_pair_gen().test_properties(|(x, y)| {
+        binary_properties_helper(
+            &x,
+            &y,
+            ComplexFloat::sub_prec_round,
+            rug_complex_sub_prec_round,
+        );
+        let (difference, o_re, o_im) = x.sub_prec_round(&y, 20, 30, Floor, Up);
+        let (sum, o_re_alt, o_im_alt) = x.add_prec_round(&-&y, 20, 30, Floor, Up);
+        assert_same(&sum, &difference);
+        assert_eq!((o_re_alt, o_im_alt), (o_re, o_im));
+    });
+}
+
+#[test]
+fn mul_prec_round_properties() {
+    complex_float_pair_gen().test_properties(|(x, y)| {
+        binary_properties_helper(
+            &x,
+            &y,
+            ComplexFloat::mul_prec_round,
+            rug_complex_mul_prec_round,
+        );
+    });
+}
+
+#[test]
+fn div_prec_round_properties() {
+    complex_float_pair_gen().test_properties(|(x, y)| {
+        binary_properties_helper(
+            &x,
+            &y,
+            ComplexFloat::div_prec_round,
+            rug_complex_div_prec_round,
+        );
+    });
+}
+
+#[test]
+fn sqrt_prec_round_properties() {
+    complex_float_gen().test_properties(|x| {
+        unary_properties_helper(
+            &x,
+            ComplexFloat::sqrt_prec_round,
+            rug_complex_sqrt_prec_round,
+        );
+    });
+}
+
+#[test]
+fn abs_prec_round_properties() {
+    complex_float_gen().test_properties(|x| {
+        real_properties_helper(&x, ComplexFloat::abs_prec_round, rug_complex_abs_prec_round);
+    });
+}
+
+#[test]
+fn arg_prec_round_properties() {
+    complex_float_gen().test_properties(|x| {
+        real_properties_helper(&x, ComplexFloat::arg_prec_round, rug_complex_arg_prec_round);
+    });
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    complex_float_gen().test_properties(|x| {
+        // Avoid exponentials whose exponents can't be represented
+        if x.re().get_exponent().map_or(true, |e| e <= 20) {
+            unary_properties_helper(&x, ComplexFloat::exp_prec_round, rug_complex_exp_prec_round);
+        }
+    });
+}
+
+#[test]
+fn ln_prec_round_properties() {
+    complex_float_gen().test_properties(|x| {
+        unary_properties_helper(&x, ComplexFloat::ln_prec_round, rug_complex_ln_prec_round);
+    });
+}
@@ -0,0 +1,156 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_float::complex::ComplexFloat;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::complex::to_hex_strings;
+use malachite_float::test_util::generators::{complex_float_gen, float_pair_gen};
+use malachite_float::ComparableFloatRef;
+
+fn complex(re: &str, im: &str) -> ComplexFloat {
+    ComplexFloat::new(parse_hex_string(re), parse_hex_string(im))
+}
+
+#[test]
+fn test_new() {
+    let test = |s, t| {
+        let x = complex(s, t);
+        assert_eq!(to_hex_strings(&x), (s.to_string(), t.to_string()));
+        let (re, im) = x.into_parts();
+        assert_eq!(
+            (to_hex_string(&re), to_hex_string(&im)),
+            (s.to_string(), t.to_string())
+        );
+    };
+    test("0x1.8#2", "-0x2.0#1");
+    test("-0x0.0", "0x0.0");
+    test("NaN", "Infinity");
+    test("-Infinity", "0x1.0#1");
+}
+
+#[test]
+fn test_is_finite_is_infinite_is_zero() {
+    let test = |s, t, finite, infinite, zero| {
+        let x = complex(s, t);
+        assert_eq!(x.is_finite(), finite);
+        assert_eq!(x.is_infinite(), infinite);
+        assert_eq!(x.is_zero(), zero);
+    };
+    test("0x1.8#2", "-0x2.0#1", true, false, false);
+    test("-0x0.0", "0x0.0", true, false, true);
+    test("0x0.0", "0x1.0#1", true, false, false);
+    test("NaN", "0x0.0", false, false, false);
+    test("NaN", "Infinity", false, true, false);
+    test("-Infinity", "0x1.0#1", false, true, false);
+}
+
+#[test]
+fn test_from_float() {
+    let test = |s, out: (&str, &str)| {
+        let x = ComplexFloat::from(parse_hex_string(s));
+        assert_eq!(to_hex_strings(&x), (out.0.to_string(), out.1.to_string()));
+    };
+    test("0x1.8#2", ("0x1.8#2", "0x0.0"));
+    test("-0x0.0", ("-0x0.0", "0x0.0"));
+    test("NaN", ("NaN", "0x0.0"));
+}
+
+#[test]
+fn test_eq() {
+    let test = |s, t, u, v, out| {
+        assert_eq!(complex(s, t) == complex(u, v), out);
+    };
+    test("0x1.8#2", "-0x2.0#1", "0x1.8#2", "-0x2.0#1", true);
+    test("0x1.8#2", "-0x2.0#1", "0x1.800#10", "-0x2.00#10", true);
+    test("0x0.0", "0x0.0", "-0x0.0", "-0x0.0", true);
+    test("0x1.8#2", "-0x2.0#1", "0x1.8#2", "0x2.0#1", false);
+    test("NaN", "0x1.0#1", "NaN", "0x1.0#1", false);
+}
+
+#[test]
+fn test_to_string() {
+    let test = |s, t, out| {
+        let x = complex(s, t);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+    };
+    test("0x1.8#2", "-0x2.0#1", "(1.5, -2.0)");
+    test("-0x0.0", "0x0.0", "(-0.0, 0.0)");
+    test("NaN", "-Infinity", "(NaN, -Infinity)");
+}
+
+#[test]
+fn test_neg() {
+    let test = |s, t, out: (&str, &str)| {
+        let x = complex(s, t);
+        let neg = -x.clone();
+        assert_eq!(to_hex_strings(&neg), (out.0.to_string(), out.1.to_string()));
+        assert_eq!(to_hex_strings(&-&x), (out.0.to_string(), out.1.to_string()));
+    };
+    test("0x1.8#2", "-0x2.0#1", ("-0x1.8#2", "0x2.0#1"));
+    test("-0x0.0", "0x0.0", ("0x0.0", "-0x0.0"));
+    test("NaN", "Infinity", ("NaN", "-Infinity"));
+}
+
+#[test]
+fn test_conj() {
+    let test = |s, t, out: (&str, &str)| {
+        assert_eq!(
+            to_hex_strings(&complex(s, t).conj()),
+            (out.0.to_string(), out.1.to_string())
+        );
+    };
+    test("0x1.8#2", "-0x2.0#1", ("0x1.8#2", "0x2.0#1"));
+    test("-0x0.0", "0x0.0", ("-0x0.0", "-0x0.0"));
+    test("NaN", "Infinity", ("NaN", "-Infinity"));
+}
+
+#[test]
+fn new_properties() {
+    float_pair_gen().test_properties(|(re, im)| {
+        let x = ComplexFloat::new(re.clone(), im.clone());
+        assert_eq!(ComparableFloatRef(x.re()), ComparableFloatRef(&re));
+        assert_eq!(ComparableFloatRef(x.im()), ComparableFloatRef(&im));
+        assert_eq!(x.is_finite(), re.is_finite() && im.is_finite());
+        assert_eq!(x.is_infinite(), re.is_infinite() || im.is_infinite());
+        assert_eq!(x.is_zero(), re == 0u32 && im == 0u32);
+        assert_eq!(x == x.clone(), !re.is_nan() && !im.is_nan());
+    });
+}
+
+#[test]
+fn neg_and_conj_properties() {
+    complex_float_gen().test_properties(|x| {
+        let neg = -x.clone();
+        let neg_alt = -&x;
+        assert_eq!(
+            ComparableFloatRef(neg.re()),
+            ComparableFloatRef(neg_alt.re())
+        );
+        assert_eq!(
+            ComparableFloatRef(neg.im()),
+            ComparableFloatRef(neg_alt.im())
+        );
+        let neg_neg = -neg;
+        assert_eq!(ComparableFloatRef(neg_neg.re()), ComparableFloatRef(x.re()));
+        assert_eq!(ComparableFloatRef(neg_neg.im()), ComparableFloatRef(x.im()));
+
+        let conj = x.conj();
+        assert_eq!(ComparableFloatRef(conj.re()), ComparableFloatRef(x.re()));
+        assert_eq!(ComparableFloatRef(conj.im()), ComparableFloatRef(&-x.im()));
+        let conj_conj = conj.conj();
+        assert_eq!(
+            ComparableFloatRef(conj_conj.re()),
+            ComparableFloatRef(x.re())
+        );
+        assert_eq!(
+            ComparableFloatRef(conj_conj.im()),
+            ComparableFloatRef(x.im())
+        );
+    });
+}
@@ -0,0 +1,280 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::catalan::rug_catalan_prec_round;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use rug::float::Round;
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_catalan_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::catalan_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (rug_x, rug_o) = rug_catalan_prec_round(prec, Round::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_catalan_prec() {
+    test_catalan_prec_helper(1, "1.0", "0x1.0#1", Greater);
+    test_catalan_prec_helper(2, "1.0", "0x1.0#2", Greater);
+    test_catalan_prec_helper(3, "0.9", "0x0.e#3", Less);
+    test_catalan_prec_helper(4, "0.94", "0x0.f#4", Greater);
+    test_catalan_prec_helper(5, "0.91", "0x0.e8#5", Less);
+    test_catalan_prec_helper(6, "0.92", "0x0.ec#6", Greater);
+    test_catalan_prec_helper(7, "0.914", "0x0.ea#7", Less);
+    test_catalan_prec_helper(8, "0.914", "0x0.ea#8", Less);
+    test_catalan_prec_helper(9, "0.916", "0x0.ea8#9", Greater);
+    test_catalan_prec_helper(10, "0.916", "0x0.ea8#10", Greater);
+    test_catalan_prec_helper(
+        100,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Greater,
+    );
+    test_catalan_prec_helper(
+        1000,
+        "0.915965594177219015054603514932384110774149374281672134266498119621763019776254769479356\
+        512926115106248574422619196199579035898803325859059431594737481158406995332028773319460519\
+        038727478164087865909024706484152163000228727640942388259957741508816397470252482011560707\
+        644883807873370489900864775113226",
+        "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924\
+        d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a\
+        17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af91a#1000",
+        Greater,
+    );
+}
+
+fn test_catalan_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::catalan_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_catalan_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_catalan_prec_round() {
+    test_catalan_prec_round_helper(1, Floor, "0.5", "0x0.8#1", Less);
+    test_catalan_prec_round_helper(1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test_catalan_prec_round_helper(1, Down, "0.5", "0x0.8#1", Less);
+    test_catalan_prec_round_helper(1, Up, "1.0", "0x1.0#1", Greater);
+    test_catalan_prec_round_helper(1, Nearest, "1.0", "0x1.0#1", Greater);
+
+    test_catalan_prec_round_helper(2, Floor, "0.8", "0x0.c#2", Less);
+    test_catalan_prec_round_helper(2, Ceiling, "1.0", "0x1.0#2", Greater);
+    test_catalan_prec_round_helper(2, Down, "0.8", "0x0.c#2", Less);
+    test_catalan_prec_round_helper(2, Up, "1.0", "0x1.0#2", Greater);
+    test_catalan_prec_round_helper(2, Nearest, "1.0", "0x1.0#2", Greater);
+
+    test_catalan_prec_round_helper(3, Floor, "0.9", "0x0.e#3", Less);
+    test_catalan_prec_round_helper(3, Ceiling, "1.0", "0x1.0#3", Greater);
+    test_catalan_prec_round_helper(3, Down, "0.9", "0x0.e#3", Less);
+    test_catalan_prec_round_helper(3, Up, "1.0", "0x1.0#3", Greater);
+    test_catalan_prec_round_helper(3, Nearest, "0.9", "0x0.e#3", Less);
+
+    test_catalan_prec_round_helper(4, Floor, "0.88", "0x0.e#4", Less);
+    test_catalan_prec_round_helper(4, Ceiling, "0.94", "0x0.f#4", Greater);
+    test_catalan_prec_round_helper(4, Down, "0.88", "0x0.e#4", Less);
+    test_catalan_prec_round_helper(4, Up, "0.94", "0x0.f#4", Greater);
+    test_catalan_prec_round_helper(4, Nearest, "0.94", "0x0.f#4", Greater);
+
+    test_catalan_prec_round_helper(5, Floor, "0.91", "0x0.e8#5", Less);
+    test_catalan_prec_round_helper(5, Ceiling, "0.94", "0x0.f0#5", Greater);
+    test_catalan_prec_round_helper(5, Down, "0.91", "0x0.e8#5", Less);
+    test_catalan_prec_round_helper(5, Up, "0.94", "0x0.f0#5", Greater);
+    test_catalan_prec_round_helper(5, Nearest, "0.91", "0x0.e8#5", Less);
+
+    test_catalan_prec_round_helper(6, Floor, "0.91", "0x0.e8#6", Less);
+    test_catalan_prec_round_helper(6, Ceiling, "0.92", "0x0.ec#6", Greater);
+    test_catalan_prec_round_helper(6, Down, "0.91", "0x0.e8#6", Less);
+    test_catalan_prec_round_helper(6, Up, "0.92", "0x0.ec#6", Greater);
+    test_catalan_prec_round_helper(6, Nearest, "0.92", "0x0.ec#6", Greater);
+
+    test_catalan_prec_round_helper(7, Floor, "0.914", "0x0.ea#7", Less);
+    test_catalan_prec_round_helper(7, Ceiling, "0.92", "0x0.ec#7", Greater);
+    test_catalan_prec_round_helper(7, Down, "0.914", "0x0.ea#7", Less);
+    test_catalan_prec_round_helper(7, Up, "0.92", "0x0.ec#7", Greater);
+    test_catalan_prec_round_helper(7, Nearest, "0.914", "0x0.ea#7", Less);
+
+    test_catalan_prec_round_helper(8, Floor, "0.914", "0x0.ea#8", Less);
+    test_catalan_prec_round_helper(8, Ceiling, "0.918", "0x0.eb#8", Greater);
+    test_catalan_prec_round_helper(8, Down, "0.914", "0x0.ea#8", Less);
+    test_catalan_prec_round_helper(8, Up, "0.918", "0x0.eb#8", Greater);
+    test_catalan_prec_round_helper(8, Nearest, "0.914", "0x0.ea#8", Less);
+
+    test_catalan_prec_round_helper(9, Floor, "0.914", "0x0.ea0#9", Less);
+    test_catalan_prec_round_helper(9, Ceiling, "0.916", "0x0.ea8#9", Greater);
+    test_catalan_prec_round_helper(9, Down, "0.914", "0x0.ea0#9", Less);
+    test_catalan_prec_round_helper(9, Up, "0.916", "0x0.ea8#9", Greater);
+    test_catalan_prec_round_helper(9, Nearest, "0.916", "0x0.ea8#9", Greater);
+
+    test_catalan_prec_round_helper(10, Floor, "0.915", "0x0.ea4#10", Less);
+    test_catalan_prec_round_helper(10, Ceiling, "0.916", "0x0.ea8#10", Greater);
+    test_catalan_prec_round_helper(10, Down, "0.915", "0x0.ea4#10", Less);
+    test_catalan_prec_round_helper(10, Up, "0.916", "0x0.ea8#10", Greater);
+    test_catalan_prec_round_helper(10, Nearest, "0.916", "0x0.ea8#10", Greater);
+
+    test_catalan_prec_round_helper(
+        100,
+        Floor,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Less,
+    );
+    test_catalan_prec_round_helper(
+        100,
+        Ceiling,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Greater,
+    );
+    test_catalan_prec_round_helper(
+        100,
+        Down,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Less,
+    );
+    test_catalan_prec_round_helper(
+        100,
+        Up,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Greater,
+    );
+    test_catalan_prec_round_helper(
+        100,
+        Nearest,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Greater,
+    );
+    test_catalan_prec_round_helper(10, NearestTiesAway, "0.916", "0x0.ea8#10", Greater);
+    test_catalan_prec_round_helper(10, ToOdd, "0.915", "0x0.ea4#10", Less);
+}
+
+#[test]
+#[should_panic]
+fn catalan_prec_round_fail_1() {
+    Float::catalan_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn catalan_prec_round_fail_2() {
+    Float::catalan_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn catalan_prec_round_fail_3() {
+    Float::catalan_prec_round(1000, Exact);
+}
+
+#[test]
+fn catalan_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (g, o) = Float::catalan_prec(prec);
+        assert!(g.is_valid());
+        assert_eq!(g.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (g_alt, o_alt) = Float::catalan_prec_round(prec, Ceiling);
+            let mut next_upper = g.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(g_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !g.is_power_of_2() {
+            let (g_alt, o_alt) = Float::catalan_prec_round(prec, Floor);
+            let mut next_lower = g.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(g_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (g_alt, o_alt) = Float::catalan_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&g_alt), ComparableFloatRef(&g));
+        assert_eq!(o_alt, o);
+
+        let (rug_g, rug_o) = rug_catalan_prec_round(prec, Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_g)),
+            ComparableFloatRef(&g)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn catalan_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (g, o) = Float::catalan_prec_round(prec, rm);
+        assert!(g.is_valid());
+        assert_eq!(g.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (g_alt, o_alt) = Float::catalan_prec_round(prec, Ceiling);
+            let mut next_upper = g.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(g_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !g.is_power_of_2() {
+            let (g_alt, o_alt) = Float::catalan_prec_round(prec, Floor);
+            let mut next_lower = g.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(g_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_g, rug_o) = rug_catalan_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_g)),
+                ComparableFloatRef(&g)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::catalan_prec_round(prec, Exact));
+    });
+}
@@ -0,0 +1,280 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::euler_gamma::rug_euler_gamma_prec_round;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use rug::float::Round;
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_euler_gamma_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::euler_gamma_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (rug_x, rug_o) = rug_euler_gamma_prec_round(prec, Round::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_euler_gamma_prec() {
+    test_euler_gamma_prec_helper(1, "0.5", "0x0.8#1", Less);
+    test_euler_gamma_prec_helper(2, "0.5", "0x0.8#2", Less);
+    test_euler_gamma_prec_helper(3, "0.6", "0x0.a#3", Greater);
+    test_euler_gamma_prec_helper(4, "0.56", "0x0.9#4", Less);
+    test_euler_gamma_prec_helper(5, "0.56", "0x0.90#5", Less);
+    test_euler_gamma_prec_helper(6, "0.58", "0x0.94#6", Greater);
+    test_euler_gamma_prec_helper(7, "0.58", "0x0.94#7", Greater);
+    test_euler_gamma_prec_helper(8, "0.578", "0x0.94#8", Greater);
+    test_euler_gamma_prec_helper(9, "0.578", "0x0.940#9", Greater);
+    test_euler_gamma_prec_helper(10, "0.577", "0x0.93c#10", Less);
+    test_euler_gamma_prec_helper(
+        100,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Less,
+    );
+    test_euler_gamma_prec_helper(
+        1000,
+        "0.577215664901532860606512090082402431042159335939923598805767234884867726777664670936947\
+        063291746749514631447249807082480960504014486542836224173997644923536253500333742937337737\
+        673942792595258247094916008735203948165670853233151776611528621199501507984793745085705740\
+        029921354786146694029604325421519",
+        "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649\
+        ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaa\
+        e0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3ce#1000",
+        Less,
+    );
+}
+
+fn test_euler_gamma_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::euler_gamma_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_euler_gamma_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_euler_gamma_prec_round() {
+    test_euler_gamma_prec_round_helper(1, Floor, "0.5", "0x0.8#1", Less);
+    test_euler_gamma_prec_round_helper(1, Ceiling, "1.0", "0x1.0#1", Greater);
+    test_euler_gamma_prec_round_helper(1, Down, "0.5", "0x0.8#1", Less);
+    test_euler_gamma_prec_round_helper(1, Up, "1.0", "0x1.0#1", Greater);
+    test_euler_gamma_prec_round_helper(1, Nearest, "0.5", "0x0.8#1", Less);
+
+    test_euler_gamma_prec_round_helper(2, Floor, "0.5", "0x0.8#2", Less);
+    test_euler_gamma_prec_round_helper(2, Ceiling, "0.8", "0x0.c#2", Greater);
+    test_euler_gamma_prec_round_helper(2, Down, "0.5", "0x0.8#2", Less);
+    test_euler_gamma_prec_round_helper(2, Up, "0.8", "0x0.c#2", Greater);
+    test_euler_gamma_prec_round_helper(2, Nearest, "0.5", "0x0.8#2", Less);
+
+    test_euler_gamma_prec_round_helper(3, Floor, "0.5", "0x0.8#3", Less);
+    test_euler_gamma_prec_round_helper(3, Ceiling, "0.6", "0x0.a#3", Greater);
+    test_euler_gamma_prec_round_helper(3, Down, "0.5", "0x0.8#3", Less);
+    test_euler_gamma_prec_round_helper(3, Up, "0.6", "0x0.a#3", Greater);
+    test_euler_gamma_prec_round_helper(3, Nearest, "0.6", "0x0.a#3", Greater);
+
+    test_euler_gamma_prec_round_helper(4, Floor, "0.56", "0x0.9#4", Less);
+    test_euler_gamma_prec_round_helper(4, Ceiling, "0.62", "0x0.a#4", Greater);
+    test_euler_gamma_prec_round_helper(4, Down, "0.56", "0x0.9#4", Less);
+    test_euler_gamma_prec_round_helper(4, Up, "0.62", "0x0.a#4", Greater);
+    test_euler_gamma_prec_round_helper(4, Nearest, "0.56", "0x0.9#4", Less);
+
+    test_euler_gamma_prec_round_helper(5, Floor, "0.56", "0x0.90#5", Less);
+    test_euler_gamma_prec_round_helper(5, Ceiling, "0.59", "0x0.98#5", Greater);
+    test_euler_gamma_prec_round_helper(5, Down, "0.56", "0x0.90#5", Less);
+    test_euler_gamma_prec_round_helper(5, Up, "0.59", "0x0.98#5", Greater);
+    test_euler_gamma_prec_round_helper(5, Nearest, "0.56", "0x0.90#5", Less);
+
+    test_euler_gamma_prec_round_helper(6, Floor, "0.56", "0x0.90#6", Less);
+    test_euler_gamma_prec_round_helper(6, Ceiling, "0.58", "0x0.94#6", Greater);
+    test_euler_gamma_prec_round_helper(6, Down, "0.56", "0x0.90#6", Less);
+    test_euler_gamma_prec_round_helper(6, Up, "0.58", "0x0.94#6", Greater);
+    test_euler_gamma_prec_round_helper(6, Nearest, "0.58", "0x0.94#6", Greater);
+
+    test_euler_gamma_prec_round_helper(7, Floor, "0.57", "0x0.92#7", Less);
+    test_euler_gamma_prec_round_helper(7, Ceiling, "0.58", "0x0.94#7", Greater);
+    test_euler_gamma_prec_round_helper(7, Down, "0.57", "0x0.92#7", Less);
+    test_euler_gamma_prec_round_helper(7, Up, "0.58", "0x0.94#7", Greater);
+    test_euler_gamma_prec_round_helper(7, Nearest, "0.58", "0x0.94#7", Greater);
+
+    test_euler_gamma_prec_round_helper(8, Floor, "0.574", "0x0.93#8", Less);
+    test_euler_gamma_prec_round_helper(8, Ceiling, "0.578", "0x0.94#8", Greater);
+    test_euler_gamma_prec_round_helper(8, Down, "0.574", "0x0.93#8", Less);
+    test_euler_gamma_prec_round_helper(8, Up, "0.578", "0x0.94#8", Greater);
+    test_euler_gamma_prec_round_helper(8, Nearest, "0.578", "0x0.94#8", Greater);
+
+    test_euler_gamma_prec_round_helper(9, Floor, "0.576", "0x0.938#9", Less);
+    test_euler_gamma_prec_round_helper(9, Ceiling, "0.578", "0x0.940#9", Greater);
+    test_euler_gamma_prec_round_helper(9, Down, "0.576", "0x0.938#9", Less);
+    test_euler_gamma_prec_round_helper(9, Up, "0.578", "0x0.940#9", Greater);
+    test_euler_gamma_prec_round_helper(9, Nearest, "0.578", "0x0.940#9", Greater);
+
+    test_euler_gamma_prec_round_helper(10, Floor, "0.577", "0x0.93c#10", Less);
+    test_euler_gamma_prec_round_helper(10, Ceiling, "0.578", "0x0.940#10", Greater);
+    test_euler_gamma_prec_round_helper(10, Down, "0.577", "0x0.93c#10", Less);
+    test_euler_gamma_prec_round_helper(10, Up, "0.578", "0x0.940#10", Greater);
+    test_euler_gamma_prec_round_helper(10, Nearest, "0.577", "0x0.93c#10", Less);
+
+    test_euler_gamma_prec_round_helper(
+        100,
+        Floor,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Less,
+    );
+    test_euler_gamma_prec_round_helper(
+        100,
+        Ceiling,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Greater,
+    );
+    test_euler_gamma_prec_round_helper(
+        100,
+        Down,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Less,
+    );
+    test_euler_gamma_prec_round_helper(
+        100,
+        Up,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Greater,
+    );
+    test_euler_gamma_prec_round_helper(
+        100,
+        Nearest,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Less,
+    );
+    test_euler_gamma_prec_round_helper(10, NearestTiesAway, "0.577", "0x0.93c#10", Less);
+    test_euler_gamma_prec_round_helper(10, ToOdd, "0.577", "0x0.93c#10", Less);
+}
+
+#[test]
+#[should_panic]
+fn euler_gamma_prec_round_fail_1() {
+    Float::euler_gamma_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn euler_gamma_prec_round_fail_2() {
+    Float::euler_gamma_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn euler_gamma_prec_round_fail_3() {
+    Float::euler_gamma_prec_round(1000, Exact);
+}
+
+#[test]
+fn euler_gamma_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (gamma, o) = Float::euler_gamma_prec(prec);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, Ceiling);
+            let mut next_upper = gamma.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(gamma_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !gamma.is_power_of_2() {
+            let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, Floor);
+            let mut next_lower = gamma.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(gamma_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&gamma_alt), ComparableFloatRef(&gamma));
+        assert_eq!(o_alt, o);
+
+        let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_gamma)),
+            ComparableFloatRef(&gamma)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn euler_gamma_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (gamma, o) = Float::euler_gamma_prec_round(prec, rm);
+        assert!(gamma.is_valid());
+        assert_eq!(gamma.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, Ceiling);
+            let mut next_upper = gamma.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(gamma_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !gamma.is_power_of_2() {
+            let (gamma_alt, o_alt) = Float::euler_gamma_prec_round(prec, Floor);
+            let mut next_lower = gamma.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(gamma_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_gamma, rug_o) = rug_euler_gamma_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_gamma)),
+                ComparableFloatRef(&gamma)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::euler_gamma_prec_round(prec, Exact));
+    });
+}
@@ -252,6 +252,8 @@ pub fn test_prime_constant_prec_round() {
         "0x0.6a28a20a08a208282282208088#100",
         Less,
     );
+    test_prime_constant_prec_round_helper(10, NearestTiesAway, "0.4146", "0x0.6a2#10", Less);
+    test_prime_constant_prec_round_helper(10, ToOdd, "0.4146", "0x0.6a2#10", Less);
 }
 
 #[test]
@@ -0,0 +1,286 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_11, unsigned_rounding_mode_pair_gen_var_4,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::sqrt_2::rug_sqrt_2_prec_round;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use rug::float::Round;
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+fn test_sqrt_2_prec_helper(prec: u64, out: &str, out_hex: &str, out_o: Ordering) {
+    let (x, o) = Float::sqrt_2_prec(prec);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    let (rug_x, rug_o) = rug_sqrt_2_prec_round(prec, Round::Nearest);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+pub fn test_sqrt_2_prec() {
+    test_sqrt_2_prec_helper(1, "1.0", "0x1.0#1", Less);
+    test_sqrt_2_prec_helper(2, "1.5", "0x1.8#2", Greater);
+    test_sqrt_2_prec_helper(3, "1.5", "0x1.8#3", Greater);
+    test_sqrt_2_prec_helper(4, "1.4", "0x1.6#4", Less);
+    test_sqrt_2_prec_helper(5, "1.44", "0x1.7#5", Greater);
+    test_sqrt_2_prec_helper(6, "1.41", "0x1.68#6", Less);
+    test_sqrt_2_prec_helper(7, "1.42", "0x1.6c#7", Greater);
+    test_sqrt_2_prec_helper(8, "1.414", "0x1.6a#8", Less);
+    test_sqrt_2_prec_helper(9, "1.414", "0x1.6a#9", Less);
+    test_sqrt_2_prec_helper(10, "1.414", "0x1.6a0#10", Less);
+    test_sqrt_2_prec_helper(
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test_sqrt_2_prec_helper(
+        1000,
+        "1.414213562373095048801688724209698078569671875376948073176679737990732478462107038850387\
+        534327641572735013846230912297024924836055850737212644121497099935831413222665927505592755\
+        799950501152782060571470109559971605970274534596862014728517418640889198609552329230484308\
+        7143214508397626036279952514079896",
+        "0x1.6a09e667f3bcc908b2fb1366ea957d3e3adec17512775099da2f590b0667322a95f90608757145875163f\
+        cdfb907b6721ee950bc8738f694f0090e6c7bf44ed1a4405d0e855e3e9ca60b38c0237866f7956379222d108b1\
+        48c1578e45ef89c678dab5147176fd3b99654c68663e7909bea5e241f06dcb05dd549411320#1000",
+        Less,
+    );
+
+    let r2_f32 = Float::sqrt_2_prec(u64::from(f32::MANTISSA_DIGITS)).0;
+    assert_eq!(r2_f32, core::f32::consts::SQRT_2);
+
+    let r2_f64 = Float::sqrt_2_prec(u64::from(f64::MANTISSA_DIGITS)).0;
+    assert_eq!(r2_f64, core::f64::consts::SQRT_2);
+}
+
+fn test_sqrt_2_prec_round_helper(
+    prec: u64,
+    rm: RoundingMode,
+    out: &str,
+    out_hex: &str,
+    out_o: Ordering,
+) {
+    let (x, o) = Float::sqrt_2_prec_round(prec, rm);
+    assert!(x.is_valid());
+    assert_eq!(x.to_string(), out);
+    assert_eq!(to_hex_string(&x), out_hex);
+    assert_eq!(o, out_o);
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_x, rug_o) = rug_sqrt_2_prec_round(prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    }
+}
+
+#[test]
+pub fn test_sqrt_2_prec_round() {
+    test_sqrt_2_prec_round_helper(1, Floor, "1.0", "0x1.0#1", Less);
+    test_sqrt_2_prec_round_helper(1, Ceiling, "2.0", "0x2.0#1", Greater);
+    test_sqrt_2_prec_round_helper(1, Down, "1.0", "0x1.0#1", Less);
+    test_sqrt_2_prec_round_helper(1, Up, "2.0", "0x2.0#1", Greater);
+    test_sqrt_2_prec_round_helper(1, Nearest, "1.0", "0x1.0#1", Less);
+
+    test_sqrt_2_prec_round_helper(2, Floor, "1.0", "0x1.0#2", Less);
+    test_sqrt_2_prec_round_helper(2, Ceiling, "1.5", "0x1.8#2", Greater);
+    test_sqrt_2_prec_round_helper(2, Down, "1.0", "0x1.0#2", Less);
+    test_sqrt_2_prec_round_helper(2, Up, "1.5", "0x1.8#2", Greater);
+    test_sqrt_2_prec_round_helper(2, Nearest, "1.5", "0x1.8#2", Greater);
+
+    test_sqrt_2_prec_round_helper(3, Floor, "1.2", "0x1.4#3", Less);
+    test_sqrt_2_prec_round_helper(3, Ceiling, "1.5", "0x1.8#3", Greater);
+    test_sqrt_2_prec_round_helper(3, Down, "1.2", "0x1.4#3", Less);
+    test_sqrt_2_prec_round_helper(3, Up, "1.5", "0x1.8#3", Greater);
+    test_sqrt_2_prec_round_helper(3, Nearest, "1.5", "0x1.8#3", Greater);
+
+    test_sqrt_2_prec_round_helper(4, Floor, "1.4", "0x1.6#4", Less);
+    test_sqrt_2_prec_round_helper(4, Ceiling, "1.5", "0x1.8#4", Greater);
+    test_sqrt_2_prec_round_helper(4, Down, "1.4", "0x1.6#4", Less);
+    test_sqrt_2_prec_round_helper(4, Up, "1.5", "0x1.8#4", Greater);
+    test_sqrt_2_prec_round_helper(4, Nearest, "1.4", "0x1.6#4", Less);
+
+    test_sqrt_2_prec_round_helper(5, Floor, "1.38", "0x1.6#5", Less);
+    test_sqrt_2_prec_round_helper(5, Ceiling, "1.44", "0x1.7#5", Greater);
+    test_sqrt_2_prec_round_helper(5, Down, "1.38", "0x1.6#5", Less);
+    test_sqrt_2_prec_round_helper(5, Up, "1.44", "0x1.7#5", Greater);
+    test_sqrt_2_prec_round_helper(5, Nearest, "1.44", "0x1.7#5", Greater);
+
+    test_sqrt_2_prec_round_helper(6, Floor, "1.41", "0x1.68#6", Less);
+    test_sqrt_2_prec_round_helper(6, Ceiling, "1.44", "0x1.70#6", Greater);
+    test_sqrt_2_prec_round_helper(6, Down, "1.41", "0x1.68#6", Less);
+    test_sqrt_2_prec_round_helper(6, Up, "1.44", "0x1.70#6", Greater);
+    test_sqrt_2_prec_round_helper(6, Nearest, "1.41", "0x1.68#6", Less);
+
+    test_sqrt_2_prec_round_helper(7, Floor, "1.41", "0x1.68#7", Less);
+    test_sqrt_2_prec_round_helper(7, Ceiling, "1.42", "0x1.6c#7", Greater);
+    test_sqrt_2_prec_round_helper(7, Down, "1.41", "0x1.68#7", Less);
+    test_sqrt_2_prec_round_helper(7, Up, "1.42", "0x1.6c#7", Greater);
+    test_sqrt_2_prec_round_helper(7, Nearest, "1.42", "0x1.6c#7", Greater);
+
+    test_sqrt_2_prec_round_helper(8, Floor, "1.414", "0x1.6a#8", Less);
+    test_sqrt_2_prec_round_helper(8, Ceiling, "1.42", "0x1.6c#8", Greater);
+    test_sqrt_2_prec_round_helper(8, Down, "1.414", "0x1.6a#8", Less);
+    test_sqrt_2_prec_round_helper(8, Up, "1.42", "0x1.6c#8", Greater);
+    test_sqrt_2_prec_round_helper(8, Nearest, "1.414", "0x1.6a#8", Less);
+
+    test_sqrt_2_prec_round_helper(9, Floor, "1.414", "0x1.6a#9", Less);
+    test_sqrt_2_prec_round_helper(9, Ceiling, "1.418", "0x1.6b#9", Greater);
+    test_sqrt_2_prec_round_helper(9, Down, "1.414", "0x1.6a#9", Less);
+    test_sqrt_2_prec_round_helper(9, Up, "1.418", "0x1.6b#9", Greater);
+    test_sqrt_2_prec_round_helper(9, Nearest, "1.414", "0x1.6a#9", Less);
+
+    test_sqrt_2_prec_round_helper(10, Floor, "1.414", "0x1.6a0#10", Less);
+    test_sqrt_2_prec_round_helper(10, Ceiling, "1.416", "0x1.6a8#10", Greater);
+    test_sqrt_2_prec_round_helper(10, Down, "1.414", "0x1.6a0#10", Less);
+    test_sqrt_2_prec_round_helper(10, Up, "1.416", "0x1.6a8#10", Greater);
+    test_sqrt_2_prec_round_helper(10, Nearest, "1.414", "0x1.6a0#10", Less);
+
+    test_sqrt_2_prec_round_helper(
+        100,
+        Floor,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test_sqrt_2_prec_round_helper(
+        100,
+        Ceiling,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Greater,
+    );
+    test_sqrt_2_prec_round_helper(
+        100,
+        Down,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test_sqrt_2_prec_round_helper(
+        100,
+        Up,
+        "1.414213562373095048801688724211",
+        "0x1.6a09e667f3bcc908b2fb13670#100",
+        Greater,
+    );
+    test_sqrt_2_prec_round_helper(
+        100,
+        Nearest,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Less,
+    );
+    test_sqrt_2_prec_round_helper(10, NearestTiesAway, "1.414", "0x1.6a0#10", Less);
+    test_sqrt_2_prec_round_helper(10, ToOdd, "1.416", "0x1.6a8#10", Greater);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_2_prec_round_fail_1() {
+    Float::sqrt_2_prec_round(0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_2_prec_round_fail_2() {
+    Float::sqrt_2_prec_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn sqrt_2_prec_round_fail_3() {
+    Float::sqrt_2_prec_round(1000, Exact);
+}
+
+#[test]
+fn sqrt_2_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (r2, o) = Float::sqrt_2_prec(prec);
+        assert!(r2.is_valid());
+        assert_eq!(r2.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (r2_alt, o_alt) = Float::sqrt_2_prec_round(prec, Ceiling);
+            let mut next_upper = r2.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(r2_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !r2.is_power_of_2() {
+            let (r2_alt, o_alt) = Float::sqrt_2_prec_round(prec, Floor);
+            let mut next_lower = r2.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(r2_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+        let (r2_alt, o_alt) = Float::sqrt_2_prec_round(prec, Nearest);
+        assert_eq!(ComparableFloatRef(&r2_alt), ComparableFloatRef(&r2));
+        assert_eq!(o_alt, o);
+
+        let (rug_r2, rug_o) = rug_sqrt_2_prec_round(prec, Round::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_r2)),
+            ComparableFloatRef(&r2)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn sqrt_2_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_4().test_properties(|(prec, rm)| {
+        let (r2, o) = Float::sqrt_2_prec_round(prec, rm);
+        assert!(r2.is_valid());
+        assert_eq!(r2.get_prec(), Some(prec));
+        assert_ne!(o, Equal);
+        if o == Less {
+            let (r2_alt, o_alt) = Float::sqrt_2_prec_round(prec, Ceiling);
+            let mut next_upper = r2.clone();
+            next_upper.increment();
+            if !next_upper.is_power_of_2() {
+                assert_eq!(ComparableFloat(r2_alt), ComparableFloat(next_upper));
+                assert_eq!(o_alt, Greater);
+            }
+        } else if !r2.is_power_of_2() {
+            let (r2_alt, o_alt) = Float::sqrt_2_prec_round(prec, Floor);
+            let mut next_lower = r2.clone();
+            next_lower.decrement();
+            assert_eq!(ComparableFloat(r2_alt), ComparableFloat(next_lower));
+            assert_eq!(o_alt, Less);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_r2, rug_o) = rug_sqrt_2_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_r2)),
+                ComparableFloatRef(&r2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::sqrt_2_prec_round(prec, Exact));
+    });
+}
@@ -252,6 +252,14 @@ pub fn test_thue_morse_constant_prec_round() {
         "0x0.69969669966969969669699668#100",
         Less,
     );
+    test_thue_morse_constant_prec_round_helper(
+        10,
+        NearestTiesAway,
+        "0.4126",
+        "0x0.69a#10",
+        Greater,
+    );
+    test_thue_morse_constant_prec_round_helper(10, ToOdd, "0.4126", "0x0.69a#10", Greater);
 }
 
 #[test]
@@ -0,0 +1,261 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_base::test_util::generators::{
+    primitive_float_gen, primitive_float_pair_gen, primitive_float_triple_gen,
+};
+use malachite_float::context::{Context, Flags};
+use malachite_float::conversion::ieee_bits::IeeeFormat;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::Float;
+use malachite_q::Rational;
+
+fn check_result<T: PrimitiveFloat + RoundingFrom<Float>>(
+    ctx: &Context,
+    result: Float,
+    o: Ordering,
+    expected: T,
+    exact: Option<Rational>,
+) where
+    Rational: TryFrom<T>,
+{
+    assert!(result.is_valid());
+    let flags = ctx.get_flags();
+    assert_eq!(flags.inexact, o != Equal);
+    assert!(!flags.overflow || o != Equal);
+    assert!(!flags.underflow || o != Equal);
+    if let Some(exact) = exact {
+        if let Ok(q) = Rational::try_from(expected) {
+            assert_eq!(q.cmp(&exact), o);
+        }
+    }
+    let (actual, o_convert) = T::rounding_from(result, Exact);
+    assert_eq!(o_convert, Equal);
+    assert_eq!(NiceFloat(actual), NiceFloat(expected));
+}
+
+fn exact_value<T: PrimitiveFloat>(x: T) -> Option<Rational>
+where
+    Rational: TryFrom<T>,
+{
+    Rational::try_from(x).ok()
+}
+
+#[test]
+fn test_from_float_prec_round() {
+    let test = |ctx: &Context, s: &str, prec: u64, out: &str, o_out: Ordering, flags: Flags| {
+        let mut ctx = ctx.clone();
+        let (x, o) = ctx.from_float_prec_round(&parse_hex_string(s), prec, Nearest);
+        assert!(x.is_valid());
+        assert_eq!(to_hex_string(&x), out);
+        assert_eq!(o, o_out);
+        assert_eq!(ctx.get_flags(), flags);
+    };
+    let ctx = Context::from_ieee_format(IeeeFormat::new(2, 2));
+    let underflow = Flags {
+        inexact: true,
+        underflow: true,
+        ..Flags::default()
+    };
+    let overflow = Flags {
+        inexact: true,
+        overflow: true,
+        ..Flags::default()
+    };
+    test(&ctx, "0x1.0#1", 3, "0x1.0#3", Equal, Flags::default());
+    test(&ctx, "0x3.c#4", 3, "Infinity", Greater, overflow);
+    test(&ctx, "0x0.50#4", 3, "0x0.4#3", Less, underflow);
+    test(&ctx, "0x0.0", 3, "0x0.0", Equal, Flags::default());
+}
+
+#[test]
+fn test_special_flags() {
+    let mut ctx = Context::from_ieee_format(IeeeFormat::BINARY32);
+
+    let (x, o) = ctx.add_prec_round(&Float::INFINITY, &Float::NEGATIVE_INFINITY, 24, Nearest);
+    assert!(x.is_nan());
+    assert_eq!(o, Equal);
+    assert_eq!(
+        ctx.take_flags(),
+        Flags {
+            invalid: true,
+            ..Flags::default()
+        }
+    );
+
+    let (x, _) = ctx.sub_prec_round(&Float::INFINITY, &Float::INFINITY, 24, Nearest);
+    assert!(x.is_nan());
+    assert!(ctx.take_flags().invalid);
+
+    let (x, _) = ctx.mul_prec_round(&Float::ZERO, &Float::INFINITY, 24, Nearest);
+    assert!(x.is_nan());
+    assert!(ctx.take_flags().invalid);
+
+    let (x, _) = ctx.div_prec_round(&Float::ZERO, &Float::ZERO, 24, Nearest);
+    assert!(x.is_nan());
+    let flags = ctx.take_flags();
+    assert!(flags.invalid);
+    assert!(!flags.divide_by_zero);
+
+    let (x, _) = ctx.sqrt_prec_round(&Float::from(-2), 24, Nearest);
+    assert!(x.is_nan());
+    assert!(ctx.take_flags().invalid);
+
+    let (x, o) = ctx.div_prec_round(&Float::from(-1), &Float::ZERO, 24, Nearest);
+    assert_eq!(x, Float::NEGATIVE_INFINITY);
+    assert_eq!(o, Equal);
+    assert_eq!(
+        ctx.take_flags(),
+        Flags {
+            divide_by_zero: true,
+            ..Flags::default()
+        }
+    );
+
+    let (x, _) = ctx.div_prec_round(&Float::INFINITY, &Float::ZERO, 24, Nearest);
+    assert_eq!(x, Float::INFINITY);
+    assert_eq!(ctx.take_flags(), Flags::default());
+
+    let (x, _) = ctx.add_prec_round(&Float::NAN, &Float::ONE, 24, Nearest);
+    assert!(x.is_nan());
+    assert_eq!(ctx.take_flags(), Flags::default());
+
+    let (x, _) = ctx.mul_add_prec_round(&Float::ZERO, &Float::INFINITY, &Float::ONE, 24, Nearest);
+    assert!(x.is_nan());
+    assert!(ctx.take_flags().invalid);
+
+    let (x, _) = ctx.mul_add_prec_round(&Float::ZERO, &Float::INFINITY, &Float::NAN, 24, Nearest);
+    assert!(x.is_nan());
+    assert_eq!(ctx.take_flags(), Flags::default());
+}
+
+#[test]
+fn test_nearest_ties_away_and_to_odd() {
+    let test = |ctx: &mut Context, (x, o): (Float, Ordering), out: &str, o_out: Ordering, flags| {
+        assert!(x.is_valid());
+        assert_eq!(to_hex_string(&x), out);
+        assert_eq!(o, o_out);
+        assert_eq!(ctx.take_flags(), flags);
+    };
+    let inexact = Flags {
+        inexact: true,
+        ..Flags::default()
+    };
+    let overflow = Flags {
+        inexact: true,
+        overflow: true,
+        ..Flags::default()
+    };
+    let mut ctx = Context::new(-10, 10);
+    let one = parse_hex_string("0x1.0#1");
+    let two = parse_hex_string("0x2.0#1");
+    let three = parse_hex_string("0x3.0#2");
+    let eighth = parse_hex_string("0x0.2#1");
+    let big = parse_hex_string("0x3e8.0#10");
+    for rm in [NearestTiesAway, ToOdd] {
+        let x = ctx.from_float_prec_round(&parse_hex_string("0x1.2#4"), 3, rm);
+        test(&mut ctx, x, "0x1.4#3", Greater, inexact);
+        let x = ctx.add_prec_round(&one, &eighth, 3, rm);
+        test(&mut ctx, x, "0x1.4#3", Greater, inexact);
+        let x = ctx.sub_prec_round(&one, &-&eighth, 3, rm);
+        test(&mut ctx, x, "0x1.4#3", Greater, inexact);
+        let x = ctx.mul_prec_round(&three, &three, 3, rm);
+        test(&mut ctx, x, "0xa.0#3", Greater, inexact);
+        let x = ctx.div_prec_round(&one, &three, 3, rm);
+        test(&mut ctx, x, "0x0.5#3", Less, inexact);
+        let x = ctx.mul_add_prec_round(&two, &parse_hex_string("0x4.0#1"), &one, 3, rm);
+        test(&mut ctx, x, "0xa.0#3", Greater, inexact);
+    }
+
+    let x = ctx.sqrt_prec_round(&two, 3, NearestTiesAway);
+    test(&mut ctx, x, "0x1.8#3", Greater, inexact);
+    let x = ctx.sqrt_prec_round(&two, 3, ToOdd);
+    test(&mut ctx, x, "0x1.4#3", Less, inexact);
+
+    let x = ctx.add_prec_round(&big, &big, 3, NearestTiesAway);
+    test(&mut ctx, x, "Infinity", Greater, overflow);
+    let x = ctx.add_prec_round(&big, &big, 3, ToOdd);
+    test(&mut ctx, x, "0x3.8E+2#3", Less, overflow);
+}
+
+#[test]
+fn binary32_arithmetic_properties() {
+    let prec = f32::MANTISSA_WIDTH + 1;
+    let ctx = Context::from_ieee_format(IeeeFormat::BINARY32);
+    primitive_float_pair_gen::<f32>().test_properties(|(x, y)| {
+        let fx = Float::from(x);
+        let fy = Float::from(y);
+        let (qx, qy) = (exact_value(x), exact_value(y));
+
+        let mut c = ctx.clone();
+        let (sum, o) = c.add_prec_round(&fx, &fy, prec, Nearest);
+        let exact = qx.clone().zip(qy.clone()).map(|(qx, qy)| qx + qy);
+        check_result(&c, sum, o, x + y, exact);
+
+        let mut c = ctx.clone();
+        let (difference, o) = c.sub_prec_round(&fx, &fy, prec, Nearest);
+        let exact = qx.clone().zip(qy.clone()).map(|(qx, qy)| qx - qy);
+        check_result(&c, difference, o, x - y, exact);
+
+        let mut c = ctx.clone();
+        let (product, o) = c.mul_prec_round(&fx, &fy, prec, Nearest);
+        let exact = qx.clone().zip(qy.clone()).map(|(qx, qy)| qx * qy);
+        check_result(&c, product, o, x * y, exact);
+
+        let mut c = ctx.clone();
+        let (quotient, o) = c.div_prec_round(&fx, &fy, prec, Nearest);
+        let exact = qx
+            .zip(qy)
+            .and_then(|(qx, qy)| if qy == 0u32 { None } else { Some(qx / qy) });
+        check_result(&c, quotient, o, x / y, exact);
+        assert_eq!(
+            c.get_flags().divide_by_zero,
+            y == 0.0 && x.is_finite() && x != 0.0
+        );
+    });
+}
+
+#[test]
+fn binary64_arithmetic_properties() {
+    let prec = f64::MANTISSA_WIDTH + 1;
+    let ctx = Context::from_ieee_format(IeeeFormat::BINARY64);
+    primitive_float_gen::<f64>().test_properties(|x| {
+        let mut c = ctx.clone();
+        let (root, o) = c.sqrt_prec_round(&Float::from(x), prec, Nearest);
+        check_result(&c, root, o, x.sqrt(), None);
+        assert_eq!(c.get_flags().invalid, x < 0.0);
+
+        let mut c = ctx.clone();
+        let (y, o) = c.from_float_prec_round(&Float::from(x), prec, Nearest);
+        assert_eq!(o, Equal);
+        assert_eq!(c.get_flags(), Flags::default());
+        check_result(&c, y, o, x, None);
+    });
+
+    primitive_float_triple_gen::<f64>().test_properties(|(x, y, z)| {
+        let mut c = ctx.clone();
+        let (result, o) = c.mul_add_prec_round(
+            &Float::from(x),
+            &Float::from(y),
+            &Float::from(z),
+            prec,
+            Nearest,
+        );
+        let exact = exact_value(x)
+            .zip(exact_value(y))
+            .zip(exact_value(z))
+            .map(|((qx, qy), qz)| qx * qy + qz);
+        check_result(&c, result, o, x.mul_add(y, z), exact);
+    });
+}
@@ -0,0 +1,281 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::cmp::Ordering::{self, *};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::context::{Context, Flags};
+use malachite_float::conversion::ieee_bits::IeeeFormat;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_new() {
+    let ctx = Context::new(-10, 20);
+    assert_eq!(ctx.get_emin(), -10);
+    assert_eq!(ctx.get_emax(), 20);
+    assert!(!ctx.get_subnormals());
+    assert_eq!(ctx.get_flags(), Flags::default());
+
+    let ctx = Context::new(5, 5);
+    assert_eq!(ctx.get_emin(), 5);
+    assert_eq!(ctx.get_emax(), 5);
+
+    let ctx = Context::default();
+    assert_eq!(ctx.get_emin(), i32::MIN);
+    assert_eq!(ctx.get_emax(), i32::MAX);
+    assert!(!ctx.get_subnormals());
+}
+
+#[test]
+fn new_fail() {
+    assert_panic!(Context::new(1, 0));
+}
+
+#[test]
+fn test_from_ieee_format() {
+    let test = |format: IeeeFormat, emin: i32, emax: i32| {
+        let ctx = Context::from_ieee_format(format);
+        assert_eq!(ctx.get_emin(), emin);
+        assert_eq!(ctx.get_emax(), emax);
+        assert!(ctx.get_subnormals());
+        assert_eq!(ctx.get_flags(), Flags::default());
+    };
+    test(IeeeFormat::new(2, 2), -1, 2);
+    test(IeeeFormat::BINARY16, -23, 16);
+    test(IeeeFormat::BFLOAT16, -132, 128);
+    test(IeeeFormat::BINARY32, -148, 128);
+    test(IeeeFormat::BINARY64, -1073, 1024);
+    test(IeeeFormat::BINARY128, -16493, 16384);
+}
+
+#[test]
+fn test_setters() {
+    let mut ctx = Context::new(-10, 10);
+    ctx.set_emin(-20);
+    assert_eq!(ctx.get_emin(), -20);
+    ctx.set_emax(20);
+    assert_eq!(ctx.get_emax(), 20);
+    ctx.set_emin(20);
+    assert_eq!(ctx.get_emin(), 20);
+    ctx.set_subnormals(true);
+    assert!(ctx.get_subnormals());
+    ctx.set_subnormals(false);
+    assert!(!ctx.get_subnormals());
+}
+
+#[test]
+fn set_emin_fail() {
+    assert_panic!({
+        let mut ctx = Context::new(-10, 10);
+        ctx.set_emin(11);
+    });
+}
+
+#[test]
+fn set_emax_fail() {
+    assert_panic!({
+        let mut ctx = Context::new(-10, 10);
+        ctx.set_emax(-11);
+    });
+}
+
+#[test]
+fn test_check_range() {
+    let test = |ctx: &Context,
+                s_hex: &str,
+                o_in: Ordering,
+                rm: RoundingMode,
+                out_hex: &str,
+                o_out: Ordering,
+                flags_out: Flags| {
+        let mut ctx = ctx.clone();
+        let (x, o) = ctx.check_range(parse_hex_string(s_hex), o_in, rm);
+        assert!(x.is_valid());
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+        assert_eq!(ctx.get_flags(), flags_out);
+    };
+    let none = Flags::default();
+    let inexact = Flags {
+        inexact: true,
+        ..Flags::default()
+    };
+    let overflow = Flags {
+        inexact: true,
+        overflow: true,
+        ..Flags::default()
+    };
+    let underflow = Flags {
+        inexact: true,
+        underflow: true,
+        ..Flags::default()
+    };
+
+    // Values are in range when they are at least 2^-3 and less than 4.
+    let ctx = Context::new(-2, 2);
+    test(&ctx, "NaN", Equal, Nearest, "NaN", Equal, none);
+    test(&ctx, "Infinity", Equal, Nearest, "Infinity", Equal, none);
+    test(&ctx, "-0x0.0", Equal, Nearest, "-0x0.0", Equal, none);
+    test(&ctx, "0x3.8#3", Equal, Nearest, "0x3.8#3", Equal, none);
+    test(&ctx, "0x3.8#3", Less, Nearest, "0x3.8#3", Less, inexact);
+    test(&ctx, "0x0.2#1", Greater, Floor, "0x0.2#1", Greater, inexact);
+
+    test(
+        &ctx, "0x4.0#1", Equal, Nearest, "Infinity", Greater, overflow,
+    );
+    test(&ctx, "0x4.0#1", Less, Up, "Infinity", Greater, overflow);
+    test(&ctx, "0x4.0#1", Equal, Floor, "0x2.0#1", Less, overflow);
+    test(&ctx, "0x4.0#3", Equal, Down, "0x3.8#3", Less, overflow);
+    test(&ctx, "0x4.0#3", Equal, ToOdd, "0x3.8#3", Less, overflow);
+    test(&ctx, "-0x4.0#3", Equal, Floor, "-Infinity", Less, overflow);
+    test(
+        &ctx, "-0x4.0#3", Equal, Ceiling, "-0x3.8#3", Greater, overflow,
+    );
+    test(
+        &ctx,
+        "-0x4.0#3",
+        Equal,
+        Nearest,
+        "-Infinity",
+        Less,
+        overflow,
+    );
+
+    test(&ctx, "0x0.1#1", Equal, Nearest, "0x0.0", Less, underflow);
+    test(
+        &ctx, "0x0.1#1", Less, Nearest, "0x0.2#1", Greater, underflow,
+    );
+    test(&ctx, "0x0.1#1", Greater, Nearest, "0x0.0", Less, underflow);
+    test(
+        &ctx,
+        "0x0.1#1",
+        Equal,
+        NearestTiesAway,
+        "0x0.2#1",
+        Greater,
+        underflow,
+    );
+    test(
+        &ctx, "0x0.18#2", Equal, Nearest, "0x0.2#2", Greater, underflow,
+    );
+    test(&ctx, "0x0.08#1", Equal, Nearest, "0x0.0", Less, underflow);
+    test(&ctx, "0x0.08#1", Equal, Up, "0x0.2#1", Greater, underflow);
+    test(
+        &ctx, "0x0.08#1", Equal, ToOdd, "0x0.2#1", Greater, underflow,
+    );
+    test(&ctx, "0x0.18#2", Equal, Down, "0x0.0", Less, underflow);
+    test(
+        &ctx,
+        "-0x0.18#2",
+        Equal,
+        Nearest,
+        "-0x0.2#2",
+        Less,
+        underflow,
+    );
+    test(
+        &ctx,
+        "-0x0.18#2",
+        Equal,
+        Ceiling,
+        "-0x0.0",
+        Greater,
+        underflow,
+    );
+    test(
+        &ctx,
+        "-0x0.08#1",
+        Equal,
+        Nearest,
+        "-0x0.0",
+        Greater,
+        underflow,
+    );
+
+    // A format with 3 bits of precision, whose largest value is 3.5 and whose smallest normal
+    // and subnormal values are 1 and 0.25.
+    let ctx = Context::from_ieee_format(IeeeFormat::new(2, 2));
+    test(&ctx, "0x1.0#3", Equal, Nearest, "0x1.0#3", Equal, none);
+    test(&ctx, "0x3.8#3", Equal, Nearest, "0x3.8#3", Equal, none);
+    test(&ctx, "0x0.c#3", Equal, Nearest, "0x0.c#3", Equal, none);
+    test(&ctx, "0x0.4#3", Equal, Nearest, "0x0.4#3", Equal, none);
+    test(
+        &ctx, "0x0.e#3", Less, Nearest, "0x1.0#3", Greater, underflow,
+    );
+    test(
+        &ctx, "0x0.e#3", Equal, Nearest, "0x1.0#3", Greater, underflow,
+    );
+    test(&ctx, "0x0.a#3", Equal, Nearest, "0x0.8#3", Less, underflow);
+    test(
+        &ctx, "0x0.a#3", Less, Nearest, "0x0.c#3", Greater, underflow,
+    );
+    test(
+        &ctx, "0x0.a#3", Greater, Nearest, "0x0.8#3", Less, underflow,
+    );
+    test(
+        &ctx,
+        "0x0.a#3",
+        Equal,
+        NearestTiesAway,
+        "0x0.c#3",
+        Greater,
+        underflow,
+    );
+    test(&ctx, "0x0.a#3", Equal, Floor, "0x0.8#3", Less, underflow);
+    test(
+        &ctx, "0x0.a#3", Equal, Ceiling, "0x0.c#3", Greater, underflow,
+    );
+    test(&ctx, "0x0.a#3", Less, Floor, "0x0.8#3", Less, underflow);
+    test(&ctx, "0x0.6#3", Equal, Floor, "0x0.4#3", Less, underflow);
+    test(&ctx, "-0x0.6#3", Equal, Floor, "-0x0.8#3", Less, underflow);
+    test(
+        &ctx, "-0x0.a#3", Equal, Nearest, "-0x0.8#3", Greater, underflow,
+    );
+    test(&ctx, "0x0.20#3", Equal, Nearest, "0x0.0", Less, underflow);
+    test(
+        &ctx, "0x0.30#3", Equal, Nearest, "0x0.4#3", Greater, underflow,
+    );
+    test(
+        &ctx, "0x4.0#3", Equal, Nearest, "Infinity", Greater, overflow,
+    );
+    test(&ctx, "0x3.c#4", Equal, Nearest, "0x3.c#4", Equal, none);
+}
+
+#[test]
+fn check_range_fail() {
+    assert_panic!(Context::new(-2, 2).check_range(parse_hex_string("0x4.0#1"), Equal, Exact));
+    assert_panic!(Context::new(-2, 2).check_range(parse_hex_string("0x0.08#1"), Equal, Exact));
+    assert_panic!(
+        Context::from_ieee_format(IeeeFormat::new(2, 2)).check_range(
+            parse_hex_string("0x0.a#3"),
+            Equal,
+            Exact
+        )
+    );
+}
+
+#[test]
+fn test_flags() {
+    let mut ctx = Context::new(-2, 2);
+    ctx.check_range(parse_hex_string("0x4.0#1"), Equal, Nearest);
+    ctx.check_range(parse_hex_string("0x0.08#1"), Equal, Nearest);
+    ctx.check_range(parse_hex_string("0x1.0#1"), Equal, Nearest);
+    let flags = Flags {
+        inexact: true,
+        underflow: true,
+        overflow: true,
+        ..Flags::default()
+    };
+    assert_eq!(ctx.get_flags(), flags);
+    assert_eq!(ctx.take_flags(), flags);
+    assert_eq!(ctx.get_flags(), Flags::default());
+
+    ctx.check_range(parse_hex_string("0x4.0#1"), Equal, Nearest);
+    ctx.clear_flags();
+    assert_eq!(ctx.get_flags(), Flags::default());
+}
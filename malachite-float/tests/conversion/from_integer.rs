@@ -428,6 +428,8 @@ fn test_from_integer_prec_round() {
         "-0xe.8d4aE+9#20",
         Greater,
     );
+    test("-9", 3, NearestTiesAway, "-10.0", "-0xa.0#3", Less);
+    test("-9", 3, ToOdd, "-10.0", "-0xa.0#3", Less);
 }
 
 #[test]
@@ -338,6 +338,8 @@ fn test_from_natural_prec_round() {
         "0x2.3a88E+18#15",
         Less,
     );
+    test("9", 3, NearestTiesAway, "10.0", "0xa.0#3", Greater);
+    test("9", 3, ToOdd, "10.0", "0xa.0#3", Greater);
 }
 
 #[test]
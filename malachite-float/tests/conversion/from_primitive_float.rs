@@ -1265,6 +1265,8 @@ fn test_from_primitive_float_prec_round() {
         "-0x1.000E+256#10",
         Less,
     );
+    test_helper(9.0f64, 3, NearestTiesAway, "10.0", "0xa.0#3", Greater);
+    test_helper(9.0f64, 3, ToOdd, "10.0", "0xa.0#3", Greater);
 }
 
 fn from_primitive_float_prec_round_fail_helper<T: PrimitiveFloat>()
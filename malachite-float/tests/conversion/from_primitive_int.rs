@@ -761,6 +761,10 @@ fn test_from_primitive_int_prec_round() {
         "-0xe.8d4aE+9#20",
         Greater,
     );
+    test_helper_u(9u8, 3, NearestTiesAway, "10.0", "0xa.0#3", Greater);
+    test_helper_u(9u8, 3, ToOdd, "10.0", "0xa.0#3", Greater);
+    test_helper_i(-9i8, 3, NearestTiesAway, "-10.0", "-0xa.0#3", Less);
+    test_helper_i(-9i8, 3, ToOdd, "-10.0", "-0xa.0#3", Less);
 }
 
 fn from_unsigned_prec_round_fail_helper<T: PrimitiveUnsigned>()
@@ -655,6 +655,8 @@ fn test_from_rational_prec_round() {
         "-0x3.2492492492492492492492494#100",
         Less,
     );
+    test("9/8", 3, NearestTiesAway, "1.2", "0x1.4#3", Greater);
+    test("9/8", 3, ToOdd, "1.2", "0x1.4#3", Greater);
 }
 
 #[test]
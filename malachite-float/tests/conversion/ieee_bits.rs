@@ -0,0 +1,491 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_float::conversion::ieee_bits::IeeeFormat;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{
+    float_rounding_mode_pair_gen, float_rounding_mode_pair_gen_var_6,
+};
+use malachite_float::{ComparableFloat, Float};
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering::{self, *};
+
+const FORMATS: [IeeeFormat; 6] = [
+    IeeeFormat::BINARY16,
+    IeeeFormat::BFLOAT16,
+    IeeeFormat::BINARY32,
+    IeeeFormat::BINARY64,
+    IeeeFormat::BINARY128,
+    IeeeFormat::new(4, 3),
+];
+
+#[test]
+fn test_ieee_format() {
+    let test = |format: IeeeFormat, precision: u64, width: u64, max_exponent: i64| {
+        assert_eq!(format.precision(), precision);
+        assert_eq!(format.width(), width);
+        assert_eq!(format.max_exponent(), max_exponent);
+        assert_eq!(format.min_exponent(), 1 - max_exponent);
+        assert_eq!(
+            IeeeFormat::new(format.exponent_bits(), format.significand_bits()),
+            format
+        );
+    };
+    test(IeeeFormat::BINARY16, 11, 16, 15);
+    test(IeeeFormat::BFLOAT16, 8, 16, 127);
+    test(IeeeFormat::BINARY32, 24, 32, 127);
+    test(IeeeFormat::BINARY64, 53, 64, 1023);
+    test(IeeeFormat::BINARY128, 113, 128, 16383);
+    test(IeeeFormat::new(4, 3), 4, 8, 7);
+    test(IeeeFormat::new(2, 1), 2, 4, 1);
+    test(
+        IeeeFormat::new(30, 1 << 30),
+        (1 << 30) + 1,
+        (1 << 30) + 31,
+        (1 << 29) - 1,
+    );
+}
+
+#[test]
+#[should_panic]
+fn ieee_format_new_fail_1() {
+    IeeeFormat::new(1, 10);
+}
+
+#[test]
+#[should_panic]
+fn ieee_format_new_fail_2() {
+    IeeeFormat::new(31, 10);
+}
+
+#[test]
+#[should_panic]
+fn ieee_format_new_fail_3() {
+    IeeeFormat::new(5, 0);
+}
+
+#[test]
+fn test_to_ieee_bits_round() {
+    let test = |s, s_hex, format: IeeeFormat, rm: RoundingMode, out: u128, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (bits, o) = x.to_ieee_bits_round(format, rm);
+        assert_eq!(bits, out);
+        assert_eq!(o, o_out);
+        if rm == Nearest {
+            assert_eq!(x.to_ieee_bits(format), (bits, o));
+        }
+    };
+    let half = IeeeFormat::BINARY16;
+    test("NaN", "NaN", half, Exact, 0x7e00, Equal);
+    test("Infinity", "Infinity", half, Exact, 0x7c00, Equal);
+    test("-Infinity", "-Infinity", half, Exact, 0xfc00, Equal);
+    test("0.0", "0x0.0", half, Exact, 0, Equal);
+    test("-0.0", "-0x0.0", half, Exact, 0x8000, Equal);
+    test("1.0", "0x1.0#1", half, Exact, 0x3c00, Equal);
+    test("-1.0", "-0x1.0#1", half, Exact, 0xbc00, Equal);
+    test("-2.0", "-0x2.0#1", half, Exact, 0xc000, Equal);
+    test("6.55e4", "0xf.feE+3#11", half, Exact, 0x7bff, Equal);
+    test("0.00006", "0x0.0004#1", half, Exact, 0x0400, Equal);
+    test("6.0e-8", "0x1.0E-6#1", half, Exact, 1, Equal);
+    test("-6.0e-8", "-0x1.0E-6#1", half, Exact, 0x8001, Equal);
+    test("0.00006098", "0x0.0003ff#10", half, Exact, 0x03ff, Equal);
+
+    // 1/3
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        Floor,
+        0x3555,
+        Less,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        Ceiling,
+        0x3556,
+        Greater,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        Down,
+        0x3555,
+        Less,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        Up,
+        0x3556,
+        Greater,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        Nearest,
+        0x3555,
+        Less,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        NearestTiesAway,
+        0x3555,
+        Less,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        half,
+        ToOdd,
+        0x3555,
+        Less,
+    );
+    test(
+        "-0.33333333333333333326",
+        "-0x0.5555555555555554#63",
+        half,
+        Floor,
+        0xb556,
+        Less,
+    );
+    test(
+        "-0.33333333333333333326",
+        "-0x0.5555555555555554#63",
+        half,
+        Ceiling,
+        0xb555,
+        Greater,
+    );
+    test(
+        "-0.33333333333333333326",
+        "-0x0.5555555555555554#63",
+        half,
+        Nearest,
+        0xb555,
+        Greater,
+    );
+
+    // A tie between 1 and 1 + 2^-10
+    test("1.0005", "0x1.002#12", half, Nearest, 0x3c00, Less);
+    test(
+        "1.0005",
+        "0x1.002#12",
+        half,
+        NearestTiesAway,
+        0x3c01,
+        Greater,
+    );
+    test("1.0005", "0x1.002#12", half, ToOdd, 0x3c01, Greater);
+    // A tie between 1 + 2^-10 and 1 + 2^-9
+    test("1.0015", "0x1.006#12", half, Nearest, 0x3c02, Greater);
+    test(
+        "1.0015",
+        "0x1.006#12",
+        half,
+        NearestTiesAway,
+        0x3c02,
+        Greater,
+    );
+    test("1.0015", "0x1.006#12", half, ToOdd, 0x3c01, Less);
+
+    // Rounding a subnormal up to the smallest normal
+    test("0.00006101", "0x0.0003ff8#11", half, Up, 0x0400, Greater);
+    test("0.00006101", "0x0.0003ff8#11", half, Down, 0x03ff, Less);
+    test(
+        "0.00006101",
+        "0x0.0003ff8#11",
+        half,
+        Nearest,
+        0x0400,
+        Greater,
+    );
+    // Values below the smallest subnormal
+    test("3.0e-8", "0x8.0E-7#1", half, Nearest, 0, Less);
+    test("3.0e-8", "0x8.0E-7#1", half, NearestTiesAway, 1, Greater);
+    test("3.0e-8", "0x8.0E-7#1", half, ToOdd, 1, Greater);
+    test("3.0e-8", "0x8.0E-7#1", half, Up, 1, Greater);
+    test("-3.0e-8", "-0x8.0E-7#1", half, Nearest, 0x8000, Greater);
+    test("-3.0e-8", "-0x8.0E-7#1", half, Floor, 0x8001, Less);
+    test("4.0e-8", "0xc.0E-7#2", half, Nearest, 1, Greater);
+    test("9.0e-41", "0x8.0E-34#1", half, Down, 0, Less);
+    test("9.0e-41", "0x8.0E-34#1", half, Up, 1, Greater);
+    test("9.0e-41", "0x8.0E-34#1", half, ToOdd, 1, Greater);
+
+    // Overflow
+    test("6.552e4", "0xf.ffE+3#12", half, Nearest, 0x7bff, Less);
+    test("6.552e4", "0xf.ffE+3#12", half, Up, 0x7c00, Greater);
+    test("6.552e4", "0xf.ffE+3#12", half, Down, 0x7bff, Less);
+    test("-6.552e4", "-0xf.ffE+3#12", half, Floor, 0xfc00, Less);
+    test("-6.552e4", "-0xf.ffE+3#12", half, Ceiling, 0xfbff, Greater);
+    test("1.0e40", "0x2.0E+33#1", half, Ceiling, 0x7c00, Greater);
+    test("1.0e40", "0x2.0E+33#1", half, ToOdd, 0x7bff, Less);
+
+    let bf16 = IeeeFormat::BFLOAT16;
+    test("1.0", "0x1.0#1", bf16, Exact, 0x3f80, Equal);
+    test("NaN", "NaN", bf16, Exact, 0x7fc0, Equal);
+    test("-Infinity", "-Infinity", bf16, Exact, 0xff80, Equal);
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        bf16,
+        Nearest,
+        0x3eab,
+        Greater,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        bf16,
+        Down,
+        0x3eaa,
+        Less,
+    );
+    test("-3.396e38", "-0xf.f8E+31#9", bf16, Nearest, 0xff7f, Greater);
+    test("9.0e-41", "0x8.0E-34#1", bf16, Exact, 1, Equal);
+
+    let quad = IeeeFormat::BINARY128;
+    test(
+        "1.0",
+        "0x1.0#1",
+        quad,
+        Exact,
+        0x3fff0000000000000000000000000000,
+        Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        quad,
+        Exact,
+        0x7fff8000000000000000000000000000,
+        Equal,
+    );
+    test(
+        "0.33333333333333333326",
+        "0x0.5555555555555554#63",
+        quad,
+        Exact,
+        0x3ffd5555555555555550000000000000,
+        Equal,
+    );
+    test(
+        "3.14159265358979323846264338327950288419716939937510582097494459230781640628613",
+        "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c80#256",
+        quad,
+        Nearest,
+        0x4000921fb54442d18469898cc51701b8,
+        Less,
+    );
+    test(
+        "3.14159265358979323846264338327950288419716939937510582097494459230781640628613",
+        "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c80#256",
+        quad,
+        Up,
+        0x4000921fb54442d18469898cc51701b9,
+        Greater,
+    );
+    test("6.0e-4966", "0x4.0E-4124#1", quad, Exact, 1, Equal);
+    test(
+        "-6.0e-4966",
+        "-0x4.0E-4124#1",
+        quad,
+        Exact,
+        1 << 127 | 1,
+        Equal,
+    );
+}
+
+#[test]
+#[should_panic]
+fn to_ieee_bits_round_fail_1() {
+    parse_hex_string("0x0.5555555555555554#63").to_ieee_bits_round(IeeeFormat::BINARY16, Exact);
+}
+
+#[test]
+#[should_panic]
+fn to_ieee_bits_round_fail_2() {
+    parse_hex_string("0x1.0E+4#1").to_ieee_bits_round(IeeeFormat::BINARY16, Exact);
+}
+
+#[test]
+#[should_panic]
+fn to_ieee_bits_round_fail_3() {
+    parse_hex_string("0x8.0E-7#1").to_ieee_bits_round(IeeeFormat::BINARY16, Exact);
+}
+
+#[test]
+fn test_from_ieee_bits() {
+    let test = |bits: u128, format: IeeeFormat, out: &str, out_hex: &str| {
+        let x = Float::from_ieee_bits(&Natural::from(bits), format);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+    };
+    let half = IeeeFormat::BINARY16;
+    test(0, half, "0.0", "0x0.0");
+    test(0x8000, half, "-0.0", "-0x0.0");
+    test(0x7c00, half, "Infinity", "Infinity");
+    test(0xfc00, half, "-Infinity", "-Infinity");
+    test(0x7e00, half, "NaN", "NaN");
+    test(0x7c01, half, "NaN", "NaN");
+    test(0xffff, half, "NaN", "NaN");
+    test(0x3c00, half, "1.0", "0x1.000#11");
+    test(0xbc00, half, "-1.0", "-0x1.000#11");
+    test(0x3555, half, "0.3333", "0x0.555#11");
+    test(0x7bff, half, "6.55e4", "0xf.feE+3#11");
+    test(0x0400, half, "0.00006104", "0x0.000400#11");
+    test(0x03ff, half, "0.00006098", "0x0.0003ff#10");
+    test(0x0001, half, "6.0e-8", "0x1.0E-6#1");
+    test(0x8003, half, "-1.8e-7", "-0x3.0E-6#2");
+
+    let bf16 = IeeeFormat::BFLOAT16;
+    test(0x3f80, bf16, "1.0", "0x1.00#8");
+    test(0x7fc0, bf16, "NaN", "NaN");
+    test(0xff80, bf16, "-Infinity", "-Infinity");
+    test(0x3eab, bf16, "0.334", "0x0.558#8");
+    test(0x0001, bf16, "9.0e-41", "0x8.0E-34#1");
+
+    let quad = IeeeFormat::BINARY128;
+    test(
+        0x3fff0000000000000000000000000000,
+        quad,
+        "1.0",
+        "0x1.0000000000000000000000000000#113",
+    );
+    test(
+        0x4000921fb54442d18469898cc51701b8,
+        quad,
+        "3.1415926535897932384626433832795028",
+        "0x3.243f6a8885a308d313198a2e0370#113",
+    );
+    test(1, quad, "6.0e-4966", "0x4.0E-4124#1");
+    test(0x7fff0000000000000000000000000001, quad, "NaN", "NaN");
+}
+
+#[test]
+#[should_panic]
+fn from_ieee_bits_fail() {
+    Float::from_ieee_bits(&Natural::from(0x10000u32), IeeeFormat::BINARY16);
+}
+
+#[test]
+fn to_ieee_bits_round_properties() {
+    float_rounding_mode_pair_gen().test_properties(|(x, rm)| {
+        if rm == Exact {
+            return;
+        }
+        for format in FORMATS {
+            let (bits, o) = x.to_ieee_bits_round(format, rm);
+            assert!(bits.significant_bits() <= format.width());
+            if rm == Nearest {
+                assert_eq!(x.to_ieee_bits(format), (bits.clone(), o));
+            }
+            let y = Float::from_ieee_bits(&bits, format);
+            if x.is_nan() {
+                assert!(y.is_nan());
+                assert_eq!(o, Equal);
+                continue;
+            }
+            assert_eq!(y.partial_cmp(&x), Some(o));
+            assert_eq!(y.is_sign_positive(), x.is_sign_positive());
+            match (x.is_sign_positive(), rm) {
+                (_, Floor) | (true, Down) | (false, Up) => assert_ne!(o, Greater),
+                (_, Ceiling) | (true, Up) | (false, Down) => assert_ne!(o, Less),
+                _ => {}
+            }
+            assert_eq!(y.to_ieee_bits_round(format, Exact), (bits.clone(), Equal));
+            let sign_bit = Natural::power_of_2(format.width() - 1);
+            assert_eq!(
+                (-&x).to_ieee_bits_round(format, -rm),
+                (bits ^ sign_bit, o.reverse())
+            );
+        }
+    });
+
+    float_rounding_mode_pair_gen_var_6::<f32>().test_properties(|(x, rm)| {
+        if !x.is_nan() {
+            let (f, o) = f32::rounding_from(&x, rm);
+            assert_eq!(
+                x.to_ieee_bits_round(IeeeFormat::BINARY32, rm),
+                (Natural::from(f.to_bits()), o)
+            );
+        }
+    });
+
+    float_rounding_mode_pair_gen_var_6::<f64>().test_properties(|(x, rm)| {
+        if !x.is_nan() {
+            let (f, o) = f64::rounding_from(&x, rm);
+            assert_eq!(
+                x.to_ieee_bits_round(IeeeFormat::BINARY64, rm),
+                (Natural::from(f.to_bits()), o)
+            );
+        }
+    });
+}
+
+#[test]
+fn from_ieee_bits_properties() {
+    unsigned_gen::<u16>().test_properties(|u| {
+        let bits = Natural::from(u);
+        for format in [IeeeFormat::BINARY16, IeeeFormat::BFLOAT16] {
+            let x = Float::from_ieee_bits(&bits, format);
+            assert!(x.is_valid());
+            if x.is_nan() {
+                continue;
+            }
+            if let Some(p) = x.get_prec() {
+                assert!(p <= format.precision());
+            }
+            assert_eq!(x.to_ieee_bits_round(format, Exact), (bits.clone(), Equal));
+        }
+    });
+
+    unsigned_gen::<u32>().test_properties(|u| {
+        let x = Float::from_ieee_bits(&Natural::from(u), IeeeFormat::BINARY32);
+        assert!(x.is_valid());
+        assert_eq!(
+            ComparableFloat(x),
+            ComparableFloat(Float::from(f32::from_bits(u)))
+        );
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        let x = Float::from_ieee_bits(&Natural::from(u), IeeeFormat::BINARY64);
+        assert!(x.is_valid());
+        assert_eq!(
+            ComparableFloat(x),
+            ComparableFloat(Float::from(f64::from_bits(u)))
+        );
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        let f = f64::from_bits(u);
+        if !f.is_nan() {
+            let bits = Float::from(f)
+                .to_ieee_bits_round(IeeeFormat::BINARY128, Exact)
+                .0;
+            let x = Float::from_ieee_bits(&bits, IeeeFormat::BINARY128);
+            assert_eq!(x, f);
+            assert_eq!(x.is_sign_positive(), f.is_sign_positive());
+        }
+    });
+}
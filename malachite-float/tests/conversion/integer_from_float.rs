@@ -126,6 +126,8 @@ fn test_rounding_from_float() {
     test("0.0", "0x0.0", Down, "0", Equal);
     test("0.0", "0x0.0", Up, "0", Equal);
     test("0.0", "0x0.0", Nearest, "0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+    test("0.0", "0x0.0", ToOdd, "0", Equal);
     test("0.0", "0x0.0", Exact, "0", Equal);
 
     test("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -133,6 +135,8 @@ fn test_rounding_from_float() {
     test("-0.0", "-0x0.0", Down, "0", Equal);
     test("-0.0", "-0x0.0", Up, "0", Equal);
     test("-0.0", "-0x0.0", Nearest, "0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+    test("-0.0", "-0x0.0", ToOdd, "0", Equal);
     test("-0.0", "-0x0.0", Exact, "0", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -140,6 +144,8 @@ fn test_rounding_from_float() {
     test("1.0", "0x1.0#1", Down, "1", Equal);
     test("1.0", "0x1.0#1", Up, "1", Equal);
     test("1.0", "0x1.0#1", Nearest, "1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+    test("1.0", "0x1.0#1", ToOdd, "1", Equal);
     test("1.0", "0x1.0#1", Exact, "1", Equal);
 
     test("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -147,6 +153,8 @@ fn test_rounding_from_float() {
     test("2.0", "0x2.0#1", Down, "2", Equal);
     test("2.0", "0x2.0#1", Up, "2", Equal);
     test("2.0", "0x2.0#1", Nearest, "2", Equal);
+    test("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+    test("2.0", "0x2.0#1", ToOdd, "2", Equal);
     test("2.0", "0x2.0#1", Exact, "2", Equal);
 
     test("0.5", "0x0.8#1", Floor, "0", Less);
@@ -154,6 +162,8 @@ fn test_rounding_from_float() {
     test("0.5", "0x0.8#1", Down, "0", Less);
     test("0.5", "0x0.8#1", Up, "1", Greater);
     test("0.5", "0x0.8#1", Nearest, "0", Less);
+    test("0.5", "0x0.8#1", NearestTiesAway, "1", Greater);
+    test("0.5", "0x0.8#1", ToOdd, "1", Greater);
 
     test(
         "0.33333333333333331",
@@ -190,6 +200,20 @@ fn test_rounding_from_float() {
         "0",
         Less,
     );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        NearestTiesAway,
+        "0",
+        Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        ToOdd,
+        "1",
+        Greater,
+    );
 
     test(
         "0.6666666666666666",
@@ -226,24 +250,44 @@ fn test_rounding_from_float() {
         "1",
         Greater,
     );
+    test(
+        "0.6666666666666666",
+        "0x0.aaaaaaaaaaaaa8#53",
+        NearestTiesAway,
+        "1",
+        Greater,
+    );
+    test(
+        "0.6666666666666666",
+        "0x0.aaaaaaaaaaaaa8#53",
+        ToOdd,
+        "1",
+        Greater,
+    );
 
     test("1.5", "0x1.8#2", Floor, "1", Less);
     test("1.5", "0x1.8#2", Ceiling, "2", Greater);
     test("1.5", "0x1.8#2", Down, "1", Less);
     test("1.5", "0x1.8#2", Up, "2", Greater);
     test("1.5", "0x1.8#2", Nearest, "2", Greater);
+    test("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+    test("1.5", "0x1.8#2", ToOdd, "1", Less);
 
     test("2.5", "0x2.8#3", Floor, "2", Less);
     test("2.5", "0x2.8#3", Ceiling, "3", Greater);
     test("2.5", "0x2.8#3", Down, "2", Less);
     test("2.5", "0x2.8#3", Up, "3", Greater);
     test("2.5", "0x2.8#3", Nearest, "2", Less);
+    test("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+    test("2.5", "0x2.8#3", ToOdd, "3", Greater);
 
     test("123.0", "0x7b.0#7", Floor, "123", Equal);
     test("123.0", "0x7b.0#7", Ceiling, "123", Equal);
     test("123.0", "0x7b.0#7", Down, "123", Equal);
     test("123.0", "0x7b.0#7", Up, "123", Equal);
     test("123.0", "0x7b.0#7", Nearest, "123", Equal);
+    test("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+    test("123.0", "0x7b.0#7", ToOdd, "123", Equal);
     test("123.0", "0x7b.0#7", Exact, "123", Equal);
 
     test("-1.0", "-0x1.0#1", Floor, "-1", Equal);
@@ -251,6 +295,8 @@ fn test_rounding_from_float() {
     test("-1.0", "-0x1.0#1", Down, "-1", Equal);
     test("-1.0", "-0x1.0#1", Up, "-1", Equal);
     test("-1.0", "-0x1.0#1", Nearest, "-1", Equal);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "-1", Equal);
+    test("-1.0", "-0x1.0#1", ToOdd, "-1", Equal);
     test("-1.0", "-0x1.0#1", Exact, "-1", Equal);
 
     test("-2.0", "-0x2.0#1", Floor, "-2", Equal);
@@ -258,6 +304,8 @@ fn test_rounding_from_float() {
     test("-2.0", "-0x2.0#1", Down, "-2", Equal);
     test("-2.0", "-0x2.0#1", Up, "-2", Equal);
     test("-2.0", "-0x2.0#1", Nearest, "-2", Equal);
+    test("-2.0", "-0x2.0#1", NearestTiesAway, "-2", Equal);
+    test("-2.0", "-0x2.0#1", ToOdd, "-2", Equal);
     test("-2.0", "-0x2.0#1", Exact, "-2", Equal);
 
     test("-0.5", "-0x0.8#1", Floor, "-1", Less);
@@ -265,6 +313,8 @@ fn test_rounding_from_float() {
     test("-0.5", "-0x0.8#1", Down, "0", Greater);
     test("-0.5", "-0x0.8#1", Up, "-1", Less);
     test("-0.5", "-0x0.8#1", Nearest, "0", Greater);
+    test("-0.5", "-0x0.8#1", NearestTiesAway, "-1", Less);
+    test("-0.5", "-0x0.8#1", ToOdd, "-1", Less);
 
     test(
         "-0.33333333333333331",
@@ -301,6 +351,20 @@ fn test_rounding_from_float() {
         "0",
         Greater,
     );
+    test(
+        "-0.33333333333333331",
+        "-0x0.55555555555554#53",
+        NearestTiesAway,
+        "0",
+        Greater,
+    );
+    test(
+        "-0.33333333333333331",
+        "-0x0.55555555555554#53",
+        ToOdd,
+        "-1",
+        Less,
+    );
 
     test(
         "-0.6666666666666666",
@@ -337,24 +401,44 @@ fn test_rounding_from_float() {
         "-1",
         Less,
     );
+    test(
+        "-0.6666666666666666",
+        "-0x0.aaaaaaaaaaaaa8#53",
+        NearestTiesAway,
+        "-1",
+        Less,
+    );
+    test(
+        "-0.6666666666666666",
+        "-0x0.aaaaaaaaaaaaa8#53",
+        ToOdd,
+        "-1",
+        Less,
+    );
 
     test("-1.5", "-0x1.8#2", Floor, "-2", Less);
     test("-1.5", "-0x1.8#2", Ceiling, "-1", Greater);
     test("-1.5", "-0x1.8#2", Down, "-1", Greater);
     test("-1.5", "-0x1.8#2", Up, "-2", Less);
     test("-1.5", "-0x1.8#2", Nearest, "-2", Less);
+    test("-1.5", "-0x1.8#2", NearestTiesAway, "-2", Less);
+    test("-1.5", "-0x1.8#2", ToOdd, "-1", Greater);
 
     test("-2.5", "-0x2.8#3", Floor, "-3", Less);
     test("-2.5", "-0x2.8#3", Ceiling, "-2", Greater);
     test("-2.5", "-0x2.8#3", Down, "-2", Greater);
     test("-2.5", "-0x2.8#3", Up, "-3", Less);
     test("-2.5", "-0x2.8#3", Nearest, "-2", Greater);
+    test("-2.5", "-0x2.8#3", NearestTiesAway, "-3", Less);
+    test("-2.5", "-0x2.8#3", ToOdd, "-3", Less);
 
     test("-123.0", "-0x7b.0#7", Floor, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Ceiling, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Down, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Up, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Nearest, "-123", Equal);
+    test("-123.0", "-0x7b.0#7", NearestTiesAway, "-123", Equal);
+    test("-123.0", "-0x7b.0#7", ToOdd, "-123", Equal);
     test("-123.0", "-0x7b.0#7", Exact, "-123", Equal);
 }
 
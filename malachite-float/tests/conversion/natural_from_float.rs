@@ -127,6 +127,8 @@ fn test_rounding_from_float() {
     test("0.0", "0x0.0", Down, "0", Equal);
     test("0.0", "0x0.0", Up, "0", Equal);
     test("0.0", "0x0.0", Nearest, "0", Equal);
+    test("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+    test("0.0", "0x0.0", ToOdd, "0", Equal);
     test("0.0", "0x0.0", Exact, "0", Equal);
 
     test("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -134,6 +136,8 @@ fn test_rounding_from_float() {
     test("-0.0", "-0x0.0", Down, "0", Equal);
     test("-0.0", "-0x0.0", Up, "0", Equal);
     test("-0.0", "-0x0.0", Nearest, "0", Equal);
+    test("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+    test("-0.0", "-0x0.0", ToOdd, "0", Equal);
     test("-0.0", "-0x0.0", Exact, "0", Equal);
 
     test("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -141,6 +145,8 @@ fn test_rounding_from_float() {
     test("1.0", "0x1.0#1", Down, "1", Equal);
     test("1.0", "0x1.0#1", Up, "1", Equal);
     test("1.0", "0x1.0#1", Nearest, "1", Equal);
+    test("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+    test("1.0", "0x1.0#1", ToOdd, "1", Equal);
     test("1.0", "0x1.0#1", Exact, "1", Equal);
 
     test("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -148,6 +154,8 @@ fn test_rounding_from_float() {
     test("2.0", "0x2.0#1", Down, "2", Equal);
     test("2.0", "0x2.0#1", Up, "2", Equal);
     test("2.0", "0x2.0#1", Nearest, "2", Equal);
+    test("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+    test("2.0", "0x2.0#1", ToOdd, "2", Equal);
     test("2.0", "0x2.0#1", Exact, "2", Equal);
 
     test("0.5", "0x0.8#1", Floor, "0", Less);
@@ -155,6 +163,8 @@ fn test_rounding_from_float() {
     test("0.5", "0x0.8#1", Down, "0", Less);
     test("0.5", "0x0.8#1", Up, "1", Greater);
     test("0.5", "0x0.8#1", Nearest, "0", Less);
+    test("0.5", "0x0.8#1", NearestTiesAway, "1", Greater);
+    test("0.5", "0x0.8#1", ToOdd, "1", Greater);
 
     test(
         "0.33333333333333331",
@@ -191,6 +201,20 @@ fn test_rounding_from_float() {
         "0",
         Less,
     );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        NearestTiesAway,
+        "0",
+        Less,
+    );
+    test(
+        "0.33333333333333331",
+        "0x0.55555555555554#53",
+        ToOdd,
+        "1",
+        Greater,
+    );
 
     test(
         "0.6666666666666666",
@@ -227,37 +251,60 @@ fn test_rounding_from_float() {
         "1",
         Greater,
     );
+    test(
+        "0.6666666666666666",
+        "0x0.aaaaaaaaaaaaa8#53",
+        NearestTiesAway,
+        "1",
+        Greater,
+    );
+    test(
+        "0.6666666666666666",
+        "0x0.aaaaaaaaaaaaa8#53",
+        ToOdd,
+        "1",
+        Greater,
+    );
 
     test("1.5", "0x1.8#2", Floor, "1", Less);
     test("1.5", "0x1.8#2", Ceiling, "2", Greater);
     test("1.5", "0x1.8#2", Down, "1", Less);
     test("1.5", "0x1.8#2", Up, "2", Greater);
     test("1.5", "0x1.8#2", Nearest, "2", Greater);
+    test("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+    test("1.5", "0x1.8#2", ToOdd, "1", Less);
 
     test("2.5", "0x2.8#3", Floor, "2", Less);
     test("2.5", "0x2.8#3", Ceiling, "3", Greater);
     test("2.5", "0x2.8#3", Down, "2", Less);
     test("2.5", "0x2.8#3", Up, "3", Greater);
     test("2.5", "0x2.8#3", Nearest, "2", Less);
+    test("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+    test("2.5", "0x2.8#3", ToOdd, "3", Greater);
 
     test("123.0", "0x7b.0#7", Floor, "123", Equal);
     test("123.0", "0x7b.0#7", Ceiling, "123", Equal);
     test("123.0", "0x7b.0#7", Down, "123", Equal);
     test("123.0", "0x7b.0#7", Up, "123", Equal);
     test("123.0", "0x7b.0#7", Nearest, "123", Equal);
+    test("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+    test("123.0", "0x7b.0#7", ToOdd, "123", Equal);
     test("123.0", "0x7b.0#7", Exact, "123", Equal);
 
     test("-1.0", "-0x1.0#1", Ceiling, "0", Greater);
     test("-1.0", "-0x1.0#1", Down, "0", Greater);
     test("-1.0", "-0x1.0#1", Nearest, "0", Greater);
+    test("-1.0", "-0x1.0#1", NearestTiesAway, "0", Greater);
 
     test("-2.0", "-0x2.0#1", Ceiling, "0", Greater);
     test("-2.0", "-0x2.0#1", Down, "0", Greater);
     test("-2.0", "-0x2.0#1", Nearest, "0", Greater);
+    test("-2.0", "-0x2.0#1", NearestTiesAway, "0", Greater);
 
     test("-0.5", "-0x0.8#1", Ceiling, "0", Greater);
     test("-0.5", "-0x0.8#1", Down, "0", Greater);
     test("-0.5", "-0x0.8#1", Nearest, "0", Greater);
+    test("-0.5", "-0x0.8#1", NearestTiesAway, "0", Greater);
 
     test(
         "-0.33333333333333331",
@@ -280,6 +327,13 @@ fn test_rounding_from_float() {
         "0",
         Greater,
     );
+    test(
+        "-0.33333333333333331",
+        "-0x0.55555555555554#53",
+        NearestTiesAway,
+        "0",
+        Greater,
+    );
 
     test(
         "-0.6666666666666666",
@@ -302,14 +356,23 @@ fn test_rounding_from_float() {
         "0",
         Greater,
     );
+    test(
+        "-0.6666666666666666",
+        "-0x0.aaaaaaaaaaaaa8#53",
+        NearestTiesAway,
+        "0",
+        Greater,
+    );
 
     test("-1.5", "-0x1.8#2", Ceiling, "0", Greater);
     test("-1.5", "-0x1.8#2", Down, "0", Greater);
     test("-1.5", "-0x1.8#2", Nearest, "0", Greater);
+    test("-1.5", "-0x1.8#2", NearestTiesAway, "0", Greater);
 
     test("-2.5", "-0x2.8#3", Ceiling, "0", Greater);
     test("-2.5", "-0x2.8#3", Down, "0", Greater);
     test("-2.5", "-0x2.8#3", Nearest, "0", Greater);
+    test("-2.5", "-0x2.8#3", NearestTiesAway, "0", Greater);
 
     test("-123.0", "-0x7b.0#7", Ceiling, "0", Greater);
     test("-123.0", "-0x7b.0#7", Down, "0", Greater);
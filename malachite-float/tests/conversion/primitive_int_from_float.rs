@@ -245,6 +245,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.0", "0x0.0", Down, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Up, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", ToOdd, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -252,6 +254,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.0", "-0x0.0", Down, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Up, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", ToOdd, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -259,6 +263,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.0", "0x1.0#1", Down, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Up, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Nearest, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", ToOdd, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Exact, "1", Equal);
 
         test_helper::<T>("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -266,6 +272,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.0", "0x2.0#1", Down, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Up, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Nearest, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", ToOdd, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Exact, "2", Equal);
 
         test_helper::<T>("0.5", "0x0.8#1", Floor, "0", Less);
@@ -273,6 +281,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.5", "0x0.8#1", Down, "0", Less);
         test_helper::<T>("0.5", "0x0.8#1", Up, "1", Greater);
         test_helper::<T>("0.5", "0x0.8#1", Nearest, "0", Less);
+        test_helper::<T>("0.5", "0x0.8#1", NearestTiesAway, "1", Greater);
+        test_helper::<T>("0.5", "0x0.8#1", ToOdd, "1", Greater);
 
         test_helper::<T>(
             "0.33333333333333331",
@@ -309,6 +319,20 @@ fn test_rounding_from_float() {
             "0",
             Less,
         );
+        test_helper::<T>(
+            "0.33333333333333331",
+            "0x0.55555555555554#53",
+            NearestTiesAway,
+            "0",
+            Less,
+        );
+        test_helper::<T>(
+            "0.33333333333333331",
+            "0x0.55555555555554#53",
+            ToOdd,
+            "1",
+            Greater,
+        );
 
         test_helper::<T>(
             "0.6666666666666666",
@@ -345,37 +369,60 @@ fn test_rounding_from_float() {
             "1",
             Greater,
         );
+        test_helper::<T>(
+            "0.6666666666666666",
+            "0x0.aaaaaaaaaaaaa8#53",
+            NearestTiesAway,
+            "1",
+            Greater,
+        );
+        test_helper::<T>(
+            "0.6666666666666666",
+            "0x0.aaaaaaaaaaaaa8#53",
+            ToOdd,
+            "1",
+            Greater,
+        );
 
         test_helper::<T>("1.5", "0x1.8#2", Floor, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Ceiling, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Down, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Up, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Nearest, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", ToOdd, "1", Less);
 
         test_helper::<T>("2.5", "0x2.8#3", Floor, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Ceiling, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Down, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Up, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Nearest, "2", Less);
+        test_helper::<T>("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+        test_helper::<T>("2.5", "0x2.8#3", ToOdd, "3", Greater);
 
         test_helper::<T>("123.0", "0x7b.0#7", Floor, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Ceiling, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Down, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Up, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Nearest, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", ToOdd, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Exact, "123", Equal);
 
         test_helper::<T>("-1.0", "-0x1.0#1", Ceiling, "0", Greater);
         test_helper::<T>("-1.0", "-0x1.0#1", Down, "0", Greater);
         test_helper::<T>("-1.0", "-0x1.0#1", Nearest, "0", Greater);
+        test_helper::<T>("-1.0", "-0x1.0#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-2.0", "-0x2.0#1", Ceiling, "0", Greater);
         test_helper::<T>("-2.0", "-0x2.0#1", Down, "0", Greater);
         test_helper::<T>("-2.0", "-0x2.0#1", Nearest, "0", Greater);
+        test_helper::<T>("-2.0", "-0x2.0#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-0.5", "-0x0.8#1", Ceiling, "0", Greater);
         test_helper::<T>("-0.5", "-0x0.8#1", Down, "0", Greater);
         test_helper::<T>("-0.5", "-0x0.8#1", Nearest, "0", Greater);
+        test_helper::<T>("-0.5", "-0x0.8#1", NearestTiesAway, "0", Greater);
 
         test_helper::<T>(
             "-0.33333333333333331",
@@ -398,6 +445,13 @@ fn test_rounding_from_float() {
             "0",
             Greater,
         );
+        test_helper::<T>(
+            "-0.33333333333333331",
+            "-0x0.55555555555554#53",
+            NearestTiesAway,
+            "0",
+            Greater,
+        );
 
         test_helper::<T>(
             "-0.6666666666666666",
@@ -420,18 +474,28 @@ fn test_rounding_from_float() {
             "0",
             Greater,
         );
+        test_helper::<T>(
+            "-0.6666666666666666",
+            "-0x0.aaaaaaaaaaaaa8#53",
+            NearestTiesAway,
+            "0",
+            Greater,
+        );
 
         test_helper::<T>("-1.5", "-0x1.8#2", Ceiling, "0", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Down, "0", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Nearest, "0", Greater);
+        test_helper::<T>("-1.5", "-0x1.8#2", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-2.5", "-0x2.8#3", Ceiling, "0", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Down, "0", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Nearest, "0", Greater);
+        test_helper::<T>("-2.5", "-0x2.8#3", NearestTiesAway, "0", Greater);
 
         test_helper::<T>("-123.0", "-0x7b.0#7", Ceiling, "0", Greater);
         test_helper::<T>("-123.0", "-0x7b.0#7", Down, "0", Greater);
         test_helper::<T>("-123.0", "-0x7b.0#7", Nearest, "0", Greater);
+        test_helper::<T>("-123.0", "-0x7b.0#7", NearestTiesAway, "0", Greater);
     }
     apply_fn_to_unsigneds!(test_helper_u);
 
@@ -445,6 +509,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.0", "0x0.0", Down, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Up, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("0.0", "0x0.0", ToOdd, "0", Equal);
         test_helper::<T>("0.0", "0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("-0.0", "-0x0.0", Floor, "0", Equal);
@@ -452,6 +518,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.0", "-0x0.0", Down, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Up, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Nearest, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", NearestTiesAway, "0", Equal);
+        test_helper::<T>("-0.0", "-0x0.0", ToOdd, "0", Equal);
         test_helper::<T>("-0.0", "-0x0.0", Exact, "0", Equal);
 
         test_helper::<T>("1.0", "0x1.0#1", Floor, "1", Equal);
@@ -459,6 +527,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("1.0", "0x1.0#1", Down, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Up, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Nearest, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", NearestTiesAway, "1", Equal);
+        test_helper::<T>("1.0", "0x1.0#1", ToOdd, "1", Equal);
         test_helper::<T>("1.0", "0x1.0#1", Exact, "1", Equal);
 
         test_helper::<T>("2.0", "0x2.0#1", Floor, "2", Equal);
@@ -466,6 +536,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("2.0", "0x2.0#1", Down, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Up, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Nearest, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", NearestTiesAway, "2", Equal);
+        test_helper::<T>("2.0", "0x2.0#1", ToOdd, "2", Equal);
         test_helper::<T>("2.0", "0x2.0#1", Exact, "2", Equal);
 
         test_helper::<T>("0.5", "0x0.8#1", Floor, "0", Less);
@@ -473,6 +545,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("0.5", "0x0.8#1", Down, "0", Less);
         test_helper::<T>("0.5", "0x0.8#1", Up, "1", Greater);
         test_helper::<T>("0.5", "0x0.8#1", Nearest, "0", Less);
+        test_helper::<T>("0.5", "0x0.8#1", NearestTiesAway, "1", Greater);
+        test_helper::<T>("0.5", "0x0.8#1", ToOdd, "1", Greater);
 
         test_helper::<T>(
             "0.33333333333333331",
@@ -509,6 +583,20 @@ fn test_rounding_from_float() {
             "0",
             Less,
         );
+        test_helper::<T>(
+            "0.33333333333333331",
+            "0x0.55555555555554#53",
+            NearestTiesAway,
+            "0",
+            Less,
+        );
+        test_helper::<T>(
+            "0.33333333333333331",
+            "0x0.55555555555554#53",
+            ToOdd,
+            "1",
+            Greater,
+        );
 
         test_helper::<T>(
             "0.6666666666666666",
@@ -545,24 +633,44 @@ fn test_rounding_from_float() {
             "1",
             Greater,
         );
+        test_helper::<T>(
+            "0.6666666666666666",
+            "0x0.aaaaaaaaaaaaa8#53",
+            NearestTiesAway,
+            "1",
+            Greater,
+        );
+        test_helper::<T>(
+            "0.6666666666666666",
+            "0x0.aaaaaaaaaaaaa8#53",
+            ToOdd,
+            "1",
+            Greater,
+        );
 
         test_helper::<T>("1.5", "0x1.8#2", Floor, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Ceiling, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Down, "1", Less);
         test_helper::<T>("1.5", "0x1.8#2", Up, "2", Greater);
         test_helper::<T>("1.5", "0x1.8#2", Nearest, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", NearestTiesAway, "2", Greater);
+        test_helper::<T>("1.5", "0x1.8#2", ToOdd, "1", Less);
 
         test_helper::<T>("2.5", "0x2.8#3", Floor, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Ceiling, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Down, "2", Less);
         test_helper::<T>("2.5", "0x2.8#3", Up, "3", Greater);
         test_helper::<T>("2.5", "0x2.8#3", Nearest, "2", Less);
+        test_helper::<T>("2.5", "0x2.8#3", NearestTiesAway, "3", Greater);
+        test_helper::<T>("2.5", "0x2.8#3", ToOdd, "3", Greater);
 
         test_helper::<T>("123.0", "0x7b.0#7", Floor, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Ceiling, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Down, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Up, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Nearest, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", NearestTiesAway, "123", Equal);
+        test_helper::<T>("123.0", "0x7b.0#7", ToOdd, "123", Equal);
         test_helper::<T>("123.0", "0x7b.0#7", Exact, "123", Equal);
 
         test_helper::<T>("-1.0", "-0x1.0#1", Floor, "-1", Equal);
@@ -570,6 +678,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-1.0", "-0x1.0#1", Down, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Up, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Nearest, "-1", Equal);
+        test_helper::<T>("-1.0", "-0x1.0#1", NearestTiesAway, "-1", Equal);
+        test_helper::<T>("-1.0", "-0x1.0#1", ToOdd, "-1", Equal);
         test_helper::<T>("-1.0", "-0x1.0#1", Exact, "-1", Equal);
 
         test_helper::<T>("-2.0", "-0x2.0#1", Floor, "-2", Equal);
@@ -577,6 +687,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-2.0", "-0x2.0#1", Down, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Up, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Nearest, "-2", Equal);
+        test_helper::<T>("-2.0", "-0x2.0#1", NearestTiesAway, "-2", Equal);
+        test_helper::<T>("-2.0", "-0x2.0#1", ToOdd, "-2", Equal);
         test_helper::<T>("-2.0", "-0x2.0#1", Exact, "-2", Equal);
 
         test_helper::<T>("-0.5", "-0x0.8#1", Floor, "-1", Less);
@@ -584,6 +696,8 @@ fn test_rounding_from_float() {
         test_helper::<T>("-0.5", "-0x0.8#1", Down, "0", Greater);
         test_helper::<T>("-0.5", "-0x0.8#1", Up, "-1", Less);
         test_helper::<T>("-0.5", "-0x0.8#1", Nearest, "0", Greater);
+        test_helper::<T>("-0.5", "-0x0.8#1", NearestTiesAway, "-1", Less);
+        test_helper::<T>("-0.5", "-0x0.8#1", ToOdd, "-1", Less);
 
         test_helper::<T>(
             "-0.33333333333333331",
@@ -620,6 +734,20 @@ fn test_rounding_from_float() {
             "0",
             Greater,
         );
+        test_helper::<T>(
+            "-0.33333333333333331",
+            "-0x0.55555555555554#53",
+            NearestTiesAway,
+            "0",
+            Greater,
+        );
+        test_helper::<T>(
+            "-0.33333333333333331",
+            "-0x0.55555555555554#53",
+            ToOdd,
+            "-1",
+            Less,
+        );
 
         test_helper::<T>(
             "-0.6666666666666666",
@@ -656,24 +784,44 @@ fn test_rounding_from_float() {
             "-1",
             Less,
         );
+        test_helper::<T>(
+            "-0.6666666666666666",
+            "-0x0.aaaaaaaaaaaaa8#53",
+            NearestTiesAway,
+            "-1",
+            Less,
+        );
+        test_helper::<T>(
+            "-0.6666666666666666",
+            "-0x0.aaaaaaaaaaaaa8#53",
+            ToOdd,
+            "-1",
+            Less,
+        );
 
         test_helper::<T>("-1.5", "-0x1.8#2", Floor, "-2", Less);
         test_helper::<T>("-1.5", "-0x1.8#2", Ceiling, "-1", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Down, "-1", Greater);
         test_helper::<T>("-1.5", "-0x1.8#2", Up, "-2", Less);
         test_helper::<T>("-1.5", "-0x1.8#2", Nearest, "-2", Less);
+        test_helper::<T>("-1.5", "-0x1.8#2", NearestTiesAway, "-2", Less);
+        test_helper::<T>("-1.5", "-0x1.8#2", ToOdd, "-1", Greater);
 
         test_helper::<T>("-2.5", "-0x2.8#3", Floor, "-3", Less);
         test_helper::<T>("-2.5", "-0x2.8#3", Ceiling, "-2", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Down, "-2", Greater);
         test_helper::<T>("-2.5", "-0x2.8#3", Up, "-3", Less);
         test_helper::<T>("-2.5", "-0x2.8#3", Nearest, "-2", Greater);
+        test_helper::<T>("-2.5", "-0x2.8#3", NearestTiesAway, "-3", Less);
+        test_helper::<T>("-2.5", "-0x2.8#3", ToOdd, "-3", Less);
 
         test_helper::<T>("-123.0", "-0x7b.0#7", Floor, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Ceiling, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Down, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Up, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Nearest, "-123", Equal);
+        test_helper::<T>("-123.0", "-0x7b.0#7", NearestTiesAway, "-123", Equal);
+        test_helper::<T>("-123.0", "-0x7b.0#7", ToOdd, "-123", Equal);
         test_helper::<T>("-123.0", "-0x7b.0#7", Exact, "-123", Equal);
     }
     apply_fn_to_signeds!(test_helper_i);
@@ -0,0 +1,94 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::string_is_subset;
+use malachite_base::test_util::generators::string_gen;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, Float};
+
+#[test]
+fn test_serde() {
+    let test = |s, out| {
+        let x = parse_hex_string(s);
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        let y = serde_json::from_str::<Float>(out).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(to_hex_string(&y), s);
+
+        let bytes = bincode::serialize(&x).unwrap();
+        let y: Float = bincode::deserialize(&bytes).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(to_hex_string(&y), s);
+    };
+    test("NaN", "\"NaN\"");
+    test("Infinity", "\"Infinity\"");
+    test("-Infinity", "\"-Infinity\"");
+    test("0x0.0", "\"0x0.0\"");
+    test("-0x0.0", "\"-0x0.0\"");
+    test("0x1.0#1", "\"0x1.0#1\"");
+    test("0x1.000000#24", "\"0x1.000000#24\"");
+    test("-0x1.000000#24", "\"-0x1.000000#24\"");
+    test("0x0.5555558#24", "\"0x0.5555558#24\"");
+    test("0x3.243f6c#24", "\"0x3.243f6c#24\"");
+    test("0x1.921fb54442d18#53", "\"0x1.921fb54442d18#53\"");
+    test("-0x8.0E-38#1", "\"-0x8.0E-38#1\"");
+    test("0xf.fffffE+31#24", "\"0xf.fffffE+31#24\"");
+    test("0x1.0E+100#1", "\"0x1.0E+100#1\"");
+    test("0x1.0E-100#1", "\"0x1.0E-100#1\"");
+    test(
+        "0x1.00000000000000000000000000000000000000000000000000E+100#200",
+        "\"0x1.00000000000000000000000000000000000000000000000000E+100#200\"",
+    );
+}
+
+#[test]
+fn test_serde_invalid() {
+    let test = |s| {
+        assert!(serde_json::from_str::<Float>(s).is_err());
+    };
+    test("\"\"");
+    test("\"nan\"");
+    test("\"0x\"");
+    test("\"1.0#1\"");
+    test("\"0x1.0\"");
+    test("\"0x1.0#\"");
+    test("\"0x1.0#0\"");
+    test("\"0x0.0#1\"");
+    test("\"0x.8#1\"");
+    test("\"0x1.g#1\"");
+    test("\"0x1.8#1\"");
+    test("\"0x1.8E#2\"");
+    test("\"--0x1.8#2\"");
+    test("\"0x1.0#1000000000000\"");
+    test("\"0x1.0#99999999999999999999\"");
+    test("\"0x1.0E+1000000000000000#1\"");
+    test("\"0x1.0E+999999999999999999999#1\"");
+    test("\"0x1.0E+600000000#1\"");
+    test("123");
+}
+
+#[test]
+fn serde_properties() {
+    float_gen().test_properties(|x| {
+        let s = serde_json::to_string(&x).unwrap();
+        let y = serde_json::from_str::<Float>(&s).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x.clone()));
+        assert!(string_is_subset(&s, "\"#+-.0123456789EINabcdefinfitxy"));
+
+        let bytes = bincode::serialize(&x).unwrap();
+        let y: Float = bincode::deserialize(&bytes).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+    });
+
+    string_gen().test_properties(|s| {
+        let _x: Result<Float, _> = serde_json::from_str(&s);
+    });
+}
@@ -0,0 +1,224 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::NegativeZero;
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::traits::{FromSciString, ToSci};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::string_from_sci_string_options_pair_gen;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_from_sci_string_prec_round() {
+    let test = |s: &str, base: u8, prec: u64, rm: RoundingMode, out: &str, o_out: Ordering| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        let (x, o) = Float::from_sci_string_prec_round(s, options, prec, rm).unwrap();
+        assert!(x.is_valid());
+        assert_eq!(to_hex_string(&x), out);
+        assert_eq!(o, o_out);
+        if rm == Nearest {
+            let (x_alt, o_alt) = Float::from_sci_string_prec(s, options, prec).unwrap();
+            assert_eq!(ComparableFloat(x_alt), ComparableFloat(x));
+            assert_eq!(o_alt, o);
+        }
+    };
+    test("0", 10, 1, Nearest, "0x0.0", Equal);
+    test("-0", 10, 1, Nearest, "-0x0.0", Equal);
+    test("+0.000e5", 10, 1, Nearest, "0x0.0", Equal);
+    test("nan", 10, 1, Nearest, "NaN", Equal);
+    test("-NaN", 10, 1, Nearest, "NaN", Equal);
+    test("inf", 10, 1, Nearest, "Infinity", Equal);
+    test("-Infinity", 10, 1, Nearest, "-Infinity", Equal);
+    test("+INF", 10, 1, Nearest, "Infinity", Equal);
+
+    test("1", 10, 1, Nearest, "0x1.0#1", Equal);
+    test("-1", 10, 10, Nearest, "-0x1.000#10", Equal);
+    test("123", 10, 1, Nearest, "0x8.0E+1#1", Greater);
+    test("0.1", 10, 53, Nearest, "0x0.1999999999999a#53", Greater);
+    test("0.1", 10, 53, Floor, "0x0.19999999999999#53", Less);
+    test("0.1", 10, 53, Ceiling, "0x0.1999999999999a#53", Greater);
+    test("-0.1", 10, 53, Down, "-0x0.19999999999999#53", Greater);
+    test("-0.1", 10, 53, Up, "-0x0.1999999999999a#53", Less);
+    test(
+        "0.1",
+        10,
+        100,
+        Nearest,
+        "0x0.1999999999999999999999999a#100",
+        Greater,
+    );
+    test("3.14159", 10, 10, Floor, "0x3.24#10", Less);
+    test("3.14159", 10, 10, Ceiling, "0x3.25#10", Greater);
+    test("3.14159", 10, 10, Nearest, "0x3.24#10", Less);
+    test("1.5e-3", 10, 20, Nearest, "0x0.00624dd0#20", Less);
+    test(
+        "1e20",
+        10,
+        100,
+        Exact,
+        "0x56bc75e2d63100000.000000000#100",
+        Equal,
+    );
+    test(
+        "1e100",
+        10,
+        100,
+        Nearest,
+        "0x1.249ad2594c37ceb0b2784c4ceE+83#100",
+        Less,
+    );
+    test(
+        "1e-100",
+        10,
+        100,
+        Nearest,
+        "0xd.ff9772470297ebd59787e2b9E-84#100",
+        Less,
+    );
+    test("2.5", 10, 1, Nearest, "0x2.0#1", Less);
+    test("3.5", 10, 1, Nearest, "0x4.0#1", Greater);
+    test("2.5", 10, 1, NearestTiesAway, "0x2.0#1", Less);
+    test("2.5", 10, 1, ToOdd, "0x2.0#1", Less);
+
+    test("1.8p3", 16, 2, Exact, "0xc.0#2", Equal);
+    test("0x1.8p-3", 16, 10, Exact, "0x0.300#10", Equal);
+    test("-0XFFp0", 16, 8, Exact, "-0xff.0#8", Equal);
+    test("0xffp1", 16, 4, Nearest, "0x2.0E+2#4", Greater);
+    test("1.1e+1", 16, 10, Nearest, "0x11.00#10", Equal);
+    test("1e+5p+2", 16, 10, Nearest, "0x4.00E+5#10", Equal);
+    test("101.1", 2, 4, Exact, "0x5.8#4", Equal);
+    test("1p10", 10, 1, Exact, "0x4.0E+2#1", Equal);
+    test("1e2p-2", 10, 5, Exact, "0x19.0#5", Equal);
+    test("zz", 36, 10, Nearest, "0x510.0#10", Greater);
+    test("0.1", 3, 20, Nearest, "0x0.555558#20", Greater);
+
+    // Long inputs
+    let s = format!("0.1{}1", "0".repeat(1000));
+    test(&s, 10, 53, Nearest, "0x0.1999999999999a#53", Greater);
+    test(&s, 10, 53, Floor, "0x0.19999999999999#53", Less);
+    test(&s, 10, 53, Ceiling, "0x0.1999999999999a#53", Greater);
+    let s = format!("{}e-1000", "9".repeat(2000));
+    test(&s, 10, 10, Nearest, "0x3.ceE+830#10", Less);
+    test(&s, 10, 10, Down, "0x3.ceE+830#10", Less);
+    test(&s, 10, 10, Up, "0x3.cfE+830#10", Greater);
+    // Exactly halfway between two `Float`s, but this is only apparent from the last digit
+    let s = format!("1.5{}", "0".repeat(1000));
+    test(&s, 10, 1, Nearest, "0x2.0#1", Greater);
+    let s = format!("1.5{}1", "0".repeat(1000));
+    test(&s, 10, 1, Nearest, "0x2.0#1", Greater);
+    let s = format!("1.4{}", "9".repeat(1000));
+    test(&s, 10, 1, Nearest, "0x1.0#1", Less);
+    test(&s, 10, 1, Ceiling, "0x2.0#1", Greater);
+
+    let pi = parse_hex_string("0x3.243f6a8885a308d313198a2e0#100");
+    let options = FromSciStringOptions::default();
+    let (x, o) = Float::from_sci_string_prec(
+        "3.14159265358979323846264338327950288419716939937510582097494459",
+        options,
+        100,
+    )
+    .unwrap();
+    assert_eq!(ComparableFloat(x), ComparableFloat(pi));
+    assert_eq!(o, Less);
+}
+
+#[test]
+fn test_from_sci_string_prec_round_invalid() {
+    let test = |s: &str, base: u8| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        assert!(Float::from_sci_string_prec_round(s, options, 10, Nearest).is_none());
+        assert!(Float::from_sci_string_prec(s, options, 10).is_none());
+    };
+    for s in [
+        "", "-", "+", ".", "e5", "1e", "p3", "0x", "1.2.3", "--1", "+-1", "1p", "1e5e5", "abc",
+        "1 ", " 1", "1p2.5", "nanx",
+    ] {
+        test(s, 10);
+    }
+    for s in ["0x", "0x.", "0x1p", "0x0x1", "g"] {
+        test(s, 16);
+    }
+}
+
+#[test]
+fn from_sci_string_prec_round_fail() {
+    let options = FromSciStringOptions::default();
+    assert_panic!(Float::from_sci_string_prec_round("1", options, 0, Floor));
+    assert_panic!(Float::from_sci_string_prec_round("0.1", options, 10, Exact));
+    assert_panic!(Float::from_sci_string_prec_round("257", options, 8, Exact));
+    assert_panic!(Float::from_sci_string_prec("1", options, 0));
+}
+
+fn from_sci_string_prec_round_properties_helper(
+    s: &str,
+    options: FromSciStringOptions,
+    q: &Rational,
+    neg: bool,
+) {
+    for prec in [1, 2, 10, 53, 100] {
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact {
+                continue;
+            }
+            let (x, o) = Float::from_sci_string_prec_round(s, options, prec, rm).unwrap();
+            assert!(x.is_valid());
+            let (x_alt, o_alt) = if *q == 0u32 && neg {
+                (Float::NEGATIVE_ZERO, Equal)
+            } else {
+                Float::from_rational_prec_round(q.clone(), prec, rm)
+            };
+            assert_eq!(ComparableFloatRef(&x), ComparableFloatRef(&x_alt));
+            assert_eq!(o, o_alt);
+            if rm == Nearest {
+                let (x_alt, o_alt) = Float::from_sci_string_prec(s, options, prec).unwrap();
+                assert_eq!(ComparableFloat(x_alt), ComparableFloat(x));
+                assert_eq!(o_alt, o);
+            }
+        }
+    }
+}
+
+#[test]
+fn from_sci_string_prec_round_properties() {
+    string_from_sci_string_options_pair_gen().test_properties(|(s, options)| {
+        let unsigned = s.trim_start_matches(['+', '-']);
+        if ["nan", "inf", "infinity"]
+            .iter()
+            .any(|special| unsigned.eq_ignore_ascii_case(special))
+        {
+            return;
+        }
+        if let Some(q) = Rational::from_sci_string_with_options(&s, options) {
+            let neg = s.starts_with('-');
+            from_sci_string_prec_round_properties_helper(&s, options, &q, neg);
+        }
+    });
+
+    rational_gen().test_properties(|q| {
+        for base in [2, 3, 10, 16, 36] {
+            for precision in [1, 20, 100] {
+                let mut to_options = ToSciOptions::default();
+                to_options.set_base(base);
+                to_options.set_precision(precision);
+                let s = q.to_sci_with_options(to_options).to_string();
+                let mut options = FromSciStringOptions::default();
+                options.set_base(base);
+                let q = Rational::from_sci_string_with_options(&s, options).unwrap();
+                from_sci_string_prec_round_properties_helper(&s, options, &q, false);
+            }
+        }
+    });
+}
@@ -0,0 +1,239 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::string::options::{FromSciStringOptions, ToSciOptions};
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString, ToSci};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::Float;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_to_sci() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_sci().to_string(), out);
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            out
+        );
+    };
+    test("NaN", "NaN");
+    test("Infinity", "Infinity");
+    test("-Infinity", "-Infinity");
+    test("0x0.0", "0");
+    test("-0x0.0", "-0");
+    test("0x1.0#1", "1");
+    test("-0x1.0#1", "-1");
+    test(
+        "0x0.555555555555555555555555555555558#128",
+        "0.3333333333333333",
+    );
+    test("0x3.243f6a8885a30#53", "3.141592653589793");
+    test("0x1.0E+3#1", "4096");
+    test("0x0.00001#1", "9.5367431640625e-7");
+    test("0x1.0E+100#1", "2.582249878086909e120");
+    test("-0x1.0E+100#1", "-2.582249878086909e120");
+}
+
+#[test]
+fn test_to_sci_with_options() {
+    let test = |s_hex, options: ToSciOptions, out: &str, o_out: Ordering| {
+        let x = parse_hex_string(s_hex);
+        assert!(x.fmt_sci_valid(options));
+        assert_eq!(x.to_sci_with_options(options).to_string(), out);
+        assert_eq!(
+            x.to_sci_string_and_ordering(options),
+            (out.to_string(), o_out)
+        );
+    };
+    let pi = "0x3.243f6a8885a308d313198a2e0#100";
+    let mut options = ToSciOptions::default();
+    test(pi, options, "3.141592653589793", Less);
+    options.set_precision(1);
+    test(pi, options, "3", Less);
+    options.set_precision(5);
+    test(pi, options, "3.1416", Greater);
+    for (rm, out, o) in [
+        (Floor, "3.1415", Less),
+        (Ceiling, "3.1416", Greater),
+        (Down, "3.1415", Less),
+        (Up, "3.1416", Greater),
+        (Nearest, "3.1416", Greater),
+        (NearestTiesAway, "3.1416", Greater),
+        (ToOdd, "3.1415", Less),
+    ] {
+        options.set_rounding_mode(rm);
+        test(pi, options, out, o);
+    }
+
+    let mut options = ToSciOptions::default();
+    options.set_scale(3);
+    test(pi, options, "3.142", Greater);
+    test(
+        "-0x3.243f6a8885a308d313198a2e0#100",
+        options,
+        "-3.142",
+        Less,
+    );
+    options.set_include_trailing_zeros(true);
+    test("0x2.8#4", options, "2.500", Equal);
+    test("0x0.0", options, "0.000", Equal);
+    test("-0x0.0", options, "-0.000", Equal);
+    test("NaN", options, "NaN", Equal);
+    test("-Infinity", options, "-Infinity", Equal);
+    options.set_scale(0);
+    options.set_include_trailing_zeros(false);
+    test("0x2.8#4", options, "2", Less);
+    options.set_rounding_mode(NearestTiesAway);
+    test("0x2.8#4", options, "3", Greater);
+    options.set_rounding_mode(ToOdd);
+    test("0x2.8#4", options, "3", Greater);
+    options.set_scale(2);
+    options.set_rounding_mode(Nearest);
+    test("0x0.00001#1", options, "0", Less);
+    options.set_rounding_mode(Up);
+    test("0x0.00001#1", options, "0.01", Greater);
+
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    test("0x0.00001#1", options, "9.5367431640625e-7", Equal);
+    test(
+        pi,
+        options,
+        concat!(
+            "3.141592653589793238462643383279333315643962021382226646945556858270265365717932581",
+            "90155029296875"
+        ),
+        Equal,
+    );
+    options.set_base(2);
+    test("0x2.8#4", options, "10.1", Equal);
+    options.set_base(3);
+    test("0x1.0E+3#1", options, "12121201", Equal);
+
+    let mut options = ToSciOptions::default();
+    options.set_base(16);
+    test(pi, options, "3.243f6a8885a308d", Less);
+    options.set_uppercase();
+    test(pi, options, "3.243F6A8885A308D", Less);
+    options.set_precision(3);
+    test("0x1.0E+100#1", options, "1e+100", Equal);
+    options.set_base(2);
+    options.set_e_uppercase();
+    test("0x1.0E+100#1", options, "1E400", Equal);
+    options.set_force_exponent_plus_sign(true);
+    test("0x1.0E+100#1", options, "1E+400", Equal);
+
+    let mut options = ToSciOptions::default();
+    test("0x0.00001#1", options, "9.5367431640625e-7", Equal);
+    options.set_neg_exp_threshold(-8);
+    test("0x0.00001#1", options, "0.00000095367431640625", Equal);
+}
+
+#[test]
+fn to_sci_with_options_fail() {
+    let mut options = ToSciOptions::default();
+    options.set_rounding_mode(Exact);
+    options.set_precision(2);
+    let x = parse_hex_string("0x3.243f6a8885a30#53");
+    assert!(!x.fmt_sci_valid(options));
+    assert_panic!(x.to_sci_with_options(options).to_string());
+    assert_panic!(x.to_sci_string_and_ordering(options));
+
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    options.set_base(3);
+    assert!(!x.fmt_sci_valid(options));
+    assert_panic!(x.to_sci_with_options(options).to_string());
+}
+
+fn test_options() -> Vec<ToSciOptions> {
+    let mut all_options = Vec::new();
+    for rm in exhaustive_rounding_modes() {
+        for base in [2, 3, 10, 16, 36] {
+            let mut options = ToSciOptions::default();
+            options.set_rounding_mode(rm);
+            options.set_base(base);
+            all_options.push(options);
+            options.set_precision(1);
+            all_options.push(options);
+            options.set_precision(30);
+            all_options.push(options);
+            options.set_scale(0);
+            all_options.push(options);
+            options.set_scale(5);
+            options.set_include_trailing_zeros(true);
+            all_options.push(options);
+            options.set_size_complete();
+            all_options.push(options);
+        }
+    }
+    all_options
+}
+
+fn to_sci_properties_helper(x: &Float, options: ToSciOptions) {
+    let valid = x.fmt_sci_valid(options);
+    if !x.is_finite() || *x == 0u32 {
+        assert!(valid);
+    }
+    if !valid {
+        return;
+    }
+    let s = x.to_sci_with_options(options).to_string();
+    let (s_alt, o) = x.to_sci_string_and_ordering(options);
+    assert_eq!(s_alt, s);
+    if x.is_nan() {
+        assert_eq!(s, "NaN");
+        assert_eq!(o, Equal);
+        return;
+    }
+    if x.is_infinite() {
+        assert_eq!(s, if *x > 0u32 { "Infinity" } else { "-Infinity" });
+        assert_eq!(o, Equal);
+        return;
+    }
+    if *x == 0u32 {
+        assert_eq!(o, Equal);
+        assert_eq!(s.starts_with('-'), x.is_sign_negative());
+    } else {
+        let q = Rational::exact_from(x);
+        assert!(q.fmt_sci_valid(options));
+        assert_eq!(q.to_sci_with_options(options).to_string(), s);
+        let mut from_options = FromSciStringOptions::default();
+        from_options.set_base(options.get_base());
+        let q_out = Rational::from_sci_string_with_options(&s, from_options).unwrap();
+        assert_eq!(q_out.partial_cmp(&q), Some(o));
+        let rm: RoundingMode = options.get_rounding_mode();
+        match (*x > 0u32, rm) {
+            (_, Floor) | (true, Down) | (false, Up) => assert_ne!(o, Greater),
+            (_, Ceiling) | (true, Up) | (false, Down) => assert_ne!(o, Less),
+            (_, Exact) => assert_eq!(o, Equal),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn to_sci_properties() {
+    let all_options = test_options();
+    float_gen().test_properties(|x| {
+        assert!(x.fmt_sci_valid(ToSciOptions::default()));
+        assert_eq!(
+            x.to_sci().to_string(),
+            x.to_sci_with_options(ToSciOptions::default()).to_string()
+        );
+        for &options in &all_options {
+            to_sci_properties_helper(&x, options);
+        }
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::conversion::string::to_string::to_string_naive;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::Float;
+use malachite_q::Rational;
+
+#[test]
+fn test_to_string() {
+    let test = |s_hex, out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_string_naive(&x), out);
+    };
+    test("NaN", "NaN");
+    test("Infinity", "Infinity");
+    test("-Infinity", "-Infinity");
+    test("0x0.0", "0.0");
+    test("-0x0.0", "-0.0");
+    test("0x1.0#1", "1.0");
+    test("-0x1.0#1", "-1.0");
+    test("0x1.0000000000000000000000000#100", "1.0");
+    test(
+        "0x0.555555555555555555555555555555558#128",
+        "0.333333333333333333333333333333333333334",
+    );
+    test("0x3.243f6a8885a30#53", "3.1415926535897931");
+    test(
+        "0x3.243f6a8885a308d313198a2e0#100",
+        "3.141592653589793238462643383279",
+    );
+    test("0x7.0#3", "7.0");
+    test("0x8.0#1", "8.0");
+    test("0x1.0E+3#1", "4.0e3");
+    test("0x0.001#1", "0.0002");
+    test("0x0.00001#1", "1.0e-6");
+    test("0x1.0E+100#1", "3.0e120");
+
+    // High-precision `Float`s
+    let x = Float::one_prec(10000);
+    assert_eq!(x.to_string(), "1.0");
+    let x = Float::from_rational_prec(Rational::from_unsigneds(1u32, 3u32), 10000).0;
+    let s = x.to_string();
+    assert!(s.starts_with("0.3333333333"));
+    assert_eq!(s.len(), 3013);
+    assert_eq!(to_string_naive(&x), s);
+    let y = Float::ONE - x;
+    let s = y.to_string();
+    assert!(s.starts_with("0.6666666666"));
+    assert_eq!(s.len(), 3013);
+    assert_eq!(to_string_naive(&y), s);
+}
+
+// Display is fast even for very high-precision `Float`s, but these take several seconds in debug
+// builds. Run them with `cargo test --release -- --ignored`.
+#[test]
+#[ignore]
+fn test_to_string_million_bits() {
+    let x = Float::one_prec(1000000);
+    assert_eq!(x.to_string(), "1.0");
+    let x = Float::from_rational_prec(Rational::from_unsigneds(1u32, 3u32), 1000000).0;
+    let s = x.to_string();
+    assert!(s.starts_with("0.3333333333"));
+    assert_eq!(s.len(), 301033);
+    let s = (Float::ONE - x).to_string();
+    assert!(s.starts_with("0.6666666666"));
+    assert_eq!(s.len(), 301032);
+}
+
+#[test]
+fn to_string_properties() {
+    float_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(to_string_naive(&x), s);
+        assert_eq!(format!("{x:?}"), s);
+        assert_eq!(
+            (-&x).to_string(),
+            if x.is_nan() {
+                s
+            } else if let Some(s) = s.strip_prefix('-') {
+                s.to_string()
+            } else {
+                format!("-{s}")
+            }
+        );
+    });
+}
@@ -0,0 +1,525 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{interval_gen, interval_pair_gen};
+use malachite_float::{ComparableFloat, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::panic::catch_unwind;
+
+const PRECS: [u64; 4] = [1, 10, 64, 100];
+
+fn interval(lo: &str, hi: &str) -> Interval {
+    Interval::new(parse_hex_string(lo), parse_hex_string(hi)).unwrap()
+}
+
+fn to_hex_strings(x: &Interval) -> (String, String) {
+    (to_hex_string(x.lo()), to_hex_string(x.hi()))
+}
+
+// The finite endpoints of an `Interval`, and its exact midpoint if it is bounded.
+fn sample_points(x: &Interval) -> Vec<Rational> {
+    let mut points = Vec::new();
+    for endpoint in [x.lo(), x.hi()] {
+        if endpoint.is_finite() {
+            points.push(Rational::exact_from(endpoint));
+        }
+    }
+    if x.is_bounded() {
+        points.push((Rational::exact_from(x.lo()) + Rational::exact_from(x.hi())) >> 1u32);
+    }
+    points
+}
+
+fn max_prec(x: &Interval, y: &Interval) -> u64 {
+    [x.lo(), x.hi(), y.lo(), y.hi()]
+        .into_iter()
+        .map(SignificantBits::significant_bits)
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |x: Interval, y: Interval, prec, out_lo: &str, out_hi: &str| {
+        let sum = x.add_prec(&y, prec);
+        assert_eq!(
+            to_hex_strings(&sum),
+            (out_lo.to_string(), out_hi.to_string())
+        );
+    };
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x1.8#2", "0x1.8#2"),
+        1,
+        "0x2.0#1",
+        "0x4.0#1",
+    );
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x1.8#2", "0x1.8#2"),
+        10,
+        "0x2.80#10",
+        "0x3.80#10",
+    );
+    test(
+        interval("-Infinity", "0x1.0#1"),
+        interval("0x1.0#1", "Infinity"),
+        10,
+        "-Infinity",
+        "Infinity",
+    );
+    test(
+        interval("-0x1.0#1", "0x0.0"),
+        interval("0x1.0#1", "0x1.0#1"),
+        10,
+        "0x0.0",
+        "0x1.000#10",
+    );
+}
+
+#[test]
+fn add_prec_fail() {
+    let x = Interval::from_float(Float::ONE).unwrap();
+    assert_panic!(x.add_prec(&x, 0));
+}
+
+#[test]
+fn test_sub_prec() {
+    let test = |x: Interval, y: Interval, prec, out_lo: &str, out_hi: &str| {
+        let difference = x.sub_prec(&y, prec);
+        assert_eq!(
+            to_hex_strings(&difference),
+            (out_lo.to_string(), out_hi.to_string())
+        );
+    };
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x1.8#2", "0x1.8#2"),
+        10,
+        "-0x0.800#10",
+        "0x0.800#10",
+    );
+    test(
+        interval("0x1.0#1", "0x1.0#1"),
+        interval("0x1.0#1", "0x1.0#1"),
+        10,
+        "0x0.0",
+        "0x0.0",
+    );
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x1.0#1", "Infinity"),
+        10,
+        "-Infinity",
+        "0x1.000#10",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |x: Interval, y: Interval, prec, out_lo: &str, out_hi: &str| {
+        let product = x.mul_prec(&y, prec);
+        assert_eq!(
+            to_hex_strings(&product),
+            (out_lo.to_string(), out_hi.to_string())
+        );
+    };
+    test(
+        interval("-0x2.0#1", "0x3.0#2"),
+        interval("-0x5.0#3", "0x1.8#2"),
+        10,
+        "-0xf.00#10",
+        "0xa.00#10",
+    );
+    test(
+        interval("-0x2.0#1", "0x3.0#2"),
+        interval("-0x5.0#3", "0x1.8#2"),
+        1,
+        "-0x1.0E+1#1",
+        "0x1.0E+1#1",
+    );
+    test(
+        interval("0x0.0", "0x0.0"),
+        interval("-Infinity", "Infinity"),
+        10,
+        "0x0.0",
+        "0x0.0",
+    );
+    test(
+        interval("0x0.0", "0x1.0#1"),
+        interval("0x1.0#1", "Infinity"),
+        10,
+        "0x0.0",
+        "Infinity",
+    );
+    test(
+        interval("-0x1.0#1", "0x0.0"),
+        interval("0x1.0#1", "Infinity"),
+        10,
+        "-Infinity",
+        "0x0.0",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |x: Interval, y: Interval, prec, out_lo: &str, out_hi: &str| {
+        let quotient = x.div_prec(&y, prec);
+        assert_eq!(
+            to_hex_strings(&quotient),
+            (out_lo.to_string(), out_hi.to_string())
+        );
+    };
+    test(
+        interval("0x1.0#1", "0x1.0#1"),
+        interval("0x3.0#2", "0x4.0#1"),
+        10,
+        "0x0.400#10",
+        "0x0.556#10",
+    );
+    test(
+        interval("0x1.0#1", "0x1.0#1"),
+        interval("-0x1.0#1", "0x4.0#1"),
+        10,
+        "-Infinity",
+        "Infinity",
+    );
+    test(
+        interval("0x1.0#1", "0x1.0#1"),
+        interval("0x0.0", "0x4.0#1"),
+        10,
+        "-Infinity",
+        "Infinity",
+    );
+    test(
+        interval("0x1.0#1", "Infinity"),
+        interval("0x1.0#1", "Infinity"),
+        10,
+        "0x0.0",
+        "Infinity",
+    );
+    test(
+        interval("-Infinity", "Infinity"),
+        interval("-Infinity", "-0x1.0#1"),
+        10,
+        "-Infinity",
+        "Infinity",
+    );
+    test(
+        interval("-0x3.0#2", "0x1.0#1"),
+        interval("-Infinity", "-0x2.0#1"),
+        10,
+        "-0x0.800#10",
+        "0x1.800#10",
+    );
+}
+
+#[test]
+fn test_neg() {
+    let test = |x: Interval, out_lo: &str, out_hi: &str| {
+        let out = (out_lo.to_string(), out_hi.to_string());
+        assert_eq!(to_hex_strings(&-&x), out);
+        assert_eq!(to_hex_strings(&-x), out);
+    };
+    test(interval("0x1.0#1", "0x2.0#1"), "-0x2.0#1", "-0x1.0#1");
+    test(interval("0x0.0", "0x0.0"), "0x0.0", "0x0.0");
+    test(interval("-Infinity", "0x0.0"), "0x0.0", "Infinity");
+}
+
+#[test]
+fn test_midpoint_prec_round() {
+    let test = |x: Interval, prec, rm, out: &str, o_out| {
+        let (mid, o) = x.midpoint_prec_round(prec, rm);
+        assert!(mid.is_valid());
+        assert_eq!(to_hex_string(&mid), out);
+        assert_eq!(o, o_out);
+    };
+    test(interval("0x1.0#1", "0x1.4#4"), 2, Floor, "0x1.0#2", Less);
+    test(
+        interval("0x1.0#1", "0x1.4#4"),
+        2,
+        Ceiling,
+        "0x1.8#2",
+        Greater,
+    );
+    test(
+        interval("0x1.0#1", "0x1.4#4"),
+        10,
+        Exact,
+        "0x1.200#10",
+        Equal,
+    );
+    test(interval("-0x1.0#1", "0x1.0#1"), 10, Floor, "0x0.0", Equal);
+    test(interval("-Infinity", "Infinity"), 10, Floor, "0x0.0", Equal);
+    test(
+        interval("-Infinity", "0x1.0#1"),
+        10,
+        Floor,
+        "-Infinity",
+        Equal,
+    );
+    test(
+        interval("0x1.0#1", "Infinity"),
+        10,
+        Floor,
+        "Infinity",
+        Equal,
+    );
+    test(
+        interval("0x1.0#1", "0x1.4#4"),
+        3,
+        NearestTiesAway,
+        "0x1.4#3",
+        Greater,
+    );
+    test(interval("0x1.0#1", "0x1.4#4"), 3, ToOdd, "0x1.4#3", Greater);
+}
+
+#[test]
+fn midpoint_prec_round_fail() {
+    assert_panic!(interval("0x1.0#1", "0x2.0#1").midpoint_prec_round(0, Floor));
+    assert_panic!(interval("0x1.0#1", "0x1.4#4").midpoint_prec_round(2, Exact));
+}
+
+#[test]
+fn test_radius_prec_round() {
+    let test = |x: Interval, prec, rm, out: &str, o_out| {
+        let (radius, o) = x.radius_prec_round(prec, rm);
+        assert!(radius.is_valid());
+        assert_eq!(to_hex_string(&radius), out);
+        assert_eq!(o, o_out);
+    };
+    test(
+        interval("0x1.0#1", "0x1.4#4"),
+        10,
+        Exact,
+        "0x0.200#10",
+        Equal,
+    );
+    test(interval("0x1.0#1", "0x4.0#1"), 1, Floor, "0x1.0#1", Less);
+    test(
+        interval("0x1.0#1", "0x4.0#1"),
+        1,
+        Ceiling,
+        "0x2.0#1",
+        Greater,
+    );
+    test(interval("0x1.0#1", "0x1.0#1"), 10, Floor, "0x0.0", Equal);
+    test(
+        interval("-Infinity", "0x1.0#1"),
+        10,
+        Floor,
+        "Infinity",
+        Equal,
+    );
+    test(
+        interval("0x1.0#1", "0x4.0#1"),
+        1,
+        NearestTiesAway,
+        "0x2.0#1",
+        Greater,
+    );
+    test(interval("0x1.0#1", "0x4.0#1"), 1, ToOdd, "0x1.0#1", Less);
+}
+
+#[test]
+fn radius_prec_round_fail() {
+    assert_panic!(interval("0x1.0#1", "0x2.0#1").radius_prec_round(0, Floor));
+    assert_panic!(interval("0x1.0#1", "0x4.0#1").radius_prec_round(1, Exact));
+}
+
+fn binary_op_properties_helper(
+    x: &Interval,
+    y: &Interval,
+    prec: u64,
+    f: &dyn Fn(&Interval, &Interval, u64) -> Interval,
+    g: &dyn Fn(&Rational, &Rational) -> Option<Rational>,
+) -> Interval {
+    let result = f(x, y, prec);
+    assert!(result.lo().is_valid());
+    assert!(result.hi().is_valid());
+    for endpoint in [result.lo(), result.hi()] {
+        if endpoint.is_finite() && *endpoint != 0u32 {
+            assert_eq!(endpoint.get_prec(), Some(prec));
+        }
+    }
+    for p in sample_points(x) {
+        for q in sample_points(y) {
+            if let Some(r) = g(&p, &q) {
+                assert!(result.contains(&r));
+            }
+        }
+    }
+    result
+}
+
+#[test]
+fn add_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in PRECS {
+            let sum =
+                binary_op_properties_helper(&x, &y, prec, &Interval::add_prec, &|p, q| Some(p + q));
+            assert_eq!(y.add_prec(&x, prec), sum);
+            assert_eq!(x.sub_prec(&-&y, prec), sum);
+            assert_eq!(
+                ComparableFloat(sum.lo().clone()),
+                ComparableFloat(
+                    x.lo()
+                        .add_prec_round_ref_ref(y.lo(), prec, Floor)
+                        .0
+                        .abs_negative_zero()
+                )
+            );
+            assert_eq!(
+                ComparableFloat(sum.hi().clone()),
+                ComparableFloat(
+                    x.hi()
+                        .add_prec_round_ref_ref(y.hi(), prec, Ceiling)
+                        .0
+                        .abs_negative_zero()
+                )
+            );
+        }
+        let sum = x.add_prec(&y, max_prec(&x, &y));
+        assert_eq!(&x + &y, sum);
+        assert_eq!(x + y, sum);
+    });
+
+    interval_gen().test_properties(|x| {
+        let zero = Interval::from_float(Float::ZERO).unwrap();
+        assert_eq!(&x + &zero, x);
+        assert!((&x - &x).contains(&Rational::ZERO));
+    });
+}
+
+#[test]
+fn sub_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in PRECS {
+            let difference =
+                binary_op_properties_helper(&x, &y, prec, &Interval::sub_prec, &|p, q| Some(p - q));
+            assert_eq!(-y.sub_prec(&x, prec), difference);
+        }
+        let difference = x.sub_prec(&y, max_prec(&x, &y));
+        assert_eq!(&x - &y, difference);
+        assert_eq!(x - y, difference);
+    });
+}
+
+#[test]
+fn mul_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in PRECS {
+            let product =
+                binary_op_properties_helper(&x, &y, prec, &Interval::mul_prec, &|p, q| Some(p * q));
+            assert_eq!(y.mul_prec(&x, prec), product);
+            assert_eq!(-(-&x).mul_prec(&y, prec), product);
+            if x.is_point() && y.is_point() {
+                assert_eq!(
+                    *product.lo(),
+                    x.lo().mul_prec_round_ref_ref(y.lo(), prec, Floor).0
+                );
+                assert_eq!(
+                    *product.hi(),
+                    x.lo().mul_prec_round_ref_ref(y.lo(), prec, Ceiling).0
+                );
+            }
+        }
+        let product = x.mul_prec(&y, max_prec(&x, &y));
+        assert_eq!(&x * &y, product);
+        assert_eq!(x * y, product);
+    });
+
+    interval_gen().test_properties(|x| {
+        let one = Interval::from_float(Float::ONE).unwrap();
+        assert_eq!(&x * &one, x);
+        let zero = Interval::from_float(Float::ZERO).unwrap();
+        assert_eq!(&x * &zero, zero);
+    });
+}
+
+#[test]
+fn div_prec_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        for prec in PRECS {
+            let quotient =
+                binary_op_properties_helper(&x, &y, prec, &Interval::div_prec, &|p, q| {
+                    if *q == 0u32 {
+                        None
+                    } else {
+                        Some(p / q)
+                    }
+                });
+            if y.contains(&Rational::ZERO) {
+                assert_eq!(quotient, Interval::entire());
+            } else {
+                assert_eq!(-(-&x).div_prec(&y, prec), quotient);
+            }
+        }
+        let quotient = x.div_prec(&y, max_prec(&x, &y));
+        assert_eq!(&x / &y, quotient);
+        assert_eq!(x / y, quotient);
+    });
+
+    interval_gen().test_properties(|x| {
+        let one = Interval::from_float(Float::ONE).unwrap();
+        assert_eq!(&x / &one, x);
+    });
+}
+
+#[test]
+fn neg_properties() {
+    interval_gen().test_properties(|x| {
+        let neg = -&x;
+        assert_eq!(-x.clone(), neg);
+        assert_eq!(-&neg, x);
+        for p in sample_points(&x) {
+            assert!(neg.contains(&-p));
+        }
+    });
+}
+
+#[test]
+fn midpoint_and_radius_properties() {
+    interval_gen().test_properties(|x| {
+        for prec in PRECS {
+            let (mid_lo, o_lo) = x.midpoint_prec_round(prec, Floor);
+            let (mid_hi, o_hi) = x.midpoint_prec_round(prec, Ceiling);
+            assert!(mid_lo <= mid_hi);
+            let (mid, o) = x.midpoint_prec(prec);
+            assert_eq!(
+                ComparableFloat(x.midpoint_prec_round(prec, Nearest).0),
+                ComparableFloat(mid.clone())
+            );
+            let (radius, o_radius) = x.radius_prec_round(prec, Ceiling);
+            assert_ne!(o_radius, Less);
+            assert!(radius >= 0u32);
+            if x.is_bounded() {
+                assert_ne!(o_lo, Greater);
+                assert_ne!(o_hi, Less);
+                let exact_mid =
+                    (Rational::exact_from(x.lo()) + Rational::exact_from(x.hi())) >> 1u32;
+                assert_eq!(mid.partial_cmp(&exact_mid), Some(o));
+                let radius = Rational::exact_from(&radius);
+                assert!(Rational::exact_from(x.hi()) - &exact_mid <= radius);
+                assert!(&exact_mid - Rational::exact_from(x.lo()) <= radius);
+                let (radius_alt, _) = x.radius_prec(prec);
+                assert!(Rational::exact_from(&radius_alt) <= radius);
+            } else {
+                assert_eq!(radius, Float::INFINITY);
+            }
+        }
+    });
+}
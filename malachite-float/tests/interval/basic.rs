@@ -0,0 +1,146 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NegativeInfinity};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::{float_gen, float_pair_gen, interval_gen};
+use malachite_float::{ComparableFloatRef, Float};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_new() {
+    let test = |s, t, out: Option<(&str, &str)>| {
+        let lo = parse_hex_string(s);
+        let hi = parse_hex_string(t);
+        let x = Interval::new(lo, hi);
+        assert_eq!(
+            x.map(|x| (to_hex_string(x.lo()), to_hex_string(x.hi()))),
+            out.map(|(lo, hi)| (lo.to_string(), hi.to_string()))
+        );
+    };
+    test("0x0.0", "0x1.0#1", Some(("0x0.0", "0x1.0#1")));
+    test("-0x0.0", "-0x0.0", Some(("0x0.0", "0x0.0")));
+    test("-0x1.0#1", "-0x0.0", Some(("-0x1.0#1", "0x0.0")));
+    test("-Infinity", "Infinity", Some(("-Infinity", "Infinity")));
+    test("-Infinity", "-0x1.0#1", Some(("-Infinity", "-0x1.0#1")));
+    test("0x1.8#2", "0x1.8#2", Some(("0x1.8#2", "0x1.8#2")));
+
+    test("0x1.0#1", "0x0.0", None);
+    test("NaN", "0x1.0#1", None);
+    test("0x1.0#1", "NaN", None);
+    test("Infinity", "Infinity", None);
+    test("-Infinity", "-Infinity", None);
+}
+
+#[test]
+fn test_from_float() {
+    let test = |s, out: Option<&str>| {
+        let x = Interval::from_float(parse_hex_string(s));
+        assert_eq!(x.map(|x| x.to_string()), out.map(ToString::to_string));
+    };
+    test("0x1.8#2", Some("[1.5, 1.5]"));
+    test("-0x0.0", Some("[0.0, 0.0]"));
+    test("NaN", None);
+    test("Infinity", None);
+    test("-Infinity", None);
+}
+
+#[test]
+fn test_from_rational_prec() {
+    let test = |s, prec, out_lo, out_hi| {
+        let x = Interval::from_rational_prec(&Rational::from_str(s).unwrap(), prec);
+        assert_eq!(to_hex_string(x.lo()), out_lo);
+        assert_eq!(to_hex_string(x.hi()), out_hi);
+    };
+    test("0", 10, "0x0.0", "0x0.0");
+    test("1/3", 1, "0x0.4#1", "0x0.8#1");
+    test("1/3", 10, "0x0.554#10", "0x0.556#10");
+    test("-1/3", 10, "-0x0.556#10", "-0x0.554#10");
+    test("3/4", 10, "0x0.c00#10", "0x0.c00#10");
+}
+
+#[test]
+fn from_rational_prec_fail() {
+    assert_panic!(Interval::from_rational_prec(&Rational::from(1), 0));
+}
+
+#[test]
+fn test_entire() {
+    let x = Interval::entire();
+    assert_eq!(*x.lo(), Float::NEGATIVE_INFINITY);
+    assert_eq!(*x.hi(), Float::INFINITY);
+    assert!(!x.is_bounded());
+    assert!(!x.is_point());
+}
+
+#[test]
+fn interval_properties() {
+    interval_gen().test_properties(|x| {
+        assert!(x.lo().is_valid());
+        assert!(x.hi().is_valid());
+        assert!(x.lo() <= x.hi());
+        assert!(*x.lo() != Float::INFINITY);
+        assert!(*x.hi() != Float::NEGATIVE_INFINITY);
+        assert!(!x.lo().is_sign_negative() || *x.lo() != 0u32);
+        assert!(!x.hi().is_sign_negative() || *x.hi() != 0u32);
+        assert_eq!(x.is_bounded(), x.lo().is_finite() && x.hi().is_finite());
+        assert_eq!(x, x.clone());
+
+        let (lo, hi) = x.clone().into_endpoints();
+        let y = Interval::new(lo, hi).unwrap();
+        assert_eq!(ComparableFloatRef(y.lo()), ComparableFloatRef(x.lo()));
+        assert_eq!(ComparableFloatRef(y.hi()), ComparableFloatRef(x.hi()));
+        assert_eq!(x.to_string(), format!("[{}, {}]", x.lo(), x.hi()));
+        assert_eq!(format!("{x:?}"), x.to_string());
+    });
+
+    float_pair_gen().test_properties(|(lo, hi)| {
+        if let Some(x) = Interval::new(lo.clone(), hi.clone()) {
+            assert_eq!(*x.lo(), lo);
+            assert_eq!(*x.hi(), hi);
+        } else {
+            assert!(
+                lo.is_nan()
+                    || hi.is_nan()
+                    || lo > hi
+                    || lo == Float::INFINITY
+                    || hi == Float::NEGATIVE_INFINITY
+            );
+        }
+    });
+
+    float_gen().test_properties(|x| {
+        if let Some(y) = Interval::from_float(x.clone()) {
+            assert!(y.is_point());
+            assert!(y.contains_float(&x));
+        } else {
+            assert!(!x.is_finite());
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        for prec in [1, 10, 64, 100] {
+            let y = Interval::from_rational_prec(&x, prec);
+            assert!(y.contains(&x));
+            assert!(y.is_bounded());
+            let (lo, o) = Float::from_rational_prec_round_ref(&x, prec, Floor);
+            assert_eq!(*y.lo(), lo);
+            assert_eq!(y.is_point(), o == Equal);
+            assert_eq!(
+                *y.hi(),
+                Float::from_rational_prec_round_ref(&x, prec, Ceiling).0
+            );
+        }
+    });
+}
@@ -0,0 +1,257 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_float::interval::Interval;
+use malachite_float::test_util::common::parse_hex_string;
+use malachite_float::test_util::generators::{float_gen, interval_gen, interval_pair_gen};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn interval(lo: &str, hi: &str) -> Interval {
+    Interval::new(parse_hex_string(lo), parse_hex_string(hi)).unwrap()
+}
+
+#[test]
+fn test_order_predicates() {
+    let test = |x: Interval, y: Interval, c_lt, c_le, p_lt, p_le| {
+        assert_eq!(x.certainly_lt(&y), c_lt);
+        assert_eq!(x.certainly_le(&y), c_le);
+        assert_eq!(x.possibly_lt(&y), p_lt);
+        assert_eq!(x.possibly_le(&y), p_le);
+    };
+    let one_two = interval("0x1.0#1", "0x2.0#1");
+    let two_three = interval("0x2.0#1", "0x3.0#2");
+    let three_four = interval("0x3.0#2", "0x4.0#1");
+    let two = interval("0x2.0#1", "0x2.0#1");
+    let entire = Interval::entire();
+    test(one_two.clone(), three_four.clone(), true, true, true, true);
+    test(
+        three_four.clone(),
+        one_two.clone(),
+        false,
+        false,
+        false,
+        false,
+    );
+    test(one_two.clone(), two_three.clone(), false, true, true, true);
+    test(
+        two_three.clone(),
+        one_two.clone(),
+        false,
+        false,
+        false,
+        true,
+    );
+    test(two.clone(), two.clone(), false, true, false, true);
+    test(one_two.clone(), entire.clone(), false, false, true, true);
+    test(entire.clone(), entire, false, false, true, true);
+    test(
+        interval("-Infinity", "0x1.0#1"),
+        interval("0x2.0#1", "Infinity"),
+        true,
+        true,
+        true,
+        true,
+    );
+}
+
+#[test]
+fn test_contains() {
+    let test = |x: Interval, s, out| {
+        assert_eq!(x.contains(&Rational::from_str(s).unwrap()), out);
+    };
+    test(interval("0x1.0#1", "0x2.0#1"), "1", true);
+    test(interval("0x1.0#1", "0x2.0#1"), "3/2", true);
+    test(interval("0x1.0#1", "0x2.0#1"), "2", true);
+    test(interval("0x1.0#1", "0x2.0#1"), "5/2", false);
+    test(interval("0x1.0#1", "0x2.0#1"), "-1", false);
+    test(interval("0x0.554#10", "0x0.556#10"), "1/3", true);
+    test(interval("-Infinity", "0x0.0"), "-1000000", true);
+    test(interval("-Infinity", "0x0.0"), "1/1000000", false);
+    test(Interval::entire(), "-1/3", true);
+}
+
+#[test]
+fn test_contains_float() {
+    let test = |x: Interval, s, out| {
+        assert_eq!(x.contains_float(&parse_hex_string(s)), out);
+    };
+    test(interval("0x1.0#1", "0x2.0#1"), "0x1.8#2", true);
+    test(interval("0x1.0#1", "0x2.0#1"), "0x3.0#2", false);
+    test(interval("-0x1.0#1", "0x0.0"), "-0x0.0", true);
+    test(Interval::entire(), "Infinity", false);
+    test(Interval::entire(), "-Infinity", false);
+    test(Interval::entire(), "NaN", false);
+}
+
+#[test]
+fn test_is_subset() {
+    let test = |x: Interval, y: Interval, out| {
+        assert_eq!(x.is_subset(&y), out);
+    };
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x0.0", "0x3.0#2"),
+        true,
+    );
+    test(
+        interval("0x0.0", "0x3.0#2"),
+        interval("0x1.0#1", "0x2.0#1"),
+        false,
+    );
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x1.0#1", "0x2.0#1"),
+        true,
+    );
+    test(
+        interval("0x1.0#1", "0x3.0#2"),
+        interval("0x2.0#1", "0x4.0#1"),
+        false,
+    );
+    test(interval("0x1.0#1", "0x3.0#2"), Interval::entire(), true);
+}
+
+#[test]
+fn test_intersection() {
+    let test = |x: Interval, y: Interval, out: Option<&str>| {
+        assert_eq!(
+            x.intersection(&y).map(|z| z.to_string()),
+            out.map(ToString::to_string)
+        );
+    };
+    test(
+        interval("0x1.0#1", "0x3.0#2"),
+        interval("0x2.0#1", "0x4.0#1"),
+        Some("[2.0, 3.0]"),
+    );
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x2.0#1", "0x4.0#1"),
+        Some("[2.0, 2.0]"),
+    );
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x3.0#2", "0x4.0#1"),
+        None,
+    );
+    test(
+        interval("-Infinity", "0x2.0#1"),
+        interval("0x1.0#1", "Infinity"),
+        Some("[1.0, 2.0]"),
+    );
+}
+
+#[test]
+fn test_hull() {
+    let test = |x: Interval, y: Interval, out: &str| {
+        assert_eq!(x.hull(&y).to_string(), out);
+    };
+    test(
+        interval("0x1.0#1", "0x2.0#1"),
+        interval("0x3.0#2", "0x4.0#1"),
+        "[1.0, 4.0]",
+    );
+    test(
+        interval("0x1.0#1", "0x4.0#1"),
+        interval("0x2.0#1", "0x3.0#2"),
+        "[1.0, 4.0]",
+    );
+    test(
+        interval("-Infinity", "0x1.0#1"),
+        interval("0x2.0#1", "0x3.0#2"),
+        "[-Infinity, 3.0]",
+    );
+}
+
+#[test]
+fn order_predicate_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let c_lt = x.certainly_lt(&y);
+        let c_le = x.certainly_le(&y);
+        let p_lt = x.possibly_lt(&y);
+        let p_le = x.possibly_le(&y);
+        assert!(!c_lt || c_le);
+        assert!(!c_le || p_le);
+        assert!(!c_lt || p_lt);
+        assert!(!p_lt || p_le);
+        assert_eq!(c_lt, !y.possibly_le(&x));
+        assert_eq!(c_le, !y.possibly_lt(&x));
+        assert_eq!((-&y).certainly_lt(&-&x), c_lt);
+        assert_eq!((-&y).possibly_le(&-&x), p_le);
+        if x.is_point() && y.is_point() {
+            assert_eq!(c_lt, p_lt);
+            assert_eq!(c_le, p_le);
+            assert_eq!(c_lt, x.lo() < y.lo());
+        }
+    });
+
+    interval_gen().test_properties(|x| {
+        assert!(x.possibly_le(&x));
+        assert_eq!(x.certainly_le(&x), x.is_point());
+        assert!(!x.certainly_lt(&x));
+    });
+}
+
+#[test]
+fn contains_properties() {
+    interval_gen().test_properties(|x| {
+        for endpoint in [x.lo(), x.hi()] {
+            if endpoint.is_finite() {
+                assert!(x.contains_float(endpoint));
+                assert!(x.contains(&Rational::exact_from(endpoint)));
+            }
+        }
+    });
+
+    float_gen().test_properties(|x| {
+        assert_eq!(Interval::entire().contains_float(&x), x.is_finite());
+    });
+
+    rational_gen().test_properties(|x| {
+        assert!(Interval::entire().contains(&x));
+        let y = Interval::from_rational_prec(&x, 10);
+        assert!(y.contains(&x));
+        if let Some(z) = Interval::from_float(y.lo().clone()) {
+            assert_eq!(z.contains(&x), y.is_point());
+        }
+    });
+}
+
+#[test]
+fn intersection_and_hull_properties() {
+    interval_pair_gen().test_properties(|(x, y)| {
+        let intersection = x.intersection(&y);
+        assert_eq!(y.intersection(&x), intersection);
+        if let Some(intersection) = &intersection {
+            assert!(intersection.is_subset(&x));
+            assert!(intersection.is_subset(&y));
+            assert!(x.possibly_le(&y));
+            assert!(y.possibly_le(&x));
+        } else {
+            assert!(x.certainly_lt(&y) || y.certainly_lt(&x));
+        }
+        assert_eq!(x.is_subset(&y), intersection.as_ref() == Some(&x));
+
+        let hull = x.hull(&y);
+        assert_eq!(y.hull(&x), hull);
+        assert!(x.is_subset(&hull));
+        assert!(y.is_subset(&hull));
+        assert_eq!(x.is_subset(&y), hull == y);
+    });
+
+    interval_gen().test_properties(|x| {
+        assert_eq!(x.intersection(&x).as_ref(), Some(&x));
+        assert_eq!(x.hull(&x), x);
+        assert!(x.is_subset(&x));
+        assert!(x.is_subset(&Interval::entire()));
+        assert_eq!(Interval::entire().is_subset(&x), x == Interval::entire());
+    });
+}
@@ -63,17 +63,30 @@ extern crate malachite_base;
 pub mod arithmetic {
     pub mod abs;
     pub mod add;
+    pub mod asin;
+    pub mod atan;
     pub mod div;
+    pub mod exp;
     pub mod is_power_of_2;
+    pub mod ln;
     pub mod mul;
+    pub mod mul_add;
     pub mod neg;
+    pub mod pow;
     pub mod power_of_2;
+    pub mod product;
     pub mod reciprocal;
+    pub mod rem;
+    pub mod round_to_integral;
     pub mod shl;
     pub mod shr;
     pub mod sign;
+    pub mod sin_cos;
+    pub mod sqrt;
     pub mod square;
+    pub mod sum;
     pub mod sub;
+    pub mod tan;
 }
 pub mod basic {
     pub mod classification;
@@ -105,10 +118,24 @@ pub mod comparison {
     pub mod partial_eq_primitive_int;
     pub mod partial_eq_rational;
 }
+pub mod complex {
+    pub mod arithmetic;
+    pub mod basic;
+}
 pub mod constants {
+    pub mod catalan;
+    pub mod e;
+    pub mod euler_gamma;
+    pub mod ln_2;
+    pub mod pi;
     pub mod prime_constant;
+    pub mod sqrt_2;
     pub mod thue_morse_constant;
 }
+pub mod context {
+    pub mod arithmetic;
+    pub mod basic;
+}
 pub mod conversion {
     pub mod clone;
     pub mod from_integer;
@@ -116,12 +143,19 @@ pub mod conversion {
     pub mod from_primitive_float;
     pub mod from_primitive_int;
     pub mod from_rational;
+    pub mod ieee_bits;
     pub mod integer_from_float;
     pub mod mantissa_and_exponent;
     pub mod natural_from_float;
     pub mod primitive_float_from_float;
     pub mod primitive_int_from_float;
     pub mod rational_from_float;
+    pub mod serde;
+    pub mod string {
+        pub mod from_sci_string;
+        pub mod to_sci;
+        pub mod to_string;
+    }
 }
 pub mod exhaustive {
     pub mod exhaustive_finite_floats;
@@ -134,6 +168,11 @@ pub mod exhaustive {
     pub mod exhaustive_positive_floats_with_sci_exponent;
     pub mod exhaustive_positive_floats_with_sci_exponent_and_precision;
 }
+pub mod interval {
+    pub mod arithmetic;
+    pub mod basic;
+    pub mod comparison;
+}
 pub mod random {
     pub mod random_finite_floats;
     pub mod random_floats;
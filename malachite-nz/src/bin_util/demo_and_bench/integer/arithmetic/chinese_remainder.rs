@@ -0,0 +1,54 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::bench::bucketers::vec_pair_2_natural_sum_bits_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_vec_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_chinese_remainder);
+
+    register_bench!(runner, benchmark_integer_chinese_remainder);
+}
+
+fn demo_integer_chinese_remainder(gm: GenMode, config: &GenConfig, limit: usize) {
+    for pairs in integer_natural_pair_vec_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "Integer::chinese_remainder({:?}) = {:?}",
+            pairs,
+            Integer::chinese_remainder(&pairs)
+        );
+    }
+}
+
+fn benchmark_integer_chinese_remainder(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::chinese_remainder(&[(Integer, Natural)])",
+        BenchmarkType::Single,
+        integer_natural_pair_vec_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_pair_2_natural_sum_bits_bucketer(),
+        &mut [("Malachite", &mut |pairs| {
+            no_out!(Integer::chinese_remainder(&pairs))
+        })],
+    );
+}
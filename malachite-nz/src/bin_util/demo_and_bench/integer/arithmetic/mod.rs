@@ -14,6 +14,7 @@ pub(crate) fn register(runner: &mut Runner) {
     add::register(runner);
     add_mul::register(runner);
     binomial_coefficient::register(runner);
+    chinese_remainder::register(runner);
     div::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
@@ -24,8 +25,17 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     kronecker_symbol::register(runner);
+    mod_add::register(runner);
+    mod_inverse::register(runner);
+    mod_mul::register(runner);
+    mod_neg::register(runner);
     mod_op::register(runner);
+    mod_pow::register(runner);
     mod_power_of_2::register(runner);
+    mod_shl::register(runner);
+    mod_shr::register(runner);
+    mod_square::register(runner);
+    mod_sub::register(runner);
     mul::register(runner);
     neg::register(runner);
     parity::register(runner);
@@ -50,6 +60,7 @@ mod abs_diff;
 mod add;
 mod add_mul;
 mod binomial_coefficient;
+mod chinese_remainder;
 mod div;
 mod div_exact;
 mod div_mod;
@@ -60,8 +71,17 @@ mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod kronecker_symbol;
+mod mod_add;
+mod mod_inverse;
+mod mod_mul;
+mod mod_neg;
 mod mod_op;
+mod mod_pow;
 mod mod_power_of_2;
+mod mod_shl;
+mod mod_shr;
+mod mod_square;
+mod mod_sub;
 mod mul;
 mod neg;
 mod parity;
@@ -0,0 +1,82 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_inverse);
+    register_demo!(runner, demo_integer_mod_inverse_val_ref);
+    register_demo!(runner, demo_integer_mod_inverse_ref_val);
+    register_demo!(runner, demo_integer_mod_inverse_ref_ref);
+
+    register_bench!(runner, benchmark_integer_mod_inverse_evaluation_strategy);
+}
+
+fn demo_integer_mod_inverse(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("1/({}) ≡ {:?} mod {}", x_old, x.mod_inverse(m), m_old);
+    }
+}
+
+fn demo_integer_mod_inverse_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("1/({}) ≡ {:?} mod {}", x_old, x.mod_inverse(&m), m);
+    }
+}
+
+fn demo_integer_mod_inverse_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let m_old = m.clone();
+        println!("1/({}) ≡ {:?} mod {}", x, (&x).mod_inverse(m), m_old);
+    }
+}
+
+fn demo_integer_mod_inverse_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        println!("1/({}) ≡ {:?} mod {}", x, (&x).mod_inverse(&m), m);
+    }
+}
+
+fn benchmark_integer_mod_inverse_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_inverse(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_inverse(Natural)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(m))
+            }),
+            ("Integer.mod_inverse(&Natural)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(&m))
+            }),
+            ("(&Integer).mod_inverse(Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(m))
+            }),
+            ("(&Integer).mod_inverse(&Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(&m))
+            }),
+        ],
+    );
+}
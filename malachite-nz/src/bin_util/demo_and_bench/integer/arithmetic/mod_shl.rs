@@ -0,0 +1,396 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModShl, ModShlAssign};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_natural_signed_triple_gen_var_1, integer_natural_unsigned_triple_gen_var_1,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_integer_mod_shl_assign_unsigned);
+    register_unsigned_demos!(runner, demo_integer_mod_shl_assign_unsigned_ref);
+    register_unsigned_demos!(runner, demo_integer_mod_shl_unsigned);
+    register_unsigned_demos!(runner, demo_integer_mod_shl_unsigned_val_ref);
+    register_unsigned_demos!(runner, demo_integer_mod_shl_unsigned_ref_val);
+    register_unsigned_demos!(runner, demo_integer_mod_shl_unsigned_ref_ref);
+    register_signed_demos!(runner, demo_integer_mod_shl_assign_signed);
+    register_signed_demos!(runner, demo_integer_mod_shl_assign_signed_ref);
+    register_signed_demos!(runner, demo_integer_mod_shl_signed);
+    register_signed_demos!(runner, demo_integer_mod_shl_signed_val_ref);
+    register_signed_demos!(runner, demo_integer_mod_shl_signed_ref_val);
+    register_signed_demos!(runner, demo_integer_mod_shl_signed_ref_ref);
+
+    register_unsigned_benches!(
+        runner,
+        benchmark_integer_mod_shl_assign_unsigned_evaluation_strategy
+    );
+    register_unsigned_benches!(
+        runner,
+        benchmark_integer_mod_shl_unsigned_evaluation_strategy
+    );
+    register_signed_benches!(
+        runner,
+        benchmark_integer_mod_shl_assign_signed_evaluation_strategy
+    );
+    register_signed_benches!(runner, benchmark_integer_mod_shl_signed_evaluation_strategy);
+}
+
+fn demo_integer_mod_shl_assign_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Integer: ModShlAssign<T, Natural>,
+{
+    for (mut x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shl_assign(u, m.clone());
+        println!("x := {x_old}; x.mod_shl_assign({u}, {m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shl_assign_unsigned_ref<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> Integer: ModShlAssign<T, &'a Natural>,
+{
+    for (mut x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shl_assign(u, &m);
+        println!("x := {x_old}; x.mod_shl_assign({u}, &{m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shl_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Integer: ModShl<T, Natural, Output = Natural>,
+{
+    for (x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("{}.mod_shl({}, {}) = {}", x_old, u, m_old, x.mod_shl(u, m));
+    }
+}
+
+fn demo_integer_mod_shl_unsigned_val_ref<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> Integer: ModShl<T, &'a Natural, Output = Natural>,
+{
+    for (x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("{}.mod_shl({}, &{}) = {}", x_old, u, m, x.mod_shl(u, &m));
+    }
+}
+
+fn demo_integer_mod_shl_unsigned_ref_val<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> &'a Integer: ModShl<T, Natural, Output = Natural>,
+{
+    for (x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!(
+            "(&{}).mod_shl({}, {}) = {}",
+            x,
+            u,
+            m_old,
+            (&x).mod_shl(u, m)
+        );
+    }
+}
+
+fn demo_integer_mod_shl_unsigned_ref_ref<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a, 'b> &'a Integer: ModShl<T, &'b Natural, Output = Natural>,
+{
+    for (x, m, u) in integer_natural_unsigned_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("(&{}).mod_shl({}, &{}) = {}", x, u, m, (&x).mod_shl(u, &m));
+    }
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shl_assign_unsigned_evaluation_strategy<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer: ModShlAssign<T, Natural> + ModShlAssign<T, &'a Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shl_assign({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_unsigned_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("u"),
+        &mut [
+            (
+                &format!("Integer.mod_shl_assign({}, Natural)", T::NAME),
+                &mut |(mut x, m, u)| no_out!(x.mod_shl_assign(u, m)),
+            ),
+            (
+                &format!("Integer.mod_shl_assign({}, &Natural)", T::NAME),
+                &mut |(mut x, m, u)| no_out!(x.mod_shl_assign(u, &m)),
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shl_unsigned_evaluation_strategy<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer:
+        ModShl<T, Natural, Output = Natural> + ModShl<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShl<T, Natural, Output = Natural> + ModShl<T, &'b Natural, Output = Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shl({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_unsigned_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("u"),
+        &mut [
+            (
+                &format!("Integer.mod_shl({}, Natural)", T::NAME),
+                &mut |(x, m, u)| no_out!(x.mod_shl(u, m)),
+            ),
+            (
+                &format!("Integer.mod_shl({}, &Natural)", T::NAME),
+                &mut |(x, m, u)| no_out!(x.mod_shl(u, &m)),
+            ),
+            (
+                &format!("(&Integer).mod_shl({}, Natural)", T::NAME),
+                &mut |(x, m, u)| no_out!((&x).mod_shl(u, m)),
+            ),
+            (
+                &format!("(&Integer).mod_shl({}, &Natural)", T::NAME),
+                &mut |(x, m, u)| no_out!((&x).mod_shl(u, &m)),
+            ),
+        ],
+    );
+}
+
+fn demo_integer_mod_shl_assign_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    Integer: ModShlAssign<T, Natural>,
+{
+    for (mut x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shl_assign(i, m.clone());
+        println!("x := {x_old}; x.mod_shl_assign({i}, {m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shl_assign_signed_ref<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> Integer: ModShlAssign<T, &'a Natural>,
+{
+    for (mut x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shl_assign(i, &m);
+        println!("x := {x_old}; x.mod_shl_assign({i}, &{m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shl_signed<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Integer: ModShl<T, Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("{}.mod_shl({}, {}) = {}", x_old, i, m_old, x.mod_shl(i, m));
+    }
+}
+
+fn demo_integer_mod_shl_signed_val_ref<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> Integer: ModShl<T, &'a Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("{}.mod_shl({}, &{}) = {}", x_old, i, m, x.mod_shl(i, &m));
+    }
+}
+
+fn demo_integer_mod_shl_signed_ref_val<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> &'a Integer: ModShl<T, Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!(
+            "(&{}).mod_shl({}, {}) = {}",
+            x,
+            i,
+            m_old,
+            (&x).mod_shl(i, m)
+        );
+    }
+}
+
+fn demo_integer_mod_shl_signed_ref_ref<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a, 'b> &'a Integer: ModShl<T, &'b Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("(&{}).mod_shl({}, &{}) = {}", x, i, m, (&x).mod_shl(i, &m));
+    }
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shl_assign_signed_evaluation_strategy<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer: ModShlAssign<T, Natural> + ModShlAssign<T, &'a Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shl_assign({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_signed_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("i"),
+        &mut [
+            (
+                &format!("Integer.mod_shl_assign({}, Natural)", T::NAME),
+                &mut |(mut x, m, i)| no_out!(x.mod_shl_assign(i, m)),
+            ),
+            (
+                &format!("Integer.mod_shl_assign({}, &Natural)", T::NAME),
+                &mut |(mut x, m, i)| no_out!(x.mod_shl_assign(i, &m)),
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shl_signed_evaluation_strategy<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer:
+        ModShl<T, Natural, Output = Natural> + ModShl<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShl<T, Natural, Output = Natural> + ModShl<T, &'b Natural, Output = Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shl({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_signed_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("i"),
+        &mut [
+            (
+                &format!("Integer.mod_shl({}, Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!(x.mod_shl(i, m)),
+            ),
+            (
+                &format!("Integer.mod_shl({}, &Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!(x.mod_shl(i, &m)),
+            ),
+            (
+                &format!("(&Integer).mod_shl({}, Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!((&x).mod_shl(i, m)),
+            ),
+            (
+                &format!("(&Integer).mod_shl({}, &Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!((&x).mod_shl(i, &m)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,189 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModShr, ModShrAssign};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_signed_triple_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_signed_demos!(runner, demo_integer_mod_shr_assign);
+    register_signed_demos!(runner, demo_integer_mod_shr_assign_ref);
+    register_signed_demos!(runner, demo_integer_mod_shr);
+    register_signed_demos!(runner, demo_integer_mod_shr_val_ref);
+    register_signed_demos!(runner, demo_integer_mod_shr_ref_val);
+    register_signed_demos!(runner, demo_integer_mod_shr_ref_ref);
+
+    register_signed_benches!(runner, benchmark_integer_mod_shr_assign_evaluation_strategy);
+    register_signed_benches!(runner, benchmark_integer_mod_shr_evaluation_strategy);
+}
+
+fn demo_integer_mod_shr_assign<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Integer: ModShrAssign<T, Natural>,
+{
+    for (mut x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shr_assign(i, m.clone());
+        println!("x := {x_old}; x.mod_shr_assign({i}, {m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shr_assign_ref<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) where
+    for<'a> Integer: ModShrAssign<T, &'a Natural>,
+{
+    for (mut x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.mod_shr_assign(i, &m);
+        println!("x := {x_old}; x.mod_shr_assign({i}, &{m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_shr<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    Integer: ModShr<T, Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("{}.mod_shr({}, {}) = {}", x_old, i, m_old, x.mod_shr(i, m));
+    }
+}
+
+fn demo_integer_mod_shr_val_ref<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    for<'a> Integer: ModShr<T, &'a Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("{}.mod_shr({}, &{}) = {}", x_old, i, m, x.mod_shr(i, &m));
+    }
+}
+
+fn demo_integer_mod_shr_ref_val<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    for<'a> &'a Integer: ModShr<T, Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!(
+            "(&{}).mod_shr({}, {}) = {}",
+            x,
+            i,
+            m_old,
+            (&x).mod_shr(i, m)
+        );
+    }
+}
+
+fn demo_integer_mod_shr_ref_ref<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize)
+where
+    for<'a, 'b> &'a Integer: ModShr<T, &'b Natural, Output = Natural>,
+{
+    for (x, m, i) in integer_natural_signed_triple_gen_var_1::<T>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("(&{}).mod_shr({}, &{}) = {}", x, i, m, (&x).mod_shr(i, &m));
+    }
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shr_assign_evaluation_strategy<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer: ModShrAssign<T, Natural> + ModShrAssign<T, &'a Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shr_assign({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_signed_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("i"),
+        &mut [
+            (
+                &format!("Integer.mod_shr_assign({}, Natural)", T::NAME),
+                &mut |(mut x, m, i)| no_out!(x.mod_shr_assign(i, m)),
+            ),
+            (
+                &format!("Integer.mod_shr_assign({}, &Natural)", T::NAME),
+                &mut |(mut x, m, i)| no_out!(x.mod_shr_assign(i, &m)),
+            ),
+        ],
+    );
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn benchmark_integer_mod_shr_evaluation_strategy<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    for<'a> Integer:
+        ModShr<T, Natural, Output = Natural> + ModShr<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShr<T, Natural, Output = Natural> + ModShr<T, &'b Natural, Output = Natural>,
+{
+    run_benchmark(
+        &format!("Integer.mod_shr({}, Natural)", T::NAME),
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_signed_triple_gen_var_1::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("i"),
+        &mut [
+            (
+                &format!("Integer.mod_shr({}, Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!(x.mod_shr(i, m)),
+            ),
+            (
+                &format!("Integer.mod_shr({}, &Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!(x.mod_shr(i, &m)),
+            ),
+            (
+                &format!("(&Integer).mod_shr({}, Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!((&x).mod_shr(i, m)),
+            ),
+            (
+                &format!("(&Integer).mod_shr({}, &Natural)", T::NAME),
+                &mut |(x, m, i)| no_out!((&x).mod_shr(i, &m)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,130 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModSquare, ModSquareAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_square_assign);
+    register_demo!(runner, demo_integer_mod_square_assign_ref);
+    register_demo!(runner, demo_integer_mod_square);
+    register_demo!(runner, demo_integer_mod_square_val_ref);
+    register_demo!(runner, demo_integer_mod_square_ref_val);
+    register_demo!(runner, demo_integer_mod_square_ref_ref);
+
+    register_bench!(
+        runner,
+        benchmark_integer_mod_square_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_integer_mod_square_evaluation_strategy);
+}
+
+fn demo_integer_mod_square_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        x.mod_square_assign(m);
+        println!("x := {x_old}; x.mod_square_assign({m_old}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_square_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.mod_square_assign(&m);
+        println!("x := {x_old}; x.mod_square_assign(&{m}); x = {x}");
+    }
+}
+
+fn demo_integer_mod_square(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("({})^2 ≡ {} mod {}", x_old, x.mod_square(m), m_old);
+    }
+}
+
+fn demo_integer_mod_square_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        println!("({})^2 ≡ {} mod {}", x_old, x.mod_square(&m), m);
+    }
+}
+
+fn demo_integer_mod_square_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        let m_old = m.clone();
+        println!("({})^2 ≡ {} mod {}", x, (&x).mod_square(m), m_old);
+    }
+}
+
+fn demo_integer_mod_square_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in integer_natural_pair_gen_var_1().get(gm, config).take(limit) {
+        println!("({})^2 ≡ {} mod {}", x, (&x).mod_square(&m), m);
+    }
+}
+
+fn benchmark_integer_mod_square_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_square_assign(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_square_assign(Natural)", &mut |(mut x, m)| {
+                x.mod_square_assign(m)
+            }),
+            ("Integer.mod_square_assign(&Natural)", &mut |(mut x, m)| {
+                x.mod_square_assign(&m)
+            }),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_square_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_square(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Integer.mod_square(Natural)", &mut |(x, m)| {
+                no_out!(x.mod_square(m))
+            }),
+            ("Integer.mod_square(&Natural)", &mut |(x, m)| {
+                no_out!(x.mod_square(&m))
+            }),
+            ("(&Integer).mod_square(Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_square(m))
+            }),
+            ("(&Integer).mod_square(&Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_square(&m))
+            }),
+        ],
+    );
+}
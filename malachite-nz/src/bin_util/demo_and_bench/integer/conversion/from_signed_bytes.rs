@@ -0,0 +1,81 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_from_signed_bytes_le);
+    register_demo!(runner, demo_integer_from_signed_bytes_be);
+    register_bench!(runner, benchmark_integer_from_signed_bytes_le);
+    register_bench!(runner, benchmark_integer_from_signed_bytes_be);
+}
+
+fn demo_integer_from_signed_bytes_le(gm: GenMode, config: &GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<u8>().get(gm, config).take(limit) {
+        println!(
+            "from_signed_bytes_le({:?}) = {}",
+            xs,
+            Integer::from_signed_bytes_le(&xs)
+        );
+    }
+}
+
+fn demo_integer_from_signed_bytes_be(gm: GenMode, config: &GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<u8>().get(gm, config).take(limit) {
+        println!(
+            "from_signed_bytes_be({:?}) = {}",
+            xs,
+            Integer::from_signed_bytes_be(&xs)
+        );
+    }
+}
+
+fn benchmark_integer_from_signed_bytes_le(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::from_signed_bytes_le(&[u8])",
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |xs| {
+            no_out!(Integer::from_signed_bytes_le(&xs))
+        })],
+    );
+}
+
+fn benchmark_integer_from_signed_bytes_be(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer::from_signed_bytes_be(&[u8])",
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |xs| {
+            no_out!(Integer::from_signed_bytes_be(&xs))
+        })],
+    );
+}
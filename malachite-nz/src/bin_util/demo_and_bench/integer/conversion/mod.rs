@@ -15,12 +15,14 @@ pub(crate) fn register(runner: &mut Runner) {
     from_floating_point::register(runner);
     from_natural::register(runner);
     from_primitive_int::register(runner);
+    from_signed_bytes::register(runner);
     from_twos_complement_limbs::register(runner);
     is_integer::register(runner);
     natural_from_integer::register(runner);
     primitive_int_from_integer::register(runner);
     serde::register(runner);
     string::register(runner);
+    to_signed_bytes::register(runner);
     to_twos_complement_limbs::register(runner);
 }
 
@@ -30,10 +32,12 @@ mod from_bool;
 mod from_floating_point;
 mod from_natural;
 mod from_primitive_int;
+mod from_signed_bytes;
 mod from_twos_complement_limbs;
 mod is_integer;
 mod natural_from_integer;
 mod primitive_int_from_integer;
 mod serde;
 mod string;
+mod to_signed_bytes;
 mod to_twos_complement_limbs;
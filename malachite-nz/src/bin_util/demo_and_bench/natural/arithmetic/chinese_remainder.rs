@@ -0,0 +1,57 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::vec_pair_2_natural_sum_bits_bucketer;
+use malachite_nz::test_util::generators::natural_pair_vec_gen_var_1;
+use malachite_nz::test_util::natural::arithmetic::chinese_remainder::chinese_remainder_sequential;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_chinese_remainder);
+
+    register_bench!(runner, benchmark_natural_chinese_remainder_algorithms);
+}
+
+fn demo_natural_chinese_remainder(gm: GenMode, config: &GenConfig, limit: usize) {
+    for pairs in natural_pair_vec_gen_var_1().get(gm, config).take(limit) {
+        println!(
+            "Natural::chinese_remainder({:?}) = {:?}",
+            pairs,
+            Natural::chinese_remainder(&pairs)
+        );
+    }
+}
+
+fn benchmark_natural_chinese_remainder_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::chinese_remainder(&[(Natural, Natural)])",
+        BenchmarkType::Algorithms,
+        natural_pair_vec_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_pair_2_natural_sum_bits_bucketer(),
+        &mut [
+            ("default", &mut |pairs| {
+                no_out!(Natural::chinese_remainder(&pairs))
+            }),
+            ("sequential", &mut |pairs| {
+                no_out!(chinese_remainder_sequential(&pairs))
+            }),
+        ],
+    );
+}
@@ -15,6 +15,7 @@ pub(crate) fn register(runner: &mut Runner) {
     binomial_coefficient::register(runner);
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
+    chinese_remainder::register(runner);
     coprime_with::register(runner);
     div::register(runner);
     div_exact::register(runner);
@@ -55,6 +56,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_shr::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    montgomery::register(runner);
     mul::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
@@ -84,6 +86,7 @@ mod add_mul;
 mod binomial_coefficient;
 mod checked_sub;
 mod checked_sub_mul;
+mod chinese_remainder;
 mod coprime_with;
 mod div;
 mod div_exact;
@@ -124,6 +127,7 @@ mod mod_shl;
 mod mod_shr;
 mod mod_square;
 mod mod_sub;
+mod montgomery;
 mod mul;
 mod neg;
 mod next_power_of_2;
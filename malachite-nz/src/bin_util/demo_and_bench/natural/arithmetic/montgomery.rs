@@ -0,0 +1,158 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::montgomery::{MontgomeryContext, MontgomeryPowWindow};
+use malachite_nz::test_util::bench::bucketers::{
+    triple_1_3_prod_natural_bits_bucketer, triple_3_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::natural_triple_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_montgomery_context_mul);
+    register_demo!(runner, demo_montgomery_context_square);
+    register_demo!(runner, demo_montgomery_context_pow);
+    register_demo!(runner, demo_montgomery_context_pow_with_window);
+
+    register_bench!(runner, benchmark_montgomery_context_mul_algorithms);
+    register_bench!(runner, benchmark_montgomery_context_pow_algorithms);
+}
+
+fn demo_montgomery_context_mul(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_8().get(gm, config).take(limit) {
+        let context = MontgomeryContext::new(m);
+        let product = context.mul(&context.to_montgomery(&x), &context.to_montgomery(&y));
+        println!(
+            "{} * {} ≡ {} mod {}",
+            x,
+            y,
+            context.to_natural(&product),
+            context.modulus()
+        );
+    }
+}
+
+fn demo_montgomery_context_square(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, _, m) in natural_triple_gen_var_8().get(gm, config).take(limit) {
+        let context = MontgomeryContext::new(m);
+        let square = context.square(&context.to_montgomery(&x));
+        println!(
+            "{}^2 ≡ {} mod {}",
+            x,
+            context.to_natural(&square),
+            context.modulus()
+        );
+    }
+}
+
+fn demo_montgomery_context_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_8().get(gm, config).take(limit) {
+        let context = MontgomeryContext::new(m);
+        let power = context.pow(&context.to_montgomery(&x), &exp);
+        println!(
+            "{}^{} ≡ {} mod {}",
+            x,
+            exp,
+            context.to_natural(&power),
+            context.modulus()
+        );
+    }
+}
+
+fn demo_montgomery_context_pow_with_window(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_8().get(gm, config).take(limit) {
+        let context = MontgomeryContext::new(m);
+        let x_m = context.to_montgomery(&x);
+        for window in [MontgomeryPowWindow::Sliding, MontgomeryPowWindow::Fixed] {
+            let power = context.pow_with_window(&x_m, &exp, window);
+            println!(
+                "{}^{} ≡ {} mod {} using {:?}",
+                x,
+                exp,
+                context.to_natural(&power),
+                context.modulus(),
+                window
+            );
+        }
+    }
+}
+
+fn benchmark_montgomery_context_mul_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryContext.mul(&MontgomeryResidue, &MontgomeryResidue)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_8()
+            .get(gm, config)
+            .map(|(x, y, m)| (&x % &m, &y % &m, m)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_mul(Natural, Natural)", &mut |(x, y, m)| {
+                no_out!(x.mod_mul(y, m))
+            }),
+            ("MontgomeryContext, including setup", &mut |(x, y, m)| {
+                let context = MontgomeryContext::new(m);
+                no_out!(context.to_natural(
+                    &context.mul(&context.to_montgomery(&x), &context.to_montgomery(&y))
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_montgomery_context_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryContext.pow(&MontgomeryResidue, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_8()
+            .get(gm, config)
+            .map(|(x, exp, m)| (&x % &m, exp, m)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_prod_natural_bits_bucketer("x", "m"),
+        &mut [
+            ("Natural.mod_pow(Natural, Natural)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(exp, m))
+            }),
+            ("MontgomeryContext, sliding window", &mut |(x, exp, m)| {
+                let context = MontgomeryContext::new(m);
+                let x = context.to_montgomery(&x);
+                no_out!(context.to_natural(&context.pow_with_window(
+                    &x,
+                    &exp,
+                    MontgomeryPowWindow::Sliding
+                )))
+            }),
+            ("MontgomeryContext, fixed window", &mut |(x, exp, m)| {
+                let context = MontgomeryContext::new(m);
+                let x = context.to_montgomery(&x);
+                no_out!(context.to_natural(&context.pow_with_window(
+                    &x,
+                    &exp,
+                    MontgomeryPowWindow::Fixed
+                )))
+            }),
+        ],
+    );
+}
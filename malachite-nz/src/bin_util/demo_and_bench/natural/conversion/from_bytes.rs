@@ -0,0 +1,80 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_base::test_util::bench::bucketers::vec_len_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_from_bytes_le);
+    register_demo!(runner, demo_natural_from_bytes_be);
+    register_bench!(runner, benchmark_natural_from_bytes_le_algorithms);
+    register_bench!(runner, benchmark_natural_from_bytes_be_algorithms);
+}
+
+fn demo_natural_from_bytes_le(gm: GenMode, config: &GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<u8>().get(gm, config).take(limit) {
+        println!("from_bytes_le({:?}) = {}", xs, Natural::from_bytes_le(&xs));
+    }
+}
+
+fn demo_natural_from_bytes_be(gm: GenMode, config: &GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<u8>().get(gm, config).take(limit) {
+        println!("from_bytes_be({:?}) = {}", xs, Natural::from_bytes_be(&xs));
+    }
+}
+
+fn benchmark_natural_from_bytes_le_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::from_bytes_le(&[u8])",
+        BenchmarkType::Algorithms,
+        unsigned_vec_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [
+            ("default", &mut |xs| no_out!(Natural::from_bytes_le(&xs))),
+            ("from_power_of_2_digits_asc", &mut |xs| {
+                no_out!(Natural::from_power_of_2_digits_asc(8, xs.into_iter()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_from_bytes_be_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::from_bytes_be(&[u8])",
+        BenchmarkType::Algorithms,
+        unsigned_vec_gen::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [
+            ("default", &mut |xs| no_out!(Natural::from_bytes_be(&xs))),
+            ("from_power_of_2_digits_desc", &mut |xs| {
+                no_out!(Natural::from_power_of_2_digits_desc(8, xs.into_iter()))
+            }),
+        ],
+    );
+}
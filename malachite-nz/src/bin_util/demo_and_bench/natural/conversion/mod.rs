@@ -13,6 +13,7 @@ pub(crate) fn register(runner: &mut Runner) {
     digits::register(runner);
     floating_point_from_natural::register(runner);
     from_bool::register(runner);
+    from_bytes::register(runner);
     from_floating_point::register(runner);
     from_limbs::register(runner);
     from_primitive_int::register(runner);
@@ -22,6 +23,7 @@ pub(crate) fn register(runner: &mut Runner) {
     sci_mantissa_and_exponent::register(runner);
     serde::register(runner);
     string::register(runner);
+    to_bytes::register(runner);
     to_limbs::register(runner);
 }
 
@@ -29,6 +31,7 @@ mod clone;
 mod digits;
 mod floating_point_from_natural;
 mod from_bool;
+mod from_bytes;
 mod from_floating_point;
 mod from_limbs;
 mod from_primitive_int;
@@ -38,4 +41,5 @@ mod primitive_int_from_natural;
 mod sci_mantissa_and_exponent;
 mod serde;
 mod string;
+mod to_bytes;
 mod to_limbs;
@@ -0,0 +1,120 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_1_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_4};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_to_bytes_le);
+    register_demo!(runner, demo_natural_to_bytes_be);
+    register_demo!(runner, demo_natural_to_bytes_be_padded);
+
+    register_bench!(runner, benchmark_natural_to_bytes_le_algorithms);
+    register_bench!(runner, benchmark_natural_to_bytes_be_algorithms);
+    register_bench!(runner, benchmark_natural_to_bytes_be_padded);
+}
+
+fn demo_natural_to_bytes_le(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!("to_bytes_le({}) = {:?}", n, n.to_bytes_le());
+    }
+}
+
+fn demo_natural_to_bytes_be(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!("to_bytes_be({}) = {:?}", n, n.to_bytes_be());
+    }
+}
+
+fn demo_natural_to_bytes_be_padded(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (n, extra) in natural_unsigned_pair_gen_var_4::<u8>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let len = n.to_bytes_be().len() + usize::from(extra);
+        println!(
+            "to_bytes_be_padded({}, {}) = {:?}",
+            n,
+            len,
+            n.to_bytes_be_padded(len)
+        );
+    }
+}
+
+fn benchmark_natural_to_bytes_le_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.to_bytes_le()",
+        BenchmarkType::Algorithms,
+        natural_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("default", &mut |n| no_out!(n.to_bytes_le())),
+            ("to_power_of_2_digits_asc", &mut |n| {
+                no_out!(PowerOf2Digits::<u8>::to_power_of_2_digits_asc(&n, 8))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_to_bytes_be_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.to_bytes_be()",
+        BenchmarkType::Algorithms,
+        natural_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("default", &mut |n| no_out!(n.to_bytes_be())),
+            ("to_power_of_2_digits_desc", &mut |n| {
+                no_out!(PowerOf2Digits::<u8>::to_power_of_2_digits_desc(&n, 8))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_to_bytes_be_padded(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.to_bytes_be_padded(usize)",
+        BenchmarkType::Single,
+        natural_unsigned_pair_gen_var_4::<u8>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, extra)| {
+            let len = n.to_bytes_be().len() + usize::from(extra);
+            no_out!(n.to_bytes_be_padded(len))
+        })],
+    );
+}
@@ -0,0 +1,52 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+// Random `Natural`s may have large prime factors that take too long to find, so the effort is
+// bounded.
+const EFFORT: u64 = 100000;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_factor_with_effort);
+
+    register_bench!(runner, benchmark_natural_factor_with_effort);
+}
+
+fn demo_natural_factor_with_effort(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, config).take(limit) {
+        println!(
+            "{}.factor_with_effort({}) = {:?}",
+            n,
+            EFFORT,
+            n.factor_with_effort(EFFORT)
+        );
+    }
+}
+
+fn benchmark_natural_factor_with_effort(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("Natural.factor_with_effort({EFFORT})"),
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.factor_with_effort(EFFORT)))],
+    );
+}
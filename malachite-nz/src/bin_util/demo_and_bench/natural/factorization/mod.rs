@@ -9,7 +9,11 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    factor::register(runner);
+    is_prime::register(runner);
     primes::register(runner);
 }
 
+mod factor;
+mod is_prime;
 mod primes;
@@ -12,19 +12,29 @@ use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
 use malachite_base::test_util::runner::Runner;
 use malachite_nz::natural::Natural;
-use malachite_nz::test_util::bench::bucketers::natural_bucketer;
-use malachite_nz::test_util::generators::natural_gen_var_9;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, natural_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_gen_rm, natural_gen_var_10, natural_gen_var_9, natural_pair_gen_var_8,
+};
 
 pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_natural_primes_less_than);
     register_demo!(runner, demo_natural_primes_less_than_or_equal_to);
     register_demo!(runner, demo_natural_primes);
+    register_demo!(runner, demo_natural_primes_in_range);
+    register_demo!(runner, demo_natural_primes_greater_than_or_equal_to);
+    register_demo!(runner, demo_natural_next_prime);
+    register_demo!(runner, demo_natural_prev_prime);
 
     register_bench!(runner, benchmark_natural_primes_less_than_algorithms);
     register_bench!(
         runner,
         benchmark_natural_primes_less_than_or_equal_to_algorithms
     );
+    register_bench!(runner, benchmark_natural_next_prime_library_comparison);
+    register_bench!(runner, benchmark_natural_prev_prime);
 }
 
 fn demo_natural_primes_less_than(gm: GenMode, config: &GenConfig, limit: usize) {
@@ -52,6 +62,41 @@ fn demo_natural_primes(_gm: GenMode, _config: &GenConfig, limit: usize) {
     }
 }
 
+fn demo_natural_primes_in_range(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (a, b) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        println!(
+            "primes_in_range({}, {}).take(10) = {:?}",
+            a,
+            b,
+            Natural::primes_in_range(&a, &b).take(10).collect_vec()
+        );
+    }
+}
+
+fn demo_natural_primes_greater_than_or_equal_to(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!(
+            "primes_greater_than_or_equal_to({}).take(10) = {:?}",
+            n,
+            Natural::primes_greater_than_or_equal_to(&n)
+                .take(10)
+                .collect_vec()
+        );
+    }
+}
+
+fn demo_natural_next_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!("{}.next_prime() = {}", n, n.next_prime());
+    }
+}
+
+fn demo_natural_prev_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen_var_10().get(gm, config).take(limit) {
+        println!("{}.prev_prime() = {}", n, n.prev_prime());
+    }
+}
+
 fn benchmark_natural_primes_less_than_algorithms(
     gm: GenMode,
     config: &GenConfig,
@@ -101,3 +146,37 @@ fn benchmark_natural_primes_less_than_or_equal_to_algorithms(
         ],
     );
 }
+
+fn benchmark_natural_next_prime_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.next_prime()",
+        BenchmarkType::LibraryComparison,
+        natural_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [
+            ("Malachite", &mut |(_, n)| no_out!(n.next_prime())),
+            ("rug", &mut |(n, _)| no_out!(rug::Integer::from(n.next_prime_ref()))),
+        ],
+    );
+}
+
+fn benchmark_natural_prev_prime(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.prev_prime()",
+        BenchmarkType::Single,
+        natural_gen_var_10().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.prev_prime()))],
+    );
+}
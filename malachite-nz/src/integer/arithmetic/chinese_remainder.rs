@@ -0,0 +1,82 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::arithmetic::chinese_remainder::chinese_remainder_helper;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::ChineseRemainder;
+
+impl ChineseRemainder<Natural> for Integer {
+    /// Given a slice of pairs $(r_i, m_i)$, where each $r_i$ is an [`Integer`] and each $m_i$ is a
+    /// [`Natural`], finds the unique [`Integer`] $x$ in the symmetric range $-L/2 < x \leq L/2$,
+    /// where $L = \operatorname{lcm}(m_1, \ldots, m_k)$, such that $x \equiv r_i \mod m_i$ for
+    /// every $i$. Returns `None` if no such $x$ exists.
+    ///
+    /// This is useful for reconstructing a signed result, such as a determinant, from its residues
+    /// modulo many small primes: if $|x| < L/2$, then $x$ is recovered exactly.
+    ///
+    /// The moduli do not have to be pairwise coprime, and the residues do not have to be reduced. A
+    /// solution exists if and only if $r_i \equiv r_j \mod \gcd(m_i, m_j)$ for every $i$ and $j$.
+    /// If the slice is empty, the result is 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the bit lengths of the
+    /// moduli.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ChineseRemainder;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&[
+    ///         (Integer::from(2), Natural::from(3u32)),
+    ///         (Integer::from(3), Natural::from(5u32)),
+    ///         (Integer::from(2), Natural::from(7u32))
+    ///     ]),
+    ///     Some(Integer::from(23))
+    /// );
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&[
+    ///         (Integer::from(-1), Natural::from(3u32)),
+    ///         (Integer::from(-1), Natural::from(5u32)),
+    ///         (Integer::from(-1), Natural::from(7u32))
+    ///     ]),
+    ///     Some(Integer::from(-1))
+    /// );
+    /// assert_eq!(
+    ///     Integer::chinese_remainder(&[
+    ///         (Integer::from(3), Natural::from(4u32)),
+    ///         (Integer::from(4), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// ```
+    fn chinese_remainder(pairs: &[(Integer, Natural)]) -> Option<Integer> {
+        assert!(pairs.iter().all(|(_, m)| *m != 0u32), "Moduli must be positive");
+        let pairs: Vec<(Natural, Natural)> = pairs
+            .iter()
+            .map(|(r, m)| (r.mod_natural_ref(m), m.clone()))
+            .collect();
+        let (x, lcm) = chinese_remainder_helper(&pairs)?;
+        Some(if &x << 1 > lcm {
+            Integer::from(x) - Integer::from(lcm)
+        } else {
+            Integer::from(x)
+        })
+    }
+}
@@ -50,6 +50,20 @@ impl DivRound<Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -192,6 +206,20 @@ impl<'a> DivRound<&'a Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -334,6 +362,20 @@ impl<'a> DivRound<Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -478,6 +520,20 @@ impl<'a, 'b> DivRound<&'b Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \Z, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -23,6 +23,10 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// An implementation of
+/// [`ChineseRemainder`](malachite_base::num::arithmetic::traits::ChineseRemainder), a trait for
+/// solving a system of congruences, that returns the solution with the smallest absolute value.
+pub mod chinese_remainder;
 /// Division of [`Integer`](super::Integer)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -66,6 +70,25 @@ pub mod extended_gcd;
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// Implementations of [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd) and
+/// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
+/// numbers modulo another number, for [`Integer`](super::Integer)s and a
+/// [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_add;
+/// Implementations of [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait
+/// for finding the multiplicative inverse of a number modulo another number, for an
+/// [`Integer`](super::Integer) and a [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_inverse;
+/// Implementations of [`ModMul`](malachite_base::num::arithmetic::traits::ModMul) and
+/// [`ModMulAssign`](malachite_base::num::arithmetic::traits::ModMulAssign), traits for multiplying
+/// two numbers modulo another number, for [`Integer`](super::Integer)s and a
+/// [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_mul;
+/// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
+/// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
+/// number modulo another number, for an [`Integer`](super::Integer) and a
+/// [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_neg;
 /// Implementations of traits for finding the remainder of two numbers, subject to various rounding
 /// rules.
 ///
@@ -89,6 +112,199 @@ pub mod mod_op;
 /// | towards 0 | [`RemPowerOf2`](malachite_base::num::arithmetic::traits::RemPowerOf2) | [`RemPowerOf2Assign`](malachite_base::num::arithmetic::traits::RemPowerOf2Assign)       |
 /// | towards $\infty$  | [`CeilingModPowerOf2`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2) | [`CeilingModPowerOf2Assign`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2Assign) |
 pub mod mod_power_of_2;
+/// Implementations of [`ModPow`](malachite_base::num::arithmetic::traits::ModPow) and
+/// [`ModPowAssign`](malachite_base::num::arithmetic::traits::ModPowAssign), traits for raising a
+/// number to a power modulo another number, for [`Integer`](super::Integer)s and a
+/// [`Natural`](crate::natural::Natural) modulus. Negative exponents are allowed when the base is
+/// invertible.
+pub mod mod_pow;
+/// Implementations of [`ModShl`](malachite_base::num::arithmetic::traits::ModShl) and
+/// [`ModShlAssign`](malachite_base::num::arithmetic::traits::ModShlAssign), traits for
+/// left-shifting a number modulo another number, for an [`Integer`](super::Integer) and a
+/// [`Natural`](crate::natural::Natural) modulus.
+///
+/// # mod_shl
+/// ```
+/// use core::str::FromStr;
+/// use malachite_base::num::arithmetic::traits::ModShl;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Integer::from(-8).mod_shl(2u16, Natural::from(10u32)), 8);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shl(100u64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!(Integer::from(-8).mod_shl(2i8, Natural::from(10u32)), 8);
+/// assert_eq!(Integer::from(-3).mod_shl(-1i32, Natural::from(10u32)), 3);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shl(100i64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!(Integer::from(-8).mod_shl(2u16, &Natural::from(10u32)), 8);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shl(100u64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!(Integer::from(-8).mod_shl(2i8, &Natural::from(10u32)), 8);
+/// assert_eq!(Integer::from(-3).mod_shl(-1i32, &Natural::from(10u32)), 3);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shl(100i64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shl(2u16, Natural::from(10u32)), 8);
+/// assert_eq!(
+///     (&Integer::from(-123456)).mod_shl(100u64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shl(2i8, Natural::from(10u32)), 8);
+/// assert_eq!((&Integer::from(-3)).mod_shl(-1i32, Natural::from(10u32)), 3);
+/// assert_eq!(
+///     (&Integer::from(-123456)).mod_shl(100i64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shl(2u16, &Natural::from(10u32)), 8);
+/// assert_eq!(
+///     (&Integer::from(-123456)).mod_shl(100u64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shl(2i8, &Natural::from(10u32)), 8);
+/// assert_eq!((&Integer::from(-3)).mod_shl(-1i32, &Natural::from(10u32)), 3);
+/// assert_eq!(
+///     (&Integer::from(-123456)).mod_shl(100i64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// ```
+///
+/// # mod_shl_assign
+/// ```
+/// use core::str::FromStr;
+/// use malachite_base::num::arithmetic::traits::ModShlAssign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shl_assign(2u16, Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shl_assign(100u64, Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shl_assign(2i8, Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shl_assign(-1i32, Natural::from(10u32));
+/// assert_eq!(x, 3);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shl_assign(100i64, Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shl_assign(2u16, &Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shl_assign(100u64, &Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shl_assign(2i8, &Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shl_assign(-1i32, &Natural::from(10u32));
+/// assert_eq!(x, 3);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shl_assign(100i64, &Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+/// ```
+pub mod mod_shl;
+/// Implementations of [`ModShr`](malachite_base::num::arithmetic::traits::ModShr) and
+/// [`ModShrAssign`](malachite_base::num::arithmetic::traits::ModShrAssign), traits for
+/// right-shifting a number modulo another number, for an [`Integer`](super::Integer) and a
+/// [`Natural`](crate::natural::Natural) modulus.
+///
+/// # mod_shr
+/// ```
+/// use core::str::FromStr;
+/// use malachite_base::num::arithmetic::traits::ModShr;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Integer::from(-8).mod_shr(-2i8, Natural::from(10u32)), 8);
+/// assert_eq!(Integer::from(-3).mod_shr(1i32, Natural::from(10u32)), 3);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shr(-100i64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!(Integer::from(-8).mod_shr(-2i8, &Natural::from(10u32)), 8);
+/// assert_eq!(Integer::from(-3).mod_shr(1i32, &Natural::from(10u32)), 3);
+/// assert_eq!(
+///     Integer::from(-123456).mod_shr(-100i64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shr(-2i8, Natural::from(10u32)), 8);
+/// assert_eq!((&Integer::from(-3)).mod_shr(1i32, Natural::from(10u32)), 3);
+/// assert_eq!(
+///     (&Integer::from(-123456)).mod_shr(-100i64, Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// assert_eq!((&Integer::from(-8)).mod_shr(-2i8, &Natural::from(10u32)), 8);
+/// assert_eq!((&Integer::from(-3)).mod_shr(1i32, &Natural::from(10u32)), 3);
+/// assert_eq!(
+///     (&Integer::from(-123456))
+///         .mod_shr(-100i64, &Natural::from_str("12345678987654321").unwrap()),
+///     4909015422739176u64
+/// );
+/// ```
+///
+/// # mod_shr_assign
+/// ```
+/// use core::str::FromStr;
+/// use malachite_base::num::arithmetic::traits::ModShrAssign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shr_assign(-2i8, Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shr_assign(1i32, Natural::from(10u32));
+/// assert_eq!(x, 3);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shr_assign(-100i64, Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+///
+/// let mut x = Integer::from(-8);
+/// x.mod_shr_assign(-2i8, &Natural::from(10u32));
+/// assert_eq!(x, 8);
+///
+/// let mut x = Integer::from(-3);
+/// x.mod_shr_assign(1i32, &Natural::from(10u32));
+/// assert_eq!(x, 3);
+///
+/// let mut x = Integer::from(-123456);
+/// x.mod_shr_assign(-100i64, &Natural::from_str("12345678987654321").unwrap());
+/// assert_eq!(x, 4909015422739176u64);
+/// ```
+pub mod mod_shr;
+/// Implementations of [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare) and
+/// [`ModSquareAssign`](malachite_base::num::arithmetic::traits::ModSquareAssign), traits for
+/// squaring a number modulo another number, for an [`Integer`](super::Integer) and a
+/// [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_square;
+/// Implementations of [`ModSub`](malachite_base::num::arithmetic::traits::ModSub) and
+/// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
+/// two numbers modulo another number, for [`Integer`](super::Integer)s and a
+/// [`Natural`](crate::natural::Natural) modulus.
+pub mod mod_sub;
 /// Multiplication of [`Integer`](super::Integer)s.
 pub mod mul;
 /// Negation of an [`Integer`](super::Integer).
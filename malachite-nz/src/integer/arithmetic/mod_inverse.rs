@@ -0,0 +1,215 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::num::basic::traits::Zero;
+
+// Inverts a residue modulo `m`. Unlike `Natural::mod_inverse`, this accepts 0, which is invertible
+// only modulo 1.
+fn mod_inverse_helper(x: Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        Some(Natural::ZERO)
+    } else if x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+pub(crate) fn mod_inverse_ref_helper(x: &Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        Some(Natural::ZERO)
+    } else if *x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+impl ModInverse<Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the result is always
+    /// the least nonnegative residue. Both arguments are taken by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(3).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(7u32))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Integer::from(-4).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural(&m), &m)
+    }
+}
+
+impl<'a> ModInverse<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the result is always
+    /// the least nonnegative residue. The first argument is taken by value and the second by
+    /// reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(3).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(7u32))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Integer::from(-4).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &'a Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural(m), m)
+    }
+}
+
+impl<'a> ModInverse<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the result is always
+    /// the least nonnegative residue. The first argument is taken by reference and the second by
+    /// value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(7u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural_ref(&m), &m)
+    }
+}
+
+impl<'a, 'b> ModInverse<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the result is always
+    /// the least nonnegative residue. Both arguments are taken by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(7u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_inverse(&Natural::from(10u32)),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &'b Natural) -> Option<Natural> {
+        mod_inverse_helper(self.mod_natural_ref(m), m)
+    }
+}
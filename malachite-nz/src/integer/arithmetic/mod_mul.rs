@@ -0,0 +1,499 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModMul, ModMulAssign};
+
+impl ModMul<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// All three arguments are taken by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_mul(Integer::from(4), Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-123).mod_mul(Integer::from(-456), Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_mul(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a> ModMul<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first two arguments are taken by value and the third by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_mul(Integer::from(4), &Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-123).mod_mul(Integer::from(-456), &Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: Integer, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_mul(other.mod_natural(m), m)
+    }
+}
+
+impl<'a> ModMul<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first and third arguments are taken by value and the second by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_mul(&Integer::from(4), Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-123).mod_mul(&Integer::from(-456), Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: &'a Integer, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_mul(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b> ModMul<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first argument is taken by value and the second and third by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_mul(&Integer::from(4), &Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-123).mod_mul(&Integer::from(-456), &Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: &'a Integer, m: &'b Natural) -> Natural {
+        self.mod_natural(m).mod_mul(other.mod_natural_ref(m), m)
+    }
+}
+
+impl<'a> ModMul<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first argument is taken by reference and the second and third by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_mul(Integer::from(4), Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-123)).mod_mul(Integer::from(-456), Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_mul(other.mod_natural(&m), m)
+    }
+}
+
+impl<'a, 'b> ModMul<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first and third arguments are taken by reference and the second by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_mul(Integer::from(4), &Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-123)).mod_mul(Integer::from(-456), &Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: Integer, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_mul(other.mod_natural(m), m)
+    }
+}
+
+impl<'a, 'b> ModMul<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// The first two arguments are taken by reference and the third by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_mul(&Integer::from(4), Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-123)).mod_mul(&Integer::from(-456), Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: &'b Integer, m: Natural) -> Natural {
+        self.mod_natural_ref(&m)
+            .mod_mul(other.mod_natural_ref(&m), m)
+    }
+}
+
+impl<'a, 'b, 'c> ModMul<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$. The [`Integer`]s don't need to be
+    /// reduced modulo $m$, and may be negative; the result is always the least nonnegative residue.
+    /// All three arguments are taken by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_mul(&Integer::from(4), &Natural::from(10u32)),
+    ///     8
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-123)).mod_mul(&Integer::from(-456), &Natural::from(100u32)),
+    ///     88
+    /// );
+    /// ```
+    #[inline]
+    fn mod_mul(self, other: &'b Integer, m: &'c Natural) -> Natural {
+        self.mod_natural_ref(m).mod_mul(other.mod_natural_ref(m), m)
+    }
+}
+
+impl ModMulAssign<Integer, Natural> for Integer {
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s don't need
+    /// to be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Integer`] and the [`Natural`] on the right-hand side are both taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(Integer::from(4), Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    ///
+    /// let mut x = Integer::from(-123);
+    /// x.mod_mul_assign(Integer::from(-456), Natural::from(100u32));
+    /// assert_eq!(x, 88);
+    /// ```
+    #[inline]
+    fn mod_mul_assign(&mut self, other: Integer, m: Natural) {
+        self.mod_natural_assign(&m);
+        self.abs.mod_mul_assign(other.mod_natural(&m), m);
+    }
+}
+
+impl<'a> ModMulAssign<Integer, &'a Natural> for Integer {
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s don't need
+    /// to be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Integer`] on the right-hand side is taken by value and the [`Natural`] by
+    /// reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(Integer::from(4), &Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    ///
+    /// let mut x = Integer::from(-123);
+    /// x.mod_mul_assign(Integer::from(-456), &Natural::from(100u32));
+    /// assert_eq!(x, 88);
+    /// ```
+    #[inline]
+    fn mod_mul_assign(&mut self, other: Integer, m: &'a Natural) {
+        self.mod_natural_assign(m);
+        self.abs.mod_mul_assign(other.mod_natural(m), m);
+    }
+}
+
+impl<'a> ModMulAssign<&'a Integer, Natural> for Integer {
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s don't need
+    /// to be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Integer`] on the right-hand side is taken by reference and the [`Natural`] by
+    /// value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(&Integer::from(4), Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    ///
+    /// let mut x = Integer::from(-123);
+    /// x.mod_mul_assign(&Integer::from(-456), Natural::from(100u32));
+    /// assert_eq!(x, 88);
+    /// ```
+    #[inline]
+    fn mod_mul_assign(&mut self, other: &'a Integer, m: Natural) {
+        self.mod_natural_assign(&m);
+        self.abs.mod_mul_assign(other.mod_natural_ref(&m), m);
+    }
+}
+
+impl<'a, 'b> ModMulAssign<&'a Integer, &'b Natural> for Integer {
+    /// Multiplies two [`Integer`]s modulo a [`Natural`] $m$, in place. The [`Integer`]s don't need
+    /// to be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Integer`] and the [`Natural`] on the right-hand side are both taken by
+    /// reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(&Integer::from(4), &Natural::from(10u32));
+    /// assert_eq!(x, 8);
+    ///
+    /// let mut x = Integer::from(-123);
+    /// x.mod_mul_assign(&Integer::from(-456), &Natural::from(100u32));
+    /// assert_eq!(x, 88);
+    /// ```
+    #[inline]
+    fn mod_mul_assign(&mut self, other: &'a Integer, m: &'b Natural) {
+        self.mod_natural_assign(m);
+        self.abs.mod_mul_assign(other.mod_natural_ref(m), m);
+    }
+}
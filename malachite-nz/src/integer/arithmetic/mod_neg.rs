@@ -0,0 +1,229 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModNeg, ModNegAssign};
+
+impl ModNeg<Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] doesn't need to be reduced
+    /// modulo $m$, and may be negative; the result is always the least nonnegative residue. Both
+    /// arguments are taken by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(123).mod_neg(Natural::from(100u32)), 77);
+    /// assert_eq!(Integer::from(-3).mod_neg(Natural::from(10u32)), 3);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: Natural) -> Natural {
+        self.mod_natural(&m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<&'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] doesn't need to be reduced
+    /// modulo $m$, and may be negative; the result is always the least nonnegative residue. The
+    /// first argument is taken by value and the second by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(123).mod_neg(&Natural::from(100u32)), 77);
+    /// assert_eq!(Integer::from(-3).mod_neg(&Natural::from(10u32)), 3);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: &'a Natural) -> Natural {
+        self.mod_natural(m).mod_neg(m)
+    }
+}
+
+impl<'a> ModNeg<Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] doesn't need to be reduced
+    /// modulo $m$, and may be negative; the result is always the least nonnegative residue. The
+    /// first argument is taken by reference and the second by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(123)).mod_neg(Natural::from(100u32)), 77);
+    /// assert_eq!((&Integer::from(-3)).mod_neg(Natural::from(10u32)), 3);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: Natural) -> Natural {
+        self.mod_natural_ref(&m).mod_neg(m)
+    }
+}
+
+impl<'a, 'b> ModNeg<&'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$. The [`Integer`] doesn't need to be reduced
+    /// modulo $m$, and may be negative; the result is always the least nonnegative residue. Both
+    /// arguments are taken by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(123)).mod_neg(&Natural::from(100u32)), 77);
+    /// assert_eq!((&Integer::from(-3)).mod_neg(&Natural::from(10u32)), 3);
+    /// ```
+    #[inline]
+    fn mod_neg(self, m: &'b Natural) -> Natural {
+        self.mod_natural_ref(m).mod_neg(m)
+    }
+}
+
+impl ModNegAssign<Natural> for Integer {
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] doesn't need to
+    /// be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Natural`] is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(123);
+    /// x.mod_neg_assign(Natural::from(100u32));
+    /// assert_eq!(x, 77);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(Natural::from(10u32));
+    /// assert_eq!(x, 3);
+    /// ```
+    #[inline]
+    fn mod_neg_assign(&mut self, m: Natural) {
+        self.mod_natural_assign(&m);
+        self.abs.mod_neg_assign(m);
+    }
+}
+
+impl<'a> ModNegAssign<&'a Natural> for Integer {
+    /// Negates an [`Integer`] modulo a [`Natural`] $m$, in place. The [`Integer`] doesn't need to
+    /// be reduced modulo $m$, and may be negative; afterwards, `self` is the least nonnegative
+    /// residue. The [`Natural`] is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(123);
+    /// x.mod_neg_assign(&Natural::from(100u32));
+    /// assert_eq!(x, 77);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(&Natural::from(10u32));
+    /// assert_eq!(x, 3);
+    /// ```
+    #[inline]
+    fn mod_neg_assign(&mut self, m: &'a Natural) {
+        self.mod_natural_assign(m);
+        self.abs.mod_neg_assign(m);
+    }
+}
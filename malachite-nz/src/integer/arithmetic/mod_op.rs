@@ -7,11 +7,41 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::integer::Integer;
+use crate::natural::Natural;
 use core::ops::{Rem, RemAssign};
 use malachite_base::num::arithmetic::traits::{
     CeilingMod, CeilingModAssign, Mod, ModAssign, NegMod, NegModAssign,
 };
 
+impl Integer {
+    // Reduces an `Integer` modulo a positive `Natural`, returning the least nonnegative residue.
+    pub(crate) fn mod_natural(self, m: &Natural) -> Natural {
+        if self.sign {
+            self.abs % m
+        } else {
+            self.abs.neg_mod(m)
+        }
+    }
+
+    pub(crate) fn mod_natural_ref(&self, m: &Natural) -> Natural {
+        if self.sign {
+            &self.abs % m
+        } else {
+            (&self.abs).neg_mod(m)
+        }
+    }
+
+    // Reduces an `Integer` modulo a positive `Natural` in place, leaving it nonnegative.
+    pub(crate) fn mod_natural_assign(&mut self, m: &Natural) {
+        if self.sign {
+            self.abs %= m;
+        } else {
+            self.abs.neg_mod_assign(m);
+            self.sign = true;
+        }
+    }
+}
+
 impl Mod<Integer> for Integer {
     type Output = Integer;
 
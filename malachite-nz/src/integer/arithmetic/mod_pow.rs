@@ -0,0 +1,584 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::arithmetic::mod_inverse::mod_inverse_ref_helper;
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPow, ModPowAssign};
+
+// Raises a residue modulo `m` to a power, in place. If the exponent is negative, the residue's
+// inverse is raised to the exponent's absolute value instead.
+fn mod_pow_assign_helper(x: &mut Natural, exp: &Integer, m: &Natural) {
+    if *exp < 0 {
+        *x = mod_inverse_ref_helper(x, m).unwrap_or_else(|| {
+            panic!("Cannot raise a non-invertible number to a negative power modulo {m}")
+        });
+    }
+    x.mod_pow_assign(exp.unsigned_abs_ref(), m);
+}
+
+fn mod_pow_helper(mut x: Natural, exp: &Integer, m: &Natural) -> Natural {
+    mod_pow_assign_helper(&mut x, exp, m);
+    x
+}
+
+impl ModPow<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. All three arguments are taken by
+    /// value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_pow(Integer::from(-3), Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(&m), &exp, &m)
+    }
+}
+
+impl<'a> ModPow<Integer, &'a Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first two arguments are
+    /// taken by value and the third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_pow(Integer::from(-3), &Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &'a Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(m), &exp, m)
+    }
+}
+
+impl<'a> ModPow<&'a Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first and third arguments
+    /// are taken by value and the second by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(&Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(&Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_pow(&Integer::from(-3), Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'a Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(&m), exp, &m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'a Integer, &'b Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first argument is taken by
+    /// value and the second and third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(&Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(&Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_pow(&Integer::from(-3), &Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'a Integer, m: &'b Natural) -> Natural {
+        mod_pow_helper(self.mod_natural(m), exp, m)
+    }
+}
+
+impl<'a> ModPow<Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first argument is taken by
+    /// reference and the second and third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_pow(Integer::from(-3), Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), &exp, &m)
+    }
+}
+
+impl<'a, 'b> ModPow<Integer, &'b Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first and third arguments
+    /// are taken by reference and the second by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_pow(Integer::from(-3), &Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &'b Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), &exp, m)
+    }
+}
+
+impl<'a, 'b> ModPow<&'b Integer, Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The first two arguments are
+    /// taken by reference and the third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(&Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(&Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_pow(&Integer::from(-3), Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'b Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp, &m)
+    }
+}
+
+impl<'a, 'b, 'c> ModPow<&'b Integer, &'c Natural> for &'a Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base doesn't
+    /// need to be reduced modulo $m$, and may be negative; the result is always the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. All three arguments are taken by
+    /// reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(&Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(&Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_pow(&Integer::from(-3), &Natural::from(11u32)),
+    ///     4
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &'b Integer, m: &'c Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp, m)
+    }
+}
+
+impl ModPowAssign<Integer, Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// doesn't need to be reduced modulo $m$, and may be negative; afterwards, `self` is the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The [`Integer`] and the
+    /// [`Natural`] on the right-hand side are both taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x^n \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(Integer::from(13), Natural::from(497u32));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(Integer::from(-1), Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(Integer::from(-3), Natural::from(11u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: Integer, m: Natural) {
+        self.mod_natural_assign(&m);
+        mod_pow_assign_helper(&mut self.abs, &exp, &m);
+    }
+}
+
+impl<'a> ModPowAssign<Integer, &'a Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// doesn't need to be reduced modulo $m$, and may be negative; afterwards, `self` is the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The [`Integer`] on the
+    /// right-hand side is taken by value and the [`Natural`] by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x^n \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(Integer::from(13), &Natural::from(497u32));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(Integer::from(-1), &Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(Integer::from(-3), &Natural::from(11u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: Integer, m: &'a Natural) {
+        self.mod_natural_assign(m);
+        mod_pow_assign_helper(&mut self.abs, &exp, m);
+    }
+}
+
+impl<'a> ModPowAssign<&'a Integer, Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// doesn't need to be reduced modulo $m$, and may be negative; afterwards, `self` is the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The [`Integer`] on the
+    /// right-hand side is taken by reference and the [`Natural`] by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x^n \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(&Integer::from(13), Natural::from(497u32));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(&Integer::from(-1), Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(&Integer::from(-3), Natural::from(11u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: Natural) {
+        self.mod_natural_assign(&m);
+        mod_pow_assign_helper(&mut self.abs, exp, &m);
+    }
+}
+
+impl<'a, 'b> ModPowAssign<&'a Integer, &'b Natural> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$, in place. The base
+    /// doesn't need to be reduced modulo $m$, and may be negative; afterwards, `self` is the least
+    /// nonnegative residue. If the exponent is negative, the multiplicative inverse of the base
+    /// modulo $m$ is raised to the absolute value of the exponent. The [`Integer`] and the
+    /// [`Natural`] on the right-hand side are both taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x^n \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(&Integer::from(13), &Natural::from(497u32));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_pow_assign(&Integer::from(-1), &Natural::from(10u32));
+    /// assert_eq!(x, 7);
+    ///
+    /// let mut x = Integer::from(-2);
+    /// x.mod_pow_assign(&Integer::from(-3), &Natural::from(11u32));
+    /// assert_eq!(x, 4);
+    /// ```
+    #[inline]
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: &'b Natural) {
+        self.mod_natural_assign(m);
+        mod_pow_assign_helper(&mut self.abs, exp, m);
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModShr, ModShrAssign};
+
+macro_rules! impl_mod_shr {
+    ($t:ident) => {
+        impl ModShr<$t, Natural> for Integer {
+            type Output = Natural;
+
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$.
+            /// The [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the
+            /// result is always the least nonnegative residue. The [`Integer`] and the [`Natural`]
+            /// are both taken by value.
+            ///
+            /// $f(x, n, m) = y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and
+            /// $\lfloor 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr).
+            #[inline]
+            fn mod_shr(self, bits: $t, m: Natural) -> Natural {
+                self.mod_natural(&m).mod_shr(bits, m)
+            }
+        }
+
+        impl<'a> ModShr<$t, &'a Natural> for Integer {
+            type Output = Natural;
+
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$.
+            /// The [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the
+            /// result is always the least nonnegative residue. The [`Integer`] is taken by value
+            /// and the [`Natural`] by reference.
+            ///
+            /// $f(x, n, m) = y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and
+            /// $\lfloor 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr).
+            #[inline]
+            fn mod_shr(self, bits: $t, m: &'a Natural) -> Natural {
+                self.mod_natural(m).mod_shr(bits, m)
+            }
+        }
+
+        impl<'a> ModShr<$t, Natural> for &'a Integer {
+            type Output = Natural;
+
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$.
+            /// The [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the
+            /// result is always the least nonnegative residue. The [`Integer`] is taken by
+            /// reference and the [`Natural`] by value.
+            ///
+            /// $f(x, n, m) = y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and
+            /// $\lfloor 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr).
+            #[inline]
+            fn mod_shr(self, bits: $t, m: Natural) -> Natural {
+                self.mod_natural_ref(&m).mod_shr(bits, m)
+            }
+        }
+
+        impl<'a, 'b> ModShr<$t, &'b Natural> for &'a Integer {
+            type Output = Natural;
+
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$.
+            /// The [`Integer`] doesn't need to be reduced modulo $m$, and may be negative; the
+            /// result is always the least nonnegative residue. The [`Integer`] and the [`Natural`]
+            /// are both taken by reference.
+            ///
+            /// $f(x, n, m) = y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and
+            /// $\lfloor 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr).
+            #[inline]
+            fn mod_shr(self, bits: $t, m: &'b Natural) -> Natural {
+                self.mod_natural_ref(m).mod_shr(bits, m)
+            }
+        }
+
+        impl ModShrAssign<$t, Natural> for Integer {
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$,
+            /// in place. The [`Integer`] doesn't need to be reduced modulo $m$, and may be
+            /// negative; afterwards, `self` is the least nonnegative residue. The [`Natural`] is
+            /// taken by value.
+            ///
+            /// $x \gets y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and $\lfloor
+            /// 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr_assign).
+            #[inline]
+            fn mod_shr_assign(&mut self, bits: $t, m: Natural) {
+                self.mod_natural_assign(&m);
+                self.abs.mod_shr_assign(bits, m);
+            }
+        }
+
+        impl<'a> ModShrAssign<$t, &'a Natural> for Integer {
+            /// Right-shifts an [`Integer`] (divides it by a power of 2) modulo a [`Natural`] $m$,
+            /// in place. The [`Integer`] doesn't need to be reduced modulo $m$, and may be
+            /// negative; afterwards, `self` is the least nonnegative residue. The [`Natural`] is
+            /// taken by reference.
+            ///
+            /// $x \gets y$, where $0 \leq y < m$, $0 \leq r < m$, $x \equiv r \mod m$, and $\lfloor
+            /// 2^{-n}r \rfloor \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(mn \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+            /// m.significant_bits())`, and $m$ is `bits`.
+            ///
+            /// # Panics
+            /// Panics if `m` is zero.
+            ///
+            /// # Examples
+            /// See [here](super::mod_shr#mod_shr_assign).
+            #[inline]
+            fn mod_shr_assign(&mut self, bits: $t, m: &'a Natural) {
+                self.mod_natural_assign(m);
+                self.abs.mod_shr_assign(bits, m);
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_mod_shr);
@@ -44,6 +44,20 @@ impl RoundToMultiple<Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q) |y|
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     |y| \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     |y| \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -61,7 +75,8 @@ impl RoundToMultiple<Integer> for Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -204,6 +219,20 @@ impl<'a> RoundToMultiple<&'a Integer> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q) |y|
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     |y| \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     |y| \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -221,7 +250,8 @@ impl<'a> RoundToMultiple<&'a Integer> for Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -364,6 +394,20 @@ impl<'a> RoundToMultiple<Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q) |y|
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     |y| \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     |y| \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -381,7 +425,8 @@ impl<'a> RoundToMultiple<Integer> for &'a Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -526,6 +571,20 @@ impl<'a, 'b> RoundToMultiple<&'b Integer> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = \operatorname{sgn}(q) |y|
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     |y| \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     |y| \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -543,7 +602,8 @@ impl<'a, 'b> RoundToMultiple<&'b Integer> for &'a Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -678,7 +738,8 @@ impl RoundToMultipleAssign<Integer> for Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -794,7 +855,8 @@ impl<'a> RoundToMultipleAssign<&'a Integer> for Integer {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
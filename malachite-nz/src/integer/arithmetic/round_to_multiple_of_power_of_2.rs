@@ -45,6 +45,20 @@ impl RoundToMultipleOfPowerOf2<u64> for Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, k, \mathrm{NearestTiesAway}) = 2^k \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+    ///     2^k q & \text{if} \\quad q \in \Z, \\\\
+    ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     2^k \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -151,6 +165,20 @@ impl<'a> RoundToMultipleOfPowerOf2<u64> for &'a Integer {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, k, \mathrm{NearestTiesAway}) = 2^k \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+    ///     2^k q & \text{if} \\quad q \in \Z, \\\\
+    ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     2^k \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
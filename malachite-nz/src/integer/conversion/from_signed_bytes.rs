@@ -0,0 +1,90 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::conversion::from_bytes::{limbs_from_bytes_be, limbs_from_bytes_le};
+
+// Returns the byte used to sign-extend two's complement bytes whose most-significant byte is `x`.
+const fn sign_extension(x: Option<&u8>) -> u8 {
+    match x {
+        Some(&x) if x >> 7 != 0 => u8::MAX,
+        _ => 0,
+    }
+}
+
+impl Integer {
+    /// Converts a slice of bytes to an [`Integer`].
+    ///
+    /// The bytes are in little-endian order, so that less-significant bytes have lower indices in
+    /// the input slice. They are in two's complement, and the most significant bit of the bytes
+    /// indicates the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is
+    /// one it is negative. If the slice is empty, zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_signed_bytes_le(&[]), 0);
+    /// assert_eq!(Integer::from_signed_bytes_le(&[123]), 123);
+    /// assert_eq!(Integer::from_signed_bytes_le(&[133]), -123);
+    /// assert_eq!(Integer::from_signed_bytes_le(&[128, 0]), 128);
+    /// assert_eq!(Integer::from_signed_bytes_le(&[127, 255]), -129);
+    /// assert_eq!(
+    ///     Integer::from_signed_bytes_le(&[0xcc, 0xed, 0xff, 0xff]),
+    ///     -0x1234
+    /// );
+    /// ```
+    pub fn from_signed_bytes_le(xs: &[u8]) -> Integer {
+        Integer::from_owned_twos_complement_limbs_asc(limbs_from_bytes_le(
+            xs,
+            sign_extension(xs.last()),
+        ))
+    }
+
+    /// Converts a slice of bytes to an [`Integer`].
+    ///
+    /// The bytes are in big-endian order, so that less-significant bytes have higher indices in
+    /// the input slice. They are in two's complement, and the most significant bit of the bytes
+    /// indicates the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is
+    /// one it is negative. If the slice is empty, zero is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_signed_bytes_be(&[]), 0);
+    /// assert_eq!(Integer::from_signed_bytes_be(&[123]), 123);
+    /// assert_eq!(Integer::from_signed_bytes_be(&[133]), -123);
+    /// assert_eq!(Integer::from_signed_bytes_be(&[0, 128]), 128);
+    /// assert_eq!(Integer::from_signed_bytes_be(&[255, 127]), -129);
+    /// assert_eq!(
+    ///     Integer::from_signed_bytes_be(&[0xff, 0xff, 0xed, 0xcc]),
+    ///     -0x1234
+    /// );
+    /// ```
+    pub fn from_signed_bytes_be(xs: &[u8]) -> Integer {
+        Integer::from_owned_twos_complement_limbs_asc(limbs_from_bytes_be(
+            xs,
+            sign_extension(xs.first()),
+        ))
+    }
+}
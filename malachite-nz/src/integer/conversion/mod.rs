@@ -207,6 +207,8 @@ pub mod from_primitive_float;
 /// assert_eq!(Integer::from(-123i32).to_string(), "-123");
 /// ```
 pub mod from_primitive_int;
+/// Functions for constructing an [`Integer`](crate::integer::Integer) from two's complement bytes.
+pub mod from_signed_bytes;
 /// Functions for constructing an [`Integer`](crate::integer::Integer) from two's complement
 /// [`Limb`](crate#limbs)s.
 pub mod from_twos_complement_limbs;
@@ -462,6 +464,9 @@ pub mod primitive_float_from_integer;
 /// assert_eq!(i32::convertible_from(&-Integer::from(10u32).pow(12)), false);
 /// ```
 pub mod primitive_int_from_integer;
+/// Implementations of [`From`] for converting between [`Integer`](crate::integer::Integer)s and
+/// [num-bigint](https://docs.rs/num-bigint)'s `BigInt`s.
+pub mod num_bigint;
 /// Implementations of traits for conversions between Python integers and
 /// [`Integer`](crate::integer::Integer)s using [pyo3](https://pyo3.rs/).
 pub mod pyo3;
@@ -471,6 +476,9 @@ pub mod serde;
 /// Implementations of traits for converting [`Integer`](crate::integer::Integer)s to and from
 /// [`String`]s.
 pub mod string;
+/// Functions for extracting the two's complement bytes of an
+/// [`Integer`](crate::integer::Integer).
+pub mod to_signed_bytes;
 /// Functions for extracting two's complement [`Limb`](crate#limbs)s from an
 /// [`Integer`](crate::integer::Integer).
 pub mod to_twos_complement_limbs;
@@ -0,0 +1,72 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_base::num::arithmetic::traits::Pow;
+//! use malachite_nz::integer::Integer;
+//! use num::BigInt;
+//!
+//! let x = -Integer::from(10).pow(30);
+//! let y = BigInt::from(&x);
+//! assert_eq!(y.to_string(), "-1000000000000000000000000000000");
+//! assert_eq!(Integer::from(y), x);
+//! ```
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use core::cmp::Ordering::*;
+use malachite_base::num::arithmetic::traits::{Sign as SignTrait, UnsignedAbs};
+use num::bigint::Sign;
+use num::{BigInt, BigUint};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&BigInt> for Integer {
+    #[inline]
+    fn from(n: &BigInt) -> Integer {
+        Integer::from_sign_and_abs(n.sign() != Sign::Minus, Natural::from(n.magnitude()))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<BigInt> for Integer {
+    #[inline]
+    fn from(n: BigInt) -> Integer {
+        let (sign, abs) = n.into_parts();
+        Integer::from_sign_and_abs(sign != Sign::Minus, Natural::from(abs))
+    }
+}
+
+fn bigint_sign(n: &Integer) -> Sign {
+    match n.sign() {
+        Less => Sign::Minus,
+        Equal => Sign::NoSign,
+        Greater => Sign::Plus,
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&Integer> for BigInt {
+    #[inline]
+    fn from(n: &Integer) -> BigInt {
+        BigInt::from_biguint(bigint_sign(n), BigUint::from(n.unsigned_abs_ref()))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<Integer> for BigInt {
+    #[inline]
+    fn from(n: Integer) -> BigInt {
+        let sign = bigint_sign(&n);
+        BigInt::from_biguint(sign, BigUint::from(n.unsigned_abs()))
+    }
+}
@@ -49,6 +49,7 @@
 //! ```
 
 use crate::integer::Integer;
+use crate::natural::conversion::to_bytes::limbs_to_bytes_le;
 use alloc::vec::Vec;
 use malachite_base::num::basic::traits::Zero;
 #[allow(unused_imports)]
@@ -126,7 +127,7 @@ impl ToPyObject for Integer {
             return 0.to_object(py);
         }
 
-        let bytes = limbs_to_bytes(
+        let bytes = limbs_to_bytes_le(
             self.twos_complement_limbs(),
             self.twos_complement_limb_count(),
         );
@@ -162,36 +163,6 @@ impl IntoPy<PyObject> for Integer {
     }
 }
 
-/// Convert 32-bit limbs (little endian) used by malachite to bytes (little endian)
-#[cfg(feature = "32_bit_limbs")]
-#[inline]
-fn limbs_to_bytes(limbs: impl Iterator<Item = u32>, limb_count: u64) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity((limb_count << 3) as usize);
-
-    for limb in limbs {
-        for byte in limb.to_le_bytes() {
-            bytes.push(byte);
-        }
-    }
-
-    bytes
-}
-
-/// Convert 64-bit limbs (little endian) used by malachite to bytes (little endian)
-#[cfg(not(feature = "32_bit_limbs"))]
-#[inline]
-fn limbs_to_bytes(limbs: impl Iterator<Item = u64>, limb_count: u64) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity((limb_count << 3) as usize);
-
-    for limb in limbs {
-        for byte in limb.to_le_bytes() {
-            bytes.push(byte);
-        }
-    }
-
-    bytes
-}
-
 /// Converts a Python integer to a vector of 32-bit limbs (little endian). Takes number of bytes to
 /// convert to. Multiple of 4. If `is_signed` is true, the integer is treated as signed, and two's
 /// complement is returned.
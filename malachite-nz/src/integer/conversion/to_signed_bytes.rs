@@ -0,0 +1,99 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::conversion::to_bytes::limbs_to_bytes_le;
+use alloc::vec::Vec;
+
+impl Integer {
+    /// Returns the bytes of an [`Integer`] in little-endian order, so that less-significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. There are no trailing zero bytes if the [`Integer`] is non-negative or trailing
+    /// `0xff` bytes if the [`Integer`] is negative, except as necessary to include the correct sign
+    /// bit. Zero is a special case: it contains no bytes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_signed_bytes_le().is_empty());
+    /// assert_eq!(Integer::from(123).to_signed_bytes_le(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_signed_bytes_le(), &[133]);
+    /// // Sign-extension for a non-negative `Integer`
+    /// assert_eq!(Integer::from(128).to_signed_bytes_le(), &[128, 0]);
+    /// assert_eq!(Integer::from(-128).to_signed_bytes_le(), &[128]);
+    /// // Sign-extension for a negative `Integer`
+    /// assert_eq!(Integer::from(-129).to_signed_bytes_le(), &[127, 255]);
+    /// assert_eq!(Integer::from(-0x1234).to_signed_bytes_le(), &[0xcc, 0xed]);
+    /// ```
+    pub fn to_signed_bytes_le(&self) -> Vec<u8> {
+        let limbs = self.to_twos_complement_limbs_asc();
+        let limb_count = limbs.len() as u64;
+        let mut bytes = limbs_to_bytes_le(limbs.into_iter(), limb_count);
+        let extension = if self.sign { 0 } else { u8::MAX };
+        // A most-significant extension byte is redundant if the byte below it already has the
+        // correct sign bit.
+        while let [.., penultimate, last] = bytes[..] {
+            if last != extension || (penultimate ^ extension) >> 7 != 0 {
+                break;
+            }
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in big-endian order, so that less-significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the bytes indicates the
+    /// sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. There are no leading zero bytes if the [`Integer`] is non-negative or leading
+    /// `0xff` bytes if the [`Integer`] is negative, except as necessary to include the correct sign
+    /// bit. Zero is a special case: it contains no bytes.
+    ///
+    /// This is similar to the output of Java's `BigInteger.toByteArray`, except for zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_signed_bytes_be().is_empty());
+    /// assert_eq!(Integer::from(123).to_signed_bytes_be(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_signed_bytes_be(), &[133]);
+    /// // Sign-extension for a non-negative `Integer`
+    /// assert_eq!(Integer::from(128).to_signed_bytes_be(), &[0, 128]);
+    /// assert_eq!(Integer::from(-128).to_signed_bytes_be(), &[128]);
+    /// // Sign-extension for a negative `Integer`
+    /// assert_eq!(Integer::from(-129).to_signed_bytes_be(), &[255, 127]);
+    /// assert_eq!(Integer::from(-0x1234).to_signed_bytes_be(), &[0xed, 0xcc]);
+    /// ```
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_signed_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+}
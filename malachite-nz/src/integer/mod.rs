@@ -119,6 +119,8 @@ pub mod conversion;
 pub mod exhaustive;
 /// Traits for logic and bit manipulation.
 pub mod logic;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits) for [`Integer`]s.
+pub mod num_traits;
 #[cfg(feature = "random")]
 /// Iterators that generate [`Integer`]s randomly.
 pub mod random;
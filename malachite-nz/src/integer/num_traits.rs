@@ -0,0 +1,239 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! This allows [`Integer`] to be used with generic code that is bounded on
+//! [num-traits](https://docs.rs/num-traits), such as `nalgebra` or `ndarray`.
+//!
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_nz::integer::Integer;
+//! use num::traits::{Num, Signed};
+//!
+//! fn abs_sum<T: Signed>(xs: Vec<T>) -> T {
+//!     xs.into_iter().fold(T::zero(), |acc, x| acc + x.abs())
+//! }
+//!
+//! assert_eq!(abs_sum(vec![Integer::from(-3), Integer::from(4)]), 7);
+//! assert_eq!(Integer::from_str_radix("-ff", 16).unwrap(), -255);
+//! ```
+
+use crate::integer::Integer;
+use crate::natural::num_traits::natural_to_primitive_float;
+use core::cmp::Ordering::*;
+use malachite_base::num::arithmetic::traits::{Abs, Pow as MalachitePow, Sign};
+use malachite_base::num::basic::traits::{NegativeOne, One as MalachiteOne, Zero as MalachiteZero};
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::*;
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Zero for Integer {
+    #[inline]
+    fn zero() -> Integer {
+        Integer::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl One for Integer {
+    #[inline]
+    fn one() -> Integer {
+        Integer::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Num for Integer {
+    type FromStrRadixErr = ();
+
+    /// Converts a string, in a specified radix, to an [`Integer`].
+    ///
+    /// This is equivalent to [`Integer::from_string_base`].
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Integer, ()> {
+        Integer::from_string_base(u8::exact_from(radix), s).ok_or(())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Signed for Integer {
+    #[inline]
+    fn abs(&self) -> Integer {
+        Abs::abs(self)
+    }
+
+    /// Returns the positive difference of two [`Integer`]s: `self - other` if it is positive, and
+    /// zero otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Integer) -> Integer {
+        if self <= other {
+            Integer::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Integer {
+        match self.sign() {
+            Less => Integer::NEGATIVE_ONE,
+            Equal => Integer::ZERO,
+            Greater => Integer::ONE,
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0u32
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0u32
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl Pow<$t> for Integer {
+            type Output = Integer;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                MalachitePow::pow(self, u64::exact_from(exp))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl<'a> Pow<$t> for &'a Integer {
+            type Output = Integer;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Integer {
+                MalachitePow::pow(self, u64::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+impl_pow!(usize);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl ToPrimitive for Integer {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f32`]. Values too large in magnitude to be
+    /// represented are converted to positive or negative infinity.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        let f: f32 = natural_to_primitive_float(self.unsigned_abs_ref());
+        Some(if *self >= 0u32 { f } else { -f })
+    }
+
+    /// Converts an [`Integer`] to the nearest [`f64`]. Values too large in magnitude to be
+    /// represented are converted to positive or negative infinity.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        let f: f64 = natural_to_primitive_float(self.unsigned_abs_ref());
+        Some(if *self >= 0u32 { f } else { -f })
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl FromPrimitive for Integer {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Integer> {
+        Some(Integer::from(n))
+    }
+
+    /// Converts an [`f32`] to an [`Integer`], rounding towards zero. Returns `None` if the value
+    /// is NaN or infinite.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Integer> {
+        if n.is_finite() {
+            Some(Integer::rounding_from(n, Down).0)
+        } else {
+            None
+        }
+    }
+
+    /// Converts an [`f64`] to an [`Integer`], rounding towards zero. Returns `None` if the value
+    /// is NaN or infinite.
+    #[inline]
+    fn from_f64(n: f64) -> Option<Integer> {
+        if n.is_finite() {
+            Some(Integer::rounding_from(n, Down).0)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl CheckedMul for Integer {
+    /// Multiplies two [`Integer`]s. Since an [`Integer`] cannot overflow, the result is never
+    /// `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Integer) -> Option<Integer> {
+        Some(self * other)
+    }
+}
@@ -105,6 +105,14 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u32`] instead of the default,
 //!   [`u64`].
+//! - `num_traits`: Implements the traits of [num-traits](https://docs.rs/num-traits), such as
+//!   `Num`, `Signed`, and `Pow`, for [`Natural`](natural::Natural) and
+//!   [`Integer`](integer::Integer), and adds conversions to and from num-bigint's `BigUint` and
+//!   `BigInt`. Some of these traits have methods with the same names as methods of Malachite's
+//!   traits. If both traits are in scope, method calls such as `(&x).abs()` or `x.pow(2)` on an
+//!   [`Integer`](integer::Integer) become ambiguous and fail to compile, so enabling this feature
+//!   can break code that imports both sets of traits. In that case, use fully qualified syntax,
+//!   like `Abs::abs(&x)` or `num::traits::Pow::pow(x, 2)`.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. Some of it is also used for testing `malachite-q`, so it can't
@@ -173,7 +181,7 @@ extern crate malachite_base;
 #[macro_use]
 extern crate serde;
 
-#[cfg(feature = "test_build")]
+#[cfg(feature = "num")]
 extern crate num;
 #[cfg(feature = "test_build")]
 extern crate rug;
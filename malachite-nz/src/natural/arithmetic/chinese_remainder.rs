@@ -0,0 +1,136 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    ChineseRemainder, DivExact, DivisibleBy, ExtendedGcd,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Given $x \equiv r_1 \mod m_1$ and $x \equiv r_2 \mod m_2$, where $r_1 < m_1$ and $r_2 < m_2$,
+// returns $(x, \operatorname{lcm}(m_1, m_2))$ with $x < \operatorname{lcm}(m_1, m_2)$, or `None` if
+// the congruences are inconsistent.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^2 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(m_1.significant_bits(),
+// m_2.significant_bits())`.
+fn combine_congruences(
+    r_1: Natural,
+    m_1: Natural,
+    r_2: Natural,
+    m_2: Natural,
+) -> Option<(Natural, Natural)> {
+    if m_1 == 1u32 {
+        return Some((r_2, m_2));
+    } else if m_2 == 1u32 {
+        return Some((r_1, m_1));
+    }
+    // s * m_1 + t * m_2 = g
+    let (g, s, _) = (&m_1).extended_gcd(&m_2);
+    let diff = Integer::from(r_2) - Integer::from(&r_1);
+    if !diff.unsigned_abs_ref().divisible_by(&g) {
+        return None;
+    }
+    let m_2 = m_2.div_exact(&g);
+    // m_1 * s ≡ g mod m_2, so m_1 * s * diff / g ≡ diff mod m_2
+    let k = (diff.div_exact(Integer::from(g)) * s).mod_natural(&m_2);
+    Some((r_1 + &m_1 * k, m_1 * m_2))
+}
+
+// Solves the congruences by combining them pairwise along a balanced binary tree, so that the
+// moduli being combined at each level are of similar sizes. Returns the solution and the LCM of the
+// moduli.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^3 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the bit lengths of the moduli.
+//
+// This function assumes that every modulus is nonzero. Since it returns as soon as any subset of
+// the congruences is inconsistent, callers must check the moduli before calling it.
+pub(crate) fn chinese_remainder_helper(pairs: &[(Natural, Natural)]) -> Option<(Natural, Natural)> {
+    match pairs.len() {
+        0 => Some((Natural::ZERO, Natural::ONE)),
+        1 => {
+            let (r, m) = &pairs[0];
+            Some((r % m, m.clone()))
+        }
+        n => {
+            let (left, right) = pairs.split_at(n >> 1);
+            let (r_1, m_1) = chinese_remainder_helper(left)?;
+            let (r_2, m_2) = chinese_remainder_helper(right)?;
+            combine_congruences(r_1, m_1, r_2, m_2)
+        }
+    }
+}
+
+impl ChineseRemainder for Natural {
+    /// Given a slice of pairs $(r_i, m_i)$, finds the unique [`Natural`] $x$ less than
+    /// $\operatorname{lcm}(m_1, \ldots, m_k)$ such that $x \equiv r_i \mod m_i$ for every $i$, or
+    /// returns `None` if no such $x$ exists.
+    ///
+    /// The moduli do not have to be pairwise coprime, and the residues do not have to be reduced. A
+    /// solution exists if and only if $r_i \equiv r_j \mod \gcd(m_i, m_j)$ for every $i$ and $j$.
+    /// If the slice is empty, the result is 0.
+    ///
+    /// The congruences are combined pairwise along a balanced binary tree, which keeps the sizes of
+    /// the combined moduli balanced; thousands of small moduli are combined in quasi-linear time.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the bit lengths of the
+    /// moduli.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ChineseRemainder;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&[
+    ///         (Natural::from(2u32), Natural::from(3u32)),
+    ///         (Natural::from(3u32), Natural::from(5u32)),
+    ///         (Natural::from(2u32), Natural::from(7u32))
+    ///     ]),
+    ///     Some(Natural::from(23u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&[
+    ///         (Natural::from(3u32), Natural::from(4u32)),
+    ///         (Natural::from(5u32), Natural::from(6u32))
+    ///     ]),
+    ///     Some(Natural::from(11u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::chinese_remainder(&[
+    ///         (Natural::from(3u32), Natural::from(4u32)),
+    ///         (Natural::from(4u32), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// assert_eq!(Natural::chinese_remainder(&[]), Some(Natural::ZERO));
+    /// ```
+    fn chinese_remainder(pairs: &[(Natural, Natural)]) -> Option<Natural> {
+        assert!(pairs.iter().all(|(_, m)| *m != 0u32), "Moduli must be positive");
+        chinese_remainder_helper(pairs).map(|(x, _)| x)
+    }
+}
@@ -38,7 +38,8 @@ pub_test! {limbs_limb_div_round_limbs(n: Limb, ds: &[Limb], rm: RoundingMode)
     } else {
         match rm {
             Down | Floor => Some((0, Less)),
-            Up | Ceiling => Some((1, Greater)),
+            // 0 is even, so `ToOdd` always rounds up
+            Up | Ceiling | ToOdd => Some((1, Greater)),
             Exact => None,
             // 1 if 2 * n > Natural::from_limbs_asc(ds); otherwise, 0
             Nearest => Some(
@@ -48,6 +49,14 @@ pub_test! {limbs_limb_div_round_limbs(n: Limb, ds: &[Limb], rm: RoundingMode)
                     (0, Less)
                 },
             ),
+            // 1 if 2 * n >= Natural::from_limbs_asc(ds); otherwise, 0
+            NearestTiesAway => Some(
+                if ds.len() == 2 && ds[1] == 1 && n.get_highest_bit() && (n << 1) >= ds[0] {
+                    (1, Greater)
+                } else {
+                    (0, Less)
+                },
+            ),
         }
     }
 }}
@@ -69,6 +78,24 @@ fn div_round_nearest(q: Natural, r: &Natural, d: &Natural) -> (Natural, Ordering
     }
 }
 
+// assumes r != 0
+fn div_round_nearest_ties_away(q: Natural, r: &Natural, d: &Natural) -> (Natural, Ordering) {
+    if double_cmp(r, d) == Less {
+        (q, Less)
+    } else {
+        (q.add_limb(1), Greater)
+    }
+}
+
+// assumes r != 0
+fn div_round_to_odd(q: Natural) -> (Natural, Ordering) {
+    if q.odd() {
+        (q, Less)
+    } else {
+        (q.add_limb(1), Greater)
+    }
+}
+
 // assumes r != 0
 fn div_round_assign_nearest(q: &mut Natural, r: &Natural, d: &Natural) -> Ordering {
     let compare = double_cmp(r, d);
@@ -80,6 +107,26 @@ fn div_round_assign_nearest(q: &mut Natural, r: &Natural, d: &Natural) -> Orderi
     }
 }
 
+// assumes r != 0
+fn div_round_assign_nearest_ties_away(q: &mut Natural, r: &Natural, d: &Natural) -> Ordering {
+    if double_cmp(r, d) == Less {
+        Less
+    } else {
+        *q += Natural::ONE;
+        Greater
+    }
+}
+
+// assumes r != 0
+fn div_round_assign_to_odd(q: &mut Natural) -> Ordering {
+    if q.odd() {
+        Less
+    } else {
+        *q += Natural::ONE;
+        Greater
+    }
+}
+
 impl DivRound<Natural> for Natural {
     type Output = Natural;
 
@@ -111,6 +158,19 @@ impl DivRound<Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \N, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -212,6 +272,19 @@ impl<'a> DivRound<&'a Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \N, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -313,6 +386,19 @@ impl<'a> DivRound<Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \N, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -381,6 +467,8 @@ impl<'a> DivRound<Natural> for &'a Natural {
                 Ceiling | Up => (q.add_limb(1), Greater),
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_nearest(q, &r, &other),
+                NearestTiesAway => div_round_nearest_ties_away(q, &r, &other),
+                ToOdd => div_round_to_odd(q),
             }
         }
     }
@@ -417,6 +505,19 @@ impl<'a, 'b> DivRound<&'b Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// g(x, y, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// g(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     q & \text{if} \\quad q \in \N, \\\\
+    ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $g(x, y, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
     ///
     /// Then $f(x, y, r) = (g(x, y, r), \operatorname{cmp}(g(x, y, r), q))$.
@@ -485,6 +586,8 @@ impl<'a, 'b> DivRound<&'b Natural> for &'a Natural {
                 Ceiling | Up => (q.add_limb(1), Greater),
                 Exact => panic!("Division is not exact: {self} / {other}"),
                 Nearest => div_round_nearest(q, &r, other),
+                NearestTiesAway => div_round_nearest_ties_away(q, &r, other),
+                ToOdd => div_round_to_odd(q),
             }
         }
     }
@@ -564,6 +667,8 @@ impl DivRoundAssign<Natural> for Natural {
                 }
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_assign_nearest(self, &r, &other),
+                NearestTiesAway => div_round_assign_nearest_ties_away(self, &r, &other),
+                ToOdd => div_round_assign_to_odd(self),
             }
         }
     }
@@ -643,6 +748,8 @@ impl<'a> DivRoundAssign<&'a Natural> for Natural {
                 }
                 Exact => panic!("Division is not exact"),
                 Nearest => div_round_assign_nearest(self, &r, other),
+                NearestTiesAway => div_round_assign_nearest_ties_away(self, &r, other),
+                ToOdd => div_round_assign_to_odd(self),
             }
         }
     }
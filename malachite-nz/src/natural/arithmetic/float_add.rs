@@ -35,6 +35,7 @@ use malachite_base::slices::slice_test_zero;
 const TWICE_WIDTH: u64 = Limb::WIDTH * 2;
 const THRICE_WIDTH: u64 = Limb::WIDTH * 3;
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn add_float_significands_in_place(
     mut x: &mut Natural,
     x_exp: &mut i32,
@@ -45,6 +46,7 @@ pub fn add_float_significands_in_place(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Ordering, bool) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         add_float_significands_in_place_same_prec(x, x_exp, y, y_exp, out_prec, rm)
     } else if *x_exp >= y_exp {
@@ -288,6 +290,7 @@ pub fn add_float_significands_in_place(
     }
 }
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn add_float_significands_in_place_ref(
     mut x: &mut Natural,
     x_exp: &mut i32,
@@ -298,6 +301,7 @@ pub fn add_float_significands_in_place_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> Ordering {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         add_float_significands_in_place_same_prec_ref(x, x_exp, y, y_exp, out_prec, rm)
     } else if *x_exp >= y_exp {
@@ -541,6 +545,7 @@ pub fn add_float_significands_in_place_ref(
     }
 }
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn add_float_significands_ref_ref<'a>(
     mut x: &'a Natural,
     mut x_exp: i32,
@@ -551,6 +556,7 @@ pub fn add_float_significands_ref_ref<'a>(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         add_float_significands_same_prec_ref_ref(x, x_exp, y, y_exp, out_prec, rm)
     } else {
@@ -926,6 +932,7 @@ fn add_float_significands_same_prec_lt_w(
                     (sum, x_exp, Greater)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -990,6 +997,7 @@ fn add_float_significands_same_prec_w(
                     (sum, x_exp, Greater)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1118,6 +1126,7 @@ fn add_float_significands_same_prec_gt_w_lt_2w(
                     (sum_0, sum_1, x_exp, Greater)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1229,6 +1238,7 @@ fn add_float_significands_same_prec_2w(
                     (sum_0, sum_1, x_exp, Greater)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1441,6 +1451,7 @@ fn add_float_significands_same_prec_gt_2w_lt_3w(
                     (sum_0, sum_1, sum_2, x_exp, Greater)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1788,6 +1799,7 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else if exp_diff >= prec {
@@ -1806,6 +1818,7 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         } else {
             match rm {
@@ -1836,6 +1849,7 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else {
@@ -1899,6 +1913,7 @@ fn add_float_significands_same_prec_ge_3w_ref_ref<'a>(
                     (x_exp, Equal)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1970,6 +1985,7 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else if exp_diff >= prec {
@@ -1984,6 +2000,7 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         } else {
             match rm {
@@ -2008,6 +2025,7 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else {
@@ -2071,6 +2089,7 @@ fn add_float_significands_same_prec_ge_3w_val_ref(
                     (x_exp, Equal)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -2123,6 +2142,7 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else if exp_diff >= prec {
@@ -2141,6 +2161,7 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         } else {
             match rm {
@@ -2171,6 +2192,7 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     }
                     (x_exp, Greater)
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     } else {
@@ -2234,6 +2256,7 @@ fn add_float_significands_same_prec_ge_3w_ref_val(
                     (x_exp, Equal)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -2309,6 +2332,7 @@ fn add_float_significands_general_round(
             }
             (x_exp, Greater)
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -53,6 +53,8 @@ use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_base::slices::slice_test_zero;
 
 // This is mpfr_div from div.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn div_float_significands_in_place(
     x: &mut Natural,
     x_prec: u64,
@@ -61,6 +63,7 @@ pub fn div_float_significands_in_place(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((increment_exp, o)) =
             div_float_significands_in_place_same_prec(x, y, out_prec, rm)
@@ -96,6 +99,8 @@ pub fn div_float_significands_in_place(
 }
 
 // This is mpfr_div from div.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn div_float_significands_in_place_ref(
     x: &mut Natural,
     x_prec: u64,
@@ -104,6 +109,7 @@ pub fn div_float_significands_in_place_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((increment_exp, o)) =
             div_float_significands_in_place_same_prec_ref(x, y, out_prec, rm)
@@ -141,6 +147,8 @@ pub fn div_float_significands_in_place_ref(
 }
 
 // This is mpfr_div from div.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn div_float_significands_ref_val(
     x: &Natural,
     x_prec: u64,
@@ -149,6 +157,7 @@ pub fn div_float_significands_ref_val(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((quotient, increment_exp, o)) =
             div_float_significands_same_prec_ref_val(x, y, out_prec, rm)
@@ -179,6 +188,8 @@ pub fn div_float_significands_ref_val(
 }
 
 // This is mpfr_div from div.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn div_float_significands_ref_ref(
     x: &Natural,
     x_prec: u64,
@@ -187,6 +198,7 @@ pub fn div_float_significands_ref_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((quotient, increment_exp, o)) =
             div_float_significands_same_prec_ref_ref(x, y, out_prec, rm)
@@ -424,6 +436,7 @@ fn div_float_significands_same_prec_lt_w(
         }
         Floor | Down => (quotient, increment_exp, Less),
         Ceiling | Up => (quotient.wrapping_add(shift_bit), increment_exp, Greater),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -501,6 +514,7 @@ fn div_float_significands_same_prec_w(
         }
         Floor | Down => (quotient, increment_exp, Less),
         Ceiling | Up => (quotient.wrapping_add(1), increment_exp, Greater),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -659,6 +673,7 @@ fn div_float_significands_same_prec_gt_w_lt_2w(
                 (z_0, z_1, increment_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -885,6 +900,7 @@ fn div_float_significands_long_by_short_to_out(
             }
             (exp_offset, Greater)
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -1035,6 +1051,7 @@ fn div_float_significands_long_by_short_in_place(
             }
             (exp_offset, Greater)
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -38,7 +38,7 @@ pub(crate) fn limbs_float_can_round(
     mut prec: u64,
     rm: RoundingMode,
 ) -> bool {
-    if rm == Nearest {
+    if rm == Nearest || rm == NearestTiesAway {
         prec += 1;
     }
     let len = xs.len();
@@ -123,6 +123,8 @@ pub(crate) fn round_helper_even(
 
 // This is MPFR_RNDRAW from mpfr-impl.h, MPFR 4.2.0, returning `inexact` and a `bool` signifying
 // whether the returned exponent should be incremented.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn round_helper_raw(
     out: &mut [Limb],
     out_prec: u64,
@@ -130,6 +132,7 @@ pub fn round_helper_raw(
     x_prec: u64,
     rm: RoundingMode,
 ) -> (i8, bool) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     round_helper(out, out_prec, xs, x_prec, rm, |out, xs_hi, ulp| {
         let ulp_mask = !(ulp - 1);
         if xs_hi[0] & ulp == 0 {
@@ -247,6 +250,7 @@ fn round_helper<F: Fn(&mut [Limb], &[Limb], Limb) -> (i8, bool)>(
                     (1, increment)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -41,6 +41,8 @@ use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_base::slices::slice_leading_zeros;
 
 // This is mpfr_mul from mul.c, MPFR 4.2.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn mul_float_significands_in_place(
     x: &mut Natural,
     x_prec: u64,
@@ -49,6 +51,7 @@ pub fn mul_float_significands_in_place(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((decrement_exp, o)) =
             mul_float_significands_in_place_same_prec(x, y, out_prec, rm)
@@ -88,6 +91,8 @@ pub fn mul_float_significands_in_place(
 }
 
 // This is mpfr_mul from mul.c, MPFR 4.2.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn mul_float_significands_in_place_ref(
     x: &mut Natural,
     x_prec: u64,
@@ -96,6 +101,7 @@ pub fn mul_float_significands_in_place_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         if let Some((decrement_exp, o)) =
             mul_float_significands_in_place_same_prec_ref(x, y, out_prec, rm)
@@ -135,6 +141,8 @@ pub fn mul_float_significands_in_place_ref(
 }
 
 // This is mpfr_mul from mul.c, MPFR 4.2.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn mul_float_significands_ref_ref(
     x: &Natural,
     x_prec: u64,
@@ -143,6 +151,7 @@ pub fn mul_float_significands_ref_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     match (x, y) {
         (Natural(Small(x)), Natural(Small(y))) => {
             mul_float_significands_ref_ref_helper(&[*x], x_prec, &[*y], y_prec, out_prec, rm)
@@ -342,6 +351,7 @@ fn mul_float_significands_same_prec_lt_w(
                 (product, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -383,6 +393,7 @@ fn mul_float_significands_same_prec_w(
                 (product, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -470,6 +481,7 @@ fn mul_float_significands_same_prec_gt_w_lt_2w(
                 (z_0, z_1, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -612,6 +624,7 @@ fn mul_float_significands_same_prec_gt_2w_lt_3w(
                 (z_0, z_1, z_2, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -50,12 +50,15 @@ const HIGH_BIT: Limb = 1 << WIDTH_M1;
 const TWICE_WIDTH: u64 = Limb::WIDTH * 2;
 
 // This is mpfr_div from div.c, MPFR 4.3.0, specialized for reciprocation.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn reciprocal_float_significand_in_place(
     x: &mut Natural,
     x_prec: u64,
     out_prec: u64,
     rm: RoundingMode,
 ) -> (u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if out_prec == x_prec {
         if let Some((increment_exp, o)) =
             reciprocal_float_significand_in_place_same_prec(x, out_prec, rm)
@@ -78,12 +81,15 @@ pub fn reciprocal_float_significand_in_place(
 }
 
 // This is mpfr_div from div.c, MPFR 4.3.0, specialized for reciprocation.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn reciprocal_float_significand_ref(
     x: &Natural,
     x_prec: u64,
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, u64, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if out_prec == x_prec {
         if let Some((reciprocal, increment_exp, o)) =
             reciprocal_float_significand_same_prec_ref(x, out_prec, rm)
@@ -202,6 +208,7 @@ fn reciprocal_float_significand_same_prec_lt_w(
         }
         Floor | Down => (reciprocal, false, Less),
         Ceiling | Up => (reciprocal.wrapping_add(shift_bit), false, Greater),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -248,6 +255,7 @@ fn reciprocal_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb,
             reciprocal.wrapping_add_assign(1);
             (reciprocal, false, Greater)
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -393,6 +401,7 @@ fn reciprocal_float_significand_same_prec_gt_w_lt_2w(
                 (z_0, z_1, false, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -511,6 +520,7 @@ fn reciprocal_float_significand_short_to_out(
             }
             (exp_offset, Greater)
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
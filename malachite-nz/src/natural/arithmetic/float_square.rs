@@ -17,8 +17,10 @@ use crate::natural::arithmetic::add::{
 };
 use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
 use crate::natural::arithmetic::float_extras::{limbs_float_can_round, round_helper_raw};
-use crate::natural::arithmetic::float_mul::{limbs_float_mul_high_same_length, MPFR_MULHIGH_TAB};
-use crate::natural::arithmetic::mul::limbs_mul_same_length_to_out;
+use crate::natural::arithmetic::float_mul::{
+    limbs_float_mul_high_same_length, limbs_float_mul_high_same_length_scratch_len,
+    MPFR_MULHIGH_TAB,
+};
 use crate::natural::arithmetic::shl::limbs_slice_shl_in_place;
 use crate::natural::arithmetic::square::{
     limbs_square_to_out, limbs_square_to_out_basecase, limbs_square_to_out_scratch_len,
@@ -41,12 +43,15 @@ use malachite_base::rounding_modes::RoundingMode::{self, *};
 use malachite_base::slices::slice_leading_zeros;
 
 // This is mpfr_sqr from sqr.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn square_float_significand_in_place(
     x: &mut Natural,
     x_prec: u64,
     out_prec: u64,
     rm: RoundingMode,
 ) -> (i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if out_prec == x_prec {
         if let Some((decrement_exp, o)) =
             square_float_significand_in_place_same_prec(x, out_prec, rm)
@@ -63,12 +68,15 @@ pub fn square_float_significand_in_place(
 }
 
 // This is mpfr_sqr from sqr.c, MPFR 4.3.0.
+//
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn square_float_significand_ref(
     x: &Natural,
     x_prec: u64,
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, i32, Ordering) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     match x {
         Natural(Small(x)) => square_float_significand_ref_helper(&[*x], x_prec, out_prec, rm),
         Natural(Large(xs)) => square_float_significand_ref_helper(xs, x_prec, out_prec, rm),
@@ -204,6 +212,7 @@ fn square_float_significand_same_prec_lt_w(
                 (square, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -241,6 +250,7 @@ fn square_float_significand_same_prec_w(x: Limb, rm: RoundingMode) -> (Limb, boo
                 (square, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -325,6 +335,7 @@ fn square_float_significand_same_prec_gt_w_lt_2w(
                 (z_0, z_1, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -459,6 +470,7 @@ fn square_float_significand_same_prec_gt_2w_lt_3w(
                 (z_0, z_1, z_2, decrement_exp, Greater)
             }
         }
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -488,7 +500,10 @@ fn limbs_float_square_high_scratch_len(len: usize) -> usize {
         if k == 0 {
             0
         } else {
-            limbs_square_to_out_scratch_len(max(len, len - k))
+            max(
+                limbs_square_to_out_scratch_len(k),
+                limbs_float_mul_high_same_length_scratch_len(len - k),
+            )
         }
     }
 }
@@ -533,7 +548,7 @@ fn limbs_float_square_high(out: &mut [Limb], xs: &[Limb], scratch: &mut [Limb])
         let out = &mut out[..len << 1];
         let (out_lo, out_hi) = out.split_at_mut(l << 1);
         let (xs_lo, xs_hi) = xs.split_at(l);
-        limbs_mul_same_length_to_out(out_hi, &xs[l..], xs_hi, scratch);
+        limbs_square_to_out(out_hi, xs_hi, scratch);
         limbs_float_mul_high_same_length(out_lo, &xs[k..], xs_lo, scratch);
         let out_hi = &mut out_hi[k - l - 1..k];
         let mut carry = Limb::from(limbs_slice_add_same_length_in_place_left(
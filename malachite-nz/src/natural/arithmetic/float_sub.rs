@@ -55,6 +55,7 @@ const TWICE_WIDTH: u64 = Limb::WIDTH * 2;
 const THRICE_WIDTH: u64 = Limb::WIDTH * 3;
 const TWICE_WIDTH_P1: u64 = Limb::WIDTH * 2 + 1;
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn sub_float_significands_in_place(
     mut x: &mut Natural,
     x_exp: &mut i32,
@@ -65,6 +66,7 @@ pub fn sub_float_significands_in_place(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Ordering, bool, bool) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         sub_float_significands_in_place_same_prec(x, x_exp, y, y_exp, out_prec, rm)
     } else {
@@ -241,6 +243,7 @@ pub fn sub_float_significands_in_place(
     }
 }
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn sub_float_significands_in_place_ref(
     mut x: &mut Natural,
     x_exp: &mut i32,
@@ -251,6 +254,7 @@ pub fn sub_float_significands_in_place_ref(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Ordering, bool) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         sub_float_significands_in_place_same_prec_ref(x, x_exp, y, y_exp, out_prec, rm)
     } else {
@@ -427,6 +431,7 @@ pub fn sub_float_significands_in_place_ref(
     }
 }
 
+// `rm` must not be `NearestTiesAway` or `ToOdd`.
 pub fn sub_float_significands_ref_ref<'a>(
     x: &'a Natural,
     x_exp: i32,
@@ -437,6 +442,7 @@ pub fn sub_float_significands_ref_ref<'a>(
     out_prec: u64,
     rm: RoundingMode,
 ) -> (Natural, i32, Ordering, bool) {
+    debug_assert!(rm != NearestTiesAway && rm != ToOdd);
     if x_prec == y_prec && out_prec == x_prec {
         sub_float_significands_same_prec_ref_ref(x, x_exp, y, y_exp, out_prec, rm)
     } else {
@@ -924,6 +930,7 @@ fn sub_float_significands_same_prec_lt_w(
                         (diff, x_exp, Greater, neg)
                     }
                 }
+                NearestTiesAway | ToOdd => unreachable!(),
             }
         }
     }
@@ -1056,6 +1063,7 @@ fn sub_float_significands_same_prec_w(
                     (diff, x_exp, Greater, neg)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1294,6 +1302,7 @@ fn sub_float_significands_same_prec_gt_w_lt_2w(
                     (diff_0, diff_1, x_exp, Greater, neg)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1544,6 +1553,7 @@ fn sub_float_significands_same_prec_2w(
                     (diff_0, diff_1, x_exp, Greater, neg)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -1908,6 +1918,7 @@ fn sub_float_significands_same_prec_gt_2w_lt_3w(
                     (diff_0, diff_1, diff_2, x_exp, Greater, neg)
                 }
             }
+            NearestTiesAway | ToOdd => unreachable!(),
         }
     }
 }
@@ -2413,6 +2424,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_ref<'a>(
             (x_exp, Less, neg)
         }
         Ceiling | Up => (x_exp, Greater, neg),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -2915,6 +2927,7 @@ fn sub_float_significands_same_prec_ge_3w_val_ref_helper(
             (x_exp, Less)
         }
         Ceiling | Up => (x_exp, Greater),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -3357,6 +3370,7 @@ fn sub_float_significands_same_prec_ge_3w_ref_val_helper(
             (x_exp, Less)
         }
         Ceiling | Up => (x_exp, Greater),
+        NearestTiesAway | ToOdd => unreachable!(),
     }
 }
 
@@ -4084,6 +4098,7 @@ fn sub_float_significands_general<'a>(
                             // subtract one ulp. Note: for shift = 0 we can't have low(x) - low(y) =
                             // -0.5 ulp up to now, since low(x) - low(y) > 1 / 2 ^ shift
                         }
+                        NearestTiesAway | ToOdd => unreachable!(),
                     }
                 }
                 Greater => {
@@ -4141,6 +4156,7 @@ fn sub_float_significands_general<'a>(
                                 }
                             }
                         }
+                        NearestTiesAway | ToOdd => unreachable!(),
                     }
                 }
                 _ => {}
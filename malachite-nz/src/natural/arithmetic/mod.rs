@@ -27,6 +27,10 @@ pub mod checked_sub;
 /// trait for subtracting the product of two numbers from another number, and checking whether the
 /// result is representable.
 pub mod checked_sub_mul;
+/// An implementation of
+/// [`ChineseRemainder`](malachite_base::num::arithmetic::traits::ChineseRemainder), a trait for
+/// solving a system of congruences.
+pub mod chinese_remainder;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
@@ -528,6 +532,9 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// [`MontgomeryContext`](montgomery::MontgomeryContext), a reusable context for multiplying and
+/// exponentiating modulo a fixed odd [`Natural`](super::Natural) using Montgomery reduction.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
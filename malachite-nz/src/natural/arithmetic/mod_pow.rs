@@ -181,7 +181,7 @@ fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
 // where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
 //
 // This is equivalent to `redcify` from `mpn/generic/powm.c`, 6.2.1. Investigate changes from 6.1.2?
-fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
+pub(crate) fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
     let xs_len = xs.len();
     let ms_len = ms.len();
     if ms_len == 1 {
@@ -197,7 +197,7 @@ fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
 }
 
 // TODO tune
-const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
+pub(crate) const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
 
 // # Worst-case complexity
 // Constant time and additional memory.
@@ -238,7 +238,7 @@ fn limbs_redc_helper(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], is: &[Limb]
 // # Worst-case complexity
 // Constant time and additional memory.
 #[allow(clippy::absurd_extreme_comparisons, clippy::type_complexity)]
-fn select_fns(
+pub(crate) fn select_fns(
     ms_len: usize,
 ) -> (
     &'static dyn Fn(&mut [Limb], &[Limb], &[Limb]),
@@ -0,0 +1,446 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    get_bits, get_window_size, select_fns, to_redc, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::logic::significant_bits::limbs_significant_bits;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::{min, Ordering::*};
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2, WrappingNegAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::TrailingZeros;
+
+/// The exponentiation algorithm used by [`MontgomeryContext::pow_with_window`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MontgomeryPowWindow {
+    /// Scans the exponent from the most significant bit down, skipping runs of zeros, and
+    /// multiplies by a precomputed odd power of the base once per window. This is the algorithm
+    /// that [`Natural::mod_pow`](malachite_base::num::arithmetic::traits::ModPow::mod_pow) uses.
+    Sliding,
+    /// Splits the exponent into windows of equal width and multiplies by a precomputed power of the
+    /// base once per window, even when the window is zero. The sequence of squarings and
+    /// multiplications depends only on the bit length of the exponent.
+    Fixed,
+}
+
+/// A residue modulo the modulus of a [`MontgomeryContext`], stored in Montgomery form.
+///
+/// If the modulus $m$ has $n$ limbs and $R = 2^{Wn}$, where $W$ is the width of a
+/// [`Limb`](crate#limbs), the residue $x$ is stored as $xR \mod m$. A `MontgomeryResidue` is only
+/// meaningful together with the [`MontgomeryContext`] that created it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MontgomeryResidue(Vec<Limb>);
+
+/// Precomputed data for multiplying and exponentiating modulo a fixed odd [`Natural`].
+///
+/// Residues are converted into Montgomery form with [`to_montgomery`](Self::to_montgomery) and back
+/// with [`to_natural`](Self::to_natural). In between, multiplication, squaring, and exponentiation
+/// replace divisions by the modulus with cheaper Montgomery reductions, and don't need to recompute
+/// the inverse of the modulus. This makes a context much faster than repeated calls to
+/// [`mod_pow`](malachite_base::num::arithmetic::traits::ModPow::mod_pow) when many exponentiations
+/// share a modulus.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MontgomeryContext {
+    modulus: Natural,
+    ms: Vec<Limb>,
+    // If `ms` is shorter than `REDC_1_TO_REDC_N_THRESHOLD`, this is the single limb $-m^{-1} \mod
+    // 2^W$; otherwise it is $m^{-1} \mod 2^{Wn}$.
+    is: Vec<Limb>,
+    // $R \mod m$, the Montgomery form of 1.
+    one: Vec<Limb>,
+}
+
+impl MontgomeryContext {
+    /// Creates a [`MontgomeryContext`] for an odd modulus $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// assert_eq!(*context.modulus(), 497);
+    /// ```
+    pub fn new(m: Natural) -> MontgomeryContext {
+        assert!(m.odd(), "The modulus must be odd, but it is {m}");
+        let ms = m.to_limbs_asc();
+        let ms_len = ms.len();
+        let is = if ms_len < REDC_1_TO_REDC_N_THRESHOLD {
+            let mut i = limbs_modular_invert_limb(ms[0]);
+            i.wrapping_neg_assign();
+            vec![i]
+        } else {
+            let mut is = vec![0; ms_len];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(ms_len)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let mut one = vec![0; ms_len];
+        to_redc(&mut one, &[1], &ms);
+        MontgomeryContext {
+            modulus: m,
+            ms,
+            is,
+            one,
+        }
+    }
+
+    /// Returns the modulus of a [`MontgomeryContext`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// assert_eq!(*context.modulus(), 1000000007);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns the Montgomery form of 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// assert_eq!(context.to_natural(&context.one()), 1);
+    /// ```
+    #[inline]
+    pub fn one(&self) -> MontgomeryResidue {
+        MontgomeryResidue(self.one.clone())
+    }
+
+    /// Converts a [`Natural`] to Montgomery form. The [`Natural`] doesn't need to be reduced modulo
+    /// $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// self.modulus().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// let x = context.to_montgomery(&Natural::from(1000u32));
+    /// assert_eq!(context.to_natural(&x), 6);
+    /// ```
+    pub fn to_montgomery(&self, x: &Natural) -> MontgomeryResidue {
+        let mut out = vec![0; self.ms.len()];
+        if *x != 0u32 {
+            to_redc(&mut out, &x.to_limbs_asc(), &self.ms);
+        }
+        MontgomeryResidue(out)
+    }
+
+    /// Converts a residue in Montgomery form back to a [`Natural`] less than $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// let x = context.to_montgomery(&Natural::from(123u32));
+    /// assert_eq!(context.to_natural(&x), 123);
+    /// ```
+    pub fn to_natural(&self, x: &MontgomeryResidue) -> Natural {
+        let ms_len = self.ms.len();
+        let mut scratch = vec![0; ms_len << 1];
+        scratch[..ms_len].copy_from_slice(&x.0);
+        let mut out = vec![0; ms_len];
+        self.reduce(&mut out, &mut scratch);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Multiplies two residues in Montgomery form.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// let x = context.to_montgomery(&Natural::from(123456u32));
+    /// let y = context.to_montgomery(&Natural::from(654321u32));
+    /// assert_eq!(context.to_natural(&context.mul(&x, &y)), 779852816);
+    /// ```
+    pub fn mul(&self, x: &MontgomeryResidue, y: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut scratch = vec![0; self.ms.len() << 1];
+        let mut out = vec![0; self.ms.len()];
+        self.mul_to_out(&mut out, &x.0, &y.0, &mut scratch);
+        MontgomeryResidue(out)
+    }
+
+    /// Squares a residue in Montgomery form.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(1000000007u32));
+    /// let x = context.to_montgomery(&Natural::from(123456u32));
+    /// assert_eq!(context.to_natural(&context.square(&x)), 241383831);
+    /// ```
+    pub fn square(&self, x: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.0.clone();
+        let mut scratch = vec![0; self.ms.len() << 1];
+        self.square_in_place(&mut out, &mut scratch);
+        MontgomeryResidue(out)
+    }
+
+    /// Raises a residue in Montgomery form to a [`Natural`] power, using a sliding window.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`, and
+    /// $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// let x = context.to_montgomery(&Natural::from(4u32));
+    /// assert_eq!(
+    ///     context.to_natural(&context.pow(&x, &Natural::from(13u32))),
+    ///     445
+    /// );
+    ///
+    /// // 2^127 - 1 is prime, so 2^(2^100) = 2^(2^100 mod 127) = 2^4 mod 2^127 - 1
+    /// let context = MontgomeryContext::new(Natural::power_of_2(127) - Natural::from(1u32));
+    /// let x = context.to_montgomery(&Natural::from(2u32));
+    /// assert_eq!(
+    ///     context.to_natural(&context.pow(&x, &Natural::power_of_2(100))),
+    ///     16
+    /// );
+    /// ```
+    #[inline]
+    pub fn pow(&self, x: &MontgomeryResidue, exp: &Natural) -> MontgomeryResidue {
+        self.pow_with_window(x, exp, MontgomeryPowWindow::Sliding)
+    }
+
+    /// Raises a residue in Montgomery form to a [`Natural`] power, using the specified windowing
+    /// algorithm.
+    ///
+    /// Both algorithms choose the window width from the bit length of the exponent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`, and
+    /// $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::{MontgomeryContext, MontgomeryPowWindow};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(Natural::from(497u32));
+    /// let x = context.to_montgomery(&Natural::from(4u32));
+    /// let exp = Natural::from(13u32);
+    /// assert_eq!(
+    ///     context.to_natural(&context.pow_with_window(&x, &exp, MontgomeryPowWindow::Sliding)),
+    ///     445
+    /// );
+    /// assert_eq!(
+    ///     context.to_natural(&context.pow_with_window(&x, &exp, MontgomeryPowWindow::Fixed)),
+    ///     445
+    /// );
+    /// ```
+    pub fn pow_with_window(
+        &self,
+        x: &MontgomeryResidue,
+        exp: &Natural,
+        window: MontgomeryPowWindow,
+    ) -> MontgomeryResidue {
+        if *exp == 0u32 {
+            return self.one();
+        }
+        let es = exp.to_limbs_asc();
+        MontgomeryResidue(match window {
+            MontgomeryPowWindow::Sliding => self.pow_sliding(&x.0, &es),
+            MontgomeryPowWindow::Fixed => self.pow_fixed(&x.0, &es),
+        })
+    }
+
+    // Reduces the `2 * n` limbs of `scratch`, writing a result less than $m$ to `out`.
+    fn reduce(&self, out: &mut [Limb], scratch: &mut [Limb]) {
+        let (_, _, reduce_fn) = select_fns(self.ms.len());
+        reduce_fn(out, scratch, &self.ms, &self.is);
+        if limbs_cmp_same_length(out, &self.ms) != Less {
+            limbs_sub_same_length_in_place_left(out, &self.ms);
+        }
+    }
+
+    fn mul_to_out(&self, out: &mut [Limb], xs: &[Limb], ys: &[Limb], scratch: &mut [Limb]) {
+        let (mul_fn, _, _) = select_fns(self.ms.len());
+        mul_fn(scratch, xs, ys);
+        self.reduce(out, scratch);
+    }
+
+    fn square_in_place(&self, xs: &mut [Limb], scratch: &mut [Limb]) {
+        let (_, square_fn, _) = select_fns(self.ms.len());
+        square_fn(scratch, xs);
+        self.reduce(xs, scratch);
+    }
+
+    fn mul_in_place(&self, xs: &mut [Limb], ys: &[Limb], scratch: &mut [Limb]) {
+        let (mul_fn, _, _) = select_fns(self.ms.len());
+        mul_fn(scratch, xs, ys);
+        self.reduce(xs, scratch);
+    }
+
+    // `es` must be nonzero.
+    fn pow_sliding(&self, xs: &[Limb], es: &[Limb]) -> Vec<Limb> {
+        let ms_len = self.ms.len();
+        let mut scratch = vec![0; ms_len << 1];
+        let width = limbs_significant_bits(es);
+        let window_size = get_window_size(width);
+        // Precompute the odd powers $x, x^3, \ldots, x^{2^k - 1}$, where $k$ is the
+        // window size.
+        let mut x_squared = xs.to_vec();
+        self.square_in_place(&mut x_squared, &mut scratch);
+        let mut powers = vec![xs.to_vec()];
+        for i in 1..usize::power_of_2(window_size - 1) {
+            let mut power = vec![0; ms_len];
+            self.mul_to_out(&mut power, &powers[i - 1], &x_squared, &mut scratch);
+            powers.push(power);
+        }
+        let mut out: Option<Vec<Limb>> = None;
+        let mut bit_index = width;
+        while bit_index != 0 {
+            if !limbs_get_bit(es, bit_index - 1) {
+                // The most significant bit is 1, so `out` has already been initialized.
+                self.square_in_place(out.as_mut().unwrap(), &mut scratch);
+                bit_index -= 1;
+                continue;
+            }
+            // Extract the largest block of at most `window_size` bits whose least
+            // significant bit is 1.
+            let this_window_size = min(window_size, bit_index);
+            let exp_bits = get_bits(es, bit_index, this_window_size);
+            let trailing_zeros = TrailingZeros::trailing_zeros(Limb::exact_from(exp_bits));
+            let power = &powers[exp_bits >> trailing_zeros >> 1];
+            let this_window_size = this_window_size - trailing_zeros;
+            if let Some(out) = out.as_mut() {
+                for _ in 0..this_window_size {
+                    self.square_in_place(out, &mut scratch);
+                }
+                self.mul_in_place(out, power, &mut scratch);
+            } else {
+                out = Some(power.clone());
+            }
+            bit_index -= this_window_size;
+        }
+        out.unwrap()
+    }
+
+    // `es` must be nonzero.
+    fn pow_fixed(&self, xs: &[Limb], es: &[Limb]) -> Vec<Limb> {
+        let ms_len = self.ms.len();
+        let mut scratch = vec![0; ms_len << 1];
+        let width = limbs_significant_bits(es);
+        let window_size = get_window_size(width);
+        // Precompute the powers $1, x, x^2, \ldots, x^{2^k - 1}$, where $k$ is the
+        // window size.
+        let mut powers = vec![self.one.clone(), xs.to_vec()];
+        for i in 2..usize::power_of_2(window_size) {
+            let mut power = vec![0; ms_len];
+            self.mul_to_out(&mut power, &powers[i - 1], xs, &mut scratch);
+            powers.push(power);
+        }
+        // The most significant window may be narrower than the others.
+        let first_window_size = match width % window_size {
+            0 => window_size,
+            r => r,
+        };
+        let mut bit_index = width - first_window_size;
+        let mut out = powers[get_bits(es, width, first_window_size)].clone();
+        while bit_index != 0 {
+            for _ in 0..window_size {
+                self.square_in_place(&mut out, &mut scratch);
+            }
+            self.mul_in_place(
+                &mut out,
+                &powers[get_bits(es, bit_index, window_size)],
+                &mut scratch,
+            );
+            bit_index -= window_size;
+        }
+        out
+    }
+}
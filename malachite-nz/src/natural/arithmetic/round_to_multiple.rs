@@ -38,6 +38,20 @@ impl RoundToMultiple<Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y
+    /// \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \N, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -55,7 +69,8 @@ impl RoundToMultiple<Natural> for Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -149,6 +164,20 @@ impl<'a> RoundToMultiple<&'a Natural> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y
+    /// \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \N, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -166,7 +195,8 @@ impl<'a> RoundToMultiple<&'a Natural> for Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -260,6 +290,20 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y
+    /// \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \N, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -277,7 +321,8 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -341,7 +386,7 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
         match (self, other) {
             (x, y) if *x == y => (y, Equal),
             (x, Natural::ZERO) => match rm {
-                Down | Floor | Nearest => (Natural::ZERO, Less),
+                Down | Floor | Nearest | NearestTiesAway => (Natural::ZERO, Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -372,6 +417,22 @@ impl<'a> RoundToMultiple<Natural> for &'a Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if (r << 1u64) < y {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
+                        ToOdd => {
+                            // The odd multiple of y will have fewer trailing zeros.
+                            let ceiling = &floor + y;
+                            if floor != 0 && floor.trailing_zeros() < ceiling.trailing_zeros() {
+                                (floor, Less)
+                            } else {
+                                (ceiling, Greater)
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -408,6 +469,20 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y
+    /// \left \lfloor q + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \N, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \N
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \N$.
     ///
     /// The following two expressions are equivalent:
@@ -425,7 +500,8 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -489,7 +565,7 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
         match (self, other) {
             (x, y) if x == y => (x.clone(), Equal),
             (x, &Natural::ZERO) => match rm {
-                Down | Floor | Nearest => (Natural::ZERO, Less),
+                Down | Floor | Nearest | NearestTiesAway => (Natural::ZERO, Less),
                 _ => panic!("Cannot round {x} to zero using RoundingMode {rm}"),
             },
             (x, y) => {
@@ -520,6 +596,22 @@ impl<'a, 'b> RoundToMultiple<&'b Natural> for &'a Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if (r << 1u64) < *y {
+                                (floor, Less)
+                            } else {
+                                (floor + y, Greater)
+                            }
+                        }
+                        ToOdd => {
+                            // The odd multiple of y will have fewer trailing zeros.
+                            let ceiling = &floor + y;
+                            if floor != 0 && floor.trailing_zeros() < ceiling.trailing_zeros() {
+                                (floor, Less)
+                            } else {
+                                (ceiling, Greater)
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -553,7 +645,8 @@ impl RoundToMultipleAssign<Natural> for Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -614,7 +707,7 @@ impl RoundToMultipleAssign<Natural> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == y => Equal,
             (x, Natural::ZERO) => match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     *self = Natural::ZERO;
                     Less
                 }
@@ -655,6 +748,24 @@ impl RoundToMultipleAssign<Natural> for Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if (r << 1u64) < y {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
+                        ToOdd => {
+                            // The odd multiple of y will have fewer trailing zeros.
+                            let ceiling = &*x + y;
+                            if *x != 0 && x.trailing_zeros() < ceiling.trailing_zeros() {
+                                Less
+                            } else {
+                                *x = ceiling;
+                                Greater
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -688,7 +799,8 @@ impl<'a> RoundToMultipleAssign<&'a Natural> for Natural {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -752,7 +864,7 @@ impl<'a> RoundToMultipleAssign<&'a Natural> for Natural {
         match (&mut *self, other) {
             (x, y) if *x == *y => Equal,
             (x, &Natural::ZERO) => match rm {
-                Down | Floor | Nearest => {
+                Down | Floor | Nearest | NearestTiesAway => {
                     *self = Natural::ZERO;
                     Less
                 }
@@ -793,6 +905,24 @@ impl<'a> RoundToMultipleAssign<&'a Natural> for Natural {
                                 }
                             }
                         }
+                        NearestTiesAway => {
+                            if (r << 1u64) < *y {
+                                Less
+                            } else {
+                                *x += y;
+                                Greater
+                            }
+                        }
+                        ToOdd => {
+                            // The odd multiple of y will have fewer trailing zeros.
+                            let ceiling = &*x + y;
+                            if *x != 0 && x.trailing_zeros() < ceiling.trailing_zeros() {
+                                Less
+                            } else {
+                                *x = ceiling;
+                                Greater
+                            }
+                        }
                         Exact => {
                             panic!("Cannot round {x} to {y} using RoundingMode {rm}")
                         }
@@ -8,7 +8,7 @@
 
 use crate::natural::arithmetic::add::limbs_slice_add_limb_in_place;
 use crate::natural::arithmetic::divisible_by_power_of_2::limbs_divisible_by_power_of_2;
-use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::logic::bit_access::{limbs_get_bit, limbs_vec_set_bit};
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
@@ -155,6 +155,50 @@ pub_test! {
     }
 }}
 
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` rounded to the nearest multiple of `2 ^ pow`. If the original value is
+// exactly between two multiples, it is rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_away(
+    xs: &[Limb],
+    pow: u64
+) -> (Vec<Limb>, Ordering) {
+    if pow == 0 {
+        (xs.to_vec(), Equal)
+    } else if !limbs_get_bit(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_down(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up(xs, pow)
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` rounded to a multiple of `2 ^ pow`. If the original value is not already a
+// multiple, it is rounded to the adjacent multiple whose `pow`th bit is one.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {
+    limbs_round_to_multiple_of_power_of_2_to_odd(xs: &[Limb], pow: u64) -> (Vec<Limb>, Ordering) {
+    let (mut out, o) = limbs_round_to_multiple_of_power_of_2_down(xs, pow);
+    if o == Equal || limbs_get_bit(xs, pow) {
+        (out, o)
+    } else {
+        limbs_vec_set_bit(&mut out, pow);
+        (out, Greater)
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the `Natural` rounded to a multiple of `2 ^ pow`, using a specified rounding format. If
 // the original value is not already a multiple of the power of 2, and the `RoundingMode` is
@@ -179,6 +223,8 @@ pub_test! {limbs_round_to_multiple_of_power_of_2(
             Some(limbs_round_to_multiple_of_power_of_2_up(xs, pow))
         }
         Nearest => Some(limbs_round_to_multiple_of_power_of_2_nearest(xs, pow)),
+        NearestTiesAway => Some(limbs_round_to_multiple_of_power_of_2_nearest_ties_away(xs, pow)),
+        ToOdd => Some(limbs_round_to_multiple_of_power_of_2_to_odd(xs, pow)),
         Exact => {
             if limbs_divisible_by_power_of_2(xs, pow) {
                 Some((xs.to_vec(), Equal))
@@ -331,6 +377,53 @@ pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_in_place(
     }
 }}
 
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural`, rounded to the nearest multiple of `2 ^ pow`, to the input `Vec`. If the
+// original value is exactly between two multiples, it is rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(
+    xs: &mut Vec<Limb>,
+    pow: u64
+) -> Ordering {
+    if pow == 0 {
+        Equal
+    } else if !limbs_get_bit(xs, pow - 1) {
+        limbs_round_to_multiple_of_power_of_2_down_in_place(xs, pow)
+    } else {
+        limbs_round_to_multiple_of_power_of_2_up_in_place(xs, pow)
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural`, rounded to a multiple of `2 ^ pow`, to the input `Vec`. If the original
+// value is not already a multiple, it is rounded to the adjacent multiple whose `pow`th bit is one.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `max(xs.len(), pow / Limb::WIDTH)`.
+pub_test! {limbs_round_to_multiple_of_power_of_2_to_odd_in_place(
+    xs: &mut Vec<Limb>,
+    pow: u64
+) -> Ordering {
+    let odd = limbs_get_bit(xs, pow);
+    let o = limbs_round_to_multiple_of_power_of_2_down_in_place(xs, pow);
+    if o == Equal || odd {
+        o
+    } else {
+        limbs_vec_set_bit(xs, pow);
+        Greater
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
 // limbs of the `Natural` rounded to the nearest multiple of `2 ^ pow` to the input `Vec`, using a
 // specified rounding format. If the original value is not already a multiple of the power of two,
@@ -358,6 +451,10 @@ pub_test! {limbs_round_to_multiple_of_power_of_2_in_place(
         Nearest => Some(limbs_round_to_multiple_of_power_of_2_nearest_in_place(
             xs, pow,
         )),
+        NearestTiesAway => Some(limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(
+            xs, pow,
+        )),
+        ToOdd => Some(limbs_round_to_multiple_of_power_of_2_to_odd_in_place(xs, pow)),
         Exact => {
             if limbs_divisible_by_power_of_2(xs, pow) {
                 Some(Equal)
@@ -396,6 +493,20 @@ impl RoundToMultipleOfPowerOf2<u64> for Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, k, \mathrm{NearestTiesAway}) = 2^k \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+    ///     2^k q & \text{if} \\quad q \in \Z, \\\\
+    ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     2^k \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -498,6 +609,20 @@ impl<'a> RoundToMultipleOfPowerOf2<u64> for &'a Natural {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, k, \mathrm{NearestTiesAway}) = 2^k \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+    ///     2^k q & \text{if} \\quad q \in \Z, \\\\
+    ///     2^k \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     2^k \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, k, \mathrm{Exact}) = 2^k q$, but panics if $q \notin \Z$.
     ///
     /// The following two expressions are equivalent:
@@ -83,6 +83,19 @@ macro_rules! impl_natural_shl_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -145,6 +158,19 @@ macro_rules! impl_natural_shl_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// g(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// g(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $g(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -128,6 +128,62 @@ pub_test! {limbs_shr_round_nearest(xs: &[Limb], bits: u64) -> (Vec<Limb>, Orderi
     }
 }}
 
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` right-shifted by a `Limb`, rounding to the `Natural` nearest to the actual
+// value of `self` divided by `2 ^ bits`. If the actual value is exactly between two integers, it is
+// rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(m) = O(m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is `max(1, xs.len() -
+// bits / Limb::WIDTH)`.
+pub_test! {limbs_shr_round_nearest_ties_away(xs: &[Limb], bits: u64) -> (Vec<Limb>, Ordering) {
+    if bits == 0 {
+        (xs.to_vec(), Equal)
+    } else if limbs_get_bit(xs, bits - 1) {
+        limbs_shr_round_up(xs, bits)
+    } else {
+        (
+            limbs_shr(xs, bits),
+            if slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1) {
+                Equal
+            } else {
+                Less
+            },
+        )
+    }
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
+// limbs of the `Natural` right-shifted by a `Limb`. If the shift is inexact, the result is rounded
+// to whichever of the two nearest integers is odd.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(m) = O(m)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `xs.len()`, and $m$ is `max(1, xs.len() -
+// bits / Limb::WIDTH)`.
+pub_test! {limbs_shr_round_to_odd(xs: &[Limb], bits: u64) -> (Vec<Limb>, Ordering) {
+    let mut out = limbs_shr(xs, bits);
+    if slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits) {
+        (out, Equal)
+    } else if !out.is_empty() && out[0].odd() {
+        (out, Less)
+    } else {
+        if out.is_empty() {
+            out.push(1);
+        } else {
+            out[0] |= 1;
+        }
+        (out, Greater)
+    }
+}}
+
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the `Natural` right-shifted by a `Limb`, if the shift is exact (doesn't remove any
 // `true` bits). If the shift is inexact, `None` is returned. The limbs should not all be zero.
@@ -171,6 +227,8 @@ pub_test! {
         )),
         Up | Ceiling => Some(limbs_shr_round_up(xs, bits)),
         Nearest => Some(limbs_shr_round_nearest(xs, bits)),
+        NearestTiesAway => Some(limbs_shr_round_nearest_ties_away(xs, bits)),
+        ToOdd => Some(limbs_shr_round_to_odd(xs, bits)),
         Exact => limbs_shr_exact(xs, bits).map(|ss| (ss, Equal)),
     }
 }}
@@ -273,6 +331,63 @@ pub_test! {limbs_vec_shr_round_nearest_in_place(xs: &mut Vec<Limb>, bits: u64) -
     }
 }}
 
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`, rounding to the `Natural`
+// nearest to the actual value of `self` divided by `2 ^ bits`. If the actual value is exactly
+// between two integers, it is rounded up.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory and $n$ is `xs.len()`.
+pub_test! {limbs_vec_shr_round_nearest_ties_away_in_place(
+    xs: &mut Vec<Limb>,
+    bits: u64
+) -> Ordering {
+    if bits == 0 {
+        Equal
+    } else if limbs_get_bit(xs, bits - 1) {
+        limbs_vec_shr_round_up_in_place(xs, bits)
+    } else {
+        let exact = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits - 1);
+        limbs_vec_shr_in_place(xs, bits);
+        if exact {
+            Equal
+        } else {
+            Less
+        }
+    }
+}}
+
+// Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`. If the shift is inexact, the
+// result is rounded to whichever of the two nearest integers is odd.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory and $n$ is `xs.len()`.
+pub_test! {limbs_vec_shr_round_to_odd_in_place(xs: &mut Vec<Limb>, bits: u64) -> Ordering {
+    let exact = slice_test_zero(xs) || limbs_divisible_by_power_of_2(xs, bits);
+    limbs_vec_shr_in_place(xs, bits);
+    if exact {
+        Equal
+    } else if !xs.is_empty() && xs[0].odd() {
+        Less
+    } else {
+        if xs.is_empty() {
+            xs.push(1);
+        } else {
+            xs[0] |= 1;
+        }
+        Greater
+    }
+}}
+
 // Interpreting a `Vec` of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
 // limbs of the `Natural` right-shifted by a `Limb` to the input `Vec`, if the shift is exact
 // (doesn't remove any `true` bits). Returns whether the shift was exact. The limbs should not all
@@ -327,6 +442,8 @@ pub_test! {limbs_vec_shr_round_in_place(
             (true, limbs_vec_shr_round_up_in_place(xs, bits))
         }
         Nearest => (true, limbs_vec_shr_round_nearest_in_place(xs, bits)),
+        NearestTiesAway => (true, limbs_vec_shr_round_nearest_ties_away_in_place(xs, bits)),
+        ToOdd => (true, limbs_vec_shr_round_to_odd_in_place(xs, bits)),
         Exact => (limbs_vec_shr_exact_in_place(xs, bits), Equal),
     }
 }}
@@ -414,6 +531,19 @@ macro_rules! impl_natural_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -472,6 +602,19 @@ macro_rules! impl_natural_shr_round_unsigned {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -595,6 +738,19 @@ macro_rules! impl_natural_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
@@ -656,6 +812,19 @@ macro_rules! impl_natural_shr_round_signed {
             /// \end{cases}
             /// $$
             ///
+            /// $$
+            /// f(x, k, \mathrm{NearestTiesAway}) = \left \lfloor q + \frac{1}{2} \right \rfloor.
+            /// $$
+            ///
+            /// $$
+            /// f(x, k, \mathrm{ToOdd}) = \begin{cases}
+            ///     q & \text{if} \\quad q \in \N, \\\\
+            ///     \lfloor q \rfloor & \text{if} \\quad q \notin \N
+            ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+            ///     \lceil q \rceil & \text{otherwise.}
+            /// \end{cases}
+            /// $$
+            ///
             /// $f(x, k, \mathrm{Exact}) = q$, but panics if $q \notin \N$.
             ///
             /// Then
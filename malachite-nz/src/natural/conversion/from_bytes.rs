@@ -0,0 +1,112 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::size_of;
+
+const BYTES_PER_LIMB: usize = size_of::<Limb>();
+
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// Converts bytes, in ascending order, to limbs, in ascending order. If the length of `xs` is not a
+// multiple of the limb size, the most-significant limb is padded with copies of `fill`.
+pub(crate) fn limbs_from_bytes_le(xs: &[u8], fill: u8) -> Vec<Limb> {
+    xs.chunks(BYTES_PER_LIMB)
+        .map(|chunk| {
+            let mut bytes = [fill; BYTES_PER_LIMB];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            Limb::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// Converts bytes, in descending order, to limbs, in ascending order. If the length of `xs` is not a
+// multiple of the limb size, the most-significant limb is padded with copies of `fill`.
+pub(crate) fn limbs_from_bytes_be(xs: &[u8], fill: u8) -> Vec<Limb> {
+    xs.rchunks(BYTES_PER_LIMB)
+        .map(|chunk| {
+            let mut bytes = [fill; BYTES_PER_LIMB];
+            bytes[BYTES_PER_LIMB - chunk.len()..].copy_from_slice(chunk);
+            Limb::from_be_bytes(bytes)
+        })
+        .collect()
+}
+
+impl Natural {
+    /// Converts a slice of bytes to a [`Natural`].
+    ///
+    /// The bytes are in little-endian order, so that less-significant bytes have lower indices in
+    /// the input slice. Trailing zero bytes are allowed, and an empty slice represents zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_le(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_le(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_le(&[0x34, 0x12, 0, 0]), 0x1234);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from_bytes_le(&[0x00, 0x10, 0xa5, 0xd4, 0xe8]),
+    ///     1000000000000u64
+    /// );
+    /// ```
+    pub fn from_bytes_le(xs: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(limbs_from_bytes_le(xs, 0))
+    }
+
+    /// Converts a slice of bytes to a [`Natural`].
+    ///
+    /// The bytes are in big-endian order, so that less-significant bytes have higher indices in the
+    /// input slice. Leading zero bytes are allowed, and an empty slice represents zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_be(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_be(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_be(&[0, 0, 0x12, 0x34]), 0x1234);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from_bytes_be(&[0xe8, 0xd4, 0xa5, 0x10, 0x00]),
+    ///     1000000000000u64
+    /// );
+    /// ```
+    pub fn from_bytes_be(xs: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(limbs_from_bytes_be(xs, 0))
+    }
+}
@@ -45,7 +45,7 @@ macro_rules! float_impls {
                 } else if value == 0.0 {
                     (Natural::ZERO, Equal)
                 } else if value < 0.0 {
-                    if rm == Down || rm == Ceiling || rm == Nearest {
+                    if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
                         (Natural::ZERO, Greater)
                     } else {
                         panic!("Result is negative and cannot be converted to a Natural");
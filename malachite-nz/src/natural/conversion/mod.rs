@@ -11,6 +11,8 @@ pub mod digits;
 /// An implementation of the [`From`] trait for converting a [`bool`] to a
 /// [`Natural`](crate::natural::Natural).
 pub mod from_bool;
+/// Functions for constructing a [`Natural`](crate::natural::Natural) from bytes.
+pub mod from_bytes;
 /// Functions for constructing a [`Natural`](crate::natural::Natural) from [`Limb`](crate#limbs)s.
 pub mod from_limbs;
 /// Implementations of traits for converting a primitive float to a
@@ -559,6 +561,9 @@ pub mod primitive_float_from_natural;
 /// assert_eq!(i64::convertible_from(&(Natural::ONE << 100)), false);
 /// ```
 pub mod primitive_int_from_natural;
+/// Implementations of [`From`] for converting between [`Natural`](crate::natural::Natural)s and
+/// [num-bigint](https://docs.rs/num-bigint)'s `BigUint`s.
+pub mod num_bigint;
 /// Implementations of traits for conversions between Python integers and
 /// [`Natural`](crate::natural::Natural)s using [pyo3](https://pyo3.rs/).
 pub mod pyo3;
@@ -568,5 +573,7 @@ pub mod serde;
 /// Implementations of traits for converting [`Natural`](crate::natural::Natural)s to and from
 /// [`String`]s.
 pub mod string;
+/// Functions for extracting the bytes of a [`Natural`](crate::natural::Natural).
+pub mod to_bytes;
 /// Functions for extracting [`Limb`](crate#limbs)s from a [`Natural`](crate::natural::Natural).
 pub mod to_limbs;
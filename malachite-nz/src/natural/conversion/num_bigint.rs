@@ -0,0 +1,81 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_base::num::arithmetic::traits::Pow;
+//! use malachite_nz::natural::Natural;
+//! use num::BigUint;
+//!
+//! let x = Natural::from(10u32).pow(30);
+//! let y = BigUint::from(&x);
+//! assert_eq!(y.to_string(), "1000000000000000000000000000000");
+//! assert_eq!(Natural::from(y), x);
+//! ```
+
+use crate::natural::Natural;
+#[cfg(not(feature = "32_bit_limbs"))]
+use malachite_base::num::conversion::traits::VecFromOtherTypeSlice;
+use num::BigUint;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&BigUint> for Natural {
+    #[cfg(feature = "32_bit_limbs")]
+    #[inline]
+    fn from(n: &BigUint) -> Natural {
+        Natural::from_owned_limbs_asc(n.to_u32_digits())
+    }
+
+    #[cfg(not(feature = "32_bit_limbs"))]
+    #[inline]
+    fn from(n: &BigUint) -> Natural {
+        Natural::from_owned_limbs_asc(n.to_u64_digits())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<BigUint> for Natural {
+    #[inline]
+    fn from(n: BigUint) -> Natural {
+        Natural::from(&n)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&Natural> for BigUint {
+    #[cfg(feature = "32_bit_limbs")]
+    #[inline]
+    fn from(n: &Natural) -> BigUint {
+        BigUint::new(n.to_limbs_asc())
+    }
+
+    #[cfg(not(feature = "32_bit_limbs"))]
+    #[inline]
+    fn from(n: &Natural) -> BigUint {
+        BigUint::new(u32::vec_from_other_type_slice(&n.to_limbs_asc()))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<Natural> for BigUint {
+    #[cfg(feature = "32_bit_limbs")]
+    #[inline]
+    fn from(n: Natural) -> BigUint {
+        BigUint::new(n.into_limbs_asc())
+    }
+
+    #[cfg(not(feature = "32_bit_limbs"))]
+    #[inline]
+    fn from(n: Natural) -> BigUint {
+        BigUint::from(&n)
+    }
+}
@@ -66,7 +66,9 @@ macro_rules! float_impls {
                             Exact => {
                                 panic!("Value cannot be represented exactly as an {}", $f::NAME)
                             }
-                            Floor | Down | Nearest => ($f::MAX_FINITE, Less),
+                            Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                ($f::MAX_FINITE, Less)
+                            }
                             _ => ($f::INFINITY, Greater),
                         }
                     }
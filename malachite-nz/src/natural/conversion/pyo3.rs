@@ -49,6 +49,7 @@
 //! assert n + 1 == value
 //! ```
 
+use crate::natural::conversion::to_bytes::limbs_to_bytes_le;
 use crate::natural::Natural;
 use alloc::vec::Vec;
 use malachite_base::num::basic::traits::Zero;
@@ -135,7 +136,7 @@ impl ToPyObject for Natural {
             return 0.to_object(py);
         }
 
-        let bytes = limbs_to_bytes(self.limbs(), self.limb_count());
+        let bytes = limbs_to_bytes_le(self.limbs(), self.limb_count());
 
         #[cfg(not(Py_LIMITED_API))]
         unsafe {
@@ -167,36 +168,6 @@ impl IntoPy<PyObject> for Natural {
     }
 }
 
-/// Convert 32-bit limbs (little endian) used by malachite to bytes (little endian)
-#[cfg(feature = "32_bit_limbs")]
-#[inline]
-fn limbs_to_bytes(limbs: impl Iterator<Item = u32>, limb_count: u64) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity((limb_count << 3) as usize);
-
-    for limb in limbs {
-        for byte in limb.to_le_bytes() {
-            bytes.push(byte);
-        }
-    }
-
-    bytes
-}
-
-/// Convert 64-bit limbs (little endian) used by malachite to bytes (little endian)
-#[cfg(not(feature = "32_bit_limbs"))]
-#[inline]
-fn limbs_to_bytes(limbs: impl Iterator<Item = u64>, limb_count: u64) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity((limb_count << 3) as usize);
-
-    for limb in limbs {
-        for byte in limb.to_le_bytes() {
-            bytes.push(byte);
-        }
-    }
-
-    bytes
-}
-
 /// Converts a Python integer to a vector of 32-bit limbs (little endian). Takes number of bytes to
 /// convert to. Multiple of 4. If `is_signed` is true, the integer is treated as signed, and two's
 /// complement is returned.
@@ -0,0 +1,137 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::size_of;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `limb_count`.
+//
+// Converts an iterator of limbs, in ascending order, to bytes, in ascending order. Every limb
+// contributes exactly `size_of::<Limb>()` bytes, so trailing zero bytes are not removed.
+pub(crate) fn limbs_to_bytes_le(xs: impl Iterator<Item = Limb>, limb_count: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(usize::exact_from(limb_count) * size_of::<Limb>());
+    for x in xs {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+impl Natural {
+    /// Returns the bytes of a [`Natural`] in little-endian order, so that less-significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes; in particular, zero is represented by an empty vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_le().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_le(), &[123]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_le(), &[0x34, 0x12]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_le(),
+    ///     &[0x00, 0x10, 0xa5, 0xd4, 0xe8]
+    /// );
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = limbs_to_bytes_le(self.limbs(), self.limb_count());
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`] in big-endian order, so that less-significant bytes have
+    /// higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes; in particular, zero is represented by an empty vector. To
+    /// get a fixed-width output, use [`to_bytes_be_padded`](Self::to_bytes_be_padded).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_be(), &[123]);
+    /// assert_eq!(Natural::from(0x1234u32).to_bytes_be(), &[0x12, 0x34]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(12).to_bytes_be(),
+    ///     &[0xe8, 0xd4, 0xa5, 0x10, 0x00]
+    /// );
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the bytes of a [`Natural`] in big-endian order, left-padded with zeros so that the
+    /// output has exactly `len` bytes.
+    ///
+    /// This is useful for fixed-width encodings, such as those used by cryptographic protocols.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `len`.
+    ///
+    /// # Panics
+    /// Panics if `self` does not fit in `len` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be_padded(0).is_empty());
+    /// assert_eq!(Natural::ZERO.to_bytes_be_padded(2), &[0, 0]);
+    /// assert_eq!(Natural::from(123u32).to_bytes_be_padded(4), &[0, 0, 0, 123]);
+    /// assert_eq!(
+    ///     Natural::from(0x1234u32).to_bytes_be_padded(2),
+    ///     &[0x12, 0x34]
+    /// );
+    /// ```
+    pub fn to_bytes_be_padded(&self, len: usize) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        assert!(bytes.len() <= len, "{self} does not fit in {len} bytes");
+        bytes.resize(len, 0);
+        bytes.reverse();
+        bytes
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec;
+use alloc::vec::Vec;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Divisors, Factor};
+
+impl Divisors for Natural {
+    /// Returns all the positive divisors of a [`Natural`], in ascending order.
+    ///
+    /// The divisors are generated from the prime factorization; see [`factor`](Factor::factor).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p, d) = O(L(p) n \log n \log\log n + dn \log d)$ (expected)
+    ///
+    /// $M(n, d) = O(dn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, $p$ is the
+    /// second-largest prime factor of `self`, $d$ is the number of divisors of `self`, and $L(p) =
+    /// e^{(\sqrt{2} + o(1))\sqrt{\log p \log\log p}}$.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisors).
+    fn divisors(&self) -> Vec<Natural> {
+        assert_ne!(*self, 0u32, "Cannot find the divisors of 0");
+        let mut divisors = vec![Natural::ONE];
+        for (p, e) in self.factor() {
+            let old_len = divisors.len();
+            let mut power = Natural::ONE;
+            for _ in 0..e {
+                power *= &p;
+                for i in 0..old_len {
+                    let d = &divisors[i] * &power;
+                    divisors.push(d);
+                }
+            }
+        }
+        divisors.sort_unstable();
+        divisors
+    }
+}
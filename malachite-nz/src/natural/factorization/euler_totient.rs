@@ -0,0 +1,46 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{EulerTotient, Factor};
+
+impl EulerTotient for Natural {
+    type Output = Natural;
+
+    /// Computes Euler's totient function of a [`Natural`]: the number of integers in $[1, n]$
+    /// that are coprime to $n$.
+    ///
+    /// $$
+    /// f(n) = \varphi(n) = n \prod_{p \mid n} \left ( 1 - \frac{1}{p} \right ),
+    /// $$
+    /// where the product is over the primes dividing $n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(e^{(\sqrt{2} + o(1))\sqrt{\log p \log\log p}} n \log n \log\log n)$ (expected)
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::euler_totient#euler_totient).
+    fn euler_totient(&self) -> Natural {
+        assert_ne!(*self, 0u32, "Cannot compute the Euler totient of 0");
+        let mut totient = Natural::ONE;
+        for (p, e) in self.factor() {
+            totient *= (&p).pow(e - 1) * (p - Natural::ONE);
+        }
+        totient
+    }
+}
@@ -0,0 +1,439 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    AbsDiff, CheckedRoot, DivExact, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModInverse, ModMul,
+    ModPow, ModSquare, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
+
+// Trial division is used to find all prime factors less than $2^{14}$.
+const TRIAL_DIVISION_LIMIT_LOG_2: u64 = 14;
+
+// The largest number of steps of Pollard's rho algorithm that are taken before moving on to the
+// other algorithms.
+const RHO_MAX_STEPS: u64 = 1 << 16;
+
+// The number of steps of Pollard's rho algorithm whose differences are multiplied together before a
+// GCD is taken.
+const RHO_BATCH_SIZE: u64 = 128;
+
+// The bound for Pollard's $p - 1$ algorithm: a prime factor $p$ is found if every prime power
+// dividing $p - 1$ is at most this large.
+const P_MINUS_1_BOUND: u64 = 100000;
+
+// The stage-1 bound used by the elliptic curve method starts at this value and doubles after every
+// `ECM_CURVES_PER_BOUND` curves.
+const ECM_MIN_BOUND: u64 = 2000;
+const ECM_CURVES_PER_BOUND: u64 = 16;
+
+// The number of modular multiplications needed for each bit of a scalar in the Montgomery ladder.
+const ECM_MULS_PER_BIT: u64 = 11;
+
+fn mod_limb(n: &Natural, d: Limb) -> Limb {
+    match n {
+        Natural(Small(x)) => x % d,
+        Natural(Large(xs)) => limbs_mod_limb(xs, d),
+    }
+}
+
+// Returns the largest power of the prime `p` that is less than or equal to `bound`.
+const fn largest_power_at_most(p: u64, bound: u64) -> u64 {
+    let mut q = p;
+    while q <= bound / p {
+        q *= p;
+    }
+    q
+}
+
+// Divides all the prime factors less than $2^{14}$ out of `n`, appending them to `factors`. The
+// primes are grouped into batches whose products fit in a `Limb`, so that each batch only needs one
+// pass over the limbs of `n`.
+fn trial_division(n: &mut Natural, factors: &mut Vec<(Natural, u64)>) {
+    let mut primes = Limb::primes_less_than(&(1 << TRIAL_DIVISION_LIMIT_LOG_2)).peekable();
+    let mut batch = Vec::new();
+    while *n != 1u32 && primes.peek().is_some() {
+        batch.clear();
+        let mut product: Limb = 1;
+        while let Some(q) = primes.peek().and_then(|&p| product.checked_mul(p)) {
+            batch.push(primes.next().unwrap());
+            product = q;
+        }
+        let r = mod_limb(n, product);
+        for &p in &batch {
+            if r % p != 0 {
+                continue;
+            }
+            let mut exponent = 0;
+            while mod_limb(n, p) == 0 {
+                n.div_exact_assign(Natural::from(p));
+                exponent += 1;
+            }
+            factors.push((Natural::from(p), exponent));
+        }
+    }
+}
+
+// If `n` is a perfect power, returns $(r, k)$ such that $r^k = n$ and $k$ is prime; otherwise,
+// returns `None`. `n` must have no prime factors less than $2^{14}$, so that only exponents up to a
+// fourteenth of its bit length need to be checked.
+fn perfect_power(n: &Natural) -> Option<(Natural, u64)> {
+    let max_exponent = n.significant_bits() / TRIAL_DIVISION_LIMIT_LOG_2;
+    u64::primes()
+        .take_while(|&k| k <= max_exponent)
+        .find_map(|k| n.checked_root(k).map(|r| (r, k)))
+}
+
+// Returns `Some(g)` if `g` is a nontrivial factor of `n`.
+fn nontrivial_factor(g: Natural, n: &Natural) -> Option<Natural> {
+    if g != 1u32 && g != *n {
+        Some(g)
+    } else {
+        None
+    }
+}
+
+// Looks for a factor of `n` with Brent's variant of Pollard's rho algorithm, using the polynomial
+// $x^2 + 1$. Each step costs two modular multiplications, which are subtracted from `effort`.
+fn pollard_rho(n: &Natural, effort: &mut u64) -> Option<Natural> {
+    let max_steps = min(RHO_MAX_STEPS, *effort >> 1);
+    let f = |x: &Natural| x.mod_square(n).mod_add(Natural::ONE, n);
+    let mut x;
+    let mut y = Natural::TWO;
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1;
+    let mut steps = 0;
+    while g == 1u32 {
+        if steps >= max_steps {
+            *effort = effort.saturating_sub(steps << 1);
+            return None;
+        }
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(&y);
+                q = q.mod_mul((&x).abs_diff(&y), n);
+            }
+            g = (&q).gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        steps += r << 1;
+        r <<= 1;
+        if g == *n {
+            // The batch overshot, so step through it again one GCD at a time.
+            loop {
+                ys = f(&ys);
+                g = (&x).abs_diff(&ys).gcd(n);
+                if g != 1u32 {
+                    break;
+                }
+            }
+        }
+    }
+    *effort = effort.saturating_sub(steps << 1);
+    nontrivial_factor(g, n)
+}
+
+// Looks for a factor of `n` with stage 1 of Pollard's $p - 1$ algorithm: $2^E - 1$ is computed
+// modulo `n`, where $E$ is the product of the largest powers of all primes up to
+// `P_MINUS_1_BOUND`. Each bit of $E$ costs about one modular multiplication.
+fn pollard_p_minus_1(n: &Natural, effort: &mut u64) -> Option<Natural> {
+    let mut a = Natural::TWO;
+    let mut exponent = 1u64;
+    for p in u64::primes_less_than_or_equal_to(&P_MINUS_1_BOUND) {
+        let q = largest_power_at_most(p, P_MINUS_1_BOUND);
+        if let Some(e) = exponent.checked_mul(q) {
+            exponent = e;
+            continue;
+        }
+        if *effort == 0 {
+            return None;
+        }
+        a = a.mod_pow(Natural::from(exponent), n);
+        *effort = effort.saturating_sub(exponent.significant_bits());
+        exponent = q;
+    }
+    a = a.mod_pow(Natural::from(exponent), n);
+    *effort = effort.saturating_sub(exponent.significant_bits());
+    // `n` is odd, so $2^E$ is not 0 mod `n`.
+    nontrivial_factor((a - Natural::ONE).gcd(n), n)
+}
+
+// A point on the Montgomery curve $By^2 = x^3 + Ax^2 + x$, in projective coordinates $(X : Z)$. The
+// $y$-coordinate isn't needed.
+#[derive(Clone)]
+struct MontgomeryPoint {
+    x: Natural,
+    z: Natural,
+}
+
+// Computes $2P$, given $(A + 2)/4$.
+fn ecm_double(p: &MontgomeryPoint, a24: &Natural, n: &Natural) -> MontgomeryPoint {
+    let s = (&p.x).mod_add(&p.z, n).mod_square(n);
+    let d = (&p.x).mod_sub(&p.z, n).mod_square(n);
+    let t = (&s).mod_sub(&d, n);
+    let x = s.mod_mul(&d, n);
+    let w = d.mod_add(a24.mod_mul(&t, n), n);
+    MontgomeryPoint {
+        x,
+        z: t.mod_mul(w, n),
+    }
+}
+
+// Computes $P + Q$, given $P - Q$.
+fn ecm_add(
+    p: &MontgomeryPoint,
+    q: &MontgomeryPoint,
+    diff: &MontgomeryPoint,
+    n: &Natural,
+) -> MontgomeryPoint {
+    let u = (&p.x).mod_sub(&p.z, n).mod_mul((&q.x).mod_add(&q.z, n), n);
+    let v = (&p.x).mod_add(&p.z, n).mod_mul((&q.x).mod_sub(&q.z, n), n);
+    MontgomeryPoint {
+        x: (&diff.z).mod_mul((&u).mod_add(&v, n).mod_square(n), n),
+        z: (&diff.x).mod_mul(u.mod_sub(v, n).mod_square(n), n),
+    }
+}
+
+// Computes $kP$ with the Montgomery ladder. `k` must be at least 2.
+fn ecm_multiply(p: &MontgomeryPoint, k: u64, a24: &Natural, n: &Natural) -> MontgomeryPoint {
+    let mut r0 = p.clone();
+    let mut r1 = ecm_double(p, a24, n);
+    for bit in k.bits().rev().skip(1) {
+        if bit {
+            r0 = ecm_add(&r1, &r0, p, n);
+            r1 = ecm_double(&r1, a24, n);
+        } else {
+            r1 = ecm_add(&r0, &r1, p, n);
+            r0 = ecm_double(&r0, a24, n);
+        }
+    }
+    r0
+}
+
+// Looks for a factor of `n` with stage 1 of the elliptic curve method, on the curve given by
+// Suyama's parametrization with parameter `sigma`. A prime factor $p$ is found if the order of the
+// curve modulo $p$ has no prime power factors greater than `bound`.
+fn ecm_with_curve(n: &Natural, sigma: u64, bound: u64, effort: &mut u64) -> Option<Natural> {
+    // `n` is at least $2^{64}$, so $u$ and $v$ are already reduced.
+    let u = Natural::from(sigma * sigma - 5);
+    let v = Natural::from(sigma << 2);
+    let u_cubed = (&u).mod_square(n).mod_mul(&u, n);
+    let v_minus_u = (&v).mod_sub(&u, n);
+    // $(A + 2)/4 = (v - u)^3(3u + v)/(16u^3 v)$
+    let numerator = (&v_minus_u)
+        .mod_square(n)
+        .mod_mul(v_minus_u, n)
+        .mod_mul(Natural::from(3u32).mod_mul(&u, n).mod_add(&v, n), n);
+    let denominator = Natural::from(16u32).mod_mul(&u_cubed, n).mod_mul(&v, n);
+    let g = (&denominator).gcd(n);
+    if g != 1u32 {
+        return nontrivial_factor(g, n);
+    }
+    let a24 = numerator.mod_mul(denominator.mod_inverse(n).unwrap(), n);
+    let mut point = MontgomeryPoint {
+        x: u_cubed,
+        z: (&v).mod_square(n).mod_mul(v, n),
+    };
+    for p in u64::primes_less_than_or_equal_to(&bound) {
+        let q = largest_power_at_most(p, bound);
+        point = ecm_multiply(&point, q, &a24, n);
+        *effort = effort.saturating_sub(ECM_MULS_PER_BIT * q.significant_bits());
+        if *effort == 0 {
+            break;
+        }
+    }
+    nontrivial_factor(point.z.gcd(n), n)
+}
+
+// Looks for a factor of `n` with the elliptic curve method, trying curves with increasing bounds
+// until one is found or `effort` runs out.
+fn ecm(n: &Natural, effort: &mut u64) -> Option<Natural> {
+    let mut curve = 0;
+    while *effort != 0 {
+        let bound = ECM_MIN_BOUND << min(curve / ECM_CURVES_PER_BOUND, 20);
+        // Suyama's parametrization requires $\sigma \notin \\{0, \pm 1, \pm 3, \pm 5, \pm 5/3\\}$.
+        if let Some(d) = ecm_with_curve(n, curve + 6, bound, effort) {
+            return Some(d);
+        }
+        curve += 1;
+    }
+    None
+}
+
+// Looks for a nontrivial factor of `n`, which must be odd, composite, not a perfect power, and
+// greater than or equal to $2^{64}$.
+fn find_factor(n: &Natural, effort: &mut u64) -> Option<Natural> {
+    pollard_rho(n, effort)
+        .or_else(|| pollard_p_minus_1(n, effort))
+        .or_else(|| ecm(n, effort))
+}
+
+// Sorts a list of factor-exponent pairs and combines the pairs with equal factors.
+fn group_factors(mut factors: Vec<(Natural, u64)>) -> Vec<(Natural, u64)> {
+    factors.sort_unstable();
+    let mut grouped: Vec<(Natural, u64)> = Vec::new();
+    for (p, e) in factors {
+        match grouped.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => grouped.push((p, e)),
+        }
+    }
+    grouped
+}
+
+impl Natural {
+    /// Finds the prime factorization of a [`Natural`], giving up on factors that resist a bounded
+    /// amount of work.
+    ///
+    /// The result is a pair of lists of pairs $(m, e)$, where each $e$ is a positive exponent,
+    /// such that `self` equals the product of all $m^e$ in both lists. Each $m$ in the first list
+    /// is prime, and each $m$ in the second list is composite. Both lists are sorted by $m$, in
+    /// ascending order. If the second list is empty, the first list is the complete prime
+    /// factorization, as returned by [`factor`](Factor::factor).
+    ///
+    /// Prime factors less than $2^{14}$ are always found by trial division. Larger ones are found
+    /// by Pollard's rho algorithm, Pollard's $p - 1$ algorithm, and the elliptic curve method,
+    /// which are tried in that order. `effort` is an approximate bound on the total number of
+    /// modular multiplications that these algorithms may perform; once it is used up, any factors
+    /// that are still composite are returned in the second list. With an `effort` of 0, only
+    /// trial division, perfect-power detection, and primality tests are used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, e) = O(n^2 \log n \log\log n + e n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $e$ is
+    /// `effort`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 2^64 + 1 = 274177 * 67280421310721
+    /// let n = Natural::power_of_2(64) + Natural::ONE;
+    /// assert_eq!(
+    ///     n.factor_with_effort(0).to_debug_string(),
+    ///     "([], [(18446744073709551617, 1)])"
+    /// );
+    /// assert_eq!(
+    ///     n.factor_with_effort(100000).to_debug_string(),
+    ///     "([(274177, 1), (67280421310721, 1)], [])"
+    /// );
+    ///
+    /// let n = Natural::from(1000u32) * &n;
+    /// assert_eq!(
+    ///     n.factor_with_effort(0).to_debug_string(),
+    ///     "([(2, 3), (5, 3)], [(18446744073709551617, 1)])"
+    /// );
+    /// ```
+    pub fn factor_with_effort(
+        &self,
+        mut effort: u64,
+    ) -> (Vec<(Natural, u64)>, Vec<(Natural, u64)>) {
+        assert_ne!(*self, 0u32, "Cannot factor 0");
+        let mut n = self.clone();
+        let mut primes = Vec::new();
+        trial_division(&mut n, &mut primes);
+        let mut composites = Vec::new();
+        let mut stack = if n == 1u32 { vec![] } else { vec![(n, 1)] };
+        while let Some((m, e)) = stack.pop() {
+            if let Ok(small) = u64::try_from(&m) {
+                primes.extend(
+                    small
+                        .factor()
+                        .into_iter()
+                        .map(|(p, f)| (Natural::from(p), e * f)),
+                );
+            } else if m.is_prime() {
+                primes.push((m, e));
+            } else if let Some((r, k)) = perfect_power(&m) {
+                stack.push((r, e * k));
+            } else if let Some(d) = find_factor(&m, &mut effort) {
+                stack.push(((&m).div_exact(&d), e));
+                stack.push((d, e));
+            } else {
+                composites.push((m, e));
+            }
+        }
+        // An unsplit composite may still be divisible by primes that were found elsewhere.
+        let mut remaining = Vec::new();
+        for (mut c, e) in composites {
+            for i in 0..primes.len() {
+                let p = primes[i].0.clone();
+                while (&c).divisible_by(&p) {
+                    c.div_exact_assign(&p);
+                    primes.push((p.clone(), e));
+                }
+            }
+            if c == 1u32 {
+                continue;
+            }
+            if c.is_prime() {
+                primes.push((c, e));
+            } else {
+                remaining.push((c, e));
+            }
+        }
+        (group_factors(primes), group_factors(remaining))
+    }
+}
+
+impl Factor for Natural {
+    /// Returns the prime factorization of a [`Natural`].
+    ///
+    /// The result is a list of pairs $(p, e)$, where each $p$ is a prime and each $e$ is a positive
+    /// exponent, such that the [`Natural`] equals the product of all $p^e$. The list is sorted by
+    /// $p$, in ascending order. The factorization of 1 is empty.
+    ///
+    /// This is equivalent to [`factor_with_effort`](Natural::factor_with_effort) with unlimited
+    /// effort. Its running time depends mostly on the size of the second-largest prime factor,
+    /// which the elliptic curve method finds in subexponential time.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(e^{(\sqrt{2} + o(1))\sqrt{\log p \log\log p}} n \log n \log\log n)$ (expected)
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        let (primes, composites) = self.factor_with_effort(u64::MAX);
+        assert!(composites.is_empty());
+        primes
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::Large;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, JacobiSymbol, ModAdd, ModMul, ModPow, ModSquare, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primality, Primes};
+use malachite_base::num::logic::traits::BitIterable;
+
+// The odd primes less than 30.
+const SMALL_ODD_PRIMES: [Limb; 9] = [3, 5, 7, 11, 13, 17, 19, 23, 29];
+
+// The product of `SMALL_ODD_PRIMES`, which fits in a `u32`.
+const SMALL_ODD_PRIMES_PRODUCT: Limb = 3234846615;
+
+// Returns whether `n` is a strong probable prime to base `a`. `n` must be odd, $n - 1 = 2^s d$ with
+// $d$ odd, and $1 < a < n - 1$.
+fn is_strong_probable_prime(
+    n: &Natural,
+    n_minus_1: &Natural,
+    d: &Natural,
+    s: u64,
+    a: Natural,
+) -> bool {
+    let mut x = a.mod_pow(d, n);
+    if x == 1u32 || x == *n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.mod_square(n);
+        if x == *n_minus_1 {
+            return true;
+        } else if x == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Computes $x/2 \bmod n$, where $n$ is odd.
+fn half_mod(mut x: Natural, n: &Natural) -> Natural {
+    if x.odd() {
+        x += n;
+    }
+    x >> 1
+}
+
+// Returns whether `n` is a strong Lucas probable prime, with parameters chosen by Selfridge's
+// method: $D$ is the first of 5, -7, 9, -11, ... for which the Jacobi symbol $(D/n)$ is -1, $P =
+// 1$, and $Q = (1 - D)/4$. `n` must be odd, not a perfect square, and at least $2^{64}$, so that it
+// is much larger than any $|D|$ that is tried.
+fn is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    let mut d_abs = 5u64;
+    let (d, q) = loop {
+        let q_abs = Natural::from((d_abs >> 2) + u64::from(d_abs & 3 == 3));
+        // $D > 0$ exactly when $|D| \equiv 1 \pmod 4$
+        let (d, q) = if d_abs & 3 == 1 {
+            (Natural::from(d_abs), n - q_abs)
+        } else {
+            (n - Natural::from(d_abs), q_abs)
+        };
+        match (&d).jacobi_symbol(n) {
+            -1 => break (d, q),
+            0 => return false,
+            _ => d_abs += 2,
+        }
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    // Compute $U_k$, $V_k$, and $Q^k$ for the odd part $k$ of $n + 1$, using $U_{2k} = U_k V_k$,
+    // $V_{2k} = V_k^2 - 2Q^k$, $U_{k+1} = (U_k + V_k)/2$, and $V_{k+1} = (DU_k + V_k)/2$.
+    for bit in (&(n_plus_1 >> s)).bits().rev().skip(1) {
+        u = u.mod_mul(&v, n);
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        q_k = q_k.mod_square(n);
+        if bit {
+            let du = (&d).mod_mul(&u, n);
+            u = half_mod(u.mod_add(&v, n), n);
+            v = half_mod(du.mod_add(v, n), n);
+            q_k = q_k.mod_mul(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k = q_k.mod_square(n);
+    }
+    false
+}
+
+impl IsPrime for Natural {
+    /// Determines whether a [`Natural`] is prime.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the answer is always correct; see the
+    /// implementation of [`IsPrime`] for [`u64`]. Otherwise, after trial division by small primes,
+    /// the [`Natural`] undergoes the Baillie-PSW test: a Miller-Rabin test to base 2 followed by a
+    /// strong Lucas test. No composite number is known to pass this test.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        self.is_probable_prime(0) != Primality::NotPrime
+    }
+
+    /// Determines whether a [`Natural`] is prime, returning a [`Primality`].
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is [`Prime`](Primality::Prime) or
+    /// [`NotPrime`](Primality::NotPrime), and `rounds` is ignored. Otherwise, the [`Natural`]
+    /// undergoes the Baillie-PSW test, as in [`is_prime`](IsPrime::is_prime), followed by `rounds`
+    /// more Miller-Rabin tests, using the odd primes 3, 5, 7, 11, ... as bases. The result is
+    /// [`NotPrime`](Primality::NotPrime) if any test fails, and
+    /// [`ProbablyPrime`](Primality::ProbablyPrime) otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, r) = O(n^2 (r + 1) \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $r$ is
+    /// `rounds`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_probable_prime).
+    fn is_probable_prime(&self, rounds: u64) -> Primality {
+        if let Ok(n) = u64::try_from(self) {
+            return n.is_probable_prime(rounds);
+        }
+        // `self` is at least $2^{64}$, so it has more than one limb and is larger than any of the
+        // small primes.
+        if self.even() {
+            return Primality::NotPrime;
+        }
+        let r = match self {
+            Natural(Large(xs)) => limbs_mod_limb(xs, SMALL_ODD_PRIMES_PRODUCT),
+            _ => unreachable!(),
+        };
+        if SMALL_ODD_PRIMES.iter().any(|&p| r % p == 0) || self.checked_sqrt().is_some() {
+            return Primality::NotPrime;
+        }
+        let n_minus_1 = self - Natural::ONE;
+        let s = n_minus_1.trailing_zeros().unwrap();
+        let d = &n_minus_1 >> s;
+        if is_strong_probable_prime(self, &n_minus_1, &d, s, Natural::TWO)
+            && is_strong_lucas_probable_prime(self)
+            && u64::primes()
+                .skip(1)
+                .take(usize::exact_from(rounds))
+                .all(|a| is_strong_probable_prime(self, &n_minus_1, &d, s, Natural::from(a)))
+        {
+            Primality::ProbablyPrime
+        } else {
+            Primality::NotPrime
+        }
+    }
+}
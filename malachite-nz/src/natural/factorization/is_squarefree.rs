@@ -0,0 +1,29 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::{Factor, IsSquarefree};
+
+impl IsSquarefree for Natural {
+    /// Determines whether a [`Natural`] is squarefree; that is, whether it is not divisible by the
+    /// square of any prime. 0 is not squarefree, and 1 is.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(e^{(\sqrt{2} + o(1))\sqrt{\log p \log\log p}} n \log n \log\log n)$ (expected)
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`.
+    ///
+    /// # Examples
+    /// See [here](super::is_squarefree#is_squarefree).
+    fn is_squarefree(&self) -> bool {
+        *self != 0u32 && self.factor().into_iter().all(|(_, e)| e == 1)
+    }
+}
@@ -6,8 +6,191 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// An implementation of [`Divisors`](malachite_base::num::factorization::traits::Divisors), a
+/// trait for finding all the divisors of a number.
+///
+/// # divisors
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Divisors;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ONE.divisors().to_debug_string(), "[1]");
+/// assert_eq!(
+///     Natural::from(60u32).divisors().to_debug_string(),
+///     "[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE)
+///         .divisors()
+///         .to_debug_string(),
+///     "[1, 274177, 67280421310721, 18446744073709551617]"
+/// );
+/// ```
+pub mod divisors;
+/// An implementation of [`EulerTotient`](malachite_base::num::factorization::traits::EulerTotient),
+/// a trait for computing Euler's totient function.
+///
+/// # euler_totient
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::{One, Two};
+/// use malachite_base::num::factorization::traits::EulerTotient;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ONE.euler_totient(), 1);
+/// assert_eq!(Natural::from(360u32).euler_totient(), 96);
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE).euler_totient(),
+///     18446676793287966720u64
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(127) - Natural::ONE).euler_totient(),
+///     Natural::power_of_2(127) - Natural::TWO
+/// );
+/// ```
+pub mod euler_totient;
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait for
+/// finding the prime factorization of a number, and of
+/// [`Natural::factor_with_effort`](crate::natural::Natural::factor_with_effort).
+///
+/// # factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::ONE.factor().to_debug_string(), "[]");
+/// assert_eq!(
+///     Natural::from(360u32).factor().to_debug_string(),
+///     "[(2, 3), (3, 2), (5, 1)]"
+/// );
+/// assert_eq!(
+///     Natural::from_str("1000000000000000000000000000000")
+///         .unwrap()
+///         .factor()
+///         .to_debug_string(),
+///     "[(2, 30), (5, 30)]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(67) - Natural::ONE)
+///         .factor()
+///         .to_debug_string(),
+///     "[(193707721, 1), (761838257287, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(128) + Natural::ONE)
+///         .factor()
+///         .to_debug_string(),
+///     "[(59649589127497217, 1), (5704689200685129054721, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(91u32).is_prime(), false);
+/// assert_eq!(Natural::from(97u32).is_prime(), true);
+/// assert_eq!(
+///     Natural::from_str("318665857834031151167461")
+///         .unwrap()
+///         .is_prime(),
+///     false
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(127) - Natural::ONE).is_prime(),
+///     true
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(521) - Natural::ONE).is_prime(),
+///     true
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(523) - Natural::ONE).is_prime(),
+///     false
+/// );
+/// ```
+///
+/// # is_probable_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::{IsPrime, Primality};
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::from(91u32).is_probable_prime(10),
+///     Primality::NotPrime
+/// );
+/// assert_eq!(
+///     Natural::from(97u32).is_probable_prime(10),
+///     Primality::Prime
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(127) - Natural::ONE).is_probable_prime(10),
+///     Primality::ProbablyPrime
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(128) + Natural::ONE).is_probable_prime(10),
+///     Primality::NotPrime
+/// );
+/// ```
+pub mod is_prime;
+/// An implementation of [`IsSquarefree`](malachite_base::num::factorization::traits::IsSquarefree),
+/// a trait for determining whether a number is squarefree.
+///
+/// # is_squarefree
+/// ```
+/// use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+/// use malachite_base::num::basic::traits::{One, Zero};
+/// use malachite_base::num::factorization::traits::IsSquarefree;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ZERO.is_squarefree(), false);
+/// assert_eq!(Natural::ONE.is_squarefree(), true);
+/// assert_eq!(Natural::from(360u32).is_squarefree(), false);
+/// assert_eq!((Natural::power_of_2(64) + Natural::ONE).is_squarefree(), true);
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE)
+///         .square()
+///         .is_squarefree(),
+///     false
+/// );
+/// ```
+pub mod is_squarefree;
+/// An implementation of [`Moebius`](malachite_base::num::factorization::traits::Moebius), a trait
+/// for computing the Möbius function.
+///
+/// # moebius
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Moebius;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ONE.moebius(), 1);
+/// assert_eq!(Natural::from(30u32).moebius(), -1);
+/// assert_eq!(Natural::from(360u32).moebius(), 0);
+/// assert_eq!((Natural::power_of_2(64) + Natural::ONE).moebius(), 1);
+/// assert_eq!((Natural::power_of_2(127) - Natural::ONE).moebius(), -1);
+/// ```
+pub mod moebius;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait for
-/// generating prime numbers.
+/// generating prime numbers, and functions for finding the primes in an interval and the primes
+/// adjacent to a [`Natural`](crate::natural::Natural).
 ///
 /// # primes_less_than
 /// ```
@@ -83,4 +266,79 @@
 ///     181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251]"
 /// );
 /// ```
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::primes_in_range(&Natural::from(90u32), &Natural::from(130u32))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[97, 101, 103, 107, 109, 113, 127]"
+/// );
+/// let n = Natural::power_of_2(64);
+/// assert_eq!(
+///     Natural::primes_in_range(&n, &(&n + Natural::from(100u32)))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[18446744073709551629, 18446744073709551653, 18446744073709551667, \
+///     18446744073709551697, 18446744073709551709]"
+/// );
+/// ```
+///
+/// # primes_greater_than_or_equal_to
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::from(100u32))
+///         .take(5)
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[101, 103, 107, 109, 113]"
+/// );
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::power_of_2(64))
+///         .take(3)
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[18446744073709551629, 18446744073709551653, 18446744073709551667]"
+/// );
+/// ```
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ZERO.next_prime(), 2);
+/// assert_eq!(Natural::from(100u32).next_prime(), 101);
+/// assert_eq!(Natural::from(113u32).next_prime(), 127);
+/// assert_eq!(
+///     Natural::power_of_2(512).next_prime(),
+///     Natural::power_of_2(512) + Natural::from(75u32)
+/// );
+/// ```
+///
+/// # prev_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(3u32).prev_prime(), 2);
+/// assert_eq!(Natural::from(100u32).prev_prime(), 97);
+/// assert_eq!(Natural::from(127u32).prev_prime(), 113);
+/// assert_eq!(
+///     Natural::power_of_2(512).prev_prime(),
+///     Natural::power_of_2(512) - Natural::from(569u32)
+/// );
+/// ```
 pub mod primes;
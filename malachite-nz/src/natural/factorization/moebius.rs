@@ -0,0 +1,46 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::{Factor, Moebius};
+
+impl Moebius for Natural {
+    /// Computes the Möbius function of a [`Natural`].
+    ///
+    /// $$
+    /// f(n) = \mu(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad p^2 \mid n \text{ for some prime } p, \\\\
+    ///     (-1)^k & \text{otherwise, where } n \text{ has } k \text{ prime factors.}
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, p) = O(e^{(\sqrt{2} + o(1))\sqrt{\log p \log\log p}} n \log n \log\log n)$ (expected)
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::moebius#moebius).
+    fn moebius(&self) -> i8 {
+        assert_ne!(*self, 0u32, "Cannot compute the Möbius function of 0");
+        let mut moebius = 1;
+        for (_, e) in self.factor() {
+            if e > 1 {
+                return 0;
+            }
+            moebius = -moebius;
+        }
+        moebius
+    }
+}
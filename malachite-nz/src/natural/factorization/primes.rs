@@ -7,9 +7,129 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::natural::Natural;
-use malachite_base::num::conversion::traits::SaturatingFrom;
+use crate::platform::Limb;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, SaturatingSub};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom};
 use malachite_base::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+
+// The segmented sieve crosses out multiples of the primes less than this limit. Numbers that
+// survive the sieve and are greater than the square of the limit are tested with `is_prime`.
+const SIEVE_PRIME_LIMIT: Limb = 1 << 16;
+
+// The number of consecutive integers that are sieved together.
+const SEGMENT_LENGTH: u64 = 1 << 12;
+
+#[inline]
+fn sieve_primes() -> Vec<Limb> {
+    Limb::primes_less_than(&SIEVE_PRIME_LIMIT).collect()
+}
+
+// Sieves the `len` integers starting at `start`, returning the offsets from `start` of the integers
+// that are greater than 1 and not divisible by any smaller prime in `sieve_primes`, in ascending
+// order. Also returns whether all of these integers are known to be prime, which is the case when
+// `sieve_primes` includes every prime up to the square root of the largest integer.
+#[allow(clippy::useless_conversion)]
+fn sieve_segment(start: &Natural, len: u64, sieve_primes: &[Limb]) -> (Vec<u64>, bool) {
+    let sqrt = (start + Natural::from(len - 1)).floor_sqrt();
+    let mut composite = vec![false; usize::exact_from(len)];
+    for &p in sieve_primes {
+        if sqrt < p {
+            break;
+        }
+        // Cross out the multiples of `p`, starting from the smallest one that is at least `start`
+        // and at least $p^2$.
+        let p_squared = p * p;
+        let mut i = if *start <= p_squared {
+            u64::from(p_squared - Limb::exact_from(start))
+        } else {
+            match start.rem_limb_ref(p) {
+                0 => 0,
+                r => u64::from(p - r),
+            }
+        };
+        while i < len {
+            composite[usize::exact_from(i)] = true;
+            i += u64::from(p);
+        }
+    }
+    // 0 and 1 are not prime.
+    if let Ok(start) = u64::try_from(start) {
+        for i in start..min(2, start + len) {
+            composite[usize::exact_from(i - start)] = true;
+        }
+    }
+    let offsets = composite
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, c)| if c { None } else { Some(u64::exact_from(i)) })
+        .collect();
+    (offsets, sqrt < SIEVE_PRIME_LIMIT)
+}
+
+/// An iterator that generates the prime [`Natural`]s in an interval, in ascending order.
+///
+/// This `struct` is created by [`Natural::primes_in_range`] and
+/// [`Natural::primes_greater_than_or_equal_to`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalPrimesInRangeIterator {
+    sieve_primes: Vec<Limb>,
+    segment_start: Natural,
+    // The offsets from `segment_start` of the sieve survivors that haven't been tested yet, in
+    // descending order.
+    candidates: Vec<u64>,
+    // Whether the sieve survivors in the current segment are known to be prime.
+    fully_sieved: bool,
+    next_segment_start: Natural,
+    end: Option<Natural>,
+}
+
+impl NaturalPrimesInRangeIterator {
+    fn new(start: Natural, end: Option<Natural>) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator {
+            sieve_primes: sieve_primes(),
+            segment_start: start.clone(),
+            candidates: Vec::new(),
+            fully_sieved: true,
+            next_segment_start: start,
+            end,
+        }
+    }
+}
+
+impl Iterator for NaturalPrimesInRangeIterator {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            while let Some(offset) = self.candidates.pop() {
+                let n = &self.segment_start + Natural::from(offset);
+                if self.fully_sieved || n.is_prime() {
+                    return Some(n);
+                }
+            }
+            let len = match &self.end {
+                Some(end) if self.next_segment_start >= *end => return None,
+                Some(end) => min(
+                    SEGMENT_LENGTH,
+                    u64::saturating_from(&(end - &self.next_segment_start)),
+                ),
+                None => SEGMENT_LENGTH,
+            };
+            let (candidates, fully_sieved) =
+                sieve_segment(&self.next_segment_start, len, &self.sieve_primes);
+            self.candidates = candidates;
+            self.candidates.reverse();
+            self.fully_sieved = fully_sieved;
+            self.segment_start = self.next_segment_start.clone();
+            self.next_segment_start += Natural::from(len);
+        }
+    }
+}
 
 /// An iterator over that generates all prime [`Natural`]s less than a given value.
 ///
@@ -42,6 +162,102 @@ impl Iterator for NaturalPrimesIterator {
     }
 }
 
+impl Natural {
+    /// Returns an iterator that generates all primes greater than or equal to $a$ and less than
+    /// $b$, in ascending order.
+    ///
+    /// Rather than counting up from 2, the iterator uses a segmented sieve: it crosses out the
+    /// multiples of small primes in successive blocks of integers starting at $a$. If the integers
+    /// are too large for the sieve to determine whether they are prime, the ones that survive it
+    /// are tested with [`is_prime`](IsPrime::is_prime).
+    ///
+    /// # Expected complexity per iteration
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::primes#primes_in_range).
+    #[inline]
+    pub fn primes_in_range(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator::new(a.clone(), Some(b.clone()))
+    }
+
+    /// Returns an iterator that generates all primes greater than or equal to $a$, in ascending
+    /// order.
+    ///
+    /// The iterator works like the one produced by [`Natural::primes_in_range`], but it never
+    /// stops.
+    ///
+    /// # Expected complexity per iteration
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the generated prime.
+    ///
+    /// # Examples
+    /// See [here](super::primes#primes_greater_than_or_equal_to).
+    #[inline]
+    pub fn primes_greater_than_or_equal_to(a: &Natural) -> NaturalPrimesInRangeIterator {
+        NaturalPrimesInRangeIterator::new(a.clone(), None)
+    }
+
+    /// Returns the smallest prime greater than a [`Natural`].
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::primes#next_prime).
+    pub fn next_prime(&self) -> Natural {
+        Natural::primes_greater_than_or_equal_to(&(self + Natural::ONE))
+            .next()
+            .unwrap()
+    }
+
+    /// Returns the largest prime less than a [`Natural`].
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is less than or equal to 2.
+    ///
+    /// # Examples
+    /// See [here](super::primes#prev_prime).
+    pub fn prev_prime(&self) -> Natural {
+        assert!(*self > 2u32, "There is no prime less than {self}");
+        let sieve_primes = sieve_primes();
+        let mut end = self.clone();
+        // Sieve the segments below `self` in descending order, until a prime is found. The loop
+        // terminates because 2 is prime.
+        loop {
+            let start = (&end).saturating_sub(Natural::from(SEGMENT_LENGTH));
+            let len = u64::exact_from(&(&end - &start));
+            let (candidates, fully_sieved) = sieve_segment(&start, len, &sieve_primes);
+            for offset in candidates.into_iter().rev() {
+                let n = &start + Natural::from(offset);
+                if fully_sieved || n.is_prime() {
+                    return n;
+                }
+            }
+            end = start;
+        }
+    }
+}
+
 impl Primes for Natural {
     type I = NaturalPrimesIterator;
     type LI = NaturalPrimesLessThanIterator;
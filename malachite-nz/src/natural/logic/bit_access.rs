@@ -64,7 +64,7 @@ pub_crate_test! {limbs_slice_set_bit(xs: &mut [Limb], index: u64) {
 // where $T$ is time, $M$ is additional memory, and $n$ is `index`.
 //
 // This is equivalent to `mpz_setbit` from `mpz/setbit.c`, GMP 6.2.1, where `d` is non-negative.
-pub_test! {limbs_vec_set_bit(xs: &mut Vec<Limb>, index: u64) {
+pub_crate_test! {limbs_vec_set_bit(xs: &mut Vec<Limb>, index: u64) {
     let small_index = usize::exact_from(index >> Limb::LOG_WIDTH);
     if small_index >= xs.len() {
         xs.resize(small_index + 1, 0);
@@ -143,10 +143,12 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Natural`]s without repetition.
 pub mod exhaustive;
-/// Traits for generating primes, primality testing, and factorization (TODO!)
+/// Traits for generating primes, primality testing, and factorization.
 pub mod factorization;
 /// Traits for logic and bit manipulation.
 pub mod logic;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits) for [`Natural`]s.
+pub mod num_traits;
 #[cfg(feature = "random")]
 /// Iterators that generate [`Natural`]s randomly.
 pub mod random;
@@ -0,0 +1,225 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! This allows [`Natural`] to be used with generic code that is bounded on
+//! [num-traits](https://docs.rs/num-traits), such as `nalgebra` or `ndarray`.
+//!
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_nz::natural::Natural;
+//! use num::traits::{Num, Unsigned};
+//!
+//! fn sum_of_squares<T: Clone + Unsigned>(xs: &[T]) -> T {
+//!     xs.iter()
+//!         .cloned()
+//!         .fold(T::zero(), |acc, x| acc + x.clone() * x)
+//! }
+//!
+//! let xs = [Natural::from(3u32), Natural::from(4u32)];
+//! assert_eq!(sum_of_squares(&xs), 25);
+//! assert_eq!(Natural::from_str_radix("ff", 16).unwrap(), 255);
+//! ```
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Pow as MalachitePow, PowerOf2};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{One as MalachiteOne, Zero as MalachiteZero};
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::*;
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, ToPrimitive, Unsigned, Zero};
+
+// Rounds a [`Natural`] to the nearest primitive float. Unlike `RoundingFrom` with `Nearest`, which
+// saturates at the largest finite float, values that are too large round to infinity, as they would
+// under IEEE 754 (and as they do in `num-bigint`).
+pub(crate) fn natural_to_primitive_float<T>(x: &Natural) -> T
+where
+    T: PrimitiveFloat + for<'a> RoundingFrom<&'a Natural>,
+{
+    let (f, o) = T::rounding_from(x, Nearest);
+    if f == T::MAX_FINITE && o.is_lt() {
+        // The smallest value that rounds to infinity is halfway between the largest finite float
+        // and the next power of 2.
+        let max_exponent = u64::exact_from(T::MAX_EXPONENT);
+        let threshold = Natural::power_of_2(max_exponent + 1)
+            - Natural::power_of_2(max_exponent - T::MANTISSA_WIDTH - 1);
+        if *x >= threshold {
+            return T::INFINITY;
+        }
+    }
+    f
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Zero for Natural {
+    #[inline]
+    fn zero() -> Natural {
+        Natural::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl One for Natural {
+    #[inline]
+    fn one() -> Natural {
+        Natural::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Num for Natural {
+    type FromStrRadixErr = ();
+
+    /// Converts a string, in a specified radix, to a [`Natural`].
+    ///
+    /// This is equivalent to [`Natural::from_string_base`].
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Natural, ()> {
+        Natural::from_string_base(u8::exact_from(radix), s).ok_or(())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Unsigned for Natural {}
+
+macro_rules! impl_pow {
+    ($t: ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl Pow<$t> for Natural {
+            type Output = Natural;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                MalachitePow::pow(self, u64::exact_from(exp))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl<'a> Pow<$t> for &'a Natural {
+            type Output = Natural;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Natural {
+                MalachitePow::pow(self, u64::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8);
+impl_pow!(u16);
+impl_pow!(u32);
+impl_pow!(u64);
+impl_pow!(usize);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl ToPrimitive for Natural {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f32`]. Values too large to be represented are
+    /// converted to infinity.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(natural_to_primitive_float(self))
+    }
+
+    /// Converts a [`Natural`] to the nearest [`f64`]. Values too large to be represented are
+    /// converted to infinity.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(natural_to_primitive_float(self))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl FromPrimitive for Natural {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Natural> {
+        Natural::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Natural> {
+        Some(Natural::from(n))
+    }
+
+    /// Converts an [`f32`] to a [`Natural`], rounding towards zero. Returns `None` if the value is
+    /// NaN, infinite, or at most -1.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Natural> {
+        if n.is_finite() && n > -1.0 {
+            Some(Natural::rounding_from(n, Down).0)
+        } else {
+            None
+        }
+    }
+
+    /// Converts an [`f64`] to a [`Natural`], rounding towards zero. Returns `None` if the value is
+    /// NaN, infinite, or at most -1.
+    #[inline]
+    fn from_f64(n: f64) -> Option<Natural> {
+        if n.is_finite() && n > -1.0 {
+            Some(Natural::rounding_from(n, Down).0)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl CheckedMul for Natural {
+    /// Multiplies two [`Natural`]s. Since a [`Natural`] cannot overflow, the result is never
+    /// `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Natural) -> Option<Natural> {
+        Some(self * other)
+    }
+}
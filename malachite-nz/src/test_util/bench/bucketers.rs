@@ -771,3 +771,16 @@ pub fn triple_3_vec_natural_sum_bits_bucketer<'a, T, U>() -> Bucketer<'a, (T, U,
         bucketing_label: "xs.map(|x| x.significant_bits()).sum()".to_string(),
     }
 }
+
+pub fn vec_pair_2_natural_sum_bits_bucketer<'a, T>() -> Bucketer<'a, Vec<(T, Natural)>> {
+    Bucketer {
+        bucketing_function: &|ps| {
+            usize::exact_from(
+                ps.iter()
+                    .map(|(_, m)| m.significant_bits())
+                    .sum::<u64>(),
+            )
+        },
+        bucketing_label: "ps.map(|(_, m)| m.significant_bits()).sum()".to_string(),
+    }
+}
@@ -8,17 +8,22 @@
 
 use crate::integer::Integer;
 use crate::natural::Natural;
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(all(not(feature = "32_bit_limbs"), not(feature = "num_traits")))]
 use crate::platform::Limb;
+#[cfg(not(feature = "num_traits"))]
 use malachite_base::num::arithmetic::traits::Sign as SignTrait;
 #[cfg(not(feature = "32_bit_limbs"))]
 use malachite_base::num::conversion::traits::VecFromOtherTypeSlice;
 use num::bigint::Sign;
 use num::{BigInt, BigUint};
 use rug::integer::Order;
+#[cfg(not(feature = "num_traits"))]
 use std::cmp::Ordering::*;
 
-#[cfg(feature = "32_bit_limbs")]
+// With the `num_traits` feature, the conversions between `BigUint` and `Natural`, and between
+// `BigInt` and `Integer`, are provided by the library itself.
+
+#[cfg(all(feature = "32_bit_limbs", not(feature = "num_traits")))]
 impl From<&BigUint> for Natural {
     #[inline]
     fn from(n: &BigUint) -> Natural {
@@ -26,7 +31,7 @@ impl From<&BigUint> for Natural {
     }
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(all(not(feature = "32_bit_limbs"), not(feature = "num_traits")))]
 impl From<&BigUint> for Natural {
     #[inline]
     fn from(n: &BigUint) -> Natural {
@@ -34,7 +39,7 @@ impl From<&BigUint> for Natural {
     }
 }
 
-#[cfg(feature = "32_bit_limbs")]
+#[cfg(all(feature = "32_bit_limbs", not(feature = "num_traits")))]
 impl From<&Natural> for BigUint {
     #[inline]
     fn from(n: &Natural) -> BigUint {
@@ -42,7 +47,7 @@ impl From<&Natural> for BigUint {
     }
 }
 
-#[cfg(not(feature = "32_bit_limbs"))]
+#[cfg(all(not(feature = "32_bit_limbs"), not(feature = "num_traits")))]
 impl From<&Natural> for BigUint {
     #[inline]
     fn from(n: &Natural) -> BigUint {
@@ -92,6 +97,7 @@ impl From<&Natural> for rug::Integer {
     }
 }
 
+#[cfg(not(feature = "num_traits"))]
 impl From<&BigInt> for Integer {
     #[inline]
     fn from(n: &BigInt) -> Integer {
@@ -99,6 +105,7 @@ impl From<&BigInt> for Integer {
     }
 }
 
+#[cfg(not(feature = "num_traits"))]
 impl From<&Integer> for BigInt {
     #[inline]
     fn from(n: &Integer) -> BigInt {
@@ -88,7 +88,7 @@ use malachite_base::iterators::bit_distributor::BitDistributorOutputType;
 use malachite_base::iterators::iter_windows;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, CoprimeWith, DivRound, DivisibleBy, DivisibleByPowerOf2, EqMod,
-    EqModPowerOf2, Parity, PowerOf2,
+    EqModPowerOf2, ModInverse, Parity, PowerOf2,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -333,6 +333,20 @@ pub fn exhaustive_integer_integer_natural_triple_gen_var_2() -> It<(Integer, Int
     )
 }
 
+pub fn exhaustive_integer_integer_natural_triple_gen_var_3() -> It<(Integer, Integer, Natural)> {
+    Box::new(exhaustive_triples_xxy(
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+    ))
+}
+
+pub fn exhaustive_integer_integer_natural_triple_gen_var_4() -> It<(Integer, Integer, Natural)> {
+    Box::new(
+        exhaustive_integer_integer_natural_triple_gen_var_3()
+            .filter(|(x, exp, m)| *exp >= 0u32 || x.mod_inverse(m).is_some()),
+    )
+}
+
 // -- (Integer, Integer, PrimitiveFloat) --
 
 pub fn exhaustive_integer_integer_primitive_float_triple_gen<T: PrimitiveFloat>(
@@ -433,7 +447,11 @@ pub(crate) fn round_to_multiple_integer_filter_map(
     if x == y {
         Some((x, y, rm))
     } else if y == 0 {
-        if rm == Down || rm == if x >= 0 { Floor } else { Ceiling } || rm == Nearest {
+        if rm == Down
+            || rm == if x >= 0 { Floor } else { Ceiling }
+            || rm == Nearest
+            || rm == NearestTiesAway
+        {
             Some((x, y, rm))
         } else {
             None
@@ -466,6 +484,13 @@ pub fn exhaustive_integer_natural_pair_gen() -> It<(Integer, Natural)> {
     ))
 }
 
+pub fn exhaustive_integer_natural_pair_gen_var_1() -> It<(Integer, Natural)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+    ))
+}
+
 // -- (Integer, Natural, Natural) --
 
 pub fn exhaustive_integer_natural_natural_triple_gen() -> It<(Integer, Natural, Natural)> {
@@ -475,6 +500,34 @@ pub fn exhaustive_integer_natural_natural_triple_gen() -> It<(Integer, Natural,
     ))
 }
 
+// -- (Integer, Natural, PrimitiveSigned) --
+
+pub fn exhaustive_integer_natural_signed_triple_gen_var_1<T: PrimitiveSigned>(
+) -> It<(Integer, Natural, T)> {
+    Box::new(exhaustive_triples_custom_output(
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+        exhaustive_signeds::<T>(),
+        BitDistributorOutputType::normal(1),
+        BitDistributorOutputType::normal(1),
+        BitDistributorOutputType::tiny(),
+    ))
+}
+
+// -- (Integer, Natural, PrimitiveUnsigned) --
+
+pub fn exhaustive_integer_natural_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+) -> It<(Integer, Natural, T)> {
+    Box::new(exhaustive_triples_custom_output(
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+        exhaustive_unsigneds::<T>(),
+        BitDistributorOutputType::normal(1),
+        BitDistributorOutputType::normal(1),
+        BitDistributorOutputType::tiny(),
+    ))
+}
+
 // -- (Integer, PrimitiveFloat) --
 
 pub fn exhaustive_integer_primitive_float_pair_gen<T: PrimitiveFloat>() -> It<(Integer, T)> {
@@ -1011,6 +1064,10 @@ pub fn exhaustive_natural_gen_var_8() -> It<Natural> {
     Box::new(exhaustive_naturals().map(|n| (n << 1u32) | Natural::ONE))
 }
 
+pub fn exhaustive_natural_gen_var_10() -> It<Natural> {
+    Box::new(exhaustive_natural_range_to_infinity(Natural::from(3u32)))
+}
+
 // -- (Natural, bool) --
 
 pub fn exhaustive_natural_bool_pair_gen() -> It<(Natural, bool)> {
@@ -1428,7 +1485,7 @@ pub(crate) fn round_to_multiple_natural_filter_map(
     if x == y {
         Some((x, y, rm))
     } else if y == 0 {
-        if rm == Down || rm == Floor || rm == Nearest {
+        if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway {
             Some((x, y, rm))
         } else {
             None
@@ -2188,12 +2245,30 @@ pub fn exhaustive_string_triple_gen_var_2() -> It<(String, String, String)> {
 
 // var 3 is in malachite-q.
 
+// -- Vec<(Integer, Natural)> --
+
+pub fn exhaustive_integer_natural_pair_vec_gen_var_1() -> It<Vec<(Integer, Natural)>> {
+    Box::new(exhaustive_vecs(exhaustive_pairs(
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+    )))
+}
+
 // -- Vec<Integer> --
 
 pub fn exhaustive_integer_vec_gen() -> It<Vec<Integer>> {
     Box::new(exhaustive_vecs(exhaustive_integers()))
 }
 
+// -- Vec<(Natural, Natural)> --
+
+pub fn exhaustive_natural_pair_vec_gen_var_1() -> It<Vec<(Natural, Natural)>> {
+    Box::new(exhaustive_vecs(exhaustive_pairs(
+        exhaustive_naturals(),
+        exhaustive_positive_naturals(),
+    )))
+}
+
 // -- Vec<Natural> --
 
 pub fn exhaustive_natural_vec_gen() -> It<Vec<Natural>> {
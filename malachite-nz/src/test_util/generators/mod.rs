@@ -441,6 +441,25 @@ pub fn integer_integer_natural_triple_gen_var_2() -> Generator<(Integer, Integer
     )
 }
 
+// All `(Integer, Integer, Natural)` triples where the `Natural` is positive.
+pub fn integer_integer_natural_triple_gen_var_3() -> Generator<(Integer, Integer, Natural)> {
+    Generator::new(
+        &exhaustive_integer_integer_natural_triple_gen_var_3,
+        &random_integer_integer_natural_triple_gen_var_3,
+        &special_random_integer_integer_natural_triple_gen_var_3,
+    )
+}
+
+// All `(Integer, Integer, Natural)` triples where the `Natural` is positive, and the first
+// `Integer` is invertible modulo the `Natural` if the second `Integer` is negative.
+pub fn integer_integer_natural_triple_gen_var_4() -> Generator<(Integer, Integer, Natural)> {
+    Generator::new(
+        &exhaustive_integer_integer_natural_triple_gen_var_4,
+        &random_integer_integer_natural_triple_gen_var_4,
+        &special_random_integer_integer_natural_triple_gen_var_4,
+    )
+}
+
 // -- (Integer, Integer, PrimitiveFloat) --
 
 pub fn integer_integer_primitive_float_triple_gen<T: PrimitiveFloat>(
@@ -567,6 +586,15 @@ pub fn integer_natural_pair_gen_rm() -> T1 {
     )
 }
 
+// All `(Integer, Natural)` pairs where the `Natural` is positive.
+pub fn integer_natural_pair_gen_var_1() -> Generator<(Integer, Natural)> {
+    Generator::new(
+        &exhaustive_integer_natural_pair_gen_var_1,
+        &random_integer_natural_pair_gen_var_1,
+        &special_random_integer_natural_pair_gen_var_1,
+    )
+}
+
 // -- (Integer, Natural, Natural) --
 
 pub fn integer_natural_natural_triple_gen() -> Generator<(Integer, Natural, Natural)> {
@@ -577,6 +605,30 @@ pub fn integer_natural_natural_triple_gen() -> Generator<(Integer, Natural, Natu
     )
 }
 
+// -- (Integer, Natural, PrimitiveSigned) --
+
+// All `(Integer, Natural, T)` where `T` is signed and the `Natural` is positive.
+pub fn integer_natural_signed_triple_gen_var_1<T: PrimitiveSigned>(
+) -> Generator<(Integer, Natural, T)> {
+    Generator::new(
+        &exhaustive_integer_natural_signed_triple_gen_var_1,
+        &random_integer_natural_signed_triple_gen_var_1,
+        &special_random_integer_natural_signed_triple_gen_var_1,
+    )
+}
+
+// -- (Integer, Natural, PrimitiveUnsigned) --
+
+// All `(Integer, Natural, T)` where `T` is unsigned and the `Natural` is positive.
+pub fn integer_natural_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+) -> Generator<(Integer, Natural, T)> {
+    Generator::new(
+        &exhaustive_integer_natural_unsigned_triple_gen_var_1,
+        &random_integer_natural_unsigned_triple_gen_var_1,
+        &special_random_integer_natural_unsigned_triple_gen_var_1,
+    )
+}
+
 // -- (Integer, PrimitiveFloat) --
 
 pub fn integer_primitive_float_pair_gen<T: PrimitiveFloat>() -> Generator<(Integer, T)> {
@@ -1131,6 +1183,15 @@ pub fn natural_gen_var_9() -> Generator<Natural> {
     Generator::new_no_special(&exhaustive_natural_gen, &random_natural_gen_var_9)
 }
 
+// All `Natural`s greater than or equal to 3.
+pub fn natural_gen_var_10() -> Generator<Natural> {
+    Generator::new(
+        &exhaustive_natural_gen_var_10,
+        &random_natural_gen_var_10,
+        &special_random_natural_gen_var_9,
+    )
+}
+
 // -- (Natural, bool) --
 
 pub fn natural_bool_pair_gen() -> Generator<(Natural, bool)> {
@@ -2482,6 +2543,17 @@ pub fn string_triple_gen_var_2() -> Generator<(String, String, String)> {
 
 // var 3 is in malachite-q.
 
+// -- Vec<(Integer, Natural)> --
+
+// All `Vec`s of `(Integer, Natural)` pairs where each `Natural` is positive.
+pub fn integer_natural_pair_vec_gen_var_1() -> Generator<Vec<(Integer, Natural)>> {
+    Generator::new(
+        &exhaustive_integer_natural_pair_vec_gen_var_1,
+        &random_integer_natural_pair_vec_gen_var_1,
+        &special_random_integer_natural_pair_vec_gen_var_1,
+    )
+}
+
 // -- Vec<Integer> --
 
 pub fn integer_vec_gen() -> Generator<Vec<Integer>> {
@@ -2500,6 +2572,17 @@ pub fn integer_vec_gen_nrm() -> Generator<(Vec<BigInt>, Vec<rug::Integer>, Vec<I
     )
 }
 
+// -- Vec<(Natural, Natural)> --
+
+// All `Vec`s of `(Natural, Natural)` pairs where each second `Natural` is positive.
+pub fn natural_pair_vec_gen_var_1() -> Generator<Vec<(Natural, Natural)>> {
+    Generator::new(
+        &exhaustive_natural_pair_vec_gen_var_1,
+        &random_natural_pair_vec_gen_var_1,
+        &special_random_natural_pair_vec_gen_var_1,
+    )
+}
+
 // -- Vec<Natural> --
 
 pub fn natural_vec_gen() -> Generator<Vec<Natural>> {
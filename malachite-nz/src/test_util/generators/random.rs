@@ -83,7 +83,7 @@ use malachite_base::bools::random::{random_bools, RandomBools};
 use malachite_base::iterators::with_special_value;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, CeilingLogBase2, CoprimeWith, DivRound, DivisibleBy, DivisibleByPowerOf2,
-    EqMod, EqModPowerOf2, Parity, PowerOf2, RoundToMultipleOfPowerOf2Assign,
+    EqMod, EqModPowerOf2, ModInverse, Parity, PowerOf2, RoundToMultipleOfPowerOf2Assign,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -578,6 +578,37 @@ pub fn random_integer_integer_natural_triple_gen_var_2(
     )
 }
 
+pub fn random_integer_integer_natural_triple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Natural)> {
+    Box::new(random_triples_xxy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            random_positive_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_integer_integer_natural_triple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Natural)> {
+    Box::new(
+        random_integer_integer_natural_triple_gen_var_3(config)
+            .filter(|(x, exp, m)| *exp >= 0u32 || x.mod_inverse(m).is_some()),
+    )
+}
+
 // -- (Integer, Integer, PrimitiveFloat) --
 
 pub fn random_integer_integer_primitive_float_triple_gen<T: PrimitiveFloat>(
@@ -783,6 +814,26 @@ pub fn random_integer_natural_pair_gen(config: &GenConfig) -> It<(Integer, Natur
     ))
 }
 
+pub fn random_integer_natural_pair_gen_var_1(config: &GenConfig) -> It<(Integer, Natural)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            random_positive_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
 // -- (Integer, Natural, Natural) --
 
 pub fn random_integer_natural_natural_triple_gen(
@@ -807,6 +858,68 @@ pub fn random_integer_natural_natural_triple_gen(
     ))
 }
 
+// -- (Integer, Natural, PrimitiveSigned) --
+
+pub fn random_integer_natural_signed_triple_gen_var_1<T: PrimitiveSigned>(
+    config: &GenConfig,
+) -> It<(Integer, Natural, T)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            random_positive_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_signeds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Integer, Natural, PrimitiveUnsigned) --
+
+pub fn random_integer_natural_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Integer, Natural, T)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            random_positive_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
 // -- (Integer, PrimitiveFloat) --
 
 pub fn random_integer_primitive_float_pair_gen<T: PrimitiveFloat>(
@@ -1747,6 +1860,15 @@ pub fn random_natural_gen_var_9(config: &GenConfig) -> It<Natural> {
     )
 }
 
+pub fn random_natural_gen_var_10(config: &GenConfig) -> It<Natural> {
+    Box::new(random_natural_range_to_infinity(
+        EXAMPLE_SEED,
+        Natural::from(3u32),
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    ))
+}
+
 // -- (Natural, bool) --
 
 pub fn random_natural_bool_pair_gen(config: &GenConfig) -> It<(Natural, bool)> {
@@ -3961,6 +4083,37 @@ pub fn random_string_triple_gen_var_2(config: &GenConfig) -> It<(String, String,
     )
 }
 
+// -- Vec<(Integer, Natural)> --
+
+pub fn random_integer_natural_pair_vec_gen_var_1(
+    config: &GenConfig,
+) -> It<Vec<(Integer, Natural)>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_pairs(
+                seed,
+                &|seed_2| {
+                    random_integers(
+                        seed_2,
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+                &|seed_2| {
+                    random_positive_naturals(
+                        seed_2,
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+            )
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
 // -- Vec<Integer> --
 
 pub fn random_integer_vec_gen(config: &GenConfig) -> It<Vec<Integer>> {
@@ -3978,6 +4131,35 @@ pub fn random_integer_vec_gen(config: &GenConfig) -> It<Vec<Integer>> {
     ))
 }
 
+// -- Vec<(Natural, Natural)> --
+
+pub fn random_natural_pair_vec_gen_var_1(config: &GenConfig) -> It<Vec<(Natural, Natural)>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_pairs(
+                seed,
+                &|seed_2| {
+                    random_naturals(
+                        seed_2,
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+                &|seed_2| {
+                    random_positive_naturals(
+                        seed_2,
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+            )
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
 // -- Vec<Natural> --
 
 pub fn random_natural_vec_gen(config: &GenConfig) -> It<Vec<Natural>> {
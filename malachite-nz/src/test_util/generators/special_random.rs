@@ -78,7 +78,7 @@ use malachite_base::bools::random::{random_bools, RandomBools};
 use malachite_base::iterators::with_special_value;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, CeilingLogBase2, CoprimeWith, DivRound, DivisibleBy, DivisibleByPowerOf2,
-    EqMod, EqModPowerOf2, Parity, PowerOf2, RoundToMultipleOfPowerOf2Assign,
+    EqMod, EqModPowerOf2, ModInverse, Parity, PowerOf2, RoundToMultipleOfPowerOf2Assign,
 };
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -669,6 +669,41 @@ pub fn special_random_integer_integer_natural_triple_gen_var_2(
     )
 }
 
+pub fn special_random_integer_integer_natural_triple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Natural)> {
+    Box::new(random_triples_xxy(
+        EXAMPLE_SEED,
+        &|seed| {
+            striped_random_integers(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            striped_random_positive_naturals(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn special_random_integer_integer_natural_triple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Integer, Integer, Natural)> {
+    Box::new(
+        special_random_integer_integer_natural_triple_gen_var_3(config)
+            .filter(|(x, exp, m)| *exp >= 0u32 || x.mod_inverse(m).is_some()),
+    )
+}
+
 // -- (Integer, Integer, PrimitiveFloat) --
 
 pub fn special_random_integer_integer_primitive_float_triple_gen<T: PrimitiveFloat>(
@@ -916,6 +951,30 @@ pub fn special_random_integer_natural_pair_gen(config: &GenConfig) -> It<(Intege
     ))
 }
 
+pub fn special_random_integer_natural_pair_gen_var_1(config: &GenConfig) -> It<(Integer, Natural)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            striped_random_integers(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            striped_random_positive_naturals(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
 // -- (Integer, Natural, Natural) --
 
 pub fn special_random_integer_natural_natural_triple_gen(
@@ -944,6 +1003,76 @@ pub fn special_random_integer_natural_natural_triple_gen(
     ))
 }
 
+// -- (Integer, Natural, PrimitiveSigned) --
+
+pub fn special_random_integer_natural_signed_triple_gen_var_1<T: PrimitiveSigned>(
+    config: &GenConfig,
+) -> It<(Integer, Natural, T)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            striped_random_integers(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            striped_random_positive_naturals(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_signeds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Integer, Natural, PrimitiveUnsigned) --
+
+pub fn special_random_integer_natural_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Integer, Natural, T)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            striped_random_integers(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            striped_random_positive_naturals(
+                seed,
+                config.get_or("mean_stripe_n", 32),
+                config.get_or("mean_stripe_d", 1),
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
 // -- (Integer, PrimitiveFloat) --
 
 pub fn special_random_integer_primitive_float_pair_gen<T: PrimitiveFloat>(
@@ -2055,6 +2184,17 @@ pub fn special_random_natural_gen_var_8(config: &GenConfig) -> It<Natural> {
     )
 }
 
+pub fn special_random_natural_gen_var_9(config: &GenConfig) -> It<Natural> {
+    Box::new(striped_random_natural_range_to_infinity(
+        EXAMPLE_SEED,
+        Natural::from(3u32),
+        config.get_or("mean_stripe_n", 32),
+        config.get_or("mean_stripe_d", 1),
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    ))
+}
+
 // -- (Natural, bool) --
 
 pub fn special_random_natural_bool_pair_gen(config: &GenConfig) -> It<(Natural, bool)> {
@@ -4542,6 +4682,41 @@ pub fn special_random_string_triple_gen_var_2(config: &GenConfig) -> It<(String,
     )
 }
 
+// -- Vec<(Integer, Natural)> --
+
+pub fn special_random_integer_natural_pair_vec_gen_var_1(
+    config: &GenConfig,
+) -> It<Vec<(Integer, Natural)>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_pairs(
+                seed,
+                &|seed_2| {
+                    striped_random_integers(
+                        seed_2,
+                        config.get_or("mean_stripe_n", 32),
+                        config.get_or("mean_stripe_d", 1),
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+                &|seed_2| {
+                    striped_random_positive_naturals(
+                        seed_2,
+                        config.get_or("mean_stripe_n", 32),
+                        config.get_or("mean_stripe_d", 1),
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+            )
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
 // -- Vec<Integer> --
 
 pub fn special_random_integer_vec_gen(config: &GenConfig) -> It<Vec<Integer>> {
@@ -4561,6 +4736,41 @@ pub fn special_random_integer_vec_gen(config: &GenConfig) -> It<Vec<Integer>> {
     ))
 }
 
+// -- Vec<(Natural, Natural)> --
+
+pub fn special_random_natural_pair_vec_gen_var_1(
+    config: &GenConfig,
+) -> It<Vec<(Natural, Natural)>> {
+    Box::new(random_vecs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_pairs(
+                seed,
+                &|seed_2| {
+                    striped_random_naturals(
+                        seed_2,
+                        config.get_or("mean_stripe_n", 32),
+                        config.get_or("mean_stripe_d", 1),
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+                &|seed_2| {
+                    striped_random_positive_naturals(
+                        seed_2,
+                        config.get_or("mean_stripe_n", 32),
+                        config.get_or("mean_stripe_d", 1),
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+            )
+        },
+        config.get_or("mean_len_n", 4),
+        config.get_or("mean_len_d", 1),
+    ))
+}
+
 // -- Vec<Natural> --
 
 pub fn special_random_natural_vec_gen(config: &GenConfig) -> It<Vec<Natural>> {
@@ -0,0 +1,41 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, EqMod, Gcd, Mod, ModInverse, ModMul, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+pub fn chinese_remainder_sequential(pairs: &[(Natural, Natural)]) -> Option<Natural> {
+    let mut x = Natural::ZERO;
+    let mut lcm = Natural::ONE;
+    for (r, m) in pairs {
+        let r = r % m;
+        let g = (&lcm).gcd(m);
+        if !(&x).eq_mod(&r, &g) {
+            return None;
+        }
+        // Solve x + lcm * k ≡ r mod m, which is equivalent to (lcm / g) * k ≡ (r - x) / g mod (m /
+        // g)
+        let m_g = m.div_exact(&g);
+        if m_g == 1u32 {
+            continue;
+        }
+        let lcm_g = (&lcm).div_exact(&g) % &m_g;
+        let diff = (Integer::from(r) - Integer::from(&x))
+            .div_exact(Integer::from(g))
+            .mod_op(Integer::from(&m_g))
+            .unsigned_abs();
+        let k = lcm_g.mod_inverse(&m_g).unwrap().mod_mul(diff, &m_g);
+        x += &lcm * k;
+        lcm *= m_g;
+    }
+    Some(x)
+}
@@ -0,0 +1,96 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ChineseRemainder, EqMod, Lcm, Mod};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_vec_gen_var_1;
+use std::str::FromStr;
+
+#[test]
+fn test_chinese_remainder() {
+    let test = |pairs: &[(&str, &str)], out: Option<&str>| {
+        let pairs: Vec<(Integer, Natural)> = pairs
+            .iter()
+            .map(|&(r, m)| (Integer::from_str(r).unwrap(), Natural::from_str(m).unwrap()))
+            .collect();
+        let x = Integer::chinese_remainder(&pairs);
+        assert_eq!(x.as_ref().map(ToString::to_string).as_deref(), out);
+    };
+    test(&[], Some("0"));
+    test(&[("-5", "1")], Some("0"));
+    test(&[("5", "7")], Some("-2"));
+    test(&[("3", "7")], Some("3"));
+    test(&[("-12", "7")], Some("2"));
+    test(&[("3", "6")], Some("3"));
+    test(&[("4", "6")], Some("-2"));
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], Some("23"));
+    test(&[("-1", "3"), ("-1", "5"), ("-1", "7")], Some("-1"));
+    test(&[("60", "3"), ("58", "5"), ("-30", "7")], Some("33"));
+    test(&[("3", "4"), ("5", "6")], Some("-1"));
+    test(&[("3", "4"), ("4", "6")], None);
+    test(&[("-3", "4"), ("-3", "6")], Some("-3"));
+    test(
+        &[("-123456789", "1000000007"), ("-123456789", "998244353"), ("-123456789", "1000000009")],
+        Some("-123456789"),
+    );
+}
+
+#[test]
+#[should_panic]
+fn chinese_remainder_fail_1() {
+    Integer::chinese_remainder(&[(Integer::ONE, Natural::ZERO)]);
+}
+
+#[test]
+#[should_panic]
+fn chinese_remainder_fail_2() {
+    Integer::chinese_remainder(&[
+        (Integer::from(3), Natural::from(4u32)),
+        (Integer::from(4), Natural::from(6u32)),
+        (Integer::ONE, Natural::from(5u32)),
+        (Integer::ZERO, Natural::ZERO),
+    ]);
+}
+
+#[test]
+fn test_chinese_remainder_many_primes() {
+    let x = Integer::from_str("-123456789012345678901234567890123456789").unwrap();
+    let pairs: Vec<(Integer, Natural)> = Natural::primes()
+        .take(1000)
+        .map(|p| (x.clone(), p))
+        .collect();
+    assert_eq!(Integer::chinese_remainder(&pairs), Some(x));
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    integer_natural_pair_vec_gen_var_1().test_properties(|pairs| {
+        let x = Integer::chinese_remainder(&pairs);
+        let natural_pairs: Vec<(Natural, Natural)> = pairs
+            .iter()
+            .map(|(r, m)| (Natural::exact_from(r.mod_op(Integer::from(m))), m.clone()))
+            .collect();
+        let natural_x = Natural::chinese_remainder(&natural_pairs);
+        assert_eq!(x.is_some(), natural_x.is_some());
+        if let (Some(x), Some(natural_x)) = (x, natural_x) {
+            assert!(x.is_valid());
+            let lcm = pairs.iter().fold(Natural::ONE, |l, (_, m)| l.lcm(m));
+            assert!((&x).eq_mod(Integer::from(natural_x), &lcm));
+            let lcm = Integer::from(lcm);
+            assert!(-&lcm < (&x << 1u32));
+            assert!((&x << 1u32) <= lcm);
+            for (r, m) in &pairs {
+                assert!((&x).eq_mod(r, m));
+            }
+        }
+    });
+}
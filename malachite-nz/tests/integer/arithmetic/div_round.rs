@@ -103,6 +103,8 @@ fn test_div_round() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", ToOdd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "123", Down, "0", Equal);
@@ -110,6 +112,8 @@ fn test_div_round() {
     test("0", "123", Up, "0", Equal);
     test("0", "123", Ceiling, "0", Equal);
     test("0", "123", Nearest, "0", Equal);
+    test("0", "123", NearestTiesAway, "0", Equal);
+    test("0", "123", ToOdd, "0", Equal);
     test("0", "123", Exact, "0", Equal);
 
     test("1", "1", Down, "1", Equal);
@@ -117,6 +121,8 @@ fn test_div_round() {
     test("1", "1", Up, "1", Equal);
     test("1", "1", Ceiling, "1", Equal);
     test("1", "1", Nearest, "1", Equal);
+    test("1", "1", NearestTiesAway, "1", Equal);
+    test("1", "1", ToOdd, "1", Equal);
     test("1", "1", Exact, "1", Equal);
 
     test("123", "1", Down, "123", Equal);
@@ -124,6 +130,8 @@ fn test_div_round() {
     test("123", "1", Up, "123", Equal);
     test("123", "1", Ceiling, "123", Equal);
     test("123", "1", Nearest, "123", Equal);
+    test("123", "1", NearestTiesAway, "123", Equal);
+    test("123", "1", ToOdd, "123", Equal);
     test("123", "1", Exact, "123", Equal);
 
     test("123", "2", Down, "61", Less);
@@ -131,18 +139,24 @@ fn test_div_round() {
     test("123", "2", Up, "62", Greater);
     test("123", "2", Ceiling, "62", Greater);
     test("123", "2", Nearest, "62", Greater);
+    test("123", "2", NearestTiesAway, "62", Greater);
+    test("123", "2", ToOdd, "61", Less);
 
     test("125", "2", Down, "62", Less);
     test("125", "2", Floor, "62", Less);
     test("125", "2", Up, "63", Greater);
     test("125", "2", Ceiling, "63", Greater);
     test("125", "2", Nearest, "62", Less);
+    test("125", "2", NearestTiesAway, "63", Greater);
+    test("125", "2", ToOdd, "63", Greater);
 
     test("123", "123", Down, "1", Equal);
     test("123", "123", Floor, "1", Equal);
     test("123", "123", Up, "1", Equal);
     test("123", "123", Ceiling, "1", Equal);
     test("123", "123", Nearest, "1", Equal);
+    test("123", "123", NearestTiesAway, "1", Equal);
+    test("123", "123", ToOdd, "1", Equal);
     test("123", "123", Exact, "1", Equal);
 
     test("123", "456", Down, "0", Less);
@@ -150,12 +164,22 @@ fn test_div_round() {
     test("123", "456", Up, "1", Greater);
     test("123", "456", Ceiling, "1", Greater);
     test("123", "456", Nearest, "0", Less);
+    test("123", "456", NearestTiesAway, "0", Less);
+    test("123", "456", ToOdd, "1", Greater);
 
     test("1000000000000", "1", Down, "1000000000000", Equal);
     test("1000000000000", "1", Floor, "1000000000000", Equal);
     test("1000000000000", "1", Up, "1000000000000", Equal);
     test("1000000000000", "1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "1", Nearest, "1000000000000", Equal);
+    test(
+        "1000000000000",
+        "1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("1000000000000", "1", ToOdd, "1000000000000", Equal);
     test("1000000000000", "1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "3", Down, "333333333333", Less);
@@ -163,18 +187,36 @@ fn test_div_round() {
     test("1000000000000", "3", Up, "333333333334", Greater);
     test("1000000000000", "3", Ceiling, "333333333334", Greater);
     test("1000000000000", "3", Nearest, "333333333333", Less);
+    test("1000000000000", "3", NearestTiesAway, "333333333333", Less);
+    test("1000000000000", "3", ToOdd, "333333333333", Less);
 
     test("999999999999", "2", Down, "499999999999", Less);
     test("999999999999", "2", Floor, "499999999999", Less);
     test("999999999999", "2", Up, "500000000000", Greater);
     test("999999999999", "2", Ceiling, "500000000000", Greater);
     test("999999999999", "2", Nearest, "500000000000", Greater);
+    test(
+        "999999999999",
+        "2",
+        NearestTiesAway,
+        "500000000000",
+        Greater,
+    );
+    test("999999999999", "2", ToOdd, "499999999999", Less);
 
     test("1000000000001", "2", Down, "500000000000", Less);
     test("1000000000001", "2", Floor, "500000000000", Less);
     test("1000000000001", "2", Up, "500000000001", Greater);
     test("1000000000001", "2", Ceiling, "500000000001", Greater);
     test("1000000000001", "2", Nearest, "500000000000", Less);
+    test(
+        "1000000000001",
+        "2",
+        NearestTiesAway,
+        "500000000001",
+        Greater,
+    );
+    test("1000000000001", "2", ToOdd, "500000000001", Greater);
 
     test(
         "1000000000000000000000000",
@@ -211,6 +253,20 @@ fn test_div_round() {
         "232830643708080",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "232830643708080",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "232830643708079",
+        Less,
+    );
 
     test(
         "1000000000000000000000000",
@@ -247,6 +303,20 @@ fn test_div_round() {
         "1000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "1000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "1000000000000",
@@ -290,6 +360,20 @@ fn test_div_round() {
         "999999999999",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "999999999999",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "999999999999",
+        Less,
+    );
 
     test(
         "2999999999999999999999999",
@@ -298,6 +382,20 @@ fn test_div_round() {
         "1",
         Less,
     );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "1",
+        Less,
+    );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "3000000000000000000000000",
         "2000000000000000000000000",
@@ -305,6 +403,20 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "3000000000000000000000001",
         "2000000000000000000000000",
@@ -312,12 +424,28 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
 
     test("0", "-1", Down, "0", Equal);
     test("0", "-1", Floor, "0", Equal);
     test("0", "-1", Up, "0", Equal);
     test("0", "-1", Ceiling, "0", Equal);
     test("0", "-1", Nearest, "0", Equal);
+    test("0", "-1", NearestTiesAway, "0", Equal);
+    test("0", "-1", ToOdd, "0", Equal);
     test("0", "-1", Exact, "0", Equal);
 
     test("0", "-123", Down, "0", Equal);
@@ -325,6 +453,8 @@ fn test_div_round() {
     test("0", "-123", Up, "0", Equal);
     test("0", "-123", Ceiling, "0", Equal);
     test("0", "-123", Nearest, "0", Equal);
+    test("0", "-123", NearestTiesAway, "0", Equal);
+    test("0", "-123", ToOdd, "0", Equal);
     test("0", "-123", Exact, "0", Equal);
 
     test("1", "-1", Down, "-1", Equal);
@@ -332,6 +462,8 @@ fn test_div_round() {
     test("1", "-1", Up, "-1", Equal);
     test("1", "-1", Ceiling, "-1", Equal);
     test("1", "-1", Nearest, "-1", Equal);
+    test("1", "-1", NearestTiesAway, "-1", Equal);
+    test("1", "-1", ToOdd, "-1", Equal);
     test("1", "-1", Exact, "-1", Equal);
 
     test("123", "-1", Down, "-123", Equal);
@@ -339,6 +471,8 @@ fn test_div_round() {
     test("123", "-1", Up, "-123", Equal);
     test("123", "-1", Ceiling, "-123", Equal);
     test("123", "-1", Nearest, "-123", Equal);
+    test("123", "-1", NearestTiesAway, "-123", Equal);
+    test("123", "-1", ToOdd, "-123", Equal);
     test("123", "-1", Exact, "-123", Equal);
 
     test("123", "-2", Down, "-61", Greater);
@@ -346,18 +480,24 @@ fn test_div_round() {
     test("123", "-2", Up, "-62", Less);
     test("123", "-2", Ceiling, "-61", Greater);
     test("123", "-2", Nearest, "-62", Less);
+    test("123", "-2", NearestTiesAway, "-62", Less);
+    test("123", "-2", ToOdd, "-61", Greater);
 
     test("125", "-2", Down, "-62", Greater);
     test("125", "-2", Floor, "-63", Less);
     test("125", "-2", Up, "-63", Less);
     test("125", "-2", Ceiling, "-62", Greater);
     test("125", "-2", Nearest, "-62", Greater);
+    test("125", "-2", NearestTiesAway, "-63", Less);
+    test("125", "-2", ToOdd, "-63", Less);
 
     test("123", "-123", Down, "-1", Equal);
     test("123", "-123", Floor, "-1", Equal);
     test("123", "-123", Up, "-1", Equal);
     test("123", "-123", Ceiling, "-1", Equal);
     test("123", "-123", Nearest, "-1", Equal);
+    test("123", "-123", NearestTiesAway, "-1", Equal);
+    test("123", "-123", ToOdd, "-1", Equal);
     test("123", "-123", Exact, "-1", Equal);
 
     test("123", "-456", Down, "0", Greater);
@@ -365,12 +505,22 @@ fn test_div_round() {
     test("123", "-456", Up, "-1", Less);
     test("123", "-456", Ceiling, "0", Greater);
     test("123", "-456", Nearest, "0", Greater);
+    test("123", "-456", NearestTiesAway, "0", Greater);
+    test("123", "-456", ToOdd, "-1", Less);
 
     test("1000000000000", "-1", Down, "-1000000000000", Equal);
     test("1000000000000", "-1", Floor, "-1000000000000", Equal);
     test("1000000000000", "-1", Up, "-1000000000000", Equal);
     test("1000000000000", "-1", Ceiling, "-1000000000000", Equal);
     test("1000000000000", "-1", Nearest, "-1000000000000", Equal);
+    test(
+        "1000000000000",
+        "-1",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("1000000000000", "-1", ToOdd, "-1000000000000", Equal);
     test("1000000000000", "-1", Exact, "-1000000000000", Equal);
 
     test("1000000000000", "-3", Down, "-333333333333", Greater);
@@ -378,18 +528,36 @@ fn test_div_round() {
     test("1000000000000", "-3", Up, "-333333333334", Less);
     test("1000000000000", "-3", Ceiling, "-333333333333", Greater);
     test("1000000000000", "-3", Nearest, "-333333333333", Greater);
+    test(
+        "1000000000000",
+        "-3",
+        NearestTiesAway,
+        "-333333333333",
+        Greater,
+    );
+    test("1000000000000", "-3", ToOdd, "-333333333333", Greater);
 
     test("999999999999", "-2", Down, "-499999999999", Greater);
     test("999999999999", "-2", Floor, "-500000000000", Less);
     test("999999999999", "-2", Up, "-500000000000", Less);
     test("999999999999", "-2", Ceiling, "-499999999999", Greater);
     test("999999999999", "-2", Nearest, "-500000000000", Less);
+    test("999999999999", "-2", NearestTiesAway, "-500000000000", Less);
+    test("999999999999", "-2", ToOdd, "-499999999999", Greater);
 
     test("1000000000001", "-2", Down, "-500000000000", Greater);
     test("1000000000001", "-2", Floor, "-500000000001", Less);
     test("1000000000001", "-2", Up, "-500000000001", Less);
     test("1000000000001", "-2", Ceiling, "-500000000000", Greater);
     test("1000000000001", "-2", Nearest, "-500000000000", Greater);
+    test(
+        "1000000000001",
+        "-2",
+        NearestTiesAway,
+        "-500000000001",
+        Less,
+    );
+    test("1000000000001", "-2", ToOdd, "-500000000001", Less);
 
     test(
         "1000000000000000000000000",
@@ -426,6 +594,20 @@ fn test_div_round() {
         "-232830643708080",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "-4294967295",
+        NearestTiesAway,
+        "-232830643708080",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "-4294967295",
+        ToOdd,
+        "-232830643708079",
+        Greater,
+    );
 
     test(
         "1000000000000000000000000",
@@ -462,6 +644,20 @@ fn test_div_round() {
         "-1000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "-1000000000000",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "-1000000000000",
+        ToOdd,
+        "-1000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "-1000000000000",
@@ -505,6 +701,20 @@ fn test_div_round() {
         "-999999999999",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "-1000000000001",
+        NearestTiesAway,
+        "-999999999999",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "-1000000000001",
+        ToOdd,
+        "-999999999999",
+        Greater,
+    );
 
     test(
         "2999999999999999999999999",
@@ -513,6 +723,20 @@ fn test_div_round() {
         "-1",
         Greater,
     );
+    test(
+        "2999999999999999999999999",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-1",
+        Greater,
+    );
+    test(
+        "2999999999999999999999999",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
     test(
         "3000000000000000000000000",
         "-2000000000000000000000000",
@@ -520,6 +744,20 @@ fn test_div_round() {
         "-2",
         Less,
     );
+    test(
+        "3000000000000000000000000",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-2",
+        Less,
+    );
+    test(
+        "3000000000000000000000000",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
     test(
         "3000000000000000000000001",
         "-2000000000000000000000000",
@@ -527,12 +765,28 @@ fn test_div_round() {
         "-2",
         Less,
     );
+    test(
+        "3000000000000000000000001",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-2",
+        Less,
+    );
+    test(
+        "3000000000000000000000001",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
 
     test("-1", "1", Down, "-1", Equal);
     test("-1", "1", Floor, "-1", Equal);
     test("-1", "1", Up, "-1", Equal);
     test("-1", "1", Ceiling, "-1", Equal);
     test("-1", "1", Nearest, "-1", Equal);
+    test("-1", "1", NearestTiesAway, "-1", Equal);
+    test("-1", "1", ToOdd, "-1", Equal);
     test("-1", "1", Exact, "-1", Equal);
 
     test("-123", "1", Down, "-123", Equal);
@@ -540,6 +794,8 @@ fn test_div_round() {
     test("-123", "1", Up, "-123", Equal);
     test("-123", "1", Ceiling, "-123", Equal);
     test("-123", "1", Nearest, "-123", Equal);
+    test("-123", "1", NearestTiesAway, "-123", Equal);
+    test("-123", "1", ToOdd, "-123", Equal);
     test("-123", "1", Exact, "-123", Equal);
 
     test("-123", "2", Down, "-61", Greater);
@@ -547,18 +803,24 @@ fn test_div_round() {
     test("-123", "2", Up, "-62", Less);
     test("-123", "2", Ceiling, "-61", Greater);
     test("-123", "2", Nearest, "-62", Less);
+    test("-123", "2", NearestTiesAway, "-62", Less);
+    test("-123", "2", ToOdd, "-61", Greater);
 
     test("-125", "2", Down, "-62", Greater);
     test("-125", "2", Floor, "-63", Less);
     test("-125", "2", Up, "-63", Less);
     test("-125", "2", Ceiling, "-62", Greater);
     test("-125", "2", Nearest, "-62", Greater);
+    test("-125", "2", NearestTiesAway, "-63", Less);
+    test("-125", "2", ToOdd, "-63", Less);
 
     test("-123", "123", Down, "-1", Equal);
     test("-123", "123", Floor, "-1", Equal);
     test("-123", "123", Up, "-1", Equal);
     test("-123", "123", Ceiling, "-1", Equal);
     test("-123", "123", Nearest, "-1", Equal);
+    test("-123", "123", NearestTiesAway, "-1", Equal);
+    test("-123", "123", ToOdd, "-1", Equal);
     test("-123", "123", Exact, "-1", Equal);
 
     test("-123", "456", Down, "0", Greater);
@@ -566,12 +828,22 @@ fn test_div_round() {
     test("-123", "456", Up, "-1", Less);
     test("-123", "456", Ceiling, "0", Greater);
     test("-123", "456", Nearest, "0", Greater);
+    test("-123", "456", NearestTiesAway, "0", Greater);
+    test("-123", "456", ToOdd, "-1", Less);
 
     test("-1000000000000", "1", Down, "-1000000000000", Equal);
     test("-1000000000000", "1", Floor, "-1000000000000", Equal);
     test("-1000000000000", "1", Up, "-1000000000000", Equal);
     test("-1000000000000", "1", Ceiling, "-1000000000000", Equal);
     test("-1000000000000", "1", Nearest, "-1000000000000", Equal);
+    test(
+        "-1000000000000",
+        "1",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("-1000000000000", "1", ToOdd, "-1000000000000", Equal);
     test("-1000000000000", "1", Exact, "-1000000000000", Equal);
 
     test("-1000000000000", "3", Down, "-333333333333", Greater);
@@ -579,18 +851,36 @@ fn test_div_round() {
     test("-1000000000000", "3", Up, "-333333333334", Less);
     test("-1000000000000", "3", Ceiling, "-333333333333", Greater);
     test("-1000000000000", "3", Nearest, "-333333333333", Greater);
+    test(
+        "-1000000000000",
+        "3",
+        NearestTiesAway,
+        "-333333333333",
+        Greater,
+    );
+    test("-1000000000000", "3", ToOdd, "-333333333333", Greater);
 
     test("-999999999999", "2", Down, "-499999999999", Greater);
     test("-999999999999", "2", Floor, "-500000000000", Less);
     test("-999999999999", "2", Up, "-500000000000", Less);
     test("-999999999999", "2", Ceiling, "-499999999999", Greater);
     test("-999999999999", "2", Nearest, "-500000000000", Less);
+    test("-999999999999", "2", NearestTiesAway, "-500000000000", Less);
+    test("-999999999999", "2", ToOdd, "-499999999999", Greater);
 
     test("-1000000000001", "2", Down, "-500000000000", Greater);
     test("-1000000000001", "2", Floor, "-500000000001", Less);
     test("-1000000000001", "2", Up, "-500000000001", Less);
     test("-1000000000001", "2", Ceiling, "-500000000000", Greater);
     test("-1000000000001", "2", Nearest, "-500000000000", Greater);
+    test(
+        "-1000000000001",
+        "2",
+        NearestTiesAway,
+        "-500000000001",
+        Less,
+    );
+    test("-1000000000001", "2", ToOdd, "-500000000001", Less);
 
     test(
         "-1000000000000000000000000",
@@ -627,6 +917,20 @@ fn test_div_round() {
         "-232830643708080",
         Less,
     );
+    test(
+        "-1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "-232830643708080",
+        Less,
+    );
+    test(
+        "-1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "-232830643708079",
+        Greater,
+    );
 
     test(
         "-1000000000000000000000000",
@@ -663,6 +967,20 @@ fn test_div_round() {
         "-1000000000000",
         Equal,
     );
+    test(
+        "-1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test(
+        "-1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "-1000000000000",
+        Equal,
+    );
     test(
         "-1000000000000000000000000",
         "1000000000000",
@@ -706,6 +1024,20 @@ fn test_div_round() {
         "-999999999999",
         Greater,
     );
+    test(
+        "-1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "-999999999999",
+        Greater,
+    );
+    test(
+        "-1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "-999999999999",
+        Greater,
+    );
 
     test(
         "-2999999999999999999999999",
@@ -714,6 +1046,20 @@ fn test_div_round() {
         "-1",
         Greater,
     );
+    test(
+        "-2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-1",
+        Greater,
+    );
+    test(
+        "-2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
     test(
         "-3000000000000000000000000",
         "2000000000000000000000000",
@@ -721,6 +1067,20 @@ fn test_div_round() {
         "-2",
         Less,
     );
+    test(
+        "-3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-2",
+        Less,
+    );
+    test(
+        "-3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
     test(
         "-3000000000000000000000001",
         "2000000000000000000000000",
@@ -728,12 +1088,28 @@ fn test_div_round() {
         "-2",
         Less,
     );
+    test(
+        "-3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-2",
+        Less,
+    );
+    test(
+        "-3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "-1",
+        Greater,
+    );
 
     test("-1", "-1", Down, "1", Equal);
     test("-1", "-1", Floor, "1", Equal);
     test("-1", "-1", Up, "1", Equal);
     test("-1", "-1", Ceiling, "1", Equal);
     test("-1", "-1", Nearest, "1", Equal);
+    test("-1", "-1", NearestTiesAway, "1", Equal);
+    test("-1", "-1", ToOdd, "1", Equal);
     test("-1", "-1", Exact, "1", Equal);
 
     test("-123", "-1", Down, "123", Equal);
@@ -741,6 +1117,8 @@ fn test_div_round() {
     test("-123", "-1", Up, "123", Equal);
     test("-123", "-1", Ceiling, "123", Equal);
     test("-123", "-1", Nearest, "123", Equal);
+    test("-123", "-1", NearestTiesAway, "123", Equal);
+    test("-123", "-1", ToOdd, "123", Equal);
     test("-123", "-1", Exact, "123", Equal);
 
     test("-123", "-2", Down, "61", Less);
@@ -748,18 +1126,24 @@ fn test_div_round() {
     test("-123", "-2", Up, "62", Greater);
     test("-123", "-2", Ceiling, "62", Greater);
     test("-123", "-2", Nearest, "62", Greater);
+    test("-123", "-2", NearestTiesAway, "62", Greater);
+    test("-123", "-2", ToOdd, "61", Less);
 
     test("-125", "-2", Down, "62", Less);
     test("-125", "-2", Floor, "62", Less);
     test("-125", "-2", Up, "63", Greater);
     test("-125", "-2", Ceiling, "63", Greater);
     test("-125", "-2", Nearest, "62", Less);
+    test("-125", "-2", NearestTiesAway, "63", Greater);
+    test("-125", "-2", ToOdd, "63", Greater);
 
     test("-123", "-123", Down, "1", Equal);
     test("-123", "-123", Floor, "1", Equal);
     test("-123", "-123", Up, "1", Equal);
     test("-123", "-123", Ceiling, "1", Equal);
     test("-123", "-123", Nearest, "1", Equal);
+    test("-123", "-123", NearestTiesAway, "1", Equal);
+    test("-123", "-123", ToOdd, "1", Equal);
     test("-123", "-123", Exact, "1", Equal);
 
     test("-123", "-456", Down, "0", Less);
@@ -767,12 +1151,22 @@ fn test_div_round() {
     test("-123", "-456", Up, "1", Greater);
     test("-123", "-456", Ceiling, "1", Greater);
     test("-123", "-456", Nearest, "0", Less);
+    test("-123", "-456", NearestTiesAway, "0", Less);
+    test("-123", "-456", ToOdd, "1", Greater);
 
     test("-1000000000000", "-1", Down, "1000000000000", Equal);
     test("-1000000000000", "-1", Floor, "1000000000000", Equal);
     test("-1000000000000", "-1", Up, "1000000000000", Equal);
     test("-1000000000000", "-1", Ceiling, "1000000000000", Equal);
     test("-1000000000000", "-1", Nearest, "1000000000000", Equal);
+    test(
+        "-1000000000000",
+        "-1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("-1000000000000", "-1", ToOdd, "1000000000000", Equal);
     test("-1000000000000", "-1", Exact, "1000000000000", Equal);
 
     test("-1000000000000", "-3", Down, "333333333333", Less);
@@ -780,18 +1174,42 @@ fn test_div_round() {
     test("-1000000000000", "-3", Up, "333333333334", Greater);
     test("-1000000000000", "-3", Ceiling, "333333333334", Greater);
     test("-1000000000000", "-3", Nearest, "333333333333", Less);
+    test(
+        "-1000000000000",
+        "-3",
+        NearestTiesAway,
+        "333333333333",
+        Less,
+    );
+    test("-1000000000000", "-3", ToOdd, "333333333333", Less);
 
     test("-999999999999", "-2", Down, "499999999999", Less);
     test("-999999999999", "-2", Floor, "499999999999", Less);
     test("-999999999999", "-2", Up, "500000000000", Greater);
     test("-999999999999", "-2", Ceiling, "500000000000", Greater);
     test("-999999999999", "-2", Nearest, "500000000000", Greater);
+    test(
+        "-999999999999",
+        "-2",
+        NearestTiesAway,
+        "500000000000",
+        Greater,
+    );
+    test("-999999999999", "-2", ToOdd, "499999999999", Less);
 
     test("-1000000000001", "-2", Down, "500000000000", Less);
     test("-1000000000001", "-2", Floor, "500000000000", Less);
     test("-1000000000001", "-2", Up, "500000000001", Greater);
     test("-1000000000001", "-2", Ceiling, "500000000001", Greater);
     test("-1000000000001", "-2", Nearest, "500000000000", Less);
+    test(
+        "-1000000000001",
+        "-2",
+        NearestTiesAway,
+        "500000000001",
+        Greater,
+    );
+    test("-1000000000001", "-2", ToOdd, "500000000001", Greater);
 
     test(
         "-1000000000000000000000000",
@@ -828,6 +1246,20 @@ fn test_div_round() {
         "232830643708080",
         Greater,
     );
+    test(
+        "-1000000000000000000000000",
+        "-4294967295",
+        NearestTiesAway,
+        "232830643708080",
+        Greater,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-4294967295",
+        ToOdd,
+        "232830643708079",
+        Less,
+    );
 
     test(
         "-1000000000000000000000000",
@@ -864,6 +1296,20 @@ fn test_div_round() {
         "1000000000000",
         Equal,
     );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000000",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000000",
+        ToOdd,
+        "1000000000000",
+        Equal,
+    );
     test(
         "-1000000000000000000000000",
         "-1000000000000",
@@ -907,6 +1353,20 @@ fn test_div_round() {
         "999999999999",
         Less,
     );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000001",
+        NearestTiesAway,
+        "999999999999",
+        Less,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000001",
+        ToOdd,
+        "999999999999",
+        Less,
+    );
 
     test(
         "-2999999999999999999999999",
@@ -915,6 +1375,20 @@ fn test_div_round() {
         "1",
         Less,
     );
+    test(
+        "-2999999999999999999999999",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "1",
+        Less,
+    );
+    test(
+        "-2999999999999999999999999",
+        "-2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "-3000000000000000000000000",
         "-2000000000000000000000000",
@@ -922,6 +1396,20 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "-3000000000000000000000000",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "-3000000000000000000000000",
+        "-2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "-3000000000000000000000001",
         "-2000000000000000000000000",
@@ -929,6 +1417,20 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "-3000000000000000000000001",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "-3000000000000000000000001",
+        "-2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
 }
 
 #[test]
@@ -1076,6 +1578,8 @@ fn div_round_properties() {
         assert_eq!((&left_multiplied).div_round(&y, Floor), xo);
         assert_eq!((&left_multiplied).div_round(&y, Ceiling), xo);
         assert_eq!((&left_multiplied).div_round(&y, Nearest), xo);
+        assert_eq!((&left_multiplied).div_round(&y, NearestTiesAway), xo);
+        assert_eq!((&left_multiplied).div_round(&y, ToOdd), xo);
         assert_eq!((&left_multiplied).div_round(&y, Exact), xo);
 
         assert_eq!(
@@ -1107,6 +1611,10 @@ fn div_round_properties() {
         let ceiling = (&floor.0 + Integer::ONE, Greater);
         assert_eq!((&x).div_round(&y, Up), up);
         assert_eq!((&x).div_round(&y, Ceiling), ceiling);
+        let nearest_ties_away = (&x).div_round(&y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&x).div_round(&y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = x.div_round(y, Nearest);
         assert!(nearest == down || nearest == up);
     });
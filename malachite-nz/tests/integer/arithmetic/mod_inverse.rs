@@ -0,0 +1,100 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{ExtendedGcd, Mod, ModInverse, ModIsReduced, ModMul};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen_var_1;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_inverse() {
+    let test = |s, t, out: Option<&str>| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let n = u.clone().mod_inverse(m.clone());
+        assert!(n.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(n.as_ref().map(Natural::to_string).as_deref(), out);
+
+        let n = u.clone().mod_inverse(&m);
+        assert!(n.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(n.as_ref().map(Natural::to_string).as_deref(), out);
+
+        let n = (&u).mod_inverse(m.clone());
+        assert!(n.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(n.as_ref().map(Natural::to_string).as_deref(), out);
+
+        let n = (&u).mod_inverse(&m);
+        assert!(n.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(n.as_ref().map(Natural::to_string).as_deref(), out);
+    };
+    test("0", "1", Some("0"));
+    test("-5", "1", Some("0"));
+    test("0", "5", None);
+    test("-10", "5", None);
+    test("3", "10", Some("7"));
+    test("-3", "10", Some("3"));
+    test("13", "10", Some("7"));
+    test("-4", "10", None);
+    test("-1", "4294967296", Some("4294967295"));
+    test("-2", "4294967297", Some("2147483648"));
+    test(
+        "-1000000000000000000000000",
+        "1000000000000000000000001",
+        Some("1"),
+    );
+}
+
+#[test]
+fn mod_inverse_fail() {
+    assert_panic!(Integer::from(-1).mod_inverse(Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_inverse(&Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_inverse(Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_inverse(&Natural::ZERO));
+}
+
+#[test]
+fn mod_inverse_properties() {
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let inverse_val_val = x.clone().mod_inverse(m.clone());
+        let inverse_val_ref = x.clone().mod_inverse(&m);
+        let inverse_ref_val = (&x).mod_inverse(m.clone());
+        let inverse = (&x).mod_inverse(&m);
+        assert!(inverse.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(inverse_val_val, inverse);
+        assert_eq!(inverse_val_ref, inverse);
+        assert_eq!(inverse_ref_val, inverse);
+
+        let m_int = Integer::from(&m);
+        let (gcd, _, _) = (&x).extended_gcd(&m_int);
+        assert_eq!(inverse.is_some(), gcd == 1u32);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        if let Some(inverse) = &inverse {
+            assert!(inverse.mod_is_reduced(&m));
+            assert_eq!(
+                (&x).mod_mul(Integer::from(inverse), &m),
+                Natural::ONE.mod_op(&m)
+            );
+            if x_mod != 0u32 {
+                assert_eq!(x_mod.mod_inverse(&m), Some(inverse.clone()));
+            }
+        }
+        if m != 1u32 {
+            assert_eq!(
+                rug::Integer::from(&x)
+                    .invert(&rug::Integer::from(&m))
+                    .ok()
+                    .map(|n| Natural::exact_from(&n)),
+                inverse
+            );
+        }
+    });
+}
@@ -0,0 +1,197 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModIsReduced, ModMul, ModMulAssign, ModNeg, ModSquare,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen_var_3, integer_natural_pair_gen_var_1,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_mul() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_mul_assign(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_mul_assign(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_mul_assign(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_mul_assign(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_mul(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_mul(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_mul(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_mul(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_mul(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_mul(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_mul(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_mul(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "7", "1", "0");
+    test("2", "3", "7", "6");
+    test("-3", "4", "10", "8");
+    test("3", "-4", "10", "8");
+    test("-3", "-4", "10", "2");
+    test("-123", "-456", "100", "88");
+    test("-10", "7", "10", "0");
+    test("-1", "-1", "4294967296", "1");
+    test("-1", "1", "4294967296", "4294967295");
+    test("-1000000", "1000000", "1000000000001", "1");
+    test(
+        "-1000000000000000000000000",
+        "-1000000000000000000000000",
+        "1000000000000000000000001",
+        "1",
+    );
+}
+
+#[test]
+fn mod_mul_fail() {
+    assert_panic!(Integer::from(-1).mod_mul(Integer::from(1), Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_mul(Integer::from(1), &Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_mul(&Integer::from(1), Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_mul(&Integer::from(1), &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_mul(Integer::from(1), Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_mul(Integer::from(1), &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_mul(&Integer::from(1), Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_mul(&Integer::from(1), &Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_mul_assign(Integer::from(1), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_mul_assign(Integer::from(1), &Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_mul_assign(&Integer::from(1), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_mul_assign(&Integer::from(1), &Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_mul_properties() {
+    integer_integer_natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        let product_val_val_val = x.clone().mod_mul(y.clone(), m.clone());
+        let product_val_val_ref = x.clone().mod_mul(y.clone(), &m);
+        let product_val_ref_val = x.clone().mod_mul(&y, m.clone());
+        let product_val_ref_ref = x.clone().mod_mul(&y, &m);
+        let product_ref_val_val = (&x).mod_mul(y.clone(), m.clone());
+        let product_ref_val_ref = (&x).mod_mul(y.clone(), &m);
+        let product_ref_ref_val = (&x).mod_mul(&y, m.clone());
+        let product = (&x).mod_mul(&y, &m);
+        assert!(product_val_val_val.is_valid());
+        assert!(product_val_val_ref.is_valid());
+        assert!(product_val_ref_val.is_valid());
+        assert!(product_val_ref_ref.is_valid());
+        assert!(product_ref_val_val.is_valid());
+        assert!(product_ref_val_ref.is_valid());
+        assert!(product_ref_ref_val.is_valid());
+        assert!(product.is_valid());
+        assert!(product.mod_is_reduced(&m));
+        assert_eq!(product_val_val_val, product);
+        assert_eq!(product_val_val_ref, product);
+        assert_eq!(product_val_ref_val, product);
+        assert_eq!(product_val_ref_ref, product);
+        assert_eq!(product_ref_val_val, product);
+        assert_eq!(product_ref_val_ref, product);
+        assert_eq!(product_ref_ref_val, product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(y.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(y.clone(), &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(&y, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_mul_assign(&y, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        let m_int = Integer::from(&m);
+        assert_eq!((&x * &y).mod_op(&m_int), product);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        let y_mod = Natural::exact_from((&y).mod_op(&m_int));
+        assert_eq!((&x_mod).mod_mul(&y_mod, &m), product);
+        assert_eq!((&y).mod_mul(&x, &m), product);
+        assert_eq!((-&x).mod_mul(&y, &m), (&product).mod_neg(&m));
+        assert_eq!((-&x).mod_mul(-&y, &m), product);
+    });
+
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let x_mod = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!((&x).mod_mul(Integer::ZERO, &m), 0);
+        assert_eq!(Integer::ZERO.mod_mul(&x, &m), 0);
+        assert_eq!((&x).mod_mul(Integer::ONE, &m), x_mod);
+        assert_eq!(Integer::ONE.mod_mul(&x, &m), x_mod);
+        assert_eq!((&x).mod_mul(&x, &m), (&x).mod_square(&m));
+        assert_eq!((&x).mod_mul(Integer::NEGATIVE_ONE, &m), x.mod_neg(&m));
+    });
+}
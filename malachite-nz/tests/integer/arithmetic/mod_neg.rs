@@ -0,0 +1,115 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModIsReduced, ModNeg, ModNegAssign};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen_var_1;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_neg() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_neg_assign(m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_neg_assign(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_neg(m.clone());
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_neg(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_neg(m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_neg(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("0", "5", "0");
+    test("7", "10", "3");
+    test("-7", "10", "7");
+    test("123", "100", "77");
+    test("-123", "100", "23");
+    test("-100", "10", "0");
+    test("1", "4294967296", "4294967295");
+    test("-4294967297", "4294967296", "1");
+    test("1000000000000000000000001", "1000000000000", "999999999999");
+    test("-1000000000000000000000001", "1000000000000", "1");
+}
+
+#[test]
+fn mod_neg_fail() {
+    assert_panic!(Integer::from(-1).mod_neg(Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_neg(&Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_neg(Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_neg(&Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_neg_assign(Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_neg_assign(&Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_neg_properties() {
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let neg_val_val = x.clone().mod_neg(m.clone());
+        let neg_val_ref = x.clone().mod_neg(&m);
+        let neg_ref_val = (&x).mod_neg(m.clone());
+        let neg = (&x).mod_neg(&m);
+        assert!(neg_val_val.is_valid());
+        assert!(neg_val_ref.is_valid());
+        assert!(neg_ref_val.is_valid());
+        assert!(neg.is_valid());
+        assert!(neg.mod_is_reduced(&m));
+        assert_eq!(neg_val_val, neg);
+        assert_eq!(neg_val_ref, neg);
+        assert_eq!(neg_ref_val, neg);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_neg_assign(m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_neg_assign(&m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        let m_int = Integer::from(&m);
+        assert_eq!((-&x).mod_op(&m_int), neg);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        assert_eq!((&x_mod).mod_neg(&m), neg);
+        assert_eq!(Integer::from(&neg).mod_neg(&m), x_mod);
+        assert_eq!((&x).mod_add(Integer::from(&neg), &m), 0);
+        assert_eq!((-x).mod_neg(&m), x_mod);
+    });
+}
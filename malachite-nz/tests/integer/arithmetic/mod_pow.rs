@@ -0,0 +1,264 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModInverse, ModIsReduced, ModMul, ModNeg, ModPow, ModPowAssign, ModSquare, Parity,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen_var_3, integer_integer_natural_triple_gen_var_4,
+    integer_natural_pair_gen_var_1,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let exp = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_pow_assign(exp.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_pow_assign(exp.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_pow_assign(&exp, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_pow_assign(&exp, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_pow(exp.clone(), m.clone());
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_pow(exp.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_pow(&exp, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_pow(&exp, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_pow(exp.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_pow(exp.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_pow(&exp, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_pow(&exp, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "3", "1", "0");
+    test("-5", "-3", "1", "0");
+    test("0", "0", "5", "1");
+    test("0", "3", "5", "0");
+    test("-3", "0", "10", "1");
+    test("4", "13", "497", "445");
+    test("-4", "13", "497", "52");
+    test("3", "-1", "10", "7");
+    test("-2", "-3", "11", "4");
+    test("-1", "-1", "4294967296", "4294967295");
+    test("2", "-1", "4294967297", "2147483649");
+    test("-2", "-100", "1000000007", "979278573");
+    test("3", "-5", "18446744073709551616", "7211690069968754747");
+    test(
+        "-123456789",
+        "987654321",
+        "1000000000000000000000007",
+        "732979085920462362971570",
+    );
+}
+
+#[test]
+fn mod_pow_fail() {
+    assert_panic!(Integer::from(-1).mod_pow(Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_pow(Integer::ONE, &Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_pow(&Integer::ONE, Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_pow(&Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_pow(Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_pow(Integer::ONE, &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_pow(&Integer::ONE, Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_pow(&Integer::ONE, &Natural::ZERO));
+
+    assert_panic!(Integer::from(-4).mod_pow(Integer::from(-1), Natural::from(10u32)));
+    assert_panic!(Integer::from(-4).mod_pow(Integer::from(-1), &Natural::from(10u32)));
+    assert_panic!(Integer::from(-4).mod_pow(&Integer::from(-1), Natural::from(10u32)));
+    assert_panic!(Integer::from(-4).mod_pow(&Integer::from(-1), &Natural::from(10u32)));
+    assert_panic!((&Integer::from(-4)).mod_pow(Integer::from(-1), Natural::from(10u32)));
+    assert_panic!((&Integer::from(-4)).mod_pow(Integer::from(-1), &Natural::from(10u32)));
+    assert_panic!((&Integer::from(-4)).mod_pow(&Integer::from(-1), Natural::from(10u32)));
+    assert_panic!((&Integer::from(-4)).mod_pow(&Integer::from(-1), &Natural::from(10u32)));
+
+    assert_panic!(Integer::ZERO.mod_pow(Integer::from(-3), Natural::from(5u32)));
+
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_pow_assign(Integer::ONE, Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_pow_assign(Integer::ONE, &Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_pow_assign(&Integer::ONE, Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_pow_assign(&Integer::ONE, &Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_pow_assign(Integer::from(-1), Natural::from(10u32));
+    });
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_pow_assign(Integer::from(-1), &Natural::from(10u32));
+    });
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_pow_assign(&Integer::from(-1), Natural::from(10u32));
+    });
+    assert_panic!({
+        let mut x = Integer::from(-4);
+        x.mod_pow_assign(&Integer::from(-1), &Natural::from(10u32));
+    });
+    assert_panic!({
+        let mut x = Integer::ZERO;
+        x.mod_pow_assign(Integer::from(-3), Natural::from(5u32));
+    });
+}
+
+#[test]
+fn mod_pow_properties() {
+    integer_integer_natural_triple_gen_var_4().test_properties(|(x, exp, m)| {
+        let x_mod = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        let power_val_val_val = x.clone().mod_pow(exp.clone(), m.clone());
+        let power_val_val_ref = x.clone().mod_pow(exp.clone(), &m);
+        let power_val_ref_val = x.clone().mod_pow(&exp, m.clone());
+        let power_val_ref_ref = x.clone().mod_pow(&exp, &m);
+        let power_ref_val_val = (&x).mod_pow(exp.clone(), m.clone());
+        let power_ref_val_ref = (&x).mod_pow(exp.clone(), &m);
+        let power_ref_ref_val = (&x).mod_pow(&exp, m.clone());
+        let power = (&x).mod_pow(&exp, &m);
+        assert!(power_val_val_val.is_valid());
+        assert!(power_val_val_ref.is_valid());
+        assert!(power_val_ref_val.is_valid());
+        assert!(power_val_ref_ref.is_valid());
+        assert!(power_ref_val_val.is_valid());
+        assert!(power_ref_val_ref.is_valid());
+        assert!(power_ref_ref_val.is_valid());
+        assert!(power.is_valid());
+        assert!(power.mod_is_reduced(&m));
+        assert_eq!(power_val_val_val, power);
+        assert_eq!(power_val_val_ref, power);
+        assert_eq!(power_val_ref_val, power);
+        assert_eq!(power_val_ref_ref, power);
+        assert_eq!(power_ref_val_val, power);
+        assert_eq!(power_ref_val_ref, power);
+        assert_eq!(power_ref_ref_val, power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(exp.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(exp.clone(), &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(&exp, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_pow_assign(&exp, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, power);
+
+        if exp >= 0u32 {
+            assert_eq!((&x_mod).mod_pow(exp.unsigned_abs_ref(), &m), power);
+        } else {
+            assert_eq!(
+                (&x).mod_inverse(&m)
+                    .unwrap()
+                    .mod_pow(exp.unsigned_abs_ref(), &m),
+                power
+            );
+            assert_eq!(
+                Integer::from((&x).mod_pow(-&exp, &m)).mod_inverse(&m),
+                Some(power.clone())
+            );
+        }
+        if m != 1u32 {
+            assert_eq!(
+                Natural::exact_from(
+                    &rug::Integer::from(&x)
+                        .pow_mod(&rug::Integer::from(&exp), &rug::Integer::from(&m))
+                        .unwrap()
+                ),
+                power
+            );
+        }
+        let neg_power = if exp.even() {
+            power.clone()
+        } else {
+            (&power).mod_neg(&m)
+        };
+        assert_eq!((-&x).mod_pow(&exp, &m), neg_power);
+    });
+
+    integer_integer_natural_triple_gen_var_3().test_properties(|(x, exp, m)| {
+        if exp < 0u32 && (&x).mod_inverse(&m).is_none() {
+            assert_panic!((&x).mod_pow(&exp, &m));
+        }
+    });
+
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let x_mod = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!((&x).mod_pow(Integer::ZERO, &m), Natural::ONE.mod_op(&m));
+        assert_eq!((&x).mod_pow(Integer::ONE, &m), x_mod);
+        assert_eq!((&x).mod_pow(Integer::TWO, &m), (&x).mod_square(&m));
+        if let Some(inverse) = (&x).mod_inverse(&m) {
+            assert_eq!((&x).mod_pow(Integer::NEGATIVE_ONE, &m), inverse);
+            assert_eq!(
+                (&x).mod_pow(Integer::from(-2), &m),
+                (&inverse).mod_mul(&inverse, &m)
+            );
+        }
+    });
+}
@@ -0,0 +1,253 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModIsReduced, ModNeg, ModShl, ModShlAssign, ModShr,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_natural_signed_triple_gen_var_1, integer_natural_unsigned_triple_gen_var_1,
+};
+use std::ops::Shl;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+macro_rules! test_mod_shl_unsigned {
+    ($t:ident) => {
+        let test = |s, v: $t, t, out| {
+            let u = Integer::from_str(s).unwrap();
+            let m = Natural::from_str(t).unwrap();
+
+            let mut n = u.clone();
+            n.mod_shl_assign(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let mut n = u.clone();
+            n.mod_shl_assign(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shl(v, m.clone());
+            assert!(n.is_valid());
+            assert!(n.mod_is_reduced(&m));
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shl(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shl(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shl(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            assert_eq!((u << v).mod_op(Integer::from(m)).to_string(), out);
+        };
+        test("0", 0, "1", "0");
+        test("-5", 0, "1", "0");
+        test("0", 0, "5", "0");
+        test("8", 2, "10", "2");
+        test("-8", 2, "10", "8");
+        test("-10", 100, "17", "10");
+        test("-123456", 100, "12345678987654321", "4909015422739176");
+    };
+}
+
+macro_rules! test_mod_shl_signed {
+    ($t:ident) => {
+        let test = |s, v: $t, t, out| {
+            let u = Integer::from_str(s).unwrap();
+            let m = Natural::from_str(t).unwrap();
+
+            let mut n = u.clone();
+            n.mod_shl_assign(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let mut n = u.clone();
+            n.mod_shl_assign(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shl(v, m.clone());
+            assert!(n.is_valid());
+            assert!(n.mod_is_reduced(&m));
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shl(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shl(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shl(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let u_mod = Natural::exact_from(u.mod_op(Integer::from(&m)));
+            assert_eq!(((u_mod << v) % m).to_string(), out);
+        };
+        test("0", 0, "1", "0");
+        test("-5", 0, "1", "0");
+        test("0", 0, "5", "0");
+        test("8", 2, "10", "2");
+        test("-8", 2, "10", "8");
+        test("-3", -1, "10", "3");
+        test("-10", 100, "17", "10");
+        test("-10", -100, "19", "0");
+        test("-123456", 100, "12345678987654321", "4909015422739176");
+    };
+}
+
+#[test]
+fn test_mod_shl() {
+    apply_to_unsigneds!(test_mod_shl_unsigned);
+    apply_to_signeds!(test_mod_shl_signed);
+}
+
+fn mod_shl_fail_helper<T: PrimitiveInt>()
+where
+    for<'a> Integer: ModShlAssign<T, Natural>
+        + ModShlAssign<T, &'a Natural>
+        + ModShl<T, Natural, Output = Natural>
+        + ModShl<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShl<T, Natural, Output = Natural> + ModShl<T, &'b Natural, Output = Natural>,
+{
+    assert_panic!(Integer::from(-30).mod_shl(T::exact_from(3u8), Natural::ZERO));
+    assert_panic!(Integer::from(-30).mod_shl(T::exact_from(3u8), &Natural::ZERO));
+    assert_panic!((&Integer::from(-30)).mod_shl(T::exact_from(3u8), Natural::ZERO));
+    assert_panic!((&Integer::from(-30)).mod_shl(T::exact_from(3u8), &Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-30);
+        x.mod_shl_assign(T::exact_from(3u8), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-30);
+        x.mod_shl_assign(T::exact_from(3u8), &Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_shl_fail() {
+    apply_fn_to_primitive_ints!(mod_shl_fail_helper);
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn unsigned_properties<T: PrimitiveUnsigned>()
+where
+    for<'a> Integer: ModShlAssign<T, Natural>
+        + ModShlAssign<T, &'a Natural>
+        + ModShl<T, Natural, Output = Natural>
+        + ModShl<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer: ModShl<T, Natural, Output = Natural>
+        + ModShl<T, &'b Natural, Output = Natural>
+        + Shl<T, Output = Integer>,
+    for<'a> Natural: ModShl<T, &'a Natural, Output = Natural>,
+{
+    integer_natural_unsigned_triple_gen_var_1::<T>().test_properties(|(x, m, u)| {
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(u, &m);
+        assert!(mut_x.is_valid());
+        let shifted = Natural::exact_from(mut_x);
+        assert!(shifted.mod_is_reduced(&m));
+
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(u, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, shifted);
+
+        let shifted_alt = (&x).mod_shl(u, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = (&x).mod_shl(u, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shl(u, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shl(u, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+
+        let m_int = Integer::from(&m);
+        assert_eq!((&x << u).mod_op(&m_int), shifted);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        assert_eq!(x_mod.mod_shl(u, &m), shifted);
+        assert_eq!((-x).mod_shl(u, &m), shifted.mod_neg(m));
+    });
+}
+
+#[allow(clippy::trait_duplication_in_bounds)]
+fn signed_properties<T: PrimitiveSigned>()
+where
+    for<'a> Integer: ModShlAssign<T, Natural>
+        + ModShlAssign<T, &'a Natural>
+        + ModShl<T, Natural, Output = Natural>
+        + ModShl<T, &'a Natural, Output = Natural>
+        + ModShr<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer: ModShl<T, Natural, Output = Natural>
+        + ModShl<T, &'b Natural, Output = Natural>
+        + Shl<T, Output = Integer>,
+    for<'a> Natural: ModShl<T, &'a Natural, Output = Natural>,
+{
+    integer_natural_signed_triple_gen_var_1::<T>().test_properties(|(x, m, i)| {
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(i, &m);
+        assert!(mut_x.is_valid());
+        let shifted = Natural::exact_from(mut_x);
+        assert!(shifted.mod_is_reduced(&m));
+
+        let mut mut_x = x.clone();
+        mut_x.mod_shl_assign(i, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, shifted);
+
+        let shifted_alt = (&x).mod_shl(i, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = (&x).mod_shl(i, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shl(i, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shl(i, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+
+        let m_int = Integer::from(&m);
+        if i >= T::ZERO {
+            assert_eq!((&x << i).mod_op(&m_int), shifted);
+        }
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        assert_eq!(x_mod.mod_shl(i, &m), shifted);
+        if i != T::MIN {
+            assert_eq!(x.mod_shr(-i, &m), shifted);
+        }
+    });
+}
+
+#[test]
+fn mod_shl_properties() {
+    apply_fn_to_unsigneds!(unsigned_properties);
+    apply_fn_to_signeds!(signed_properties);
+}
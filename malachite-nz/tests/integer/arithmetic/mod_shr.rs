@@ -0,0 +1,148 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Mod, ModIsReduced, ModShl, ModShr, ModShrAssign};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_signed_triple_gen_var_1;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+macro_rules! test_mod_shr_signed {
+    ($t:ident) => {
+        let test = |s, v: $t, t, out| {
+            let u = Integer::from_str(s).unwrap();
+            let m = Natural::from_str(t).unwrap();
+
+            let mut n = u.clone();
+            n.mod_shr_assign(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let mut n = u.clone();
+            n.mod_shr_assign(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shr(v, m.clone());
+            assert!(n.is_valid());
+            assert!(n.mod_is_reduced(&m));
+            assert_eq!(n.to_string(), out);
+
+            let n = u.clone().mod_shr(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shr(v, m.clone());
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let n = (&u).mod_shr(v, &m);
+            assert!(n.is_valid());
+            assert_eq!(n.to_string(), out);
+
+            let u_mod = Natural::exact_from(u.mod_op(Integer::from(&m)));
+            assert_eq!(((u_mod >> v) % m).to_string(), out);
+        };
+        test("0", 0, "1", "0");
+        test("-5", 0, "1", "0");
+        test("0", 0, "5", "0");
+        test("8", -2, "10", "2");
+        test("-8", -2, "10", "8");
+        test("-3", 1, "10", "3");
+        test("-10", -100, "17", "10");
+        test("-10", 100, "19", "0");
+        test("-123456", -100, "12345678987654321", "4909015422739176");
+    };
+}
+
+#[test]
+fn test_mod_shr() {
+    apply_to_signeds!(test_mod_shr_signed);
+}
+
+fn mod_shr_fail_helper<T: PrimitiveSigned>()
+where
+    for<'a> Integer: ModShrAssign<T, Natural>
+        + ModShrAssign<T, &'a Natural>
+        + ModShr<T, Natural, Output = Natural>
+        + ModShr<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShr<T, Natural, Output = Natural> + ModShr<T, &'b Natural, Output = Natural>,
+{
+    assert_panic!(Integer::from(-30).mod_shr(T::exact_from(3), Natural::ZERO));
+    assert_panic!(Integer::from(-30).mod_shr(T::exact_from(3), &Natural::ZERO));
+    assert_panic!((&Integer::from(-30)).mod_shr(T::exact_from(3), Natural::ZERO));
+    assert_panic!((&Integer::from(-30)).mod_shr(T::exact_from(3), &Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-30);
+        x.mod_shr_assign(T::exact_from(3), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-30);
+        x.mod_shr_assign(T::exact_from(3), &Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_shr_fail() {
+    apply_fn_to_signeds!(mod_shr_fail_helper);
+}
+
+fn properties_helper<T: PrimitiveSigned>()
+where
+    for<'a> Integer: ModShrAssign<T, Natural>
+        + ModShrAssign<T, &'a Natural>
+        + ModShr<T, Natural, Output = Natural>
+        + ModShr<T, &'a Natural, Output = Natural>
+        + ModShl<T, &'a Natural, Output = Natural>,
+    for<'a, 'b> &'a Integer:
+        ModShr<T, Natural, Output = Natural> + ModShr<T, &'b Natural, Output = Natural>,
+    for<'a> Natural: ModShr<T, &'a Natural, Output = Natural>,
+{
+    integer_natural_signed_triple_gen_var_1::<T>().test_properties(|(x, m, i)| {
+        let mut mut_x = x.clone();
+        mut_x.mod_shr_assign(i, &m);
+        assert!(mut_x.is_valid());
+        let shifted = Natural::exact_from(mut_x);
+        assert!(shifted.mod_is_reduced(&m));
+
+        let mut mut_x = x.clone();
+        mut_x.mod_shr_assign(i, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, shifted);
+
+        let shifted_alt = (&x).mod_shr(i, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = (&x).mod_shr(i, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shr(i, &m);
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+        let shifted_alt = x.clone().mod_shr(i, m.clone());
+        assert!(shifted_alt.is_valid());
+        assert_eq!(shifted_alt, shifted);
+
+        let x_mod = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!(x_mod.mod_shr(i, &m), shifted);
+        if i != T::MIN {
+            assert_eq!(x.mod_shl(-i, &m), shifted);
+        }
+    });
+}
+
+#[test]
+fn mod_shr_properties() {
+    apply_fn_to_signeds!(properties_helper);
+}
@@ -0,0 +1,127 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModIsReduced, ModMul, ModNeg, ModSquare, ModSquareAssign, Square,
+};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen_var_3, integer_natural_pair_gen_var_1,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_square() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_square_assign(m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_square_assign(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_square(m.clone());
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_square(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_square(m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_square(&m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("0", "5", "0");
+    test("7", "10", "9");
+    test("-7", "10", "9");
+    test("-123", "100", "29");
+    test("-1", "4294967296", "1");
+    test("-65536", "4294967297", "4294967296");
+    test(
+        "-1000000000000",
+        "1000000000000000000000001",
+        "1000000000000000000000000",
+    );
+}
+
+#[test]
+fn mod_square_fail() {
+    assert_panic!(Integer::from(-1).mod_square(Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_square(&Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_square(Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_square(&Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_square_assign(Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_square_assign(&Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_square_properties() {
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let square_val_val = x.clone().mod_square(m.clone());
+        let square_val_ref = x.clone().mod_square(&m);
+        let square_ref_val = (&x).mod_square(m.clone());
+        let square = (&x).mod_square(&m);
+        assert!(square_val_val.is_valid());
+        assert!(square_val_ref.is_valid());
+        assert!(square_ref_val.is_valid());
+        assert!(square.is_valid());
+        assert!(square.mod_is_reduced(&m));
+        assert_eq!(square_val_val, square);
+        assert_eq!(square_val_ref, square);
+        assert_eq!(square_ref_val, square);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_square_assign(m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, square);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_square_assign(&m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, square);
+
+        let m_int = Integer::from(&m);
+        assert_eq!((&x).square().mod_op(&m_int), square);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        assert_eq!((&x_mod).mod_square(&m), square);
+        assert_eq!((&x).mod_mul(&x, &m), square);
+        assert_eq!((-&x).mod_square(&m), square);
+        assert_eq!(Integer::from((&x).mod_neg(&m)).mod_square(&m), square);
+    });
+
+    integer_integer_natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        assert_eq!(
+            (&x * &y).mod_square(&m),
+            x.mod_square(&m).mod_mul(y.mod_square(&m), &m)
+        );
+    });
+}
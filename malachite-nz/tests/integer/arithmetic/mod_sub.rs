@@ -0,0 +1,195 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModAdd, ModIsReduced, ModNeg, ModSub, ModSubAssign,
+};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_integer_natural_triple_gen_var_3, integer_natural_pair_gen_var_1,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sub() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_sub_assign(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_sub_assign(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_sub_assign(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.mod_sub_assign(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_sub(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_sub(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_sub(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = u.clone().mod_sub(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_sub(v.clone(), m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_sub(v.clone(), &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_sub(&v, m.clone());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).mod_sub(&v, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("-5", "7", "1", "0");
+    test("0", "0", "5", "0");
+    test("-3", "5", "10", "2");
+    test("3", "-5", "10", "8");
+    test("-3", "-5", "10", "2");
+    test("123", "-456", "100", "79");
+    test("-10", "20", "10", "0");
+    test("0", "1", "4294967296", "4294967295");
+    test("-1", "1", "4294967296", "4294967294");
+    test("-1000000000000", "-1", "1000000000000", "1");
+    test(
+        "-1000000000000000000000000",
+        "1",
+        "1000000000000",
+        "999999999999",
+    );
+}
+
+#[test]
+fn mod_sub_fail() {
+    assert_panic!(Integer::from(-1).mod_sub(Integer::from(1), Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_sub(Integer::from(1), &Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_sub(&Integer::from(1), Natural::ZERO));
+    assert_panic!(Integer::from(-1).mod_sub(&Integer::from(1), &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_sub(Integer::from(1), Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_sub(Integer::from(1), &Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_sub(&Integer::from(1), Natural::ZERO));
+    assert_panic!((&Integer::from(-1)).mod_sub(&Integer::from(1), &Natural::ZERO));
+
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_sub_assign(Integer::from(1), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_sub_assign(Integer::from(1), &Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_sub_assign(&Integer::from(1), Natural::ZERO);
+    });
+    assert_panic!({
+        let mut x = Integer::from(-1);
+        x.mod_sub_assign(&Integer::from(1), &Natural::ZERO);
+    });
+}
+
+#[test]
+fn mod_sub_properties() {
+    integer_integer_natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        let diff_val_val_val = x.clone().mod_sub(y.clone(), m.clone());
+        let diff_val_val_ref = x.clone().mod_sub(y.clone(), &m);
+        let diff_val_ref_val = x.clone().mod_sub(&y, m.clone());
+        let diff_val_ref_ref = x.clone().mod_sub(&y, &m);
+        let diff_ref_val_val = (&x).mod_sub(y.clone(), m.clone());
+        let diff_ref_val_ref = (&x).mod_sub(y.clone(), &m);
+        let diff_ref_ref_val = (&x).mod_sub(&y, m.clone());
+        let diff = (&x).mod_sub(&y, &m);
+        assert!(diff_val_val_val.is_valid());
+        assert!(diff_val_val_ref.is_valid());
+        assert!(diff_val_ref_val.is_valid());
+        assert!(diff_val_ref_ref.is_valid());
+        assert!(diff_ref_val_val.is_valid());
+        assert!(diff_ref_val_ref.is_valid());
+        assert!(diff_ref_ref_val.is_valid());
+        assert!(diff.is_valid());
+        assert!(diff.mod_is_reduced(&m));
+        assert_eq!(diff_val_val_val, diff);
+        assert_eq!(diff_val_val_ref, diff);
+        assert_eq!(diff_val_ref_val, diff);
+        assert_eq!(diff_val_ref_ref, diff);
+        assert_eq!(diff_ref_val_val, diff);
+        assert_eq!(diff_ref_val_ref, diff);
+        assert_eq!(diff_ref_ref_val, diff);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_sub_assign(y.clone(), m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_sub_assign(y.clone(), &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_sub_assign(&y, m.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        let mut mut_x = x.clone();
+        mut_x.mod_sub_assign(&y, &m);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        let m_int = Integer::from(&m);
+        assert_eq!((&x - &y).mod_op(&m_int), diff);
+        let x_mod = Natural::exact_from((&x).mod_op(&m_int));
+        let y_mod = Natural::exact_from((&y).mod_op(&m_int));
+        assert_eq!((&x_mod).mod_sub(&y_mod, &m), diff);
+        assert_eq!((&y).mod_sub(&x, &m), (&diff).mod_neg(&m));
+        assert_eq!((&x).mod_add(-&y, &m), diff);
+        assert_eq!(diff.mod_add(y_mod, &m), x_mod);
+    });
+
+    integer_natural_pair_gen_var_1().test_properties(|(x, m)| {
+        let x_mod = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!((&x).mod_sub(Integer::ZERO, &m), x_mod);
+        assert_eq!(Integer::ZERO.mod_sub(&x, &m), (&x).mod_neg(&m));
+        assert_eq!((&x).mod_sub(&x, &m), 0);
+        assert_eq!((&x).mod_sub(Integer::from(&m), &m), x_mod);
+    });
+}
@@ -63,6 +63,8 @@ fn test_round_to_multiple() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", ToOdd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "123", Down, "0", Equal);
@@ -70,6 +72,8 @@ fn test_round_to_multiple() {
     test("0", "123", Up, "0", Equal);
     test("0", "123", Ceiling, "0", Equal);
     test("0", "123", Nearest, "0", Equal);
+    test("0", "123", NearestTiesAway, "0", Equal);
+    test("0", "123", ToOdd, "0", Equal);
     test("0", "123", Exact, "0", Equal);
 
     test("1", "1", Down, "1", Equal);
@@ -77,6 +81,8 @@ fn test_round_to_multiple() {
     test("1", "1", Up, "1", Equal);
     test("1", "1", Ceiling, "1", Equal);
     test("1", "1", Nearest, "1", Equal);
+    test("1", "1", NearestTiesAway, "1", Equal);
+    test("1", "1", ToOdd, "1", Equal);
     test("1", "1", Exact, "1", Equal);
 
     test("123", "1", Down, "123", Equal);
@@ -84,6 +90,8 @@ fn test_round_to_multiple() {
     test("123", "1", Up, "123", Equal);
     test("123", "1", Ceiling, "123", Equal);
     test("123", "1", Nearest, "123", Equal);
+    test("123", "1", NearestTiesAway, "123", Equal);
+    test("123", "1", ToOdd, "123", Equal);
     test("123", "1", Exact, "123", Equal);
 
     test("123", "2", Down, "122", Less);
@@ -91,18 +99,24 @@ fn test_round_to_multiple() {
     test("123", "2", Up, "124", Greater);
     test("123", "2", Ceiling, "124", Greater);
     test("123", "2", Nearest, "124", Greater);
+    test("123", "2", NearestTiesAway, "124", Greater);
+    test("123", "2", ToOdd, "122", Less);
 
     test("125", "2", Down, "124", Less);
     test("125", "2", Floor, "124", Less);
     test("125", "2", Up, "126", Greater);
     test("125", "2", Ceiling, "126", Greater);
     test("125", "2", Nearest, "124", Less);
+    test("125", "2", NearestTiesAway, "126", Greater);
+    test("125", "2", ToOdd, "126", Greater);
 
     test("123", "123", Down, "123", Equal);
     test("123", "123", Floor, "123", Equal);
     test("123", "123", Up, "123", Equal);
     test("123", "123", Ceiling, "123", Equal);
     test("123", "123", Nearest, "123", Equal);
+    test("123", "123", NearestTiesAway, "123", Equal);
+    test("123", "123", ToOdd, "123", Equal);
     test("123", "123", Exact, "123", Equal);
 
     test("123", "456", Down, "0", Less);
@@ -110,12 +124,22 @@ fn test_round_to_multiple() {
     test("123", "456", Up, "456", Greater);
     test("123", "456", Ceiling, "456", Greater);
     test("123", "456", Nearest, "0", Less);
+    test("123", "456", NearestTiesAway, "0", Less);
+    test("123", "456", ToOdd, "456", Greater);
 
     test("1000000000000", "1", Down, "1000000000000", Equal);
     test("1000000000000", "1", Floor, "1000000000000", Equal);
     test("1000000000000", "1", Up, "1000000000000", Equal);
     test("1000000000000", "1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "1", Nearest, "1000000000000", Equal);
+    test(
+        "1000000000000",
+        "1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("1000000000000", "1", ToOdd, "1000000000000", Equal);
     test("1000000000000", "1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "3", Down, "999999999999", Less);
@@ -123,18 +147,36 @@ fn test_round_to_multiple() {
     test("1000000000000", "3", Up, "1000000000002", Greater);
     test("1000000000000", "3", Ceiling, "1000000000002", Greater);
     test("1000000000000", "3", Nearest, "999999999999", Less);
+    test("1000000000000", "3", NearestTiesAway, "999999999999", Less);
+    test("1000000000000", "3", ToOdd, "999999999999", Less);
 
     test("999999999999", "2", Down, "999999999998", Less);
     test("999999999999", "2", Floor, "999999999998", Less);
     test("999999999999", "2", Up, "1000000000000", Greater);
     test("999999999999", "2", Ceiling, "1000000000000", Greater);
     test("999999999999", "2", Nearest, "1000000000000", Greater);
+    test(
+        "999999999999",
+        "2",
+        NearestTiesAway,
+        "1000000000000",
+        Greater,
+    );
+    test("999999999999", "2", ToOdd, "999999999998", Less);
 
     test("1000000000001", "2", Down, "1000000000000", Less);
     test("1000000000001", "2", Floor, "1000000000000", Less);
     test("1000000000001", "2", Up, "1000000000002", Greater);
     test("1000000000001", "2", Ceiling, "1000000000002", Greater);
     test("1000000000001", "2", Nearest, "1000000000000", Less);
+    test(
+        "1000000000001",
+        "2",
+        NearestTiesAway,
+        "1000000000002",
+        Greater,
+    );
+    test("1000000000001", "2", ToOdd, "1000000000002", Greater);
 
     test(
         "1000000000000000000000000",
@@ -171,6 +213,20 @@ fn test_round_to_multiple() {
         "1000000000000001127243600",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "1000000000000001127243600",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "999999999999996832276305",
+        Less,
+    );
 
     test(
         "1000000000000000000000000",
@@ -207,6 +263,20 @@ fn test_round_to_multiple() {
         "1000000000000000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "1000000000000000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "1000000000000000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "1000000000000",
@@ -250,6 +320,20 @@ fn test_round_to_multiple() {
         "999999999999999999999999",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "999999999999999999999999",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "999999999999999999999999",
+        Less,
+    );
 
     test(
         "2999999999999999999999999",
@@ -258,6 +342,20 @@ fn test_round_to_multiple() {
         "2000000000000000000000000",
         Less,
     );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2000000000000000000000000",
+        Less,
+    );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000000",
         "2000000000000000000000000",
@@ -265,6 +363,20 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000001",
         "2000000000000000000000000",
@@ -272,12 +384,28 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
 
     test("0", "-1", Down, "0", Equal);
     test("0", "-1", Floor, "0", Equal);
     test("0", "-1", Up, "0", Equal);
     test("0", "-1", Ceiling, "0", Equal);
     test("0", "-1", Nearest, "0", Equal);
+    test("0", "-1", NearestTiesAway, "0", Equal);
+    test("0", "-1", ToOdd, "0", Equal);
     test("0", "-1", Exact, "0", Equal);
 
     test("0", "-123", Down, "0", Equal);
@@ -285,6 +413,8 @@ fn test_round_to_multiple() {
     test("0", "-123", Up, "0", Equal);
     test("0", "-123", Ceiling, "0", Equal);
     test("0", "-123", Nearest, "0", Equal);
+    test("0", "-123", NearestTiesAway, "0", Equal);
+    test("0", "-123", ToOdd, "0", Equal);
     test("0", "-123", Exact, "0", Equal);
 
     test("1", "-1", Down, "1", Equal);
@@ -292,6 +422,8 @@ fn test_round_to_multiple() {
     test("1", "-1", Up, "1", Equal);
     test("1", "-1", Ceiling, "1", Equal);
     test("1", "-1", Nearest, "1", Equal);
+    test("1", "-1", NearestTiesAway, "1", Equal);
+    test("1", "-1", ToOdd, "1", Equal);
     test("1", "-1", Exact, "1", Equal);
 
     test("123", "-1", Down, "123", Equal);
@@ -299,6 +431,8 @@ fn test_round_to_multiple() {
     test("123", "-1", Up, "123", Equal);
     test("123", "-1", Ceiling, "123", Equal);
     test("123", "-1", Nearest, "123", Equal);
+    test("123", "-1", NearestTiesAway, "123", Equal);
+    test("123", "-1", ToOdd, "123", Equal);
     test("123", "-1", Exact, "123", Equal);
 
     test("123", "-2", Down, "122", Less);
@@ -306,18 +440,24 @@ fn test_round_to_multiple() {
     test("123", "-2", Up, "124", Greater);
     test("123", "-2", Ceiling, "124", Greater);
     test("123", "-2", Nearest, "124", Greater);
+    test("123", "-2", NearestTiesAway, "124", Greater);
+    test("123", "-2", ToOdd, "122", Less);
 
     test("125", "-2", Down, "124", Less);
     test("125", "-2", Floor, "124", Less);
     test("125", "-2", Up, "126", Greater);
     test("125", "-2", Ceiling, "126", Greater);
     test("125", "-2", Nearest, "124", Less);
+    test("125", "-2", NearestTiesAway, "126", Greater);
+    test("125", "-2", ToOdd, "126", Greater);
 
     test("123", "-123", Down, "123", Equal);
     test("123", "-123", Floor, "123", Equal);
     test("123", "-123", Up, "123", Equal);
     test("123", "-123", Ceiling, "123", Equal);
     test("123", "-123", Nearest, "123", Equal);
+    test("123", "-123", NearestTiesAway, "123", Equal);
+    test("123", "-123", ToOdd, "123", Equal);
     test("123", "-123", Exact, "123", Equal);
 
     test("123", "-456", Down, "0", Less);
@@ -325,12 +465,22 @@ fn test_round_to_multiple() {
     test("123", "-456", Up, "456", Greater);
     test("123", "-456", Ceiling, "456", Greater);
     test("123", "-456", Nearest, "0", Less);
+    test("123", "-456", NearestTiesAway, "0", Less);
+    test("123", "-456", ToOdd, "456", Greater);
 
     test("1000000000000", "-1", Down, "1000000000000", Equal);
     test("1000000000000", "-1", Floor, "1000000000000", Equal);
     test("1000000000000", "-1", Up, "1000000000000", Equal);
     test("1000000000000", "-1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "-1", Nearest, "1000000000000", Equal);
+    test(
+        "1000000000000",
+        "-1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("1000000000000", "-1", ToOdd, "1000000000000", Equal);
     test("1000000000000", "-1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "-3", Down, "999999999999", Less);
@@ -338,18 +488,36 @@ fn test_round_to_multiple() {
     test("1000000000000", "-3", Up, "1000000000002", Greater);
     test("1000000000000", "-3", Ceiling, "1000000000002", Greater);
     test("1000000000000", "-3", Nearest, "999999999999", Less);
+    test("1000000000000", "-3", NearestTiesAway, "999999999999", Less);
+    test("1000000000000", "-3", ToOdd, "999999999999", Less);
 
     test("999999999999", "-2", Down, "999999999998", Less);
     test("999999999999", "-2", Floor, "999999999998", Less);
     test("999999999999", "-2", Up, "1000000000000", Greater);
     test("999999999999", "-2", Ceiling, "1000000000000", Greater);
     test("999999999999", "-2", Nearest, "1000000000000", Greater);
+    test(
+        "999999999999",
+        "-2",
+        NearestTiesAway,
+        "1000000000000",
+        Greater,
+    );
+    test("999999999999", "-2", ToOdd, "999999999998", Less);
 
     test("1000000000001", "-2", Down, "1000000000000", Less);
     test("1000000000001", "-2", Floor, "1000000000000", Less);
     test("1000000000001", "-2", Up, "1000000000002", Greater);
     test("1000000000001", "-2", Ceiling, "1000000000002", Greater);
     test("1000000000001", "-2", Nearest, "1000000000000", Less);
+    test(
+        "1000000000001",
+        "-2",
+        NearestTiesAway,
+        "1000000000002",
+        Greater,
+    );
+    test("1000000000001", "-2", ToOdd, "1000000000002", Greater);
 
     test(
         "1000000000000000000000000",
@@ -386,6 +554,20 @@ fn test_round_to_multiple() {
         "1000000000000001127243600",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "-4294967295",
+        NearestTiesAway,
+        "1000000000000001127243600",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "-4294967295",
+        ToOdd,
+        "999999999999996832276305",
+        Less,
+    );
 
     test(
         "1000000000000000000000000",
@@ -422,6 +604,20 @@ fn test_round_to_multiple() {
         "1000000000000000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "-1000000000000",
+        NearestTiesAway,
+        "1000000000000000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "-1000000000000",
+        ToOdd,
+        "1000000000000000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "-1000000000000",
@@ -465,6 +661,20 @@ fn test_round_to_multiple() {
         "999999999999999999999999",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "-1000000000001",
+        NearestTiesAway,
+        "999999999999999999999999",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "-1000000000001",
+        ToOdd,
+        "999999999999999999999999",
+        Less,
+    );
 
     test(
         "2999999999999999999999999",
@@ -473,6 +683,20 @@ fn test_round_to_multiple() {
         "2000000000000000000000000",
         Less,
     );
+    test(
+        "2999999999999999999999999",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "2000000000000000000000000",
+        Less,
+    );
+    test(
+        "2999999999999999999999999",
+        "-2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000000",
         "-2000000000000000000000000",
@@ -480,6 +704,20 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000000",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000000",
+        "-2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000001",
         "-2000000000000000000000000",
@@ -487,12 +725,28 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000001",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000001",
+        "-2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
 
     test("-1", "1", Down, "-1", Equal);
     test("-1", "1", Floor, "-1", Equal);
     test("-1", "1", Up, "-1", Equal);
     test("-1", "1", Ceiling, "-1", Equal);
     test("-1", "1", Nearest, "-1", Equal);
+    test("-1", "1", NearestTiesAway, "-1", Equal);
+    test("-1", "1", ToOdd, "-1", Equal);
     test("-1", "1", Exact, "-1", Equal);
 
     test("-123", "1", Down, "-123", Equal);
@@ -500,6 +754,8 @@ fn test_round_to_multiple() {
     test("-123", "1", Up, "-123", Equal);
     test("-123", "1", Ceiling, "-123", Equal);
     test("-123", "1", Nearest, "-123", Equal);
+    test("-123", "1", NearestTiesAway, "-123", Equal);
+    test("-123", "1", ToOdd, "-123", Equal);
     test("-123", "1", Exact, "-123", Equal);
 
     test("-123", "2", Down, "-122", Greater);
@@ -507,18 +763,24 @@ fn test_round_to_multiple() {
     test("-123", "2", Up, "-124", Less);
     test("-123", "2", Ceiling, "-122", Greater);
     test("-123", "2", Nearest, "-124", Less);
+    test("-123", "2", NearestTiesAway, "-124", Less);
+    test("-123", "2", ToOdd, "-122", Greater);
 
     test("-125", "2", Down, "-124", Greater);
     test("-125", "2", Floor, "-126", Less);
     test("-125", "2", Up, "-126", Less);
     test("-125", "2", Ceiling, "-124", Greater);
     test("-125", "2", Nearest, "-124", Greater);
+    test("-125", "2", NearestTiesAway, "-126", Less);
+    test("-125", "2", ToOdd, "-126", Less);
 
     test("-123", "123", Down, "-123", Equal);
     test("-123", "123", Floor, "-123", Equal);
     test("-123", "123", Up, "-123", Equal);
     test("-123", "123", Ceiling, "-123", Equal);
     test("-123", "123", Nearest, "-123", Equal);
+    test("-123", "123", NearestTiesAway, "-123", Equal);
+    test("-123", "123", ToOdd, "-123", Equal);
     test("-123", "123", Exact, "-123", Equal);
 
     test("-123", "456", Down, "0", Greater);
@@ -526,12 +788,22 @@ fn test_round_to_multiple() {
     test("-123", "456", Up, "-456", Less);
     test("-123", "456", Ceiling, "0", Greater);
     test("-123", "456", Nearest, "0", Greater);
+    test("-123", "456", NearestTiesAway, "0", Greater);
+    test("-123", "456", ToOdd, "-456", Less);
 
     test("-1000000000000", "1", Down, "-1000000000000", Equal);
     test("-1000000000000", "1", Floor, "-1000000000000", Equal);
     test("-1000000000000", "1", Up, "-1000000000000", Equal);
     test("-1000000000000", "1", Ceiling, "-1000000000000", Equal);
     test("-1000000000000", "1", Nearest, "-1000000000000", Equal);
+    test(
+        "-1000000000000",
+        "1",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("-1000000000000", "1", ToOdd, "-1000000000000", Equal);
     test("-1000000000000", "1", Exact, "-1000000000000", Equal);
 
     test("-1000000000000", "3", Down, "-999999999999", Greater);
@@ -539,18 +811,42 @@ fn test_round_to_multiple() {
     test("-1000000000000", "3", Up, "-1000000000002", Less);
     test("-1000000000000", "3", Ceiling, "-999999999999", Greater);
     test("-1000000000000", "3", Nearest, "-999999999999", Greater);
+    test(
+        "-1000000000000",
+        "3",
+        NearestTiesAway,
+        "-999999999999",
+        Greater,
+    );
+    test("-1000000000000", "3", ToOdd, "-999999999999", Greater);
 
     test("-999999999999", "2", Down, "-999999999998", Greater);
     test("-999999999999", "2", Floor, "-1000000000000", Less);
     test("-999999999999", "2", Up, "-1000000000000", Less);
     test("-999999999999", "2", Ceiling, "-999999999998", Greater);
     test("-999999999999", "2", Nearest, "-1000000000000", Less);
+    test(
+        "-999999999999",
+        "2",
+        NearestTiesAway,
+        "-1000000000000",
+        Less,
+    );
+    test("-999999999999", "2", ToOdd, "-999999999998", Greater);
 
     test("-1000000000001", "2", Down, "-1000000000000", Greater);
     test("-1000000000001", "2", Floor, "-1000000000002", Less);
     test("-1000000000001", "2", Up, "-1000000000002", Less);
     test("-1000000000001", "2", Ceiling, "-1000000000000", Greater);
     test("-1000000000001", "2", Nearest, "-1000000000000", Greater);
+    test(
+        "-1000000000001",
+        "2",
+        NearestTiesAway,
+        "-1000000000002",
+        Less,
+    );
+    test("-1000000000001", "2", ToOdd, "-1000000000002", Less);
 
     test(
         "-1000000000000000000000000",
@@ -587,6 +883,20 @@ fn test_round_to_multiple() {
         "-1000000000000001127243600",
         Less,
     );
+    test(
+        "-1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "-1000000000000001127243600",
+        Less,
+    );
+    test(
+        "-1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "-999999999999996832276305",
+        Greater,
+    );
 
     test(
         "-1000000000000000000000000",
@@ -623,6 +933,20 @@ fn test_round_to_multiple() {
         "-1000000000000000000000000",
         Equal,
     );
+    test(
+        "-1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "-1000000000000000000000000",
+        Equal,
+    );
+    test(
+        "-1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "-1000000000000000000000000",
+        Equal,
+    );
     test(
         "-1000000000000000000000000",
         "1000000000000",
@@ -666,6 +990,20 @@ fn test_round_to_multiple() {
         "-999999999999999999999999",
         Greater,
     );
+    test(
+        "-1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "-999999999999999999999999",
+        Greater,
+    );
+    test(
+        "-1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "-999999999999999999999999",
+        Greater,
+    );
 
     test(
         "-2999999999999999999999999",
@@ -674,6 +1012,20 @@ fn test_round_to_multiple() {
         "-2000000000000000000000000",
         Greater,
     );
+    test(
+        "-2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-2000000000000000000000000",
+        Greater,
+    );
+    test(
+        "-2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
     test(
         "-3000000000000000000000000",
         "2000000000000000000000000",
@@ -681,6 +1033,20 @@ fn test_round_to_multiple() {
         "-4000000000000000000000000",
         Less,
     );
+    test(
+        "-3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-4000000000000000000000000",
+        Less,
+    );
+    test(
+        "-3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
     test(
         "-3000000000000000000000001",
         "2000000000000000000000000",
@@ -688,12 +1054,28 @@ fn test_round_to_multiple() {
         "-4000000000000000000000000",
         Less,
     );
+    test(
+        "-3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "-4000000000000000000000000",
+        Less,
+    );
+    test(
+        "-3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
 
     test("-1", "-1", Down, "-1", Equal);
     test("-1", "-1", Floor, "-1", Equal);
     test("-1", "-1", Up, "-1", Equal);
     test("-1", "-1", Ceiling, "-1", Equal);
     test("-1", "-1", Nearest, "-1", Equal);
+    test("-1", "-1", NearestTiesAway, "-1", Equal);
+    test("-1", "-1", ToOdd, "-1", Equal);
     test("-1", "-1", Exact, "-1", Equal);
 
     test("-123", "-1", Down, "-123", Equal);
@@ -701,6 +1083,8 @@ fn test_round_to_multiple() {
     test("-123", "-1", Up, "-123", Equal);
     test("-123", "-1", Ceiling, "-123", Equal);
     test("-123", "-1", Nearest, "-123", Equal);
+    test("-123", "-1", NearestTiesAway, "-123", Equal);
+    test("-123", "-1", ToOdd, "-123", Equal);
     test("-123", "-1", Exact, "-123", Equal);
 
     test("-123", "-2", Down, "-122", Greater);
@@ -708,18 +1092,24 @@ fn test_round_to_multiple() {
     test("-123", "-2", Up, "-124", Less);
     test("-123", "-2", Ceiling, "-122", Greater);
     test("-123", "-2", Nearest, "-124", Less);
+    test("-123", "-2", NearestTiesAway, "-124", Less);
+    test("-123", "-2", ToOdd, "-122", Greater);
 
     test("-125", "-2", Down, "-124", Greater);
     test("-125", "-2", Floor, "-126", Less);
     test("-125", "-2", Up, "-126", Less);
     test("-125", "-2", Ceiling, "-124", Greater);
     test("-125", "-2", Nearest, "-124", Greater);
+    test("-125", "-2", NearestTiesAway, "-126", Less);
+    test("-125", "-2", ToOdd, "-126", Less);
 
     test("-123", "-123", Down, "-123", Equal);
     test("-123", "-123", Floor, "-123", Equal);
     test("-123", "-123", Up, "-123", Equal);
     test("-123", "-123", Ceiling, "-123", Equal);
     test("-123", "-123", Nearest, "-123", Equal);
+    test("-123", "-123", NearestTiesAway, "-123", Equal);
+    test("-123", "-123", ToOdd, "-123", Equal);
     test("-123", "-123", Exact, "-123", Equal);
 
     test("-123", "-456", Down, "0", Greater);
@@ -727,12 +1117,22 @@ fn test_round_to_multiple() {
     test("-123", "-456", Up, "-456", Less);
     test("-123", "-456", Ceiling, "0", Greater);
     test("-123", "-456", Nearest, "0", Greater);
+    test("-123", "-456", NearestTiesAway, "0", Greater);
+    test("-123", "-456", ToOdd, "-456", Less);
 
     test("-1000000000000", "-1", Down, "-1000000000000", Equal);
     test("-1000000000000", "-1", Floor, "-1000000000000", Equal);
     test("-1000000000000", "-1", Up, "-1000000000000", Equal);
     test("-1000000000000", "-1", Ceiling, "-1000000000000", Equal);
     test("-1000000000000", "-1", Nearest, "-1000000000000", Equal);
+    test(
+        "-1000000000000",
+        "-1",
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("-1000000000000", "-1", ToOdd, "-1000000000000", Equal);
     test("-1000000000000", "-1", Exact, "-1000000000000", Equal);
 
     test("-1000000000000", "-3", Down, "-999999999999", Greater);
@@ -740,18 +1140,42 @@ fn test_round_to_multiple() {
     test("-1000000000000", "-3", Up, "-1000000000002", Less);
     test("-1000000000000", "-3", Ceiling, "-999999999999", Greater);
     test("-1000000000000", "-3", Nearest, "-999999999999", Greater);
+    test(
+        "-1000000000000",
+        "-3",
+        NearestTiesAway,
+        "-999999999999",
+        Greater,
+    );
+    test("-1000000000000", "-3", ToOdd, "-999999999999", Greater);
 
     test("-999999999999", "-2", Down, "-999999999998", Greater);
     test("-999999999999", "-2", Floor, "-1000000000000", Less);
     test("-999999999999", "-2", Up, "-1000000000000", Less);
     test("-999999999999", "-2", Ceiling, "-999999999998", Greater);
     test("-999999999999", "-2", Nearest, "-1000000000000", Less);
+    test(
+        "-999999999999",
+        "-2",
+        NearestTiesAway,
+        "-1000000000000",
+        Less,
+    );
+    test("-999999999999", "-2", ToOdd, "-999999999998", Greater);
 
     test("-1000000000001", "-2", Down, "-1000000000000", Greater);
     test("-1000000000001", "-2", Floor, "-1000000000002", Less);
     test("-1000000000001", "-2", Up, "-1000000000002", Less);
     test("-1000000000001", "-2", Ceiling, "-1000000000000", Greater);
     test("-1000000000001", "-2", Nearest, "-1000000000000", Greater);
+    test(
+        "-1000000000001",
+        "-2",
+        NearestTiesAway,
+        "-1000000000002",
+        Less,
+    );
+    test("-1000000000001", "-2", ToOdd, "-1000000000002", Less);
 
     test(
         "-1000000000000000000000000",
@@ -788,6 +1212,20 @@ fn test_round_to_multiple() {
         "-1000000000000001127243600",
         Less,
     );
+    test(
+        "-1000000000000000000000000",
+        "-4294967295",
+        NearestTiesAway,
+        "-1000000000000001127243600",
+        Less,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-4294967295",
+        ToOdd,
+        "-999999999999996832276305",
+        Greater,
+    );
 
     test(
         "-1000000000000000000000000",
@@ -824,6 +1262,20 @@ fn test_round_to_multiple() {
         "-1000000000000000000000000",
         Equal,
     );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000000",
+        NearestTiesAway,
+        "-1000000000000000000000000",
+        Equal,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000000",
+        ToOdd,
+        "-1000000000000000000000000",
+        Equal,
+    );
     test(
         "-1000000000000000000000000",
         "-1000000000000",
@@ -867,6 +1319,20 @@ fn test_round_to_multiple() {
         "-999999999999999999999999",
         Greater,
     );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000001",
+        NearestTiesAway,
+        "-999999999999999999999999",
+        Greater,
+    );
+    test(
+        "-1000000000000000000000000",
+        "-1000000000001",
+        ToOdd,
+        "-999999999999999999999999",
+        Greater,
+    );
 
     test(
         "-2999999999999999999999999",
@@ -875,6 +1341,20 @@ fn test_round_to_multiple() {
         "-2000000000000000000000000",
         Greater,
     );
+    test(
+        "-2999999999999999999999999",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-2000000000000000000000000",
+        Greater,
+    );
+    test(
+        "-2999999999999999999999999",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
     test(
         "-3000000000000000000000000",
         "-2000000000000000000000000",
@@ -882,6 +1362,20 @@ fn test_round_to_multiple() {
         "-4000000000000000000000000",
         Less,
     );
+    test(
+        "-3000000000000000000000000",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-4000000000000000000000000",
+        Less,
+    );
+    test(
+        "-3000000000000000000000000",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
     test(
         "-3000000000000000000000001",
         "-2000000000000000000000000",
@@ -889,20 +1383,38 @@ fn test_round_to_multiple() {
         "-4000000000000000000000000",
         Less,
     );
+    test(
+        "-3000000000000000000000001",
+        "-2000000000000000000000000",
+        NearestTiesAway,
+        "-4000000000000000000000000",
+        Less,
+    );
+    test(
+        "-3000000000000000000000001",
+        "-2000000000000000000000000",
+        ToOdd,
+        "-2000000000000000000000000",
+        Greater,
+    );
 
     test("0", "0", Floor, "0", Equal);
     test("0", "0", Ceiling, "0", Equal);
     test("0", "0", Down, "0", Equal);
     test("0", "0", Up, "0", Equal);
     test("0", "0", Nearest, "0", Equal);
+    test("0", "0", NearestTiesAway, "0", Equal);
+    test("0", "0", ToOdd, "0", Equal);
     test("0", "0", Exact, "0", Equal);
 
     test("2", "0", Floor, "0", Less);
     test("2", "0", Down, "0", Less);
     test("2", "0", Nearest, "0", Less);
+    test("2", "0", NearestTiesAway, "0", Less);
     test("-2", "0", Ceiling, "0", Greater);
     test("-2", "0", Down, "0", Greater);
     test("-2", "0", Nearest, "0", Greater);
+    test("-2", "0", NearestTiesAway, "0", Greater);
 }
 
 #[test]
@@ -1121,7 +1633,7 @@ fn round_to_multiple_properties() {
                 Down => assert!(r.le_abs(&x)),
                 Up => assert!(r.ge_abs(&x)),
                 Exact => assert_eq!(r, x),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     let closest;
                     let second_closest;
                     if r <= x {
@@ -1133,9 +1645,14 @@ fn round_to_multiple_properties() {
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!(r.div_round(y, Exact).0.even());
+                        if rm == Nearest {
+                            assert!(r.div_round(y, Exact).0.even());
+                        } else {
+                            assert_eq!(o, if r > 0 { Greater } else { Less });
+                        }
                     }
                 }
+                ToOdd => assert!(r == x || r.div_round(y, Exact).0.odd()),
             }
         }
     });
@@ -1148,6 +1665,8 @@ fn round_to_multiple_properties() {
         assert_eq!((&product).round_to_multiple(&y, Floor), po);
         assert_eq!((&product).round_to_multiple(&y, Ceiling), po);
         assert_eq!((&product).round_to_multiple(&y, Nearest), po);
+        assert_eq!((&product).round_to_multiple(&y, NearestTiesAway), po);
+        assert_eq!((&product).round_to_multiple(&y, ToOdd), po);
         assert_eq!((&product).round_to_multiple(&y, Exact), po);
     });
 
@@ -1164,6 +1683,10 @@ fn round_to_multiple_properties() {
         let ceiling = (&floor.0 + (&y).abs(), Greater);
         assert_eq!((&x).round_to_multiple(&y, Up), up);
         assert_eq!((&x).round_to_multiple(&y, Ceiling), ceiling);
+        let nearest_ties_away = (&x).round_to_multiple(&y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&x).round_to_multiple(&y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = x.round_to_multiple(y, Nearest);
         assert!(nearest == down || nearest == up);
     });
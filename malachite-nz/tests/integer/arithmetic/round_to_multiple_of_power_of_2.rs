@@ -55,6 +55,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, ToOdd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -62,6 +64,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, ToOdd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -69,6 +73,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, ToOdd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "244", Less);
@@ -76,12 +82,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("245", 1, Floor, "244", Less);
     test("245", 1, Ceiling, "246", Greater);
     test("245", 1, Nearest, "244", Less);
+    test("245", 1, NearestTiesAway, "246", Greater);
+    test("245", 1, ToOdd, "246", Greater);
 
     test("246", 1, Down, "246", Equal);
     test("246", 1, Up, "246", Equal);
     test("246", 1, Floor, "246", Equal);
     test("246", 1, Ceiling, "246", Equal);
     test("246", 1, Nearest, "246", Equal);
+    test("246", 1, NearestTiesAway, "246", Equal);
+    test("246", 1, ToOdd, "246", Equal);
     test("246", 1, Exact, "246", Equal);
 
     test("247", 1, Down, "246", Less);
@@ -89,18 +99,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("247", 1, Floor, "246", Less);
     test("247", 1, Ceiling, "248", Greater);
     test("247", 1, Nearest, "248", Greater);
+    test("247", 1, NearestTiesAway, "248", Greater);
+    test("247", 1, ToOdd, "246", Less);
 
     test("491", 2, Down, "488", Less);
     test("491", 2, Up, "492", Greater);
     test("491", 2, Floor, "488", Less);
     test("491", 2, Ceiling, "492", Greater);
     test("491", 2, Nearest, "492", Greater);
+    test("491", 2, NearestTiesAway, "492", Greater);
+    test("491", 2, ToOdd, "492", Greater);
 
     test("492", 2, Down, "492", Equal);
     test("492", 2, Up, "492", Equal);
     test("492", 2, Floor, "492", Equal);
     test("492", 2, Ceiling, "492", Equal);
     test("492", 2, Nearest, "492", Equal);
+    test("492", 2, NearestTiesAway, "492", Equal);
+    test("492", 2, ToOdd, "492", Equal);
     test("492", 2, Exact, "492", Equal);
 
     test("493", 2, Down, "492", Less);
@@ -108,18 +124,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("493", 2, Floor, "492", Less);
     test("493", 2, Ceiling, "496", Greater);
     test("493", 2, Nearest, "492", Less);
+    test("493", 2, NearestTiesAway, "492", Less);
+    test("493", 2, ToOdd, "492", Less);
 
     test("4127195135", 25, Down, "4093640704", Less);
     test("4127195135", 25, Up, "4127195136", Greater);
     test("4127195135", 25, Floor, "4093640704", Less);
     test("4127195135", 25, Ceiling, "4127195136", Greater);
     test("4127195135", 25, Nearest, "4127195136", Greater);
+    test("4127195135", 25, NearestTiesAway, "4127195136", Greater);
+    test("4127195135", 25, ToOdd, "4127195136", Greater);
 
     test("4127195136", 25, Down, "4127195136", Equal);
     test("4127195136", 25, Up, "4127195136", Equal);
     test("4127195136", 25, Floor, "4127195136", Equal);
     test("4127195136", 25, Ceiling, "4127195136", Equal);
     test("4127195136", 25, Nearest, "4127195136", Equal);
+    test("4127195136", 25, NearestTiesAway, "4127195136", Equal);
+    test("4127195136", 25, ToOdd, "4127195136", Equal);
     test("4127195136", 25, Exact, "4127195136", Equal);
 
     test("4127195137", 25, Down, "4127195136", Less);
@@ -127,18 +149,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4127195137", 25, Floor, "4127195136", Less);
     test("4127195137", 25, Ceiling, "4160749568", Greater);
     test("4127195137", 25, Nearest, "4127195136", Less);
+    test("4127195137", 25, NearestTiesAway, "4127195136", Less);
+    test("4127195137", 25, ToOdd, "4127195136", Less);
 
     test("8254390271", 26, Down, "8187281408", Less);
     test("8254390271", 26, Up, "8254390272", Greater);
     test("8254390271", 26, Floor, "8187281408", Less);
     test("8254390271", 26, Ceiling, "8254390272", Greater);
     test("8254390271", 26, Nearest, "8254390272", Greater);
+    test("8254390271", 26, NearestTiesAway, "8254390272", Greater);
+    test("8254390271", 26, ToOdd, "8254390272", Greater);
 
     test("8254390272", 26, Down, "8254390272", Equal);
     test("8254390272", 26, Up, "8254390272", Equal);
     test("8254390272", 26, Floor, "8254390272", Equal);
     test("8254390272", 26, Ceiling, "8254390272", Equal);
     test("8254390272", 26, Nearest, "8254390272", Equal);
+    test("8254390272", 26, NearestTiesAway, "8254390272", Equal);
+    test("8254390272", 26, ToOdd, "8254390272", Equal);
     test("8254390272", 26, Exact, "8254390272", Equal);
 
     test("8254390273", 26, Down, "8254390272", Less);
@@ -146,6 +174,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("8254390273", 26, Floor, "8254390272", Less);
     test("8254390273", 26, Ceiling, "8321499136", Greater);
     test("8254390273", 26, Nearest, "8254390272", Less);
+    test("8254390273", 26, NearestTiesAway, "8254390272", Less);
+    test("8254390273", 26, ToOdd, "8254390272", Less);
     test(
         "155921023828072216384094494261247",
         100,
@@ -181,6 +211,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Greater,
     );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Greater,
+    );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Greater,
+    );
 
     test(
         "155921023828072216384094494261248",
@@ -217,6 +261,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Equal,
     );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Equal,
+    );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Equal,
+    );
     test(
         "155921023828072216384094494261248",
         100,
@@ -260,18 +318,36 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Less,
     );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Less,
+    );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Less,
+    );
 
     test("4294967295", 1, Down, "4294967294", Less);
     test("4294967295", 1, Up, "4294967296", Greater);
     test("4294967295", 1, Floor, "4294967294", Less);
     test("4294967295", 1, Ceiling, "4294967296", Greater);
     test("4294967295", 1, Nearest, "4294967296", Greater);
+    test("4294967295", 1, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 1, ToOdd, "4294967294", Less);
 
     test("4294967296", 1, Down, "4294967296", Equal);
     test("4294967296", 1, Up, "4294967296", Equal);
     test("4294967296", 1, Floor, "4294967296", Equal);
     test("4294967296", 1, Ceiling, "4294967296", Equal);
     test("4294967296", 1, Nearest, "4294967296", Equal);
+    test("4294967296", 1, NearestTiesAway, "4294967296", Equal);
+    test("4294967296", 1, ToOdd, "4294967296", Equal);
     test("4294967296", 1, Exact, "4294967296", Equal);
 
     test("4294967297", 1, Down, "4294967296", Less);
@@ -279,12 +355,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4294967297", 1, Floor, "4294967296", Less);
     test("4294967297", 1, Ceiling, "4294967298", Greater);
     test("4294967297", 1, Nearest, "4294967296", Less);
+    test("4294967297", 1, NearestTiesAway, "4294967298", Greater);
+    test("4294967297", 1, ToOdd, "4294967298", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, ToOdd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "7999999999992", Less);
@@ -292,12 +372,22 @@ fn test_round_to_multiple_of_power_of_2() {
     test("7999999999999", 3, Floor, "7999999999992", Less);
     test("7999999999999", 3, Ceiling, "8000000000000", Greater);
     test("7999999999999", 3, Nearest, "8000000000000", Greater);
+    test(
+        "7999999999999",
+        3,
+        NearestTiesAway,
+        "8000000000000",
+        Greater,
+    );
+    test("7999999999999", 3, ToOdd, "7999999999992", Less);
 
     test("8000000000000", 3, Down, "8000000000000", Equal);
     test("8000000000000", 3, Up, "8000000000000", Equal);
     test("8000000000000", 3, Floor, "8000000000000", Equal);
     test("8000000000000", 3, Ceiling, "8000000000000", Equal);
     test("8000000000000", 3, Nearest, "8000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "8000000000000", Equal);
+    test("8000000000000", 3, ToOdd, "8000000000000", Equal);
     test("8000000000000", 3, Exact, "8000000000000", Equal);
 
     test("8000000000001", 3, Down, "8000000000000", Less);
@@ -305,6 +395,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("8000000000001", 3, Floor, "8000000000000", Less);
     test("8000000000001", 3, Ceiling, "8000000000008", Greater);
     test("8000000000001", 3, Nearest, "8000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "8000000000000", Less);
+    test("8000000000001", 3, ToOdd, "8000000000008", Greater);
 
     test(
         "16777216000000000000",
@@ -341,6 +433,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "16777216000000000000",
         Equal,
     );
+    test(
+        "16777216000000000000",
+        24,
+        NearestTiesAway,
+        "16777216000000000000",
+        Equal,
+    );
+    test(
+        "16777216000000000000",
+        24,
+        ToOdd,
+        "16777216000000000000",
+        Equal,
+    );
     test(
         "16777216000000000000",
         24,
@@ -384,6 +490,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "33554432000000000000",
         Equal,
     );
+    test(
+        "33554432000000000000",
+        25,
+        NearestTiesAway,
+        "33554432000000000000",
+        Equal,
+    );
+    test(
+        "33554432000000000000",
+        25,
+        ToOdd,
+        "33554432000000000000",
+        Equal,
+    );
     test(
         "33554432000000000000",
         25,
@@ -427,6 +547,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "2147483648000000000000",
         Equal,
     );
+    test(
+        "2147483648000000000000",
+        31,
+        NearestTiesAway,
+        "2147483648000000000000",
+        Equal,
+    );
+    test(
+        "2147483648000000000000",
+        31,
+        ToOdd,
+        "2147483648000000000000",
+        Equal,
+    );
     test(
         "2147483648000000000000",
         31,
@@ -470,6 +604,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "4294967296000000000000",
         Equal,
     );
+    test(
+        "4294967296000000000000",
+        32,
+        NearestTiesAway,
+        "4294967296000000000000",
+        Equal,
+    );
+    test(
+        "4294967296000000000000",
+        32,
+        ToOdd,
+        "4294967296000000000000",
+        Equal,
+    );
     test(
         "4294967296000000000000",
         32,
@@ -513,6 +661,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "8589934592000000000000",
         Equal,
     );
+    test(
+        "8589934592000000000000",
+        33,
+        NearestTiesAway,
+        "8589934592000000000000",
+        Equal,
+    );
+    test(
+        "8589934592000000000000",
+        33,
+        ToOdd,
+        "8589934592000000000000",
+        Equal,
+    );
     test(
         "8589934592000000000000",
         33,
@@ -556,6 +718,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "1267650600228229401496703205376000000000000",
         Equal,
     );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        NearestTiesAway,
+        "1267650600228229401496703205376000000000000",
+        Equal,
+    );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        ToOdd,
+        "1267650600228229401496703205376000000000000",
+        Equal,
+    );
     test(
         "1267650600228229401496703205376000000000000",
         100,
@@ -569,6 +745,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("1000000000000", 10, Floor, "1000000000000", Equal);
     test("1000000000000", 10, Ceiling, "1000000000000", Equal);
     test("1000000000000", 10, Nearest, "1000000000000", Equal);
+    test("1000000000000", 10, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 10, ToOdd, "1000000000000", Equal);
     test("1000000000000", 10, Exact, "1000000000000", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -576,24 +754,32 @@ fn test_round_to_multiple_of_power_of_2() {
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "4722366482869645213696", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, ToOdd, "4722366482869645213696", Greater);
 
     test("4294967295", 31, Down, "2147483648", Less);
     test("4294967295", 31, Up, "4294967296", Greater);
     test("4294967295", 31, Floor, "2147483648", Less);
     test("4294967295", 31, Ceiling, "4294967296", Greater);
     test("4294967295", 31, Nearest, "4294967296", Greater);
+    test("4294967295", 31, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 31, ToOdd, "2147483648", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "4294967296", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "4294967296", Greater);
     test("4294967295", 32, Nearest, "4294967296", Greater);
+    test("4294967295", 32, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 32, ToOdd, "4294967296", Greater);
 
     test("4294967296", 32, Down, "4294967296", Equal);
     test("4294967296", 32, Up, "4294967296", Equal);
     test("4294967296", 32, Floor, "4294967296", Equal);
     test("4294967296", 32, Ceiling, "4294967296", Equal);
     test("4294967296", 32, Nearest, "4294967296", Equal);
+    test("4294967296", 32, NearestTiesAway, "4294967296", Equal);
+    test("4294967296", 32, ToOdd, "4294967296", Equal);
     test("4294967296", 32, Exact, "4294967296", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -601,12 +787,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "8589934592", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "8589934592", Greater);
+    test("4294967296", 33, ToOdd, "8589934592", Greater);
 
     test("-123", 0, Down, "-123", Equal);
     test("-123", 0, Up, "-123", Equal);
     test("-123", 0, Floor, "-123", Equal);
     test("-123", 0, Ceiling, "-123", Equal);
     test("-123", 0, Nearest, "-123", Equal);
+    test("-123", 0, NearestTiesAway, "-123", Equal);
+    test("-123", 0, ToOdd, "-123", Equal);
     test("-123", 0, Exact, "-123", Equal);
 
     test("-245", 1, Down, "-244", Greater);
@@ -614,12 +804,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-245", 1, Floor, "-246", Less);
     test("-245", 1, Ceiling, "-244", Greater);
     test("-245", 1, Nearest, "-244", Greater);
+    test("-245", 1, NearestTiesAway, "-246", Less);
+    test("-245", 1, ToOdd, "-246", Less);
 
     test("-246", 1, Down, "-246", Equal);
     test("-246", 1, Up, "-246", Equal);
     test("-246", 1, Floor, "-246", Equal);
     test("-246", 1, Ceiling, "-246", Equal);
     test("-246", 1, Nearest, "-246", Equal);
+    test("-246", 1, NearestTiesAway, "-246", Equal);
+    test("-246", 1, ToOdd, "-246", Equal);
     test("-246", 1, Exact, "-246", Equal);
 
     test("-247", 1, Down, "-246", Greater);
@@ -627,18 +821,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-247", 1, Floor, "-248", Less);
     test("-247", 1, Ceiling, "-246", Greater);
     test("-247", 1, Nearest, "-248", Less);
+    test("-247", 1, NearestTiesAway, "-248", Less);
+    test("-247", 1, ToOdd, "-246", Greater);
 
     test("-491", 2, Down, "-488", Greater);
     test("-491", 2, Up, "-492", Less);
     test("-491", 2, Floor, "-492", Less);
     test("-491", 2, Ceiling, "-488", Greater);
     test("-491", 2, Nearest, "-492", Less);
+    test("-491", 2, NearestTiesAway, "-492", Less);
+    test("-491", 2, ToOdd, "-492", Less);
 
     test("-492", 2, Down, "-492", Equal);
     test("-492", 2, Up, "-492", Equal);
     test("-492", 2, Floor, "-492", Equal);
     test("-492", 2, Ceiling, "-492", Equal);
     test("-492", 2, Nearest, "-492", Equal);
+    test("-492", 2, NearestTiesAway, "-492", Equal);
+    test("-492", 2, ToOdd, "-492", Equal);
     test("-492", 2, Exact, "-492", Equal);
 
     test("-493", 2, Down, "-492", Greater);
@@ -646,18 +846,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-493", 2, Floor, "-496", Less);
     test("-493", 2, Ceiling, "-492", Greater);
     test("-493", 2, Nearest, "-492", Greater);
+    test("-493", 2, NearestTiesAway, "-492", Greater);
+    test("-493", 2, ToOdd, "-492", Greater);
 
     test("-4127195135", 25, Down, "-4093640704", Greater);
     test("-4127195135", 25, Up, "-4127195136", Less);
     test("-4127195135", 25, Floor, "-4127195136", Less);
     test("-4127195135", 25, Ceiling, "-4093640704", Greater);
     test("-4127195135", 25, Nearest, "-4127195136", Less);
+    test("-4127195135", 25, NearestTiesAway, "-4127195136", Less);
+    test("-4127195135", 25, ToOdd, "-4127195136", Less);
 
     test("-4127195136", 25, Down, "-4127195136", Equal);
     test("-4127195136", 25, Up, "-4127195136", Equal);
     test("-4127195136", 25, Floor, "-4127195136", Equal);
     test("-4127195136", 25, Ceiling, "-4127195136", Equal);
     test("-4127195136", 25, Nearest, "-4127195136", Equal);
+    test("-4127195136", 25, NearestTiesAway, "-4127195136", Equal);
+    test("-4127195136", 25, ToOdd, "-4127195136", Equal);
     test("-4127195136", 25, Exact, "-4127195136", Equal);
 
     test("-4127195137", 25, Down, "-4127195136", Greater);
@@ -665,18 +871,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-4127195137", 25, Floor, "-4160749568", Less);
     test("-4127195137", 25, Ceiling, "-4127195136", Greater);
     test("-4127195137", 25, Nearest, "-4127195136", Greater);
+    test("-4127195137", 25, NearestTiesAway, "-4127195136", Greater);
+    test("-4127195137", 25, ToOdd, "-4127195136", Greater);
 
     test("-8254390271", 26, Down, "-8187281408", Greater);
     test("-8254390271", 26, Up, "-8254390272", Less);
     test("-8254390271", 26, Floor, "-8254390272", Less);
     test("-8254390271", 26, Ceiling, "-8187281408", Greater);
     test("-8254390271", 26, Nearest, "-8254390272", Less);
+    test("-8254390271", 26, NearestTiesAway, "-8254390272", Less);
+    test("-8254390271", 26, ToOdd, "-8254390272", Less);
 
     test("-8254390272", 26, Down, "-8254390272", Equal);
     test("-8254390272", 26, Up, "-8254390272", Equal);
     test("-8254390272", 26, Floor, "-8254390272", Equal);
     test("-8254390272", 26, Ceiling, "-8254390272", Equal);
     test("-8254390272", 26, Nearest, "-8254390272", Equal);
+    test("-8254390272", 26, NearestTiesAway, "-8254390272", Equal);
+    test("-8254390272", 26, ToOdd, "-8254390272", Equal);
     test("-8254390272", 26, Exact, "-8254390272", Equal);
 
     test("-8254390273", 26, Down, "-8254390272", Greater);
@@ -684,6 +896,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-8254390273", 26, Floor, "-8321499136", Less);
     test("-8254390273", 26, Ceiling, "-8254390272", Greater);
     test("-8254390273", 26, Nearest, "-8254390272", Greater);
+    test("-8254390273", 26, NearestTiesAway, "-8254390272", Greater);
+    test("-8254390273", 26, ToOdd, "-8254390272", Greater);
 
     test(
         "-155921023828072216384094494261247",
@@ -720,6 +934,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-155921023828072216384094494261248",
         Less,
     );
+    test(
+        "-155921023828072216384094494261247",
+        100,
+        NearestTiesAway,
+        "-155921023828072216384094494261248",
+        Less,
+    );
+    test(
+        "-155921023828072216384094494261247",
+        100,
+        ToOdd,
+        "-155921023828072216384094494261248",
+        Less,
+    );
 
     test(
         "-155921023828072216384094494261248",
@@ -756,6 +984,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-155921023828072216384094494261248",
         Equal,
     );
+    test(
+        "-155921023828072216384094494261248",
+        100,
+        NearestTiesAway,
+        "-155921023828072216384094494261248",
+        Equal,
+    );
+    test(
+        "-155921023828072216384094494261248",
+        100,
+        ToOdd,
+        "-155921023828072216384094494261248",
+        Equal,
+    );
     test(
         "-155921023828072216384094494261248",
         100,
@@ -799,18 +1041,36 @@ fn test_round_to_multiple_of_power_of_2() {
         "-155921023828072216384094494261248",
         Greater,
     );
+    test(
+        "-155921023828072216384094494261249",
+        100,
+        NearestTiesAway,
+        "-155921023828072216384094494261248",
+        Greater,
+    );
+    test(
+        "-155921023828072216384094494261249",
+        100,
+        ToOdd,
+        "-155921023828072216384094494261248",
+        Greater,
+    );
 
     test("-4294967295", 1, Down, "-4294967294", Greater);
     test("-4294967295", 1, Up, "-4294967296", Less);
     test("-4294967295", 1, Floor, "-4294967296", Less);
     test("-4294967295", 1, Ceiling, "-4294967294", Greater);
     test("-4294967295", 1, Nearest, "-4294967296", Less);
+    test("-4294967295", 1, NearestTiesAway, "-4294967296", Less);
+    test("-4294967295", 1, ToOdd, "-4294967294", Greater);
 
     test("-4294967296", 1, Down, "-4294967296", Equal);
     test("-4294967296", 1, Up, "-4294967296", Equal);
     test("-4294967296", 1, Floor, "-4294967296", Equal);
     test("-4294967296", 1, Ceiling, "-4294967296", Equal);
     test("-4294967296", 1, Nearest, "-4294967296", Equal);
+    test("-4294967296", 1, NearestTiesAway, "-4294967296", Equal);
+    test("-4294967296", 1, ToOdd, "-4294967296", Equal);
     test("-4294967296", 1, Exact, "-4294967296", Equal);
 
     test("-4294967297", 1, Down, "-4294967296", Greater);
@@ -818,12 +1078,22 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-4294967297", 1, Floor, "-4294967298", Less);
     test("-4294967297", 1, Ceiling, "-4294967296", Greater);
     test("-4294967297", 1, Nearest, "-4294967296", Greater);
+    test("-4294967297", 1, NearestTiesAway, "-4294967298", Less);
+    test("-4294967297", 1, ToOdd, "-4294967298", Less);
 
     test("-1000000000000", 0, Down, "-1000000000000", Equal);
     test("-1000000000000", 0, Up, "-1000000000000", Equal);
     test("-1000000000000", 0, Floor, "-1000000000000", Equal);
     test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
     test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+    test(
+        "-1000000000000",
+        0,
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("-1000000000000", 0, ToOdd, "-1000000000000", Equal);
     test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
     test("-7999999999999", 3, Down, "-7999999999992", Greater);
@@ -831,12 +1101,22 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-7999999999999", 3, Floor, "-8000000000000", Less);
     test("-7999999999999", 3, Ceiling, "-7999999999992", Greater);
     test("-7999999999999", 3, Nearest, "-8000000000000", Less);
+    test("-7999999999999", 3, NearestTiesAway, "-8000000000000", Less);
+    test("-7999999999999", 3, ToOdd, "-7999999999992", Greater);
 
     test("-8000000000000", 3, Down, "-8000000000000", Equal);
     test("-8000000000000", 3, Up, "-8000000000000", Equal);
     test("-8000000000000", 3, Floor, "-8000000000000", Equal);
     test("-8000000000000", 3, Ceiling, "-8000000000000", Equal);
     test("-8000000000000", 3, Nearest, "-8000000000000", Equal);
+    test(
+        "-8000000000000",
+        3,
+        NearestTiesAway,
+        "-8000000000000",
+        Equal,
+    );
+    test("-8000000000000", 3, ToOdd, "-8000000000000", Equal);
     test("-8000000000000", 3, Exact, "-8000000000000", Equal);
 
     test("-8000000000001", 3, Down, "-8000000000000", Greater);
@@ -844,6 +1124,14 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-8000000000001", 3, Floor, "-8000000000008", Less);
     test("-8000000000001", 3, Ceiling, "-8000000000000", Greater);
     test("-8000000000001", 3, Nearest, "-8000000000000", Greater);
+    test(
+        "-8000000000001",
+        3,
+        NearestTiesAway,
+        "-8000000000000",
+        Greater,
+    );
+    test("-8000000000001", 3, ToOdd, "-8000000000008", Less);
 
     test(
         "-16777216000000000000",
@@ -880,6 +1168,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-16777216000000000000",
         Equal,
     );
+    test(
+        "-16777216000000000000",
+        24,
+        NearestTiesAway,
+        "-16777216000000000000",
+        Equal,
+    );
+    test(
+        "-16777216000000000000",
+        24,
+        ToOdd,
+        "-16777216000000000000",
+        Equal,
+    );
     test(
         "-16777216000000000000",
         24,
@@ -923,6 +1225,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-33554432000000000000",
         Equal,
     );
+    test(
+        "-33554432000000000000",
+        25,
+        NearestTiesAway,
+        "-33554432000000000000",
+        Equal,
+    );
+    test(
+        "-33554432000000000000",
+        25,
+        ToOdd,
+        "-33554432000000000000",
+        Equal,
+    );
     test(
         "-33554432000000000000",
         25,
@@ -966,6 +1282,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-2147483648000000000000",
         Equal,
     );
+    test(
+        "-2147483648000000000000",
+        31,
+        NearestTiesAway,
+        "-2147483648000000000000",
+        Equal,
+    );
+    test(
+        "-2147483648000000000000",
+        31,
+        ToOdd,
+        "-2147483648000000000000",
+        Equal,
+    );
     test(
         "-2147483648000000000000",
         31,
@@ -1009,6 +1339,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-4294967296000000000000",
         Equal,
     );
+    test(
+        "-4294967296000000000000",
+        32,
+        NearestTiesAway,
+        "-4294967296000000000000",
+        Equal,
+    );
+    test(
+        "-4294967296000000000000",
+        32,
+        ToOdd,
+        "-4294967296000000000000",
+        Equal,
+    );
     test(
         "-4294967296000000000000",
         32,
@@ -1052,6 +1396,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-8589934592000000000000",
         Equal,
     );
+    test(
+        "-8589934592000000000000",
+        33,
+        NearestTiesAway,
+        "-8589934592000000000000",
+        Equal,
+    );
+    test(
+        "-8589934592000000000000",
+        33,
+        ToOdd,
+        "-8589934592000000000000",
+        Equal,
+    );
     test(
         "-8589934592000000000000",
         33,
@@ -1095,6 +1453,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-1267650600228229401496703205376000000000000",
         Equal,
     );
+    test(
+        "-1267650600228229401496703205376000000000000",
+        100,
+        NearestTiesAway,
+        "-1267650600228229401496703205376000000000000",
+        Equal,
+    );
+    test(
+        "-1267650600228229401496703205376000000000000",
+        100,
+        ToOdd,
+        "-1267650600228229401496703205376000000000000",
+        Equal,
+    );
     test(
         "-1267650600228229401496703205376000000000000",
         100,
@@ -1108,6 +1480,14 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-1000000000000", 10, Floor, "-1000000000000", Equal);
     test("-1000000000000", 10, Ceiling, "-1000000000000", Equal);
     test("-1000000000000", 10, Nearest, "-1000000000000", Equal);
+    test(
+        "-1000000000000",
+        10,
+        NearestTiesAway,
+        "-1000000000000",
+        Equal,
+    );
+    test("-1000000000000", 10, ToOdd, "-1000000000000", Equal);
     test("-1000000000000", 10, Exact, "-1000000000000", Equal);
 
     test("-980657949", 72, Down, "0", Greater);
@@ -1115,24 +1495,32 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-980657949", 72, Floor, "-4722366482869645213696", Less);
     test("-980657949", 72, Ceiling, "0", Greater);
     test("-980657949", 72, Nearest, "0", Greater);
+    test("-980657949", 72, NearestTiesAway, "0", Greater);
+    test("-980657949", 72, ToOdd, "-4722366482869645213696", Less);
 
     test("-4294967295", 31, Down, "-2147483648", Greater);
     test("-4294967295", 31, Up, "-4294967296", Less);
     test("-4294967295", 31, Floor, "-4294967296", Less);
     test("-4294967295", 31, Ceiling, "-2147483648", Greater);
     test("-4294967295", 31, Nearest, "-4294967296", Less);
+    test("-4294967295", 31, NearestTiesAway, "-4294967296", Less);
+    test("-4294967295", 31, ToOdd, "-2147483648", Greater);
 
     test("-4294967295", 32, Down, "0", Greater);
     test("-4294967295", 32, Up, "-4294967296", Less);
     test("-4294967295", 32, Floor, "-4294967296", Less);
     test("-4294967295", 32, Ceiling, "0", Greater);
     test("-4294967295", 32, Nearest, "-4294967296", Less);
+    test("-4294967295", 32, NearestTiesAway, "-4294967296", Less);
+    test("-4294967295", 32, ToOdd, "-4294967296", Less);
 
     test("-4294967296", 32, Down, "-4294967296", Equal);
     test("-4294967296", 32, Up, "-4294967296", Equal);
     test("-4294967296", 32, Floor, "-4294967296", Equal);
     test("-4294967296", 32, Ceiling, "-4294967296", Equal);
     test("-4294967296", 32, Nearest, "-4294967296", Equal);
+    test("-4294967296", 32, NearestTiesAway, "-4294967296", Equal);
+    test("-4294967296", 32, ToOdd, "-4294967296", Equal);
     test("-4294967296", 32, Exact, "-4294967296", Equal);
 
     test("-4294967296", 33, Down, "0", Greater);
@@ -1140,6 +1528,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-4294967296", 33, Floor, "-8589934592", Less);
     test("-4294967296", 33, Ceiling, "0", Greater);
     test("-4294967296", 33, Nearest, "0", Greater);
+    test("-4294967296", 33, NearestTiesAway, "-8589934592", Less);
+    test("-4294967296", 33, ToOdd, "-8589934592", Less);
 }
 
 #[test]
@@ -1270,7 +1660,7 @@ fn round_to_multiple_of_power_of_2_properties() {
             Down => assert!(r.le_abs(&n)),
             Up => assert!(r.ge_abs(&n)),
             Exact => assert_eq!(r, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 let k = Integer::power_of_2(pow);
                 let closest;
                 let second_closest;
@@ -1283,9 +1673,14 @@ fn round_to_multiple_of_power_of_2_properties() {
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(!r.get_bit(pow));
+                    if rm == Nearest {
+                        assert!(!r.get_bit(pow));
+                    } else {
+                        assert_eq!(o, if r > 0 { Greater } else { Less });
+                    }
                 }
             }
+            ToOdd => assert!(r == n || r.get_bit(pow)),
         }
     });
 
@@ -1297,6 +1692,11 @@ fn round_to_multiple_of_power_of_2_properties() {
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Floor), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Ceiling), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Nearest), so);
+        assert_eq!(
+            (&shifted).round_to_multiple_of_power_of_2(pow, NearestTiesAway),
+            so
+        );
+        assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, ToOdd), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Exact), so);
     });
 
@@ -1312,6 +1712,10 @@ fn round_to_multiple_of_power_of_2_properties() {
             assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Up), floor);
             assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Down), ceiling);
         }
+        let nearest_ties_away = (&n).round_to_multiple_of_power_of_2(pow, NearestTiesAway);
+        assert!(nearest_ties_away == ceiling || nearest_ties_away == floor);
+        let to_odd = (&n).round_to_multiple_of_power_of_2(pow, ToOdd);
+        assert!(to_odd == ceiling || to_odd == floor);
         let nearest = n.round_to_multiple_of_power_of_2(pow, Nearest);
         assert!(nearest == ceiling || nearest == floor);
     });
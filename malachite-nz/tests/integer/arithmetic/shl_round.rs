@@ -124,6 +124,8 @@ macro_rules! test_shl_round_signed_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, ToOdd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", -10, Down, "0", Equal);
@@ -131,6 +133,8 @@ macro_rules! test_shl_round_signed_helper {
         test("0", -10, Floor, "0", Equal);
         test("0", -10, Ceiling, "0", Equal);
         test("0", -10, Nearest, "0", Equal);
+        test("0", -10, NearestTiesAway, "0", Equal);
+        test("0", -10, ToOdd, "0", Equal);
         test("0", -10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -138,6 +142,8 @@ macro_rules! test_shl_round_signed_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, ToOdd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", -1, Down, "122", Less);
@@ -145,12 +151,16 @@ macro_rules! test_shl_round_signed_helper {
         test("245", -1, Floor, "122", Less);
         test("245", -1, Ceiling, "123", Greater);
         test("245", -1, Nearest, "122", Less);
+        test("245", -1, NearestTiesAway, "123", Greater);
+        test("245", -1, ToOdd, "123", Greater);
 
         test("246", -1, Down, "123", Equal);
         test("246", -1, Up, "123", Equal);
         test("246", -1, Floor, "123", Equal);
         test("246", -1, Ceiling, "123", Equal);
         test("246", -1, Nearest, "123", Equal);
+        test("246", -1, NearestTiesAway, "123", Equal);
+        test("246", -1, ToOdd, "123", Equal);
         test("246", -1, Exact, "123", Equal);
 
         test("247", -1, Down, "123", Less);
@@ -158,18 +168,24 @@ macro_rules! test_shl_round_signed_helper {
         test("247", -1, Floor, "123", Less);
         test("247", -1, Ceiling, "124", Greater);
         test("247", -1, Nearest, "124", Greater);
+        test("247", -1, NearestTiesAway, "124", Greater);
+        test("247", -1, ToOdd, "123", Less);
 
         test("491", -2, Down, "122", Less);
         test("491", -2, Up, "123", Greater);
         test("491", -2, Floor, "122", Less);
         test("491", -2, Ceiling, "123", Greater);
         test("491", -2, Nearest, "123", Greater);
+        test("491", -2, NearestTiesAway, "123", Greater);
+        test("491", -2, ToOdd, "123", Greater);
 
         test("492", -2, Down, "123", Equal);
         test("492", -2, Up, "123", Equal);
         test("492", -2, Floor, "123", Equal);
         test("492", -2, Ceiling, "123", Equal);
         test("492", -2, Nearest, "123", Equal);
+        test("492", -2, NearestTiesAway, "123", Equal);
+        test("492", -2, ToOdd, "123", Equal);
         test("492", -2, Exact, "123", Equal);
 
         test("493", -2, Down, "123", Less);
@@ -177,18 +193,24 @@ macro_rules! test_shl_round_signed_helper {
         test("493", -2, Floor, "123", Less);
         test("493", -2, Ceiling, "124", Greater);
         test("493", -2, Nearest, "123", Less);
+        test("493", -2, NearestTiesAway, "123", Less);
+        test("493", -2, ToOdd, "123", Less);
 
         test("4127195135", -25, Down, "122", Less);
         test("4127195135", -25, Up, "123", Greater);
         test("4127195135", -25, Floor, "122", Less);
         test("4127195135", -25, Ceiling, "123", Greater);
         test("4127195135", -25, Nearest, "123", Greater);
+        test("4127195135", -25, NearestTiesAway, "123", Greater);
+        test("4127195135", -25, ToOdd, "123", Greater);
 
         test("4127195136", -25, Down, "123", Equal);
         test("4127195136", -25, Up, "123", Equal);
         test("4127195136", -25, Floor, "123", Equal);
         test("4127195136", -25, Ceiling, "123", Equal);
         test("4127195136", -25, Nearest, "123", Equal);
+        test("4127195136", -25, NearestTiesAway, "123", Equal);
+        test("4127195136", -25, ToOdd, "123", Equal);
         test("4127195136", -25, Exact, "123", Equal);
 
         test("4127195137", -25, Down, "123", Less);
@@ -196,18 +218,24 @@ macro_rules! test_shl_round_signed_helper {
         test("4127195137", -25, Floor, "123", Less);
         test("4127195137", -25, Ceiling, "124", Greater);
         test("4127195137", -25, Nearest, "123", Less);
+        test("4127195137", -25, NearestTiesAway, "123", Less);
+        test("4127195137", -25, ToOdd, "123", Less);
 
         test("8254390271", -26, Down, "122", Less);
         test("8254390271", -26, Up, "123", Greater);
         test("8254390271", -26, Floor, "122", Less);
         test("8254390271", -26, Ceiling, "123", Greater);
         test("8254390271", -26, Nearest, "123", Greater);
+        test("8254390271", -26, NearestTiesAway, "123", Greater);
+        test("8254390271", -26, ToOdd, "123", Greater);
 
         test("8254390272", -26, Down, "123", Equal);
         test("8254390272", -26, Up, "123", Equal);
         test("8254390272", -26, Floor, "123", Equal);
         test("8254390272", -26, Ceiling, "123", Equal);
         test("8254390272", -26, Nearest, "123", Equal);
+        test("8254390272", -26, NearestTiesAway, "123", Equal);
+        test("8254390272", -26, ToOdd, "123", Equal);
         test("8254390272", -26, Exact, "123", Equal);
 
         test("8254390273", -26, Down, "123", Less);
@@ -215,6 +243,8 @@ macro_rules! test_shl_round_signed_helper {
         test("8254390273", -26, Floor, "123", Less);
         test("8254390273", -26, Ceiling, "124", Greater);
         test("8254390273", -26, Nearest, "123", Less);
+        test("8254390273", -26, NearestTiesAway, "123", Less);
+        test("8254390273", -26, ToOdd, "123", Less);
 
         test("155921023828072216384094494261247", -100, Down, "122", Less);
         test(
@@ -245,6 +275,20 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Greater,
         );
+        test(
+            "155921023828072216384094494261247",
+            -100,
+            NearestTiesAway,
+            "123",
+            Greater,
+        );
+        test(
+            "155921023828072216384094494261247",
+            -100,
+            ToOdd,
+            "123",
+            Greater,
+        );
 
         test(
             "155921023828072216384094494261248",
@@ -275,6 +319,20 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Equal,
         );
+        test(
+            "155921023828072216384094494261248",
+            -100,
+            NearestTiesAway,
+            "123",
+            Equal,
+        );
+        test(
+            "155921023828072216384094494261248",
+            -100,
+            ToOdd,
+            "123",
+            Equal,
+        );
         test(
             "155921023828072216384094494261248",
             -100,
@@ -312,18 +370,36 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Less,
         );
+        test(
+            "155921023828072216384094494261249",
+            -100,
+            NearestTiesAway,
+            "123",
+            Less,
+        );
+        test(
+            "155921023828072216384094494261249",
+            -100,
+            ToOdd,
+            "123",
+            Less,
+        );
 
         test("4294967295", -1, Down, "2147483647", Less);
         test("4294967295", -1, Up, "2147483648", Greater);
         test("4294967295", -1, Floor, "2147483647", Less);
         test("4294967295", -1, Ceiling, "2147483648", Greater);
         test("4294967295", -1, Nearest, "2147483648", Greater);
+        test("4294967295", -1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", -1, ToOdd, "2147483647", Less);
 
         test("4294967296", -1, Down, "2147483648", Equal);
         test("4294967296", -1, Up, "2147483648", Equal);
         test("4294967296", -1, Floor, "2147483648", Equal);
         test("4294967296", -1, Ceiling, "2147483648", Equal);
         test("4294967296", -1, Nearest, "2147483648", Equal);
+        test("4294967296", -1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", -1, ToOdd, "2147483648", Equal);
         test("4294967296", -1, Exact, "2147483648", Equal);
 
         test("4294967297", -1, Down, "2147483648", Less);
@@ -331,12 +407,16 @@ macro_rules! test_shl_round_signed_helper {
         test("4294967297", -1, Floor, "2147483648", Less);
         test("4294967297", -1, Ceiling, "2147483649", Greater);
         test("4294967297", -1, Nearest, "2147483648", Less);
+        test("4294967297", -1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", -1, ToOdd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, ToOdd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", -3, Down, "999999999999", Less);
@@ -344,12 +424,22 @@ macro_rules! test_shl_round_signed_helper {
         test("7999999999999", -3, Floor, "999999999999", Less);
         test("7999999999999", -3, Ceiling, "1000000000000", Greater);
         test("7999999999999", -3, Nearest, "1000000000000", Greater);
+        test(
+            "7999999999999",
+            -3,
+            NearestTiesAway,
+            "1000000000000",
+            Greater,
+        );
+        test("7999999999999", -3, ToOdd, "999999999999", Less);
 
         test("8000000000000", -3, Down, "1000000000000", Equal);
         test("8000000000000", -3, Up, "1000000000000", Equal);
         test("8000000000000", -3, Floor, "1000000000000", Equal);
         test("8000000000000", -3, Ceiling, "1000000000000", Equal);
         test("8000000000000", -3, Nearest, "1000000000000", Equal);
+        test("8000000000000", -3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", -3, ToOdd, "1000000000000", Equal);
         test("8000000000000", -3, Exact, "1000000000000", Equal);
 
         test("8000000000001", -3, Down, "1000000000000", Less);
@@ -357,12 +447,22 @@ macro_rules! test_shl_round_signed_helper {
         test("8000000000001", -3, Floor, "1000000000000", Less);
         test("8000000000001", -3, Ceiling, "1000000000001", Greater);
         test("8000000000001", -3, Nearest, "1000000000000", Less);
+        test("8000000000001", -3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", -3, ToOdd, "1000000000001", Greater);
 
         test("16777216000000000000", -24, Down, "1000000000000", Equal);
         test("16777216000000000000", -24, Up, "1000000000000", Equal);
         test("16777216000000000000", -24, Floor, "1000000000000", Equal);
         test("16777216000000000000", -24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", -24, Nearest, "1000000000000", Equal);
+        test(
+            "16777216000000000000",
+            -24,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("16777216000000000000", -24, ToOdd, "1000000000000", Equal);
         test("16777216000000000000", -24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", -25, Down, "1000000000000", Equal);
@@ -370,6 +470,14 @@ macro_rules! test_shl_round_signed_helper {
         test("33554432000000000000", -25, Floor, "1000000000000", Equal);
         test("33554432000000000000", -25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", -25, Nearest, "1000000000000", Equal);
+        test(
+            "33554432000000000000",
+            -25,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("33554432000000000000", -25, ToOdd, "1000000000000", Equal);
         test("33554432000000000000", -25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", -31, Down, "1000000000000", Equal);
@@ -389,6 +497,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "2147483648000000000000",
+            -31,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("2147483648000000000000", -31, ToOdd, "1000000000000", Equal);
         test("2147483648000000000000", -31, Exact, "1000000000000", Equal);
 
         test("4294967296000000000000", -32, Down, "1000000000000", Equal);
@@ -408,6 +524,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "4294967296000000000000",
+            -32,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("4294967296000000000000", -32, ToOdd, "1000000000000", Equal);
         test("4294967296000000000000", -32, Exact, "1000000000000", Equal);
 
         test("8589934592000000000000", -33, Down, "1000000000000", Equal);
@@ -427,6 +551,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "8589934592000000000000",
+            -33,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("8589934592000000000000", -33, ToOdd, "1000000000000", Equal);
         test("8589934592000000000000", -33, Exact, "1000000000000", Equal);
 
         test(
@@ -464,6 +596,20 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "1267650600228229401496703205376000000000000",
+            -100,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test(
+            "1267650600228229401496703205376000000000000",
+            -100,
+            ToOdd,
+            "1000000000000",
+            Equal,
+        );
         test(
             "1267650600228229401496703205376000000000000",
             -100,
@@ -477,6 +623,8 @@ macro_rules! test_shl_round_signed_helper {
         test("1000000000000", -10, Floor, "976562500", Equal);
         test("1000000000000", -10, Ceiling, "976562500", Equal);
         test("1000000000000", -10, Nearest, "976562500", Equal);
+        test("1000000000000", -10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", -10, ToOdd, "976562500", Equal);
         test("1000000000000", -10, Exact, "976562500", Equal);
 
         test("980657949", -72, Down, "0", Less);
@@ -484,24 +632,32 @@ macro_rules! test_shl_round_signed_helper {
         test("980657949", -72, Floor, "0", Less);
         test("980657949", -72, Ceiling, "1", Greater);
         test("980657949", -72, Nearest, "0", Less);
+        test("980657949", -72, NearestTiesAway, "0", Less);
+        test("980657949", -72, ToOdd, "1", Greater);
 
         test("4294967295", -31, Down, "1", Less);
         test("4294967295", -31, Up, "2", Greater);
         test("4294967295", -31, Floor, "1", Less);
         test("4294967295", -31, Ceiling, "2", Greater);
         test("4294967295", -31, Nearest, "2", Greater);
+        test("4294967295", -31, NearestTiesAway, "2", Greater);
+        test("4294967295", -31, ToOdd, "1", Less);
 
         test("4294967295", -32, Down, "0", Less);
         test("4294967295", -32, Up, "1", Greater);
         test("4294967295", -32, Floor, "0", Less);
         test("4294967295", -32, Ceiling, "1", Greater);
         test("4294967295", -32, Nearest, "1", Greater);
+        test("4294967295", -32, NearestTiesAway, "1", Greater);
+        test("4294967295", -32, ToOdd, "1", Greater);
 
         test("4294967296", -32, Down, "1", Equal);
         test("4294967296", -32, Up, "1", Equal);
         test("4294967296", -32, Floor, "1", Equal);
         test("4294967296", -32, Ceiling, "1", Equal);
         test("4294967296", -32, Nearest, "1", Equal);
+        test("4294967296", -32, NearestTiesAway, "1", Equal);
+        test("4294967296", -32, ToOdd, "1", Equal);
         test("4294967296", -32, Exact, "1", Equal);
 
         test("4294967296", -33, Down, "0", Less);
@@ -509,12 +665,16 @@ macro_rules! test_shl_round_signed_helper {
         test("4294967296", -33, Floor, "0", Less);
         test("4294967296", -33, Ceiling, "1", Greater);
         test("4294967296", -33, Nearest, "0", Less);
+        test("4294967296", -33, NearestTiesAway, "1", Greater);
+        test("4294967296", -33, ToOdd, "1", Greater);
 
         test("-123", 0, Down, "-123", Equal);
         test("-123", 0, Up, "-123", Equal);
         test("-123", 0, Floor, "-123", Equal);
         test("-123", 0, Ceiling, "-123", Equal);
         test("-123", 0, Nearest, "-123", Equal);
+        test("-123", 0, NearestTiesAway, "-123", Equal);
+        test("-123", 0, ToOdd, "-123", Equal);
         test("-123", 0, Exact, "-123", Equal);
 
         test("-245", -1, Down, "-122", Greater);
@@ -522,12 +682,16 @@ macro_rules! test_shl_round_signed_helper {
         test("-245", -1, Floor, "-123", Less);
         test("-245", -1, Ceiling, "-122", Greater);
         test("-245", -1, Nearest, "-122", Greater);
+        test("-245", -1, NearestTiesAway, "-123", Less);
+        test("-245", -1, ToOdd, "-123", Less);
 
         test("-246", -1, Down, "-123", Equal);
         test("-246", -1, Up, "-123", Equal);
         test("-246", -1, Floor, "-123", Equal);
         test("-246", -1, Ceiling, "-123", Equal);
         test("-246", -1, Nearest, "-123", Equal);
+        test("-246", -1, NearestTiesAway, "-123", Equal);
+        test("-246", -1, ToOdd, "-123", Equal);
         test("-246", -1, Exact, "-123", Equal);
 
         test("-247", -1, Down, "-123", Greater);
@@ -535,18 +699,24 @@ macro_rules! test_shl_round_signed_helper {
         test("-247", -1, Floor, "-124", Less);
         test("-247", -1, Ceiling, "-123", Greater);
         test("-247", -1, Nearest, "-124", Less);
+        test("-247", -1, NearestTiesAway, "-124", Less);
+        test("-247", -1, ToOdd, "-123", Greater);
 
         test("-491", -2, Down, "-122", Greater);
         test("-491", -2, Up, "-123", Less);
         test("-491", -2, Floor, "-123", Less);
         test("-491", -2, Ceiling, "-122", Greater);
         test("-491", -2, Nearest, "-123", Less);
+        test("-491", -2, NearestTiesAway, "-123", Less);
+        test("-491", -2, ToOdd, "-123", Less);
 
         test("-492", -2, Down, "-123", Equal);
         test("-492", -2, Up, "-123", Equal);
         test("-492", -2, Floor, "-123", Equal);
         test("-492", -2, Ceiling, "-123", Equal);
         test("-492", -2, Nearest, "-123", Equal);
+        test("-492", -2, NearestTiesAway, "-123", Equal);
+        test("-492", -2, ToOdd, "-123", Equal);
         test("-492", -2, Exact, "-123", Equal);
 
         test("-493", -2, Down, "-123", Greater);
@@ -554,18 +724,24 @@ macro_rules! test_shl_round_signed_helper {
         test("-493", -2, Floor, "-124", Less);
         test("-493", -2, Ceiling, "-123", Greater);
         test("-493", -2, Nearest, "-123", Greater);
+        test("-493", -2, NearestTiesAway, "-123", Greater);
+        test("-493", -2, ToOdd, "-123", Greater);
 
         test("-4127195135", -25, Down, "-122", Greater);
         test("-4127195135", -25, Up, "-123", Less);
         test("-4127195135", -25, Floor, "-123", Less);
         test("-4127195135", -25, Ceiling, "-122", Greater);
         test("-4127195135", -25, Nearest, "-123", Less);
+        test("-4127195135", -25, NearestTiesAway, "-123", Less);
+        test("-4127195135", -25, ToOdd, "-123", Less);
 
         test("-4127195136", -25, Down, "-123", Equal);
         test("-4127195136", -25, Up, "-123", Equal);
         test("-4127195136", -25, Floor, "-123", Equal);
         test("-4127195136", -25, Ceiling, "-123", Equal);
         test("-4127195136", -25, Nearest, "-123", Equal);
+        test("-4127195136", -25, NearestTiesAway, "-123", Equal);
+        test("-4127195136", -25, ToOdd, "-123", Equal);
         test("-4127195136", -25, Exact, "-123", Equal);
 
         test("-4127195137", -25, Down, "-123", Greater);
@@ -573,18 +749,24 @@ macro_rules! test_shl_round_signed_helper {
         test("-4127195137", -25, Floor, "-124", Less);
         test("-4127195137", -25, Ceiling, "-123", Greater);
         test("-4127195137", -25, Nearest, "-123", Greater);
+        test("-4127195137", -25, NearestTiesAway, "-123", Greater);
+        test("-4127195137", -25, ToOdd, "-123", Greater);
 
         test("-8254390271", -26, Down, "-122", Greater);
         test("-8254390271", -26, Up, "-123", Less);
         test("-8254390271", -26, Floor, "-123", Less);
         test("-8254390271", -26, Ceiling, "-122", Greater);
         test("-8254390271", -26, Nearest, "-123", Less);
+        test("-8254390271", -26, NearestTiesAway, "-123", Less);
+        test("-8254390271", -26, ToOdd, "-123", Less);
 
         test("-8254390272", -26, Down, "-123", Equal);
         test("-8254390272", -26, Up, "-123", Equal);
         test("-8254390272", -26, Floor, "-123", Equal);
         test("-8254390272", -26, Ceiling, "-123", Equal);
         test("-8254390272", -26, Nearest, "-123", Equal);
+        test("-8254390272", -26, NearestTiesAway, "-123", Equal);
+        test("-8254390272", -26, ToOdd, "-123", Equal);
         test("-8254390272", -26, Exact, "-123", Equal);
 
         test("-8254390273", -26, Down, "-123", Greater);
@@ -592,6 +774,8 @@ macro_rules! test_shl_round_signed_helper {
         test("-8254390273", -26, Floor, "-124", Less);
         test("-8254390273", -26, Ceiling, "-123", Greater);
         test("-8254390273", -26, Nearest, "-123", Greater);
+        test("-8254390273", -26, NearestTiesAway, "-123", Greater);
+        test("-8254390273", -26, ToOdd, "-123", Greater);
 
         test(
             "-155921023828072216384094494261247",
@@ -622,6 +806,20 @@ macro_rules! test_shl_round_signed_helper {
             "-123",
             Less,
         );
+        test(
+            "-155921023828072216384094494261247",
+            -100,
+            NearestTiesAway,
+            "-123",
+            Less,
+        );
+        test(
+            "-155921023828072216384094494261247",
+            -100,
+            ToOdd,
+            "-123",
+            Less,
+        );
 
         test(
             "-155921023828072216384094494261248",
@@ -658,6 +856,20 @@ macro_rules! test_shl_round_signed_helper {
             "-123",
             Equal,
         );
+        test(
+            "-155921023828072216384094494261248",
+            -100,
+            NearestTiesAway,
+            "-123",
+            Equal,
+        );
+        test(
+            "-155921023828072216384094494261248",
+            -100,
+            ToOdd,
+            "-123",
+            Equal,
+        );
         test(
             "-155921023828072216384094494261248",
             -100,
@@ -695,18 +907,36 @@ macro_rules! test_shl_round_signed_helper {
             "-123",
             Greater,
         );
+        test(
+            "-155921023828072216384094494261249",
+            -100,
+            NearestTiesAway,
+            "-123",
+            Greater,
+        );
+        test(
+            "-155921023828072216384094494261249",
+            -100,
+            ToOdd,
+            "-123",
+            Greater,
+        );
 
         test("-4294967295", -1, Down, "-2147483647", Greater);
         test("-4294967295", -1, Up, "-2147483648", Less);
         test("-4294967295", -1, Floor, "-2147483648", Less);
         test("-4294967295", -1, Ceiling, "-2147483647", Greater);
         test("-4294967295", -1, Nearest, "-2147483648", Less);
+        test("-4294967295", -1, NearestTiesAway, "-2147483648", Less);
+        test("-4294967295", -1, ToOdd, "-2147483647", Greater);
 
         test("-4294967296", -1, Down, "-2147483648", Equal);
         test("-4294967296", -1, Up, "-2147483648", Equal);
         test("-4294967296", -1, Floor, "-2147483648", Equal);
         test("-4294967296", -1, Ceiling, "-2147483648", Equal);
         test("-4294967296", -1, Nearest, "-2147483648", Equal);
+        test("-4294967296", -1, NearestTiesAway, "-2147483648", Equal);
+        test("-4294967296", -1, ToOdd, "-2147483648", Equal);
         test("-4294967296", -1, Exact, "-2147483648", Equal);
 
         test("-4294967297", -1, Down, "-2147483648", Greater);
@@ -714,12 +944,22 @@ macro_rules! test_shl_round_signed_helper {
         test("-4294967297", -1, Floor, "-2147483649", Less);
         test("-4294967297", -1, Ceiling, "-2147483648", Greater);
         test("-4294967297", -1, Nearest, "-2147483648", Greater);
+        test("-4294967297", -1, NearestTiesAway, "-2147483649", Less);
+        test("-4294967297", -1, ToOdd, "-2147483649", Less);
 
         test("-1000000000000", 0, Down, "-1000000000000", Equal);
         test("-1000000000000", 0, Up, "-1000000000000", Equal);
         test("-1000000000000", 0, Floor, "-1000000000000", Equal);
         test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
         test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+        test(
+            "-1000000000000",
+            0,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-1000000000000", 0, ToOdd, "-1000000000000", Equal);
         test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
         test("-7999999999999", -3, Down, "-999999999999", Greater);
@@ -727,12 +967,28 @@ macro_rules! test_shl_round_signed_helper {
         test("-7999999999999", -3, Floor, "-1000000000000", Less);
         test("-7999999999999", -3, Ceiling, "-999999999999", Greater);
         test("-7999999999999", -3, Nearest, "-1000000000000", Less);
+        test(
+            "-7999999999999",
+            -3,
+            NearestTiesAway,
+            "-1000000000000",
+            Less,
+        );
+        test("-7999999999999", -3, ToOdd, "-999999999999", Greater);
 
         test("-8000000000000", -3, Down, "-1000000000000", Equal);
         test("-8000000000000", -3, Up, "-1000000000000", Equal);
         test("-8000000000000", -3, Floor, "-1000000000000", Equal);
         test("-8000000000000", -3, Ceiling, "-1000000000000", Equal);
         test("-8000000000000", -3, Nearest, "-1000000000000", Equal);
+        test(
+            "-8000000000000",
+            -3,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-8000000000000", -3, ToOdd, "-1000000000000", Equal);
         test("-8000000000000", -3, Exact, "-1000000000000", Equal);
 
         test("-8000000000001", -3, Down, "-1000000000000", Greater);
@@ -740,6 +996,14 @@ macro_rules! test_shl_round_signed_helper {
         test("-8000000000001", -3, Floor, "-1000000000001", Less);
         test("-8000000000001", -3, Ceiling, "-1000000000000", Greater);
         test("-8000000000001", -3, Nearest, "-1000000000000", Greater);
+        test(
+            "-8000000000001",
+            -3,
+            NearestTiesAway,
+            "-1000000000000",
+            Greater,
+        );
+        test("-8000000000001", -3, ToOdd, "-1000000000001", Less);
 
         test("-16777216000000000000", -24, Down, "-1000000000000", Equal);
         test("-16777216000000000000", -24, Up, "-1000000000000", Equal);
@@ -758,6 +1022,14 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-16777216000000000000",
+            -24,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-16777216000000000000", -24, ToOdd, "-1000000000000", Equal);
         test("-16777216000000000000", -24, Exact, "-1000000000000", Equal);
 
         test("-33554432000000000000", -25, Down, "-1000000000000", Equal);
@@ -777,6 +1049,14 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-33554432000000000000",
+            -25,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-33554432000000000000", -25, ToOdd, "-1000000000000", Equal);
         test("-33554432000000000000", -25, Exact, "-1000000000000", Equal);
 
         test(
@@ -808,6 +1088,20 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-2147483648000000000000",
+            -31,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-2147483648000000000000",
+            -31,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-2147483648000000000000",
             -31,
@@ -845,6 +1139,20 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-4294967296000000000000",
+            -32,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-4294967296000000000000",
+            -32,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-4294967296000000000000",
             -32,
@@ -882,6 +1190,20 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-8589934592000000000000",
+            -33,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-8589934592000000000000",
+            -33,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-8589934592000000000000",
             -33,
@@ -925,6 +1247,20 @@ macro_rules! test_shl_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            -100,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            -100,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-1267650600228229401496703205376000000000000",
             -100,
@@ -938,6 +1274,8 @@ macro_rules! test_shl_round_signed_helper {
         test("-1000000000000", -10, Floor, "-976562500", Equal);
         test("-1000000000000", -10, Ceiling, "-976562500", Equal);
         test("-1000000000000", -10, Nearest, "-976562500", Equal);
+        test("-1000000000000", -10, NearestTiesAway, "-976562500", Equal);
+        test("-1000000000000", -10, ToOdd, "-976562500", Equal);
         test("-1000000000000", -10, Exact, "-976562500", Equal);
 
         test("-980657949", -72, Down, "0", Greater);
@@ -945,24 +1283,32 @@ macro_rules! test_shl_round_signed_helper {
         test("-980657949", -72, Floor, "-1", Less);
         test("-980657949", -72, Ceiling, "0", Greater);
         test("-980657949", -72, Nearest, "0", Greater);
+        test("-980657949", -72, NearestTiesAway, "0", Greater);
+        test("-980657949", -72, ToOdd, "-1", Less);
 
         test("-4294967295", -31, Down, "-1", Greater);
         test("-4294967295", -31, Up, "-2", Less);
         test("-4294967295", -31, Floor, "-2", Less);
         test("-4294967295", -31, Ceiling, "-1", Greater);
         test("-4294967295", -31, Nearest, "-2", Less);
+        test("-4294967295", -31, NearestTiesAway, "-2", Less);
+        test("-4294967295", -31, ToOdd, "-1", Greater);
 
         test("-4294967295", -32, Down, "0", Greater);
         test("-4294967295", -32, Up, "-1", Less);
         test("-4294967295", -32, Floor, "-1", Less);
         test("-4294967295", -32, Ceiling, "0", Greater);
         test("-4294967295", -32, Nearest, "-1", Less);
+        test("-4294967295", -32, NearestTiesAway, "-1", Less);
+        test("-4294967295", -32, ToOdd, "-1", Less);
 
         test("-4294967296", -32, Down, "-1", Equal);
         test("-4294967296", -32, Up, "-1", Equal);
         test("-4294967296", -32, Floor, "-1", Equal);
         test("-4294967296", -32, Ceiling, "-1", Equal);
         test("-4294967296", -32, Nearest, "-1", Equal);
+        test("-4294967296", -32, NearestTiesAway, "-1", Equal);
+        test("-4294967296", -32, ToOdd, "-1", Equal);
         test("-4294967296", -32, Exact, "-1", Equal);
 
         test("-4294967296", -33, Down, "0", Greater);
@@ -970,6 +1316,8 @@ macro_rules! test_shl_round_signed_helper {
         test("-4294967296", -33, Floor, "-1", Less);
         test("-4294967296", -33, Ceiling, "0", Greater);
         test("-4294967296", -33, Nearest, "0", Greater);
+        test("-4294967296", -33, NearestTiesAway, "-1", Less);
+        test("-4294967296", -33, ToOdd, "-1", Less);
     };
 }
 
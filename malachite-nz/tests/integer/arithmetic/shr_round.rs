@@ -58,6 +58,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, ToOdd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", 10, Down, "0", Equal);
@@ -65,6 +67,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("0", 10, Floor, "0", Equal);
         test("0", 10, Ceiling, "0", Equal);
         test("0", 10, Nearest, "0", Equal);
+        test("0", 10, NearestTiesAway, "0", Equal);
+        test("0", 10, ToOdd, "0", Equal);
         test("0", 10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -72,6 +76,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, ToOdd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", 1, Down, "122", Less);
@@ -79,12 +85,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("245", 1, Floor, "122", Less);
         test("245", 1, Ceiling, "123", Greater);
         test("245", 1, Nearest, "122", Less);
+        test("245", 1, NearestTiesAway, "123", Greater);
+        test("245", 1, ToOdd, "123", Greater);
 
         test("246", 1, Down, "123", Equal);
         test("246", 1, Up, "123", Equal);
         test("246", 1, Floor, "123", Equal);
         test("246", 1, Ceiling, "123", Equal);
         test("246", 1, Nearest, "123", Equal);
+        test("246", 1, NearestTiesAway, "123", Equal);
+        test("246", 1, ToOdd, "123", Equal);
         test("246", 1, Exact, "123", Equal);
 
         test("247", 1, Down, "123", Less);
@@ -92,18 +102,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("247", 1, Floor, "123", Less);
         test("247", 1, Ceiling, "124", Greater);
         test("247", 1, Nearest, "124", Greater);
+        test("247", 1, NearestTiesAway, "124", Greater);
+        test("247", 1, ToOdd, "123", Less);
 
         test("491", 2, Down, "122", Less);
         test("491", 2, Up, "123", Greater);
         test("491", 2, Floor, "122", Less);
         test("491", 2, Ceiling, "123", Greater);
         test("491", 2, Nearest, "123", Greater);
+        test("491", 2, NearestTiesAway, "123", Greater);
+        test("491", 2, ToOdd, "123", Greater);
 
         test("492", 2, Down, "123", Equal);
         test("492", 2, Up, "123", Equal);
         test("492", 2, Floor, "123", Equal);
         test("492", 2, Ceiling, "123", Equal);
         test("492", 2, Nearest, "123", Equal);
+        test("492", 2, NearestTiesAway, "123", Equal);
+        test("492", 2, ToOdd, "123", Equal);
         test("492", 2, Exact, "123", Equal);
 
         test("493", 2, Down, "123", Less);
@@ -111,18 +127,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("493", 2, Floor, "123", Less);
         test("493", 2, Ceiling, "124", Greater);
         test("493", 2, Nearest, "123", Less);
+        test("493", 2, NearestTiesAway, "123", Less);
+        test("493", 2, ToOdd, "123", Less);
 
         test("4127195135", 25, Down, "122", Less);
         test("4127195135", 25, Up, "123", Greater);
         test("4127195135", 25, Floor, "122", Less);
         test("4127195135", 25, Ceiling, "123", Greater);
         test("4127195135", 25, Nearest, "123", Greater);
+        test("4127195135", 25, NearestTiesAway, "123", Greater);
+        test("4127195135", 25, ToOdd, "123", Greater);
 
         test("4127195136", 25, Down, "123", Equal);
         test("4127195136", 25, Up, "123", Equal);
         test("4127195136", 25, Floor, "123", Equal);
         test("4127195136", 25, Ceiling, "123", Equal);
         test("4127195136", 25, Nearest, "123", Equal);
+        test("4127195136", 25, NearestTiesAway, "123", Equal);
+        test("4127195136", 25, ToOdd, "123", Equal);
         test("4127195136", 25, Exact, "123", Equal);
 
         test("4127195137", 25, Down, "123", Less);
@@ -130,18 +152,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4127195137", 25, Floor, "123", Less);
         test("4127195137", 25, Ceiling, "124", Greater);
         test("4127195137", 25, Nearest, "123", Less);
+        test("4127195137", 25, NearestTiesAway, "123", Less);
+        test("4127195137", 25, ToOdd, "123", Less);
 
         test("8254390271", 26, Down, "122", Less);
         test("8254390271", 26, Up, "123", Greater);
         test("8254390271", 26, Floor, "122", Less);
         test("8254390271", 26, Ceiling, "123", Greater);
         test("8254390271", 26, Nearest, "123", Greater);
+        test("8254390271", 26, NearestTiesAway, "123", Greater);
+        test("8254390271", 26, ToOdd, "123", Greater);
 
         test("8254390272", 26, Down, "123", Equal);
         test("8254390272", 26, Up, "123", Equal);
         test("8254390272", 26, Floor, "123", Equal);
         test("8254390272", 26, Ceiling, "123", Equal);
         test("8254390272", 26, Nearest, "123", Equal);
+        test("8254390272", 26, NearestTiesAway, "123", Equal);
+        test("8254390272", 26, ToOdd, "123", Equal);
         test("8254390272", 26, Exact, "123", Equal);
 
         test("8254390273", 26, Down, "123", Less);
@@ -149,6 +177,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("8254390273", 26, Floor, "123", Less);
         test("8254390273", 26, Ceiling, "124", Greater);
         test("8254390273", 26, Nearest, "123", Less);
+        test("8254390273", 26, NearestTiesAway, "123", Less);
+        test("8254390273", 26, ToOdd, "123", Less);
 
         test("155921023828072216384094494261247", 100, Down, "122", Less);
         test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -167,6 +197,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "123",
             Greater,
         );
+        test(
+            "155921023828072216384094494261247",
+            100,
+            NearestTiesAway,
+            "123",
+            Greater,
+        );
+        test(
+            "155921023828072216384094494261247",
+            100,
+            ToOdd,
+            "123",
+            Greater,
+        );
 
         test("155921023828072216384094494261248", 100, Down, "123", Equal);
         test("155921023828072216384094494261248", 100, Up, "123", Equal);
@@ -191,6 +235,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "123",
             Equal,
         );
+        test(
+            "155921023828072216384094494261248",
+            100,
+            NearestTiesAway,
+            "123",
+            Equal,
+        );
+        test(
+            "155921023828072216384094494261248",
+            100,
+            ToOdd,
+            "123",
+            Equal,
+        );
         test(
             "155921023828072216384094494261248",
             100,
@@ -216,18 +274,30 @@ macro_rules! test_shr_round_unsigned_helper {
             "123",
             Less,
         );
+        test(
+            "155921023828072216384094494261249",
+            100,
+            NearestTiesAway,
+            "123",
+            Less,
+        );
+        test("155921023828072216384094494261249", 100, ToOdd, "123", Less);
 
         test("4294967295", 1, Down, "2147483647", Less);
         test("4294967295", 1, Up, "2147483648", Greater);
         test("4294967295", 1, Floor, "2147483647", Less);
         test("4294967295", 1, Ceiling, "2147483648", Greater);
         test("4294967295", 1, Nearest, "2147483648", Greater);
+        test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", 1, ToOdd, "2147483647", Less);
 
         test("4294967296", 1, Down, "2147483648", Equal);
         test("4294967296", 1, Up, "2147483648", Equal);
         test("4294967296", 1, Floor, "2147483648", Equal);
         test("4294967296", 1, Ceiling, "2147483648", Equal);
         test("4294967296", 1, Nearest, "2147483648", Equal);
+        test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", 1, ToOdd, "2147483648", Equal);
         test("4294967296", 1, Exact, "2147483648", Equal);
 
         test("4294967297", 1, Down, "2147483648", Less);
@@ -235,12 +305,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4294967297", 1, Floor, "2147483648", Less);
         test("4294967297", 1, Ceiling, "2147483649", Greater);
         test("4294967297", 1, Nearest, "2147483648", Less);
+        test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", 1, ToOdd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, ToOdd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", 3, Down, "999999999999", Less);
@@ -248,12 +322,22 @@ macro_rules! test_shr_round_unsigned_helper {
         test("7999999999999", 3, Floor, "999999999999", Less);
         test("7999999999999", 3, Ceiling, "1000000000000", Greater);
         test("7999999999999", 3, Nearest, "1000000000000", Greater);
+        test(
+            "7999999999999",
+            3,
+            NearestTiesAway,
+            "1000000000000",
+            Greater,
+        );
+        test("7999999999999", 3, ToOdd, "999999999999", Less);
 
         test("8000000000000", 3, Down, "1000000000000", Equal);
         test("8000000000000", 3, Up, "1000000000000", Equal);
         test("8000000000000", 3, Floor, "1000000000000", Equal);
         test("8000000000000", 3, Ceiling, "1000000000000", Equal);
         test("8000000000000", 3, Nearest, "1000000000000", Equal);
+        test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", 3, ToOdd, "1000000000000", Equal);
         test("8000000000000", 3, Exact, "1000000000000", Equal);
 
         test("8000000000001", 3, Down, "1000000000000", Less);
@@ -261,12 +345,22 @@ macro_rules! test_shr_round_unsigned_helper {
         test("8000000000001", 3, Floor, "1000000000000", Less);
         test("8000000000001", 3, Ceiling, "1000000000001", Greater);
         test("8000000000001", 3, Nearest, "1000000000000", Less);
+        test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", 3, ToOdd, "1000000000001", Greater);
 
         test("16777216000000000000", 24, Down, "1000000000000", Equal);
         test("16777216000000000000", 24, Up, "1000000000000", Equal);
         test("16777216000000000000", 24, Floor, "1000000000000", Equal);
         test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+        test(
+            "16777216000000000000",
+            24,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("16777216000000000000", 24, ToOdd, "1000000000000", Equal);
         test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -274,6 +368,14 @@ macro_rules! test_shr_round_unsigned_helper {
         test("33554432000000000000", 25, Floor, "1000000000000", Equal);
         test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+        test(
+            "33554432000000000000",
+            25,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("33554432000000000000", 25, ToOdd, "1000000000000", Equal);
         test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -293,6 +395,14 @@ macro_rules! test_shr_round_unsigned_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "2147483648000000000000",
+            31,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("2147483648000000000000", 31, ToOdd, "1000000000000", Equal);
         test("2147483648000000000000", 31, Exact, "1000000000000", Equal);
 
         test("4294967296000000000000", 32, Down, "1000000000000", Equal);
@@ -312,6 +422,14 @@ macro_rules! test_shr_round_unsigned_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "4294967296000000000000",
+            32,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("4294967296000000000000", 32, ToOdd, "1000000000000", Equal);
         test("4294967296000000000000", 32, Exact, "1000000000000", Equal);
 
         test("8589934592000000000000", 33, Down, "1000000000000", Equal);
@@ -331,6 +449,14 @@ macro_rules! test_shr_round_unsigned_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "8589934592000000000000",
+            33,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("8589934592000000000000", 33, ToOdd, "1000000000000", Equal);
         test("8589934592000000000000", 33, Exact, "1000000000000", Equal);
 
         test(
@@ -368,6 +494,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "1267650600228229401496703205376000000000000",
+            100,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test(
+            "1267650600228229401496703205376000000000000",
+            100,
+            ToOdd,
+            "1000000000000",
+            Equal,
+        );
         test(
             "1267650600228229401496703205376000000000000",
             100,
@@ -381,6 +521,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("1000000000000", 10, Floor, "976562500", Equal);
         test("1000000000000", 10, Ceiling, "976562500", Equal);
         test("1000000000000", 10, Nearest, "976562500", Equal);
+        test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", 10, ToOdd, "976562500", Equal);
         test("1000000000000", 10, Exact, "976562500", Equal);
 
         test("980657949", 72, Down, "0", Less);
@@ -388,24 +530,32 @@ macro_rules! test_shr_round_unsigned_helper {
         test("980657949", 72, Floor, "0", Less);
         test("980657949", 72, Ceiling, "1", Greater);
         test("980657949", 72, Nearest, "0", Less);
+        test("980657949", 72, NearestTiesAway, "0", Less);
+        test("980657949", 72, ToOdd, "1", Greater);
 
         test("4294967295", 31, Down, "1", Less);
         test("4294967295", 31, Up, "2", Greater);
         test("4294967295", 31, Floor, "1", Less);
         test("4294967295", 31, Ceiling, "2", Greater);
         test("4294967295", 31, Nearest, "2", Greater);
+        test("4294967295", 31, NearestTiesAway, "2", Greater);
+        test("4294967295", 31, ToOdd, "1", Less);
 
         test("4294967295", 32, Down, "0", Less);
         test("4294967295", 32, Up, "1", Greater);
         test("4294967295", 32, Floor, "0", Less);
         test("4294967295", 32, Ceiling, "1", Greater);
         test("4294967295", 32, Nearest, "1", Greater);
+        test("4294967295", 32, NearestTiesAway, "1", Greater);
+        test("4294967295", 32, ToOdd, "1", Greater);
 
         test("4294967296", 32, Down, "1", Equal);
         test("4294967296", 32, Up, "1", Equal);
         test("4294967296", 32, Floor, "1", Equal);
         test("4294967296", 32, Ceiling, "1", Equal);
         test("4294967296", 32, Nearest, "1", Equal);
+        test("4294967296", 32, NearestTiesAway, "1", Equal);
+        test("4294967296", 32, ToOdd, "1", Equal);
         test("4294967296", 32, Exact, "1", Equal);
 
         test("4294967296", 33, Down, "0", Less);
@@ -413,12 +563,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("4294967296", 33, Floor, "0", Less);
         test("4294967296", 33, Ceiling, "1", Greater);
         test("4294967296", 33, Nearest, "0", Less);
+        test("4294967296", 33, NearestTiesAway, "1", Greater);
+        test("4294967296", 33, ToOdd, "1", Greater);
 
         test("-123", 0, Down, "-123", Equal);
         test("-123", 0, Up, "-123", Equal);
         test("-123", 0, Floor, "-123", Equal);
         test("-123", 0, Ceiling, "-123", Equal);
         test("-123", 0, Nearest, "-123", Equal);
+        test("-123", 0, NearestTiesAway, "-123", Equal);
+        test("-123", 0, ToOdd, "-123", Equal);
         test("-123", 0, Exact, "-123", Equal);
 
         test("-245", 1, Down, "-122", Greater);
@@ -426,12 +580,16 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-245", 1, Floor, "-123", Less);
         test("-245", 1, Ceiling, "-122", Greater);
         test("-245", 1, Nearest, "-122", Greater);
+        test("-245", 1, NearestTiesAway, "-123", Less);
+        test("-245", 1, ToOdd, "-123", Less);
 
         test("-246", 1, Down, "-123", Equal);
         test("-246", 1, Up, "-123", Equal);
         test("-246", 1, Floor, "-123", Equal);
         test("-246", 1, Ceiling, "-123", Equal);
         test("-246", 1, Nearest, "-123", Equal);
+        test("-246", 1, NearestTiesAway, "-123", Equal);
+        test("-246", 1, ToOdd, "-123", Equal);
         test("-246", 1, Exact, "-123", Equal);
 
         test("-247", 1, Down, "-123", Greater);
@@ -439,18 +597,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-247", 1, Floor, "-124", Less);
         test("-247", 1, Ceiling, "-123", Greater);
         test("-247", 1, Nearest, "-124", Less);
+        test("-247", 1, NearestTiesAway, "-124", Less);
+        test("-247", 1, ToOdd, "-123", Greater);
 
         test("-491", 2, Down, "-122", Greater);
         test("-491", 2, Up, "-123", Less);
         test("-491", 2, Floor, "-123", Less);
         test("-491", 2, Ceiling, "-122", Greater);
         test("-491", 2, Nearest, "-123", Less);
+        test("-491", 2, NearestTiesAway, "-123", Less);
+        test("-491", 2, ToOdd, "-123", Less);
 
         test("-492", 2, Down, "-123", Equal);
         test("-492", 2, Up, "-123", Equal);
         test("-492", 2, Floor, "-123", Equal);
         test("-492", 2, Ceiling, "-123", Equal);
         test("-492", 2, Nearest, "-123", Equal);
+        test("-492", 2, NearestTiesAway, "-123", Equal);
+        test("-492", 2, ToOdd, "-123", Equal);
         test("-492", 2, Exact, "-123", Equal);
 
         test("-493", 2, Down, "-123", Greater);
@@ -458,18 +622,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-493", 2, Floor, "-124", Less);
         test("-493", 2, Ceiling, "-123", Greater);
         test("-493", 2, Nearest, "-123", Greater);
+        test("-493", 2, NearestTiesAway, "-123", Greater);
+        test("-493", 2, ToOdd, "-123", Greater);
 
         test("-4127195135", 25, Down, "-122", Greater);
         test("-4127195135", 25, Up, "-123", Less);
         test("-4127195135", 25, Floor, "-123", Less);
         test("-4127195135", 25, Ceiling, "-122", Greater);
         test("-4127195135", 25, Nearest, "-123", Less);
+        test("-4127195135", 25, NearestTiesAway, "-123", Less);
+        test("-4127195135", 25, ToOdd, "-123", Less);
 
         test("-4127195136", 25, Down, "-123", Equal);
         test("-4127195136", 25, Up, "-123", Equal);
         test("-4127195136", 25, Floor, "-123", Equal);
         test("-4127195136", 25, Ceiling, "-123", Equal);
         test("-4127195136", 25, Nearest, "-123", Equal);
+        test("-4127195136", 25, NearestTiesAway, "-123", Equal);
+        test("-4127195136", 25, ToOdd, "-123", Equal);
         test("-4127195136", 25, Exact, "-123", Equal);
 
         test("-4127195137", 25, Down, "-123", Greater);
@@ -477,18 +647,24 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4127195137", 25, Floor, "-124", Less);
         test("-4127195137", 25, Ceiling, "-123", Greater);
         test("-4127195137", 25, Nearest, "-123", Greater);
+        test("-4127195137", 25, NearestTiesAway, "-123", Greater);
+        test("-4127195137", 25, ToOdd, "-123", Greater);
 
         test("-8254390271", 26, Down, "-122", Greater);
         test("-8254390271", 26, Up, "-123", Less);
         test("-8254390271", 26, Floor, "-123", Less);
         test("-8254390271", 26, Ceiling, "-122", Greater);
         test("-8254390271", 26, Nearest, "-123", Less);
+        test("-8254390271", 26, NearestTiesAway, "-123", Less);
+        test("-8254390271", 26, ToOdd, "-123", Less);
 
         test("-8254390272", 26, Down, "-123", Equal);
         test("-8254390272", 26, Up, "-123", Equal);
         test("-8254390272", 26, Floor, "-123", Equal);
         test("-8254390272", 26, Ceiling, "-123", Equal);
         test("-8254390272", 26, Nearest, "-123", Equal);
+        test("-8254390272", 26, NearestTiesAway, "-123", Equal);
+        test("-8254390272", 26, ToOdd, "-123", Equal);
         test("-8254390272", 26, Exact, "-123", Equal);
 
         test("-8254390273", 26, Down, "-123", Greater);
@@ -496,6 +672,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-8254390273", 26, Floor, "-124", Less);
         test("-8254390273", 26, Ceiling, "-123", Greater);
         test("-8254390273", 26, Nearest, "-123", Greater);
+        test("-8254390273", 26, NearestTiesAway, "-123", Greater);
+        test("-8254390273", 26, ToOdd, "-123", Greater);
 
         test(
             "-155921023828072216384094494261247",
@@ -526,6 +704,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-123",
             Less,
         );
+        test(
+            "-155921023828072216384094494261247",
+            100,
+            NearestTiesAway,
+            "-123",
+            Less,
+        );
+        test(
+            "-155921023828072216384094494261247",
+            100,
+            ToOdd,
+            "-123",
+            Less,
+        );
 
         test(
             "-155921023828072216384094494261248",
@@ -556,6 +748,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-123",
             Equal,
         );
+        test(
+            "-155921023828072216384094494261248",
+            100,
+            NearestTiesAway,
+            "-123",
+            Equal,
+        );
+        test(
+            "-155921023828072216384094494261248",
+            100,
+            ToOdd,
+            "-123",
+            Equal,
+        );
         test(
             "-155921023828072216384094494261248",
             100,
@@ -593,18 +799,36 @@ macro_rules! test_shr_round_unsigned_helper {
             "-123",
             Greater,
         );
+        test(
+            "-155921023828072216384094494261249",
+            100,
+            NearestTiesAway,
+            "-123",
+            Greater,
+        );
+        test(
+            "-155921023828072216384094494261249",
+            100,
+            ToOdd,
+            "-123",
+            Greater,
+        );
 
         test("-4294967295", 1, Down, "-2147483647", Greater);
         test("-4294967295", 1, Up, "-2147483648", Less);
         test("-4294967295", 1, Floor, "-2147483648", Less);
         test("-4294967295", 1, Ceiling, "-2147483647", Greater);
         test("-4294967295", 1, Nearest, "-2147483648", Less);
+        test("-4294967295", 1, NearestTiesAway, "-2147483648", Less);
+        test("-4294967295", 1, ToOdd, "-2147483647", Greater);
 
         test("-4294967296", 1, Down, "-2147483648", Equal);
         test("-4294967296", 1, Up, "-2147483648", Equal);
         test("-4294967296", 1, Floor, "-2147483648", Equal);
         test("-4294967296", 1, Ceiling, "-2147483648", Equal);
         test("-4294967296", 1, Nearest, "-2147483648", Equal);
+        test("-4294967296", 1, NearestTiesAway, "-2147483648", Equal);
+        test("-4294967296", 1, ToOdd, "-2147483648", Equal);
         test("-4294967296", 1, Exact, "-2147483648", Equal);
 
         test("-4294967297", 1, Down, "-2147483648", Greater);
@@ -612,12 +836,22 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4294967297", 1, Floor, "-2147483649", Less);
         test("-4294967297", 1, Ceiling, "-2147483648", Greater);
         test("-4294967297", 1, Nearest, "-2147483648", Greater);
+        test("-4294967297", 1, NearestTiesAway, "-2147483649", Less);
+        test("-4294967297", 1, ToOdd, "-2147483649", Less);
 
         test("-1000000000000", 0, Down, "-1000000000000", Equal);
         test("-1000000000000", 0, Up, "-1000000000000", Equal);
         test("-1000000000000", 0, Floor, "-1000000000000", Equal);
         test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
         test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+        test(
+            "-1000000000000",
+            0,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-1000000000000", 0, ToOdd, "-1000000000000", Equal);
         test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
         test("-7999999999999", 3, Down, "-999999999999", Greater);
@@ -625,12 +859,22 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-7999999999999", 3, Floor, "-1000000000000", Less);
         test("-7999999999999", 3, Ceiling, "-999999999999", Greater);
         test("-7999999999999", 3, Nearest, "-1000000000000", Less);
+        test("-7999999999999", 3, NearestTiesAway, "-1000000000000", Less);
+        test("-7999999999999", 3, ToOdd, "-999999999999", Greater);
 
         test("-8000000000000", 3, Down, "-1000000000000", Equal);
         test("-8000000000000", 3, Up, "-1000000000000", Equal);
         test("-8000000000000", 3, Floor, "-1000000000000", Equal);
         test("-8000000000000", 3, Ceiling, "-1000000000000", Equal);
         test("-8000000000000", 3, Nearest, "-1000000000000", Equal);
+        test(
+            "-8000000000000",
+            3,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-8000000000000", 3, ToOdd, "-1000000000000", Equal);
         test("-8000000000000", 3, Exact, "-1000000000000", Equal);
 
         test("-8000000000001", 3, Down, "-1000000000000", Greater);
@@ -638,6 +882,14 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-8000000000001", 3, Floor, "-1000000000001", Less);
         test("-8000000000001", 3, Ceiling, "-1000000000000", Greater);
         test("-8000000000001", 3, Nearest, "-1000000000000", Greater);
+        test(
+            "-8000000000001",
+            3,
+            NearestTiesAway,
+            "-1000000000000",
+            Greater,
+        );
+        test("-8000000000001", 3, ToOdd, "-1000000000001", Less);
 
         test("-16777216000000000000", 24, Down, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Up, "-1000000000000", Equal);
@@ -656,6 +908,14 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-16777216000000000000",
+            24,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-16777216000000000000", 24, ToOdd, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Exact, "-1000000000000", Equal);
 
         test("-33554432000000000000", 25, Down, "-1000000000000", Equal);
@@ -675,6 +935,14 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-33554432000000000000",
+            25,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-33554432000000000000", 25, ToOdd, "-1000000000000", Equal);
         test("-33554432000000000000", 25, Exact, "-1000000000000", Equal);
 
         test("-2147483648000000000000", 31, Down, "-1000000000000", Equal);
@@ -700,6 +968,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-2147483648000000000000",
+            31,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-2147483648000000000000",
+            31,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-2147483648000000000000",
             31,
@@ -731,6 +1013,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-4294967296000000000000",
+            32,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-4294967296000000000000",
+            32,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-4294967296000000000000",
             32,
@@ -762,6 +1058,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-8589934592000000000000",
+            33,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-8589934592000000000000",
+            33,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-8589934592000000000000",
             33,
@@ -805,6 +1115,20 @@ macro_rules! test_shr_round_unsigned_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            100,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            100,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-1267650600228229401496703205376000000000000",
             100,
@@ -818,6 +1142,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-1000000000000", 10, Floor, "-976562500", Equal);
         test("-1000000000000", 10, Ceiling, "-976562500", Equal);
         test("-1000000000000", 10, Nearest, "-976562500", Equal);
+        test("-1000000000000", 10, NearestTiesAway, "-976562500", Equal);
+        test("-1000000000000", 10, ToOdd, "-976562500", Equal);
         test("-1000000000000", 10, Exact, "-976562500", Equal);
 
         test("-980657949", 72, Down, "0", Greater);
@@ -825,24 +1151,32 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-980657949", 72, Floor, "-1", Less);
         test("-980657949", 72, Ceiling, "0", Greater);
         test("-980657949", 72, Nearest, "0", Greater);
+        test("-980657949", 72, NearestTiesAway, "0", Greater);
+        test("-980657949", 72, ToOdd, "-1", Less);
 
         test("-4294967295", 31, Down, "-1", Greater);
         test("-4294967295", 31, Up, "-2", Less);
         test("-4294967295", 31, Floor, "-2", Less);
         test("-4294967295", 31, Ceiling, "-1", Greater);
         test("-4294967295", 31, Nearest, "-2", Less);
+        test("-4294967295", 31, NearestTiesAway, "-2", Less);
+        test("-4294967295", 31, ToOdd, "-1", Greater);
 
         test("-4294967295", 32, Down, "0", Greater);
         test("-4294967295", 32, Up, "-1", Less);
         test("-4294967295", 32, Floor, "-1", Less);
         test("-4294967295", 32, Ceiling, "0", Greater);
         test("-4294967295", 32, Nearest, "-1", Less);
+        test("-4294967295", 32, NearestTiesAway, "-1", Less);
+        test("-4294967295", 32, ToOdd, "-1", Less);
 
         test("-4294967296", 32, Down, "-1", Equal);
         test("-4294967296", 32, Up, "-1", Equal);
         test("-4294967296", 32, Floor, "-1", Equal);
         test("-4294967296", 32, Ceiling, "-1", Equal);
         test("-4294967296", 32, Nearest, "-1", Equal);
+        test("-4294967296", 32, NearestTiesAway, "-1", Equal);
+        test("-4294967296", 32, ToOdd, "-1", Equal);
         test("-4294967296", 32, Exact, "-1", Equal);
 
         test("-4294967296", 33, Down, "0", Greater);
@@ -850,6 +1184,8 @@ macro_rules! test_shr_round_unsigned_helper {
         test("-4294967296", 33, Floor, "-1", Less);
         test("-4294967296", 33, Ceiling, "0", Greater);
         test("-4294967296", 33, Nearest, "0", Greater);
+        test("-4294967296", 33, NearestTiesAway, "-1", Less);
+        test("-4294967296", 33, ToOdd, "-1", Less);
     };
 }
 
@@ -915,6 +1251,8 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, ToOdd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", 10, Down, "0", Equal);
@@ -922,6 +1260,8 @@ macro_rules! test_shr_round_signed_helper {
         test("0", 10, Floor, "0", Equal);
         test("0", 10, Ceiling, "0", Equal);
         test("0", 10, Nearest, "0", Equal);
+        test("0", 10, NearestTiesAway, "0", Equal);
+        test("0", 10, ToOdd, "0", Equal);
         test("0", 10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -929,6 +1269,8 @@ macro_rules! test_shr_round_signed_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, ToOdd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", 1, Down, "122", Less);
@@ -936,12 +1278,16 @@ macro_rules! test_shr_round_signed_helper {
         test("245", 1, Floor, "122", Less);
         test("245", 1, Ceiling, "123", Greater);
         test("245", 1, Nearest, "122", Less);
+        test("245", 1, NearestTiesAway, "123", Greater);
+        test("245", 1, ToOdd, "123", Greater);
 
         test("246", 1, Down, "123", Equal);
         test("246", 1, Up, "123", Equal);
         test("246", 1, Floor, "123", Equal);
         test("246", 1, Ceiling, "123", Equal);
         test("246", 1, Nearest, "123", Equal);
+        test("246", 1, NearestTiesAway, "123", Equal);
+        test("246", 1, ToOdd, "123", Equal);
         test("246", 1, Exact, "123", Equal);
 
         test("247", 1, Down, "123", Less);
@@ -949,18 +1295,24 @@ macro_rules! test_shr_round_signed_helper {
         test("247", 1, Floor, "123", Less);
         test("247", 1, Ceiling, "124", Greater);
         test("247", 1, Nearest, "124", Greater);
+        test("247", 1, NearestTiesAway, "124", Greater);
+        test("247", 1, ToOdd, "123", Less);
 
         test("491", 2, Down, "122", Less);
         test("491", 2, Up, "123", Greater);
         test("491", 2, Floor, "122", Less);
         test("491", 2, Ceiling, "123", Greater);
         test("491", 2, Nearest, "123", Greater);
+        test("491", 2, NearestTiesAway, "123", Greater);
+        test("491", 2, ToOdd, "123", Greater);
 
         test("492", 2, Down, "123", Equal);
         test("492", 2, Up, "123", Equal);
         test("492", 2, Floor, "123", Equal);
         test("492", 2, Ceiling, "123", Equal);
         test("492", 2, Nearest, "123", Equal);
+        test("492", 2, NearestTiesAway, "123", Equal);
+        test("492", 2, ToOdd, "123", Equal);
         test("492", 2, Exact, "123", Equal);
 
         test("493", 2, Down, "123", Less);
@@ -968,18 +1320,24 @@ macro_rules! test_shr_round_signed_helper {
         test("493", 2, Floor, "123", Less);
         test("493", 2, Ceiling, "124", Greater);
         test("493", 2, Nearest, "123", Less);
+        test("493", 2, NearestTiesAway, "123", Less);
+        test("493", 2, ToOdd, "123", Less);
 
         test("4127195135", 25, Down, "122", Less);
         test("4127195135", 25, Up, "123", Greater);
         test("4127195135", 25, Floor, "122", Less);
         test("4127195135", 25, Ceiling, "123", Greater);
         test("4127195135", 25, Nearest, "123", Greater);
+        test("4127195135", 25, NearestTiesAway, "123", Greater);
+        test("4127195135", 25, ToOdd, "123", Greater);
 
         test("4127195136", 25, Down, "123", Equal);
         test("4127195136", 25, Up, "123", Equal);
         test("4127195136", 25, Floor, "123", Equal);
         test("4127195136", 25, Ceiling, "123", Equal);
         test("4127195136", 25, Nearest, "123", Equal);
+        test("4127195136", 25, NearestTiesAway, "123", Equal);
+        test("4127195136", 25, ToOdd, "123", Equal);
         test("4127195136", 25, Exact, "123", Equal);
 
         test("4127195137", 25, Down, "123", Less);
@@ -987,18 +1345,24 @@ macro_rules! test_shr_round_signed_helper {
         test("4127195137", 25, Floor, "123", Less);
         test("4127195137", 25, Ceiling, "124", Greater);
         test("4127195137", 25, Nearest, "123", Less);
+        test("4127195137", 25, NearestTiesAway, "123", Less);
+        test("4127195137", 25, ToOdd, "123", Less);
 
         test("8254390271", 26, Down, "122", Less);
         test("8254390271", 26, Up, "123", Greater);
         test("8254390271", 26, Floor, "122", Less);
         test("8254390271", 26, Ceiling, "123", Greater);
         test("8254390271", 26, Nearest, "123", Greater);
+        test("8254390271", 26, NearestTiesAway, "123", Greater);
+        test("8254390271", 26, ToOdd, "123", Greater);
 
         test("8254390272", 26, Down, "123", Equal);
         test("8254390272", 26, Up, "123", Equal);
         test("8254390272", 26, Floor, "123", Equal);
         test("8254390272", 26, Ceiling, "123", Equal);
         test("8254390272", 26, Nearest, "123", Equal);
+        test("8254390272", 26, NearestTiesAway, "123", Equal);
+        test("8254390272", 26, ToOdd, "123", Equal);
         test("8254390272", 26, Exact, "123", Equal);
 
         test("8254390273", 26, Down, "123", Less);
@@ -1006,6 +1370,8 @@ macro_rules! test_shr_round_signed_helper {
         test("8254390273", 26, Floor, "123", Less);
         test("8254390273", 26, Ceiling, "124", Greater);
         test("8254390273", 26, Nearest, "123", Less);
+        test("8254390273", 26, NearestTiesAway, "123", Less);
+        test("8254390273", 26, ToOdd, "123", Less);
 
         test("155921023828072216384094494261247", 100, Down, "122", Less);
         test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -1024,6 +1390,20 @@ macro_rules! test_shr_round_signed_helper {
             "123",
             Greater,
         );
+        test(
+            "155921023828072216384094494261247",
+            100,
+            NearestTiesAway,
+            "123",
+            Greater,
+        );
+        test(
+            "155921023828072216384094494261247",
+            100,
+            ToOdd,
+            "123",
+            Greater,
+        );
 
         test("155921023828072216384094494261248", 100, Down, "123", Equal);
         test("155921023828072216384094494261248", 100, Up, "123", Equal);
@@ -1048,6 +1428,20 @@ macro_rules! test_shr_round_signed_helper {
             "123",
             Equal,
         );
+        test(
+            "155921023828072216384094494261248",
+            100,
+            NearestTiesAway,
+            "123",
+            Equal,
+        );
+        test(
+            "155921023828072216384094494261248",
+            100,
+            ToOdd,
+            "123",
+            Equal,
+        );
         test(
             "155921023828072216384094494261248",
             100,
@@ -1073,18 +1467,30 @@ macro_rules! test_shr_round_signed_helper {
             "123",
             Less,
         );
+        test(
+            "155921023828072216384094494261249",
+            100,
+            NearestTiesAway,
+            "123",
+            Less,
+        );
+        test("155921023828072216384094494261249", 100, ToOdd, "123", Less);
 
         test("4294967295", 1, Down, "2147483647", Less);
         test("4294967295", 1, Up, "2147483648", Greater);
         test("4294967295", 1, Floor, "2147483647", Less);
         test("4294967295", 1, Ceiling, "2147483648", Greater);
         test("4294967295", 1, Nearest, "2147483648", Greater);
+        test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", 1, ToOdd, "2147483647", Less);
 
         test("4294967296", 1, Down, "2147483648", Equal);
         test("4294967296", 1, Up, "2147483648", Equal);
         test("4294967296", 1, Floor, "2147483648", Equal);
         test("4294967296", 1, Ceiling, "2147483648", Equal);
         test("4294967296", 1, Nearest, "2147483648", Equal);
+        test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", 1, ToOdd, "2147483648", Equal);
         test("4294967296", 1, Exact, "2147483648", Equal);
 
         test("4294967297", 1, Down, "2147483648", Less);
@@ -1092,12 +1498,16 @@ macro_rules! test_shr_round_signed_helper {
         test("4294967297", 1, Floor, "2147483648", Less);
         test("4294967297", 1, Ceiling, "2147483649", Greater);
         test("4294967297", 1, Nearest, "2147483648", Less);
+        test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", 1, ToOdd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, ToOdd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", 3, Down, "999999999999", Less);
@@ -1105,12 +1515,22 @@ macro_rules! test_shr_round_signed_helper {
         test("7999999999999", 3, Floor, "999999999999", Less);
         test("7999999999999", 3, Ceiling, "1000000000000", Greater);
         test("7999999999999", 3, Nearest, "1000000000000", Greater);
+        test(
+            "7999999999999",
+            3,
+            NearestTiesAway,
+            "1000000000000",
+            Greater,
+        );
+        test("7999999999999", 3, ToOdd, "999999999999", Less);
 
         test("8000000000000", 3, Down, "1000000000000", Equal);
         test("8000000000000", 3, Up, "1000000000000", Equal);
         test("8000000000000", 3, Floor, "1000000000000", Equal);
         test("8000000000000", 3, Ceiling, "1000000000000", Equal);
         test("8000000000000", 3, Nearest, "1000000000000", Equal);
+        test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", 3, ToOdd, "1000000000000", Equal);
         test("8000000000000", 3, Exact, "1000000000000", Equal);
 
         test("8000000000001", 3, Down, "1000000000000", Less);
@@ -1118,12 +1538,22 @@ macro_rules! test_shr_round_signed_helper {
         test("8000000000001", 3, Floor, "1000000000000", Less);
         test("8000000000001", 3, Ceiling, "1000000000001", Greater);
         test("8000000000001", 3, Nearest, "1000000000000", Less);
+        test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", 3, ToOdd, "1000000000001", Greater);
 
         test("16777216000000000000", 24, Down, "1000000000000", Equal);
         test("16777216000000000000", 24, Up, "1000000000000", Equal);
         test("16777216000000000000", 24, Floor, "1000000000000", Equal);
         test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+        test(
+            "16777216000000000000",
+            24,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("16777216000000000000", 24, ToOdd, "1000000000000", Equal);
         test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -1131,6 +1561,14 @@ macro_rules! test_shr_round_signed_helper {
         test("33554432000000000000", 25, Floor, "1000000000000", Equal);
         test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+        test(
+            "33554432000000000000",
+            25,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("33554432000000000000", 25, ToOdd, "1000000000000", Equal);
         test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -1150,6 +1588,14 @@ macro_rules! test_shr_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "2147483648000000000000",
+            31,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("2147483648000000000000", 31, ToOdd, "1000000000000", Equal);
         test("2147483648000000000000", 31, Exact, "1000000000000", Equal);
 
         test("4294967296000000000000", 32, Down, "1000000000000", Equal);
@@ -1169,6 +1615,14 @@ macro_rules! test_shr_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "4294967296000000000000",
+            32,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("4294967296000000000000", 32, ToOdd, "1000000000000", Equal);
         test("4294967296000000000000", 32, Exact, "1000000000000", Equal);
 
         test("8589934592000000000000", 33, Down, "1000000000000", Equal);
@@ -1188,6 +1642,14 @@ macro_rules! test_shr_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "8589934592000000000000",
+            33,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("8589934592000000000000", 33, ToOdd, "1000000000000", Equal);
         test("8589934592000000000000", 33, Exact, "1000000000000", Equal);
 
         test(
@@ -1225,6 +1687,20 @@ macro_rules! test_shr_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "1267650600228229401496703205376000000000000",
+            100,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test(
+            "1267650600228229401496703205376000000000000",
+            100,
+            ToOdd,
+            "1000000000000",
+            Equal,
+        );
         test(
             "1267650600228229401496703205376000000000000",
             100,
@@ -1238,6 +1714,8 @@ macro_rules! test_shr_round_signed_helper {
         test("1000000000000", 10, Floor, "976562500", Equal);
         test("1000000000000", 10, Ceiling, "976562500", Equal);
         test("1000000000000", 10, Nearest, "976562500", Equal);
+        test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", 10, ToOdd, "976562500", Equal);
         test("1000000000000", 10, Exact, "976562500", Equal);
 
         test("980657949", 72, Down, "0", Less);
@@ -1245,24 +1723,32 @@ macro_rules! test_shr_round_signed_helper {
         test("980657949", 72, Floor, "0", Less);
         test("980657949", 72, Ceiling, "1", Greater);
         test("980657949", 72, Nearest, "0", Less);
+        test("980657949", 72, NearestTiesAway, "0", Less);
+        test("980657949", 72, ToOdd, "1", Greater);
 
         test("4294967295", 31, Down, "1", Less);
         test("4294967295", 31, Up, "2", Greater);
         test("4294967295", 31, Floor, "1", Less);
         test("4294967295", 31, Ceiling, "2", Greater);
         test("4294967295", 31, Nearest, "2", Greater);
+        test("4294967295", 31, NearestTiesAway, "2", Greater);
+        test("4294967295", 31, ToOdd, "1", Less);
 
         test("4294967295", 32, Down, "0", Less);
         test("4294967295", 32, Up, "1", Greater);
         test("4294967295", 32, Floor, "0", Less);
         test("4294967295", 32, Ceiling, "1", Greater);
         test("4294967295", 32, Nearest, "1", Greater);
+        test("4294967295", 32, NearestTiesAway, "1", Greater);
+        test("4294967295", 32, ToOdd, "1", Greater);
 
         test("4294967296", 32, Down, "1", Equal);
         test("4294967296", 32, Up, "1", Equal);
         test("4294967296", 32, Floor, "1", Equal);
         test("4294967296", 32, Ceiling, "1", Equal);
         test("4294967296", 32, Nearest, "1", Equal);
+        test("4294967296", 32, NearestTiesAway, "1", Equal);
+        test("4294967296", 32, ToOdd, "1", Equal);
         test("4294967296", 32, Exact, "1", Equal);
 
         test("4294967296", 33, Down, "0", Less);
@@ -1270,12 +1756,16 @@ macro_rules! test_shr_round_signed_helper {
         test("4294967296", 33, Floor, "0", Less);
         test("4294967296", 33, Ceiling, "1", Greater);
         test("4294967296", 33, Nearest, "0", Less);
+        test("4294967296", 33, NearestTiesAway, "1", Greater);
+        test("4294967296", 33, ToOdd, "1", Greater);
 
         test("-123", 0, Down, "-123", Equal);
         test("-123", 0, Up, "-123", Equal);
         test("-123", 0, Floor, "-123", Equal);
         test("-123", 0, Ceiling, "-123", Equal);
         test("-123", 0, Nearest, "-123", Equal);
+        test("-123", 0, NearestTiesAway, "-123", Equal);
+        test("-123", 0, ToOdd, "-123", Equal);
         test("-123", 0, Exact, "-123", Equal);
 
         test("-245", 1, Down, "-122", Greater);
@@ -1283,12 +1773,16 @@ macro_rules! test_shr_round_signed_helper {
         test("-245", 1, Floor, "-123", Less);
         test("-245", 1, Ceiling, "-122", Greater);
         test("-245", 1, Nearest, "-122", Greater);
+        test("-245", 1, NearestTiesAway, "-123", Less);
+        test("-245", 1, ToOdd, "-123", Less);
 
         test("-246", 1, Down, "-123", Equal);
         test("-246", 1, Up, "-123", Equal);
         test("-246", 1, Floor, "-123", Equal);
         test("-246", 1, Ceiling, "-123", Equal);
         test("-246", 1, Nearest, "-123", Equal);
+        test("-246", 1, NearestTiesAway, "-123", Equal);
+        test("-246", 1, ToOdd, "-123", Equal);
         test("-246", 1, Exact, "-123", Equal);
 
         test("-247", 1, Down, "-123", Greater);
@@ -1296,18 +1790,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-247", 1, Floor, "-124", Less);
         test("-247", 1, Ceiling, "-123", Greater);
         test("-247", 1, Nearest, "-124", Less);
+        test("-247", 1, NearestTiesAway, "-124", Less);
+        test("-247", 1, ToOdd, "-123", Greater);
 
         test("-491", 2, Down, "-122", Greater);
         test("-491", 2, Up, "-123", Less);
         test("-491", 2, Floor, "-123", Less);
         test("-491", 2, Ceiling, "-122", Greater);
         test("-491", 2, Nearest, "-123", Less);
+        test("-491", 2, NearestTiesAway, "-123", Less);
+        test("-491", 2, ToOdd, "-123", Less);
 
         test("-492", 2, Down, "-123", Equal);
         test("-492", 2, Up, "-123", Equal);
         test("-492", 2, Floor, "-123", Equal);
         test("-492", 2, Ceiling, "-123", Equal);
         test("-492", 2, Nearest, "-123", Equal);
+        test("-492", 2, NearestTiesAway, "-123", Equal);
+        test("-492", 2, ToOdd, "-123", Equal);
         test("-492", 2, Exact, "-123", Equal);
 
         test("-493", 2, Down, "-123", Greater);
@@ -1315,18 +1815,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-493", 2, Floor, "-124", Less);
         test("-493", 2, Ceiling, "-123", Greater);
         test("-493", 2, Nearest, "-123", Greater);
+        test("-493", 2, NearestTiesAway, "-123", Greater);
+        test("-493", 2, ToOdd, "-123", Greater);
 
         test("-4127195135", 25, Down, "-122", Greater);
         test("-4127195135", 25, Up, "-123", Less);
         test("-4127195135", 25, Floor, "-123", Less);
         test("-4127195135", 25, Ceiling, "-122", Greater);
         test("-4127195135", 25, Nearest, "-123", Less);
+        test("-4127195135", 25, NearestTiesAway, "-123", Less);
+        test("-4127195135", 25, ToOdd, "-123", Less);
 
         test("-4127195136", 25, Down, "-123", Equal);
         test("-4127195136", 25, Up, "-123", Equal);
         test("-4127195136", 25, Floor, "-123", Equal);
         test("-4127195136", 25, Ceiling, "-123", Equal);
         test("-4127195136", 25, Nearest, "-123", Equal);
+        test("-4127195136", 25, NearestTiesAway, "-123", Equal);
+        test("-4127195136", 25, ToOdd, "-123", Equal);
         test("-4127195136", 25, Exact, "-123", Equal);
 
         test("-4127195137", 25, Down, "-123", Greater);
@@ -1334,18 +1840,24 @@ macro_rules! test_shr_round_signed_helper {
         test("-4127195137", 25, Floor, "-124", Less);
         test("-4127195137", 25, Ceiling, "-123", Greater);
         test("-4127195137", 25, Nearest, "-123", Greater);
+        test("-4127195137", 25, NearestTiesAway, "-123", Greater);
+        test("-4127195137", 25, ToOdd, "-123", Greater);
 
         test("-8254390271", 26, Down, "-122", Greater);
         test("-8254390271", 26, Up, "-123", Less);
         test("-8254390271", 26, Floor, "-123", Less);
         test("-8254390271", 26, Ceiling, "-122", Greater);
         test("-8254390271", 26, Nearest, "-123", Less);
+        test("-8254390271", 26, NearestTiesAway, "-123", Less);
+        test("-8254390271", 26, ToOdd, "-123", Less);
 
         test("-8254390272", 26, Down, "-123", Equal);
         test("-8254390272", 26, Up, "-123", Equal);
         test("-8254390272", 26, Floor, "-123", Equal);
         test("-8254390272", 26, Ceiling, "-123", Equal);
         test("-8254390272", 26, Nearest, "-123", Equal);
+        test("-8254390272", 26, NearestTiesAway, "-123", Equal);
+        test("-8254390272", 26, ToOdd, "-123", Equal);
         test("-8254390272", 26, Exact, "-123", Equal);
 
         test("-8254390273", 26, Down, "-123", Greater);
@@ -1353,6 +1865,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-8254390273", 26, Floor, "-124", Less);
         test("-8254390273", 26, Ceiling, "-123", Greater);
         test("-8254390273", 26, Nearest, "-123", Greater);
+        test("-8254390273", 26, NearestTiesAway, "-123", Greater);
+        test("-8254390273", 26, ToOdd, "-123", Greater);
 
         test(
             "-155921023828072216384094494261247",
@@ -1383,6 +1897,20 @@ macro_rules! test_shr_round_signed_helper {
             "-123",
             Less,
         );
+        test(
+            "-155921023828072216384094494261247",
+            100,
+            NearestTiesAway,
+            "-123",
+            Less,
+        );
+        test(
+            "-155921023828072216384094494261247",
+            100,
+            ToOdd,
+            "-123",
+            Less,
+        );
 
         test(
             "-155921023828072216384094494261248",
@@ -1413,6 +1941,20 @@ macro_rules! test_shr_round_signed_helper {
             "-123",
             Equal,
         );
+        test(
+            "-155921023828072216384094494261248",
+            100,
+            NearestTiesAway,
+            "-123",
+            Equal,
+        );
+        test(
+            "-155921023828072216384094494261248",
+            100,
+            ToOdd,
+            "-123",
+            Equal,
+        );
         test(
             "-155921023828072216384094494261248",
             100,
@@ -1450,18 +1992,36 @@ macro_rules! test_shr_round_signed_helper {
             "-123",
             Greater,
         );
+        test(
+            "-155921023828072216384094494261249",
+            100,
+            NearestTiesAway,
+            "-123",
+            Greater,
+        );
+        test(
+            "-155921023828072216384094494261249",
+            100,
+            ToOdd,
+            "-123",
+            Greater,
+        );
 
         test("-4294967295", 1, Down, "-2147483647", Greater);
         test("-4294967295", 1, Up, "-2147483648", Less);
         test("-4294967295", 1, Floor, "-2147483648", Less);
         test("-4294967295", 1, Ceiling, "-2147483647", Greater);
         test("-4294967295", 1, Nearest, "-2147483648", Less);
+        test("-4294967295", 1, NearestTiesAway, "-2147483648", Less);
+        test("-4294967295", 1, ToOdd, "-2147483647", Greater);
 
         test("-4294967296", 1, Down, "-2147483648", Equal);
         test("-4294967296", 1, Up, "-2147483648", Equal);
         test("-4294967296", 1, Floor, "-2147483648", Equal);
         test("-4294967296", 1, Ceiling, "-2147483648", Equal);
         test("-4294967296", 1, Nearest, "-2147483648", Equal);
+        test("-4294967296", 1, NearestTiesAway, "-2147483648", Equal);
+        test("-4294967296", 1, ToOdd, "-2147483648", Equal);
         test("-4294967296", 1, Exact, "-2147483648", Equal);
 
         test("-4294967297", 1, Down, "-2147483648", Greater);
@@ -1469,12 +2029,22 @@ macro_rules! test_shr_round_signed_helper {
         test("-4294967297", 1, Floor, "-2147483649", Less);
         test("-4294967297", 1, Ceiling, "-2147483648", Greater);
         test("-4294967297", 1, Nearest, "-2147483648", Greater);
+        test("-4294967297", 1, NearestTiesAway, "-2147483649", Less);
+        test("-4294967297", 1, ToOdd, "-2147483649", Less);
 
         test("-1000000000000", 0, Down, "-1000000000000", Equal);
         test("-1000000000000", 0, Up, "-1000000000000", Equal);
         test("-1000000000000", 0, Floor, "-1000000000000", Equal);
         test("-1000000000000", 0, Ceiling, "-1000000000000", Equal);
         test("-1000000000000", 0, Nearest, "-1000000000000", Equal);
+        test(
+            "-1000000000000",
+            0,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-1000000000000", 0, ToOdd, "-1000000000000", Equal);
         test("-1000000000000", 0, Exact, "-1000000000000", Equal);
 
         test("-7999999999999", 3, Down, "-999999999999", Greater);
@@ -1482,12 +2052,22 @@ macro_rules! test_shr_round_signed_helper {
         test("-7999999999999", 3, Floor, "-1000000000000", Less);
         test("-7999999999999", 3, Ceiling, "-999999999999", Greater);
         test("-7999999999999", 3, Nearest, "-1000000000000", Less);
+        test("-7999999999999", 3, NearestTiesAway, "-1000000000000", Less);
+        test("-7999999999999", 3, ToOdd, "-999999999999", Greater);
 
         test("-8000000000000", 3, Down, "-1000000000000", Equal);
         test("-8000000000000", 3, Up, "-1000000000000", Equal);
         test("-8000000000000", 3, Floor, "-1000000000000", Equal);
         test("-8000000000000", 3, Ceiling, "-1000000000000", Equal);
         test("-8000000000000", 3, Nearest, "-1000000000000", Equal);
+        test(
+            "-8000000000000",
+            3,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-8000000000000", 3, ToOdd, "-1000000000000", Equal);
         test("-8000000000000", 3, Exact, "-1000000000000", Equal);
 
         test("-8000000000001", 3, Down, "-1000000000000", Greater);
@@ -1495,6 +2075,14 @@ macro_rules! test_shr_round_signed_helper {
         test("-8000000000001", 3, Floor, "-1000000000001", Less);
         test("-8000000000001", 3, Ceiling, "-1000000000000", Greater);
         test("-8000000000001", 3, Nearest, "-1000000000000", Greater);
+        test(
+            "-8000000000001",
+            3,
+            NearestTiesAway,
+            "-1000000000000",
+            Greater,
+        );
+        test("-8000000000001", 3, ToOdd, "-1000000000001", Less);
 
         test("-16777216000000000000", 24, Down, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Up, "-1000000000000", Equal);
@@ -1513,6 +2101,14 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-16777216000000000000",
+            24,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-16777216000000000000", 24, ToOdd, "-1000000000000", Equal);
         test("-16777216000000000000", 24, Exact, "-1000000000000", Equal);
 
         test("-33554432000000000000", 25, Down, "-1000000000000", Equal);
@@ -1532,6 +2128,14 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-33554432000000000000",
+            25,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test("-33554432000000000000", 25, ToOdd, "-1000000000000", Equal);
         test("-33554432000000000000", 25, Exact, "-1000000000000", Equal);
 
         test("-2147483648000000000000", 31, Down, "-1000000000000", Equal);
@@ -1557,6 +2161,20 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-2147483648000000000000",
+            31,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-2147483648000000000000",
+            31,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-2147483648000000000000",
             31,
@@ -1588,6 +2206,20 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-4294967296000000000000",
+            32,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-4294967296000000000000",
+            32,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-4294967296000000000000",
             32,
@@ -1619,6 +2251,20 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-8589934592000000000000",
+            33,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-8589934592000000000000",
+            33,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-8589934592000000000000",
             33,
@@ -1662,6 +2308,20 @@ macro_rules! test_shr_round_signed_helper {
             "-1000000000000",
             Equal,
         );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            100,
+            NearestTiesAway,
+            "-1000000000000",
+            Equal,
+        );
+        test(
+            "-1267650600228229401496703205376000000000000",
+            100,
+            ToOdd,
+            "-1000000000000",
+            Equal,
+        );
         test(
             "-1267650600228229401496703205376000000000000",
             100,
@@ -1675,6 +2335,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-1000000000000", 10, Floor, "-976562500", Equal);
         test("-1000000000000", 10, Ceiling, "-976562500", Equal);
         test("-1000000000000", 10, Nearest, "-976562500", Equal);
+        test("-1000000000000", 10, NearestTiesAway, "-976562500", Equal);
+        test("-1000000000000", 10, ToOdd, "-976562500", Equal);
         test("-1000000000000", 10, Exact, "-976562500", Equal);
 
         test("-980657949", 72, Down, "0", Greater);
@@ -1682,24 +2344,32 @@ macro_rules! test_shr_round_signed_helper {
         test("-980657949", 72, Floor, "-1", Less);
         test("-980657949", 72, Ceiling, "0", Greater);
         test("-980657949", 72, Nearest, "0", Greater);
+        test("-980657949", 72, NearestTiesAway, "0", Greater);
+        test("-980657949", 72, ToOdd, "-1", Less);
 
         test("-4294967295", 31, Down, "-1", Greater);
         test("-4294967295", 31, Up, "-2", Less);
         test("-4294967295", 31, Floor, "-2", Less);
         test("-4294967295", 31, Ceiling, "-1", Greater);
         test("-4294967295", 31, Nearest, "-2", Less);
+        test("-4294967295", 31, NearestTiesAway, "-2", Less);
+        test("-4294967295", 31, ToOdd, "-1", Greater);
 
         test("-4294967295", 32, Down, "0", Greater);
         test("-4294967295", 32, Up, "-1", Less);
         test("-4294967295", 32, Floor, "-1", Less);
         test("-4294967295", 32, Ceiling, "0", Greater);
         test("-4294967295", 32, Nearest, "-1", Less);
+        test("-4294967295", 32, NearestTiesAway, "-1", Less);
+        test("-4294967295", 32, ToOdd, "-1", Less);
 
         test("-4294967296", 32, Down, "-1", Equal);
         test("-4294967296", 32, Up, "-1", Equal);
         test("-4294967296", 32, Floor, "-1", Equal);
         test("-4294967296", 32, Ceiling, "-1", Equal);
         test("-4294967296", 32, Nearest, "-1", Equal);
+        test("-4294967296", 32, NearestTiesAway, "-1", Equal);
+        test("-4294967296", 32, ToOdd, "-1", Equal);
         test("-4294967296", 32, Exact, "-1", Equal);
 
         test("-4294967296", 33, Down, "0", Greater);
@@ -1707,6 +2377,8 @@ macro_rules! test_shr_round_signed_helper {
         test("-4294967296", 33, Floor, "-1", Less);
         test("-4294967296", 33, Ceiling, "0", Greater);
         test("-4294967296", 33, Nearest, "0", Greater);
+        test("-4294967296", 33, NearestTiesAway, "-1", Less);
+        test("-4294967296", 33, ToOdd, "-1", Less);
 
         test("0", -10, Exact, "0", Equal);
         test("123", -1, Exact, "246", Equal);
@@ -1870,6 +2542,8 @@ where
         assert_eq!((&left_shifted).shr_round(u, Floor), no);
         assert_eq!((&left_shifted).shr_round(u, Ceiling), no);
         assert_eq!((&left_shifted).shr_round(u, Nearest), no);
+        assert_eq!((&left_shifted).shr_round(u, NearestTiesAway), no);
+        assert_eq!((&left_shifted).shr_round(u, ToOdd), no);
         assert_eq!((&left_shifted).shr_round(u, Exact), no);
     });
 
@@ -1885,6 +2559,10 @@ where
             assert_eq!((&n).shr_round(u, Up), floor);
             assert_eq!((&n).shr_round(u, Down), ceiling);
         }
+        let nearest_ties_away = (&n).shr_round(u, NearestTiesAway);
+        assert!(nearest_ties_away == floor || nearest_ties_away == ceiling);
+        let to_odd = (&n).shr_round(u, ToOdd);
+        assert!(to_odd == floor || to_odd == ceiling);
         let nearest = n.shr_round(u, Nearest);
         assert!(nearest == floor || nearest == ceiling);
     });
@@ -0,0 +1,78 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use num::BigInt;
+
+#[test]
+fn test_from_signed_bytes_le() {
+    let test = |xs: &[u8], out| {
+        let x = Integer::from_signed_bytes_le(xs);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        let mut xs_be = xs.to_vec();
+        xs_be.reverse();
+        let x = Integer::from_signed_bytes_be(&xs_be);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[133], "-123");
+    test(&[123, 0, 0], "123");
+    test(&[133, 255, 255], "-123");
+    test(&[128, 0], "128");
+    test(&[128], "-128");
+    test(&[127, 255], "-129");
+    test(&[255], "-1");
+    test(&[255; 9], "-1");
+    test(&[0xcc, 0xed], "-4660");
+    test(&[0x00, 0x10, 0xa5, 0xd4, 0xe8, 0x00], "1000000000000");
+    test(&[0x00, 0xf0, 0x5a, 0x2b, 0x17, 0xff], "-1000000000000");
+    test(&[0x00, 0x10, 0xa5, 0xd4, 0xe8], "-99511627776");
+    test(&[0, 0, 0, 0, 0, 0, 0, 128], "-9223372036854775808");
+    test(&[0, 0, 0, 0, 0, 0, 0, 128, 0], "9223372036854775808");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 255], "-18446744073709551616");
+}
+
+#[test]
+fn from_signed_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Integer::from_signed_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(Integer::from(&BigInt::from_signed_bytes_le(&xs)), x);
+        let mut xs_be = xs.clone();
+        xs_be.reverse();
+        assert_eq!(Integer::from_signed_bytes_be(&xs_be), x);
+        if xs.last().map_or(true, |&b| b < 128) {
+            assert_eq!(Integer::from(Natural::from_bytes_le(&xs)), x);
+        }
+        let bytes = x.to_signed_bytes_le();
+        assert!(bytes.len() <= xs.len());
+        assert_eq!(bytes, xs[..bytes.len()]);
+    });
+}
+
+#[test]
+fn from_signed_bytes_be_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Integer::from_signed_bytes_be(&xs);
+        assert!(x.is_valid());
+        assert_eq!(Integer::from(&BigInt::from_signed_bytes_be(&xs)), x);
+        if xs.first().map_or(true, |&b| b < 128) {
+            assert_eq!(Integer::from(Natural::from_bytes_be(&xs)), x);
+        }
+        let bytes = x.to_signed_bytes_be();
+        assert!(bytes.len() <= xs.len());
+        assert_eq!(bytes, xs[xs.len() - bytes.len()..]);
+    });
+}
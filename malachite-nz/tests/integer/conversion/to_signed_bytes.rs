@@ -0,0 +1,121 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use num::BigInt;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_to_signed_bytes_le() {
+    let test = |n, out: &[u8]| {
+        let n = Integer::from_str(n).unwrap();
+        assert_eq!(n.to_signed_bytes_le(), out);
+        let mut out_be = out.to_vec();
+        out_be.reverse();
+        assert_eq!(n.to_signed_bytes_be(), out_be);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("-123", &[133]);
+    test("127", &[127]);
+    test("128", &[128, 0]);
+    test("-128", &[128]);
+    test("-129", &[127, 255]);
+    test("255", &[255, 0]);
+    test("-255", &[1, 255]);
+    test("-256", &[0, 255]);
+    test("-1", &[255]);
+    test("4660", &[0x34, 0x12]);
+    test("-4660", &[0xcc, 0xed]);
+    test("1000000000000", &[0x00, 0x10, 0xa5, 0xd4, 0xe8, 0x00]);
+    test("-1000000000000", &[0x00, 0xf0, 0x5a, 0x2b, 0x17, 0xff]);
+    test("4294967295", &[255, 255, 255, 255, 0]);
+    test("-4294967295", &[1, 0, 0, 0, 255]);
+    test("-4294967296", &[0, 0, 0, 0, 255]);
+    test(
+        "9223372036854775807",
+        &[255, 255, 255, 255, 255, 255, 255, 127],
+    );
+    test("-9223372036854775808", &[0, 0, 0, 0, 0, 0, 0, 128]);
+    test("9223372036854775808", &[0, 0, 0, 0, 0, 0, 0, 128, 0]);
+    test(
+        "-9223372036854775809",
+        &[255, 255, 255, 255, 255, 255, 255, 127, 255],
+    );
+    test("18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test("-18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 255]);
+}
+
+#[test]
+fn to_signed_bytes_le_properties() {
+    integer_gen().test_properties(|x| {
+        let bytes = x.to_signed_bytes_le();
+        assert_eq!(Integer::from_signed_bytes_le(&bytes), x);
+        match x.sign() {
+            Equal => assert!(bytes.is_empty()),
+            Greater => {
+                assert_eq!(BigInt::from(&x).to_signed_bytes_le(), bytes);
+                let last = *bytes.last().unwrap();
+                assert!(last < 128);
+                if last == 0 {
+                    assert!(bytes[bytes.len() - 2] >= 128);
+                }
+            }
+            Less => {
+                assert_eq!(BigInt::from(&x).to_signed_bytes_le(), bytes);
+                let last = *bytes.last().unwrap();
+                assert!(last >= 128);
+                if last == u8::MAX && bytes.len() > 1 {
+                    assert!(bytes[bytes.len() - 2] < 128);
+                }
+            }
+        }
+    });
+
+    natural_gen().test_properties(|x| {
+        let bytes = Integer::from(&x).to_signed_bytes_le();
+        let unsigned_bytes = x.to_bytes_le();
+        assert!(bytes.len() - unsigned_bytes.len() <= 1);
+        assert_eq!(bytes[..unsigned_bytes.len()], unsigned_bytes);
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        let bytes = Integer::from(i).to_signed_bytes_le();
+        let full_bytes = i.to_le_bytes();
+        assert_eq!(bytes, full_bytes[..bytes.len()]);
+        assert_eq!(Integer::from_signed_bytes_le(&full_bytes), i);
+    });
+}
+
+#[test]
+fn to_signed_bytes_be_properties() {
+    integer_gen().test_properties(|x| {
+        let bytes = x.to_signed_bytes_be();
+        assert_eq!(Integer::from_signed_bytes_be(&bytes), x);
+        let mut bytes_le = x.to_signed_bytes_le();
+        bytes_le.reverse();
+        assert_eq!(bytes_le, bytes);
+        if x != 0 {
+            assert_eq!(BigInt::from(&x).to_signed_bytes_be(), bytes);
+        }
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        let bytes = Integer::from(i).to_signed_bytes_be();
+        let full_bytes = i.to_be_bytes();
+        assert_eq!(bytes, full_bytes[8 - bytes.len()..]);
+        assert_eq!(Integer::from_signed_bytes_be(&full_bytes), i);
+    });
+
+    assert!(Integer::ZERO.to_signed_bytes_be().is_empty());
+}
@@ -71,6 +71,7 @@ pub mod integer {
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod chinese_remainder;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -81,8 +82,17 @@ pub mod integer {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod kronecker_symbol;
+        pub mod mod_add;
+        pub mod mod_inverse;
+        pub mod mod_mul;
+        pub mod mod_neg;
         pub mod mod_op;
+        pub mod mod_pow;
         pub mod mod_power_of_2;
+        pub mod mod_shl;
+        pub mod mod_shr;
+        pub mod mod_square;
+        pub mod mod_sub;
         pub mod mul;
         pub mod neg;
         pub mod parity;
@@ -133,6 +143,7 @@ pub mod integer {
         pub mod from_natural;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
+        pub mod from_signed_bytes;
         pub mod from_twos_complement_limbs;
         pub mod is_integer;
         pub mod natural_from_integer;
@@ -146,6 +157,7 @@ pub mod integer {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_signed_bytes;
         pub mod to_twos_complement_limbs;
     }
     pub mod exhaustive {
@@ -226,6 +238,7 @@ pub mod natural {
         pub mod binomial_coefficient;
         pub mod checked_sub;
         pub mod checked_sub_mul;
+        pub mod chinese_remainder;
         pub mod coprime_with;
         pub mod div;
         pub mod div_exact;
@@ -266,6 +279,7 @@ pub mod natural {
         pub mod mod_shr;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod montgomery;
         pub mod mul;
         pub mod neg;
         pub mod next_power_of_2;
@@ -317,6 +331,7 @@ pub mod natural {
             pub mod to_power_of_2_digits;
         }
         pub mod from_bool;
+        pub mod from_bytes;
         pub mod from_limbs;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
@@ -335,6 +350,7 @@ pub mod natural {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_bytes;
         pub mod to_limbs;
     }
     pub mod exhaustive {
@@ -345,6 +361,12 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod divisors;
+        pub mod euler_totient;
+        pub mod factor;
+        pub mod is_prime;
+        pub mod is_squarefree;
+        pub mod moebius;
         pub mod primes;
     }
     pub mod logic {
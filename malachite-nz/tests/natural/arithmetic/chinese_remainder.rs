@@ -0,0 +1,126 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ChineseRemainder, EqMod, Gcd, Lcm};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Primes;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_vec_gen_var_1;
+use malachite_nz::test_util::natural::arithmetic::chinese_remainder::chinese_remainder_sequential;
+use std::str::FromStr;
+
+#[test]
+fn test_chinese_remainder() {
+    let test = |pairs: &[(&str, &str)], out: Option<&str>| {
+        let pairs: Vec<(Natural, Natural)> = pairs
+            .iter()
+            .map(|&(r, m)| (Natural::from_str(r).unwrap(), Natural::from_str(m).unwrap()))
+            .collect();
+        let x = Natural::chinese_remainder(&pairs);
+        assert_eq!(x.as_ref().map(ToString::to_string).as_deref(), out);
+        assert_eq!(chinese_remainder_sequential(&pairs), x);
+    };
+    test(&[], Some("0"));
+    test(&[("0", "1")], Some("0"));
+    test(&[("5", "1")], Some("0"));
+    test(&[("5", "7")], Some("5"));
+    test(&[("12", "7")], Some("5"));
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], Some("23"));
+    test(
+        &[("1", "2"), ("2", "3"), ("3", "5"), ("4", "7")],
+        Some("53"),
+    );
+    test(&[("3", "4"), ("5", "6")], Some("11"));
+    test(&[("3", "4"), ("4", "6")], None);
+    test(&[("1", "6"), ("1", "10"), ("1", "15")], Some("1"));
+    test(&[("1", "6"), ("1", "10"), ("2", "15")], None);
+    test(&[("5", "12"), ("5", "12")], Some("5"));
+    test(&[("5", "12"), ("6", "12")], None);
+    test(&[("3", "5"), ("0", "1"), ("4", "9")], Some("13"));
+    test(
+        &[("123456789", "1000000007"), ("987654321", "998244353"), ("555555555", "1000000009")],
+        Some("891430600046840047771238026"),
+    );
+    test(
+        &[
+            (
+                "1000000000000000000000",
+                "340282366920938463463374607431768211297",
+            ),
+            ("0", "18446744073709551557"),
+        ],
+        Some("5985713252406666734776803590369287461708601150820466554979"),
+    );
+}
+
+#[test]
+#[should_panic]
+fn chinese_remainder_fail_1() {
+    Natural::chinese_remainder(&[(Natural::ONE, Natural::ZERO)]);
+}
+
+#[test]
+#[should_panic]
+fn chinese_remainder_fail_2() {
+    Natural::chinese_remainder(&[
+        (Natural::ONE, Natural::from(3u32)),
+        (Natural::ONE, Natural::ZERO),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn chinese_remainder_fail_3() {
+    Natural::chinese_remainder(&[
+        (Natural::from(3u32), Natural::from(4u32)),
+        (Natural::from(4u32), Natural::from(6u32)),
+        (Natural::ONE, Natural::from(5u32)),
+        (Natural::ZERO, Natural::ZERO),
+    ]);
+}
+
+#[test]
+fn test_chinese_remainder_many_primes() {
+    let x = Natural::from_str("123456789012345678901234567890123456789").unwrap();
+    let pairs: Vec<(Natural, Natural)> =
+        Natural::primes().take(1000).map(|p| (&x % &p, p)).collect();
+    assert_eq!(Natural::chinese_remainder(&pairs), Some(x));
+}
+
+#[test]
+fn chinese_remainder_properties() {
+    natural_pair_vec_gen_var_1().test_properties(|pairs| {
+        let x = Natural::chinese_remainder(&pairs);
+        assert_eq!(chinese_remainder_sequential(&pairs), x);
+        let lcm = pairs.iter().fold(Natural::ONE, |l, (_, m)| l.lcm(m));
+        let consistent = pairs.iter().enumerate().all(|(i, (r_i, m_i))| {
+            pairs[i + 1..]
+                .iter()
+                .all(|(r_j, m_j)| r_i.eq_mod(r_j, m_i.gcd(m_j)))
+        });
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+            assert!(*x < lcm);
+            for (r, m) in &pairs {
+                assert!(x.eq_mod(r, m));
+            }
+        }
+        assert_eq!(x.is_some(), consistent);
+
+        let mut reversed = pairs.clone();
+        reversed.reverse();
+        assert_eq!(Natural::chinese_remainder(&reversed), x);
+
+        // Any number is recovered modulo the LCM from its own residues.
+        if let Some((r, _)) = pairs.first() {
+            let y_pairs: Vec<(Natural, Natural)> =
+                pairs.iter().map(|(_, m)| (r % m, m.clone())).collect();
+            assert_eq!(Natural::chinese_remainder(&y_pairs), Some(r % &lcm));
+        }
+    });
+}
@@ -109,6 +109,8 @@ fn test_div_round() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", ToOdd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "123", Down, "0", Equal);
@@ -116,6 +118,8 @@ fn test_div_round() {
     test("0", "123", Up, "0", Equal);
     test("0", "123", Ceiling, "0", Equal);
     test("0", "123", Nearest, "0", Equal);
+    test("0", "123", NearestTiesAway, "0", Equal);
+    test("0", "123", ToOdd, "0", Equal);
     test("0", "123", Exact, "0", Equal);
 
     test("1", "1", Down, "1", Equal);
@@ -123,6 +127,8 @@ fn test_div_round() {
     test("1", "1", Up, "1", Equal);
     test("1", "1", Ceiling, "1", Equal);
     test("1", "1", Nearest, "1", Equal);
+    test("1", "1", NearestTiesAway, "1", Equal);
+    test("1", "1", ToOdd, "1", Equal);
     test("1", "1", Exact, "1", Equal);
 
     test("123", "1", Down, "123", Equal);
@@ -130,6 +136,8 @@ fn test_div_round() {
     test("123", "1", Up, "123", Equal);
     test("123", "1", Ceiling, "123", Equal);
     test("123", "1", Nearest, "123", Equal);
+    test("123", "1", NearestTiesAway, "123", Equal);
+    test("123", "1", ToOdd, "123", Equal);
     test("123", "1", Exact, "123", Equal);
 
     test("123", "2", Down, "61", Less);
@@ -137,18 +145,24 @@ fn test_div_round() {
     test("123", "2", Up, "62", Greater);
     test("123", "2", Ceiling, "62", Greater);
     test("123", "2", Nearest, "62", Greater);
+    test("123", "2", NearestTiesAway, "62", Greater);
+    test("123", "2", ToOdd, "61", Less);
 
     test("125", "2", Down, "62", Less);
     test("125", "2", Floor, "62", Less);
     test("125", "2", Up, "63", Greater);
     test("125", "2", Ceiling, "63", Greater);
     test("125", "2", Nearest, "62", Less);
+    test("125", "2", NearestTiesAway, "63", Greater);
+    test("125", "2", ToOdd, "63", Greater);
 
     test("123", "123", Down, "1", Equal);
     test("123", "123", Floor, "1", Equal);
     test("123", "123", Up, "1", Equal);
     test("123", "123", Ceiling, "1", Equal);
     test("123", "123", Nearest, "1", Equal);
+    test("123", "123", NearestTiesAway, "1", Equal);
+    test("123", "123", ToOdd, "1", Equal);
     test("123", "123", Exact, "1", Equal);
 
     test("123", "456", Down, "0", Less);
@@ -156,12 +170,22 @@ fn test_div_round() {
     test("123", "456", Up, "1", Greater);
     test("123", "456", Ceiling, "1", Greater);
     test("123", "456", Nearest, "0", Less);
+    test("123", "456", NearestTiesAway, "0", Less);
+    test("123", "456", ToOdd, "1", Greater);
 
     test("1000000000000", "1", Down, "1000000000000", Equal);
     test("1000000000000", "1", Floor, "1000000000000", Equal);
     test("1000000000000", "1", Up, "1000000000000", Equal);
     test("1000000000000", "1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "1", Nearest, "1000000000000", Equal);
+    test(
+        "1000000000000",
+        "1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("1000000000000", "1", ToOdd, "1000000000000", Equal);
     test("1000000000000", "1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "3", Down, "333333333333", Less);
@@ -169,18 +193,36 @@ fn test_div_round() {
     test("1000000000000", "3", Up, "333333333334", Greater);
     test("1000000000000", "3", Ceiling, "333333333334", Greater);
     test("1000000000000", "3", Nearest, "333333333333", Less);
+    test("1000000000000", "3", NearestTiesAway, "333333333333", Less);
+    test("1000000000000", "3", ToOdd, "333333333333", Less);
 
     test("999999999999", "2", Down, "499999999999", Less);
     test("999999999999", "2", Floor, "499999999999", Less);
     test("999999999999", "2", Up, "500000000000", Greater);
     test("999999999999", "2", Ceiling, "500000000000", Greater);
     test("999999999999", "2", Nearest, "500000000000", Greater);
+    test(
+        "999999999999",
+        "2",
+        NearestTiesAway,
+        "500000000000",
+        Greater,
+    );
+    test("999999999999", "2", ToOdd, "499999999999", Less);
 
     test("1000000000001", "2", Down, "500000000000", Less);
     test("1000000000001", "2", Floor, "500000000000", Less);
     test("1000000000001", "2", Up, "500000000001", Greater);
     test("1000000000001", "2", Ceiling, "500000000001", Greater);
     test("1000000000001", "2", Nearest, "500000000000", Less);
+    test(
+        "1000000000001",
+        "2",
+        NearestTiesAway,
+        "500000000001",
+        Greater,
+    );
+    test("1000000000001", "2", ToOdd, "500000000001", Greater);
 
     test(
         "1000000000000000000000000",
@@ -217,6 +259,20 @@ fn test_div_round() {
         "232830643708080",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "232830643708080",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "232830643708079",
+        Less,
+    );
 
     test(
         "1000000000000000000000000",
@@ -253,6 +309,20 @@ fn test_div_round() {
         "1000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "1000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "1000000000000",
@@ -296,6 +366,20 @@ fn test_div_round() {
         "999999999999",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "999999999999",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "999999999999",
+        Less,
+    );
 
     test(
         "2999999999999999999999999",
@@ -304,6 +388,20 @@ fn test_div_round() {
         "1",
         Less,
     );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "1",
+        Less,
+    );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "3000000000000000000000000",
         "2000000000000000000000000",
@@ -311,6 +409,20 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
     test(
         "3000000000000000000000001",
         "2000000000000000000000000",
@@ -318,6 +430,20 @@ fn test_div_round() {
         "2",
         Greater,
     );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2",
+        Greater,
+    );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "1",
+        Less,
+    );
 }
 
 #[test]
@@ -468,6 +594,8 @@ fn div_round_properties() {
         assert_eq!((&left_multiplied).div_round(&y, Floor), xo);
         assert_eq!((&left_multiplied).div_round(&y, Ceiling), xo);
         assert_eq!((&left_multiplied).div_round(&y, Nearest), xo);
+        assert_eq!((&left_multiplied).div_round(&y, NearestTiesAway), xo);
+        assert_eq!((&left_multiplied).div_round(&y, ToOdd), xo);
         assert_eq!((&left_multiplied).div_round(&y, Exact), xo);
 
         assert_eq!(
@@ -496,6 +624,10 @@ fn div_round_properties() {
         assert_eq!((&x).div_round(&y, Up), up);
         assert_eq!((&x).div_round(&y, Floor), down);
         assert_eq!((&x).div_round(&y, Ceiling), up);
+        let nearest_ties_away = (&x).div_round(&y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&x).div_round(&y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = x.div_round(y, Nearest);
         assert!(nearest == down || nearest == up);
     });
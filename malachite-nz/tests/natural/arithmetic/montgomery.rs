@@ -0,0 +1,193 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow, ModSquare, PowerOf2};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::arithmetic::montgomery::{MontgomeryContext, MontgomeryPowWindow};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_12, natural_triple_gen_var_8};
+use std::str::FromStr;
+
+#[test]
+fn test_montgomery_context() {
+    let test = |x: &str, y: &str, m: &str, product: &str, square: &str| {
+        let m = Natural::from_str(m).unwrap();
+        let context = MontgomeryContext::new(m.clone());
+        assert_eq!(*context.modulus(), m);
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let x_m = context.to_montgomery(&x);
+        let y_m = context.to_montgomery(&y);
+        assert_eq!(context.to_natural(&x_m), &x % &m);
+        assert_eq!(
+            context.to_natural(&context.mul(&x_m, &y_m)).to_string(),
+            product
+        );
+        assert_eq!(
+            context.to_natural(&context.square(&x_m)).to_string(),
+            square
+        );
+    };
+    test("0", "0", "1", "0", "0");
+    test("5", "7", "1", "0", "0");
+    test("0", "5", "7", "0", "0");
+    test("3", "5", "7", "1", "2");
+    test("123", "456", "1000000007", "56088", "15129");
+    test("1000", "1000", "497", "36", "36");
+    test(
+        "123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "1000000000000000000000000000057",
+        "672763357427221473218716661518",
+        "667428798567291505096784013123",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "2",
+        "170141183460469231731687303715884105727",
+        "2",
+        "1",
+    );
+}
+
+#[test]
+#[should_panic]
+fn montgomery_context_new_fail_1() {
+    MontgomeryContext::new(Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn montgomery_context_new_fail_2() {
+    MontgomeryContext::new(Natural::from(10u32));
+}
+
+#[test]
+fn test_montgomery_pow() {
+    let test = |x: &str, exp: &str, m: &str, out: &str| {
+        let m = Natural::from_str(m).unwrap();
+        let context = MontgomeryContext::new(m);
+        let x = context.to_montgomery(&Natural::from_str(x).unwrap());
+        let exp = Natural::from_str(exp).unwrap();
+        assert_eq!(context.to_natural(&context.pow(&x, &exp)).to_string(), out);
+        for window in [MontgomeryPowWindow::Sliding, MontgomeryPowWindow::Fixed] {
+            assert_eq!(
+                context
+                    .to_natural(&context.pow_with_window(&x, &exp, window))
+                    .to_string(),
+                out
+            );
+        }
+    };
+    test("0", "0", "1", "0");
+    test("4", "0", "497", "1");
+    test("0", "0", "497", "1");
+    test("0", "5", "497", "0");
+    test("4", "1", "497", "4");
+    test("4", "13", "497", "445");
+    test("10", "1000", "33", "1");
+    test(
+        "2",
+        "1267650600228229401496703205376",
+        "170141183460469231731687303715884105727",
+        "16",
+    );
+    test(
+        "1234567890123456789",
+        "98765432109876543210",
+        "1000000000000000000000000000057",
+        "6362026787444688256697138991",
+    );
+}
+
+#[test]
+fn test_montgomery_large_modulus() {
+    // Large enough to use a multi-limb inverse of the modulus
+    for bits in [1000, 7000, 20000] {
+        let m = Natural::power_of_2(bits) - Natural::from(3u32);
+        let context = MontgomeryContext::new(m.clone());
+        let x = Natural::power_of_2(bits - 1) + Natural::from(12345u32);
+        let y = (Natural::power_of_2(bits) - Natural::from(1000u32)) / Natural::from(3u32);
+        let x_m = context.to_montgomery(&x);
+        let y_m = context.to_montgomery(&y);
+        assert_eq!(context.to_natural(&x_m), x);
+        assert_eq!(
+            context.to_natural(&context.mul(&x_m, &y_m)),
+            (&x).mod_mul(&y, &m)
+        );
+        assert_eq!(
+            context.to_natural(&context.square(&y_m)),
+            (&y).mod_square(&m)
+        );
+        let exp = Natural::from(1000003u32);
+        let power = (&x).mod_pow(&exp, &m);
+        for window in [MontgomeryPowWindow::Sliding, MontgomeryPowWindow::Fixed] {
+            assert_eq!(
+                context.to_natural(&context.pow_with_window(&x_m, &exp, window)),
+                power
+            );
+        }
+    }
+}
+
+#[test]
+fn montgomery_context_properties() {
+    natural_triple_gen_var_8().test_properties(|(x, y, m)| {
+        let context = MontgomeryContext::new(m.clone());
+        let x_m = context.to_montgomery(&x);
+        let y_m = context.to_montgomery(&y);
+        let x_reduced = context.to_natural(&x_m);
+        assert!(x_reduced.is_valid());
+        assert_eq!(x_reduced, &x % &m);
+        assert_eq!(context.to_montgomery(&x_reduced), x_m);
+
+        let product = context.to_natural(&context.mul(&x_m, &y_m));
+        assert!(product.is_valid());
+        assert_eq!(product, (&x % &m).mod_mul(&y % &m, &m));
+        assert_eq!(context.mul(&x_m, &y_m), context.mul(&y_m, &x_m));
+        assert_eq!(context.mul(&x_m, &context.one()), x_m);
+
+        let square = context.to_natural(&context.square(&x_m));
+        assert!(square.is_valid());
+        assert_eq!(square, (&x % &m).mod_square(&m));
+        assert_eq!(context.square(&x_m), context.mul(&x_m, &x_m));
+    });
+
+    natural_triple_gen_var_8().test_properties(|(x, exp, m)| {
+        let context = MontgomeryContext::new(m.clone());
+        let x_m = context.to_montgomery(&x);
+        let power = context.pow(&x_m, &exp);
+        assert_eq!(
+            context.pow_with_window(&x_m, &exp, MontgomeryPowWindow::Sliding),
+            power
+        );
+        assert_eq!(
+            context.pow_with_window(&x_m, &exp, MontgomeryPowWindow::Fixed),
+            power
+        );
+        let power = context.to_natural(&power);
+        assert!(power.is_valid());
+        assert_eq!(power, (&x % &m).mod_pow(&exp, &m));
+        let rug_power = rug::Integer::from(&x)
+            .pow_mod(&rug::Integer::from(&exp), &rug::Integer::from(&m))
+            .unwrap();
+        assert_eq!(Natural::exact_from(&rug_power), power);
+    });
+
+    natural_pair_gen_var_12().test_properties(|(x, m)| {
+        let context = MontgomeryContext::new(m.clone());
+        let one = context.one();
+        assert_eq!(context.to_natural(&one), Natural::ONE % &m);
+        assert_eq!(context.to_montgomery(&Natural::ONE), one);
+        let x_m = context.to_montgomery(&x);
+        assert_eq!(context.pow(&x_m, &Natural::ZERO), one);
+        assert_eq!(context.pow(&x_m, &Natural::ONE), x_m);
+        assert_eq!(context.pow(&x_m, &Natural::TWO), context.square(&x_m));
+    });
+}
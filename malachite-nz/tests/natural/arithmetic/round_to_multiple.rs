@@ -63,6 +63,8 @@ fn test_round_to_multiple() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", ToOdd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "123", Down, "0", Equal);
@@ -70,6 +72,8 @@ fn test_round_to_multiple() {
     test("0", "123", Up, "0", Equal);
     test("0", "123", Ceiling, "0", Equal);
     test("0", "123", Nearest, "0", Equal);
+    test("0", "123", NearestTiesAway, "0", Equal);
+    test("0", "123", ToOdd, "0", Equal);
     test("0", "123", Exact, "0", Equal);
 
     test("1", "1", Down, "1", Equal);
@@ -77,6 +81,8 @@ fn test_round_to_multiple() {
     test("1", "1", Up, "1", Equal);
     test("1", "1", Ceiling, "1", Equal);
     test("1", "1", Nearest, "1", Equal);
+    test("1", "1", NearestTiesAway, "1", Equal);
+    test("1", "1", ToOdd, "1", Equal);
     test("1", "1", Exact, "1", Equal);
 
     test("123", "1", Down, "123", Equal);
@@ -84,6 +90,8 @@ fn test_round_to_multiple() {
     test("123", "1", Up, "123", Equal);
     test("123", "1", Ceiling, "123", Equal);
     test("123", "1", Nearest, "123", Equal);
+    test("123", "1", NearestTiesAway, "123", Equal);
+    test("123", "1", ToOdd, "123", Equal);
     test("123", "1", Exact, "123", Equal);
 
     test("123", "2", Down, "122", Less);
@@ -91,18 +99,24 @@ fn test_round_to_multiple() {
     test("123", "2", Up, "124", Greater);
     test("123", "2", Ceiling, "124", Greater);
     test("123", "2", Nearest, "124", Greater);
+    test("123", "2", NearestTiesAway, "124", Greater);
+    test("123", "2", ToOdd, "122", Less);
 
     test("125", "2", Down, "124", Less);
     test("125", "2", Floor, "124", Less);
     test("125", "2", Up, "126", Greater);
     test("125", "2", Ceiling, "126", Greater);
     test("125", "2", Nearest, "124", Less);
+    test("125", "2", NearestTiesAway, "126", Greater);
+    test("125", "2", ToOdd, "126", Greater);
 
     test("123", "123", Down, "123", Equal);
     test("123", "123", Floor, "123", Equal);
     test("123", "123", Up, "123", Equal);
     test("123", "123", Ceiling, "123", Equal);
     test("123", "123", Nearest, "123", Equal);
+    test("123", "123", NearestTiesAway, "123", Equal);
+    test("123", "123", ToOdd, "123", Equal);
     test("123", "123", Exact, "123", Equal);
 
     test("123", "456", Down, "0", Less);
@@ -110,12 +124,22 @@ fn test_round_to_multiple() {
     test("123", "456", Up, "456", Greater);
     test("123", "456", Ceiling, "456", Greater);
     test("123", "456", Nearest, "0", Less);
+    test("123", "456", NearestTiesAway, "0", Less);
+    test("123", "456", ToOdd, "456", Greater);
 
     test("1000000000000", "1", Down, "1000000000000", Equal);
     test("1000000000000", "1", Floor, "1000000000000", Equal);
     test("1000000000000", "1", Up, "1000000000000", Equal);
     test("1000000000000", "1", Ceiling, "1000000000000", Equal);
     test("1000000000000", "1", Nearest, "1000000000000", Equal);
+    test(
+        "1000000000000",
+        "1",
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("1000000000000", "1", ToOdd, "1000000000000", Equal);
     test("1000000000000", "1", Exact, "1000000000000", Equal);
 
     test("1000000000000", "3", Down, "999999999999", Less);
@@ -123,18 +147,36 @@ fn test_round_to_multiple() {
     test("1000000000000", "3", Up, "1000000000002", Greater);
     test("1000000000000", "3", Ceiling, "1000000000002", Greater);
     test("1000000000000", "3", Nearest, "999999999999", Less);
+    test("1000000000000", "3", NearestTiesAway, "999999999999", Less);
+    test("1000000000000", "3", ToOdd, "999999999999", Less);
 
     test("999999999999", "2", Down, "999999999998", Less);
     test("999999999999", "2", Floor, "999999999998", Less);
     test("999999999999", "2", Up, "1000000000000", Greater);
     test("999999999999", "2", Ceiling, "1000000000000", Greater);
     test("999999999999", "2", Nearest, "1000000000000", Greater);
+    test(
+        "999999999999",
+        "2",
+        NearestTiesAway,
+        "1000000000000",
+        Greater,
+    );
+    test("999999999999", "2", ToOdd, "999999999998", Less);
 
     test("1000000000001", "2", Down, "1000000000000", Less);
     test("1000000000001", "2", Floor, "1000000000000", Less);
     test("1000000000001", "2", Up, "1000000000002", Greater);
     test("1000000000001", "2", Ceiling, "1000000000002", Greater);
     test("1000000000001", "2", Nearest, "1000000000000", Less);
+    test(
+        "1000000000001",
+        "2",
+        NearestTiesAway,
+        "1000000000002",
+        Greater,
+    );
+    test("1000000000001", "2", ToOdd, "1000000000002", Greater);
 
     test(
         "1000000000000000000000000",
@@ -171,6 +213,20 @@ fn test_round_to_multiple() {
         "1000000000000001127243600",
         Greater,
     );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        NearestTiesAway,
+        "1000000000000001127243600",
+        Greater,
+    );
+    test(
+        "1000000000000000000000000",
+        "4294967295",
+        ToOdd,
+        "999999999999996832276305",
+        Less,
+    );
 
     test(
         "1000000000000000000000000",
@@ -207,6 +263,20 @@ fn test_round_to_multiple() {
         "1000000000000000000000000",
         Equal,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        NearestTiesAway,
+        "1000000000000000000000000",
+        Equal,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000000",
+        ToOdd,
+        "1000000000000000000000000",
+        Equal,
+    );
     test(
         "1000000000000000000000000",
         "1000000000000",
@@ -250,6 +320,20 @@ fn test_round_to_multiple() {
         "999999999999999999999999",
         Less,
     );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        NearestTiesAway,
+        "999999999999999999999999",
+        Less,
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000001",
+        ToOdd,
+        "999999999999999999999999",
+        Less,
+    );
 
     test(
         "2999999999999999999999999",
@@ -258,6 +342,20 @@ fn test_round_to_multiple() {
         "2000000000000000000000000",
         Less,
     );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "2000000000000000000000000",
+        Less,
+    );
+    test(
+        "2999999999999999999999999",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000000",
         "2000000000000000000000000",
@@ -265,6 +363,20 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000000",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
     test(
         "3000000000000000000000001",
         "2000000000000000000000000",
@@ -272,17 +384,34 @@ fn test_round_to_multiple() {
         "4000000000000000000000000",
         Greater,
     );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        NearestTiesAway,
+        "4000000000000000000000000",
+        Greater,
+    );
+    test(
+        "3000000000000000000000001",
+        "2000000000000000000000000",
+        ToOdd,
+        "2000000000000000000000000",
+        Less,
+    );
 
     test("0", "0", Floor, "0", Equal);
     test("0", "0", Ceiling, "0", Equal);
     test("0", "0", Down, "0", Equal);
     test("0", "0", Up, "0", Equal);
     test("0", "0", Nearest, "0", Equal);
+    test("0", "0", NearestTiesAway, "0", Equal);
+    test("0", "0", ToOdd, "0", Equal);
     test("0", "0", Exact, "0", Equal);
 
     test("2", "0", Floor, "0", Less);
     test("2", "0", Down, "0", Less);
     test("2", "0", Nearest, "0", Less);
+    test("2", "0", NearestTiesAway, "0", Less);
 }
 
 #[test]
@@ -486,7 +615,7 @@ fn round_to_multiple_properties() {
                 Floor | Down => assert!(r <= x),
                 Ceiling | Up => assert!(r >= x),
                 Exact => assert_eq!(r, x),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     let closest;
                     let second_closest;
                     if r <= x {
@@ -498,9 +627,14 @@ fn round_to_multiple_properties() {
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!((&r).div_round(&y, Exact).0.even());
+                        if rm == Nearest {
+                            assert!((&r).div_round(&y, Exact).0.even());
+                        } else {
+                            assert_eq!(o, Greater);
+                        }
                     }
                 }
+                ToOdd => assert!(r == x || (&r).div_round(&y, Exact).0.odd()),
             }
         }
     });
@@ -513,6 +647,8 @@ fn round_to_multiple_properties() {
         assert_eq!((&product).round_to_multiple(&y, Floor), po);
         assert_eq!((&product).round_to_multiple(&y, Ceiling), po);
         assert_eq!((&product).round_to_multiple(&y, Nearest), po);
+        assert_eq!((&product).round_to_multiple(&y, NearestTiesAway), po);
+        assert_eq!((&product).round_to_multiple(&y, ToOdd), po);
         assert_eq!((&product).round_to_multiple(y, Exact), po);
     });
 
@@ -523,6 +659,10 @@ fn round_to_multiple_properties() {
         assert_eq!((&x).round_to_multiple(&y, Up), up);
         assert_eq!((&x).round_to_multiple(&y, Floor), down);
         assert_eq!((&x).round_to_multiple(&y, Ceiling), up);
+        let nearest_ties_away = (&x).round_to_multiple(&y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&x).round_to_multiple(&y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = x.round_to_multiple(y, Nearest);
         assert!(nearest == down || nearest == up);
     });
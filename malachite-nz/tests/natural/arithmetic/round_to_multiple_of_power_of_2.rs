@@ -30,6 +30,10 @@ use malachite_nz::natural::arithmetic::round_to_multiple_of_power_of_2::{
     limbs_round_to_multiple_of_power_of_2_down_in_place,
     limbs_round_to_multiple_of_power_of_2_in_place, limbs_round_to_multiple_of_power_of_2_nearest,
     limbs_round_to_multiple_of_power_of_2_nearest_in_place,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_away,
+    limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place,
+    limbs_round_to_multiple_of_power_of_2_to_odd,
+    limbs_round_to_multiple_of_power_of_2_to_odd_in_place,
     limbs_round_to_multiple_of_power_of_2_up, limbs_round_to_multiple_of_power_of_2_up_in_place,
 };
 use malachite_nz::natural::Natural;
@@ -189,6 +193,14 @@ fn test_limbs_round_to_multiple_of_power_of_2() {
         Down,
         Some((&[0, u32::MAX], Less)),
     );
+    test(&[3], 1, NearestTiesAway, Some((&[4], Greater)));
+    test(&[3], 1, ToOdd, Some((&[2], Less)));
+    test(&[123, 456], 31, NearestTiesAway, Some((&[0, 456], Less)));
+    test(&[123, 456], 31, ToOdd, Some((&[0x80000000, 456], Greater)));
+    test(&[123, 456], 100, NearestTiesAway, Some((&[], Less)));
+    test(&[123, 456], 100, ToOdd, Some((&[0, 0, 0, 16], Greater)));
+    test(&[u32::MAX, 1], 1, NearestTiesAway, Some((&[0, 2], Greater)));
+    test(&[u32::MAX, 1], 1, ToOdd, Some((&[u32::MAX - 1, 1], Less)));
 }
 
 #[test]
@@ -220,6 +232,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, ToOdd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -227,6 +241,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, ToOdd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -234,6 +250,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, ToOdd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "244", Less);
@@ -241,12 +259,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("245", 1, Floor, "244", Less);
     test("245", 1, Ceiling, "246", Greater);
     test("245", 1, Nearest, "244", Less);
+    test("245", 1, NearestTiesAway, "246", Greater);
+    test("245", 1, ToOdd, "246", Greater);
 
     test("246", 1, Down, "246", Equal);
     test("246", 1, Up, "246", Equal);
     test("246", 1, Floor, "246", Equal);
     test("246", 1, Ceiling, "246", Equal);
     test("246", 1, Nearest, "246", Equal);
+    test("246", 1, NearestTiesAway, "246", Equal);
+    test("246", 1, ToOdd, "246", Equal);
     test("246", 1, Exact, "246", Equal);
 
     test("247", 1, Down, "246", Less);
@@ -254,18 +276,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("247", 1, Floor, "246", Less);
     test("247", 1, Ceiling, "248", Greater);
     test("247", 1, Nearest, "248", Greater);
+    test("247", 1, NearestTiesAway, "248", Greater);
+    test("247", 1, ToOdd, "246", Less);
 
     test("491", 2, Down, "488", Less);
     test("491", 2, Up, "492", Greater);
     test("491", 2, Floor, "488", Less);
     test("491", 2, Ceiling, "492", Greater);
     test("491", 2, Nearest, "492", Greater);
+    test("491", 2, NearestTiesAway, "492", Greater);
+    test("491", 2, ToOdd, "492", Greater);
 
     test("492", 2, Down, "492", Equal);
     test("492", 2, Up, "492", Equal);
     test("492", 2, Floor, "492", Equal);
     test("492", 2, Ceiling, "492", Equal);
     test("492", 2, Nearest, "492", Equal);
+    test("492", 2, NearestTiesAway, "492", Equal);
+    test("492", 2, ToOdd, "492", Equal);
     test("492", 2, Exact, "492", Equal);
 
     test("493", 2, Down, "492", Less);
@@ -273,18 +301,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("493", 2, Floor, "492", Less);
     test("493", 2, Ceiling, "496", Greater);
     test("493", 2, Nearest, "492", Less);
+    test("493", 2, NearestTiesAway, "492", Less);
+    test("493", 2, ToOdd, "492", Less);
 
     test("4127195135", 25, Down, "4093640704", Less);
     test("4127195135", 25, Up, "4127195136", Greater);
     test("4127195135", 25, Floor, "4093640704", Less);
     test("4127195135", 25, Ceiling, "4127195136", Greater);
     test("4127195135", 25, Nearest, "4127195136", Greater);
+    test("4127195135", 25, NearestTiesAway, "4127195136", Greater);
+    test("4127195135", 25, ToOdd, "4127195136", Greater);
 
     test("4127195136", 25, Down, "4127195136", Equal);
     test("4127195136", 25, Up, "4127195136", Equal);
     test("4127195136", 25, Floor, "4127195136", Equal);
     test("4127195136", 25, Ceiling, "4127195136", Equal);
     test("4127195136", 25, Nearest, "4127195136", Equal);
+    test("4127195136", 25, NearestTiesAway, "4127195136", Equal);
+    test("4127195136", 25, ToOdd, "4127195136", Equal);
     test("4127195136", 25, Exact, "4127195136", Equal);
 
     test("4127195137", 25, Down, "4127195136", Less);
@@ -292,18 +326,24 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4127195137", 25, Floor, "4127195136", Less);
     test("4127195137", 25, Ceiling, "4160749568", Greater);
     test("4127195137", 25, Nearest, "4127195136", Less);
+    test("4127195137", 25, NearestTiesAway, "4127195136", Less);
+    test("4127195137", 25, ToOdd, "4127195136", Less);
 
     test("8254390271", 26, Down, "8187281408", Less);
     test("8254390271", 26, Up, "8254390272", Greater);
     test("8254390271", 26, Floor, "8187281408", Less);
     test("8254390271", 26, Ceiling, "8254390272", Greater);
     test("8254390271", 26, Nearest, "8254390272", Greater);
+    test("8254390271", 26, NearestTiesAway, "8254390272", Greater);
+    test("8254390271", 26, ToOdd, "8254390272", Greater);
 
     test("8254390272", 26, Down, "8254390272", Equal);
     test("8254390272", 26, Up, "8254390272", Equal);
     test("8254390272", 26, Floor, "8254390272", Equal);
     test("8254390272", 26, Ceiling, "8254390272", Equal);
     test("8254390272", 26, Nearest, "8254390272", Equal);
+    test("8254390272", 26, NearestTiesAway, "8254390272", Equal);
+    test("8254390272", 26, ToOdd, "8254390272", Equal);
     test("8254390272", 26, Exact, "8254390272", Equal);
 
     test("8254390273", 26, Down, "8254390272", Less);
@@ -311,6 +351,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("8254390273", 26, Floor, "8254390272", Less);
     test("8254390273", 26, Ceiling, "8321499136", Greater);
     test("8254390273", 26, Nearest, "8254390272", Less);
+    test("8254390273", 26, NearestTiesAway, "8254390272", Less);
+    test("8254390273", 26, ToOdd, "8254390272", Less);
     test(
         "155921023828072216384094494261247",
         100,
@@ -346,6 +388,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Greater,
     );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Greater,
+    );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Greater,
+    );
 
     test(
         "155921023828072216384094494261248",
@@ -382,6 +438,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Equal,
     );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Equal,
+    );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Equal,
+    );
     test(
         "155921023828072216384094494261248",
         100,
@@ -425,18 +495,36 @@ fn test_round_to_multiple_of_power_of_2() {
         "155921023828072216384094494261248",
         Less,
     );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        NearestTiesAway,
+        "155921023828072216384094494261248",
+        Less,
+    );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        ToOdd,
+        "155921023828072216384094494261248",
+        Less,
+    );
 
     test("4294967295", 1, Down, "4294967294", Less);
     test("4294967295", 1, Up, "4294967296", Greater);
     test("4294967295", 1, Floor, "4294967294", Less);
     test("4294967295", 1, Ceiling, "4294967296", Greater);
     test("4294967295", 1, Nearest, "4294967296", Greater);
+    test("4294967295", 1, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 1, ToOdd, "4294967294", Less);
 
     test("4294967296", 1, Down, "4294967296", Equal);
     test("4294967296", 1, Up, "4294967296", Equal);
     test("4294967296", 1, Floor, "4294967296", Equal);
     test("4294967296", 1, Ceiling, "4294967296", Equal);
     test("4294967296", 1, Nearest, "4294967296", Equal);
+    test("4294967296", 1, NearestTiesAway, "4294967296", Equal);
+    test("4294967296", 1, ToOdd, "4294967296", Equal);
     test("4294967296", 1, Exact, "4294967296", Equal);
 
     test("4294967297", 1, Down, "4294967296", Less);
@@ -444,12 +532,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4294967297", 1, Floor, "4294967296", Less);
     test("4294967297", 1, Ceiling, "4294967298", Greater);
     test("4294967297", 1, Nearest, "4294967296", Less);
+    test("4294967297", 1, NearestTiesAway, "4294967298", Greater);
+    test("4294967297", 1, ToOdd, "4294967298", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, ToOdd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "7999999999992", Less);
@@ -457,12 +549,22 @@ fn test_round_to_multiple_of_power_of_2() {
     test("7999999999999", 3, Floor, "7999999999992", Less);
     test("7999999999999", 3, Ceiling, "8000000000000", Greater);
     test("7999999999999", 3, Nearest, "8000000000000", Greater);
+    test(
+        "7999999999999",
+        3,
+        NearestTiesAway,
+        "8000000000000",
+        Greater,
+    );
+    test("7999999999999", 3, ToOdd, "7999999999992", Less);
 
     test("8000000000000", 3, Down, "8000000000000", Equal);
     test("8000000000000", 3, Up, "8000000000000", Equal);
     test("8000000000000", 3, Floor, "8000000000000", Equal);
     test("8000000000000", 3, Ceiling, "8000000000000", Equal);
     test("8000000000000", 3, Nearest, "8000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "8000000000000", Equal);
+    test("8000000000000", 3, ToOdd, "8000000000000", Equal);
     test("8000000000000", 3, Exact, "8000000000000", Equal);
 
     test("8000000000001", 3, Down, "8000000000000", Less);
@@ -470,6 +572,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("8000000000001", 3, Floor, "8000000000000", Less);
     test("8000000000001", 3, Ceiling, "8000000000008", Greater);
     test("8000000000001", 3, Nearest, "8000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "8000000000000", Less);
+    test("8000000000001", 3, ToOdd, "8000000000008", Greater);
 
     test(
         "16777216000000000000",
@@ -506,6 +610,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "16777216000000000000",
         Equal,
     );
+    test(
+        "16777216000000000000",
+        24,
+        NearestTiesAway,
+        "16777216000000000000",
+        Equal,
+    );
+    test(
+        "16777216000000000000",
+        24,
+        ToOdd,
+        "16777216000000000000",
+        Equal,
+    );
     test(
         "16777216000000000000",
         24,
@@ -549,6 +667,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "33554432000000000000",
         Equal,
     );
+    test(
+        "33554432000000000000",
+        25,
+        NearestTiesAway,
+        "33554432000000000000",
+        Equal,
+    );
+    test(
+        "33554432000000000000",
+        25,
+        ToOdd,
+        "33554432000000000000",
+        Equal,
+    );
     test(
         "33554432000000000000",
         25,
@@ -592,6 +724,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "2147483648000000000000",
         Equal,
     );
+    test(
+        "2147483648000000000000",
+        31,
+        NearestTiesAway,
+        "2147483648000000000000",
+        Equal,
+    );
+    test(
+        "2147483648000000000000",
+        31,
+        ToOdd,
+        "2147483648000000000000",
+        Equal,
+    );
     test(
         "2147483648000000000000",
         31,
@@ -635,6 +781,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "4294967296000000000000",
         Equal,
     );
+    test(
+        "4294967296000000000000",
+        32,
+        NearestTiesAway,
+        "4294967296000000000000",
+        Equal,
+    );
+    test(
+        "4294967296000000000000",
+        32,
+        ToOdd,
+        "4294967296000000000000",
+        Equal,
+    );
     test(
         "4294967296000000000000",
         32,
@@ -678,6 +838,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "8589934592000000000000",
         Equal,
     );
+    test(
+        "8589934592000000000000",
+        33,
+        NearestTiesAway,
+        "8589934592000000000000",
+        Equal,
+    );
+    test(
+        "8589934592000000000000",
+        33,
+        ToOdd,
+        "8589934592000000000000",
+        Equal,
+    );
     test(
         "8589934592000000000000",
         33,
@@ -721,6 +895,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "1267650600228229401496703205376000000000000",
         Equal,
     );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        NearestTiesAway,
+        "1267650600228229401496703205376000000000000",
+        Equal,
+    );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        ToOdd,
+        "1267650600228229401496703205376000000000000",
+        Equal,
+    );
     test(
         "1267650600228229401496703205376000000000000",
         100,
@@ -734,6 +922,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("1000000000000", 10, Floor, "1000000000000", Equal);
     test("1000000000000", 10, Ceiling, "1000000000000", Equal);
     test("1000000000000", 10, Nearest, "1000000000000", Equal);
+    test("1000000000000", 10, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 10, ToOdd, "1000000000000", Equal);
     test("1000000000000", 10, Exact, "1000000000000", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -741,24 +931,32 @@ fn test_round_to_multiple_of_power_of_2() {
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "4722366482869645213696", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, ToOdd, "4722366482869645213696", Greater);
 
     test("4294967295", 31, Down, "2147483648", Less);
     test("4294967295", 31, Up, "4294967296", Greater);
     test("4294967295", 31, Floor, "2147483648", Less);
     test("4294967295", 31, Ceiling, "4294967296", Greater);
     test("4294967295", 31, Nearest, "4294967296", Greater);
+    test("4294967295", 31, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 31, ToOdd, "2147483648", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "4294967296", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "4294967296", Greater);
     test("4294967295", 32, Nearest, "4294967296", Greater);
+    test("4294967295", 32, NearestTiesAway, "4294967296", Greater);
+    test("4294967295", 32, ToOdd, "4294967296", Greater);
 
     test("4294967296", 32, Down, "4294967296", Equal);
     test("4294967296", 32, Up, "4294967296", Equal);
     test("4294967296", 32, Floor, "4294967296", Equal);
     test("4294967296", 32, Ceiling, "4294967296", Equal);
     test("4294967296", 32, Nearest, "4294967296", Equal);
+    test("4294967296", 32, NearestTiesAway, "4294967296", Equal);
+    test("4294967296", 32, ToOdd, "4294967296", Equal);
     test("4294967296", 32, Exact, "4294967296", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -766,6 +964,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "8589934592", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "8589934592", Greater);
+    test("4294967296", 33, ToOdd, "8589934592", Greater);
 }
 
 #[test]
@@ -894,6 +1094,38 @@ fn limbs_round_to_multiple_of_power_of_2_nearest_properties() {
     });
 }
 
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_away_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, pow)| {
+        let (r, o) = limbs_round_to_multiple_of_power_of_2_nearest_ties_away(&xs, pow);
+        let r = Natural::from_owned_limbs_asc(r);
+        let x = Natural::from_owned_limbs_asc(xs);
+        let (r_alt, o_alt) = (&x).round_to_multiple_of_power_of_2(pow, NearestTiesAway);
+        assert_eq!(r_alt, r);
+        assert_eq!(o_alt, o);
+        assert_eq!(r.cmp(&x), o);
+    });
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_to_odd_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, pow)| {
+        let (r, o) = limbs_round_to_multiple_of_power_of_2_to_odd(&xs, pow);
+        let r = Natural::from_owned_limbs_asc(r);
+        let x = Natural::from_owned_limbs_asc(xs);
+        let (r_alt, o_alt) = (&x).round_to_multiple_of_power_of_2(pow, ToOdd);
+        assert_eq!(r_alt, r);
+        assert_eq!(o_alt, o);
+        assert_eq!(r.cmp(&x), o);
+    });
+}
+
 #[test]
 fn limbs_round_to_multiple_of_power_of_2_properties() {
     let mut config = GenConfig::new();
@@ -973,6 +1205,42 @@ fn limbs_round_to_multiple_of_power_of_2_nearest_in_place_properties() {
     );
 }
 
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, pow)| {
+            let old_xs = xs.clone();
+            let o = limbs_round_to_multiple_of_power_of_2_nearest_ties_away_in_place(&mut xs, pow);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs)
+                .round_to_multiple_of_power_of_2(pow, NearestTiesAway);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o_alt, o);
+        },
+    );
+}
+
+#[test]
+fn limbs_round_to_multiple_of_power_of_2_to_odd_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, pow)| {
+            let old_xs = xs.clone();
+            let o = limbs_round_to_multiple_of_power_of_2_to_odd_in_place(&mut xs, pow);
+            let (n, o_alt) =
+                Natural::from_owned_limbs_asc(old_xs).round_to_multiple_of_power_of_2(pow, ToOdd);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o_alt, o);
+        },
+    );
+}
+
 #[test]
 fn limbs_round_to_multiple_of_power_of_2_in_place_properties() {
     let mut config = GenConfig::new();
@@ -1035,7 +1303,7 @@ fn round_to_multiple_of_power_of_2_properties() {
             Floor | Down => assert!(r <= n),
             Ceiling | Up => assert!(r >= n),
             Exact => assert_eq!(r, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 let k = Natural::power_of_2(pow);
                 let closest;
                 let second_closest;
@@ -1048,9 +1316,14 @@ fn round_to_multiple_of_power_of_2_properties() {
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(!r.get_bit(pow));
+                    if rm == Nearest {
+                        assert!(!r.get_bit(pow));
+                    } else {
+                        assert_eq!(o, Greater);
+                    }
                 }
             }
+            ToOdd => assert!(r == n || r.get_bit(pow)),
         }
     });
 
@@ -1062,6 +1335,11 @@ fn round_to_multiple_of_power_of_2_properties() {
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Floor), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Ceiling), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Nearest), so);
+        assert_eq!(
+            (&shifted).round_to_multiple_of_power_of_2(pow, NearestTiesAway),
+            so
+        );
+        assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, ToOdd), so);
         assert_eq!((&shifted).round_to_multiple_of_power_of_2(pow, Exact), so);
     });
 
@@ -1072,6 +1350,10 @@ fn round_to_multiple_of_power_of_2_properties() {
         assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Up), up);
         assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Floor), down);
         assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Ceiling), up);
+        let nearest_ties_away = (&n).round_to_multiple_of_power_of_2(pow, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&n).round_to_multiple_of_power_of_2(pow, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = n.round_to_multiple_of_power_of_2(pow, Nearest);
         assert!(nearest == down || nearest == up);
     });
@@ -49,6 +49,8 @@ macro_rules! test_shl_round_signed_helper {
         test("0", 0, Floor, "0", Equal);
         test("0", 0, Ceiling, "0", Equal);
         test("0", 0, Nearest, "0", Equal);
+        test("0", 0, NearestTiesAway, "0", Equal);
+        test("0", 0, ToOdd, "0", Equal);
         test("0", 0, Exact, "0", Equal);
 
         test("0", -10, Down, "0", Equal);
@@ -56,6 +58,8 @@ macro_rules! test_shl_round_signed_helper {
         test("0", -10, Floor, "0", Equal);
         test("0", -10, Ceiling, "0", Equal);
         test("0", -10, Nearest, "0", Equal);
+        test("0", -10, NearestTiesAway, "0", Equal);
+        test("0", -10, ToOdd, "0", Equal);
         test("0", -10, Exact, "0", Equal);
 
         test("123", 0, Down, "123", Equal);
@@ -63,6 +67,8 @@ macro_rules! test_shl_round_signed_helper {
         test("123", 0, Floor, "123", Equal);
         test("123", 0, Ceiling, "123", Equal);
         test("123", 0, Nearest, "123", Equal);
+        test("123", 0, NearestTiesAway, "123", Equal);
+        test("123", 0, ToOdd, "123", Equal);
         test("123", 0, Exact, "123", Equal);
 
         test("245", -1, Down, "122", Less);
@@ -70,12 +76,16 @@ macro_rules! test_shl_round_signed_helper {
         test("245", -1, Floor, "122", Less);
         test("245", -1, Ceiling, "123", Greater);
         test("245", -1, Nearest, "122", Less);
+        test("245", -1, NearestTiesAway, "123", Greater);
+        test("245", -1, ToOdd, "123", Greater);
 
         test("246", -1, Down, "123", Equal);
         test("246", -1, Up, "123", Equal);
         test("246", -1, Floor, "123", Equal);
         test("246", -1, Ceiling, "123", Equal);
         test("246", -1, Nearest, "123", Equal);
+        test("246", -1, NearestTiesAway, "123", Equal);
+        test("246", -1, ToOdd, "123", Equal);
         test("246", -1, Exact, "123", Equal);
 
         test("247", -1, Down, "123", Less);
@@ -83,18 +93,24 @@ macro_rules! test_shl_round_signed_helper {
         test("247", -1, Floor, "123", Less);
         test("247", -1, Ceiling, "124", Greater);
         test("247", -1, Nearest, "124", Greater);
+        test("247", -1, NearestTiesAway, "124", Greater);
+        test("247", -1, ToOdd, "123", Less);
 
         test("491", -2, Down, "122", Less);
         test("491", -2, Up, "123", Greater);
         test("491", -2, Floor, "122", Less);
         test("491", -2, Ceiling, "123", Greater);
         test("491", -2, Nearest, "123", Greater);
+        test("491", -2, NearestTiesAway, "123", Greater);
+        test("491", -2, ToOdd, "123", Greater);
 
         test("492", -2, Down, "123", Equal);
         test("492", -2, Up, "123", Equal);
         test("492", -2, Floor, "123", Equal);
         test("492", -2, Ceiling, "123", Equal);
         test("492", -2, Nearest, "123", Equal);
+        test("492", -2, NearestTiesAway, "123", Equal);
+        test("492", -2, ToOdd, "123", Equal);
         test("492", -2, Exact, "123", Equal);
 
         test("493", -2, Down, "123", Less);
@@ -102,18 +118,24 @@ macro_rules! test_shl_round_signed_helper {
         test("493", -2, Floor, "123", Less);
         test("493", -2, Ceiling, "124", Greater);
         test("493", -2, Nearest, "123", Less);
+        test("493", -2, NearestTiesAway, "123", Less);
+        test("493", -2, ToOdd, "123", Less);
 
         test("4127195135", -25, Down, "122", Less);
         test("4127195135", -25, Up, "123", Greater);
         test("4127195135", -25, Floor, "122", Less);
         test("4127195135", -25, Ceiling, "123", Greater);
         test("4127195135", -25, Nearest, "123", Greater);
+        test("4127195135", -25, NearestTiesAway, "123", Greater);
+        test("4127195135", -25, ToOdd, "123", Greater);
 
         test("4127195136", -25, Down, "123", Equal);
         test("4127195136", -25, Up, "123", Equal);
         test("4127195136", -25, Floor, "123", Equal);
         test("4127195136", -25, Ceiling, "123", Equal);
         test("4127195136", -25, Nearest, "123", Equal);
+        test("4127195136", -25, NearestTiesAway, "123", Equal);
+        test("4127195136", -25, ToOdd, "123", Equal);
         test("4127195136", -25, Exact, "123", Equal);
 
         test("4127195137", -25, Down, "123", Less);
@@ -121,18 +143,24 @@ macro_rules! test_shl_round_signed_helper {
         test("4127195137", -25, Floor, "123", Less);
         test("4127195137", -25, Ceiling, "124", Greater);
         test("4127195137", -25, Nearest, "123", Less);
+        test("4127195137", -25, NearestTiesAway, "123", Less);
+        test("4127195137", -25, ToOdd, "123", Less);
 
         test("8254390271", -26, Down, "122", Less);
         test("8254390271", -26, Up, "123", Greater);
         test("8254390271", -26, Floor, "122", Less);
         test("8254390271", -26, Ceiling, "123", Greater);
         test("8254390271", -26, Nearest, "123", Greater);
+        test("8254390271", -26, NearestTiesAway, "123", Greater);
+        test("8254390271", -26, ToOdd, "123", Greater);
 
         test("8254390272", -26, Down, "123", Equal);
         test("8254390272", -26, Up, "123", Equal);
         test("8254390272", -26, Floor, "123", Equal);
         test("8254390272", -26, Ceiling, "123", Equal);
         test("8254390272", -26, Nearest, "123", Equal);
+        test("8254390272", -26, NearestTiesAway, "123", Equal);
+        test("8254390272", -26, ToOdd, "123", Equal);
         test("8254390272", -26, Exact, "123", Equal);
 
         test("8254390273", -26, Down, "123", Less);
@@ -140,6 +168,8 @@ macro_rules! test_shl_round_signed_helper {
         test("8254390273", -26, Floor, "123", Less);
         test("8254390273", -26, Ceiling, "124", Greater);
         test("8254390273", -26, Nearest, "123", Less);
+        test("8254390273", -26, NearestTiesAway, "123", Less);
+        test("8254390273", -26, ToOdd, "123", Less);
 
         test("155921023828072216384094494261247", -100, Down, "122", Less);
         test(
@@ -170,6 +200,20 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Greater,
         );
+        test(
+            "155921023828072216384094494261247",
+            -100,
+            NearestTiesAway,
+            "123",
+            Greater,
+        );
+        test(
+            "155921023828072216384094494261247",
+            -100,
+            ToOdd,
+            "123",
+            Greater,
+        );
 
         test(
             "155921023828072216384094494261248",
@@ -200,6 +244,20 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Equal,
         );
+        test(
+            "155921023828072216384094494261248",
+            -100,
+            NearestTiesAway,
+            "123",
+            Equal,
+        );
+        test(
+            "155921023828072216384094494261248",
+            -100,
+            ToOdd,
+            "123",
+            Equal,
+        );
         test(
             "155921023828072216384094494261248",
             -100,
@@ -237,18 +295,36 @@ macro_rules! test_shl_round_signed_helper {
             "123",
             Less,
         );
+        test(
+            "155921023828072216384094494261249",
+            -100,
+            NearestTiesAway,
+            "123",
+            Less,
+        );
+        test(
+            "155921023828072216384094494261249",
+            -100,
+            ToOdd,
+            "123",
+            Less,
+        );
 
         test("4294967295", -1, Down, "2147483647", Less);
         test("4294967295", -1, Up, "2147483648", Greater);
         test("4294967295", -1, Floor, "2147483647", Less);
         test("4294967295", -1, Ceiling, "2147483648", Greater);
         test("4294967295", -1, Nearest, "2147483648", Greater);
+        test("4294967295", -1, NearestTiesAway, "2147483648", Greater);
+        test("4294967295", -1, ToOdd, "2147483647", Less);
 
         test("4294967296", -1, Down, "2147483648", Equal);
         test("4294967296", -1, Up, "2147483648", Equal);
         test("4294967296", -1, Floor, "2147483648", Equal);
         test("4294967296", -1, Ceiling, "2147483648", Equal);
         test("4294967296", -1, Nearest, "2147483648", Equal);
+        test("4294967296", -1, NearestTiesAway, "2147483648", Equal);
+        test("4294967296", -1, ToOdd, "2147483648", Equal);
         test("4294967296", -1, Exact, "2147483648", Equal);
 
         test("4294967297", -1, Down, "2147483648", Less);
@@ -256,12 +332,16 @@ macro_rules! test_shl_round_signed_helper {
         test("4294967297", -1, Floor, "2147483648", Less);
         test("4294967297", -1, Ceiling, "2147483649", Greater);
         test("4294967297", -1, Nearest, "2147483648", Less);
+        test("4294967297", -1, NearestTiesAway, "2147483649", Greater);
+        test("4294967297", -1, ToOdd, "2147483649", Greater);
 
         test("1000000000000", 0, Down, "1000000000000", Equal);
         test("1000000000000", 0, Up, "1000000000000", Equal);
         test("1000000000000", 0, Floor, "1000000000000", Equal);
         test("1000000000000", 0, Ceiling, "1000000000000", Equal);
         test("1000000000000", 0, Nearest, "1000000000000", Equal);
+        test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+        test("1000000000000", 0, ToOdd, "1000000000000", Equal);
         test("1000000000000", 0, Exact, "1000000000000", Equal);
 
         test("7999999999999", -3, Down, "999999999999", Less);
@@ -269,12 +349,22 @@ macro_rules! test_shl_round_signed_helper {
         test("7999999999999", -3, Floor, "999999999999", Less);
         test("7999999999999", -3, Ceiling, "1000000000000", Greater);
         test("7999999999999", -3, Nearest, "1000000000000", Greater);
+        test(
+            "7999999999999",
+            -3,
+            NearestTiesAway,
+            "1000000000000",
+            Greater,
+        );
+        test("7999999999999", -3, ToOdd, "999999999999", Less);
 
         test("8000000000000", -3, Down, "1000000000000", Equal);
         test("8000000000000", -3, Up, "1000000000000", Equal);
         test("8000000000000", -3, Floor, "1000000000000", Equal);
         test("8000000000000", -3, Ceiling, "1000000000000", Equal);
         test("8000000000000", -3, Nearest, "1000000000000", Equal);
+        test("8000000000000", -3, NearestTiesAway, "1000000000000", Equal);
+        test("8000000000000", -3, ToOdd, "1000000000000", Equal);
         test("8000000000000", -3, Exact, "1000000000000", Equal);
 
         test("8000000000001", -3, Down, "1000000000000", Less);
@@ -282,12 +372,22 @@ macro_rules! test_shl_round_signed_helper {
         test("8000000000001", -3, Floor, "1000000000000", Less);
         test("8000000000001", -3, Ceiling, "1000000000001", Greater);
         test("8000000000001", -3, Nearest, "1000000000000", Less);
+        test("8000000000001", -3, NearestTiesAway, "1000000000000", Less);
+        test("8000000000001", -3, ToOdd, "1000000000001", Greater);
 
         test("16777216000000000000", -24, Down, "1000000000000", Equal);
         test("16777216000000000000", -24, Up, "1000000000000", Equal);
         test("16777216000000000000", -24, Floor, "1000000000000", Equal);
         test("16777216000000000000", -24, Ceiling, "1000000000000", Equal);
         test("16777216000000000000", -24, Nearest, "1000000000000", Equal);
+        test(
+            "16777216000000000000",
+            -24,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("16777216000000000000", -24, ToOdd, "1000000000000", Equal);
         test("16777216000000000000", -24, Exact, "1000000000000", Equal);
 
         test("33554432000000000000", -25, Down, "1000000000000", Equal);
@@ -295,6 +395,14 @@ macro_rules! test_shl_round_signed_helper {
         test("33554432000000000000", -25, Floor, "1000000000000", Equal);
         test("33554432000000000000", -25, Ceiling, "1000000000000", Equal);
         test("33554432000000000000", -25, Nearest, "1000000000000", Equal);
+        test(
+            "33554432000000000000",
+            -25,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("33554432000000000000", -25, ToOdd, "1000000000000", Equal);
         test("33554432000000000000", -25, Exact, "1000000000000", Equal);
 
         test("2147483648000000000000", -31, Down, "1000000000000", Equal);
@@ -314,6 +422,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "2147483648000000000000",
+            -31,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("2147483648000000000000", -31, ToOdd, "1000000000000", Equal);
         test("2147483648000000000000", -31, Exact, "1000000000000", Equal);
 
         test("4294967296000000000000", -32, Down, "1000000000000", Equal);
@@ -333,6 +449,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "4294967296000000000000",
+            -32,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("4294967296000000000000", -32, ToOdd, "1000000000000", Equal);
         test("4294967296000000000000", -32, Exact, "1000000000000", Equal);
 
         test("8589934592000000000000", -33, Down, "1000000000000", Equal);
@@ -352,6 +476,14 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "8589934592000000000000",
+            -33,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test("8589934592000000000000", -33, ToOdd, "1000000000000", Equal);
         test("8589934592000000000000", -33, Exact, "1000000000000", Equal);
 
         test(
@@ -389,6 +521,20 @@ macro_rules! test_shl_round_signed_helper {
             "1000000000000",
             Equal,
         );
+        test(
+            "1267650600228229401496703205376000000000000",
+            -100,
+            NearestTiesAway,
+            "1000000000000",
+            Equal,
+        );
+        test(
+            "1267650600228229401496703205376000000000000",
+            -100,
+            ToOdd,
+            "1000000000000",
+            Equal,
+        );
         test(
             "1267650600228229401496703205376000000000000",
             -100,
@@ -402,6 +548,8 @@ macro_rules! test_shl_round_signed_helper {
         test("1000000000000", -10, Floor, "976562500", Equal);
         test("1000000000000", -10, Ceiling, "976562500", Equal);
         test("1000000000000", -10, Nearest, "976562500", Equal);
+        test("1000000000000", -10, NearestTiesAway, "976562500", Equal);
+        test("1000000000000", -10, ToOdd, "976562500", Equal);
         test("1000000000000", -10, Exact, "976562500", Equal);
 
         test("980657949", -72, Down, "0", Less);
@@ -409,24 +557,32 @@ macro_rules! test_shl_round_signed_helper {
         test("980657949", -72, Floor, "0", Less);
         test("980657949", -72, Ceiling, "1", Greater);
         test("980657949", -72, Nearest, "0", Less);
+        test("980657949", -72, NearestTiesAway, "0", Less);
+        test("980657949", -72, ToOdd, "1", Greater);
 
         test("4294967295", -31, Down, "1", Less);
         test("4294967295", -31, Up, "2", Greater);
         test("4294967295", -31, Floor, "1", Less);
         test("4294967295", -31, Ceiling, "2", Greater);
         test("4294967295", -31, Nearest, "2", Greater);
+        test("4294967295", -31, NearestTiesAway, "2", Greater);
+        test("4294967295", -31, ToOdd, "1", Less);
 
         test("4294967295", -32, Down, "0", Less);
         test("4294967295", -32, Up, "1", Greater);
         test("4294967295", -32, Floor, "0", Less);
         test("4294967295", -32, Ceiling, "1", Greater);
         test("4294967295", -32, Nearest, "1", Greater);
+        test("4294967295", -32, NearestTiesAway, "1", Greater);
+        test("4294967295", -32, ToOdd, "1", Greater);
 
         test("4294967296", -32, Down, "1", Equal);
         test("4294967296", -32, Up, "1", Equal);
         test("4294967296", -32, Floor, "1", Equal);
         test("4294967296", -32, Ceiling, "1", Equal);
         test("4294967296", -32, Nearest, "1", Equal);
+        test("4294967296", -32, NearestTiesAway, "1", Equal);
+        test("4294967296", -32, ToOdd, "1", Equal);
         test("4294967296", -32, Exact, "1", Equal);
 
         test("4294967296", -33, Down, "0", Less);
@@ -434,6 +590,8 @@ macro_rules! test_shl_round_signed_helper {
         test("4294967296", -33, Floor, "0", Less);
         test("4294967296", -33, Ceiling, "1", Greater);
         test("4294967296", -33, Nearest, "0", Less);
+        test("4294967296", -33, NearestTiesAway, "1", Greater);
+        test("4294967296", -33, ToOdd, "1", Greater);
 
         test("0", 10, Exact, "0", Equal);
         test("123", 1, Exact, "246", Equal);
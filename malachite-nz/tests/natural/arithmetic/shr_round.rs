@@ -27,9 +27,11 @@ use malachite_base::test_util::generators::{
     unsigned_vec_unsigned_pair_gen_var_20, unsigned_vec_unsigned_rounding_mode_triple_gen_var_2,
 };
 use malachite_nz::natural::arithmetic::shr_round::{
-    limbs_shr_exact, limbs_shr_round, limbs_shr_round_nearest, limbs_shr_round_up,
-    limbs_vec_shr_exact_in_place, limbs_vec_shr_round_in_place,
-    limbs_vec_shr_round_nearest_in_place, limbs_vec_shr_round_up_in_place,
+    limbs_shr_exact, limbs_shr_round, limbs_shr_round_nearest, limbs_shr_round_nearest_ties_away,
+    limbs_shr_round_to_odd, limbs_shr_round_up, limbs_vec_shr_exact_in_place,
+    limbs_vec_shr_round_in_place, limbs_vec_shr_round_nearest_in_place,
+    limbs_vec_shr_round_nearest_ties_away_in_place, limbs_vec_shr_round_to_odd_in_place,
+    limbs_vec_shr_round_up_in_place,
 };
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
@@ -163,6 +165,14 @@ fn test_limbs_shr_round_and_limbs_vec_shr_round_in_place() {
     test(&[256, 456], 8, Exact, Some(&[3355443201, 1]), Equal);
     test(&[u32::MAX, 1], 1, Exact, None, Equal);
     test(&[u32::MAX, u32::MAX], 32, Down, Some(&[u32::MAX]), Less);
+    test(&[3], 1, NearestTiesAway, Some(&[2]), Greater);
+    test(&[3], 1, ToOdd, Some(&[1]), Less);
+    test(&[123, 456], 31, NearestTiesAway, Some(&[912, 0]), Less);
+    test(&[123, 456], 31, ToOdd, Some(&[913, 0]), Greater);
+    test(&[123, 456], 100, NearestTiesAway, Some(&[]), Less);
+    test(&[123, 456], 100, ToOdd, Some(&[1]), Greater);
+    test(&[u32::MAX, 1], 1, NearestTiesAway, Some(&[0, 1]), Greater);
+    test(&[u32::MAX, 1], 1, ToOdd, Some(&[u32::MAX, 0]), Less);
 }
 
 fn test_shr_round_unsigned_helper<T: PrimitiveUnsigned>()
@@ -194,6 +204,8 @@ where
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, ToOdd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -201,6 +213,8 @@ where
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, ToOdd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -208,6 +222,8 @@ where
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, ToOdd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "122", Less);
@@ -215,12 +231,16 @@ where
     test("245", 1, Floor, "122", Less);
     test("245", 1, Ceiling, "123", Greater);
     test("245", 1, Nearest, "122", Less);
+    test("245", 1, NearestTiesAway, "123", Greater);
+    test("245", 1, ToOdd, "123", Greater);
 
     test("246", 1, Down, "123", Equal);
     test("246", 1, Up, "123", Equal);
     test("246", 1, Floor, "123", Equal);
     test("246", 1, Ceiling, "123", Equal);
     test("246", 1, Nearest, "123", Equal);
+    test("246", 1, NearestTiesAway, "123", Equal);
+    test("246", 1, ToOdd, "123", Equal);
     test("246", 1, Exact, "123", Equal);
 
     test("247", 1, Down, "123", Less);
@@ -228,18 +248,24 @@ where
     test("247", 1, Floor, "123", Less);
     test("247", 1, Ceiling, "124", Greater);
     test("247", 1, Nearest, "124", Greater);
+    test("247", 1, NearestTiesAway, "124", Greater);
+    test("247", 1, ToOdd, "123", Less);
 
     test("491", 2, Down, "122", Less);
     test("491", 2, Up, "123", Greater);
     test("491", 2, Floor, "122", Less);
     test("491", 2, Ceiling, "123", Greater);
     test("491", 2, Nearest, "123", Greater);
+    test("491", 2, NearestTiesAway, "123", Greater);
+    test("491", 2, ToOdd, "123", Greater);
 
     test("492", 2, Down, "123", Equal);
     test("492", 2, Up, "123", Equal);
     test("492", 2, Floor, "123", Equal);
     test("492", 2, Ceiling, "123", Equal);
     test("492", 2, Nearest, "123", Equal);
+    test("492", 2, NearestTiesAway, "123", Equal);
+    test("492", 2, ToOdd, "123", Equal);
     test("492", 2, Exact, "123", Equal);
 
     test("493", 2, Down, "123", Less);
@@ -247,18 +273,24 @@ where
     test("493", 2, Floor, "123", Less);
     test("493", 2, Ceiling, "124", Greater);
     test("493", 2, Nearest, "123", Less);
+    test("493", 2, NearestTiesAway, "123", Less);
+    test("493", 2, ToOdd, "123", Less);
 
     test("4127195135", 25, Down, "122", Less);
     test("4127195135", 25, Up, "123", Greater);
     test("4127195135", 25, Floor, "122", Less);
     test("4127195135", 25, Ceiling, "123", Greater);
     test("4127195135", 25, Nearest, "123", Greater);
+    test("4127195135", 25, NearestTiesAway, "123", Greater);
+    test("4127195135", 25, ToOdd, "123", Greater);
 
     test("4127195136", 25, Down, "123", Equal);
     test("4127195136", 25, Up, "123", Equal);
     test("4127195136", 25, Floor, "123", Equal);
     test("4127195136", 25, Ceiling, "123", Equal);
     test("4127195136", 25, Nearest, "123", Equal);
+    test("4127195136", 25, NearestTiesAway, "123", Equal);
+    test("4127195136", 25, ToOdd, "123", Equal);
     test("4127195136", 25, Exact, "123", Equal);
 
     test("4127195137", 25, Down, "123", Less);
@@ -266,18 +298,24 @@ where
     test("4127195137", 25, Floor, "123", Less);
     test("4127195137", 25, Ceiling, "124", Greater);
     test("4127195137", 25, Nearest, "123", Less);
+    test("4127195137", 25, NearestTiesAway, "123", Less);
+    test("4127195137", 25, ToOdd, "123", Less);
 
     test("8254390271", 26, Down, "122", Less);
     test("8254390271", 26, Up, "123", Greater);
     test("8254390271", 26, Floor, "122", Less);
     test("8254390271", 26, Ceiling, "123", Greater);
     test("8254390271", 26, Nearest, "123", Greater);
+    test("8254390271", 26, NearestTiesAway, "123", Greater);
+    test("8254390271", 26, ToOdd, "123", Greater);
 
     test("8254390272", 26, Down, "123", Equal);
     test("8254390272", 26, Up, "123", Equal);
     test("8254390272", 26, Floor, "123", Equal);
     test("8254390272", 26, Ceiling, "123", Equal);
     test("8254390272", 26, Nearest, "123", Equal);
+    test("8254390272", 26, NearestTiesAway, "123", Equal);
+    test("8254390272", 26, ToOdd, "123", Equal);
     test("8254390272", 26, Exact, "123", Equal);
 
     test("8254390273", 26, Down, "123", Less);
@@ -285,6 +323,8 @@ where
     test("8254390273", 26, Floor, "123", Less);
     test("8254390273", 26, Ceiling, "124", Greater);
     test("8254390273", 26, Nearest, "123", Less);
+    test("8254390273", 26, NearestTiesAway, "123", Less);
+    test("8254390273", 26, ToOdd, "123", Less);
 
     test("155921023828072216384094494261247", 100, Down, "122", Less);
     test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -303,6 +343,20 @@ where
         "123",
         Greater,
     );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        NearestTiesAway,
+        "123",
+        Greater,
+    );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        ToOdd,
+        "123",
+        Greater,
+    );
 
     test("155921023828072216384094494261248", 100, Down, "123", Equal);
     test("155921023828072216384094494261248", 100, Up, "123", Equal);
@@ -327,6 +381,20 @@ where
         "123",
         Equal,
     );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        NearestTiesAway,
+        "123",
+        Equal,
+    );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        ToOdd,
+        "123",
+        Equal,
+    );
     test(
         "155921023828072216384094494261248",
         100,
@@ -352,18 +420,30 @@ where
         "123",
         Less,
     );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        NearestTiesAway,
+        "123",
+        Less,
+    );
+    test("155921023828072216384094494261249", 100, ToOdd, "123", Less);
 
     test("4294967295", 1, Down, "2147483647", Less);
     test("4294967295", 1, Up, "2147483648", Greater);
     test("4294967295", 1, Floor, "2147483647", Less);
     test("4294967295", 1, Ceiling, "2147483648", Greater);
     test("4294967295", 1, Nearest, "2147483648", Greater);
+    test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+    test("4294967295", 1, ToOdd, "2147483647", Less);
 
     test("4294967296", 1, Down, "2147483648", Equal);
     test("4294967296", 1, Up, "2147483648", Equal);
     test("4294967296", 1, Floor, "2147483648", Equal);
     test("4294967296", 1, Ceiling, "2147483648", Equal);
     test("4294967296", 1, Nearest, "2147483648", Equal);
+    test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+    test("4294967296", 1, ToOdd, "2147483648", Equal);
     test("4294967296", 1, Exact, "2147483648", Equal);
 
     test("4294967297", 1, Down, "2147483648", Less);
@@ -371,12 +451,16 @@ where
     test("4294967297", 1, Floor, "2147483648", Less);
     test("4294967297", 1, Ceiling, "2147483649", Greater);
     test("4294967297", 1, Nearest, "2147483648", Less);
+    test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+    test("4294967297", 1, ToOdd, "2147483649", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, ToOdd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "999999999999", Less);
@@ -384,12 +468,22 @@ where
     test("7999999999999", 3, Floor, "999999999999", Less);
     test("7999999999999", 3, Ceiling, "1000000000000", Greater);
     test("7999999999999", 3, Nearest, "1000000000000", Greater);
+    test(
+        "7999999999999",
+        3,
+        NearestTiesAway,
+        "1000000000000",
+        Greater,
+    );
+    test("7999999999999", 3, ToOdd, "999999999999", Less);
 
     test("8000000000000", 3, Down, "1000000000000", Equal);
     test("8000000000000", 3, Up, "1000000000000", Equal);
     test("8000000000000", 3, Floor, "1000000000000", Equal);
     test("8000000000000", 3, Ceiling, "1000000000000", Equal);
     test("8000000000000", 3, Nearest, "1000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+    test("8000000000000", 3, ToOdd, "1000000000000", Equal);
     test("8000000000000", 3, Exact, "1000000000000", Equal);
 
     test("8000000000001", 3, Down, "1000000000000", Less);
@@ -397,12 +491,22 @@ where
     test("8000000000001", 3, Floor, "1000000000000", Less);
     test("8000000000001", 3, Ceiling, "1000000000001", Greater);
     test("8000000000001", 3, Nearest, "1000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+    test("8000000000001", 3, ToOdd, "1000000000001", Greater);
 
     test("16777216000000000000", 24, Down, "1000000000000", Equal);
     test("16777216000000000000", 24, Up, "1000000000000", Equal);
     test("16777216000000000000", 24, Floor, "1000000000000", Equal);
     test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
     test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+    test(
+        "16777216000000000000",
+        24,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("16777216000000000000", 24, ToOdd, "1000000000000", Equal);
     test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
     test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -410,6 +514,14 @@ where
     test("33554432000000000000", 25, Floor, "1000000000000", Equal);
     test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
     test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+    test(
+        "33554432000000000000",
+        25,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("33554432000000000000", 25, ToOdd, "1000000000000", Equal);
     test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
     test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -429,6 +541,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "2147483648000000000000",
+        31,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("2147483648000000000000", 31, ToOdd, "1000000000000", Equal);
     test("2147483648000000000000", 31, Exact, "1000000000000", Equal);
 
     test("4294967296000000000000", 32, Down, "1000000000000", Equal);
@@ -448,6 +568,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "4294967296000000000000",
+        32,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("4294967296000000000000", 32, ToOdd, "1000000000000", Equal);
     test("4294967296000000000000", 32, Exact, "1000000000000", Equal);
 
     test("8589934592000000000000", 33, Down, "1000000000000", Equal);
@@ -467,6 +595,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "8589934592000000000000",
+        33,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("8589934592000000000000", 33, ToOdd, "1000000000000", Equal);
     test("8589934592000000000000", 33, Exact, "1000000000000", Equal);
 
     test(
@@ -504,6 +640,20 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        ToOdd,
+        "1000000000000",
+        Equal,
+    );
     test(
         "1267650600228229401496703205376000000000000",
         100,
@@ -517,6 +667,8 @@ where
     test("1000000000000", 10, Floor, "976562500", Equal);
     test("1000000000000", 10, Ceiling, "976562500", Equal);
     test("1000000000000", 10, Nearest, "976562500", Equal);
+    test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+    test("1000000000000", 10, ToOdd, "976562500", Equal);
     test("1000000000000", 10, Exact, "976562500", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -524,24 +676,32 @@ where
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "1", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, ToOdd, "1", Greater);
 
     test("4294967295", 31, Down, "1", Less);
     test("4294967295", 31, Up, "2", Greater);
     test("4294967295", 31, Floor, "1", Less);
     test("4294967295", 31, Ceiling, "2", Greater);
     test("4294967295", 31, Nearest, "2", Greater);
+    test("4294967295", 31, NearestTiesAway, "2", Greater);
+    test("4294967295", 31, ToOdd, "1", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "1", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "1", Greater);
     test("4294967295", 32, Nearest, "1", Greater);
+    test("4294967295", 32, NearestTiesAway, "1", Greater);
+    test("4294967295", 32, ToOdd, "1", Greater);
 
     test("4294967296", 32, Down, "1", Equal);
     test("4294967296", 32, Up, "1", Equal);
     test("4294967296", 32, Floor, "1", Equal);
     test("4294967296", 32, Ceiling, "1", Equal);
     test("4294967296", 32, Nearest, "1", Equal);
+    test("4294967296", 32, NearestTiesAway, "1", Equal);
+    test("4294967296", 32, ToOdd, "1", Equal);
     test("4294967296", 32, Exact, "1", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -549,6 +709,8 @@ where
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "1", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "1", Greater);
+    test("4294967296", 33, ToOdd, "1", Greater);
 }
 
 #[test]
@@ -617,6 +779,8 @@ where
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, ToOdd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -624,6 +788,8 @@ where
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, ToOdd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -631,6 +797,8 @@ where
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, ToOdd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("245", 1, Down, "122", Less);
@@ -638,12 +806,16 @@ where
     test("245", 1, Floor, "122", Less);
     test("245", 1, Ceiling, "123", Greater);
     test("245", 1, Nearest, "122", Less);
+    test("245", 1, NearestTiesAway, "123", Greater);
+    test("245", 1, ToOdd, "123", Greater);
 
     test("246", 1, Down, "123", Equal);
     test("246", 1, Up, "123", Equal);
     test("246", 1, Floor, "123", Equal);
     test("246", 1, Ceiling, "123", Equal);
     test("246", 1, Nearest, "123", Equal);
+    test("246", 1, NearestTiesAway, "123", Equal);
+    test("246", 1, ToOdd, "123", Equal);
     test("246", 1, Exact, "123", Equal);
 
     test("247", 1, Down, "123", Less);
@@ -651,18 +823,24 @@ where
     test("247", 1, Floor, "123", Less);
     test("247", 1, Ceiling, "124", Greater);
     test("247", 1, Nearest, "124", Greater);
+    test("247", 1, NearestTiesAway, "124", Greater);
+    test("247", 1, ToOdd, "123", Less);
 
     test("491", 2, Down, "122", Less);
     test("491", 2, Up, "123", Greater);
     test("491", 2, Floor, "122", Less);
     test("491", 2, Ceiling, "123", Greater);
     test("491", 2, Nearest, "123", Greater);
+    test("491", 2, NearestTiesAway, "123", Greater);
+    test("491", 2, ToOdd, "123", Greater);
 
     test("492", 2, Down, "123", Equal);
     test("492", 2, Up, "123", Equal);
     test("492", 2, Floor, "123", Equal);
     test("492", 2, Ceiling, "123", Equal);
     test("492", 2, Nearest, "123", Equal);
+    test("492", 2, NearestTiesAway, "123", Equal);
+    test("492", 2, ToOdd, "123", Equal);
     test("492", 2, Exact, "123", Equal);
 
     test("493", 2, Down, "123", Less);
@@ -670,18 +848,24 @@ where
     test("493", 2, Floor, "123", Less);
     test("493", 2, Ceiling, "124", Greater);
     test("493", 2, Nearest, "123", Less);
+    test("493", 2, NearestTiesAway, "123", Less);
+    test("493", 2, ToOdd, "123", Less);
 
     test("4127195135", 25, Down, "122", Less);
     test("4127195135", 25, Up, "123", Greater);
     test("4127195135", 25, Floor, "122", Less);
     test("4127195135", 25, Ceiling, "123", Greater);
     test("4127195135", 25, Nearest, "123", Greater);
+    test("4127195135", 25, NearestTiesAway, "123", Greater);
+    test("4127195135", 25, ToOdd, "123", Greater);
 
     test("4127195136", 25, Down, "123", Equal);
     test("4127195136", 25, Up, "123", Equal);
     test("4127195136", 25, Floor, "123", Equal);
     test("4127195136", 25, Ceiling, "123", Equal);
     test("4127195136", 25, Nearest, "123", Equal);
+    test("4127195136", 25, NearestTiesAway, "123", Equal);
+    test("4127195136", 25, ToOdd, "123", Equal);
     test("4127195136", 25, Exact, "123", Equal);
 
     test("4127195137", 25, Down, "123", Less);
@@ -689,18 +873,24 @@ where
     test("4127195137", 25, Floor, "123", Less);
     test("4127195137", 25, Ceiling, "124", Greater);
     test("4127195137", 25, Nearest, "123", Less);
+    test("4127195137", 25, NearestTiesAway, "123", Less);
+    test("4127195137", 25, ToOdd, "123", Less);
 
     test("8254390271", 26, Down, "122", Less);
     test("8254390271", 26, Up, "123", Greater);
     test("8254390271", 26, Floor, "122", Less);
     test("8254390271", 26, Ceiling, "123", Greater);
     test("8254390271", 26, Nearest, "123", Greater);
+    test("8254390271", 26, NearestTiesAway, "123", Greater);
+    test("8254390271", 26, ToOdd, "123", Greater);
 
     test("8254390272", 26, Down, "123", Equal);
     test("8254390272", 26, Up, "123", Equal);
     test("8254390272", 26, Floor, "123", Equal);
     test("8254390272", 26, Ceiling, "123", Equal);
     test("8254390272", 26, Nearest, "123", Equal);
+    test("8254390272", 26, NearestTiesAway, "123", Equal);
+    test("8254390272", 26, ToOdd, "123", Equal);
     test("8254390272", 26, Exact, "123", Equal);
 
     test("8254390273", 26, Down, "123", Less);
@@ -708,6 +898,8 @@ where
     test("8254390273", 26, Floor, "123", Less);
     test("8254390273", 26, Ceiling, "124", Greater);
     test("8254390273", 26, Nearest, "123", Less);
+    test("8254390273", 26, NearestTiesAway, "123", Less);
+    test("8254390273", 26, ToOdd, "123", Less);
 
     test("155921023828072216384094494261247", 100, Down, "122", Less);
     test("155921023828072216384094494261247", 100, Up, "123", Greater);
@@ -726,6 +918,20 @@ where
         "123",
         Greater,
     );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        NearestTiesAway,
+        "123",
+        Greater,
+    );
+    test(
+        "155921023828072216384094494261247",
+        100,
+        ToOdd,
+        "123",
+        Greater,
+    );
 
     test("155921023828072216384094494261248", 100, Down, "123", Equal);
     test("155921023828072216384094494261248", 100, Up, "123", Equal);
@@ -750,6 +956,20 @@ where
         "123",
         Equal,
     );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        NearestTiesAway,
+        "123",
+        Equal,
+    );
+    test(
+        "155921023828072216384094494261248",
+        100,
+        ToOdd,
+        "123",
+        Equal,
+    );
     test(
         "155921023828072216384094494261248",
         100,
@@ -775,18 +995,30 @@ where
         "123",
         Less,
     );
+    test(
+        "155921023828072216384094494261249",
+        100,
+        NearestTiesAway,
+        "123",
+        Less,
+    );
+    test("155921023828072216384094494261249", 100, ToOdd, "123", Less);
 
     test("4294967295", 1, Down, "2147483647", Less);
     test("4294967295", 1, Up, "2147483648", Greater);
     test("4294967295", 1, Floor, "2147483647", Less);
     test("4294967295", 1, Ceiling, "2147483648", Greater);
     test("4294967295", 1, Nearest, "2147483648", Greater);
+    test("4294967295", 1, NearestTiesAway, "2147483648", Greater);
+    test("4294967295", 1, ToOdd, "2147483647", Less);
 
     test("4294967296", 1, Down, "2147483648", Equal);
     test("4294967296", 1, Up, "2147483648", Equal);
     test("4294967296", 1, Floor, "2147483648", Equal);
     test("4294967296", 1, Ceiling, "2147483648", Equal);
     test("4294967296", 1, Nearest, "2147483648", Equal);
+    test("4294967296", 1, NearestTiesAway, "2147483648", Equal);
+    test("4294967296", 1, ToOdd, "2147483648", Equal);
     test("4294967296", 1, Exact, "2147483648", Equal);
 
     test("4294967297", 1, Down, "2147483648", Less);
@@ -794,12 +1026,16 @@ where
     test("4294967297", 1, Floor, "2147483648", Less);
     test("4294967297", 1, Ceiling, "2147483649", Greater);
     test("4294967297", 1, Nearest, "2147483648", Less);
+    test("4294967297", 1, NearestTiesAway, "2147483649", Greater);
+    test("4294967297", 1, ToOdd, "2147483649", Greater);
 
     test("1000000000000", 0, Down, "1000000000000", Equal);
     test("1000000000000", 0, Up, "1000000000000", Equal);
     test("1000000000000", 0, Floor, "1000000000000", Equal);
     test("1000000000000", 0, Ceiling, "1000000000000", Equal);
     test("1000000000000", 0, Nearest, "1000000000000", Equal);
+    test("1000000000000", 0, NearestTiesAway, "1000000000000", Equal);
+    test("1000000000000", 0, ToOdd, "1000000000000", Equal);
     test("1000000000000", 0, Exact, "1000000000000", Equal);
 
     test("7999999999999", 3, Down, "999999999999", Less);
@@ -807,12 +1043,22 @@ where
     test("7999999999999", 3, Floor, "999999999999", Less);
     test("7999999999999", 3, Ceiling, "1000000000000", Greater);
     test("7999999999999", 3, Nearest, "1000000000000", Greater);
+    test(
+        "7999999999999",
+        3,
+        NearestTiesAway,
+        "1000000000000",
+        Greater,
+    );
+    test("7999999999999", 3, ToOdd, "999999999999", Less);
 
     test("8000000000000", 3, Down, "1000000000000", Equal);
     test("8000000000000", 3, Up, "1000000000000", Equal);
     test("8000000000000", 3, Floor, "1000000000000", Equal);
     test("8000000000000", 3, Ceiling, "1000000000000", Equal);
     test("8000000000000", 3, Nearest, "1000000000000", Equal);
+    test("8000000000000", 3, NearestTiesAway, "1000000000000", Equal);
+    test("8000000000000", 3, ToOdd, "1000000000000", Equal);
     test("8000000000000", 3, Exact, "1000000000000", Equal);
 
     test("8000000000001", 3, Down, "1000000000000", Less);
@@ -820,12 +1066,22 @@ where
     test("8000000000001", 3, Floor, "1000000000000", Less);
     test("8000000000001", 3, Ceiling, "1000000000001", Greater);
     test("8000000000001", 3, Nearest, "1000000000000", Less);
+    test("8000000000001", 3, NearestTiesAway, "1000000000000", Less);
+    test("8000000000001", 3, ToOdd, "1000000000001", Greater);
 
     test("16777216000000000000", 24, Down, "1000000000000", Equal);
     test("16777216000000000000", 24, Up, "1000000000000", Equal);
     test("16777216000000000000", 24, Floor, "1000000000000", Equal);
     test("16777216000000000000", 24, Ceiling, "1000000000000", Equal);
     test("16777216000000000000", 24, Nearest, "1000000000000", Equal);
+    test(
+        "16777216000000000000",
+        24,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("16777216000000000000", 24, ToOdd, "1000000000000", Equal);
     test("16777216000000000000", 24, Exact, "1000000000000", Equal);
 
     test("33554432000000000000", 25, Down, "1000000000000", Equal);
@@ -833,6 +1089,14 @@ where
     test("33554432000000000000", 25, Floor, "1000000000000", Equal);
     test("33554432000000000000", 25, Ceiling, "1000000000000", Equal);
     test("33554432000000000000", 25, Nearest, "1000000000000", Equal);
+    test(
+        "33554432000000000000",
+        25,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("33554432000000000000", 25, ToOdd, "1000000000000", Equal);
     test("33554432000000000000", 25, Exact, "1000000000000", Equal);
 
     test("2147483648000000000000", 31, Down, "1000000000000", Equal);
@@ -852,6 +1116,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "2147483648000000000000",
+        31,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("2147483648000000000000", 31, ToOdd, "1000000000000", Equal);
     test("2147483648000000000000", 31, Exact, "1000000000000", Equal);
 
     test("4294967296000000000000", 32, Down, "1000000000000", Equal);
@@ -871,6 +1143,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "4294967296000000000000",
+        32,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("4294967296000000000000", 32, ToOdd, "1000000000000", Equal);
     test("4294967296000000000000", 32, Exact, "1000000000000", Equal);
 
     test("8589934592000000000000", 33, Down, "1000000000000", Equal);
@@ -890,6 +1170,14 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "8589934592000000000000",
+        33,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test("8589934592000000000000", 33, ToOdd, "1000000000000", Equal);
     test("8589934592000000000000", 33, Exact, "1000000000000", Equal);
 
     test(
@@ -927,6 +1215,20 @@ where
         "1000000000000",
         Equal,
     );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        NearestTiesAway,
+        "1000000000000",
+        Equal,
+    );
+    test(
+        "1267650600228229401496703205376000000000000",
+        100,
+        ToOdd,
+        "1000000000000",
+        Equal,
+    );
     test(
         "1267650600228229401496703205376000000000000",
         100,
@@ -940,6 +1242,8 @@ where
     test("1000000000000", 10, Floor, "976562500", Equal);
     test("1000000000000", 10, Ceiling, "976562500", Equal);
     test("1000000000000", 10, Nearest, "976562500", Equal);
+    test("1000000000000", 10, NearestTiesAway, "976562500", Equal);
+    test("1000000000000", 10, ToOdd, "976562500", Equal);
     test("1000000000000", 10, Exact, "976562500", Equal);
 
     test("980657949", 72, Down, "0", Less);
@@ -947,24 +1251,32 @@ where
     test("980657949", 72, Floor, "0", Less);
     test("980657949", 72, Ceiling, "1", Greater);
     test("980657949", 72, Nearest, "0", Less);
+    test("980657949", 72, NearestTiesAway, "0", Less);
+    test("980657949", 72, ToOdd, "1", Greater);
 
     test("4294967295", 31, Down, "1", Less);
     test("4294967295", 31, Up, "2", Greater);
     test("4294967295", 31, Floor, "1", Less);
     test("4294967295", 31, Ceiling, "2", Greater);
     test("4294967295", 31, Nearest, "2", Greater);
+    test("4294967295", 31, NearestTiesAway, "2", Greater);
+    test("4294967295", 31, ToOdd, "1", Less);
 
     test("4294967295", 32, Down, "0", Less);
     test("4294967295", 32, Up, "1", Greater);
     test("4294967295", 32, Floor, "0", Less);
     test("4294967295", 32, Ceiling, "1", Greater);
     test("4294967295", 32, Nearest, "1", Greater);
+    test("4294967295", 32, NearestTiesAway, "1", Greater);
+    test("4294967295", 32, ToOdd, "1", Greater);
 
     test("4294967296", 32, Down, "1", Equal);
     test("4294967296", 32, Up, "1", Equal);
     test("4294967296", 32, Floor, "1", Equal);
     test("4294967296", 32, Ceiling, "1", Equal);
     test("4294967296", 32, Nearest, "1", Equal);
+    test("4294967296", 32, NearestTiesAway, "1", Equal);
+    test("4294967296", 32, ToOdd, "1", Equal);
     test("4294967296", 32, Exact, "1", Equal);
 
     test("4294967296", 33, Down, "0", Less);
@@ -972,6 +1284,8 @@ where
     test("4294967296", 33, Floor, "0", Less);
     test("4294967296", 33, Ceiling, "1", Greater);
     test("4294967296", 33, Nearest, "0", Less);
+    test("4294967296", 33, NearestTiesAway, "1", Greater);
+    test("4294967296", 33, ToOdd, "1", Greater);
 
     test("0", -10, Exact, "0", Equal);
     test("123", -1, Exact, "246", Equal);
@@ -1066,6 +1380,34 @@ fn limbs_shr_round_nearest_properties() {
     });
 }
 
+#[test]
+fn limbs_shr_round_nearest_ties_away_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, bits)| {
+        let (s, o) = limbs_shr_round_nearest_ties_away(&xs, bits);
+        assert_eq!(
+            (Natural::from_owned_limbs_asc(s), o),
+            Natural::from_owned_limbs_asc(xs).shr_round(bits, NearestTiesAway),
+        );
+    });
+}
+
+#[test]
+fn limbs_shr_round_to_odd_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(&config, |(xs, bits)| {
+        let (s, o) = limbs_shr_round_to_odd(&xs, bits);
+        assert_eq!(
+            (Natural::from_owned_limbs_asc(s), o),
+            Natural::from_owned_limbs_asc(xs).shr_round(bits, ToOdd),
+        );
+    });
+}
+
 #[test]
 fn limbs_shr_exact_properties() {
     let mut config = GenConfig::new();
@@ -1148,6 +1490,40 @@ fn limbs_vec_shr_round_nearest_in_place_properties() {
     );
 }
 
+#[test]
+fn limbs_vec_shr_round_nearest_ties_away_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, bits)| {
+            let old_xs = xs.clone();
+            let o = limbs_vec_shr_round_nearest_ties_away_in_place(&mut xs, bits);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs).shr_round(bits, NearestTiesAway);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o, o_alt);
+        },
+    );
+}
+
+#[test]
+fn limbs_vec_shr_round_to_odd_in_place_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 32);
+    config.insert("mean_stripe_n", 16 << Limb::LOG_WIDTH);
+    unsigned_vec_unsigned_pair_gen_var_16().test_properties_with_config(
+        &config,
+        |(mut xs, bits)| {
+            let old_xs = xs.clone();
+            let o = limbs_vec_shr_round_to_odd_in_place(&mut xs, bits);
+            let (n, o_alt) = Natural::from_owned_limbs_asc(old_xs).shr_round(bits, ToOdd);
+            assert_eq!(Natural::from_owned_limbs_asc(xs), n);
+            assert_eq!(o, o_alt);
+        },
+    );
+}
+
 #[test]
 fn limbs_vec_shr_exact_in_place_properties() {
     let mut config = GenConfig::new();
@@ -1239,6 +1615,8 @@ where
         assert_eq!((&left_shifted).shr_round(u, Floor), no);
         assert_eq!((&left_shifted).shr_round(u, Ceiling), no);
         assert_eq!((&left_shifted).shr_round(u, Nearest), no);
+        assert_eq!((&left_shifted).shr_round(u, NearestTiesAway), no);
+        assert_eq!((&left_shifted).shr_round(u, ToOdd), no);
         assert_eq!((&left_shifted).shr_round(u, Exact), no);
     });
 
@@ -1248,6 +1626,10 @@ where
         assert_eq!((&n).shr_round(u, Up), up);
         assert_eq!((&n).shr_round(u, Floor), down);
         assert_eq!((&n).shr_round(u, Ceiling), up);
+        let nearest_ties_away = (&n).shr_round(u, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&n).shr_round(u, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = n.shr_round(u, Nearest);
         assert!(nearest == down || nearest == up);
     });
@@ -0,0 +1,73 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::Natural;
+use num::BigUint;
+
+#[test]
+fn test_from_bytes_le() {
+    let test = |xs: &[u8], out| {
+        let x = Natural::from_bytes_le(xs);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        let mut xs_be = xs.to_vec();
+        xs_be.reverse();
+        let x = Natural::from_bytes_be(&xs_be);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[123, 0, 0], "123");
+    test(&[0x34, 0x12], "4660");
+    test(&[0x00, 0x10, 0xa5, 0xd4, 0xe8], "1000000000000");
+    test(&[255, 255, 255, 255], "4294967295");
+    test(&[0, 0, 0, 0, 1], "4294967296");
+    test(&[255; 8], "18446744073709551615");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 1], "18446744073709551616");
+    test(
+        &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0],
+        "1701411834921604967429270619762735448065",
+    );
+}
+
+#[test]
+fn from_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            Natural::from_power_of_2_digits_asc(8, xs.iter().copied()).unwrap(),
+            x
+        );
+        assert_eq!(Natural::from(&BigUint::from_bytes_le(&xs)), x);
+        let mut trimmed = xs;
+        while trimmed.last() == Some(&0) {
+            trimmed.pop();
+        }
+        assert_eq!(x.to_bytes_le(), trimmed);
+    });
+}
+
+#[test]
+fn from_bytes_be_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_be(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            Natural::from_power_of_2_digits_desc(8, xs.iter().copied()).unwrap(),
+            x
+        );
+        assert_eq!(Natural::from(&BigUint::from_bytes_be(&xs)), x);
+        assert_eq!(x.to_bytes_be_padded(xs.len()), xs);
+    });
+}
@@ -59,10 +59,16 @@ fn test_rounding_from_f32() {
 
     test(123.5, Nearest, "124", Greater);
     test(124.5, Nearest, "124", Less);
+    test(123.5, NearestTiesAway, "124", Greater);
+    test(124.5, NearestTiesAway, "125", Greater);
+    test(123.1, ToOdd, "123", Less);
+    test(123.9, ToOdd, "123", Less);
+    test(124.5, ToOdd, "125", Greater);
     test(-0.99, Ceiling, "0", Greater);
     test(-0.99, Down, "0", Greater);
     test(-0.499, Nearest, "0", Greater);
     test(-0.5, Nearest, "0", Greater);
+    test(-0.5, NearestTiesAway, "0", Greater);
 
     test(f32::NEGATIVE_INFINITY, Nearest, "0", Greater);
     test(f32::NEGATIVE_INFINITY, Down, "0", Greater);
@@ -164,10 +170,16 @@ fn test_rounding_from_f64() {
 
     test(123.5, Nearest, "124", Greater);
     test(124.5, Nearest, "124", Less);
+    test(123.5, NearestTiesAway, "124", Greater);
+    test(124.5, NearestTiesAway, "125", Greater);
+    test(123.1, ToOdd, "123", Less);
+    test(123.9, ToOdd, "123", Less);
+    test(124.5, ToOdd, "125", Greater);
     test(-0.99, Ceiling, "0", Greater);
     test(-0.99, Down, "0", Greater);
     test(-0.499, Nearest, "0", Greater);
     test(-0.5, Nearest, "0", Greater);
+    test(-0.5, NearestTiesAway, "0", Greater);
 
     test(f64::NEGATIVE_INFINITY, Nearest, "0", Greater);
     test(f64::NEGATIVE_INFINITY, Down, "0", Greater);
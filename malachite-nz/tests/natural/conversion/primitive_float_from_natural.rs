@@ -56,6 +56,10 @@ fn test_f32_rounding_from_natural() {
 
     test("33554434", Nearest, 3.3554432e7, Less);
     test("33554435", Nearest, 3.3554436e7, Greater);
+    test("33554433", NearestTiesAway, 3.3554432e7, Less);
+    test("33554434", NearestTiesAway, 3.3554436e7, Greater);
+    test("33554433", ToOdd, 3.3554436e7, Greater);
+    test("33554434", ToOdd, 3.3554436e7, Greater);
 
     test(
         "340282346638528859811704183484516925439",
@@ -227,6 +231,13 @@ fn test_f64_rounding_from_natural() {
     test("18014398509481985", Nearest, 1.8014398509481984e16, Less);
     test("18014398509481986", Nearest, 1.8014398509481984e16, Less);
     test("18014398509481987", Nearest, 1.8014398509481988e16, Greater);
+    test(
+        "18014398509481986",
+        NearestTiesAway,
+        1.8014398509481988e16,
+        Greater,
+    );
+    test("18014398509481985", ToOdd, 1.8014398509481988e16, Greater);
     test(
         "179769313486231570814527423731704356798070567525844996598917476803157260780028538760589558\
         6327668781715404589535143824642343213268894641827684675467035375169860499105765512820762454\
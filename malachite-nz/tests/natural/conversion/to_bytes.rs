@@ -0,0 +1,131 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::{ExactFrom, PowerOf2Digits};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_4};
+use num::BigUint;
+use std::str::FromStr;
+
+#[test]
+fn test_to_bytes_le() {
+    let test = |n, out: &[u8]| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.to_bytes_le(), out);
+        let mut out_be = out.to_vec();
+        out_be.reverse();
+        assert_eq!(n.to_bytes_be(), out_be);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("255", &[255]);
+    test("256", &[0, 1]);
+    test("4660", &[0x34, 0x12]);
+    test("1000000000000", &[0x00, 0x10, 0xa5, 0xd4, 0xe8]);
+    test("4294967295", &[255, 255, 255, 255]);
+    test("4294967296", &[0, 0, 0, 0, 1]);
+    test("18446744073709551615", &[255; 8]);
+    test("18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "1701411834921604967429270619762735448065",
+        &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5],
+    );
+}
+
+#[test]
+fn test_to_bytes_be_padded() {
+    let test = |n, len, out: &[u8]| {
+        assert_eq!(Natural::from_str(n).unwrap().to_bytes_be_padded(len), out);
+    };
+    test("0", 0, &[]);
+    test("0", 3, &[0, 0, 0]);
+    test("123", 1, &[123]);
+    test("123", 4, &[0, 0, 0, 123]);
+    test("4660", 2, &[0x12, 0x34]);
+    test("4660", 5, &[0, 0, 0, 0x12, 0x34]);
+    test("18446744073709551616", 9, &[1, 0, 0, 0, 0, 0, 0, 0, 0]);
+    test("18446744073709551616", 10, &[0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn to_bytes_be_padded_fail_1() {
+    Natural::from(123u32).to_bytes_be_padded(0);
+}
+
+#[test]
+#[should_panic]
+fn to_bytes_be_padded_fail_2() {
+    Natural::from(0x1234u32).to_bytes_be_padded(1);
+}
+
+#[test]
+fn to_bytes_le_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_bytes_le();
+        assert_eq!(bytes, PowerOf2Digits::<u8>::to_power_of_2_digits_asc(&x, 8));
+        assert_eq!(Natural::from_bytes_le(&bytes), x);
+        assert_eq!(
+            bytes.len(),
+            usize::exact_from(x.significant_bits().div_ceil(8))
+        );
+        if x != 0 {
+            assert_ne!(*bytes.last().unwrap(), 0);
+            assert_eq!(BigUint::from(&x).to_bytes_le(), bytes);
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        let mut bytes = u.to_le_bytes().to_vec();
+        while bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        assert_eq!(Natural::from(u).to_bytes_le(), bytes);
+    });
+}
+
+#[test]
+fn to_bytes_be_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_bytes_be();
+        assert_eq!(
+            bytes,
+            PowerOf2Digits::<u8>::to_power_of_2_digits_desc(&x, 8)
+        );
+        assert_eq!(Natural::from_bytes_be(&bytes), x);
+        assert_eq!(x.to_bytes_be_padded(bytes.len()), bytes);
+        if x != 0 {
+            assert_ne!(bytes[0], 0);
+            assert_eq!(BigUint::from(&x).to_bytes_be(), bytes);
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        let bytes = Natural::from(u).to_bytes_be_padded(8);
+        assert_eq!(bytes, u.to_be_bytes());
+    });
+}
+
+#[test]
+fn to_bytes_be_padded_properties() {
+    natural_unsigned_pair_gen_var_4::<u8>().test_properties(|(x, extra)| {
+        let len = x.to_bytes_be().len() + usize::from(extra);
+        let bytes = x.to_bytes_be_padded(len);
+        assert_eq!(bytes.len(), len);
+        assert_eq!(Natural::from_bytes_be(&bytes), x);
+        assert!(bytes[..usize::from(extra)].iter().all(|&b| b == 0));
+    });
+
+    unsigned_gen::<u8>().test_properties(|len| {
+        let len = usize::from(len);
+        assert_eq!(Natural::ZERO.to_bytes_be_padded(len), vec![0; len]);
+    });
+}
@@ -0,0 +1,80 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::factorization::traits::{Divisors, Factor};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen_var_4};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_divisors() {
+    let test = |n: &str, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.divisors().to_debug_string(), out);
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test("12", "[1, 2, 3, 4, 6, 12]");
+    test("97", "[1, 97]");
+    test(
+        "18446744073709551617",
+        "[1, 274177, 67280421310721, 18446744073709551617]",
+    );
+    test(
+        "5316911983139663487003542222693990401",
+        "[1, 2305843009213693951, 5316911983139663487003542222693990401]",
+    );
+    assert_eq!(
+        Natural::from_str("1000000000000000000000000000000")
+            .unwrap()
+            .divisors()
+            .len(),
+        961
+    );
+}
+
+#[test]
+#[should_panic]
+fn divisors_fail() {
+    Natural::from(0u32).divisors();
+}
+
+#[test]
+fn divisors_properties() {
+    unsigned_vec_gen_var_4::<u32>().test_properties(|xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        // Products of many small numbers can have too many divisors to list.
+        let divisor_count = n
+            .factor()
+            .iter()
+            .try_fold(1u64, |count, (_, e)| count.checked_mul(e + 1))
+            .filter(|&count| count <= 1 << 16);
+        let Some(divisor_count) = divisor_count else {
+            return;
+        };
+        let divisors = n.divisors();
+        assert!(divisors.iter().tuple_windows().all(|(x, y)| x < y));
+        assert!(divisors.iter().all(|d| (&n).divisible_by(d)));
+        assert_eq!(divisors[0], 1u32);
+        assert_eq!(*divisors.last().unwrap(), n);
+        assert_eq!(u64::try_from(divisors.len()).unwrap(), divisor_count);
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).divisors(),
+            n.divisors().into_iter().map(Natural::from).collect_vec()
+        );
+    });
+}
@@ -0,0 +1,64 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, PowerOf2};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{EulerTotient, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_euler_totient() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.euler_totient().to_string(), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("12", "4");
+    test("97", "96");
+    test("360", "96");
+    test("18446744073709551615", "9208981628670443520");
+    test("18446744073709551617", "18446676793287966720");
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+    );
+    test(
+        "5316911983139663487003542222693990401",
+        "5316911983139663484697699213480296450",
+    );
+
+    let p = Natural::power_of_2(127) - Natural::ONE;
+    assert_eq!(p.euler_totient(), Natural::power_of_2(127) - Natural::TWO);
+}
+
+#[test]
+#[should_panic]
+fn euler_totient_fail() {
+    Natural::from(0u32).euler_totient();
+}
+
+#[test]
+fn euler_totient_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let totient = Natural::from(n).euler_totient();
+        assert_eq!(totient, n.euler_totient());
+        assert_eq!(totient == n - 1, n.is_prime());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.gcd(y) == 1 {
+            assert_eq!(
+                (Natural::from(x) * Natural::from(y)).euler_totient(),
+                Natural::from(x.euler_totient()) * Natural::from(y.euler_totient())
+            );
+        }
+    });
+}
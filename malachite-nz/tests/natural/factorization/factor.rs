@@ -0,0 +1,170 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen_var_4};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+use std::str::FromStr;
+
+fn product_of_factors(factors: &[(Natural, u64)]) -> Natural {
+    factors.iter().map(|(p, e)| p.pow(*e)).product()
+}
+
+#[test]
+fn test_factor() {
+    let test = |n: &str, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.factor().to_debug_string(), out);
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("12", "[(2, 2), (3, 1)]");
+    test("97", "[(97, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    test(
+        "147573952589676412927",
+        "[(193707721, 1), (761838257287, 1)]",
+    );
+    test("1000000000000000000000000000000", "[(2, 30), (5, 30)]");
+    test(
+        "170141183460469231731687303715884105727",
+        "[(170141183460469231731687303715884105727, 1)]",
+    );
+    // the square of a prime
+    test(
+        "5316911983139663487003542222693990401",
+        "[(2305843009213693951, 2)]",
+    );
+    // the product of two primes near 2^64
+    test(
+        "340282366920938462614824380041128836353",
+        "[(18446744073709551557, 1), (18446744073709551629, 1)]",
+    );
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+
+    let n = Natural::power_of_2(256) - Natural::ONE;
+    let factors = n.factor();
+    assert_eq!(factors.len(), 11);
+    assert_eq!(product_of_factors(&factors), n);
+}
+
+#[test]
+fn test_factor_with_effort() {
+    let test = |n: &str, effort, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.factor_with_effort(effort).to_debug_string(), out);
+    };
+    test("1", 0, "([], [])");
+    test("360", 0, "([(2, 3), (3, 2), (5, 1)], [])");
+    test(
+        "18446744073709551617",
+        0,
+        "([], [(18446744073709551617, 1)])",
+    );
+    test(
+        "18446744073709551617",
+        100000,
+        "([(274177, 1), (67280421310721, 1)], [])",
+    );
+    test(
+        "18446744073709551617000",
+        0,
+        "([(2, 3), (5, 3)], [(18446744073709551617, 1)])",
+    );
+    // perfect powers are detected even with no effort
+    test(
+        "5316911983139663487003542222693990401",
+        0,
+        "([(2305843009213693951, 2)], [])",
+    );
+    test(
+        "340282366920938463463374607431768211457",
+        0,
+        "([], [(340282366920938463463374607431768211457, 1)])",
+    );
+    test(
+        "340282366920938463463374607431768211457",
+        u64::MAX,
+        "([(59649589127497217, 1), (5704689200685129054721, 1)], [])",
+    );
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    Natural::from(0u32).factor();
+}
+
+#[test]
+#[should_panic]
+fn factor_with_effort_fail() {
+    Natural::from(0u32).factor_with_effort(0);
+}
+
+#[test]
+fn factor_properties() {
+    unsigned_vec_gen_var_4::<u32>().test_properties(|xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        assert!(factors.iter().all(|(p, e)| p.is_prime() && *e != 0));
+        assert!(factors.iter().tuple_windows().all(|(x, y)| x.0 < y.0));
+        assert_eq!(product_of_factors(&factors), n);
+        for x in xs {
+            for (p, _) in x.factor() {
+                let p = Natural::from(p);
+                assert!(factors.iter().any(|(q, _)| *q == p));
+            }
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).factor(),
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e))
+                .collect_vec()
+        );
+    });
+}
+
+#[test]
+fn factor_with_effort_properties() {
+    natural_gen_var_2().test_properties(|n| {
+        let (primes, composites) = n.factor_with_effort(1000);
+        assert!(primes.iter().all(|(p, e)| p.is_prime() && *e != 0));
+        assert!(composites
+            .iter()
+            .all(|(c, e)| !c.is_prime() && *c > 1u32 && *e != 0));
+        assert!(primes.iter().tuple_windows().all(|(x, y)| x.0 < y.0));
+        assert!(composites.iter().tuple_windows().all(|(x, y)| x.0 < y.0));
+        assert_eq!(
+            product_of_factors(&primes) * product_of_factors(&composites),
+            n
+        );
+        if composites.is_empty() {
+            assert_eq!(n.factor(), primes);
+        }
+    });
+}
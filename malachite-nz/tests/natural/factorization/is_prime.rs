@@ -0,0 +1,114 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsPrime, Primality, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |n: &str, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(
+            rug::Integer::from(&n).is_probably_prime(30) != rug::integer::IsPrime::No,
+            out
+        );
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("91", false);
+    test("97", true);
+    test("3215031751", false);
+    test("3825123056546413051", false);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    test("18446744073709551616", false);
+    test("18446744073709551617", false);
+    test("18446744073709551629", true);
+    // strong pseudoprimes to the first eleven and twelve prime bases
+    test("318665857834031151167461", false);
+    test("3317044064679887385961981", false);
+    // the square of a prime
+    test("5316911983139663487003542222693990401", false);
+    test("170141183460469231731687303715884105727", true);
+    test("340282366920938463463374607431768211297", true);
+    // the product of two primes near 2^64
+    test("340282366920938462614824380041128836353", false);
+
+    let test_mersenne = |p: u64, out| {
+        let n = Natural::power_of_2(p) - Natural::ONE;
+        assert_eq!(n.is_prime(), out);
+    };
+    test_mersenne(89, true);
+    test_mersenne(127, true);
+    test_mersenne(521, true);
+    test_mersenne(523, false);
+    test_mersenne(607, true);
+    test_mersenne(1279, true);
+    test_mersenne(1281, false);
+}
+
+#[test]
+fn test_is_probable_prime() {
+    let test = |n: &str, rounds, out| {
+        assert_eq!(Natural::from_str(n).unwrap().is_probable_prime(rounds), out);
+    };
+    test("0", 0, Primality::NotPrime);
+    test("1", 10, Primality::NotPrime);
+    test("97", 0, Primality::Prime);
+    test("97", 10, Primality::Prime);
+    test("18446744073709551557", 10, Primality::Prime);
+    test("18446744073709551629", 0, Primality::ProbablyPrime);
+    test("18446744073709551629", 10, Primality::ProbablyPrime);
+    test("318665857834031151167461", 0, Primality::NotPrime);
+    test("318665857834031151167461", 20, Primality::NotPrime);
+    test(
+        "170141183460469231731687303715884105727",
+        100,
+        Primality::ProbablyPrime,
+    );
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_probable_prime(0) != Primality::NotPrime, is_prime);
+        assert_eq!(n.is_probable_prime(5) != Primality::NotPrime, is_prime);
+        assert_eq!(
+            rug::Integer::from(&n).is_probably_prime(30) != rug::integer::IsPrime::No,
+            is_prime
+        );
+        if let Ok(u) = u128::try_from(&n) {
+            assert_eq!(u.is_prime(), is_prime);
+            assert_eq!(u.is_probable_prime(5), n.is_probable_prime(5));
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        if x > 1u32 && y > 1u32 {
+            assert!(!(x * y).is_prime());
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from(u).is_prime(), u.is_prime());
+    });
+
+    for p in Natural::primes().take(1000) {
+        assert!(p.is_prime());
+    }
+}
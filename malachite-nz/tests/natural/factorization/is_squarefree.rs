@@ -0,0 +1,48 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{PowerOf2, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::IsSquarefree;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_1};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_is_squarefree() {
+    let test = |n: &str, out| {
+        assert_eq!(Natural::from_str(n).unwrap().is_squarefree(), out);
+    };
+    test("0", false);
+    test("1", true);
+    test("2", true);
+    test("4", false);
+    test("30", true);
+    test("360", false);
+    test("18446744073709551615", true);
+    test("18446744073709551617", true);
+    test("1000000000000000000000000000000", false);
+    test("5316911983139663487003542222693990401", false);
+    test("340282366920938463463374607431768211457", true);
+
+    let n = Natural::power_of_2(127) - Natural::ONE;
+    assert!(n.is_squarefree());
+    assert!(!n.square().is_squarefree());
+}
+
+#[test]
+fn is_squarefree_properties() {
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).is_squarefree(), n.is_squarefree());
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n = Natural::from(n);
+        assert_eq!((&n).square().is_squarefree(), n == 1u32);
+    });
+}
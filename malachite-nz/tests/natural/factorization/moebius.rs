@@ -0,0 +1,60 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, PowerOf2, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsSquarefree, Moebius};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius() {
+    let test = |n: &str, out| {
+        assert_eq!(Natural::from_str(n).unwrap().moebius(), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("4", 0);
+    test("6", 1);
+    test("30", -1);
+    test("360", 0);
+    test("18446744073709551615", -1);
+    test("18446744073709551617", 1);
+    test("1000000000000000000000000000000", 0);
+    test("5316911983139663487003542222693990401", 0);
+    test("340282366920938463463374607431768211457", 1);
+
+    let n = Natural::power_of_2(127) - Natural::ONE;
+    assert_eq!(n.moebius(), -1);
+    assert_eq!(n.square().moebius(), 0);
+}
+
+#[test]
+#[should_panic]
+fn moebius_fail() {
+    Natural::from(0u32).moebius();
+}
+
+#[test]
+fn moebius_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let moebius = Natural::from(n).moebius();
+        assert_eq!(moebius, n.moebius());
+        assert_eq!(moebius != 0, Natural::from(n).is_squarefree());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.gcd(y) == 1 {
+            assert_eq!(
+                (Natural::from(x) * Natural::from(y)).moebius(),
+                x.moebius() * y.moebius()
+            );
+        }
+    });
+}
@@ -8,12 +8,15 @@
 
 use itertools::Itertools;
 use malachite_base::iterators::comparison::is_strictly_ascending;
-use malachite_base::num::arithmetic::traits::SaturatingSub;
-use malachite_base::num::basic::traits::One;
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::arithmetic::traits::{PowerOf2, SaturatingSub};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
 use malachite_base::strings::ToDebugString;
 use malachite_nz::natural::Natural;
-use malachite_nz::test_util::generators::natural_gen_var_9;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_gen_var_10, natural_gen_var_9, natural_pair_gen_var_8,
+};
 use std::str::FromStr;
 
 #[test]
@@ -188,3 +191,169 @@ fn primes_less_than_or_equal_to_properties() {
         assert_eq!(Natural::primes().take_while(|p| *p <= n).collect_vec(), ps);
     });
 }
+
+#[test]
+fn test_primes_in_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "0", "[]");
+    test("0", "1", "[]");
+    test("0", "2", "[]");
+    test("0", "3", "[2]");
+    test("2", "3", "[2]");
+    test("3", "2", "[]");
+    test("0", "10", "[2, 3, 5, 7]");
+    test("5", "7", "[5]");
+    test("90", "130", "[97, 101, 103, 107, 109, 113, 127]");
+    test("114", "127", "[]");
+    test(
+        "18446744073709551616",
+        "18446744073709551716",
+        "[18446744073709551629, 18446744073709551653, 18446744073709551667, \
+        18446744073709551697, 18446744073709551709]",
+    );
+}
+
+#[test]
+fn test_primes_greater_than_or_equal_to() {
+    let test = |a: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let ps = Natural::primes_greater_than_or_equal_to(&a)
+            .take(5)
+            .collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "[2, 3, 5, 7, 11]");
+    test("2", "[2, 3, 5, 7, 11]");
+    test("100", "[101, 103, 107, 109, 113]");
+    test(
+        "18446744073709551616",
+        "[18446744073709551629, 18446744073709551653, 18446744073709551667, \
+        18446744073709551697, 18446744073709551709]",
+    );
+}
+
+#[test]
+fn test_next_prime() {
+    let test = |n: &str, out: &str| {
+        let p = Natural::from_str(n).unwrap().next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("3", "5");
+    test("100", "101");
+    test("113", "127");
+    test("4294967296", "4294967311");
+    test("4294836225", "4294836241");
+    test("18446744073709551616", "18446744073709551629");
+    test("100000000000000000000", "100000000000000000039");
+}
+
+#[test]
+fn test_next_prime_large() {
+    let n = Natural::power_of_2(512);
+    assert_eq!(n.next_prime(), n + Natural::from(75u32));
+}
+
+#[test]
+fn test_prev_prime() {
+    let test = |n: &str, out: &str| {
+        let p = Natural::from_str(n).unwrap().prev_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("3", "2");
+    test("4", "3");
+    test("100", "97");
+    test("127", "113");
+    test("4294967296", "4294967291");
+    test("18446744073709551616", "18446744073709551557");
+    test("100000000000000000000", "99999999999999999989");
+}
+
+#[test]
+fn test_prev_prime_large() {
+    let n = Natural::power_of_2(512);
+    assert_eq!(n.prev_prime(), n - Natural::from(569u32));
+}
+
+#[test]
+#[should_panic]
+fn prev_prime_fail_1() {
+    Natural::TWO.prev_prime();
+}
+
+#[test]
+#[should_panic]
+fn prev_prime_fail_2() {
+    Natural::ZERO.prev_prime();
+}
+
+#[test]
+fn primes_in_range_properties() {
+    natural_pair_gen_var_8().test_properties(|(a, b)| {
+        let ps = Natural::primes_in_range(&a, &b).take(10).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(ps.iter()));
+        assert!(ps.iter().all(|p| *p >= a && *p < b && p.is_prime()));
+        assert_eq!(
+            Natural::primes_greater_than_or_equal_to(&a)
+                .take_while(|p| *p < b)
+                .take(10)
+                .collect_vec(),
+            ps
+        );
+        if let Some(p) = ps.first() {
+            assert_eq!(*p, (&a).saturating_sub(Natural::ONE).next_prime());
+        }
+    });
+
+    natural_gen_var_9().test_properties(|n| {
+        assert_eq!(
+            Natural::primes_in_range(&Natural::ZERO, &n).collect_vec(),
+            Natural::primes_less_than(&n).collect_vec()
+        );
+    });
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert_eq!(
+            Natural::primes_in_range(&(&n + Natural::ONE), &p).next(),
+            None
+        );
+        assert_eq!(Natural::exact_from(&rug::Integer::from(&n).next_prime()), p);
+        if p > 2u32 {
+            assert!(p.prev_prime() <= n);
+        }
+    });
+}
+
+#[test]
+fn prev_prime_properties() {
+    natural_gen_var_10().test_properties(|n| {
+        let p = n.prev_prime();
+        assert!(p.is_valid());
+        assert!(p < n);
+        assert!(p.is_prime());
+        assert_eq!(
+            Natural::primes_in_range(&(&p + Natural::ONE), &n).next(),
+            None
+        );
+        assert!(p.next_prime() >= n);
+    });
+}
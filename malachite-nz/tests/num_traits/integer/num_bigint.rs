@@ -0,0 +1,57 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_gen;
+use num::BigInt;
+use std::str::FromStr;
+
+#[test]
+fn test_from_big_int() {
+    let test = |s| {
+        let x = BigInt::from_str(s).unwrap();
+        let y = Integer::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+        let y = Integer::from(x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("4294967296");
+    test("-18446744073709551616");
+    test("-1000000000000000000000000");
+}
+
+#[test]
+fn test_from_integer() {
+    let test = |s| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(BigInt::from(&x).to_string(), s);
+        assert_eq!(BigInt::from(x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("4294967296");
+    test("-18446744073709551616");
+    test("-1000000000000000000000000");
+}
+
+#[test]
+fn num_bigint_properties() {
+    integer_gen().test_properties(|x| {
+        let y = BigInt::from(&x);
+        assert_eq!(BigInt::from(x.clone()), y);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(Integer::from(&y), x);
+        assert_eq!(Integer::from(y), x);
+    });
+}
@@ -0,0 +1,188 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::NegativeInfinity;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::{primitive_float_gen, signed_gen};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen};
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use num::BigInt;
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Integer as Zero>::zero(), 0);
+    assert_eq!(<Integer as One>::one(), 1);
+    assert!(Zero::is_zero(&Integer::from(0)));
+    assert!(!Zero::is_zero(&Integer::from(-1)));
+    assert!(One::is_one(&Integer::from(1)));
+    assert!(!One::is_one(&Integer::from(-1)));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Integer::from_str_radix(s, radix)
+                .ok()
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test("0", 10, Some("0"));
+    test("-0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("-123", 10, Some("-123"));
+    test("-ff", 16, Some("-255"));
+    test("1010", 2, Some("10"));
+    test("", 10, None);
+    test("-", 10, None);
+    test("--1", 10, None);
+    test("12", 2, None);
+}
+
+#[test]
+#[should_panic]
+fn from_str_radix_fail() {
+    Integer::from_str_radix("1", 37).unwrap();
+}
+
+#[test]
+fn test_signed() {
+    let test = |s, abs, signum, positive, negative| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(Signed::abs(&x).to_string(), abs);
+        assert_eq!(Signed::signum(&x).to_string(), signum);
+        assert_eq!(x.is_positive(), positive);
+        assert_eq!(x.is_negative(), negative);
+    };
+    test("0", "0", "0", false, false);
+    test("123", "123", "1", true, false);
+    test("-123", "123", "-1", false, true);
+
+    let test_abs_sub = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = Integer::from_str(t).unwrap();
+        assert_eq!(x.abs_sub(&y).to_string(), out);
+    };
+    test_abs_sub("5", "-3", "8");
+    test_abs_sub("-3", "5", "0");
+    test_abs_sub("5", "5", "0");
+}
+
+#[test]
+fn test_pow() {
+    let x = Integer::from(-3);
+    assert_eq!(Pow::pow(&x, 3u8), -27);
+    assert_eq!(Pow::pow(&x, 0usize), 1);
+    assert_eq!(Pow::pow(x, 41u64).to_string(), "-36472996377170786403");
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, f: f64| {
+        let x = Integer::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(NiceFloat(x.to_f64().unwrap()), NiceFloat(f));
+    };
+    test("0", Some(0), Some(0), 0.0);
+    test("123", Some(123), Some(123), 123.0);
+    test("-123", Some(-123), None, -123.0);
+    test(
+        "-9223372036854775808",
+        Some(i64::MIN),
+        None,
+        -9.223372036854776e18,
+    );
+    test(
+        "9223372036854775808",
+        None,
+        Some(9223372036854775808),
+        9.223372036854776e18,
+    );
+
+    let x = Integer::from_str("-9223372036854775809").unwrap();
+    assert_eq!(x.to_i128(), Some(-9223372036854775809));
+    assert_eq!(x.to_u128(), None);
+    let x = Integer::from_str("-170141183460469231731687303715884105729").unwrap();
+    assert_eq!(x.to_i128(), None);
+
+    // The smallest value that rounds to infinity is halfway between `f64::MAX_FINITE` and 2^1024
+    let halfway = Integer::power_of_2(1024) - Integer::power_of_2(970);
+    assert_eq!(
+        NiceFloat((&halfway - Integer::from(1)).to_f64().unwrap()),
+        NiceFloat(f64::MAX_FINITE)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f64().unwrap()),
+        NiceFloat(f64::INFINITY)
+    );
+    assert_eq!(
+        NiceFloat((-&halfway).to_f64().unwrap()),
+        NiceFloat(f64::NEGATIVE_INFINITY)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f32().unwrap()),
+        NiceFloat(f32::INFINITY)
+    );
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Integer::from_i64(-5).unwrap(), -5);
+    assert_eq!(Integer::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Integer::from_f64(2.9).unwrap(), 2);
+    assert_eq!(Integer::from_f64(-2.9).unwrap(), -2);
+    assert_eq!(Integer::from_f64(f64::NAN), None);
+    assert_eq!(Integer::from_f32(f32::NEGATIVE_INFINITY), None);
+}
+
+#[test]
+fn num_traits_properties() {
+    integer_gen().test_properties(|x| {
+        let num_x = BigInt::from(&x);
+        assert_eq!(x.to_i64(), num_x.to_i64());
+        assert_eq!(x.to_i128(), num_x.to_i128());
+        assert_eq!(x.to_u64(), num_x.to_u64());
+        assert_eq!(
+            NiceFloat(x.to_f64().unwrap()),
+            NiceFloat(num_x.to_f64().unwrap())
+        );
+        assert_eq!(
+            NiceFloat(x.to_f32().unwrap()),
+            NiceFloat(num_x.to_f32().unwrap())
+        );
+        assert_eq!(Signed::abs(&x), Integer::from(Signed::abs(&num_x)));
+        assert_eq!(Signed::signum(&x), Integer::from(Signed::signum(&num_x)));
+        assert_eq!(
+            Integer::from_str_radix(&num_x.to_str_radix(16), 16).unwrap(),
+            x
+        );
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(CheckedMul::checked_mul(&x, &y), Some(&x * &y));
+        assert_eq!(
+            x.abs_sub(&y),
+            Integer::from(BigInt::from(&x).abs_sub(&BigInt::from(&y)))
+        );
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        assert_eq!(Integer::from_f64(f), BigInt::from_f64(f).map(Integer::from));
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        assert_eq!(Integer::from_i64(i).unwrap().to_i64(), Some(i));
+    });
+}
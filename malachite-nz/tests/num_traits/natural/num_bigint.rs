@@ -0,0 +1,55 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use num::BigUint;
+use std::str::FromStr;
+
+#[test]
+fn test_from_big_uint() {
+    let test = |s| {
+        let x = BigUint::from_str(s).unwrap();
+        let y = Natural::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+        let y = Natural::from(x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("4294967296");
+    test("18446744073709551616");
+    test("1000000000000000000000000");
+}
+
+#[test]
+fn test_from_natural() {
+    let test = |s| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(BigUint::from(&x).to_string(), s);
+        assert_eq!(BigUint::from(x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("4294967296");
+    test("18446744073709551616");
+    test("1000000000000000000000000");
+}
+
+#[test]
+fn num_bigint_properties() {
+    natural_gen().test_properties(|x| {
+        let y = BigUint::from(&x);
+        assert_eq!(BigUint::from(x.clone()), y);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(Natural::from(&y), x);
+        assert_eq!(Natural::from(y), x);
+    });
+}
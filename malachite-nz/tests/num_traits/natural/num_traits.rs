@@ -0,0 +1,151 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::{primitive_float_gen, unsigned_gen};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, ToPrimitive, Zero};
+use num::BigUint;
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Natural as Zero>::zero(), 0);
+    assert_eq!(<Natural as One>::one(), 1);
+    assert!(Zero::is_zero(&Natural::from(0u32)));
+    assert!(!Zero::is_zero(&Natural::from(1u32)));
+    assert!(One::is_one(&Natural::from(1u32)));
+    assert!(!One::is_one(&Natural::from(2u32)));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Natural::from_str_radix(s, radix)
+                .ok()
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test("0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("ff", 16, Some("255"));
+    test("1010", 2, Some("10"));
+    test("", 10, None);
+    test("-1", 10, None);
+    test("12", 2, None);
+}
+
+#[test]
+#[should_panic]
+fn from_str_radix_fail() {
+    Natural::from_str_radix("1", 37).unwrap();
+}
+
+#[test]
+fn test_pow() {
+    let x = Natural::from(3u32);
+    assert_eq!(Pow::pow(&x, 4u8), 81);
+    assert_eq!(Pow::pow(&x, 0usize), 1);
+    assert_eq!(Pow::pow(x, 40u64).to_string(), "12157665459056928801");
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, f: f64| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(NiceFloat(x.to_f64().unwrap()), NiceFloat(f));
+    };
+    test("0", Some(0), Some(0), 0.0);
+    test("123", Some(123), Some(123), 123.0);
+    test(
+        "9223372036854775808",
+        None,
+        Some(9223372036854775808),
+        9.223372036854776e18,
+    );
+    test("18446744073709551616", None, None, 1.8446744073709552e19);
+
+    let x = Natural::from_str("9223372036854775808").unwrap();
+    assert_eq!(x.to_i128(), Some(9223372036854775808));
+    assert_eq!(x.to_u128(), Some(9223372036854775808));
+    let x = Natural::from_str("340282366920938463463374607431768211456").unwrap();
+    assert_eq!(x.to_i128(), None);
+    assert_eq!(x.to_u128(), None);
+
+    // The smallest value that rounds to infinity is halfway between `f64::MAX_FINITE` and 2^1024
+    let halfway = Natural::power_of_2(1024) - Natural::power_of_2(970);
+    assert_eq!(
+        NiceFloat((&halfway - Natural::from(1u32)).to_f64().unwrap()),
+        NiceFloat(f64::MAX_FINITE)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f64().unwrap()),
+        NiceFloat(f64::INFINITY)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f32().unwrap()),
+        NiceFloat(f32::INFINITY)
+    );
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Natural::from_i64(5).unwrap(), 5);
+    assert_eq!(Natural::from_i64(-5), None);
+    assert_eq!(Natural::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Natural::from_f64(2.9).unwrap(), 2);
+    assert_eq!(Natural::from_f64(-0.9).unwrap(), 0);
+    assert_eq!(Natural::from_f64(-1.0), None);
+    assert_eq!(Natural::from_f64(f64::NAN), None);
+    assert_eq!(Natural::from_f32(f32::INFINITY), None);
+}
+
+#[test]
+fn num_traits_properties() {
+    natural_gen().test_properties(|x| {
+        let num_x = BigUint::from(&x);
+        assert_eq!(x.to_i64(), num_x.to_i64());
+        assert_eq!(x.to_u64(), num_x.to_u64());
+        assert_eq!(x.to_u128(), num_x.to_u128());
+        assert_eq!(
+            NiceFloat(x.to_f64().unwrap()),
+            NiceFloat(num_x.to_f64().unwrap())
+        );
+        assert_eq!(
+            NiceFloat(x.to_f32().unwrap()),
+            NiceFloat(num_x.to_f32().unwrap())
+        );
+        assert_eq!(
+            Natural::from_str_radix(&num_x.to_str_radix(16), 16).unwrap(),
+            x
+        );
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(CheckedMul::checked_mul(&x, &y), Some(&x * &y));
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        assert_eq!(
+            Natural::from_f64(f),
+            BigUint::from_f64(f).map(Natural::from)
+        );
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(Natural::from_u64(u).unwrap().to_u64(), Some(u));
+    });
+}
@@ -49,6 +49,10 @@ pub mod log_base_2;
 /// [`CeilingLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CeilingLogBasePowerOf2), and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// Implementations of [`Rem`](core::ops::Rem) and [`RemAssign`](core::ops::RemAssign), traits
+/// for finding the remainder of two [`Rational`](super::Rational)s, with the quotient rounded
+/// towards zero. These are only available with the `num_traits` feature.
+pub mod mod_op;
 /// Multiplication of [`Rational`](super::Rational)s.
 pub mod mul;
 /// Negation of [`Rational`](super::Rational)s.
@@ -0,0 +1,368 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! These exist so that [`Rational`] can implement [num-traits](https://docs.rs/num-traits)'s
+//! `Num`, which requires [`Rem`].
+//!
+//! To use this enable the `num_traits` feature.
+
+use crate::Rational;
+use core::ops::{Rem, RemAssign};
+
+impl Rem<Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by value and returning just the
+    /// remainder. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % Rational::from_signeds(2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % Rational::from_signeds(-2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % Rational::from_signeds(2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % Rational::from_signeds(-2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        &self % &other
+    }
+}
+
+impl<'a> Rem<&'a Rational> for Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by value and the second by
+    /// reference and returning just the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % &Rational::from_signeds(2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(22, 7) % &Rational::from_signeds(-2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % &Rational::from_signeds(2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (Rational::from_signeds(-22, 7) % &Rational::from_signeds(-2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Rational) -> Rational {
+        &self % other
+    }
+}
+
+impl<'a> Rem<Rational> for &'a Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking the first by reference and the second
+    /// by value and returning just the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % Rational::from_signeds(2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % Rational::from_signeds(-2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % Rational::from_signeds(2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % Rational::from_signeds(-2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    /// ```
+    #[inline]
+    fn rem(self, other: Rational) -> Rational {
+        self % &other
+    }
+}
+
+impl<'a, 'b> Rem<&'a Rational> for &'b Rational {
+    type Output = Rational;
+
+    /// Divides a [`Rational`] by another [`Rational`], taking both by reference and returning just
+    /// the remainder. The remainder has the same sign as the first [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// f(x, y) = x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % &Rational::from_signeds(2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7) % &Rational::from_signeds(-2, 3)).to_string(),
+    ///     "10/21"
+    /// );
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % &Rational::from_signeds(2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-22, 7) % &Rational::from_signeds(-2, 3)).to_string(),
+    ///     "-10/21"
+    /// );
+    /// ```
+    fn rem(self, other: &'a Rational) -> Rational {
+        assert_ne!(*other, 0u32, "division by zero");
+        // If x = a/b and y = c/d, then x % y = (ad % bc) / bd, with the sign of x.
+        Rational::from_sign_and_naturals(
+            self.sign,
+            (&self.numerator * &other.denominator) % (&other.numerator * &self.denominator),
+            &self.denominator * &other.denominator,
+        )
+    }
+}
+
+impl RemAssign<Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by value and
+    /// replacing the first by the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= Rational::from_signeds(2, 3);
+    /// assert_eq!(x.to_string(), "10/21");
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= Rational::from_signeds(-2, 3);
+    /// assert_eq!(x.to_string(), "10/21");
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= Rational::from_signeds(2, 3);
+    /// assert_eq!(x.to_string(), "-10/21");
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= Rational::from_signeds(-2, 3);
+    /// assert_eq!(x.to_string(), "-10/21");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Rational) {
+        *self = &*self % &other;
+    }
+}
+
+impl<'a> RemAssign<&'a Rational> for Rational {
+    /// Divides a [`Rational`] by another [`Rational`], taking the second [`Rational`] by reference
+    /// and replacing the first by the remainder. The remainder has the same sign as the first
+    /// [`Rational`].
+    ///
+    /// If the quotient were computed, it would be an integer $q$ satisfying $x = qy + r$ and $0
+    /// \leq |r| < |y|$.
+    ///
+    /// $$
+    /// x \gets x - y \operatorname{sgn}(xy)
+    ///     \left \lfloor \left | \frac{x}{y} \right | \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// // 4 * 2/3 + 10/21 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= &Rational::from_signeds(2, 3);
+    /// assert_eq!(x.to_string(), "10/21");
+    ///
+    /// // -4 * -2/3 + 10/21 = 22/7
+    /// let mut x = Rational::from_signeds(22, 7);
+    /// x %= &Rational::from_signeds(-2, 3);
+    /// assert_eq!(x.to_string(), "10/21");
+    ///
+    /// // -4 * 2/3 + -10/21 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= &Rational::from_signeds(2, 3);
+    /// assert_eq!(x.to_string(), "-10/21");
+    ///
+    /// // 4 * -2/3 + -10/21 = -22/7
+    /// let mut x = Rational::from_signeds(-22, 7);
+    /// x %= &Rational::from_signeds(-2, 3);
+    /// assert_eq!(x.to_string(), "-10/21");
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &'a Rational) {
+        *self = &*self % other;
+    }
+}
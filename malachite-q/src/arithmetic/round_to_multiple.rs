@@ -41,6 +41,20 @@ impl RoundToMultiple<Rational> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -52,7 +66,8 @@ impl RoundToMultiple<Rational> for Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -137,6 +152,20 @@ impl<'a> RoundToMultiple<&'a Rational> for Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -148,7 +177,8 @@ impl<'a> RoundToMultiple<&'a Rational> for Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -233,6 +263,20 @@ impl<'a> RoundToMultiple<Rational> for &'a Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -244,7 +288,8 @@ impl<'a> RoundToMultiple<Rational> for &'a Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -295,7 +340,11 @@ impl<'a> RoundToMultiple<Rational> for &'a Rational {
             return (self.clone(), Equal);
         }
         if other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 return (Rational::ZERO, if *self >= 0u32 { Less } else { Greater });
             }
             panic!("Cannot round {self} to zero using RoundingMode {rm}");
@@ -338,6 +387,20 @@ impl<'a, 'b> RoundToMultiple<&'b Rational> for &'a Rational {
     /// \end{cases}
     /// $$
     ///
+    /// $$
+    /// f(x, y, \mathrm{NearestTiesAway}) = y \operatorname{sgn}(q)
+    /// \left \lfloor |q| + \frac{1}{2} \right \rfloor.
+    /// $$
+    ///
+    /// $$
+    /// f(x, y, \mathrm{ToOdd}) = \begin{cases}
+    ///     x & \text{if} \\quad q \in \Z, \\\\
+    ///     y \lfloor q \rfloor & \text{if} \\quad q \notin \Z
+    ///     \\ \text{and} \\ \lfloor q \rfloor \\ \text{is odd}, \\\\
+    ///     y \lceil q \rceil & \text{otherwise.}
+    /// \end{cases}
+    /// $$
+    ///
     /// $f(x, y, \mathrm{Exact}) = x$, but panics if $q \notin \Z$.
     ///
     /// # Worst-case complexity
@@ -349,7 +412,8 @@ impl<'a, 'b> RoundToMultiple<&'b Rational> for &'a Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -397,7 +461,11 @@ impl<'a, 'b> RoundToMultiple<&'b Rational> for &'a Rational {
             return (self.clone(), Equal);
         }
         if *other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 return (Rational::ZERO, if *self >= 0 { Less } else { Greater });
             }
             panic!("Cannot round {self} to zero using RoundingMode {rm}");
@@ -430,7 +498,8 @@ impl RoundToMultipleAssign<Rational> for Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -476,7 +545,11 @@ impl RoundToMultipleAssign<Rational> for Rational {
             return Equal;
         }
         if other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 let o = if *self >= 0 { Less } else { Greater };
                 *self = Rational::ZERO;
                 return o;
@@ -515,7 +588,8 @@ impl<'a> RoundToMultipleAssign<&'a Rational> for Rational {
     ///
     /// # Panics
     /// - If `rm` is `Exact`, but `self` is not a multiple of `other`.
-    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero.
+    /// - If `self` is nonzero, `other` is zero, and `rm` is trying to round away from zero or is
+    ///   `ToOdd`.
     ///
     /// # Examples
     /// ```
@@ -558,7 +632,11 @@ impl<'a> RoundToMultipleAssign<&'a Rational> for Rational {
             return Equal;
         }
         if *other == 0u32 {
-            if rm == Down || rm == Nearest || rm == if *self >= 0u32 { Floor } else { Ceiling } {
+            if rm == Down
+                || rm == Nearest
+                || rm == NearestTiesAway
+                || rm == if *self >= 0u32 { Floor } else { Ceiling }
+            {
                 let o = if *self >= 0u32 { Less } else { Greater };
                 *self = Rational::ZERO;
                 return o;
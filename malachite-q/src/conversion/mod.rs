@@ -490,6 +490,12 @@ pub mod primitive_float_from_rational;
 /// );
 /// ```
 pub mod primitive_int_from_rational;
+/// Implementations of [`From`] for converting between [`Rational`](crate::Rational)s and
+/// [num-rational](https://docs.rs/num-rational)'s `BigRational`s.
+pub mod num_rational;
+/// Implementations of traits for conversions between Python `fractions.Fraction`s and
+/// [`Rational`](crate::Rational)s using [pyo3](https://pyo3.rs/).
+pub mod pyo3;
 /// Implementations of traits for converting [`Rational`](crate::Rational)s to and from [`String`]s.
 pub mod string;
 /// Functions for extracting or referencing the numerator and/or denominator of a
@@ -121,7 +121,7 @@ impl RoundingFrom<Rational> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Rational`] is negative, then it will be rounded to zero when the [`RoundingMode`]
-    /// is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+    /// is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this function will panic.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -132,7 +132,7 @@ impl RoundingFrom<Rational> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, or if the [`Rational`] is
-    /// less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`.
+    /// less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`.
     ///
     /// # Examples
     /// ```
@@ -184,7 +184,7 @@ impl RoundingFrom<Rational> for Natural {
     fn rounding_from(x: Rational, rm: RoundingMode) -> (Natural, Ordering) {
         if x.sign {
             x.numerator.div_round(x.denominator, rm)
-        } else if rm == Down || rm == Ceiling || rm == Nearest {
+        } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (Natural::ZERO, Greater)
         } else {
             panic!("Cannot round negative Rational to Natural using RoundingMode {rm}");
@@ -198,7 +198,7 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
     /// value is less than, equal to, or greater than the original value.
     ///
     /// If the [`Rational`] is negative, then it will be rounded to zero when the [`RoundingMode`]
-    /// is `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+    /// is `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this function will panic.
     ///
     /// # Worst-case complexity
     /// $T(n) = O(n \log n \log\log n)$
@@ -209,7 +209,7 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
     ///
     /// # Panics
     /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, or if the [`Rational`] is
-    /// less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`.
+    /// less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or `NearestTiesAway`.
     ///
     /// # Examples
     /// ```
@@ -261,7 +261,7 @@ impl<'a> RoundingFrom<&'a Rational> for Natural {
     fn rounding_from(x: &Rational, rm: RoundingMode) -> (Natural, Ordering) {
         if x.sign {
             (&x.numerator).div_round(&x.denominator, rm)
-        } else if rm == Down || rm == Ceiling || rm == Nearest {
+        } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (Natural::ZERO, Greater)
         } else {
             panic!("Cannot round negative Rational to Natural using RoundingMode {rm}");
@@ -0,0 +1,69 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_q::Rational;
+//! use num::BigRational;
+//!
+//! let x = Rational::from_signeds(-22, 7);
+//! let y = BigRational::from(&x);
+//! assert_eq!(y.to_string(), "-22/7");
+//! assert_eq!(Rational::from(y), x);
+//! ```
+
+use crate::Rational;
+use malachite_nz::integer::Integer;
+use num::bigint::Sign;
+use num::{BigInt, BigRational, BigUint};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&BigRational> for Rational {
+    #[inline]
+    fn from(n: &BigRational) -> Rational {
+        Rational::from_integers(Integer::from(n.numer()), Integer::from(n.denom()))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<BigRational> for Rational {
+    #[inline]
+    fn from(n: BigRational) -> Rational {
+        let (numerator, denominator) = n.into_raw();
+        Rational::from_integers(Integer::from(numerator), Integer::from(denominator))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<&Rational> for BigRational {
+    #[inline]
+    fn from(n: &Rational) -> BigRational {
+        let sign = if *n < 0u32 { Sign::Minus } else { Sign::Plus };
+        BigRational::new_raw(
+            BigInt::from_biguint(sign, BigUint::from(n.numerator_ref())),
+            BigInt::from(BigUint::from(n.denominator_ref())),
+        )
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl From<Rational> for BigRational {
+    #[inline]
+    fn from(n: Rational) -> BigRational {
+        let sign = if n < 0u32 { Sign::Minus } else { Sign::Plus };
+        let (numerator, denominator) = n.into_numerator_and_denominator();
+        BigRational::new_raw(
+            BigInt::from_biguint(sign, BigUint::from(numerator)),
+            BigInt::from(BigUint::from(denominator)),
+        )
+    }
+}
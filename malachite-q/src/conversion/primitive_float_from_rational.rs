@@ -58,6 +58,18 @@ macro_rules! float_impls {
             ///   If the [`Rational`] is closer to zero than to any float (or if there is a tie
             ///   between zero and another float), then positive or negative zero is returned,
             ///   depending on the [`Rational`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway`, then the nearest float is returned. If
+            ///   the [`Rational`] is exactly between two floats, the float with the greater
+            ///   absolute value is selected. If the [`Rational`] is greater than the maximum finite
+            ///   float, then the maximum finite float is returned. If the [`Rational`] is closer to
+            ///   zero than to any float, then positive or negative zero is returned, depending on
+            ///   the [`Rational`]'s sign.
+            /// - If the rounding mode is `ToOdd`, then the [`Rational`] is returned if it can be
+            ///   represented exactly; otherwise, whichever of the two adjacent floats has a one
+            ///   least-significant bit in its representation is selected. If the [`Rational`] is
+            ///   greater than the maximum finite float, then the maximum finite float is returned.
+            ///   If the [`Rational`] is between zero and the minimum positive float, then the
+            ///   minimum positive float, with the [`Rational`]'s sign, is returned.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -85,7 +97,9 @@ macro_rules! float_impls {
                             Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            Floor | Down | Nearest => ($f::MAX_FINITE, Less),
+                            Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                ($f::MAX_FINITE, Less)
+                            }
                             _ => ($f::INFINITY, Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -108,7 +122,9 @@ macro_rules! float_impls {
                                 Exact => {
                                     panic!("Value cannot be represented exactly as a float")
                                 }
-                                Floor | Down | Nearest => ($f::MAX_FINITE, Less),
+                                Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                    ($f::MAX_FINITE, Less)
+                                }
                                 _ => ($f::INFINITY, Greater),
                             }
                         } else {
@@ -152,6 +168,13 @@ macro_rules! float_impls {
                                     (0.0, Less)
                                 }
                             }
+                            NearestTiesAway => {
+                                if exponent == $f::MIN_EXPONENT - 1 {
+                                    ($f::MIN_POSITIVE_SUBNORMAL, Greater)
+                                } else {
+                                    (0.0, Less)
+                                }
+                            }
                             _ => ($f::MIN_POSITIVE_SUBNORMAL, Greater),
                         }
                     };
@@ -265,6 +288,18 @@ macro_rules! float_impls {
             ///   If the [`Rational`] is closer to zero than to any float (or if there is a tie
             ///   between zero and another float), then positive or negative zero is returned,
             ///   depending on the [`Rational`]'s sign.
+            /// - If the rounding mode is `NearestTiesAway`, then the nearest float is returned. If
+            ///   the [`Rational`] is exactly between two floats, the float with the greater
+            ///   absolute value is selected. If the [`Rational`] is greater than the maximum finite
+            ///   float, then the maximum finite float is returned. If the [`Rational`] is closer to
+            ///   zero than to any float, then positive or negative zero is returned, depending on
+            ///   the [`Rational`]'s sign.
+            /// - If the rounding mode is `ToOdd`, then the [`Rational`] is returned if it can be
+            ///   represented exactly; otherwise, whichever of the two adjacent floats has a one
+            ///   least-significant bit in its representation is selected. If the [`Rational`] is
+            ///   greater than the maximum finite float, then the maximum finite float is returned.
+            ///   If the [`Rational`] is between zero and the minimum positive float, then the
+            ///   minimum positive float, with the [`Rational`]'s sign, is returned.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -291,7 +326,9 @@ macro_rules! float_impls {
                             Exact => {
                                 panic!("Value cannot be represented exactly as a float")
                             }
-                            Floor | Down | Nearest => ($f::MAX_FINITE, Less),
+                            Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                ($f::MAX_FINITE, Less)
+                            }
                             _ => ($f::INFINITY, Greater),
                         }
                     } else if exponent >= $f::MIN_NORMAL_EXPONENT {
@@ -314,7 +351,9 @@ macro_rules! float_impls {
                                 Exact => {
                                     panic!("Value cannot be represented exactly as a float")
                                 }
-                                Floor | Down | Nearest => ($f::MAX_FINITE, Less),
+                                Floor | Down | Nearest | NearestTiesAway | ToOdd => {
+                                    ($f::MAX_FINITE, Less)
+                                }
                                 _ => ($f::INFINITY, Greater),
                             }
                         } else {
@@ -358,6 +397,13 @@ macro_rules! float_impls {
                                     (0.0, Less)
                                 }
                             }
+                            NearestTiesAway => {
+                                if exponent == $f::MIN_EXPONENT - 1 {
+                                    ($f::MIN_POSITIVE_SUBNORMAL, Greater)
+                                } else {
+                                    (0.0, Less)
+                                }
+                            }
                             _ => ($f::MIN_POSITIVE_SUBNORMAL, Greater),
                         }
                     };
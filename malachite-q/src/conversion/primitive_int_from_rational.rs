@@ -46,7 +46,8 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
         let (n, o) = (&x.numerator).div_round(&x.denominator, rm);
         let out = if let Ok(q) = T::try_from(&n) {
             (q, o)
-        } else if rm == Down || rm == Floor || rm == Nearest {
+        } else if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway || rm == ToOdd
+        {
             (T::MAX, Less)
         } else {
             panic!(
@@ -56,7 +57,7 @@ fn rounding_from_unsigned<'a, T: for<'b> TryFrom<&'b Natural> + Max + Named + Ze
             );
         };
         out
-    } else if rm == Down || rm == Ceiling || rm == Nearest {
+    } else if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
         (T::ZERO, Greater)
     } else {
         panic!(
@@ -141,7 +142,7 @@ where
 {
     let (i, o) = Integer::rounding_from(x, rm);
     if i > T::MAX {
-        if rm == Down || rm == Floor || rm == Nearest {
+        if rm == Down || rm == Floor || rm == Nearest || rm == NearestTiesAway || rm == ToOdd {
             (T::MAX, Less)
         } else {
             panic!(
@@ -151,7 +152,7 @@ where
             );
         }
     } else if i < T::MIN {
-        if rm == Down || rm == Ceiling || rm == Nearest {
+        if rm == Down || rm == Ceiling || rm == Nearest || rm == NearestTiesAway {
             (T::MIN, Greater)
         } else {
             panic!(
@@ -202,11 +203,12 @@ macro_rules! impl_from_unsigned {
             /// Converts a [`Rational`] to an unsigned integer, using a specified [`RoundingMode`].
             ///
             /// If the [`Rational`] is negative, then it will be rounded to zero when `rm` is
-            /// `Ceiling`, `Down`, or `Nearest`. Otherwise, this function will panic.
+            /// `Ceiling`, `Down`, `Nearest`, or `NearestTiesAway`. Otherwise, this function will
+            /// panic.
             ///
             /// If the [`Rational`] is larger than the maximum value of the unsigned type, then it
-            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `ToOdd`. Otherwise, this function will panic.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -217,9 +219,9 @@ macro_rules! impl_from_unsigned {
             ///
             /// # Panics
             /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, if the
-            /// [`Rational`] is less than zero and `rm` is not `Down`, `Ceiling`, or `Nearest`, or
-            /// if the [`Rational`] is greater than `T::MAX` and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`.
+            /// [`Rational`] is less than zero and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, or if the [`Rational`] is greater than `T::MAX` and `rm` is not
+            /// `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_rational#rounding_from).
@@ -269,12 +271,12 @@ macro_rules! impl_from_signed {
             /// Converts a [`Rational`] to a signed integer, using a specified [`RoundingMode`].
             ///
             /// If the [`Rational`] is smaller than the minimum value of the unsigned type, then it
-            /// will be rounded to the minimum value when `rm` is `Ceiling`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the minimum value when `rm` is `Ceiling`, `Down`, `Nearest`, or
+            /// `NearestTiesAway`. Otherwise, this function will panic.
             ///
             /// If the [`Rational`] is larger than the maximum value of the unsigned type, then it
-            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, or `Nearest`.
-            /// Otherwise, this function will panic.
+            /// will be rounded to the maximum value when `rm` is `Floor`, `Down`, `Nearest`,
+            /// `NearestTiesAway`, or `ToOdd`. Otherwise, this function will panic.
             ///
             /// # Worst-case complexity
             /// $T(n) = O(n \log n \log\log n)$
@@ -285,9 +287,9 @@ macro_rules! impl_from_signed {
             ///
             /// # Panics
             /// Panics if the [`Rational`] is not an integer and `rm` is `Exact`, if the
-            /// [`Rational`] is less than `T::MIN` and `rm` is not `Down`, `Ceiling`, or `Nearest`,
-            /// or if the [`Rational`] is greater than `T::MAX` and `rm` is not `Down`, `Floor`, or
-            /// `Nearest`.
+            /// [`Rational`] is less than `T::MIN` and `rm` is not `Down`, `Ceiling`, `Nearest`, or
+            /// `NearestTiesAway`, or if the [`Rational`] is greater than `T::MAX` and `rm` is not
+            /// `Down`, `Floor`, `Nearest`, `NearestTiesAway`, or `ToOdd`.
             ///
             /// # Examples
             /// See [here](super::primitive_int_from_rational#rounding_from).
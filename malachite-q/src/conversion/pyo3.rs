@@ -0,0 +1,165 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "enable_pyo3")]
+
+//!
+//! This is useful for passing exact fractions between Python and Rust. A [`Rational`] is converted
+//! to a Python `fractions.Fraction`. Any Python object with integer `numerator` and `denominator`
+//! attributes, such as a `fractions.Fraction` or an `int`, can be converted to a [`Rational`].
+//!
+//! To use this enable the `enable_pyo3` feature.
+//!
+//! ## Examples
+//!
+//! Using [`Rational`] to add fractions without losing precision.
+//! ```rust
+//! use malachite_q::Rational;
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn add_half(x: Rational) -> Rational {
+//!     x + Rational::from_signeds(1, 2)
+//! }
+//!
+//! #[pymodule]
+//! fn my_module(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+//!     m.add_function(wrap_pyfunction!(add_half, m)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Python code:
+//! ```python
+//! from fractions import Fraction
+//! from my_module import add_half
+//!
+//! assert add_half(Fraction(1, 3)) == Fraction(5, 6)
+//! ```
+
+use crate::Rational;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use pyo3::{
+    exceptions::PyZeroDivisionError, intern, types::*, FromPyObject, IntoPy, PyErr, PyObject,
+    PyResult, Python, ToPyObject,
+};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl<'source> FromPyObject<'source> for Rational {
+    fn extract(ob: &'source PyAny) -> PyResult<Rational> {
+        let py = ob.py();
+        // `fractions.Fraction`, `int`, and any other `numbers.Rational` have these attributes
+        let numerator: Integer = ob.getattr(intern!(py, "numerator"))?.extract()?;
+        let denominator: Integer = ob.getattr(intern!(py, "denominator"))?.extract()?;
+        if denominator == 0u32 {
+            return Err(PyErr::new::<PyZeroDivisionError, _>(
+                "denominator must be nonzero",
+            ));
+        }
+        Ok(Rational::from_integers(numerator, denominator))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl ToPyObject for Rational {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        let numerator =
+            Integer::from_sign_and_abs_ref(*self >= Rational::ZERO, self.numerator_ref());
+        py.import_bound(intern!(py, "fractions"))
+            .and_then(|fractions| fractions.getattr(intern!(py, "Fraction")))
+            .and_then(|fraction| fraction.call1((numerator, self.denominator_ref().to_object(py))))
+            .expect("fractions.Fraction() failed during to_object()")
+            .into()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "enable_pyo3")))]
+impl IntoPy<PyObject> for Rational {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.to_object(py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use malachite_base::num::arithmetic::traits::Pow;
+
+    /// Prepare Python
+    fn prepare_python() {
+        pyo3::prepare_freethreaded_python();
+    }
+
+    /// Evaluate a Python expression, with the `fractions` module available
+    fn eval<'py>(py: Python<'py>, code: &str) -> &'py PyAny {
+        let locals = PyDict::new_bound(py);
+        locals
+            .set_item("fractions", py.import_bound("fractions").unwrap())
+            .unwrap();
+        py.eval_bound(code, None, Some(&locals))
+            .unwrap()
+            .into_gil_ref()
+    }
+
+    /// Test conversion to and from Rational
+    #[test]
+    fn convert_rational() {
+        prepare_python();
+        Python::with_gil(|py| {
+            let test = |code: &str, x: Rational| {
+                let ob = eval(py, code);
+                // Python -> Rust
+                assert_eq!(ob.extract::<Rational>().unwrap(), x);
+                // Rust -> Python
+                let y = x.to_object(py);
+                assert!(y.bind(py).eq(ob).unwrap());
+                assert_eq!(y.bind(py).get_type().name().unwrap(), "Fraction");
+            };
+            test("fractions.Fraction(0)", Rational::ZERO);
+            test("fractions.Fraction(1, 2)", Rational::from_signeds(1, 2));
+            test("fractions.Fraction(-22, 7)", Rational::from_signeds(-22, 7));
+            test("fractions.Fraction(6, -4)", Rational::from_signeds(-3, 2));
+            test(
+                "fractions.Fraction(1, 3 ** 100)",
+                Rational::from_integers(Integer::from(1), Integer::from(3).pow(100)),
+            );
+            test(
+                "fractions.Fraction(-(1 << 1337), 3)",
+                Rational::from_integers(-(Integer::from(1) << 1337u64), Integer::from(3)),
+            );
+        });
+    }
+
+    /// Test conversion from Python integers
+    #[test]
+    fn convert_int() {
+        prepare_python();
+        Python::with_gil(|py| {
+            assert_eq!(
+                eval(py, "-5").extract::<Rational>().unwrap(),
+                Rational::from(-5)
+            );
+            assert_eq!(
+                eval(py, "1 << 200").extract::<Rational>().unwrap(),
+                Rational::from(Integer::from(1) << 200u64)
+            );
+        });
+    }
+
+    /// Test that objects which are not rational numbers are rejected
+    #[test]
+    fn reject_non_rational() {
+        prepare_python();
+        Python::with_gil(|py| {
+            assert!(eval(py, "1.5").extract::<Rational>().is_err());
+            assert!(eval(py, "'1/2'").extract::<Rational>().is_err());
+            assert!(eval(py, "None").extract::<Rational>().is_err());
+        });
+    }
+}
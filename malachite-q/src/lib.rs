@@ -56,6 +56,14 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
 //!   default, [`u64`].
+//! - `num_traits`: Implements the traits of [num-traits](https://docs.rs/num-traits), such as
+//!   `Num` and `Signed`, for [`Rational`], adds conversions to and from num-rational's
+//!   `BigRational`, and implements [`Rem`](core::ops::Rem) for [`Rational`], which `Num` requires.
+//!   It also enables the `num_traits` feature of `malachite-nz`. Some of these traits have methods
+//!   with the same names as methods of Malachite's traits. If both traits are in scope, method
+//!   calls such as `(&x).abs()` become ambiguous and fail to compile, so enabling this feature can
+//!   break code that imports both sets of traits. In that case, use fully qualified syntax, like
+//!   `Abs::abs(&x)`.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
@@ -123,7 +131,7 @@ extern crate serde;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
-#[cfg(feature = "test_build")]
+#[cfg(feature = "num")]
 extern crate num;
 #[cfg(feature = "test_build")]
 extern crate rug;
@@ -254,6 +262,8 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Rational`]s without repetition.
 pub mod exhaustive;
+/// Implementations of traits from [num-traits](https://docs.rs/num-traits) for [`Rational`]s.
+pub mod num_traits;
 #[cfg(feature = "random")]
 /// Iterators that generate [`Rational`]s randomly.
 pub mod random;
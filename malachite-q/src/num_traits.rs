@@ -0,0 +1,301 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "num_traits")]
+
+//! This allows [`Rational`] to be used with generic code that is bounded on
+//! [num-traits](https://docs.rs/num-traits), such as `nalgebra` or `ndarray`.
+//!
+//! To use this enable the `num_traits` feature.
+//!
+//! ## Examples
+//! ```rust
+//! use malachite_q::Rational;
+//! use num::traits::{Num, Signed};
+//!
+//! fn abs_sum<T: Signed>(xs: Vec<T>) -> T {
+//!     xs.into_iter().fold(T::zero(), |acc, x| acc + x.abs())
+//! }
+//!
+//! assert_eq!(
+//!     abs_sum(vec![
+//!         Rational::from_signeds(-1, 2),
+//!         Rational::from_signeds(1, 3)
+//!     ])
+//!     .to_string(),
+//!     "5/6"
+//! );
+//! assert_eq!(
+//!     Rational::from_str_radix("-ff/10", 16).unwrap().to_string(),
+//!     "-255/16"
+//! );
+//! ```
+
+use crate::Rational;
+use core::cmp::Ordering::*;
+use malachite_base::num::arithmetic::traits::{Abs, Pow as MalachitePow, PowerOf2, Sign};
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::{NegativeOne, One as MalachiteOne, Zero as MalachiteZero};
+use malachite_base::num::comparison::traits::PartialOrdAbs;
+use malachite_base::num::conversion::traits::{ExactFrom, FromStringBase, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+
+// Rounds a [`Rational`] to the nearest primitive float. Unlike `RoundingFrom` with `Nearest`, which
+// saturates at the largest finite float, values that are too large in magnitude round to positive
+// or negative infinity.
+fn rational_to_primitive_float<T>(x: &Rational) -> T
+where
+    T: PrimitiveFloat + for<'a> RoundingFrom<&'a Rational>,
+{
+    let f = T::rounding_from(x, Nearest).0;
+    if f.abs() == T::MAX_FINITE {
+        // The smallest magnitude that rounds to infinity is halfway between the largest finite
+        // float and the next power of 2.
+        let max_exponent = u64::exact_from(T::MAX_EXPONENT);
+        let threshold = Natural::power_of_2(max_exponent + 1)
+            - Natural::power_of_2(max_exponent - T::MANTISSA_WIDTH - 1);
+        if x.ge_abs(&threshold) {
+            return if *x > 0u32 {
+                T::INFINITY
+            } else {
+                T::NEGATIVE_INFINITY
+            };
+        }
+    }
+    f
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Zero for Rational {
+    #[inline]
+    fn zero() -> Rational {
+        Rational::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == 0u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl One for Rational {
+    #[inline]
+    fn one() -> Rational {
+        Rational::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == 1u32
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Num for Rational {
+    type FromStrRadixErr = ();
+
+    /// Converts a string, in a specified radix, to a [`Rational`].
+    ///
+    /// The string may be an integer, like `"-ff"`, or a fraction, like `"-ff/10"`, whose numerator
+    /// and denominator are in the given radix. The fraction need not be in lowest terms, but its
+    /// denominator may not be zero.
+    ///
+    /// # Panics
+    /// Panics if `radix` is less than 2 or greater than 36.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Rational, ()> {
+        let base = u8::exact_from(radix);
+        let (sign, s) = match s.strip_prefix('-') {
+            Some(s) => (false, s),
+            None => (true, s),
+        };
+        let (numerator, denominator) = match s.split_once('/') {
+            Some((n, d)) => (
+                Natural::from_string_base(base, n).ok_or(())?,
+                Natural::from_string_base(base, d).ok_or(())?,
+            ),
+            None => (Natural::from_string_base(base, s).ok_or(())?, Natural::ONE),
+        };
+        if denominator == 0u32 {
+            Err(())
+        } else {
+            Ok(Rational::from_sign_and_naturals(
+                sign,
+                numerator,
+                denominator,
+            ))
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl Signed for Rational {
+    #[inline]
+    fn abs(&self) -> Rational {
+        Abs::abs(self)
+    }
+
+    /// Returns the positive difference of two [`Rational`]s: `self - other` if it is positive, and
+    /// zero otherwise.
+    #[inline]
+    fn abs_sub(&self, other: &Rational) -> Rational {
+        if self <= other {
+            Rational::ZERO
+        } else {
+            self - other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Rational {
+        match self.sign() {
+            Less => Rational::NEGATIVE_ONE,
+            Equal => Rational::ZERO,
+            Greater => Rational::ONE,
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        *self > 0u32
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        *self < 0u32
+    }
+}
+
+macro_rules! impl_pow {
+    ($t: ident, $u: ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl Pow<$t> for Rational {
+            type Output = Rational;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                MalachitePow::pow(self, $u::exact_from(exp))
+            }
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+        impl<'a> Pow<$t> for &'a Rational {
+            type Output = Rational;
+
+            #[inline]
+            fn pow(self, exp: $t) -> Rational {
+                MalachitePow::pow(self, $u::exact_from(exp))
+            }
+        }
+    };
+}
+impl_pow!(u8, u64);
+impl_pow!(u16, u64);
+impl_pow!(u32, u64);
+impl_pow!(u64, u64);
+impl_pow!(usize, u64);
+impl_pow!(i8, i64);
+impl_pow!(i16, i64);
+impl_pow!(i32, i64);
+impl_pow!(i64, i64);
+impl_pow!(isize, i64);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl ToPrimitive for Rational {
+    /// Converts a [`Rational`] to an [`i64`], rounding towards zero. Returns `None` if the rounded
+    /// value is out of range.
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(&Integer::rounding_from(self, Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to an [`i128`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(&Integer::rounding_from(self, Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u64`], rounding towards zero. Returns `None` if the rounded
+    /// value is out of range.
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(&Integer::rounding_from(self, Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to a [`u128`], rounding towards zero. Returns `None` if the
+    /// rounded value is out of range.
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(&Integer::rounding_from(self, Down).0).ok()
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f32`]. Values too large in magnitude to be
+    /// represented are converted to positive or negative infinity.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(rational_to_primitive_float(self))
+    }
+
+    /// Converts a [`Rational`] to the nearest [`f64`]. Values too large in magnitude to be
+    /// represented are converted to positive or negative infinity.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(rational_to_primitive_float(self))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl FromPrimitive for Rational {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Rational> {
+        Some(Rational::from(n))
+    }
+
+    /// Converts an [`f32`] to a [`Rational`] exactly. Returns `None` if the value is NaN or
+    /// infinite.
+    #[inline]
+    fn from_f32(n: f32) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+
+    /// Converts an [`f64`] to a [`Rational`] exactly. Returns `None` if the value is NaN or
+    /// infinite.
+    #[inline]
+    fn from_f64(n: f64) -> Option<Rational> {
+        Rational::try_from(n).ok()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "num_traits")))]
+impl CheckedMul for Rational {
+    /// Multiplies two [`Rational`]s. Since a [`Rational`] cannot overflow, the result is never
+    /// `None`.
+    #[inline]
+    fn checked_mul(&self, other: &Rational) -> Option<Rational> {
+        Some(self * other)
+    }
+}
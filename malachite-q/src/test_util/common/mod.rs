@@ -8,14 +8,19 @@
 
 use crate::Rational;
 use malachite_nz::integer::Integer;
+#[cfg(not(feature = "num_traits"))]
 use num::{BigInt, BigRational};
 
+// With the `num_traits` feature, the conversions between `BigRational` and `Rational` are provided
+// by the library itself.
+#[cfg(not(feature = "num_traits"))]
 impl From<&BigRational> for Rational {
     fn from(n: &BigRational) -> Rational {
         Rational::from_integers(Integer::from(n.numer()), Integer::from(n.denom()))
     }
 }
 
+#[cfg(not(feature = "num_traits"))]
 impl From<&Rational> for BigRational {
     fn from(n: &Rational) -> BigRational {
         let mut q = BigRational::new_raw(
@@ -457,7 +457,10 @@ pub(crate) fn round_to_multiple_rational_filter(t: &(Rational, Rational, Roundin
     if x == y {
         true
     } else if *y == 0u32 {
-        rm == Down || rm == (if *x >= 0 { Floor } else { Ceiling }) || rm == Nearest
+        rm == Down
+            || rm == (if *x >= 0 { Floor } else { Ceiling })
+            || rm == Nearest
+            || rm == NearestTiesAway
     } else {
         rm != Exact || (x / y).is_integer()
     }
@@ -573,7 +576,7 @@ pub fn exhaustive_rational_rounding_mode_pair_gen() -> It<(Rational, RoundingMod
 pub fn exhaustive_rational_rounding_mode_pair_gen_var_1() -> It<(Rational, RoundingMode)> {
     Box::new(
         lex_pairs(exhaustive_rationals(), exhaustive_rounding_modes()).filter(|(x, rm)| match rm {
-            Floor | Up => *x >= 0u32,
+            Floor | Up | ToOdd => *x >= 0u32,
             Exact => Natural::convertible_from(x),
             _ => true,
         }),
@@ -595,7 +598,7 @@ where
 {
     Box::new(
         lex_pairs(exhaustive_rationals(), exhaustive_rounding_modes()).filter(|(x, rm)| match rm {
-            Floor => *x >= T::MIN,
+            Floor | ToOdd => *x >= T::MIN,
             Ceiling => *x <= T::MAX,
             Up => *x >= T::MIN && *x <= T::MAX,
             Exact => T::convertible_from(x),
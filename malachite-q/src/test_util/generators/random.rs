@@ -1350,7 +1350,7 @@ pub fn random_rational_rounding_mode_pair_gen_var_1(
             &random_rounding_modes,
         )
         .filter(|(x, rm)| match rm {
-            Floor | Up => *x >= 0u32,
+            Floor | Up | ToOdd => *x >= 0u32,
             Exact => Natural::convertible_from(x),
             _ => true,
         }),
@@ -1397,7 +1397,7 @@ where
             &random_rounding_modes,
         )
         .filter(|(x, rm)| match rm {
-            Floor => *x >= T::MIN,
+            Floor | ToOdd => *x >= T::MIN,
             Ceiling => *x <= T::MAX,
             Up => *x >= T::MIN && *x <= T::MAX,
             Exact => T::convertible_from(x),
@@ -66,6 +66,8 @@ fn test_round_to_multiple() {
     test("0", "1", Up, "0", Equal);
     test("0", "1", Ceiling, "0", Equal);
     test("0", "1", Nearest, "0", Equal);
+    test("0", "1", NearestTiesAway, "0", Equal);
+    test("0", "1", ToOdd, "0", Equal);
     test("0", "1", Exact, "0", Equal);
 
     test("0", "22/7", Down, "0", Equal);
@@ -73,6 +75,8 @@ fn test_round_to_multiple() {
     test("0", "22/7", Up, "0", Equal);
     test("0", "22/7", Ceiling, "0", Equal);
     test("0", "22/7", Nearest, "0", Equal);
+    test("0", "22/7", NearestTiesAway, "0", Equal);
+    test("0", "22/7", ToOdd, "0", Equal);
     test("0", "22/7", Exact, "0", Equal);
 
     test("1/3", "1", Down, "0", Less);
@@ -80,12 +84,16 @@ fn test_round_to_multiple() {
     test("1/3", "1", Up, "1", Greater);
     test("1/3", "1", Ceiling, "1", Greater);
     test("1/3", "1", Nearest, "0", Less);
+    test("1/3", "1", NearestTiesAway, "0", Less);
+    test("1/3", "1", ToOdd, "1", Greater);
 
     test("1/3", "1/3", Down, "1/3", Equal);
     test("1/3", "1/3", Floor, "1/3", Equal);
     test("1/3", "1/3", Up, "1/3", Equal);
     test("1/3", "1/3", Ceiling, "1/3", Equal);
     test("1/3", "1/3", Nearest, "1/3", Equal);
+    test("1/3", "1/3", NearestTiesAway, "1/3", Equal);
+    test("1/3", "1/3", ToOdd, "1/3", Equal);
     test("1/3", "1/3", Exact, "1/3", Equal);
 
     test("1/3", "1/4", Down, "1/4", Less);
@@ -93,6 +101,8 @@ fn test_round_to_multiple() {
     test("1/3", "1/4", Up, "1/2", Greater);
     test("1/3", "1/4", Ceiling, "1/2", Greater);
     test("1/3", "1/4", Nearest, "1/4", Less);
+    test("1/3", "1/4", NearestTiesAway, "1/4", Less);
+    test("1/3", "1/4", ToOdd, "1/4", Less);
 
     test(
         "884279719003555/281474976710656",
@@ -129,6 +139,20 @@ fn test_round_to_multiple() {
         "3141593/1000000",
         Greater,
     );
+    test(
+        "884279719003555/281474976710656",
+        "1/1000000",
+        NearestTiesAway,
+        "3141593/1000000",
+        Greater,
+    );
+    test(
+        "884279719003555/281474976710656",
+        "1/1000000",
+        ToOdd,
+        "3141593/1000000",
+        Greater,
+    );
 
     test(
         "1000000",
@@ -165,18 +189,36 @@ fn test_round_to_multiple() {
         "140737538678010796025/140737488355328",
         Greater,
     );
+    test(
+        "1000000",
+        "884279719003555/281474976710656",
+        NearestTiesAway,
+        "140737538678010796025/140737488355328",
+        Greater,
+    );
+    test(
+        "1000000",
+        "884279719003555/281474976710656",
+        ToOdd,
+        "281474193076302588495/281474976710656",
+        Less,
+    );
 
     test("-1/3", "1", Down, "0", Greater);
     test("-1/3", "1", Floor, "-1", Less);
     test("-1/3", "1", Up, "-1", Less);
     test("-1/3", "1", Ceiling, "0", Greater);
     test("-1/3", "1", Nearest, "0", Greater);
+    test("-1/3", "1", NearestTiesAway, "0", Greater);
+    test("-1/3", "1", ToOdd, "-1", Less);
 
     test("-1/3", "1/3", Down, "-1/3", Equal);
     test("-1/3", "1/3", Floor, "-1/3", Equal);
     test("-1/3", "1/3", Up, "-1/3", Equal);
     test("-1/3", "1/3", Ceiling, "-1/3", Equal);
     test("-1/3", "1/3", Nearest, "-1/3", Equal);
+    test("-1/3", "1/3", NearestTiesAway, "-1/3", Equal);
+    test("-1/3", "1/3", ToOdd, "-1/3", Equal);
     test("-1/3", "1/3", Exact, "-1/3", Equal);
 
     test("-1/3", "1/4", Down, "-1/4", Greater);
@@ -184,6 +226,8 @@ fn test_round_to_multiple() {
     test("-1/3", "1/4", Up, "-1/2", Less);
     test("-1/3", "1/4", Ceiling, "-1/4", Greater);
     test("-1/3", "1/4", Nearest, "-1/4", Greater);
+    test("-1/3", "1/4", NearestTiesAway, "-1/4", Greater);
+    test("-1/3", "1/4", ToOdd, "-1/4", Greater);
 
     test(
         "-884279719003555/281474976710656",
@@ -220,6 +264,20 @@ fn test_round_to_multiple() {
         "-3141593/1000000",
         Less,
     );
+    test(
+        "-884279719003555/281474976710656",
+        "1/1000000",
+        NearestTiesAway,
+        "-3141593/1000000",
+        Less,
+    );
+    test(
+        "-884279719003555/281474976710656",
+        "1/1000000",
+        ToOdd,
+        "-3141593/1000000",
+        Less,
+    );
 
     test(
         "-1000000",
@@ -256,12 +314,28 @@ fn test_round_to_multiple() {
         "-140737538678010796025/140737488355328",
         Less,
     );
+    test(
+        "-1000000",
+        "884279719003555/281474976710656",
+        NearestTiesAway,
+        "-140737538678010796025/140737488355328",
+        Less,
+    );
+    test(
+        "-1000000",
+        "884279719003555/281474976710656",
+        ToOdd,
+        "-281474193076302588495/281474976710656",
+        Greater,
+    );
 
     test("0", "-1", Down, "0", Equal);
     test("0", "-1", Floor, "0", Equal);
     test("0", "-1", Up, "0", Equal);
     test("0", "-1", Ceiling, "0", Equal);
     test("0", "-1", Nearest, "0", Equal);
+    test("0", "-1", NearestTiesAway, "0", Equal);
+    test("0", "-1", ToOdd, "0", Equal);
     test("0", "-1", Exact, "0", Equal);
 
     test("0", "-22/7", Down, "0", Equal);
@@ -269,6 +343,8 @@ fn test_round_to_multiple() {
     test("0", "-22/7", Up, "0", Equal);
     test("0", "-22/7", Ceiling, "0", Equal);
     test("0", "-22/7", Nearest, "0", Equal);
+    test("0", "-22/7", NearestTiesAway, "0", Equal);
+    test("0", "-22/7", ToOdd, "0", Equal);
     test("0", "-22/7", Exact, "0", Equal);
 
     test("1/3", "-1", Down, "0", Less);
@@ -276,12 +352,16 @@ fn test_round_to_multiple() {
     test("1/3", "-1", Up, "1", Greater);
     test("1/3", "-1", Ceiling, "1", Greater);
     test("1/3", "-1", Nearest, "0", Less);
+    test("1/3", "-1", NearestTiesAway, "0", Less);
+    test("1/3", "-1", ToOdd, "1", Greater);
 
     test("1/3", "-1/3", Down, "1/3", Equal);
     test("1/3", "-1/3", Floor, "1/3", Equal);
     test("1/3", "-1/3", Up, "1/3", Equal);
     test("1/3", "-1/3", Ceiling, "1/3", Equal);
     test("1/3", "-1/3", Nearest, "1/3", Equal);
+    test("1/3", "-1/3", NearestTiesAway, "1/3", Equal);
+    test("1/3", "-1/3", ToOdd, "1/3", Equal);
     test("1/3", "-1/3", Exact, "1/3", Equal);
 
     test("1/3", "-1/4", Down, "1/4", Less);
@@ -289,6 +369,8 @@ fn test_round_to_multiple() {
     test("1/3", "-1/4", Up, "1/2", Greater);
     test("1/3", "-1/4", Ceiling, "1/2", Greater);
     test("1/3", "-1/4", Nearest, "1/4", Less);
+    test("1/3", "-1/4", NearestTiesAway, "1/4", Less);
+    test("1/3", "-1/4", ToOdd, "1/4", Less);
 
     test(
         "884279719003555/281474976710656",
@@ -325,6 +407,20 @@ fn test_round_to_multiple() {
         "3141593/1000000",
         Greater,
     );
+    test(
+        "884279719003555/281474976710656",
+        "-1/1000000",
+        NearestTiesAway,
+        "3141593/1000000",
+        Greater,
+    );
+    test(
+        "884279719003555/281474976710656",
+        "-1/1000000",
+        ToOdd,
+        "3141593/1000000",
+        Greater,
+    );
 
     test(
         "1000000",
@@ -361,18 +457,36 @@ fn test_round_to_multiple() {
         "140737538678010796025/140737488355328",
         Greater,
     );
+    test(
+        "1000000",
+        "-884279719003555/281474976710656",
+        NearestTiesAway,
+        "140737538678010796025/140737488355328",
+        Greater,
+    );
+    test(
+        "1000000",
+        "-884279719003555/281474976710656",
+        ToOdd,
+        "281474193076302588495/281474976710656",
+        Less,
+    );
 
     test("-1/3", "-1", Down, "0", Greater);
     test("-1/3", "-1", Floor, "-1", Less);
     test("-1/3", "-1", Up, "-1", Less);
     test("-1/3", "-1", Ceiling, "0", Greater);
     test("-1/3", "-1", Nearest, "0", Greater);
+    test("-1/3", "-1", NearestTiesAway, "0", Greater);
+    test("-1/3", "-1", ToOdd, "-1", Less);
 
     test("-1/3", "-1/3", Down, "-1/3", Equal);
     test("-1/3", "-1/3", Floor, "-1/3", Equal);
     test("-1/3", "-1/3", Up, "-1/3", Equal);
     test("-1/3", "-1/3", Ceiling, "-1/3", Equal);
     test("-1/3", "-1/3", Nearest, "-1/3", Equal);
+    test("-1/3", "-1/3", NearestTiesAway, "-1/3", Equal);
+    test("-1/3", "-1/3", ToOdd, "-1/3", Equal);
     test("-1/3", "-1/3", Exact, "-1/3", Equal);
 
     test("-1/3", "-1/4", Down, "-1/4", Greater);
@@ -380,6 +494,8 @@ fn test_round_to_multiple() {
     test("-1/3", "-1/4", Up, "-1/2", Less);
     test("-1/3", "-1/4", Ceiling, "-1/4", Greater);
     test("-1/3", "-1/4", Nearest, "-1/4", Greater);
+    test("-1/3", "-1/4", NearestTiesAway, "-1/4", Greater);
+    test("-1/3", "-1/4", ToOdd, "-1/4", Greater);
 
     test(
         "-884279719003555/281474976710656",
@@ -416,6 +532,20 @@ fn test_round_to_multiple() {
         "-3141593/1000000",
         Less,
     );
+    test(
+        "-884279719003555/281474976710656",
+        "-1/1000000",
+        NearestTiesAway,
+        "-3141593/1000000",
+        Less,
+    );
+    test(
+        "-884279719003555/281474976710656",
+        "-1/1000000",
+        ToOdd,
+        "-3141593/1000000",
+        Less,
+    );
 
     test(
         "-1000000",
@@ -452,13 +582,29 @@ fn test_round_to_multiple() {
         "-140737538678010796025/140737488355328",
         Less,
     );
+    test(
+        "-1000000",
+        "-884279719003555/281474976710656",
+        NearestTiesAway,
+        "-140737538678010796025/140737488355328",
+        Less,
+    );
+    test(
+        "-1000000",
+        "-884279719003555/281474976710656",
+        ToOdd,
+        "-281474193076302588495/281474976710656",
+        Greater,
+    );
 
     test("1/3", "0", Down, "0", Less);
     test("1/3", "0", Floor, "0", Less);
     test("1/3", "0", Nearest, "0", Less);
+    test("1/3", "0", NearestTiesAway, "0", Less);
     test("-1/3", "0", Down, "0", Greater);
     test("-1/3", "0", Ceiling, "0", Greater);
     test("-1/3", "0", Nearest, "0", Greater);
+    test("-1/3", "0", NearestTiesAway, "0", Greater);
 }
 
 #[test]
@@ -676,7 +822,7 @@ fn round_to_multiple_properties() {
                 Down => assert!(r.le_abs(&x)),
                 Up => assert!(r.ge_abs(&x)),
                 Exact => assert_eq!(r, x),
-                Nearest => {
+                Nearest | NearestTiesAway => {
                     let closest;
                     let second_closest;
                     if r <= x {
@@ -688,9 +834,14 @@ fn round_to_multiple_properties() {
                     }
                     assert!(closest <= second_closest);
                     if closest == second_closest {
-                        assert!(Integer::exact_from(r / y).even());
+                        if rm == Nearest {
+                            assert!(Integer::exact_from(r / y).even());
+                        } else {
+                            assert_eq!(o, if r > 0 { Greater } else { Less });
+                        }
                     }
                 }
+                ToOdd => assert!(r == x || Integer::exact_from(r / y).odd()),
             }
         }
     });
@@ -703,6 +854,8 @@ fn round_to_multiple_properties() {
         assert_eq!((&rounded).round_to_multiple(&y, Floor), ro);
         assert_eq!((&rounded).round_to_multiple(&y, Ceiling), ro);
         assert_eq!((&rounded).round_to_multiple(&y, Nearest), ro);
+        assert_eq!((&rounded).round_to_multiple(&y, NearestTiesAway), ro);
+        assert_eq!((&rounded).round_to_multiple(&y, ToOdd), ro);
         assert_eq!((&rounded).round_to_multiple(&y, Exact), ro);
     });
 
@@ -718,6 +871,10 @@ fn round_to_multiple_properties() {
         let ceiling = (&floor.0 + (&y).abs(), Greater);
         assert_eq!((&x).round_to_multiple(&y, Up), up);
         assert_eq!((&x).round_to_multiple(&y, Ceiling), ceiling);
+        let nearest_ties_away = (&x).round_to_multiple(&y, NearestTiesAway);
+        assert!(nearest_ties_away == down || nearest_ties_away == up);
+        let to_odd = (&x).round_to_multiple(&y, ToOdd);
+        assert!(to_odd == down || to_odd == up);
         let nearest = x.round_to_multiple(y, Nearest);
         assert!(nearest == down || nearest == up);
     });
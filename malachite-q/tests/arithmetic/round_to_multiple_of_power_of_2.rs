@@ -52,6 +52,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 0, Floor, "0", Equal);
     test("0", 0, Ceiling, "0", Equal);
     test("0", 0, Nearest, "0", Equal);
+    test("0", 0, NearestTiesAway, "0", Equal);
+    test("0", 0, ToOdd, "0", Equal);
     test("0", 0, Exact, "0", Equal);
 
     test("0", 10, Down, "0", Equal);
@@ -59,6 +61,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("0", 10, Floor, "0", Equal);
     test("0", 10, Ceiling, "0", Equal);
     test("0", 10, Nearest, "0", Equal);
+    test("0", 10, NearestTiesAway, "0", Equal);
+    test("0", 10, ToOdd, "0", Equal);
     test("0", 10, Exact, "0", Equal);
 
     test("123", 0, Down, "123", Equal);
@@ -66,6 +70,8 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 0, Floor, "123", Equal);
     test("123", 0, Ceiling, "123", Equal);
     test("123", 0, Nearest, "123", Equal);
+    test("123", 0, NearestTiesAway, "123", Equal);
+    test("123", 0, ToOdd, "123", Equal);
     test("123", 0, Exact, "123", Equal);
 
     test("123", 2, Down, "120", Less);
@@ -73,12 +79,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("123", 2, Floor, "120", Less);
     test("123", 2, Ceiling, "124", Greater);
     test("123", 2, Nearest, "124", Greater);
+    test("123", 2, NearestTiesAway, "124", Greater);
+    test("123", 2, ToOdd, "124", Greater);
 
     test("123", -2, Down, "123", Equal);
     test("123", -2, Up, "123", Equal);
     test("123", -2, Floor, "123", Equal);
     test("123", -2, Ceiling, "123", Equal);
     test("123", -2, Nearest, "123", Equal);
+    test("123", -2, NearestTiesAway, "123", Equal);
+    test("123", -2, ToOdd, "123", Equal);
     test("123", -2, Exact, "123", Equal);
 
     test("884279719003555/281474976710656", 2, Down, "0", Less);
@@ -86,6 +96,14 @@ fn test_round_to_multiple_of_power_of_2() {
     test("884279719003555/281474976710656", 2, Floor, "0", Less);
     test("884279719003555/281474976710656", 2, Ceiling, "4", Greater);
     test("884279719003555/281474976710656", 2, Nearest, "4", Greater);
+    test(
+        "884279719003555/281474976710656",
+        2,
+        NearestTiesAway,
+        "4",
+        Greater,
+    );
+    test("884279719003555/281474976710656", 2, ToOdd, "4", Greater);
 
     test("884279719003555/281474976710656", -4, Down, "25/8", Less);
     test("884279719003555/281474976710656", -4, Up, "51/16", Greater);
@@ -98,6 +116,20 @@ fn test_round_to_multiple_of_power_of_2() {
         Greater,
     );
     test("884279719003555/281474976710656", -4, Nearest, "25/8", Less);
+    test(
+        "884279719003555/281474976710656",
+        -4,
+        NearestTiesAway,
+        "25/8",
+        Less,
+    );
+    test(
+        "884279719003555/281474976710656",
+        -4,
+        ToOdd,
+        "51/16",
+        Greater,
+    );
 
     test("884279719003555/281474976710656", -10, Down, "201/64", Less);
     test(
@@ -128,12 +160,28 @@ fn test_round_to_multiple_of_power_of_2() {
         "3217/1024",
         Greater,
     );
+    test(
+        "884279719003555/281474976710656",
+        -10,
+        NearestTiesAway,
+        "3217/1024",
+        Greater,
+    );
+    test(
+        "884279719003555/281474976710656",
+        -10,
+        ToOdd,
+        "3217/1024",
+        Greater,
+    );
 
     test("-123", 0, Down, "-123", Equal);
     test("-123", 0, Up, "-123", Equal);
     test("-123", 0, Floor, "-123", Equal);
     test("-123", 0, Ceiling, "-123", Equal);
     test("-123", 0, Nearest, "-123", Equal);
+    test("-123", 0, NearestTiesAway, "-123", Equal);
+    test("-123", 0, ToOdd, "-123", Equal);
     test("-123", 0, Exact, "-123", Equal);
 
     test("-123", 2, Down, "-120", Greater);
@@ -141,12 +189,16 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-123", 2, Floor, "-124", Less);
     test("-123", 2, Ceiling, "-120", Greater);
     test("-123", 2, Nearest, "-124", Less);
+    test("-123", 2, NearestTiesAway, "-124", Less);
+    test("-123", 2, ToOdd, "-124", Less);
 
     test("-123", -2, Down, "-123", Equal);
     test("-123", -2, Up, "-123", Equal);
     test("-123", -2, Floor, "-123", Equal);
     test("-123", -2, Ceiling, "-123", Equal);
     test("-123", -2, Nearest, "-123", Equal);
+    test("-123", -2, NearestTiesAway, "-123", Equal);
+    test("-123", -2, ToOdd, "-123", Equal);
     test("-123", -2, Exact, "-123", Equal);
 
     test("-884279719003555/281474976710656", 2, Down, "0", Greater);
@@ -154,6 +206,14 @@ fn test_round_to_multiple_of_power_of_2() {
     test("-884279719003555/281474976710656", 2, Floor, "-4", Less);
     test("-884279719003555/281474976710656", 2, Ceiling, "0", Greater);
     test("-884279719003555/281474976710656", 2, Nearest, "-4", Less);
+    test(
+        "-884279719003555/281474976710656",
+        2,
+        NearestTiesAway,
+        "-4",
+        Less,
+    );
+    test("-884279719003555/281474976710656", 2, ToOdd, "-4", Less);
 
     test(
         "-884279719003555/281474976710656",
@@ -184,6 +244,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-25/8",
         Greater,
     );
+    test(
+        "-884279719003555/281474976710656",
+        -4,
+        NearestTiesAway,
+        "-25/8",
+        Greater,
+    );
+    test(
+        "-884279719003555/281474976710656",
+        -4,
+        ToOdd,
+        "-51/16",
+        Less,
+    );
 
     test(
         "-884279719003555/281474976710656",
@@ -220,6 +294,20 @@ fn test_round_to_multiple_of_power_of_2() {
         "-3217/1024",
         Less,
     );
+    test(
+        "-884279719003555/281474976710656",
+        -10,
+        NearestTiesAway,
+        "-3217/1024",
+        Less,
+    );
+    test(
+        "-884279719003555/281474976710656",
+        -10,
+        ToOdd,
+        "-3217/1024",
+        Less,
+    );
 }
 
 #[test]
@@ -345,7 +433,7 @@ fn round_to_multiple_of_power_of_2_properties() {
             Down => assert!(r.le_abs(&n)),
             Up => assert!(r.ge_abs(&n)),
             Exact => assert_eq!(r, n),
-            Nearest => {
+            Nearest | NearestTiesAway => {
                 let k = Rational::power_of_2(pow);
                 let closest;
                 let second_closest;
@@ -358,9 +446,14 @@ fn round_to_multiple_of_power_of_2_properties() {
                 }
                 assert!(closest <= second_closest);
                 if closest == second_closest {
-                    assert!(Integer::exact_from(r >> pow).even());
+                    if rm == Nearest {
+                        assert!(Integer::exact_from(r >> pow).even());
+                    } else {
+                        assert_eq!(o, if r > 0 { Greater } else { Less });
+                    }
                 }
             }
+            ToOdd => assert!(r == n || Integer::exact_from(r >> pow).odd()),
         }
     });
 
@@ -372,6 +465,11 @@ fn round_to_multiple_of_power_of_2_properties() {
         assert_eq!((&rounded).round_to_multiple_of_power_of_2(pow, Floor), ro);
         assert_eq!((&rounded).round_to_multiple_of_power_of_2(pow, Ceiling), ro);
         assert_eq!((&rounded).round_to_multiple_of_power_of_2(pow, Nearest), ro);
+        assert_eq!(
+            (&rounded).round_to_multiple_of_power_of_2(pow, NearestTiesAway),
+            ro
+        );
+        assert_eq!((&rounded).round_to_multiple_of_power_of_2(pow, ToOdd), ro);
         assert_eq!((&rounded).round_to_multiple_of_power_of_2(pow, Exact), ro);
     });
 
@@ -387,6 +485,10 @@ fn round_to_multiple_of_power_of_2_properties() {
             assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Up), floor);
             assert_eq!((&n).round_to_multiple_of_power_of_2(pow, Down), ceiling);
         }
+        let nearest_ties_away = (&n).round_to_multiple_of_power_of_2(pow, NearestTiesAway);
+        assert!(nearest_ties_away == ceiling || nearest_ties_away == floor);
+        let to_odd = (&n).round_to_multiple_of_power_of_2(pow, ToOdd);
+        assert!(to_odd == ceiling || to_odd == floor);
         let nearest = n.round_to_multiple_of_power_of_2(pow, Nearest);
         assert!(nearest == ceiling || nearest == floor);
     });
@@ -110,6 +110,8 @@ fn test_rounding_from_rational() {
     test("123", Ceiling, "123", Equal);
     test("123", Up, "123", Equal);
     test("123", Nearest, "123", Equal);
+    test("123", NearestTiesAway, "123", Equal);
+    test("123", ToOdd, "123", Equal);
     test("123", Exact, "123", Equal);
 
     test("-123", Floor, "-123", Equal);
@@ -117,6 +119,8 @@ fn test_rounding_from_rational() {
     test("-123", Ceiling, "-123", Equal);
     test("-123", Up, "-123", Equal);
     test("-123", Nearest, "-123", Equal);
+    test("-123", NearestTiesAway, "-123", Equal);
+    test("-123", ToOdd, "-123", Equal);
     test("-123", Exact, "-123", Equal);
 
     test("22/7", Floor, "3", Less);
@@ -124,24 +128,32 @@ fn test_rounding_from_rational() {
     test("22/7", Ceiling, "4", Greater);
     test("22/7", Up, "4", Greater);
     test("22/7", Nearest, "3", Less);
+    test("22/7", NearestTiesAway, "3", Less);
+    test("22/7", ToOdd, "3", Less);
 
     test("-22/7", Floor, "-4", Less);
     test("-22/7", Down, "-3", Greater);
     test("-22/7", Ceiling, "-3", Greater);
     test("-22/7", Up, "-4", Less);
     test("-22/7", Nearest, "-3", Greater);
+    test("-22/7", NearestTiesAway, "-3", Greater);
+    test("-22/7", ToOdd, "-3", Greater);
 
     test("7/2", Floor, "3", Less);
     test("7/2", Down, "3", Less);
     test("7/2", Ceiling, "4", Greater);
     test("7/2", Up, "4", Greater);
     test("7/2", Nearest, "4", Greater);
+    test("7/2", NearestTiesAway, "4", Greater);
+    test("7/2", ToOdd, "3", Less);
 
     test("9/2", Floor, "4", Less);
     test("9/2", Down, "4", Less);
     test("9/2", Ceiling, "5", Greater);
     test("9/2", Up, "5", Greater);
     test("9/2", Nearest, "4", Less);
+    test("9/2", NearestTiesAway, "5", Greater);
+    test("9/2", ToOdd, "5", Greater);
 }
 
 #[test]
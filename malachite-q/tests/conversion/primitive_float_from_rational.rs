@@ -64,6 +64,8 @@ fn test_f32_rounding_from_rational() {
     test("16777217", Ceiling, 1.6777218e7, Greater);
     test("16777217", Up, 1.6777218e7, Greater);
     test("16777217", Nearest, 1.6777216e7, Less);
+    test("16777217", NearestTiesAway, 1.6777218e7, Greater);
+    test("16777217", ToOdd, 1.6777218e7, Greater);
 
     test("-16777217", Floor, -1.6777218e7, Less);
     test("-16777217", Down, -1.6777216e7, Greater);
@@ -92,6 +94,10 @@ fn test_f32_rounding_from_rational() {
     test("-33554434", Nearest, -3.3554432e7, Greater);
     test("33554435", Nearest, 3.3554436e7, Greater);
     test("-33554435", Nearest, -3.3554436e7, Less);
+    test("33554434", NearestTiesAway, 3.3554436e7, Greater);
+    test("-33554434", NearestTiesAway, -3.3554436e7, Less);
+    test("33554433", ToOdd, 3.3554436e7, Greater);
+    test("-33554433", ToOdd, -3.3554436e7, Less);
 
     test(
         "340282346638528859811704183484516925439",
@@ -424,6 +430,18 @@ fn test_f32_rounding_from_rational() {
         0.0,
         Less,
     );
+    test(
+        "1/100000000000000000000000000000000000000000000000000",
+        NearestTiesAway,
+        0.0,
+        Less,
+    );
+    test(
+        "1/100000000000000000000000000000000000000000000000000",
+        ToOdd,
+        1.0e-45,
+        Greater,
+    );
 
     test(
         "-1/100000000000000000000000000000000000000000000000000",
@@ -455,6 +473,18 @@ fn test_f32_rounding_from_rational() {
         -0.0,
         Greater,
     );
+    test(
+        "-1/100000000000000000000000000000000000000000000000000",
+        NearestTiesAway,
+        -0.0,
+        Greater,
+    );
+    test(
+        "-1/100000000000000000000000000000000000000000000000000",
+        ToOdd,
+        -1.0e-45,
+        Less,
+    );
 
     // half of smallest positive
     test(
@@ -487,6 +517,18 @@ fn test_f32_rounding_from_rational() {
         0.0,
         Less,
     );
+    test(
+        "1/1427247692705959881058285969449495136382746624",
+        NearestTiesAway,
+        1.0e-45,
+        Greater,
+    );
+    test(
+        "1/1427247692705959881058285969449495136382746624",
+        ToOdd,
+        1.0e-45,
+        Greater,
+    );
 
     test(
         "-1/1427247692705959881058285969449495136382746624",
@@ -518,6 +560,18 @@ fn test_f32_rounding_from_rational() {
         -0.0,
         Greater,
     );
+    test(
+        "-1/1427247692705959881058285969449495136382746624",
+        NearestTiesAway,
+        -1.0e-45,
+        Less,
+    );
+    test(
+        "-1/1427247692705959881058285969449495136382746624",
+        ToOdd,
+        -1.0e-45,
+        Less,
+    );
 
     // just over half of smallest positive; Nearest rounds up
     test(
@@ -103,6 +103,8 @@ fn test_u32_rounding_from_rational() {
     test("123", Down, 123, Equal);
     test("123", Up, 123, Equal);
     test("123", Nearest, 123, Equal);
+    test("123", NearestTiesAway, 123, Equal);
+    test("123", ToOdd, 123, Equal);
     test("123", Exact, 123, Equal);
 
     test("22/7", Floor, 3, Less);
@@ -110,26 +112,35 @@ fn test_u32_rounding_from_rational() {
     test("22/7", Down, 3, Less);
     test("22/7", Up, 4, Greater);
     test("22/7", Nearest, 3, Less);
+    test("22/7", NearestTiesAway, 3, Less);
+    test("22/7", ToOdd, 3, Less);
 
     test("7/2", Floor, 3, Less);
     test("7/2", Ceiling, 4, Greater);
     test("7/2", Down, 3, Less);
     test("7/2", Up, 4, Greater);
     test("7/2", Nearest, 4, Greater);
+    test("7/2", NearestTiesAway, 4, Greater);
+    test("7/2", ToOdd, 3, Less);
 
     test("9/2", Floor, 4, Less);
     test("9/2", Ceiling, 5, Greater);
     test("9/2", Down, 4, Less);
     test("9/2", Up, 5, Greater);
     test("9/2", Nearest, 4, Less);
+    test("9/2", NearestTiesAway, 5, Greater);
+    test("9/2", ToOdd, 5, Greater);
 
     test("-123", Ceiling, 0, Greater);
     test("-123", Down, 0, Greater);
     test("-123", Nearest, 0, Greater);
+    test("-123", NearestTiesAway, 0, Greater);
 
     test("1000000000000", Floor, u32::MAX, Less);
     test("1000000000000", Down, u32::MAX, Less);
     test("1000000000000", Nearest, u32::MAX, Less);
+    test("1000000000000", NearestTiesAway, u32::MAX, Less);
+    test("1000000000000", ToOdd, u32::MAX, Less);
 }
 
 #[test]
@@ -145,6 +156,8 @@ fn test_i32_rounding_from_rational() {
     test("123", Down, 123, Equal);
     test("123", Up, 123, Equal);
     test("123", Nearest, 123, Equal);
+    test("123", NearestTiesAway, 123, Equal);
+    test("123", ToOdd, 123, Equal);
     test("123", Exact, 123, Equal);
 
     test("22/7", Floor, 3, Less);
@@ -152,32 +165,43 @@ fn test_i32_rounding_from_rational() {
     test("22/7", Down, 3, Less);
     test("22/7", Up, 4, Greater);
     test("22/7", Nearest, 3, Less);
+    test("22/7", NearestTiesAway, 3, Less);
+    test("22/7", ToOdd, 3, Less);
 
     test("-22/7", Floor, -4, Less);
     test("-22/7", Ceiling, -3, Greater);
     test("-22/7", Down, -3, Greater);
     test("-22/7", Up, -4, Less);
     test("-22/7", Nearest, -3, Greater);
+    test("-22/7", NearestTiesAway, -3, Greater);
+    test("-22/7", ToOdd, -3, Greater);
 
     test("7/2", Floor, 3, Less);
     test("7/2", Ceiling, 4, Greater);
     test("7/2", Down, 3, Less);
     test("7/2", Up, 4, Greater);
     test("7/2", Nearest, 4, Greater);
+    test("7/2", NearestTiesAway, 4, Greater);
+    test("7/2", ToOdd, 3, Less);
 
     test("9/2", Floor, 4, Less);
     test("9/2", Ceiling, 5, Greater);
     test("9/2", Down, 4, Less);
     test("9/2", Up, 5, Greater);
     test("9/2", Nearest, 4, Less);
+    test("9/2", NearestTiesAway, 5, Greater);
+    test("9/2", ToOdd, 5, Greater);
 
     test("-1000000000000", Ceiling, i32::MIN, Greater);
     test("-1000000000000", Down, i32::MIN, Greater);
     test("-1000000000000", Nearest, i32::MIN, Greater);
+    test("-1000000000000", NearestTiesAway, i32::MIN, Greater);
 
     test("1000000000000", Floor, i32::MAX, Less);
     test("1000000000000", Down, i32::MAX, Less);
     test("1000000000000", Nearest, i32::MAX, Less);
+    test("1000000000000", NearestTiesAway, i32::MAX, Less);
+    test("1000000000000", ToOdd, i32::MAX, Less);
 }
 
 #[test]
@@ -188,6 +212,7 @@ fn rounding_from_rational_fail() {
     let x = Rational::from_str("-123").unwrap();
     assert_panic!(u32::rounding_from(&x, Floor));
     assert_panic!(u32::rounding_from(&x, Up));
+    assert_panic!(u32::rounding_from(&x, ToOdd));
     assert_panic!(u32::rounding_from(&x, Exact));
 
     let x = Rational::from_str("1000000000000").unwrap();
@@ -201,6 +226,7 @@ fn rounding_from_rational_fail() {
     let x = Rational::from_str("-1000000000000").unwrap();
     assert_panic!(i32::rounding_from(&x, Floor));
     assert_panic!(i32::rounding_from(&x, Up));
+    assert_panic!(i32::rounding_from(&x, ToOdd));
     assert_panic!(i32::rounding_from(&x, Exact));
 
     let x = Rational::from_str("1000000000000").unwrap();
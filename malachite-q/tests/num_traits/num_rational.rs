@@ -0,0 +1,64 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use num::{BigInt, BigRational};
+use std::str::FromStr;
+
+#[test]
+fn test_from_big_rational() {
+    let test = |s| {
+        let x = BigRational::from_str(s).unwrap();
+        let y = Rational::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+        let y = Rational::from(x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("22/7");
+    test("-22/7");
+    test("1000000000000000000000000/3");
+    test("-1/1000000000000000000000000");
+
+    // A `BigRational` constructed with `new_raw` need not be normalized
+    let x = BigRational::new_raw(BigInt::from(6), BigInt::from(-4));
+    assert_eq!(Rational::from(&x).to_string(), "-3/2");
+    assert_eq!(Rational::from(x).to_string(), "-3/2");
+}
+
+#[test]
+fn test_from_rational() {
+    let test = |s| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(BigRational::from(&x).to_string(), s);
+        assert_eq!(BigRational::from(x).to_string(), s);
+    };
+    test("0");
+    test("123");
+    test("-123");
+    test("22/7");
+    test("-22/7");
+    test("1000000000000000000000000/3");
+    test("-1/1000000000000000000000000");
+}
+
+#[test]
+fn num_rational_properties() {
+    rational_gen().test_properties(|x| {
+        let y = BigRational::from(&x);
+        assert_eq!(BigRational::from(x.clone()), y);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(Rational::from(&y), x);
+        assert_eq!(Rational::from(y), x);
+    });
+}
@@ -0,0 +1,208 @@
+// Copyright © 2024 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::basic::traits::NegativeInfinity;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::test_util::generators::{primitive_float_gen, signed_gen, unsigned_gen};
+use malachite_q::test_util::generators::{rational_gen, rational_pair_gen};
+use malachite_q::Rational;
+use num::traits::{CheckedMul, FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Zero};
+use num::BigRational;
+use std::str::FromStr;
+
+#[test]
+fn test_zero_and_one() {
+    assert_eq!(<Rational as Zero>::zero(), 0);
+    assert_eq!(<Rational as One>::one(), 1);
+    assert!(Zero::is_zero(&Rational::from(0)));
+    assert!(!Zero::is_zero(&Rational::from_signeds(1, 2)));
+    assert!(One::is_one(&Rational::from(1)));
+    assert!(!One::is_one(&Rational::from(-1)));
+}
+
+#[test]
+fn test_from_str_radix() {
+    let test = |s, radix, out: Option<&str>| {
+        assert_eq!(
+            Rational::from_str_radix(s, radix)
+                .ok()
+                .map(|x| x.to_string())
+                .as_deref(),
+            out
+        );
+    };
+    test("0", 10, Some("0"));
+    test("-0", 10, Some("0"));
+    test("123", 10, Some("123"));
+    test("-123", 10, Some("-123"));
+    test("22/7", 10, Some("22/7"));
+    test("-22/7", 10, Some("-22/7"));
+    test("6/4", 10, Some("3/2"));
+    test("ff", 16, Some("255"));
+    test("-ff/10", 16, Some("-255/16"));
+    test("1010/11", 2, Some("10/3"));
+    test("", 10, None);
+    test("-", 10, None);
+    test("/", 10, None);
+    test("1/", 10, None);
+    test("/1", 10, None);
+    test("1/0", 10, None);
+    test("1/-2", 10, None);
+    test("--1", 10, None);
+    test("1/2/3", 10, None);
+    test("12", 2, None);
+}
+
+#[test]
+#[should_panic]
+fn from_str_radix_fail() {
+    Rational::from_str_radix("1", 37).unwrap();
+}
+
+#[test]
+fn test_signed() {
+    let test = |s, abs, signum, positive, negative| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(Signed::abs(&x).to_string(), abs);
+        assert_eq!(Signed::signum(&x).to_string(), signum);
+        assert_eq!(x.is_positive(), positive);
+        assert_eq!(x.is_negative(), negative);
+    };
+    test("0", "0", "0", false, false);
+    test("22/7", "22/7", "1", true, false);
+    test("-22/7", "22/7", "-1", false, true);
+
+    let test_abs_sub = |s, t, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = Rational::from_str(t).unwrap();
+        assert_eq!(x.abs_sub(&y).to_string(), out);
+    };
+    test_abs_sub("1/2", "1/3", "1/6");
+    test_abs_sub("1/3", "1/2", "0");
+    test_abs_sub("1/2", "1/2", "0");
+}
+
+#[test]
+fn test_pow() {
+    let x = Rational::from_signeds(-2, 3);
+    assert_eq!(Pow::pow(&x, 3u8).to_string(), "-8/27");
+    assert_eq!(Pow::pow(&x, 0usize).to_string(), "1");
+    assert_eq!(Pow::pow(&x, -2i32).to_string(), "9/4");
+    assert_eq!(Pow::pow(x, -3isize).to_string(), "-27/8");
+}
+
+#[test]
+fn test_to_primitive() {
+    let test = |s, i: Option<i64>, u: Option<u64>, f: f64| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.to_i64(), i);
+        assert_eq!(x.to_u64(), u);
+        assert_eq!(NiceFloat(x.to_f64().unwrap()), NiceFloat(f));
+    };
+    test("0", Some(0), Some(0), 0.0);
+    test("22/7", Some(3), Some(3), 3.142857142857143);
+    test("-22/7", Some(-3), None, -3.142857142857143);
+    test("-1/2", Some(0), Some(0), -0.5);
+    test(
+        "9223372036854775808",
+        None,
+        Some(9223372036854775808),
+        9.223372036854776e18,
+    );
+
+    let x = Rational::from_str("-55340232221128654849/3").unwrap();
+    assert_eq!(x.to_i128(), Some(-18446744073709551616));
+    assert_eq!(x.to_u128(), None);
+
+    let max = Rational::exact_from(f64::MAX_FINITE);
+    let ulp =
+        Rational::exact_from(f64::MAX_FINITE) - Rational::exact_from(f64::MAX_FINITE.next_lower());
+    let halfway = &max + (&ulp >> 1u32);
+    assert_eq!(
+        NiceFloat((&halfway - Rational::from(1u32)).to_f64().unwrap()),
+        NiceFloat(f64::MAX_FINITE)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f64().unwrap()),
+        NiceFloat(f64::INFINITY)
+    );
+    assert_eq!(
+        NiceFloat((-&halfway).to_f64().unwrap()),
+        NiceFloat(f64::NEGATIVE_INFINITY)
+    );
+    assert_eq!(
+        NiceFloat(halfway.to_f32().unwrap()),
+        NiceFloat(f32::INFINITY)
+    );
+}
+
+#[test]
+fn test_from_primitive() {
+    assert_eq!(Rational::from_i64(-5).unwrap(), -5);
+    assert_eq!(Rational::from_u128(u128::MAX).unwrap(), u128::MAX);
+    assert_eq!(Rational::from_f64(-0.75).unwrap().to_string(), "-3/4");
+    assert_eq!(
+        Rational::from_f32(0.1).unwrap().to_string(),
+        "13421773/134217728"
+    );
+    assert_eq!(Rational::from_f64(f64::NAN), None);
+    assert_eq!(Rational::from_f64(f64::INFINITY), None);
+    assert_eq!(Rational::from_f32(f32::NEGATIVE_INFINITY), None);
+}
+
+#[test]
+fn test_checked_mul() {
+    let x = Rational::from_signeds(22, 7);
+    let y = Rational::from_signeds(-7, 2);
+    assert_eq!(CheckedMul::checked_mul(&x, &y).unwrap(), -11);
+}
+
+#[test]
+fn num_traits_properties() {
+    rational_gen().test_properties(|x| {
+        let num_x = BigRational::from(&x);
+        assert_eq!(x.to_i64(), num_x.to_i64());
+        assert_eq!(x.to_u64(), num_x.to_u64());
+        assert_eq!(
+            NiceFloat(x.to_f64().unwrap()),
+            NiceFloat(num_x.to_f64().unwrap())
+        );
+        assert_eq!(Signed::abs(&x), Rational::from(Signed::abs(&num_x)));
+        assert_eq!(Signed::signum(&x), Rational::from(Signed::signum(&num_x)));
+        assert_eq!(Rational::from_str_radix(&x.to_string(), 10).unwrap(), x);
+    });
+
+    rational_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(CheckedMul::checked_mul(&x, &y), Some(&x * &y));
+        assert_eq!(
+            x.abs_sub(&y),
+            Rational::from(BigRational::from(&x).abs_sub(&BigRational::from(&y)))
+        );
+    });
+
+    primitive_float_gen::<f64>().test_properties(|f| {
+        let x = Rational::from_f64(f);
+        assert_eq!(x.is_some(), f.is_finite());
+        if let Some(x) = x {
+            assert_eq!(
+                NiceFloat(x.to_f64().unwrap()),
+                NiceFloat(f.abs_negative_zero())
+            );
+        }
+    });
+
+    signed_gen::<i64>().test_properties(|i| {
+        assert_eq!(Rational::from_i64(i).unwrap().to_i64(), Some(i));
+    });
+
+    unsigned_gen::<u64>().test_properties(|u| {
+        assert_eq!(Rational::from_u64(u).unwrap().to_u64(), Some(u));
+    });
+}
@@ -14,6 +14,11 @@ pub use malachite_base::*;
 #[cfg(feature = "floats")]
 pub use malachite_float::Float;
 #[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "floats")]
+#[cfg(feature = "enable_pyo3")]
+pub use malachite_float::conversion::pyo3::DecimalFloat;
+#[cfg(feature = "naturals_and_integers")]
 pub use malachite_nz::integer::Integer;
 #[cfg(feature = "naturals_and_integers")]
 pub use malachite_nz::natural::Natural;